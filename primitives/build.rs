@@ -0,0 +1,572 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+//! Generate the poseidon and rescue constants at build time.
+//!
+//! The poseidon tables are derived with the Grain LFSR of the reference
+//! implementation, through `find_poseidon_ark_and_mds` of ark, and written
+//! to `$OUT_DIR/poseidon_constants.rs` as `const` field elements and
+//! `PoseidonConstants`, which is included by `src/poseidon/constants.rs`.
+//! Adding a field only takes a new entry in `main` and the matching
+//! `impl PoseidonParameter`.
+//!
+//! The rescue parameters are derived as in the Marvellous scripts, from the
+//! multiplicative generator of the field and from the initial constants and
+//! key schedule sampled for it, and written to `$OUT_DIR/rescue_constants.rs`
+//! as `impl RescueParameter` blocks, which is included by
+//! `src/rescue/rescue_constants.rs`. The samples are the ones printed by the
+//! Marvellous scripts, or taken from SHAKE128 for the fields they do not
+//! cover.
+
+use ark_crypto_primitives::sponge::poseidon::find_poseidon_ark_and_mds;
+use ark_ff::{BigInteger, PrimeField};
+use num_bigint::BigUint;
+use sha3::{
+    digest::{ExtendableOutput, Update, XofReader},
+    Shake128,
+};
+use std::{array, env, fmt::Write, fs, path::Path};
+
+/// The # of full rounds for every instance.
+const FULL_ROUNDS: u64 = 8;
+/// The rate of `PoseidonParameter::config`.
+const CRHF_RATE: usize = 3;
+/// The # of partial rounds of circomlib for `1..=16` inputs.
+const CIRCOM_PARTIAL_ROUNDS: [u64; 16] = [
+    56, 57, 56, 60, 60, 63, 64, 63, 60, 66, 60, 65, 70, 60, 64, 68,
+];
+
+/// The # of rounds of the rescue permutation, `ROUNDS` of `src/rescue`.
+const RESCUE_ROUNDS: usize = 12;
+/// The state size of the rescue permutation, `STATE_SIZE` of `src/rescue`.
+const RESCUE_STATE_SIZE: usize = 4;
+
+type RescueVector<F> = [F; RESCUE_STATE_SIZE];
+type RescueMatrix<F> = [RescueVector<F>; RESCUE_STATE_SIZE];
+
+fn main() {
+    let mut out = String::new();
+    // gcd(5, p - 1) = 1 for both the bn254 and bls12_381 scalar fields
+    write_tables::<ark_ed_on_bn254::Fq>(
+        &mut out,
+        "ED_ON_BN254",
+        "ark_ed_on_bn254::Fq",
+        CRHF_RATE,
+        5,
+        56,
+    );
+    write_tables::<ark_ed_on_bls12_381::Fq>(
+        &mut out,
+        "ED_ON_BLS12_381",
+        "ark_ed_on_bls12_381::Fq",
+        CRHF_RATE,
+        5,
+        56,
+    );
+    // 11 is the smallest alpha such that gcd(alpha, p - 1) = 1 for the
+    // bls12_377 scalar field, same as `RescueParameter::A`
+    write_tables::<ark_ed_on_bls12_377::Fq>(
+        &mut out,
+        "ED_ON_BLS12_377",
+        "ark_ed_on_bls12_377::Fq",
+        CRHF_RATE,
+        11,
+        37,
+    );
+
+    let mut circom =
+        String::from("pub(crate) const CIRCOM: [PoseidonConstants<ark_bn254::Fr>; 16] = [");
+    for (i, partial_rounds) in CIRCOM_PARTIAL_ROUNDS.iter().enumerate() {
+        let name = format!("CIRCOM_{}", i + 1);
+        write_tables::<ark_bn254::Fr>(&mut out, &name, "ark_bn254::Fr", i + 1, 5, *partial_rounds);
+        write!(circom, "{name}, ").unwrap();
+    }
+    writeln!(out, "{circom}];").unwrap();
+
+    let out_dir = env::var("OUT_DIR").expect("cargo sets OUT_DIR");
+    fs::write(Path::new(&out_dir).join("poseidon_constants.rs"), out)
+        .expect("failed to write the poseidon constants");
+
+    let mut out = String::new();
+    write_rescue_parameter::<ark_bn254::Fq>(&mut out, "ark_bn254::Fq", 5, BN254_BASE.parse());
+    write_rescue_parameter::<ark_ed_on_bn254::Fq>(
+        &mut out,
+        "ark_ed_on_bn254::Fq",
+        5,
+        ED_ON_BN254_BASE.parse(),
+    );
+    write_rescue_parameter::<ark_ed_on_bls12_381::Fq>(
+        &mut out,
+        "ark_ed_on_bls12_381::Fq",
+        5,
+        ED_ON_BLS12_381_BASE.parse(),
+    );
+    // 11 is the smallest alpha such that gcd(alpha, p - 1) = 1
+    write_rescue_parameter::<ark_ed_on_bls12_377::Fq>(
+        &mut out,
+        "ark_ed_on_bls12_377::Fq",
+        11,
+        ED_ON_BLS12_377_BASE.parse(),
+    );
+    write_rescue_parameter::<ark_bls12_377::Fq>(
+        &mut out,
+        "ark_bls12_377::Fq",
+        5,
+        BLS12_377_BASE.parse(),
+    );
+    write_rescue_parameter::<ark_pallas::Fq>(
+        &mut out,
+        "ark_pallas::Fq",
+        5,
+        RescueSamples::from_shake("Jellyfish Rescue pallas_base"),
+    );
+    write_rescue_parameter::<ark_pallas::Fr>(
+        &mut out,
+        "ark_pallas::Fr",
+        5,
+        RescueSamples::from_shake("Jellyfish Rescue vesta_base"),
+    );
+    fs::write(Path::new(&out_dir).join("rescue_constants.rs"), out)
+        .expect("failed to write the rescue constants");
+    println!("cargo:rerun-if-changed=build.rs");
+}
+
+/// Write `{name}`, the `PoseidonConstants` of a poseidon instance with `rate`
+/// and a capacity of 1 over the field `F` named `ty`, and its tables
+/// `{name}_ARK` and `{name}_MDS`.
+fn write_tables<F: PrimeField>(
+    out: &mut String,
+    name: &str,
+    ty: &str,
+    rate: usize,
+    alpha: u64,
+    partial_rounds: u64,
+) {
+    let (ark, mds) = find_poseidon_ark_and_mds::<F>(
+        F::MODULUS_BIT_SIZE as u64,
+        rate,
+        FULL_ROUNDS,
+        partial_rounds,
+        0,
+    );
+    write_matrix(out, &format!("{name}_ARK"), ty, &ark);
+    write_matrix(out, &format!("{name}_MDS"), ty, &mds);
+    writeln!(
+        out,
+        "pub(crate) const {name}: PoseidonConstants<{ty}> = PoseidonConstants {{ \
+         partial_rounds: {partial_rounds}, alpha: {alpha}, ark: {name}_ARK, mds: {name}_MDS }};"
+    )
+    .unwrap();
+}
+
+fn write_matrix<F: PrimeField>(out: &mut String, name: &str, ty: &str, matrix: &[Vec<F>]) {
+    writeln!(out, "const {name}: &[&[{ty}]] = &[").unwrap();
+    for row in matrix {
+        out.push_str("    &[");
+        for elem in row {
+            let value: BigUint = elem.into_bigint().into();
+            write!(out, "MontFp!(\"{value}\"), ").unwrap();
+        }
+        out.push_str("],\n");
+    }
+    out.push_str("];\n");
+}
+
+/// The inputs of a rescue instance that the Marvellous scripts sample at
+/// random: the initial constants, the key schedule matrix and the key
+/// schedule constants.
+struct RescueSamples<F> {
+    init_vec: RescueVector<F>,
+    key_sched_matrix: RescueMatrix<F>,
+    key_sched_vec: RescueVector<F>,
+}
+
+impl<F: PrimeField> RescueSamples<F> {
+    /// Sample the inputs, in this order, from SHAKE128 seeded with `seed`:
+    /// each element is a 64 bytes little-endian chunk of the output reduced
+    /// mod p, and a key schedule matrix candidate that is not invertible is
+    /// discarded.
+    fn from_shake(seed: &str) -> Self {
+        let mut hasher = Shake128::default();
+        hasher.update(seed.as_bytes());
+        let mut reader = hasher.finalize_xof();
+        let mut next = || {
+            let mut bytes = [0u8; 64];
+            reader.read(&mut bytes);
+            F::from_le_bytes_mod_order(&bytes)
+        };
+        let init_vec = array::from_fn(|_| next());
+        let key_sched_matrix = loop {
+            let candidate: RescueMatrix<F> = array::from_fn(|_| array::from_fn(|_| next()));
+            let mut rows: Vec<Vec<F>> = candidate.iter().map(|row| row.to_vec()).collect();
+            if reduce_echelon(&mut rows) {
+                break candidate;
+            }
+        };
+        let key_sched_vec = array::from_fn(|_| next());
+        Self {
+            init_vec,
+            key_sched_matrix,
+            key_sched_vec,
+        }
+    }
+}
+
+impl RescueSamples<&str> {
+    /// Parse decimal samples in the field `F`.
+    fn parse<F: PrimeField>(&self) -> RescueSamples<F> {
+        let parse = |value: &str| {
+            let value = BigUint::parse_bytes(value.as_bytes(), 10).expect("a decimal integer");
+            F::from_le_bytes_mod_order(&value.to_bytes_le())
+        };
+        RescueSamples {
+            init_vec: self.init_vec.map(parse),
+            key_sched_matrix: self.key_sched_matrix.map(|row| row.map(parse)),
+            key_sched_vec: self.key_sched_vec.map(parse),
+        }
+    }
+}
+
+/// Write the `impl RescueParameter` block of the field `F` named `ty`, with
+/// the S-box `x^alpha` and the sampled inputs `samples`:
+/// - the MDS matrix is the right half of the reduced echelon form of the
+///   `STATE_SIZE x 2 * STATE_SIZE` matrix `[g^(i * j)]`, where `g` is the
+///   multiplicative generator of the field;
+/// - `KEY_INJECTION[-1] = INIT_VEC` and `KEY_INJECTION[i] = KEY_SCHED_MATRIX *
+///   KEY_INJECTION[i - 1] + KEY_SCHED_VEC` for `i` in `0..2 * ROUNDS`;
+/// - the permutation round keys are the key schedule of the zero key, as
+///   computed by `PRP::key_schedule`.
+fn write_rescue_parameter<F: PrimeField>(
+    out: &mut String,
+    ty: &str,
+    alpha: u64,
+    samples: RescueSamples<F>,
+) {
+    // alpha * a_inv = 1 mod (p - 1), with a_inv = (k * (p - 1) + 1) / alpha
+    let order = BigUint::from(F::MODULUS) - 1u32;
+    let a_inv = (1..alpha)
+        .map(|k| &order * k + 1u32)
+        .find(|x| x % alpha == BigUint::from(0u32))
+        .expect("gcd(alpha, p - 1) = 1")
+        / alpha;
+    let a_inv = a_inv.to_u64_digits();
+
+    let mut rows: Vec<Vec<F>> = (0..RESCUE_STATE_SIZE as u64)
+        .map(|i| {
+            (0..2 * RESCUE_STATE_SIZE as u64)
+                .map(|j| F::GENERATOR.pow([i * j]))
+                .collect()
+        })
+        .collect();
+    assert!(reduce_echelon(&mut rows));
+    let mds: RescueMatrix<F> =
+        array::from_fn(|i| array::from_fn(|j| rows[i][RESCUE_STATE_SIZE + j]));
+
+    let mut key_injection = Vec::with_capacity(2 * RESCUE_ROUNDS);
+    let mut aux = samples.init_vec;
+    for _ in 0..2 * RESCUE_ROUNDS {
+        aux = affine(&samples.key_sched_matrix, &aux, &samples.key_sched_vec);
+        key_injection.push(aux);
+    }
+
+    let mut round_keys = Vec::with_capacity(2 * RESCUE_ROUNDS + 1);
+    let mut aux = samples.init_vec;
+    round_keys.push(aux);
+    for (i, injection) in key_injection.iter().enumerate() {
+        let exp: &[u64] = if i % 2 == 0 { &a_inv } else { &[alpha] };
+        aux.iter_mut().for_each(|x| *x = x.pow(exp));
+        aux = affine(&mds, &aux, injection);
+        round_keys.push(aux);
+    }
+
+    writeln!(out, "impl RescueParameter for {ty} {{").unwrap();
+    writeln!(out, "    const A: u64 = {alpha};").unwrap();
+    out.push_str("    const A_INV: &'static [u64] = &[");
+    for limb in a_inv.iter() {
+        write!(out, "{limb:#018x}, ").unwrap();
+    }
+    out.push_str("];\n");
+    out.push_str("    const MDS_LE: [[&'static [u8]; STATE_SIZE]; STATE_SIZE] = ");
+    write_vectors(out, &mds);
+    out.push_str("    const INIT_VEC_LE: [&'static [u8]; STATE_SIZE] = ");
+    write_vector(out, &samples.init_vec);
+    out.push_str(";\n");
+    out.push_str("    const KEY_INJECTION_LE: [[&'static [u8]; 4]; 2 * ROUNDS] = ");
+    write_vectors(out, &key_injection);
+    out.push_str("    const PERMUTATION_ROUND_KEYS: [[&'static [u8]; 4]; 25] = ");
+    write_vectors(out, &round_keys);
+    out.push_str("}\n");
+}
+
+/// `matrix * vector + constant`
+fn affine<F: PrimeField>(
+    matrix: &RescueMatrix<F>,
+    vector: &RescueVector<F>,
+    constant: &RescueVector<F>,
+) -> RescueVector<F> {
+    array::from_fn(|i| {
+        matrix[i]
+            .iter()
+            .zip(vector.iter())
+            .map(|(m, x)| *m * x)
+            .sum::<F>()
+            + constant[i]
+    })
+}
+
+/// Put `rows` in reduced echelon form, return false if the left square
+/// block is not invertible.
+fn reduce_echelon<F: PrimeField>(rows: &mut [Vec<F>]) -> bool {
+    for col in 0..rows.len() {
+        let pivot = match (col..rows.len()).find(|&i| !rows[i][col].is_zero()) {
+            Some(pivot) => pivot,
+            None => return false,
+        };
+        rows.swap(col, pivot);
+        let inv = rows[col][col].inverse().expect("the pivot is not zero");
+        rows[col].iter_mut().for_each(|x| *x *= inv);
+        for i in 0..rows.len() {
+            if i != col && !rows[i][col].is_zero() {
+                let factor = rows[i][col];
+                let pivot_row = rows[col].clone();
+                rows[i]
+                    .iter_mut()
+                    .zip(pivot_row.iter())
+                    .for_each(|(x, y)| *x -= factor * y);
+            }
+        }
+    }
+    true
+}
+
+fn write_vectors<F: PrimeField>(out: &mut String, vectors: &[RescueVector<F>]) {
+    out.push_str("[\n");
+    for vector in vectors {
+        out.push_str("        ");
+        write_vector(out, vector);
+        out.push_str(",\n");
+    }
+    out.push_str("    ];\n");
+}
+
+/// Write `vector` as little-endian bytes, the encoding of `RescueParameter`.
+fn write_vector<F: PrimeField>(out: &mut String, vector: &RescueVector<F>) {
+    out.push('[');
+    for elem in vector {
+        out.push_str("&[");
+        for byte in elem.into_bigint().to_bytes_le() {
+            write!(out, "{byte:#04X}, ").unwrap();
+        }
+        out.push_str("], ");
+    }
+    out.push(']');
+}
+
+/// The samples of <https://github.com/EspressoSystems/Marvellous/blob/c189c3ffb7a6a2dd1413b5dc44075b9252c03fe3/rescue_fq254.sage>.
+const BN254_BASE: RescueSamples<&str> = RescueSamples {
+    init_vec: [
+        "14613516837064033601098425266946467757443473160059438324202885522528091826259",
+        "376600575581954944138907282479272561597235884521558434184951080537836545251",
+        "7549886658634274343394883631367643169484909645484867875506884466044920568104",
+        "3682071510138521345600424597536598175842704818239502275576768037591864539852",
+    ],
+    key_sched_matrix: [
+        [
+            "7897853564276958334214468603203897360224447300386032941256994464150170514451",
+            "11310972723508634666485784440114195252677286556466788632574516368513254988277",
+            "16383651337644090722313274572289379126793153853622511649760101581593033542071",
+            "19306563486653957297319716776525895227884092520649933781858037007320767804139",
+        ],
+        [
+            "13972664482939365210126934094159234345695863232497649806392126831140949408017",
+            "3714466336318438487208859168496175478770731896221487243902867315435067535769",
+            "1596938859090899602275687562366103360382671306826725202199223581804895801767",
+            "1804362872899866995550019591021112981788735234705997105061814227248512897822",
+        ],
+        [
+            "11997476979649706166628591475876380417499808454811630791817394950169820584877",
+            "3358221985266097444445103450467306734158849252930592515234916121780934256030",
+            "20936039221738076169991664483088569051709971830788699198763780286950646240322",
+            "19667773949019642472493030024822902488141956158764951449149080401147206088312",
+        ],
+        [
+            "13863553253145612564629096839163156806807653993832358244234635849448929160427",
+            "19384255837703781581818619941103992201663296963767983811656613453424286224072",
+            "21184773793927862104225525427705251361157634391465432236297349113815397067519",
+            "17250450671778424279069213596445482754960723695334722624539983424882343924607",
+        ],
+    ],
+    key_sched_vec: [
+        "14910919218145812220780527690731032091022381281958763374721404950659865181562",
+        "10257734867868901489281448873462275872879440919614134115374023384296039465588",
+        "400456570908486696849138298375881077019481924389373524724992118340704160521",
+        "21663317380571791832909997299481896449625390207097572583867492890910773880130",
+    ],
+};
+/// The samples of <https://github.com/EspressoSystems/Marvellous/blob/fcd4c41672f485ac2f62526bc87a16789d4d0459/rescue254.sage>.
+const ED_ON_BN254_BASE: RescueSamples<&str> = RescueSamples {
+    init_vec: [
+        "14613516837064033601098425266946467918409544647446217386229959902054563533267",
+        "376600575581954944138907282479272751264978206975465380433764825531344567663",
+        "7549886658634274343394883631367643327196152481472281919735617268044202589860",
+        "3682071510138521345600424597536598375718773365536872232193107639375194756918",
+    ],
+    key_sched_matrix: [
+        [
+            "7897853564276958334214468603203897520154891489600286770348232887190718230697",
+            "11310972723508634666485784440114195323987623373489240386156363657972592637889",
+            "16383651337644090722313274572289379168218245780523520967193539841029993172551",
+            "19306563486653957297319716776525895326416632603921620229753286581552964639495",
+        ],
+        [
+            "13972664482939365210126934094159234477516423614171933027224960720992131660723",
+            "3714466336318438487208859168496175617840683365103447095286552900687717723809",
+            "1596938859090899602275687562366103397961147554801212225870985431436994895131",
+            "1804362872899866995550019591021113047181201776456876099010313193931142028794",
+        ],
+        [
+            "11997476979649706166628591475876380529791396928089723905860179374857864726071",
+            "3358221985266097444445103450467306798663635253390735595238412840047057109206",
+            "20936039221738076169991664483088569164149507060948581631797398419708108094482",
+            "19667773949019642472493030024822902610198030586241139616586889558416819285262",
+        ],
+        [
+            "13863553253145612564629096839163156810802216429640669856987145967323207410509",
+            "19384255837703781581818619941103992392218719827512626671850429446834300524280",
+            "21184773793927862104225525427705251552748684553382600329427001063711335358489",
+            "17250450671778424279069213596445482771974600736740494308485859852865380915697",
+        ],
+    ],
+    key_sched_vec: [
+        "14910919218145812220780527690731032179494541897268776131239962376171657535230",
+        "10257734867868901489281448873462275888117956878438433971429895315446063901086",
+        "400456570908486696849138298375881100690963025475664563258385409447538235081",
+        "21663317380571791832909997299481896459241929404413878318271683915422925222920",
+    ],
+};
+/// The samples of <https://github.com/EspressoSystems/Marvellous/blob/fcd4c41672f485ac2f62526bc87a16789d4d0459/rescue381.sage>.
+const ED_ON_BLS12_381_BASE: RescueSamples<&str> = RescueSamples {
+    init_vec: [
+        "23134431890904997735913685390433273947519177060544011867815065124418348995661",
+        "7910743581020883359489900822814213105822551758045258908574127548576902234202",
+        "6452335108146897903818881932868089947456740590166061243393158685187431809297",
+        "864592593827916191968939823230510547087468030011538620003456937932684270153",
+    ],
+    key_sched_matrix: [
+        [
+            "20508694040621567351648110034447675442497630601368754313856884903339587777311",
+            "21833126749675005420257511508741654628498211767962591705355382899857469438170",
+            "10094259962876866855921243786568400951965635335279511958062644189442373357290",
+            "19703017446530302026954081842172601421653728134317178448940156258115853425803",
+        ],
+        [
+            "10251498144790729877114984073212046753223574188577263253763854582885300616634",
+            "23799697215770479455315980501436150478606639549341703710964277906023879274693",
+            "3007859742890615231402252990001597094047140204544326329227026628202747223471",
+            "28206680002640467010077105488518988050496606663258696654543362871739559141116",
+        ],
+        [
+            "3001386190657217866716811031197002190094834176598601007437349105366277408753",
+            "3302832234223427084389235892793462946069958738751625733789554798277785616852",
+            "33828191304584863092326289783666465913001308709873493077072772336792329272781",
+            "39527082973012175895755035046102602497048600747962062191946750704586900696815",
+        ],
+        [
+            "28051483866417948291356906371063959987011977735069581088198413305545643762525",
+            "940230548799789892304826428424685764994822279495712794369041189518965610982",
+            "51086698257646416011541091115454938869982232807222651212625724712549497545484",
+            "17476372527237931823914329908105757745889986759257828348803723712031461055028",
+        ],
+    ],
+    key_sched_vec: [
+        "47547237971610965741643776816276041546468880714675495834455049663798422970459",
+        "10004118136888058764408398782965078987905732598601545000387602435395348015578",
+        "41751554506863950612723183999266149980852802057217063263129581065630539355943",
+        "28630474321717538333837377020183699597240697917209889448356171144508785456174",
+    ],
+};
+/// The samples of <https://github.com/EspressoSystems/Marvellous/blob/fcd4c41672f485ac2f62526bc87a16789d4d0459/rescue377.sage>.
+const ED_ON_BLS12_377_BASE: RescueSamples<&str> = RescueSamples {
+    init_vec: [
+        "7195166260762508599252723804118999247617987830477524724582535753522743429902",
+        "157568923057870085185801131226812508154648823377393772750758002166934615414",
+        "8368974368714179058560102222209821528142740294286535716971241458054331447299",
+        "2193144866846120881109650827139290126159606618067745139279401080525317213903",
+    ],
+    key_sched_matrix: [
+        [
+            "7706576124239544837371600760424887325666871237646566433582815095059014199792",
+            "5866850164576261478222180177187623784425095431438009129648961690750738724033",
+            "5967963618215854522411152750142075651481461240008734293775989630299855162504",
+            "4846413119387162211456448903358698082073272134778198460673863015758257657569",
+        ],
+        [
+            "1245960362769537792640019594237304364405740843817026904000374334904749831719",
+            "7953944257726793928692708838487408421612439320876245321369789667138797299913",
+            "4754797182399066897135937139456358243903942729099936743831234828037073492871",
+            "7499009130375379463556804089865923760153528008940973027774256635004918389563",
+        ],
+        [
+            "6473093830626604929963072604064598990230085260662177396257715740435130466686",
+            "4390337253131514942705827559491664950984265110689583880841633000427121081888",
+            "3522051946840768458577251601407876587632962453320814154152205148191545374550",
+            "6319999439708992933292400869565273518174083699772881386205747820203056621547",
+        ],
+        [
+            "5289326583391743443615481307619780473919202444935065353437083634733980820257",
+            "6938831675933050844113924302108891647008288148791676051457678992328784482036",
+            "1512276495893019861517996168661692331234716309646677384152244252805703243785",
+            "1059856104783947562758392666568158542266706238365295453209304533860586199352",
+        ],
+    ],
+    key_sched_vec: [
+        "6719983214629867114203683304402864967396584522104316535218975820603408161605",
+        "1631001820510975681976157041507139599858468300000865485702360346888662886649",
+        "6512151801847166617260532883979631278806942338258734789784404511769472570827",
+        "396144897873338732377306209560320349606829120111196081730035080973497562014",
+    ],
+};
+/// The samples of <https://github.com/EspressoSystems/Marvellous/blob/fcd4c41672f485ac2f62526bc87a16789d4d0459/rescue761.sage>.
+const BLS12_377_BASE: RescueSamples<&str> = RescueSamples {
+    init_vec: [
+        "77507741804555316993326082334329667156580222818473918560077191823296473118317123729262887858623098431781053309697",
+        "177993200452785627663434075184773911478631764927526237009207307351752484358508273099085386344157374772412110572723",
+        "42671898647707236005613181291770821066233014145576918788154689427213509168112961864404241863766382952036322055440",
+        "45033590264584434678143420627501913399760575844210996496564881493751236188913039187009657997807557295408210829651",
+    ],
+    key_sched_matrix: [
+        [
+            "21388085173807843040147935573417925569769020225193617578144868856502027742399743078769141493256606803926503599374",
+            "148746542684121341018714066208374265919075055377306679043064024347118850671751185133057962508923687274838824500925",
+            "143819582770047829101663158711390879513705210146311295028160623473148675467598427198647083753029698768858402079541",
+            "3320680946084112103693932626141017732413334717099114430920766606434955369475855543216863218041474664802403073058",
+        ],
+        [
+            "183768457328422669222977223024408961920779409488665102575667756351407991511156109410669976864277433172454062790865",
+            "86262470992355870287064697438766110866091133226131257608030067284797613730404633680389970467544288414448970640431",
+            "10141529452607706846164010038504864885212242001966416290596439642012716063698654410399480463149170988637418892069",
+            "34929829384562879985325569510344487753295144848070995250563110087160439452627991533789013213400479994287969078208",
+        ],
+        [
+            "6650937378529070682526639898286326847015452054856629877525247129417093488313988331200070521759802495574854233202",
+            "11207651975622440481011491604501335135454465278672018847895237603502933967106346119682895132466234315451111630038",
+            "178758033912697096063915270457152713862989796724245057415505952206286592260540400038234250838461711082589505119629",
+            "37770614912116059492309039778060447526645530728888326579646215635246607032626978794416808338344285355744338833098",
+        ],
+        [
+            "48007962636822532786431277677071434318837497813225484890354314276686082130099472494756251599795523661039717661648",
+            "40817968235770745163853460250471094884778138289227149084515575536005465633031575643407841686155502248718950599741",
+            "117774843913239531037310660892595295228882572377609668933575123042777696376612146381343869264422107812884980847766",
+            "247129210489551277563634781872704504466060455510724849767887332822384975784500582930038181009342808401241993040433",
+        ],
+    ],
+    key_sched_vec: [
+        "79702341772299253897339955563653516863594788002161465441781145347864436800928803472079765054940723020608613684412",
+        "175404047423848792009109941610650776559153917447549764559702460944568985816882495819821877031781301935630268652925",
+        "163231881198883668972116510882189788203165805540745582259684539022051705322963949586079216081621597497526516392877",
+        "111733091351916013764359218715441472223367220983119617059118647873633602779707362569392865689770593452084742051420",
+    ],
+};
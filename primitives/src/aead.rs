@@ -9,6 +9,18 @@
 //!
 //! We only provide an ultra-thin wrapper for stable APIs for jellyfish users,
 //! independent of RustCrypto's upstream changes.
+//!
+//! Long messages can also be encrypted by segments with the STREAM
+//! construction of [Hoang, Reyhanitabar, Rogaway and Vizár][stream], see
+//! [`EncKey::encrypt_stream`], which protects against the reordering,
+//! removal and truncation of segments.
+//!
+//! XChaCha20-Poly1305 is the only cipher of the module: its extended 24-byte
+//! nonces are sampled at random by [`EncKey::encrypt`], which is safe since
+//! nonce collisions only happen with negligible probability, even for a
+//! large number of messages under the same key.
+//!
+//! [stream]: https://eprint.iacr.org/2015/189
 
 use crate::errors::PrimitivesError;
 use ark_serialize::*;
@@ -19,10 +31,14 @@ use ark_std::{
     vec::Vec,
 };
 use chacha20poly1305::{
-    aead::{Aead, AeadCore, Payload},
+    aead::{
+        stream::{DecryptorBE32, EncryptorBE32},
+        Aead, AeadCore, Payload,
+    },
     KeyInit, XChaCha20Poly1305, XNonce,
 };
 use serde::{Deserialize, Deserializer, Serialize};
+use zeroize::Zeroize;
 
 #[derive(Clone, Eq, Derivative, Serialize, Deserialize)]
 #[derivative(PartialEq, Hash)]
@@ -93,16 +109,45 @@ impl EncKey {
             ephemeral_pk: EncKey(*ephemeral_keypair.public()),
         })
     }
+
+    /// Start the encryption of a message by segments, each authenticated
+    /// along with `aad`. The returned header is needed for decryption, see
+    /// [`KeyPair::decrypt_stream`].
+    pub fn encrypt_stream(
+        &self,
+        mut rng: impl RngCore + CryptoRng,
+        aad: &[u8],
+    ) -> (StreamHeader, StreamEncryptor) {
+        // same derivation of the shared secret as in `Self::encrypt`
+        let ephemeral_keypair = crypto_kx::Keypair::generate(&mut rng);
+        let shared_secret = ephemeral_keypair.session_keys_to(&self.0).tx;
+        let cipher = XChaCha20Poly1305::new(shared_secret.as_ref().into());
+        let mut nonce_prefix = [0u8; STREAM_NONCE_PREFIX_LEN];
+        rng.fill_bytes(&mut nonce_prefix);
+
+        let encryptor = StreamEncryptor {
+            stream: EncryptorBE32::from_aead(cipher, (&nonce_prefix).into()),
+            aad: aad.to_vec(),
+        };
+        let header = StreamHeader {
+            nonce_prefix,
+            ephemeral_pk: EncKey(*ephemeral_keypair.public()),
+        };
+        (header, encryptor)
+    }
 }
 
 /// Private/decryption key for AEAD
-// look into zeroization logic from aead lib
+// the key itself is zeroized on drop by `crypto_kx`, we only scrub the byte
+// copies made when converting or (de)serializing it
 #[derive(Clone, Serialize, Deserialize)]
 struct DecKey(crypto_kx::SecretKey);
 
 impl From<[u8; 32]> for DecKey {
-    fn from(bytes: [u8; 32]) -> Self {
-        Self(crypto_kx::SecretKey::from(bytes))
+    fn from(mut bytes: [u8; 32]) -> Self {
+        let dec_key = Self(crypto_kx::SecretKey::from(bytes));
+        bytes.zeroize();
+        dec_key
     }
 }
 impl From<DecKey> for [u8; 32] {
@@ -178,6 +223,19 @@ impl KeyPair {
             .map_err(|e| PrimitivesError::FailedDecryption(format!("{e:?}")))?;
         Ok(plaintext)
     }
+
+    /// Start the decryption of a message encrypted by segments under
+    /// `header`, with the same associated data as used during encryption.
+    pub fn decrypt_stream(&self, header: &StreamHeader, aad: &[u8]) -> StreamDecryptor {
+        let shared_secret = crypto_kx::Keypair::from(self.dec_key.0.clone())
+            .session_keys_from(&header.ephemeral_pk.0)
+            .rx;
+        let cipher = XChaCha20Poly1305::new(shared_secret.as_ref().into());
+        StreamDecryptor {
+            stream: DecryptorBE32::from_aead(cipher, (&header.nonce_prefix).into()),
+            aad: aad.to_vec(),
+        }
+    }
 }
 // newtype for `chacha20poly1305::XNonce` for easier serde support for
 // `Ciphertext`.
@@ -260,6 +318,114 @@ pub struct Ciphertext {
     ephemeral_pk: EncKey,
 }
 
+// The extended nonce of a segment is made of the nonce prefix of the stream, a
+// 32-bit big-endian segment counter and a 1-byte flag for the last segment.
+const STREAM_NONCE_PREFIX_LEN: usize = 19;
+
+/// The header of a message encrypted by segments, to be sent along with the
+/// encrypted segments.
+#[derive(
+    Clone,
+    Debug,
+    PartialEq,
+    Eq,
+    Hash,
+    Serialize,
+    Deserialize,
+    CanonicalSerialize,
+    CanonicalDeserialize,
+)]
+pub struct StreamHeader {
+    nonce_prefix: [u8; STREAM_NONCE_PREFIX_LEN],
+    ephemeral_pk: EncKey,
+}
+
+/// Encryptor of a message by segments, see [`EncKey::encrypt_stream`].
+///
+/// Segments must be decrypted in the order of their encryption, and the
+/// encryption must end with [`Self::encrypt_last`] for the decryption to
+/// detect truncations.
+pub struct StreamEncryptor {
+    stream: EncryptorBE32<XChaCha20Poly1305>,
+    aad: Vec<u8>,
+}
+
+impl StreamEncryptor {
+    /// Encrypt the next segment of the message.
+    /// * `returns` - Err() if the maximum number of segments, `2^32`, is
+    ///   reached
+    pub fn encrypt_next(&mut self, segment: &[u8]) -> Result<Vec<u8>, PrimitivesError> {
+        self.stream
+            .encrypt_next(Payload {
+                msg: segment,
+                aad: &self.aad,
+            })
+            .map_err(|e| PrimitivesError::InternalError(format!("{e:?}")))
+    }
+
+    /// Encrypt the last segment of the message, which may be empty.
+    pub fn encrypt_last(self, segment: &[u8]) -> Result<Vec<u8>, PrimitivesError> {
+        self.stream
+            .encrypt_last(Payload {
+                msg: segment,
+                aad: &self.aad,
+            })
+            .map_err(|e| PrimitivesError::InternalError(format!("{e:?}")))
+    }
+}
+
+impl fmt::Debug for StreamEncryptor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("aead::StreamEncryptor")
+            .field("aad", &self.aad)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Decryptor of a message encrypted by segments, see
+/// [`KeyPair::decrypt_stream`].
+///
+/// The message is only complete once [`Self::decrypt_last`] succeeds. The
+/// plaintext of the previous segments should not be trusted before, as the
+/// message may have been truncated.
+pub struct StreamDecryptor {
+    stream: DecryptorBE32<XChaCha20Poly1305>,
+    aad: Vec<u8>,
+}
+
+impl StreamDecryptor {
+    /// Decrypt the next segment of the message.
+    /// * `returns` - Err() if the segment is not the next one, or is the last
+    ///   one
+    pub fn decrypt_next(&mut self, segment: &[u8]) -> Result<Vec<u8>, PrimitivesError> {
+        self.stream
+            .decrypt_next(Payload {
+                msg: segment,
+                aad: &self.aad,
+            })
+            .map_err(|e| PrimitivesError::FailedDecryption(format!("{e:?}")))
+    }
+
+    /// Decrypt the last segment of the message.
+    /// * `returns` - Err() if the segment is not the last one of the message
+    pub fn decrypt_last(self, segment: &[u8]) -> Result<Vec<u8>, PrimitivesError> {
+        self.stream
+            .decrypt_last(Payload {
+                msg: segment,
+                aad: &self.aad,
+            })
+            .map_err(|e| PrimitivesError::FailedDecryption(format!("{e:?}")))
+    }
+}
+
+impl fmt::Debug for StreamDecryptor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("aead::StreamDecryptor")
+            .field("aad", &self.aad)
+            .finish_non_exhaustive()
+    }
+}
+
 // TODO: (alex) Temporarily add CanonicalSerde back to these structs due to the
 // limitations of `tagged` proc macro and requests from downstream usage.
 // Tracking issue: <https://github.com/EspressoSystems/jellyfish/issues/288>
@@ -305,8 +471,10 @@ mod canonical_serde {
             mut writer: W,
             _compress: Compress,
         ) -> Result<(), SerializationError> {
-            let bytes: [u8; crypto_kx::SecretKey::BYTES] = self.clone().into();
-            writer.write_all(&bytes)?;
+            let mut bytes: [u8; crypto_kx::SecretKey::BYTES] = self.clone().into();
+            let result = writer.write_all(&bytes);
+            bytes.zeroize();
+            result?;
             Ok(())
         }
         fn serialized_size(&self, _compress: Compress) -> usize {
@@ -322,7 +490,9 @@ mod canonical_serde {
         ) -> Result<Self, SerializationError> {
             let mut result = [0u8; crypto_kx::SecretKey::BYTES];
             reader.read_exact(&mut result)?;
-            Ok(DecKey(crypto_kx::SecretKey::from(result)))
+            let dec_key = DecKey::from(result);
+            result.zeroize();
+            Ok(dec_key)
         }
     }
     impl Valid for DecKey {
@@ -401,6 +571,97 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn test_random_nonces() -> Result<(), PrimitivesError> {
+        let mut rng = jf_utils::test_rng();
+        let keypair = KeyPair::generate(&mut rng);
+        let msg = b"The quick brown fox jumps over the lazy dog".to_vec();
+        let aad = b"my associated data".to_vec();
+
+        // the same message is encrypted under distinct extended nonces
+        let ct1 = keypair.enc_key.encrypt(&mut rng, &msg, &aad)?;
+        let ct2 = keypair.enc_key.encrypt(&mut rng, &msg, &aad)?;
+        assert_eq!(ct1.nonce.len(), 24);
+        assert_ne!(ct1.nonce, ct2.nonce);
+        assert_ne!(ct1.ct, ct2.ct);
+        assert_eq!(keypair.decrypt(&ct1, &aad)?, msg);
+        assert_eq!(keypair.decrypt(&ct2, &aad)?, msg);
+
+        // a ciphertext does not decrypt under another nonce
+        let mut ct3 = ct1.clone();
+        ct3.nonce = ct2.nonce.clone();
+        assert!(keypair.decrypt(&ct3, &aad).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_stream_encryption() -> Result<(), PrimitivesError> {
+        let mut rng = jf_utils::test_rng();
+        let keypair1 = KeyPair::generate(&mut rng);
+        let keypair2 = KeyPair::generate(&mut rng);
+        let msg = b"The quick brown fox jumps over the lazy dog".repeat(100);
+        let aad = b"my associated data".to_vec();
+        let segments: Vec<&[u8]> = msg.chunks(1000).collect();
+
+        let (header, mut encryptor) = keypair1.enc_key.encrypt_stream(&mut rng, &aad);
+        let mut cts = Vec::new();
+        for segment in &segments[..segments.len() - 1] {
+            cts.push(encryptor.encrypt_next(segment)?);
+        }
+        cts.push(encryptor.encrypt_last(segments[segments.len() - 1])?);
+
+        // check correctness
+        let decrypt = |keypair: &KeyPair, header: &StreamHeader, aad: &[u8], cts: &[Vec<u8>]| {
+            let mut decryptor = keypair.decrypt_stream(header, aad);
+            let mut plaintext = Vec::new();
+            for ct in &cts[..cts.len() - 1] {
+                plaintext.extend(decryptor.decrypt_next(ct)?);
+            }
+            plaintext.extend(decryptor.decrypt_last(&cts[cts.len() - 1])?);
+            Ok::<_, PrimitivesError>(plaintext)
+        };
+        assert_eq!(decrypt(&keypair1, &header, &aad, &cts)?, msg);
+
+        // check soundness
+        assert!(decrypt(&keypair2, &header, &aad, &cts).is_err());
+        assert!(decrypt(&keypair1, &header, b"wrong associated data", &cts).is_err());
+        let (other_header, _) = keypair1.enc_key.encrypt_stream(&mut rng, &aad);
+        assert!(decrypt(&keypair1, &other_header, &aad, &cts).is_err());
+        // reordered segments
+        let mut reordered = cts.clone();
+        reordered.swap(0, 1);
+        assert!(decrypt(&keypair1, &header, &aad, &reordered).is_err());
+        // removed segment
+        let mut removed = cts.clone();
+        removed.remove(1);
+        assert!(decrypt(&keypair1, &header, &aad, &removed).is_err());
+        // truncated stream
+        assert!(decrypt(&keypair1, &header, &aad, &cts[..cts.len() - 1]).is_err());
+        // tampered segment
+        let mut tampered = cts.clone();
+        tampered[2][0] ^= 1;
+        assert!(decrypt(&keypair1, &header, &aad, &tampered).is_err());
+
+        // a single, empty, segment
+        let (header, encryptor) = keypair1.enc_key.encrypt_stream(&mut rng, &aad);
+        let ct = encryptor.encrypt_last(&[])?;
+        assert!(keypair1
+            .decrypt_stream(&header, &aad)
+            .decrypt_last(&ct)?
+            .is_empty());
+
+        // serde for StreamHeader
+        let bytes = bincode::serialize(&header).unwrap();
+        assert_eq!(header, bincode::deserialize(&bytes).unwrap());
+        let mut bytes = Vec::new();
+        CanonicalSerialize::serialize_compressed(&header, &mut bytes).unwrap();
+        assert_eq!(
+            header,
+            StreamHeader::deserialize_compressed(&bytes[..]).unwrap()
+        );
+        Ok(())
+    }
+
     #[test]
     fn test_serde() {
         let mut rng = jf_utils::test_rng();
@@ -0,0 +1,372 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+#![deny(missing_docs)]
+//! This module implements the Anemoi permutation and its Jive compression
+//! mode over the following fields
+//! - ed_on_bls12_377 base field (i.e. bls12_377 scalar field)
+//! - ed_on_bls12_381 base field (i.e. bls12_381 scalar field)
+//! - ed_on_bn254 base field (i.e. bn254 scalar field)
+//!
+//! The permutation follows the [Anemoi paper](https://eprint.iacr.org/2022/840.pdf)
+//! for a state `(x, y)` of 2 elements, i.e. a single column. Each round adds
+//! the round constants, applies the linear layer, which for a single column is
+//! the pseudo-Hadamard transform `(x, y) -> (2 x + y, x + y)`, and the open
+//! Flystel S-box
+//! ```text
+//! x <- x - (beta y^2 + gamma)
+//! y <- y - x^(1/alpha)
+//! x <- x + (beta y^2 + delta)
+//! ```
+//! with `beta = g`, `gamma = 0` and `delta = g^-1`, where `g` is the
+//! multiplicative generator of the field. The linear layer is applied once
+//! more after the last round.
+//!
+//! The parameters are those of the [reference implementation](https://github.com/anemoi-hash/anemoi-hash):
+//! the round constants are `c_i = g pi_0^(2 i) + (pi_0^i + 1)^alpha` and
+//! `d_i = g + (pi_0^i + 1)^alpha + g^-1`, which is its derivation for the
+//! single column `j = 0`, as `pi_1^j = 1`.
+//!
+//! In a circuit, the Flystel is checked in its closed form, which only needs
+//! the low degree maps `y -> beta y^2 + gamma`, `y -> beta y^2 + delta` and
+//! `x -> x^alpha`: this is what makes Anemoi cheaper than Rescue to verify in
+//! Plonk. [`AnemoiPermutation`] exposes all the parameters needed by such a
+//! gadget.
+//!
+//! [`jive`] compresses two elements into one as `x + y + u + v`, where `(u,
+//! v)` is the image of `(x, y)` by the permutation.
+
+#![deny(warnings)]
+
+use ark_ff::PrimeField;
+use ark_std::{boxed::Box, vec::Vec};
+use num_bigint::BigUint;
+use once_cell::race::OnceBox;
+
+/// The state size of the anemoi permutation.
+pub const STATE_SIZE: usize = 2;
+
+/// Decimal digits 1 to 76 of pi, used to derive the round constants.
+const PI_0: &[u8] = b"1415926535897932384626433832795028841971693993751058209749445923078164062862";
+
+/// This trait defines constants that are used for anemoi hash functions.
+pub trait AnemoiParameter: PrimeField {
+    /// parameter alpha of the Flystel, the smallest integer such that
+    /// `gcd(alpha, p - 1) = 1`
+    const ALPHA: u64;
+    /// The # of rounds, for 128 bits of security.
+    const ROUNDS: usize;
+
+    /// The anemoi permutation of this field.
+    fn permutation() -> &'static AnemoiPermutation<Self>;
+}
+
+/// The Anemoi permutation over a state of `STATE_SIZE` elements, see the
+/// [module documentation](self).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AnemoiPermutation<F: AnemoiParameter> {
+    /// The inverse of alpha modulo `p - 1`, as little-endian limbs
+    alpha_inv: Vec<u64>,
+    /// The multiplicative generator `g` of the field, which is also `beta`
+    generator: F,
+    /// `g^-1`, which is also `delta`
+    generator_inv: F,
+    /// Round constants added to `x`, in order
+    c: Vec<F>,
+    /// Round constants added to `y`, in order
+    d: Vec<F>,
+}
+
+impl<F: AnemoiParameter> Default for AnemoiPermutation<F> {
+    fn default() -> Self {
+        F::permutation().clone()
+    }
+}
+
+impl<F: AnemoiParameter> AnemoiPermutation<F> {
+    /// Derive the parameters of the permutation of `F`, see the
+    /// [module documentation](self) for the round constants.
+    fn derive() -> Self {
+        let modulus: BigUint = F::MODULUS.into();
+        let modulus_minus_one = modulus - 1u64;
+        let alpha_inv = (1..F::ALPHA)
+            .map(|k| &modulus_minus_one * k + 1u64)
+            .find(|e| (e % F::ALPHA) == BigUint::from(0u64))
+            .expect("alpha should be invertible modulo p - 1")
+            / F::ALPHA;
+
+        let generator = F::GENERATOR;
+        let generator_inv = generator
+            .inverse()
+            .expect("the generator of a field is not zero");
+        let pi_0 = F::from(BigUint::parse_bytes(PI_0, 10).expect("digits of pi"));
+        let (c, d) = (0..F::ROUNDS as u64)
+            .map(|i| {
+                let pi_0_i = pi_0.pow([i]);
+                let shared = (pi_0_i + F::one()).pow([F::ALPHA]);
+                (
+                    generator * pi_0_i.square() + shared,
+                    generator + shared + generator_inv,
+                )
+            })
+            .unzip();
+        Self {
+            alpha_inv: alpha_inv.to_u64_digits(),
+            generator,
+            generator_inv,
+            c,
+            d,
+        }
+    }
+
+    /// Return alpha, the exponent of the Flystel.
+    pub fn alpha(&self) -> u64 {
+        F::ALPHA
+    }
+
+    /// Return `beta = g`, where `g` is the multiplicative generator of the
+    /// field, also used by the linear layer.
+    pub fn beta(&self) -> F {
+        self.generator
+    }
+
+    /// Return `gamma = 0`.
+    pub fn gamma(&self) -> F {
+        F::zero()
+    }
+
+    /// Return `delta = g^-1`.
+    pub fn delta(&self) -> F {
+        self.generator_inv
+    }
+
+    /// Return the round constants added to `x`.
+    pub fn c(&self) -> &[F] {
+        &self.c
+    }
+
+    /// Return the round constants added to `y`.
+    pub fn d(&self) -> &[F] {
+        &self.d
+    }
+
+    /// Compute the permutation on `input`.
+    pub fn eval(&self, input: &[F; STATE_SIZE]) -> [F; STATE_SIZE] {
+        let [mut x, mut y] = *input;
+        for (c, d) in self.c.iter().zip(&self.d) {
+            x += c;
+            y += d;
+            self.linear_layer(&mut x, &mut y);
+            [x, y] = self.flystel(x, y);
+        }
+        self.linear_layer(&mut x, &mut y);
+        [x, y]
+    }
+
+    /// Compute the open Flystel on `(x, y)`.
+    fn flystel(&self, mut x: F, mut y: F) -> [F; STATE_SIZE] {
+        x -= self.generator * y.square() + self.gamma();
+        y -= x.pow(&self.alpha_inv);
+        x += self.generator * y.square() + self.generator_inv;
+        [x, y]
+    }
+
+    /// Compute the pseudo-Hadamard transform `(x, y) -> (2 x + y, x + y)`.
+    fn linear_layer(&self, x: &mut F, y: &mut F) {
+        *y += *x;
+        *x += *y;
+    }
+}
+
+/// Compress two elements into one with the Jive mode of the anemoi
+/// permutation: `x + y + u + v` where `(u, v)` is the image of `(x, y)`.
+pub fn jive<F: AnemoiParameter>(x: F, y: F) -> F {
+    let [u, v] = F::permutation().eval(&[x, y]);
+    x + y + u + v
+}
+
+macro_rules! impl_anemoi_parameter {
+    ($field:ty, $alpha:expr, $rounds:expr) => {
+        impl AnemoiParameter for $field {
+            const ALPHA: u64 = $alpha;
+            const ROUNDS: usize = $rounds;
+
+            fn permutation() -> &'static AnemoiPermutation<Self> {
+                static PERMUTATION: OnceBox<AnemoiPermutation<$field>> = OnceBox::new();
+                PERMUTATION.get_or_init(|| Box::new(AnemoiPermutation::derive()))
+            }
+        }
+    };
+}
+
+// Round numbers of the Anemoi paper for a state of 2 elements and 128 bits of
+// security, including its security margin
+impl_anemoi_parameter!(ark_ed_on_bn254::Fq, 5, 21);
+impl_anemoi_parameter!(ark_ed_on_bls12_381::Fq, 5, 21);
+impl_anemoi_parameter!(ark_ed_on_bls12_377::Fq, 11, 19);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use ark_ed_on_bls12_377::Fq as Fq377;
+    use ark_ed_on_bls12_381::Fq as Fq381;
+    use ark_ed_on_bn254::Fq as Fq254;
+    use ark_ff::MontFp;
+    use ark_std::UniformRand;
+
+    #[test]
+    fn test_anemoi_permutation() {
+        test_anemoi_permutation_helper::<Fq254>();
+        test_anemoi_permutation_helper::<Fq377>();
+        test_anemoi_permutation_helper::<Fq381>();
+    }
+
+    fn test_anemoi_permutation_helper<F: AnemoiParameter>() {
+        let rng = &mut jf_utils::test_rng();
+        let permutation = F::permutation();
+        assert_eq!(permutation.c().len(), F::ROUNDS);
+        assert_eq!(permutation.d().len(), F::ROUNDS);
+        // permutation is cached
+        assert!(core::ptr::eq(permutation, F::permutation()));
+        assert_eq!(&AnemoiPermutation::<F>::default(), permutation);
+        assert_eq!(permutation.delta() * permutation.beta(), F::one());
+
+        // x^(1/alpha) is the inverse of x^alpha
+        let x = F::rand(rng);
+        assert_eq!(x.pow(&permutation.alpha_inv).pow([F::ALPHA]), x);
+
+        let input = [F::rand(rng), F::rand(rng)];
+        let output = permutation.eval(&input);
+        assert_ne!(output, input);
+        assert_eq!(output, permutation.eval(&input));
+        let other_output = permutation.eval(&[input[0], input[1] + F::one()]);
+        assert!(output.iter().zip(other_output).all(|(x, y)| *x != y));
+
+        // The closed Flystel holds for every round
+        let [mut x, mut y] = input;
+        for (c, d) in permutation.c().iter().zip(permutation.d()) {
+            x += c;
+            y += d;
+            permutation.linear_layer(&mut x, &mut y);
+            let [u, v] = permutation.flystel(x, y);
+            let beta = permutation.beta();
+            assert_eq!(
+                (y - v).pow([F::ALPHA]),
+                x - beta * y.square() - permutation.gamma()
+            );
+            assert_eq!(
+                (y - v).pow([F::ALPHA]),
+                u - beta * v.square() - permutation.delta()
+            );
+            x = u;
+            y = v;
+        }
+        permutation.linear_layer(&mut x, &mut y);
+        assert_eq!([x, y], output);
+
+        assert_eq!(
+            jive(input[0], input[1]),
+            input[0] + input[1] + output[0] + output[1]
+        );
+        assert_ne!(jive(input[0], input[1]), jive(input[1], input[0]));
+    }
+
+    // Test vectors of the reference implementation at
+    // https://github.com/anemoi-hash/anemoi-hash, for a single column and the
+    // round numbers above: the images of `(0, 0)` and `(1, 2)` and the Jive
+    // compression of `(1, 2)`.
+    #[test]
+    fn test_anemoi_vectors() {
+        let expected: [[Fq254; STATE_SIZE]; 2] = [
+            [
+                MontFp!(
+                    "1332968686644143296255837701756922566066300289841138130764091490130068921177"
+                ),
+                MontFp!(
+                    "9806374700793745216055880276019693008733304529292161563371802205670280925390"
+                ),
+            ],
+            [
+                MontFp!(
+                    "2478704670152296924216305350486540792839401653248424005294780903329165806559"
+                ),
+                MontFp!(
+                    "8806228415918607747891323680248680190155286281630929597547776716634301186690"
+                ),
+            ],
+        ];
+        test_anemoi_vectors_helper(
+            expected,
+            MontFp!(
+                "11284933086070904672107629030735220982994687934879353602842557619963466993252"
+            ),
+        );
+
+        let expected: [[Fq381; STATE_SIZE]; 2] = [
+            [
+                MontFp!(
+                    "2217421750029741369401495691271208002884301815557943879798853944237701475506"
+                ),
+                MontFp!(
+                    "2179243446232496715099509712092368265959709565449432545284026399076526028686"
+                ),
+            ],
+            [
+                MontFp!(
+                    "37689761519302190692358872825094327990315686037487583808238476451356880922755"
+                ),
+                MontFp!(
+                    "42885879599383038937912097785169060302303181624925913610532850620679725646556"
+                ),
+            ],
+        ];
+        test_anemoi_vectors_helper(
+            expected,
+            MontFp!(
+                "28139765943559039150823230102077422454928315161885859596167668372098025384801"
+            ),
+        );
+
+        let expected: [[Fq377; STATE_SIZE]; 2] = [
+            [
+                MontFp!(
+                    "7315691465866293093305785884155184105969358478452456802874150711475765725840"
+                ),
+                MontFp!(
+                    "5522125489082849115356308384418540316446100198491657078852403162614840964062"
+                ),
+            ],
+            [
+                MontFp!(
+                    "5269272239507123352246142356356408665669767075711537807499929091069696942399"
+                ),
+                MontFp!(
+                    "5832359301950962521424307192576549287983119854804347646232866637000568183083"
+                ),
+            ],
+        ];
+        test_anemoi_vectors_helper(
+            expected,
+            MontFp!("2657169792029715449421624610151411422276987595361821625797562272152855886444"),
+        );
+    }
+
+    fn test_anemoi_vectors_helper<F: AnemoiParameter>(
+        expected: [[F; STATE_SIZE]; 2],
+        expected_jive: F,
+    ) {
+        let permutation = F::permutation();
+        // c_0 = g + 2^alpha
+        assert_eq!(
+            permutation.c()[0],
+            F::GENERATOR + F::from(2u64).pow([F::ALPHA])
+        );
+        assert_eq!(permutation.eval(&[F::zero(), F::zero()]), expected[0]);
+        let input = [F::one(), F::from(2u64)];
+        assert_eq!(permutation.eval(&input), expected[1]);
+        assert_eq!(jive(input[0], input[1]), expected_jive);
+    }
+}
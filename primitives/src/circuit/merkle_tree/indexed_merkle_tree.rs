@@ -0,0 +1,451 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+//! Circuit implementation of an indexed Merkle tree, see
+//! [`crate::merkle_tree::indexed_merkle_tree`].
+
+use crate::{
+    circuit::merkle_tree::{DigestAlgorithmGadget, RescueDigestGadget},
+    merkle_tree::{
+        indexed_merkle_tree::{
+            IndexedInsertionProof, IndexedLeaf, IndexedMerkleProof, IndexedNonMembershipProof,
+        },
+        internal::MerkleNode,
+        ToTraversalPath,
+    },
+    rescue::RescueParameter,
+};
+use ark_std::{string::ToString, vec::Vec};
+use jf_relation::{errors::CircuitError, BoolVar, Circuit, PlonkCircuit, Variable};
+use typenum::U3;
+
+use super::{compute_root_from_leaf_label, constrain_sibling_order, Merkle3AryNodeVar};
+
+/// Circuit variable for a leaf of an indexed Merkle tree.
+#[derive(Debug, Clone)]
+pub struct IndexedLeafVar {
+    /// Value stored in the leaf
+    pub value: Variable,
+    /// Index of the next leaf
+    pub next_index: Variable,
+    /// Value of the next leaf
+    pub next_value: Variable,
+}
+
+/// Circuit variable for a non-membership proof of an indexed Merkle tree.
+/// Contains:
+/// * the low leaf,
+/// * a list of node variables in the path of the low leaf.
+#[derive(Debug, Clone)]
+pub struct IndexedNonMembershipProofVar {
+    low_leaf: IndexedLeafVar,
+    node_vars: Vec<Merkle3AryNodeVar>,
+}
+
+/// Circuit variable for an insertion proof of an indexed Merkle tree.
+/// Contains:
+/// * the low leaf before the insertion,
+/// * a list of node variables in the path of the low leaf,
+/// * a list of node variables in the path of the new leaf.
+#[derive(Debug, Clone)]
+pub struct IndexedInsertionProofVar {
+    low_leaf: IndexedLeafVar,
+    low_leaf_node_vars: Vec<Merkle3AryNodeVar>,
+    new_leaf_node_vars: Vec<Merkle3AryNodeVar>,
+}
+
+/// Gadget for an indexed Merkle tree
+///
+/// # Examples
+///
+/// ```
+/// use ark_bls12_377::Fq;
+/// use jf_primitives::{
+///     circuit::merkle_tree::IndexedMerkleTreeGadget,
+///     merkle_tree::{indexed_merkle_tree::IndexedMerkleTree, MerkleCommitment},
+/// };
+/// use jf_relation::{Circuit, PlonkCircuit};
+///
+/// let mut circuit = PlonkCircuit::<Fq>::new_turbo_plonk();
+/// let mut mt = IndexedMerkleTree::<Fq>::new(2).unwrap();
+/// let old_root = mt.commitment().digest();
+/// let num_leaves = mt.num_leaves();
+/// let proof = mt.insert(Fq::from(42u64)).unwrap();
+/// let new_root = mt.commitment().digest();
+///
+/// let value_var = circuit.create_variable(Fq::from(42u64)).unwrap();
+/// let proof_var = circuit.create_indexed_insertion_proof_variable(&proof).unwrap();
+/// let old_root_var = circuit.create_variable(old_root).unwrap();
+/// let new_root_var = circuit.create_variable(new_root).unwrap();
+/// let num_leaves_var = circuit.create_variable(Fq::from(num_leaves)).unwrap();
+/// circuit
+///     .enforce_indexed_insertion_proof(
+///         value_var,
+///         proof_var,
+///         old_root_var,
+///         new_root_var,
+///         num_leaves_var,
+///     )
+///     .unwrap();
+/// assert!(circuit.check_circuit_satisfiability(&[]).is_ok());
+/// ```
+pub trait IndexedMerkleTreeGadget<F: RescueParameter> {
+    /// Allocate a variable for the non-membership proof.
+    fn create_indexed_non_membership_proof_variable(
+        &mut self,
+        proof: &IndexedNonMembershipProof<F>,
+    ) -> Result<IndexedNonMembershipProofVar, CircuitError>;
+
+    /// Allocate a variable for the insertion proof.
+    fn create_indexed_insertion_proof_variable(
+        &mut self,
+        proof: &IndexedInsertionProof<F>,
+    ) -> Result<IndexedInsertionProofVar, CircuitError>;
+
+    /// Given variables representing:
+    /// * a value
+    /// * its non-membership proof
+    /// * root
+    /// * return `BoolVar` indicating the correctness of its non-membership
+    ///   proof.
+    fn is_indexed_non_member(
+        &mut self,
+        value_var: Variable,
+        proof_var: IndexedNonMembershipProofVar,
+        root_var: Variable,
+    ) -> Result<BoolVar, CircuitError>;
+
+    /// Enforce correct `proof_var` for the absence of `value_var` against
+    /// `expected_root_var`.
+    fn enforce_indexed_non_membership_proof(
+        &mut self,
+        value_var: Variable,
+        proof_var: IndexedNonMembershipProofVar,
+        expected_root_var: Variable,
+    ) -> Result<(), CircuitError>;
+
+    /// Enforce that `new_root_var` is obtained by inserting `value_var` into
+    /// the tree whose root is `old_root_var` and which has `num_leaves_var`
+    /// leaves, the new leaf being appended at position `num_leaves_var`.
+    fn enforce_indexed_insertion_proof(
+        &mut self,
+        value_var: Variable,
+        proof_var: IndexedInsertionProofVar,
+        old_root_var: Variable,
+        new_root_var: Variable,
+        num_leaves_var: Variable,
+    ) -> Result<(), CircuitError>;
+}
+
+impl<F: RescueParameter> IndexedMerkleTreeGadget<F> for PlonkCircuit<F> {
+    fn create_indexed_non_membership_proof_variable(
+        &mut self,
+        proof: &IndexedNonMembershipProof<F>,
+    ) -> Result<IndexedNonMembershipProofVar, CircuitError> {
+        let low_leaf = create_leaf_variable(self, &proof.low_leaf_proof)?;
+        let node_vars = create_path_variable(self, &proof.low_leaf_proof)?;
+        Ok(IndexedNonMembershipProofVar {
+            low_leaf,
+            node_vars,
+        })
+    }
+
+    fn create_indexed_insertion_proof_variable(
+        &mut self,
+        proof: &IndexedInsertionProof<F>,
+    ) -> Result<IndexedInsertionProofVar, CircuitError> {
+        let low_leaf = create_leaf_variable(self, &proof.low_leaf_proof)?;
+        let low_leaf_node_vars = create_path_variable(self, &proof.low_leaf_proof)?;
+        let new_leaf_node_vars = create_path_variable(self, &proof.new_leaf_proof)?;
+        Ok(IndexedInsertionProofVar {
+            low_leaf,
+            low_leaf_node_vars,
+            new_leaf_node_vars,
+        })
+    }
+
+    fn is_indexed_non_member(
+        &mut self,
+        value_var: Variable,
+        proof_var: IndexedNonMembershipProofVar,
+        root_var: Variable,
+    ) -> Result<BoolVar, CircuitError> {
+        is_low_leaf(
+            self,
+            value_var,
+            &proof_var.low_leaf,
+            &proof_var.node_vars,
+            root_var,
+        )
+    }
+
+    fn enforce_indexed_non_membership_proof(
+        &mut self,
+        value_var: Variable,
+        proof_var: IndexedNonMembershipProofVar,
+        expected_root_var: Variable,
+    ) -> Result<(), CircuitError> {
+        let bool_val = self.is_indexed_non_member(value_var, proof_var, expected_root_var)?;
+        self.enforce_true(bool_val.into())
+    }
+
+    fn enforce_indexed_insertion_proof(
+        &mut self,
+        value_var: Variable,
+        proof_var: IndexedInsertionProofVar,
+        old_root_var: Variable,
+        new_root_var: Variable,
+        num_leaves_var: Variable,
+    ) -> Result<(), CircuitError> {
+        let low_leaf = &proof_var.low_leaf;
+        let bool_val = is_low_leaf(
+            self,
+            value_var,
+            low_leaf,
+            &proof_var.low_leaf_node_vars,
+            old_root_var,
+        )?;
+        self.enforce_true(bool_val.into())?;
+
+        // the new leaf is appended, and the low leaf now points to it
+        let new_pos = compute_position(self, &proof_var.new_leaf_node_vars)?;
+        self.enforce_equal(new_pos, num_leaves_var)?;
+        let updated_low_leaf_label =
+            RescueDigestGadget::digest(self, &[low_leaf.value, new_pos, value_var])?;
+        let intermediate_root = compute_root_from_leaf_label(
+            self,
+            updated_low_leaf_label,
+            &proof_var.low_leaf_node_vars,
+        )?;
+
+        // the position of the new leaf is empty in the intermediate tree
+        let empty_root = compute_root_from_empty_leaf(self, &proof_var.new_leaf_node_vars)?;
+        self.enforce_equal(empty_root, intermediate_root)?;
+
+        // the new leaf inherits the successor of the low leaf
+        let new_leaf_label = RescueDigestGadget::digest(
+            self,
+            &[value_var, low_leaf.next_index, low_leaf.next_value],
+        )?;
+        let computed_new_root =
+            compute_root_from_leaf_label(self, new_leaf_label, &proof_var.new_leaf_node_vars)?;
+        self.enforce_equal(computed_new_root, new_root_var)
+    }
+}
+
+fn create_leaf_variable<F: RescueParameter>(
+    circuit: &mut PlonkCircuit<F>,
+    proof: &IndexedMerkleProof<F>,
+) -> Result<IndexedLeafVar, CircuitError> {
+    let leaf: &IndexedLeaf<F> = proof.elem().ok_or_else(|| {
+        CircuitError::InternalError("The proof doesn't contain a leaf element".to_string())
+    })?;
+    Ok(IndexedLeafVar {
+        value: circuit.create_variable(leaf.value)?,
+        next_index: circuit.create_variable(F::from(leaf.next_index))?,
+        next_value: circuit.create_variable(leaf.next_value)?,
+    })
+}
+
+/// Allocate the node variables of a membership or non-membership proof. An
+/// empty node in a non-membership proof means all the siblings are empty.
+fn create_path_variable<F: RescueParameter>(
+    circuit: &mut PlonkCircuit<F>,
+    proof: &IndexedMerkleProof<F>,
+) -> Result<Vec<Merkle3AryNodeVar>, CircuitError> {
+    let path = <u64 as ToTraversalPath<U3>>::to_traversal_path(&proof.pos, proof.tree_height() - 1);
+    let nodes = path
+        .iter()
+        .zip(proof.proof.iter().skip(1))
+        .map(|(branch, node)| {
+            let (sibling1, sibling2) = match node {
+                MerkleNode::Branch { value: _, children } if children.len() == 3 => {
+                    let sib_branch1 = if branch == &0 { 1 } else { 0 };
+                    let sib_branch2 = if branch == &2 { 1 } else { 2 };
+                    (
+                        circuit.create_variable(children[sib_branch1].value())?,
+                        circuit.create_variable(children[sib_branch2].value())?,
+                    )
+                },
+                MerkleNode::Empty => (circuit.zero(), circuit.zero()),
+                _ => {
+                    return Err(CircuitError::InternalError(
+                        "Incompatible proof for this merkle tree".to_string(),
+                    ))
+                },
+            };
+            Ok(Merkle3AryNodeVar {
+                sibling1,
+                sibling2,
+                is_left_child: circuit.create_boolean_variable(branch == &0)?,
+                is_right_child: circuit.create_boolean_variable(branch == &2)?,
+            })
+        })
+        .collect::<Result<Vec<Merkle3AryNodeVar>, CircuitError>>()?;
+
+    for node in nodes.iter() {
+        // Boolean constrain `is_left_child + is_right_child` because a node
+        // can either be the left or the right child of its parent
+        let left_plus_right = circuit.add(node.is_left_child.into(), node.is_right_child.into())?;
+        circuit.enforce_bool(left_plus_right)?;
+    }
+    Ok(nodes)
+}
+
+/// Return a `BoolVar` indicating whether `leaf` is in the tree with root
+/// `root_var`, and `leaf.value < value_var < leaf.next_value`, where a zero
+/// `leaf.next_value` stands for infinity.
+fn is_low_leaf<F: RescueParameter>(
+    circuit: &mut PlonkCircuit<F>,
+    value_var: Variable,
+    leaf: &IndexedLeafVar,
+    node_vars: &[Merkle3AryNodeVar],
+    root_var: Variable,
+) -> Result<BoolVar, CircuitError> {
+    let leaf_label =
+        RescueDigestGadget::digest(circuit, &[leaf.value, leaf.next_index, leaf.next_value])?;
+    let computed_root = compute_root_from_leaf_label(circuit, leaf_label, node_vars)?;
+    let is_member = circuit.is_equal(computed_root, root_var)?;
+
+    let above_low = circuit.is_lt(leaf.value, value_var)?;
+    let is_last = circuit.is_zero(leaf.next_value)?;
+    let below_next = circuit.is_lt(value_var, leaf.next_value)?;
+    let below_next = circuit.logic_or(is_last, below_next)?;
+    circuit.logic_and_all(&[is_member, above_low, below_next])
+}
+
+/// Compute the leaf position of a path, `sum_i 3^i * branch_i`.
+fn compute_position<F: RescueParameter>(
+    circuit: &mut PlonkCircuit<F>,
+    node_vars: &[Merkle3AryNodeVar],
+) -> Result<Variable, CircuitError> {
+    let one = circuit.one();
+    let mut pos = circuit.zero();
+    let mut pow = F::one();
+    for node in node_vars.iter() {
+        // branch = 1 - is_left_child + is_right_child
+        pos = circuit.lc(
+            &[
+                pos,
+                node.is_left_child.into(),
+                node.is_right_child.into(),
+                one,
+            ],
+            &[F::one(), -pow, pow, pow],
+        )?;
+        pow *= F::from(3u64);
+    }
+    Ok(pos)
+}
+
+/// Compute the root of a path whose leaf is empty. As in the native tree, a
+/// subtree with only empty children is empty and has a zero label.
+fn compute_root_from_empty_leaf<F: RescueParameter>(
+    circuit: &mut PlonkCircuit<F>,
+    node_vars: &[Merkle3AryNodeVar],
+) -> Result<Variable, CircuitError> {
+    let zero = circuit.zero();
+    let mut cur_label = zero;
+    for cur_node in node_vars.iter() {
+        let input_labels = constrain_sibling_order(
+            circuit,
+            cur_label,
+            cur_node.sibling1,
+            cur_node.sibling2,
+            cur_node.is_left_child,
+            cur_node.is_right_child,
+        )?;
+        let digest = RescueDigestGadget::digest(circuit, &input_labels)?;
+        let is_empty = input_labels
+            .iter()
+            .map(|label| circuit.is_zero(*label))
+            .collect::<Result<Vec<_>, CircuitError>>()?;
+        let is_empty = circuit.logic_and_all(&is_empty)?;
+        cur_label = circuit.conditional_select(is_empty, digest, zero)?;
+    }
+    Ok(cur_label)
+}
+
+#[cfg(test)]
+mod test {
+    use super::IndexedMerkleTreeGadget;
+    use crate::{
+        merkle_tree::{indexed_merkle_tree::IndexedMerkleTree, MerkleCommitment},
+        rescue::RescueParameter,
+    };
+    use ark_bls12_377::Fq as Fq377;
+    use ark_ed_on_bls12_377::Fq as FqEd377;
+    use ark_ed_on_bls12_381::Fq as FqEd381;
+    use ark_ed_on_bn254::Fq as FqEd254;
+    use jf_relation::{Circuit, PlonkCircuit};
+
+    #[test]
+    fn test_indexed_mt_gadget() {
+        test_indexed_mt_gadget_helper::<FqEd254>();
+        test_indexed_mt_gadget_helper::<FqEd377>();
+        test_indexed_mt_gadget_helper::<FqEd381>();
+        test_indexed_mt_gadget_helper::<Fq377>();
+    }
+
+    fn test_indexed_mt_gadget_helper<F: RescueParameter>() {
+        let mut mt = IndexedMerkleTree::<F>::new(2).unwrap();
+        for value in [20u64, 40, 10, 30] {
+            let value = F::from(value);
+            let old_root = mt.commitment().digest();
+            let num_leaves = mt.num_leaves();
+            let proof = mt.insert(value).unwrap();
+            let new_root = mt.commitment().digest();
+
+            let mut circuit = PlonkCircuit::<F>::new_turbo_plonk();
+            let value_var = circuit.create_variable(value).unwrap();
+            let proof_var = circuit
+                .create_indexed_insertion_proof_variable(&proof)
+                .unwrap();
+            let old_root_var = circuit.create_variable(old_root).unwrap();
+            let new_root_var = circuit.create_variable(new_root).unwrap();
+            let num_leaves_var = circuit.create_variable(F::from(num_leaves)).unwrap();
+            circuit
+                .enforce_indexed_insertion_proof(
+                    value_var,
+                    proof_var,
+                    old_root_var,
+                    new_root_var,
+                    num_leaves_var,
+                )
+                .unwrap();
+            assert!(circuit.check_circuit_satisfiability(&[]).is_ok());
+            *circuit.witness_mut(new_root_var) = old_root;
+            assert!(circuit.check_circuit_satisfiability(&[]).is_err());
+            *circuit.witness_mut(new_root_var) = new_root;
+            *circuit.witness_mut(num_leaves_var) = F::from(num_leaves + 1);
+            assert!(circuit.check_circuit_satisfiability(&[]).is_err());
+            *circuit.witness_mut(num_leaves_var) = F::from(num_leaves);
+            *circuit.witness_mut(value_var) = value + F::one();
+            assert!(circuit.check_circuit_satisfiability(&[]).is_err());
+        }
+
+        let root = mt.commitment().digest();
+        let value = F::from(25u64);
+        let proof = mt.lookup(&value).unwrap().expect_not_found().unwrap();
+
+        let mut circuit = PlonkCircuit::<F>::new_turbo_plonk();
+        let value_var = circuit.create_variable(value).unwrap();
+        let proof_var = circuit
+            .create_indexed_non_membership_proof_variable(&proof)
+            .unwrap();
+        let root_var = circuit.create_variable(root).unwrap();
+        circuit
+            .enforce_indexed_non_membership_proof(value_var, proof_var, root_var)
+            .unwrap();
+        assert!(circuit.check_circuit_satisfiability(&[]).is_ok());
+        // 30 is in the tree
+        *circuit.witness_mut(value_var) = F::from(30u64);
+        assert!(circuit.check_circuit_satisfiability(&[]).is_err());
+        *circuit.witness_mut(value_var) = value;
+        *circuit.witness_mut(root_var) = F::zero();
+        assert!(circuit.check_circuit_satisfiability(&[]).is_err());
+    }
+}
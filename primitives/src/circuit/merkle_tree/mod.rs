@@ -5,13 +5,17 @@
 // along with the Jellyfish library. If not, see <https://mit-license.org/>.
 
 //! Trait definitions for a Merkle tree gadget and implementations for
-//! RescueMerkleTree and RescueSparseMerkleTree.
+//! RescueMerkleTree, RescueSparseMerkleTree and IndexedMerkleTree.
 
 use ark_ff::PrimeField;
 use jf_relation::{errors::CircuitError, BoolVar, Circuit, PlonkCircuit, Variable};
 
+mod indexed_merkle_tree;
 mod universal_merkle_tree;
 use ark_std::{string::ToString, vec::Vec};
+pub use indexed_merkle_tree::{
+    IndexedInsertionProofVar, IndexedLeafVar, IndexedMerkleTreeGadget, IndexedNonMembershipProofVar,
+};
 use typenum::{Unsigned, U3};
 
 use crate::{
@@ -222,6 +226,32 @@ fn constrain_sibling_order<F: RescueParameter>(
     Ok([left_node, mid_node, right_node])
 }
 
+/// Computes the root of a Merkle path given the label of its leaf.
+/// * `leaf_label` - label of the leaf
+/// * `node_vars` - nodes in the path, from the leaf up
+/// * `returns` - variable of the computed root
+fn compute_root_from_leaf_label<F: RescueParameter>(
+    circuit: &mut PlonkCircuit<F>,
+    leaf_label: Variable,
+    node_vars: &[Merkle3AryNodeVar],
+) -> Result<Variable, CircuitError> {
+    let mut cur_label = leaf_label;
+    for cur_node in node_vars.iter() {
+        let input_labels = constrain_sibling_order(
+            circuit,
+            cur_label,
+            cur_node.sibling1,
+            cur_node.sibling2,
+            cur_node.is_left_child,
+            cur_node.is_right_child,
+        )?;
+        // check that the left child's label is non-zero
+        circuit.non_zero_gate(input_labels[0])?;
+        cur_label = RescueDigestGadget::digest(circuit, &input_labels)?;
+    }
+    Ok(cur_label)
+}
+
 #[derive(Debug, Clone)]
 /// Circuit variable for a node in the Merkle path.
 pub struct Merkle3AryNodeVar {
@@ -408,22 +438,9 @@ where
             let proof_var = &proof_var;
 
             // elem label = H(0, uid, elem)
-            let mut cur_label =
+            let leaf_label =
                 Self::DigestGadget::digest_leaf(self, elem_idx_var, proof_var.elem_var)?;
-            for cur_node in proof_var.node_vars.iter() {
-                let input_labels = constrain_sibling_order(
-                    self,
-                    cur_label,
-                    cur_node.sibling1,
-                    cur_node.sibling2,
-                    cur_node.is_left_child,
-                    cur_node.is_right_child,
-                )?;
-                // check that the left child's label is non-zero
-                self.non_zero_gate(input_labels[0])?;
-                cur_label = Self::DigestGadget::digest(self, &input_labels)?;
-            }
-            Ok(cur_label)
+            compute_root_from_leaf_label(self, leaf_label, &proof_var.node_vars)
         }?;
         self.is_equal(root_var, computed_root_var)
     }
@@ -451,12 +468,13 @@ mod test {
         },
         rescue::RescueParameter,
     };
+    use alloc::sync::Arc;
     use ark_bls12_377::Fq as Fq377;
     use ark_ed_on_bls12_377::Fq as FqEd377;
     use ark_ed_on_bls12_381::Fq as FqEd381;
     use ark_ed_on_bls12_381_bandersnatch::Fq as FqEd381b;
     use ark_ed_on_bn254::Fq as FqEd254;
-    use ark_std::{boxed::Box, vec::Vec};
+    use ark_std::vec::Vec;
     use jf_relation::{Circuit, PlonkCircuit, Variable};
 
     #[test]
@@ -607,7 +625,7 @@ mod test {
 
             if let MerkleNode::Branch { value: _, children } = &mut bad_proof.proof[1] {
                 let left_sib = if uid % 3 == 0 { 1 } else { 0 };
-                children[left_sib] = Box::new(MerkleNode::ForgettenSubtree { value: F::zero() });
+                children[left_sib] = Arc::new(MerkleNode::ForgettenSubtree { value: F::zero() });
             }
             let path_vars: Merkle3AryMembershipProofVar =
                 MerkleTreeGadget::<RescueMerkleTree<F>>::create_membership_proof_variable(
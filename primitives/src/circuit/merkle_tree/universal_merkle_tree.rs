@@ -6,29 +6,34 @@
 
 //! Circuit implementation of a sparse, 3-ary Merkle tree, instantiated
 //! with a Rescue hash function.
+//!
+//! The gadgets work for any numeric index type of the native tree, e.g.
+//! [`u64`], [`u128`] or [`BigUint`](num_bigint::BigUint) for trees higher
+//! than 64 levels. The position of a leaf is bound by the circuit as a field
+//! element, hence indices should be smaller than the field modulus.
 
 use crate::{
-    circuit::merkle_tree::DigestAlgorithmGadget,
     merkle_tree::{
-        internal::MerkleNode, prelude::RescueSparseMerkleTree, MerkleTreeScheme, ToTraversalPath,
+        internal::MerkleNode,
+        prelude::{RescueHash, RescueSparseMerkleTree},
+        DigestAlgorithm, Index, MerkleTreeScheme, ToTraversalPath,
     },
     rescue::RescueParameter,
 };
 use ark_std::vec::Vec;
 use jf_relation::{errors::CircuitError, BoolVar, Circuit, PlonkCircuit, Variable};
-
-type SparseMerkleTree<F> = RescueSparseMerkleTree<BigUint, F>;
-use num_bigint::BigUint;
 use typenum::U3;
 
 use super::{
-    constrain_sibling_order, Merkle3AryNodeVar, Merkle3AryNonMembershipProofVar,
+    compute_root_from_leaf_label, Merkle3AryNodeVar, Merkle3AryNonMembershipProofVar,
     UniversalMerkleTreeGadget,
 };
 
-impl<F> UniversalMerkleTreeGadget<SparseMerkleTree<F>> for PlonkCircuit<F>
+impl<F, I> UniversalMerkleTreeGadget<RescueSparseMerkleTree<I, F>> for PlonkCircuit<F>
 where
-    F: RescueParameter,
+    F: RescueParameter + From<I>,
+    I: Index + ToTraversalPath<U3>,
+    RescueHash<F>: DigestAlgorithm<F, I, F>,
 {
     type NonMembershipProofVar = Merkle3AryNonMembershipProofVar;
 
@@ -41,22 +46,8 @@ where
         // constrain that the element's index is part of the proof
         self.enforce_equal(proof_var.pos_var, non_elem_idx_var)?;
         let computed_root_var = {
-            let path_vars = &proof_var;
-            let mut cur_label = self.zero();
-            for cur_node in path_vars.node_vars.iter() {
-                let input_labels = constrain_sibling_order(
-                    self,
-                    cur_label,
-                    cur_node.sibling1,
-                    cur_node.sibling2,
-                    cur_node.is_left_child,
-                    cur_node.is_right_child,
-                )?;
-                // check that the left child's label is non-zero
-                self.non_zero_gate(input_labels[0])?;
-                cur_label = Self::DigestGadget::digest(self, &input_labels)?;
-            }
-            Ok(cur_label)
+            let zero = self.zero();
+            compute_root_from_leaf_label(self, zero, &proof_var.node_vars)
         }?;
         self.is_equal(computed_root_var, root_var)
     }
@@ -73,9 +64,9 @@ where
 
     fn create_non_membership_proof_variable(
         &mut self,
-        merkle_proof: &<SparseMerkleTree<F> as MerkleTreeScheme>::MembershipProof,
+        merkle_proof: &<RescueSparseMerkleTree<I, F> as MerkleTreeScheme>::MembershipProof,
     ) -> Result<Self::NonMembershipProofVar, CircuitError> {
-        let path = <BigUint as ToTraversalPath<U3>>::to_traversal_path(
+        let path = <I as ToTraversalPath<U3>>::to_traversal_path(
             &merkle_proof.pos,
             merkle_proof.tree_height() - 1,
         );
@@ -222,4 +213,85 @@ mod test {
         // Circuit does not verify because a left node value is 0
         assert!(circuit.check_circuit_satisfiability(&[]).is_err());
     }
+
+    #[test]
+    fn test_universal_mt_gadget_wide_indices() {
+        test_wide_indices_helper::<FqEd254>();
+        test_wide_indices_helper::<FqEd377>();
+        test_wide_indices_helper::<FqEd381>();
+    }
+
+    fn test_wide_indices_helper<F: RescueParameter>() {
+        // 160-bit addresses, 3^101 > 2^160
+        let address = BigUint::from_bytes_be(&[0xab; 20]);
+        let absent = BigUint::from_bytes_be(&[0xcd; 20]);
+        let mt =
+            SparseMerkleTree::<F>::from_kv_set(101, [(address.clone(), F::from(1u64))]).unwrap();
+        let root = mt.commitment().digest();
+
+        let mut circuit = PlonkCircuit::<F>::new_turbo_plonk();
+        let (_, proof) = mt.lookup(&address).expect_ok().unwrap();
+        let elem_idx_var = circuit.create_variable(address.into()).unwrap();
+        let proof_var = MerkleTreeGadget::<SparseMerkleTree<F>>::create_membership_proof_variable(
+            &mut circuit,
+            &proof,
+        )
+        .unwrap();
+        let root_var =
+            MerkleTreeGadget::<SparseMerkleTree<F>>::create_root_variable(&mut circuit, root)
+                .unwrap();
+        MerkleTreeGadget::<SparseMerkleTree<F>>::enforce_membership_proof(
+            &mut circuit,
+            elem_idx_var,
+            proof_var,
+            root_var,
+        )
+        .unwrap();
+        let proof = mt.universal_lookup(&absent).expect_not_found().unwrap();
+        let non_elem_idx_var = circuit.create_variable(absent.into()).unwrap();
+        let proof_var =
+            UniversalMerkleTreeGadget::<SparseMerkleTree<F>>::create_non_membership_proof_variable(
+                &mut circuit,
+                &proof,
+            )
+            .unwrap();
+        UniversalMerkleTreeGadget::<SparseMerkleTree<F>>::enforce_non_membership_proof(
+            &mut circuit,
+            non_elem_idx_var,
+            proof_var,
+            root_var,
+        )
+        .unwrap();
+        assert!(circuit.check_circuit_satisfiability(&[]).is_ok());
+
+        // u128 indices beyond the range of u64, 3^81 > 2^128
+        type WideSparseMerkleTree<F> = RescueSparseMerkleTree<u128, F>;
+        let pos = u128::MAX - 1;
+        let mut mt = WideSparseMerkleTree::<F>::new(81);
+        mt.update(pos, F::from(2u64)).unwrap();
+        let root = mt.commitment().digest();
+
+        let mut circuit = PlonkCircuit::<F>::new_turbo_plonk();
+        let proof = mt.universal_lookup(pos + 1).expect_not_found().unwrap();
+        let non_elem_idx_var = circuit.create_variable(F::from(pos + 1)).unwrap();
+        let proof_var =
+            UniversalMerkleTreeGadget::<WideSparseMerkleTree<F>>::create_non_membership_proof_variable(
+                &mut circuit,
+                &proof,
+            )
+            .unwrap();
+        let root_var =
+            MerkleTreeGadget::<WideSparseMerkleTree<F>>::create_root_variable(&mut circuit, root)
+                .unwrap();
+        UniversalMerkleTreeGadget::<WideSparseMerkleTree<F>>::enforce_non_membership_proof(
+            &mut circuit,
+            non_elem_idx_var,
+            proof_var,
+            root_var,
+        )
+        .unwrap();
+        assert!(circuit.check_circuit_satisfiability(&[]).is_ok());
+        *circuit.witness_mut(non_elem_idx_var) = F::from(pos);
+        assert!(circuit.check_circuit_satisfiability(&[]).is_err());
+    }
 }
@@ -4,22 +4,40 @@
 // You should have received a copy of the MIT License
 // along with the Jellyfish library. If not, see <https://mit-license.org/>.
 
-//! Implements a rescue hash based commitment scheme.
+//! Implements a rescue hash based commitment scheme, a vector commitment
+//! scheme based on Merkle trees, and (vector) Pedersen commitments.
 
 use ark_std::marker::PhantomData;
 
 use crate::{
+    constants::CS_ID_PEDERSEN_COMMITMENT,
     crhf::{FixedLengthRescueCRHF, CRHF},
     errors::PrimitivesError,
+    merkle_tree::{
+        append_only::MerkleTree,
+        internal::{MerkleProof, MerkleTreeCommitment},
+        DigestAlgorithm, Element, LookupResult, MaybeSendSync, MerkleCommitment, MerkleTreeScheme,
+        NodeValue,
+    },
+    pcs::transcript::IOPTranscript,
     rescue::RescueParameter,
+    sigma::{Proof, Representation, RepresentationStatement, SigmaProtocol},
 };
+use ark_ec::CurveGroup;
 use ark_std::{
     borrow::Borrow,
     fmt::Debug,
     hash::Hash,
+    rand::{CryptoRng, RngCore, SeedableRng},
     string::{String, ToString},
+    vec,
+    vec::Vec,
     UniformRand,
 };
+use digest::Digest;
+use rand_chacha::ChaCha20Rng;
+use sha2::Sha256;
+use typenum::Unsigned;
 
 /// A trait for cryptographic commitment scheme
 pub trait CommitmentScheme {
@@ -95,20 +113,311 @@ impl<F: RescueParameter, const INPUT_LEN: usize, const INPUT_LEN_PLUS_ONE: usize
     }
 }
 
+/// Merkle tree based commitment to a vector of elements: the commitment is the
+/// commitment of the [`MerkleTree`] of minimal height holding the vector, and
+/// an element is opened with its membership proof. This commitment is not
+/// hiding, so the randomness is ignored.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct MerkleTreeCommitmentScheme<E, H, Arity, T>(PhantomData<(E, H, Arity, T)>);
+
+impl<E, H, Arity, T> MerkleTreeCommitmentScheme<E, H, Arity, T>
+where
+    E: Element + MaybeSendSync,
+    H: DigestAlgorithm<E, u64, T>,
+    Arity: Unsigned,
+    T: NodeValue + MaybeSendSync,
+{
+    fn tree(input: &[E]) -> Result<MerkleTree<E, H, u64, Arity, T>, PrimitivesError> {
+        MerkleTree::from_elems(None, input)
+    }
+
+    /// Open the element at position `pos` of a committed vector.
+    /// * `returns` - the element and its membership proof, or `Err()` if `pos`
+    ///   is out of range
+    pub fn open(
+        input: &[E],
+        pos: u64,
+    ) -> Result<(E, MerkleProof<E, u64, T, Arity>), PrimitivesError> {
+        match Self::tree(input)?.lookup(pos) {
+            LookupResult::Ok(elem, proof) => Ok((elem.clone(), proof)),
+            _ => Err(PrimitivesError::ParameterError(
+                "Position out of range".to_string(),
+            )),
+        }
+    }
+
+    /// Verify that `elem` is at position `pos` in the vector committed by
+    /// `comm`, given an opening proof.
+    /// * `returns` - `Ok(())` if the opening is accepted, `Err()` otherwise
+    pub fn verify_opening(
+        comm: &MerkleTreeCommitment<T>,
+        pos: u64,
+        elem: &E,
+        proof: &MerkleProof<E, u64, T, Arity>,
+    ) -> Result<(), PrimitivesError> {
+        if pos >= comm.size()
+            || proof.tree_height() != comm.height() + 1
+            || proof.elem() != Some(elem)
+            || MerkleTree::<E, H, u64, Arity, T>::verify(comm.digest(), pos, proof)?.is_err()
+        {
+            return Err(PrimitivesError::VerificationError(String::from(
+                "Opening verification failed",
+            )));
+        }
+        Ok(())
+    }
+}
+
+impl<E, H, Arity, T> CommitmentScheme for MerkleTreeCommitmentScheme<E, H, Arity, T>
+where
+    E: Element + MaybeSendSync,
+    H: DigestAlgorithm<E, u64, T>,
+    Arity: Unsigned,
+    T: NodeValue + MaybeSendSync,
+{
+    type Input = Vec<E>;
+    type Output = MerkleTreeCommitment<T>;
+    type Randomness = ();
+
+    fn commit<I: Borrow<Self::Input>>(
+        input: I,
+        _r: Option<&Self::Randomness>,
+    ) -> Result<Self::Output, PrimitivesError> {
+        Ok(Self::tree(input.borrow())?.commitment())
+    }
+
+    fn verify<I: Borrow<Self::Input>>(
+        input: I,
+        r: Option<&Self::Randomness>,
+        comm: &Self::Output,
+    ) -> Result<(), PrimitivesError> {
+        if <Self as CommitmentScheme>::commit(input, r)? == *comm {
+            Ok(())
+        } else {
+            Err(PrimitivesError::VerificationError(String::from(
+                "Commitment verification failed",
+            )))
+        }
+    }
+}
+
+/// Pedersen commitment `C=mG+rH` to a scalar `m` with blinding factor `r`,
+/// where `G` is the generator of the group and `H` is a base of unknown
+/// discrete logarithm derived by hashing. It is perfectly hiding and
+/// computationally binding.
+///
+/// Commitments are additively homomorphic: the sum of the commitments to `m1`
+/// and `m2` under `r1` and `r2` is the commitment to `m1+m2` under `r1+r2`,
+/// and the product of a commitment by a scalar `k` commits to `km` under `kr`.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct PedersenCommitmentScheme<G>(PhantomData<G>);
+
+impl<G: CurveGroup> PedersenCommitmentScheme<G> {
+    /// The blinding base `H`.
+    pub fn blinding_base() -> G {
+        pedersen_base(0)
+    }
+
+    /// Prove the knowledge of the opening `(input, r)` of the commitment to
+    /// `input` under `r`, the challenge being derived from `transcript`.
+    pub fn prove_opening<R: CryptoRng + RngCore>(
+        transcript: &mut IOPTranscript<G::ScalarField>,
+        input: &G::ScalarField,
+        r: &G::ScalarField,
+        rng: &mut R,
+    ) -> Result<Proof<Representation<G>>, PrimitivesError> {
+        let statement = Self::opening_statement(&pedersen_commit(&[*input], r, &[G::generator()]));
+        Representation::prove(transcript, &statement, &vec![*input, *r], rng)
+    }
+
+    /// Verify a proof of [`Self::prove_opening`] for the commitment `comm`.
+    /// * `returns` - Err() if the proof is invalid
+    pub fn verify_opening(
+        transcript: &mut IOPTranscript<G::ScalarField>,
+        comm: &G,
+        proof: &Proof<Representation<G>>,
+    ) -> Result<(), PrimitivesError> {
+        Representation::verify(transcript, &Self::opening_statement(comm), proof)
+    }
+
+    fn opening_statement(comm: &G) -> RepresentationStatement<G> {
+        RepresentationStatement {
+            bases: vec![G::generator(), Self::blinding_base()],
+            point: *comm,
+        }
+    }
+}
+
+impl<G: CurveGroup> CommitmentScheme for PedersenCommitmentScheme<G> {
+    type Input = G::ScalarField;
+    type Output = G;
+    type Randomness = G::ScalarField;
+
+    fn commit<T: Borrow<Self::Input>>(
+        input: T,
+        r: Option<&Self::Randomness>,
+    ) -> Result<Self::Output, PrimitivesError> {
+        let r = r.ok_or_else(|| {
+            PrimitivesError::ParameterError("Expecting a blinding factor".to_string())
+        })?;
+        Ok(pedersen_commit(&[*input.borrow()], r, &[G::generator()]))
+    }
+
+    fn verify<T: Borrow<Self::Input>>(
+        input: T,
+        r: Option<&Self::Randomness>,
+        comm: &Self::Output,
+    ) -> Result<(), PrimitivesError> {
+        if <Self as CommitmentScheme>::commit(input, r)? == *comm {
+            Ok(())
+        } else {
+            Err(PrimitivesError::VerificationError(String::from(
+                "Commitment verification failed",
+            )))
+        }
+    }
+}
+
+/// Pedersen commitment `C=m_1G_1+...+m_nG_n+rH` to a vector of scalars
+/// `(m_1, ..., m_n)` with blinding factor `r`, where the bases `G_i` and `H`
+/// are derived by hashing so that no relation between them is known. It is
+/// additively homomorphic as [`PedersenCommitmentScheme`], for vectors of the
+/// same length.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct VectorPedersenCommitmentScheme<G>(PhantomData<G>);
+
+impl<G: CurveGroup> VectorPedersenCommitmentScheme<G> {
+    /// The bases `G_1, ..., G_len` of the commitments to vectors of length
+    /// `len`. The bases of a shorter vector are a prefix of them.
+    pub fn bases(len: usize) -> Vec<G> {
+        (1..=len as u64).map(pedersen_base).collect()
+    }
+
+    /// The blinding base `H`, the same as the one of
+    /// [`PedersenCommitmentScheme`].
+    pub fn blinding_base() -> G {
+        pedersen_base(0)
+    }
+
+    /// Prove the knowledge of the opening `(input, r)` of the commitment to
+    /// `input` under `r`, the challenge being derived from `transcript`.
+    pub fn prove_opening<R: CryptoRng + RngCore>(
+        transcript: &mut IOPTranscript<G::ScalarField>,
+        input: &[G::ScalarField],
+        r: &G::ScalarField,
+        rng: &mut R,
+    ) -> Result<Proof<Representation<G>>, PrimitivesError> {
+        let comm = pedersen_commit(input, r, &Self::bases(input.len()));
+        let mut witness = input.to_vec();
+        witness.push(*r);
+        Representation::prove(
+            transcript,
+            &Self::opening_statement(&comm, input.len()),
+            &witness,
+            rng,
+        )
+    }
+
+    /// Verify a proof of [`Self::prove_opening`] for the commitment `comm` to
+    /// a vector of length `len`.
+    /// * `returns` - Err() if the proof is invalid
+    pub fn verify_opening(
+        transcript: &mut IOPTranscript<G::ScalarField>,
+        comm: &G,
+        len: usize,
+        proof: &Proof<Representation<G>>,
+    ) -> Result<(), PrimitivesError> {
+        Representation::verify(transcript, &Self::opening_statement(comm, len), proof)
+    }
+
+    fn opening_statement(comm: &G, len: usize) -> RepresentationStatement<G> {
+        let mut bases = Self::bases(len);
+        bases.push(Self::blinding_base());
+        RepresentationStatement {
+            bases,
+            point: *comm,
+        }
+    }
+}
+
+impl<G: CurveGroup> CommitmentScheme for VectorPedersenCommitmentScheme<G> {
+    type Input = Vec<G::ScalarField>;
+    type Output = G;
+    type Randomness = G::ScalarField;
+
+    fn commit<T: Borrow<Self::Input>>(
+        input: T,
+        r: Option<&Self::Randomness>,
+    ) -> Result<Self::Output, PrimitivesError> {
+        let r = r.ok_or_else(|| {
+            PrimitivesError::ParameterError("Expecting a blinding factor".to_string())
+        })?;
+        let input = input.borrow();
+        Ok(pedersen_commit(input, r, &Self::bases(input.len())))
+    }
+
+    fn verify<T: Borrow<Self::Input>>(
+        input: T,
+        r: Option<&Self::Randomness>,
+        comm: &Self::Output,
+    ) -> Result<(), PrimitivesError> {
+        if <Self as CommitmentScheme>::commit(input, r)? == *comm {
+            Ok(())
+        } else {
+            Err(PrimitivesError::VerificationError(String::from(
+                "Commitment verification failed",
+            )))
+        }
+    }
+}
+
+// The `index`-th base of the Pedersen commitments, a point of unknown discrete
+// logarithm sampled from a seed derived from the domain separator. The
+// blinding base is at index `0`.
+fn pedersen_base<G: CurveGroup>(index: u64) -> G {
+    let mut seed = [0u8; 32];
+    seed.copy_from_slice(
+        Sha256::new()
+            .chain_update(CS_ID_PEDERSEN_COMMITMENT.as_bytes())
+            .chain_update(index.to_be_bytes())
+            .finalize()
+            .as_ref(),
+    );
+    G::rand(&mut ChaCha20Rng::from_seed(seed))
+}
+
+// `sum_i input[i] * bases[i] + r * H`.
+fn pedersen_commit<G: CurveGroup>(input: &[G::ScalarField], r: &G::ScalarField, bases: &[G]) -> G {
+    input
+        .iter()
+        .zip(bases.iter())
+        .fold(pedersen_base::<G>(0) * r, |acc, (m, base)| acc + *base * m)
+}
+
 #[cfg(test)]
 mod test {
     use crate::{
-        commitment::{CommitmentScheme, FixedLengthRescueCommitment},
-        rescue::{sponge::RescueCRHF, CRHF_RATE},
+        commitment::{
+            CommitmentScheme, FixedLengthRescueCommitment, MerkleTreeCommitmentScheme,
+            PedersenCommitmentScheme, VectorPedersenCommitmentScheme,
+        },
+        merkle_tree::{
+            prelude::{RescueHash, RescueMerkleTree},
+            MerkleTreeScheme,
+        },
+        pcs::transcript::IOPTranscript,
+        rescue::{sponge::RescueCRHF, RescueParameter, CRHF_RATE},
     };
     use ark_bls12_377::Fq as Fq377;
     use ark_bn254::Fq as Fq254;
+    use ark_ec::CurveGroup;
     use ark_ed_on_bls12_377::Fq as FqEd377;
     use ark_ed_on_bls12_381::Fq as FqEd381;
     use ark_ed_on_bls12_381_bandersnatch::Fq as FqEd381b;
     use ark_ed_on_bn254::Fq as FqEd254;
     use ark_ff::UniformRand;
-    use ark_std::vec;
+    use ark_std::{vec, vec::Vec};
+    use typenum::U3;
 
     macro_rules! test_commit {
         ($tr:tt) => {
@@ -160,4 +469,134 @@ mod test {
         test_commit!(Fq377);
         test_commit!(Fq254);
     }
+
+    #[test]
+    fn test_merkle_tree_commitment() {
+        test_merkle_tree_commitment_helper::<FqEd254>();
+        test_merkle_tree_commitment_helper::<FqEd377>();
+        test_merkle_tree_commitment_helper::<FqEd381>();
+    }
+
+    fn test_merkle_tree_commitment_helper<F: RescueParameter>() {
+        type Scheme<F> = MerkleTreeCommitmentScheme<F, RescueHash<F>, U3, F>;
+        let input: Vec<F> = (0..10u64).map(F::from).collect();
+        let c = Scheme::<F>::commit(&input, None).unwrap();
+        assert_eq!(
+            c,
+            RescueMerkleTree::<F>::from_elems(None, &input)
+                .unwrap()
+                .commitment()
+        );
+        assert!(Scheme::<F>::verify(&input, None, &c).is_ok());
+        assert!(Scheme::<F>::verify(&input[..9].to_vec(), None, &c).is_err());
+        let mut bad_input = input.clone();
+        bad_input.swap(0, 1);
+        assert!(Scheme::<F>::verify(&bad_input, None, &c).is_err());
+
+        for pos in 0..10u64 {
+            let (elem, proof) = Scheme::<F>::open(&input, pos).unwrap();
+            assert_eq!(elem, input[pos as usize]);
+            assert!(Scheme::<F>::verify_opening(&c, pos, &elem, &proof).is_ok());
+            assert!(Scheme::<F>::verify_opening(&c, pos, &F::from(100u64), &proof).is_err());
+            assert!(Scheme::<F>::verify_opening(&c, (pos + 1) % 10, &elem, &proof).is_err());
+        }
+        assert!(Scheme::<F>::open(&input, 10).is_err());
+    }
+
+    #[test]
+    fn test_pedersen_commitment() {
+        test_pedersen_commitment_helper::<ark_bn254::G1Projective>();
+        test_pedersen_commitment_helper::<ark_bls12_381::G1Projective>();
+        test_pedersen_commitment_helper::<ark_ed_on_bn254::EdwardsProjective>();
+    }
+
+    fn test_pedersen_commitment_helper<G: CurveGroup>() {
+        type Scheme<G> = PedersenCommitmentScheme<G>;
+        let mut rng = jf_utils::test_rng();
+        let m = G::ScalarField::rand(&mut rng);
+        let r = G::ScalarField::rand(&mut rng);
+        let c = Scheme::<G>::commit(m, Some(&r)).unwrap();
+        assert_eq!(c, G::generator() * m + Scheme::<G>::blinding_base() * r);
+        assert!(Scheme::<G>::verify(m, Some(&r), &c).is_ok());
+        assert!(Scheme::<G>::verify(m + r, Some(&r), &c).is_err());
+        assert!(Scheme::<G>::verify(m, Some(&m), &c).is_err());
+        assert!(Scheme::<G>::commit(m, None).is_err());
+
+        // homomorphism
+        let m2 = G::ScalarField::rand(&mut rng);
+        let r2 = G::ScalarField::rand(&mut rng);
+        let c2 = Scheme::<G>::commit(m2, Some(&r2)).unwrap();
+        assert!(Scheme::<G>::verify(m + m2, Some(&(r + r2)), &(c + c2)).is_ok());
+        assert!(Scheme::<G>::verify(m * m2, Some(&(r * m2)), &(c * m2)).is_ok());
+
+        // opening proof
+        let proof =
+            Scheme::<G>::prove_opening(&mut IOPTranscript::new(b"test"), &m, &r, &mut rng).unwrap();
+        assert!(Scheme::<G>::verify_opening(&mut IOPTranscript::new(b"test"), &c, &proof).is_ok());
+        assert!(
+            Scheme::<G>::verify_opening(&mut IOPTranscript::new(b"test"), &c2, &proof).is_err()
+        );
+        assert!(
+            Scheme::<G>::verify_opening(&mut IOPTranscript::new(b"other"), &c, &proof).is_err()
+        );
+
+        type VectorScheme<G> = VectorPedersenCommitmentScheme<G>;
+        let input: Vec<_> = (0..5).map(|_| G::ScalarField::rand(&mut rng)).collect();
+        let c = VectorScheme::<G>::commit(&input, Some(&r)).unwrap();
+        let bases = VectorScheme::<G>::bases(5);
+        let expected = bases
+            .iter()
+            .zip(input.iter())
+            .fold(VectorScheme::<G>::blinding_base() * r, |acc, (base, m)| {
+                acc + *base * m
+            });
+        assert_eq!(c, expected);
+        assert_eq!(VectorScheme::<G>::bases(3)[..], bases[..3]);
+        assert_eq!(
+            VectorScheme::<G>::blinding_base(),
+            Scheme::<G>::blinding_base()
+        );
+        assert_ne!(bases[0], bases[1]);
+        assert!(VectorScheme::<G>::verify(&input, Some(&r), &c).is_ok());
+        assert!(VectorScheme::<G>::verify(&input[..4].to_vec(), Some(&r), &c).is_err());
+        let mut bad_input = input.clone();
+        bad_input.swap(0, 1);
+        assert!(VectorScheme::<G>::verify(&bad_input, Some(&r), &c).is_err());
+        assert!(VectorScheme::<G>::verify(&input, Some(&m), &c).is_err());
+        assert!(VectorScheme::<G>::commit(&input, None).is_err());
+
+        // homomorphism
+        let input2: Vec<_> = (0..5).map(|_| G::ScalarField::rand(&mut rng)).collect();
+        let c2 = VectorScheme::<G>::commit(&input2, Some(&r2)).unwrap();
+        let sum: Vec<_> = input
+            .iter()
+            .zip(input2.iter())
+            .map(|(a, b)| *a + b)
+            .collect();
+        assert!(VectorScheme::<G>::verify(&sum, Some(&(r + r2)), &(c + c2)).is_ok());
+
+        // opening proof
+        let proof = VectorScheme::<G>::prove_opening(
+            &mut IOPTranscript::new(b"test"),
+            &input,
+            &r,
+            &mut rng,
+        )
+        .unwrap();
+        assert!(
+            VectorScheme::<G>::verify_opening(&mut IOPTranscript::new(b"test"), &c, 5, &proof)
+                .is_ok()
+        );
+        assert!(
+            VectorScheme::<G>::verify_opening(&mut IOPTranscript::new(b"test"), &c, 4, &proof)
+                .is_err()
+        );
+        assert!(VectorScheme::<G>::verify_opening(
+            &mut IOPTranscript::new(b"test"),
+            &c2,
+            5,
+            &proof
+        )
+        .is_err());
+    }
 }
@@ -9,10 +9,84 @@
 /// ciphersuite identifier for schnorr signature
 pub const CS_ID_SCHNORR: &str = "SCHNORR_WITH_RESCUE_HASH_v01";
 
+/// ciphersuite identifier for BIP-340 Schnorr signatures over secp256k1,
+/// which is the tag of the challenge hash, see: <https://github.com/bitcoin/bips/blob/master/bip-0340.mediawiki>
+pub const CS_ID_BIP340: &str = "BIP0340/challenge";
+
+/// ciphersuite identifier for Ed25519 signatures, see: <https://datatracker.ietf.org/doc/html/rfc8032>
+pub const CS_ID_ED25519: &str = "ED25519_SHA512_RFC8032";
+
+/// ciphersuite identifier for ECDSA over secp256k1 with Keccak256 message
+/// hashes and RFC 6979 deterministic nonces
+pub const CS_ID_ECDSA_SECP256K1: &str = "ECDSA_SECP256K1_KECCAK256_RFC6979";
+
+/// ciphersuite identifier for ring signatures over the Schnorr curves
+pub const CS_ID_RING_SIG: &str = "RING_SIG_WITH_RESCUE_HASH_v01";
+
+/// domain separator of the hash to curve deriving the base of the key images
+/// of linkable ring signatures
+pub const CS_ID_RING_SIG_KEY_IMAGE: &str = "RING_SIG_KEY_IMAGE_WITH_RESCUE_HASH_v01";
+
+/// domain separator of the key aggregation coefficients of MuSig2
+pub const CS_ID_MUSIG2_KEY_AGG: &str = "MUSIG2_KEY_AGG_WITH_RESCUE_HASH_v01";
+
+/// domain separator of the nonce coefficient of MuSig2
+pub const CS_ID_MUSIG2_NONCE: &str = "MUSIG2_NONCE_WITH_RESCUE_HASH_v01";
+
+/// domain separator of the proofs of validity of threshold ElGamal decryption
+/// shares
+pub const CS_ID_THRESHOLD_ELGAMAL_DLEQ: &str = "THRESHOLD_ELGAMAL_DLEQ_WITH_RESCUE_HASH_v01";
+
+/// domain separator of the proofs of correct rerandomization of homomorphic
+/// ElGamal ciphertexts
+pub const CS_ID_ELGAMAL_RERANDOMIZATION: &str = "ELGAMAL_RERANDOMIZATION_WITH_RESCUE_HASH_v01";
+
+/// domain separator of the shares encrypted by the dealers of the Pedersen
+/// distributed key generation
+pub const CS_ID_PEDERSEN_DKG: &str = "PEDERSEN_DKG_SHARE_v01";
+
+/// domain separator of the blinding base of the Pedersen verifiable secret
+/// sharing
+pub const CS_ID_PEDERSEN_VSS: &str = "PEDERSEN_VSS_BLINDING_BASE_v01";
+
+/// domain separator of the bases of Pedersen commitments
+pub const CS_ID_PEDERSEN_COMMITMENT: &str = "PEDERSEN_COMMITMENT_BASES_v01";
+
+/// domain separator of the keys derived with HKDF from a master seed
+pub const CS_ID_HKDF: &str = "JF_HKDF_SHA256_KEY_DERIVATION_v01";
+
+/// domain separator of the Fiat-Shamir challenges of sigma protocols
+pub const CS_ID_SIGMA: &str = "JF_SIGMA_FIAT_SHAMIR_v01";
+
+/// domain separator of the hashes to the group and to the challenge primes of
+/// the Wesolowski VDF
+pub const CS_ID_WESOLOWSKI_VDF: &str = "WESOLOWSKI_VDF_RSA_SHA256_v01";
+
+/// salt of the derivation of the keys of timelock encryption
+pub const CS_ID_TIMELOCK: &str = "JF_TIMELOCK_RSW_AEAD_v01";
+
+/// domain separator of the hash of the shared points of stealth addresses
+pub const CS_ID_STEALTH_ADDRESS: &str = "JF_STEALTH_ADDRESS_v01";
+
 /// ciphersuite identifier for BLS signature over BLS12_381, see:
 /// <https://www.ietf.org/archive/id/draft-irtf-cfrg-bls-signature-05.html#name-ciphersuite-format>
 pub const CS_ID_BLS_MIN_SIG: &str = "BLS_SIG_BLS12381G1_XMD:SHA-256_SSWU_RO_NUL_";
 
+/// API identifier of the BLS12-381-SHA-256 ciphersuite of BBS+ signatures,
+/// prefixing the DSTs of all its hashes, see:
+/// <https://datatracker.ietf.org/doc/draft-irtf-cfrg-bbs-signatures/>
+pub const CS_ID_BBS: &str = "BBS_BLS12381G1_XMD:SHA-256_SSWU_RO_H2G_HM2S_";
+
+/// ciphersuite identifier for proofs of possession of BLS keys over BLS12_381,
+/// see: <https://www.ietf.org/archive/id/draft-irtf-cfrg-bls-signature-05.html#name-proof-of-possession>
+pub const CS_ID_BLS_MIN_SIG_POP: &str = "BLS_POP_BLS12381G1_XMD:SHA-256_SSWU_RO_POP_";
+
+/// purpose of the EIP-2334 derivation paths of BLS keys, see:
+/// <https://eips.ethereum.org/EIPS/eip-2334>
+pub const EIP2334_PURPOSE: u32 = 12381;
+/// coin type of Ethereum in the EIP-2334 derivation paths of BLS keys.
+pub const EIP2334_COIN_TYPE: u32 = 3600;
+
 /// Size in bytes of a secret key in our BLS signature scheme.
 pub const BLS_SIG_SK_SIZE: usize = 32;
 /// Size in bytes of a signature in our BLS signature scheme.
@@ -24,7 +98,34 @@ pub const BLS_SIG_PK_SIZE: usize = 192;
 /// Size in bytes of a compressed verification key in our BLS signature scheme.
 pub const BLS_SIG_COMPRESSED_PK_SIZE: usize = 96;
 
+/// ciphersuite identifier for BLS signatures over BLS12_381 with keys in G1,
+/// the ciphersuite of Ethereum, see:
+/// <https://www.ietf.org/archive/id/draft-irtf-cfrg-bls-signature-05.html#name-proof-of-possession>
+pub const CS_ID_BLS_MIN_PK: &str = "BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_";
+
+/// ciphersuite identifier for proofs of possession of BLS keys in G1 over
+/// BLS12_381.
+pub const CS_ID_BLS_MIN_PK_POP: &str = "BLS_POP_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_";
+
+/// Size in bytes of a signature in the min-pk BLS signature scheme.
+pub const BLS_MIN_PK_SIGNATURE_SIZE: usize = 192;
+/// Size in bytes of a compressed signature in the min-pk BLS signature scheme.
+pub const BLS_MIN_PK_COMPRESSED_SIGNATURE_SIZE: usize = 96;
+/// Size in bytes of a verification key in the min-pk BLS signature scheme.
+pub const BLS_MIN_PK_PK_SIZE: usize = 96;
+/// Size in bytes of a compressed verification key in the min-pk BLS signature
+/// scheme.
+pub const BLS_MIN_PK_COMPRESSED_PK_SIZE: usize = 48;
+
 /// ciphersuite identifier for BLS signature over BN254
 /// Note this is **adapted** from <https://www.ietf.org/archive/id/draft-irtf-cfrg-bls-signature-05.html#name-ciphersuite-format>.
 /// In particular the "hash-and-pray" method is not part of <https://datatracker.ietf.org/doc/html/draft-irtf-cfrg-hash-to-curve-16>, so the tag "NCTH" (non constant time hash) is not standard.
 pub const CS_ID_BLS_BN254: &str = "BLS_SIG_BN254G1_XMD:KECCAK_NCTH_NUL_";
+
+/// ciphersuite identifier for proofs of possession of BLS keys over BN254,
+/// adapted in the same way as [`CS_ID_BLS_BN254`].
+pub const CS_ID_BLS_BN254_POP: &str = "BLS_POP_BN254G1_XMD:KECCAK_NCTH_POP_";
+
+/// ciphersuite identifier for the proofs of the BLS VRF over BN254, adapted
+/// in the same way as [`CS_ID_BLS_BN254`].
+pub const CS_ID_BLS_VRF_BN254: &str = "BLS_VRF_BN254G1_XMD:KECCAK_NCTH_NUL_";
@@ -12,7 +12,12 @@ use ark_std::{
 
 use crate::{
     errors::PrimitivesError,
-    rescue::{sponge::RescueCRHF, RescueParameter, CRHF_RATE},
+    poseidon2::{self, Poseidon2CRHF, Poseidon2Parameter},
+    rescue::{
+        prime::{RescuePrimeCRHF, RescuePrimeParameter},
+        sponge::{RescueCRHF, RescueSpongeConfig},
+        RescueParameter,
+    },
 };
 
 /// A trait for CRHF
@@ -31,6 +36,24 @@ pub trait CRHF {
     fn evaluate<T: Borrow<Self::Input>>(input: T) -> Result<Self::Output, PrimitivesError>;
 }
 
+/// An application-level domain separator known at compile time, which lets
+/// hash functions used through static interfaces, such as the merkle tree
+/// [`DigestAlgorithm`](crate::merkle_tree::DigestAlgorithm)s of the prelude,
+/// hash in their own domain. Implementors are usually unit structs.
+pub trait DomainSeparator {
+    /// The domain separator, absorbed before the message. An empty domain
+    /// separator is the same as none.
+    const DOMAIN_SEPARATOR: &'static [u8];
+}
+
+/// The empty domain separator.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct NoDomainSeparator;
+
+impl DomainSeparator for NoDomainSeparator {
+    const DOMAIN_SEPARATOR: &'static [u8] = &[];
+}
+
 #[derive(Debug, Clone)]
 /// A rescue-sponge-based CRHF with fixed-input size (if not multiple of 3 will
 /// get auto-padded) and variable-output size
@@ -51,12 +74,23 @@ impl<F: RescueParameter, const INPUT_LEN: usize, const OUTPUT_LEN: usize> CRHF
     /// padded. By default, we use "zero padding"-style where as many "0" as
     /// required are added.
     fn evaluate<T: Borrow<Self::Input>>(input: T) -> Result<Self::Output, PrimitivesError> {
-        let mut output = [F::zero(); OUTPUT_LEN];
+        Self::evaluate_with_config(RescueSpongeConfig::default(), input)
+    }
+}
 
-        let res = match INPUT_LEN % CRHF_RATE {
-            0 => RescueCRHF::<F>::sponge_no_padding(input.borrow(), OUTPUT_LEN)?,
-            _ => RescueCRHF::<F>::sponge_with_zero_padding(input.borrow(), OUTPUT_LEN),
-        };
+impl<F: RescueParameter, const INPUT_LEN: usize, const OUTPUT_LEN: usize>
+    FixedLengthRescueCRHF<F, INPUT_LEN, OUTPUT_LEN>
+{
+    /// Same as [`CRHF::evaluate`] with the rate/capacity split of `config`
+    /// instead of the default one, `input` being zero-padded to a multiple of
+    /// its rate.
+    pub fn evaluate_with_config<T: Borrow<[F; INPUT_LEN]>>(
+        config: RescueSpongeConfig,
+        input: T,
+    ) -> Result<[F; OUTPUT_LEN], PrimitivesError> {
+        let mut output = [F::zero(); OUTPUT_LEN];
+        let crhf = RescueCRHF::<F>::new(config)?;
+        let res = crhf.hash_with_zero_padding(input.borrow(), OUTPUT_LEN);
         if res.len() != OUTPUT_LEN {
             return Err(PrimitivesError::InternalError(
                 "Unexpected rescue sponge return length".to_string(),
@@ -82,8 +116,21 @@ impl<F: RescueParameter, const OUTPUT_LEN: usize> CRHF for VariableLengthRescueC
     /// appended, then as many "0" as required are added for the overall
     /// length to be a multiple of `CRHF_RATE`.
     fn evaluate<T: Borrow<Self::Input>>(input: T) -> Result<Self::Output, PrimitivesError> {
+        Self::evaluate_with_config(RescueSpongeConfig::default(), input)
+    }
+}
+
+impl<F: RescueParameter, const OUTPUT_LEN: usize> VariableLengthRescueCRHF<F, OUTPUT_LEN> {
+    /// Same as [`CRHF::evaluate`] with the rate/capacity split of `config`
+    /// instead of the default one, `input` being bit-padded to a multiple of
+    /// its rate.
+    pub fn evaluate_with_config<T: Borrow<Vec<F>>>(
+        config: RescueSpongeConfig,
+        input: T,
+    ) -> Result<[F; OUTPUT_LEN], PrimitivesError> {
         let mut output = [F::zero(); OUTPUT_LEN];
-        let res = RescueCRHF::<F>::sponge_with_bit_padding(input.borrow(), OUTPUT_LEN);
+        let crhf = RescueCRHF::<F>::new(config)?;
+        let res = crhf.hash_with_bit_padding(input.borrow(), OUTPUT_LEN);
         if res.len() != OUTPUT_LEN {
             return Err(PrimitivesError::InternalError(
                 "Unexpected rescue sponge return length".to_string(),
@@ -93,3 +140,95 @@ impl<F: RescueParameter, const OUTPUT_LEN: usize> CRHF for VariableLengthRescueC
         Ok(output)
     }
 }
+
+#[derive(Debug, Clone)]
+/// A poseidon2-sponge-based CRHF with fixed-input size (if not multiple of 2
+/// will get auto-padded) and variable-output size
+pub struct FixedLengthPoseidon2CRHF<
+    F: Poseidon2Parameter,
+    const INPUT_LEN: usize,
+    const OUTPUT_LEN: usize,
+>(PhantomData<F>);
+
+impl<F: Poseidon2Parameter, const INPUT_LEN: usize, const OUTPUT_LEN: usize> CRHF
+    for FixedLengthPoseidon2CRHF<F, INPUT_LEN, OUTPUT_LEN>
+{
+    type Input = [F; INPUT_LEN];
+    type Output = [F; OUTPUT_LEN];
+
+    /// ## Padding
+    /// if `input` length is not a multiple of `poseidon2::CRHF_RATE`, then it
+    /// will be padded with as many "0" as required.
+    fn evaluate<T: Borrow<Self::Input>>(input: T) -> Result<Self::Output, PrimitivesError> {
+        let mut output = [F::zero(); OUTPUT_LEN];
+
+        let res = match INPUT_LEN % poseidon2::CRHF_RATE {
+            0 => Poseidon2CRHF::<F>::sponge_no_padding(input.borrow(), OUTPUT_LEN)?,
+            _ => Poseidon2CRHF::<F>::sponge_with_zero_padding(input.borrow(), OUTPUT_LEN),
+        };
+        if res.len() != OUTPUT_LEN {
+            return Err(PrimitivesError::InternalError(
+                "Unexpected poseidon2 sponge return length".to_string(),
+            ));
+        }
+
+        output.copy_from_slice(&res[..]);
+        Ok(output)
+    }
+}
+
+#[derive(Debug, Clone)]
+/// A poseidon2-sponge-based CRHF with variable-input and variable-output size
+pub struct VariableLengthPoseidon2CRHF<F: Poseidon2Parameter, const OUTPUT_LEN: usize>(
+    PhantomData<F>,
+);
+
+impl<F: Poseidon2Parameter, const OUTPUT_LEN: usize> CRHF
+    for VariableLengthPoseidon2CRHF<F, OUTPUT_LEN>
+{
+    type Input = Vec<F>;
+    type Output = [F; OUTPUT_LEN];
+
+    /// ## Padding
+    /// "1" is always appended, then as many "0" as required are added for the
+    /// overall length to be a multiple of `poseidon2::CRHF_RATE`.
+    fn evaluate<T: Borrow<Self::Input>>(input: T) -> Result<Self::Output, PrimitivesError> {
+        let mut output = [F::zero(); OUTPUT_LEN];
+        let res = Poseidon2CRHF::<F>::sponge_with_bit_padding(input.borrow(), OUTPUT_LEN);
+        if res.len() != OUTPUT_LEN {
+            return Err(PrimitivesError::InternalError(
+                "Unexpected poseidon2 sponge return length".to_string(),
+            ));
+        }
+        output.copy_from_slice(&res[..]);
+        Ok(output)
+    }
+}
+
+#[derive(Debug, Clone)]
+/// A Rescue-Prime-based CRHF with variable-input and variable-output size
+pub struct VariableLengthRescuePrimeCRHF<F: RescuePrimeParameter, const OUTPUT_LEN: usize>(
+    PhantomData<F>,
+);
+
+impl<F: RescuePrimeParameter, const OUTPUT_LEN: usize> CRHF
+    for VariableLengthRescuePrimeCRHF<F, OUTPUT_LEN>
+{
+    type Input = Vec<F>;
+    type Output = [F; OUTPUT_LEN];
+
+    /// ## Padding
+    /// "1" is always appended, then as many "0" as required are added for the
+    /// overall length to be a multiple of `rescue::CRHF_RATE`.
+    fn evaluate<T: Borrow<Self::Input>>(input: T) -> Result<Self::Output, PrimitivesError> {
+        let mut output = [F::zero(); OUTPUT_LEN];
+        let res = RescuePrimeCRHF::<F>::sponge_with_padding(input.borrow(), OUTPUT_LEN);
+        if res.len() != OUTPUT_LEN {
+            return Err(PrimitivesError::InternalError(
+                "Unexpected rescue prime sponge return length".to_string(),
+            ));
+        }
+        output.copy_from_slice(&res[..]);
+        Ok(output)
+    }
+}
@@ -0,0 +1,1009 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+//! This module implements the distributed key generation (DKG) of Pedersen,
+//! in its Joint-Feldman form: `n` parties generate a key `x` shared between
+//! them, such that any `t` of them can use it while no party learns `x`. The
+//! outputs are the keys of [`ThresholdBLS`] and [`ThresholdElGamal`].
+//!
+//! Every party holds a long-term [`aead`] key pair, under which the shares
+//! dealt to it are encrypted, so that all messages are broadcast and the
+//! whole protocol is determined by its [`Transcript`]. With `G` the generator
+//! of the group:
+//!
+//! **Deal** (every party `i`)
+//!    * share a random secret with the [Feldman VSS][vss]: sample a polynomial
+//!      `f_i` of degree `t-1` with coefficients `a_{i,k}`, and broadcast the
+//!      commitments `C_{i,k}=a_{i,k}*G` along with the shares `f_i(j)` of the
+//!      parties `j` in `1..=n`, encrypted to them
+//!
+//! **Complain** (every party `j`)
+//!    * broadcast a complaint against every dealer `i` whose share `s` does not
+//!      decrypt, or does not satisfy `s*G=sum_k j^k*C_{i,k}`
+//!
+//! **Respond** (every dealer `i`)
+//!    * answer every complaint of a party `j` by broadcasting `f_i(j)`
+//!
+//! **Finalize** (every party `j`)
+//!    * the qualified dealers `Q` are those whose dealing is well-formed and
+//!      who answered every complaint against them with a valid share. The
+//!      shared key is `x=sum_{i in Q} f_i(0)`, with public key `sum_{i in Q}
+//!      C_{i,0}`; the key share of party `j` is `x_j=sum_{i in Q} f_i(j)`, with
+//!      verification share `sum_{i in Q} sum_k j^k*C_{i,k}`
+//!
+//! The transport must authenticate the sender of every message, check it
+//! against the indices in the message, and deliver the same messages to all
+//! parties before the end of each phase. With at most `t-1` corrupted
+//! parties, honest dealers are always qualified, and the transcript is
+//! rejected if fewer than `t` dealers are. The transcript determines the
+//! public outputs, which anyone can compute with
+//! [`PedersenDkg::verify_transcript`].
+//!
+//! As shown by Gennaro, Jarecki, Krawczyk and Rabin, the corrupted parties can
+//! bias the distribution of the public key of this protocol, by choosing
+//! which of them are qualified after seeing the honest dealings. The shared
+//! key remains unknown to them, which suffices for the security of threshold
+//! signatures and decryption, see their *Secure Applications of Pedersen's
+//! Distributed Key Generation Protocol* (CT-RSA 2003).
+//!
+//! ```
+//! use jf_primitives::{
+//!     aead,
+//!     dkg::{PedersenDkg, Transcript},
+//! };
+//!
+//! let mut rng = jf_utils::test_rng();
+//! let dkg = PedersenDkg::new(2, 3).unwrap();
+//! let key_pairs: Vec<_> = (0..3).map(|_| aead::KeyPair::generate(&mut rng)).collect();
+//! let enc_keys: Vec<_> = key_pairs.iter().map(|kp| kp.enc_key()).collect();
+//!
+//! let mut transcript = Transcript::<ark_bn254::G2Projective>::default();
+//! for dealer in 1..=3 {
+//!     let (_secret, dealing) = dkg.deal(dealer, &enc_keys, &mut rng).unwrap();
+//!     transcript.dealings.push(dealing);
+//! }
+//! for (kp, index) in key_pairs.iter().zip(1..) {
+//!     for dealing in transcript.dealings.iter() {
+//!         assert!(dkg.complaint(dealing, index, kp).unwrap().is_none());
+//!     }
+//! }
+//!
+//! let outputs: Vec<_> = key_pairs
+//!     .iter()
+//!     .zip(1..)
+//!     .map(|(kp, index)| dkg.finalize(&transcript, index, kp).unwrap())
+//!     .collect();
+//! assert_eq!(outputs[1].public_output(), outputs[0].public_output());
+//! let (tbls, _vk, _vk_shares, _sk_share) = outputs[0].threshold_bls_keys();
+//! assert_eq!(tbls.threshold(), 2);
+//! ```
+//!
+//! [`ThresholdBLS`]: crate::signatures::threshold_bls::ThresholdBLS
+//! [`ThresholdElGamal`]: crate::elgamal::threshold::ThresholdElGamal
+//! [vss]: crate::vss
+
+use crate::{
+    aead,
+    constants::CS_ID_PEDERSEN_DKG,
+    elgamal::{
+        threshold::{DecKeyShare, EncKeyShare, ThresholdElGamal},
+        EncKey,
+    },
+    errors::PrimitivesError::{self, ParameterError, VerificationError},
+    shamir::{self, Share},
+    signatures::{
+        bls_over_bn254::{SignKey, VerKey},
+        threshold_bls::{SignKeyShare, ThresholdBLS, VerKeyShare},
+    },
+    vss::{FeldmanCommitment, FeldmanVss},
+};
+use ark_bn254::G2Projective;
+use ark_ec::{
+    twisted_edwards::{Projective, TECurveConfig},
+    CurveGroup,
+};
+use ark_serialize::*;
+use ark_std::{
+    format,
+    rand::{CryptoRng, RngCore},
+    vec,
+    vec::Vec,
+    UniformRand, Zero,
+};
+use zeroize::Zeroize;
+
+/// Parameters of the distributed generation of a key shared between
+/// `num_parties` parties, any `threshold` of which can use it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct PedersenDkg {
+    vss: FeldmanVss,
+}
+
+/// The shares dealt by a dealer, to keep until the complaints against it are
+/// answered.
+#[derive(CanonicalSerialize, CanonicalDeserialize)]
+pub struct DealerSecret<G: CurveGroup> {
+    dealer: u32,
+    shares: Vec<Share<G::ScalarField>>,
+}
+
+/// The message broadcast by a dealer: the commitments to its polynomial and
+/// the encrypted shares of the parties.
+#[derive(Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct Dealing<G: CurveGroup> {
+    dealer: u32,
+    commitment: FeldmanCommitment<G>,
+    enc_shares: Vec<aead::Ciphertext>,
+}
+
+/// The complaint of a party against the share dealt to it by a dealer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, CanonicalSerialize, CanonicalDeserialize)]
+pub struct Complaint {
+    dealer: u32,
+    complainer: u32,
+}
+
+/// The answer of a dealer to a complaint, which reveals the share of the
+/// complainer.
+#[derive(Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct ComplaintResponse<G: CurveGroup> {
+    dealer: u32,
+    share: Share<G::ScalarField>,
+}
+
+/// All the messages broadcast during a distributed key generation, in any
+/// order.
+#[derive(Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct Transcript<G: CurveGroup> {
+    /// The dealings of the dealers
+    pub dealings: Vec<Dealing<G>>,
+    /// The complaints of the parties against the dealings
+    pub complaints: Vec<Complaint>,
+    /// The answers of the dealers to the complaints
+    pub responses: Vec<ComplaintResponse<G>>,
+}
+
+/// The public outcome of a distributed key generation.
+#[derive(Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct PublicOutput<G: CurveGroup> {
+    params: PedersenDkg,
+    qualified: Vec<u32>,
+    public_key: G,
+    ver_key_shares: Vec<G>,
+}
+
+/// The outcome of a distributed key generation for one party, including its
+/// key share.
+#[derive(Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct PartyOutput<G: CurveGroup> {
+    index: u32,
+    key_share: G::ScalarField,
+    public: PublicOutput<G>,
+}
+
+impl<G: CurveGroup> Drop for PartyOutput<G> {
+    fn drop(&mut self) {
+        self.key_share.zeroize();
+    }
+}
+
+impl<G: CurveGroup> Default for Transcript<G> {
+    fn default() -> Self {
+        Self {
+            dealings: Vec::new(),
+            complaints: Vec::new(),
+            responses: Vec::new(),
+        }
+    }
+}
+
+impl<G: CurveGroup> DealerSecret<G> {
+    /// The index of the dealer, in `1..=n`.
+    pub fn dealer(&self) -> u32 {
+        self.dealer
+    }
+
+    /// Answer a complaint against the dealer by revealing the share of the
+    /// complainer.
+    /// * `returns` - Err() if the complaint is against another dealer, or if
+    ///   the complainer is out of range
+    pub fn respond(&self, complaint: &Complaint) -> Result<ComplaintResponse<G>, PrimitivesError> {
+        if complaint.dealer != self.dealer {
+            return Err(ParameterError(format!(
+                "complaint against dealer {} answered by dealer {}",
+                complaint.dealer, self.dealer
+            )));
+        }
+        let share = complaint
+            .complainer
+            .checked_sub(1)
+            .and_then(|i| self.shares.get(i as usize))
+            .ok_or_else(|| {
+                ParameterError(format!(
+                    "party index {} out of range 1..={}",
+                    complaint.complainer,
+                    self.shares.len()
+                ))
+            })?;
+        Ok(ComplaintResponse {
+            dealer: self.dealer,
+            share: share.clone(),
+        })
+    }
+}
+
+impl<G: CurveGroup> Dealing<G> {
+    /// The index of the dealer, in `1..=n`.
+    pub fn dealer(&self) -> u32 {
+        self.dealer
+    }
+
+    /// The commitments to the polynomial of the dealer.
+    pub fn commitment(&self) -> &FeldmanCommitment<G> {
+        &self.commitment
+    }
+}
+
+impl Complaint {
+    /// The index of the dealer the complaint is against.
+    pub fn dealer(&self) -> u32 {
+        self.dealer
+    }
+
+    /// The index of the party which complained.
+    pub fn complainer(&self) -> u32 {
+        self.complainer
+    }
+}
+
+impl<G: CurveGroup> ComplaintResponse<G> {
+    /// The index of the dealer which answered the complaint.
+    pub fn dealer(&self) -> u32 {
+        self.dealer
+    }
+
+    /// The index of the party which complained.
+    pub fn complainer(&self) -> u32 {
+        self.share.index()
+    }
+}
+
+impl<G: CurveGroup> PublicOutput<G> {
+    /// The parameters of the distributed key generation.
+    pub fn params(&self) -> &PedersenDkg {
+        &self.params
+    }
+
+    /// The indices of the qualified dealers, in increasing order.
+    pub fn qualified(&self) -> &[u32] {
+        &self.qualified
+    }
+
+    /// The public key matching the shared key.
+    pub fn public_key(&self) -> &G {
+        &self.public_key
+    }
+
+    /// The verification shares of the parties, ordered by index.
+    pub fn ver_key_shares(&self) -> &[G] {
+        &self.ver_key_shares
+    }
+}
+
+impl<G: CurveGroup> PartyOutput<G> {
+    /// The index of the party, in `1..=n`.
+    pub fn index(&self) -> u32 {
+        self.index
+    }
+
+    /// The key share of the party.
+    pub fn key_share(&self) -> &G::ScalarField {
+        &self.key_share
+    }
+
+    /// The public outcome of the distributed key generation.
+    pub fn public_output(&self) -> &PublicOutput<G> {
+        &self.public
+    }
+}
+
+impl PublicOutput<G2Projective> {
+    /// The shared key as a threshold BLS key.
+    /// * `returns` - the scheme, the group verification key and the
+    ///   verification shares of the parties, ordered by index
+    pub fn threshold_bls_keys(&self) -> (ThresholdBLS, VerKey, Vec<VerKeyShare>) {
+        // safe unwraps: the parameters and indices are valid
+        let tbls = ThresholdBLS::new(self.params.threshold(), self.params.num_parties()).unwrap();
+        let vk_shares = self
+            .ver_key_shares
+            .iter()
+            .zip(1..)
+            .map(|(vk, index)| VerKeyShare::new(index, VerKey(*vk)).unwrap())
+            .collect();
+        (tbls, VerKey(self.public_key), vk_shares)
+    }
+}
+
+impl PartyOutput<G2Projective> {
+    /// The shared key as a threshold BLS key.
+    /// * `returns` - the scheme, the group verification key, the verification
+    ///   shares of the parties, ordered by index, and the key share of the
+    ///   party
+    pub fn threshold_bls_keys(&self) -> (ThresholdBLS, VerKey, Vec<VerKeyShare>, SignKeyShare) {
+        let (tbls, vk, vk_shares) = self.public.threshold_bls_keys();
+        // safe unwrap: the index is valid
+        let sk_share = SignKeyShare::new(self.index, SignKey(self.key_share)).unwrap();
+        (tbls, vk, vk_shares, sk_share)
+    }
+}
+
+impl<P: TECurveConfig> PublicOutput<Projective<P>> {
+    /// The shared key as a threshold ElGamal key.
+    /// * `returns` - the scheme, the group encryption key and the verification
+    ///   shares of the parties, ordered by index
+    pub fn threshold_elgamal_keys(&self) -> (ThresholdElGamal, EncKey<P>, Vec<EncKeyShare<P>>) {
+        // safe unwraps: the parameters and indices are valid
+        let tenc =
+            ThresholdElGamal::new(self.params.threshold(), self.params.num_parties()).unwrap();
+        let enc_shares = self
+            .ver_key_shares
+            .iter()
+            .zip(1..)
+            .map(|(key, index)| EncKeyShare::new(index, EncKey { key: *key }).unwrap())
+            .collect();
+        (
+            tenc,
+            EncKey {
+                key: self.public_key,
+            },
+            enc_shares,
+        )
+    }
+}
+
+impl<P: TECurveConfig> PartyOutput<Projective<P>> {
+    /// The shared key as a threshold ElGamal key.
+    /// * `returns` - the scheme, the group encryption key, the verification
+    ///   shares of the parties, ordered by index, and the key share of the
+    ///   party
+    pub fn threshold_elgamal_keys(
+        &self,
+    ) -> (
+        ThresholdElGamal,
+        EncKey<P>,
+        Vec<EncKeyShare<P>>,
+        DecKeyShare<P>,
+    ) {
+        let (tenc, enc_key, enc_shares) = self.public.threshold_elgamal_keys();
+        // safe unwrap: the index is valid
+        let dec_share = DecKeyShare::new(self.index, self.key_share).unwrap();
+        (tenc, enc_key, enc_shares, dec_share)
+    }
+}
+
+impl PedersenDkg {
+    /// Setup a key generation between `num_parties` parties, where any
+    /// `threshold` of them can use the key.
+    /// * `returns` - Err() if `threshold` is zero or greater than `num_parties`
+    pub fn new(threshold: u32, num_parties: u32) -> Result<Self, PrimitivesError> {
+        Ok(Self {
+            vss: FeldmanVss::new(threshold, num_parties)?,
+        })
+    }
+
+    /// The number of parties needed to use the key.
+    pub fn threshold(&self) -> u32 {
+        self.vss.threshold()
+    }
+
+    /// The number of parties holding a key share.
+    pub fn num_parties(&self) -> u32 {
+        self.vss.num_parties()
+    }
+
+    /// Deal the shares of a random secret as the dealer of index `dealer`.
+    /// * `recipients` - the encryption keys of the parties, ordered by index
+    /// * `returns` - the secret of the dealer, and its dealing to broadcast.
+    ///   Err() if `dealer` is out of range, or if there are not `num_parties`
+    ///   recipients
+    pub fn deal<G: CurveGroup, R: CryptoRng + RngCore>(
+        &self,
+        dealer: u32,
+        recipients: &[aead::EncKey],
+        prng: &mut R,
+    ) -> Result<(DealerSecret<G>, Dealing<G>), PrimitivesError> {
+        self.check_index(dealer)?;
+        if recipients.len() != self.num_parties() as usize {
+            return Err(ParameterError(format!(
+                "{} recipients for {} parties",
+                recipients.len(),
+                self.num_parties()
+            )));
+        }
+        let mut value = G::ScalarField::rand(prng);
+        let (commitment, shares) = self.vss.share::<G, _>(&value, prng);
+        value.zeroize();
+        let enc_shares = recipients
+            .iter()
+            .zip(shares.iter())
+            .map(|(enc_key, share)| {
+                let mut bytes = Vec::new();
+                share.serialize_compressed(&mut bytes)?;
+                let enc_share =
+                    enc_key.encrypt(&mut *prng, &bytes, &share_aad(dealer, share.index()));
+                bytes.zeroize();
+                enc_share
+            })
+            .collect::<Result<_, PrimitivesError>>()?;
+        Ok((
+            DealerSecret { dealer, shares },
+            Dealing {
+                dealer,
+                commitment,
+                enc_shares,
+            },
+        ))
+    }
+
+    /// Check that a dealing is well-formed: its dealer is in range, and it has
+    /// `threshold` commitments and `num_parties` encrypted shares.
+    /// * `returns` - Err() if the dealing is malformed
+    pub fn verify_dealing<G: CurveGroup>(
+        &self,
+        dealing: &Dealing<G>,
+    ) -> Result<(), PrimitivesError> {
+        self.check_index(dealing.dealer)?;
+        if dealing.commitment.coeffs().len() != self.threshold() as usize
+            || dealing.enc_shares.len() != self.num_parties() as usize
+        {
+            return Err(VerificationError(format!(
+                "malformed dealing of dealer {}",
+                dealing.dealer
+            )));
+        }
+        Ok(())
+    }
+
+    /// The complaint of the party of index `index` against a dealing, if its
+    /// share does not decrypt under `key_pair` or does not match the
+    /// commitments of the dealer.
+    /// * `returns` - the complaint to broadcast, if any. Err() if `index` is
+    ///   out of range, or if the dealing is malformed, in which case its dealer
+    ///   is disqualified without complaints
+    pub fn complaint<G: CurveGroup>(
+        &self,
+        dealing: &Dealing<G>,
+        index: u32,
+        key_pair: &aead::KeyPair,
+    ) -> Result<Option<Complaint>, PrimitivesError> {
+        self.check_index(index)?;
+        self.verify_dealing(dealing)?;
+        match self.decrypt_share(dealing, index, key_pair) {
+            Ok(_) => Ok(None),
+            Err(_) => Ok(Some(Complaint {
+                dealer: dealing.dealer,
+                complainer: index,
+            })),
+        }
+    }
+
+    /// Verify a transcript and compute its public outputs. A dealer is
+    /// disqualified if its dealing is malformed or repeated with a different
+    /// content, or if a complaint against it is not answered with a share
+    /// matching its commitments.
+    /// * `returns` - the qualified dealers, the public key and the verification
+    ///   shares of the parties. Err() if fewer than `threshold` dealers are
+    ///   qualified
+    pub fn verify_transcript<G: CurveGroup>(
+        &self,
+        transcript: &Transcript<G>,
+    ) -> Result<PublicOutput<G>, PrimitivesError> {
+        let n = self.num_parties() as usize;
+        let mut dealings: Vec<Option<&Dealing<G>>> = vec![None; n];
+        let mut disqualified = vec![false; n];
+        for dealing in transcript.dealings.iter() {
+            if self.check_index(dealing.dealer).is_err() {
+                continue;
+            }
+            let i = dealing.dealer as usize - 1;
+            if self.verify_dealing(dealing).is_err()
+                || matches!(dealings[i], Some(other) if other != dealing)
+            {
+                disqualified[i] = true;
+            }
+            dealings[i] = Some(dealing);
+        }
+        for complaint in transcript.complaints.iter() {
+            if self.check_index(complaint.dealer).is_err()
+                || self.check_index(complaint.complainer).is_err()
+            {
+                continue;
+            }
+            let i = complaint.dealer as usize - 1;
+            if let Some(dealing) = dealings[i] {
+                if self
+                    .revealed_share(transcript, dealing, complaint.complainer)
+                    .is_none()
+                {
+                    disqualified[i] = true;
+                }
+            }
+        }
+
+        let qualified: Vec<u32> = (1..=self.num_parties())
+            .filter(|&i| dealings[i as usize - 1].is_some() && !disqualified[i as usize - 1])
+            .collect();
+        if qualified.len() < self.threshold() as usize {
+            return Err(VerificationError(format!(
+                "{} qualified dealers for a threshold of {}",
+                qualified.len(),
+                self.threshold()
+            )));
+        }
+
+        // the commitments to the shared polynomial, sum of the qualified ones
+        let mut coeffs = vec![G::zero(); self.threshold() as usize];
+        for i in qualified.iter() {
+            // safe unwrap: qualified dealers have a well-formed dealing
+            let dealing = dealings[*i as usize - 1].unwrap();
+            for (acc, coeff) in coeffs.iter_mut().zip(dealing.commitment.coeffs().iter()) {
+                *acc += coeff;
+            }
+        }
+        let commitment = FeldmanCommitment::new(coeffs);
+        let ver_key_shares = (1..=self.num_parties())
+            .map(|index| commitment.eval(index))
+            .collect();
+        Ok(PublicOutput {
+            params: *self,
+            qualified,
+            public_key: commitment.secret_commitment(),
+            ver_key_shares,
+        })
+    }
+
+    /// Verify a transcript and compute the outputs of the party of index
+    /// `index`, whose shares are decrypted with `key_pair`.
+    /// * `returns` - the public outputs and the key share of the party. Err()
+    ///   if `index` is out of range, if the transcript is rejected by
+    ///   [`Self::verify_transcript`], or if a share of a qualified dealer to
+    ///   the party is invalid and not revealed in answer to a complaint
+    pub fn finalize<G: CurveGroup>(
+        &self,
+        transcript: &Transcript<G>,
+        index: u32,
+        key_pair: &aead::KeyPair,
+    ) -> Result<PartyOutput<G>, PrimitivesError> {
+        self.check_index(index)?;
+        let mut output = PartyOutput {
+            index,
+            key_share: G::ScalarField::zero(),
+            public: self.verify_transcript(transcript)?,
+        };
+        for dealer in output.public.qualified.iter() {
+            // safe unwrap: qualified dealers have a dealing
+            let dealing = transcript
+                .dealings
+                .iter()
+                .find(|dealing| dealing.dealer == *dealer)
+                .unwrap();
+            let share = match self.revealed_share(transcript, dealing, index) {
+                Some(share) => share,
+                None => self.decrypt_share(dealing, index, key_pair)?,
+            };
+            output.key_share += share.value();
+        }
+        Ok(output)
+    }
+
+    // Check that `index` is a party index, in `1..=n`.
+    fn check_index(&self, index: u32) -> Result<(), PrimitivesError> {
+        shamir::check_index(index, self.num_parties())
+    }
+
+    // Decrypt the share of the party of index `index` from a well-formed
+    // dealing, and check it against the commitments.
+    fn decrypt_share<G: CurveGroup>(
+        &self,
+        dealing: &Dealing<G>,
+        index: u32,
+        key_pair: &aead::KeyPair,
+    ) -> Result<Share<G::ScalarField>, PrimitivesError> {
+        let mut bytes = key_pair.decrypt(
+            &dealing.enc_shares[index as usize - 1],
+            &share_aad(dealing.dealer, index),
+        )?;
+        let share = Share::<G::ScalarField>::deserialize_compressed(&bytes[..]);
+        bytes.zeroize();
+        let share = share?;
+        if share.index() != index {
+            return Err(VerificationError(format!(
+                "share of party {} dealt to party {} by dealer {}",
+                share.index(),
+                index,
+                dealing.dealer
+            )));
+        }
+        self.vss.verify_share(&dealing.commitment, &share)?;
+        Ok(share)
+    }
+
+    // The valid share of the party of index `index` revealed by the dealer of
+    // `dealing`, if any.
+    fn revealed_share<G: CurveGroup>(
+        &self,
+        transcript: &Transcript<G>,
+        dealing: &Dealing<G>,
+        index: u32,
+    ) -> Option<Share<G::ScalarField>> {
+        transcript
+            .responses
+            .iter()
+            .find(|response| {
+                response.dealer == dealing.dealer
+                    && response.share.index() == index
+                    && self
+                        .vss
+                        .verify_share(&dealing.commitment, &response.share)
+                        .is_ok()
+            })
+            .map(|response| response.share.clone())
+    }
+}
+
+// The associated data of the encrypted share of a party, which binds it to
+// its dealer and recipient.
+fn share_aad(dealer: u32, index: u32) -> Vec<u8> {
+    [
+        CS_ID_PEDERSEN_DKG.as_bytes(),
+        &dealer.to_be_bytes()[..],
+        &index.to_be_bytes()[..],
+    ]
+    .concat()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        rescue::RescueParameter,
+        signatures::{bls_over_bn254::BLSOverBN254CurveSignatureScheme, SignatureScheme},
+    };
+    use ark_ec::{twisted_edwards::TECurveConfig as Config, Group};
+    use ark_ed_on_bls12_377::EdwardsConfig as Param377;
+    use ark_ed_on_bls12_381::EdwardsConfig as Param381;
+    use ark_ed_on_bls12_381_bandersnatch::EdwardsConfig as Param381b;
+    use ark_ed_on_bn254::EdwardsConfig as Param254;
+
+    type G254 = Projective<Param254>;
+
+    fn key_pairs<R: CryptoRng + RngCore>(n: u32, rng: &mut R) -> Vec<aead::KeyPair> {
+        (0..n).map(|_| aead::KeyPair::generate(rng)).collect()
+    }
+
+    fn enc_keys(key_pairs: &[aead::KeyPair]) -> Vec<aead::EncKey> {
+        key_pairs.iter().map(|kp| kp.enc_key()).collect()
+    }
+
+    // An execution without complaints.
+    fn honest_run<G: CurveGroup, R: CryptoRng + RngCore>(
+        dkg: &PedersenDkg,
+        key_pairs: &[aead::KeyPair],
+        rng: &mut R,
+    ) -> (Vec<DealerSecret<G>>, Transcript<G>) {
+        let mut secrets = Vec::new();
+        let mut transcript = Transcript::default();
+        for dealer in 1..=dkg.num_parties() {
+            let (secret, dealing) = dkg.deal(dealer, &enc_keys(key_pairs), rng).unwrap();
+            secrets.push(secret);
+            transcript.dealings.push(dealing);
+        }
+        for (kp, index) in key_pairs.iter().zip(1..) {
+            for dealing in transcript.dealings.iter() {
+                assert!(dkg.complaint(dealing, index, kp).unwrap().is_none());
+            }
+        }
+        (secrets, transcript)
+    }
+
+    fn finalize_all<G: CurveGroup>(
+        dkg: &PedersenDkg,
+        transcript: &Transcript<G>,
+        key_pairs: &[aead::KeyPair],
+    ) -> Vec<PartyOutput<G>> {
+        let outputs: Vec<_> = key_pairs
+            .iter()
+            .zip(1..)
+            .map(|(kp, index)| dkg.finalize(transcript, index, kp).unwrap())
+            .collect();
+        let public = outputs[0].public_output();
+        assert_eq!(public, &dkg.verify_transcript(transcript).unwrap());
+        for output in outputs.iter() {
+            assert_eq!(output.public_output(), public);
+            assert_eq!(
+                G::generator() * output.key_share(),
+                public.ver_key_shares()[output.index() as usize - 1]
+            );
+        }
+        outputs
+    }
+
+    // The shared key, interpolated from the key shares of `outputs`.
+    fn interpolate<G: CurveGroup>(
+        dkg: &PedersenDkg,
+        outputs: &[&PartyOutput<G>],
+    ) -> G::ScalarField {
+        let shares: Vec<_> = outputs
+            .iter()
+            .map(|output| Share::new(output.index(), *output.key_share()).unwrap())
+            .collect();
+        dkg.vss.reconstruct(&shares).unwrap()
+    }
+
+    // The secret shared by a dealer.
+    fn dealt_secret<G: CurveGroup>(dkg: &PedersenDkg, secret: &DealerSecret<G>) -> G::ScalarField {
+        dkg.vss.reconstruct(&secret.shares).unwrap()
+    }
+
+    #[test]
+    fn test_dkg_bls() {
+        let mut rng = jf_utils::test_rng();
+        let dkg = PedersenDkg::new(3, 5).unwrap();
+        let key_pairs = key_pairs(5, &mut rng);
+        let (secrets, transcript) = honest_run::<G2Projective, _>(&dkg, &key_pairs, &mut rng);
+        let outputs = finalize_all(&dkg, &transcript, &key_pairs);
+        assert_eq!(outputs[0].public_output().qualified(), &[1, 2, 3, 4, 5]);
+
+        // the shared key is the sum of the secrets of the dealers
+        let key: ark_bn254::Fr = secrets
+            .iter()
+            .map(|secret| dealt_secret(&dkg, secret))
+            .sum();
+        assert_eq!(
+            interpolate(&dkg, &[&outputs[0], &outputs[2], &outputs[4]]),
+            key
+        );
+        assert_eq!(
+            interpolate(&dkg, &[&outputs[3], &outputs[1], &outputs[0]]),
+            key
+        );
+
+        let msg = b"distributed decision";
+        let (tbls, vk, vk_shares, _) = outputs[0].threshold_bls_keys();
+        assert_eq!(vk, VerKey::from(&SignKey(key)));
+        let partials: Vec<_> = outputs[1..4]
+            .iter()
+            .map(|output| {
+                let (_, _, _, sk_share) = output.threshold_bls_keys();
+                ThresholdBLS::partial_sign(&sk_share, msg)
+            })
+            .collect();
+        for partial in partials.iter() {
+            let vk_share = &vk_shares[partial.index() as usize - 1];
+            assert!(ThresholdBLS::partial_verify(vk_share, msg, partial).is_ok());
+        }
+        let sig = tbls.combine(&partials).unwrap();
+        assert!(BLSOverBN254CurveSignatureScheme::verify(&(), &vk, msg, &sig).is_ok());
+
+        // a single party
+        let dkg = PedersenDkg::new(1, 1).unwrap();
+        let (secrets, transcript) = honest_run::<G2Projective, _>(&dkg, &key_pairs[..1], &mut rng);
+        let outputs = finalize_all(&dkg, &transcript, &key_pairs[..1]);
+        assert_eq!(outputs[0].key_share(), secrets[0].shares[0].value());
+    }
+
+    fn test_dkg_elgamal_helper<F, P>()
+    where
+        F: RescueParameter,
+        P: Config<BaseField = F>,
+    {
+        let mut rng = jf_utils::test_rng();
+        let dkg = PedersenDkg::new(2, 4).unwrap();
+        let key_pairs = key_pairs(4, &mut rng);
+        let (_, transcript) = honest_run::<Projective<P>, _>(&dkg, &key_pairs, &mut rng);
+        let outputs = finalize_all(&dkg, &transcript, &key_pairs);
+
+        let msg: Vec<F> = (0..5).map(|_| F::rand(&mut rng)).collect();
+        let (tenc, enc_key, enc_shares, _) = outputs[0].threshold_elgamal_keys();
+        let ctext = enc_key.encrypt(&mut rng, &msg);
+        let shares: Vec<_> = [&outputs[3], &outputs[1]]
+            .iter()
+            .map(|output| {
+                let (_, _, _, dec_share) = output.threshold_elgamal_keys();
+                ThresholdElGamal::partial_decrypt(&dec_share, &ctext, &mut rng).unwrap()
+            })
+            .collect();
+        for share in shares.iter() {
+            let enc_share = &enc_shares[share.index() as usize - 1];
+            assert!(ThresholdElGamal::verify_share(enc_share, &ctext, share).is_ok());
+        }
+        assert_eq!(tenc.combine(&ctext, &shares).unwrap(), msg);
+    }
+
+    #[test]
+    fn test_dkg_elgamal() {
+        test_dkg_elgamal_helper::<_, Param254>();
+        test_dkg_elgamal_helper::<_, Param377>();
+        test_dkg_elgamal_helper::<_, Param381>();
+        test_dkg_elgamal_helper::<_, Param381b>();
+    }
+
+    #[test]
+    fn test_dkg_complaints() {
+        let mut rng = jf_utils::test_rng();
+        let dkg = PedersenDkg::new(3, 7).unwrap();
+        let key_pairs = key_pairs(7, &mut rng);
+        let enc_keys = enc_keys(&key_pairs);
+        let (secrets, mut transcript) = honest_run::<G254, _>(&dkg, &key_pairs, &mut rng);
+
+        // dealer 1 deals a wrong share to party 2, and reveals the right one
+        let mut bytes = Vec::new();
+        Share::new(
+            2,
+            *secrets[0].shares[1].value() + ark_ed_on_bn254::Fr::from(1u8),
+        )
+        .unwrap()
+        .serialize_compressed(&mut bytes)
+        .unwrap();
+        transcript.dealings[0].enc_shares[1] = enc_keys[1]
+            .encrypt(&mut rng, &bytes, &share_aad(1, 2))
+            .unwrap();
+        // dealer 2 deals a share to party 3 for another party, and does not answer
+        let mut bytes = Vec::new();
+        secrets[1].shares[2]
+            .serialize_compressed(&mut bytes)
+            .unwrap();
+        transcript.dealings[1].enc_shares[2] = enc_keys[2]
+            .encrypt(&mut rng, &bytes, &share_aad(2, 4))
+            .unwrap();
+        let mut complaints = Vec::new();
+        for (dealer, index) in [(1, 2), (2, 3)] {
+            let complaint = dkg
+                .complaint(
+                    &transcript.dealings[dealer - 1],
+                    index,
+                    &key_pairs[index as usize - 1],
+                )
+                .unwrap()
+                .unwrap();
+            assert_eq!(complaint.dealer(), dealer as u32);
+            assert_eq!(complaint.complainer(), index);
+            complaints.push(complaint);
+        }
+        // party 4 complains against dealer 3, who reveals a wrong share
+        complaints.push(Complaint {
+            dealer: 3,
+            complainer: 4,
+        });
+        // party 5 complains falsely against dealer 6, who answers
+        complaints.push(Complaint {
+            dealer: 6,
+            complainer: 5,
+        });
+        transcript.complaints = complaints.clone();
+
+        let response = secrets[0].respond(&complaints[0]).unwrap();
+        assert_eq!((response.dealer(), response.complainer()), (1, 2));
+        transcript.responses.push(response);
+        let mut response = secrets[2].respond(&complaints[2]).unwrap();
+        response.share =
+            Share::new(4, *response.share.value() + ark_ed_on_bn254::Fr::from(1u8)).unwrap();
+        transcript.responses.push(response);
+        transcript
+            .responses
+            .push(secrets[5].respond(&complaints[3]).unwrap());
+        // dealers can only reveal the shares of parties in range
+        assert!(secrets[0].respond(&complaints[1]).is_err());
+        for complainer in [0, 8] {
+            let complaint = Complaint {
+                dealer: 1,
+                complainer,
+            };
+            assert!(secrets[0].respond(&complaint).is_err());
+        }
+
+        // dealer 4 deals a malformed dealing, dealer 5 two different dealings
+        let coeffs = transcript.dealings[3].commitment.coeffs()[..2].to_vec();
+        transcript.dealings[3].commitment = FeldmanCommitment::new(coeffs);
+        assert!(dkg.verify_dealing(&transcript.dealings[3]).is_err());
+        assert!(dkg
+            .complaint(&transcript.dealings[3], 1, &key_pairs[0])
+            .is_err());
+        let (_, other) = dkg.deal(5, &enc_keys, &mut rng).unwrap();
+        transcript.dealings.push(other);
+
+        let outputs = finalize_all(&dkg, &transcript, &key_pairs);
+        assert_eq!(outputs[0].public_output().qualified(), &[1, 6, 7]);
+        let key: ark_ed_on_bn254::Fr = [0, 5, 6]
+            .iter()
+            .map(|i| dealt_secret(&dkg, &secrets[*i]))
+            .sum();
+        assert_eq!(
+            interpolate(&dkg, &[&outputs[1], &outputs[2], &outputs[6]]),
+            key
+        );
+        assert_eq!(
+            outputs[0].public_output().public_key(),
+            &(G254::generator() * key)
+        );
+
+        // party 2 cannot finalize without its complaint
+        let mut silent = transcript.clone();
+        silent.complaints.remove(0);
+        silent.responses.remove(0);
+        assert!(dkg.finalize(&silent, 2, &key_pairs[1]).is_err());
+        assert!(dkg.finalize(&silent, 3, &key_pairs[2]).is_ok());
+
+        // too few qualified dealers
+        silent.complaints.push(Complaint {
+            dealer: 7,
+            complainer: 1,
+        });
+        assert!(dkg.verify_transcript(&silent).is_err());
+        assert!(dkg.finalize(&silent, 1, &key_pairs[0]).is_err());
+        assert!(dkg
+            .verify_transcript(&Transcript::<G254>::default())
+            .is_err());
+    }
+
+    #[test]
+    fn test_dkg_params() {
+        let mut rng = jf_utils::test_rng();
+        assert!(PedersenDkg::new(0, 3).is_err());
+        assert!(PedersenDkg::new(4, 3).is_err());
+        let dkg = PedersenDkg::new(2, 3).unwrap();
+        assert_eq!(dkg.threshold(), 2);
+        assert_eq!(dkg.num_parties(), 3);
+
+        let key_pairs = key_pairs(3, &mut rng);
+        let enc_keys = enc_keys(&key_pairs);
+        assert!(dkg.deal::<G254, _>(0, &enc_keys, &mut rng).is_err());
+        assert!(dkg.deal::<G254, _>(4, &enc_keys, &mut rng).is_err());
+        assert!(dkg.deal::<G254, _>(1, &enc_keys[..2], &mut rng).is_err());
+        let (secret, dealing) = dkg.deal::<G254, _>(1, &enc_keys, &mut rng).unwrap();
+        assert_eq!(secret.dealer(), 1);
+        assert_eq!(dealing.dealer(), 1);
+        assert_eq!(dealing.commitment().coeffs().len(), 2);
+        assert!(dkg.complaint(&dealing, 0, &key_pairs[0]).is_err());
+        assert!(dkg.complaint(&dealing, 4, &key_pairs[0]).is_err());
+        // a party decrypting the share of another party
+        assert!(dkg.complaint(&dealing, 2, &key_pairs[0]).unwrap().is_some());
+    }
+
+    #[test]
+    fn test_serde() {
+        let mut rng = jf_utils::test_rng();
+        let dkg = PedersenDkg::new(2, 3).unwrap();
+        let key_pairs = key_pairs(3, &mut rng);
+        let (secrets, mut transcript) = honest_run::<G254, _>(&dkg, &key_pairs, &mut rng);
+        let complaint = Complaint {
+            dealer: 2,
+            complainer: 1,
+        };
+        transcript.complaints.push(complaint);
+        transcript
+            .responses
+            .push(secrets[1].respond(&complaint).unwrap());
+        let output = dkg.finalize(&transcript, 1, &key_pairs[0]).unwrap();
+
+        let mut bytes = Vec::new();
+        transcript.serialize_compressed(&mut bytes).unwrap();
+        assert_eq!(
+            Transcript::<G254>::deserialize_compressed(&bytes[..]).unwrap(),
+            transcript
+        );
+        let mut bytes = Vec::new();
+        output.serialize_compressed(&mut bytes).unwrap();
+        assert_eq!(
+            PartyOutput::<G254>::deserialize_compressed(&bytes[..]).unwrap(),
+            output
+        );
+        let mut bytes = Vec::new();
+        secrets[0].serialize_compressed(&mut bytes).unwrap();
+        let secret = DealerSecret::<G254>::deserialize_compressed(&bytes[..]).unwrap();
+        assert_eq!(secret.shares, secrets[0].shares);
+    }
+}
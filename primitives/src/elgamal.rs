@@ -7,6 +7,7 @@
 //! Implements the ElGamal encryption scheme.
 
 use crate::{
+    crhf::{VariableLengthRescueCRHF, CRHF},
     elgamal::Direction::{Decrypt, Encrypt},
     errors::PrimitivesError,
     rescue::{Permutation, RescueParameter, RescueVector, PRP, STATE_SIZE},
@@ -24,11 +25,14 @@ use ark_std::{
     vec,
     vec::Vec,
 };
-use jf_utils::pad_with_zeros;
+use jf_utils::{fq_to_fr_with_mask, pad_with_zeros};
 #[cfg(feature = "parallel")]
 use rayon::prelude::*;
 use zeroize::Zeroize;
 
+pub mod homomorphic;
+pub mod threshold;
+
 // =====================================================
 // encrypt key
 // =====================================================
@@ -218,20 +222,23 @@ where
         ephemeral_key_pair: KeyPair<P>,
         msg: &[F],
     ) -> Ciphertext<P> {
-        let shared_key = (self.key * ephemeral_key_pair.dec_key_ref().key).into_affine();
+        let mut shared_key = (self.key * ephemeral_key_pair.dec_key_ref().key).into_affine();
         let perm = Permutation::default();
         // TODO check if ok to use (x,y,0,0) as a key, since
         // key = perm(x,y,0,0) doesn't buy us anything.
-        let key = perm.eval(&RescueVector::from(&[
+        let mut key = perm.eval(&RescueVector::from(&[
             shared_key.x,
             shared_key.y,
             F::zero(),
             F::zero(),
         ]));
+        shared_key.zeroize();
         // since key was just sampled and to be used only once, we can allow NONCE = 0
+        let data = apply_counter_mode_stream::<F>(&key, msg, &F::zero(), Encrypt);
+        key.zeroize();
         Ciphertext {
             ephemeral: ephemeral_key_pair.enc_key(),
-            data: apply_counter_mode_stream::<F>(&key, msg, &F::zero(), Encrypt),
+            data,
         }
     }
 
@@ -262,19 +269,67 @@ where
 {
     /// Decryption function
     fn decrypt(&self, ctext: &Ciphertext<P>) -> Vec<P::BaseField> {
-        let perm = Permutation::default();
-        let shared_key = (ctext.ephemeral.key * self.key).into_affine();
-        let key = perm.eval(&RescueVector::from(&[
-            shared_key.x,
-            shared_key.y,
-            F::zero(),
-            F::zero(),
-        ]));
-        // since key was just samples and to be used only once, we can have NONCE = 0
-        apply_counter_mode_stream::<F>(&key, ctext.data.as_slice(), &F::zero(), Decrypt)
+        let mut shared_key = (ctext.ephemeral.key * self.key).into_affine();
+        let plaintext = decrypt_with_shared_key(&shared_key, ctext);
+        shared_key.zeroize();
+        plaintext
     }
 }
 
+/// Decrypt `ctext` with the shared key `x*R`, for `x` the decryption key and
+/// `R` the ephemeral key of `ctext`.
+pub(crate) fn decrypt_with_shared_key<F, P>(shared_key: &Affine<P>, ctext: &Ciphertext<P>) -> Vec<F>
+where
+    F: RescueParameter,
+    P: Config<BaseField = F>,
+{
+    let perm = Permutation::default();
+    let mut key = perm.eval(&RescueVector::from(&[
+        shared_key.x,
+        shared_key.y,
+        F::zero(),
+        F::zero(),
+    ]));
+    // since key was just samples and to be used only once, we can have NONCE = 0
+    let plaintext =
+        apply_counter_mode_stream::<F>(&key, ctext.data.as_slice(), &F::zero(), Decrypt);
+    key.zeroize();
+    plaintext
+}
+
+// Whether `point` is in the prime-order subgroup of the curve.
+pub(crate) fn in_prime_order_subgroup<P: Config>(point: &Projective<P>) -> bool {
+    point
+        .into_affine()
+        .is_in_correct_subgroup_assuming_on_curve()
+}
+
+// The challenge of the Chaum-Pedersen proof that `log_G(X)=log_R(D)`, with
+// nonce points `A` for `G` and `B` for `R`, under the domain separator `cs_id`.
+#[allow(non_snake_case)]
+pub(crate) fn dleq_challenge<F, P>(
+    cs_id: &str,
+    X: &Projective<P>,
+    R: &Projective<P>,
+    D: &Projective<P>,
+    A: &Projective<P>,
+    B: &Projective<P>,
+) -> P::ScalarField
+where
+    F: RescueParameter,
+    P: Config<BaseField = F>,
+{
+    let mut input = Vec::with_capacity(11);
+    input.push(F::from_be_bytes_mod_order(cs_id.as_bytes()));
+    input.extend(
+        CurveGroup::normalize_batch(&[*X, *R, *D, *A, *B])
+            .into_iter()
+            .flat_map(|p| [p.x, p.y]),
+    );
+    let challenge = VariableLengthRescueCRHF::<F, 1>::evaluate(input).unwrap()[0]; // safe unwrap
+    fq_to_fr_with_mask(&challenge)
+}
+
 impl<P> From<&DecKey<P>> for EncKey<P>
 where
     P: Config,
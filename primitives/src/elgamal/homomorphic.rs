@@ -0,0 +1,398 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+//! This module implements the homomorphic ElGamal encryption of curve points,
+//! under the keys of the [parent module][elgamal].
+//!
+//! Unlike the hybrid ciphertexts of the parent module, a
+//! [`HomomorphicCiphertext`] `(R,C)` encrypts a point `M` itself, so that
+//! ciphertexts can be combined without the decryption key. With `G` the
+//! generator of the curve and `X=x*G` the encryption key:
+//!
+//! **Encrypt(X,M)**
+//!    * sample `r`, the ciphertext is `(r*G,M+r*X)`, and decrypts to `C-x*R`
+//!
+//! **Add((R_1,C_1),(R_2,C_2))**
+//!    * `(R_1+R_2,C_1+C_2)` encrypts `M_1+M_2`
+//!
+//! **Mul((R,C),k)**
+//!    * `(k*R,k*C)` encrypts `k*M`
+//!
+//! **Rerandomize(X,(R,C))**
+//!    * sample `s`, the ciphertext `(R+s*G,C+s*X)` encrypts the same point, and
+//!      can not be linked to `(R,C)` without the decryption key. The optional
+//!      proof of correct rerandomization is a Chaum-Pedersen proof `(c,z)` that
+//!      `log_G(R'-R)=log_X(C'-C)`, with the same hash `H` as the decryption
+//!      shares of the [threshold][threshold] scheme
+//!
+//! A scalar `m` is encrypted as the point `m*G` with
+//! [`EncKey::encrypt_scalar`], for instance to add up votes. Decrypting it
+//! requires a discrete logarithm, so [`KeyPair::decrypt_scalar`] only
+//! recovers small scalars, by exhaustive search.
+//!
+//! ```
+//! use ark_ed_on_bn254::{EdwardsConfig, Fr};
+//! use jf_primitives::elgamal::KeyPair;
+//!
+//! let mut rng = jf_utils::test_rng();
+//! let key_pair = KeyPair::<EdwardsConfig>::generate(&mut rng);
+//! let enc_key = key_pair.enc_key();
+//! let votes = [1u8, 0, 1, 1];
+//! let tally = votes
+//!     .iter()
+//!     .map(|vote| enc_key.encrypt_scalar(&mut rng, &Fr::from(*vote)))
+//!     .reduce(|acc, ctext| acc + ctext)
+//!     .unwrap();
+//! let tally = enc_key.rerandomize(&mut rng, &tally);
+//! assert_eq!(key_pair.decrypt_scalar(&tally, 4).unwrap(), 3);
+//! ```
+//!
+//! [elgamal]: crate::elgamal
+//! [threshold]: crate::elgamal::threshold
+
+use super::{dleq_challenge, in_prime_order_subgroup, EncKey, KeyPair};
+use crate::{
+    constants::CS_ID_ELGAMAL_RERANDOMIZATION,
+    errors::PrimitivesError::{self, FailedDecryption, VerificationError},
+    rescue::RescueParameter,
+};
+use ark_ec::{
+    twisted_edwards::{Projective, TECurveConfig as Config},
+    Group,
+};
+use ark_serialize::*;
+use ark_std::{
+    format,
+    ops::{Add, Mul},
+    rand::{CryptoRng, RngCore},
+    string::ToString,
+    UniformRand, Zero,
+};
+use zeroize::Zeroize;
+
+/// Homomorphic encryption of a curve point.
+#[derive(CanonicalSerialize, CanonicalDeserialize, Derivative)]
+#[derivative(
+    Debug(bound = "P: Config"),
+    Clone(bound = "P: Config"),
+    Copy(bound = "P: Config"),
+    PartialEq(bound = "P: Config"),
+    Eq(bound = "P: Config"),
+    Hash(bound = "P: Config")
+)]
+pub struct HomomorphicCiphertext<P: Config> {
+    ephemeral: Projective<P>,
+    data: Projective<P>,
+}
+
+/// The proof that a ciphertext is a rerandomization of another one.
+#[derive(CanonicalSerialize, CanonicalDeserialize, Derivative)]
+#[derivative(
+    Debug(bound = "P: Config"),
+    Clone(bound = "P: Config"),
+    PartialEq(bound = "P: Config")
+)]
+pub struct RerandomizationProof<P: Config> {
+    challenge: P::ScalarField,
+    response: P::ScalarField,
+}
+
+impl<P: Config> HomomorphicCiphertext<P> {
+    /// The ephemeral point `R` of the ciphertext.
+    pub fn ephemeral(&self) -> &Projective<P> {
+        &self.ephemeral
+    }
+
+    /// The masked point `C` of the ciphertext.
+    pub fn data(&self) -> &Projective<P> {
+        &self.data
+    }
+}
+
+impl<P: Config> Add for HomomorphicCiphertext<P> {
+    type Output = Self;
+
+    /// The encryption of the sum of the two plaintexts.
+    fn add(self, rhs: Self) -> Self::Output {
+        Self {
+            ephemeral: self.ephemeral + rhs.ephemeral,
+            data: self.data + rhs.data,
+        }
+    }
+}
+
+impl<P: Config> Mul<P::ScalarField> for HomomorphicCiphertext<P> {
+    type Output = Self;
+
+    /// The encryption of the plaintext multiplied by `rhs`.
+    fn mul(self, rhs: P::ScalarField) -> Self::Output {
+        Self {
+            ephemeral: self.ephemeral * rhs,
+            data: self.data * rhs,
+        }
+    }
+}
+
+impl<P: Config> EncKey<P> {
+    /// Homomorphic encryption of a curve point.
+    pub fn encrypt_point<R: CryptoRng + RngCore>(
+        &self,
+        prng: &mut R,
+        msg: &Projective<P>,
+    ) -> HomomorphicCiphertext<P> {
+        let mut r = P::ScalarField::rand(prng);
+        let ctext = HomomorphicCiphertext {
+            ephemeral: Projective::<P>::generator() * r,
+            data: *msg + self.key * r,
+        };
+        r.zeroize();
+        ctext
+    }
+
+    /// Homomorphic encryption of the scalar `msg`, as the point `msg*G`.
+    pub fn encrypt_scalar<R: CryptoRng + RngCore>(
+        &self,
+        prng: &mut R,
+        msg: &P::ScalarField,
+    ) -> HomomorphicCiphertext<P> {
+        self.encrypt_point(prng, &(Projective::<P>::generator() * msg))
+    }
+
+    /// Rerandomize a ciphertext under this key: the result encrypts the same
+    /// plaintext, and can not be linked to `ctext`.
+    pub fn rerandomize<R: CryptoRng + RngCore>(
+        &self,
+        prng: &mut R,
+        ctext: &HomomorphicCiphertext<P>,
+    ) -> HomomorphicCiphertext<P> {
+        *ctext + self.encrypt_point(prng, &Projective::<P>::zero())
+    }
+}
+
+impl<F, P> EncKey<P>
+where
+    F: RescueParameter,
+    P: Config<BaseField = F>,
+{
+    /// Rerandomize a ciphertext under this key, along with a proof of correct
+    /// rerandomization which reveals nothing about the plaintext.
+    pub fn rerandomize_with_proof<R: CryptoRng + RngCore>(
+        &self,
+        prng: &mut R,
+        ctext: &HomomorphicCiphertext<P>,
+    ) -> (HomomorphicCiphertext<P>, RerandomizationProof<P>) {
+        let generator = Projective::<P>::generator();
+        let mut s = P::ScalarField::rand(prng);
+        let delta = HomomorphicCiphertext {
+            ephemeral: generator * s,
+            data: self.key * s,
+        };
+        let mut k = P::ScalarField::rand(prng);
+        let challenge = dleq_challenge(
+            CS_ID_ELGAMAL_RERANDOMIZATION,
+            &delta.ephemeral,
+            &self.key,
+            &delta.data,
+            &(generator * k),
+            &(self.key * k),
+        );
+        let response = k + challenge * s;
+        s.zeroize();
+        k.zeroize();
+        (
+            *ctext + delta,
+            RerandomizationProof {
+                challenge,
+                response,
+            },
+        )
+    }
+
+    /// Verify that `rerandomized` is a rerandomization of `ctext` under this
+    /// key.
+    /// * `returns` - Err() if a point of `rerandomized` is not in the
+    ///   prime-order subgroup, or if the proof is invalid
+    pub fn verify_rerandomization(
+        &self,
+        ctext: &HomomorphicCiphertext<P>,
+        rerandomized: &HomomorphicCiphertext<P>,
+        proof: &RerandomizationProof<P>,
+    ) -> Result<(), PrimitivesError> {
+        if !in_prime_order_subgroup(&rerandomized.ephemeral)
+            || !in_prime_order_subgroup(&rerandomized.data)
+        {
+            return Err(VerificationError(
+                "rerandomized ciphertext not in the prime-order subgroup".to_string(),
+            ));
+        }
+        let delta_ephemeral = rerandomized.ephemeral - ctext.ephemeral;
+        let delta_data = rerandomized.data - ctext.data;
+        let (c, z) = (proof.challenge, proof.response);
+        let challenge = dleq_challenge(
+            CS_ID_ELGAMAL_RERANDOMIZATION,
+            &delta_ephemeral,
+            &self.key,
+            &delta_data,
+            &(Projective::<P>::generator() * z - delta_ephemeral * c),
+            &(self.key * z - delta_data * c),
+        );
+        if challenge != c {
+            return Err(VerificationError(
+                "invalid proof of rerandomization".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl<P: Config> KeyPair<P> {
+    /// Decryption of a homomorphic ciphertext to the curve point it encrypts.
+    pub fn decrypt_point(&self, ctext: &HomomorphicCiphertext<P>) -> Projective<P> {
+        ctext.data - ctext.ephemeral * self.dec.key
+    }
+
+    /// Decryption of the homomorphic encryption of a scalar `m` in `0..=max`,
+    /// by exhaustive search, in time linear in `m`.
+    /// * `returns` - Err() if the plaintext is not `m*G` for any `m` in
+    ///   `0..=max`
+    pub fn decrypt_scalar(
+        &self,
+        ctext: &HomomorphicCiphertext<P>,
+        max: u64,
+    ) -> Result<u64, PrimitivesError> {
+        let point = self.decrypt_point(ctext);
+        let generator = Projective::<P>::generator();
+        let mut candidate = Projective::<P>::zero();
+        for m in 0..=max {
+            if candidate == point {
+                return Ok(m);
+            }
+            candidate += generator;
+        }
+        Err(FailedDecryption(format!(
+            "plaintext is not a scalar in 0..={max}"
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_ed_on_bls12_377::EdwardsConfig as Param377;
+    use ark_ed_on_bls12_381::EdwardsConfig as Param381;
+    use ark_ed_on_bls12_381_bandersnatch::EdwardsConfig as Param381b;
+    use ark_ed_on_bn254::EdwardsConfig as Param254;
+    use ark_std::vec::Vec;
+
+    fn test_homomorphic_elgamal_helper<F, P>()
+    where
+        F: RescueParameter,
+        P: Config<BaseField = F>,
+    {
+        let mut rng = jf_utils::test_rng();
+        let key_pair = KeyPair::<P>::generate(&mut rng);
+        let enc_key = key_pair.enc_key();
+        let other = KeyPair::<P>::generate(&mut rng);
+
+        let msg1 = Projective::<P>::rand(&mut rng);
+        let msg2 = Projective::<P>::rand(&mut rng);
+        let ctext1 = enc_key.encrypt_point(&mut rng, &msg1);
+        let ctext2 = enc_key.encrypt_point(&mut rng, &msg2);
+        assert_eq!(key_pair.decrypt_point(&ctext1), msg1);
+        assert_ne!(other.decrypt_point(&ctext1), msg1);
+        assert_ne!(enc_key.encrypt_point(&mut rng, &msg1), ctext1);
+
+        // homomorphic operations
+        let k = P::ScalarField::rand(&mut rng);
+        assert_eq!(key_pair.decrypt_point(&(ctext1 + ctext2)), msg1 + msg2);
+        assert_eq!(key_pair.decrypt_point(&(ctext1 * k)), msg1 * k);
+        assert_eq!(
+            key_pair.decrypt_point(&(ctext1 * k + ctext2)),
+            msg1 * k + msg2
+        );
+
+        // scalars
+        let votes: Vec<_> = [1u8, 0, 1, 1, 0, 1]
+            .iter()
+            .map(|vote| enc_key.encrypt_scalar(&mut rng, &P::ScalarField::from(*vote)))
+            .collect();
+        let tally = votes.iter().fold(
+            enc_key.encrypt_scalar(&mut rng, &P::ScalarField::zero()),
+            |acc, ctext| acc + *ctext,
+        );
+        assert_eq!(key_pair.decrypt_scalar(&tally, 6).unwrap(), 4);
+        assert_eq!(key_pair.decrypt_scalar(&tally, 4).unwrap(), 4);
+        assert!(key_pair.decrypt_scalar(&tally, 3).is_err());
+        let doubled = tally * P::ScalarField::from(2u8);
+        assert_eq!(key_pair.decrypt_scalar(&doubled, 10).unwrap(), 8);
+        assert!(key_pair.decrypt_scalar(&ctext1, 100).is_err());
+
+        // rerandomization
+        let rerandomized = enc_key.rerandomize(&mut rng, &ctext1);
+        assert_ne!(rerandomized, ctext1);
+        assert_eq!(key_pair.decrypt_point(&rerandomized), msg1);
+        let (rerandomized, proof) = enc_key.rerandomize_with_proof(&mut rng, &ctext1);
+        assert_ne!(rerandomized, ctext1);
+        assert_eq!(key_pair.decrypt_point(&rerandomized), msg1);
+        assert!(enc_key
+            .verify_rerandomization(&ctext1, &rerandomized, &proof)
+            .is_ok());
+
+        // a ciphertext of another plaintext, under another key or another proof
+        let forged = rerandomized + enc_key.encrypt_scalar(&mut rng, &P::ScalarField::from(1u8));
+        assert!(enc_key
+            .verify_rerandomization(&ctext1, &forged, &proof)
+            .is_err());
+        assert!(enc_key
+            .verify_rerandomization(&ctext2, &rerandomized, &proof)
+            .is_err());
+        assert!(other
+            .enc_key()
+            .verify_rerandomization(&ctext1, &rerandomized, &proof)
+            .is_err());
+        let (_, other_proof) = enc_key.rerandomize_with_proof(&mut rng, &ctext1);
+        assert!(enc_key
+            .verify_rerandomization(&ctext1, &rerandomized, &other_proof)
+            .is_err());
+        // a rerandomization under another key
+        let (rerandomized, proof) = other.enc_key().rerandomize_with_proof(&mut rng, &ctext1);
+        assert_ne!(key_pair.decrypt_point(&rerandomized), msg1);
+        assert!(enc_key
+            .verify_rerandomization(&ctext1, &rerandomized, &proof)
+            .is_err());
+    }
+
+    #[test]
+    fn test_homomorphic_elgamal() {
+        test_homomorphic_elgamal_helper::<_, Param254>();
+        test_homomorphic_elgamal_helper::<_, Param377>();
+        test_homomorphic_elgamal_helper::<_, Param381>();
+        test_homomorphic_elgamal_helper::<_, Param381b>();
+    }
+
+    #[test]
+    fn test_serde() {
+        let mut rng = jf_utils::test_rng();
+        let key_pair = KeyPair::<Param254>::generate(&mut rng);
+        let ctext = key_pair
+            .enc_key()
+            .encrypt_scalar(&mut rng, &ark_ed_on_bn254::Fr::from(3u8));
+        let (rerandomized, proof) = key_pair.enc_key().rerandomize_with_proof(&mut rng, &ctext);
+
+        let mut bytes = Vec::new();
+        rerandomized.serialize_compressed(&mut bytes).unwrap();
+        assert_eq!(
+            HomomorphicCiphertext::deserialize_compressed(&bytes[..]).unwrap(),
+            rerandomized
+        );
+        let mut bytes = Vec::new();
+        proof.serialize_compressed(&mut bytes).unwrap();
+        assert_eq!(
+            RerandomizationProof::<Param254>::deserialize_compressed(&bytes[..]).unwrap(),
+            proof
+        );
+    }
+}
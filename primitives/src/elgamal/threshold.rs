@@ -0,0 +1,478 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+//! This module implements `t`-out-of-`n` threshold decryption of the ElGamal
+//! ciphertexts of the [parent module][elgamal].
+//!
+//! The decryption key `x` is split into `n` Shamir shares, either by a dealer
+//! with [`ThresholdElGamal::key_gen`] or [`ThresholdElGamal::share_key`], or
+//! by the [distributed key generation][dkg] of the parties: party `i` (for
+//! `i` in `1..=n`) holds the share `x_i=f(i)` of a polynomial `f` of degree
+//! `t-1` with `f(0)=x`, and its public verification share is `X_i=x_i*G`.
+//!
+//! **PartialDecrypt(x_i,(R,data))**
+//!    * `D_i=x_i*R`, along with a Chaum-Pedersen proof `(c,z)` that
+//!      `log_G(X_i)=log_R(D_i)`: sample `k`, `c=H(X_i,R,D_i,k*G,k*R)`,
+//!      `z=k+c*x_i`
+//!
+//! **VerifyShare(X_i,(R,data),(D_i,c,z))**
+//!    * check that `c=H(X_i,R,D_i,z*G-c*X_i,z*R-c*D_i)`
+//!
+//! **Combine((R,data),{(i,D_i)})**
+//!    * given `t` decryption shares with distinct indices `I`, the shared key
+//!      is `x*R=sum_{i in I} L_i*D_i` where `L_i=prod_{j in I, j!=i} j/(j-i)`
+//!      is the Lagrange coefficient of `i` at `0`, from which `data` is
+//!      decrypted as with the decryption key
+//!
+//! `H` is Rescue over the base field of the curve, as for the Schnorr
+//! challenge. [`ThresholdElGamal::combine`] does not check the decryption
+//! shares, so they should be checked with [`ThresholdElGamal::verify_share`]
+//! beforehand, as a single invalid share yields a wrong plaintext.
+//!
+//! [elgamal]: crate::elgamal
+//! [dkg]: crate::dkg
+
+use super::{
+    decrypt_with_shared_key, dleq_challenge, in_prime_order_subgroup, Ciphertext, EncKey, KeyPair,
+};
+use crate::{
+    constants::CS_ID_THRESHOLD_ELGAMAL_DLEQ,
+    errors::PrimitivesError::{self, ParameterError, VerificationError},
+    rescue::RescueParameter,
+    shamir::{check_indices, check_params, eval_shares, lagrange_coeff_at_zero, random_polynomial},
+};
+use ark_ec::{
+    twisted_edwards::{Projective, TECurveConfig as Config},
+    CurveGroup, Group,
+};
+use ark_serialize::*;
+use ark_std::{
+    format,
+    rand::{CryptoRng, RngCore},
+    string::ToString,
+    vec::Vec,
+    UniformRand, Zero,
+};
+use zeroize::Zeroize;
+
+/// Parameters of a `t`-out-of-`n` threshold ElGamal decryption scheme.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct ThresholdElGamal {
+    threshold: u32,
+    num_parties: u32,
+}
+
+/// The share of the decryption key held by one party.
+#[derive(Zeroize, CanonicalSerialize, CanonicalDeserialize, Derivative)]
+#[derivative(
+    Debug(bound = "P: Config"),
+    Clone(bound = "P: Config"),
+    PartialEq(bound = "P: Config")
+)]
+pub struct DecKeyShare<P: Config> {
+    index: u32,
+    key: P::ScalarField,
+}
+
+impl<P: Config> Drop for DecKeyShare<P> {
+    fn drop(&mut self) {
+        self.key.zeroize();
+    }
+}
+
+/// The public verification share of one party.
+#[derive(CanonicalSerialize, CanonicalDeserialize, Derivative)]
+#[derivative(
+    Debug(bound = "P: Config"),
+    Clone(bound = "P: Config"),
+    PartialEq(bound = "P: Config"),
+    Eq(bound = "P: Config"),
+    Hash(bound = "P: Config")
+)]
+pub struct EncKeyShare<P: Config> {
+    index: u32,
+    key: EncKey<P>,
+}
+
+/// The decryption share of a ciphertext produced by one party, with its
+/// proof of validity.
+#[derive(CanonicalSerialize, CanonicalDeserialize, Derivative)]
+#[derivative(
+    Debug(bound = "P: Config"),
+    Clone(bound = "P: Config"),
+    PartialEq(bound = "P: Config")
+)]
+pub struct DecryptionShare<P: Config> {
+    index: u32,
+    share: Projective<P>,
+    challenge: P::ScalarField,
+    response: P::ScalarField,
+}
+
+impl<P: Config> DecKeyShare<P> {
+    /// Import the key share `key` of the party of index `index`, for instance
+    /// from a distributed key generation.
+    /// * `returns` - Err() if `index` is zero
+    pub fn new(index: u32, key: P::ScalarField) -> Result<Self, PrimitivesError> {
+        if index == 0 {
+            return Err(ParameterError("party indices start at 1".to_string()));
+        }
+        Ok(Self { index, key })
+    }
+
+    /// The index of the party holding the share, in `1..=n`.
+    pub fn index(&self) -> u32 {
+        self.index
+    }
+
+    /// The verification share matching this key share.
+    pub fn enc_key_share(&self) -> EncKeyShare<P> {
+        EncKeyShare {
+            index: self.index,
+            key: EncKey {
+                key: Projective::<P>::generator() * self.key,
+            },
+        }
+    }
+}
+
+impl<P: Config> EncKeyShare<P> {
+    /// Import the verification share `key` of the party of index `index`.
+    /// * `returns` - Err() if `index` is zero
+    pub fn new(index: u32, key: EncKey<P>) -> Result<Self, PrimitivesError> {
+        if index == 0 {
+            return Err(ParameterError("party indices start at 1".to_string()));
+        }
+        Ok(Self { index, key })
+    }
+
+    /// The index of the party holding the share, in `1..=n`.
+    pub fn index(&self) -> u32 {
+        self.index
+    }
+
+    /// The verification share as an encryption key.
+    pub fn enc_key(&self) -> &EncKey<P> {
+        &self.key
+    }
+}
+
+impl<P: Config> DecryptionShare<P> {
+    /// The index of the party which produced the decryption share.
+    pub fn index(&self) -> u32 {
+        self.index
+    }
+}
+
+impl ThresholdElGamal {
+    /// Setup a scheme where any `threshold` out of `num_parties` parties can
+    /// decrypt.
+    /// * `returns` - Err() if `threshold` is zero or greater than `num_parties`
+    pub fn new(threshold: u32, num_parties: u32) -> Result<Self, PrimitivesError> {
+        check_params(threshold, num_parties)?;
+        Ok(Self {
+            threshold,
+            num_parties,
+        })
+    }
+
+    /// The number of decryption shares needed to decrypt.
+    pub fn threshold(&self) -> u32 {
+        self.threshold
+    }
+
+    /// The number of parties holding a key share.
+    pub fn num_parties(&self) -> u32 {
+        self.num_parties
+    }
+
+    /// Sample a decryption key and share it between the parties.
+    /// * `returns` - the group encryption key, and the verification shares and
+    ///   key shares of the parties, ordered by index
+    pub fn key_gen<P: Config, R: CryptoRng + RngCore>(
+        &self,
+        prng: &mut R,
+    ) -> (EncKey<P>, Vec<EncKeyShare<P>>, Vec<DecKeyShare<P>>) {
+        let key_pair = KeyPair::generate(prng);
+        self.share_key(&key_pair, prng)
+    }
+
+    /// Share the decryption key of `key_pair` between the parties, by a
+    /// trusted dealer which knows it.
+    /// * `returns` - the group encryption key, and the verification shares and
+    ///   key shares of the parties, ordered by index
+    pub fn share_key<P: Config, R: CryptoRng + RngCore>(
+        &self,
+        key_pair: &KeyPair<P>,
+        prng: &mut R,
+    ) -> (EncKey<P>, Vec<EncKeyShare<P>>, Vec<DecKeyShare<P>>) {
+        let mut coeffs = random_polynomial(&key_pair.dec.key, self.threshold, prng);
+        let dec_shares: Vec<_> = eval_shares(&coeffs, self.num_parties)
+            .iter()
+            .map(|share| DecKeyShare {
+                index: share.index(),
+                key: *share.value(),
+            })
+            .collect();
+        coeffs.zeroize();
+
+        let enc_shares = dec_shares.iter().map(|s| s.enc_key_share()).collect();
+        (key_pair.enc_key(), enc_shares, dec_shares)
+    }
+
+    /// Compute the decryption share of `ctext` with a key share.
+    /// * `returns` - Err() if the ephemeral key of `ctext` is not in the
+    ///   prime-order subgroup, or is the identity
+    pub fn partial_decrypt<F, P, R>(
+        share: &DecKeyShare<P>,
+        ctext: &Ciphertext<P>,
+        prng: &mut R,
+    ) -> Result<DecryptionShare<P>, PrimitivesError>
+    where
+        F: RescueParameter,
+        P: Config<BaseField = F>,
+        R: CryptoRng + RngCore,
+    {
+        let ephemeral = ctext.ephemeral.key;
+        if !in_prime_order_subgroup(&ephemeral) || ephemeral.is_zero() {
+            return Err(ParameterError(
+                "ciphertext with an invalid ephemeral key".to_string(),
+            ));
+        }
+        let enc_share = share.enc_key_share();
+        let d = ephemeral * share.key;
+        let mut k = P::ScalarField::rand(prng);
+        let challenge = dleq_challenge(
+            CS_ID_THRESHOLD_ELGAMAL_DLEQ,
+            &enc_share.key.key,
+            &ephemeral,
+            &d,
+            &(Projective::<P>::generator() * k),
+            &(ephemeral * k),
+        );
+        let response = k + challenge * share.key;
+        k.zeroize();
+        Ok(DecryptionShare {
+            index: share.index,
+            share: d,
+            challenge,
+            response,
+        })
+    }
+
+    /// Verify a decryption share of `ctext` against the verification share of
+    /// its producer.
+    /// * `returns` - Err() if the indices differ or if the proof of validity is
+    ///   invalid
+    pub fn verify_share<F, P>(
+        enc_share: &EncKeyShare<P>,
+        ctext: &Ciphertext<P>,
+        dec_share: &DecryptionShare<P>,
+    ) -> Result<(), PrimitivesError>
+    where
+        F: RescueParameter,
+        P: Config<BaseField = F>,
+    {
+        if enc_share.index != dec_share.index {
+            return Err(ParameterError(format!(
+                "verification share of party {} for a decryption share of party {}",
+                enc_share.index, dec_share.index
+            )));
+        }
+        if !in_prime_order_subgroup(&dec_share.share) {
+            return Err(VerificationError(
+                "decryption share not in the prime-order subgroup".to_string(),
+            ));
+        }
+        let ephemeral = ctext.ephemeral.key;
+        let (c, z) = (dec_share.challenge, dec_share.response);
+        let challenge = dleq_challenge(
+            CS_ID_THRESHOLD_ELGAMAL_DLEQ,
+            &enc_share.key.key,
+            &ephemeral,
+            &dec_share.share,
+            &(Projective::<P>::generator() * z - enc_share.key.key * c),
+            &(ephemeral * z - dec_share.share * c),
+        );
+        if challenge != c {
+            return Err(VerificationError(
+                "invalid proof of the decryption share".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Combine decryption shares of `ctext` and decrypt it. Only the first
+    /// `threshold` decryption shares are used.
+    /// * `returns` - Err() if there are fewer than `threshold` decryption
+    ///   shares, or if an index is out of range or repeated
+    pub fn combine<F, P>(
+        &self,
+        ctext: &Ciphertext<P>,
+        shares: &[DecryptionShare<P>],
+    ) -> Result<Vec<F>, PrimitivesError>
+    where
+        F: RescueParameter,
+        P: Config<BaseField = F>,
+    {
+        let indices: Vec<u32> = shares.iter().map(|s| s.index).collect();
+        check_indices(&indices, self.threshold, self.num_parties)?;
+        let shares = &shares[..self.threshold as usize];
+
+        let indices: Vec<P::ScalarField> = shares
+            .iter()
+            .map(|s| P::ScalarField::from(s.index))
+            .collect();
+        let mut shared_key = Projective::<P>::zero();
+        for (share, x_i) in shares.iter().zip(indices.iter()) {
+            shared_key += share.share * lagrange_coeff_at_zero(*x_i, &indices);
+        }
+        let mut shared_key = shared_key.into_affine();
+        let plaintext = decrypt_with_shared_key(&shared_key, ctext);
+        shared_key.zeroize();
+        Ok(plaintext)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_ed_on_bls12_377::EdwardsConfig as Param377;
+    use ark_ed_on_bls12_381::EdwardsConfig as Param381;
+    use ark_ed_on_bls12_381_bandersnatch::EdwardsConfig as Param381b;
+    use ark_ed_on_bn254::EdwardsConfig as Param254;
+    use ark_std::vec;
+
+    fn test_threshold_elgamal_helper<F, P>()
+    where
+        F: RescueParameter,
+        P: Config<BaseField = F>,
+    {
+        let mut rng = jf_utils::test_rng();
+        let tenc = ThresholdElGamal::new(3, 5).unwrap();
+        let key_pair = KeyPair::<P>::generate(&mut rng);
+        let (enc_key, enc_shares, dec_shares) = tenc.share_key(&key_pair, &mut rng);
+        assert_eq!(enc_key, key_pair.enc_key());
+        assert_eq!(enc_shares.len(), 5);
+
+        let msg: Vec<F> = (0..7).map(|_| F::rand(&mut rng)).collect();
+        let ctext = enc_key.encrypt(&mut rng, &msg);
+        let other_ctext = enc_key.encrypt(&mut rng, &msg);
+        let shares: Vec<_> = dec_shares
+            .iter()
+            .map(|share| ThresholdElGamal::partial_decrypt(share, &ctext, &mut rng).unwrap())
+            .collect();
+        for (enc_share, share) in enc_shares.iter().zip(shares.iter()) {
+            assert!(ThresholdElGamal::verify_share(enc_share, &ctext, share).is_ok());
+            assert!(ThresholdElGamal::verify_share(enc_share, &other_ctext, share).is_err());
+        }
+        assert!(ThresholdElGamal::verify_share(&enc_shares[0], &ctext, &shares[1]).is_err());
+        // a share under the verification share of another party
+        let mut forged = shares[1].clone();
+        forged.index = 1;
+        assert!(ThresholdElGamal::verify_share(&enc_shares[0], &ctext, &forged).is_err());
+        // a share which does not match its proof
+        let mut forged = shares[0].clone();
+        forged.share += Projective::<P>::generator();
+        assert!(ThresholdElGamal::verify_share(&enc_shares[0], &ctext, &forged).is_err());
+
+        // any `t` decryption shares decrypt the ciphertext
+        for subset in [
+            vec![0, 1, 2],
+            vec![4, 2, 0],
+            vec![1, 3, 4],
+            vec![3, 2, 1, 0],
+        ] {
+            let subset: Vec<_> = subset.into_iter().map(|i| shares[i].clone()).collect();
+            assert_eq!(tenc.combine(&ctext, &subset).unwrap(), msg);
+        }
+        assert_ne!(
+            tenc.combine(&ctext, &[forged, shares[1].clone(), shares[2].clone()])
+                .unwrap(),
+            msg
+        );
+        assert!(tenc.combine(&ctext, &shares[..2]).is_err());
+        assert!(tenc
+            .combine(
+                &ctext,
+                &[shares[0].clone(), shares[0].clone(), shares[1].clone()]
+            )
+            .is_err());
+
+        // imported key shares
+        let imported: Vec<_> = dec_shares
+            .iter()
+            .map(|share| DecKeyShare::<P>::new(share.index(), share.key).unwrap())
+            .collect();
+        assert_eq!(imported, dec_shares);
+        assert_eq!(
+            EncKeyShare::new(1, enc_shares[0].enc_key().clone()).unwrap(),
+            enc_shares[0]
+        );
+        assert!(DecKeyShare::<P>::new(0, P::ScalarField::from(1u8)).is_err());
+        assert!(EncKeyShare::new(0, enc_key.clone()).is_err());
+
+        // random key generation
+        let tenc = ThresholdElGamal::new(1, 1).unwrap();
+        let (enc_key, enc_shares, dec_shares) = tenc.key_gen::<P, _>(&mut rng);
+        let ctext = enc_key.encrypt(&mut rng, &msg);
+        let share = ThresholdElGamal::partial_decrypt(&dec_shares[0], &ctext, &mut rng).unwrap();
+        assert!(ThresholdElGamal::verify_share(&enc_shares[0], &ctext, &share).is_ok());
+        assert_eq!(tenc.combine(&ctext, &[share]).unwrap(), msg);
+    }
+
+    #[test]
+    fn test_threshold_elgamal() {
+        test_threshold_elgamal_helper::<_, Param254>();
+        test_threshold_elgamal_helper::<_, Param377>();
+        test_threshold_elgamal_helper::<_, Param381>();
+        test_threshold_elgamal_helper::<_, Param381b>();
+    }
+
+    #[test]
+    fn test_threshold_params() {
+        assert!(ThresholdElGamal::new(0, 3).is_err());
+        assert!(ThresholdElGamal::new(4, 3).is_err());
+        let tenc = ThresholdElGamal::new(2, 3).unwrap();
+        assert_eq!(tenc.threshold(), 2);
+        assert_eq!(tenc.num_parties(), 3);
+    }
+
+    #[test]
+    fn test_serde() {
+        let mut rng = jf_utils::test_rng();
+        let tenc = ThresholdElGamal::new(2, 3).unwrap();
+        let (enc_key, enc_shares, dec_shares) = tenc.key_gen::<Param254, _>(&mut rng);
+        let msg = vec![ark_ed_on_bn254::Fq::from(1u8)];
+        let ctext = enc_key.encrypt(&mut rng, &msg);
+        let share = ThresholdElGamal::partial_decrypt(&dec_shares[0], &ctext, &mut rng).unwrap();
+
+        let mut bytes = Vec::new();
+        dec_shares[0].serialize_compressed(&mut bytes).unwrap();
+        assert_eq!(
+            DecKeyShare::<Param254>::deserialize_compressed(&bytes[..]).unwrap(),
+            dec_shares[0]
+        );
+        let mut bytes = Vec::new();
+        enc_shares[0].serialize_compressed(&mut bytes).unwrap();
+        assert_eq!(
+            EncKeyShare::<Param254>::deserialize_compressed(&bytes[..]).unwrap(),
+            enc_shares[0]
+        );
+        let mut bytes = Vec::new();
+        share.serialize_compressed(&mut bytes).unwrap();
+        assert_eq!(
+            DecryptionShare::<Param254>::deserialize_compressed(&bytes[..]).unwrap(),
+            share
+        );
+        let mut bytes = Vec::new();
+        tenc.serialize_compressed(&mut bytes).unwrap();
+        assert_eq!(
+            ThresholdElGamal::deserialize_compressed(&bytes[..]).unwrap(),
+            tenc
+        );
+    }
+}
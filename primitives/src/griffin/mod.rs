@@ -0,0 +1,434 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+#![deny(missing_docs)]
+//! This module implements the Griffin hash function over the following
+//! fields
+//! - ed_on_bls12_377 base field (i.e. bls12_377 scalar field)
+//! - ed_on_bls12_381 base field (i.e. bls12_381 scalar field)
+//! - ed_on_bn254 base field (i.e. bn254 scalar field)
+//!
+//! The permutation follows the [Griffin paper](https://eprint.iacr.org/2022/403.pdf)
+//! for a state of 3 elements: an initial linear layer, then rounds made of
+//! the non-linear layer
+//! ```text
+//! y_0 = x_0^(1/d)
+//! y_1 = x_1^d
+//! y_2 = x_2 ((y_0 + y_1)^2 + alpha (y_0 + y_1) + beta)
+//! ```
+//! the linear layer `circ(2, 1, 1)` and the addition of the round constants,
+//! except for the last round. Since `y_0` is checked as `y_0^d = x_0`, a round
+//! costs a handful of multiplications in a circuit, against 3 S-boxes for
+//! Rescue and Poseidon with full rounds.
+//!
+//! The parameters are derived as in the [reference implementation](https://extgit.iaik.tugraz.at/krypto/zkfriendlyhashzoo):
+//! the round constants, then `(alpha, beta)`, non-zero and with `alpha^2 - 4
+//! beta` a non-square, are read in this order from SHAKE128 on input
+//! `"Griffin"` followed by the 64-bit little-endian limbs of the field order.
+//! Each element is a little-endian integer of as many bytes as the field
+//! order, with the bits above the bit size of the order cleared, and is
+//! rejected if it is not smaller than the order. They are generated once per
+//! field on first use.
+//!
+//! The reference implementation only supports `d = 3` and `d = 5`, so it has
+//! no instance for the bls12_377 scalar field, where `d = 11`: the parameters
+//! of this field follow the same derivation with the round number of the
+//! Griffin paper for `d = 11`.
+
+#![deny(warnings)]
+
+use crate::{
+    errors::PrimitivesError,
+    sponge::{PermutationSponge, SpongePermutation},
+};
+use ark_crypto_primitives::sponge::{Absorb, CryptographicSponge, FieldBasedCryptographicSponge};
+use ark_ff::PrimeField;
+use ark_std::{boxed::Box, format, vec::Vec};
+use jf_utils::pad_with_zeros;
+use num_bigint::BigUint;
+use once_cell::race::OnceBox;
+use sha3::{
+    digest::{ExtendableOutput, Update, XofReader},
+    Shake128,
+};
+
+/// The state size of griffin hash.
+pub const STATE_SIZE: usize = 3;
+/// The rate of the sponge used in GriffinCRHF.
+pub const CRHF_RATE: usize = 2;
+
+/// This trait defines constants that are used for griffin hash functions.
+pub trait GriffinParameter: PrimeField + Absorb {
+    /// parameter d of the non-linear layer, the smallest integer such that
+    /// `gcd(d, p - 1) = 1`
+    const D: u64;
+    /// The # of rounds, for 128 bits of security.
+    const ROUNDS: usize;
+
+    /// The griffin permutation of this field.
+    fn permutation() -> &'static GriffinPermutation<Self>;
+}
+
+/// The Griffin permutation over a state of `STATE_SIZE` elements, see the
+/// [module documentation](self).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GriffinPermutation<F: GriffinParameter> {
+    /// The inverse of d modulo `p - 1`, as little-endian limbs
+    d_inv: Vec<u64>,
+    /// Coefficients `(alpha, beta)` of the non-linear layer
+    alpha_beta: (F, F),
+    /// Round constants of all rounds but the last one, in order
+    round_constants: Vec<[F; STATE_SIZE]>,
+}
+
+impl<F: GriffinParameter> Default for GriffinPermutation<F> {
+    fn default() -> Self {
+        F::permutation().clone()
+    }
+}
+
+impl<F: GriffinParameter> GriffinPermutation<F> {
+    /// Derive the parameters of the permutation of `F`.
+    fn derive() -> Self {
+        let modulus: BigUint = F::MODULUS.into();
+        let modulus_minus_one = &modulus - 1u64;
+        let d_inv = (1..F::D)
+            .map(|k| &modulus_minus_one * k + 1u64)
+            .find(|e| (e % F::D) == BigUint::from(0u64))
+            .expect("d should be invertible modulo p - 1")
+            / F::D;
+
+        let mut hasher = Shake128::default();
+        hasher.update(b"Griffin");
+        for limb in F::MODULUS.as_ref() {
+            hasher.update(&limb.to_le_bytes());
+        }
+        let mut reader = hasher.finalize_xof();
+        let num_bits = F::MODULUS_BIT_SIZE as usize;
+        let num_bytes = (num_bits + 7) / 8;
+        let mut next = || loop {
+            let mut buf = [0u8; 64];
+            let bytes = &mut buf[..num_bytes];
+            reader.read(bytes);
+            if num_bits % 8 != 0 {
+                bytes[num_bytes - 1] &= (1u8 << (num_bits % 8)) - 1;
+            }
+            let value = BigUint::from_bytes_le(bytes);
+            if value < modulus {
+                return F::from(value);
+            }
+        };
+        let round_constants = (1..F::ROUNDS).map(|_| [next(), next(), next()]).collect();
+        let mut next_non_zero = || loop {
+            let value = next();
+            if !value.is_zero() {
+                return value;
+            }
+        };
+        let alpha_beta = loop {
+            let (alpha, beta) = (next_non_zero(), next_non_zero());
+            // y^2 + alpha y + beta has no root, so the layer is invertible
+            if (alpha.square() - beta.double().double())
+                .legendre()
+                .is_qnr()
+            {
+                break (alpha, beta);
+            }
+        };
+        Self {
+            d_inv: d_inv.to_u64_digits(),
+            alpha_beta,
+            round_constants,
+        }
+    }
+
+    /// Return the coefficients `(alpha, beta)` of the non-linear layer.
+    pub fn alpha_beta(&self) -> (F, F) {
+        self.alpha_beta
+    }
+
+    /// Return the round constants, which are added after every round but the
+    /// last one.
+    pub fn round_constants(&self) -> &[[F; STATE_SIZE]] {
+        &self.round_constants
+    }
+
+    /// Compute the permutation on `input`.
+    pub fn eval(&self, input: &[F; STATE_SIZE]) -> [F; STATE_SIZE] {
+        let mut state = *input;
+        self.permute_in_place(&mut state);
+        state
+    }
+
+    /// Compute the permutation on `state` in place.
+    pub fn permute_in_place(&self, state: &mut [F; STATE_SIZE]) {
+        linear_layer(state);
+        for round in 0..F::ROUNDS {
+            self.non_linear_layer(state);
+            linear_layer(state);
+            if let Some(constants) = self.round_constants.get(round) {
+                state.iter_mut().zip(constants).for_each(|(x, c)| *x += c);
+            }
+        }
+    }
+
+    fn non_linear_layer(&self, state: &mut [F; STATE_SIZE]) {
+        let (alpha, beta) = self.alpha_beta;
+        state[0] = state[0].pow(&self.d_inv);
+        state[1] = state[1].pow([F::D]);
+        let l = state[0] + state[1];
+        state[2] *= l.square() + alpha * l + beta;
+    }
+}
+
+/// Multiply `state` by `circ(2, 1, 1)`.
+fn linear_layer<F: PrimeField>(state: &mut [F; STATE_SIZE]) {
+    let sum: F = state.iter().sum();
+    state.iter_mut().for_each(|x| *x += sum);
+}
+
+macro_rules! impl_griffin_parameter {
+    ($field:ty, $d:expr, $rounds:expr) => {
+        impl GriffinParameter for $field {
+            const D: u64 = $d;
+            const ROUNDS: usize = $rounds;
+
+            fn permutation() -> &'static GriffinPermutation<Self> {
+                static PERMUTATION: OnceBox<GriffinPermutation<$field>> = OnceBox::new();
+                PERMUTATION.get_or_init(|| Box::new(GriffinPermutation::derive()))
+            }
+        }
+    };
+}
+
+// Round numbers of the Griffin paper for a state of 3 elements and 128 bits of
+// security, including its security margin
+impl_griffin_parameter!(ark_ed_on_bn254::Fq, 5, 12);
+impl_griffin_parameter!(ark_ed_on_bls12_381::Fq, 5, 12);
+impl_griffin_parameter!(ark_ed_on_bls12_377::Fq, 11, 10);
+
+impl<F: GriffinParameter> SpongePermutation<F, STATE_SIZE> for GriffinPermutation<F> {
+    fn permute(&self, state: &mut [F; STATE_SIZE]) {
+        self.permute_in_place(state);
+    }
+}
+
+/// A sponge over the griffin permutation, absorbing `RATE` elements per
+/// permutation into the first elements of the state.
+pub type GriffinSponge<F, const RATE: usize> =
+    PermutationSponge<F, GriffinPermutation<F>, STATE_SIZE, RATE>;
+
+/// CRHF
+#[derive(Debug, Clone)]
+pub struct GriffinCRHF<F: GriffinParameter> {
+    sponge: GriffinSponge<F, CRHF_RATE>,
+}
+
+impl<F: GriffinParameter> GriffinCRHF<F> {
+    /// Sponge hashing based on griffin permutation for RATE 2. It allows
+    /// unrestricted variable length input and returns a vector of
+    /// `num_outputs` elements, with "bit padding": "1" is always appended,
+    /// then as many "0" as required for the overall length to be a multiple
+    /// of RATE.
+    pub fn sponge_with_bit_padding(input: &[F], num_outputs: usize) -> Vec<F> {
+        let mut padded = input.to_vec();
+        padded.push(F::one());
+        pad_with_zeros(&mut padded, CRHF_RATE);
+        Self::sponge_no_padding(padded.as_slice(), num_outputs)
+            .expect("Bug in JF Primitives : bad padding of input for FSKS construction")
+    }
+
+    /// Similar to [`GriffinCRHF::sponge_with_bit_padding`] except we use
+    /// "zero padding" where as many "0" as required are added for the overall
+    /// length to be a multiple of RATE.
+    pub fn sponge_with_zero_padding(input: &[F], num_outputs: usize) -> Vec<F> {
+        let mut padded = input.to_vec();
+        pad_with_zeros(&mut padded, CRHF_RATE);
+        Self::sponge_no_padding(padded.as_slice(), num_outputs)
+            .expect("Bug in JF Primitives : bad padding of input for FSKS construction")
+    }
+
+    /// Sponge hashing based on griffin permutation for RATE 2 and CAPACITY
+    /// 1. It allows inputs with length that is a multiple of `CRHF_RATE` and
+    /// returns a vector of `num_outputs` elements.
+    pub fn sponge_no_padding(input: &[F], num_output: usize) -> Result<Vec<F>, PrimitivesError> {
+        if input.len() % CRHF_RATE != 0 {
+            return Err(PrimitivesError::ParameterError(format!(
+                "Griffin sponge Error : input length {} to sponge hashing function is not \
+                 multiple of RATE.",
+                input.len()
+            )));
+        }
+        let mut crhf = Self {
+            sponge: GriffinSponge::new(F::permutation()),
+        };
+        // ABSORB PHASE
+        crhf.sponge.absorb(&input);
+        // SQUEEZE PHASE
+        Ok(crhf.sponge.squeeze_native_field_elements(num_output))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use ark_ed_on_bls12_377::Fq as Fq377;
+    use ark_ed_on_bls12_381::Fq as Fq381;
+    use ark_ed_on_bn254::Fq as Fq254;
+    use ark_ff::MontFp;
+    use ark_std::{vec, UniformRand};
+
+    #[test]
+    fn test_griffin_permutation() {
+        test_griffin_permutation_helper::<Fq254>();
+        test_griffin_permutation_helper::<Fq377>();
+        test_griffin_permutation_helper::<Fq381>();
+    }
+
+    fn test_griffin_permutation_helper<F: GriffinParameter>() {
+        let rng = &mut jf_utils::test_rng();
+        let permutation = F::permutation();
+        assert_eq!(permutation.round_constants().len(), F::ROUNDS - 1);
+        // permutation is cached
+        assert!(core::ptr::eq(permutation, F::permutation()));
+        assert_eq!(&GriffinPermutation::<F>::default(), permutation);
+
+        // x^(1/d) is the inverse of x^d
+        let x = F::rand(rng);
+        assert_eq!(x.pow(&permutation.d_inv).pow([F::D]), x);
+
+        let state = [F::rand(rng), F::rand(rng), F::rand(rng)];
+        let output = permutation.eval(&state);
+        assert_ne!(output, state);
+        let mut other = state;
+        other[2] += F::one();
+        let other_output = permutation.eval(&other);
+        assert!(output.iter().zip(other_output).all(|(x, y)| *x != y));
+    }
+
+    // Test vectors computed with the derivation and permutation of the
+    // reference implementation, see the module documentation, for the
+    // instances of this module.
+    #[test]
+    fn test_griffin_vectors() {
+        let input = [0u64, 1, 2];
+        let sponge_input = [1u64, 2, 3, 4];
+
+        let expected: [Fq254; 3] = [
+            MontFp!(
+                "15862405785128810275837435502653224425290071258167230490599117376332100235254"
+            ),
+            MontFp!(
+                "13220756517509979517684528785753328587257706928708746278499548208567338458968"
+            ),
+            MontFp!(
+                "15550532036911446928426039913328049280239190234626561457568755196858003615133"
+            ),
+        ];
+        assert_eq!(Fq254::permutation().eval(&input.map(Fq254::from)), expected);
+        let expected: [Fq254; 3] = [
+            MontFp!(
+                "10774692784732925322586716780053318012597940241673855530839326660578494136704"
+            ),
+            MontFp!(
+                "14824837525372177037375882122499282180378203424769574271430627243462429358812"
+            ),
+            MontFp!(
+                "18056229975273873760313209133748030207961833248035461070452830327491881786695"
+            ),
+        ];
+        assert_eq!(
+            GriffinCRHF::sponge_no_padding(&sponge_input.map(Fq254::from), 3).unwrap(),
+            expected
+        );
+
+        let expected: [Fq381; 3] = [
+            MontFp!(
+                "27379052990992335868007513827616442821891910747512806453448496790052721925738"
+            ),
+            MontFp!(
+                "24772506163846602410384726533562269659751910620421046453035480040071869301700"
+            ),
+            MontFp!(
+                "49516412382145609386411188123247611096923270094753177293676945877936768369921"
+            ),
+        ];
+        assert_eq!(Fq381::permutation().eval(&input.map(Fq381::from)), expected);
+        let expected: [Fq381; 3] = [
+            MontFp!(
+                "20332285636325184770685721357547104187961547014360123744926360827118085928483"
+            ),
+            MontFp!(
+                "16592764556807488570498524221551326612605894499578457522405773918996709222155"
+            ),
+            MontFp!("8058463253652021573198573438668373327460859503815877059694380217562226177178"),
+        ];
+        assert_eq!(
+            GriffinCRHF::sponge_no_padding(&sponge_input.map(Fq381::from), 3).unwrap(),
+            expected
+        );
+
+        let expected: [Fq377; 3] = [
+            MontFp!("4069249657823752210085908795965041211028536771046096828946740422285737399071"),
+            MontFp!("1093452105028882196607767169677921182797846114857118191416415396279807917622"),
+            MontFp!("4517953333408492249208882885210702049410785959604754892494651369611030414854"),
+        ];
+        assert_eq!(Fq377::permutation().eval(&input.map(Fq377::from)), expected);
+        let expected: [Fq377; 3] = [
+            MontFp!("4541203564947332313759681473052652151464748140327504181027504046434171572788"),
+            MontFp!("6438405416527289238664968386810985916981225432062017406448831503557455874889"),
+            MontFp!("7965769238651785573728029679567961848378231429093005518990373195406051061394"),
+        ];
+        assert_eq!(
+            GriffinCRHF::sponge_no_padding(&sponge_input.map(Fq377::from), 3).unwrap(),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_griffin_crhf() {
+        test_griffin_crhf_helper::<Fq254>();
+        test_griffin_crhf_helper::<Fq377>();
+        test_griffin_crhf_helper::<Fq381>();
+    }
+
+    fn test_griffin_crhf_helper<F: GriffinParameter>() {
+        let rng = &mut jf_utils::test_rng();
+        let input: Vec<F> = (0..2 * CRHF_RATE).map(|_| F::rand(rng)).collect();
+        let output = GriffinCRHF::sponge_no_padding(&input, 3).unwrap();
+        assert_eq!(output.len(), 3);
+        assert_eq!(output, GriffinCRHF::sponge_no_padding(&input, 3).unwrap());
+        assert_eq!(
+            output[..1],
+            GriffinCRHF::sponge_no_padding(&input, 1).unwrap()
+        );
+
+        // The sponge absorbs into the rate and squeezes from it
+        let permutation = F::permutation();
+        let mut state = [F::zero(); STATE_SIZE];
+        for chunk in input.chunks(CRHF_RATE) {
+            state[0] += chunk[0];
+            state[1] += chunk[1];
+            permutation.permute_in_place(&mut state);
+        }
+        assert_eq!(output[..2], state[..2]);
+        assert_eq!(output[2], permutation.eval(&state)[0]);
+
+        let mut other = input.clone();
+        other[0] += F::one();
+        assert_ne!(output, GriffinCRHF::sponge_no_padding(&other, 3).unwrap());
+
+        assert!(GriffinCRHF::sponge_no_padding(&vec![F::zero(); CRHF_RATE + 1], 1).is_err());
+        assert_eq!(
+            GriffinCRHF::sponge_with_zero_padding(&input[..3], 1),
+            GriffinCRHF::sponge_no_padding(&[input[0], input[1], input[2], F::zero()], 1).unwrap()
+        );
+        assert_eq!(
+            GriffinCRHF::sponge_with_bit_padding(&input[..2], 1),
+            GriffinCRHF::sponge_no_padding(&[input[0], input[1], F::one(), F::zero()], 1).unwrap()
+        );
+    }
+}
@@ -0,0 +1,319 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+//! Hashing to a prime field, following `hash_to_field` of
+//! [RFC 9380](https://www.rfc-editor.org/rfc/rfc9380.html#section-5).
+//!
+//! The message is first expanded into `count * L` uniform bytes with either
+//! `expand_message_xmd` over a fixed-output hash ([`ExpandMsgXmd`]) or
+//! `expand_message_xof` over an extendable-output function
+//! ([`ExpandMsgXof`]), where `L = ceil((ceil(log2(p)) + k) / 8)` and `k =
+//! 128` is the target security level. Each chunk of `L` bytes is then read
+//! as a big-endian integer and reduced modulo `p`, which makes the elements
+//! statistically close to uniform.
+//!
+//! [`hash_to_field`] is the common case of `expand_message_xmd` over SHA-256,
+//! the expander of the `XMD:SHA-256` suites.
+
+use crate::errors::PrimitivesError;
+use ark_ff::PrimeField;
+use ark_std::{format, marker::PhantomData, string::ToString, vec, vec::Vec};
+use digest::{core_api::BlockSizeUser, Digest, ExtendableOutput, Update, XofReader};
+use sha2::Sha256;
+
+/// The target security level `k` in bits.
+pub const SECURITY_BITS: usize = 128;
+
+/// The prefix of the hash of a domain separation tag longer than 255 bytes.
+const OVERSIZE_DST_PREFIX: &[u8] = b"H2C-OVERSIZE-DST-";
+
+/// A `expand_message` function of RFC 9380.
+pub trait MessageExpander {
+    /// Expand `msg` into `len_in_bytes` uniform bytes, with domain separation
+    /// tag `dst`.
+    /// * `returns` - Err() if `dst` is empty or `len_in_bytes` is too large for
+    ///   the expander
+    fn expand_message(
+        msg: &[u8],
+        dst: &[u8],
+        len_in_bytes: usize,
+    ) -> Result<Vec<u8>, PrimitivesError>;
+}
+
+/// `expand_message_xmd` over the fixed-output hash function `H`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ExpandMsgXmd<H>(PhantomData<H>);
+
+/// `expand_message_xof` over the extendable-output function `X`, for `k =
+/// SECURITY_BITS`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ExpandMsgXof<X>(PhantomData<X>);
+
+impl<H: Digest + BlockSizeUser> MessageExpander for ExpandMsgXmd<H> {
+    fn expand_message(
+        msg: &[u8],
+        dst: &[u8],
+        len_in_bytes: usize,
+    ) -> Result<Vec<u8>, PrimitivesError> {
+        expand_message_xmd::<H>(msg, dst, len_in_bytes)
+    }
+}
+
+impl<X: Default + Update + ExtendableOutput> MessageExpander for ExpandMsgXof<X> {
+    fn expand_message(
+        msg: &[u8],
+        dst: &[u8],
+        len_in_bytes: usize,
+    ) -> Result<Vec<u8>, PrimitivesError> {
+        expand_message_xof::<X>(msg, dst, len_in_bytes)
+    }
+}
+
+/// `expand_message_xmd` of RFC 9380, section 5.3.1.
+/// * `msg` - the message to expand
+/// * `dst` - domain separation tag, hashed first if longer than 255 bytes
+/// * `len_in_bytes` - the # of output bytes, at most 255 hash outputs and 65535
+///   bytes
+/// * `returns` - Err() if `dst` is empty or `len_in_bytes` is out of range
+pub fn expand_message_xmd<H: Digest + BlockSizeUser>(
+    msg: &[u8],
+    dst: &[u8],
+    len_in_bytes: usize,
+) -> Result<Vec<u8>, PrimitivesError> {
+    let b_in_bytes = <H as Digest>::output_size();
+    let ell = (len_in_bytes + b_in_bytes - 1) / b_in_bytes;
+    if ell > 255 || len_in_bytes > u16::MAX as usize {
+        return Err(PrimitivesError::ParameterError(format!(
+            "expand_message_xmd Error : cannot expand to {len_in_bytes} bytes."
+        )));
+    }
+    let dst_prime = dst_prime(dst, |long_dst| {
+        H::new()
+            .chain_update(OVERSIZE_DST_PREFIX)
+            .chain_update(long_dst)
+            .finalize()
+            .to_vec()
+    })?;
+
+    let b_0 = H::new()
+        .chain_update(vec![0u8; H::block_size()])
+        .chain_update(msg)
+        .chain_update((len_in_bytes as u16).to_be_bytes())
+        .chain_update([0u8])
+        .chain_update(&dst_prime)
+        .finalize();
+    let mut b_i = H::new()
+        .chain_update(&b_0)
+        .chain_update([1u8])
+        .chain_update(&dst_prime)
+        .finalize();
+    let mut uniform_bytes = Vec::with_capacity(ell * b_in_bytes);
+    uniform_bytes.extend_from_slice(&b_i);
+    for i in 2..=ell {
+        let xored: Vec<u8> = b_0.iter().zip(b_i.iter()).map(|(x, y)| x ^ y).collect();
+        b_i = H::new()
+            .chain_update(xored)
+            .chain_update([i as u8])
+            .chain_update(&dst_prime)
+            .finalize();
+        uniform_bytes.extend_from_slice(&b_i);
+    }
+    uniform_bytes.truncate(len_in_bytes);
+    Ok(uniform_bytes)
+}
+
+/// `expand_message_xof` of RFC 9380, section 5.3.2, for `k = SECURITY_BITS`.
+/// * `msg` - the message to expand
+/// * `dst` - domain separation tag, hashed first if longer than 255 bytes
+/// * `len_in_bytes` - the # of output bytes, at most 65535
+/// * `returns` - Err() if `dst` is empty or `len_in_bytes` is out of range
+pub fn expand_message_xof<X: Default + Update + ExtendableOutput>(
+    msg: &[u8],
+    dst: &[u8],
+    len_in_bytes: usize,
+) -> Result<Vec<u8>, PrimitivesError> {
+    if len_in_bytes > u16::MAX as usize {
+        return Err(PrimitivesError::ParameterError(format!(
+            "expand_message_xof Error : cannot expand to {len_in_bytes} bytes."
+        )));
+    }
+    let dst_prime = dst_prime(dst, |long_dst| {
+        let mut hashed = vec![0u8; (2 * SECURITY_BITS + 7) / 8];
+        X::default()
+            .chain(OVERSIZE_DST_PREFIX)
+            .chain(long_dst)
+            .finalize_xof()
+            .read(&mut hashed);
+        hashed
+    })?;
+
+    let mut uniform_bytes = vec![0u8; len_in_bytes];
+    X::default()
+        .chain(msg)
+        .chain((len_in_bytes as u16).to_be_bytes())
+        .chain(&dst_prime)
+        .finalize_xof()
+        .read(&mut uniform_bytes);
+    Ok(uniform_bytes)
+}
+
+/// `DST_prime = DST || I2OSP(len(DST), 1)`, where a tag longer than 255
+/// bytes is first replaced by its hash with `hash_long_dst`.
+fn dst_prime(
+    dst: &[u8],
+    hash_long_dst: impl FnOnce(&[u8]) -> Vec<u8>,
+) -> Result<Vec<u8>, PrimitivesError> {
+    if dst.is_empty() {
+        return Err(PrimitivesError::ParameterError(
+            "expand_message Error : the domain separation tag is empty.".to_string(),
+        ));
+    }
+    let mut dst_prime = if dst.len() > 255 {
+        hash_long_dst(dst)
+    } else {
+        dst.to_vec()
+    };
+    dst_prime.push(dst_prime.len() as u8);
+    Ok(dst_prime)
+}
+
+/// Hash `msg` to `count` elements of `F` with `expand_message_xmd` over
+/// SHA-256, as `hash_to_field` of RFC 9380.
+/// * `dst` - domain separation tag, unique to the application
+/// * `returns` - Err() if `dst` is empty or `count` is too large
+pub fn hash_to_field<F: PrimeField>(
+    msg: &[u8],
+    dst: &[u8],
+    count: usize,
+) -> Result<Vec<F>, PrimitivesError> {
+    hash_to_field_with::<F, ExpandMsgXmd<Sha256>>(msg, dst, count)
+}
+
+/// Same as [`hash_to_field`], with the expander `E`.
+pub fn hash_to_field_with<F: PrimeField, E: MessageExpander>(
+    msg: &[u8],
+    dst: &[u8],
+    count: usize,
+) -> Result<Vec<F>, PrimitivesError> {
+    let len_per_elem = (F::MODULUS_BIT_SIZE as usize + SECURITY_BITS + 7) / 8;
+    let uniform_bytes = E::expand_message(msg, dst, count * len_per_elem)?;
+    Ok(uniform_bytes
+        .chunks(len_per_elem)
+        .map(F::from_be_bytes_mod_order)
+        .collect())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use ark_ff::field_hashers::{DefaultFieldHasher, HashToField};
+    use sha3::Shake128;
+
+    fn from_hex(s: &str) -> Vec<u8> {
+        (0..s.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_expand_message() {
+        // test vectors of RFC 9380, appendix K.1 and K.4
+        let dst = b"QUUX-V01-CS02-with-expander-SHA256-128";
+        assert_eq!(
+            expand_message_xmd::<Sha256>(b"", dst, 0x20).unwrap(),
+            from_hex("68a985b87eb6b46952128911f2a4412bbc302a9d759667f87f7a21d803f07235")
+        );
+        assert_eq!(
+            expand_message_xmd::<Sha256>(b"abc", dst, 0x20).unwrap(),
+            from_hex("d8ccab23b5985ccea865c6c97b6e5b8350e794e603b4b97902f53a8a0d605615")
+        );
+        assert_eq!(
+            expand_message_xmd::<Sha256>(b"abcdef0123456789", dst, 0x80).unwrap(),
+            from_hex(
+                "ef904a29bffc4cf9ee82832451c946ac3c8f8058ae97d8d629831a74c6572bd9ebd0df635cd1f2\
+                 08e2038e760c4994984ce73f0d55ea9f22af83ba4734569d4bc95e18350f740c07eef653cbb9f8\
+                 7910d833751825f0ebefa1abe5420bb52be14cf489b37fe1a72f7de2d10be453b2c9d9eb20c7e3\
+                 f6edc5a60629178d9478df"
+            )
+        );
+        let dst = b"QUUX-V01-CS02-with-expander-SHAKE128";
+        assert_eq!(
+            expand_message_xof::<Shake128>(b"", dst, 0x20).unwrap(),
+            from_hex("86518c9cd86581486e9485aa74ab35ba150d1c75c88e26b7043e44e2acd735a2")
+        );
+        assert_eq!(
+            expand_message_xof::<Shake128>(b"abc", dst, 0x20).unwrap(),
+            from_hex("8696af52a4d862417c0763556073f47bc9b9ba43c99b505305cb1ec04a9ab468")
+        );
+
+        // the native keccak hashers can be used as expanders
+        assert_eq!(
+            expand_message_xmd::<crate::keccak::Keccak256>(b"abc", dst, 100).unwrap(),
+            expand_message_xmd::<sha3::Keccak256>(b"abc", dst, 100).unwrap()
+        );
+
+        // a long tag is hashed first
+        let long_dst = [b'a'; 300];
+        let mut hashed_dst = Sha256::new()
+            .chain_update(OVERSIZE_DST_PREFIX)
+            .chain_update(long_dst)
+            .finalize()
+            .to_vec();
+        assert_eq!(
+            expand_message_xmd::<Sha256>(b"abc", &long_dst, 100).unwrap(),
+            expand_message_xmd::<Sha256>(b"abc", &hashed_dst, 100).unwrap()
+        );
+        hashed_dst = vec![0u8; 32];
+        Shake128::default()
+            .chain(OVERSIZE_DST_PREFIX)
+            .chain(long_dst)
+            .finalize_xof()
+            .read(&mut hashed_dst);
+        assert_eq!(
+            expand_message_xof::<Shake128>(b"abc", &long_dst, 100).unwrap(),
+            expand_message_xof::<Shake128>(b"abc", &hashed_dst, 100).unwrap()
+        );
+
+        // out of range parameters
+        assert!(expand_message_xmd::<Sha256>(b"abc", b"", 32).is_err());
+        assert!(expand_message_xof::<Shake128>(b"abc", b"", 32).is_err());
+        assert!(expand_message_xmd::<Sha256>(b"abc", dst, 255 * 32).is_ok());
+        assert!(expand_message_xmd::<Sha256>(b"abc", dst, 255 * 32 + 1).is_err());
+        assert!(expand_message_xof::<Shake128>(b"abc", dst, 1 << 16).is_err());
+    }
+
+    #[test]
+    fn test_hash_to_field() {
+        test_hash_to_field_helper::<ark_bn254::Fr>();
+        test_hash_to_field_helper::<ark_bls12_381::Fq>();
+        test_hash_to_field_helper::<ark_bw6_761::Fr>();
+    }
+
+    fn test_hash_to_field_helper<F: PrimeField>() {
+        let dst = b"JF-HASH-TO-FIELD-TEST";
+        let elems = hash_to_field::<F>(b"jellyfish", dst, 3).unwrap();
+        assert_eq!(elems.len(), 3);
+        assert_ne!(elems[0], elems[1]);
+        assert_eq!(
+            elems[..2],
+            hash_to_field::<F>(b"jellyfish", dst, 2).unwrap()[..]
+        );
+        assert_ne!(
+            elems,
+            hash_to_field::<F>(b"jellyfish", b"JF-OTHER", 3).unwrap()
+        );
+
+        // same as the field hasher of arkworks, which implements the XMD:SHA-256
+        // expander of the RFC
+        let hasher = <DefaultFieldHasher<Sha256, 128> as HashToField<F>>::new(dst);
+        assert_eq!(elems, hasher.hash_to_field(b"jellyfish", 3));
+
+        let xof_elems = hash_to_field_with::<F, ExpandMsgXof<Shake128>>(b"jellyfish", dst, 3);
+        assert_ne!(elems, xof_elems.unwrap());
+        assert!(hash_to_field::<F>(b"jellyfish", b"", 1).is_err());
+    }
+}
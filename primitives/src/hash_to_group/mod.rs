@@ -6,6 +6,8 @@
 
 //! Module for hash to various elliptic curve groups
 
+mod short_weierstrass;
 mod twisted_edwards;
 
+pub use short_weierstrass::{SWHashToGroup, SvdWConfig, SvdWMap};
 pub use twisted_edwards::TEHashToGroup;
@@ -0,0 +1,321 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+//! Hash to Elliptic Curve implementation of [RFC 9380](https://www.rfc-editor.org/rfc/rfc9380.html)
+//! for the groups of the BLS12-381 and BN254 pairings.
+//!
+//! Messages are hashed to two field elements with `expand_message_xmd` over
+//! SHA-256, each of them is mapped to the curve, and the sum of both points
+//! is multiplied by the effective cofactor. BLS12-381 uses the simplified
+//! SWU map to an isogenous curve, while BN254, whose curves have `A = 0` and
+//! no convenient isogeny, uses the Shallue-van de Woestijne map.
+//!
+//! The SSWU map and the isogenies are those of arkworks. The SvdW map follows
+//! the straight-line procedure of the RFC: every candidate is computed and
+//! the result is selected arithmetically rather than by branching, so its
+//! timing only depends on the field arithmetic of arkworks.
+
+use crate::errors::PrimitivesError;
+use ark_ec::{
+    hashing::{
+        curve_maps::wb::WBMap,
+        map_to_curve_hasher::{MapToCurve, MapToCurveBasedHasher},
+        HashToCurve, HashToCurveError,
+    },
+    short_weierstrass::{Affine, Projective, SWCurveConfig},
+};
+use ark_ff::{field_hashers::DefaultFieldHasher, BigInteger, Field, PrimeField, Zero};
+use ark_std::{format, string::ToString};
+use sha2::Sha256;
+
+/// Trait definition for hash to group functions for short Weierstrass
+/// curves, following a ciphersuite of RFC 9380.
+pub trait SWHashToGroup: SWCurveConfig + Sized {
+    /// The ID of the hash-to-curve suite implemented for this group. DSTs
+    /// are conventionally made of an application tag followed by this ID,
+    /// e.g. `BLS_SIG_BLS12381G1_XMD:SHA-256_SSWU_RO_NUL_` for BLS
+    /// signatures.
+    const SUITE_ID: &'static str;
+
+    /// Hash `data` to a point of the prime order subgroup, with domain
+    /// separation tag `dst`.
+    /// * `returns` - Err() if `dst` is empty
+    fn hash_to_group<B: AsRef<[u8]>>(data: B, dst: B) -> Result<Projective<Self>, PrimitivesError>;
+}
+
+/// Additional parameter of the Shallue-van de Woestijne map for a curve.
+pub trait SvdWConfig: SWCurveConfig {
+    /// The constant `Z` of the map, chosen with the `find_z_svdw` procedure
+    /// of RFC 9380.
+    const Z: Self::BaseField;
+}
+
+/// The Shallue-van de Woestijne map of RFC 9380, section 6.6.1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SvdWMap<P: SvdWConfig> {
+    /// `g(Z)`
+    c1: P::BaseField,
+    /// `-Z / 2`
+    c2: P::BaseField,
+    /// `sqrt(-g(Z) * (3 Z^2 + 4 A))`, with `sgn0(c3) = 0`
+    c3: P::BaseField,
+    /// `-4 g(Z) / (3 Z^2 + 4 A)`
+    c4: P::BaseField,
+}
+
+impl<P: SvdWConfig> MapToCurve<Projective<P>> for SvdWMap<P> {
+    fn new() -> Result<Self, HashToCurveError> {
+        let z = P::Z;
+        let g_z = curve_equation::<P>(z);
+        let h_z = P::BaseField::from(3u64) * z.square() + P::COEFF_A.double().double();
+        let bad_z = || HashToCurveError::MapToCurveError("Invalid SvdW constant Z".to_string());
+        if g_z.is_zero() || h_z.is_zero() {
+            return Err(bad_z());
+        }
+        let mut c3 = (-g_z * h_z).sqrt().ok_or_else(bad_z)?;
+        if sgn0(c3) {
+            c3 = -c3;
+        }
+        let two_inv = P::BaseField::from(2u64).inverse().ok_or_else(bad_z)?;
+        Ok(Self {
+            c1: g_z,
+            c2: -z * two_inv,
+            c3,
+            c4: -g_z.double().double() * h_z.inverse().ok_or_else(bad_z)?,
+        })
+    }
+
+    fn map_to_curve(&self, u: P::BaseField) -> Result<Affine<P>, HashToCurveError> {
+        let one = <P::BaseField as Field>::ONE;
+        let tv1 = u.square() * self.c1;
+        let tv2 = one + tv1;
+        let tv1 = one - tv1;
+        let tv3 = (tv1 * tv2).inverse().unwrap_or_default();
+        let tv4 = u * tv1 * tv3 * self.c3;
+        let x1 = self.c2 - tv4;
+        let x2 = self.c2 + tv4;
+        let x3 = (tv2.square() * tv3).square() * self.c4 + P::Z;
+
+        // Exactly one candidate is selected, every one of them is computed
+        let e1 = is_square(curve_equation::<P>(x1));
+        let e2 = is_square(curve_equation::<P>(x2)) & !e1;
+        let x = select(select(x3, x1, e1), x2, e2);
+        let y = curve_equation::<P>(x).sqrt().ok_or_else(|| {
+            HashToCurveError::MapToCurveError("SvdW map to a non-square".to_string())
+        })?;
+        let y = select(-y, y, sgn0(u) == sgn0(y));
+        Ok(Affine::new_unchecked(x, y))
+    }
+}
+
+/// `x^3 + A x + B`
+fn curve_equation<P: SWCurveConfig>(x: P::BaseField) -> P::BaseField {
+    x.square() * x + P::mul_by_a(x) + P::COEFF_B
+}
+
+fn is_square<F: Field>(x: F) -> bool {
+    !x.legendre().is_qnr()
+}
+
+/// `CMOV(a, b, c)` of RFC 9380: `b` if `c`, `a` otherwise.
+fn select<F: Field>(a: F, b: F, c: bool) -> F {
+    let c = F::from(c);
+    a + c * (b - a)
+}
+
+/// The `sgn0` function of RFC 9380, section 4.1.
+fn sgn0<F: Field>(x: F) -> bool {
+    let mut sign = false;
+    let mut zero = true;
+    for x_i in x.to_base_prime_field_elements() {
+        sign |= zero & x_i.into_bigint().is_odd();
+        zero &= x_i.is_zero();
+    }
+    sign
+}
+
+fn hash_with_map<P, M>(data: &[u8], dst: &[u8]) -> Result<Projective<P>, PrimitivesError>
+where
+    P: SWCurveConfig,
+    M: MapToCurve<Projective<P>>,
+{
+    if dst.is_empty() {
+        return Err(PrimitivesError::ParameterError(
+            "Hash to curve requires a non-empty DST".to_string(),
+        ));
+    }
+    let to_error = |e: HashToCurveError| PrimitivesError::InternalError(format!("{e}"));
+    let hasher =
+        MapToCurveBasedHasher::<Projective<P>, DefaultFieldHasher<Sha256, 128>, M>::new(dst)
+            .map_err(to_error)?;
+    Ok(hasher.hash(data).map_err(to_error)?.into())
+}
+
+macro_rules! impl_sw_hash_to_group {
+    ($config:ty, $map:ident, $suite_id:expr) => {
+        impl SWHashToGroup for $config {
+            const SUITE_ID: &'static str = $suite_id;
+
+            fn hash_to_group<B: AsRef<[u8]>>(
+                data: B,
+                dst: B,
+            ) -> Result<Projective<Self>, PrimitivesError> {
+                hash_with_map::<Self, $map<Self>>(data.as_ref(), dst.as_ref())
+            }
+        }
+    };
+}
+
+impl SvdWConfig for ark_bn254::g1::Config {
+    const Z: ark_bn254::Fq = ark_bn254::Fq::ONE;
+}
+
+impl SvdWConfig for ark_bn254::g2::Config {
+    const Z: ark_bn254::Fq2 = ark_bn254::Fq2::ONE;
+}
+
+impl_sw_hash_to_group!(
+    ark_bls12_381::g1::Config,
+    WBMap,
+    "BLS12381G1_XMD:SHA-256_SSWU_RO_"
+);
+impl_sw_hash_to_group!(
+    ark_bls12_381::g2::Config,
+    WBMap,
+    "BLS12381G2_XMD:SHA-256_SSWU_RO_"
+);
+impl_sw_hash_to_group!(
+    ark_bn254::g1::Config,
+    SvdWMap,
+    "BN254G1_XMD:SHA-256_SVDW_RO_"
+);
+impl_sw_hash_to_group!(
+    ark_bn254::g2::Config,
+    SvdWMap,
+    "BN254G2_XMD:SHA-256_SVDW_RO_"
+);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use ark_ec::{AffineRepr, CurveGroup};
+    use ark_ff::{MontFp, One};
+    use ark_std::vec::Vec;
+
+    fn quux_dst<P: SWHashToGroup>() -> Vec<u8> {
+        format!("QUUX-V01-CS02-with-{}", P::SUITE_ID).into_bytes()
+    }
+
+    fn test_hash_to_group_helper<P: SWHashToGroup>() {
+        let dst = quux_dst::<P>();
+        let dst = &dst[..];
+        let point = P::hash_to_group(&b"abc"[..], dst).unwrap();
+        let affine = point.into_affine();
+        assert!(affine.is_on_curve());
+        assert!(affine.is_in_correct_subgroup_assuming_on_curve());
+        assert!(!affine.is_zero());
+        assert_eq!(point, P::hash_to_group(&b"abc"[..], dst).unwrap());
+        assert_ne!(point, P::hash_to_group(&b"abd"[..], dst).unwrap());
+        assert_ne!(
+            point,
+            P::hash_to_group(&b"abc"[..], &b"other DST"[..]).unwrap()
+        );
+        assert!(P::hash_to_group(&b"abc"[..], &b""[..]).is_err());
+    }
+
+    #[test]
+    fn test_hash_to_group() {
+        test_hash_to_group_helper::<ark_bls12_381::g1::Config>();
+        test_hash_to_group_helper::<ark_bls12_381::g2::Config>();
+        test_hash_to_group_helper::<ark_bn254::g1::Config>();
+        test_hash_to_group_helper::<ark_bn254::g2::Config>();
+    }
+
+    #[test]
+    fn test_sgn0() {
+        use ark_bn254::{Fq, Fq2};
+        assert!(!sgn0(Fq::zero()));
+        assert!(sgn0(Fq::one()));
+        assert!(!sgn0(-Fq::one()));
+        assert!(sgn0(Fq2::new(Fq::zero(), Fq::one())));
+        assert!(!sgn0(Fq2::new(Fq::from(2u64), Fq::one())));
+    }
+
+    // Test vectors of RFC 9380, appendix J.9
+    #[test]
+    fn test_bls12_381_vectors() {
+        use ark_bls12_381::{Fq2, G1Affine, G2Affine};
+
+        let dst = quux_dst::<ark_bls12_381::g1::Config>();
+        let expected = G1Affine::new(
+            MontFp!("794311575721400831362957049303781044852006323422624111893352859557450008308620925451441746926395141598720928151969"),
+            MontFp!("1343412193624222137939591894701031123123641958980729764240763391191550653712890272928110356903136085217047453540965"),
+        );
+        assert_eq!(
+            ark_bls12_381::g1::Config::hash_to_group(&b""[..], &dst[..])
+                .unwrap()
+                .into_affine(),
+            expected
+        );
+        let expected = G1Affine::new(
+            MontFp!("513738460217615943921285247703448567647875874745567372796164155472383127756567780059136521508428662765965997467907"),
+            MontFp!("1786897908129645780825838873875416513994655004408749907941296449131605892957529391590865627492442562626458913769565"),
+        );
+        assert_eq!(
+            ark_bls12_381::g1::Config::hash_to_group(&b"abc"[..], &dst[..])
+                .unwrap()
+                .into_affine(),
+            expected
+        );
+
+        let dst = quux_dst::<ark_bls12_381::g2::Config>();
+        let x: Fq2 = Fq2::new(
+            MontFp!("193548053368451749411421515628510806626565736652086807419354395577367693778571452628423727082668900187036482254730"),
+            MontFp!("891930009643099423308102777951250899694559203647724988361022851024990473423938537113948850338098230396747396259901"),
+        );
+        let y: Fq2 = Fq2::new(
+            MontFp!("771717272055834152378281705972671257005357145478800908373659404991537354153455452961747174765859335819766715637138"),
+            MontFp!("2810310118582126634041133454180705304393079139103252956502404531123692847658283858246402311867775854528543237781718"),
+        );
+        assert_eq!(
+            ark_bls12_381::g2::Config::hash_to_group(&b""[..], &dst[..])
+                .unwrap()
+                .into_affine(),
+            G2Affine::new(x, y)
+        );
+    }
+
+    // There is no BN254 suite in RFC 9380 itself: the vectors below were
+    // generated by an independent implementation of the suite.
+    #[test]
+    fn test_bn254_vectors() {
+        use ark_bn254::G1Affine;
+
+        let dst = quux_dst::<ark_bn254::g1::Config>();
+        let expected = G1Affine::new(
+            MontFp!("4790658965958450548702669593570794336562317867247372723806336874591549759110"),
+            MontFp!("1163238807669877429342450210709044731909255047583162173012265677391336920021"),
+        );
+        assert_eq!(
+            ark_bn254::g1::Config::hash_to_group(&b""[..], &dst[..])
+                .unwrap()
+                .into_affine(),
+            expected
+        );
+        let expected = G1Affine::new(
+            MontFp!(
+                "16267524812466668166267883771992486438338357688076900798565538061554532963281"
+            ),
+            MontFp!("1844916233815282837483764409618609279507070495361570126601873459268232811805"),
+        );
+        assert_eq!(
+            ark_bn254::g1::Config::hash_to_group(&b"abc"[..], &dst[..])
+                .unwrap()
+                .into_affine(),
+            expected
+        );
+    }
+}
@@ -0,0 +1,966 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+//! Hybrid public-key encryption of [RFC 9180][rfc9180], in base mode.
+//!
+//! A ciphersuite combines one of the KEMs
+//! * DHKEM(P-256, HKDF-SHA256), with 65-byte uncompressed SEC1 public keys and
+//!   encapsulated keys, and 32-byte big-endian secret keys;
+//! * DHKEM(X25519, HKDF-SHA256), with the 32-byte keys of RFC 7748;
+//!
+//! with one of the KDFs HKDF-SHA256, HKDF-SHA384 and HKDF-SHA512, and one of
+//! the AEADs AES-128-GCM, AES-256-GCM and ChaCha20-Poly1305. Keys,
+//! encapsulated keys and ciphertexts are the byte strings of the RFC, so
+//! that they interoperate with any other implementation of the same
+//! ciphersuite.
+//!
+//! The sender encapsulates a fresh shared secret to the public key of the
+//! receiver, from which both derive an encryption context. Messages sealed in
+//! a context are opened in the same order by the receiver, and both can
+//! export secrets bound to the context. [`HpkeCipherSuite::seal`] and
+//! [`HpkeCipherSuite::open`] encrypt a single message.
+//!
+//! [rfc9180]: https://www.rfc-editor.org/rfc/rfc9180
+
+use crate::{
+    errors::PrimitivesError,
+    utils::{bytes_from_int, int_from_bytes},
+};
+use aes_gcm::{Aes128Gcm, Aes256Gcm};
+use ark_std::{
+    format,
+    rand::{CryptoRng, RngCore},
+    string::ToString,
+    vec,
+    vec::Vec,
+};
+use chacha20poly1305::{
+    aead::{Aead, KeyInit, Nonce, Payload},
+    ChaCha20Poly1305,
+};
+use hkdf::SimpleHkdf;
+use serde::{Deserialize, Serialize};
+use sha2::{digest::core_api::BlockSizeUser, Digest, Sha256, Sha384, Sha512};
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+/// Supported KEMs of HPKE.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HpkeKem {
+    /// DHKEM(P-256, HKDF-SHA256), with identifier 0x0010
+    DhKemP256HkdfSha256,
+    /// DHKEM(X25519, HKDF-SHA256), with identifier 0x0020
+    DhKemX25519HkdfSha256,
+}
+
+/// Supported KDFs of HPKE.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HpkeKdf {
+    /// HKDF-SHA256, with identifier 0x0001
+    HkdfSha256,
+    /// HKDF-SHA384, with identifier 0x0002
+    HkdfSha384,
+    /// HKDF-SHA512, with identifier 0x0003
+    HkdfSha512,
+}
+
+/// Supported AEADs of HPKE.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HpkeAead {
+    /// AES-128-GCM, with identifier 0x0001
+    Aes128Gcm,
+    /// AES-256-GCM, with identifier 0x0002
+    Aes256Gcm,
+    /// ChaCha20-Poly1305, with identifier 0x0003
+    ChaCha20Poly1305,
+}
+
+/// HPKE ciphersuite.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HpkeCipherSuite {
+    /// The KEM of the ciphersuite
+    pub kem: HpkeKem,
+    /// The KDF of the key schedule
+    pub kdf: HpkeKdf,
+    /// The AEAD of the encryption contexts
+    pub aead: HpkeAead,
+}
+
+/// HPKE secret key, in the secret key encoding of the KEM.
+#[derive(Clone, Debug, Zeroize, Eq, PartialEq, Serialize, Deserialize)]
+pub struct HpkeSecretKey([u8; 32]);
+
+impl Drop for HpkeSecretKey {
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl ZeroizeOnDrop for HpkeSecretKey {}
+
+/// HPKE public key, in the public key encoding of the KEM.
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub struct HpkePublicKey(Vec<u8>);
+
+impl HpkeSecretKey {
+    /// The secret key of the 32-byte encoding `bytes`. The key is checked to
+    /// be in range for the KEM when used.
+    pub fn from_bytes(bytes: &[u8; 32]) -> Self {
+        Self(*bytes)
+    }
+
+    /// The 32-byte encoding of the secret key.
+    pub fn to_bytes(&self) -> [u8; 32] {
+        self.0
+    }
+}
+
+impl HpkePublicKey {
+    /// The public key of the encoding `bytes`. The key is checked when used.
+    pub fn from_bytes(bytes: &[u8]) -> Self {
+        Self(bytes.to_vec())
+    }
+
+    /// The encoding of the public key.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+/// Encryption context of the sender.
+#[derive(Debug)]
+pub struct HpkeSenderContext(Context);
+
+/// Encryption context of the receiver.
+#[derive(Debug)]
+pub struct HpkeReceiverContext(Context);
+
+impl HpkeSenderContext {
+    /// Encrypt the next message of the context, authenticating `aad` along
+    /// with it.
+    /// * `returns` - Err() if the maximum number of messages is reached
+    pub fn seal(&mut self, aad: &[u8], plaintext: &[u8]) -> Result<Vec<u8>, PrimitivesError> {
+        let nonce = self.0.next_nonce()?;
+        self.0.aead.seal(&self.0.key, &nonce, aad, plaintext)
+    }
+
+    /// Export a secret of `len` bytes bound to the context and to
+    /// `exporter_context`.
+    /// * `returns` - Err() if `len` exceeds 255 times the hash length of the
+    ///   KDF
+    pub fn export(&self, exporter_context: &[u8], len: usize) -> Result<Vec<u8>, PrimitivesError> {
+        self.0.export(exporter_context, len)
+    }
+}
+
+impl HpkeReceiverContext {
+    /// Decrypt the next message of the context, with the associated data
+    /// used for its encryption.
+    /// * `returns` - Err() if the ciphertext is invalid, or is not the next one
+    ///   of the context
+    pub fn open(&mut self, aad: &[u8], ciphertext: &[u8]) -> Result<Vec<u8>, PrimitivesError> {
+        // the sequence number only advances on success, as in the RFC
+        let nonce = self.0.nonce()?;
+        let plaintext = self.0.aead.open(&self.0.key, &nonce, aad, ciphertext)?;
+        self.0.seq += 1;
+        Ok(plaintext)
+    }
+
+    /// Export a secret of `len` bytes bound to the context and to
+    /// `exporter_context`.
+    /// * `returns` - Err() if `len` exceeds 255 times the hash length of the
+    ///   KDF
+    pub fn export(&self, exporter_context: &[u8], len: usize) -> Result<Vec<u8>, PrimitivesError> {
+        self.0.export(exporter_context, len)
+    }
+}
+
+impl HpkeCipherSuite {
+    /// Creates a ciphersuite from its KEM, KDF and AEAD.
+    pub fn new(kem: HpkeKem, kdf: HpkeKdf, aead: HpkeAead) -> Self {
+        Self { kem, kdf, aead }
+    }
+
+    /// Randomly sample a key pair of the KEM.
+    pub fn generate_key_pair<R: CryptoRng + RngCore>(
+        &self,
+        prng: &mut R,
+    ) -> (HpkeSecretKey, HpkePublicKey) {
+        let mut ikm = [0u8; 32];
+        prng.fill_bytes(&mut ikm);
+        let key_pair = self.derive_key_pair(&ikm);
+        ikm.zeroize();
+        key_pair.expect("Bug in JF Primitives : failed to derive an HPKE key pair")
+    }
+
+    /// Deterministically derive a key pair of the KEM from the secret `ikm`.
+    /// * `returns` - Err() if `ikm` is shorter than 32 bytes
+    pub fn derive_key_pair(
+        &self,
+        ikm: &[u8],
+    ) -> Result<(HpkeSecretKey, HpkePublicKey), PrimitivesError> {
+        if ikm.len() < 32 {
+            return Err(PrimitivesError::ParameterError(
+                "HPKE key derivation material must be at least 32 bytes".to_string(),
+            ));
+        }
+        let sk = self.kem.derive_secret_key(ikm)?;
+        let pk = self.kem.public_key(&sk)?;
+        Ok((HpkeSecretKey(sk), HpkePublicKey(pk)))
+    }
+
+    /// The public key of a secret key.
+    /// * `returns` - Err() if the secret key is out of range for the KEM
+    pub fn public_key(&self, sk: &HpkeSecretKey) -> Result<HpkePublicKey, PrimitivesError> {
+        Ok(HpkePublicKey(self.kem.public_key(&sk.0)?))
+    }
+
+    /// Encapsulate a fresh shared secret to `pk_r`, and derive the
+    /// encryption context of the sender bound to `info`.
+    /// * `returns` - the encapsulated key to send to the receiver, and the
+    ///   context. Err() if `pk_r` is invalid
+    pub fn setup_base_sender<R: CryptoRng + RngCore>(
+        &self,
+        pk_r: &HpkePublicKey,
+        info: &[u8],
+        prng: &mut R,
+    ) -> Result<(Vec<u8>, HpkeSenderContext), PrimitivesError> {
+        let (sk_e, _) = self.generate_key_pair(prng);
+        self.setup_base_sender_with_ephemeral_key(pk_r, info, &sk_e)
+    }
+
+    /// Decapsulate the shared secret of `enc` with `sk_r`, and derive the
+    /// encryption context of the receiver bound to `info`.
+    /// * `returns` - Err() if `enc` or `sk_r` is invalid
+    pub fn setup_base_receiver(
+        &self,
+        enc: &[u8],
+        sk_r: &HpkeSecretKey,
+        info: &[u8],
+    ) -> Result<HpkeReceiverContext, PrimitivesError> {
+        let pk_r = self.kem.public_key(&sk_r.0)?;
+        let dh = self.kem.dh(&sk_r.0, enc)?;
+        let shared_secret = self
+            .kem
+            .extract_and_expand(&dh, &[enc, &pk_r[..]].concat())?;
+        Ok(HpkeReceiverContext(
+            self.key_schedule(&shared_secret, info)?,
+        ))
+    }
+
+    /// Encrypt a single message to `pk_r`.
+    /// * `returns` - the encapsulated key and the ciphertext. Err() if `pk_r`
+    ///   is invalid
+    pub fn seal<R: CryptoRng + RngCore>(
+        &self,
+        pk_r: &HpkePublicKey,
+        info: &[u8],
+        aad: &[u8],
+        plaintext: &[u8],
+        prng: &mut R,
+    ) -> Result<(Vec<u8>, Vec<u8>), PrimitivesError> {
+        let (enc, mut ctx) = self.setup_base_sender(pk_r, info, prng)?;
+        Ok((enc, ctx.seal(aad, plaintext)?))
+    }
+
+    /// Decrypt a single message encrypted with [`Self::seal`].
+    /// * `returns` - Err() if `enc`, `sk_r` or the ciphertext is invalid
+    pub fn open(
+        &self,
+        enc: &[u8],
+        sk_r: &HpkeSecretKey,
+        info: &[u8],
+        aad: &[u8],
+        ciphertext: &[u8],
+    ) -> Result<Vec<u8>, PrimitivesError> {
+        self.setup_base_receiver(enc, sk_r, info)?
+            .open(aad, ciphertext)
+    }
+
+    // `SetupBaseS` with the ephemeral key `sk_e`.
+    fn setup_base_sender_with_ephemeral_key(
+        &self,
+        pk_r: &HpkePublicKey,
+        info: &[u8],
+        sk_e: &HpkeSecretKey,
+    ) -> Result<(Vec<u8>, HpkeSenderContext), PrimitivesError> {
+        let enc = self.kem.public_key(&sk_e.0)?;
+        let dh = self.kem.dh(&sk_e.0, &pk_r.0)?;
+        let shared_secret = self
+            .kem
+            .extract_and_expand(&dh, &[&enc, &pk_r.0[..]].concat())?;
+        let ctx = HpkeSenderContext(self.key_schedule(&shared_secret, info)?);
+        Ok((enc, ctx))
+    }
+
+    // `KeySchedule` in base mode, with an empty PSK and PSK identifier.
+    fn key_schedule(&self, shared_secret: &[u8], info: &[u8]) -> Result<Context, PrimitivesError> {
+        let suite_id = self.suite_id();
+        let kdf = self.kdf;
+        let psk_id_hash = kdf.labeled_extract(&suite_id, &[], b"psk_id_hash", &[]);
+        let info_hash = kdf.labeled_extract(&suite_id, &[], b"info_hash", info);
+        let context = [&[MODE_BASE][..], &psk_id_hash[..], &info_hash[..]].concat();
+
+        let mut secret = kdf.labeled_extract(&suite_id, shared_secret, b"secret", &[]);
+        let key = kdf.labeled_expand(&suite_id, &secret, b"key", &context, self.aead.key_len())?;
+        let mut base_nonce = [0u8; NONCE_LEN];
+        base_nonce.copy_from_slice(&kdf.labeled_expand(
+            &suite_id,
+            &secret,
+            b"base_nonce",
+            &context,
+            NONCE_LEN,
+        )?);
+        let exporter_secret =
+            kdf.labeled_expand(&suite_id, &secret, b"exp", &context, kdf.hash_len())?;
+        secret.zeroize();
+        Ok(Context {
+            suite_id,
+            kdf,
+            aead: self.aead,
+            key,
+            base_nonce,
+            exporter_secret,
+            seq: 0,
+        })
+    }
+
+    fn suite_id(&self) -> Vec<u8> {
+        [
+            &b"HPKE"[..],
+            &self.kem.id().to_be_bytes(),
+            &self.kdf.id().to_be_bytes(),
+            &self.aead.id().to_be_bytes(),
+        ]
+        .concat()
+    }
+}
+
+const MODE_BASE: u8 = 0x00;
+const NONCE_LEN: usize = 12;
+
+#[derive(Debug, Zeroize)]
+struct Context {
+    #[zeroize(skip)]
+    suite_id: Vec<u8>,
+    #[zeroize(skip)]
+    kdf: HpkeKdf,
+    #[zeroize(skip)]
+    aead: HpkeAead,
+    key: Vec<u8>,
+    base_nonce: [u8; NONCE_LEN],
+    exporter_secret: Vec<u8>,
+    seq: u64,
+}
+
+impl Drop for Context {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl Context {
+    // The nonce of the current sequence number.
+    fn nonce(&self) -> Result<[u8; NONCE_LEN], PrimitivesError> {
+        if self.seq == u64::MAX {
+            return Err(PrimitivesError::ParameterError(
+                "HPKE message limit reached".to_string(),
+            ));
+        }
+        let mut nonce = self.base_nonce;
+        for (n, s) in nonce[NONCE_LEN - 8..]
+            .iter_mut()
+            .zip(self.seq.to_be_bytes())
+        {
+            *n ^= s;
+        }
+        Ok(nonce)
+    }
+
+    // The nonce of the current sequence number, moving to the next one.
+    fn next_nonce(&mut self) -> Result<[u8; NONCE_LEN], PrimitivesError> {
+        let nonce = self.nonce()?;
+        self.seq += 1;
+        Ok(nonce)
+    }
+
+    fn export(&self, exporter_context: &[u8], len: usize) -> Result<Vec<u8>, PrimitivesError> {
+        self.kdf.labeled_expand(
+            &self.suite_id,
+            &self.exporter_secret,
+            b"sec",
+            exporter_context,
+            len,
+        )
+    }
+}
+
+impl HpkeKem {
+    fn id(&self) -> u16 {
+        match self {
+            HpkeKem::DhKemP256HkdfSha256 => 0x0010,
+            HpkeKem::DhKemX25519HkdfSha256 => 0x0020,
+        }
+    }
+
+    fn suite_id(&self) -> Vec<u8> {
+        [&b"KEM"[..], &self.id().to_be_bytes()].concat()
+    }
+
+    // `DeriveKeyPair`, returning the secret key only.
+    fn derive_secret_key(&self, ikm: &[u8]) -> Result<[u8; 32], PrimitivesError> {
+        let suite_id = self.suite_id();
+        let kdf = HpkeKdf::HkdfSha256;
+        let mut dkp_prk = kdf.labeled_extract(&suite_id, &[], b"dkp_prk", ikm);
+        let mut sk = [0u8; 32];
+        let result = match self {
+            HpkeKem::DhKemP256HkdfSha256 => {
+                // rejection sampling of a scalar in `[1,n-1]`
+                let mut found = false;
+                for counter in 0..=255u8 {
+                    let mut candidate =
+                        kdf.labeled_expand(&suite_id, &dkp_prk, b"candidate", &[counter], 32)?;
+                    sk.copy_from_slice(&candidate);
+                    candidate.zeroize();
+                    if p256::scalar(&sk).is_ok() {
+                        found = true;
+                        break;
+                    }
+                }
+                if found {
+                    Ok(sk)
+                } else {
+                    Err(PrimitivesError::InternalError(
+                        "failed to derive an HPKE key pair".to_string(),
+                    ))
+                }
+            },
+            HpkeKem::DhKemX25519HkdfSha256 => {
+                let mut bytes = kdf.labeled_expand(&suite_id, &dkp_prk, b"sk", &[], 32)?;
+                sk.copy_from_slice(&bytes);
+                bytes.zeroize();
+                Ok(sk)
+            },
+        };
+        dkp_prk.zeroize();
+        sk.zeroize();
+        result
+    }
+
+    fn public_key(&self, sk: &[u8; 32]) -> Result<Vec<u8>, PrimitivesError> {
+        match self {
+            HpkeKem::DhKemP256HkdfSha256 => p256::public_key(sk),
+            HpkeKem::DhKemX25519HkdfSha256 => Ok(x25519::public_key(sk)),
+        }
+    }
+
+    fn dh(&self, sk: &[u8; 32], pk: &[u8]) -> Result<Vec<u8>, PrimitivesError> {
+        match self {
+            HpkeKem::DhKemP256HkdfSha256 => p256::dh(sk, pk),
+            HpkeKem::DhKemX25519HkdfSha256 => x25519::dh(sk, pk),
+        }
+    }
+
+    fn extract_and_expand(
+        &self,
+        dh: &[u8],
+        kem_context: &[u8],
+    ) -> Result<Vec<u8>, PrimitivesError> {
+        let suite_id = self.suite_id();
+        let kdf = HpkeKdf::HkdfSha256;
+        let mut eae_prk = kdf.labeled_extract(&suite_id, &[], b"eae_prk", dh);
+        let shared_secret =
+            kdf.labeled_expand(&suite_id, &eae_prk, b"shared_secret", kem_context, 32);
+        eae_prk.zeroize();
+        shared_secret
+    }
+}
+
+impl HpkeKdf {
+    fn id(&self) -> u16 {
+        match self {
+            HpkeKdf::HkdfSha256 => 0x0001,
+            HpkeKdf::HkdfSha384 => 0x0002,
+            HpkeKdf::HkdfSha512 => 0x0003,
+        }
+    }
+
+    fn hash_len(&self) -> usize {
+        match self {
+            HpkeKdf::HkdfSha256 => 32,
+            HpkeKdf::HkdfSha384 => 48,
+            HpkeKdf::HkdfSha512 => 64,
+        }
+    }
+
+    fn labeled_extract(&self, suite_id: &[u8], salt: &[u8], label: &[u8], ikm: &[u8]) -> Vec<u8> {
+        let mut labeled_ikm = [HPKE_VERSION, suite_id, label, ikm].concat();
+        let prk = match self {
+            HpkeKdf::HkdfSha256 => hkdf_extract::<Sha256>(salt, &labeled_ikm),
+            HpkeKdf::HkdfSha384 => hkdf_extract::<Sha384>(salt, &labeled_ikm),
+            HpkeKdf::HkdfSha512 => hkdf_extract::<Sha512>(salt, &labeled_ikm),
+        };
+        labeled_ikm.zeroize();
+        prk
+    }
+
+    fn labeled_expand(
+        &self,
+        suite_id: &[u8],
+        prk: &[u8],
+        label: &[u8],
+        info: &[u8],
+        len: usize,
+    ) -> Result<Vec<u8>, PrimitivesError> {
+        let encoded_len = u16::try_from(len)
+            .map_err(|_| PrimitivesError::ParameterError("HPKE output too long".to_string()))?
+            .to_be_bytes();
+        let labeled_info = [&encoded_len[..], HPKE_VERSION, suite_id, label, info].concat();
+        match self {
+            HpkeKdf::HkdfSha256 => hkdf_expand::<Sha256>(prk, &labeled_info, len),
+            HpkeKdf::HkdfSha384 => hkdf_expand::<Sha384>(prk, &labeled_info, len),
+            HpkeKdf::HkdfSha512 => hkdf_expand::<Sha512>(prk, &labeled_info, len),
+        }
+    }
+}
+
+const HPKE_VERSION: &[u8] = b"HPKE-v1";
+
+fn hkdf_extract<H: Digest + BlockSizeUser + Clone>(salt: &[u8], ikm: &[u8]) -> Vec<u8> {
+    SimpleHkdf::<H>::extract(Some(salt), ikm).0.to_vec()
+}
+
+fn hkdf_expand<H: Digest + BlockSizeUser + Clone>(
+    prk: &[u8],
+    info: &[u8],
+    len: usize,
+) -> Result<Vec<u8>, PrimitivesError> {
+    let hkdf = SimpleHkdf::<H>::from_prk(prk)
+        .map_err(|e| PrimitivesError::InternalError(format!("{e:?}")))?;
+    let mut okm = vec![0u8; len];
+    hkdf.expand(info, &mut okm)
+        .map_err(|_| PrimitivesError::ParameterError("HPKE output too long".to_string()))?;
+    Ok(okm)
+}
+
+impl HpkeAead {
+    fn id(&self) -> u16 {
+        match self {
+            HpkeAead::Aes128Gcm => 0x0001,
+            HpkeAead::Aes256Gcm => 0x0002,
+            HpkeAead::ChaCha20Poly1305 => 0x0003,
+        }
+    }
+
+    fn key_len(&self) -> usize {
+        match self {
+            HpkeAead::Aes128Gcm => 16,
+            HpkeAead::Aes256Gcm | HpkeAead::ChaCha20Poly1305 => 32,
+        }
+    }
+
+    fn seal(
+        &self,
+        key: &[u8],
+        nonce: &[u8; NONCE_LEN],
+        aad: &[u8],
+        plaintext: &[u8],
+    ) -> Result<Vec<u8>, PrimitivesError> {
+        match self {
+            HpkeAead::Aes128Gcm => aead_seal::<Aes128Gcm>(key, nonce, aad, plaintext),
+            HpkeAead::Aes256Gcm => aead_seal::<Aes256Gcm>(key, nonce, aad, plaintext),
+            HpkeAead::ChaCha20Poly1305 => aead_seal::<ChaCha20Poly1305>(key, nonce, aad, plaintext),
+        }
+    }
+
+    fn open(
+        &self,
+        key: &[u8],
+        nonce: &[u8; NONCE_LEN],
+        aad: &[u8],
+        ciphertext: &[u8],
+    ) -> Result<Vec<u8>, PrimitivesError> {
+        match self {
+            HpkeAead::Aes128Gcm => aead_open::<Aes128Gcm>(key, nonce, aad, ciphertext),
+            HpkeAead::Aes256Gcm => aead_open::<Aes256Gcm>(key, nonce, aad, ciphertext),
+            HpkeAead::ChaCha20Poly1305 => {
+                aead_open::<ChaCha20Poly1305>(key, nonce, aad, ciphertext)
+            },
+        }
+    }
+}
+
+fn aead_seal<A: Aead + KeyInit>(
+    key: &[u8],
+    nonce: &[u8; NONCE_LEN],
+    aad: &[u8],
+    plaintext: &[u8],
+) -> Result<Vec<u8>, PrimitivesError> {
+    A::new_from_slice(key)
+        .map_err(|e| PrimitivesError::InternalError(format!("{e:?}")))?
+        .encrypt(
+            Nonce::<A>::from_slice(nonce),
+            Payload {
+                msg: plaintext,
+                aad,
+            },
+        )
+        .map_err(|e| PrimitivesError::InternalError(format!("{e:?}")))
+}
+
+fn aead_open<A: Aead + KeyInit>(
+    key: &[u8],
+    nonce: &[u8; NONCE_LEN],
+    aad: &[u8],
+    ciphertext: &[u8],
+) -> Result<Vec<u8>, PrimitivesError> {
+    A::new_from_slice(key)
+        .map_err(|e| PrimitivesError::InternalError(format!("{e:?}")))?
+        .decrypt(
+            Nonce::<A>::from_slice(nonce),
+            Payload {
+                msg: ciphertext,
+                aad,
+            },
+        )
+        .map_err(|e| PrimitivesError::FailedDecryption(format!("{e:?}")))
+}
+
+fn invalid_public_key() -> PrimitivesError {
+    PrimitivesError::ParameterError("invalid HPKE public key".to_string())
+}
+
+// DHKEM(P-256), with big-endian integers and uncompressed SEC1 points.
+mod p256 {
+    use super::*;
+    use ark_ec::{CurveGroup, Group};
+    use ark_ff::Zero;
+    use ark_secp256r1::{Affine, Fq, Fr, Projective};
+
+    // The scalar of the secret key `sk`, in `[1,n-1]`.
+    pub(super) fn scalar(sk: &[u8; 32]) -> Result<Fr, PrimitivesError> {
+        match int_from_bytes::<Fr>(sk) {
+            Some(x) if !x.is_zero() => Ok(x),
+            _ => Err(PrimitivesError::ParameterError(
+                "invalid HPKE secret key".to_string(),
+            )),
+        }
+    }
+
+    pub(super) fn public_key(sk: &[u8; 32]) -> Result<Vec<u8>, PrimitivesError> {
+        let x = scalar(sk)?;
+        let point = (Projective::generator() * x).into_affine();
+        let mut bytes = Vec::with_capacity(65);
+        bytes.push(4);
+        bytes.extend_from_slice(&bytes_from_int(&point.x));
+        bytes.extend_from_slice(&bytes_from_int(&point.y));
+        Ok(bytes)
+    }
+
+    // The x-coordinate of the product of the secret key `sk` by the point of
+    // the public key `pk`.
+    pub(super) fn dh(sk: &[u8; 32], pk: &[u8]) -> Result<Vec<u8>, PrimitivesError> {
+        let x = scalar(sk)?;
+        if pk.len() != 65 || pk[0] != 4 {
+            return Err(invalid_public_key());
+        }
+        let px = int_from_bytes::<Fq>(pk[1..33].try_into().unwrap()); // safe unwrap
+        let py = int_from_bytes::<Fq>(pk[33..].try_into().unwrap()); // safe unwrap
+        let point = match (px, py) {
+            (Some(px), Some(py)) => Affine::new_unchecked(px, py),
+            _ => return Err(invalid_public_key()),
+        };
+        // the curve has prime order, so that points on the curve are in the
+        // group, and their nonzero multiples are not the identity
+        if !point.is_on_curve() {
+            return Err(invalid_public_key());
+        }
+        let shared = (point * x).into_affine();
+        Ok(bytes_from_int(&shared.x).to_vec())
+    }
+}
+
+// DHKEM(X25519), with the encodings of RFC 7748.
+mod x25519 {
+    use super::*;
+    use curve25519_dalek::montgomery::MontgomeryPoint;
+
+    pub(super) fn public_key(sk: &[u8; 32]) -> Vec<u8> {
+        MontgomeryPoint::mul_base_clamped(*sk).to_bytes().to_vec()
+    }
+
+    pub(super) fn dh(sk: &[u8; 32], pk: &[u8]) -> Result<Vec<u8>, PrimitivesError> {
+        let pk: [u8; 32] = pk.try_into().map_err(|_| invalid_public_key())?;
+        let shared = MontgomeryPoint(pk).mul_clamped(*sk).to_bytes();
+        // reject public keys of small order, as required by the RFC
+        if shared == [0u8; 32] {
+            return Err(invalid_public_key());
+        }
+        Ok(shared.to_vec())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use jf_utils::test_rng;
+
+    fn from_hex(hex: &str) -> Vec<u8> {
+        (0..hex.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_hpke_vectors() {
+        struct Vector {
+            suite: HpkeCipherSuite,
+            ikm_e: &'static str,
+            ikm_r: &'static str,
+            pk_r: &'static str,
+            enc: &'static str,
+            cts: [&'static str; 2],
+            exports: [&'static str; 2],
+        }
+        // the base mode vectors of RFC 9180, Appendices A.1.1, A.2.1 and
+        // A.3.1, and a vector computed with an independent implementation
+        // for the last ciphersuite
+        let vectors = [
+            Vector {
+                suite: HpkeCipherSuite::new(
+                    HpkeKem::DhKemX25519HkdfSha256,
+                    HpkeKdf::HkdfSha256,
+                    HpkeAead::Aes128Gcm,
+                ),
+                ikm_e: "7268600d403fce431561aef583ee1613527cff655c1343f29812e66706df3234",
+                ikm_r: "6db9df30aa07dd42ee5e8181afdb977e538f5e1fec8a06223f33f7013e525037",
+                pk_r: "3948cfe0ad1ddb695d780e59077195da6c56506b027329794ab02bca80815c4d",
+                enc: "37fda3567bdbd628e88668c3c8d7e97d1d1253b6d4ea6d44c150f741f1bf4431",
+                cts: [
+                    "f938558b5d72f1a23810b4be2ab4f84331acc02fc97babc53a52ae8218a355a96d8770ac83d07bea87e13c512a",
+                    "af2d7e9ac9ae7e270f46ba1f975be53c09f8d875bdc8535458c2494e8a6eab251c03d0c22a56b8ca42c2063b84",
+                ],
+                exports: [
+                    "3853fe2b4035195a573ffc53856e77058e15d9ea064de3e59f4961d0095250ee",
+                    "e9e43065102c3836401bed8c3c3c75ae46be1639869391d62c61f1ec7af54931",
+                ],
+            },
+            Vector {
+                suite: HpkeCipherSuite::new(
+                    HpkeKem::DhKemX25519HkdfSha256,
+                    HpkeKdf::HkdfSha256,
+                    HpkeAead::ChaCha20Poly1305,
+                ),
+                ikm_e: "909a9b35d3dc4713a5e72a4da274b55d3d3821a37e5d099e74a647db583a904b",
+                ikm_r: "1ac01f181fdf9f352797655161c58b75c656a6cc2716dcb66372da835542e1df",
+                pk_r: "4310ee97d88cc1f088a5576c77ab0cf5c3ac797f3d95139c6c84b5429c59662a",
+                enc: "1afa08d3dec047a643885163f1180476fa7ddb54c6a8029ea33f95796bf2ac4a",
+                cts: [
+                    "1c5250d8034ec2b784ba2cfd69dbdb8af406cfe3ff938e131f0def8c8b60b4db21993c62ce81883d2dd1b51a28",
+                    "6b53c051e4199c518de79594e1c4ab18b96f081549d45ce015be002090bb119e85285337cc95ba5f59992dc98c",
+                ],
+                exports: [
+                    "4bbd6243b8bb54cec311fac9df81841b6fd61f56538a775e7c80a9f40160606e",
+                    "5acb09211139c43b3090489a9da433e8a30ee7188ba8b0a9a1ccf0c229283e53",
+                ],
+            },
+            Vector {
+                suite: HpkeCipherSuite::new(
+                    HpkeKem::DhKemP256HkdfSha256,
+                    HpkeKdf::HkdfSha256,
+                    HpkeAead::Aes128Gcm,
+                ),
+                ikm_e: "4270e54ffd08d79d5928020af4686d8f6b7d35dbe470265f1f5aa22816ce860e",
+                ikm_r: "668b37171f1072f3cf12ea8a236a45df23fc13b82af3609ad1e354f6ef817550",
+                pk_r: "04fe8c19ce0905191ebc298a9245792531f26f0cece2460639e8bc39cb7f706a826a779b4cf969b8a0e539c7f62fb3d30ad6aa8f80e30f1d128aafd68a2ce72ea0",
+                enc: "04a92719c6195d5085104f469a8b9814d5838ff72b60501e2c4466e5e67b325ac98536d7b61a1af4b78e5b7f951c0900be863c403ce65c9bfcb9382657222d18c4",
+                cts: [
+                    "5ad590bb8baa577f8619db35a36311226a896e7342a6d836d8b7bcd2f20b6c7f9076ac232e3ab2523f39513434",
+                    "fa6f037b47fc21826b610172ca9637e82d6e5801eb31cbd3748271affd4ecb06646e0329cbdf3c3cd655b28e82",
+                ],
+                exports: [
+                    "5e9bc3d236e1911d95e65b576a8a86d478fb827e8bdfe77b741b289890490d4d",
+                    "d8f1ea7942adbba7412c6d431c62d01371ea476b823eb697e1f6e6cae1dab85a",
+                ],
+            },
+            Vector {
+                suite: HpkeCipherSuite::new(
+                    HpkeKem::DhKemP256HkdfSha256,
+                    HpkeKdf::HkdfSha512,
+                    HpkeAead::Aes256Gcm,
+                ),
+                ikm_e: "4270e54ffd08d79d5928020af4686d8f6b7d35dbe470265f1f5aa22816ce860e",
+                ikm_r: "668b37171f1072f3cf12ea8a236a45df23fc13b82af3609ad1e354f6ef817550",
+                pk_r: "04fe8c19ce0905191ebc298a9245792531f26f0cece2460639e8bc39cb7f706a826a779b4cf969b8a0e539c7f62fb3d30ad6aa8f80e30f1d128aafd68a2ce72ea0",
+                enc: "04a92719c6195d5085104f469a8b9814d5838ff72b60501e2c4466e5e67b325ac98536d7b61a1af4b78e5b7f951c0900be863c403ce65c9bfcb9382657222d18c4",
+                cts: [
+                    "09abdf5a4a04ec8a8bb833d7c1b3615306ea505eea017029713e1966e57a37e17085a476f3d6fa8cb2e8ac0a6d",
+                    "6564f23aec28f69628a8bc33820a2a011a28653f10a21563185d1dfea39ae2507ecaa7d84f7f438cb97860ba0c",
+                ],
+                exports: [
+                    "f3394c149f47d149859df3dbf5362de1fd6d0108096bb2b029deaa4da15e9b5b",
+                    "b46631c0e767275f7099d6a104378376d92cea11da1c8b4a69ba0cf263be07a4",
+                ],
+            },
+        ];
+
+        let info = b"Ode on a Grecian Urn";
+        let plaintext = b"Beauty is truth, truth beauty";
+        for vector in vectors.iter() {
+            let suite = vector.suite;
+            let (sk_e, _) = suite.derive_key_pair(&from_hex(vector.ikm_e)).unwrap();
+            let (sk_r, pk_r) = suite.derive_key_pair(&from_hex(vector.ikm_r)).unwrap();
+            assert_eq!(pk_r.as_bytes(), from_hex(vector.pk_r));
+
+            let (enc, mut sender) = suite
+                .setup_base_sender_with_ephemeral_key(&pk_r, info, &sk_e)
+                .unwrap();
+            assert_eq!(enc, from_hex(vector.enc));
+            let mut receiver = suite.setup_base_receiver(&enc, &sk_r, info).unwrap();
+            for (seq, ct) in vector.cts.iter().enumerate() {
+                let aad = format!("Count-{seq}");
+                assert_eq!(
+                    sender.seal(aad.as_bytes(), plaintext).unwrap(),
+                    from_hex(ct)
+                );
+                assert_eq!(
+                    receiver.open(aad.as_bytes(), &from_hex(ct)).unwrap(),
+                    plaintext
+                );
+            }
+            for (exporter_context, export) in [&b""[..], b"TestContext"].iter().zip(vector.exports)
+            {
+                assert_eq!(
+                    sender.export(exporter_context, 32).unwrap(),
+                    from_hex(export)
+                );
+                assert_eq!(
+                    receiver.export(exporter_context, 32).unwrap(),
+                    from_hex(export)
+                );
+            }
+        }
+    }
+
+    fn seal_and_open(suite: HpkeCipherSuite) {
+        let rng = &mut test_rng();
+        let (sk_r, pk_r) = suite.generate_key_pair(rng);
+        let (other_sk, _) = suite.generate_key_pair(rng);
+        assert_eq!(suite.public_key(&sk_r).unwrap(), pk_r);
+        let info = b"info";
+        let aad = b"my associated data";
+        let msg = b"The quick brown fox jumps over the lazy dog";
+
+        // single message
+        let (enc, ct) = suite.seal(&pk_r, info, aad, msg, rng).unwrap();
+        assert_eq!(suite.open(&enc, &sk_r, info, aad, &ct).unwrap(), msg);
+        assert!(suite.open(&enc, &other_sk, info, aad, &ct).is_err());
+        assert!(suite.open(&enc, &sk_r, b"wrong info", aad, &ct).is_err());
+        assert!(suite.open(&enc, &sk_r, info, b"wrong aad", &ct).is_err());
+        let mut bad_ct = ct.clone();
+        bad_ct[0] ^= 1;
+        assert!(suite.open(&enc, &sk_r, info, aad, &bad_ct).is_err());
+
+        // messages are opened in order, and a failure does not advance the
+        // context of the receiver
+        let (enc, mut sender) = suite.setup_base_sender(&pk_r, info, rng).unwrap();
+        let mut receiver = suite.setup_base_receiver(&enc, &sk_r, info).unwrap();
+        let ct1 = sender.seal(aad, b"first").unwrap();
+        let ct2 = sender.seal(aad, b"second").unwrap();
+        assert!(receiver.open(aad, &ct2).is_err());
+        assert_eq!(receiver.open(aad, &ct1).unwrap(), b"first");
+        assert_eq!(receiver.open(aad, &ct2).unwrap(), b"second");
+        assert_eq!(
+            sender.export(b"context", 100).unwrap(),
+            receiver.export(b"context", 100).unwrap()
+        );
+        assert!(sender.export(b"context", 255 * 64 + 1).is_err());
+
+        // invalid keys
+        assert!(suite.derive_key_pair(&[0u8; 31]).is_err());
+        assert!(suite.setup_base_receiver(&enc[1..], &sk_r, info).is_err());
+        assert!(suite
+            .setup_base_sender(&HpkePublicKey::from_bytes(&enc[1..]), info, rng)
+            .is_err());
+    }
+
+    #[test]
+    fn test_hpke() {
+        for kem in [HpkeKem::DhKemP256HkdfSha256, HpkeKem::DhKemX25519HkdfSha256] {
+            for kdf in [
+                HpkeKdf::HkdfSha256,
+                HpkeKdf::HkdfSha384,
+                HpkeKdf::HkdfSha512,
+            ] {
+                for aead in [
+                    HpkeAead::Aes128Gcm,
+                    HpkeAead::Aes256Gcm,
+                    HpkeAead::ChaCha20Poly1305,
+                ] {
+                    seal_and_open(HpkeCipherSuite::new(kem, kdf, aead));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_hpke_invalid_keys() {
+        let rng = &mut test_rng();
+
+        // P-256 keys off the curve, and secret keys out of range
+        let suite = HpkeCipherSuite::new(
+            HpkeKem::DhKemP256HkdfSha256,
+            HpkeKdf::HkdfSha256,
+            HpkeAead::Aes128Gcm,
+        );
+        let (sk, pk) = suite.generate_key_pair(rng);
+        let mut off_curve = pk.as_bytes().to_vec();
+        off_curve[64] ^= 1;
+        assert!(suite
+            .setup_base_sender(&HpkePublicKey::from_bytes(&off_curve), b"", rng)
+            .is_err());
+        assert!(suite.setup_base_receiver(&off_curve, &sk, b"").is_err());
+        assert!(suite
+            .public_key(&HpkeSecretKey::from_bytes(&[0u8; 32]))
+            .is_err());
+        assert!(suite
+            .public_key(&HpkeSecretKey::from_bytes(&[0xff; 32]))
+            .is_err());
+
+        // X25519 public keys of small order
+        let suite = HpkeCipherSuite::new(
+            HpkeKem::DhKemX25519HkdfSha256,
+            HpkeKdf::HkdfSha256,
+            HpkeAead::ChaCha20Poly1305,
+        );
+        let (sk, _) = suite.generate_key_pair(rng);
+        let mut one = [0u8; 32];
+        one[0] = 1;
+        for small_order in [[0u8; 32], one] {
+            assert!(suite
+                .setup_base_sender(&HpkePublicKey::from_bytes(&small_order), b"", rng)
+                .is_err());
+            assert!(suite.setup_base_receiver(&small_order, &sk, b"").is_err());
+        }
+    }
+
+    #[test]
+    fn test_serde() {
+        let rng = &mut test_rng();
+        let suite = HpkeCipherSuite::new(
+            HpkeKem::DhKemX25519HkdfSha256,
+            HpkeKdf::HkdfSha256,
+            HpkeAead::ChaCha20Poly1305,
+        );
+        let (sk, pk) = suite.generate_key_pair(rng);
+        let bytes = bincode::serialize(&sk).unwrap();
+        assert_eq!(sk, bincode::deserialize(&bytes).unwrap());
+        let bytes = bincode::serialize(&pk).unwrap();
+        assert_eq!(pk, bincode::deserialize(&bytes).unwrap());
+        assert_eq!(HpkeSecretKey::from_bytes(&sk.to_bytes()), sk);
+        assert_eq!(HpkePublicKey::from_bytes(pk.as_bytes()), pk);
+    }
+}
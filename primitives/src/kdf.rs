@@ -0,0 +1,343 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+//! This module implements the HKDF key derivation function of RFC 5869 with
+//! SHA-256, and the derivation of independent keys from a master seed.
+//!
+//! [`extract`] and [`expand`] are the two steps of HKDF. A [`MasterKey`] is
+//! extracted once from a master seed, and every key is then expanded from it
+//! with a [`KdfLabel`], which names the purpose of the key, and an
+//! application context, which tells apart the keys of the same purpose. The
+//! HKDF info of a derivation is
+//!
+//! `CS_ID_HKDF || len(label) || label || context`
+//!
+//! with the length of the label on 2 big-endian bytes, so that distinct
+//! labels and contexts always yield independent keys. The AEAD, PRF and
+//! signing keys are sampled with a ChaCha20 RNG seeded by the derived bytes,
+//! as their key generation would from a random seed.
+//!
+//! ```
+//! use jf_primitives::{
+//!     kdf::{KdfLabel, MasterKey},
+//!     prf::{ChaCha20PRF, PRF},
+//! };
+//!
+//! let master = MasterKey::new(&[7u8; 32], None).unwrap();
+//! let aead_key = master.derive_aead_key_pair(b"alice");
+//! assert_eq!(aead_key, master.derive_aead_key_pair(b"alice"));
+//! assert_ne!(aead_key, master.derive_aead_key_pair(b"bob"));
+//!
+//! let seed = master.derive_prf_seed::<ChaCha20PRF<32>>(b"alice");
+//! let _output = ChaCha20PRF::<32>::evaluate(&seed, [0u8; 12]).unwrap();
+//!
+//! const SESSION: KdfLabel = KdfLabel::new("session-id");
+//! let _id: [u8; 16] = master.derive_bytes(SESSION, b"alice").unwrap();
+//! ```
+
+use crate::{
+    aead,
+    constants::CS_ID_HKDF,
+    errors::PrimitivesError::{self, ParameterError},
+    prf::PRF,
+    signatures::SignatureScheme,
+};
+use ark_ff::PrimeField;
+use ark_std::{format, rand::SeedableRng, string::ToString, vec, vec::Vec, UniformRand};
+use hkdf::SimpleHkdf;
+use rand_chacha::ChaCha20Rng;
+use sha2::Sha256;
+use zeroize::Zeroize;
+
+/// The minimal length in bytes of a master seed.
+pub const MIN_SEED_LEN: usize = 32;
+
+/// The purpose of a derived key, which separates its derivation from those of
+/// the keys of other purposes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct KdfLabel(&'static str);
+
+/// The pseudorandom key extracted from a master seed, from which all keys are
+/// derived.
+#[derive(Clone, Zeroize)]
+#[zeroize(drop)]
+pub struct MasterKey {
+    prk: [u8; 32],
+}
+
+impl KdfLabel {
+    /// The label of the keys derived by [`MasterKey::derive_aead_key_pair`].
+    pub const AEAD: Self = Self("jf-aead-key-pair");
+    /// The label of the seeds derived by [`MasterKey::derive_prf_seed`].
+    pub const PRF: Self = Self("jf-prf-seed");
+    /// The label of the keys derived by [`MasterKey::derive_signing_key`].
+    pub const SIGNING: Self = Self("jf-signing-key");
+
+    /// An application label. Labels starting with `jf-` are reserved.
+    pub const fn new(label: &'static str) -> Self {
+        Self(label)
+    }
+
+    /// The label as a string.
+    pub fn as_str(&self) -> &'static str {
+        self.0
+    }
+}
+
+/// HKDF-Extract: the pseudorandom key of the input keying material `ikm`,
+/// with an optional `salt`.
+pub fn extract(salt: Option<&[u8]>, ikm: &[u8]) -> [u8; 32] {
+    let mut prk = [0u8; 32];
+    prk.copy_from_slice(&SimpleHkdf::<Sha256>::extract(salt, ikm).0);
+    prk
+}
+
+/// HKDF-Expand: fill `okm` with the output keying material of the
+/// pseudorandom key `prk` for the info `info`.
+/// * `returns` - Err() if `okm` is longer than `255*32` bytes
+pub fn expand(prk: &[u8; 32], info: &[u8], okm: &mut [u8]) -> Result<(), PrimitivesError> {
+    // safe unwrap: `prk` has the length of a SHA-256 digest
+    SimpleHkdf::<Sha256>::from_prk(prk)
+        .unwrap()
+        .expand(info, okm)
+        .map_err(|_| ParameterError(format!("HKDF output of {} bytes", okm.len())))
+}
+
+impl MasterKey {
+    /// Extract the master key from a master seed, with an optional `salt`.
+    /// * `seed` - a secret of at least [`MIN_SEED_LEN`] bytes, with as much
+    ///   entropy
+    /// * `returns` - Err() if the seed is too short
+    pub fn new(seed: &[u8], salt: Option<&[u8]>) -> Result<Self, PrimitivesError> {
+        if seed.len() < MIN_SEED_LEN {
+            return Err(ParameterError(format!(
+                "master seed of {} bytes, at least {} required",
+                seed.len(),
+                MIN_SEED_LEN
+            )));
+        }
+        Ok(Self {
+            prk: extract(salt, seed),
+        })
+    }
+
+    /// Fill `okm` with the key of purpose `label` in the context `context`.
+    /// * `returns` - Err() if `okm` is longer than `255*32` bytes
+    pub fn derive(
+        &self,
+        label: KdfLabel,
+        context: &[u8],
+        okm: &mut [u8],
+    ) -> Result<(), PrimitivesError> {
+        expand(&self.prk, &derivation_info(label, context)?, okm)
+    }
+
+    /// The `N`-byte key of purpose `label` in the context `context`.
+    /// * `returns` - Err() if `N` is greater than `255*32`
+    pub fn derive_bytes<const N: usize>(
+        &self,
+        label: KdfLabel,
+        context: &[u8],
+    ) -> Result<[u8; N], PrimitivesError> {
+        let mut okm = [0u8; N];
+        self.derive(label, context, &mut okm)?;
+        Ok(okm)
+    }
+
+    /// The field element of purpose `label` in the context `context`, reduced
+    /// from 16 more bytes than the modulus so that its bias is negligible.
+    pub fn derive_field_element<F: PrimeField>(&self, label: KdfLabel, context: &[u8]) -> F {
+        let mut okm = vec![0u8; (F::MODULUS_BIT_SIZE as usize + 7) / 8 + 16];
+        // safe unwrap: `okm` is shorter than `255*32` bytes for any usual field
+        self.derive(label, context, &mut okm).unwrap();
+        let element = F::from_be_bytes_mod_order(&okm);
+        okm.zeroize();
+        element
+    }
+
+    /// A ChaCha20 RNG seeded with the key of purpose `label` in the context
+    /// `context`.
+    pub fn derive_rng(&self, label: KdfLabel, context: &[u8]) -> ChaCha20Rng {
+        // safe unwrap: 32 bytes is a valid HKDF output length
+        let mut seed = self.derive_bytes::<32>(label, context).unwrap();
+        let rng = ChaCha20Rng::from_seed(seed);
+        seed.zeroize();
+        rng
+    }
+
+    /// The AEAD key pair of the context `context`.
+    pub fn derive_aead_key_pair(&self, context: &[u8]) -> aead::KeyPair {
+        aead::KeyPair::generate(&mut self.derive_rng(KdfLabel::AEAD, context))
+    }
+
+    /// The seed of the PRF `P` in the context `context`. The context should
+    /// name the PRF, as all PRFs with the same seed type get the same seed for
+    /// the same context.
+    pub fn derive_prf_seed<P: PRF>(&self, context: &[u8]) -> P::Seed {
+        P::Seed::rand(&mut self.derive_rng(KdfLabel::PRF, context))
+    }
+
+    /// The key pair of the signature scheme `S` in the context `context`. The
+    /// context should name the scheme, as schemes with the same key
+    /// generation get the same keys for the same context.
+    /// * `returns` - Err() if the key generation of `S` fails
+    pub fn derive_signing_key<S: SignatureScheme>(
+        &self,
+        pp: &S::PublicParameter,
+        context: &[u8],
+    ) -> Result<(S::SigningKey, S::VerificationKey), PrimitivesError> {
+        S::key_gen(pp, &mut self.derive_rng(KdfLabel::SIGNING, context))
+    }
+}
+
+// The HKDF info of the key of purpose `label` in the context `context`.
+fn derivation_info(label: KdfLabel, context: &[u8]) -> Result<Vec<u8>, PrimitivesError> {
+    let label_len = u16::try_from(label.0.len())
+        .map_err(|_| ParameterError("label longer than 65535 bytes".to_string()))?;
+    Ok([
+        CS_ID_HKDF.as_bytes(),
+        &label_len.to_be_bytes()[..],
+        label.0.as_bytes(),
+        context,
+    ]
+    .concat())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        prf::{AesCtrPRF, ChaCha20PRF, RescuePRF},
+        signatures::{bls_over_bn254::BLSOverBN254CurveSignatureScheme, SchnorrSignatureScheme},
+    };
+    use ark_ed_on_bn254::EdwardsConfig as Param254;
+
+    fn from_hex(hex: &str) -> Vec<u8> {
+        (0..hex.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_hkdf_vectors() {
+        // test cases 1 and 3 of RFC 5869, Appendix A
+        let ikm = [0x0bu8; 22];
+        let salt: Vec<u8> = (0x00..=0x0c).collect();
+        let info: Vec<u8> = (0xf0..=0xf9).collect();
+        let prk = extract(Some(&salt), &ikm);
+        assert_eq!(
+            prk.to_vec(),
+            from_hex("077709362c2e32df0ddc3f0dc47bba6390b6c73bb50f9c3122ec844ad7c2b3e5")
+        );
+        let mut okm = [0u8; 42];
+        expand(&prk, &info, &mut okm).unwrap();
+        assert_eq!(
+            okm.to_vec(),
+            from_hex("3cb25f25faacd57a90434f64d0362f2a2d2d0a90cf1a5a4c5db02d56ecc4c5bf34007208d5b887185865")
+        );
+
+        let prk = extract(None, &ikm);
+        assert_eq!(prk, extract(Some(&[]), &ikm));
+        assert_eq!(
+            prk.to_vec(),
+            from_hex("19ef24a32c717b167f33a91d6f648bdf96596776afdb6377ac434c1c293ccb04")
+        );
+        expand(&prk, &[], &mut okm).unwrap();
+        assert_eq!(
+            okm.to_vec(),
+            from_hex("8da4e775a563c18f715f802a063c5a31b8a11f5c5ee1879ec3454e5f3c738d2d9d201395faa4b61a96c8")
+        );
+
+        let mut okm = vec![0u8; 255 * 32];
+        assert!(expand(&prk, &[], &mut okm).is_ok());
+        let mut okm = vec![0u8; 255 * 32 + 1];
+        assert!(expand(&prk, &[], &mut okm).is_err());
+    }
+
+    #[test]
+    fn test_key_derivation() {
+        assert!(MasterKey::new(&[1u8; MIN_SEED_LEN - 1], None).is_err());
+        let master = MasterKey::new(&[1u8; MIN_SEED_LEN], None).unwrap();
+        let salted = MasterKey::new(&[1u8; MIN_SEED_LEN], Some(b"salt")).unwrap();
+        let other = MasterKey::new(&[2u8; MIN_SEED_LEN], None).unwrap();
+        const LABEL: KdfLabel = KdfLabel::new("test");
+        assert_eq!(LABEL.as_str(), "test");
+
+        // keys depend on the seed, the salt, the label and the context
+        let key: [u8; 32] = master.derive_bytes(LABEL, b"ctx").unwrap();
+        assert_eq!(key, master.derive_bytes(LABEL, b"ctx").unwrap());
+        assert_ne!(key, salted.derive_bytes(LABEL, b"ctx").unwrap());
+        assert_ne!(key, other.derive_bytes(LABEL, b"ctx").unwrap());
+        assert_ne!(key, master.derive_bytes(LABEL, b"ctx2").unwrap());
+        assert_ne!(key, master.derive_bytes(KdfLabel::PRF, b"ctx").unwrap());
+        // the label and the context are not ambiguous
+        assert_ne!(
+            master
+                .derive_bytes::<32>(KdfLabel::new("ab"), b"c")
+                .unwrap(),
+            master
+                .derive_bytes::<32>(KdfLabel::new("a"), b"bc")
+                .unwrap()
+        );
+        // shorter keys are prefixes of longer ones
+        let short: [u8; 16] = master.derive_bytes(LABEL, b"ctx").unwrap();
+        assert_eq!(short[..], key[..16]);
+        assert!(master.derive_bytes::<{ 255 * 32 + 1 }>(LABEL, b"").is_err());
+
+        let element: ark_bn254::Fr = master.derive_field_element(LABEL, b"ctx");
+        assert_eq!(element, master.derive_field_element(LABEL, b"ctx"));
+        assert_ne!(element, master.derive_field_element(LABEL, b"ctx2"));
+
+        // AEAD keys
+        let key_pair = master.derive_aead_key_pair(b"ctx");
+        assert_eq!(key_pair, master.derive_aead_key_pair(b"ctx"));
+        assert_ne!(key_pair, master.derive_aead_key_pair(b"ctx2"));
+        let mut rng = jf_utils::test_rng();
+        let ctext = key_pair
+            .enc_key()
+            .encrypt(&mut rng, b"message", b"")
+            .unwrap();
+        assert_eq!(
+            master
+                .derive_aead_key_pair(b"ctx")
+                .decrypt(&ctext, b"")
+                .unwrap(),
+            b"message"
+        );
+
+        // PRF seeds
+        let seed = master.derive_prf_seed::<ChaCha20PRF<32>>(b"ctx");
+        assert_eq!(seed, master.derive_prf_seed::<AesCtrPRF<32>>(b"ctx"));
+        assert_ne!(seed, master.derive_prf_seed::<ChaCha20PRF<32>>(b"ctx2"));
+        let seed = master.derive_prf_seed::<RescuePRF<ark_bn254::Fq, 1, 1>>(b"ctx");
+        assert_eq!(
+            seed,
+            master.derive_prf_seed::<RescuePRF<ark_bn254::Fq, 1, 1>>(b"ctx")
+        );
+
+        // signing keys
+        let (sk, vk) = master
+            .derive_signing_key::<BLSOverBN254CurveSignatureScheme>(&(), b"bls")
+            .unwrap();
+        let (_, vk2) = master
+            .derive_signing_key::<BLSOverBN254CurveSignatureScheme>(&(), b"bls")
+            .unwrap();
+        assert_eq!(vk, vk2);
+        let (_, vk2) = master
+            .derive_signing_key::<BLSOverBN254CurveSignatureScheme>(&(), b"bls2")
+            .unwrap();
+        assert_ne!(vk, vk2);
+        let sig = BLSOverBN254CurveSignatureScheme::sign(&(), &sk, b"message", &mut rng).unwrap();
+        assert!(BLSOverBN254CurveSignatureScheme::verify(&(), &vk, b"message", &sig).is_ok());
+        let (sk, vk) = master
+            .derive_signing_key::<SchnorrSignatureScheme<Param254>>(&(), b"schnorr")
+            .unwrap();
+        let msg = [ark_ed_on_bn254::Fq::from(1u8)];
+        let sig = SchnorrSignatureScheme::<Param254>::sign(&(), &sk, msg, &mut rng).unwrap();
+        assert!(SchnorrSignatureScheme::<Param254>::verify(&(), &vk, msg, &sig).is_ok());
+    }
+}
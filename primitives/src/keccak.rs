@@ -0,0 +1,424 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+//! Native implementation of the Keccak-f[1600] permutation from
+//! [FIPS 202](https://nvlpubs.nist.gov/nistpubs/FIPS/NIST.FIPS.202.pdf), and
+//! of the byte-oriented sponge built on it.
+//!
+//! [`KeccakSponge`] is exposed through ark's [`CryptographicSponge`], and the
+//! fixed-output hashes [`Keccak256`] (as used by the EVM) and [`Sha3_256`]
+//! implement the [`digest`] traits, so they can replace the `sha3` crate
+//! wherever a [`Digest`](digest::Digest) is expected. [`Keccak256CRHF`]
+//! implements [`CRHF`].
+
+use crate::{crhf::CRHF, errors::PrimitivesError};
+use ark_crypto_primitives::sponge::{Absorb, CryptographicSponge};
+use ark_std::{borrow::Borrow, format, vec, vec::Vec};
+use digest::{
+    consts::{U136, U32},
+    core_api::BlockSizeUser,
+    generic_array::GenericArray,
+    FixedOutput, FixedOutputReset, HashMarker, OutputSizeUser, Reset, Update,
+};
+
+/// The # of 64-bit lanes of the Keccak-f[1600] state.
+pub const STATE_LANES: usize = 25;
+/// The # of bytes of the Keccak-f[1600] state.
+pub const STATE_BYTES: usize = 8 * STATE_LANES;
+/// The # of rounds of Keccak-f[1600].
+pub const ROUNDS: usize = 24;
+/// The rate in bytes of Keccak256 and SHA3-256.
+pub const RATE_256: usize = 136;
+/// The padding delimiter of the original Keccak submission, used by the EVM.
+pub const KECCAK_DELIMITER: u8 = 0x01;
+/// The padding delimiter of the SHA-3 hash functions.
+pub const SHA3_DELIMITER: u8 = 0x06;
+
+/// Round constants of the iota step.
+const ROUND_CONSTANTS: [u64; ROUNDS] = [
+    0x0000000000000001,
+    0x0000000000008082,
+    0x800000000000808a,
+    0x8000000080008000,
+    0x000000000000808b,
+    0x0000000080000001,
+    0x8000000080008081,
+    0x8000000000008009,
+    0x000000000000008a,
+    0x0000000000000088,
+    0x0000000080008009,
+    0x000000008000000a,
+    0x000000008000808b,
+    0x800000000000008b,
+    0x8000000000008089,
+    0x8000000000008003,
+    0x8000000000008002,
+    0x8000000000000080,
+    0x000000000000800a,
+    0x800000008000000a,
+    0x8000000080008081,
+    0x8000000000008080,
+    0x0000000080000001,
+    0x8000000080008008,
+];
+
+/// Rotation offsets of the rho step, for lane `x + 5 * y`.
+const ROTATION_OFFSETS: [u32; STATE_LANES] = [
+    0, 1, 62, 28, 27, 36, 44, 6, 55, 20, 3, 10, 43, 25, 39, 41, 45, 15, 21, 8, 18, 2, 61, 56, 14,
+];
+
+/// Apply the Keccak-f[1600] permutation to `state`, where lane `(x, y)` is
+/// `state[x + 5 * y]`.
+pub fn keccak_f1600(state: &mut [u64; STATE_LANES]) {
+    for round_constant in ROUND_CONSTANTS.iter() {
+        // theta
+        let mut columns = [0u64; 5];
+        for (i, lane) in state.iter().enumerate() {
+            columns[i % 5] ^= lane;
+        }
+        for (i, lane) in state.iter_mut().enumerate() {
+            let x = i % 5;
+            *lane ^= columns[(x + 4) % 5] ^ columns[(x + 1) % 5].rotate_left(1);
+        }
+
+        // rho and pi
+        let mut permuted = [0u64; STATE_LANES];
+        for (i, lane) in state.iter().enumerate() {
+            let (x, y) = (i % 5, i / 5);
+            permuted[y + 5 * ((2 * x + 3 * y) % 5)] = lane.rotate_left(ROTATION_OFFSETS[i]);
+        }
+
+        // chi
+        for (i, lane) in state.iter_mut().enumerate() {
+            let (x, row) = (i % 5, i - i % 5);
+            *lane = permuted[i] ^ (!permuted[row + (x + 1) % 5] & permuted[row + (x + 2) % 5]);
+        }
+
+        // iota
+        state[0] ^= round_constant;
+    }
+}
+
+/// Where the next byte of a [`KeccakSponge`] is absorbed or squeezed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum SpongeMode {
+    Absorbing(usize),
+    Squeezing(usize),
+}
+
+/// The Keccak sponge over bytes, with a rate of `rate` bytes and the padding
+/// `delimiter || 0* || 0x80` of FIPS 202.
+///
+/// Absorbing the concatenation of several inputs in separate calls is the
+/// same as absorbing it at once, and so is squeezing. Absorbing after a
+/// squeeze keeps the sponge going from the current state, which FIPS 202 does
+/// not define: [`Keccak256`] and [`Sha3_256`] never do so, as finalizing them
+/// consumes or resets their sponge.
+#[derive(Clone, Debug)]
+pub struct KeccakSponge {
+    state: [u64; STATE_LANES],
+    rate: usize,
+    delimiter: u8,
+    mode: SpongeMode,
+}
+
+impl Default for KeccakSponge {
+    /// The sponge of [`Keccak256`].
+    fn default() -> Self {
+        Self {
+            state: [0; STATE_LANES],
+            rate: RATE_256,
+            delimiter: KECCAK_DELIMITER,
+            mode: SpongeMode::Absorbing(0),
+        }
+    }
+}
+
+impl KeccakSponge {
+    /// Create an empty sponge.
+    /// * `rate` - in bytes, a non-zero multiple of 8 lower than `STATE_BYTES`
+    /// * `delimiter` - the domain bits of the padding, e.g. `KECCAK_DELIMITER`
+    ///   or `SHA3_DELIMITER`
+    /// * `returns` - Err() if `rate` is invalid
+    pub fn new(rate: usize, delimiter: u8) -> Result<Self, PrimitivesError> {
+        if rate == 0 || rate >= STATE_BYTES || rate % 8 != 0 {
+            return Err(PrimitivesError::ParameterError(format!(
+                "Keccak sponge Error : rate {} is not a non-zero multiple of 8 lower than {}.",
+                rate, STATE_BYTES
+            )));
+        }
+        Ok(Self {
+            rate,
+            delimiter,
+            ..Self::default()
+        })
+    }
+
+    /// Return the rate in bytes.
+    pub fn rate(&self) -> usize {
+        self.rate
+    }
+
+    /// Absorb `bytes` into the sponge. The permutation is applied every time
+    /// the rate is filled.
+    pub fn absorb_bytes(&mut self, bytes: &[u8]) {
+        let mut next = match self.mode {
+            SpongeMode::Absorbing(next) => next,
+            SpongeMode::Squeezing(_) => {
+                keccak_f1600(&mut self.state);
+                0
+            },
+        };
+        for byte in bytes {
+            self.xor_byte(next, *byte);
+            next += 1;
+            if next == self.rate {
+                keccak_f1600(&mut self.state);
+                next = 0;
+            }
+        }
+        self.mode = SpongeMode::Absorbing(next);
+    }
+
+    /// Fill `output` with bytes squeezed from the sponge. The absorbed input
+    /// is padded first.
+    pub fn squeeze_into(&mut self, output: &mut [u8]) {
+        let mut next = match self.mode {
+            SpongeMode::Absorbing(next) => {
+                self.xor_byte(next, self.delimiter);
+                self.xor_byte(self.rate - 1, 0x80);
+                keccak_f1600(&mut self.state);
+                0
+            },
+            SpongeMode::Squeezing(next) => next,
+        };
+        for byte in output.iter_mut() {
+            if next == self.rate {
+                keccak_f1600(&mut self.state);
+                next = 0;
+            }
+            *byte = self.state[next / 8].to_le_bytes()[next % 8];
+            next += 1;
+        }
+        self.mode = SpongeMode::Squeezing(next);
+    }
+
+    fn xor_byte(&mut self, pos: usize, byte: u8) {
+        self.state[pos / 8] ^= (byte as u64) << (8 * (pos % 8));
+    }
+}
+
+impl CryptographicSponge for KeccakSponge {
+    /// The sponge is always the one of [`Keccak256`], use
+    /// [`KeccakSponge::new`] for other parameters.
+    type Config = ();
+
+    fn new(_config: &Self::Config) -> Self {
+        Self::default()
+    }
+
+    fn absorb(&mut self, input: &impl Absorb) {
+        self.absorb_bytes(&input.to_sponge_bytes_as_vec());
+    }
+
+    fn squeeze_bytes(&mut self, num_bytes: usize) -> Vec<u8> {
+        let mut output = vec![0u8; num_bytes];
+        self.squeeze_into(&mut output);
+        output
+    }
+
+    /// Bits are squeezed in little-endian order within each byte.
+    fn squeeze_bits(&mut self, num_bits: usize) -> Vec<bool> {
+        self.squeeze_bytes((num_bits + 7) / 8)
+            .iter()
+            .flat_map(|byte| (0..8).map(move |i| (byte >> i) & 1 == 1))
+            .take(num_bits)
+            .collect()
+    }
+}
+
+macro_rules! impl_keccak_hash {
+    ($name:ident, $delimiter:expr, $doc:expr) => {
+        #[doc = $doc]
+        #[derive(Clone, Debug)]
+        pub struct $name {
+            sponge: KeccakSponge,
+        }
+
+        impl Default for $name {
+            fn default() -> Self {
+                Self {
+                    sponge: KeccakSponge {
+                        delimiter: $delimiter,
+                        ..KeccakSponge::default()
+                    },
+                }
+            }
+        }
+
+        impl HashMarker for $name {}
+
+        impl OutputSizeUser for $name {
+            type OutputSize = U32;
+        }
+
+        impl BlockSizeUser for $name {
+            type BlockSize = U136;
+        }
+
+        impl Update for $name {
+            fn update(&mut self, data: &[u8]) {
+                self.sponge.absorb_bytes(data);
+            }
+        }
+
+        impl FixedOutput for $name {
+            fn finalize_into(mut self, out: &mut GenericArray<u8, U32>) {
+                self.sponge.squeeze_into(out);
+            }
+        }
+
+        impl Reset for $name {
+            fn reset(&mut self) {
+                *self = Self::default();
+            }
+        }
+
+        impl FixedOutputReset for $name {
+            fn finalize_into_reset(&mut self, out: &mut GenericArray<u8, U32>) {
+                self.sponge.squeeze_into(out);
+                self.reset();
+            }
+        }
+    };
+}
+
+impl_keccak_hash!(
+    Keccak256,
+    KECCAK_DELIMITER,
+    "The Keccak256 hash function of the EVM."
+);
+impl_keccak_hash!(Sha3_256, SHA3_DELIMITER, "The SHA3-256 hash function.");
+
+/// Compute the Keccak256 hash of `data`.
+pub fn keccak256(data: &[u8]) -> [u8; 32] {
+    let mut output = [0u8; 32];
+    let mut sponge = KeccakSponge::default();
+    sponge.absorb_bytes(data);
+    sponge.squeeze_into(&mut output);
+    output
+}
+
+#[derive(Debug, Clone)]
+/// A CRHF on byte strings with Keccak256.
+pub struct Keccak256CRHF;
+
+impl CRHF for Keccak256CRHF {
+    type Input = Vec<u8>;
+    type Output = [u8; 32];
+
+    fn evaluate<T: Borrow<Self::Input>>(input: T) -> Result<Self::Output, PrimitivesError> {
+        Ok(keccak256(input.borrow()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use ark_std::rand::RngCore;
+    use digest::Digest;
+
+    #[test]
+    fn test_keccak_vectors() {
+        assert_eq!(
+            keccak256(b""),
+            hex_literal("c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470")
+        );
+        assert_eq!(
+            keccak256(b"abc"),
+            hex_literal("4e03657aea45a94fc7d47ba826c8d667c0d1e6e33a64a036ec44f58fa12d6c45")
+        );
+        assert_eq!(
+            <[u8; 32]>::from(Sha3_256::digest(b"abc")),
+            hex_literal("3a985da74fe225b2045c172d6bd390bd855f086e3e9d525b46bfe24511431532")
+        );
+        assert_eq!(
+            Keccak256CRHF::evaluate(b"abc".to_vec()).unwrap(),
+            keccak256(b"abc")
+        );
+    }
+
+    fn hex_literal(s: &str) -> [u8; 32] {
+        let mut output = [0u8; 32];
+        for (i, byte) in output.iter_mut().enumerate() {
+            *byte = u8::from_str_radix(&s[2 * i..2 * i + 2], 16).unwrap();
+        }
+        output
+    }
+
+    #[test]
+    fn test_keccak_against_sha3() {
+        let rng = &mut jf_utils::test_rng();
+        for len in [0, 1, 135, 136, 137, 272, 500] {
+            let mut data = vec![0u8; len];
+            rng.fill_bytes(&mut data);
+            assert_eq!(
+                Keccak256::digest(&data)[..],
+                sha3::Keccak256::digest(&data)[..]
+            );
+            assert_eq!(
+                Sha3_256::digest(&data)[..],
+                sha3::Sha3_256::digest(&data)[..]
+            );
+
+            // incremental updates and a reset do not change the digest
+            let mut hasher = Keccak256::new();
+            Digest::update(&mut hasher, b"garbage");
+            Digest::reset(&mut hasher);
+            data.chunks(50)
+                .for_each(|chunk| Digest::update(&mut hasher, chunk));
+            assert_eq!(hasher.finalize()[..], sha3::Keccak256::digest(&data)[..]);
+        }
+    }
+
+    #[test]
+    fn test_keccak_sponge() {
+        assert!(KeccakSponge::new(0, KECCAK_DELIMITER).is_err());
+        assert!(KeccakSponge::new(135, KECCAK_DELIMITER).is_err());
+        assert!(KeccakSponge::new(STATE_BYTES, KECCAK_DELIMITER).is_err());
+
+        // SHAKE128 has a rate of 168 bytes and an extendable output
+        let mut sponge = KeccakSponge::new(168, 0x1f).unwrap();
+        assert_eq!(sponge.rate(), 168);
+        sponge.absorb_bytes(b"jellyfish");
+        let output = sponge.squeeze_bytes(400);
+        let mut expected = vec![0u8; 400];
+        sha3::digest::ExtendableOutput::finalize_xof_into(
+            sha3::digest::Update::chain(sha3::Shake128::default(), b"jellyfish"),
+            &mut expected,
+        );
+        assert_eq!(output, expected);
+
+        // squeezing in several calls is the same as at once
+        let mut sponge = KeccakSponge::new(168, 0x1f).unwrap();
+        sponge.absorb_bytes(b"jelly");
+        sponge.absorb_bytes(b"fish");
+        let mut split = sponge.squeeze_bytes(200);
+        split.extend(sponge.squeeze_bytes(200));
+        assert_eq!(split, expected);
+
+        // the ark sponge is the Keccak256 one
+        let mut sponge = <KeccakSponge as CryptographicSponge>::new(&());
+        sponge.absorb(&b"abc".to_vec());
+        let bits = sponge.squeeze_bits(10);
+        let byte = keccak256(b"abc")[0];
+        assert_eq!(
+            bits[..8],
+            (0..8).map(|i| (byte >> i) & 1 == 1).collect::<Vec<_>>()
+        );
+        assert_eq!(bits.len(), 10);
+    }
+}
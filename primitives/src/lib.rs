@@ -21,22 +21,40 @@ extern crate derivative;
 extern crate alloc;
 
 pub mod aead;
+pub mod anemoi;
 pub mod circuit;
 pub mod commitment;
 pub mod constants;
 pub mod crhf;
+pub mod dkg;
 pub mod elgamal;
 pub mod errors;
+pub mod griffin;
+pub mod hash_to_field;
 pub mod hash_to_group;
+pub mod hpke;
+pub mod kdf;
+pub mod keccak;
 pub mod merkle_tree;
+pub mod monolith;
 pub mod pcs;
+pub mod poseidon;
+pub mod poseidon2;
 pub mod prf;
 pub mod reed_solomon_code;
 pub mod rescue;
+pub mod safe;
+pub mod shamir;
+pub mod sigma;
 pub mod signatures;
+pub mod sponge;
+pub mod stealth;
+pub mod timelock;
+pub mod tip5;
 pub mod toeplitz;
 pub mod vdf;
 pub mod vid;
 pub mod vrf;
+pub mod vss;
 
 pub(crate) mod utils;
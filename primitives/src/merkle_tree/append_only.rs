@@ -7,26 +7,65 @@
 //! Implementation of a typical append only merkle tree
 
 use super::{
+    consistency::{MerkleAppendProof, MerkleConsistencyProof},
     internal::{
-        build_tree_internal, MerkleNode, MerkleProof, MerkleTreeCommitment, MerkleTreeIntoIter,
-        MerkleTreeIter,
+        build_tree_internal, digest_branch, MerkleBatchProof, MerkleNode, MerklePath, MerkleProof,
+        MerkleProofIter, MerkleTreeCommitment, MerkleTreeIntoIter, MerkleTreeIter,
     },
+    store::{missing_data, read_node, read_path, write_path, ElemCache, MemoryStore, MerkleStore},
     AppendableMerkleTreeScheme, DigestAlgorithm, Element, ForgetableMerkleTreeScheme, Index,
-    LookupResult, MerkleCommitment, MerkleTreeScheme, NodeValue, ToTraversalPath,
+    LookupResult, MaybeSendSync, MerkleCommitment, MerkleTreeScheme, MissingSubtree, NodeValue,
+    ToTraversalPath,
 };
 use crate::{
     errors::{PrimitivesError, VerificationResult},
     impl_forgetable_merkle_tree_scheme, impl_merkle_tree_scheme,
 };
+use alloc::sync::Arc;
 use ark_std::{
     borrow::Borrow, boxed::Box, fmt::Debug, marker::PhantomData, string::ToString, vec, vec::Vec,
 };
 use num_bigint::BigUint;
-use num_traits::pow::pow;
+use num_traits::{pow::pow, ToPrimitive};
 use serde::{Deserialize, Serialize};
 use typenum::Unsigned;
 
-impl_merkle_tree_scheme!(MerkleTree);
+/// A standard append only Merkle tree implementation.
+///
+/// The whole tree is kept in memory with the default [`MemoryStore`]. With
+/// another [`MerkleStore`], its nodes and elements are written to the store
+/// and only its frontier is kept in memory, see the
+/// [store module](super::store).
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(bound(
+    serialize = "E: ark_serialize::CanonicalSerialize + ark_serialize::CanonicalDeserialize,
+                 I: ark_serialize::CanonicalSerialize + ark_serialize::CanonicalDeserialize,",
+    deserialize = "E: ark_serialize::CanonicalSerialize + ark_serialize::CanonicalDeserialize,
+                   I: ark_serialize::CanonicalSerialize + ark_serialize::CanonicalDeserialize,
+                   S: Default,"
+))]
+pub struct MerkleTree<E, H, I, Arity, T, S = MemoryStore>
+where
+    E: Element,
+    H: DigestAlgorithm<E, I, T>,
+    I: Index,
+    Arity: Unsigned,
+    T: NodeValue,
+{
+    root: Arc<MerkleNode<E, I, T>>,
+    height: usize,
+    num_leaves: u64,
+    #[serde(skip)]
+    store: S,
+    // Elements read from the store by lookups
+    #[serde(skip)]
+    elems: ElemCache<E>,
+
+    _phantom: PhantomData<(H, Arity)>,
+}
+
+impl_merkle_tree_scheme!(@inherent MerkleTree);
+impl_merkle_tree_scheme!(@into_iter MerkleTree);
 impl_forgetable_merkle_tree_scheme!(MerkleTree);
 
 impl<E, H, I, Arity, T> MerkleTree<E, H, I, Arity, T>
@@ -37,15 +76,38 @@ where
     Arity: Unsigned,
     T: NodeValue,
 {
-    /// Initialize an empty Merkle tree.
-    pub fn new(height: usize) -> Self {
+    fn from_root(root: Arc<MerkleNode<E, I, T>>, height: usize, num_leaves: u64) -> Self {
         Self {
-            root: Box::new(MerkleNode::<E, I, T>::Empty),
+            root,
             height,
-            num_leaves: 0,
+            num_leaves,
+            store: MemoryStore,
+            elems: ElemCache::default(),
             _phantom: PhantomData,
         }
     }
+
+    /// Initialize an empty Merkle tree.
+    pub fn new(height: usize) -> Self {
+        Self::from_root(Arc::new(MerkleNode::<E, I, T>::Empty), height, 0)
+    }
+
+    /// Increase the height of this tree, keeping its leaves at the same
+    /// positions. The new root is the digest of the old one with empty
+    /// siblings, so the tree is the one built at the new height.
+    /// * `height` - the new height, at least the current one
+    /// * `returns` - Ok(()) if successful, Err() if `height` is smaller than
+    ///   the current height
+    pub fn increase_height(&mut self, height: usize) -> Result<(), PrimitivesError> {
+        if height < self.height {
+            return Err(PrimitivesError::ParameterError(
+                "Cannot decrease the height of a merkle tree".to_string(),
+            ));
+        }
+        Arc::make_mut(&mut self.root).grow_internal::<H, Arity>(height - self.height)?;
+        self.height = height;
+        Ok(())
+    }
 }
 
 impl<E, H, Arity, T> MerkleTree<E, H, u64, Arity, T>
@@ -63,37 +125,494 @@ where
     pub fn from_elems(
         height: Option<usize>,
         elems: impl IntoIterator<Item = impl Borrow<E>>,
-    ) -> Result<Self, PrimitivesError> {
+    ) -> Result<Self, PrimitivesError>
+    where
+        E: MaybeSendSync,
+        T: MaybeSendSync,
+    {
         let (root, height, num_leaves) = build_tree_internal::<E, H, Arity, T>(height, elems)?;
-        Ok(Self {
-            root,
+        Ok(Self::from_root(root, height, num_leaves))
+    }
+
+    /// Merge two trees of the same height into a tree one level taller, whose
+    /// first child is `left` and second child is `right`, the other children
+    /// being empty. The digests of `left` are reused, including its forgotten
+    /// subtrees. Since leaf digests bind their position, those of `right`,
+    /// whose leaves move by the capacity of `left`, are recomputed.
+    /// * `left` - a full tree
+    /// * `right` - a tree of the same height, fully in memory
+    /// * `returns` - the merged tree, or Err() if `left` is not full, if the
+    ///   heights differ, or if parts of `right` are forgotten
+    pub fn merge(left: Self, right: Self) -> Result<Self, PrimitivesError> {
+        if left.height != right.height {
+            return Err(PrimitivesError::ParameterError(
+                "Merkle trees of different heights".to_string(),
+            ));
+        }
+        let capacity = num_traits::checked_pow(Arity::to_u64(), left.height + 1)
+            .map(|capacity| capacity / Arity::to_u64())
+            .ok_or_else(|| {
+                PrimitivesError::ParameterError("Merkle tree size too large.".to_string())
+            })?;
+        if Arity::to_usize() < 2 || left.num_leaves != capacity {
+            return Err(PrimitivesError::ParameterError(
+                "Only a full tree can be merged with another one".to_string(),
+            ));
+        }
+        let mut children = vec![Arc::new(MerkleNode::Empty); Arity::to_usize()];
+        children[0] = left.root;
+        children[1] = right.root.shift_internal::<H>(capacity)?;
+        Ok(Self::from_root(
+            Arc::new(MerkleNode::Branch {
+                value: digest_branch::<E, H, u64, T>(&children)?,
+                children,
+            }),
+            left.height + 1,
+            capacity + right.num_leaves,
+        ))
+    }
+
+    /// Return the digest of the `index`-th sub-tree of the given height, or
+    /// `None` if it is not in memory.
+    pub(crate) fn subtree_digest(&self, height: usize, index: u64) -> Option<T> {
+        let traversal_path = ToTraversalPath::<Arity>::to_traversal_path(
+            &(index * pow(Arity::to_u64(), height)),
+            self.height,
+        );
+        self.root
+            .subtree_value(self.height, self.height - height, &traversal_path)
+    }
+
+    /// Forget the whole `index`-th sub-tree of the given height.
+    /// * `returns` - the digest of the forgotten sub-tree, `NotFound` if it is
+    ///   empty and `NotInMemory` if it was already forgotten
+    pub(crate) fn forget_subtree(&mut self, height: usize, index: u64) -> LookupResult<T, (), ()> {
+        let traversal_path = ToTraversalPath::<Arity>::to_traversal_path(
+            &(index * pow(Arity::to_u64(), height)),
+            self.height,
+        );
+        Arc::make_mut(&mut self.root).forget_subtree_internal(
+            self.height,
+            self.height - height,
+            &traversal_path,
+        )
+    }
+
+    /// Prove that this tree is an append-only extension of its first
+    /// `old_num_leaves` leaves, see [`MerkleConsistencyProof`].
+    pub fn consistency_proof(
+        &self,
+        old_num_leaves: u64,
+    ) -> Result<MerkleConsistencyProof<E, T, Arity>, PrimitivesError> {
+        MerkleConsistencyProof::generate(self, old_num_leaves)
+    }
+
+    /// Prove the insertions which will be performed next on this tree, see
+    /// [`MerkleAppendProof`].
+    pub fn append_proof(&self) -> Result<MerkleAppendProof<E, T, Arity>, PrimitivesError> {
+        MerkleAppendProof::generate(self)
+    }
+}
+
+impl<E, H, I, Arity, T, S> MerkleTree<E, H, I, Arity, T, S>
+where
+    E: Element,
+    H: DigestAlgorithm<E, I, T>,
+    I: Index,
+    Arity: Unsigned,
+    T: NodeValue,
+    S: MerkleStore<E, T>,
+{
+    /// Initialize an empty Merkle tree of the given height on top of an empty
+    /// store.
+    pub fn with_store(store: S, height: usize) -> Self {
+        Self {
+            root: Arc::new(MerkleNode::Empty),
             height,
-            num_leaves,
+            num_leaves: 0,
+            store,
+            elems: ElemCache::default(),
             _phantom: PhantomData,
-        })
+        }
+    }
+
+    /// Return the underlying store.
+    pub fn store(&self) -> &S {
+        &self.store
+    }
+
+    /// Consume the tree and return the underlying store.
+    pub fn into_store(self) -> S {
+        self.store
+    }
+
+    /// Drop from memory the elements read from the store by lookups.
+    pub fn evict(&mut self) {
+        self.elems.clear();
+        self.elems.reserve(self.num_leaves);
+    }
+}
+
+impl<E, H, I, Arity, T, S> MerkleTree<E, H, I, Arity, T, S>
+where
+    E: Element,
+    H: DigestAlgorithm<E, I, T>,
+    I: Index + ToTraversalPath<Arity>,
+    Arity: Unsigned,
+    T: NodeValue,
+    S: MerkleStore<E, T>,
+{
+    // Read from the store the element at the given position, which is not in
+    // memory, with its membership proof.
+    fn lookup_stored(
+        &self,
+        pos: &I,
+        traversal_path: &[usize],
+    ) -> Result<(&E, MerkleProof<E, I, T, Arity>), PrimitivesError> {
+        let index = traversal_path
+            .iter()
+            .rev()
+            .try_fold(0u64, |index, branch| {
+                index
+                    .checked_mul(Arity::to_u64())?
+                    .checked_add(*branch as u64)
+            })
+            .filter(|index| *index < self.num_leaves)
+            .ok_or_else(missing_data)?;
+        let elem = self.elems.get_or_try_init(index, || {
+            self.store.get_elem(index)?.ok_or_else(missing_data)
+        })?;
+        let proof = read_path::<E, I, T, Arity, S>(
+            &self.store,
+            self.height,
+            self.num_leaves,
+            pos.clone(),
+            index,
+            elem.clone(),
+        )?;
+        Ok((elem, MerkleProof::new(pos.clone(), proof)))
+    }
+}
+
+impl<E, H, Arity, T, S> MerkleTree<E, H, u64, Arity, T, S>
+where
+    E: Element,
+    H: DigestAlgorithm<E, u64, T>,
+    Arity: Unsigned,
+    T: NodeValue,
+    S: MerkleStore<E, T>,
+{
+    /// Reopen a tree from a store holding its content.
+    /// * `commitment` - commitment of the stored tree, which is checked against
+    ///   the content of the store
+    /// * `returns` - the reopened tree, or `Err()` if the store doesn't match
+    ///   the commitment
+    pub fn open(store: S, commitment: &MerkleTreeCommitment<T>) -> Result<Self, PrimitivesError> {
+        let mismatch = || {
+            PrimitivesError::ParameterError(
+                "Store content doesn't match the commitment".to_string(),
+            )
+        };
+        let mut mt = Self::with_store(store, commitment.height());
+        if commitment.size() == 0 {
+            if commitment.digest() != T::default() {
+                return Err(mismatch());
+            }
+            return Ok(mt);
+        }
+        if read_node(&mt.store, mt.height, 0)? != commitment.digest() {
+            return Err(mismatch());
+        }
+        let (root, _) = mt.read_frontier(commitment.size())?;
+        if root.value() != commitment.digest() {
+            return Err(mismatch());
+        }
+        mt.root = Arc::new(root);
+        mt.num_leaves = commitment.size();
+        mt.elems.reserve(mt.num_leaves);
+        Ok(mt)
+    }
+
+    // Rebuild from the store the frontier of the tree made of its first
+    // `num_leaves` leaves, along with the membership proof of its last leaf.
+    fn read_frontier(
+        &self,
+        num_leaves: u64,
+    ) -> Result<(MerkleNode<E, u64, T>, MerklePath<E, u64, T>), PrimitivesError> {
+        let pos = num_leaves - 1;
+        let elem = self.store.get_elem(pos)?.ok_or_else(missing_data)?;
+        let traversal_path = ToTraversalPath::<Arity>::to_traversal_path(&pos, self.height);
+        let proof = MerkleProof::<E, u64, T, Arity>::new(
+            pos,
+            read_path::<E, u64, T, Arity, S>(
+                &self.store,
+                self.height,
+                num_leaves,
+                pos,
+                pos,
+                elem.clone(),
+            )?,
+        );
+        let path_values = proof.membership_path_values::<H>(&traversal_path, &elem)?;
+        let mut root = MerkleNode::ForgettenSubtree {
+            value: path_values[self.height],
+        };
+        root.remember_internal::<H, Arity>(
+            self.height,
+            &traversal_path,
+            &path_values,
+            &proof.proof,
+        )?;
+        Ok((root, proof.proof))
+    }
+
+    // Insert an element in a storage-backed tree, keeping only the frontier in
+    // memory.
+    fn push_stored(&mut self, elem: &E) -> Result<(), PrimitivesError> {
+        let pos = self.num_leaves;
+        if num_traits::checked_pow(Arity::to_u64(), self.height).map_or(false, |cap| pos >= cap) {
+            return Err(PrimitivesError::ParameterError(
+                "Exceed merkle tree capacity".to_string(),
+            ));
+        }
+        let traversal_path = ToTraversalPath::<Arity>::to_traversal_path(&pos, self.height);
+        // Insert into a copy of the in-memory part, which shares its nodes, and
+        // keep it once the store is written.
+        let mut root = self.root.clone();
+        Arc::make_mut(&mut root).extend_and_forget_internal::<H, Arity>(
+            self.height,
+            &pos,
+            &traversal_path,
+            true,
+            &mut ark_std::iter::once(elem).peekable(),
+        )?;
+        let proof = match root.lookup_internal(self.height, &traversal_path) {
+            LookupResult::Ok(_, proof) => proof,
+            _ => {
+                return Err(PrimitivesError::InternalError(
+                    "The last leaf of the tree is not in memory".to_string(),
+                ))
+            },
+        };
+        write_path::<E, u64, T, Arity, S>(&mut self.store, pos, &proof, root.value())?;
+        // Write the element last, so that it is only counted by stores such as
+        // file stores once its nodes are written.
+        self.store.put_elem(pos, elem)?;
+        self.root = root;
+        self.num_leaves += 1;
+        self.elems.reserve(self.num_leaves);
+        Ok(())
+    }
+
+    // Truncate a storage-backed tree, rebuilding its new frontier from the
+    // store.
+    fn truncate_stored(&mut self, num_leaves: u64) -> Result<(), PrimitivesError> {
+        let root = if num_leaves == 0 {
+            MerkleNode::Empty
+        } else {
+            let (root, proof) = self.read_frontier(num_leaves)?;
+            write_path::<E, u64, T, Arity, S>(
+                &mut self.store,
+                num_leaves - 1,
+                &proof,
+                root.value(),
+            )?;
+            root
+        };
+        self.store.truncate(num_leaves)?;
+        self.root = Arc::new(root);
+        self.num_leaves = num_leaves;
+        self.evict();
+        Ok(())
     }
 }
 
-impl<E, H, Arity, T> AppendableMerkleTreeScheme for MerkleTree<E, H, u64, Arity, T>
+impl<E, H, I, Arity, T, S> MerkleTreeScheme for MerkleTree<E, H, I, Arity, T, S>
+where
+    E: Element,
+    H: DigestAlgorithm<E, I, T>,
+    I: Index + ToTraversalPath<Arity>,
+    Arity: Unsigned,
+    T: NodeValue,
+    S: MerkleStore<E, T>,
+{
+    type Element = E;
+    type Index = I;
+    type NodeValue = T;
+    type MembershipProof = MerkleProof<E, I, T, Arity>;
+    type BatchMembershipProof = MerkleBatchProof<E, I, T, Arity>;
+    type Commitment = MerkleTreeCommitment<T>;
+
+    const ARITY: usize = Arity::USIZE;
+
+    fn height(&self) -> usize {
+        self.height
+    }
+
+    fn capacity(&self) -> BigUint {
+        pow(BigUint::from(Self::ARITY), self.height)
+    }
+
+    fn num_leaves(&self) -> u64 {
+        self.num_leaves
+    }
+
+    fn commitment(&self) -> Self::Commitment {
+        MerkleTreeCommitment::new(self.root.value(), self.height, self.num_leaves)
+    }
+
+    /// Leaves which are not in memory are read from the store, if any. If the
+    /// store fails, they are reported as `NotInMemory`.
+    fn lookup(
+        &self,
+        pos: impl Borrow<Self::Index>,
+    ) -> LookupResult<
+        &Self::Element,
+        Self::MembershipProof,
+        (),
+        Option<MissingSubtree<Self::NodeValue>>,
+    > {
+        let pos = pos.borrow();
+        let traversal_path = pos.to_traversal_path(self.height);
+        match self.root.lookup_internal(self.height, &traversal_path) {
+            LookupResult::Ok(value, proof) => {
+                LookupResult::Ok(value, MerkleProof::new(pos.clone(), proof))
+            },
+            LookupResult::NotInMemory(missing) => {
+                if S::IN_MEMORY {
+                    return LookupResult::NotInMemory(Some(missing));
+                }
+                match self.lookup_stored(pos, &traversal_path) {
+                    Ok((elem, proof)) => LookupResult::Ok(elem, proof),
+                    Err(_) => LookupResult::NotInMemory(Some(missing)),
+                }
+            },
+            LookupResult::NotFound(_) => LookupResult::NotFound(()),
+        }
+    }
+
+    fn verify(
+        root: impl Borrow<Self::NodeValue>,
+        pos: impl Borrow<Self::Index>,
+        proof: impl Borrow<Self::MembershipProof>,
+    ) -> Result<VerificationResult, PrimitivesError> {
+        if *pos.borrow() != proof.borrow().pos {
+            return Ok(Err(())); // invalid proof for the given pos
+        }
+        proof.borrow().verify_membership_proof::<H>(root.borrow())
+    }
+
+    /// Leaves which are not in memory are read from the store, if any.
+    fn multi_lookup(
+        &self,
+        pos: impl IntoIterator<Item = impl Borrow<Self::Index>>,
+    ) -> LookupResult<Vec<Self::Element>, Self::BatchMembershipProof, ()> {
+        let pos = pos
+            .into_iter()
+            .map(|pos| pos.borrow().clone())
+            .collect::<Vec<_>>();
+        let traversal_paths = pos
+            .iter()
+            .map(|pos| pos.to_traversal_path(self.height))
+            .collect::<Vec<_>>();
+        let paths = traversal_paths
+            .iter()
+            .map(|path| path.as_slice())
+            .collect::<Vec<_>>();
+        let mut lookup = self.root.batch_lookup_internal(self.height, &paths);
+        if !S::IN_MEMORY && matches!(lookup, LookupResult::NotInMemory(())) {
+            // Remember the missing leaves in a copy of the in-memory part
+            let mut root = (*self.root).clone();
+            for (pos, path) in pos.iter().zip(&traversal_paths) {
+                match root.lookup_internal(self.height, path) {
+                    LookupResult::Ok(..) => continue,
+                    LookupResult::NotFound(_) => return LookupResult::NotFound(()),
+                    LookupResult::NotInMemory(_) => {},
+                }
+                let remembered = self.lookup_stored(pos, path).and_then(|(elem, proof)| {
+                    let path_values = proof.membership_path_values::<H>(path, elem)?;
+                    root.remember_internal::<H, Arity>(
+                        self.height,
+                        path,
+                        &path_values,
+                        &proof.proof,
+                    )
+                });
+                if remembered.is_err() {
+                    return LookupResult::NotInMemory(());
+                }
+            }
+            lookup = root.batch_lookup_internal(self.height, &paths);
+        }
+        match lookup {
+            LookupResult::Ok(_, proof) => {
+                let proof = MerkleBatchProof::new(pos, self.height, proof);
+                let elems = match proof.elems() {
+                    Some(elems) => elems.into_iter().cloned().collect(),
+                    None => return LookupResult::NotInMemory(()),
+                };
+                LookupResult::Ok(elems, proof)
+            },
+            LookupResult::NotInMemory(()) => LookupResult::NotInMemory(()),
+            LookupResult::NotFound(_) => LookupResult::NotFound(()),
+        }
+    }
+
+    fn verify_batch(
+        root: impl Borrow<Self::NodeValue>,
+        pos: impl IntoIterator<Item = impl Borrow<Self::Index>>,
+        proof: impl Borrow<Self::BatchMembershipProof>,
+    ) -> Result<VerificationResult, PrimitivesError> {
+        let proof = proof.borrow();
+        let pos = pos
+            .into_iter()
+            .map(|pos| pos.borrow().clone())
+            .collect::<Vec<_>>();
+        if pos != proof.pos {
+            return Ok(Err(())); // invalid proof for the given pos
+        }
+        proof.verify_batch_membership_proof::<H>(root.borrow())
+    }
+
+    /// Only the leaves in memory are visited.
+    fn iter(&self) -> MerkleTreeIter<E, I, T> {
+        MerkleTreeIter::new(&self.root)
+    }
+
+    /// Only the leaves in memory are visited.
+    fn iter_with_proofs(&self) -> Box<dyn Iterator<Item = (I, &E, Self::MembershipProof)> + '_> {
+        Box::new(MerkleProofIter::<E, I, T, Arity>::new(&self.root))
+    }
+}
+
+impl<E, H, Arity, T, S> AppendableMerkleTreeScheme for MerkleTree<E, H, u64, Arity, T, S>
 where
     E: Element,
     H: DigestAlgorithm<E, u64, T>,
     Arity: Unsigned,
     T: NodeValue,
+    S: MerkleStore<E, T>,
 {
     fn push(&mut self, elem: impl Borrow<Self::Element>) -> Result<(), PrimitivesError> {
         <Self as AppendableMerkleTreeScheme>::extend(self, [elem])
     }
 
+    /// With a store, if the store fails, the tree keeps the elements inserted
+    /// so far but the store may contain partially written data.
     fn extend(
         &mut self,
         elems: impl IntoIterator<Item = impl Borrow<Self::Element>>,
     ) -> Result<(), PrimitivesError> {
+        if !S::IN_MEMORY {
+            return elems
+                .into_iter()
+                .try_for_each(|elem| self.push_stored(elem.borrow()));
+        }
         let mut iter = elems.into_iter().peekable();
 
         let traversal_path =
             ToTraversalPath::<Arity>::to_traversal_path(&self.num_leaves, self.height);
-        self.num_leaves += self.root.extend_internal::<H, Arity>(
+        self.num_leaves += Arc::make_mut(&mut self.root).extend_internal::<H, Arity>(
             self.height,
             &self.num_leaves,
             &traversal_path,
@@ -107,6 +626,204 @@ where
         }
         Ok(())
     }
+
+    fn truncate(&mut self, num_leaves: u64) -> Result<(), PrimitivesError> {
+        if num_leaves > self.num_leaves {
+            return Err(PrimitivesError::ParameterError(
+                "Cannot truncate a merkle tree to a larger size".to_string(),
+            ));
+        }
+        if num_leaves < self.num_leaves {
+            if !S::IN_MEMORY {
+                return self.truncate_stored(num_leaves);
+            }
+            Arc::make_mut(&mut self.root).truncate_internal::<H, Arity>(self.height, num_leaves)?;
+            self.num_leaves = num_leaves;
+        }
+        Ok(())
+    }
+}
+
+/// An append only Merkle tree whose height increases whenever its capacity is
+/// exceeded, instead of rejecting the insertion, see
+/// [`MerkleTree::increase_height`]. Its commitment, and thus the proofs it
+/// generates, depend on its current height.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(bound = "E: ark_serialize::CanonicalSerialize + ark_serialize::CanonicalDeserialize")]
+pub struct DynamicHeightMerkleTree<E, H, Arity, T>
+where
+    E: Element,
+    H: DigestAlgorithm<E, u64, T>,
+    Arity: Unsigned,
+    T: NodeValue,
+{
+    tree: MerkleTree<E, H, u64, Arity, T>,
+}
+
+impl<E, H, Arity, T> DynamicHeightMerkleTree<E, H, Arity, T>
+where
+    E: Element,
+    H: DigestAlgorithm<E, u64, T>,
+    Arity: Unsigned,
+    T: NodeValue,
+{
+    /// Initialize an empty Merkle tree.
+    /// * `height` - initial height of the tree
+    pub fn new(height: usize) -> Self {
+        Self::from_tree(MerkleTree::new(height))
+    }
+
+    /// Let an existing Merkle tree grow when its capacity is exceeded.
+    pub fn from_tree(tree: MerkleTree<E, H, u64, Arity, T>) -> Self {
+        Self { tree }
+    }
+
+    /// Return the underlying Merkle tree at its current height.
+    pub fn into_tree(self) -> MerkleTree<E, H, u64, Arity, T> {
+        self.tree
+    }
+}
+
+impl<E, H, Arity, T> MerkleTreeScheme for DynamicHeightMerkleTree<E, H, Arity, T>
+where
+    E: Element,
+    H: DigestAlgorithm<E, u64, T>,
+    Arity: Unsigned,
+    T: NodeValue,
+{
+    type Element = E;
+    type Index = u64;
+    type NodeValue = T;
+    type MembershipProof = MerkleProof<E, u64, T, Arity>;
+    type BatchMembershipProof = MerkleBatchProof<E, u64, T, Arity>;
+    type Commitment = MerkleTreeCommitment<T>;
+
+    const ARITY: usize = Arity::USIZE;
+
+    fn height(&self) -> usize {
+        self.tree.height()
+    }
+
+    fn capacity(&self) -> BigUint {
+        self.tree.capacity()
+    }
+
+    fn num_leaves(&self) -> u64 {
+        self.tree.num_leaves()
+    }
+
+    fn commitment(&self) -> Self::Commitment {
+        self.tree.commitment()
+    }
+
+    fn lookup(
+        &self,
+        pos: impl Borrow<Self::Index>,
+    ) -> LookupResult<
+        &Self::Element,
+        Self::MembershipProof,
+        (),
+        Option<MissingSubtree<Self::NodeValue>>,
+    > {
+        self.tree.lookup(pos)
+    }
+
+    fn verify(
+        root: impl Borrow<Self::NodeValue>,
+        pos: impl Borrow<Self::Index>,
+        proof: impl Borrow<Self::MembershipProof>,
+    ) -> Result<VerificationResult, PrimitivesError> {
+        MerkleTree::<E, H, u64, Arity, T>::verify(root, pos, proof)
+    }
+
+    fn multi_lookup(
+        &self,
+        pos: impl IntoIterator<Item = impl Borrow<Self::Index>>,
+    ) -> LookupResult<Vec<Self::Element>, Self::BatchMembershipProof, ()> {
+        self.tree.multi_lookup(pos)
+    }
+
+    fn verify_batch(
+        root: impl Borrow<Self::NodeValue>,
+        pos: impl IntoIterator<Item = impl Borrow<Self::Index>>,
+        proof: impl Borrow<Self::BatchMembershipProof>,
+    ) -> Result<VerificationResult, PrimitivesError> {
+        MerkleTree::<E, H, u64, Arity, T>::verify_batch(root, pos, proof)
+    }
+
+    fn iter(&self) -> MerkleTreeIter<E, u64, T> {
+        self.tree.iter()
+    }
+
+    fn iter_with_proofs(
+        &self,
+    ) -> Box<dyn Iterator<Item = (u64, &Self::Element, Self::MembershipProof)> + '_> {
+        self.tree.iter_with_proofs()
+    }
+}
+
+impl<E, H, Arity, T> AppendableMerkleTreeScheme for DynamicHeightMerkleTree<E, H, Arity, T>
+where
+    E: Element,
+    H: DigestAlgorithm<E, u64, T>,
+    Arity: Unsigned,
+    T: NodeValue,
+{
+    fn push(&mut self, elem: impl Borrow<Self::Element>) -> Result<(), PrimitivesError> {
+        <Self as AppendableMerkleTreeScheme>::extend(self, [elem])
+    }
+
+    fn extend(
+        &mut self,
+        elems: impl IntoIterator<Item = impl Borrow<Self::Element>>,
+    ) -> Result<(), PrimitivesError> {
+        let mut iter = elems.into_iter().peekable();
+        while iter.peek().is_some() {
+            if BigUint::from(self.tree.num_leaves) == self.tree.capacity() {
+                self.tree.increase_height(self.tree.height + 1)?;
+            }
+            // Never insert more than the remaining capacity, so that the tree
+            // only grows when there is an element left to insert.
+            let remaining = (self.tree.capacity() - self.tree.num_leaves)
+                .to_usize()
+                .unwrap_or(usize::MAX);
+            self.tree.extend(iter.by_ref().take(remaining))?;
+        }
+        Ok(())
+    }
+
+    /// The height of the tree is left unchanged.
+    fn truncate(&mut self, num_leaves: u64) -> Result<(), PrimitivesError> {
+        self.tree.truncate(num_leaves)
+    }
+}
+
+impl<E, H, Arity, T> ForgetableMerkleTreeScheme for DynamicHeightMerkleTree<E, H, Arity, T>
+where
+    E: Element,
+    H: DigestAlgorithm<E, u64, T>,
+    Arity: Unsigned,
+    T: NodeValue,
+{
+    fn forget(
+        &mut self,
+        pos: Self::Index,
+    ) -> LookupResult<Self::Element, Self::MembershipProof, ()> {
+        self.tree.forget(pos)
+    }
+
+    fn remember(
+        &mut self,
+        pos: Self::Index,
+        element: impl Borrow<Self::Element>,
+        proof: impl Borrow<Self::MembershipProof>,
+    ) -> Result<(), PrimitivesError> {
+        self.tree.remember(pos, element, proof)
+    }
+
+    fn from_commitment(commitment: impl Borrow<Self::Commitment>) -> Self {
+        Self::from_tree(MerkleTree::from_commitment(commitment))
+    }
 }
 
 // TODO(Chengyu): extract a merkle frontier
@@ -115,15 +832,20 @@ where
 mod mt_tests {
     use crate::{
         merkle_tree::{
+            append_only::DynamicHeightMerkleTree,
             internal::{MerkleNode, MerkleProof},
-            prelude::{RescueMerkleTree, RescueSparseMerkleTree},
+            prelude::{
+                RescueHash, RescueLightWeightMerkleTree, RescueMerkleTree, RescueSparseMerkleTree,
+            },
             *,
         },
         rescue::RescueParameter,
     };
+    use alloc::sync::Arc;
     use ark_ed_on_bls12_377::Fq as Fq377;
     use ark_ed_on_bls12_381::Fq as Fq381;
     use ark_ed_on_bn254::Fq as Fq254;
+    use typenum::U3;
 
     #[test]
     fn test_mt_builder() {
@@ -135,6 +857,260 @@ mod mt_tests {
     fn test_mt_builder_helper<F: RescueParameter>() {
         assert!(RescueMerkleTree::<F>::from_elems(None, [F::from(0u64); 3]).is_ok());
         assert!(RescueMerkleTree::<F>::from_elems(Some(1), [F::from(0u64); 4]).is_err());
+
+        // Bulk construction agrees with incremental insertions
+        let data: Vec<F> = (0..50u64).map(F::from).collect();
+        let mt = RescueMerkleTree::<F>::from_elems(None, &data).unwrap();
+        let mut expected = RescueMerkleTree::<F>::new(mt.height());
+        expected.extend(&data).unwrap();
+        assert_eq!(mt.commitment(), expected.commitment());
+        let root = mt.commitment().digest();
+        for (i, elem) in data.iter().enumerate() {
+            let (val, proof) = mt.lookup(i as u64).expect_ok().unwrap();
+            assert_eq!(val, elem);
+            assert!(RescueMerkleTree::<F>::verify(&root, i as u64, &proof)
+                .unwrap()
+                .is_ok());
+        }
+    }
+
+    #[test]
+    fn test_mt_truncate() {
+        test_mt_truncate_helper::<Fq254>();
+        test_mt_truncate_helper::<Fq377>();
+        test_mt_truncate_helper::<Fq381>();
+    }
+
+    fn test_mt_truncate_helper<F: RescueParameter>() {
+        let elems: Vec<F> = (0..20u64).map(F::from).collect();
+        let mt = RescueMerkleTree::<F>::from_elems(Some(3), &elems).unwrap();
+        for num_leaves in 0..=20 {
+            let mut truncated = mt.clone();
+            truncated.truncate(num_leaves as u64).unwrap();
+            assert_eq!(truncated.num_leaves(), num_leaves as u64);
+            assert_eq!(
+                truncated.commitment(),
+                RescueMerkleTree::<F>::from_elems(Some(3), &elems[..num_leaves])
+                    .unwrap()
+                    .commitment()
+            );
+            // The truncated tree can grow again
+            truncated.extend(&elems[num_leaves..]).unwrap();
+            assert_eq!(truncated, mt);
+        }
+        assert!(mt.clone().truncate(21).is_err());
+
+        let mut popped = mt.clone();
+        assert!(popped.pop().is_ok());
+        assert_eq!(popped.num_leaves(), 19);
+        assert!(popped.lookup(19).expect_not_found().is_ok());
+        (0..19).for_each(|_| popped.pop().unwrap());
+        assert_eq!(popped, RescueMerkleTree::<F>::new(3));
+        assert!(popped.pop().is_err());
+
+        // Truncation fails, and leaves the tree untouched, if it needs a
+        // forgotten part of the tree
+        let mut forgotten = mt.clone();
+        (0..3).for_each(|i| {
+            forgotten.forget(i).expect_ok().unwrap();
+        });
+        let expected = forgotten.clone();
+        assert!(forgotten.truncate(2).is_err());
+        assert_eq!(forgotten, expected);
+        assert!(forgotten.truncate(3).is_ok());
+    }
+
+    #[test]
+    fn test_mt_increase_height() {
+        test_mt_increase_height_helper::<Fq254>();
+        test_mt_increase_height_helper::<Fq377>();
+        test_mt_increase_height_helper::<Fq381>();
+    }
+
+    fn test_mt_increase_height_helper<F: RescueParameter>() {
+        let elems: Vec<F> = (0..30u64).map(F::from).collect();
+        let mut mt = RescueMerkleTree::<F>::from_elems(Some(2), &elems[..5]).unwrap();
+        assert!(mt.increase_height(1).is_err());
+        mt.increase_height(4).unwrap();
+        assert_eq!(mt.height(), 4);
+        assert_eq!(
+            mt.commitment(),
+            RescueMerkleTree::<F>::from_elems(Some(4), &elems[..5])
+                .unwrap()
+                .commitment()
+        );
+        mt.extend(&elems[5..]).unwrap();
+        let expected = RescueMerkleTree::<F>::from_elems(Some(4), &elems).unwrap();
+        assert_eq!(mt.commitment(), expected.commitment());
+        let (_, proof) = mt.lookup(3).expect_ok().unwrap();
+        assert_eq!(proof, expected.lookup(3).expect_ok().unwrap().1);
+
+        let mut light_mt =
+            RescueLightWeightMerkleTree::<F>::from_elems(Some(2), &elems[..9]).unwrap();
+        assert!(light_mt.push(elems[9]).is_err());
+        light_mt.increase_height(4).unwrap();
+        light_mt.extend(&elems[9..]).unwrap();
+        assert_eq!(light_mt.commitment(), expected.commitment());
+
+        let mut empty = RescueMerkleTree::<F>::new(1);
+        empty.increase_height(3).unwrap();
+        assert_eq!(empty, RescueMerkleTree::<F>::new(3));
+    }
+
+    #[test]
+    fn test_mt_merge() {
+        test_mt_merge_helper::<Fq254>();
+        test_mt_merge_helper::<Fq377>();
+        test_mt_merge_helper::<Fq381>();
+    }
+
+    fn test_mt_merge_helper<F: RescueParameter>() {
+        let elems: Vec<F> = (0..18u64).map(F::from).collect();
+        let mut left = RescueMerkleTree::<F>::from_elems(Some(2), &elems[..9]).unwrap();
+        let right = RescueMerkleTree::<F>::from_elems(Some(2), &elems[9..]).unwrap();
+        let expected = RescueMerkleTree::<F>::from_elems(Some(3), &elems).unwrap();
+        let mt = RescueMerkleTree::<F>::merge(left.clone(), right.clone()).unwrap();
+        assert_eq!(mt, expected);
+        let (_, proof) = mt.lookup(12).expect_ok().unwrap();
+        assert!(
+            RescueMerkleTree::<F>::verify(expected.commitment().digest(), 12, &proof)
+                .unwrap()
+                .is_ok()
+        );
+
+        // Forgotten leaves of the left tree stay forgotten
+        left.forget(4).expect_ok().unwrap();
+        let mt = RescueMerkleTree::<F>::merge(left.clone(), right.clone()).unwrap();
+        assert_eq!(mt.commitment(), expected.commitment());
+        assert!(mt.lookup(4).expect_not_in_memory().is_ok());
+
+        // The right tree may be partial, and the merged tree can grow
+        let partial = RescueMerkleTree::<F>::from_elems(Some(2), &elems[9..14]).unwrap();
+        let mut merged = RescueMerkleTree::<F>::merge(left.clone(), partial).unwrap();
+        assert_eq!(merged.num_leaves(), 14);
+        merged.extend(&elems[14..]).unwrap();
+        assert_eq!(merged.commitment(), expected.commitment());
+
+        let not_full = RescueMerkleTree::<F>::from_elems(Some(2), &elems[..8]).unwrap();
+        assert!(RescueMerkleTree::<F>::merge(not_full, right.clone()).is_err());
+        let taller = RescueMerkleTree::<F>::from_elems(Some(3), &elems[9..]).unwrap();
+        assert!(RescueMerkleTree::<F>::merge(left.clone(), taller).is_err());
+        let mut forgotten = right;
+        forgotten.forget(3).expect_ok().unwrap();
+        assert!(RescueMerkleTree::<F>::merge(left, forgotten).is_err());
+    }
+
+    #[test]
+    fn test_mt_validate() {
+        test_mt_validate_helper::<Fq254>();
+        test_mt_validate_helper::<Fq377>();
+        test_mt_validate_helper::<Fq381>();
+    }
+
+    fn test_mt_validate_helper<F: RescueParameter>() {
+        use bincode::Options;
+
+        let elems: Vec<F> = (0..10u64).map(F::from).collect();
+        let mut mt = RescueMerkleTree::<F>::from_elems(Some(3), &elems).unwrap();
+        assert!(mt.validate().is_ok());
+        assert!(RescueMerkleTree::<F>::new(3).validate().is_ok());
+        mt.forget(4).expect_ok().unwrap();
+        assert!(mt.validate().is_ok());
+        let light_mt = RescueLightWeightMerkleTree::<F>::from_elems(Some(3), &elems).unwrap();
+        assert!(light_mt.validate().is_ok());
+
+        let options = bincode::DefaultOptions::new();
+        let bytes = options.serialize(&mt).unwrap();
+        let checked = RescueMerkleTree::<F>::deserialize_and_check(
+            &mut bincode::Deserializer::from_slice(&bytes, options),
+        )
+        .unwrap();
+        assert_eq!(checked, mt);
+
+        let mut bad_mt = mt.clone();
+        bad_mt.num_leaves = 28;
+        assert!(bad_mt.validate().is_err());
+        let mut bad_mt = RescueMerkleTree::<F>::from_elems(Some(3), &elems).unwrap();
+        bad_mt.num_leaves = 9;
+        assert!(bad_mt.validate().is_err());
+        let bytes = options.serialize(&bad_mt).unwrap();
+        assert!(RescueMerkleTree::<F>::deserialize_and_check(
+            &mut bincode::Deserializer::from_slice(&bytes, options),
+        )
+        .is_err());
+
+        // Tampered digests and swapped subtrees are detected
+        let mut bad_mt = mt.clone();
+        bad_mt.root = Arc::new(MerkleNode::Branch {
+            value: F::default(),
+            children: match &*mt.root {
+                MerkleNode::Branch { children, .. } => children.clone(),
+                _ => unreachable!(),
+            },
+        });
+        assert!(bad_mt.validate().is_err());
+        let mut children = match &*mt.root {
+            MerkleNode::Branch { children, .. } => children.clone(),
+            _ => unreachable!(),
+        };
+        children.swap(0, 1);
+        let mut bad_mt = mt.clone();
+        bad_mt.root = Arc::new(MerkleNode::Branch {
+            value: <RescueHash<F> as DigestAlgorithm<F, u64, F>>::digest(
+                &children
+                    .iter()
+                    .map(|child| child.value())
+                    .collect::<Vec<_>>(),
+            )
+            .unwrap(),
+            children,
+        });
+        assert!(bad_mt.validate().is_err());
+    }
+
+    #[test]
+    fn test_dynamic_height_mt() {
+        test_dynamic_height_mt_helper::<Fq254>();
+        test_dynamic_height_mt_helper::<Fq377>();
+        test_dynamic_height_mt_helper::<Fq381>();
+    }
+
+    fn test_dynamic_height_mt_helper<F: RescueParameter>() {
+        type DynamicMT<F> = DynamicHeightMerkleTree<F, RescueHash<F>, U3, F>;
+        let elems: Vec<F> = (0..30u64).map(F::from).collect();
+        let mut mt = DynamicMT::<F>::new(1);
+        for (i, elem) in elems.iter().enumerate() {
+            mt.push(elem).unwrap();
+            let height = match i {
+                0..=2 => 1,
+                3..=8 => 2,
+                9..=26 => 3,
+                _ => 4,
+            };
+            assert_eq!(mt.height(), height);
+            assert_eq!(
+                mt.commitment(),
+                RescueMerkleTree::<F>::from_elems(Some(height), &elems[..=i])
+                    .unwrap()
+                    .commitment()
+            );
+        }
+
+        // Bulk insertions grow the tree as much as needed
+        let mut bulk_mt = DynamicMT::<F>::new(0);
+        bulk_mt.extend(&elems[..1]).unwrap();
+        assert_eq!(bulk_mt.height(), 0);
+        bulk_mt.extend(&elems[1..]).unwrap();
+        assert_eq!(bulk_mt.commitment(), mt.commitment());
+        let root = mt.commitment().digest();
+        for (i, elem) in elems.iter().enumerate() {
+            let (val, proof) = bulk_mt.lookup(i as u64).expect_ok().unwrap();
+            assert_eq!(val, elem);
+            assert!(DynamicMT::<F>::verify(root, i as u64, &proof)
+                .unwrap()
+                .is_ok());
+        }
+        assert_eq!(bulk_mt.into_tree().height(), 4);
     }
 
     #[test]
@@ -207,6 +1183,135 @@ mod mt_tests {
         assert!(result.unwrap().is_err());
     }
 
+    #[test]
+    fn test_mt_batch_lookup() {
+        test_mt_batch_lookup_helper::<Fq254>();
+        test_mt_batch_lookup_helper::<Fq377>();
+        test_mt_batch_lookup_helper::<Fq381>();
+    }
+
+    fn test_mt_batch_lookup_helper<F: RescueParameter>() {
+        let elems = (0..20u64).map(F::from).collect::<Vec<_>>();
+        let mut mt = RescueMerkleTree::<F>::from_elems(Some(3), &elems).unwrap();
+        let root = mt.commitment().digest();
+        let indices = [0u64, 1, 7, 19];
+        let (vals, proof) = mt.multi_lookup(indices).expect_ok().unwrap();
+        assert_eq!(
+            vals,
+            indices.iter().map(|i| F::from(*i)).collect::<Vec<_>>()
+        );
+        assert_eq!(proof.indices(), &indices);
+        assert!(RescueMerkleTree::<F>::verify_batch(&root, indices, &proof)
+            .unwrap()
+            .is_ok());
+        // An empty batch is trivially valid
+        let (vals, empty_proof) = mt.multi_lookup(Vec::<u64>::new()).expect_ok().unwrap();
+        assert!(vals.is_empty());
+        assert!(
+            RescueMerkleTree::<F>::verify_batch(&root, Vec::<u64>::new(), &empty_proof)
+                .unwrap()
+                .is_ok()
+        );
+
+        // Proof shall not be accepted for other indices
+        assert!(
+            RescueMerkleTree::<F>::verify_batch(&root, [0u64, 1, 7], &proof)
+                .unwrap()
+                .is_err()
+        );
+        assert!(
+            RescueMerkleTree::<F>::verify_batch(&root, [0u64, 1, 8, 19], &proof)
+                .unwrap()
+                .is_err()
+        );
+
+        // Proof shall not be accepted against another root
+        let mut other_elems = elems.clone();
+        other_elems[7] = F::from(100u64);
+        let other_mt = RescueMerkleTree::<F>::from_elems(Some(3), &other_elems).unwrap();
+        let other_root = other_mt.commitment().digest();
+        assert!(
+            RescueMerkleTree::<F>::verify_batch(&other_root, indices, &proof)
+                .unwrap()
+                .is_err()
+        );
+
+        // Tampering with the proof shall be detected
+        let mut forge_proof = proof.clone();
+        forge_proof.pos[2] = 8;
+        assert!(
+            RescueMerkleTree::<F>::verify_batch(&root, [0u64, 1, 8, 19], &forge_proof)
+                .unwrap()
+                .is_err()
+        );
+
+        // Batch proof shall be consistent with individual proofs
+        indices.iter().for_each(|i| {
+            let (_, proof) = mt.lookup(i).expect_ok().unwrap();
+            assert!(RescueMerkleTree::<F>::verify(&root, i, &proof)
+                .unwrap()
+                .is_ok());
+        });
+
+        assert!(mt.multi_lookup([0u64, 20]).expect_not_found().is_ok());
+        mt.forget(7).expect_ok().unwrap();
+        assert!(mt.multi_lookup([0u64, 7]).expect_not_in_memory().is_ok());
+        assert!(mt.multi_lookup([0u64, 19]).expect_ok().is_ok());
+    }
+
+    #[test]
+    fn test_mt_lookup_range() {
+        test_mt_lookup_range_helper::<Fq254>();
+        test_mt_lookup_range_helper::<Fq377>();
+        test_mt_lookup_range_helper::<Fq381>();
+    }
+
+    fn test_mt_lookup_range_helper<F: RescueParameter>() {
+        fn num_digests<F: RescueParameter>(node: &MerkleNode<F, u64, F>) -> usize {
+            match node {
+                MerkleNode::Branch { children, .. } => {
+                    children.iter().map(|child| num_digests(child)).sum()
+                },
+                MerkleNode::Empty => 0,
+                _ => 1,
+            }
+        }
+
+        let elems = (0..20u64).map(F::from).collect::<Vec<_>>();
+        let mut mt = RescueMerkleTree::<F>::from_elems(Some(3), &elems).unwrap();
+        let root = mt.commitment().digest();
+        let (vals, proof) = mt.lookup_range(4..13).expect_ok().unwrap();
+        assert_eq!(vals, elems[4..13].to_vec());
+        assert_eq!(proof, mt.multi_lookup(4..13u64).expect_ok().unwrap().1);
+        assert!(RescueMerkleTree::<F>::verify_range(&root, 4..13, &proof)
+            .unwrap()
+            .is_ok());
+        assert!(RescueMerkleTree::<F>::verify_range(&root, 4..12, &proof)
+            .unwrap()
+            .is_err());
+        assert!(RescueMerkleTree::<F>::verify_range(&root, 5..14, &proof)
+            .unwrap()
+            .is_err());
+
+        // The range proof is smaller than the individual proofs
+        let individual: usize = (4..13u64)
+            .map(|i| mt.lookup(i).expect_ok().unwrap().1.proof.len())
+            .sum();
+        assert!(num_digests(&proof.proof) < individual / 2);
+
+        // Empty ranges are trivially valid
+        let (vals, proof) = mt.lookup_range(3..3).expect_ok().unwrap();
+        assert!(vals.is_empty());
+        assert!(RescueMerkleTree::<F>::verify_range(&root, 3..3, &proof)
+            .unwrap()
+            .is_ok());
+
+        assert!(mt.lookup_range(15..21).expect_not_found().is_ok());
+        mt.forget(7).expect_ok().unwrap();
+        assert!(mt.lookup_range(4..13).expect_not_in_memory().is_ok());
+        assert!(mt.lookup_range(9..20).expect_ok().is_ok());
+    }
+
     #[test]
     fn test_mt_forget_remember() {
         test_mt_forget_remember_helper::<Fq254>();
@@ -233,7 +1338,13 @@ mod mt_tests {
             .is_ok());
 
         assert!(mt.forget(0).expect_ok().is_err());
-        assert!(matches!(mt.lookup(0), LookupResult::NotInMemory));
+        assert_eq!(
+            mt.lookup(0).expect_not_in_memory().unwrap(),
+            Some(MissingSubtree {
+                height: 0,
+                digest: proof.proof[0].value()
+            })
+        );
 
         let mut bad_proof = proof.clone();
         if let MerkleNode::Leaf {
@@ -267,6 +1378,84 @@ mod mt_tests {
 
         assert!(mt.remember(0, elem, &proof).is_ok());
         assert!(mt.lookup(0).expect_ok().is_ok());
+
+        // Once all the leaves are forgotten, the whole tree is missing
+        mt.forget(0).expect_ok().unwrap();
+        mt.forget(1).expect_ok().unwrap();
+        assert_eq!(
+            mt.lookup(1).expect_not_in_memory().unwrap(),
+            Some(MissingSubtree {
+                height: 2,
+                digest: root
+            })
+        );
+    }
+
+    #[test]
+    fn test_mt_batch_forget_remember() {
+        test_mt_batch_forget_remember_helper::<Fq254>();
+        test_mt_batch_forget_remember_helper::<Fq377>();
+        test_mt_batch_forget_remember_helper::<Fq381>();
+    }
+
+    fn test_mt_batch_forget_remember_helper<F: RescueParameter>() {
+        let mut mt = RescueMerkleTree::<F>::from_elems(Some(3), (0..20u64).map(F::from)).unwrap();
+        let commitment = mt.commitment();
+        let indices = [0u64, 1, 2, 5, 9, 10, 11, 17];
+        let forgotten = mt
+            .forget_batch(indices)
+            .into_iter()
+            .zip(indices)
+            .map(|(result, pos)| {
+                let (elem, proof) = result.expect_ok().unwrap();
+                (pos, elem, proof)
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(mt.commitment(), commitment);
+        for pos in indices {
+            assert!(mt.lookup(pos).expect_not_in_memory().is_ok());
+        }
+        assert!(mt.forget_batch([0u64, 3])[0].clone().expect_ok().is_err());
+        assert!(mt.lookup(3).expect_not_in_memory().is_ok());
+
+        // One bad proof rejects the whole batch
+        let mut bad = forgotten.clone();
+        bad[4].1 = F::from(100u64);
+        assert!(mt
+            .remember_batch(bad.iter().map(|(pos, elem, proof)| (*pos, elem, proof)))
+            .is_err());
+        let mut bad = forgotten.clone();
+        bad[2].2 = bad[3].2.clone();
+        bad[2].1 = bad[3].1;
+        assert!(mt
+            .remember_batch(bad.iter().map(|(pos, elem, proof)| (*pos, elem, proof)))
+            .is_err());
+        for pos in indices {
+            assert!(mt.lookup(pos).expect_not_in_memory().is_ok());
+        }
+
+        // Proofs are merged in any order, along with leaves already in memory
+        mt.remember(1, forgotten[1].1, &forgotten[1].2).unwrap();
+        mt.remember_batch(
+            forgotten
+                .iter()
+                .rev()
+                .map(|(pos, elem, proof)| (*pos, elem, proof)),
+        )
+        .unwrap();
+        assert_eq!(mt.commitment(), commitment);
+        for pos in 0..20u64 {
+            if pos == 3 {
+                continue;
+            }
+            let (elem, proof) = mt.lookup(pos).expect_ok().unwrap();
+            assert_eq!(elem, &F::from(pos));
+            assert!(
+                RescueMerkleTree::<F>::verify(commitment.digest(), pos, &proof)
+                    .unwrap()
+                    .is_ok()
+            );
+        }
     }
 
     #[test]
@@ -290,6 +1479,11 @@ mod mt_tests {
             proof,
             bincode::deserialize(&bincode::serialize(&proof).unwrap()).unwrap()
         );
+        let batch_proof = mt.multi_lookup([0u64, 1]).expect_ok().unwrap().1;
+        assert_eq!(
+            batch_proof,
+            bincode::deserialize(&bincode::serialize(&batch_proof).unwrap()).unwrap()
+        );
         assert_eq!(
             *node,
             bincode::deserialize(&bincode::serialize(node).unwrap()).unwrap()
@@ -342,4 +1536,107 @@ mod mt_tests {
             ]
         );
     }
+
+    #[test]
+    fn test_mt_iter_with_proofs() {
+        test_mt_iter_with_proofs_helper::<Fq254>();
+        test_mt_iter_with_proofs_helper::<Fq377>();
+        test_mt_iter_with_proofs_helper::<Fq381>();
+    }
+
+    fn test_mt_iter_with_proofs_helper<F: RescueParameter>() {
+        let mut mt = RescueMerkleTree::<F>::from_elems(Some(3), (0..20u64).map(F::from)).unwrap();
+        mt.forget(4).expect_ok().unwrap();
+        mt.forget(10).expect_ok().unwrap();
+        mt.forget(11).expect_ok().unwrap();
+        let root = mt.commitment().digest();
+        let proofs = mt.iter_with_proofs().collect::<Vec<_>>();
+        assert_eq!(
+            proofs.iter().map(|(pos, ..)| *pos).collect::<Vec<_>>(),
+            mt.iter().map(|(pos, _)| *pos).collect::<Vec<_>>()
+        );
+        assert_eq!(proofs.len(), 17);
+        for (pos, elem, proof) in proofs {
+            assert_eq!(elem, &F::from(pos));
+            assert_eq!(proof, mt.lookup(pos).expect_ok().unwrap().1);
+            assert!(RescueMerkleTree::<F>::verify(root, pos, &proof)
+                .unwrap()
+                .is_ok());
+        }
+
+        // Sparse trees, and trees of height 0
+        let mt = RescueSparseMerkleTree::<BigUint, F>::from_kv_set(
+            10,
+            [
+                (BigUint::from(64u64), F::from(32u64)),
+                (BigUint::from(123u64), F::from(234u64)),
+            ],
+        )
+        .unwrap();
+        for (pos, elem, proof) in mt.iter_with_proofs() {
+            assert_eq!(mt.lookup(&pos).expect_ok().unwrap(), (elem, proof));
+        }
+        assert_eq!(mt.iter_with_proofs().count(), 2);
+        let mut mt = RescueMerkleTree::<F>::new(0);
+        mt.push(F::from(1u64)).unwrap();
+        let (pos, elem, proof) = mt.iter_with_proofs().next().unwrap();
+        assert_eq!((pos, elem), (0, &F::from(1u64)));
+        assert!(
+            RescueMerkleTree::<F>::verify(mt.commitment().digest(), 0, &proof)
+                .unwrap()
+                .is_ok()
+        );
+        assert_eq!(RescueMerkleTree::<F>::new(2).iter_with_proofs().count(), 0);
+    }
+
+    #[test]
+    fn test_mt_snapshot() {
+        test_mt_snapshot_helper::<Fq254>();
+        test_mt_snapshot_helper::<Fq377>();
+        test_mt_snapshot_helper::<Fq381>();
+    }
+
+    fn test_mt_snapshot_helper<F: RescueParameter>() {
+        let elems = (0..10u64).map(F::from).collect::<Vec<_>>();
+        let mut mt = RescueMerkleTree::<F>::from_elems(Some(3), &elems).unwrap();
+        let snapshot = mt.snapshot();
+        assert_eq!(snapshot, mt);
+        let commitment = snapshot.commitment();
+
+        // Updates of the tree are not visible in the snapshot
+        mt.extend((10..20u64).map(F::from)).unwrap();
+        mt.forget(3).expect_ok().unwrap();
+        assert_eq!(snapshot.commitment(), commitment);
+        assert_ne!(mt.commitment(), commitment);
+        assert_eq!(snapshot.num_leaves(), 10);
+        for (i, elem) in elems.iter().enumerate() {
+            let (val, proof) = snapshot.lookup(i as u64).expect_ok().unwrap();
+            assert_eq!(val, elem);
+            assert!(
+                RescueMerkleTree::<F>::verify(&commitment.digest(), i as u64, &proof)
+                    .unwrap()
+                    .is_ok()
+            );
+        }
+        assert!(mt.lookup(3).expect_not_in_memory().is_ok());
+        assert!(snapshot.lookup(10).expect_not_found().is_ok());
+
+        // The tree is unaffected by the snapshot going away, and both can be
+        // consumed while they still share nodes
+        let expected = RescueMerkleTree::<F>::from_elems(Some(3), (0..20u64).map(F::from))
+            .unwrap()
+            .commitment();
+        assert_eq!(mt.commitment(), expected);
+        let other = mt.snapshot();
+        assert_eq!(
+            snapshot.into_iter().collect::<Vec<_>>(),
+            elems
+                .iter()
+                .enumerate()
+                .map(|(i, elem)| (i as u64, *elem))
+                .collect::<Vec<_>>()
+        );
+        drop(mt);
+        assert_eq!(other.into_iter().count(), 19);
+    }
 }
@@ -0,0 +1,352 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+//! Compact encoding of Merkle proofs.
+//!
+//! Besides the digests of the siblings along the path, a [`MerkleProof`]
+//! embeds a copy of the proven element, the leaf digest, one node for every
+//! empty sibling and the digest of every node on the path. A
+//! [`CompressedMerkleProof`] drops everything that can be recomputed from the
+//! element and its position: it only keeps the position, a bitmap telling
+//! which siblings are non-empty and the digests of those siblings.
+
+use super::{
+    internal::{digest_branch, MerkleNode, MerkleProof},
+    DigestAlgorithm, Element, Index, NodeValue, ToTraversalPath,
+};
+use crate::errors::PrimitivesError;
+use alloc::sync::Arc;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use ark_std::{string::ToString, vec, vec::Vec};
+use core::marker::PhantomData;
+use jf_utils::canonical;
+use serde::{Deserialize, Serialize};
+use typenum::Unsigned;
+
+/// A compressed membership or non-membership proof, see the
+/// [module documentation](self).
+#[derive(Derivative, Debug, Clone, Serialize, Deserialize)]
+#[derivative(Eq, Hash, PartialEq)]
+#[serde(bound = "I: CanonicalSerialize + CanonicalDeserialize")]
+pub struct CompressedMerkleProof<I, T, Arity>
+where
+    I: Index,
+    T: NodeValue,
+    Arity: Unsigned,
+{
+    /// Position of the proven leaf
+    #[serde(with = "canonical")]
+    pub pos: I,
+    /// Height of the tree
+    pub height: usize,
+    /// `None` for a membership proof, otherwise the height of the empty
+    /// subtree that the path ends in
+    pub empty_height: Option<usize>,
+    /// One bit per sibling, from the leaf to the root and little-endian within
+    /// each byte, set if the sibling is not empty
+    pub bitmap: Vec<u8>,
+    /// Digests of the non-empty siblings, from the leaf to the root
+    #[serde(with = "canonical")]
+    pub siblings: Vec<T>,
+
+    /// Place holder for Arity
+    _phantom_arity: PhantomData<Arity>,
+}
+
+impl<E, I, T, Arity> MerkleProof<E, I, T, Arity>
+where
+    E: Element,
+    I: Index + ToTraversalPath<Arity>,
+    T: NodeValue,
+    Arity: Unsigned,
+{
+    /// Compress this proof, see [`CompressedMerkleProof`].
+    /// * `returns` - the compressed proof, or `Err()` if this proof is
+    ///   malformed
+    pub fn compress(&self) -> Result<CompressedMerkleProof<I, T, Arity>, PrimitivesError> {
+        let height = self
+            .tree_height()
+            .checked_sub(1)
+            .ok_or_else(malformed_proof)?;
+        let num_empty = self
+            .proof
+            .iter()
+            .take_while(|node| matches!(node, MerkleNode::Empty))
+            .count();
+        let empty_height = match (num_empty, &self.proof[0]) {
+            (0, MerkleNode::Leaf { pos, .. }) if *pos == self.pos => None,
+            (0, _) => return Err(malformed_proof()),
+            (n, _) => Some(n - 1),
+        };
+
+        let traversal_path = self.pos.to_traversal_path(height);
+        let mut bits = vec![];
+        let mut siblings = vec![];
+        for (level, node) in self.proof.iter().enumerate().skip(num_empty.max(1)) {
+            let children = match node {
+                MerkleNode::Branch { children, .. } if children.len() == Arity::to_usize() => {
+                    children
+                },
+                _ => return Err(malformed_proof()),
+            };
+            for (branch, child) in children.iter().enumerate() {
+                if branch == traversal_path[level - 1] {
+                    continue;
+                }
+                match **child {
+                    MerkleNode::Empty => bits.push(false),
+                    MerkleNode::ForgettenSubtree { value } => {
+                        bits.push(true);
+                        siblings.push(value);
+                    },
+                    _ => return Err(malformed_proof()),
+                }
+            }
+        }
+
+        Ok(CompressedMerkleProof {
+            pos: self.pos.clone(),
+            height,
+            empty_height,
+            bitmap: pack_bits(&bits),
+            siblings,
+            _phantom_arity: PhantomData,
+        })
+    }
+}
+
+impl<I, T, Arity> CompressedMerkleProof<I, T, Arity>
+where
+    I: Index + ToTraversalPath<Arity>,
+    T: NodeValue,
+    Arity: Unsigned,
+{
+    /// Return the position proven by this proof.
+    pub fn index(&self) -> &I {
+        &self.pos
+    }
+
+    /// Return whether this is a membership proof.
+    pub fn is_membership(&self) -> bool {
+        self.empty_height.is_none()
+    }
+
+    /// Recover the original proof.
+    /// * `elem` - the proven element for a membership proof, `None` for a
+    ///   non-membership proof
+    /// * `returns` - the decompressed proof, or `Err()` if `elem` does not
+    ///   match the proof type or if this proof is malformed
+    pub fn decompress<E, H>(
+        &self,
+        elem: Option<&E>,
+    ) -> Result<MerkleProof<E, I, T, Arity>, PrimitivesError>
+    where
+        E: Element,
+        H: DigestAlgorithm<E, I, T>,
+    {
+        let mut proof = match (self.empty_height, elem) {
+            (None, Some(elem)) => vec![MerkleNode::Leaf {
+                value: H::digest_leaf(&self.pos, elem)?,
+                pos: self.pos.clone(),
+                elem: elem.clone(),
+            }],
+            (Some(empty_height), None) if empty_height <= self.height => {
+                vec![MerkleNode::Empty; empty_height + 1]
+            },
+            _ => {
+                return Err(PrimitivesError::ParameterError(
+                    "Element does not match the proof type".to_string(),
+                ))
+            },
+        };
+
+        let traversal_path = self.pos.to_traversal_path(self.height);
+        let mut bits = vec![];
+        let mut siblings = self.siblings.iter();
+        for level in proof.len()..=self.height {
+            let below = &proof[level - 1];
+            let children = (0..Arity::to_usize())
+                .map(|branch| {
+                    if branch == traversal_path[level - 1] {
+                        return Ok(Arc::new(below.pruned()));
+                    }
+                    let bit = self
+                        .bitmap
+                        .get(bits.len() / 8)
+                        .map(|byte| (byte >> (bits.len() % 8)) & 1 == 1)
+                        .ok_or_else(malformed_proof)?;
+                    bits.push(bit);
+                    if bit {
+                        let value = *siblings.next().ok_or_else(malformed_proof)?;
+                        Ok(Arc::new(MerkleNode::ForgettenSubtree { value }))
+                    } else {
+                        Ok(Arc::new(MerkleNode::Empty))
+                    }
+                })
+                .collect::<Result<Vec<_>, PrimitivesError>>()?;
+            // Only the nodes below the root need their digest, as the path child
+            // of the next branch
+            let value = if level < self.height {
+                digest_branch::<E, H, I, T>(&children)?
+            } else {
+                T::default()
+            };
+            proof.push(MerkleNode::Branch { value, children });
+        }
+        if siblings.next().is_some() || pack_bits(&bits) != self.bitmap {
+            return Err(malformed_proof());
+        }
+
+        // Branches in a proof carry a default value, their digest is only
+        // needed to fill in the path child of their parent.
+        proof.iter_mut().for_each(|node| {
+            if let MerkleNode::Branch { value, .. } = node {
+                *value = T::default();
+            }
+        });
+        Ok(MerkleProof::new(self.pos.clone(), proof))
+    }
+}
+
+/// Pack bits into little-endian bytes.
+fn pack_bits(bits: &[bool]) -> Vec<u8> {
+    bits.chunks(8)
+        .map(|chunk| {
+            chunk
+                .iter()
+                .enumerate()
+                .fold(0u8, |byte, (i, bit)| byte | ((*bit as u8) << i))
+        })
+        .collect()
+}
+
+fn malformed_proof() -> PrimitivesError {
+    PrimitivesError::ParameterError("Malformed Merkle proof".to_string())
+}
+
+#[cfg(test)]
+mod mt_tests {
+    use crate::{
+        merkle_tree::{
+            prelude::{RescueHash, RescueMerkleTree, RescueSparseMerkleTree},
+            *,
+        },
+        rescue::RescueParameter,
+    };
+    use ark_ed_on_bls12_377::Fq as Fq377;
+    use ark_ed_on_bls12_381::Fq as Fq381;
+    use ark_ed_on_bn254::Fq as Fq254;
+    use num_bigint::BigUint;
+
+    #[test]
+    fn test_compressed_membership_proof() {
+        test_compressed_membership_proof_helper::<Fq254>();
+        test_compressed_membership_proof_helper::<Fq377>();
+        test_compressed_membership_proof_helper::<Fq381>();
+    }
+
+    fn test_compressed_membership_proof_helper<F: RescueParameter>() {
+        let mt = RescueMerkleTree::<F>::from_elems(Some(3), (0..10u64).map(F::from)).unwrap();
+        let root = mt.commitment().digest();
+        for pos in 0..10u64 {
+            let (elem, proof) = mt.lookup(pos).expect_ok().unwrap();
+            let compressed = proof.compress().unwrap();
+            assert!(compressed.is_membership());
+            assert_eq!(compressed.index(), &pos);
+            assert!(
+                bincode::serialize(&compressed).unwrap().len()
+                    < bincode::serialize(&proof).unwrap().len()
+            );
+
+            assert_eq!(
+                compressed,
+                bincode::deserialize(&bincode::serialize(&compressed).unwrap()).unwrap()
+            );
+
+            let decompressed = compressed
+                .decompress::<F, RescueHash<F>>(Some(elem))
+                .unwrap();
+            assert_eq!(decompressed, proof);
+            assert!(RescueMerkleTree::<F>::verify(&root, pos, &decompressed)
+                .unwrap()
+                .is_ok());
+
+            // A membership proof needs the element, and only this element
+            assert!(compressed.decompress::<F, RescueHash<F>>(None).is_err());
+            let bad = compressed
+                .decompress::<F, RescueHash<F>>(Some(&F::from(100u64)))
+                .unwrap();
+            assert!(RescueMerkleTree::<F>::verify(&root, pos, &bad)
+                .unwrap()
+                .is_err());
+        }
+
+        // Truncated or padded encodings are rejected
+        let (elem, proof) = mt.lookup(9).expect_ok().unwrap();
+        let compressed = proof.compress().unwrap();
+        let mut bad = compressed.clone();
+        bad.siblings.pop();
+        assert!(bad.decompress::<F, RescueHash<F>>(Some(elem)).is_err());
+        let mut bad = compressed.clone();
+        bad.siblings.push(F::from(0u64));
+        assert!(bad.decompress::<F, RescueHash<F>>(Some(elem)).is_err());
+        let mut bad = compressed;
+        bad.bitmap.push(0);
+        assert!(bad.decompress::<F, RescueHash<F>>(Some(elem)).is_err());
+    }
+
+    #[test]
+    fn test_compressed_non_membership_proof() {
+        test_compressed_non_membership_proof_helper::<Fq254>();
+        test_compressed_non_membership_proof_helper::<Fq377>();
+        test_compressed_non_membership_proof_helper::<Fq381>();
+    }
+
+    fn test_compressed_non_membership_proof_helper<F: RescueParameter>() {
+        let mt = RescueSparseMerkleTree::<BigUint, F>::from_kv_set(
+            10,
+            [
+                (BigUint::from(1u64), F::from(1u64)),
+                (BigUint::from(2u64), F::from(2u64)),
+            ],
+        )
+        .unwrap();
+        let root = mt.commitment().digest();
+        for pos in [0u64, 3, 100, 59048] {
+            let pos = BigUint::from(pos);
+            let proof = mt.universal_lookup(&pos).expect_not_found().unwrap();
+            let compressed = proof.compress().unwrap();
+            assert!(!compressed.is_membership());
+
+            let decompressed = compressed.decompress::<F, RescueHash<F>>(None).unwrap();
+            assert_eq!(decompressed, proof);
+            assert!(RescueSparseMerkleTree::<BigUint, F>::verify_non_membership(
+                &root,
+                &pos,
+                &decompressed
+            )
+            .unwrap()
+            .is_ok());
+            assert!(compressed
+                .decompress::<F, RescueHash<F>>(Some(&F::from(0u64)))
+                .is_err());
+        }
+
+        // Proofs from an empty tree only consist of empty nodes
+        let empty = RescueSparseMerkleTree::<BigUint, F>::new(10);
+        let proof = empty
+            .universal_lookup(BigUint::from(0u64))
+            .expect_not_found()
+            .unwrap();
+        let compressed = proof.compress().unwrap();
+        assert_eq!(compressed.empty_height, Some(10));
+        assert!(compressed.bitmap.is_empty() && compressed.siblings.is_empty());
+        assert_eq!(
+            compressed.decompress::<F, RescueHash<F>>(None).unwrap(),
+            proof
+        );
+    }
+}
@@ -0,0 +1,497 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+//! Consistency proofs between two commitments of an appendable Merkle tree,
+//! in the spirit of [RFC 6962](https://www.rfc-editor.org/rfc/rfc6962#section-2.1.2).
+//!
+//! Our trees have a fixed height, so the tree with `n` leaves and any of its
+//! extensions only differ on the right of the path to the leaf `n - 1`: all
+//! the siblings on the left of this path are full subtrees, and all the
+//! siblings on its right are empty in the old tree. A consistency proof is
+//! thus the membership proof of the leaf `n - 1` in the new tree, from which
+//! the verifier recomputes both the new root and, after emptying every
+//! sibling on the right of the path, the old root.
+//!
+//! The same membership proof of the last leaf in the old tree, i.e. its
+//! frontier, also proves which elements have been appended: a
+//! [`MerkleAppendProof`] lets a verifier who only knows both commitments and
+//! the appended elements replay the insertions on a light weight tree.
+//! Along with the appended elements, it is also all a holder of a membership
+//! proof needs to refresh it against the new commitment, see
+//! [`MerkleAppendProof::update_membership_proof`].
+
+use super::{
+    append_only::MerkleTree,
+    internal::{MerkleNode, MerkleProof, MerkleTreeCommitment},
+    light_weight::LightWeightMerkleTree,
+    AppendableMerkleTreeScheme, DigestAlgorithm, Element, ForgetableMerkleTreeScheme, LookupResult,
+    MerkleCommitment, MerkleTreeScheme, NodeValue, ToTraversalPath,
+};
+use crate::errors::{PrimitivesError, VerificationResult};
+use alloc::sync::Arc;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use ark_std::{borrow::Borrow, string::ToString};
+use serde::{Deserialize, Serialize};
+use typenum::Unsigned;
+
+/// A proof that a commitment of an appendable tree is an append-only
+/// extension of an older commitment of the same tree.
+#[derive(Derivative, Debug, Clone, Serialize, Deserialize)]
+#[derivative(Eq, Hash, PartialEq)]
+#[serde(bound = "E: CanonicalSerialize + CanonicalDeserialize")]
+pub struct MerkleConsistencyProof<E, T, Arity>
+where
+    E: Element,
+    T: NodeValue,
+    Arity: Unsigned,
+{
+    /// Membership proof of the last leaf of the old tree in the new tree,
+    /// `None` if the old tree is empty.
+    pub proof: Option<MerkleProof<E, u64, T, Arity>>,
+}
+
+impl<E, T, Arity> MerkleConsistencyProof<E, T, Arity>
+where
+    E: Element,
+    T: NodeValue,
+    Arity: Unsigned,
+{
+    /// Prove that the current content of `mt` extends its first
+    /// `old_num_leaves` leaves.
+    /// * `returns` - a consistency proof, or `Err()` if `old_num_leaves`
+    ///   exceeds the number of leaves of `mt`, or if the leaf at position
+    ///   `old_num_leaves - 1` has been forgotten
+    pub fn generate<MT>(mt: &MT, old_num_leaves: u64) -> Result<Self, PrimitivesError>
+    where
+        MT: MerkleTreeScheme<
+            Element = E,
+            Index = u64,
+            NodeValue = T,
+            MembershipProof = MerkleProof<E, u64, T, Arity>,
+        >,
+    {
+        if old_num_leaves > mt.num_leaves() {
+            return Err(PrimitivesError::ParameterError(
+                "Old tree is larger than the current one".to_string(),
+            ));
+        }
+        if old_num_leaves == 0 {
+            return Ok(Self { proof: None });
+        }
+        match mt.lookup(old_num_leaves - 1) {
+            LookupResult::Ok(_, proof) => Ok(Self { proof: Some(proof) }),
+            LookupResult::NotInMemory(_) => Err(PrimitivesError::ParameterError(
+                "Last leaf of the old tree is forgotten".to_string(),
+            )),
+            LookupResult::NotFound(_) => Err(PrimitivesError::InternalError(
+                "Missing leaf in an appendable tree".to_string(),
+            )),
+        }
+    }
+
+    /// Verify that `new` is an append-only extension of `old`.
+    /// * `returns` - Ok(Ok(())) if the proof is accepted, Ok(Err(())) if not.
+    ///   Err() if the proof is not well structured, E.g. not for this merkle
+    ///   tree.
+    pub fn verify<H>(
+        &self,
+        old: &MerkleTreeCommitment<T>,
+        new: &MerkleTreeCommitment<T>,
+    ) -> Result<VerificationResult, PrimitivesError>
+    where
+        H: DigestAlgorithm<E, u64, T>,
+    {
+        if old.height() != new.height() {
+            return Err(PrimitivesError::ParameterError(
+                "Commitments are for trees of different heights".to_string(),
+            ));
+        }
+        if old.size() > new.size() {
+            return Ok(Err(()));
+        }
+        let proof = match &self.proof {
+            None if old.size() == 0 => {
+                return Ok(if old.digest() == T::default() {
+                    Ok(())
+                } else {
+                    Err(())
+                })
+            },
+            Some(proof) if old.size() == proof.pos + 1 => proof,
+            _ => return Ok(Err(())),
+        };
+        if proof.tree_height() != old.height() + 1 {
+            return Err(PrimitivesError::ParameterError(
+                "Incompatible proof for this merkle tree".to_string(),
+            ));
+        }
+        if proof.verify_membership_proof::<H>(&new.digest())?.is_err() {
+            return Ok(Err(()));
+        }
+
+        // In the old tree, everything on the right of its last leaf is empty
+        let traversal_path = ToTraversalPath::<Arity>::to_traversal_path(&proof.pos, old.height());
+        let mut old_proof = proof.clone();
+        old_proof
+            .proof
+            .iter_mut()
+            .skip(1)
+            .zip(traversal_path)
+            .for_each(|(node, branch)| {
+                if let MerkleNode::Branch { children, .. } = node {
+                    children
+                        .iter_mut()
+                        .skip(branch + 1)
+                        .for_each(|child| *child = Arc::new(MerkleNode::Empty));
+                }
+            });
+        old_proof.verify_membership_proof::<H>(&old.digest())
+    }
+}
+
+/// A proof that appending a batch of elements to a tree yields a given
+/// commitment, see the [module documentation](self).
+#[derive(Derivative, Debug, Clone, Serialize, Deserialize)]
+#[derivative(Eq, Hash, PartialEq)]
+#[serde(bound = "E: CanonicalSerialize + CanonicalDeserialize")]
+pub struct MerkleAppendProof<E, T, Arity>
+where
+    E: Element,
+    T: NodeValue,
+    Arity: Unsigned,
+{
+    /// Membership proof of the last leaf of the tree before the insertions,
+    /// `None` if this tree is empty.
+    pub frontier: Option<MerkleProof<E, u64, T, Arity>>,
+}
+
+impl<E, T, Arity> MerkleAppendProof<E, T, Arity>
+where
+    E: Element,
+    T: NodeValue,
+    Arity: Unsigned,
+{
+    /// Prove the insertions which will be performed on the current content of
+    /// `mt`. The proof should be generated before the insertions.
+    /// * `returns` - an append proof, or `Err()` if the last leaf of `mt` has
+    ///   been forgotten
+    pub fn generate<MT>(mt: &MT) -> Result<Self, PrimitivesError>
+    where
+        MT: MerkleTreeScheme<
+            Element = E,
+            Index = u64,
+            NodeValue = T,
+            MembershipProof = MerkleProof<E, u64, T, Arity>,
+        >,
+    {
+        let proof = MerkleConsistencyProof::<E, T, Arity>::generate(mt, mt.num_leaves())?.proof;
+        Ok(Self { frontier: proof })
+    }
+
+    /// Verify that appending `elems` to the tree committed by `old` results
+    /// in the tree committed by `new`.
+    /// * `returns` - Ok(Ok(())) if the proof is accepted, Ok(Err(())) if not.
+    ///   Err() if the proof is not well structured, E.g. not for this merkle
+    ///   tree.
+    pub fn verify<H>(
+        &self,
+        old: &MerkleTreeCommitment<T>,
+        elems: impl IntoIterator<Item = impl Borrow<E>>,
+        new: &MerkleTreeCommitment<T>,
+    ) -> Result<VerificationResult, PrimitivesError>
+    where
+        H: DigestAlgorithm<E, u64, T>,
+    {
+        if old.height() != new.height() {
+            return Err(PrimitivesError::ParameterError(
+                "Commitments are for trees of different heights".to_string(),
+            ));
+        }
+        let mut mt = match &self.frontier {
+            None if old.size() == 0 => {
+                if old.digest() != T::default() {
+                    return Ok(Err(()));
+                }
+                LightWeightMerkleTree::<E, H, u64, Arity, T>::new(old.height())
+            },
+            Some(frontier) if old.size() == frontier.pos + 1 => {
+                if frontier.tree_height() != old.height() + 1 {
+                    return Err(PrimitivesError::ParameterError(
+                        "Incompatible proof for this merkle tree".to_string(),
+                    ));
+                }
+                if frontier
+                    .verify_membership_proof::<H>(&old.digest())?
+                    .is_err()
+                {
+                    return Ok(Err(()));
+                }
+                LightWeightMerkleTree::<E, H, u64, Arity, T>::from_frontier(old, frontier)?
+            },
+            _ => return Ok(Err(())),
+        };
+        if mt.extend(elems).is_err() {
+            // Too many elements for the tree
+            return Ok(Err(()));
+        }
+        Ok(if mt.commitment() == *new {
+            Ok(())
+        } else {
+            Err(())
+        })
+    }
+
+    /// Update a membership proof of a leaf of the tree committed by `old`, so
+    /// that it is valid in the tree obtained by appending `elems`. Only the
+    /// leaf, the frontier and the appended elements are needed, not the
+    /// whole tree.
+    /// * `old` - commitment of the tree before the insertions
+    /// * `proof` - membership proof of the leaf against `old`
+    /// * `elems` - the elements appended since
+    /// * `returns` - the membership proof of the same leaf in the new tree, or
+    ///   `Err()` if `proof` or this append proof is invalid for `old`, or if
+    ///   there are too many elements for the tree
+    pub fn update_membership_proof<H>(
+        &self,
+        old: &MerkleTreeCommitment<T>,
+        proof: &MerkleProof<E, u64, T, Arity>,
+        elems: impl IntoIterator<Item = impl Borrow<E>>,
+    ) -> Result<MerkleProof<E, u64, T, Arity>, PrimitivesError>
+    where
+        H: DigestAlgorithm<E, u64, T>,
+    {
+        let invalid_proof = || PrimitivesError::ParameterError("Invalid proof".to_string());
+        if proof.pos >= old.size() || proof.tree_height() != old.height() + 1 {
+            return Err(invalid_proof());
+        }
+        let frontier = match &self.frontier {
+            Some(frontier)
+                if frontier.pos + 1 == old.size() && frontier.tree_height() == old.height() + 1 =>
+            {
+                frontier
+            },
+            _ => return Err(invalid_proof()),
+        };
+        // Replay the insertions on a tree which only remembers the leaf and
+        // the frontier, both are checked against the old commitment.
+        let mut mt = MerkleTree::<E, H, u64, Arity, T>::from_commitment(old);
+        mt.remember(
+            frontier.pos,
+            frontier.elem().ok_or_else(invalid_proof)?,
+            frontier,
+        )?;
+        mt.remember(proof.pos, proof.elem().ok_or_else(invalid_proof)?, proof)?;
+        mt.extend(elems)?;
+        match mt.lookup(proof.pos) {
+            LookupResult::Ok(_, proof) => Ok(proof),
+            _ => Err(PrimitivesError::InternalError(
+                "Remembered leaf is missing".to_string(),
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod mt_tests {
+    use crate::{
+        merkle_tree::{
+            prelude::{RescueHash, RescueLightWeightMerkleTree, RescueMerkleTree},
+            *,
+        },
+        rescue::RescueParameter,
+    };
+    use ark_ed_on_bls12_377::Fq as Fq377;
+    use ark_ed_on_bls12_381::Fq as Fq381;
+    use ark_ed_on_bn254::Fq as Fq254;
+
+    #[test]
+    fn test_mt_consistency_proof() {
+        test_mt_consistency_proof_helper::<Fq254>();
+        test_mt_consistency_proof_helper::<Fq377>();
+        test_mt_consistency_proof_helper::<Fq381>();
+    }
+
+    fn test_mt_consistency_proof_helper<F: RescueParameter>() {
+        let elems: Vec<F> = (0..20u64).map(F::from).collect();
+        let mut mt = RescueMerkleTree::<F>::new(3);
+        let mut commitments = vec![mt.commitment()];
+        for elem in elems.iter() {
+            mt.push(elem).unwrap();
+            commitments.push(mt.commitment());
+        }
+
+        for (old_num_leaves, old) in commitments.iter().enumerate() {
+            let proof = mt.consistency_proof(old_num_leaves as u64).unwrap();
+            assert_eq!(
+                proof,
+                bincode::deserialize(&bincode::serialize(&proof).unwrap()).unwrap()
+            );
+            assert!(proof
+                .verify::<RescueHash<F>>(old, &mt.commitment())
+                .unwrap()
+                .is_ok());
+            // The proof is only valid for this pair of commitments
+            for (i, other) in commitments.iter().enumerate() {
+                if i != old_num_leaves && other.size() != 0 {
+                    assert!(proof
+                        .verify::<RescueHash<F>>(other, &mt.commitment())
+                        .unwrap()
+                        .is_err());
+                }
+            }
+            if old_num_leaves > 0 && old_num_leaves + 1 < elems.len() {
+                assert!(proof
+                    .verify::<RescueHash<F>>(old, &commitments[old_num_leaves + 1])
+                    .unwrap()
+                    .is_err());
+            }
+        }
+        assert!(mt.consistency_proof(21).is_err());
+
+        // A rewritten history is detected
+        let mut forked = elems.clone();
+        forked[5] = F::from(100u64);
+        let forked = RescueMerkleTree::<F>::from_elems(Some(3), &forked).unwrap();
+        for old_num_leaves in 6..=20 {
+            let proof = forked.consistency_proof(old_num_leaves).unwrap();
+            assert!(proof
+                .verify::<RescueHash<F>>(
+                    &commitments[old_num_leaves as usize],
+                    &forked.commitment()
+                )
+                .unwrap()
+                .is_err());
+        }
+
+        // Light weight trees can only prove consistency with the leaves they
+        // still remember
+        let light_mt = RescueLightWeightMerkleTree::<F>::from_elems(Some(3), &elems).unwrap();
+        let proof = light_mt.consistency_proof(20).unwrap();
+        assert!(proof
+            .verify::<RescueHash<F>>(&commitments[20], &mt.commitment())
+            .unwrap()
+            .is_ok());
+        assert!(light_mt.consistency_proof(10).is_err());
+    }
+
+    #[test]
+    fn test_mt_append_proof() {
+        test_mt_append_proof_helper::<Fq254>();
+        test_mt_append_proof_helper::<Fq377>();
+        test_mt_append_proof_helper::<Fq381>();
+    }
+
+    fn test_mt_append_proof_helper<F: RescueParameter>() {
+        let elems: Vec<F> = (0..20u64).map(F::from).collect();
+        let mut mt = RescueLightWeightMerkleTree::<F>::new(3);
+        for batch in [
+            &elems[..0],
+            &elems[..1],
+            &elems[1..8],
+            &elems[8..9],
+            &elems[9..],
+        ] {
+            let old = mt.commitment();
+            let proof = mt.append_proof().unwrap();
+            assert_eq!(
+                proof,
+                bincode::deserialize(&bincode::serialize(&proof).unwrap()).unwrap()
+            );
+            mt.extend(batch).unwrap();
+            let new = mt.commitment();
+            assert!(proof
+                .verify::<RescueHash<F>>(&old, batch, &new)
+                .unwrap()
+                .is_ok());
+
+            // Wrong batches or commitments are rejected
+            assert!(proof
+                .verify::<RescueHash<F>>(&old, [F::from(100u64)].iter().chain(batch), &new)
+                .unwrap()
+                .is_err());
+            if !batch.is_empty() {
+                assert!(proof
+                    .verify::<RescueHash<F>>(&old, &batch[1..], &new)
+                    .unwrap()
+                    .is_err());
+                assert!(proof
+                    .verify::<RescueHash<F>>(&new, batch, &new)
+                    .unwrap()
+                    .is_err());
+            }
+        }
+
+        // A full tree proves the same insertions, but not beyond its capacity
+        let full_mt = RescueMerkleTree::<F>::from_elems(Some(3), &elems).unwrap();
+        let proof = full_mt.append_proof().unwrap();
+        assert_eq!(proof, mt.append_proof().unwrap());
+        let mut extended = full_mt.clone();
+        extended.extend(&elems[..7]).unwrap();
+        assert!(proof
+            .verify::<RescueHash<F>>(&full_mt.commitment(), &elems[..7], &extended.commitment())
+            .unwrap()
+            .is_ok());
+        assert!(proof
+            .verify::<RescueHash<F>>(&full_mt.commitment(), &elems[..8], &extended.commitment())
+            .unwrap()
+            .is_err());
+
+        // Proofs are refreshed without the rest of the tree
+        for old_num_leaves in [1usize, 2, 3, 9, 10, 19] {
+            let old_mt =
+                RescueMerkleTree::<F>::from_elems(Some(3), &elems[..old_num_leaves]).unwrap();
+            let old = old_mt.commitment();
+            let append_proof = old_mt.append_proof().unwrap();
+            for pos in 0..old_num_leaves as u64 {
+                let (_, proof) = old_mt.lookup(pos).expect_ok().unwrap();
+                let new_proof = append_proof
+                    .update_membership_proof::<RescueHash<F>>(
+                        &old,
+                        &proof,
+                        &elems[old_num_leaves..],
+                    )
+                    .unwrap();
+                assert_eq!(new_proof, full_mt.lookup(pos).expect_ok().unwrap().1);
+                assert!(RescueMerkleTree::<F>::verify(
+                    full_mt.commitment().digest(),
+                    pos,
+                    &new_proof
+                )
+                .unwrap()
+                .is_ok());
+            }
+
+            // Proofs inconsistent with the old commitment are rejected
+            let (_, proof) = full_mt.lookup(0).expect_ok().unwrap();
+            assert!(append_proof
+                .update_membership_proof::<RescueHash<F>>(&old, &proof, &elems[old_num_leaves..])
+                .is_err());
+            let (_, proof) = old_mt.lookup(0).expect_ok().unwrap();
+            assert!(append_proof
+                .update_membership_proof::<RescueHash<F>>(
+                    &full_mt.commitment(),
+                    &proof,
+                    &elems[old_num_leaves..],
+                )
+                .is_err());
+        }
+        let old_mt = RescueMerkleTree::<F>::from_elems(Some(3), &elems[..5]).unwrap();
+        let (_, proof) = old_mt.lookup(2).expect_ok().unwrap();
+        assert!(old_mt
+            .append_proof()
+            .unwrap()
+            .update_membership_proof::<RescueHash<F>>(
+                &old_mt.commitment(),
+                &proof,
+                (0..23u64).map(F::from)
+            )
+            .is_err());
+
+        // No proof once the last leaf is forgotten
+        mt.forget(19).expect_ok().unwrap();
+        assert!(mt.append_proof().is_err());
+    }
+}
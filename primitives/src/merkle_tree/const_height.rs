@@ -0,0 +1,372 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+//! An append-only Merkle tree whose height is a type-level constant.
+//!
+//! The membership proofs of a [`ConstHeightMerkleTree`] are
+//! [`ConstHeightMerkleProof`]s, which hold the children of every level of the
+//! path in fixed-size arrays rather than in a `Vec` of nodes. Their size is
+//! therefore known at compile time, they can be allocated on the stack, and
+//! [`ConstHeightMerkleProof::verify`] doesn't allocate, which suits embedded
+//! and `no_std` verifiers.
+//!
+//! A [`ConstHeightMerkleTree`] has the same commitment as the
+//! [`MerkleTree`] of the same height and content, and its proofs can be
+//! converted from the proofs of such a tree with
+//! [`ConstHeightMerkleProof::from_merkle_proof`].
+
+use super::{
+    append_only::MerkleTree,
+    internal::{MerkleBatchProof, MerkleNode, MerkleProof, MerkleTreeCommitment, MerkleTreeIter},
+    AppendableMerkleTreeScheme, DigestAlgorithm, Element, LookupResult, MerkleTreeScheme,
+    MissingSubtree, NodeValue,
+};
+use crate::errors::{PrimitivesError, VerificationResult};
+use ark_std::{borrow::Borrow, string::ToString, vec::Vec};
+use generic_array::{ArrayLength, GenericArray};
+use num_bigint::BigUint;
+use typenum::Unsigned;
+
+/// A membership proof of a [`ConstHeightMerkleTree`] of height `HEIGHT`.
+#[derive(Derivative, Debug, Clone)]
+#[derivative(Eq, Hash, PartialEq)]
+pub struct ConstHeightMerkleProof<E, T, Arity, const HEIGHT: usize>
+where
+    E: Element,
+    T: NodeValue,
+    Arity: Unsigned + ArrayLength<T>,
+{
+    /// Position of the proven leaf
+    pub pos: u64,
+    /// The proven leaf
+    pub elem: E,
+    /// Values of the children of each node on the path, from the leaf up. The
+    /// values on the path itself are ignored by the verifier.
+    pub children: [GenericArray<T, Arity>; HEIGHT],
+}
+
+impl<E, T, Arity, const HEIGHT: usize> ConstHeightMerkleProof<E, T, Arity, HEIGHT>
+where
+    E: Element,
+    T: NodeValue,
+    Arity: Unsigned + ArrayLength<T>,
+{
+    /// Convert a membership proof of a [`MerkleTree`] of height `HEIGHT`.
+    /// * `returns` - Err() if the proof is not well structured, or not for a
+    ///   tree of height `HEIGHT`
+    pub fn from_merkle_proof(
+        proof: &MerkleProof<E, u64, T, Arity>,
+    ) -> Result<Self, PrimitivesError> {
+        let elem = proof
+            .elem()
+            .ok_or_else(|| PrimitivesError::ParameterError("Invalid proof type".to_string()))?;
+        if proof.tree_height() != HEIGHT + 1 {
+            return Err(PrimitivesError::ParameterError(
+                "Proof is not for a tree of this height".to_string(),
+            ));
+        }
+        let children = proof.proof[1..]
+            .iter()
+            .map(|node| match node {
+                MerkleNode::Branch { children, .. } => {
+                    GenericArray::from_exact_iter(children.iter().map(|child| child.value()))
+                },
+                _ => None,
+            })
+            .collect::<Option<Vec<_>>>()
+            .ok_or_else(|| {
+                PrimitivesError::ParameterError(
+                    "Incompatible proof for this merkle tree".to_string(),
+                )
+            })?;
+        Ok(Self {
+            pos: proof.pos,
+            elem: elem.clone(),
+            children: children.try_into().unwrap_or_else(|_| unreachable!()),
+        })
+    }
+
+    /// Verify the proof against a root, without allocating.
+    /// * `returns` - Ok(true) if the proof is accepted, Ok(false) if not. Err()
+    ///   if the position doesn't fit in a tree of height `HEIGHT`.
+    pub fn verify<H: DigestAlgorithm<E, u64, T>>(
+        &self,
+        root: impl Borrow<T>,
+    ) -> Result<VerificationResult, PrimitivesError> {
+        if matches!(num_traits::checked_pow(Arity::to_u64(), HEIGHT), Some(cap) if self.pos >= cap)
+        {
+            return Err(PrimitivesError::ParameterError(
+                "Position is out of the tree".to_string(),
+            ));
+        }
+        let mut index = self.pos;
+        let mut value = H::digest_leaf(&self.pos, &self.elem)?;
+        for children in self.children.iter() {
+            let mut data = children.clone();
+            data[(index % Arity::to_u64()) as usize] = value;
+            value = H::digest(&data)?;
+            index /= Arity::to_u64();
+        }
+        if value == *root.borrow() {
+            Ok(Ok(()))
+        } else {
+            Ok(Err(()))
+        }
+    }
+}
+
+/// An append-only Merkle tree of height `HEIGHT`, see the [module
+/// documentation](self).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ConstHeightMerkleTree<E, H, Arity, T, const HEIGHT: usize>
+where
+    E: Element,
+    H: DigestAlgorithm<E, u64, T>,
+    Arity: Unsigned + ArrayLength<T>,
+    T: NodeValue,
+{
+    tree: MerkleTree<E, H, u64, Arity, T>,
+}
+
+impl<E, H, Arity, T, const HEIGHT: usize> ConstHeightMerkleTree<E, H, Arity, T, HEIGHT>
+where
+    E: Element,
+    H: DigestAlgorithm<E, u64, T>,
+    Arity: Unsigned + ArrayLength<T>,
+    T: NodeValue,
+{
+    /// Initialize an empty Merkle tree.
+    pub fn new() -> Self {
+        Self {
+            tree: MerkleTree::new(HEIGHT),
+        }
+    }
+
+    /// Construct a new Merkle tree from a data slice
+    /// * `elems` - an iterator to all elements
+    /// * `returns` - A constructed Merkle tree, or `Err()` if there are too
+    ///   many elements for the height
+    pub fn from_elems(
+        elems: impl IntoIterator<Item = impl Borrow<E>>,
+    ) -> Result<Self, PrimitivesError> {
+        Self::from_tree(MerkleTree::from_elems(Some(HEIGHT), elems)?)
+    }
+
+    /// Use an existing Merkle tree of height `HEIGHT`.
+    /// * `returns` - Err() if the tree has another height
+    pub fn from_tree(tree: MerkleTree<E, H, u64, Arity, T>) -> Result<Self, PrimitivesError> {
+        if tree.height() != HEIGHT {
+            return Err(PrimitivesError::ParameterError(
+                "Merkle tree height mismatch".to_string(),
+            ));
+        }
+        Ok(Self { tree })
+    }
+
+    /// Return the underlying Merkle tree.
+    pub fn into_tree(self) -> MerkleTree<E, H, u64, Arity, T> {
+        self.tree
+    }
+}
+
+impl<E, H, Arity, T, const HEIGHT: usize> Default for ConstHeightMerkleTree<E, H, Arity, T, HEIGHT>
+where
+    E: Element,
+    H: DigestAlgorithm<E, u64, T>,
+    Arity: Unsigned + ArrayLength<T>,
+    T: NodeValue,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<E, H, Arity, T, const HEIGHT: usize> MerkleTreeScheme
+    for ConstHeightMerkleTree<E, H, Arity, T, HEIGHT>
+where
+    E: Element,
+    H: DigestAlgorithm<E, u64, T>,
+    Arity: Unsigned + ArrayLength<T>,
+    T: NodeValue,
+{
+    type Element = E;
+    type Index = u64;
+    type NodeValue = T;
+    type MembershipProof = ConstHeightMerkleProof<E, T, Arity, HEIGHT>;
+    type BatchMembershipProof = MerkleBatchProof<E, u64, T, Arity>;
+    type Commitment = MerkleTreeCommitment<T>;
+
+    const ARITY: usize = Arity::USIZE;
+
+    fn height(&self) -> usize {
+        HEIGHT
+    }
+
+    fn capacity(&self) -> BigUint {
+        self.tree.capacity()
+    }
+
+    fn num_leaves(&self) -> u64 {
+        self.tree.num_leaves()
+    }
+
+    fn commitment(&self) -> Self::Commitment {
+        self.tree.commitment()
+    }
+
+    fn lookup(
+        &self,
+        pos: impl Borrow<Self::Index>,
+    ) -> LookupResult<
+        &Self::Element,
+        Self::MembershipProof,
+        (),
+        Option<MissingSubtree<Self::NodeValue>>,
+    > {
+        match self.tree.lookup(pos) {
+            LookupResult::Ok(elem, proof) => LookupResult::Ok(
+                elem,
+                ConstHeightMerkleProof::from_merkle_proof(&proof)
+                    .expect("Proofs of the tree should have its height"),
+            ),
+            LookupResult::NotInMemory(missing) => LookupResult::NotInMemory(missing),
+            LookupResult::NotFound(()) => LookupResult::NotFound(()),
+        }
+    }
+
+    fn verify(
+        root: impl Borrow<Self::NodeValue>,
+        pos: impl Borrow<Self::Index>,
+        proof: impl Borrow<Self::MembershipProof>,
+    ) -> Result<VerificationResult, PrimitivesError> {
+        let proof = proof.borrow();
+        if *pos.borrow() != proof.pos {
+            return Ok(Err(()));
+        }
+        proof.verify::<H>(root)
+    }
+
+    fn multi_lookup(
+        &self,
+        pos: impl IntoIterator<Item = impl Borrow<Self::Index>>,
+    ) -> LookupResult<Vec<Self::Element>, Self::BatchMembershipProof, ()> {
+        self.tree.multi_lookup(pos)
+    }
+
+    fn verify_batch(
+        root: impl Borrow<Self::NodeValue>,
+        pos: impl IntoIterator<Item = impl Borrow<Self::Index>>,
+        proof: impl Borrow<Self::BatchMembershipProof>,
+    ) -> Result<VerificationResult, PrimitivesError> {
+        MerkleTree::<E, H, u64, Arity, T>::verify_batch(root, pos, proof)
+    }
+
+    fn iter(&self) -> MerkleTreeIter<E, u64, T> {
+        self.tree.iter()
+    }
+}
+
+impl<E, H, Arity, T, const HEIGHT: usize> AppendableMerkleTreeScheme
+    for ConstHeightMerkleTree<E, H, Arity, T, HEIGHT>
+where
+    E: Element,
+    H: DigestAlgorithm<E, u64, T>,
+    Arity: Unsigned + ArrayLength<T>,
+    T: NodeValue,
+{
+    fn push(&mut self, elem: impl Borrow<Self::Element>) -> Result<(), PrimitivesError> {
+        self.tree.push(elem)
+    }
+
+    fn extend(
+        &mut self,
+        elems: impl IntoIterator<Item = impl Borrow<Self::Element>>,
+    ) -> Result<(), PrimitivesError> {
+        self.tree.extend(elems)
+    }
+
+    fn truncate(&mut self, num_leaves: u64) -> Result<(), PrimitivesError> {
+        self.tree.truncate(num_leaves)
+    }
+}
+
+#[cfg(test)]
+mod mt_tests {
+    use crate::{
+        merkle_tree::{
+            const_height::{ConstHeightMerkleProof, ConstHeightMerkleTree},
+            prelude::{RescueHash, RescueMerkleTree},
+            *,
+        },
+        rescue::RescueParameter,
+    };
+    use ark_ed_on_bls12_377::Fq as Fq377;
+    use ark_ed_on_bls12_381::Fq as Fq381;
+    use ark_ed_on_bn254::Fq as Fq254;
+    use typenum::U3;
+
+    type RescueConstHeightMerkleTree<F, const HEIGHT: usize> =
+        ConstHeightMerkleTree<F, RescueHash<F>, U3, F, HEIGHT>;
+
+    #[test]
+    fn test_const_height_mt() {
+        test_const_height_mt_helper::<Fq254>();
+        test_const_height_mt_helper::<Fq377>();
+        test_const_height_mt_helper::<Fq381>();
+    }
+
+    fn test_const_height_mt_helper<F: RescueParameter>() {
+        let elems: Vec<F> = (0..10u64).map(F::from).collect();
+        let mut mt = RescueConstHeightMerkleTree::<F, 3>::from_elems(&elems).unwrap();
+        let full_mt = RescueMerkleTree::<F>::from_elems(Some(3), &elems).unwrap();
+        assert_eq!(mt.commitment(), full_mt.commitment());
+        assert_eq!(mt.height(), 3);
+        assert!(
+            RescueConstHeightMerkleTree::<F, 3>::from_tree(RescueMerkleTree::<F>::new(4)).is_err()
+        );
+
+        let root = mt.commitment().digest();
+        for pos in 0..10u64 {
+            let (elem, proof) = mt.lookup(pos).expect_ok().unwrap();
+            assert_eq!(elem, &F::from(pos));
+            assert_eq!(proof.children.len(), 3);
+            assert!(
+                RescueConstHeightMerkleTree::<F, 3>::verify(root, pos, &proof)
+                    .unwrap()
+                    .is_ok()
+            );
+            assert!(
+                RescueConstHeightMerkleTree::<F, 3>::verify(root, pos + 1, &proof)
+                    .unwrap()
+                    .is_err()
+            );
+            let mut bad_proof = proof.clone();
+            bad_proof.elem = F::from(100u64);
+            assert!(bad_proof.verify::<RescueHash<F>>(root).unwrap().is_err());
+
+            // Proofs of the dynamically sized tree convert to the same proofs
+            let full_proof = full_mt.lookup(pos).expect_ok().unwrap().1;
+            assert_eq!(
+                ConstHeightMerkleProof::from_merkle_proof(&full_proof).unwrap(),
+                proof
+            );
+        }
+        assert!(mt.lookup(10).expect_not_found().is_ok());
+
+        // Proofs of a tree of another height are rejected
+        let other_mt = RescueMerkleTree::<F>::from_elems(Some(4), &elems).unwrap();
+        let other_proof = other_mt.lookup(0).expect_ok().unwrap().1;
+        assert!(ConstHeightMerkleProof::<F, F, U3, 3>::from_merkle_proof(&other_proof).is_err());
+
+        mt.extend((10..27u64).map(F::from)).unwrap();
+        assert!(mt.push(F::from(27u64)).is_err());
+        let (_, proof) = mt.lookup(26).expect_ok().unwrap();
+        assert!(proof
+            .verify::<RescueHash<F>>(mt.commitment().digest())
+            .unwrap()
+            .is_ok());
+    }
+}
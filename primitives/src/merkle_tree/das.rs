@@ -0,0 +1,326 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+//! A two-dimensional Merkle commitment for data-availability sampling.
+//!
+//! The data is laid out as a `k x k` square of field elements, which is
+//! erasure-extended into a `2k x 2k` square: every row is extended with `k`
+//! parity elements, then every column of the resulting `k x 2k` rectangle is
+//! extended the same way. The encoding is systematic, i.e. the original
+//! square is the top-left quadrant of the extended one, and since it is
+//! linear, the parity rows are themselves codewords, so every row and every
+//! column of the extended square can be recovered from any `k` of its
+//! elements.
+//!
+//! Each row and each column of the extended square is committed in its own
+//! [`MerkleTree`], and the commitment of the square, a [`DasCommitment`], is
+//! the list of all row and column roots. A sample at `(row, col)` comes with a
+//! membership proof in both its row tree and its column tree, see
+//! [`DasSampleProof`].
+
+use super::{
+    append_only::MerkleTree, internal::MerkleProof, DigestAlgorithm, Element, LookupResult,
+    MerkleCommitment, MerkleTreeScheme, NodeValue,
+};
+use crate::{
+    errors::{PrimitivesError, VerificationResult},
+    reed_solomon_code::{reed_solomon_erasure_decode, reed_solomon_erasure_encode},
+};
+use ark_ff::Field;
+use ark_std::{borrow::Borrow, format, string::ToString, vec::Vec};
+use jf_utils::canonical;
+use serde::{Deserialize, Serialize};
+use typenum::Unsigned;
+
+/// Commitment to an extended data square: the roots of its row trees and
+/// column trees.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct DasCommitment<T: NodeValue> {
+    /// Roots of the `2k` row trees, from top to bottom
+    #[serde(with = "canonical")]
+    pub row_roots: Vec<T>,
+    /// Roots of the `2k` column trees, from left to right
+    #[serde(with = "canonical")]
+    pub col_roots: Vec<T>,
+}
+
+/// Proof that an element is in an extended data square at a given `(row,
+/// col)` position.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(bound = "E: ark_serialize::CanonicalSerialize + ark_serialize::CanonicalDeserialize")]
+pub struct DasSampleProof<E, T, Arity>
+where
+    E: Element,
+    T: NodeValue,
+    Arity: Unsigned,
+{
+    /// Membership proof at position `col` of the tree of row `row`
+    pub row_proof: MerkleProof<E, u64, T, Arity>,
+    /// Membership proof at position `row` of the tree of column `col`
+    pub col_proof: MerkleProof<E, u64, T, Arity>,
+}
+
+/// An erasure-extended data square with its row and column trees, see the
+/// [module documentation](self).
+#[derive(Debug, Clone)]
+pub struct DataSquare<F, H, Arity, T>
+where
+    F: Field,
+    H: DigestAlgorithm<F, u64, T>,
+    Arity: Unsigned,
+    T: NodeValue,
+{
+    /// The `2k x 2k` extended square, row by row
+    square: Vec<Vec<F>>,
+    row_trees: Vec<MerkleTree<F, H, u64, Arity, T>>,
+    col_trees: Vec<MerkleTree<F, H, u64, Arity, T>>,
+}
+
+impl<F, H, Arity, T> DataSquare<F, H, Arity, T>
+where
+    F: Field,
+    H: DigestAlgorithm<F, u64, T>,
+    Arity: Unsigned,
+    T: NodeValue,
+{
+    /// Extend a square of data and commit to it.
+    /// * `data` - the `k x k` original square, row by row
+    /// * `returns` - Err() if `data` is empty or not square
+    pub fn new(data: &[Vec<F>]) -> Result<Self, PrimitivesError> {
+        let k = data.len();
+        if k == 0 || data.iter().any(|row| row.len() != k) {
+            return Err(PrimitivesError::ParameterError(
+                "Data must be a non-empty square".to_string(),
+            ));
+        }
+        let rows = data
+            .iter()
+            .map(|row| extend(row))
+            .collect::<Result<Vec<_>, _>>()?;
+        // Extending the columns of the top half yields the bottom half
+        let cols = (0..2 * k)
+            .map(|col| extend(&rows.iter().map(|row| row[col]).collect::<Vec<_>>()))
+            .collect::<Result<Vec<_>, _>>()?;
+        let square: Vec<Vec<F>> = (0..2 * k)
+            .map(|row| cols.iter().map(|col| col[row]).collect())
+            .collect();
+
+        let row_trees = square
+            .iter()
+            .map(|row| MerkleTree::from_elems(None, row))
+            .collect::<Result<Vec<_>, _>>()?;
+        let col_trees = cols
+            .iter()
+            .map(|col| MerkleTree::from_elems(None, col))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self {
+            square,
+            row_trees,
+            col_trees,
+        })
+    }
+
+    /// Return the width `k` of the original square.
+    pub fn original_width(&self) -> usize {
+        self.square.len() / 2
+    }
+
+    /// Return the extended square, row by row.
+    pub fn extended_square(&self) -> &[Vec<F>] {
+        &self.square
+    }
+
+    /// Return the commitment to the extended square.
+    pub fn commitment(&self) -> DasCommitment<T> {
+        DasCommitment {
+            row_roots: self
+                .row_trees
+                .iter()
+                .map(|mt| mt.commitment().digest())
+                .collect(),
+            col_roots: self
+                .col_trees
+                .iter()
+                .map(|mt| mt.commitment().digest())
+                .collect(),
+        }
+    }
+
+    /// Return the element of the extended square at `(row, col)` along with
+    /// its sample proof.
+    /// * `returns` - Err() if the position is out of the extended square
+    pub fn sample(
+        &self,
+        row: usize,
+        col: usize,
+    ) -> Result<(F, DasSampleProof<F, T, Arity>), PrimitivesError> {
+        let width = self.square.len();
+        if row >= width || col >= width {
+            return Err(PrimitivesError::ParameterError(format!(
+                "Sample ({row}, {col}) is out of the {width}x{width} square"
+            )));
+        }
+        let row_proof = match self.row_trees[row].lookup(col as u64) {
+            LookupResult::Ok(_, proof) => proof,
+            _ => return Err(missing_sample()),
+        };
+        let col_proof = match self.col_trees[col].lookup(row as u64) {
+            LookupResult::Ok(_, proof) => proof,
+            _ => return Err(missing_sample()),
+        };
+        Ok((
+            self.square[row][col],
+            DasSampleProof {
+                row_proof,
+                col_proof,
+            },
+        ))
+    }
+
+    /// Verify that `elem` is at `(row, col)` in the extended square committed
+    /// by `commitment`.
+    /// * `returns` - Ok(true) if the proof is accepted, Ok(false) if not. Err()
+    ///   if the proof or the commitment is not well structured.
+    pub fn verify_sample(
+        commitment: &DasCommitment<T>,
+        row: usize,
+        col: usize,
+        elem: impl Borrow<F>,
+        proof: &DasSampleProof<F, T, Arity>,
+    ) -> Result<VerificationResult, PrimitivesError> {
+        let (row_root, col_root) =
+            match (commitment.row_roots.get(row), commitment.col_roots.get(col)) {
+                (Some(row_root), Some(col_root)) => (row_root, col_root),
+                _ => {
+                    return Err(PrimitivesError::ParameterError(format!(
+                        "Sample ({row}, {col}) is out of the committed square"
+                    )))
+                },
+            };
+        let elem = elem.borrow();
+        if proof.row_proof.elem() != Some(elem) || proof.col_proof.elem() != Some(elem) {
+            return Ok(Err(()));
+        }
+        if MerkleTree::<F, H, u64, Arity, T>::verify(row_root, col as u64, &proof.row_proof)?
+            .is_err()
+        {
+            return Ok(Err(()));
+        }
+        MerkleTree::<F, H, u64, Arity, T>::verify(col_root, row as u64, &proof.col_proof)
+    }
+}
+
+/// Systematically extend `data` with as many parity elements: `data` are the
+/// evaluations at `1..=k` of the polynomial of degree less than `k` which is
+/// then evaluated at `1..=2k`.
+fn extend<F: Field>(data: &[F]) -> Result<Vec<F>, PrimitivesError> {
+    let k = data.len();
+    let coeffs = reed_solomon_erasure_decode(
+        data.iter()
+            .enumerate()
+            .map(|(i, elem)| (F::from(i as u64 + 1), *elem)),
+        k,
+    )?;
+    Ok(reed_solomon_erasure_encode(coeffs, k)?.collect())
+}
+
+fn missing_sample() -> PrimitivesError {
+    PrimitivesError::InternalError("Sample is missing from its tree".to_string())
+}
+
+#[cfg(test)]
+mod mt_tests {
+    use crate::{
+        merkle_tree::{das::DataSquare, prelude::RescueHash},
+        reed_solomon_code::reed_solomon_erasure_decode,
+        rescue::RescueParameter,
+    };
+    use ark_ed_on_bls12_377::Fq as Fq377;
+    use ark_ed_on_bls12_381::Fq as Fq381;
+    use ark_ed_on_bn254::Fq as Fq254;
+    use typenum::U3;
+
+    type RescueDataSquare<F> = DataSquare<F, RescueHash<F>, U3, F>;
+
+    #[test]
+    fn test_data_square() {
+        test_data_square_helper::<Fq254>();
+        test_data_square_helper::<Fq377>();
+        test_data_square_helper::<Fq381>();
+    }
+
+    fn test_data_square_helper<F: RescueParameter>() {
+        assert!(RescueDataSquare::<F>::new(&[]).is_err());
+        assert!(RescueDataSquare::<F>::new(&[vec![F::from(1u64)], vec![]]).is_err());
+
+        let k = 4;
+        let data: Vec<Vec<F>> = (0..k)
+            .map(|i| (0..k).map(|j| F::from((i * k + j) as u64)).collect())
+            .collect();
+        let square = RescueDataSquare::<F>::new(&data).unwrap();
+        assert_eq!(square.original_width(), k);
+        let extended = square.extended_square();
+        assert_eq!(extended.len(), 2 * k);
+
+        // The original data is the top-left quadrant, and every row and
+        // column can be recovered from any k of its elements
+        for i in 0..k {
+            assert_eq!(extended[i][..k], data[i][..]);
+        }
+        for i in 0..2 * k {
+            let row = &extended[i];
+            let col: Vec<F> = extended.iter().map(|row| row[i]).collect();
+            for line in [row, &col] {
+                let shares: Vec<_> = (k..2 * k)
+                    .map(|j| (F::from(j as u64 + 1), line[j]))
+                    .collect();
+                let expected: Vec<_> = (0..k).map(|j| (F::from(j as u64 + 1), line[j])).collect();
+                assert_eq!(
+                    reed_solomon_erasure_decode(shares, k).unwrap(),
+                    reed_solomon_erasure_decode(expected, k).unwrap()
+                );
+            }
+        }
+
+        // Samples are verified against both their row and column roots
+        let commitment = square.commitment();
+        assert_eq!(commitment.row_roots.len(), 2 * k);
+        assert_eq!(commitment.col_roots.len(), 2 * k);
+        for (row, col) in [(0, 0), (1, 3), (5, 2), (7, 7)] {
+            let (elem, proof) = square.sample(row, col).unwrap();
+            assert_eq!(elem, extended[row][col]);
+            assert!(
+                RescueDataSquare::<F>::verify_sample(&commitment, row, col, elem, &proof)
+                    .unwrap()
+                    .is_ok()
+            );
+            assert!(RescueDataSquare::<F>::verify_sample(
+                &commitment,
+                row,
+                col,
+                elem + F::from(1u64),
+                &proof
+            )
+            .unwrap()
+            .is_err());
+            assert!(RescueDataSquare::<F>::verify_sample(
+                &commitment,
+                (row + 1) % (2 * k),
+                col,
+                elem,
+                &proof
+            )
+            .unwrap()
+            .is_err());
+            assert!(
+                RescueDataSquare::<F>::verify_sample(&commitment, 2 * k, col, elem, &proof)
+                    .is_err()
+            );
+        }
+        assert!(square.sample(2 * k, 0).is_err());
+        assert!(square.sample(0, 2 * k).is_err());
+    }
+}
@@ -0,0 +1,459 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+//! An append-only Merkle tree which only caches the digests of its filled
+//! subtrees along the frontier.
+//!
+//! Every subtree left of the path to the last leaf is full and will never
+//! change again, so a [`FrontierMerkleTree`] only keeps, at each level of the
+//! tree, the digests of the filled subtrees which are siblings of that path.
+//! Appending a leaf hashes the path from the new leaf to the root exactly
+//! once, i.e. one leaf digest and `height` branch digests, without ever
+//! revisiting a finalized subtree or allocating tree nodes. The cache lives
+//! directly in the (serializable) state of the tree, which is
+//! `O(height * Arity)` digests.
+//!
+//! The commitment of a [`FrontierMerkleTree`] is the same as the one of a
+//! [`MerkleTree`](super::append_only::MerkleTree) or a
+//! [`LightWeightMerkleTree`] with the same content. Only the membership proof
+//! of the last leaf, see [`FrontierMerkleTree::frontier`], can be served;
+//! for anything else, switch to a [`LightWeightMerkleTree`] with
+//! [`FrontierMerkleTree::into_light_weight`].
+
+use super::{
+    internal::{MerkleNode, MerkleProof, MerkleTreeCommitment},
+    light_weight::LightWeightMerkleTree,
+    DigestAlgorithm, Element, NodeValue, ToTraversalPath,
+};
+use crate::errors::PrimitivesError;
+use alloc::sync::Arc;
+use ark_std::{borrow::Borrow, marker::PhantomData, string::ToString, vec, vec::Vec};
+use jf_utils::canonical;
+use serde::{Deserialize, Serialize};
+use typenum::Unsigned;
+
+/// An append-only Merkle tree keeping only the digests of its filled subtrees
+/// along the frontier, see the [module documentation](self).
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(bound = "E: ark_serialize::CanonicalSerialize + ark_serialize::CanonicalDeserialize")]
+pub struct FrontierMerkleTree<E, H, Arity, T>
+where
+    E: Element,
+    H: DigestAlgorithm<E, u64, T>,
+    Arity: Unsigned,
+    T: NodeValue,
+{
+    height: usize,
+    num_leaves: u64,
+    /// Digests of the filled subtrees at each level left of the path to the
+    /// last leaf, from the leaves up.
+    #[serde(with = "canonical")]
+    filled: Vec<Vec<T>>,
+    /// Digests of the nodes on the path to the last leaf, from the leaf up to
+    /// the root. Empty if there is no leaf.
+    #[serde(with = "canonical")]
+    path: Vec<T>,
+    /// The last leaf.
+    #[serde(with = "canonical")]
+    last: Option<E>,
+    _phantom: PhantomData<(H, Arity)>,
+}
+
+impl<E, H, Arity, T> FrontierMerkleTree<E, H, Arity, T>
+where
+    E: Element,
+    H: DigestAlgorithm<E, u64, T>,
+    Arity: Unsigned,
+    T: NodeValue,
+{
+    /// Initialize an empty Merkle tree.
+    pub fn new(height: usize) -> Self {
+        Self {
+            height,
+            num_leaves: 0,
+            filled: vec![vec![]; height],
+            path: vec![],
+            last: None,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Construct a new Merkle tree with given height from a data slice
+    /// * `height` - height of the Merkle tree
+    /// * `elems` - an iterator to all elements
+    /// * `returns` - A constructed Merkle tree, or `Err()` if there are too
+    ///   many elements for the given height
+    pub fn from_elems(
+        height: usize,
+        elems: impl IntoIterator<Item = impl Borrow<E>>,
+    ) -> Result<Self, PrimitivesError> {
+        let mut mt = Self::new(height);
+        mt.extend(elems)?;
+        Ok(mt)
+    }
+
+    /// Return the height of this tree.
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Return the number of leaves in this tree.
+    pub fn num_leaves(&self) -> u64 {
+        self.num_leaves
+    }
+
+    /// Return the maximum number of leaves of this tree, or `None` if it does
+    /// not fit in a `u64`.
+    pub fn capacity(&self) -> Option<u64> {
+        num_traits::checked_pow(Arity::to_u64(), self.height)
+    }
+
+    /// Return the commitment of this tree.
+    pub fn commitment(&self) -> MerkleTreeCommitment<T> {
+        MerkleTreeCommitment::new(
+            self.path.last().copied().unwrap_or_default(),
+            self.height,
+            self.num_leaves,
+        )
+    }
+
+    /// Return the digests of the filled subtrees at level `height` (0 being
+    /// the leaves) which are left of the path to the last leaf, i.e. the
+    /// cached digests used by the next insertion at this level.
+    pub fn filled_subtrees(&self, height: usize) -> &[T] {
+        self.filled.get(height).map(Vec::as_slice).unwrap_or(&[])
+    }
+
+    /// Insert a new leaf, hashing only the path from this leaf to the root.
+    /// * `returns` - Err() if the tree is full, in which case it is left
+    ///   unchanged
+    pub fn push(&mut self, elem: impl Borrow<E>) -> Result<(), PrimitivesError> {
+        let elem = elem.borrow();
+        if matches!(self.capacity(), Some(capacity) if self.num_leaves >= capacity) {
+            return Err(PrimitivesError::ParameterError(
+                "Exceed merkle tree capacity".to_string(),
+            ));
+        }
+        let pos = self.num_leaves;
+        let traversal_path = ToTraversalPath::<Arity>::to_traversal_path(&pos, self.height);
+
+        let mut cur = H::digest_leaf(&pos, elem)?;
+        let mut filled = Vec::with_capacity(self.height);
+        let mut path = Vec::with_capacity(self.height + 1);
+        path.push(cur);
+        // Whether the ancestor of the new leaf at the current level is a new
+        // node, rather than the ancestor of the previous last leaf.
+        let mut new_node = true;
+        for (level, &branch) in traversal_path.iter().enumerate() {
+            let left = if pos == 0 || (new_node && branch == 0) {
+                vec![]
+            } else if new_node {
+                // The ancestor of the previous last leaf at this level is now
+                // filled and becomes a left sibling.
+                let mut left = self.filled[level].clone();
+                left.push(self.path[level]);
+                left
+            } else {
+                self.filled[level].clone()
+            };
+            new_node = new_node && branch == 0;
+
+            let mut children = Vec::with_capacity(Arity::to_usize());
+            children.extend_from_slice(&left);
+            children.push(cur);
+            children.resize(Arity::to_usize(), T::default());
+            cur = H::digest(&children)?;
+            path.push(cur);
+            filled.push(left);
+        }
+
+        self.filled = filled;
+        self.path = path;
+        self.last = Some(elem.clone());
+        self.num_leaves += 1;
+        Ok(())
+    }
+
+    /// Insert a list of new leaves, see [`Self::push`].
+    /// * `returns` - Err() if the tree gets full, in which case the elements
+    ///   which fit have been inserted.
+    pub fn extend(
+        &mut self,
+        elems: impl IntoIterator<Item = impl Borrow<E>>,
+    ) -> Result<(), PrimitivesError> {
+        for elem in elems {
+            self.push(elem)?;
+        }
+        Ok(())
+    }
+
+    /// Return the frontier of this tree, i.e. the membership proof of its
+    /// last leaf, which is the same as the one served by a full Merkle tree
+    /// with the same content. Together with the commitment of the tree, it is
+    /// enough to restore the tree with [`Self::from_frontier`].
+    /// * `returns` - `None` if the tree is empty
+    pub fn frontier(&self) -> Option<MerkleProof<E, u64, T, Arity>> {
+        let elem = self.last.as_ref()?;
+        let pos = self.num_leaves - 1;
+        let traversal_path = ToTraversalPath::<Arity>::to_traversal_path(&pos, self.height);
+        let mut proof = Vec::with_capacity(self.height + 1);
+        proof.push(MerkleNode::Leaf {
+            value: self.path[0],
+            pos,
+            elem: elem.clone(),
+        });
+        for (level, &branch) in traversal_path.iter().enumerate() {
+            let mut children: Vec<_> = self.filled[level]
+                .iter()
+                .chain([&self.path[level]])
+                .map(|&value| Arc::new(MerkleNode::ForgettenSubtree { value }))
+                .collect();
+            debug_assert_eq!(children.len(), branch + 1);
+            children.resize_with(Arity::to_usize(), || Arc::new(MerkleNode::Empty));
+            proof.push(MerkleNode::Branch {
+                value: T::default(),
+                children,
+            });
+        }
+        Some(MerkleProof::new(pos, proof))
+    }
+
+    /// Restore a tree from its commitment and its frontier, as returned by
+    /// [`Self::frontier`] or by
+    /// [`LightWeightMerkleTree::frontier`]. An empty tree has no frontier
+    /// and should be restored with [`Self::new`] instead.
+    /// * `commitment` - commitment of the tree to restore
+    /// * `frontier` - membership proof of the right-most leaf of the tree
+    /// * `returns` - the restored tree, which accepts further insertions, or
+    ///   `Err()` if the frontier is inconsistent with the commitment
+    pub fn from_frontier(
+        commitment: &MerkleTreeCommitment<T>,
+        frontier: impl Borrow<MerkleProof<E, u64, T, Arity>>,
+    ) -> Result<Self, PrimitivesError> {
+        let frontier = frontier.borrow();
+        let height = commitment.height();
+        let num_leaves = commitment.size();
+        if num_leaves == 0 {
+            return Err(PrimitivesError::ParameterError(
+                "An empty tree has no frontier".to_string(),
+            ));
+        }
+        if frontier.pos != num_leaves - 1 || frontier.tree_height() != height + 1 {
+            return Err(PrimitivesError::ParameterError(
+                "Frontier does not match the tree commitment".to_string(),
+            ));
+        }
+        let elem = frontier.elem().ok_or_else(|| {
+            PrimitivesError::ParameterError("Frontier is missing its leaf".to_string())
+        })?;
+        let traversal_path = ToTraversalPath::<Arity>::to_traversal_path(&frontier.pos, height);
+        let path = frontier.membership_path_values::<H>(&traversal_path, elem)?;
+        if path.last() != Some(&commitment.digest()) {
+            return Err(PrimitivesError::ParameterError(
+                "Frontier does not match the tree commitment".to_string(),
+            ));
+        }
+        let mut filled = Vec::with_capacity(height);
+        for (&branch, node) in traversal_path.iter().zip(frontier.proof.iter().skip(1)) {
+            match node {
+                // Everything right of the last leaf is empty
+                MerkleNode::Branch { children, .. }
+                    if children[branch + 1..]
+                        .iter()
+                        .all(|child| matches!(**child, MerkleNode::Empty)) =>
+                {
+                    filled.push(
+                        children[..branch]
+                            .iter()
+                            .map(|child| child.value())
+                            .collect(),
+                    )
+                },
+                _ => {
+                    return Err(PrimitivesError::ParameterError(
+                        "Frontier has non-empty nodes right of the last leaf".to_string(),
+                    ))
+                },
+            }
+        }
+        Ok(Self {
+            height,
+            num_leaves,
+            filled,
+            path,
+            last: Some(elem.clone()),
+            _phantom: PhantomData,
+        })
+    }
+
+    /// Convert this tree into a light weight Merkle tree with the same
+    /// content, which only remembers its last leaf.
+    pub fn into_light_weight(self) -> LightWeightMerkleTree<E, H, u64, Arity, T> {
+        match self.frontier() {
+            Some(frontier) => LightWeightMerkleTree::from_frontier(&self.commitment(), frontier)
+                .expect("The frontier should be consistent with the commitment"),
+            None => LightWeightMerkleTree::new(self.height),
+        }
+    }
+}
+
+#[cfg(test)]
+mod mt_tests {
+    use crate::{
+        merkle_tree::{
+            frontier::FrontierMerkleTree,
+            prelude::{RescueHash, RescueMerkleTree},
+            *,
+        },
+        rescue::RescueParameter,
+    };
+    use ark_bls12_377::Fq as Fq377;
+    use ark_bls12_381::Fq as Fq381;
+    use ark_bn254::Fq as Fq254;
+    use core::sync::atomic::{AtomicUsize, Ordering};
+    use typenum::U3;
+
+    static NUM_HASHES: AtomicUsize = AtomicUsize::new(0);
+
+    /// A Rescue hash which counts its invocations.
+    struct CountingHash;
+
+    impl<F: RescueParameter> DigestAlgorithm<F, u64, F> for CountingHash {
+        fn digest(data: &[F]) -> Result<F, PrimitivesError> {
+            NUM_HASHES.fetch_add(1, Ordering::Relaxed);
+            <RescueHash<F> as DigestAlgorithm<F, u64, F>>::digest(data)
+        }
+
+        fn digest_leaf(pos: &u64, elem: &F) -> Result<F, PrimitivesError> {
+            NUM_HASHES.fetch_add(1, Ordering::Relaxed);
+            <RescueHash<F> as DigestAlgorithm<F, u64, F>>::digest_leaf(pos, elem)
+        }
+    }
+
+    #[test]
+    fn test_frontier_mt() {
+        test_frontier_mt_helper::<Fq254>();
+        test_frontier_mt_helper::<Fq377>();
+        test_frontier_mt_helper::<Fq381>();
+    }
+
+    fn test_frontier_mt_helper<F: RescueParameter>() {
+        let height = 3;
+        let mut mt = FrontierMerkleTree::<F, RescueHash<F>, U3, F>::new(height);
+        let mut full_mt = RescueMerkleTree::<F>::new(height);
+        assert_eq!(mt.commitment(), full_mt.commitment());
+        assert!(mt.frontier().is_none());
+        assert_eq!(
+            mt.clone().into_light_weight().commitment(),
+            full_mt.commitment()
+        );
+
+        for i in 0..27u64 {
+            mt.push(F::from(i)).unwrap();
+            full_mt.push(F::from(i)).unwrap();
+            assert_eq!(mt.commitment(), full_mt.commitment());
+            assert_eq!(mt.num_leaves(), i + 1);
+
+            let frontier = mt.frontier().unwrap();
+            assert_eq!(frontier, full_mt.lookup(i).expect_ok().unwrap().1);
+            assert!(
+                RescueMerkleTree::<F>::verify(mt.commitment().digest(), i, &frontier)
+                    .unwrap()
+                    .is_ok()
+            );
+            assert_eq!(
+                mt.filled_subtrees(0).len() as u64,
+                (i + 1) % 3,
+                "Filled leaves should be cached until their branch is full"
+            );
+        }
+        // The tree is full
+        assert!(mt.push(F::from(27u64)).is_err());
+        assert_eq!(mt.commitment(), full_mt.commitment());
+
+        // Switching to a light weight tree keeps the content
+        let mut mt = FrontierMerkleTree::<F, RescueHash<F>, U3, F>::from_elems(
+            height,
+            (0..10u64).map(F::from),
+        )
+        .unwrap();
+        let mut light_mt = mt.clone().into_light_weight();
+        let mut full_mt =
+            RescueMerkleTree::<F>::from_elems(height, (0..10u64).map(F::from)).unwrap();
+        assert_eq!(light_mt.commitment(), full_mt.commitment());
+        mt.extend((10..15u64).map(F::from)).unwrap();
+        light_mt.extend((10..15u64).map(F::from)).unwrap();
+        full_mt.extend((10..15u64).map(F::from)).unwrap();
+        assert_eq!(mt.commitment(), full_mt.commitment());
+        assert_eq!(light_mt.commitment(), full_mt.commitment());
+        assert_eq!(
+            mt.frontier().unwrap(),
+            light_mt.lookup(14).expect_ok().unwrap().1
+        );
+
+        // Restoring from the frontier, including the one of a light weight
+        // tree, keeps appending to the same tree
+        let mut restored = FrontierMerkleTree::<F, RescueHash<F>, U3, F>::from_frontier(
+            &light_mt.commitment(),
+            light_mt.frontier().unwrap(),
+        )
+        .unwrap();
+        assert_eq!(restored, mt);
+        restored.push(F::from(15u64)).unwrap();
+        full_mt.push(F::from(15u64)).unwrap();
+        assert_eq!(restored.commitment(), full_mt.commitment());
+
+        // Inconsistent frontiers are rejected
+        let commitment = mt.commitment();
+        let proof = full_mt.lookup(14).expect_ok().unwrap().1;
+        assert!(
+            FrontierMerkleTree::<F, RescueHash<F>, U3, F>::from_frontier(&commitment, &proof)
+                .is_err()
+        );
+        let proof = full_mt.lookup(13).expect_ok().unwrap().1;
+        assert!(
+            FrontierMerkleTree::<F, RescueHash<F>, U3, F>::from_frontier(&commitment, &proof)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_frontier_mt_hash_count() {
+        let height = 10;
+        let mut mt = FrontierMerkleTree::<Fq254, CountingHash, U3, Fq254>::new(height);
+        for i in 0..100u64 {
+            NUM_HASHES.store(0, Ordering::Relaxed);
+            mt.push(Fq254::from(i)).unwrap();
+            // One leaf digest and exactly one digest per level
+            assert_eq!(NUM_HASHES.load(Ordering::Relaxed), height + 1);
+        }
+        let full_mt =
+            RescueMerkleTree::<Fq254>::from_elems(height, (0..100u64).map(Fq254::from)).unwrap();
+        assert_eq!(mt.commitment().digest(), full_mt.commitment().digest());
+    }
+
+    #[test]
+    fn test_frontier_mt_serde() {
+        test_frontier_mt_serde_helper::<Fq254>();
+        test_frontier_mt_serde_helper::<Fq377>();
+        test_frontier_mt_serde_helper::<Fq381>();
+    }
+
+    fn test_frontier_mt_serde_helper<F: RescueParameter>() {
+        let mut mt =
+            FrontierMerkleTree::<F, RescueHash<F>, U3, F>::from_elems(3, (0..5u64).map(F::from))
+                .unwrap();
+        let restored: FrontierMerkleTree<F, RescueHash<F>, U3, F> =
+            bincode::deserialize(&bincode::serialize(&mt).unwrap()).unwrap();
+        assert_eq!(restored, mt);
+
+        // The restored cache is enough to keep appending
+        let mut restored = restored;
+        mt.push(F::from(5u64)).unwrap();
+        restored.push(F::from(5u64)).unwrap();
+        assert_eq!(restored.commitment(), mt.commitment());
+    }
+}
@@ -0,0 +1,503 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+//! Implementation of an indexed Merkle tree, a.k.a. a nullifier tree.
+//!
+//! Leaves are appended from left to right and form a linked list sorted by
+//! value: each leaf stores its value together with the index and the value of
+//! its successor. The first leaf is always the zero leaf, and the last leaf of
+//! the list points to `(0, 0)`. This turns a non-membership proof for a value
+//! into a membership proof for its predecessor (the "low leaf"), which is much
+//! cheaper to verify in a circuit than a proof over a tree indexed by value.
+
+use super::{
+    internal::{MerkleProof, MerkleTreeCommitment},
+    prelude::UniversalMerkleTree,
+    DigestAlgorithm, LookupResult, MerkleCommitment, MerkleTreeScheme, UniversalMerkleTreeScheme,
+};
+use crate::{
+    errors::{PrimitivesError, VerificationResult},
+    rescue::{sponge::RescueCRHF, RescueParameter},
+};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use ark_std::{borrow::Borrow, collections::BTreeMap, marker::PhantomData, string::ToString};
+use num_bigint::BigUint;
+use num_traits::pow::pow;
+use serde::{Deserialize, Serialize};
+use typenum::U3;
+
+/// A leaf of an indexed Merkle tree.
+#[derive(
+    Clone, Copy, Debug, Default, PartialEq, Eq, Hash, CanonicalSerialize, CanonicalDeserialize,
+)]
+pub struct IndexedLeaf<F: RescueParameter> {
+    /// The value stored in this leaf
+    pub value: F,
+    /// Index of the leaf storing the next larger value, 0 if none
+    pub next_index: u64,
+    /// The next larger value in the tree, 0 if none
+    pub next_value: F,
+}
+
+impl<F: RescueParameter> IndexedLeaf<F> {
+    /// Whether `value` lies strictly between this leaf and its successor.
+    pub fn is_low_leaf_of(&self, value: &F) -> bool {
+        self.value < *value && (self.next_value.is_zero() || *value < self.next_value)
+    }
+}
+
+/// Rescue-based digest of indexed leaves. A leaf is hashed as
+/// `H(value, next_index, next_value)`, internal nodes as the usual 3-ary
+/// rescue digest.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct IndexedLeafHash<F: RescueParameter> {
+    phantom_f: PhantomData<F>,
+}
+
+impl<F: RescueParameter> DigestAlgorithm<IndexedLeaf<F>, u64, F> for IndexedLeafHash<F> {
+    fn digest(data: &[F]) -> Result<F, PrimitivesError> {
+        Ok(RescueCRHF::<F>::sponge_no_padding(data, 1)?[0])
+    }
+
+    fn digest_leaf(_pos: &u64, elem: &IndexedLeaf<F>) -> Result<F, PrimitivesError> {
+        let data = [elem.value, F::from(elem.next_index), elem.next_value];
+        Ok(RescueCRHF::<F>::sponge_no_padding(&data, 1)?[0])
+    }
+}
+
+/// The underlying 3-ary Merkle tree of an indexed Merkle tree.
+pub type IndexedLeafMerkleTree<F> =
+    UniversalMerkleTree<IndexedLeaf<F>, IndexedLeafHash<F>, u64, U3, F>;
+
+/// A Merkle proof of a leaf of an indexed Merkle tree.
+pub type IndexedMerkleProof<F> = MerkleProof<IndexedLeaf<F>, u64, F, U3>;
+
+/// A non-membership proof of a value, i.e. a membership proof of its low leaf.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(bound = "F: RescueParameter")]
+pub struct IndexedNonMembershipProof<F: RescueParameter> {
+    /// Membership proof of the low leaf
+    pub low_leaf_proof: IndexedMerkleProof<F>,
+}
+
+/// A proof that a new root is obtained by inserting a value into the tree
+/// with the old root.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(bound = "F: RescueParameter")]
+pub struct IndexedInsertionProof<F: RescueParameter> {
+    /// Membership proof of the low leaf against the old root
+    pub low_leaf_proof: IndexedMerkleProof<F>,
+    /// Non-membership proof of the new leaf's position, against the root
+    /// obtained after updating the low leaf
+    pub new_leaf_proof: IndexedMerkleProof<F>,
+}
+
+/// An indexed Merkle tree, see the module documentation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IndexedMerkleTree<F: RescueParameter> {
+    tree: IndexedLeafMerkleTree<F>,
+    /// Position of every value in the tree
+    index: BTreeMap<F, u64>,
+}
+
+impl<F: RescueParameter> IndexedMerkleTree<F> {
+    /// Initialize an indexed Merkle tree of a given height, containing only
+    /// the zero leaf.
+    pub fn new(height: usize) -> Result<Self, PrimitivesError> {
+        if height == 0 {
+            return Err(PrimitivesError::ParameterError(
+                "Indexed merkle tree should have a positive height".to_string(),
+            ));
+        }
+        let mut tree = IndexedLeafMerkleTree::<F>::new(height);
+        tree.update(0, IndexedLeaf::default())?;
+        let mut index = BTreeMap::new();
+        index.insert(F::zero(), 0);
+        Ok(Self { tree, index })
+    }
+
+    /// Return the height of the tree
+    pub fn height(&self) -> usize {
+        self.tree.height()
+    }
+
+    /// Return the number of leaves, including the zero leaf
+    pub fn num_leaves(&self) -> u64 {
+        self.tree.num_leaves()
+    }
+
+    /// Return the current commitment
+    pub fn commitment(&self) -> MerkleTreeCommitment<F> {
+        self.tree.commitment()
+    }
+
+    /// Whether `value` is in the tree
+    pub fn contains(&self, value: &F) -> bool {
+        self.index.contains_key(value)
+    }
+
+    /// Look up a value in the tree
+    /// * `returns` - The leaf storing `value` with its membership proof, or a
+    ///   non-membership proof if `value` is not in the tree.
+    pub fn lookup(
+        &self,
+        value: &F,
+    ) -> Result<
+        LookupResult<&IndexedLeaf<F>, IndexedMerkleProof<F>, IndexedNonMembershipProof<F>>,
+        PrimitivesError,
+    > {
+        match self.index.get(value) {
+            Some(pos) => {
+                let (leaf, proof) = self.tree.lookup(pos).expect_ok()?;
+                Ok(LookupResult::Ok(leaf, proof))
+            },
+            None => {
+                let (_, proof) = self.low_leaf(value)?;
+                Ok(LookupResult::NotFound(IndexedNonMembershipProof {
+                    low_leaf_proof: proof,
+                }))
+            },
+        }
+    }
+
+    /// Insert a new non-zero value into the tree
+    /// * `returns` - A proof of the insertion, or `Err()` if the value is
+    ///   already in the tree or the tree is full.
+    pub fn insert(&mut self, value: F) -> Result<IndexedInsertionProof<F>, PrimitivesError> {
+        if self.contains(&value) {
+            return Err(PrimitivesError::ParameterError(
+                "Value already exists in the indexed merkle tree".to_string(),
+            ));
+        }
+        let new_pos = self.num_leaves();
+        if BigUint::from(new_pos) >= pow(BigUint::from(3u64), self.height()) {
+            return Err(PrimitivesError::ParameterError(
+                "Exceed merkle tree capacity".to_string(),
+            ));
+        }
+        let (low_leaf, low_leaf_proof) = self.low_leaf(&value)?;
+        let new_leaf = IndexedLeaf {
+            value,
+            next_index: low_leaf.next_index,
+            next_value: low_leaf.next_value,
+        };
+        let updated_low_leaf = IndexedLeaf {
+            value: low_leaf.value,
+            next_index: new_pos,
+            next_value: value,
+        };
+
+        self.tree.update(low_leaf_proof.pos, updated_low_leaf)?;
+        let new_leaf_proof = self.tree.universal_lookup(new_pos).expect_not_found()?;
+        self.tree.update(new_pos, new_leaf)?;
+        self.index.insert(value, new_pos);
+
+        Ok(IndexedInsertionProof {
+            low_leaf_proof,
+            new_leaf_proof,
+        })
+    }
+
+    /// Verify a membership proof of `value` against `root`
+    pub fn verify_membership(
+        root: impl Borrow<F>,
+        value: &F,
+        proof: impl Borrow<IndexedMerkleProof<F>>,
+    ) -> Result<VerificationResult, PrimitivesError> {
+        let proof = proof.borrow();
+        match proof.elem() {
+            Some(leaf) if leaf.value == *value => {
+                IndexedLeafMerkleTree::<F>::verify(root, proof.pos, proof)
+            },
+            _ => Ok(Err(())),
+        }
+    }
+
+    /// Verify a non-membership proof of `value` against `root`
+    pub fn verify_non_membership(
+        root: impl Borrow<F>,
+        value: &F,
+        proof: impl Borrow<IndexedNonMembershipProof<F>>,
+    ) -> Result<VerificationResult, PrimitivesError> {
+        let proof = &proof.borrow().low_leaf_proof;
+        match proof.elem() {
+            Some(low_leaf) if low_leaf.is_low_leaf_of(value) => {
+                IndexedLeafMerkleTree::<F>::verify(root, proof.pos, proof)
+            },
+            _ => Ok(Err(())),
+        }
+    }
+
+    /// Verify that `new_root` is obtained by inserting `value` into the tree
+    /// whose root is `old_root` and which has `num_leaves` leaves, the new
+    /// leaf being appended at position `num_leaves`.
+    pub fn verify_insertion(
+        old_root: impl Borrow<F>,
+        new_root: impl Borrow<F>,
+        num_leaves: u64,
+        value: &F,
+        proof: impl Borrow<IndexedInsertionProof<F>>,
+    ) -> Result<VerificationResult, PrimitivesError> {
+        let proof = proof.borrow();
+        let new_pos = proof.new_leaf_proof.pos;
+        if new_pos != num_leaves {
+            return Ok(Err(()));
+        }
+        let low_leaf_proof = &proof.low_leaf_proof;
+        let low_leaf = match low_leaf_proof.elem() {
+            Some(low_leaf) if low_leaf.is_low_leaf_of(value) => low_leaf,
+            _ => return Ok(Err(())),
+        };
+        if IndexedLeafMerkleTree::<F>::verify(old_root, low_leaf_proof.pos, low_leaf_proof)?
+            .is_err()
+        {
+            return Ok(Err(()));
+        }
+
+        let updated_low_leaf = IndexedLeaf {
+            value: low_leaf.value,
+            next_index: new_pos,
+            next_value: *value,
+        };
+        let intermediate_root =
+            low_leaf_proof.compute_root_with_elem::<IndexedLeafHash<F>>(&updated_low_leaf)?;
+        if !proof
+            .new_leaf_proof
+            .verify_non_membership_proof::<IndexedLeafHash<F>>(&intermediate_root)?
+        {
+            return Ok(Err(()));
+        }
+
+        let new_leaf = IndexedLeaf {
+            value: *value,
+            next_index: low_leaf.next_index,
+            next_value: low_leaf.next_value,
+        };
+        if proof
+            .new_leaf_proof
+            .compute_root_with_elem::<IndexedLeafHash<F>>(&new_leaf)?
+            == *new_root.borrow()
+        {
+            Ok(Ok(()))
+        } else {
+            Ok(Err(()))
+        }
+    }
+
+    /// Find the low leaf of a value not in the tree, with its proof.
+    fn low_leaf(
+        &self,
+        value: &F,
+    ) -> Result<(IndexedLeaf<F>, IndexedMerkleProof<F>), PrimitivesError> {
+        let pos = self
+            .index
+            .range(..*value)
+            .next_back()
+            .map(|(_, pos)| *pos)
+            .ok_or_else(|| {
+                PrimitivesError::ParameterError("Zero is always in the tree".to_string())
+            })?;
+        let (leaf, proof) = self.tree.lookup(pos).expect_ok()?;
+        Ok((*leaf, proof))
+    }
+}
+
+#[cfg(test)]
+mod indexed_mt_tests {
+    use super::*;
+    use ark_ed_on_bls12_377::Fq as Fq377;
+    use ark_ed_on_bls12_381::Fq as Fq381;
+    use ark_ed_on_bn254::Fq as Fq254;
+    use ark_std::vec::Vec;
+
+    #[test]
+    fn test_indexed_mt_insertion() {
+        test_indexed_mt_insertion_helper::<Fq254>();
+        test_indexed_mt_insertion_helper::<Fq377>();
+        test_indexed_mt_insertion_helper::<Fq381>();
+    }
+
+    fn test_indexed_mt_insertion_helper<F: RescueParameter>() {
+        let mut mt = IndexedMerkleTree::<F>::new(2).unwrap();
+        assert!(IndexedMerkleTree::<F>::new(0).is_err());
+        assert_eq!(mt.num_leaves(), 1);
+
+        let values = [30u64, 10, 20, 50, 40].map(F::from);
+        for value in values.iter() {
+            let old_root = mt.commitment().digest();
+            let num_leaves = mt.num_leaves();
+            let proof = mt.insert(*value).unwrap();
+            let new_root = mt.commitment().digest();
+            assert!(IndexedMerkleTree::<F>::verify_insertion(
+                old_root, new_root, num_leaves, value, &proof
+            )
+            .unwrap()
+            .is_ok());
+            // wrong value, wrong roots or wrong number of leaves
+            assert!(IndexedMerkleTree::<F>::verify_insertion(
+                old_root,
+                new_root,
+                num_leaves,
+                &(*value + F::one()),
+                &proof
+            )
+            .unwrap()
+            .is_err());
+            assert!(IndexedMerkleTree::<F>::verify_insertion(
+                new_root, new_root, num_leaves, value, &proof
+            )
+            .unwrap()
+            .is_err());
+            assert!(IndexedMerkleTree::<F>::verify_insertion(
+                old_root, old_root, num_leaves, value, &proof
+            )
+            .unwrap()
+            .is_err());
+            assert!(IndexedMerkleTree::<F>::verify_insertion(
+                old_root,
+                new_root,
+                num_leaves + 1,
+                value,
+                &proof
+            )
+            .unwrap()
+            .is_err());
+        }
+        assert_eq!(mt.num_leaves(), 6);
+        assert!(mt.insert(F::from(20u64)).is_err());
+        assert!(mt.insert(F::zero()).is_err());
+
+        // The leaves form a sorted linked list
+        let mut leaf = *mt.lookup(&F::zero()).unwrap().expect_ok().unwrap().0;
+        let mut sorted = Vec::new();
+        while !leaf.next_value.is_zero() {
+            sorted.push(leaf.next_value);
+            let (next, proof) = mt.tree.lookup(leaf.next_index).expect_ok().unwrap();
+            assert_eq!(next.value, leaf.next_value);
+            assert!(IndexedLeafMerkleTree::<F>::verify(
+                mt.commitment().digest(),
+                proof.pos,
+                &proof
+            )
+            .unwrap()
+            .is_ok());
+            leaf = *next;
+        }
+        assert_eq!(sorted, [10u64, 20, 30, 40, 50].map(F::from));
+
+        // Fill the tree
+        for i in 0..3u64 {
+            mt.insert(F::from(100 + i)).unwrap();
+        }
+        assert!(mt.insert(F::from(200u64)).is_err());
+    }
+
+    #[test]
+    fn test_indexed_mt_insertion_position() {
+        test_indexed_mt_insertion_position_helper::<Fq254>();
+        test_indexed_mt_insertion_position_helper::<Fq377>();
+        test_indexed_mt_insertion_position_helper::<Fq381>();
+    }
+
+    // An insertion at an empty position other than the next one is consistent
+    // with the roots, but is rejected.
+    fn test_indexed_mt_insertion_position_helper<F: RescueParameter>() {
+        let mut mt = IndexedMerkleTree::<F>::new(2).unwrap();
+        mt.insert(F::from(10u64)).unwrap();
+        let old_root = mt.commitment().digest();
+        let num_leaves = mt.num_leaves();
+
+        let value = F::from(20u64);
+        let wrong_pos = num_leaves + 1;
+        let (low_leaf, low_leaf_proof) = mt.low_leaf(&value).unwrap();
+        let mut tree = mt.tree.clone();
+        tree.update(
+            low_leaf_proof.pos,
+            IndexedLeaf {
+                value: low_leaf.value,
+                next_index: wrong_pos,
+                next_value: value,
+            },
+        )
+        .unwrap();
+        let new_leaf_proof = tree.universal_lookup(wrong_pos).expect_not_found().unwrap();
+        tree.update(
+            wrong_pos,
+            IndexedLeaf {
+                value,
+                next_index: low_leaf.next_index,
+                next_value: low_leaf.next_value,
+            },
+        )
+        .unwrap();
+        let new_root = tree.commitment().digest();
+        let proof = IndexedInsertionProof {
+            low_leaf_proof,
+            new_leaf_proof,
+        };
+
+        assert!(IndexedMerkleTree::<F>::verify_insertion(
+            old_root, new_root, num_leaves, &value, &proof
+        )
+        .unwrap()
+        .is_err());
+        assert!(IndexedMerkleTree::<F>::verify_insertion(
+            old_root, new_root, wrong_pos, &value, &proof
+        )
+        .unwrap()
+        .is_ok());
+    }
+
+    #[test]
+    fn test_indexed_mt_lookup() {
+        test_indexed_mt_lookup_helper::<Fq254>();
+        test_indexed_mt_lookup_helper::<Fq377>();
+        test_indexed_mt_lookup_helper::<Fq381>();
+    }
+
+    fn test_indexed_mt_lookup_helper<F: RescueParameter>() {
+        let mut mt = IndexedMerkleTree::<F>::new(3).unwrap();
+        for value in [5u64, 15, 25] {
+            mt.insert(F::from(value)).unwrap();
+        }
+        let root = mt.commitment().digest();
+
+        let value = F::from(15u64);
+        let (leaf, proof) = mt.lookup(&value).unwrap().expect_ok().unwrap();
+        assert_eq!(leaf.next_value, F::from(25u64));
+        assert!(
+            IndexedMerkleTree::<F>::verify_membership(root, &value, &proof)
+                .unwrap()
+                .is_ok()
+        );
+        assert!(
+            IndexedMerkleTree::<F>::verify_membership(root, &F::from(16u64), &proof)
+                .unwrap()
+                .is_err()
+        );
+
+        for (value, low_value) in [(10u64, 5u64), (1, 0), (100, 25)] {
+            let value = F::from(value);
+            assert!(!mt.contains(&value));
+            let proof = mt.lookup(&value).unwrap().expect_not_found().unwrap();
+            assert_eq!(
+                proof.low_leaf_proof.elem().unwrap().value,
+                F::from(low_value)
+            );
+            assert!(
+                IndexedMerkleTree::<F>::verify_non_membership(root, &value, &proof)
+                    .unwrap()
+                    .is_ok()
+            );
+            // cannot prove non-membership of a value in the tree
+            assert!(
+                IndexedMerkleTree::<F>::verify_non_membership(root, &F::from(15u64), &proof)
+                    .unwrap()
+                    .is_err()
+            );
+        }
+    }
+}
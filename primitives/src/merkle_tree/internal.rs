@@ -5,17 +5,18 @@
 // along with the Jellyfish library. If not, see <https://mit-license.org/>.
 
 use super::{
-    DigestAlgorithm, Element, Index, LookupResult, MerkleCommitment, NodeValue, ToTraversalPath,
+    DigestAlgorithm, Element, Index, LookupResult, MaybeSendSync, MerkleCommitment, MissingSubtree,
+    NodeValue, ToTraversalPath,
 };
 use crate::errors::{PrimitivesError, VerificationResult};
+use alloc::sync::Arc;
 use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
-use ark_std::{
-    borrow::Borrow, boxed::Box, format, iter::Peekable, string::ToString, vec, vec::Vec,
-};
+use ark_std::{borrow::Borrow, format, iter::Peekable, mem, string::ToString, vec, vec::Vec};
 use core::marker::PhantomData;
-use itertools::Itertools;
 use jf_utils::canonical;
 use num_bigint::BigUint;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
 use serde::{Deserialize, Serialize};
 use tagged_base64::tagged;
 use typenum::Unsigned;
@@ -28,7 +29,7 @@ pub enum MerkleNode<E: Element, I: Index, T: NodeValue> {
     Branch {
         #[serde(with = "canonical")]
         value: T,
-        children: Vec<Box<MerkleNode<E, I, T>>>,
+        children: Vec<Arc<MerkleNode<E, I, T>>>,
     },
     Leaf {
         #[serde(with = "canonical")]
@@ -69,6 +70,34 @@ where
     pub(crate) fn is_forgotten(&self) -> bool {
         matches!(self, Self::ForgettenSubtree { .. })
     }
+
+    /// Return a copy of this node with all its content forgotten, except for
+    /// empty nodes which are cheaper to keep as they are.
+    #[inline]
+    pub(crate) fn pruned(&self) -> Self {
+        if let Self::Empty = self {
+            Self::Empty
+        } else {
+            Self::ForgettenSubtree {
+                value: self.value(),
+            }
+        }
+    }
+
+    /// Follow the given traversal path from the current node, stopping early
+    /// at the first node which is not a branch.
+    pub(crate) fn descend(&self, height: usize, traversal_path: &[usize]) -> &Self {
+        let mut node = self;
+        for branch in traversal_path[..height].iter().rev() {
+            match node {
+                Self::Branch { children, .. } if *branch < children.len() => {
+                    node = &children[*branch];
+                },
+                _ => break,
+            }
+        }
+        node
+    }
 }
 
 pub type MerklePath<E, I, T> = Vec<MerkleNode<E, I, T>>;
@@ -173,20 +202,176 @@ where
     }
 }
 
-#[allow(clippy::type_complexity)]
-pub(crate) fn build_tree_internal<E, H, Arity, T>(
-    height: Option<usize>,
-    elems: impl IntoIterator<Item = impl Borrow<E>>,
-) -> Result<(Box<MerkleNode<E, u64, T>>, usize, u64), PrimitivesError>
+/// A batch membership proof for a list of leaves. Instead of independent
+/// paths, it consists of the smallest sub-tree covering all the queried
+/// leaves, where every node off the paths is replaced by its digest. A sibling
+/// shared by several paths is therefore only included once.
+#[derive(Derivative, Debug, Clone, Serialize, Deserialize)]
+#[derivative(Eq, Hash, PartialEq)]
+#[serde(bound = "E: CanonicalSerialize + CanonicalDeserialize,
+             I: CanonicalSerialize + CanonicalDeserialize,")]
+pub struct MerkleBatchProof<E, I, T, Arity>
 where
     E: Element,
-    H: DigestAlgorithm<E, u64, T>,
+    I: Index,
+    T: NodeValue,
     Arity: Unsigned,
+{
+    /// Proof of inclusion for elements at indices `pos`
+    #[serde(with = "canonical")]
+    pub pos: Vec<I>,
+    /// Height of the tree
+    pub height: usize,
+    /// Root of the pruned tree that contains all the proof paths
+    pub proof: MerkleNode<E, I, T>,
+
+    /// Place holder for Arity
+    _phantom_arity: PhantomData<Arity>,
+}
+
+impl<E, I, T, Arity> MerkleBatchProof<E, I, T, Arity>
+where
+    E: Element,
+    I: Index,
     T: NodeValue,
+    Arity: Unsigned,
 {
-    let leaves: Vec<_> = elems.into_iter().collect();
-    let num_leaves = leaves.len() as u64;
-    let height = height.unwrap_or_else(|| {
+    pub fn new(pos: Vec<I>, height: usize, proof: MerkleNode<E, I, T>) -> Self {
+        MerkleBatchProof {
+            pos,
+            height,
+            proof,
+            _phantom_arity: PhantomData,
+        }
+    }
+
+    pub fn indices(&self) -> &[I] {
+        &self.pos
+    }
+}
+
+/// The # of leaves, or branches, digested by one call to
+/// [`DigestAlgorithm::digest_many_leaves`] or [`DigestAlgorithm::digest_many`]
+/// when building a tree. Batches are digested in parallel if the `parallel`
+/// feature is on.
+const DIGEST_BATCH_SIZE: usize = 64;
+
+/// Digest the leaves of a tree by batches of `DIGEST_BATCH_SIZE`, and move
+/// each element into its leaf.
+fn build_leaves<E, H, T>(leaves: Vec<E>) -> Result<Vec<Arc<MerkleNode<E, u64, T>>>, PrimitivesError>
+where
+    E: Element + MaybeSendSync,
+    H: DigestAlgorithm<E, u64, T>,
+    T: NodeValue + MaybeSendSync,
+{
+    let digest_batch = |(batch, elems): (usize, &[E])| -> Result<Vec<T>, PrimitivesError> {
+        let start = (batch * DIGEST_BATCH_SIZE) as u64;
+        let pos: Vec<u64> = (start..start + elems.len() as u64).collect();
+        H::digest_many_leaves(&pos, elems)
+    };
+    #[cfg(feature = "parallel")]
+    let batches = leaves.par_chunks(DIGEST_BATCH_SIZE).enumerate();
+    #[cfg(not(feature = "parallel"))]
+    let batches = leaves.chunks(DIGEST_BATCH_SIZE).enumerate();
+    let values = batches
+        .map(digest_batch)
+        .collect::<Result<Vec<_>, _>>()?
+        .into_iter()
+        .flatten();
+    Ok((0u64..)
+        .zip(leaves)
+        .zip(values)
+        .map(|((pos, elem), value)| Arc::new(MerkleNode::Leaf { value, pos, elem }))
+        .collect())
+}
+
+/// Digest the leaves of a light weight tree by batches of
+/// `DIGEST_BATCH_SIZE`, forgetting all of them but the last one. The elements
+/// are borrowed and digested one by one, so that only the last one is cloned.
+fn build_frontier_leaves<E, H, T>(
+    leaves: &[&E],
+) -> Result<Vec<Arc<MerkleNode<E, u64, T>>>, PrimitivesError>
+where
+    E: Element + MaybeSendSync,
+    H: DigestAlgorithm<E, u64, T>,
+    T: NodeValue + MaybeSendSync,
+{
+    let last = leaves.len() as u64 - 1;
+    let build_batch = |(batch, elems): (usize, &[&E])| -> Result<Vec<_>, PrimitivesError> {
+        let start = (batch * DIGEST_BATCH_SIZE) as u64;
+        (start..)
+            .zip(elems)
+            .map(|(pos, elem)| {
+                let value = H::digest_leaf(&pos, elem)?;
+                Ok(Arc::new(if pos == last {
+                    MerkleNode::Leaf {
+                        value,
+                        pos,
+                        elem: (*elem).clone(),
+                    }
+                } else {
+                    MerkleNode::ForgettenSubtree { value }
+                }))
+            })
+            .collect()
+    };
+    #[cfg(feature = "parallel")]
+    let batches = leaves.par_chunks(DIGEST_BATCH_SIZE).enumerate();
+    #[cfg(not(feature = "parallel"))]
+    let batches = leaves.chunks(DIGEST_BATCH_SIZE).enumerate();
+    Ok(batches
+        .map(build_batch)
+        .collect::<Result<Vec<_>, _>>()?
+        .into_iter()
+        .flatten()
+        .collect())
+}
+
+/// Group the nodes of a level by chunks of `Arity` and digest each chunk into
+/// a branch of the level above, by batches of `DIGEST_BATCH_SIZE` branches.
+fn build_branches<E, H, Arity, T>(
+    mut nodes: Vec<Arc<MerkleNode<E, u64, T>>>,
+) -> Result<Vec<Arc<MerkleNode<E, u64, T>>>, PrimitivesError>
+where
+    E: Element + MaybeSendSync,
+    H: DigestAlgorithm<E, u64, T>,
+    Arity: Unsigned,
+    T: NodeValue + MaybeSendSync,
+{
+    let arity = Arity::to_usize();
+    let num_branches = (nodes.len() + arity - 1) / arity;
+    nodes.resize_with(num_branches * arity, || Arc::new(MerkleNode::Empty));
+    let build_batch = |nodes: &[Arc<MerkleNode<E, u64, T>>]| -> Result<Vec<_>, PrimitivesError> {
+        let values: Vec<T> = nodes.iter().map(|node| node.value()).collect();
+        let data: Vec<&[T]> = values.chunks(arity).collect();
+        let digests = H::digest_many(&data)?;
+        Ok(nodes
+            .chunks(arity)
+            .zip(digests)
+            .map(|(children, value)| {
+                Arc::new(MerkleNode::Branch {
+                    value,
+                    children: children.to_vec(),
+                })
+            })
+            .collect())
+    };
+    #[cfg(feature = "parallel")]
+    let batches = nodes.par_chunks(arity * DIGEST_BATCH_SIZE);
+    #[cfg(not(feature = "parallel"))]
+    let batches = nodes.chunks(arity * DIGEST_BATCH_SIZE);
+    Ok(batches
+        .map(build_batch)
+        .collect::<Result<Vec<_>, _>>()?
+        .into_iter()
+        .flatten()
+        .collect())
+}
+
+/// Compute the height of a tree from the given one, or the minimum height
+/// that could hold all the leaves if `None`.
+fn tree_height<Arity: Unsigned>(height: Option<usize>, num_leaves: u64) -> usize {
+    height.unwrap_or_else(|| {
         let mut height = 0usize;
         let mut capacity = 1;
         while capacity < num_leaves {
@@ -194,7 +379,26 @@ where
             capacity *= Arity::to_u64();
         }
         height
-    });
+    })
+}
+
+#[allow(clippy::type_complexity)]
+pub(crate) fn build_tree_internal<E, H, Arity, T>(
+    height: Option<usize>,
+    elems: impl IntoIterator<Item = impl Borrow<E>>,
+) -> Result<(Arc<MerkleNode<E, u64, T>>, usize, u64), PrimitivesError>
+where
+    E: Element + MaybeSendSync,
+    H: DigestAlgorithm<E, u64, T>,
+    Arity: Unsigned,
+    T: NodeValue + MaybeSendSync,
+{
+    let leaves: Vec<E> = elems
+        .into_iter()
+        .map(|elem| elem.borrow().clone())
+        .collect();
+    let num_leaves = leaves.len() as u64;
+    let height = tree_height::<Arity>(height, num_leaves);
     let capacity = BigUint::from(Arity::to_u64()).pow(height as u32);
 
     if BigUint::from(num_leaves) > capacity {
@@ -202,50 +406,13 @@ where
             "Too many data for merkle tree".to_string(),
         ))
     } else if num_leaves > 0 {
-        let mut cur_nodes = leaves
-            .into_iter()
-            .enumerate()
-            .chunks(Arity::to_usize())
-            .into_iter()
-            .map(|chunk| {
-                let children = chunk
-                    .map(|(pos, elem)| {
-                        let pos = pos as u64;
-                        Ok(Box::new(MerkleNode::Leaf {
-                            value: H::digest_leaf(&pos, elem.borrow())?,
-                            pos,
-                            elem: elem.borrow().clone(),
-                        }))
-                    })
-                    .pad_using(Arity::to_usize(), |_| Ok(Box::new(MerkleNode::Empty)))
-                    .collect::<Result<Vec<_>, PrimitivesError>>()?;
-                Ok(Box::new(MerkleNode::<E, u64, T>::Branch {
-                    value: digest_branch::<E, H, u64, T>(&children)?,
-                    children,
-                }))
-            })
-            .collect::<Result<Vec<_>, PrimitivesError>>()?;
+        let mut cur_nodes = build_branches::<E, H, Arity, T>(build_leaves::<E, H, T>(leaves)?)?;
         for _ in 1..height {
-            cur_nodes = cur_nodes
-                .into_iter()
-                .chunks(Arity::to_usize())
-                .into_iter()
-                .map(|chunk| {
-                    let children = chunk
-                        .pad_using(Arity::to_usize(), |_| {
-                            Box::new(MerkleNode::<E, u64, T>::Empty)
-                        })
-                        .collect::<Vec<_>>();
-                    Ok(Box::new(MerkleNode::<E, u64, T>::Branch {
-                        value: digest_branch::<E, H, u64, T>(&children)?,
-                        children,
-                    }))
-                })
-                .collect::<Result<Vec<_>, PrimitivesError>>()?;
+            cur_nodes = build_branches::<E, H, Arity, T>(cur_nodes)?;
         }
-        Ok((cur_nodes[0].clone(), height, num_leaves))
+        Ok((cur_nodes.swap_remove(0), height, num_leaves))
     } else {
-        Ok((Box::new(MerkleNode::<E, u64, T>::Empty), height, 0))
+        Ok((Arc::new(MerkleNode::<E, u64, T>::Empty), height, 0))
     }
 }
 
@@ -253,24 +420,17 @@ where
 pub(crate) fn build_light_weight_tree_internal<E, H, Arity, T>(
     height: Option<usize>,
     elems: impl IntoIterator<Item = impl Borrow<E>>,
-) -> Result<(Box<MerkleNode<E, u64, T>>, usize, u64), PrimitivesError>
+) -> Result<(Arc<MerkleNode<E, u64, T>>, usize, u64), PrimitivesError>
 where
-    E: Element,
+    E: Element + MaybeSendSync,
     H: DigestAlgorithm<E, u64, T>,
     Arity: Unsigned,
-    T: NodeValue,
+    T: NodeValue + MaybeSendSync,
 {
-    let leaves: Vec<_> = elems.into_iter().collect();
+    let elems: Vec<_> = elems.into_iter().collect();
+    let leaves: Vec<&E> = elems.iter().map(|elem| elem.borrow()).collect();
     let num_leaves = leaves.len() as u64;
-    let height = height.unwrap_or_else(|| {
-        let mut height = 0usize;
-        let mut capacity = 1;
-        while capacity < num_leaves {
-            height += 1;
-            capacity *= Arity::to_u64();
-        }
-        height
-    });
+    let height = tree_height::<Arity>(height, num_leaves);
     let capacity = num_traits::checked_pow(Arity::to_u64(), height).ok_or_else(|| {
         PrimitivesError::ParameterError("Merkle tree size too large.".to_string())
     })?;
@@ -280,71 +440,30 @@ where
             "Too many data for merkle tree".to_string(),
         ))
     } else if num_leaves > 0 {
-        let mut cur_nodes = leaves
-            .into_iter()
-            .enumerate()
-            .chunks(Arity::to_usize())
-            .into_iter()
-            .map(|chunk| {
-                let children = chunk
-                    .map(|(pos, elem)| {
-                        let pos = pos as u64;
-                        Ok(if pos < num_leaves - 1 {
-                            Box::new(MerkleNode::ForgettenSubtree {
-                                value: H::digest_leaf(&pos, elem.borrow())?,
-                            })
-                        } else {
-                            Box::new(MerkleNode::Leaf {
-                                value: H::digest_leaf(&pos, elem.borrow())?,
-                                pos,
-                                elem: elem.borrow().clone(),
-                            })
-                        })
-                    })
-                    .pad_using(Arity::to_usize(), |_| Ok(Box::new(MerkleNode::Empty)))
-                    .collect::<Result<Vec<_>, PrimitivesError>>()?;
-                Ok(Box::new(MerkleNode::<E, u64, T>::Branch {
-                    value: digest_branch::<E, H, u64, T>(&children)?,
-                    children,
-                }))
-            })
-            .collect::<Result<Vec<_>, PrimitivesError>>()?;
+        // Only keep the frontier, i.e. the last leaf
+        let mut cur_nodes =
+            build_branches::<E, H, Arity, T>(build_frontier_leaves::<E, H, T>(&leaves)?)?;
         for i in 1..cur_nodes.len() - 1 {
-            cur_nodes[i] = Box::new(MerkleNode::ForgettenSubtree {
+            cur_nodes[i] = Arc::new(MerkleNode::ForgettenSubtree {
                 value: cur_nodes[i].value(),
             })
         }
         for _ in 1..height {
-            cur_nodes = cur_nodes
-                .into_iter()
-                .chunks(Arity::to_usize())
-                .into_iter()
-                .map(|chunk| {
-                    let children = chunk
-                        .pad_using(Arity::to_usize(), |_| {
-                            Box::new(MerkleNode::<E, u64, T>::Empty)
-                        })
-                        .collect::<Vec<_>>();
-                    Ok(Box::new(MerkleNode::<E, u64, T>::Branch {
-                        value: digest_branch::<E, H, u64, T>(&children)?,
-                        children,
-                    }))
-                })
-                .collect::<Result<Vec<_>, PrimitivesError>>()?;
+            cur_nodes = build_branches::<E, H, Arity, T>(cur_nodes)?;
             for i in 1..cur_nodes.len() - 1 {
-                cur_nodes[i] = Box::new(MerkleNode::ForgettenSubtree {
+                cur_nodes[i] = Arc::new(MerkleNode::ForgettenSubtree {
                     value: cur_nodes[i].value(),
                 })
             }
         }
-        Ok((cur_nodes[0].clone(), height, num_leaves))
+        Ok((cur_nodes.swap_remove(0), height, num_leaves))
     } else {
-        Ok((Box::new(MerkleNode::<E, u64, T>::Empty), height, 0))
+        Ok((Arc::new(MerkleNode::<E, u64, T>::Empty), height, 0))
     }
 }
 
 pub(crate) fn digest_branch<E, H, I, T>(
-    data: &[Box<MerkleNode<E, I, T>>],
+    data: &[Arc<MerkleNode<E, I, T>>],
 ) -> Result<T, PrimitivesError>
 where
     E: Element,
@@ -373,7 +492,7 @@ where
         match self {
             MerkleNode::Empty => LookupResult::NotFound(vec![MerkleNode::Empty; height + 1]),
             MerkleNode::Branch { value, children } => {
-                match children[traversal_path[height - 1]]
+                match Arc::make_mut(&mut children[traversal_path[height - 1]])
                     .forget_internal(height - 1, traversal_path)
                 {
                     LookupResult::Ok(elem, mut proof) => {
@@ -383,9 +502,9 @@ where
                                 .iter()
                                 .map(|child| {
                                     if let MerkleNode::Empty = **child {
-                                        Box::new(MerkleNode::Empty)
+                                        Arc::new(MerkleNode::Empty)
                                     } else {
-                                        Box::new(MerkleNode::ForgettenSubtree {
+                                        Arc::new(MerkleNode::ForgettenSubtree {
                                             value: child.value(),
                                         })
                                     }
@@ -402,7 +521,7 @@ where
                         }
                         LookupResult::Ok(elem, proof)
                     },
-                    LookupResult::NotInMemory => LookupResult::NotInMemory,
+                    LookupResult::NotInMemory(()) => LookupResult::NotInMemory(()),
                     LookupResult::NotFound(mut non_membership_proof) => {
                         non_membership_proof.push(MerkleNode::Branch {
                             value: T::default(),
@@ -410,9 +529,9 @@ where
                                 .iter()
                                 .map(|child| {
                                     if let MerkleNode::Empty = **child {
-                                        Box::new(MerkleNode::Empty)
+                                        Arc::new(MerkleNode::Empty)
                                     } else {
-                                        Box::new(MerkleNode::ForgettenSubtree {
+                                        Arc::new(MerkleNode::ForgettenSubtree {
                                             value: child.value(),
                                         })
                                     }
@@ -433,7 +552,60 @@ where
                 *self = MerkleNode::ForgettenSubtree { value: *value };
                 LookupResult::Ok(elem, proof)
             },
-            _ => LookupResult::NotInMemory,
+            _ => LookupResult::NotInMemory(()),
+        }
+    }
+
+    /// Forget the whole sub-tree `depth` levels below the current node, on
+    /// the given traversal path. Internal branch merkle node will also be
+    /// forgotten if all its children are forgotten or empty.
+    /// * `returns` - the value of the forgotten sub-tree, `NotFound` if it is
+    ///   empty and `NotInMemory` if it was already forgotten
+    pub(crate) fn forget_subtree_internal(
+        &mut self,
+        height: usize,
+        depth: usize,
+        traversal_path: &[usize],
+    ) -> LookupResult<T, (), ()> {
+        match self {
+            MerkleNode::Empty => LookupResult::NotFound(()),
+            MerkleNode::ForgettenSubtree { .. } => LookupResult::NotInMemory(()),
+            _ if depth == 0 => {
+                let value = self.value();
+                *self = MerkleNode::ForgettenSubtree { value };
+                LookupResult::Ok(value, ())
+            },
+            MerkleNode::Branch { value, children } => {
+                let result = Arc::make_mut(&mut children[traversal_path[height - 1]])
+                    .forget_subtree_internal(height - 1, depth - 1, traversal_path);
+                if children.iter().all(|child| {
+                    matches!(
+                        **child,
+                        MerkleNode::Empty | MerkleNode::ForgettenSubtree { value: _ }
+                    )
+                }) {
+                    *self = MerkleNode::ForgettenSubtree { value: *value };
+                }
+                result
+            },
+            MerkleNode::Leaf { .. } => LookupResult::NotFound(()),
+        }
+    }
+
+    /// Return the value of the node `depth` levels below the current node, on
+    /// the given traversal path, or `None` if it is not in memory.
+    pub(crate) fn subtree_value(
+        &self,
+        height: usize,
+        depth: usize,
+        traversal_path: &[usize],
+    ) -> Option<T> {
+        match self {
+            _ if depth == 0 => Some(self.value()),
+            MerkleNode::Empty => Some(T::default()),
+            MerkleNode::Branch { children, .. } => children[traversal_path[height - 1]]
+                .subtree_value(height - 1, depth - 1, traversal_path),
+            _ => None,
         }
     }
 
@@ -462,12 +634,13 @@ where
             (Self::ForgettenSubtree { value }, Self::Branch { children, .. }) => {
                 // Recurse into the appropriate sub-tree to remember the rest of the path.
                 let mut children = children.clone();
-                children[traversal_path[height - 1]].remember_internal::<H, Arity>(
-                    height - 1,
-                    traversal_path,
-                    path_values,
-                    proof,
-                )?;
+                Arc::make_mut(&mut children[traversal_path[height - 1]])
+                    .remember_internal::<H, Arity>(
+                        height - 1,
+                        traversal_path,
+                        path_values,
+                        proof,
+                    )?;
                 // Remember `*self`.
                 *self = Self::Branch {
                     value: *value,
@@ -481,9 +654,10 @@ where
                 *self = node.clone();
                 Ok(())
             },
-            (Self::Branch { children, .. }, Self::Branch { .. }) => children
-                [traversal_path[height - 1]]
-                .remember_internal::<H, Arity>(height - 1, traversal_path, path_values, proof),
+            (Self::Branch { children, .. }, Self::Branch { .. }) => {
+                Arc::make_mut(&mut children[traversal_path[height - 1]])
+                    .remember_internal::<H, Arity>(height - 1, traversal_path, path_values, proof)
+            },
             (Self::Leaf { .. }, Self::Leaf { .. }) | (Self::Empty, Self::Empty) => {
                 // This node is already a complete sub-tree, so there's nothing to remember. The
                 // proof matches, so just return success.
@@ -493,6 +667,67 @@ where
         }
     }
 
+    /// Batch version of [`Self::remember_internal`], merging every proof into
+    /// the tree in a single traversal.
+    /// * `proofs` - tuples of (traversal path, path values, proof) for each
+    ///   remembered leaf, sorted by traversal path from the root
+    pub(crate) fn remember_batch_internal<H, Arity>(
+        &mut self,
+        height: usize,
+        proofs: &[(Vec<usize>, Vec<T>, &[MerkleNode<E, I, T>])],
+    ) -> Result<(), PrimitivesError>
+    where
+        H: DigestAlgorithm<E, I, T>,
+        Arity: Unsigned,
+    {
+        if proofs.is_empty() {
+            return Ok(());
+        }
+        if proofs
+            .iter()
+            .any(|(_, path_values, _)| self.value() != path_values[height])
+        {
+            return Err(PrimitivesError::ParameterError(format!(
+                "Invalid proof. Hash differs at height {}",
+                height
+            )));
+        }
+
+        let node = &proofs[0].2[height];
+        if let (Self::ForgettenSubtree { value }, Self::Branch { children, .. }) = (&*self, node) {
+            // Expand the forgotten sub-tree with the siblings from the proof, which
+            // are consistent with `value` as checked above.
+            let value = *value;
+            *self = Self::Branch {
+                value,
+                children: children.clone(),
+            };
+        }
+        match (&mut *self, node) {
+            (Self::Branch { children, .. }, Self::Branch { .. }) => {
+                let mut start = 0;
+                while start < proofs.len() {
+                    let branch = proofs[start].0[height - 1];
+                    let end = start
+                        + proofs[start..]
+                            .iter()
+                            .take_while(|(path, ..)| path[height - 1] == branch)
+                            .count();
+                    Arc::make_mut(&mut children[branch])
+                        .remember_batch_internal::<H, Arity>(height - 1, &proofs[start..end])?;
+                    start = end;
+                }
+                Ok(())
+            },
+            (Self::ForgettenSubtree { .. }, node) => {
+                *self = node.clone();
+                Ok(())
+            },
+            (Self::Leaf { .. }, Self::Leaf { .. }) | (Self::Empty, Self::Empty) => Ok(()),
+            (..) => Err(PrimitivesError::ParameterError("Invalid proof".into())),
+        }
+    }
+
     /// Query the given index at the current Merkle node. Return the element
     /// with a membership proof if presence, otherwise return a non-membership
     /// proof.
@@ -501,7 +736,7 @@ where
         &self,
         height: usize,
         traversal_path: &[usize],
-    ) -> LookupResult<&E, MerklePath<E, I, T>, MerklePath<E, I, T>> {
+    ) -> LookupResult<&E, MerklePath<E, I, T>, MerklePath<E, I, T>, MissingSubtree<T>> {
         match self {
             MerkleNode::Empty => {
                 LookupResult::NotFound(vec![MerkleNode::<E, I, T>::Empty; height + 1])
@@ -517,9 +752,9 @@ where
                                 .iter()
                                 .map(|child| {
                                     if let MerkleNode::Empty = **child {
-                                        Box::new(MerkleNode::Empty)
+                                        Arc::new(MerkleNode::Empty)
                                     } else {
-                                        Box::new(MerkleNode::ForgettenSubtree {
+                                        Arc::new(MerkleNode::ForgettenSubtree {
                                             value: child.value(),
                                         })
                                     }
@@ -528,7 +763,7 @@ where
                         });
                         LookupResult::Ok(elem, proof)
                     },
-                    LookupResult::NotInMemory => LookupResult::NotInMemory,
+                    LookupResult::NotInMemory(missing) => LookupResult::NotInMemory(missing),
                     LookupResult::NotFound(mut non_membership_proof) => {
                         non_membership_proof.push(MerkleNode::Branch {
                             value: T::default(),
@@ -536,9 +771,9 @@ where
                                 .iter()
                                 .map(|child| {
                                     if let MerkleNode::Empty = **child {
-                                        Box::new(MerkleNode::Empty)
+                                        Arc::new(MerkleNode::Empty)
                                     } else {
-                                        Box::new(MerkleNode::ForgettenSubtree {
+                                        Arc::new(MerkleNode::ForgettenSubtree {
                                             value: child.value(),
                                         })
                                     }
@@ -554,7 +789,81 @@ where
                 value: _,
                 pos: _,
             } => LookupResult::Ok(elem, vec![self.clone()]),
-            _ => LookupResult::NotInMemory,
+            MerkleNode::ForgettenSubtree { value } => LookupResult::NotInMemory(MissingSubtree {
+                height,
+                digest: *value,
+            }),
+        }
+    }
+
+    /// Query a list of indices at the current Merkle node. Return the pruned
+    /// sub-tree containing the paths to all the queried leaves if they are
+    /// all present.
+    #[allow(clippy::type_complexity)]
+    pub(crate) fn batch_lookup_internal(
+        &self,
+        height: usize,
+        traversal_paths: &[&[usize]],
+    ) -> LookupResult<(), MerkleNode<E, I, T>, ()> {
+        if traversal_paths.is_empty() {
+            return LookupResult::Ok((), self.pruned());
+        }
+        match self {
+            MerkleNode::Empty => LookupResult::NotFound(()),
+            MerkleNode::Branch { value: _, children } => {
+                let mut proof_children = Vec::with_capacity(children.len());
+                for (branch, child) in children.iter().enumerate() {
+                    let paths = traversal_paths
+                        .iter()
+                        .filter(|path| path[height - 1] == branch)
+                        .copied()
+                        .collect::<Vec<_>>();
+                    match child.batch_lookup_internal(height - 1, &paths) {
+                        LookupResult::Ok(_, node) => proof_children.push(Arc::new(node)),
+                        LookupResult::NotInMemory(()) => return LookupResult::NotInMemory(()),
+                        LookupResult::NotFound(_) => return LookupResult::NotFound(()),
+                    }
+                }
+                LookupResult::Ok(
+                    (),
+                    MerkleNode::Branch {
+                        value: T::default(),
+                        children: proof_children,
+                    },
+                )
+            },
+            MerkleNode::Leaf { .. } => LookupResult::Ok((), self.clone()),
+            MerkleNode::ForgettenSubtree { .. } => LookupResult::NotInMemory(()),
+        }
+    }
+
+    /// Recompute the digest of a (partial) sub-tree from its leaves and
+    /// forgotten sub-trees, regardless of the values stored in its branches.
+    pub(crate) fn compute_digest<H, Arity>(&self, height: usize) -> Result<T, PrimitivesError>
+    where
+        H: DigestAlgorithm<E, I, T>,
+        Arity: Unsigned,
+    {
+        match self {
+            MerkleNode::Empty => Ok(T::default()),
+            MerkleNode::ForgettenSubtree { value } => Ok(*value),
+            MerkleNode::Leaf {
+                value: _,
+                pos,
+                elem,
+            } if height == 0 => H::digest_leaf(pos, elem),
+            MerkleNode::Branch { value: _, children }
+                if height > 0 && children.len() == Arity::to_usize() =>
+            {
+                let data = children
+                    .iter()
+                    .map(|child| child.compute_digest::<H, Arity>(height - 1))
+                    .collect::<Result<Vec<_>, _>>()?;
+                H::digest(&data)
+            },
+            _ => Err(PrimitivesError::ParameterError(
+                "Incompatible proof for this merkle tree".to_string(),
+            )),
         }
     }
 
@@ -597,12 +906,8 @@ where
             },
             MerkleNode::Branch { value, children } => {
                 let branch = traversal_path[height - 1];
-                let result = children[branch].update_with_internal::<H, Arity, _>(
-                    height - 1,
-                    pos,
-                    traversal_path,
-                    f,
-                )?;
+                let result = Arc::make_mut(&mut children[branch])
+                    .update_with_internal::<H, Arity, _>(height - 1, pos, traversal_path, f)?;
                 if matches!(*children[branch], MerkleNode::ForgettenSubtree { .. }) {
                     // If the branch containing the update was forgotten by
                     // user, the update failed and nothing was changed, so we
@@ -634,13 +939,9 @@ where
                     }
                 } else {
                     let branch = traversal_path[height - 1];
-                    let mut children = vec![Box::new(MerkleNode::Empty); Arity::to_usize()];
-                    let result = children[branch].update_with_internal::<H, Arity, _>(
-                        height - 1,
-                        pos,
-                        traversal_path,
-                        f,
-                    )?;
+                    let mut children = vec![Arc::new(MerkleNode::Empty); Arity::to_usize()];
+                    let result = Arc::make_mut(&mut children[branch])
+                        .update_with_internal::<H, Arity, _>(height - 1, pos, traversal_path, f)?;
                     if matches!(*children[branch], MerkleNode::Empty) {
                         // No update performed.
                     } else {
@@ -652,7 +953,227 @@ where
                     Ok(result)
                 }
             },
-            MerkleNode::ForgettenSubtree { .. } => Ok((0, LookupResult::NotInMemory)),
+            MerkleNode::ForgettenSubtree { .. } => Ok((0, LookupResult::NotInMemory(()))),
+        }
+    }
+
+    /// Set the elements at the given indices, computing the value of every
+    /// affected node only once.
+    /// * `updates` - tuples of (traversal path, index, element), sorted by
+    ///   traversal path from the root and without duplicated indices
+    /// * `returns` - `Err()` if any error happens internally, or if an update
+    ///   falls into a forgotten subtree, in which case the tree is left in an
+    ///   inconsistent state: callers check upfront that every update is in
+    ///   memory. Otherwise the number of newly inserted leaves.
+    pub(crate) fn update_batch_internal<H, Arity>(
+        &mut self,
+        height: usize,
+        updates: &[(Vec<usize>, I, E)],
+    ) -> Result<u64, PrimitivesError>
+    where
+        H: DigestAlgorithm<E, I, T>,
+        Arity: Unsigned,
+    {
+        if updates.is_empty() {
+            return Ok(0);
+        }
+        match self {
+            MerkleNode::Leaf { elem, value, pos } => {
+                let (_, _, new_elem) = &updates[0];
+                *value = H::digest_leaf(pos, new_elem)?;
+                *elem = new_elem.clone();
+                Ok(0)
+            },
+            MerkleNode::Branch { value, children } => {
+                let mut inserted = 0;
+                let mut start = 0;
+                while start < updates.len() {
+                    let branch = updates[start].0[height - 1];
+                    let end = start
+                        + updates[start..]
+                            .iter()
+                            .take_while(|(path, ..)| path[height - 1] == branch)
+                            .count();
+                    inserted += Arc::make_mut(&mut children[branch])
+                        .update_batch_internal::<H, Arity>(height - 1, &updates[start..end])?;
+                    start = end;
+                }
+                *value = digest_branch::<E, H, I, T>(children)?;
+                Ok(inserted)
+            },
+            MerkleNode::Empty => {
+                if height == 0 {
+                    let (_, pos, elem) = &updates[0];
+                    *self = MerkleNode::Leaf {
+                        value: H::digest_leaf(pos, elem)?,
+                        pos: pos.clone(),
+                        elem: elem.clone(),
+                    };
+                    Ok(1)
+                } else {
+                    *self = MerkleNode::Branch {
+                        value: T::default(),
+                        children: vec![Arc::new(MerkleNode::Empty); Arity::to_usize()],
+                    };
+                    self.update_batch_internal::<H, Arity>(height, updates)
+                }
+            },
+            MerkleNode::ForgettenSubtree { .. } => Err(PrimitivesError::ParameterError(
+                "Given part of merkle tree is not in memory".to_string(),
+            )),
+        }
+    }
+
+    /// Collect the indices of the leaves whose elements differ between the
+    /// current node and `other`, or which are only present in one of them,
+    /// skipping the sub-trees with equal digests.
+    /// * `returns` - `Err()` if differing sub-trees are not in memory
+    pub(crate) fn diff_internal(
+        &self,
+        other: &Self,
+        out: &mut Vec<I>,
+    ) -> Result<(), PrimitivesError> {
+        if self.value() == other.value() {
+            return Ok(());
+        }
+        match (self, other) {
+            (
+                MerkleNode::Branch { children, .. },
+                MerkleNode::Branch {
+                    children: other, ..
+                },
+            ) => children
+                .iter()
+                .zip(other.iter())
+                .try_for_each(|(child, other)| child.diff_internal(other, out)),
+            (MerkleNode::Leaf { pos, .. }, MerkleNode::Leaf { pos: other_pos, .. })
+                if pos == other_pos =>
+            {
+                out.push(pos.clone());
+                Ok(())
+            },
+            (MerkleNode::ForgettenSubtree { .. }, _) | (_, MerkleNode::ForgettenSubtree { .. }) => {
+                Err(PrimitivesError::ParameterError(
+                    "Given part of merkle tree is not in memory".to_string(),
+                ))
+            },
+            _ => {
+                let mut indices = vec![];
+                self.collect_indices(&mut indices)?;
+                other.collect_indices(&mut indices)?;
+                indices.sort();
+                indices.dedup();
+                out.extend(indices);
+                Ok(())
+            },
+        }
+    }
+
+    /// Collect the indices of all the leaves below the current node.
+    /// * `returns` - `Err()` if some of them are not in memory
+    fn collect_indices(&self, out: &mut Vec<I>) -> Result<(), PrimitivesError> {
+        match self {
+            MerkleNode::Empty => Ok(()),
+            MerkleNode::Leaf { pos, .. } => {
+                out.push(pos.clone());
+                Ok(())
+            },
+            MerkleNode::Branch { children, .. } => children
+                .iter()
+                .try_for_each(|child| child.collect_indices(out)),
+            MerkleNode::ForgettenSubtree { .. } => Err(PrimitivesError::ParameterError(
+                "Given part of merkle tree is not in memory".to_string(),
+            )),
+        }
+    }
+
+    /// Make the current node the left-most descendant, `levels` levels below,
+    /// of a new root whose other descendants are all empty. Leaves keep their
+    /// index, so this turns a tree into the same tree with a larger height.
+    pub(crate) fn grow_internal<H, Arity>(&mut self, levels: usize) -> Result<(), PrimitivesError>
+    where
+        H: DigestAlgorithm<E, I, T>,
+        Arity: Unsigned,
+    {
+        // An empty tree is empty at any height
+        if matches!(self, MerkleNode::Empty) {
+            return Ok(());
+        }
+        for _ in 0..levels {
+            let mut children = vec![Arc::new(MerkleNode::Empty); Arity::to_usize()];
+            children[0] = Arc::new(mem::replace(self, MerkleNode::Empty));
+            *self = MerkleNode::Branch {
+                value: digest_branch::<E, H, I, T>(&children)?,
+                children,
+            };
+        }
+        Ok(())
+    }
+
+    /// Check that the digests of this subtree match its content and that
+    /// its leaves are at the positions given by their place in the tree.
+    /// * `path` - the branches taken from the root to this node
+    /// * `returns` - the number of leaves in memory and whether some subtrees
+    ///   are forgotten, or Err() if the subtree is not well structured
+    pub(crate) fn validate_internal<H, Arity>(
+        &self,
+        height: usize,
+        path: &mut Vec<usize>,
+    ) -> Result<(u64, bool), PrimitivesError>
+    where
+        H: DigestAlgorithm<E, I, T>,
+        I: ToTraversalPath<Arity>,
+        Arity: Unsigned,
+    {
+        match self {
+            MerkleNode::Empty => Ok((0, false)),
+            MerkleNode::ForgettenSubtree { .. } => Ok((0, true)),
+            MerkleNode::Leaf { value, pos, elem } => {
+                if height != 0 {
+                    return Err(PrimitivesError::InconsistentStructureError(
+                        "Leaf above the bottom of the merkle tree".to_string(),
+                    ));
+                }
+                if !pos
+                    .to_traversal_path(path.len())
+                    .iter()
+                    .rev()
+                    .eq(path.iter())
+                {
+                    return Err(PrimitivesError::InconsistentStructureError(format!(
+                        "Leaf at {pos:?} is misplaced in the merkle tree"
+                    )));
+                }
+                if H::digest_leaf(pos, elem)? != *value {
+                    return Err(PrimitivesError::InconsistentStructureError(format!(
+                        "Wrong digest for the leaf at {pos:?}"
+                    )));
+                }
+                Ok((1, false))
+            },
+            MerkleNode::Branch { value, children } => {
+                if height == 0 || children.len() != Arity::to_usize() {
+                    return Err(PrimitivesError::InconsistentStructureError(
+                        "Malformed branch in the merkle tree".to_string(),
+                    ));
+                }
+                let mut num_leaves = 0;
+                let mut forgotten = false;
+                for (branch, child) in children.iter().enumerate() {
+                    path.push(branch);
+                    let (child_leaves, child_forgotten) =
+                        child.validate_internal::<H, Arity>(height - 1, path)?;
+                    path.pop();
+                    num_leaves += child_leaves;
+                    forgotten |= child_forgotten;
+                }
+                if digest_branch::<E, H, I, T>(children)? != *value {
+                    return Err(PrimitivesError::InconsistentStructureError(
+                        "Wrong digest for a branch of the merkle tree".to_string(),
+                    ));
+                }
+                Ok((num_leaves, forgotten))
+            },
         }
     }
 }
@@ -689,13 +1210,14 @@ where
                 };
                 let cap = Arity::to_usize();
                 while data.peek().is_some() && frontier < cap {
-                    let increment = children[frontier].extend_internal::<H, Arity>(
-                        height - 1,
-                        &cur_pos,
-                        traversal_path,
-                        at_frontier && frontier == traversal_path[height - 1],
-                        data,
-                    )?;
+                    let increment = Arc::make_mut(&mut children[frontier])
+                        .extend_internal::<H, Arity>(
+                            height - 1,
+                            &cur_pos,
+                            traversal_path,
+                            at_frontier && frontier == traversal_path[height - 1],
+                            data,
+                        )?;
                     cnt += increment;
                     cur_pos += increment;
                     frontier += 1;
@@ -721,15 +1243,16 @@ where
                         0
                     };
                     let cap = Arity::to_usize();
-                    let mut children = vec![Box::new(MerkleNode::Empty); cap];
+                    let mut children = vec![Arc::new(MerkleNode::Empty); cap];
                     while data.peek().is_some() && frontier < cap {
-                        let increment = children[frontier].extend_internal::<H, Arity>(
-                            height - 1,
-                            &cur_pos,
-                            traversal_path,
-                            at_frontier && frontier == traversal_path[height - 1],
-                            data,
-                        )?;
+                        let increment = Arc::make_mut(&mut children[frontier])
+                            .extend_internal::<H, Arity>(
+                                height - 1,
+                                &cur_pos,
+                                traversal_path,
+                                at_frontier && frontier == traversal_path[height - 1],
+                                data,
+                            )?;
                         cnt += increment;
                         cur_pos += increment;
                         frontier += 1;
@@ -750,6 +1273,50 @@ where
         }
     }
 
+    /// Remove every leaf at position `num_leaves` or beyond from the subtree of
+    /// the given height. Nothing is modified if an error is returned.
+    pub(crate) fn truncate_internal<H, Arity>(
+        &mut self,
+        height: usize,
+        num_leaves: u64,
+    ) -> Result<(), PrimitivesError>
+    where
+        H: DigestAlgorithm<E, u64, T>,
+        Arity: Unsigned,
+    {
+        if num_leaves == 0 {
+            *self = MerkleNode::Empty;
+            return Ok(());
+        }
+        match self {
+            MerkleNode::Branch { value, children } => {
+                let child_capacity = num_traits::checked_pow(Arity::to_u64(), height - 1);
+                let (last, remainder) = match child_capacity {
+                    Some(capacity) => (
+                        ((num_leaves - 1) / capacity) as usize,
+                        (num_leaves - 1) % capacity + 1,
+                    ),
+                    None => (0, num_leaves),
+                };
+                // Only the last remaining child may be partially truncated
+                if child_capacity != Some(remainder) {
+                    Arc::make_mut(&mut children[last])
+                        .truncate_internal::<H, Arity>(height - 1, remainder)?;
+                }
+                children
+                    .iter_mut()
+                    .skip(last + 1)
+                    .for_each(|child| *child = Arc::new(MerkleNode::Empty));
+                *value = digest_branch::<E, H, u64, T>(children)?;
+                Ok(())
+            },
+            MerkleNode::Empty | MerkleNode::Leaf { .. } => Ok(()),
+            MerkleNode::ForgettenSubtree { .. } => Err(PrimitivesError::ParameterError(
+                "Given part of merkle tree is not in memory".to_string(),
+            )),
+        }
+    }
+
     /// Similar to [`extend_internal`], but this function will automatically
     /// forget every leaf except for the Merkle tree frontier.
     pub(crate) fn extend_and_forget_internal<H, Arity>(
@@ -780,17 +1347,18 @@ where
                 while data.peek().is_some() && frontier < cap {
                     if frontier > 0 && !children[frontier - 1].is_forgotten() {
                         children[frontier - 1] =
-                            Box::new(MerkleNode::<E, u64, T>::ForgettenSubtree {
+                            Arc::new(MerkleNode::<E, u64, T>::ForgettenSubtree {
                                 value: children[frontier - 1].value(),
                             });
                     }
-                    let increment = children[frontier].extend_and_forget_internal::<H, Arity>(
-                        height - 1,
-                        &cur_pos,
-                        traversal_path,
-                        at_frontier && frontier == traversal_path[height - 1],
-                        data,
-                    )?;
+                    let increment = Arc::make_mut(&mut children[frontier])
+                        .extend_and_forget_internal::<H, Arity>(
+                            height - 1,
+                            &cur_pos,
+                            traversal_path,
+                            at_frontier && frontier == traversal_path[height - 1],
+                            data,
+                        )?;
                     cnt += increment;
                     cur_pos += increment;
                     frontier += 1;
@@ -816,21 +1384,22 @@ where
                         0
                     };
                     let cap = Arity::to_usize();
-                    let mut children = vec![Box::new(MerkleNode::Empty); cap];
+                    let mut children = vec![Arc::new(MerkleNode::Empty); cap];
                     while data.peek().is_some() && frontier < cap {
                         if frontier > 0 && !children[frontier - 1].is_forgotten() {
                             children[frontier - 1] =
-                                Box::new(MerkleNode::<E, u64, T>::ForgettenSubtree {
+                                Arc::new(MerkleNode::<E, u64, T>::ForgettenSubtree {
                                     value: children[frontier - 1].value(),
                                 });
                         }
-                        let increment = children[frontier].extend_and_forget_internal::<H, Arity>(
-                            height - 1,
-                            &cur_pos,
-                            traversal_path,
-                            at_frontier && frontier == traversal_path[height - 1],
-                            data,
-                        )?;
+                        let increment = Arc::make_mut(&mut children[frontier])
+                            .extend_and_forget_internal::<H, Arity>(
+                                height - 1,
+                                &cur_pos,
+                                traversal_path,
+                                at_frontier && frontier == traversal_path[height - 1],
+                                data,
+                            )?;
                         cnt += increment;
                         cur_pos += increment;
                         frontier += 1;
@@ -850,6 +1419,39 @@ where
             )),
         }
     }
+
+    /// Return a copy of this subtree with all its leaves moved `offset`
+    /// positions to the right. Leaf digests bind their position, so all the
+    /// digests of the subtree are recomputed.
+    pub(crate) fn shift_internal<H>(&self, offset: u64) -> Result<Arc<Self>, PrimitivesError>
+    where
+        H: DigestAlgorithm<E, u64, T>,
+    {
+        match self {
+            MerkleNode::Empty => Ok(Arc::new(MerkleNode::Empty)),
+            MerkleNode::Leaf { pos, elem, .. } => {
+                let pos = pos + offset;
+                Ok(Arc::new(MerkleNode::Leaf {
+                    value: H::digest_leaf(&pos, elem)?,
+                    pos,
+                    elem: elem.clone(),
+                }))
+            },
+            MerkleNode::Branch { children, .. } => {
+                let children = children
+                    .iter()
+                    .map(|child| child.shift_internal::<H>(offset))
+                    .collect::<Result<Vec<_>, _>>()?;
+                Ok(Arc::new(MerkleNode::Branch {
+                    value: digest_branch::<E, H, u64, T>(&children)?,
+                    children,
+                }))
+            },
+            MerkleNode::ForgettenSubtree { .. } => Err(PrimitivesError::ParameterError(
+                "Given part of merkle tree is not in memory".to_string(),
+            )),
+        }
+    }
 }
 
 impl<E, I, T, Arity> MerkleProof<E, I, T, Arity>
@@ -906,6 +1508,55 @@ where
         }
     }
 
+    /// Compute the values of the nodes along the path of a membership proof
+    /// for `element`, from the leaf to the root.
+    /// * `traversal_path` - traversal path of the proven position
+    /// * `returns` - the computed values, or `Err()` if the proof is not a
+    ///   membership proof for `element` following `traversal_path`
+    pub(crate) fn membership_path_values<H>(
+        &self,
+        traversal_path: &[usize],
+        element: &E,
+    ) -> Result<Vec<T>, PrimitivesError>
+    where
+        H: DigestAlgorithm<E, I, T>,
+    {
+        if self.proof.len() != traversal_path.len() + 1 {
+            return Err(PrimitivesError::ParameterError(
+                "Incompatible proof for this merkle tree".to_string(),
+            ));
+        }
+        match &self.proof[0] {
+            MerkleNode::Leaf { pos, elem, .. } => {
+                if !elem.eq(element) {
+                    return Err(PrimitivesError::ParameterError(
+                        "Element does not match the proof.".to_string(),
+                    ));
+                }
+                let mut path_values = vec![H::digest_leaf(pos, elem)?];
+                for (branch, node) in traversal_path.iter().zip(self.proof.iter().skip(1)) {
+                    match node {
+                        MerkleNode::Branch { children, .. } if *branch < children.len() => {
+                            let mut data: Vec<_> =
+                                children.iter().map(|node| node.value()).collect();
+                            data[*branch] = *path_values.last().unwrap();
+                            path_values.push(H::digest(&data)?);
+                        },
+                        _ => {
+                            return Err(PrimitivesError::ParameterError(
+                                "Incompatible proof for this merkle tree".to_string(),
+                            ))
+                        },
+                    }
+                }
+                Ok(path_values)
+            },
+            _ => Err(PrimitivesError::ParameterError(
+                "Invalid proof type".to_string(),
+            )),
+        }
+    }
+
     /// Verify a non membership proof by comparing the computed root value
     /// to the expected one.
     pub(crate) fn verify_non_membership_proof<H>(
@@ -943,6 +1594,92 @@ where
             ))
         }
     }
+
+    /// Compute the root of the tree where the leaf at `self.pos` is set to
+    /// `elem` and every other node is given by this proof. Works for both
+    /// membership and non-membership proofs.
+    pub(crate) fn compute_root_with_elem<H>(&self, elem: &E) -> Result<T, PrimitivesError>
+    where
+        H: DigestAlgorithm<E, I, T>,
+    {
+        let init = H::digest_leaf(&self.pos, elem)?;
+        self.pos
+            .to_traversal_path(self.tree_height() - 1)
+            .iter()
+            .zip(self.proof.iter().skip(1))
+            .try_fold(init, |val, (branch, node)| -> Result<T, PrimitivesError> {
+                let mut data = match node {
+                    MerkleNode::Branch { value: _, children } => {
+                        children.iter().map(|node| node.value()).collect::<Vec<_>>()
+                    },
+                    MerkleNode::Empty => vec![T::default(); Arity::to_usize()],
+                    _ => {
+                        return Err(PrimitivesError::ParameterError(
+                            "Incompatible proof for this merkle tree".to_string(),
+                        ))
+                    },
+                };
+                if data.len() != Arity::to_usize() {
+                    return Err(PrimitivesError::ParameterError(
+                        "Incompatible proof for this merkle tree".to_string(),
+                    ));
+                }
+                data[*branch] = val;
+                H::digest(&data)
+            })
+    }
+}
+
+impl<E, I, T, Arity> MerkleBatchProof<E, I, T, Arity>
+where
+    E: Element,
+    I: Index + ToTraversalPath<Arity>,
+    T: NodeValue,
+    Arity: Unsigned,
+{
+    /// Return the elements covered by this proof, in the same order as
+    /// [`Self::indices`]. Return `None` if any of them is missing from the
+    /// proof.
+    pub fn elems(&self) -> Option<Vec<&E>> {
+        self.pos
+            .iter()
+            .map(|pos| {
+                let traversal_path = pos.to_traversal_path(self.height);
+                match self.proof.descend(self.height, &traversal_path) {
+                    MerkleNode::Leaf { elem, .. } => Some(elem),
+                    _ => None,
+                }
+            })
+            .collect()
+    }
+
+    /// Verify a batch membership proof by recomputing the root of the pruned
+    /// tree and comparing it to the expected one.
+    pub(crate) fn verify_batch_membership_proof<H>(
+        &self,
+        expected_root: &T,
+    ) -> Result<VerificationResult, PrimitivesError>
+    where
+        H: DigestAlgorithm<E, I, T>,
+    {
+        for pos in self.pos.iter() {
+            let traversal_path = pos.to_traversal_path(self.height);
+            match self.proof.descend(self.height, &traversal_path) {
+                MerkleNode::Leaf {
+                    value: _,
+                    pos: leaf_pos,
+                    elem: _,
+                } if leaf_pos == pos => {},
+                _ => return Ok(Err(())),
+            }
+        }
+        let computed_root = self.proof.compute_digest::<H, Arity>(self.height)?;
+        if computed_root == *expected_root {
+            Ok(Ok(()))
+        } else {
+            Ok(Err(()))
+        }
+    }
 }
 
 /// Iterator type for a merkle tree
@@ -994,14 +1731,107 @@ where
     }
 }
 
+/// An iterator type over the in-memory leaves of a merkle tree along with
+/// their membership proofs. The proof nodes of the branches on the current
+/// path are computed once and shared by all the leaves below them.
+pub struct MerkleProofIter<'a, E: Element, I: Index, T: NodeValue, Arity: Unsigned> {
+    /// A leaf at the root of a tree of height 0, yielded first
+    root_leaf: Option<&'a MerkleNode<E, I, T>>,
+    /// Children of the branches on the current path, from the root, along with
+    /// the index of the next child to visit
+    stack: Vec<(&'a [Arc<MerkleNode<E, I, T>>], usize)>,
+    /// Proof nodes of the branches on the current path, from the root
+    path: Vec<MerkleNode<E, I, T>>,
+
+    _phantom: PhantomData<Arity>,
+}
+
+impl<'a, E, I, T, Arity> MerkleProofIter<'a, E, I, T, Arity>
+where
+    E: Element,
+    I: Index,
+    T: NodeValue,
+    Arity: Unsigned,
+{
+    /// Initialize an iterator
+    pub fn new(root: &'a MerkleNode<E, I, T>) -> Self {
+        let mut iter = Self {
+            root_leaf: None,
+            stack: vec![],
+            path: vec![],
+            _phantom: PhantomData,
+        };
+        match root {
+            MerkleNode::Branch { children, .. } => iter.enter(children),
+            MerkleNode::Leaf { .. } => iter.root_leaf = Some(root),
+            _ => {},
+        }
+        iter
+    }
+
+    fn enter(&mut self, children: &'a [Arc<MerkleNode<E, I, T>>]) {
+        self.path.push(MerkleNode::Branch {
+            value: T::default(),
+            children: children
+                .iter()
+                .map(|child| Arc::new(child.pruned()))
+                .collect(),
+        });
+        self.stack.push((children, 0));
+    }
+
+    fn proof_for(&self, leaf: &MerkleNode<E, I, T>, pos: &I) -> MerkleProof<E, I, T, Arity> {
+        let mut proof = Vec::with_capacity(self.path.len() + 1);
+        proof.push(leaf.clone());
+        proof.extend(self.path.iter().rev().cloned());
+        MerkleProof::new(pos.clone(), proof)
+    }
+}
+
+impl<'a, E, I, T, Arity> Iterator for MerkleProofIter<'a, E, I, T, Arity>
+where
+    E: Element,
+    I: Index,
+    T: NodeValue,
+    Arity: Unsigned,
+{
+    type Item = (I, &'a E, MerkleProof<E, I, T, Arity>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(leaf @ MerkleNode::Leaf { pos, elem, .. }) = self.root_leaf.take() {
+            return Some((pos.clone(), elem, self.proof_for(leaf, pos)));
+        }
+        loop {
+            let (children, idx) = match self.stack.last_mut() {
+                Some((children, idx)) => {
+                    *idx += 1;
+                    (*children, *idx - 1)
+                },
+                None => return None,
+            };
+            match children.get(idx).map(|child| &**child) {
+                None => {
+                    self.stack.pop();
+                    self.path.pop();
+                },
+                Some(MerkleNode::Branch { children, .. }) => self.enter(children),
+                Some(leaf @ MerkleNode::Leaf { pos, elem, .. }) => {
+                    return Some((pos.clone(), elem, self.proof_for(leaf, pos)));
+                },
+                Some(_) => {},
+            }
+        }
+    }
+}
+
 /// An owned iterator type for a merkle tree
 pub struct MerkleTreeIntoIter<E: Element, I: Index, T: NodeValue> {
-    stack: Vec<Box<MerkleNode<E, I, T>>>,
+    stack: Vec<Arc<MerkleNode<E, I, T>>>,
 }
 
 impl<E: Element, I: Index, T: NodeValue> MerkleTreeIntoIter<E, I, T> {
     /// Initialize an iterator
-    pub fn new(root: Box<MerkleNode<E, I, T>>) -> Self {
+    pub fn new(root: Arc<MerkleNode<E, I, T>>) -> Self {
         Self { stack: vec![root] }
     }
 }
@@ -1016,7 +1846,9 @@ where
 
     fn next(&mut self) -> Option<Self::Item> {
         while let Some(node) = self.stack.pop() {
-            match *node {
+            // Nodes still shared with a snapshot are copied, the others are
+            // moved out
+            match Arc::try_unwrap(node).unwrap_or_else(|node| (*node).clone()) {
                 MerkleNode::Branch { value: _, children } => {
                     children
                         .into_iter()
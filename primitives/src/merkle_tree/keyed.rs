@@ -0,0 +1,221 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+//! Byte-string keys for sparse Merkle trees.
+//!
+//! A [`HashedKey`] can index a [`UniversalMerkleTree`] with arbitrary byte
+//! strings, turning it into an authenticated key-value map: the position of a
+//! key is the digest of the key under the path hash `H`, so keys of any length
+//! are spread uniformly over the tree. The tree should be at least
+//! [`HashedKey::min_tree_height`] high, so that distinct digests never share a
+//! position.
+//!
+//! ```
+//! # use jf_primitives::errors::PrimitivesError;
+//! use jf_primitives::merkle_tree::{
+//!     keyed::{HashedKey, Sha3KeyedMerkleTree},
+//!     MerkleCommitment, MerkleTreeScheme, UniversalMerkleTreeScheme,
+//! };
+//! use sha3::Sha3_256;
+//! use typenum::U2;
+//!
+//! # fn main() -> Result<(), PrimitivesError> {
+//! let height = HashedKey::<Sha3_256>::min_tree_height::<U2>();
+//! let mut mt = Sha3KeyedMerkleTree::<u64>::new(height);
+//! mt.update(HashedKey::new(b"alice"), 42)?;
+//!
+//! let root = mt.commitment().digest();
+//! let (val, proof) = mt.lookup(HashedKey::new(b"alice")).expect_ok()?;
+//! assert_eq!(val, &42);
+//! assert!(Sha3KeyedMerkleTree::<u64>::verify(root, HashedKey::new(b"alice"), proof)?.is_ok());
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! [`UniversalMerkleTree`]: super::universal_merkle_tree::UniversalMerkleTree
+
+use super::{
+    prelude::{Sha3Digest, Sha3Node},
+    universal_merkle_tree::UniversalMerkleTree,
+    ToTraversalPath,
+};
+use ark_serialize::{
+    CanonicalDeserialize, CanonicalSerialize, Compress, Read, SerializationError, Valid, Validate,
+    Write,
+};
+use ark_std::{marker::PhantomData, vec, vec::Vec};
+use digest::Digest;
+use num_bigint::BigUint;
+use num_traits::ToPrimitive;
+use sha3::Sha3_256;
+use typenum::{Unsigned, U2};
+
+/// A binary sparse Merkle tree keyed by byte strings hashed with SHA3-256.
+pub type Sha3KeyedMerkleTree<E> =
+    UniversalMerkleTree<E, Sha3Digest, HashedKey<Sha3_256>, U2, Sha3Node>;
+
+/// A byte-string key whose position in a tree is its digest under `H`, see
+/// the [module documentation](self).
+#[derive(Derivative)]
+#[derivative(
+    Clone(bound = ""),
+    Debug(bound = ""),
+    Eq(bound = ""),
+    Hash(bound = ""),
+    Ord(bound = ""),
+    PartialEq(bound = ""),
+    PartialOrd(bound = "")
+)]
+pub struct HashedKey<H: Digest> {
+    key: Vec<u8>,
+    #[derivative(Debug = "ignore")]
+    _phantom: PhantomData<H>,
+}
+
+impl<H: Digest> HashedKey<H> {
+    /// Wrap a byte-string key.
+    pub fn new(key: impl AsRef<[u8]>) -> Self {
+        Self {
+            key: key.as_ref().to_vec(),
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Return the underlying key.
+    pub fn key(&self) -> &[u8] {
+        &self.key
+    }
+
+    /// Return the minimum height of a tree of the given arity such that every
+    /// digest of `H` has a distinct position.
+    pub fn min_tree_height<Arity: Unsigned>() -> usize {
+        let capacity = BigUint::from(1u8) << (8 * <H as Digest>::output_size());
+        let mut height = 0;
+        let mut size = BigUint::from(1u8);
+        while size < capacity {
+            size *= Arity::to_u64();
+            height += 1;
+        }
+        height
+    }
+}
+
+impl<H: Digest, Arity: Unsigned> ToTraversalPath<Arity> for HashedKey<H> {
+    fn to_traversal_path(&self, height: usize) -> Vec<usize> {
+        let mut pos = BigUint::from_bytes_be(&H::digest(&self.key));
+        let mut ret = vec![];
+        for _i in 0..height {
+            ret.push((&pos % (Arity::to_u64())).to_usize().unwrap());
+            pos /= Arity::to_u64();
+        }
+        ret
+    }
+}
+
+impl<H: Digest> CanonicalSerialize for HashedKey<H> {
+    fn serialize_with_mode<W: Write>(
+        &self,
+        writer: W,
+        compress: Compress,
+    ) -> Result<(), SerializationError> {
+        self.key.serialize_with_mode(writer, compress)
+    }
+
+    fn serialized_size(&self, compress: Compress) -> usize {
+        self.key.serialized_size(compress)
+    }
+}
+
+impl<H: Digest> CanonicalDeserialize for HashedKey<H> {
+    fn deserialize_with_mode<R: Read>(
+        reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        Ok(Self::new(Vec::<u8>::deserialize_with_mode(
+            reader, compress, validate,
+        )?))
+    }
+}
+
+impl<H: Digest> Valid for HashedKey<H> {
+    fn check(&self) -> Result<(), SerializationError> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod mt_tests {
+    use crate::merkle_tree::{
+        keyed::{HashedKey, Sha3KeyedMerkleTree},
+        prelude::{Sha3Digest, Sha3Node},
+        universal_merkle_tree::UniversalMerkleTree,
+        *,
+    };
+    use sha2::Sha256;
+    use sha3::Sha3_256;
+    use typenum::{U2, U3};
+
+    #[test]
+    fn test_hashed_key_height() {
+        assert_eq!(HashedKey::<Sha3_256>::min_tree_height::<U2>(), 256);
+        assert_eq!(HashedKey::<Sha3_256>::min_tree_height::<U3>(), 162);
+    }
+
+    #[test]
+    fn test_keyed_mt() {
+        let height = HashedKey::<Sha3_256>::min_tree_height::<U2>();
+        let mut mt = Sha3KeyedMerkleTree::<u64>::new(height);
+        let keys: Vec<HashedKey<Sha3_256>> = ["", "a", "alice", "bob", "a much longer key"]
+            .iter()
+            .map(HashedKey::new)
+            .collect();
+        for (i, key) in keys.iter().enumerate() {
+            assert!(mt.update(key, i as u64).unwrap().expect_not_found().is_ok());
+        }
+        assert_eq!(mt.num_leaves(), keys.len() as u64);
+
+        let root = mt.commitment().digest();
+        for (i, key) in keys.iter().enumerate() {
+            let (val, proof) = mt.lookup(key).expect_ok().unwrap();
+            assert_eq!(val, &(i as u64));
+            assert!(Sha3KeyedMerkleTree::<u64>::verify(root, key, &proof)
+                .unwrap()
+                .is_ok());
+            let other = &keys[(i + 1) % keys.len()];
+            assert!(Sha3KeyedMerkleTree::<u64>::verify(root, other, &proof)
+                .unwrap()
+                .is_err());
+        }
+
+        // Absent keys come with non-membership proofs
+        let absent = HashedKey::new(b"carol");
+        let proof = mt.universal_lookup(&absent).expect_not_found().unwrap();
+        assert!(
+            Sha3KeyedMerkleTree::<u64>::verify_non_membership(root, &absent, &proof)
+                .unwrap()
+                .is_ok()
+        );
+
+        // Updating a key replaces its value
+        assert_eq!(
+            mt.update(&keys[2], 100u64).unwrap().expect_ok().unwrap().0,
+            2
+        );
+        assert_eq!(mt.lookup(&keys[2]).expect_ok().unwrap().0, &100);
+        assert_eq!(mt.num_leaves(), keys.len() as u64);
+
+        // The path hash is configurable and changes the layout of the tree
+        let mut other_mt =
+            UniversalMerkleTree::<u64, Sha3Digest, HashedKey<Sha256>, U2, Sha3Node>::new(height);
+        other_mt.update(HashedKey::new(b"alice"), 2u64).unwrap();
+        assert!(other_mt
+            .lookup(HashedKey::new(b"alice"))
+            .expect_ok()
+            .is_ok());
+        assert_ne!(other_mt.commitment().digest(), root);
+    }
+}
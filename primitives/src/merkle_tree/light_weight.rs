@@ -5,22 +5,26 @@
 // along with the Jellyfish library. If not, see <https://mit-license.org/>.
 
 //! A light weight merkle tree is an append only merkle tree who only keeps its
-//! frontier -- the right-most path.
+//! frontier -- the right-most path. A [`SlidingWindowMerkleTree`] additionally
+//! keeps a fixed number of its latest leaves.
 
 use super::{
+    consistency::{MerkleAppendProof, MerkleConsistencyProof},
     internal::{
-        build_light_weight_tree_internal, MerkleNode, MerkleProof, MerkleTreeCommitment,
-        MerkleTreeIntoIter, MerkleTreeIter,
+        build_light_weight_tree_internal, MerkleBatchProof, MerkleNode, MerkleProof,
+        MerkleProofIter, MerkleTreeCommitment, MerkleTreeIntoIter, MerkleTreeIter,
     },
     AppendableMerkleTreeScheme, DigestAlgorithm, Element, ForgetableMerkleTreeScheme, Index,
-    LookupResult, MerkleCommitment, MerkleTreeScheme, NodeValue, ToTraversalPath,
+    LookupResult, MaybeSendSync, MerkleCommitment, MerkleTreeScheme, MissingSubtree, NodeValue,
+    ToTraversalPath,
 };
 use crate::{
     errors::{PrimitivesError, VerificationResult},
     impl_forgetable_merkle_tree_scheme, impl_merkle_tree_scheme,
 };
+use alloc::sync::Arc;
 use ark_std::{
-    borrow::Borrow, boxed::Box, fmt::Debug, marker::PhantomData, string::ToString, vec, vec::Vec,
+    borrow::Borrow, boxed::Box, fmt::Debug, marker::PhantomData, string::ToString, vec::Vec,
 };
 use num_bigint::BigUint;
 use num_traits::pow::pow;
@@ -41,12 +45,29 @@ where
     /// Initialize an empty Merkle tree.
     pub fn new(height: usize) -> Self {
         Self {
-            root: Box::new(MerkleNode::<E, I, T>::Empty),
+            root: Arc::new(MerkleNode::<E, I, T>::Empty),
             height,
             num_leaves: 0,
             _phantom: PhantomData,
         }
     }
+
+    /// Increase the height of this tree, keeping its leaves at the same
+    /// positions. The new root is the digest of the old one with empty
+    /// siblings, so the tree is the one built at the new height.
+    /// * `height` - the new height, at least the current one
+    /// * `returns` - Ok(()) if successful, Err() if `height` is smaller than
+    ///   the current height
+    pub fn increase_height(&mut self, height: usize) -> Result<(), PrimitivesError> {
+        if height < self.height {
+            return Err(PrimitivesError::ParameterError(
+                "Cannot decrease the height of a merkle tree".to_string(),
+            ));
+        }
+        Arc::make_mut(&mut self.root).grow_internal::<H, Arity>(height - self.height)?;
+        self.height = height;
+        Ok(())
+    }
 }
 
 impl<E, H, Arity, T> LightWeightMerkleTree<E, H, u64, Arity, T>
@@ -64,7 +85,11 @@ where
     pub fn from_elems(
         height: Option<usize>,
         elems: impl IntoIterator<Item = impl Borrow<E>>,
-    ) -> Result<Self, PrimitivesError> {
+    ) -> Result<Self, PrimitivesError>
+    where
+        E: MaybeSendSync,
+        T: MaybeSendSync,
+    {
         let (root, height, num_leaves) =
             build_light_weight_tree_internal::<E, H, Arity, T>(height, elems)?;
         Ok(Self {
@@ -74,6 +99,128 @@ where
             _phantom: PhantomData,
         })
     }
+
+    /// Construct a new Merkle tree with given height from a stream of
+    /// elements, in a single pass and with memory usage bounded by the height
+    /// of the tree. Unlike [`Self::from_elems`], which collects all elements to
+    /// hash the leaves in parallel, the elements are never materialized.
+    /// * `height` - height of the Merkle tree
+    /// * `elems` - an iterator to all elements
+    /// * `returns` - A constructed Merkle tree, or `Err()` if there are too
+    ///   many elements for the given height
+    pub fn from_elems_streaming(
+        height: usize,
+        elems: impl IntoIterator<Item = impl Borrow<E>>,
+    ) -> Result<Self, PrimitivesError> {
+        let mut mt = Self::new(height);
+        <Self as AppendableMerkleTreeScheme>::extend(&mut mt, elems)?;
+        Ok(mt)
+    }
+
+    /// Prove that this tree is an append-only extension of its first
+    /// `old_num_leaves` leaves, see [`MerkleConsistencyProof`].
+    /// Only possible if the leaf at position `old_num_leaves - 1` is still in
+    /// memory.
+    pub fn consistency_proof(
+        &self,
+        old_num_leaves: u64,
+    ) -> Result<MerkleConsistencyProof<E, T, Arity>, PrimitivesError> {
+        MerkleConsistencyProof::generate(self, old_num_leaves)
+    }
+
+    /// Prove the insertions which will be performed next on this tree, see
+    /// [`MerkleAppendProof`].
+    /// Only possible while the last leaf is in memory, see also
+    /// [`Self::frontier`].
+    pub fn append_proof(&self) -> Result<MerkleAppendProof<E, T, Arity>, PrimitivesError> {
+        MerkleAppendProof::generate(self)
+    }
+
+    /// Rewind this tree to its first `num_leaves` leaves, given the membership
+    /// proof in the current tree of the leaf at position `num_leaves - 1`,
+    /// which becomes the new frontier. Such a proof can for instance be
+    /// obtained from a full [`MerkleTree`](super::append_only::MerkleTree)
+    /// with the same content, or from a [`MerkleConsistencyProof`].
+    /// * `returns` - Ok(()) if successful, Err() if the proof is invalid.
+    pub fn truncate_with_proof(
+        &mut self,
+        num_leaves: u64,
+        proof: impl Borrow<MerkleProof<E, u64, T, Arity>>,
+    ) -> Result<(), PrimitivesError> {
+        let proof = proof.borrow();
+        if num_leaves > 0 && num_leaves <= self.num_leaves {
+            let elem = proof
+                .elem()
+                .ok_or_else(|| PrimitivesError::ParameterError("Invalid proof type".to_string()))?;
+            if proof.pos != num_leaves - 1 {
+                return Err(PrimitivesError::ParameterError(
+                    "Proof is not for the new frontier".to_string(),
+                ));
+            }
+            self.remember(proof.pos, elem, proof)?;
+        }
+        <Self as AppendableMerkleTreeScheme>::truncate(self, num_leaves)
+    }
+
+    /// Return the frontier of this tree, i.e. the membership proof of its
+    /// right-most leaf, whose nodes are the right-most path of the tree.
+    /// Together with the commitment of the tree, it is enough to restore the
+    /// tree with [`Self::from_frontier`].
+    /// * `returns` - `None` if the tree is empty or if its last leaf has been
+    ///   forgotten.
+    pub fn frontier(&self) -> Option<MerkleProof<E, u64, T, Arity>> {
+        if self.num_leaves == 0 {
+            return None;
+        }
+        match self.lookup(self.num_leaves - 1) {
+            LookupResult::Ok(_, proof) => Some(proof),
+            _ => None,
+        }
+    }
+
+    /// Same as [`Self::frontier`], the frontier to persist this tree.
+    pub fn to_frontier(&self) -> Option<MerkleProof<E, u64, T, Arity>> {
+        self.frontier()
+    }
+
+    /// Restore a light weight Merkle tree from its commitment and its frontier
+    /// as returned by [`Self::frontier`]. An empty tree has no frontier and
+    /// should be restored with [`Self::new`] instead.
+    /// * `commitment` - commitment of the tree to restore
+    /// * `frontier` - membership proof of the right-most leaf of the tree
+    /// * `returns` - the restored tree, which accepts further insertions, or
+    ///   `Err()` if the frontier is inconsistent with the commitment
+    pub fn from_frontier(
+        commitment: &MerkleTreeCommitment<T>,
+        frontier: impl Borrow<MerkleProof<E, u64, T, Arity>>,
+    ) -> Result<Self, PrimitivesError> {
+        let frontier = frontier.borrow();
+        let height = commitment.height();
+        let num_leaves = commitment.size();
+        if num_leaves == 0 {
+            return Err(PrimitivesError::ParameterError(
+                "An empty tree has no frontier".to_string(),
+            ));
+        }
+        if frontier.pos != num_leaves - 1 || frontier.tree_height() != height + 1 {
+            return Err(PrimitivesError::ParameterError(
+                "Frontier does not match the tree commitment".to_string(),
+            ));
+        }
+        let elem = frontier.elem().ok_or_else(|| {
+            PrimitivesError::ParameterError("Frontier is missing its leaf".to_string())
+        })?;
+        let mut mt = Self {
+            root: Arc::new(MerkleNode::ForgettenSubtree {
+                value: commitment.digest(),
+            }),
+            height,
+            num_leaves,
+            _phantom: PhantomData,
+        };
+        mt.remember(frontier.pos, elem, frontier)?;
+        Ok(mt)
+    }
 }
 
 impl<E, H, Arity, T> AppendableMerkleTreeScheme for LightWeightMerkleTree<E, H, u64, Arity, T>
@@ -95,7 +242,7 @@ where
 
         let traversal_path =
             ToTraversalPath::<Arity>::to_traversal_path(&self.num_leaves, self.height);
-        self.num_leaves += self.root.extend_and_forget_internal::<H, Arity>(
+        self.num_leaves += Arc::make_mut(&mut self.root).extend_and_forget_internal::<H, Arity>(
             self.height,
             &self.num_leaves,
             &traversal_path,
@@ -109,6 +256,219 @@ where
         }
         Ok(())
     }
+
+    /// The new last leaf must be in memory so that the tree keeps its
+    /// frontier, see [`LightWeightMerkleTree::truncate_with_proof`] otherwise.
+    fn truncate(&mut self, num_leaves: u64) -> Result<(), PrimitivesError> {
+        if num_leaves > self.num_leaves {
+            return Err(PrimitivesError::ParameterError(
+                "Cannot truncate a merkle tree to a larger size".to_string(),
+            ));
+        }
+        if num_leaves < self.num_leaves {
+            if num_leaves > 0 && !matches!(self.lookup(num_leaves - 1), LookupResult::Ok(..)) {
+                return Err(PrimitivesError::ParameterError(
+                    "New frontier of the merkle tree is not in memory".to_string(),
+                ));
+            }
+            Arc::make_mut(&mut self.root).truncate_internal::<H, Arity>(self.height, num_leaves)?;
+            self.num_leaves = num_leaves;
+        }
+        Ok(())
+    }
+}
+
+/// A light weight Merkle tree which keeps in memory its `window` latest leaves,
+/// rather than only its frontier, so that proofs for recent leaves can still
+/// be served. Older leaves are forgotten as they fall out of the window.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(bound = "E: ark_serialize::CanonicalSerialize + ark_serialize::CanonicalDeserialize")]
+pub struct SlidingWindowMerkleTree<E, H, Arity, T>
+where
+    E: Element,
+    H: DigestAlgorithm<E, u64, T>,
+    Arity: Unsigned,
+    T: NodeValue,
+{
+    tree: LightWeightMerkleTree<E, H, u64, Arity, T>,
+    window: u64,
+}
+
+impl<E, H, Arity, T> SlidingWindowMerkleTree<E, H, Arity, T>
+where
+    E: Element,
+    H: DigestAlgorithm<E, u64, T>,
+    Arity: Unsigned,
+    T: NodeValue,
+{
+    /// Initialize an empty Merkle tree.
+    /// * `window` - number of latest leaves to keep in memory, at least 1
+    pub fn new(height: usize, window: u64) -> Result<Self, PrimitivesError> {
+        Self::from_tree(LightWeightMerkleTree::new(height), window)
+    }
+
+    /// Keep the latest leaves of an existing light weight Merkle tree, e.g.
+    /// one restored with [`LightWeightMerkleTree::from_frontier`]. The leaves
+    /// it has already forgotten stay forgotten.
+    /// * `window` - number of latest leaves to keep in memory, at least 1
+    pub fn from_tree(
+        tree: LightWeightMerkleTree<E, H, u64, Arity, T>,
+        window: u64,
+    ) -> Result<Self, PrimitivesError> {
+        if window == 0 {
+            return Err(PrimitivesError::ParameterError(
+                "The window should contain at least the last leaf".to_string(),
+            ));
+        }
+        Ok(Self { tree, window })
+    }
+
+    /// Return the number of latest leaves kept in memory.
+    pub fn window(&self) -> u64 {
+        self.window
+    }
+
+    /// Return the underlying light weight Merkle tree.
+    pub fn into_tree(self) -> LightWeightMerkleTree<E, H, u64, Arity, T> {
+        self.tree
+    }
+
+    /// Forget the leaves at positions `from` up to the beginning of the window.
+    fn forget_before_window(&mut self, from: u64) {
+        let start = self.tree.num_leaves.saturating_sub(self.window);
+        for pos in from..start {
+            self.tree.forget(pos);
+        }
+    }
+}
+
+impl<E, H, Arity, T> MerkleTreeScheme for SlidingWindowMerkleTree<E, H, Arity, T>
+where
+    E: Element,
+    H: DigestAlgorithm<E, u64, T>,
+    Arity: Unsigned,
+    T: NodeValue,
+{
+    type Element = E;
+    type Index = u64;
+    type NodeValue = T;
+    type MembershipProof = MerkleProof<E, u64, T, Arity>;
+    type BatchMembershipProof = MerkleBatchProof<E, u64, T, Arity>;
+    type Commitment = MerkleTreeCommitment<T>;
+
+    const ARITY: usize = Arity::USIZE;
+
+    fn height(&self) -> usize {
+        self.tree.height()
+    }
+
+    fn capacity(&self) -> BigUint {
+        self.tree.capacity()
+    }
+
+    fn num_leaves(&self) -> u64 {
+        self.tree.num_leaves()
+    }
+
+    fn commitment(&self) -> Self::Commitment {
+        self.tree.commitment()
+    }
+
+    fn lookup(
+        &self,
+        pos: impl Borrow<Self::Index>,
+    ) -> LookupResult<
+        &Self::Element,
+        Self::MembershipProof,
+        (),
+        Option<MissingSubtree<Self::NodeValue>>,
+    > {
+        self.tree.lookup(pos)
+    }
+
+    fn verify(
+        root: impl Borrow<Self::NodeValue>,
+        pos: impl Borrow<Self::Index>,
+        proof: impl Borrow<Self::MembershipProof>,
+    ) -> Result<VerificationResult, PrimitivesError> {
+        LightWeightMerkleTree::<E, H, u64, Arity, T>::verify(root, pos, proof)
+    }
+
+    fn multi_lookup(
+        &self,
+        pos: impl IntoIterator<Item = impl Borrow<Self::Index>>,
+    ) -> LookupResult<Vec<Self::Element>, Self::BatchMembershipProof, ()> {
+        self.tree.multi_lookup(pos)
+    }
+
+    fn verify_batch(
+        root: impl Borrow<Self::NodeValue>,
+        pos: impl IntoIterator<Item = impl Borrow<Self::Index>>,
+        proof: impl Borrow<Self::BatchMembershipProof>,
+    ) -> Result<VerificationResult, PrimitivesError> {
+        LightWeightMerkleTree::<E, H, u64, Arity, T>::verify_batch(root, pos, proof)
+    }
+
+    fn iter(&self) -> MerkleTreeIter<E, u64, T> {
+        self.tree.iter()
+    }
+
+    fn iter_with_proofs(
+        &self,
+    ) -> Box<dyn Iterator<Item = (u64, &Self::Element, Self::MembershipProof)> + '_> {
+        self.tree.iter_with_proofs()
+    }
+}
+
+impl<E, H, Arity, T> AppendableMerkleTreeScheme for SlidingWindowMerkleTree<E, H, Arity, T>
+where
+    E: Element,
+    H: DigestAlgorithm<E, u64, T>,
+    Arity: Unsigned,
+    T: NodeValue,
+{
+    fn push(&mut self, elem: impl Borrow<Self::Element>) -> Result<(), PrimitivesError> {
+        <Self as AppendableMerkleTreeScheme>::extend(self, [elem])
+    }
+
+    fn extend(
+        &mut self,
+        elems: impl IntoIterator<Item = impl Borrow<Self::Element>>,
+    ) -> Result<(), PrimitivesError> {
+        let mut iter = elems.into_iter().peekable();
+        // Insert at most a window worth of leaves at a time, so that no more
+        // than two windows are ever in memory.
+        let chunk_size = usize::try_from(self.window).unwrap_or(usize::MAX);
+        while iter.peek().is_some() {
+            let start = self.tree.num_leaves.saturating_sub(self.window);
+            let mut chunk = iter.by_ref().take(chunk_size).peekable();
+            let traversal_path = ToTraversalPath::<Arity>::to_traversal_path(
+                &self.tree.num_leaves,
+                self.tree.height,
+            );
+            self.tree.num_leaves += Arc::make_mut(&mut self.tree.root)
+                .extend_internal::<H, Arity>(
+                    self.tree.height,
+                    &self.tree.num_leaves,
+                    &traversal_path,
+                    true,
+                    &mut chunk,
+                )?;
+            self.forget_before_window(start);
+            if chunk.peek().is_some() {
+                return Err(PrimitivesError::ParameterError(
+                    "Exceed merkle tree capacity".to_string(),
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// The leaves forgotten before the truncation are not remembered, so the
+    /// window only fills up again as new leaves are inserted.
+    fn truncate(&mut self, num_leaves: u64) -> Result<(), PrimitivesError> {
+        self.tree.truncate(num_leaves)
+    }
 }
 
 #[cfg(test)]
@@ -116,7 +476,8 @@ mod mt_tests {
     use crate::{
         merkle_tree::{
             internal::{MerkleNode, MerkleProof},
-            prelude::{RescueLightWeightMerkleTree, RescueMerkleTree},
+            light_weight::SlidingWindowMerkleTree,
+            prelude::{RescueHash, RescueLightWeightMerkleTree, RescueMerkleTree},
             *,
         },
         rescue::RescueParameter,
@@ -124,6 +485,7 @@ mod mt_tests {
     use ark_ed_on_bls12_377::Fq as Fq377;
     use ark_ed_on_bls12_381::Fq as Fq381;
     use ark_ed_on_bn254::Fq as Fq254;
+    use typenum::U3;
 
     #[test]
     fn test_light_mt_builder() {
@@ -138,6 +500,202 @@ mod mt_tests {
         assert!(RescueLightWeightMerkleTree::<F>::from_elems(Some(1), &data).is_ok());
         data.push(F::from(0u64));
         assert!(RescueLightWeightMerkleTree::<F>::from_elems(Some(1), &data).is_err());
+
+        // Bulk construction agrees with incremental insertions, and only keeps
+        // the frontier
+        let data: Vec<F> = (0..50u64).map(F::from).collect();
+        let mt = RescueLightWeightMerkleTree::<F>::from_elems(None, &data).unwrap();
+        let mut expected = RescueLightWeightMerkleTree::<F>::new(mt.height());
+        expected.extend(&data).unwrap();
+        assert_eq!(mt.commitment(), expected.commitment());
+        assert_eq!(
+            mt.commitment(),
+            RescueMerkleTree::<F>::from_elems(None, &data)
+                .unwrap()
+                .commitment()
+        );
+        (0..49).for_each(|i| assert!(mt.lookup(i).expect_not_in_memory().is_ok()));
+        assert!(mt.lookup(49).expect_ok().is_ok());
+    }
+
+    #[test]
+    fn test_light_mt_streaming_builder() {
+        test_light_mt_streaming_builder_helper::<Fq254>();
+        test_light_mt_streaming_builder_helper::<Fq377>();
+        test_light_mt_streaming_builder_helper::<Fq381>();
+    }
+
+    fn test_light_mt_streaming_builder_helper<F: RescueParameter>() {
+        let mt =
+            RescueLightWeightMerkleTree::<F>::from_elems_streaming(7, (0..1000u64).map(F::from))
+                .unwrap();
+        let expected =
+            RescueLightWeightMerkleTree::<F>::from_elems(Some(7), (0..1000u64).map(F::from))
+                .unwrap();
+        assert_eq!(mt.commitment(), expected.commitment());
+        assert!(mt.lookup(999).expect_ok().is_ok());
+        assert!(mt.lookup(998).expect_not_in_memory().is_ok());
+        assert!(
+            RescueLightWeightMerkleTree::<F>::from_elems_streaming(2, (0..10u64).map(F::from))
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_light_mt_frontier() {
+        test_light_mt_frontier_helper::<Fq254>();
+        test_light_mt_frontier_helper::<Fq377>();
+        test_light_mt_frontier_helper::<Fq381>();
+    }
+
+    fn test_light_mt_frontier_helper<F: RescueParameter>() {
+        let mut mt = RescueLightWeightMerkleTree::<F>::new(3);
+        assert!(mt.to_frontier().is_none());
+        mt.extend((0..10u64).map(F::from)).unwrap();
+        let commitment = mt.commitment();
+        let frontier = mt.to_frontier().unwrap();
+        assert_eq!(frontier.pos, 9);
+
+        // The frontier survives serialization
+        let frontier: <RescueLightWeightMerkleTree<F> as MerkleTreeScheme>::MembershipProof =
+            bincode::deserialize(&bincode::serialize(&frontier).unwrap()).unwrap();
+
+        let mut restored =
+            RescueLightWeightMerkleTree::<F>::from_frontier(&commitment, &frontier).unwrap();
+        assert_eq!(restored.commitment(), commitment);
+        assert_eq!(restored.lookup(9).expect_ok().unwrap().0, &F::from(9u64));
+        assert!(restored.lookup(8).expect_not_in_memory().is_ok());
+
+        // The restored tree keeps growing like the original one
+        mt.extend((10..20u64).map(F::from)).unwrap();
+        restored.extend((10..20u64).map(F::from)).unwrap();
+        assert_eq!(restored.commitment(), mt.commitment());
+
+        // Inconsistent frontiers are rejected
+        let other = RescueLightWeightMerkleTree::<F>::from_elems(Some(3), [F::from(1u64); 10])
+            .unwrap()
+            .to_frontier()
+            .unwrap();
+        assert!(RescueLightWeightMerkleTree::<F>::from_frontier(&commitment, &other).is_err());
+        let proof = RescueLightWeightMerkleTree::<F>::from_elems(Some(3), [F::from(1u64); 9])
+            .unwrap()
+            .to_frontier()
+            .unwrap();
+        assert!(RescueLightWeightMerkleTree::<F>::from_frontier(&commitment, &proof).is_err());
+        let empty = RescueLightWeightMerkleTree::<F>::new(3).commitment();
+        assert!(RescueLightWeightMerkleTree::<F>::from_frontier(&empty, &frontier).is_err());
+
+        // Once the last leaf has been forgotten, there is no frontier anymore
+        mt.forget(19).expect_ok().unwrap();
+        assert!(mt.to_frontier().is_none());
+    }
+
+    #[test]
+    fn test_light_mt_truncate() {
+        test_light_mt_truncate_helper::<Fq254>();
+        test_light_mt_truncate_helper::<Fq377>();
+        test_light_mt_truncate_helper::<Fq381>();
+    }
+
+    fn test_light_mt_truncate_helper<F: RescueParameter>() {
+        let elems: Vec<F> = (0..20u64).map(F::from).collect();
+        let full_mt = RescueMerkleTree::<F>::from_elems(Some(3), &elems).unwrap();
+        let mut mt = RescueLightWeightMerkleTree::<F>::from_elems(Some(3), &elems).unwrap();
+
+        // The new frontier has been forgotten
+        assert!(mt.pop().is_err());
+        assert!(mt.truncate(10).is_err());
+        assert_eq!(mt.num_leaves(), 20);
+
+        // Rewinding with the help of a proof
+        let proof = full_mt.lookup(8).expect_ok().unwrap().1;
+        assert!(mt.truncate_with_proof(10, &proof).is_err());
+        let proof = full_mt.consistency_proof(10).unwrap().proof.unwrap();
+        mt.truncate_with_proof(10, &proof).unwrap();
+        assert_eq!(
+            mt.commitment(),
+            RescueLightWeightMerkleTree::<F>::from_elems(Some(3), &elems[..10])
+                .unwrap()
+                .commitment()
+        );
+        assert!(mt.lookup(9).expect_ok().is_ok());
+        assert_eq!(
+            mt.to_frontier().unwrap(),
+            mt.lookup(9).expect_ok().unwrap().1
+        );
+
+        // The frontier is still in memory, so it can be truncated to itself
+        mt.truncate(10).unwrap();
+        mt.extend(&elems[10..]).unwrap();
+        assert_eq!(mt.commitment(), full_mt.commitment());
+        mt.truncate_with_proof(0, &proof).unwrap();
+        assert_eq!(mt, RescueLightWeightMerkleTree::<F>::new(3));
+    }
+
+    #[test]
+    fn test_sliding_window_mt() {
+        test_sliding_window_mt_helper::<Fq254>();
+        test_sliding_window_mt_helper::<Fq377>();
+        test_sliding_window_mt_helper::<Fq381>();
+    }
+
+    fn test_sliding_window_mt_helper<F: RescueParameter>() {
+        type WindowMT<F> = SlidingWindowMerkleTree<F, RescueHash<F>, U3, F>;
+        assert!(WindowMT::<F>::new(3, 0).is_err());
+
+        let elems: Vec<F> = (0..20u64).map(F::from).collect();
+        let full_mt = RescueMerkleTree::<F>::from_elems(Some(3), &elems).unwrap();
+        let mut mt = WindowMT::<F>::new(3, 4).unwrap();
+        let mut batch_mt = WindowMT::<F>::new(3, 4).unwrap();
+        batch_mt.extend(&elems[..3]).unwrap();
+        batch_mt.extend(&elems[3..]).unwrap();
+        for (i, elem) in elems.iter().enumerate() {
+            mt.push(elem).unwrap();
+            assert_eq!(mt.num_leaves(), i as u64 + 1);
+            assert_eq!(
+                mt.commitment(),
+                RescueMerkleTree::<F>::from_elems(Some(3), &elems[..=i])
+                    .unwrap()
+                    .commitment()
+            );
+        }
+        assert_eq!(mt.commitment(), full_mt.commitment());
+        assert_eq!(batch_mt.commitment(), full_mt.commitment());
+
+        // Only the leaves within the window are in memory
+        for mt in [&mt, &batch_mt] {
+            let root = mt.commitment().digest();
+            for pos in 0..16u64 {
+                assert!(mt.lookup(pos).expect_not_in_memory().is_ok());
+            }
+            for pos in 16..20u64 {
+                let (elem, proof) = mt.lookup(pos).expect_ok().unwrap();
+                assert_eq!(elem, &F::from(pos));
+                assert!(WindowMT::<F>::verify(root, pos, &proof).unwrap().is_ok());
+            }
+            assert_eq!(mt.iter().count(), 4);
+        }
+
+        // Rewinding within the window
+        mt.truncate(17).unwrap();
+        assert!(mt.truncate(15).is_err());
+        mt.extend(&elems[17..]).unwrap();
+        assert_eq!(mt.commitment(), full_mt.commitment());
+
+        // Resuming from a frontier
+        let mut mt = WindowMT::<F>::from_tree(
+            RescueLightWeightMerkleTree::<F>::from_elems(Some(3), &elems[..10]).unwrap(),
+            3,
+        )
+        .unwrap();
+        assert_eq!(mt.window(), 3);
+        mt.extend(&elems[10..]).unwrap();
+        assert_eq!(mt.commitment(), full_mt.commitment());
+        assert!(mt.lookup(16).expect_not_in_memory().is_ok());
+        assert!(mt.lookup(17).expect_ok().is_ok());
+        assert!(mt.push(F::from(0u64)).is_ok());
+        assert!(mt.extend(&elems).is_err());
+        assert_eq!(mt.num_leaves(), 27);
     }
 
     #[test]
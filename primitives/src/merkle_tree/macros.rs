@@ -24,13 +24,33 @@ macro_rules! impl_merkle_tree_scheme {
             Arity: Unsigned,
             T: NodeValue,
         {
-            root: Box<MerkleNode<E, I, T>>,
+            root: Arc<MerkleNode<E, I, T>>,
             height: usize,
             num_leaves: u64,
 
             _phantom: PhantomData<(H, Arity)>,
         }
 
+        impl<E, H, I, Arity, T> $name<E, H, I, Arity, T>
+        where
+            E: Element,
+            H: DigestAlgorithm<E, I, T>,
+            I: Index,
+            Arity: Unsigned,
+            T: NodeValue,
+        {
+            fn from_root(root: Arc<MerkleNode<E, I, T>>, height: usize, num_leaves: u64) -> Self {
+                Self {
+                    root,
+                    height,
+                    num_leaves,
+                    _phantom: PhantomData,
+                }
+            }
+        }
+
+        $crate::impl_merkle_tree_scheme!(@inherent $name);
+
         impl<E, H, I, Arity, T> MerkleTreeScheme for $name<E, H, I, Arity, T>
         where
             E: Element,
@@ -43,8 +63,7 @@ macro_rules! impl_merkle_tree_scheme {
             type Index = I;
             type NodeValue = T;
             type MembershipProof = MerkleProof<E, I, T, Arity>;
-            // TODO(Chengyu): implement batch membership proof
-            type BatchMembershipProof = ();
+            type BatchMembershipProof = MerkleBatchProof<E, I, T, Arity>;
             type Commitment = MerkleTreeCommitment<T>;
 
             const ARITY: usize = Arity::USIZE;
@@ -68,14 +87,19 @@ macro_rules! impl_merkle_tree_scheme {
             fn lookup(
                 &self,
                 pos: impl Borrow<Self::Index>,
-            ) -> LookupResult<&Self::Element, Self::MembershipProof, ()> {
+            ) -> LookupResult<
+                &Self::Element,
+                Self::MembershipProof,
+                (),
+                Option<MissingSubtree<Self::NodeValue>>,
+            > {
                 let pos = pos.borrow();
                 let traversal_path = pos.to_traversal_path(self.height);
                 match self.root.lookup_internal(self.height, &traversal_path) {
                     LookupResult::Ok(value, proof) => {
                         LookupResult::Ok(&value, MerkleProof::new(pos.clone(), proof))
                     },
-                    LookupResult::NotInMemory => LookupResult::NotInMemory,
+                    LookupResult::NotInMemory(missing) => LookupResult::NotInMemory(Some(missing)),
                     LookupResult::NotFound(_) => LookupResult::NotFound(()),
                 }
             }
@@ -91,11 +115,155 @@ macro_rules! impl_merkle_tree_scheme {
                 proof.borrow().verify_membership_proof::<H>(root.borrow())
             }
 
+            fn multi_lookup(
+                &self,
+                pos: impl IntoIterator<Item = impl Borrow<Self::Index>>,
+            ) -> LookupResult<Vec<Self::Element>, Self::BatchMembershipProof, ()> {
+                let pos = pos
+                    .into_iter()
+                    .map(|pos| pos.borrow().clone())
+                    .collect::<Vec<_>>();
+                let traversal_paths = pos
+                    .iter()
+                    .map(|pos| pos.to_traversal_path(self.height))
+                    .collect::<Vec<_>>();
+                let traversal_paths = traversal_paths
+                    .iter()
+                    .map(|path| path.as_slice())
+                    .collect::<Vec<_>>();
+                match self
+                    .root
+                    .batch_lookup_internal(self.height, &traversal_paths)
+                {
+                    LookupResult::Ok(_, proof) => {
+                        let proof = MerkleBatchProof::new(pos, self.height, proof);
+                        let elems = match proof.elems() {
+                            Some(elems) => elems.into_iter().cloned().collect(),
+                            None => return LookupResult::NotInMemory(()),
+                        };
+                        LookupResult::Ok(elems, proof)
+                    },
+                    LookupResult::NotInMemory(()) => LookupResult::NotInMemory(()),
+                    LookupResult::NotFound(_) => LookupResult::NotFound(()),
+                }
+            }
+
+            fn verify_batch(
+                root: impl Borrow<Self::NodeValue>,
+                pos: impl IntoIterator<Item = impl Borrow<Self::Index>>,
+                proof: impl Borrow<Self::BatchMembershipProof>,
+            ) -> Result<VerificationResult, PrimitivesError> {
+                let proof = proof.borrow();
+                let pos = pos
+                    .into_iter()
+                    .map(|pos| pos.borrow().clone())
+                    .collect::<Vec<_>>();
+                if pos != proof.pos {
+                    return Ok(Err(())); // invalid proof for the given pos
+                }
+                proof.verify_batch_membership_proof::<H>(root.borrow())
+            }
+
             fn iter(&self) -> MerkleTreeIter<E, I, T> {
                 MerkleTreeIter::new(&self.root)
             }
+
+            fn iter_with_proofs(
+                &self,
+            ) -> Box<dyn Iterator<Item = (I, &E, Self::MembershipProof)> + '_> {
+                Box::new(MerkleProofIter::<E, I, T, Arity>::new(
+                    &self.root,
+                ))
+            }
+        }
+
+        $crate::impl_merkle_tree_scheme!(@into_iter $name);
+    };
+    (@inherent $name: ident) => {
+        impl<E, H, I, Arity, T> $name<E, H, I, Arity, T>
+        where
+            E: Element,
+            H: DigestAlgorithm<E, I, T>,
+            I: Index,
+            Arity: Unsigned,
+            T: NodeValue,
+        {
+            /// Return the indices of the leaves whose elements differ between
+            /// this tree and `other`, including the leaves only present in one
+            /// of them. Identical sub-trees are skipped by comparing their
+            /// digests, so the cost is proportional to the size of the
+            /// difference.
+            /// * `returns` - the differing indices in increasing order, or
+            ///   `Err()` if the trees have different heights or if some
+            ///   differing leaves are not in memory
+            pub fn diff(&self, other: &Self) -> Result<Vec<I>, PrimitivesError> {
+                if self.height != other.height {
+                    return Err(PrimitivesError::ParameterError(
+                        "Merkle trees of different heights".to_string(),
+                    ));
+                }
+                let mut indices = Vec::new();
+                self.root.diff_internal(&other.root, &mut indices)?;
+                Ok(indices)
+            }
+
+            /// Return an immutable snapshot of the current state of this tree
+            /// in constant time. The nodes are shared with the tree and only
+            /// copied on write, so lookups and iterations can proceed on the
+            /// snapshot, e.g. from another thread, while the tree keeps being
+            /// updated. Later updates are not visible in the snapshot.
+            pub fn snapshot(&self) -> Self {
+                self.clone()
+            }
         }
 
+        impl<E, H, I, Arity, T> $name<E, H, I, Arity, T>
+        where
+            E: Element,
+            H: DigestAlgorithm<E, I, T>,
+            I: Index + ToTraversalPath<Arity>,
+            Arity: Unsigned,
+            T: NodeValue,
+        {
+            /// Check the invariants of this tree: every digest in memory
+            /// matches its children or its leaf, leaves are at the positions
+            /// given by their place in the tree, and the number of leaves is
+            /// consistent with the height and with the leaves in memory.
+            /// Forgotten subtrees are trusted.
+            /// * `returns` - Ok(()) if the tree is well structured, Err()
+            ///   otherwise
+            pub fn validate(&self) -> Result<(), PrimitivesError> {
+                let (in_memory, forgotten) = self
+                    .root
+                    .validate_internal::<H, Arity>(self.height, &mut Vec::new())?;
+                let too_many = num_traits::checked_pow(Arity::to_u64(), self.height)
+                    .map_or(false, |capacity| self.num_leaves > capacity);
+                if too_many
+                    || in_memory > self.num_leaves
+                    || (!forgotten && in_memory != self.num_leaves)
+                {
+                    return Err(PrimitivesError::InconsistentStructureError(
+                        "Inconsistent number of leaves in the merkle tree".to_string(),
+                    ));
+                }
+                Ok(())
+            }
+
+            /// Deserialize a tree and [validate](Self::validate) it, for trees
+            /// coming from untrusted sources.
+            pub fn deserialize_and_check<'de, D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+                Self: Deserialize<'de>,
+            {
+                let tree = Self::deserialize(deserializer)?;
+                tree.validate().map_err(serde::de::Error::custom)?;
+                Ok(tree)
+            }
+        }
+
+    };
+    (@into_iter $name: ident) => {
         impl<'a, E, H, I, Arity, T> IntoIterator for &'a $name<E, H, I, Arity, T>
         where
             E: Element,
@@ -147,14 +315,13 @@ macro_rules! impl_forgetable_merkle_tree_scheme {
         {
             fn from_commitment(com: impl Borrow<Self::Commitment>) -> Self {
                 let com = com.borrow();
-                $name {
-                    root: Box::new(MerkleNode::ForgettenSubtree {
+                Self::from_root(
+                    Arc::new(MerkleNode::ForgettenSubtree {
                         value: com.digest(),
                     }),
-                    height: com.height(),
-                    num_leaves: com.size(),
-                    _phantom: PhantomData,
-                }
+                    com.height(),
+                    com.size(),
+                )
             }
 
             fn forget(
@@ -162,11 +329,11 @@ macro_rules! impl_forgetable_merkle_tree_scheme {
                 pos: Self::Index,
             ) -> LookupResult<Self::Element, Self::MembershipProof, ()> {
                 let traversal_path = pos.to_traversal_path(self.height);
-                match self.root.forget_internal(self.height, &traversal_path) {
+                match Arc::make_mut(&mut self.root).forget_internal(self.height, &traversal_path) {
                     LookupResult::Ok(elem, proof) => {
                         LookupResult::Ok(elem, MerkleProof::new(pos, proof))
                     },
-                    LookupResult::NotInMemory => LookupResult::NotInMemory,
+                    LookupResult::NotInMemory(()) => LookupResult::NotInMemory(()),
                     LookupResult::NotFound(_) => LookupResult::NotFound(()),
                 }
             }
@@ -179,51 +346,45 @@ macro_rules! impl_forgetable_merkle_tree_scheme {
             ) -> Result<(), PrimitivesError> {
                 let proof = proof.borrow();
                 let traversal_path = pos.to_traversal_path(self.height);
-                if let MerkleNode::<E, I, T>::Leaf {
-                    value: _,
-                    pos,
-                    elem,
-                } = &proof.proof[0]
-                {
-                    if !elem.eq(element.borrow()) {
-                        return Err(PrimitivesError::ParameterError(
-                            "Element does not match the proof.".to_string(),
-                        ));
-                    }
-                    let proof_leaf_value = H::digest_leaf(pos, elem)?;
-                    let mut path_values = vec![proof_leaf_value];
-                    traversal_path.iter().zip(proof.proof.iter().skip(1)).fold(
-                        Ok(proof_leaf_value),
-                        |result, (branch, node)| -> Result<T, PrimitivesError> {
-                            match result {
-                                Ok(val) => match node {
-                                    MerkleNode::Branch { value: _, children } => {
-                                        let mut data: Vec<_> =
-                                            children.iter().map(|node| node.value()).collect();
-                                        data[*branch] = val;
-                                        let digest = H::digest(&data)?;
-                                        path_values.push(digest);
-                                        Ok(digest)
-                                    },
-                                    _ => Err(PrimitivesError::ParameterError(
-                                        "Incompatible proof for this merkle tree".to_string(),
-                                    )),
-                                },
-                                Err(e) => Err(e),
-                            }
-                        },
-                    )?;
-                    self.root.remember_internal::<H, Arity>(
-                        self.height,
-                        &traversal_path,
-                        &path_values,
-                        &proof.proof,
-                    )
-                } else {
-                    Err(PrimitivesError::ParameterError(
-                        "Invalid proof type".to_string(),
-                    ))
-                }
+                let path_values =
+                    proof.membership_path_values::<H>(&traversal_path, element.borrow())?;
+                Arc::make_mut(&mut self.root).remember_internal::<H, Arity>(
+                    self.height,
+                    &traversal_path,
+                    &path_values,
+                    &proof.proof,
+                )
+            }
+
+            fn remember_batch<BE, BP>(
+                &mut self,
+                leaves: impl IntoIterator<Item = (Self::Index, BE, BP)>,
+            ) -> Result<(), PrimitivesError>
+            where
+                BE: Borrow<Self::Element>,
+                BP: Borrow<Self::MembershipProof>,
+            {
+                let leaves: Vec<_> = leaves.into_iter().collect();
+                let root = self.root.value();
+                let mut proofs = leaves
+                    .iter()
+                    .map(|(pos, elem, proof)| {
+                        let proof = proof.borrow();
+                        let traversal_path = pos.to_traversal_path(self.height);
+                        let path_values =
+                            proof.membership_path_values::<H>(&traversal_path, elem.borrow())?;
+                        // Validate every proof before touching the tree
+                        if path_values[self.height] != root {
+                            return Err(PrimitivesError::ParameterError(
+                                "Invalid proof. Hash differs at the root".to_string(),
+                            ));
+                        }
+                        Ok((traversal_path, path_values, proof.proof.as_slice()))
+                    })
+                    .collect::<Result<Vec<_>, PrimitivesError>>()?;
+                proofs.sort_by(|(a, ..), (b, ..)| a.iter().rev().cmp(b.iter().rev()));
+                Arc::make_mut(&mut self.root)
+                    .remember_batch_internal::<H, Arity>(self.height, &proofs)
             }
         }
     };
@@ -0,0 +1,357 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+//! A Merkle Mountain Range (MMR) is an append only binary merkle tree
+//! accumulator without a fixed height. It is a list of perfect binary
+//! subtrees, called peaks, whose sizes are given by the binary
+//! representation of the number of leaves. The root is obtained by "bagging"
+//! the peaks from right to left.
+//!
+//! Like [`super::light_weight::LightWeightMerkleTree`], this implementation
+//! only keeps its frontier: the peaks, and the last appended leaf together with
+//! its path inside its peak.
+
+use super::{
+    internal::MerkleTreeCommitment, DigestAlgorithm, Element, LookupResult, MerkleCommitment,
+    NodeValue,
+};
+use crate::errors::{PrimitivesError, VerificationResult};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use ark_std::{borrow::Borrow, marker::PhantomData, string::ToString, vec::Vec};
+use jf_utils::canonical;
+use serde::{Deserialize, Serialize};
+
+/// A membership proof of an element in a merkle mountain range.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(bound = "E: CanonicalSerialize + CanonicalDeserialize")]
+pub struct MmrProof<E, T>
+where
+    E: Element,
+    T: NodeValue,
+{
+    /// Position of the leaf
+    pub pos: u64,
+    /// The element at `pos`
+    #[serde(with = "canonical")]
+    pub elem: E,
+    /// Siblings inside the peak containing `pos`, from the leaf up
+    #[serde(with = "canonical")]
+    pub path: Vec<T>,
+    /// All other peaks, from left to right
+    #[serde(with = "canonical")]
+    pub peaks: Vec<T>,
+}
+
+/// An append only merkle mountain range, see the module documentation.
+///
+/// Internal nodes and the bagging of peaks are both computed with a two
+/// element input to `H::digest`, so `H` has to accept it.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MerkleMountainRange<E, H, T>
+where
+    E: Element,
+    H: DigestAlgorithm<E, u64, T>,
+    T: NodeValue,
+{
+    /// Peaks, from the highest (left-most) to the lowest (right-most)
+    peaks: Vec<T>,
+    num_leaves: u64,
+    /// The last appended element and its path in its peak
+    frontier: Option<(E, Vec<T>)>,
+
+    _phantom: PhantomData<H>,
+}
+
+impl<E, H, T> Default for MerkleMountainRange<E, H, T>
+where
+    E: Element,
+    H: DigestAlgorithm<E, u64, T>,
+    T: NodeValue,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<E, H, T> MerkleMountainRange<E, H, T>
+where
+    E: Element,
+    H: DigestAlgorithm<E, u64, T>,
+    T: NodeValue,
+{
+    /// Initialize an empty merkle mountain range.
+    pub fn new() -> Self {
+        Self {
+            peaks: Vec::new(),
+            num_leaves: 0,
+            frontier: None,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Construct a merkle mountain range from a list of elements
+    /// * `elems` - an iterator to all elements
+    /// * `returns` - A constructed merkle mountain range, or `Err()` if errors
+    pub fn from_elems(
+        elems: impl IntoIterator<Item = impl Borrow<E>>,
+    ) -> Result<Self, PrimitivesError> {
+        let mut mmr = Self::new();
+        mmr.extend(elems)?;
+        Ok(mmr)
+    }
+
+    /// Return the number of leaves
+    pub fn num_leaves(&self) -> u64 {
+        self.num_leaves
+    }
+
+    /// Return the peaks, from the highest (left-most) to the lowest
+    /// (right-most)
+    pub fn peaks(&self) -> &[T] {
+        &self.peaks
+    }
+
+    /// Return the bagged root, `T::default()` for an empty range.
+    pub fn root(&self) -> Result<T, PrimitivesError> {
+        bag_peaks::<E, H, T>(&self.peaks)
+    }
+
+    /// Return a commitment to the current state. The height of the
+    /// commitment is the height of the highest peak.
+    pub fn commitment(&self) -> Result<MerkleTreeCommitment<T>, PrimitivesError> {
+        Ok(MerkleTreeCommitment::new(
+            self.root()?,
+            peak_heights(self.num_leaves).first().copied().unwrap_or(0),
+            self.num_leaves,
+        ))
+    }
+
+    /// Append a new element
+    pub fn push(&mut self, elem: impl Borrow<E>) -> Result<(), PrimitivesError> {
+        let elem = elem.borrow();
+        let num_leaves = self
+            .num_leaves
+            .checked_add(1)
+            .ok_or_else(|| PrimitivesError::ParameterError("Exceed MMR capacity".to_string()))?;
+        let mut node = H::digest_leaf(&self.num_leaves, elem)?;
+        let mut path = Vec::new();
+        // every trailing one of the current size is a peak to merge with
+        for _ in 0..self.num_leaves.trailing_ones() {
+            let sibling = self.peaks.pop().ok_or_else(|| {
+                PrimitivesError::InternalError("Inconsistent MMR peaks".to_string())
+            })?;
+            node = H::digest(&[sibling, node])?;
+            path.push(sibling);
+        }
+        self.peaks.push(node);
+        self.num_leaves = num_leaves;
+        self.frontier = Some((elem.clone(), path));
+        Ok(())
+    }
+
+    /// Append a list of new elements
+    pub fn extend(
+        &mut self,
+        elems: impl IntoIterator<Item = impl Borrow<E>>,
+    ) -> Result<(), PrimitivesError> {
+        elems.into_iter().try_for_each(|elem| self.push(elem))
+    }
+
+    /// Returns the leaf value given a position
+    /// * `pos` - zero-based index of the leaf in the range
+    /// * `returns` - Leaf value and its membership proof if it is the last
+    ///   appended leaf, `NotInMemory` for other existing leaves, `NotFound` if
+    ///   `pos` is out of range.
+    pub fn lookup(&self, pos: u64) -> LookupResult<&E, MmrProof<E, T>, ()> {
+        if pos >= self.num_leaves {
+            return LookupResult::NotFound(());
+        }
+        match &self.frontier {
+            Some((elem, path)) if pos + 1 == self.num_leaves => {
+                let peaks = self.peaks[..self.peaks.len() - 1].to_vec();
+                LookupResult::Ok(
+                    elem,
+                    MmrProof {
+                        pos,
+                        elem: elem.clone(),
+                        path: path.clone(),
+                        peaks,
+                    },
+                )
+            },
+            _ => LookupResult::NotInMemory(()),
+        }
+    }
+
+    /// Verify an element is a leaf of a merkle mountain range.
+    /// * `commitment` - the commitment of the range
+    /// * `pos` - zero-based index of the leaf in the range
+    /// * `proof` - a membership proof for `pos`
+    /// * `returns` - Ok(true) if the proof is accepted, Ok(false) if not. Err()
+    ///   if the proof is not well structured, E.g. not for this range.
+    pub fn verify(
+        commitment: impl Borrow<MerkleTreeCommitment<T>>,
+        pos: u64,
+        proof: impl Borrow<MmrProof<E, T>>,
+    ) -> Result<VerificationResult, PrimitivesError> {
+        let commitment = commitment.borrow();
+        let proof = proof.borrow();
+        let num_leaves = commitment.size();
+        if pos != proof.pos || pos >= num_leaves {
+            return Ok(Err(()));
+        }
+        let heights = peak_heights(num_leaves);
+        if proof.peaks.len() + 1 != heights.len() {
+            return Err(PrimitivesError::ParameterError(
+                "Incompatible proof for this MMR".to_string(),
+            ));
+        }
+        // locate the peak containing `pos`
+        let mut start = 0u64;
+        let mut peak_index = 0;
+        for height in heights.iter() {
+            if pos < start + (1u64 << height) {
+                break;
+            }
+            start += 1u64 << height;
+            peak_index += 1;
+        }
+        if proof.path.len() != heights[peak_index] {
+            return Err(PrimitivesError::ParameterError(
+                "Incompatible proof for this MMR".to_string(),
+            ));
+        }
+
+        let local_pos = pos - start;
+        let peak = proof.path.iter().enumerate().try_fold(
+            H::digest_leaf(&pos, &proof.elem)?,
+            |node, (i, sibling)| {
+                if (local_pos >> i) & 1 == 1 {
+                    H::digest(&[*sibling, node])
+                } else {
+                    H::digest(&[node, *sibling])
+                }
+            },
+        )?;
+        let mut peaks = proof.peaks.clone();
+        peaks.insert(peak_index, peak);
+        if bag_peaks::<E, H, T>(&peaks)? == commitment.digest() {
+            Ok(Ok(()))
+        } else {
+            Ok(Err(()))
+        }
+    }
+}
+
+/// Heights of the peaks of a range with `num_leaves` leaves, from the highest
+/// to the lowest.
+fn peak_heights(num_leaves: u64) -> Vec<usize> {
+    (0..u64::BITS as usize)
+        .rev()
+        .filter(|i| (num_leaves >> i) & 1 == 1)
+        .collect()
+}
+
+/// Bag the peaks from right to left:
+/// `H(p_0, H(p_1, ... H(p_{n-2}, p_{n-1})))`.
+fn bag_peaks<E, H, T>(peaks: &[T]) -> Result<T, PrimitivesError>
+where
+    E: Element,
+    H: DigestAlgorithm<E, u64, T>,
+    T: NodeValue,
+{
+    match peaks.split_last() {
+        None => Ok(T::default()),
+        Some((last, rest)) => rest
+            .iter()
+            .rev()
+            .try_fold(*last, |acc, peak| H::digest(&[*peak, acc])),
+    }
+}
+
+#[cfg(test)]
+mod mmr_tests {
+    use super::*;
+    use crate::merkle_tree::prelude::{MerkleTree, MerkleTreeScheme, Sha3Digest, Sha3Node};
+    use ark_ed_on_bls12_377::Fq as Fq377;
+    use ark_ed_on_bls12_381::Fq as Fq381;
+    use ark_ed_on_bn254::Fq as Fq254;
+    use ark_ff::PrimeField;
+    use typenum::U2;
+
+    type Sha3Mmr<F> = MerkleMountainRange<F, Sha3Digest, Sha3Node>;
+
+    #[test]
+    fn test_mmr_peaks() {
+        test_mmr_peaks_helper::<Fq254>();
+        test_mmr_peaks_helper::<Fq377>();
+        test_mmr_peaks_helper::<Fq381>();
+    }
+
+    fn test_mmr_peaks_helper<F: PrimeField>() {
+        let mut mmr = Sha3Mmr::<F>::new();
+        assert_eq!(mmr.root().unwrap(), Sha3Node::default());
+        for i in 0..33u64 {
+            mmr.push(F::from(i)).unwrap();
+            assert_eq!(mmr.num_leaves(), i + 1);
+            assert_eq!(mmr.peaks().len(), (i + 1).count_ones() as usize);
+        }
+
+        // A range of 2^k leaves has a single peak, which is the root of a
+        // binary merkle tree of height k.
+        let elems = (0..16u64).map(F::from).collect::<Vec<_>>();
+        let mmr = Sha3Mmr::<F>::from_elems(&elems).unwrap();
+        let mt =
+            MerkleTree::<F, Sha3Digest, u64, U2, Sha3Node>::from_elems(Some(4), &elems).unwrap();
+        assert_eq!(mmr.peaks(), &[mt.commitment().digest()]);
+        assert_eq!(mmr.root().unwrap(), mt.commitment().digest());
+        assert_eq!(mmr.commitment().unwrap().height(), 4);
+    }
+
+    #[test]
+    fn test_mmr_lookup() {
+        test_mmr_lookup_helper::<Fq254>();
+        test_mmr_lookup_helper::<Fq377>();
+        test_mmr_lookup_helper::<Fq381>();
+    }
+
+    fn test_mmr_lookup_helper<F: PrimeField>() {
+        let mut mmr = Sha3Mmr::<F>::new();
+        assert!(mmr.lookup(0).expect_not_found().is_ok());
+        let mut proofs = Vec::new();
+        for i in 0..20u64 {
+            mmr.push(F::from(i)).unwrap();
+            let comm = mmr.commitment().unwrap();
+            let (elem, proof) = mmr.lookup(i).expect_ok().unwrap();
+            assert_eq!(elem, &F::from(i));
+            assert!(Sha3Mmr::<F>::verify(&comm, i, &proof).unwrap().is_ok());
+            assert!(Sha3Mmr::<F>::verify(&comm, i + 1, &proof).unwrap().is_err());
+
+            let mut bad_proof = proof.clone();
+            bad_proof.elem = F::from(i + 1);
+            assert!(Sha3Mmr::<F>::verify(&comm, i, &bad_proof).unwrap().is_err());
+
+            if i > 0 {
+                assert!(mmr.lookup(i - 1).expect_not_in_memory().is_ok());
+            }
+            assert!(mmr.lookup(i + 1).expect_not_found().is_ok());
+            proofs.push((comm, proof));
+        }
+
+        // Proofs remain valid against the commitments they were issued for,
+        // but not against the latest one once the peaks have changed.
+        let latest = mmr.commitment().unwrap();
+        for (i, (comm, proof)) in proofs.iter().enumerate() {
+            assert!(Sha3Mmr::<F>::verify(comm, i as u64, proof).unwrap().is_ok());
+            if i + 1 < proofs.len() {
+                assert!(!matches!(
+                    Sha3Mmr::<F>::verify(&latest, i as u64, proof),
+                    Ok(Ok(()))
+                ));
+            }
+        }
+    }
+}
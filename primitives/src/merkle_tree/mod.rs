@@ -6,12 +6,27 @@
 
 //! Merkle Tree traits and implementations
 pub mod append_only;
+pub mod compressed_proof;
+pub mod consistency;
+pub mod const_height;
+pub mod das;
 pub mod examples;
+pub mod frontier;
 pub mod hasher;
+pub mod indexed_merkle_tree;
+pub mod keyed;
 pub mod light_weight;
 pub mod macros;
+pub mod mmr;
 pub mod namespaced_merkle_tree;
+pub mod openzeppelin;
+pub mod retention;
+pub mod rfc6962;
+pub mod store;
+pub mod tiered;
 pub mod universal_merkle_tree;
+pub mod verkle;
+pub mod versioned;
 
 pub(crate) mod internal;
 
@@ -22,7 +37,10 @@ use crate::{
     impl_to_traversal_path_biguint, impl_to_traversal_path_primitives,
 };
 use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
-use ark_std::{borrow::Borrow, fmt::Debug, hash::Hash, string::ToString, vec, vec::Vec};
+use ark_std::{
+    borrow::Borrow, boxed::Box, fmt::Debug, format, hash::Hash, ops::Range, string::ToString, vec,
+    vec::Vec,
+};
 use num_bigint::BigUint;
 use num_traits::ToPrimitive;
 use serde::{Deserialize, Serialize};
@@ -32,25 +50,39 @@ use self::internal::MerkleTreeIter;
 
 #[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
 /// The result of querying at an index in the tree
-/// Typically, F for element type, P for membership proof type and N for
-/// non-membership proof type
-pub enum LookupResult<F, P, N> {
+/// Typically, F for element type, P for membership proof type, N for
+/// non-membership proof type and M for what is known about a leaf which is not
+/// in memory
+pub enum LookupResult<F, P, N, M = ()> {
     /// The value at the given index, and a proof of validity
     Ok(F, P),
     /// The index is valid but we do not have the leaf in memory
-    NotInMemory,
+    NotInMemory(M),
     /// The index is outside the occupied range in the tree, and a
     /// non-membership proof
     NotFound(N),
 }
 
-impl<F, P, N> LookupResult<F, P, N> {
+/// The forgotten subtree containing a leaf which is not in memory, i.e. the
+/// deepest node on the path to this leaf whose digest is still remembered.
+/// The leaf can be remembered again with the data of this subtree, e.g.
+/// requested from a node which stores the full tree, and checked against its
+/// digest.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+pub struct MissingSubtree<T: NodeValue> {
+    /// Height of the forgotten subtree, 0 if only the leaf is forgotten
+    pub height: usize,
+    /// Digest of the forgotten subtree
+    pub digest: T,
+}
+
+impl<F, P, N, M> LookupResult<F, P, N, M> {
     /// Assert the lookup result is Ok. Return a tuple of element and membership
     /// proof.
     pub fn expect_ok(self) -> Result<(F, P), PrimitivesError> {
         match self {
             LookupResult::Ok(x, proof) => Ok((x, proof)),
-            LookupResult::NotInMemory => Err(PrimitivesError::InternalError(
+            LookupResult::NotInMemory(_) => Err(PrimitivesError::InternalError(
                 "Expected Ok, found NotInMemory".to_string(),
             )),
             LookupResult::NotFound(_) => Err(PrimitivesError::InternalError(
@@ -66,16 +98,17 @@ impl<F, P, N> LookupResult<F, P, N> {
             LookupResult::Ok(..) => Err(PrimitivesError::InternalError(
                 "Expected NotFound, found Ok".to_string(),
             )),
-            LookupResult::NotInMemory => Err(PrimitivesError::InternalError(
+            LookupResult::NotInMemory(_) => Err(PrimitivesError::InternalError(
                 "Expected NotFound, found NotInMemory".to_string(),
             )),
         }
     }
 
-    /// Assert the lookup result is NotInMemory.
-    pub fn expect_not_in_memory(self) -> Result<(), PrimitivesError> {
+    /// Assert the lookup result is NotInMemory. Return what is known about
+    /// the missing leaf.
+    pub fn expect_not_in_memory(self) -> Result<M, PrimitivesError> {
         match self {
-            LookupResult::NotInMemory => Ok(()),
+            LookupResult::NotInMemory(m) => Ok(m),
             LookupResult::Ok(..) => Err(PrimitivesError::InternalError(
                 "Expected NotInMemory, found Ok".to_string(),
             )),
@@ -124,6 +157,22 @@ impl<T> NodeValue for T where
 {
 }
 
+/// Types that can be shared across threads when the `parallel` feature is
+/// on, i.e. `Send + Sync`. Without the feature, every type qualifies. It
+/// bounds the elements and node values of trees built in parallel.
+#[cfg(feature = "parallel")]
+pub trait MaybeSendSync: Send + Sync {}
+#[cfg(feature = "parallel")]
+impl<T: Send + Sync> MaybeSendSync for T {}
+
+/// Types that can be shared across threads when the `parallel` feature is
+/// on, i.e. `Send + Sync`. Without the feature, every type qualifies. It
+/// bounds the elements and node values of trees built in parallel.
+#[cfg(not(feature = "parallel"))]
+pub trait MaybeSendSync {}
+#[cfg(not(feature = "parallel"))]
+impl<T> MaybeSendSync for T {}
+
 /// Merkle tree hash function
 pub trait DigestAlgorithm<E, I, T>
 where
@@ -136,6 +185,33 @@ where
 
     /// Digest an indexed element
     fn digest_leaf(pos: &I, elem: &E) -> Result<T, PrimitivesError>;
+
+    /// Digest each of the lists of values `data`, e.g. the children of every
+    /// branch of a tree level. The default implementation calls
+    /// [`Self::digest`] on each list, hash functions with a vectorized or
+    /// parallel version should override it.
+    fn digest_many(data: &[&[T]]) -> Result<Vec<T>, PrimitivesError> {
+        data.iter().map(|data| Self::digest(data)).collect()
+    }
+
+    /// Digest each of the elements `elems` indexed by the matching entry of
+    /// `pos`. The default implementation calls [`Self::digest_leaf`] on each
+    /// element, hash functions with a vectorized or parallel version should
+    /// override it.
+    /// * `returns` - Err() if `pos` and `elems` have different lengths
+    fn digest_many_leaves(pos: &[I], elems: &[E]) -> Result<Vec<T>, PrimitivesError> {
+        if pos.len() != elems.len() {
+            return Err(PrimitivesError::ParameterError(format!(
+                "{} positions for {} leaves",
+                pos.len(),
+                elems.len()
+            )));
+        }
+        pos.iter()
+            .zip(elems)
+            .map(|(pos, elem)| Self::digest_leaf(pos, elem))
+            .collect()
+    }
 }
 
 /// An trait for Merkle tree index type.
@@ -212,11 +288,17 @@ pub trait MerkleTreeScheme: Sized {
     /// * `pos` - zero-based index of the leaf in the tree
     /// * `returns` - Leaf value at the position along with a proof.
     ///   LookupResult::EmptyLeaf if the leaf position is empty or invalid,
-    ///   LookupResult::NotInMemory if the leaf position has been forgotten.
+    ///   LookupResult::NotInMemory if the leaf position has been forgotten,
+    ///   with the forgotten subtree containing it if it is known.
     fn lookup(
         &self,
         pos: impl Borrow<Self::Index>,
-    ) -> LookupResult<&Self::Element, Self::MembershipProof, ()>;
+    ) -> LookupResult<
+        &Self::Element,
+        Self::MembershipProof,
+        (),
+        Option<MissingSubtree<Self::NodeValue>>,
+    >;
 
     /// Verify an element is a leaf of a Merkle tree given the proof
     /// * `root` - a merkle tree root, usually obtained from
@@ -231,16 +313,76 @@ pub trait MerkleTreeScheme: Sized {
         proof: impl Borrow<Self::MembershipProof>,
     ) -> Result<VerificationResult, PrimitivesError>;
 
-    // fn batch_lookup(&self, pos: impl Iterator<Item = usize>) -> LookupResult<(),
-    // Self::BatchProof>; fn batch_verify(
-    //     &self,
-    //     pos: impl Iterator<Item = usize>,
-    //     proof: impl Borrow<Self::BatchProof>,
-    // ) -> Result<(), PrimitivesError>;
+    /// Verify an element is a leaf of a Merkle tree given the proof, against
+    /// any of several roots, e.g. the latest few roots of a tree, see also
+    /// [`RootHistory`](versioned::RootHistory).
+    /// * `roots` - accepted merkle tree roots
+    /// * `pos` - zero-based index of the leaf in the tree
+    /// * `proof` - a merkle tree proof
+    /// * `returns` - Ok(true) if the proof is accepted for at least one of the
+    ///   roots, Ok(false) if not. Err() if the proof is not well structured,
+    ///   E.g. not for this merkle tree.
+    fn verify_against_roots(
+        roots: impl IntoIterator<Item = impl Borrow<Self::NodeValue>>,
+        pos: impl Borrow<Self::Index>,
+        proof: impl Borrow<Self::MembershipProof>,
+    ) -> Result<VerificationResult, PrimitivesError> {
+        let (pos, proof) = (pos.borrow(), proof.borrow());
+        for root in roots {
+            if Self::verify(root, pos, proof)?.is_ok() {
+                return Ok(Ok(()));
+            }
+        }
+        Ok(Err(()))
+    }
+
+    /// Returns the leaf values at a list of positions along with a single
+    /// batch proof for all of them
+    /// * `pos` - zero-based indices of the leaves in the tree
+    /// * `returns` - Leaf values at the positions (in the given order) along
+    ///   with a batch proof. LookupResult::NotFound if any of the positions is
+    ///   empty or invalid, LookupResult::NotInMemory if any of the positions
+    ///   has been forgotten.
+    fn multi_lookup(
+        &self,
+        pos: impl IntoIterator<Item = impl Borrow<Self::Index>>,
+    ) -> LookupResult<Vec<Self::Element>, Self::BatchMembershipProof, ()>;
+
+    /// Verify a list of elements are leaves of a Merkle tree given a batch
+    /// proof
+    /// * `root` - a merkle tree root, usually obtained from
+    ///   `Self::commitment().digest()`
+    /// * `pos` - zero-based indices of the leaves in the tree, in the same
+    ///   order as they were queried
+    /// * `proof` - a batch merkle tree proof
+    /// * `returns` - Ok(true) if the proof is accepted, Ok(false) if not. Err()
+    ///   if the proof is not well structured, E.g. not for this merkle tree.
+    fn verify_batch(
+        root: impl Borrow<Self::NodeValue>,
+        pos: impl IntoIterator<Item = impl Borrow<Self::Index>>,
+        proof: impl Borrow<Self::BatchMembershipProof>,
+    ) -> Result<VerificationResult, PrimitivesError>;
 
     /// Return an iterator that iterates through all element that are not
     /// forgetton
     fn iter(&self) -> MerkleTreeIter<Self::Element, Self::Index, Self::NodeValue>;
+
+    /// Return an iterator through all the elements that are not forgotten,
+    /// along with their index and membership proof. The default
+    /// implementation looks up every element independently, implementations
+    /// may share the work between the proofs of neighboring leaves.
+    #[allow(clippy::type_complexity)]
+    fn iter_with_proofs(
+        &self,
+    ) -> Box<dyn Iterator<Item = (Self::Index, &Self::Element, Self::MembershipProof)> + '_> {
+        Box::new(
+            self.iter()
+                .filter_map(move |(pos, elem)| match self.lookup(pos) {
+                    LookupResult::Ok(_, proof) => Some((pos.clone(), elem, proof)),
+                    _ => None,
+                }),
+        )
+    }
 }
 
 /// Merkle tree that allows insertion at back. Abstracted as a commitment for
@@ -265,6 +407,57 @@ pub trait AppendableMerkleTreeScheme: MerkleTreeScheme<Index = u64> {
         }
         Ok(())
     }
+
+    /// Remove every leaf at position `num_leaves` or beyond, e.g. to rewind
+    /// the tree after a reorg.
+    /// * `num_leaves` - number of leaves to keep
+    /// * `returns` - Ok(()) if successful. Err() if `num_leaves` exceeds the
+    ///   current number of leaves, or if the part of the tree to rewrite is not
+    ///   in memory, in which case the tree is left untouched.
+    fn truncate(&mut self, num_leaves: u64) -> Result<(), PrimitivesError>;
+
+    /// Remove the last leaf of the tree
+    /// * `returns` - Ok(()) if successful, Err() if the tree is empty or if
+    ///   [`truncate`](Self::truncate) fails.
+    fn pop(&mut self) -> Result<(), PrimitivesError> {
+        match self.num_leaves().checked_sub(1) {
+            Some(num_leaves) => self.truncate(num_leaves),
+            None => Err(PrimitivesError::ParameterError(
+                "Cannot pop from an empty merkle tree".to_string(),
+            )),
+        }
+    }
+
+    /// Returns the leaf values in a contiguous range of positions along with a
+    /// single proof for all of them. Siblings shared by neighboring leaves
+    /// appear only once in the proof, and none of the nodes inside the range
+    /// are needed, so it is much smaller than independent proofs.
+    /// * `range` - zero-based indices of the leaves, from `range.start`
+    ///   included to `range.end` excluded
+    /// * `returns` - Leaf values in the range along with a batch proof, see
+    ///   [`MerkleTreeScheme::multi_lookup`]
+    fn lookup_range(
+        &self,
+        range: Range<u64>,
+    ) -> LookupResult<Vec<Self::Element>, Self::BatchMembershipProof, ()> {
+        self.multi_lookup(range)
+    }
+
+    /// Verify a contiguous range of elements are leaves of a Merkle tree given
+    /// a proof obtained from [`Self::lookup_range`]
+    /// * `root` - a merkle tree root, usually obtained from
+    ///   `Self::commitment().digest()`
+    /// * `range` - zero-based indices of the leaves in the tree
+    /// * `proof` - a batch merkle tree proof for the range
+    /// * `returns` - Ok(true) if the proof is accepted, Ok(false) if not. Err()
+    ///   if the proof is not well structured, E.g. not for this merkle tree.
+    fn verify_range(
+        root: impl Borrow<Self::NodeValue>,
+        range: Range<u64>,
+        proof: impl Borrow<Self::BatchMembershipProof>,
+    ) -> Result<VerificationResult, PrimitivesError> {
+        Self::verify_batch(root, range, proof)
+    }
 }
 
 /// A universal merkle tree is abstracted as a random-access array or a
@@ -296,12 +489,16 @@ pub trait UniversalMerkleTreeScheme: MerkleTreeScheme {
         pos: impl Borrow<Self::Index>,
     ) -> Result<LookupResult<Self::Element, (), ()>, PrimitivesError>;
 
-    /// Apply an update function `f` at a given position
+    /// Apply an update function `f` at a given position, reading the current
+    /// element and writing the new one in a single traversal of the tree.
     /// * `pos` - zero-based index of the leaf in the tree
     /// * `f` - the update function, `None` means the given leaf doesn't exist
     ///   or should be removed.
-    /// * `returns` - Err() if any error occurs internally. Ok(result) if the
-    ///   update is success or the given leaf is not in memory.
+    /// * `returns` - Err() if any error occurs internally. Otherwise
+    ///   Ok(LookupResult::Ok(old, ())) with the previous element if the leaf
+    ///   existed, Ok(LookupResult::NotFound(())) if it was empty, and
+    ///   Ok(LookupResult::NotInMemory) if it has been forgotten, in which case
+    ///   `f` is not called and the tree is left untouched.
     fn update_with<F>(
         &mut self,
         pos: impl Borrow<Self::Index>,
@@ -310,16 +507,50 @@ pub trait UniversalMerkleTreeScheme: MerkleTreeScheme {
     where
         F: FnOnce(Option<&Self::Element>) -> Option<Self::Element>;
 
+    /// Update the leaf values at several positions at once. Implementations
+    /// may recompute the nodes shared by several updates only once, which is
+    /// much cheaper than calling [`update`](Self::update) for each of them.
+    /// * `updates` - pairs of (position, newly updated element), if a position
+    ///   occurs several times only its last update is kept
+    /// * `returns` - Err() if one of the given leaves is not in memory, in
+    ///   which case no update is applied, or if any error occurs internally.
+    fn update_batch(
+        &mut self,
+        updates: impl IntoIterator<Item = (impl Borrow<Self::Index>, impl Borrow<Self::Element>)>,
+    ) -> Result<(), PrimitivesError> {
+        let updates: Vec<_> = updates.into_iter().collect();
+        // Check every position before touching the tree.
+        if updates.iter().any(|(pos, _)| {
+            matches!(
+                self.universal_lookup(pos.borrow()),
+                LookupResult::NotInMemory(_)
+            )
+        }) {
+            return Err(PrimitivesError::ParameterError(
+                "Given part of merkle tree is not in memory".to_string(),
+            ));
+        }
+        for (pos, elem) in updates {
+            self.update(pos, elem)?;
+        }
+        Ok(())
+    }
+
     /// Returns the leaf value given a position
     /// * `pos` - zero-based index of the leaf in the tree
     /// * `returns` - Leaf value at the position along with a proof.
     ///   LookupResult::EmptyLeaf(p) if the leaf position is empty along with a
     ///   proof p. LookupResult::NotInMemory if the leaf position has been
-    ///   forgotten.
+    ///   forgotten, with the forgotten subtree containing it if it is known.
     fn universal_lookup(
         &self,
         pos: impl Borrow<Self::Index>,
-    ) -> LookupResult<&Self::Element, Self::MembershipProof, Self::NonMembershipProof>;
+    ) -> LookupResult<
+        &Self::Element,
+        Self::MembershipProof,
+        Self::NonMembershipProof,
+        Option<MissingSubtree<Self::NodeValue>>,
+    >;
 
     /// Verify an index is not in this merkle tree
     /// * `pos` - zero-based index of the leaf in the tree
@@ -331,7 +562,22 @@ pub trait UniversalMerkleTreeScheme: MerkleTreeScheme {
         pos: impl Borrow<Self::Index>,
         proof: impl Borrow<Self::NonMembershipProof>,
     ) -> Result<bool, PrimitivesError>;
-    // TODO(Chengyu): non-membership proof interfaces
+
+    /// Verify an index is empty in a merkle tree given a non-membership proof,
+    /// without access to the tree itself
+    /// * `root` - a merkle tree root, usually obtained from
+    ///   `Self::commitment().digest()`
+    /// * `pos` - zero-based index of the leaf in the tree
+    /// * `proof` - a non-membership proof, usually obtained from
+    ///   [`universal_lookup`](Self::universal_lookup)
+    /// * `returns` - Ok(Ok(())) if the proof is accepted, Ok(Err(())) if not.
+    ///   Err() if the proof is not well structured, E.g. not for this merkle
+    ///   tree.
+    fn verify_non_membership(
+        root: impl Borrow<Self::NodeValue>,
+        pos: impl Borrow<Self::Index>,
+        proof: impl Borrow<Self::NonMembershipProof>,
+    ) -> Result<VerificationResult, PrimitivesError>;
 }
 
 /// Merkle tree that allows forget/remember elements from the memory
@@ -355,6 +601,36 @@ pub trait ForgetableMerkleTreeScheme: MerkleTreeScheme {
         proof: impl Borrow<Self::MembershipProof>,
     ) -> Result<(), PrimitivesError>;
 
+    /// Trim the leaves at the given positions from memory, see
+    /// [`forget`](Self::forget).
+    /// * `returns` - the result of forgetting each position, in the given order
+    fn forget_batch(
+        &mut self,
+        indices: impl IntoIterator<Item = Self::Index>,
+    ) -> Vec<LookupResult<Self::Element, Self::MembershipProof, ()>> {
+        indices.into_iter().map(|pos| self.forget(pos)).collect()
+    }
+
+    /// "Re-insert" several leaves into the tree using their proofs, see
+    /// [`remember`](Self::remember). Implementations may validate all the
+    /// proofs upfront and merge them into the tree in a single traversal.
+    /// * `leaves` - tuples of (position, element, membership proof)
+    /// * `returns` - Ok(()) if every insertion is successful, or Err(err) if
+    ///   one of the proofs disagrees with the merkle tree
+    fn remember_batch<BE, BP>(
+        &mut self,
+        leaves: impl IntoIterator<Item = (Self::Index, BE, BP)>,
+    ) -> Result<(), PrimitivesError>
+    where
+        BE: Borrow<Self::Element>,
+        BP: Borrow<Self::MembershipProof>,
+    {
+        for (pos, elem, proof) in leaves {
+            self.remember(pos, elem, proof)?;
+        }
+        Ok(())
+    }
+
     /// Rebuild a merkle tree from a commitment.
     /// Return a tree which is entirely forgotten.
     fn from_commitment(commitment: impl Borrow<Self::Commitment>) -> Self;
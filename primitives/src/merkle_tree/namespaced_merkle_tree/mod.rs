@@ -7,7 +7,7 @@
 //! Implementation of a Namespaced Merkle Tree.
 use alloc::collections::{btree_map::Entry, BTreeMap};
 use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
-use ark_std::vec::Vec;
+use ark_std::{boxed::Box, vec::Vec};
 use core::{borrow::Borrow, fmt::Debug, hash::Hash, marker::PhantomData, ops::Range};
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 
@@ -23,8 +23,8 @@ use self::{
 use super::{
     append_only::MerkleTree,
     internal::{MerkleProof, MerkleTreeIter},
-    AppendableMerkleTreeScheme, DigestAlgorithm, Element, Index, LookupResult, MerkleCommitment,
-    MerkleTreeScheme, NodeValue,
+    AppendableMerkleTreeScheme, DigestAlgorithm, Element, Index, LookupResult, MaybeSendSync,
+    MerkleCommitment, MerkleTreeScheme, MissingSubtree, NodeValue,
 };
 
 mod hash;
@@ -66,7 +66,9 @@ pub trait NamespaceProof {
     /// Return the set of leaves associated with this Namespace proof
     fn get_namespace_leaves(&self) -> Vec<&Self::Leaf>;
 
-    /// Verify a namespace proof
+    /// Verify a namespace proof. An accepted proof guarantees that the leaves
+    /// it contains are all the leaves of the tree in `namespace`, or that the
+    /// tree has no such leaf for an absence proof.
     fn verify(
         &self,
         root: &NamespacedHash<Self::Node, Self::Namespace>,
@@ -166,7 +168,11 @@ where
     pub fn from_elems(
         height: Option<usize>,
         elems: impl IntoIterator<Item = impl Borrow<E>>,
-    ) -> Result<Self, PrimitivesError> {
+    ) -> Result<Self, PrimitivesError>
+    where
+        E: MaybeSendSync,
+        NamespacedHash<T, N>: MaybeSendSync,
+    {
         let mut namespace_ranges: BTreeMap<N, Range<u64>> = BTreeMap::new();
         let leaves =
             NMT::<E, H, Arity, N, T>::update_namespace_metadata(&mut namespace_ranges, elems)?;
@@ -214,7 +220,12 @@ where
     fn lookup(
         &self,
         pos: impl Borrow<Self::Index>,
-    ) -> super::LookupResult<&Self::Element, Self::MembershipProof, ()> {
+    ) -> LookupResult<
+        &Self::Element,
+        Self::MembershipProof,
+        (),
+        Option<MissingSubtree<Self::NodeValue>>,
+    > {
         self.inner.lookup(pos)
     }
 
@@ -226,9 +237,30 @@ where
         <InnerTree<E, H, T, N, Arity> as MerkleTreeScheme>::verify(root, pos, proof)
     }
 
+    fn multi_lookup(
+        &self,
+        pos: impl IntoIterator<Item = impl Borrow<Self::Index>>,
+    ) -> LookupResult<Vec<Self::Element>, Self::BatchMembershipProof, ()> {
+        self.inner.multi_lookup(pos)
+    }
+
+    fn verify_batch(
+        root: impl Borrow<Self::NodeValue>,
+        pos: impl IntoIterator<Item = impl Borrow<Self::Index>>,
+        proof: impl Borrow<Self::BatchMembershipProof>,
+    ) -> Result<VerificationResult, PrimitivesError> {
+        <InnerTree<E, H, T, N, Arity> as MerkleTreeScheme>::verify_batch(root, pos, proof)
+    }
+
     fn iter(&self) -> MerkleTreeIter<Self::Element, Self::Index, Self::NodeValue> {
         self.inner.iter()
     }
+
+    fn iter_with_proofs(
+        &self,
+    ) -> Box<dyn Iterator<Item = (u64, &Self::Element, Self::MembershipProof)> + '_> {
+        self.inner.iter_with_proofs()
+    }
 }
 
 impl<E, H, Arity, N, T> AppendableMerkleTreeScheme for NMT<E, H, Arity, N, T>
@@ -254,6 +286,17 @@ where
     ) -> Result<(), PrimitivesError> {
         self.extend([elem])
     }
+
+    fn truncate(&mut self, num_leaves: u64) -> Result<(), PrimitivesError> {
+        self.inner.truncate(num_leaves)?;
+        // Leaves are sorted by namespace, so only the last namespaces are affected
+        self.namespace_ranges
+            .retain(|_, range| range.start < num_leaves);
+        self.namespace_ranges
+            .values_mut()
+            .for_each(|range| range.end = range.end.min(num_leaves));
+        Ok(())
+    }
 }
 
 impl<E, H, Arity, N, T> NMT<E, H, Arity, N, T>
@@ -349,8 +392,8 @@ where
             if left_index > 0 {
                 left_boundary_proof = Some(self.lookup_proof(left_index - 1));
             }
-            if right_index < self.num_leaves() - 1 {
-                right_boundary_proof = Some(self.lookup_proof(right_index + 1));
+            if right_index < self.num_leaves() {
+                right_boundary_proof = Some(self.lookup_proof(right_index));
             }
         } else {
             proof_type = NamespaceProofType::Absence;
@@ -478,6 +521,26 @@ mod nmt_tests {
         }
     }
 
+    #[test]
+    fn test_nmt_truncate() {
+        let namespaces = [1, 2, 2, 2, 4, 4, 4, 5];
+        let leaves: Vec<Leaf> = namespaces.iter().map(|i| Leaf::new(*i)).collect();
+        for num_leaves in 0..=leaves.len() {
+            let mut tree = build_tree(&leaves, BuildType::Extend);
+            tree.truncate(num_leaves as u64).unwrap();
+            let expected = build_tree(&leaves[..num_leaves], BuildType::Extend);
+            assert_eq!(tree.commitment(), expected.commitment());
+            assert_eq!(tree.namespace_ranges, expected.namespace_ranges);
+        }
+
+        let mut tree = build_tree(&leaves, BuildType::Extend);
+        tree.pop().unwrap();
+        assert!(tree.namespace_ranges.get(&5).is_none());
+        tree.pop().unwrap();
+        assert_eq!(tree.namespace_ranges.get(&4), Some(&(4..6)));
+        assert!(tree.truncate(7).is_err());
+    }
+
     #[test]
     fn test_nmt() {
         test_nmt_with_build_type(BuildType::Extend);
@@ -611,4 +674,54 @@ mod nmt_tests {
             .unwrap()
             .is_err());
     }
+
+    #[test]
+    fn test_nmt_completeness() {
+        // Namespace 2 is the tree's minimum namespace and 4 is its maximum one
+        let namespaces = [2, 2, 2, 3, 4, 4, 4];
+        let leaves: Vec<Leaf> = namespaces.iter().map(|i| Leaf::new(*i)).collect();
+        let tree = build_tree(&leaves, BuildType::Extend);
+        for ns in [2, 3, 4] {
+            let proof = tree.get_namespace_proof(ns);
+            assert!(tree.verify_namespace_proof(&proof, ns).unwrap().is_ok());
+        }
+
+        // Omitting the first leaves of the minimum namespace is detected
+        let mut proof = tree.get_namespace_proof(2);
+        proof.proofs.remove(0);
+        proof.first_index = 1;
+        assert!(tree.verify_namespace_proof(&proof, 2).unwrap().is_err());
+
+        // Omitting the last leaves of the maximum namespace is detected
+        let mut proof = tree.get_namespace_proof(4);
+        proof.proofs.pop();
+        assert!(tree.verify_namespace_proof(&proof, 4).unwrap().is_err());
+
+        // Omitting the last leaves of an internal namespace is detected, even without
+        // a right boundary
+        let mut proof = tree.get_namespace_proof(2);
+        proof.proofs.pop();
+        assert!(tree.verify_namespace_proof(&proof, 2).unwrap().is_err());
+        proof.right_boundary_proof = None;
+        assert!(tree.verify_namespace_proof(&proof, 2).unwrap().is_err());
+
+        // Boundaries must be adjacent to the namespace range
+        let mut proof = tree.get_namespace_proof(3);
+        proof.right_boundary_proof = Some(tree.lookup_proof(5));
+        assert!(tree.verify_namespace_proof(&proof, 3).unwrap().is_err());
+        let mut proof = tree.get_namespace_proof(3);
+        proof.left_boundary_proof = Some(tree.lookup_proof(1));
+        assert!(tree.verify_namespace_proof(&proof, 3).unwrap().is_err());
+
+        // A presence proof without leaves is rejected
+        let mut proof = tree.get_namespace_proof(3);
+        proof.proofs.clear();
+        assert!(tree.verify_namespace_proof(&proof, 3).unwrap().is_err());
+
+        // Any namespace is absent from an empty tree
+        let tree = TestNMT::new(3);
+        let proof = tree.get_namespace_proof(0);
+        assert!(proof.get_namespace_leaves().is_empty());
+        assert!(tree.verify_namespace_proof(&proof, 0).unwrap().is_ok());
+    }
 }
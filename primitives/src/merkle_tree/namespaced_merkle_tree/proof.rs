@@ -7,7 +7,10 @@ use typenum::Unsigned;
 
 use crate::{
     errors::{PrimitivesError, VerificationResult},
-    merkle_tree::{internal::MerkleProof, DigestAlgorithm, MerkleTreeScheme, NodeValue},
+    merkle_tree::{
+        internal::{MerkleNode, MerkleProof},
+        DigestAlgorithm, MerkleTreeScheme, NodeValue, ToTraversalPath,
+    },
 };
 
 use super::{
@@ -102,7 +105,7 @@ where
                 ))?
                 .get_namespace()
                 >= namespace
-                || *boundary_proof.index() != self.first_index - 1
+                || Some(*boundary_proof.index()) != self.first_index.checked_sub(1)
             {
                 return Ok(Err(()));
             }
@@ -113,9 +116,10 @@ where
                 return Ok(Err(()));
             }
         } else {
-            // If there is no left boundary, ensure that target namespace is the tree's
-            // minimum namespace
-            if root.min_namespace != namespace {
+            // If there is no left boundary, ensure that the namespace range starts at
+            // the first leaf, and thus that the target namespace is the tree's minimum
+            // namespace
+            if self.first_index != 0 || root.min_namespace != namespace {
                 return Ok(Err(()));
             }
         }
@@ -128,8 +132,8 @@ where
         namespace: N,
     ) -> Result<VerificationResult, PrimitivesError> {
         if let Some(boundary_proof) = self.right_boundary_proof.as_ref() {
-            // If there is a leaf to the left of the namespace range, check that it is less
-            // than the target namespace
+            // If there is a leaf to the right of the namespace range, check that it is
+            // greater than the target namespace
             if boundary_proof
                 .elem()
                 .ok_or(PrimitivesError::InconsistentStructureError(
@@ -148,8 +152,13 @@ where
                 return Ok(Err(()));
             }
         } else {
-            // If there is no left boundary, ensure that target namespace is the tree's
-            // minimum namespace
+            // If there is no right boundary, ensure that the namespace range ends at the
+            // last leaf, and thus that the target namespace is the tree's maximum
+            // namespace
+            match self.proofs.last() {
+                Some(last_proof) if Self::is_last_leaf(last_proof) => {},
+                _ => return Ok(Err(())),
+            }
             if root.max_namespace != namespace {
                 return Ok(Err(()));
             }
@@ -162,8 +171,12 @@ where
         root: &NamespacedHash<T, N>,
         namespace: N,
     ) -> Result<VerificationResult, PrimitivesError> {
-        if namespace < root.min_namespace || namespace > root.max_namespace {
-            // Easy case where the namespace isn't covered by the range of the tree root
+        if *root == NamespacedHash::default()
+            || namespace < root.min_namespace
+            || namespace > root.max_namespace
+        {
+            // Easy case where the tree is empty or the namespace isn't covered by the
+            // range of the tree root
             return Ok(Ok(()));
         } else {
             // Harder case: Find an element whose namespace is greater than our
@@ -223,7 +236,10 @@ where
         root: &NamespacedHash<T, N>,
         namespace: N,
     ) -> Result<VerificationResult, PrimitivesError> {
-        let mut last_idx: Option<u64> = None;
+        // A presence proof must contain at least one leaf
+        if self.proofs.is_empty() {
+            return Ok(Err(()));
+        }
         for (idx, proof) in self.proofs.iter().enumerate() {
             let leaf_index = self.first_index + idx as u64;
             if <InnerTree<E, H, T, N, Arity>>::verify(root, leaf_index, proof)?.is_err() {
@@ -239,18 +255,12 @@ where
             {
                 return Ok(Err(()));
             }
-            // Indices must be sequential, this checks that there are no gaps in the
-            // namespace
-            if let Some(prev_index) = last_idx {
-                if leaf_index != prev_index + 1 {
-                    return Ok(Err(()));
-                }
-                last_idx = Some(leaf_index);
-            }
         }
-        // Verify that the proof contains the left boundary of the namespace
+        // Leaves are proven at sequential indices, so there are no gaps in the
+        // namespace. Verify that the proof contains the left boundary of the
+        // namespace
         if self
-            .verify_left_namespace_boundary(root, namespace)
+            .verify_left_namespace_boundary(root, namespace)?
             .is_err()
         {
             return Ok(Err(()));
@@ -258,7 +268,7 @@ where
 
         // Verify that the proof contains the right boundary of the namespace
         if self
-            .verify_right_namespace_boundary(root, namespace)
+            .verify_right_namespace_boundary(root, namespace)?
             .is_err()
         {
             return Ok(Err(()));
@@ -266,4 +276,24 @@ where
 
         Ok(Ok(()))
     }
+
+    // Check that no leaf follows the one proven by `proof`, i.e. that every sibling
+    // to the right of its path is empty. Combined with the verification of `proof`,
+    // this shows that the proven leaf is the last one of the tree.
+    fn is_last_leaf(proof: &MerkleProof<E, u64, NamespacedHash<T, N>, Arity>) -> bool {
+        let traversal_path = <u64 as ToTraversalPath<Arity>>::to_traversal_path(
+            proof.index(),
+            proof.tree_height() - 1,
+        );
+        traversal_path
+            .iter()
+            .zip(proof.proof.iter().skip(1))
+            .all(|(branch, node)| match node {
+                MerkleNode::Branch { children, .. } => children
+                    .iter()
+                    .skip(branch + 1)
+                    .all(|child| child.value() == NamespacedHash::default()),
+                _ => false,
+            })
+    }
 }
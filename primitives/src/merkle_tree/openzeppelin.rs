@@ -0,0 +1,248 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+//! Merkle trees and proofs compatible with OpenZeppelin's
+//! [`MerkleProof.verify`](https://docs.openzeppelin.com/contracts/5.x/api/utils#MerkleProof-verify-bytes32---bytes32-bytes32-),
+//! as used by Solidity airdrop and allowlist contracts.
+//!
+//! An [`OpenZeppelinMerkleTree`] is built as the `StandardMerkleTree` of the
+//! [`@openzeppelin/merkle-tree`](https://github.com/OpenZeppelin/merkle-tree)
+//! JavaScript library, so that both have the same root for the same leaves:
+//! - a leaf is hashed twice as `keccak256(keccak256(elem))`, where `elem` is
+//!   the ABI encoding of the leaf values, i.e. `abi.encode(...)`;
+//! - the leaf hashes are sorted;
+//! - the tree is a complete binary tree stored in an array, without padding:
+//!   the `n` sorted leaves are the last `n` nodes of the array, in reverse
+//!   order, and the node at index `i` is the hash of its sorted children at
+//!   indices `2i + 1` and `2i + 2`, i.e. `keccak256(min(a, b) || max(a, b))`.
+//!
+//! [`OpenZeppelinMerkleTree::openzeppelin_proof`] returns the leaf hash and
+//! the `bytes32[]` array of siblings, from the leaf up, expected by
+//! `MerkleProof.verify(proof, root, leaf)`, which is the output of
+//! `StandardMerkleTree.getProof`. Since pairs are sorted, these proofs don't
+//! bind the position of the leaf, only its membership.
+
+use crate::errors::{PrimitivesError, VerificationResult};
+use ark_std::{borrow::Borrow, string::ToString, vec, vec::Vec};
+use sha3::{Digest, Keccak256};
+
+/// A 32-byte word, as `bytes32` in Solidity.
+pub type Bytes32 = [u8; 32];
+
+/// Hash functions of an [`OpenZeppelinMerkleTree`]: double Keccak256 for
+/// leaves, and sorted-pair Keccak256 for internal nodes.
+#[derive(Clone, Debug, Hash, Eq, PartialEq)]
+pub struct OpenZeppelinDigest();
+
+impl OpenZeppelinDigest {
+    /// Hash an encoded leaf as `keccak256(keccak256(elem))`.
+    pub fn hash_leaf(elem: impl AsRef<[u8]>) -> Bytes32 {
+        Keccak256::digest(Keccak256::digest(elem.as_ref())).into()
+    }
+
+    /// Hash a pair of nodes as `keccak256(min(a, b) || max(a, b))`, as
+    /// OpenZeppelin's `Hashes.commutativeKeccak256`.
+    pub fn hash_pair(a: &Bytes32, b: &Bytes32) -> Bytes32 {
+        let (first, second) = if a < b { (a, b) } else { (b, a) };
+        let mut hasher = Keccak256::new();
+        hasher.update(first);
+        hasher.update(second);
+        hasher.finalize().into()
+    }
+}
+
+/// A membership proof in the format of OpenZeppelin's `MerkleProof.verify`.
+#[derive(Clone, Debug, Hash, Eq, PartialEq)]
+pub struct OpenZeppelinProof {
+    /// Hash of the proven leaf
+    pub leaf: Bytes32,
+    /// Siblings of the path from the leaf to the root, from the leaf up
+    pub proof: Vec<Bytes32>,
+}
+
+/// Verify a proof as OpenZeppelin's `MerkleProof.verify` does.
+/// * `root` - root of an [`OpenZeppelinMerkleTree`]
+/// * `returns` - Ok(()) if the proof is accepted, Err(()) otherwise
+pub fn verify_openzeppelin_proof(root: &Bytes32, proof: &OpenZeppelinProof) -> VerificationResult {
+    let computed = proof.proof.iter().fold(proof.leaf, |node, sibling| {
+        OpenZeppelinDigest::hash_pair(&node, sibling)
+    });
+    if computed == *root {
+        Ok(())
+    } else {
+        Err(())
+    }
+}
+
+/// A Merkle tree with the layout of OpenZeppelin's `StandardMerkleTree`, see
+/// the [module documentation](self).
+#[derive(Clone, Debug, Hash, Eq, PartialEq)]
+pub struct OpenZeppelinMerkleTree<E: AsRef<[u8]>> {
+    /// Nodes of the tree, the root first, the children of the node at index
+    /// `i` being at indices `2i + 1` and `2i + 2`
+    tree: Vec<Bytes32>,
+    /// Elements in the order they were given, with the index of their leaf in
+    /// `tree`
+    elems: Vec<(E, usize)>,
+}
+
+impl<E: AsRef<[u8]> + Clone> OpenZeppelinMerkleTree<E> {
+    /// Build a tree from a list of ABI encoded elements.
+    /// * `returns` - Err() if there are no elements, as `StandardMerkleTree`
+    ///   doesn't build empty trees
+    pub fn from_elems(
+        elems: impl IntoIterator<Item = impl Borrow<E>>,
+    ) -> Result<Self, PrimitivesError> {
+        let elems: Vec<E> = elems
+            .into_iter()
+            .map(|elem| elem.borrow().clone())
+            .collect();
+        if elems.is_empty() {
+            return Err(PrimitivesError::ParameterError(
+                "OpenZeppelin Merkle trees have at least one leaf".to_string(),
+            ));
+        }
+        let mut leaves: Vec<(Bytes32, usize)> = elems
+            .iter()
+            .map(OpenZeppelinDigest::hash_leaf)
+            .zip(0..)
+            .collect();
+        leaves.sort();
+
+        let num_nodes = 2 * leaves.len() - 1;
+        let mut tree = vec![[0u8; 32]; num_nodes];
+        let mut leaf_indices = vec![0; elems.len()];
+        for (i, (leaf, elem_index)) in leaves.into_iter().enumerate() {
+            tree[num_nodes - 1 - i] = leaf;
+            leaf_indices[elem_index] = num_nodes - 1 - i;
+        }
+        for i in (0..num_nodes / 2).rev() {
+            tree[i] = OpenZeppelinDigest::hash_pair(&tree[2 * i + 1], &tree[2 * i + 2]);
+        }
+        Ok(Self {
+            tree,
+            elems: elems.into_iter().zip(leaf_indices).collect(),
+        })
+    }
+}
+
+impl<E: AsRef<[u8]>> OpenZeppelinMerkleTree<E> {
+    /// Return the root of the tree.
+    pub fn root(&self) -> Bytes32 {
+        self.tree[0]
+    }
+
+    /// Return the number of leaves in the tree.
+    pub fn num_leaves(&self) -> u64 {
+        self.elems.len() as u64
+    }
+
+    /// Return the `pos`-th element, in the order they were given.
+    pub fn elem(&self, pos: u64) -> Option<&E> {
+        self.elems.get(pos as usize).map(|(elem, _)| elem)
+    }
+
+    /// Return the proof of the `pos`-th element, in the order they were
+    /// given, in the format of OpenZeppelin's `MerkleProof.verify`.
+    /// * `returns` - `None` if `pos` is out of range
+    pub fn openzeppelin_proof(&self, pos: u64) -> Option<OpenZeppelinProof> {
+        let (_, mut index) = self.elems.get(pos as usize)?;
+        let leaf = self.tree[index];
+        let mut proof = Vec::new();
+        while index > 0 {
+            let sibling = if index % 2 == 1 { index + 1 } else { index - 1 };
+            proof.push(self.tree[sibling]);
+            index = (index - 1) / 2;
+        }
+        Some(OpenZeppelinProof { leaf, proof })
+    }
+}
+
+#[cfg(test)]
+mod mt_tests {
+    use crate::merkle_tree::openzeppelin::{
+        verify_openzeppelin_proof, Bytes32, OpenZeppelinDigest, OpenZeppelinMerkleTree,
+    };
+    use ark_std::{vec, vec::Vec};
+
+    fn from_hex(s: &str) -> Bytes32 {
+        let mut output = [0u8; 32];
+        for (i, byte) in output.iter_mut().enumerate() {
+            *byte = u8::from_str_radix(&s[2 * i..2 * i + 2], 16).unwrap();
+        }
+        output
+    }
+
+    /// `abi.encode(address, uint256)`
+    fn encode(address: u8, amount: u128) -> Vec<u8> {
+        let mut elem = [0u8; 64];
+        elem[12..32].copy_from_slice(&[address; 20]);
+        elem[48..].copy_from_slice(&amount.to_be_bytes());
+        elem.to_vec()
+    }
+
+    #[test]
+    fn test_openzeppelin_mt() {
+        // Example of the `@openzeppelin/merkle-tree` README:
+        // `StandardMerkleTree.of([["0x1111...1111", "5000000000000000000"],
+        // ["0x2222...2222", "2500000000000000000"]], ["address", "uint256"])`
+        let elems = vec![
+            encode(0x11, 5_000_000_000_000_000_000),
+            encode(0x22, 2_500_000_000_000_000_000),
+        ];
+        let mt = OpenZeppelinMerkleTree::<Vec<u8>>::from_elems(&elems).unwrap();
+        assert_eq!(
+            mt.root(),
+            from_hex("d4dee0beab2d53f2cc83e567171bd2820e49898130a22622b10ead383e90bd77")
+        );
+        let proof = mt.openzeppelin_proof(0).unwrap();
+        assert_eq!(proof.leaf, OpenZeppelinDigest::hash_leaf(&elems[0]));
+        assert_eq!(proof.proof, vec![OpenZeppelinDigest::hash_leaf(&elems[1])]);
+        assert!(verify_openzeppelin_proof(&mt.root(), &proof).is_ok());
+
+        // An unbalanced tree, with the leaves at two depths. The root and the
+        // proof follow `StandardMerkleTree.of` and `getProof`.
+        let elems: Vec<_> = (1..=5u8)
+            .map(|i| encode(0x11 * i, i as u128 * 1_000_000_000_000_000_000))
+            .collect();
+        let mt = OpenZeppelinMerkleTree::<Vec<u8>>::from_elems(&elems).unwrap();
+        assert_eq!(mt.num_leaves(), 5);
+        assert_eq!(
+            mt.root(),
+            from_hex("cd2bc7262ffb6d412e83324914e605c9fa1857d8a239effd5e62011a99055358")
+        );
+        assert_eq!(
+            mt.openzeppelin_proof(1).unwrap().proof,
+            vec![
+                from_hex("4fbeb3a61b1cff6e0c0ce5b1b39fea03ad430b57b7dc3d101170299a1656619b"),
+                from_hex("fdbe7f6037e41f2990b76f709a322291b887c6f1471c81a7f27010f33bcd1bde"),
+                from_hex("6f6089f2f0c856673147996233075f79f1aea9481772b778a3f7746fe7b479bc"),
+            ]
+        );
+        assert_eq!(mt.openzeppelin_proof(3).unwrap().proof.len(), 2);
+
+        for (i, elem) in elems.iter().enumerate() {
+            assert_eq!(mt.elem(i as u64), Some(elem));
+            let proof = mt.openzeppelin_proof(i as u64).unwrap();
+            assert_eq!(proof.leaf, OpenZeppelinDigest::hash_leaf(elem));
+            assert!(verify_openzeppelin_proof(&mt.root(), &proof).is_ok());
+
+            let mut bad_proof = proof.clone();
+            bad_proof.leaf = OpenZeppelinDigest::hash_leaf([0xffu8; 64]);
+            assert!(verify_openzeppelin_proof(&mt.root(), &bad_proof).is_err());
+            let mut bad_proof = proof;
+            bad_proof.proof.pop();
+            assert!(verify_openzeppelin_proof(&mt.root(), &bad_proof).is_err());
+        }
+        assert_eq!(mt.openzeppelin_proof(5), None);
+
+        // A single leaf is the root
+        let mt = OpenZeppelinMerkleTree::<Vec<u8>>::from_elems(&elems[..1]).unwrap();
+        assert_eq!(mt.root(), OpenZeppelinDigest::hash_leaf(&elems[0]));
+        assert!(mt.openzeppelin_proof(0).unwrap().proof.is_empty());
+        assert!(OpenZeppelinMerkleTree::<Vec<u8>>::from_elems(&elems[..0]).is_err());
+    }
+}
@@ -18,32 +18,90 @@ pub use crate::{
 
 use super::light_weight::LightWeightMerkleTree;
 use crate::{
+    anemoi::{jive, AnemoiParameter},
+    crhf::{DomainSeparator, NoDomainSeparator},
     errors::PrimitivesError,
-    rescue::{sponge::RescueCRHF, RescueParameter},
+    monolith::goldilocks::Goldilocks,
+    poseidon::{PoseidonCRHF, PoseidonParameter},
+    rescue::{
+        sponge::{RescueCRHF, RescueSpongeConfig},
+        RescueParameter,
+    },
+    tip5::{self, Tip5CRHF},
 };
+use ark_ff::{PrimeField, Zero};
 use ark_serialize::{
     CanonicalDeserialize, CanonicalSerialize, Compress, Read, SerializationError, Valid, Validate,
     Write,
 };
-use ark_std::{marker::PhantomData, vec::Vec};
+use ark_std::{format, marker::PhantomData, vec::Vec};
+use jf_utils::bytes_to_field_elements;
 use num_bigint::BigUint;
-use sha3::{Digest, Sha3_256};
-use typenum::U3;
+use sha3::{Digest, Keccak256, Sha3_256};
+use typenum::{Unsigned, U2, U3, U8};
 
-/// Wrapper for rescue hash function
+/// Wrapper for rescue hash function, hashing in the domain of `D`
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
-pub struct RescueHash<F: RescueParameter> {
-    phantom_f: PhantomData<F>,
+pub struct RescueHash<F: RescueParameter, D = NoDomainSeparator> {
+    phantom: PhantomData<(F, D)>,
+}
+
+/// Rescue sponge hash of `data` in the domain of `D`.
+fn rescue_digest<F: RescueParameter, D: DomainSeparator>(data: &[F]) -> Result<F, PrimitivesError> {
+    let config = RescueSpongeConfig::default().with_domain_separator(D::DOMAIN_SEPARATOR);
+    Ok(RescueCRHF::<F>::new(config)?.hash_no_padding(data, 1)?[0])
 }
 
-impl<F: RescueParameter> DigestAlgorithm<F, u64, F> for RescueHash<F> {
+/// Rescue sponge hashes of each of `data` in the domain of `D`, with all the
+/// sponges permuted together.
+fn rescue_digest_many<F: RescueParameter, D: DomainSeparator>(
+    data: &[&[F]],
+) -> Result<Vec<F>, PrimitivesError> {
+    let config = RescueSpongeConfig::default().with_domain_separator(D::DOMAIN_SEPARATOR);
+    Ok(RescueCRHF::<F>::new(config)?
+        .batch_hash_no_padding(data, 1)?
+        .into_iter()
+        .map(|output| output[0])
+        .collect())
+}
+
+/// Rescue leaf digests of `elems` at positions `pos` in the domain of `D`, with
+/// all the sponges permuted together.
+fn rescue_digest_many_leaves<F: RescueParameter, D: DomainSeparator>(
+    pos: impl ExactSizeIterator<Item = F>,
+    elems: &[F],
+) -> Result<Vec<F>, PrimitivesError> {
+    if pos.len() != elems.len() {
+        return Err(PrimitivesError::ParameterError(format!(
+            "{} positions for {} leaves",
+            pos.len(),
+            elems.len()
+        )));
+    }
+    let data: Vec<[F; 3]> = pos
+        .zip(elems)
+        .map(|(pos, elem)| [F::zero(), pos, *elem])
+        .collect();
+    let data: Vec<&[F]> = data.iter().map(|data| &data[..]).collect();
+    rescue_digest_many::<F, D>(&data)
+}
+
+impl<F: RescueParameter, D: DomainSeparator> DigestAlgorithm<F, u64, F> for RescueHash<F, D> {
     fn digest(data: &[F]) -> Result<F, PrimitivesError> {
-        Ok(RescueCRHF::<F>::sponge_no_padding(data, 1)?[0])
+        rescue_digest::<F, D>(data)
     }
 
     fn digest_leaf(pos: &u64, elem: &F) -> Result<F, PrimitivesError> {
         let data = [F::zero(), F::from(*pos), *elem];
-        Ok(RescueCRHF::<F>::sponge_no_padding(&data, 1)?[0])
+        rescue_digest::<F, D>(&data)
+    }
+
+    fn digest_many(data: &[&[F]]) -> Result<Vec<F>, PrimitivesError> {
+        rescue_digest_many::<F, D>(data)
+    }
+
+    fn digest_many_leaves(pos: &[u64], elems: &[F]) -> Result<Vec<F>, PrimitivesError> {
+        rescue_digest_many_leaves::<F, D>(pos.iter().map(|pos| F::from(*pos)), elems)
     }
 }
 
@@ -53,31 +111,284 @@ pub type RescueMerkleTree<F> = MerkleTree<F, RescueHash<F>, u64, U3, F>;
 /// A standard light merkle tree using RATE-3 rescue hash function
 pub type RescueLightWeightMerkleTree<F> = LightWeightMerkleTree<F, RescueHash<F>, u64, U3, F>;
 
-impl<F: RescueParameter> DigestAlgorithm<F, BigUint, F> for RescueHash<F> {
+impl<F: RescueParameter, D: DomainSeparator> DigestAlgorithm<F, u128, F> for RescueHash<F, D> {
     fn digest(data: &[F]) -> Result<F, PrimitivesError> {
-        Ok(RescueCRHF::<F>::sponge_no_padding(data, 1)?[0])
+        rescue_digest::<F, D>(data)
+    }
+
+    fn digest_leaf(pos: &u128, elem: &F) -> Result<F, PrimitivesError> {
+        let data = [F::zero(), F::from(*pos), *elem];
+        rescue_digest::<F, D>(&data)
+    }
+
+    fn digest_many(data: &[&[F]]) -> Result<Vec<F>, PrimitivesError> {
+        rescue_digest_many::<F, D>(data)
+    }
+
+    fn digest_many_leaves(pos: &[u128], elems: &[F]) -> Result<Vec<F>, PrimitivesError> {
+        rescue_digest_many_leaves::<F, D>(pos.iter().map(|pos| F::from(*pos)), elems)
+    }
+}
+
+impl<F: RescueParameter, D: DomainSeparator> DigestAlgorithm<F, BigUint, F> for RescueHash<F, D> {
+    fn digest(data: &[F]) -> Result<F, PrimitivesError> {
+        rescue_digest::<F, D>(data)
     }
 
     fn digest_leaf(pos: &BigUint, elem: &F) -> Result<F, PrimitivesError> {
         let data = [F::zero(), F::from(pos.clone()), *elem];
-        Ok(RescueCRHF::<F>::sponge_no_padding(&data, 1)?[0])
+        rescue_digest::<F, D>(&data)
+    }
+
+    fn digest_many(data: &[&[F]]) -> Result<Vec<F>, PrimitivesError> {
+        rescue_digest_many::<F, D>(data)
+    }
+
+    fn digest_many_leaves(pos: &[BigUint], elems: &[F]) -> Result<Vec<F>, PrimitivesError> {
+        rescue_digest_many_leaves::<F, D>(pos.iter().map(|pos| F::from(pos.clone())), elems)
     }
 }
 
-impl<F: RescueParameter> DigestAlgorithm<F, F, F> for RescueHash<F> {
+impl<F: RescueParameter, D: DomainSeparator> DigestAlgorithm<F, F, F> for RescueHash<F, D> {
     fn digest(data: &[F]) -> Result<F, PrimitivesError> {
-        Ok(RescueCRHF::<F>::sponge_no_padding(data, 1)?[0])
+        rescue_digest::<F, D>(data)
     }
 
     fn digest_leaf(pos: &F, elem: &F) -> Result<F, PrimitivesError> {
         let data = [F::zero(), *pos, *elem];
-        Ok(RescueCRHF::<F>::sponge_no_padding(&data, 1)?[0])
+        rescue_digest::<F, D>(&data)
+    }
+
+    fn digest_many(data: &[&[F]]) -> Result<Vec<F>, PrimitivesError> {
+        rescue_digest_many::<F, D>(data)
+    }
+
+    fn digest_many_leaves(pos: &[F], elems: &[F]) -> Result<Vec<F>, PrimitivesError> {
+        rescue_digest_many_leaves::<F, D>(pos.iter().copied(), elems)
     }
 }
 
 /// Example instantiation of a SparseMerkleTree indexed by I
 pub type RescueSparseMerkleTree<I, F> = UniversalMerkleTree<F, RescueHash<F>, I, U3, F>;
 
+/// Wrapper for poseidon hash function, hashing in the domain of `D`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PoseidonHash<F: PoseidonParameter, D = NoDomainSeparator> {
+    phantom: PhantomData<(F, D)>,
+}
+
+/// Poseidon sponge hash of `data` in the domain of `D`.
+fn poseidon_digest<F: PoseidonParameter, D: DomainSeparator>(
+    data: &[F],
+) -> Result<F, PrimitivesError> {
+    Ok(PoseidonCRHF::<F>::with_domain_separator(D::DOMAIN_SEPARATOR).hash_no_padding(data, 1)?[0])
+}
+
+impl<F: PoseidonParameter, D: DomainSeparator> DigestAlgorithm<F, u64, F> for PoseidonHash<F, D> {
+    fn digest(data: &[F]) -> Result<F, PrimitivesError> {
+        poseidon_digest::<F, D>(data)
+    }
+
+    fn digest_leaf(pos: &u64, elem: &F) -> Result<F, PrimitivesError> {
+        let data = [F::zero(), F::from(*pos), *elem];
+        poseidon_digest::<F, D>(&data)
+    }
+}
+
+impl<F: PoseidonParameter, D: DomainSeparator> DigestAlgorithm<F, u128, F> for PoseidonHash<F, D> {
+    fn digest(data: &[F]) -> Result<F, PrimitivesError> {
+        poseidon_digest::<F, D>(data)
+    }
+
+    fn digest_leaf(pos: &u128, elem: &F) -> Result<F, PrimitivesError> {
+        let data = [F::zero(), F::from(*pos), *elem];
+        poseidon_digest::<F, D>(&data)
+    }
+}
+
+impl<F: PoseidonParameter, D: DomainSeparator> DigestAlgorithm<F, BigUint, F>
+    for PoseidonHash<F, D>
+{
+    fn digest(data: &[F]) -> Result<F, PrimitivesError> {
+        poseidon_digest::<F, D>(data)
+    }
+
+    fn digest_leaf(pos: &BigUint, elem: &F) -> Result<F, PrimitivesError> {
+        let data = [F::zero(), F::from(pos.clone()), *elem];
+        poseidon_digest::<F, D>(&data)
+    }
+}
+
+impl<F: PoseidonParameter, D: DomainSeparator> DigestAlgorithm<F, F, F> for PoseidonHash<F, D> {
+    fn digest(data: &[F]) -> Result<F, PrimitivesError> {
+        poseidon_digest::<F, D>(data)
+    }
+
+    fn digest_leaf(pos: &F, elem: &F) -> Result<F, PrimitivesError> {
+        let data = [F::zero(), *pos, *elem];
+        poseidon_digest::<F, D>(&data)
+    }
+}
+
+/// A standard merkle tree using RATE-3 poseidon hash function
+pub type PoseidonMerkleTree<F> = MerkleTree<F, PoseidonHash<F>, u64, U3, F>;
+
+/// A standard light merkle tree using RATE-3 poseidon hash function
+pub type PoseidonLightWeightMerkleTree<F> = LightWeightMerkleTree<F, PoseidonHash<F>, u64, U3, F>;
+
+/// Example instantiation of a SparseMerkleTree indexed by I, using poseidon
+/// hash function
+pub type PoseidonSparseMerkleTree<I, F> = UniversalMerkleTree<F, PoseidonHash<F>, I, U3, F>;
+
+/// Wrapper for the Jive compression mode of the anemoi permutation, which
+/// compresses exactly two field elements, so it only fits binary trees.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AnemoiJiveHash<F: AnemoiParameter> {
+    phantom_f: PhantomData<F>,
+}
+
+impl<F: AnemoiParameter> DigestAlgorithm<F, u64, F> for AnemoiJiveHash<F> {
+    fn digest(data: &[F]) -> Result<F, PrimitivesError> {
+        if data.len() != 2 {
+            return Err(PrimitivesError::ParameterError(format!(
+                "Jive compresses 2 elements, got {}",
+                data.len()
+            )));
+        }
+        Ok(jive(data[0], data[1]))
+    }
+
+    fn digest_leaf(pos: &u64, elem: &F) -> Result<F, PrimitivesError> {
+        Ok(jive(F::from(*pos), *elem))
+    }
+}
+
+/// A binary merkle tree using the Jive compression of the anemoi permutation
+pub type AnemoiMerkleTree<F> = MerkleTree<F, AnemoiJiveHash<F>, u64, U2, F>;
+
+/// A node of a tip5 merkle tree, i.e. a tip5 digest.
+#[derive(Default, Eq, PartialEq, Clone, Copy, Debug, Ord, PartialOrd, Hash)]
+pub struct Tip5Node(pub [Goldilocks; tip5::DIGEST_LEN]);
+
+impl CanonicalSerialize for Tip5Node {
+    fn serialize_with_mode<W: Write>(
+        &self,
+        mut writer: W,
+        compress: Compress,
+    ) -> Result<(), SerializationError> {
+        for elem in self.0.iter() {
+            elem.serialize_with_mode(&mut writer, compress)?;
+        }
+        Ok(())
+    }
+
+    fn serialized_size(&self, compress: Compress) -> usize {
+        self.0
+            .iter()
+            .map(|elem| elem.serialized_size(compress))
+            .sum()
+    }
+}
+
+impl CanonicalDeserialize for Tip5Node {
+    fn deserialize_with_mode<R: Read>(
+        mut reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        let mut ret = [Goldilocks::zero(); tip5::DIGEST_LEN];
+        for elem in ret.iter_mut() {
+            *elem = Goldilocks::deserialize_with_mode(&mut reader, compress, validate)?;
+        }
+        Ok(Tip5Node(ret))
+    }
+}
+
+impl Valid for Tip5Node {
+    fn check(&self) -> Result<(), SerializationError> {
+        Ok(())
+    }
+}
+
+/// Wrapper for the tip5 hash function, which compresses exactly two digests,
+/// so it only fits binary trees. A leaf is the variable-length tip5 hash of
+/// `[0, pos, elem]`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Tip5Hash;
+
+impl DigestAlgorithm<Goldilocks, u64, Tip5Node> for Tip5Hash {
+    fn digest(data: &[Tip5Node]) -> Result<Tip5Node, PrimitivesError> {
+        if data.len() != 2 {
+            return Err(PrimitivesError::ParameterError(format!(
+                "Tip5 compresses 2 digests, got {}",
+                data.len()
+            )));
+        }
+        Ok(Tip5Node(Tip5CRHF::hash_pair(&data[0].0, &data[1].0)))
+    }
+
+    fn digest_leaf(pos: &u64, elem: &Goldilocks) -> Result<Tip5Node, PrimitivesError> {
+        let data = [Goldilocks::zero(), Goldilocks::from(*pos), *elem];
+        Ok(Tip5Node(Tip5CRHF::hash_varlen(&data)))
+    }
+}
+
+/// A binary merkle tree of Goldilocks elements using the tip5 hash function
+pub type Tip5MerkleTree = MerkleTree<Goldilocks, Tip5Hash, u64, U2, Tip5Node>;
+
+/// Leaf-hashing adapter which lets a field-based hash `H` commit to byte
+/// strings, i.e. elements of any type implementing `AsRef<[u8]>` such as
+/// `Vec<u8>`.
+///
+/// A leaf is first encoded with [`bytes_to_field_elements`], zero-padded to a
+/// non-empty multiple of `Arity` field elements and compressed with
+/// `H::digest`. The result is then hashed together with the position as a
+/// regular field element leaf. Internal nodes are digested by `H` unchanged,
+/// so `Arity` should be the arity of the tree.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ByteLeafDigest<H, Arity> {
+    phantom: PhantomData<(H, Arity)>,
+}
+
+impl<E, I, F, H, Arity> DigestAlgorithm<E, I, F> for ByteLeafDigest<H, Arity>
+where
+    E: Element + AsRef<[u8]>,
+    I: Index,
+    F: NodeValue + PrimeField,
+    H: DigestAlgorithm<F, I, F>,
+    Arity: Unsigned,
+{
+    fn digest(data: &[F]) -> Result<F, PrimitivesError> {
+        H::digest(data)
+    }
+
+    fn digest_many(data: &[&[F]]) -> Result<Vec<F>, PrimitivesError> {
+        H::digest_many(data)
+    }
+
+    fn digest_leaf(pos: &I, elem: &E) -> Result<F, PrimitivesError> {
+        let mut data = bytes_to_field_elements::<_, F>(elem.as_ref());
+        let arity = Arity::to_usize();
+        let len = ark_std::cmp::max(1, (data.len() + arity - 1) / arity) * arity;
+        data.resize(len, F::zero());
+        H::digest_leaf(pos, &H::digest(&data)?)
+    }
+}
+
+/// A standard merkle tree with byte-string elements, using RATE-3 rescue hash
+/// function
+pub type RescueBytesMerkleTree<E, F> = MerkleTree<E, ByteLeafDigest<RescueHash<F>, U3>, u64, U3, F>;
+
+/// A sparse merkle tree with byte-string elements indexed by I, using RATE-3
+/// rescue hash function
+pub type RescueBytesSparseMerkleTree<E, I, F> =
+    UniversalMerkleTree<E, ByteLeafDigest<RescueHash<F>, U3>, I, U3, F>;
+
+/// A standard merkle tree with byte-string elements, using RATE-3 poseidon
+/// hash function
+pub type PoseidonBytesMerkleTree<E, F> =
+    MerkleTree<E, ByteLeafDigest<PoseidonHash<F>, U3>, u64, U3, F>;
+
 /// Update the array length here
 #[derive(Default, Eq, PartialEq, Clone, Copy, Debug, Ord, PartialOrd, Hash)]
 pub struct Sha3Node(pub(crate) [u8; 32]);
@@ -120,7 +431,9 @@ impl Valid for Sha3Node {
     }
 }
 
-/// Wrapper for SHA3_512 hash function
+/// Wrapper for SHA3_256 hash function. Leaves are hashed from the compressed
+/// canonical serialization of the element, so any byte-oriented element (e.g.
+/// `Vec<u8>`) or arkworks type can be used.
 #[derive(Clone, Debug, Hash, Eq, PartialEq)]
 pub struct Sha3Digest();
 
@@ -135,7 +448,7 @@ impl<E: Element + CanonicalSerialize, I: Index> DigestAlgorithm<E, I, Sha3Node>
 
     fn digest_leaf(_pos: &I, elem: &E) -> Result<Sha3Node, PrimitivesError> {
         let mut writer = Vec::new();
-        elem.serialize_compressed(&mut writer).unwrap();
+        elem.serialize_compressed(&mut writer)?;
         let mut hasher = Sha3_256::new();
         hasher.update(writer);
         Ok(Sha3Node(hasher.finalize().into()))
@@ -146,3 +459,582 @@ impl<E: Element + CanonicalSerialize, I: Index> DigestAlgorithm<E, I, Sha3Node>
 pub type SHA3MerkleTree<E> = MerkleTree<E, Sha3Digest, u64, U3, Sha3Node>;
 /// Light weight merkle tree using SHA3 hash
 pub type LightWeightSHA3MerkleTree<E> = LightWeightMerkleTree<E, Sha3Digest, u64, U3, Sha3Node>;
+
+/// Domain separator for hashing a leaf with [`Keccak256Digest`]
+pub const KECCAK256_LEAF_PREFIX: u8 = 0x00;
+/// Domain separator for hashing an internal node with [`Keccak256Digest`]
+pub const KECCAK256_NODE_PREFIX: u8 = 0x01;
+
+/// Wrapper for Keccak256 hash function. It follows the usual convention of
+/// on-chain verifiers to domain separate leaves and internal nodes: a leaf is
+/// hashed as `keccak256(0x00 || elem)`, where `elem` is the uncompressed
+/// canonical serialization of the element, and an internal node as
+/// `keccak256(0x01 || child_0 || ... || child_k)`.
+#[derive(Clone, Debug, Hash, Eq, PartialEq)]
+pub struct Keccak256Digest();
+
+impl<E: Element + CanonicalSerialize, I: Index> DigestAlgorithm<E, I, Sha3Node>
+    for Keccak256Digest
+{
+    fn digest(data: &[Sha3Node]) -> Result<Sha3Node, PrimitivesError> {
+        let mut hasher = Keccak256::new();
+        hasher.update([KECCAK256_NODE_PREFIX]);
+        for value in data {
+            hasher.update(value);
+        }
+        Ok(Sha3Node(hasher.finalize().into()))
+    }
+
+    fn digest_leaf(_pos: &I, elem: &E) -> Result<Sha3Node, PrimitivesError> {
+        let mut writer = Vec::new();
+        elem.serialize_uncompressed(&mut writer)?;
+        let mut hasher = Keccak256::new();
+        hasher.update([KECCAK256_LEAF_PREFIX]);
+        hasher.update(writer);
+        Ok(Sha3Node(hasher.finalize().into()))
+    }
+}
+
+/// Binary Merkle tree using Keccak256 hash, suitable for verification on EVM.
+///
+/// An empty subtree, of any height, has the value `bytes32(0)`, rather than
+/// the digest of its empty children, so that a proof of a leaf is the list of
+/// its siblings from the leaf level up, `bytes32(0)` for the empty ones, and
+/// is checked on-chain by
+/// ```solidity
+/// function verify(bytes32 root, uint256 pos, bytes32 leaf, bytes32[] memory siblings)
+///     internal pure returns (bool)
+/// {
+///     bytes32 node = leaf;
+///     for (uint256 i = 0; i < siblings.length; i++) {
+///         node = pos & 1 == 0
+///             ? keccak256(abi.encodePacked(bytes1(0x01), node, siblings[i]))
+///             : keccak256(abi.encodePacked(bytes1(0x01), siblings[i], node));
+///         pos >>= 1;
+///     }
+///     return node == root;
+/// }
+/// ```
+/// where `leaf` is `keccak256(abi.encodePacked(bytes1(0x00), elem))`.
+pub type Keccak256MerkleTree<E> = MerkleTree<E, Keccak256Digest, u64, U2, Sha3Node>;
+/// Light weight binary merkle tree using Keccak256 hash
+pub type LightWeightKeccak256MerkleTree<E> =
+    LightWeightMerkleTree<E, Keccak256Digest, u64, U2, Sha3Node>;
+
+/// Wrapper for Blake3 hash function. Leaves and internal nodes are domain
+/// separated by a prefix byte, `0x00` for leaves and `0x01` for internal
+/// nodes.
+#[derive(Clone, Debug, Hash, Eq, PartialEq)]
+pub struct Blake3Digest();
+
+impl<E: Element + CanonicalSerialize, I: Index> DigestAlgorithm<E, I, Sha3Node> for Blake3Digest {
+    fn digest(data: &[Sha3Node]) -> Result<Sha3Node, PrimitivesError> {
+        let mut hasher = blake3::Hasher::new();
+        hasher.update(&[0x01]);
+        for value in data {
+            hasher.update(value.as_ref());
+        }
+        Ok(Sha3Node(hasher.finalize().into()))
+    }
+
+    fn digest_leaf(_pos: &I, elem: &E) -> Result<Sha3Node, PrimitivesError> {
+        let mut writer = Vec::new();
+        elem.serialize_compressed(&mut writer)?;
+        let mut hasher = blake3::Hasher::new();
+        hasher.update(&[0x00]);
+        hasher.update(&writer);
+        Ok(Sha3Node(hasher.finalize().into()))
+    }
+}
+
+/// Merkle tree using Blake3 hash with a configurable arity. A wide arity keeps
+/// the tree shallow, which pays off for trees with millions of leaves.
+pub type GenericBlake3MerkleTree<E, Arity> = MerkleTree<E, Blake3Digest, u64, Arity, Sha3Node>;
+/// Merkle tree using Blake3 hash, with arity 8
+pub type Blake3MerkleTree<E> = GenericBlake3MerkleTree<E, U8>;
+/// Light weight merkle tree using Blake3 hash, with arity 8
+pub type LightWeightBlake3MerkleTree<E> = LightWeightMerkleTree<E, Blake3Digest, u64, U8, Sha3Node>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::merkle_tree::internal::MerkleNode;
+    use ark_ed_on_bls12_377::Fq as Fq377;
+    use ark_ed_on_bls12_381::Fq as Fq381;
+    use ark_ed_on_bn254::Fq as Fq254;
+    use ark_std::vec;
+
+    #[test]
+    fn test_poseidon_mt() {
+        test_poseidon_mt_helper::<Fq254>();
+        test_poseidon_mt_helper::<Fq377>();
+        test_poseidon_mt_helper::<Fq381>();
+    }
+
+    fn test_poseidon_mt_helper<F: PoseidonParameter + RescueParameter>() {
+        let elems = (0..10u64).map(F::from).collect::<Vec<_>>();
+        let mt = PoseidonMerkleTree::<F>::from_elems(None, &elems).unwrap();
+        let light_mt = PoseidonLightWeightMerkleTree::<F>::from_elems(None, &elems).unwrap();
+        assert_eq!(mt.commitment(), light_mt.commitment());
+        // Differs from the rescue instantiation over the same leaves
+        let rescue_mt = RescueMerkleTree::<F>::from_elems(None, &elems).unwrap();
+        assert_ne!(mt.commitment().digest(), rescue_mt.commitment().digest());
+
+        let root = mt.commitment().digest();
+        let (elem, proof) = mt.lookup(3).expect_ok().unwrap();
+        assert_eq!(elem, &elems[3]);
+        assert!(PoseidonMerkleTree::<F>::verify(root, 3, &proof)
+            .unwrap()
+            .is_ok());
+        assert!(PoseidonMerkleTree::<F>::verify(root, 4, &proof)
+            .unwrap()
+            .is_err());
+
+        let mut smt = PoseidonSparseMerkleTree::<BigUint, F>::new(10);
+        smt.update(BigUint::from(42u64), F::from(7u64)).unwrap();
+        let root = smt.commitment().digest();
+        let (elem, proof) = smt.lookup(BigUint::from(42u64)).expect_ok().unwrap();
+        assert_eq!(elem, &F::from(7u64));
+        assert!(
+            PoseidonSparseMerkleTree::<BigUint, F>::verify(root, BigUint::from(42u64), &proof)
+                .unwrap()
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn test_anemoi_mt() {
+        test_anemoi_mt_helper::<Fq254>();
+        test_anemoi_mt_helper::<Fq377>();
+        test_anemoi_mt_helper::<Fq381>();
+    }
+
+    fn test_anemoi_mt_helper<F: AnemoiParameter>() {
+        let elems = (0..10u64).map(F::from).collect::<Vec<_>>();
+        let mt = AnemoiMerkleTree::<F>::from_elems(None, &elems).unwrap();
+        assert_eq!(mt.height(), 4);
+
+        let root = mt.commitment().digest();
+        let (elem, proof) = mt.lookup(3).expect_ok().unwrap();
+        assert_eq!(elem, &elems[3]);
+        assert!(AnemoiMerkleTree::<F>::verify(root, 3, &proof)
+            .unwrap()
+            .is_ok());
+        assert!(AnemoiMerkleTree::<F>::verify(root, 4, &proof)
+            .unwrap()
+            .is_err());
+
+        // Internal nodes are the Jive compression of their children
+        assert_eq!(
+            AnemoiJiveHash::<F>::digest(&[elems[0], elems[1]]).unwrap(),
+            jive(elems[0], elems[1])
+        );
+        assert!(AnemoiJiveHash::<F>::digest(&elems[..3]).is_err());
+    }
+
+    #[test]
+    fn test_tip5_mt() {
+        let elems = (0..10u64).map(Goldilocks::from).collect::<Vec<_>>();
+        let mt = Tip5MerkleTree::from_elems(None, &elems).unwrap();
+        let root = mt.commitment().digest();
+        let (elem, proof) = mt.lookup(3).expect_ok().unwrap();
+        assert_eq!(elem, &elems[3]);
+        assert!(Tip5MerkleTree::verify(root, 3, &proof).unwrap().is_ok());
+        assert!(Tip5MerkleTree::verify(root, 4, &proof).unwrap().is_err());
+
+        // Leaves are the tip5 hash of [0, pos, elem], internal nodes the
+        // compression of their two children
+        let expected = [
+            4365260348264685916u64,
+            15828222637234099177,
+            15326935842165775127,
+            4549010178246467768,
+            743127212553281926,
+        ]
+        .map(Goldilocks::from);
+        assert_eq!(
+            Tip5Hash::digest_leaf(&3, &Goldilocks::from(42u64)).unwrap(),
+            Tip5Node(expected)
+        );
+        let children = [Tip5Node(expected), Tip5Node::default()];
+        assert_eq!(
+            Tip5Hash::digest(&children).unwrap(),
+            Tip5Node(Tip5CRHF::hash_pair(&expected, &Default::default()))
+        );
+        assert!(Tip5Hash::digest(&children[..1]).is_err());
+
+        let mut bytes = Vec::new();
+        root.serialize_compressed(&mut bytes).unwrap();
+        assert_eq!(bytes.len(), root.compressed_size());
+        assert_eq!(Tip5Node::deserialize_compressed(&bytes[..]).unwrap(), root);
+    }
+
+    #[test]
+    fn test_digest_many() {
+        test_digest_many_helper::<Fq254>();
+        test_digest_many_helper::<Fq377>();
+        test_digest_many_helper::<Fq381>();
+    }
+
+    fn test_digest_many_helper<F: PoseidonParameter + RescueParameter>() {
+        type Rescue<F> = RescueHash<F, TestDomain>;
+        let elems = (0..200u64).map(F::from).collect::<Vec<_>>();
+        let pos = (0..200u64).collect::<Vec<_>>();
+        let data = elems.chunks(3).collect::<Vec<_>>();
+
+        // The batched rescue digests and the default loop of poseidon match
+        // the digests of each input
+        assert_eq!(
+            <Rescue<F> as DigestAlgorithm<F, u64, F>>::digest_many(&data[..66]).unwrap(),
+            data[..66]
+                .iter()
+                .map(|data| <Rescue<F> as DigestAlgorithm<F, u64, F>>::digest(data).unwrap())
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            <Rescue<F> as DigestAlgorithm<F, u64, F>>::digest_many_leaves(&pos, &elems).unwrap(),
+            pos.iter()
+                .zip(&elems)
+                .map(|(pos, elem)| {
+                    <Rescue<F> as DigestAlgorithm<F, u64, F>>::digest_leaf(pos, elem).unwrap()
+                })
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            <PoseidonHash<F> as DigestAlgorithm<F, u64, F>>::digest_many(&data[..66]).unwrap(),
+            data[..66]
+                .iter()
+                .map(|data| <PoseidonHash<F> as DigestAlgorithm<F, u64, F>>::digest(data).unwrap())
+                .collect::<Vec<_>>()
+        );
+        assert!(
+            <Rescue<F> as DigestAlgorithm<F, u64, F>>::digest_many_leaves(&pos[1..], &elems)
+                .is_err()
+        );
+        assert!(
+            <PoseidonHash<F> as DigestAlgorithm<F, u64, F>>::digest_many_leaves(&pos[1..], &elems)
+                .is_err()
+        );
+        // Inputs which are not a multiple of the rate are rejected, as by `digest`
+        assert!(<Rescue<F> as DigestAlgorithm<F, u64, F>>::digest_many(&[
+            &elems[..3],
+            &elems[..2]
+        ])
+        .is_err());
+
+        // Trees built by batches of several digests match trees built leaf by
+        // leaf
+        let mt = MerkleTree::<F, Rescue<F>, u64, U3, F>::from_elems(Some(5), &elems).unwrap();
+        let mut expected = MerkleTree::<F, Rescue<F>, u64, U3, F>::new(5);
+        expected.extend(&elems).unwrap();
+        assert_eq!(mt.commitment(), expected.commitment());
+        let light_mt = PoseidonLightWeightMerkleTree::<F>::from_elems(Some(5), &elems).unwrap();
+        let mut expected = PoseidonMerkleTree::<F>::new(5);
+        expected.extend(&elems).unwrap();
+        assert_eq!(light_mt.commitment(), expected.commitment());
+    }
+
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    struct TestDomain;
+
+    impl DomainSeparator for TestDomain {
+        const DOMAIN_SEPARATOR: &'static [u8] = b"jellyfish test";
+    }
+
+    #[test]
+    fn test_domain_separated_mt() {
+        test_domain_separated_mt_helper::<Fq254>();
+        test_domain_separated_mt_helper::<Fq377>();
+        test_domain_separated_mt_helper::<Fq381>();
+    }
+
+    fn test_domain_separated_mt_helper<F: PoseidonParameter + RescueParameter>() {
+        type RescueTestMerkleTree<F> = MerkleTree<F, RescueHash<F, TestDomain>, u64, U3, F>;
+        type PoseidonTestMerkleTree<F> = MerkleTree<F, PoseidonHash<F, TestDomain>, u64, U3, F>;
+
+        let elems = (0..10u64).map(F::from).collect::<Vec<_>>();
+        let mt = RescueTestMerkleTree::<F>::from_elems(None, &elems).unwrap();
+        let root = mt.commitment().digest();
+        let (_, proof) = mt.lookup(3).expect_ok().unwrap();
+        assert!(RescueTestMerkleTree::<F>::verify(root, 3, &proof)
+            .unwrap()
+            .is_ok());
+        // Proofs do not carry over to another domain
+        let default_mt = RescueMerkleTree::<F>::from_elems(None, &elems).unwrap();
+        assert_ne!(root, default_mt.commitment().digest());
+        assert!(
+            RescueMerkleTree::<F>::verify(default_mt.commitment().digest(), 3, &proof)
+                .unwrap()
+                .is_err()
+        );
+        assert_eq!(
+            <RescueHash<F, TestDomain> as DigestAlgorithm<F, u64, F>>::digest(&elems[..3]).unwrap(),
+            RescueCRHF::<F>::new(
+                RescueSpongeConfig::default().with_domain_separator(b"jellyfish test")
+            )
+            .unwrap()
+            .hash_no_padding(&elems[..3], 1)
+            .unwrap()[0]
+        );
+        // The default domain is the empty domain separator
+        assert_eq!(
+            <RescueHash<F, NoDomainSeparator> as DigestAlgorithm<F, u64, F>>::digest(&elems[..3])
+                .unwrap(),
+            RescueCRHF::<F>::sponge_no_padding(&elems[..3], 1).unwrap()[0]
+        );
+
+        let mt = PoseidonTestMerkleTree::<F>::from_elems(None, &elems).unwrap();
+        let default_mt = PoseidonMerkleTree::<F>::from_elems(None, &elems).unwrap();
+        assert_ne!(mt.commitment().digest(), default_mt.commitment().digest());
+        assert_eq!(
+            <PoseidonHash<F, TestDomain> as DigestAlgorithm<F, u64, F>>::digest(&elems[..3])
+                .unwrap(),
+            PoseidonCRHF::<F>::with_domain_separator(b"jellyfish test")
+                .hash_no_padding(&elems[..3], 1)
+                .unwrap()[0]
+        );
+    }
+
+    #[test]
+    fn test_byte_leaf_mt() {
+        test_byte_leaf_mt_helper::<Fq254>();
+        test_byte_leaf_mt_helper::<Fq377>();
+        test_byte_leaf_mt_helper::<Fq381>();
+    }
+
+    fn test_byte_leaf_mt_helper<F: PoseidonParameter + RescueParameter>() {
+        let elems = (0..10u8)
+            .map(|i| vec![i; 17 * i as usize])
+            .collect::<Vec<_>>();
+        let mt = RescueBytesMerkleTree::<Vec<u8>, F>::from_elems(None, &elems).unwrap();
+        let root = mt.commitment().digest();
+        for (i, elem) in elems.iter().enumerate() {
+            let (val, proof) = mt.lookup(i as u64).expect_ok().unwrap();
+            assert_eq!(val, elem);
+            assert!(
+                RescueBytesMerkleTree::<Vec<u8>, F>::verify(root, i as u64, &proof)
+                    .unwrap()
+                    .is_ok()
+            );
+        }
+        let (_, mut proof) = mt.lookup(2).expect_ok().unwrap();
+        proof.proof[0] = MerkleNode::Leaf {
+            value: F::zero(),
+            pos: 2,
+            elem: vec![2u8; 33],
+        };
+        assert!(RescueBytesMerkleTree::<Vec<u8>, F>::verify(root, 2, &proof)
+            .unwrap()
+            .is_err());
+
+        // Leaves are encoded injectively, including the empty string
+        type Adapter<F> = ByteLeafDigest<RescueHash<F>, U3>;
+        let digest = |elem: &[u8]| {
+            <Adapter<F> as DigestAlgorithm<Vec<u8>, u64, F>>::digest_leaf(&0, &elem.to_vec())
+                .unwrap()
+        };
+        assert_ne!(digest(&[]), digest(&[0]));
+        assert_ne!(digest(&[0]), digest(&[0, 0]));
+        let mut data = jf_utils::bytes_to_field_elements::<_, F>([1u8, 2, 3].as_ref());
+        data.resize(3, F::zero());
+        let expected = <RescueHash<F> as DigestAlgorithm<F, u64, F>>::digest_leaf(
+            &0,
+            &RescueCRHF::<F>::sponge_no_padding(&data, 1).unwrap()[0],
+        )
+        .unwrap();
+        assert_eq!(digest(&[1, 2, 3]), expected);
+
+        // Byte-string elements also work for sparse trees and other hashes
+        let mut smt = RescueBytesSparseMerkleTree::<Vec<u8>, BigUint, F>::new(10);
+        smt.update(BigUint::from(42u64), b"hello".to_vec()).unwrap();
+        let root = smt.commitment().digest();
+        let (_, proof) = smt.lookup(BigUint::from(42u64)).expect_ok().unwrap();
+        assert!(RescueBytesSparseMerkleTree::<Vec<u8>, BigUint, F>::verify(
+            root,
+            BigUint::from(42u64),
+            &proof
+        )
+        .unwrap()
+        .is_ok());
+        let poseidon_mt = PoseidonBytesMerkleTree::<Vec<u8>, F>::from_elems(None, &elems).unwrap();
+        assert_ne!(poseidon_mt.commitment().digest(), mt.commitment().digest());
+    }
+
+    #[test]
+    fn test_sha3_mt() {
+        let elems = (0..10u8).map(|i| vec![i; i as usize]).collect::<Vec<_>>();
+        let mut mt = SHA3MerkleTree::<Vec<u8>>::from_elems(None, &elems).unwrap();
+        let mut light_mt = LightWeightSHA3MerkleTree::<Vec<u8>>::from_elems(None, &elems).unwrap();
+        assert_eq!(mt.commitment(), light_mt.commitment());
+
+        let root = mt.commitment().digest();
+        let (elem, proof) = mt.lookup(7).expect_ok().unwrap();
+        assert_eq!(elem, &elems[7]);
+        assert!(SHA3MerkleTree::<Vec<u8>>::verify(root, 7, &proof)
+            .unwrap()
+            .is_ok());
+        assert!(SHA3MerkleTree::<Vec<u8>>::verify(root, 6, &proof)
+            .unwrap()
+            .is_err());
+
+        // Leaves are hashed from their canonical serialization
+        let mut bytes = Vec::new();
+        elems[7].serialize_compressed(&mut bytes).unwrap();
+        let expected_leaf = Sha3Node(Sha3_256::digest(bytes).into());
+        assert_eq!(
+            <Sha3Digest as DigestAlgorithm<Vec<u8>, u64, Sha3Node>>::digest_leaf(&7, &elems[7])
+                .unwrap(),
+            expected_leaf
+        );
+
+        mt.push(vec![0xffu8; 100]).unwrap();
+        light_mt.push(vec![0xffu8; 100]).unwrap();
+        assert_eq!(mt.commitment(), light_mt.commitment());
+        assert!(light_mt.lookup(10).expect_ok().is_ok());
+        assert!(light_mt.lookup(7).expect_not_in_memory().is_ok());
+    }
+
+    #[test]
+    fn test_blake3_mt() {
+        let elems = (0..100u64).collect::<Vec<_>>();
+        let mt = Blake3MerkleTree::<u64>::from_elems(None, &elems).unwrap();
+        assert_eq!(mt.height(), 3);
+        let light_mt = LightWeightBlake3MerkleTree::<u64>::from_elems(None, &elems).unwrap();
+        assert_eq!(mt.commitment(), light_mt.commitment());
+
+        let root = mt.commitment().digest();
+        let (elem, proof) = mt.lookup(42).expect_ok().unwrap();
+        assert_eq!(elem, &42);
+        assert!(Blake3MerkleTree::<u64>::verify(root, 42, &proof)
+            .unwrap()
+            .is_ok());
+        assert!(Blake3MerkleTree::<u64>::verify(root, 43, &proof)
+            .unwrap()
+            .is_err());
+
+        let mut hasher = blake3::Hasher::new();
+        hasher.update(&[0x00]);
+        hasher.update(&42u64.to_le_bytes());
+        assert_eq!(
+            <Blake3Digest as DigestAlgorithm<u64, u64, Sha3Node>>::digest_leaf(&42, &42).unwrap(),
+            Sha3Node(hasher.finalize().into())
+        );
+
+        // Binary Blake3 tree
+        let mt = GenericBlake3MerkleTree::<u64, typenum::U2>::from_elems(None, &elems).unwrap();
+        assert_eq!(mt.height(), 7);
+        let (_, proof) = mt.lookup(99).expect_ok().unwrap();
+        assert!(GenericBlake3MerkleTree::<u64, typenum::U2>::verify(
+            mt.commitment().digest(),
+            99,
+            &proof
+        )
+        .unwrap()
+        .is_ok());
+    }
+
+    #[test]
+    fn test_keccak256_mt() {
+        let elems = (0..10u64).map(Fq254::from).collect::<Vec<_>>();
+        let mt = Keccak256MerkleTree::<Fq254>::from_elems(None, &elems).unwrap();
+        let root = mt.commitment().digest();
+        let (elem, proof) = mt.lookup(4).expect_ok().unwrap();
+        assert_eq!(elem, &elems[4]);
+        assert!(Keccak256MerkleTree::<Fq254>::verify(root, 4, &proof)
+            .unwrap()
+            .is_ok());
+        assert!(Keccak256MerkleTree::<Fq254>::verify(root, 5, &proof)
+            .unwrap()
+            .is_err());
+
+        // Check the leaf and node domain separation
+        let mut bytes = Vec::new();
+        elems[4].serialize_uncompressed(&mut bytes).unwrap();
+        let mut hasher = Keccak256::new();
+        hasher.update([0u8]);
+        hasher.update(&bytes);
+        let expected_leaf = Sha3Node(hasher.finalize().into());
+        assert_eq!(
+            <Keccak256Digest as DigestAlgorithm<Fq254, u64, Sha3Node>>::digest_leaf(&4, &elems[4])
+                .unwrap(),
+            expected_leaf
+        );
+        let children = [expected_leaf, Sha3Node::default()];
+        let mut hasher = Keccak256::new();
+        hasher.update([1u8]);
+        children.iter().for_each(|child| hasher.update(child));
+        let expected_node = Sha3Node(hasher.finalize().into());
+        assert_eq!(
+            <Keccak256Digest as DigestAlgorithm<Fq254, u64, Sha3Node>>::digest(&children).unwrap(),
+            expected_node
+        );
+        assert_ne!(expected_leaf, expected_node);
+
+        // Light weight tree agrees with the full tree
+        let light_mt = LightWeightKeccak256MerkleTree::<Fq254>::from_elems(None, &elems).unwrap();
+        assert_eq!(light_mt.commitment(), mt.commitment());
+    }
+
+    /// The Solidity verifier documented on [`Keccak256MerkleTree`].
+    fn solidity_verify(
+        root: [u8; 32],
+        mut pos: u64,
+        leaf: [u8; 32],
+        siblings: &[[u8; 32]],
+    ) -> bool {
+        let mut node = leaf;
+        for sibling in siblings {
+            let (left, right) = if pos & 1 == 0 {
+                (node, *sibling)
+            } else {
+                (*sibling, node)
+            };
+            let mut hasher = Keccak256::new();
+            hasher.update([0x01]);
+            hasher.update(left);
+            hasher.update(right);
+            node = hasher.finalize().into();
+            pos >>= 1;
+        }
+        node == root
+    }
+
+    #[test]
+    fn test_keccak256_mt_against_solidity() {
+        // 5 leaves in a tree of height 4: the empty subtrees of heights 0 to 3
+        // are all `bytes32(0)`
+        let elems = (0..5u64).map(Fq254::from).collect::<Vec<_>>();
+        let mt = Keccak256MerkleTree::<Fq254>::from_elems(Some(4), &elems).unwrap();
+        let root = mt.commitment().digest().0;
+        for (pos, elem) in elems.iter().enumerate() {
+            let (_, proof) = mt.lookup(pos as u64).expect_ok().unwrap();
+            let leaf = <Keccak256Digest as DigestAlgorithm<Fq254, u64, Sha3Node>>::digest_leaf(
+                &(pos as u64),
+                elem,
+            )
+            .unwrap()
+            .0;
+            let siblings = proof
+                .proof
+                .iter()
+                .skip(1)
+                .zip(<u64 as ToTraversalPath<U2>>::to_traversal_path(
+                    &proof.pos, 4,
+                ))
+                .map(|(node, branch)| match node {
+                    MerkleNode::Branch { children, .. } => children[1 - branch].value().0,
+                    _ => unreachable!(),
+                })
+                .collect::<Vec<_>>();
+            assert_eq!(siblings.len(), 4);
+            // the subtree of positions 8 to 15 is empty
+            assert_eq!(siblings[3], [0u8; 32]);
+            if pos == 4 {
+                // so are the leaf at position 5 and the subtree of 6 and 7
+                assert_eq!(siblings[..2], [[0u8; 32]; 2]);
+            }
+            assert!(solidity_verify(root, pos as u64, leaf, &siblings));
+            assert!(!solidity_verify(root, pos as u64 ^ 1, leaf, &siblings));
+        }
+    }
+}
@@ -0,0 +1,371 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+//! Automatic garbage collection of forgetable Merkle trees.
+//!
+//! A [`RetainingMerkleTree`] wraps an appendable and forgetable tree together
+//! with a [`RetentionPolicy`]. After every insertion, each leaf still in memory
+//! is submitted to the policy, and the leaves it does not retain are
+//! forgotten, so that callers don't need to issue `forget` calls themselves.
+//! The last leaf is never forgotten, as it is needed for further insertions.
+//!
+//! Policies provided here keep the latest leaves ([`KeepLast`]), the leaves
+//! selected by a predicate ([`KeepIf`]), or the leaves inserted during the
+//! latest insertion calls ([`KeepForAppends`]). A pair of policies retains
+//! the leaves retained by either of them.
+
+use super::{
+    internal::MerkleTreeIter, AppendableMerkleTreeScheme, ForgetableMerkleTreeScheme, LookupResult,
+    MerkleTreeScheme, MissingSubtree,
+};
+use crate::errors::{PrimitivesError, VerificationResult};
+use ark_std::{borrow::Borrow, boxed::Box, collections::BTreeMap, vec::Vec};
+use num_bigint::BigUint;
+
+/// The state of a leaf in memory, as seen by a [`RetentionPolicy`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LeafContext<'a, E> {
+    /// Position of the leaf
+    pub pos: u64,
+    /// The leaf
+    pub elem: &'a E,
+    /// Index of the insertion call which appended the leaf, insertion calls
+    /// being numbered from 0
+    pub appended_at: u64,
+    /// Current number of leaves of the tree
+    pub num_leaves: u64,
+    /// Current number of insertion calls
+    pub num_appends: u64,
+}
+
+/// Decide which leaves a [`RetainingMerkleTree`] keeps in memory.
+pub trait RetentionPolicy<E> {
+    /// Return whether the given leaf should stay in memory. Forgotten leaves
+    /// are never submitted again, unless they are remembered.
+    fn retain(&self, leaf: &LeafContext<E>) -> bool;
+}
+
+/// Keep the `n` latest leaves.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KeepLast(pub u64);
+
+impl<E> RetentionPolicy<E> for KeepLast {
+    fn retain(&self, leaf: &LeafContext<E>) -> bool {
+        leaf.pos.saturating_add(self.0) >= leaf.num_leaves
+    }
+}
+
+/// Keep the leaves satisfying a predicate on their position and value, e.g.
+/// those owned by a wallet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KeepIf<P>(pub P);
+
+impl<E, P: Fn(u64, &E) -> bool> RetentionPolicy<E> for KeepIf<P> {
+    fn retain(&self, leaf: &LeafContext<E>) -> bool {
+        (self.0)(leaf.pos, leaf.elem)
+    }
+}
+
+/// Keep the leaves for the given number of insertion calls after the one
+/// which appended them, i.e. a time to live measured in append order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KeepForAppends(pub u64);
+
+impl<E> RetentionPolicy<E> for KeepForAppends {
+    fn retain(&self, leaf: &LeafContext<E>) -> bool {
+        leaf.num_appends - 1 - leaf.appended_at <= self.0
+    }
+}
+
+impl<E, A: RetentionPolicy<E>, B: RetentionPolicy<E>> RetentionPolicy<E> for (A, B) {
+    fn retain(&self, leaf: &LeafContext<E>) -> bool {
+        self.0.retain(leaf) || self.1.retain(leaf)
+    }
+}
+
+/// A forgetable Merkle tree which forgets its leaves according to a
+/// [`RetentionPolicy`], see the [module documentation](self).
+#[derive(Debug, Clone)]
+pub struct RetainingMerkleTree<MT, P> {
+    tree: MT,
+    policy: P,
+    /// Index of the insertion call which appended (or remembered) each leaf
+    /// in memory
+    appended_at: BTreeMap<u64, u64>,
+    num_appends: u64,
+}
+
+impl<MT, P> RetainingMerkleTree<MT, P>
+where
+    MT: AppendableMerkleTreeScheme + ForgetableMerkleTreeScheme,
+    P: RetentionPolicy<MT::Element>,
+{
+    /// Apply a retention policy to a tree. The leaves already in memory are
+    /// considered appended by the first insertion call, and are only
+    /// submitted to the policy from then on.
+    pub fn new(tree: MT, policy: P) -> Self {
+        let appended_at = tree.iter().map(|(pos, _)| (*pos, 0)).collect();
+        Self {
+            tree,
+            policy,
+            appended_at,
+            num_appends: 0,
+        }
+    }
+
+    /// Return the retention policy.
+    pub fn policy(&self) -> &P {
+        &self.policy
+    }
+
+    /// Return the underlying tree.
+    pub fn into_tree(self) -> MT {
+        self.tree
+    }
+
+    /// Return the positions of the leaves in memory, in increasing order.
+    pub fn retained(&self) -> impl Iterator<Item = u64> + '_ {
+        self.tree.iter().map(|(pos, _)| *pos)
+    }
+
+    /// Submit every leaf in memory but the last one to the policy, and forget
+    /// those which are not retained.
+    fn collect_garbage(&mut self) {
+        let num_leaves = self.tree.num_leaves();
+        let mut appended_at = BTreeMap::new();
+        let mut forgotten = Vec::new();
+        for (pos, elem) in self.tree.iter() {
+            let leaf = LeafContext {
+                pos: *pos,
+                elem,
+                appended_at: self.appended_at.get(pos).copied().unwrap_or_default(),
+                num_leaves,
+                num_appends: self.num_appends,
+            };
+            if leaf.pos + 1 == num_leaves || self.policy.retain(&leaf) {
+                appended_at.insert(leaf.pos, leaf.appended_at);
+            } else {
+                forgotten.push(leaf.pos);
+            }
+        }
+        self.appended_at = appended_at;
+        for pos in forgotten {
+            self.tree.forget(pos);
+        }
+    }
+}
+
+impl<MT, P> MerkleTreeScheme for RetainingMerkleTree<MT, P>
+where
+    MT: AppendableMerkleTreeScheme + ForgetableMerkleTreeScheme,
+    P: RetentionPolicy<MT::Element>,
+{
+    type Element = MT::Element;
+    type Index = u64;
+    type NodeValue = MT::NodeValue;
+    type MembershipProof = MT::MembershipProof;
+    type BatchMembershipProof = MT::BatchMembershipProof;
+    type Commitment = MT::Commitment;
+
+    const ARITY: usize = MT::ARITY;
+
+    fn height(&self) -> usize {
+        self.tree.height()
+    }
+
+    fn capacity(&self) -> BigUint {
+        self.tree.capacity()
+    }
+
+    fn num_leaves(&self) -> u64 {
+        self.tree.num_leaves()
+    }
+
+    fn commitment(&self) -> Self::Commitment {
+        self.tree.commitment()
+    }
+
+    fn lookup(
+        &self,
+        pos: impl Borrow<Self::Index>,
+    ) -> LookupResult<
+        &Self::Element,
+        Self::MembershipProof,
+        (),
+        Option<MissingSubtree<Self::NodeValue>>,
+    > {
+        self.tree.lookup(pos)
+    }
+
+    fn verify(
+        root: impl Borrow<Self::NodeValue>,
+        pos: impl Borrow<Self::Index>,
+        proof: impl Borrow<Self::MembershipProof>,
+    ) -> Result<VerificationResult, PrimitivesError> {
+        MT::verify(root, pos, proof)
+    }
+
+    fn multi_lookup(
+        &self,
+        pos: impl IntoIterator<Item = impl Borrow<Self::Index>>,
+    ) -> LookupResult<Vec<Self::Element>, Self::BatchMembershipProof, ()> {
+        self.tree.multi_lookup(pos)
+    }
+
+    fn verify_batch(
+        root: impl Borrow<Self::NodeValue>,
+        pos: impl IntoIterator<Item = impl Borrow<Self::Index>>,
+        proof: impl Borrow<Self::BatchMembershipProof>,
+    ) -> Result<VerificationResult, PrimitivesError> {
+        MT::verify_batch(root, pos, proof)
+    }
+
+    fn iter(&self) -> MerkleTreeIter<Self::Element, u64, Self::NodeValue> {
+        self.tree.iter()
+    }
+
+    fn iter_with_proofs(
+        &self,
+    ) -> Box<dyn Iterator<Item = (u64, &Self::Element, Self::MembershipProof)> + '_> {
+        self.tree.iter_with_proofs()
+    }
+}
+
+impl<MT, P> AppendableMerkleTreeScheme for RetainingMerkleTree<MT, P>
+where
+    MT: AppendableMerkleTreeScheme + ForgetableMerkleTreeScheme,
+    P: RetentionPolicy<MT::Element>,
+{
+    fn push(&mut self, elem: impl Borrow<Self::Element>) -> Result<(), PrimitivesError> {
+        <Self as AppendableMerkleTreeScheme>::extend(self, [elem])
+    }
+
+    /// The policy is applied even if the tree gets full, to the leaves which
+    /// were inserted.
+    fn extend(
+        &mut self,
+        elems: impl IntoIterator<Item = impl Borrow<Self::Element>>,
+    ) -> Result<(), PrimitivesError> {
+        let start = self.tree.num_leaves();
+        let result = self.tree.extend(elems);
+        for pos in start..self.tree.num_leaves() {
+            self.appended_at.insert(pos, self.num_appends);
+        }
+        self.num_appends += 1;
+        self.collect_garbage();
+        result
+    }
+
+    fn truncate(&mut self, num_leaves: u64) -> Result<(), PrimitivesError> {
+        self.tree.truncate(num_leaves)?;
+        self.appended_at.split_off(&num_leaves);
+        Ok(())
+    }
+}
+
+impl<MT, P> ForgetableMerkleTreeScheme for RetainingMerkleTree<MT, P>
+where
+    MT: AppendableMerkleTreeScheme + ForgetableMerkleTreeScheme,
+    P: RetentionPolicy<MT::Element>,
+{
+    fn forget(&mut self, pos: u64) -> LookupResult<Self::Element, Self::MembershipProof, ()> {
+        let result = self.tree.forget(pos);
+        if matches!(result, LookupResult::Ok(..)) {
+            self.appended_at.remove(&pos);
+        }
+        result
+    }
+
+    /// A remembered leaf is considered appended by the latest insertion call.
+    fn remember(
+        &mut self,
+        pos: u64,
+        element: impl Borrow<Self::Element>,
+        proof: impl Borrow<Self::MembershipProof>,
+    ) -> Result<(), PrimitivesError> {
+        self.tree.remember(pos, element, proof)?;
+        self.appended_at
+            .insert(pos, self.num_appends.saturating_sub(1));
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod mt_tests {
+    use crate::{
+        merkle_tree::{
+            prelude::RescueMerkleTree,
+            retention::{KeepForAppends, KeepIf, KeepLast, RetainingMerkleTree},
+            *,
+        },
+        rescue::RescueParameter,
+    };
+    use ark_ed_on_bls12_377::Fq as Fq377;
+    use ark_ed_on_bls12_381::Fq as Fq381;
+    use ark_ed_on_bn254::Fq as Fq254;
+
+    #[test]
+    fn test_retaining_mt() {
+        test_retaining_mt_helper::<Fq254>();
+        test_retaining_mt_helper::<Fq377>();
+        test_retaining_mt_helper::<Fq381>();
+    }
+
+    fn test_retaining_mt_helper<F: RescueParameter>() {
+        let elems: Vec<F> = (0..20u64).map(F::from).collect();
+        let full_mt = RescueMerkleTree::<F>::from_elems(Some(3), &elems).unwrap();
+
+        // Keep the last leaves
+        let mut mt = RetainingMerkleTree::new(RescueMerkleTree::<F>::new(3), KeepLast(3));
+        mt.extend(&elems[..10]).unwrap();
+        assert_eq!(mt.retained().collect::<Vec<_>>(), vec![7, 8, 9]);
+        mt.extend(&elems[10..]).unwrap();
+        assert_eq!(mt.retained().collect::<Vec<_>>(), vec![17, 18, 19]);
+        assert_eq!(mt.commitment(), full_mt.commitment());
+        assert!(mt.lookup(16).expect_not_in_memory().is_ok());
+        let (_, proof) = mt.lookup(17).expect_ok().unwrap();
+        assert_eq!(proof, full_mt.lookup(17).expect_ok().unwrap().1);
+
+        // Keep by predicate, the last leaf always stays
+        let mut mt = RetainingMerkleTree::new(
+            RescueMerkleTree::<F>::new(3),
+            KeepIf(|pos: u64, _: &F| pos % 5 == 0),
+        );
+        mt.extend(&elems[..12]).unwrap();
+        assert_eq!(mt.retained().collect::<Vec<_>>(), vec![0, 5, 10, 11]);
+        mt.push(elems[12]).unwrap();
+        assert_eq!(mt.retained().collect::<Vec<_>>(), vec![0, 5, 10, 12]);
+
+        // Keep for a number of insertion calls, combined with the last leaves
+        let mut mt = RetainingMerkleTree::new(
+            RescueMerkleTree::<F>::new(3),
+            (KeepForAppends(1), KeepLast(1)),
+        );
+        mt.extend(&elems[..4]).unwrap();
+        assert_eq!(mt.retained().collect::<Vec<_>>(), vec![0, 1, 2, 3]);
+        mt.extend(&elems[4..6]).unwrap();
+        assert_eq!(mt.retained().collect::<Vec<_>>(), vec![0, 1, 2, 3, 4, 5]);
+        mt.push(elems[6]).unwrap();
+        assert_eq!(mt.retained().collect::<Vec<_>>(), vec![4, 5, 6]);
+        mt.push(elems[7]).unwrap();
+        assert_eq!(mt.retained().collect::<Vec<_>>(), vec![6, 7]);
+
+        // Remembered leaves are subject to the policy too
+        let prefix_mt = RescueMerkleTree::<F>::from_elems(Some(3), &elems[..8]).unwrap();
+        mt.remember(2, elems[2], prefix_mt.lookup(2).expect_ok().unwrap().1)
+            .unwrap();
+        assert!(mt.lookup(2).expect_ok().is_ok());
+        mt.push(elems[8]).unwrap();
+        assert_eq!(mt.retained().collect::<Vec<_>>(), vec![2, 7, 8]);
+        mt.push(elems[9]).unwrap();
+        assert_eq!(mt.retained().collect::<Vec<_>>(), vec![8, 9]);
+
+        // The policy is applied when the tree gets full
+        let mut mt = RetainingMerkleTree::new(RescueMerkleTree::<F>::new(2), KeepLast(2));
+        assert!(mt.extend(&elems[..10]).is_err());
+        assert_eq!(mt.retained().collect::<Vec<_>>(), vec![7, 8]);
+    }
+}
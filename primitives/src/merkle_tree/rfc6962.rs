@@ -0,0 +1,510 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+//! Implementation of a Certificate Transparency style append-only log, as
+//! specified in [RFC 6962](https://www.rfc-editor.org/rfc/rfc6962#section-2.1).
+//!
+//! Unlike the fixed-height trees of this module, the RFC 6962 tree over `n`
+//! leaves splits them into the largest power of two smaller than `n` on the
+//! left and the rest on the right, and grows with the log. Leaves are hashed
+//! as `H(0x00 || leaf)` and internal nodes as `H(0x01 || left || right)`, so
+//! that roots, audit paths and consistency proofs interoperate with standard
+//! CT clients when `H` is SHA-256, see [`CtLog`].
+//!
+//! Hashes of complete power-of-two subtrees are cached, hence appending a leaf
+//! costs an amortized constant number of hashes while proofs and roots cost
+//! a logarithmic number of hashes.
+
+use super::hasher::{HasherDigest, HasherNode};
+use crate::errors::{PrimitivesError, VerificationResult};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use ark_std::{borrow::Borrow, string::ToString, vec, vec::Vec};
+use sha2::Sha256;
+
+const LEAF_PREFIX: u8 = 0x00;
+const NODE_PREFIX: u8 = 0x01;
+
+/// A CT log with SHA-256, compatible with RFC 6962 clients.
+pub type CtLog = Rfc6962Log<Sha256>;
+
+/// An audit path proving that a leaf is included in a log of a given size.
+#[derive(Derivative, CanonicalSerialize, CanonicalDeserialize)]
+#[derivative(
+    Clone(bound = ""),
+    Debug(bound = ""),
+    Eq(bound = ""),
+    Hash(bound = ""),
+    PartialEq(bound = "")
+)]
+pub struct Rfc6962InclusionProof<H: HasherDigest> {
+    /// Index of the proven leaf
+    pub leaf_index: u64,
+    /// Size of the log the proof is for
+    pub tree_size: u64,
+    /// Audit path, from the leaf to the root
+    pub path: Vec<HasherNode<H>>,
+}
+
+/// A proof that a log of size `old_size` is a prefix of a log of size
+/// `new_size`.
+#[derive(Derivative, CanonicalSerialize, CanonicalDeserialize)]
+#[derivative(
+    Clone(bound = ""),
+    Debug(bound = ""),
+    Eq(bound = ""),
+    Hash(bound = ""),
+    PartialEq(bound = "")
+)]
+pub struct Rfc6962ConsistencyProof<H: HasherDigest> {
+    /// Size of the older log
+    pub old_size: u64,
+    /// Size of the newer log
+    pub new_size: u64,
+    /// Consistency path as specified in RFC 6962
+    pub path: Vec<HasherNode<H>>,
+}
+
+/// An RFC 6962 append-only log, see the [module documentation](self).
+#[derive(Derivative)]
+#[derivative(Clone(bound = ""), Debug(bound = ""), Default(bound = ""))]
+pub struct Rfc6962Log<H: HasherDigest> {
+    /// `levels[l][i]` is the hash of the complete subtree covering leaves
+    /// `i * 2^l .. (i + 1) * 2^l`
+    levels: Vec<Vec<HasherNode<H>>>,
+}
+
+impl<H: HasherDigest> Rfc6962Log<H> {
+    /// Initialize an empty log.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Build a log from a list of leaves.
+    pub fn from_leaves(leaves: impl IntoIterator<Item = impl AsRef<[u8]>>) -> Self {
+        let mut log = Self::new();
+        log.extend(leaves);
+        log
+    }
+
+    /// Return the number of leaves in the log.
+    pub fn num_leaves(&self) -> u64 {
+        self.levels.first().map_or(0, |leaves| leaves.len() as u64)
+    }
+
+    /// Return the hash of a leaf, i.e. `H(0x00 || leaf)`.
+    pub fn hash_leaf(leaf: impl AsRef<[u8]>) -> HasherNode<H> {
+        let mut hasher = H::new();
+        hasher.update([LEAF_PREFIX]);
+        hasher.update(leaf);
+        hasher.finalize().into()
+    }
+
+    /// Return the hash of an internal node, i.e. `H(0x01 || left || right)`.
+    pub fn hash_children(left: &HasherNode<H>, right: &HasherNode<H>) -> HasherNode<H> {
+        let mut hasher = H::new();
+        hasher.update([NODE_PREFIX]);
+        hasher.update(left.as_ref());
+        hasher.update(right.as_ref());
+        hasher.finalize().into()
+    }
+
+    /// Append a leaf to the log.
+    pub fn push(&mut self, leaf: impl AsRef<[u8]>) {
+        self.push_leaf_hash(Self::hash_leaf(leaf))
+    }
+
+    /// Append a list of leaves to the log.
+    pub fn extend(&mut self, leaves: impl IntoIterator<Item = impl AsRef<[u8]>>) {
+        for leaf in leaves {
+            self.push(leaf);
+        }
+    }
+
+    /// Append a leaf given by its hash to the log.
+    pub fn push_leaf_hash(&mut self, leaf_hash: HasherNode<H>) {
+        let mut node = leaf_hash;
+        let mut level = 0;
+        loop {
+            if self.levels.len() == level {
+                self.levels.push(Vec::new());
+            }
+            self.levels[level].push(node);
+            let len = self.levels[level].len();
+            if len % 2 == 1 {
+                break;
+            }
+            node = Self::hash_children(&self.levels[level][len - 2], &self.levels[level][len - 1]);
+            level += 1;
+        }
+    }
+
+    /// Return the root of the log, i.e. the Merkle Tree Hash of all its
+    /// leaves. The root of an empty log is `H("")`.
+    pub fn root(&self) -> HasherNode<H> {
+        self.subtree_hash(0, self.num_leaves())
+    }
+
+    /// Return the root of the log as it was when it had `size` leaves.
+    pub fn root_at(&self, size: u64) -> Result<HasherNode<H>, PrimitivesError> {
+        if size > self.num_leaves() {
+            return Err(PrimitivesError::ParameterError(
+                "Requested size is larger than the log".to_string(),
+            ));
+        }
+        Ok(self.subtree_hash(0, size))
+    }
+
+    /// Return the audit path of the `leaf_index`-th leaf in the log as it was
+    /// when it had `tree_size` leaves.
+    pub fn inclusion_proof(
+        &self,
+        leaf_index: u64,
+        tree_size: u64,
+    ) -> Result<Rfc6962InclusionProof<H>, PrimitivesError> {
+        if tree_size > self.num_leaves() || leaf_index >= tree_size {
+            return Err(PrimitivesError::ParameterError(
+                "Leaf index out of range".to_string(),
+            ));
+        }
+        let mut path = vec![];
+        self.audit_path(leaf_index, 0, tree_size, &mut path);
+        Ok(Rfc6962InclusionProof {
+            leaf_index,
+            tree_size,
+            path,
+        })
+    }
+
+    /// Return a proof that the log of size `old_size` is a prefix of the log
+    /// of size `new_size`.
+    pub fn consistency_proof(
+        &self,
+        old_size: u64,
+        new_size: u64,
+    ) -> Result<Rfc6962ConsistencyProof<H>, PrimitivesError> {
+        if new_size > self.num_leaves() || old_size > new_size {
+            return Err(PrimitivesError::ParameterError(
+                "Log sizes out of range".to_string(),
+            ));
+        }
+        let mut path = vec![];
+        if old_size > 0 && old_size < new_size {
+            self.subproof(old_size, 0, new_size, true, &mut path);
+        }
+        Ok(Rfc6962ConsistencyProof {
+            old_size,
+            new_size,
+            path,
+        })
+    }
+
+    /// Verify an audit path, following RFC 9162 section 2.1.3.2.
+    /// * `root` - root of the log of size `proof.tree_size`
+    /// * `leaf` - the leaf claimed to be at `proof.leaf_index`
+    /// * `proof` - the audit path
+    /// * `returns` - Ok(Ok(())) if the proof is accepted, Ok(Err(())) if not.
+    ///   Err() if the proof is not well structured, E.g. its leaf index is out
+    ///   of range.
+    pub fn verify_inclusion(
+        root: impl Borrow<HasherNode<H>>,
+        leaf: impl AsRef<[u8]>,
+        proof: &Rfc6962InclusionProof<H>,
+    ) -> Result<VerificationResult, PrimitivesError> {
+        Self::verify_inclusion_of_hash(root, Self::hash_leaf(leaf), proof)
+    }
+
+    /// Same as [`Self::verify_inclusion`], given the leaf hash.
+    pub fn verify_inclusion_of_hash(
+        root: impl Borrow<HasherNode<H>>,
+        leaf_hash: HasherNode<H>,
+        proof: &Rfc6962InclusionProof<H>,
+    ) -> Result<VerificationResult, PrimitivesError> {
+        if proof.leaf_index >= proof.tree_size {
+            return Err(PrimitivesError::ParameterError(
+                "Leaf index out of range".to_string(),
+            ));
+        }
+        let (mut fn_, mut sn) = (proof.leaf_index, proof.tree_size - 1);
+        let mut r = leaf_hash;
+        for p in proof.path.iter() {
+            if sn == 0 {
+                return Ok(Err(()));
+            }
+            if fn_ & 1 == 1 || fn_ == sn {
+                r = Self::hash_children(p, &r);
+                while fn_ & 1 == 0 && fn_ != 0 {
+                    fn_ >>= 1;
+                    sn >>= 1;
+                }
+            } else {
+                r = Self::hash_children(&r, p);
+            }
+            fn_ >>= 1;
+            sn >>= 1;
+        }
+        if sn == 0 && r == *root.borrow() {
+            Ok(Ok(()))
+        } else {
+            Ok(Err(()))
+        }
+    }
+
+    /// Verify a consistency proof, following RFC 9162 section 2.1.4.2.
+    /// * `old_root` - root of the log of size `proof.old_size`
+    /// * `new_root` - root of the log of size `proof.new_size`
+    /// * `proof` - the consistency proof
+    /// * `returns` - Ok(Ok(())) if the proof is accepted, Ok(Err(())) if not.
+    ///   Err() if the proof is not well structured, E.g. its old size is larger
+    ///   than its new size.
+    pub fn verify_consistency(
+        old_root: impl Borrow<HasherNode<H>>,
+        new_root: impl Borrow<HasherNode<H>>,
+        proof: &Rfc6962ConsistencyProof<H>,
+    ) -> Result<VerificationResult, PrimitivesError> {
+        let (old_root, new_root) = (old_root.borrow(), new_root.borrow());
+        if proof.old_size > proof.new_size {
+            return Err(PrimitivesError::ParameterError(
+                "Old size is larger than new size".to_string(),
+            ));
+        }
+        // Any log is consistent with the empty log
+        if proof.old_size == 0 {
+            return Ok(if proof.path.is_empty() {
+                Ok(())
+            } else {
+                Err(())
+            });
+        }
+        if proof.old_size == proof.new_size {
+            return Ok(if proof.path.is_empty() && old_root == new_root {
+                Ok(())
+            } else {
+                Err(())
+            });
+        }
+
+        let mut path = proof.path.iter();
+        let (mut fn_, mut sn) = (proof.old_size - 1, proof.new_size - 1);
+        // The old root itself is omitted from the proof when the old log is a
+        // complete subtree
+        let first = if proof.old_size.is_power_of_two() {
+            *old_root
+        } else {
+            match path.next() {
+                Some(node) => *node,
+                None => return Ok(Err(())),
+            }
+        };
+        while fn_ & 1 == 1 {
+            fn_ >>= 1;
+            sn >>= 1;
+        }
+        let (mut fr, mut sr) = (first, first);
+        for c in path {
+            if sn == 0 {
+                return Ok(Err(()));
+            }
+            if fn_ & 1 == 1 || fn_ == sn {
+                fr = Self::hash_children(c, &fr);
+                sr = Self::hash_children(c, &sr);
+                while fn_ & 1 == 0 && fn_ != 0 {
+                    fn_ >>= 1;
+                    sn >>= 1;
+                }
+            } else {
+                sr = Self::hash_children(&sr, c);
+            }
+            fn_ >>= 1;
+            sn >>= 1;
+        }
+        if sn == 0 && fr == *old_root && sr == *new_root {
+            Ok(Ok(()))
+        } else {
+            Ok(Err(()))
+        }
+    }
+
+    /// Merkle Tree Hash of the leaves `start .. start + size`, where the range
+    /// is either empty at the origin or the right part of an RFC 6962 split.
+    fn subtree_hash(&self, start: u64, size: u64) -> HasherNode<H> {
+        if size == 0 {
+            return H::new().finalize().into();
+        }
+        if size.is_power_of_two() {
+            let level = size.trailing_zeros() as usize;
+            return self.levels[level][(start >> level) as usize];
+        }
+        let k = split_point(size);
+        Self::hash_children(
+            &self.subtree_hash(start, k),
+            &self.subtree_hash(start + k, size - k),
+        )
+    }
+
+    /// `PATH(m, D[start .. start + size])` of RFC 6962 section 2.1.1.
+    fn audit_path(&self, m: u64, start: u64, size: u64, path: &mut Vec<HasherNode<H>>) {
+        if size == 1 {
+            return;
+        }
+        let k = split_point(size);
+        if m < k {
+            self.audit_path(m, start, k, path);
+            path.push(self.subtree_hash(start + k, size - k));
+        } else {
+            self.audit_path(m - k, start + k, size - k, path);
+            path.push(self.subtree_hash(start, k));
+        }
+    }
+
+    /// `SUBPROOF(m, D[start .. start + size], b)` of RFC 6962 section 2.1.2.
+    fn subproof(
+        &self,
+        m: u64,
+        start: u64,
+        size: u64,
+        complete: bool,
+        path: &mut Vec<HasherNode<H>>,
+    ) {
+        if m == size {
+            if !complete {
+                path.push(self.subtree_hash(start, size));
+            }
+            return;
+        }
+        let k = split_point(size);
+        if m <= k {
+            self.subproof(m, start, k, complete, path);
+            path.push(self.subtree_hash(start + k, size - k));
+        } else {
+            self.subproof(m - k, start + k, size - k, false, path);
+            path.push(self.subtree_hash(start, k));
+        }
+    }
+}
+
+/// Largest power of two smaller than `size`, for `size > 1`.
+fn split_point(size: u64) -> u64 {
+    1 << (63 - (size - 1).leading_zeros())
+}
+
+#[cfg(test)]
+mod mt_tests {
+    use crate::merkle_tree::{
+        hasher::HasherNode,
+        rfc6962::{CtLog, Rfc6962Log},
+    };
+    use ark_std::{format, string::String, vec, vec::Vec};
+    use sha2::{Digest, Sha256};
+    use sha3::Keccak256;
+
+    fn naive_root(leaves: &[Vec<u8>]) -> HasherNode<Sha256> {
+        match leaves.len() {
+            0 => Sha256::digest(b"").into(),
+            1 => CtLog::hash_leaf(&leaves[0]),
+            n => {
+                let k = n.next_power_of_two() / 2;
+                CtLog::hash_children(&naive_root(&leaves[..k]), &naive_root(&leaves[k..]))
+            },
+        }
+    }
+
+    #[test]
+    fn test_rfc6962_log() {
+        let leaves: Vec<Vec<u8>> = (0..20u8).map(|i| vec![i; i as usize]).collect();
+        let mut log = CtLog::new();
+        assert_eq!(log.root(), naive_root(&[]));
+        for (i, leaf) in leaves.iter().enumerate() {
+            log.push(leaf);
+            assert_eq!(log.root(), naive_root(&leaves[..=i]));
+        }
+        assert_eq!(log.root(), CtLog::from_leaves(&leaves).root());
+        assert!(log.root_at(21).is_err());
+
+        for size in 1..=20u64 {
+            let root = log.root_at(size).unwrap();
+            assert_eq!(root, naive_root(&leaves[..size as usize]));
+            for idx in 0..size {
+                let proof = log.inclusion_proof(idx, size).unwrap();
+                let leaf = &leaves[idx as usize];
+                assert!(CtLog::verify_inclusion(root, leaf, &proof).unwrap().is_ok());
+                assert!(CtLog::verify_inclusion(root, [42u8], &proof)
+                    .unwrap()
+                    .is_err());
+                let mut bad_proof = proof.clone();
+                bad_proof.leaf_index = (idx + 1) % size;
+                if bad_proof.leaf_index != idx {
+                    assert!(CtLog::verify_inclusion(root, leaf, &bad_proof)
+                        .unwrap()
+                        .is_err());
+                }
+            }
+            assert!(log.inclusion_proof(size, size).is_err());
+        }
+
+        for new_size in 0..=20u64 {
+            let new_root = log.root_at(new_size).unwrap();
+            for old_size in 0..=new_size {
+                let old_root = log.root_at(old_size).unwrap();
+                let proof = log.consistency_proof(old_size, new_size).unwrap();
+                assert!(CtLog::verify_consistency(old_root, new_root, &proof)
+                    .unwrap()
+                    .is_ok());
+                if old_size > 0 && old_size < new_size {
+                    let wrong_root = log.root_at(old_size - 1).unwrap();
+                    assert!(CtLog::verify_consistency(wrong_root, new_root, &proof)
+                        .unwrap()
+                        .is_err());
+                    assert!(CtLog::verify_consistency(old_root, old_root, &proof)
+                        .unwrap()
+                        .is_err());
+                }
+            }
+        }
+        assert!(log.consistency_proof(5, 4).is_err());
+        assert!(log.consistency_proof(5, 21).is_err());
+
+        // Any RustCrypto hash function works
+        let log = Rfc6962Log::<Keccak256>::from_leaves(&leaves);
+        let proof = log.inclusion_proof(7, 20).unwrap();
+        assert!(
+            Rfc6962Log::<Keccak256>::verify_inclusion(log.root(), &leaves[7], &proof)
+                .unwrap()
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn test_rfc6962_known_answer() {
+        // Test vectors from the certificate-transparency-go test suite
+        let leaves: [&[u8]; 8] = [
+            b"",
+            b"\x00",
+            b"\x10",
+            b"\x20\x21",
+            b"\x30\x31",
+            b"\x40\x41\x42\x43",
+            b"\x50\x51\x52\x53\x54\x55\x56\x57",
+            b"\x60\x61\x62\x63\x64\x65\x66\x67\x68\x69\x6a\x6b\x6c\x6d\x6e\x6f",
+        ];
+        let log = CtLog::from_leaves(leaves);
+        assert_eq!(
+            hex(&log.root()),
+            "5dc9da79a70659a9ad559cb701ded9a2ab9d823aad2f4960cfe370eff4604328"
+        );
+        assert_eq!(
+            hex(&log.root_at(1).unwrap()),
+            "6e340b9cffb37a989ca544e6bb780a2c78901d3fb33738768511a30617afa01d"
+        );
+        assert_eq!(
+            hex(&CtLog::new().root()),
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+        );
+    }
+
+    fn hex(node: &HasherNode<Sha256>) -> String {
+        node.as_ref().iter().map(|b| format!("{b:02x}")).collect()
+    }
+}
@@ -0,0 +1,1066 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+//! Storage backends for append-only Merkle trees.
+//!
+//! A [`MerkleTree`](super::append_only::MerkleTree) holds all its nodes in
+//! memory with the default [`MemoryStore`]. Given another [`MerkleStore`], see
+//! [`MerkleTree::with_store`](super::append_only::MerkleTree::with_store), it
+//! writes its nodes and elements through the store instead, so that trees which
+//! don't fit in memory can be kept on disk or in a key-value database. Only its
+//! frontier stays in memory, as in a
+//! [`LightWeightMerkleTree`](super::light_weight::LightWeightMerkleTree), along
+//! with the elements read from the store by lookups, until they are
+//! [evicted](super::append_only::MerkleTree::evict).
+//!
+//! A storage-backed tree serves the lookups of every leaf by reading the
+//! missing nodes from its store, and can be truncated. Its proofs are the
+//! usual [`MerkleProof`](super::internal::MerkleProof)s: both kinds of trees
+//! have the same commitments and proofs for the same elements. Iterations only
+//! visit the leaves in memory.
+//!
+//! With the `async` feature, an `AsyncStoredMerkleTree` works the same way
+//! over an `AsyncMerkleStore`, e.g. a networked key-value store, without
+//! blocking executor threads on storage accesses. Its methods are
+//! asynchronous, so it only has inherent methods, not the Merkle tree traits.
+
+use super::{
+    internal::{MerkleNode, MerklePath},
+    Element, Index, NodeValue,
+};
+use crate::errors::PrimitivesError;
+use alloc::sync::Arc;
+use ark_std::{
+    boxed::Box, collections::BTreeMap, fmt, hash::Hasher, string::ToString, vec, vec::Vec,
+};
+use once_cell::race::OnceBox;
+use typenum::Unsigned;
+
+/// Position of a node in a tree: its height, `0` for leaves, and its index
+/// among the nodes of that height, from left to right.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NodePosition {
+    /// Height of the node
+    pub height: usize,
+    /// Index of the node among the nodes of the same height
+    pub index: u64,
+}
+
+/// A storage backend for the nodes and elements of a
+/// [`MerkleTree`](super::append_only::MerkleTree).
+pub trait MerkleStore<E: Element, T: NodeValue> {
+    /// Whether the tree is entirely kept in memory, in which case it never
+    /// accesses its store.
+    const IN_MEMORY: bool = false;
+
+    /// Return the value of the node at the given position, or `None` if it has
+    /// never been written.
+    fn get_node(&self, pos: NodePosition) -> Result<Option<T>, PrimitivesError>;
+
+    /// Write the value of the node at the given position.
+    fn put_node(&mut self, pos: NodePosition, value: T) -> Result<(), PrimitivesError>;
+
+    /// Return the element of the leaf at the given index, or `None` if it has
+    /// never been written.
+    fn get_elem(&self, pos: u64) -> Result<Option<E>, PrimitivesError>;
+
+    /// Write the element of the leaf at the given index.
+    fn put_elem(&mut self, pos: u64, elem: &E) -> Result<(), PrimitivesError>;
+
+    /// Remove the elements at index `num_elems` or beyond. Nodes are left as
+    /// they are, and overwritten by later insertions.
+    fn truncate(&mut self, num_elems: u64) -> Result<(), PrimitivesError>;
+}
+
+/// The default store of a [`MerkleTree`](super::append_only::MerkleTree), which
+/// keeps the whole tree in memory. It holds nothing itself, as such trees never
+/// access their store.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct MemoryStore;
+
+impl<E: Element, T: NodeValue> MerkleStore<E, T> for MemoryStore {
+    const IN_MEMORY: bool = true;
+
+    fn get_node(&self, _pos: NodePosition) -> Result<Option<T>, PrimitivesError> {
+        Ok(None)
+    }
+
+    fn put_node(&mut self, _pos: NodePosition, _value: T) -> Result<(), PrimitivesError> {
+        Ok(())
+    }
+
+    fn get_elem(&self, _pos: u64) -> Result<Option<E>, PrimitivesError> {
+        Ok(None)
+    }
+
+    fn put_elem(&mut self, _pos: u64, _elem: &E) -> Result<(), PrimitivesError> {
+        Ok(())
+    }
+
+    fn truncate(&mut self, _num_elems: u64) -> Result<(), PrimitivesError> {
+        Ok(())
+    }
+}
+
+/// A [`MerkleStore`] keeping the nodes and elements in maps, e.g. for tests or
+/// as a write-through cache in front of another store.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MapStore<E, T> {
+    nodes: BTreeMap<NodePosition, T>,
+    elems: BTreeMap<u64, E>,
+}
+
+impl<E, T> Default for MapStore<E, T> {
+    fn default() -> Self {
+        Self {
+            nodes: BTreeMap::new(),
+            elems: BTreeMap::new(),
+        }
+    }
+}
+
+impl<E: Element, T: NodeValue> MerkleStore<E, T> for MapStore<E, T> {
+    fn get_node(&self, pos: NodePosition) -> Result<Option<T>, PrimitivesError> {
+        Ok(self.nodes.get(&pos).copied())
+    }
+
+    fn put_node(&mut self, pos: NodePosition, value: T) -> Result<(), PrimitivesError> {
+        self.nodes.insert(pos, value);
+        Ok(())
+    }
+
+    fn get_elem(&self, pos: u64) -> Result<Option<E>, PrimitivesError> {
+        Ok(self.elems.get(&pos).cloned())
+    }
+
+    fn put_elem(&mut self, pos: u64, elem: &E) -> Result<(), PrimitivesError> {
+        self.elems.insert(pos, elem.clone());
+        Ok(())
+    }
+
+    fn truncate(&mut self, num_elems: u64) -> Result<(), PrimitivesError> {
+        self.elems.split_off(&num_elems);
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+pub use file_store::FileStore;
+
+#[cfg(feature = "std")]
+mod file_store {
+    use super::{MerkleStore, NodePosition};
+    use crate::{
+        errors::PrimitivesError,
+        merkle_tree::{Element, NodeValue},
+    };
+    use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+    use ark_std::{format, marker::PhantomData, vec, vec::Vec};
+    use std::{
+        fs::{File, OpenOptions},
+        io::{Read, Seek, SeekFrom, Write},
+        path::{Path, PathBuf},
+    };
+
+    /// A [`MerkleStore`] backed by files in a directory.
+    ///
+    /// The nodes of each height are stored in their own file as fixed-size
+    /// records, so all node values must have the same serialized size.
+    /// Elements are appended to a data file, alongside an index file recording
+    /// where each of them starts and ends. Elements can therefore only be
+    /// written in order, which is what an append-only tree does.
+    ///
+    /// Writes are not atomic: a crash in the middle of an insertion may leave
+    /// the store inconsistent with the commitment of the tree. Trees write the
+    /// element of a leaf after its nodes, so that the element is only counted
+    /// once the nodes are written.
+    #[derive(Debug)]
+    pub struct FileStore<E, T> {
+        dir: PathBuf,
+        node_files: Vec<File>,
+        elem_file: File,
+        index_file: File,
+        num_elems: u64,
+        node_size: u64,
+        _phantom: PhantomData<(E, T)>,
+    }
+
+    /// Size of an entry of the index file: the start and end offsets of an
+    /// element in the data file.
+    const INDEX_ENTRY_SIZE: u64 = 16;
+
+    fn io_error(e: std::io::Error) -> PrimitivesError {
+        PrimitivesError::InternalError(format!("Merkle store I/O error: {e}"))
+    }
+
+    fn open_file(path: &Path) -> Result<File, PrimitivesError> {
+        OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .open(path)
+            .map_err(io_error)
+    }
+
+    fn read_at(mut file: &File, offset: u64, len: u64) -> Result<Vec<u8>, PrimitivesError> {
+        let mut buf = vec![0u8; len as usize];
+        file.seek(SeekFrom::Start(offset)).map_err(io_error)?;
+        file.read_exact(&mut buf).map_err(io_error)?;
+        Ok(buf)
+    }
+
+    fn write_at(mut file: &File, offset: u64, data: &[u8]) -> Result<(), PrimitivesError> {
+        file.seek(SeekFrom::Start(offset)).map_err(io_error)?;
+        file.write_all(data).map_err(io_error)
+    }
+
+    fn file_len(file: &File) -> Result<u64, PrimitivesError> {
+        Ok(file.metadata().map_err(io_error)?.len())
+    }
+
+    impl<E: Element, T: NodeValue> FileStore<E, T> {
+        /// Open the store in the given directory, creating it if needed.
+        /// * `height` - height of the tree stored in this directory
+        pub fn open(dir: impl AsRef<Path>, height: usize) -> Result<Self, PrimitivesError> {
+            let dir = dir.as_ref().to_path_buf();
+            std::fs::create_dir_all(&dir).map_err(io_error)?;
+            let node_files = (0..=height)
+                .map(|height| open_file(&dir.join(format!("nodes_{height}"))))
+                .collect::<Result<Vec<_>, _>>()?;
+            let elem_file = open_file(&dir.join("elems"))?;
+            let index_file = open_file(&dir.join("elems_index"))?;
+            let num_elems = file_len(&index_file)? / INDEX_ENTRY_SIZE;
+            Ok(Self {
+                dir,
+                node_files,
+                elem_file,
+                index_file,
+                num_elems,
+                node_size: T::default().compressed_size() as u64,
+                _phantom: PhantomData,
+            })
+        }
+
+        /// Return the directory of this store.
+        pub fn dir(&self) -> &Path {
+            &self.dir
+        }
+
+        /// Return the number of elements in this store.
+        pub fn num_elems(&self) -> u64 {
+            self.num_elems
+        }
+
+        fn node_file(&self, height: usize) -> Result<&File, PrimitivesError> {
+            self.node_files.get(height).ok_or_else(|| {
+                PrimitivesError::ParameterError(format!(
+                    "Node height {height} exceeds the height of the store"
+                ))
+            })
+        }
+
+        // Return the start and end offsets of the element at index `pos` in the
+        // data file.
+        fn elem_range(&self, pos: u64) -> Result<(u64, u64), PrimitivesError> {
+            let entry = read_at(&self.index_file, pos * INDEX_ENTRY_SIZE, INDEX_ENTRY_SIZE)?;
+            let start = u64::from_le_bytes(entry[..8].try_into().unwrap());
+            let end = u64::from_le_bytes(entry[8..].try_into().unwrap());
+            Ok((start, end))
+        }
+    }
+
+    impl<E: Element, T: NodeValue> MerkleStore<E, T> for FileStore<E, T> {
+        fn get_node(&self, pos: NodePosition) -> Result<Option<T>, PrimitivesError> {
+            let file = self.node_file(pos.height)?;
+            let offset = pos.index * self.node_size;
+            if offset + self.node_size > file_len(file)? {
+                return Ok(None);
+            }
+            let bytes = read_at(file, offset, self.node_size)?;
+            Ok(Some(T::deserialize_compressed(&bytes[..])?))
+        }
+
+        fn put_node(&mut self, pos: NodePosition, value: T) -> Result<(), PrimitivesError> {
+            let mut bytes = Vec::new();
+            value.serialize_compressed(&mut bytes)?;
+            if bytes.len() as u64 != self.node_size {
+                return Err(PrimitivesError::ParameterError(
+                    "Node values must have a fixed serialized size".into(),
+                ));
+            }
+            write_at(
+                self.node_file(pos.height)?,
+                pos.index * self.node_size,
+                &bytes,
+            )
+        }
+
+        fn get_elem(&self, pos: u64) -> Result<Option<E>, PrimitivesError> {
+            if pos >= self.num_elems {
+                return Ok(None);
+            }
+            let (start, end) = self.elem_range(pos)?;
+            let bytes = read_at(&self.elem_file, start, end - start)?;
+            Ok(Some(E::deserialize_compressed(&bytes[..])?))
+        }
+
+        fn put_elem(&mut self, pos: u64, elem: &E) -> Result<(), PrimitivesError> {
+            if pos != self.num_elems {
+                return Err(PrimitivesError::ParameterError(
+                    "Elements of a file store can only be appended".into(),
+                ));
+            }
+            let mut bytes = Vec::new();
+            elem.serialize_compressed(&mut bytes)?;
+            let start = file_len(&self.elem_file)?;
+            let end = start + bytes.len() as u64;
+            write_at(&self.elem_file, start, &bytes)?;
+            let mut entry = start.to_le_bytes().to_vec();
+            entry.extend_from_slice(&end.to_le_bytes());
+            write_at(&self.index_file, pos * INDEX_ENTRY_SIZE, &entry)?;
+            self.num_elems += 1;
+            Ok(())
+        }
+
+        fn truncate(&mut self, num_elems: u64) -> Result<(), PrimitivesError> {
+            if num_elems >= self.num_elems {
+                return Ok(());
+            }
+            let (start, _) = self.elem_range(num_elems)?;
+            self.index_file
+                .set_len(num_elems * INDEX_ENTRY_SIZE)
+                .map_err(io_error)?;
+            self.num_elems = num_elems;
+            self.elem_file.set_len(start).map_err(io_error)
+        }
+    }
+}
+
+/// Number of elements per page of an [`ElemCache`].
+const PAGE_SIZE: u64 = 1024;
+
+/// Elements read from the store of a
+/// [`MerkleTree`](super::append_only::MerkleTree), so that its lookups can
+/// return references to them. Pages of elements are only allocated once one of
+/// their elements is read.
+pub(crate) struct ElemCache<E> {
+    pages: Vec<OnceBox<Vec<OnceBox<E>>>>,
+}
+
+impl<E> ElemCache<E> {
+    /// Make room for the elements of a tree with `num_leaves` leaves.
+    pub(crate) fn reserve(&mut self, num_leaves: u64) {
+        let num_pages = ((num_leaves + PAGE_SIZE - 1) / PAGE_SIZE) as usize;
+        if num_pages > self.pages.len() {
+            self.pages.resize_with(num_pages, OnceBox::new);
+        }
+    }
+
+    /// Drop all the cached elements.
+    pub(crate) fn clear(&mut self) {
+        self.pages.clear();
+    }
+
+    /// Return the cached element at index `pos`, reading it with `read` first
+    /// if needed.
+    pub(crate) fn get_or_try_init(
+        &self,
+        pos: u64,
+        read: impl FnOnce() -> Result<E, PrimitivesError>,
+    ) -> Result<&E, PrimitivesError> {
+        let page = self
+            .pages
+            .get((pos / PAGE_SIZE) as usize)
+            .ok_or_else(|| {
+                PrimitivesError::InternalError("Element beyond the cached leaves".to_string())
+            })?
+            .get_or_init(|| Box::new((0..PAGE_SIZE).map(|_| OnceBox::new()).collect()));
+        page[(pos % PAGE_SIZE) as usize].get_or_try_init(|| read().map(Box::new))
+    }
+}
+
+impl<E> Default for ElemCache<E> {
+    fn default() -> Self {
+        Self { pages: Vec::new() }
+    }
+}
+
+// The cache is not part of the state of a tree: clones start empty, and it is
+// ignored by comparisons.
+impl<E> Clone for ElemCache<E> {
+    fn clone(&self) -> Self {
+        let mut cache = Self::default();
+        cache.pages.resize_with(self.pages.len(), OnceBox::new);
+        cache
+    }
+}
+
+impl<E> fmt::Debug for ElemCache<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ElemCache")
+            .field("num_pages", &self.pages.len())
+            .finish()
+    }
+}
+
+impl<E> PartialEq for ElemCache<E> {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl<E> Eq for ElemCache<E> {}
+
+impl<E> ark_std::hash::Hash for ElemCache<E> {
+    fn hash<H: Hasher>(&self, _state: &mut H) {}
+}
+
+/// Read from `store` the membership proof of the leaf at index `index`, with
+/// element `elem`, of a tree of height `height` with `num_leaves` leaves.
+/// * `pos` - position of the leaf, as an index of the tree
+pub(crate) fn read_path<E, I, T, Arity, S>(
+    store: &S,
+    height: usize,
+    num_leaves: u64,
+    pos: I,
+    index: u64,
+    elem: E,
+) -> Result<MerklePath<E, I, T>, PrimitivesError>
+where
+    E: Element,
+    I: Index,
+    T: NodeValue,
+    Arity: Unsigned,
+    S: MerkleStore<E, T>,
+{
+    let mut proof = vec![MerkleNode::Leaf {
+        value: read_node(store, 0, index)?,
+        pos,
+        elem,
+    }];
+    let mut index = index;
+    for height in 1..=height {
+        let first_child = index - index % Arity::to_u64();
+        let children = (first_child..first_child + Arity::to_u64())
+            .map(|child| {
+                Ok(Arc::new(
+                    if is_empty_subtree::<Arity>(num_leaves, height - 1, child) {
+                        MerkleNode::Empty
+                    } else {
+                        MerkleNode::ForgettenSubtree {
+                            value: read_node(store, height - 1, child)?,
+                        }
+                    },
+                ))
+            })
+            .collect::<Result<Vec<_>, PrimitivesError>>()?;
+        proof.push(MerkleNode::Branch {
+            value: T::default(),
+            children,
+        });
+        index /= Arity::to_u64();
+    }
+    Ok(proof)
+}
+
+/// Write to `store` the nodes on the path of the leaf at index `index`, read
+/// from its membership proof, and the root of the tree.
+pub(crate) fn write_path<E, I, T, Arity, S>(
+    store: &mut S,
+    index: u64,
+    proof: &[MerkleNode<E, I, T>],
+    root: T,
+) -> Result<(), PrimitivesError>
+where
+    E: Element,
+    I: Index,
+    T: NodeValue,
+    Arity: Unsigned,
+    S: MerkleStore<E, T>,
+{
+    let mut index = index;
+    // The branch at height `height + 1` of the proof holds the value of the
+    // node on the path at height `height` among its children.
+    for (height, branch) in proof.iter().skip(1).enumerate() {
+        let value = match branch {
+            MerkleNode::Branch { children, .. } => {
+                children[(index % Arity::to_u64()) as usize].value()
+            },
+            _ => {
+                return Err(PrimitivesError::InternalError(
+                    "Malformed membership proof".to_string(),
+                ))
+            },
+        };
+        store.put_node(NodePosition { height, index }, value)?;
+        index /= Arity::to_u64();
+    }
+    store.put_node(
+        NodePosition {
+            height: proof.len() - 1,
+            index: 0,
+        },
+        root,
+    )
+}
+
+/// Read a node which is known to be non-empty.
+pub(crate) fn read_node<E: Element, T: NodeValue, S: MerkleStore<E, T>>(
+    store: &S,
+    height: usize,
+    index: u64,
+) -> Result<T, PrimitivesError> {
+    store
+        .get_node(NodePosition { height, index })?
+        .ok_or_else(missing_data)
+}
+
+pub(crate) fn missing_data() -> PrimitivesError {
+    PrimitivesError::InconsistentStructureError(
+        "Merkle store is missing data of the tree".to_string(),
+    )
+}
+
+/// Return whether the subtree at the given position of a tree with
+/// `num_leaves` leaves is empty.
+fn is_empty_subtree<Arity: Unsigned>(num_leaves: u64, height: usize, index: u64) -> bool {
+    match num_traits::checked_pow(Arity::to_u64(), height) {
+        Some(capacity) => match index.checked_mul(capacity) {
+            Some(first_leaf) => first_leaf >= num_leaves,
+            None => true,
+        },
+        None => index > 0 || num_leaves == 0,
+    }
+}
+
+#[cfg(feature = "async")]
+pub use async_store::{AsyncMerkleStore, AsyncStoredMerkleTree};
+
+#[cfg(feature = "async")]
+mod async_store {
+    use super::{is_empty_subtree, missing_data, MapStore, MerkleStore, NodePosition};
+    use crate::{
+        errors::{PrimitivesError, VerificationResult},
+        merkle_tree::{
+            internal::{MerkleNode, MerkleProof, MerkleTreeCommitment},
+            DigestAlgorithm, Element, LookupResult, MerkleCommitment, NodeValue,
+        },
+    };
+    use alloc::sync::Arc;
+    use ark_std::{
+        borrow::Borrow, boxed::Box, marker::PhantomData, string::ToString, vec, vec::Vec,
+    };
+    use async_trait::async_trait;
+    use typenum::Unsigned;
+
+    /// An asynchronous storage backend for the nodes and elements of an
+    /// [`AsyncStoredMerkleTree`], e.g. a networked key-value store. It mirrors
+    /// [`MerkleStore`].
+    #[async_trait]
+    pub trait AsyncMerkleStore<E, T>: Send + Sync
+    where
+        E: Element + Send + Sync,
+        T: NodeValue + Send + Sync,
+    {
+        /// Return the value of the node at the given position, or `None` if it
+        /// has never been written.
+        async fn get_node(&self, pos: NodePosition) -> Result<Option<T>, PrimitivesError>;
+
+        /// Write the value of the node at the given position.
+        async fn put_node(&mut self, pos: NodePosition, value: T) -> Result<(), PrimitivesError>;
+
+        /// Return the element of the leaf at the given index, or `None` if it
+        /// has never been written.
+        async fn get_elem(&self, pos: u64) -> Result<Option<E>, PrimitivesError>;
+
+        /// Write the element of the leaf at the given index.
+        async fn put_elem(&mut self, pos: u64, elem: &E) -> Result<(), PrimitivesError>;
+    }
+
+    #[async_trait]
+    impl<E, T> AsyncMerkleStore<E, T> for MapStore<E, T>
+    where
+        E: Element + Send + Sync,
+        T: NodeValue + Send + Sync,
+    {
+        async fn get_node(&self, pos: NodePosition) -> Result<Option<T>, PrimitivesError> {
+            MerkleStore::get_node(self, pos)
+        }
+
+        async fn put_node(&mut self, pos: NodePosition, value: T) -> Result<(), PrimitivesError> {
+            MerkleStore::put_node(self, pos, value)
+        }
+
+        async fn get_elem(&self, pos: u64) -> Result<Option<E>, PrimitivesError> {
+            MerkleStore::get_elem(self, pos)
+        }
+
+        async fn put_elem(&mut self, pos: u64, elem: &E) -> Result<(), PrimitivesError> {
+            MerkleStore::put_elem(self, pos, elem)
+        }
+    }
+
+    /// The asynchronous counterpart of a storage-backed
+    /// [`MerkleTree`](crate::merkle_tree::append_only::MerkleTree), whose nodes
+    /// and elements live in an [`AsyncMerkleStore`]. Reads and writes to the
+    /// store are awaited, so that serving proofs doesn't block the
+    /// executor; hashing is still performed inline. Trees built on either
+    /// kind of store have the same layout and the same commitments.
+    ///
+    /// Unlike its synchronous counterpart, the leaves of this tree can also
+    /// be updated, provided the store supports overwriting elements.
+    #[derive(Debug)]
+    pub struct AsyncStoredMerkleTree<E, H, Arity, T, S>
+    where
+        E: Element + Send + Sync,
+        H: DigestAlgorithm<E, u64, T>,
+        Arity: Unsigned,
+        T: NodeValue + Send + Sync,
+        S: AsyncMerkleStore<E, T>,
+    {
+        store: S,
+        height: usize,
+        num_leaves: u64,
+        _phantom: PhantomData<(E, H, Arity, T)>,
+    }
+
+    impl<E, H, Arity, T, S> AsyncStoredMerkleTree<E, H, Arity, T, S>
+    where
+        E: Element + Send + Sync,
+        H: DigestAlgorithm<E, u64, T>,
+        Arity: Unsigned,
+        T: NodeValue + Send + Sync,
+        S: AsyncMerkleStore<E, T>,
+    {
+        /// Initialize an empty Merkle tree of the given height on top of an
+        /// empty store.
+        pub fn new(store: S, height: usize) -> Self {
+            Self {
+                store,
+                height,
+                num_leaves: 0,
+                _phantom: PhantomData,
+            }
+        }
+
+        /// Reopen a tree from a store holding its content.
+        /// * `commitment` - commitment of the stored tree, which is checked
+        ///   against the content of the store
+        /// * `returns` - the reopened tree, or `Err()` if the store doesn't
+        ///   match the commitment
+        pub async fn open(
+            store: S,
+            commitment: &MerkleTreeCommitment<T>,
+        ) -> Result<Self, PrimitivesError> {
+            let mt = Self {
+                store,
+                height: commitment.height(),
+                num_leaves: commitment.size(),
+                _phantom: PhantomData,
+            };
+            if mt.root().await? != commitment.digest() {
+                return Err(PrimitivesError::ParameterError(
+                    "Store content doesn't match the commitment".to_string(),
+                ));
+            }
+            Ok(mt)
+        }
+
+        /// Return the height of the tree.
+        pub fn height(&self) -> usize {
+            self.height
+        }
+
+        /// Return the number of leaves of the tree.
+        pub fn num_leaves(&self) -> u64 {
+            self.num_leaves
+        }
+
+        /// Return the underlying store.
+        pub fn store(&self) -> &S {
+            &self.store
+        }
+
+        /// Consume the tree and return the underlying store.
+        pub fn into_store(self) -> S {
+            self.store
+        }
+
+        /// Return the commitment of the tree.
+        pub async fn commitment(&self) -> Result<MerkleTreeCommitment<T>, PrimitivesError> {
+            Ok(MerkleTreeCommitment::new(
+                self.root().await?,
+                self.height,
+                self.num_leaves,
+            ))
+        }
+
+        /// Insert a new value at the leftmost available slot.
+        /// * `returns` - Ok(()) if successful, Err() if the tree is full or if
+        ///   the store fails, in which case the tree is left untouched but the
+        ///   store may contain partially written data.
+        pub async fn push(&mut self, elem: impl Borrow<E>) -> Result<(), PrimitivesError> {
+            let pos = self.num_leaves;
+            if matches!(num_traits::checked_pow(Arity::to_u64(), self.height), Some(cap) if pos >= cap)
+            {
+                return Err(PrimitivesError::ParameterError(
+                    "Exceed merkle tree capacity".to_string(),
+                ));
+            }
+            self.write_leaf(pos, elem.borrow()).await?;
+            self.num_leaves += 1;
+            Ok(())
+        }
+
+        /// Insert a list of new values at the leftmost available slots.
+        /// * `returns` - Ok(()) if successful. If there are too many elements,
+        ///   insertions will be performed until the merkle tree is full, and
+        ///   will return an Err().
+        pub async fn extend(
+            &mut self,
+            elems: impl IntoIterator<Item = impl Borrow<E>>,
+        ) -> Result<(), PrimitivesError> {
+            for elem in elems {
+                self.push(elem).await?;
+            }
+            Ok(())
+        }
+
+        /// Replace the element at an existing position.
+        /// * `returns` - Ok(previous element) if successful, Err() if the
+        ///   position is beyond the last leaf or if the store fails.
+        pub async fn update(
+            &mut self,
+            pos: u64,
+            elem: impl Borrow<E>,
+        ) -> Result<E, PrimitivesError> {
+            if pos >= self.num_leaves {
+                return Err(PrimitivesError::ParameterError(
+                    "Cannot update a leaf beyond the last one".to_string(),
+                ));
+            }
+            let old = self.store.get_elem(pos).await?.ok_or_else(missing_data)?;
+            self.write_leaf(pos, elem.borrow()).await?;
+            Ok(old)
+        }
+
+        /// Return the element at the given position with its membership proof.
+        /// * `returns` - Ok(LookupResult::Ok(elem, proof)) if the element is in
+        ///   the tree, Ok(LookupResult::NotFound(())) if the position is beyond
+        ///   the last leaf, and Err() if the store fails.
+        #[allow(clippy::type_complexity)]
+        pub async fn lookup(
+            &self,
+            pos: u64,
+        ) -> Result<LookupResult<E, MerkleProof<E, u64, T, Arity>, ()>, PrimitivesError> {
+            if pos >= self.num_leaves {
+                return Ok(LookupResult::NotFound(()));
+            }
+            let elem = self.store.get_elem(pos).await?.ok_or_else(missing_data)?;
+            let mut proof = vec![MerkleNode::Leaf {
+                value: self.get_node(0, pos).await?,
+                pos,
+                elem: elem.clone(),
+            }];
+            let mut index = pos;
+            for height in 1..=self.height {
+                let first_child = index - index % Arity::to_u64();
+                let mut children = Vec::with_capacity(Arity::to_usize());
+                for child in first_child..first_child + Arity::to_u64() {
+                    children.push(Arc::new(if self.is_empty(height - 1, child) {
+                        MerkleNode::Empty
+                    } else {
+                        MerkleNode::ForgettenSubtree {
+                            value: self.get_node(height - 1, child).await?,
+                        }
+                    }));
+                }
+                proof.push(MerkleNode::Branch {
+                    value: T::default(),
+                    children,
+                });
+                index /= Arity::to_u64();
+            }
+            Ok(LookupResult::Ok(elem, MerkleProof::new(pos, proof)))
+        }
+
+        /// Verify an element is a leaf of a Merkle tree given the proof, see
+        /// [`MerkleTreeScheme::verify`](crate::merkle_tree::MerkleTreeScheme::verify).
+        pub fn verify(
+            root: impl Borrow<T>,
+            pos: u64,
+            proof: impl Borrow<MerkleProof<E, u64, T, Arity>>,
+        ) -> Result<VerificationResult, PrimitivesError> {
+            let proof = proof.borrow();
+            if pos != proof.pos {
+                return Ok(Err(()));
+            }
+            proof.verify_membership_proof::<H>(root.borrow())
+        }
+
+        /// Write the leaf at the given position and recompute its path to the
+        /// root. The leaf is considered non-empty even if it is the first
+        /// position after the last leaf.
+        async fn write_leaf(&mut self, pos: u64, elem: &E) -> Result<(), PrimitivesError> {
+            let mut value = H::digest_leaf(&pos, elem)?;
+            self.store
+                .put_node(
+                    NodePosition {
+                        height: 0,
+                        index: pos,
+                    },
+                    value,
+                )
+                .await?;
+            let mut index = pos;
+            for height in 1..=self.height {
+                let first_child = index - index % Arity::to_u64();
+                let mut children = Vec::with_capacity(Arity::to_usize());
+                for child in first_child..first_child + Arity::to_u64() {
+                    children.push(if child == index {
+                        value
+                    } else if self.is_empty(height - 1, child) {
+                        T::default()
+                    } else {
+                        self.get_node(height - 1, child).await?
+                    });
+                }
+                value = H::digest(&children)?;
+                index /= Arity::to_u64();
+                self.store
+                    .put_node(NodePosition { height, index }, value)
+                    .await?;
+            }
+            // Write the element last, so that it is only counted by stores
+            // such as file stores once its nodes are written.
+            self.store.put_elem(pos, elem).await
+        }
+
+        /// Return the root digest of the tree.
+        async fn root(&self) -> Result<T, PrimitivesError> {
+            if self.num_leaves == 0 {
+                Ok(T::default())
+            } else {
+                self.get_node(self.height, 0).await
+            }
+        }
+
+        /// Return whether the subtree at the given position is empty.
+        fn is_empty(&self, height: usize, index: u64) -> bool {
+            is_empty_subtree::<Arity>(self.num_leaves, height, index)
+        }
+
+        /// Read a node which is known to be non-empty.
+        async fn get_node(&self, height: usize, index: u64) -> Result<T, PrimitivesError> {
+            self.store
+                .get_node(NodePosition { height, index })
+                .await?
+                .ok_or_else(missing_data)
+        }
+    }
+}
+
+#[cfg(test)]
+mod mt_tests {
+    use super::{MapStore, MerkleStore};
+    use crate::{
+        merkle_tree::{
+            prelude::{MerkleTree, RescueHash, RescueMerkleTree},
+            *,
+        },
+        rescue::RescueParameter,
+    };
+    use ark_ed_on_bls12_377::Fq as Fq377;
+    use ark_ed_on_bls12_381::Fq as Fq381;
+    use ark_ed_on_bn254::Fq as Fq254;
+    use typenum::U3;
+
+    type RescueStoredMerkleTree<F, S> = MerkleTree<F, RescueHash<F>, u64, U3, F, S>;
+
+    #[test]
+    fn test_stored_mt() {
+        test_stored_mt_helper::<Fq254>();
+        test_stored_mt_helper::<Fq377>();
+        test_stored_mt_helper::<Fq381>();
+    }
+
+    fn test_stored_mt_helper<F: RescueParameter>() {
+        check_store::<F, _>(MapStore::default());
+
+        #[cfg(feature = "std")]
+        {
+            let dir = std::env::temp_dir().join(format!("jf_stored_mt_{}", std::process::id()));
+            let _ = std::fs::remove_dir_all(&dir);
+            check_store::<F, _>(super::FileStore::open(&dir, 3).unwrap());
+
+            // Reopen the store, which was truncated to 10 elements, and keep
+            // appending
+            let expected =
+                RescueMerkleTree::<F>::from_elems(Some(3), (0..20u64).map(F::from)).unwrap();
+            let store = super::FileStore::open(&dir, 3).unwrap();
+            assert_eq!(store.num_elems(), 10);
+            let mut prefix = expected.clone();
+            prefix.truncate(10).unwrap();
+            let mut mt = RescueStoredMerkleTree::<F, _>::open(store, &prefix.commitment()).unwrap();
+            mt.extend((10..20u64).map(F::from)).unwrap();
+            assert_eq!(mt.commitment(), expected.commitment());
+            assert_eq!(mt.store().num_elems(), 20);
+            assert!(RescueStoredMerkleTree::<F, _>::open(
+                super::FileStore::open(&dir, 3).unwrap(),
+                &prefix.commitment()
+            )
+            .is_err());
+            std::fs::remove_dir_all(&dir).unwrap();
+        }
+    }
+
+    fn check_store<F: RescueParameter, S: MerkleStore<F, F>>(store: S) {
+        let elems: Vec<F> = (0..15u64).map(F::from).collect();
+        let mut expected = RescueMerkleTree::<F>::from_elems(Some(3), &elems).unwrap();
+        let mut mt = RescueStoredMerkleTree::<F, S>::with_store(store, 3);
+        assert_eq!(mt.commitment(), RescueMerkleTree::<F>::new(3).commitment());
+        mt.extend(&elems).unwrap();
+        assert_eq!(mt.commitment(), expected.commitment());
+
+        // Every leaf is served, whether it is in memory or in the store
+        let root = expected.commitment().digest();
+        for pos in 0..15u64 {
+            let (elem, proof) = mt.lookup(pos).expect_ok().unwrap();
+            assert_eq!(*elem, F::from(pos));
+            assert_eq!(proof, expected.lookup(pos).expect_ok().unwrap().1);
+            assert!(RescueStoredMerkleTree::<F, S>::verify(root, pos, &proof)
+                .unwrap()
+                .is_ok());
+        }
+        assert!(mt.lookup(15).expect_not_found().is_ok());
+        let (elems_read, proof) = mt.multi_lookup([2u64, 7, 14]).expect_ok().unwrap();
+        assert_eq!(
+            elems_read,
+            vec![F::from(2u64), F::from(7u64), F::from(14u64)]
+        );
+        assert_eq!(
+            proof,
+            expected.multi_lookup([2u64, 7, 14]).expect_ok().unwrap().1
+        );
+        assert!(mt.multi_lookup([2u64, 15]).expect_not_found().is_ok());
+        mt.evict();
+        assert_eq!(*mt.lookup(4).expect_ok().unwrap().0, F::from(4u64));
+
+        // Only the frontier is iterated over
+        assert_eq!(mt.iter().map(|(pos, _)| *pos).collect::<Vec<_>>(), [14u64]);
+
+        // Truncated trees match in-memory ones, and keep growing from there
+        mt.truncate(10).unwrap();
+        expected.truncate(10).unwrap();
+        assert_eq!(mt.commitment(), expected.commitment());
+        assert!(mt.lookup(12).expect_not_found().is_ok());
+        assert_eq!(
+            mt.lookup(4).expect_ok().unwrap().1,
+            expected.lookup(4).expect_ok().unwrap().1
+        );
+        assert!(mt.truncate(11).is_err());
+        mt.push(F::from(100u64)).unwrap();
+        expected.push(F::from(100u64)).unwrap();
+        assert_eq!(mt.commitment(), expected.commitment());
+        assert_eq!(*mt.lookup(10).expect_ok().unwrap().0, F::from(100u64));
+        mt.pop().unwrap();
+        assert_eq!(mt.num_leaves(), 10);
+
+        // The tree can be used wherever an appendable tree is expected
+        fn truncate_all<M: AppendableMerkleTreeScheme>(mt: &mut M) {
+            mt.truncate(0).unwrap();
+        }
+        let mut empty = RescueStoredMerkleTree::<F, _>::with_store(MapStore::default(), 3);
+        empty.extend(&elems).unwrap();
+        truncate_all(&mut empty);
+        assert_eq!(
+            empty.commitment(),
+            RescueMerkleTree::<F>::new(3).commitment()
+        );
+        empty.extend(&elems[..2]).unwrap();
+        assert_eq!(
+            empty.commitment(),
+            RescueMerkleTree::<F>::from_elems(Some(3), &elems[..2])
+                .unwrap()
+                .commitment()
+        );
+
+        // Full tree
+        let mut full = RescueStoredMerkleTree::<F, _>::with_store(MapStore::default(), 1);
+        full.extend(&elems[..3]).unwrap();
+        assert!(full.push(F::from(0u64)).is_err());
+        assert_eq!(full.num_leaves(), 3);
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn test_async_stored_mt() {
+        test_async_stored_mt_helper::<Fq254>();
+        test_async_stored_mt_helper::<Fq377>();
+        test_async_stored_mt_helper::<Fq381>();
+    }
+
+    #[cfg(feature = "async")]
+    fn test_async_stored_mt_helper<F: RescueParameter>() {
+        use super::AsyncStoredMerkleTree;
+        type RescueAsyncMerkleTree<F> =
+            AsyncStoredMerkleTree<F, RescueHash<F>, U3, F, MapStore<F, F>>;
+
+        futures::executor::block_on(async {
+            let elems: Vec<F> = (0..10u64).map(F::from).collect();
+            let mut expected = RescueMerkleTree::<F>::from_elems(Some(3), &elems).unwrap();
+            let mut mt = RescueAsyncMerkleTree::<F>::new(MapStore::default(), 3);
+            assert_eq!(
+                mt.commitment().await.unwrap(),
+                RescueMerkleTree::<F>::new(3).commitment()
+            );
+            mt.extend(&elems).await.unwrap();
+            assert_eq!(mt.commitment().await.unwrap(), expected.commitment());
+
+            let root = expected.commitment().digest();
+            for pos in 0..10u64 {
+                let (elem, proof) = mt.lookup(pos).await.unwrap().expect_ok().unwrap();
+                assert_eq!(elem, F::from(pos));
+                assert_eq!(proof, expected.lookup(pos).expect_ok().unwrap().1);
+                assert!(RescueAsyncMerkleTree::<F>::verify(root, pos, &proof)
+                    .unwrap()
+                    .is_ok());
+            }
+            assert!(mt.lookup(10).await.unwrap().expect_not_found().is_ok());
+
+            // Updates match a tree rebuilt from the updated elements
+            let mut updated = elems.clone();
+            updated[4] = F::from(100u64);
+            assert_eq!(mt.update(4, F::from(100u64)).await.unwrap(), F::from(4u64));
+            assert!(mt.update(10, F::from(0u64)).await.is_err());
+            expected = RescueMerkleTree::<F>::from_elems(Some(3), &updated).unwrap();
+            assert_eq!(mt.commitment().await.unwrap(), expected.commitment());
+            let (elem, proof) = mt.lookup(4).await.unwrap().expect_ok().unwrap();
+            assert_eq!(elem, F::from(100u64));
+            assert_eq!(proof, expected.lookup(4).expect_ok().unwrap().1);
+
+            // The store can be reopened, also synchronously
+            let commitment = mt.commitment().await.unwrap();
+            let store = mt.into_store();
+            let mut mt = RescueAsyncMerkleTree::<F>::open(store.clone(), &commitment)
+                .await
+                .unwrap();
+            let stored = RescueStoredMerkleTree::<F, _>::open(store, &commitment).unwrap();
+            assert_eq!(stored.commitment(), commitment);
+            assert_eq!(*stored.lookup(4).expect_ok().unwrap().0, F::from(100u64));
+            mt.push(F::from(10u64)).await.unwrap();
+            expected.push(F::from(10u64)).unwrap();
+            assert_eq!(mt.commitment().await.unwrap(), expected.commitment());
+
+            // Full tree
+            let mut full =
+                AsyncStoredMerkleTree::<F, RescueHash<F>, U3, F, _>::new(MapStore::default(), 1);
+            full.extend(&elems[..3]).await.unwrap();
+            assert!(full.push(F::from(0u64)).await.is_err());
+            assert_eq!(full.num_leaves(), 3);
+        });
+    }
+}
@@ -0,0 +1,346 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+//! A tiered commitment tree: a fixed-depth upper tree whose leaves are the
+//! roots of append-only lower trees of a fixed height.
+//!
+//! Elements are appended to the current lower tree, and a new one is started
+//! whenever it is full. Once a lower tree is not needed anymore, e.g. after
+//! its owners have been handed their proofs, it can be forgotten as a whole,
+//! only keeping its root in the upper tree.
+//!
+//! The tiers are laid out as a single [`MerkleTree`] whose height is the sum
+//! of the heights of both tiers, so a [`TieredMerkleTree`] has the same
+//! commitment as the corresponding [`MerkleTree`], and its membership proofs,
+//! which span both tiers, are the usual [`MerkleProof`]s.
+
+use super::{
+    append_only::MerkleTree,
+    internal::{MerkleBatchProof, MerkleProof, MerkleTreeCommitment, MerkleTreeIter},
+    AppendableMerkleTreeScheme, DigestAlgorithm, Element, ForgetableMerkleTreeScheme, LookupResult,
+    MerkleTreeScheme, MissingSubtree, NodeValue,
+};
+use crate::errors::{PrimitivesError, VerificationResult};
+use ark_std::{borrow::Borrow, boxed::Box, string::ToString, vec::Vec};
+use num_bigint::BigUint;
+use serde::{Deserialize, Serialize};
+use typenum::Unsigned;
+
+/// A fixed-depth upper tree over append-only lower trees, see the [module
+/// documentation](self).
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(bound = "E: ark_serialize::CanonicalSerialize + ark_serialize::CanonicalDeserialize")]
+pub struct TieredMerkleTree<E, H, Arity, T>
+where
+    E: Element,
+    H: DigestAlgorithm<E, u64, T>,
+    Arity: Unsigned,
+    T: NodeValue,
+{
+    tree: MerkleTree<E, H, u64, Arity, T>,
+    lower_height: usize,
+}
+
+impl<E, H, Arity, T> TieredMerkleTree<E, H, Arity, T>
+where
+    E: Element,
+    H: DigestAlgorithm<E, u64, T>,
+    Arity: Unsigned,
+    T: NodeValue,
+{
+    /// Initialize an empty tiered Merkle tree.
+    /// * `upper_height` - height of the upper tree, there are at most
+    ///   `Arity^upper_height` lower trees
+    /// * `lower_height` - height of each lower tree
+    /// * `returns` - Err() if the tree would have more than `u64::MAX` leaves
+    pub fn new(upper_height: usize, lower_height: usize) -> Result<Self, PrimitivesError> {
+        if num_traits::checked_pow(Arity::to_u64(), upper_height + lower_height).is_none() {
+            return Err(PrimitivesError::ParameterError(
+                "Merkle tree size too large.".to_string(),
+            ));
+        }
+        Ok(Self {
+            tree: MerkleTree::new(upper_height + lower_height),
+            lower_height,
+        })
+    }
+
+    /// Return the height of the upper tree.
+    pub fn upper_height(&self) -> usize {
+        self.tree.height() - self.lower_height
+    }
+
+    /// Return the height of each lower tree.
+    pub fn lower_height(&self) -> usize {
+        self.lower_height
+    }
+
+    /// Return the number of leaves of a full lower tree.
+    pub fn lower_capacity(&self) -> u64 {
+        num_traits::pow(Arity::to_u64(), self.lower_height)
+    }
+
+    /// Return the number of lower trees started so far.
+    pub fn num_lower_trees(&self) -> u64 {
+        let capacity = self.lower_capacity();
+        (self.tree.num_leaves() + capacity - 1) / capacity
+    }
+
+    /// Return the commitment of the `index`-th lower tree, which is also a
+    /// leaf of the upper tree.
+    /// * `returns` - `None` if there is no such lower tree, or if its root is
+    ///   not in memory
+    pub fn lower_tree_commitment(&self, index: u64) -> Option<MerkleTreeCommitment<T>> {
+        if index >= self.num_lower_trees() {
+            return None;
+        }
+        let capacity = self.lower_capacity();
+        let num_leaves = (self.tree.num_leaves() - index * capacity).min(capacity);
+        self.tree
+            .subtree_digest(self.lower_height, index)
+            .map(|digest| MerkleTreeCommitment::new(digest, self.lower_height, num_leaves))
+    }
+
+    /// Forget the whole `index`-th lower tree, only keeping its root. The
+    /// lower tree receiving the next insertions can not be forgotten.
+    /// * `returns` - the commitment of the forgotten lower tree, or Err() if
+    ///   there is no such lower tree, if it is the last one or if it has
+    ///   already been forgotten
+    pub fn forget_lower_tree(
+        &mut self,
+        index: u64,
+    ) -> Result<MerkleTreeCommitment<T>, PrimitivesError> {
+        if index + 1 >= self.num_lower_trees() {
+            return Err(PrimitivesError::ParameterError(
+                "Only the lower trees before the last one can be forgotten".to_string(),
+            ));
+        }
+        let commitment = self.lower_tree_commitment(index);
+        match (
+            self.tree.forget_subtree(self.lower_height, index),
+            commitment,
+        ) {
+            (LookupResult::Ok(..), Some(commitment)) => Ok(commitment),
+            (LookupResult::NotInMemory(()), _) | (LookupResult::Ok(..), None) => Err(
+                PrimitivesError::ParameterError("Lower tree is already forgotten".to_string()),
+            ),
+            (LookupResult::NotFound(_), _) => Err(PrimitivesError::InternalError(
+                "Missing lower tree in an appendable tree".to_string(),
+            )),
+        }
+    }
+
+    /// Trim the leaf at the given position from memory, if present.
+    pub fn forget(&mut self, pos: u64) -> LookupResult<E, MerkleProof<E, u64, T, Arity>, ()> {
+        self.tree.forget(pos)
+    }
+
+    /// Re-insert a forgotten leaf, possibly of a forgotten lower tree, if the
+    /// proof is valid.
+    pub fn remember(
+        &mut self,
+        pos: u64,
+        element: impl Borrow<E>,
+        proof: impl Borrow<MerkleProof<E, u64, T, Arity>>,
+    ) -> Result<(), PrimitivesError> {
+        self.tree.remember(pos, element, proof)
+    }
+
+    /// Return both tiers as a single Merkle tree.
+    pub fn into_tree(self) -> MerkleTree<E, H, u64, Arity, T> {
+        self.tree
+    }
+}
+
+impl<E, H, Arity, T> MerkleTreeScheme for TieredMerkleTree<E, H, Arity, T>
+where
+    E: Element,
+    H: DigestAlgorithm<E, u64, T>,
+    Arity: Unsigned,
+    T: NodeValue,
+{
+    type Element = E;
+    type Index = u64;
+    type NodeValue = T;
+    type MembershipProof = MerkleProof<E, u64, T, Arity>;
+    type BatchMembershipProof = MerkleBatchProof<E, u64, T, Arity>;
+    type Commitment = MerkleTreeCommitment<T>;
+
+    const ARITY: usize = Arity::USIZE;
+
+    fn height(&self) -> usize {
+        self.tree.height()
+    }
+
+    fn capacity(&self) -> BigUint {
+        self.tree.capacity()
+    }
+
+    fn num_leaves(&self) -> u64 {
+        self.tree.num_leaves()
+    }
+
+    fn commitment(&self) -> Self::Commitment {
+        self.tree.commitment()
+    }
+
+    fn lookup(
+        &self,
+        pos: impl Borrow<Self::Index>,
+    ) -> LookupResult<
+        &Self::Element,
+        Self::MembershipProof,
+        (),
+        Option<MissingSubtree<Self::NodeValue>>,
+    > {
+        self.tree.lookup(pos)
+    }
+
+    fn verify(
+        root: impl Borrow<Self::NodeValue>,
+        pos: impl Borrow<Self::Index>,
+        proof: impl Borrow<Self::MembershipProof>,
+    ) -> Result<VerificationResult, PrimitivesError> {
+        MerkleTree::<E, H, u64, Arity, T>::verify(root, pos, proof)
+    }
+
+    fn multi_lookup(
+        &self,
+        pos: impl IntoIterator<Item = impl Borrow<Self::Index>>,
+    ) -> LookupResult<Vec<Self::Element>, Self::BatchMembershipProof, ()> {
+        self.tree.multi_lookup(pos)
+    }
+
+    fn verify_batch(
+        root: impl Borrow<Self::NodeValue>,
+        pos: impl IntoIterator<Item = impl Borrow<Self::Index>>,
+        proof: impl Borrow<Self::BatchMembershipProof>,
+    ) -> Result<VerificationResult, PrimitivesError> {
+        MerkleTree::<E, H, u64, Arity, T>::verify_batch(root, pos, proof)
+    }
+
+    fn iter(&self) -> MerkleTreeIter<E, u64, T> {
+        self.tree.iter()
+    }
+
+    fn iter_with_proofs(
+        &self,
+    ) -> Box<dyn Iterator<Item = (u64, &Self::Element, Self::MembershipProof)> + '_> {
+        self.tree.iter_with_proofs()
+    }
+}
+
+impl<E, H, Arity, T> AppendableMerkleTreeScheme for TieredMerkleTree<E, H, Arity, T>
+where
+    E: Element,
+    H: DigestAlgorithm<E, u64, T>,
+    Arity: Unsigned,
+    T: NodeValue,
+{
+    fn push(&mut self, elem: impl Borrow<Self::Element>) -> Result<(), PrimitivesError> {
+        self.tree.push(elem)
+    }
+
+    fn extend(
+        &mut self,
+        elems: impl IntoIterator<Item = impl Borrow<Self::Element>>,
+    ) -> Result<(), PrimitivesError> {
+        self.tree.extend(elems)
+    }
+
+    fn truncate(&mut self, num_leaves: u64) -> Result<(), PrimitivesError> {
+        self.tree.truncate(num_leaves)
+    }
+}
+
+#[cfg(test)]
+mod mt_tests {
+    use crate::{
+        merkle_tree::{
+            prelude::{RescueHash, RescueMerkleTree},
+            tiered::TieredMerkleTree,
+            *,
+        },
+        rescue::RescueParameter,
+    };
+    use ark_ed_on_bls12_377::Fq as Fq377;
+    use ark_ed_on_bls12_381::Fq as Fq381;
+    use ark_ed_on_bn254::Fq as Fq254;
+    use typenum::U3;
+
+    type RescueTieredMerkleTree<F> = TieredMerkleTree<F, RescueHash<F>, U3, F>;
+
+    #[test]
+    fn test_tiered_mt() {
+        test_tiered_mt_helper::<Fq254>();
+        test_tiered_mt_helper::<Fq377>();
+        test_tiered_mt_helper::<Fq381>();
+    }
+
+    fn test_tiered_mt_helper<F: RescueParameter>() {
+        let elems = (0..40u64).map(F::from).collect::<Vec<_>>();
+        let mut mt = RescueTieredMerkleTree::<F>::new(2, 2).unwrap();
+        assert_eq!(mt.upper_height(), 2);
+        assert_eq!(mt.lower_capacity(), 9);
+        assert_eq!(mt.num_lower_trees(), 0);
+        mt.extend(&elems).unwrap();
+        assert_eq!(mt.num_lower_trees(), 5);
+
+        // Same commitment and proofs as a single tree of both heights
+        let flat_mt = RescueMerkleTree::<F>::from_elems(Some(4), &elems).unwrap();
+        assert_eq!(mt.commitment(), flat_mt.commitment());
+        for i in 0..5u64 {
+            // The root of a lower tree is on the path of its leaves
+            let commitment = mt.lower_tree_commitment(i).unwrap();
+            assert_eq!(commitment.height(), 2);
+            assert_eq!(commitment.size(), if i < 4 { 9 } else { 4 });
+            let (elem, proof) = mt.lookup(i * 9).expect_ok().unwrap();
+            let traversal_path = ToTraversalPath::<U3>::to_traversal_path(&(i * 9), 4);
+            let path_values = proof
+                .membership_path_values::<RescueHash<F>>(&traversal_path, elem)
+                .unwrap();
+            assert_eq!(commitment.digest(), path_values[2]);
+        }
+        assert_eq!(mt.lower_tree_commitment(5), None);
+
+        // Forgetting whole lower trees keeps the commitment and the other proofs
+        let root = mt.commitment().digest();
+        let (_, proof) = mt.lookup(12).expect_ok().unwrap();
+        let lower_commitment = mt.lower_tree_commitment(1).unwrap();
+        assert_eq!(mt.forget_lower_tree(1).unwrap(), lower_commitment);
+        assert_eq!(mt.lower_tree_commitment(1), Some(lower_commitment));
+        assert!(mt.forget_lower_tree(1).is_err());
+        assert!(mt.forget_lower_tree(4).is_err());
+        assert_eq!(mt.commitment().digest(), root);
+        for i in 9..18 {
+            assert!(mt.lookup(i).expect_not_in_memory().is_ok());
+        }
+        let (_, other_proof) = mt.lookup(30).expect_ok().unwrap();
+        assert_eq!(other_proof, flat_mt.lookup(30).expect_ok().unwrap().1);
+        assert!(RescueTieredMerkleTree::<F>::verify(root, 30, &other_proof)
+            .unwrap()
+            .is_ok());
+
+        // Appends are unaffected
+        mt.forget_lower_tree(0).unwrap();
+        mt.forget_lower_tree(2).unwrap();
+        mt.extend(&elems[..5]).unwrap();
+        assert_eq!(mt.num_lower_trees(), 5);
+        let mut flat_mt = flat_mt;
+        flat_mt.extend(&elems[..5]).unwrap();
+        assert_eq!(mt.commitment(), flat_mt.commitment());
+        mt.push(F::from(100u64)).unwrap();
+        assert_eq!(mt.num_lower_trees(), 6);
+        assert!(mt.forget_lower_tree(4).is_ok());
+
+        // Leaves of a forgotten lower tree can be remembered from their proofs
+        mt.remember(12, elems[12], &proof).unwrap();
+        assert_eq!(mt.lookup(12).expect_ok().unwrap().0, &elems[12]);
+        assert!(mt.lookup(13).expect_not_in_memory().is_ok());
+
+        assert!(RescueTieredMerkleTree::<F>::new(40, 1).is_err());
+    }
+}
@@ -5,16 +5,27 @@
 // along with the Jellyfish library. If not, see <https://mit-license.org/>.
 
 //! Implementation of a typical Sparse Merkle Tree.
+//!
+//! An empty subtree, at any height, is represented by a single
+//! [`MerkleNode::Empty`] whose digest is `T::default()`, instead of the digest
+//! of its (empty) children. Hence there is no chain of empty-subtree digests
+//! to compute: lookups, insertions and proof verification never hash empty
+//! children, and a verifier only needs `T::default()` to handle the empty
+//! siblings of a proof.
 use super::{
-    internal::{MerkleNode, MerkleProof, MerkleTreeCommitment, MerkleTreeIntoIter, MerkleTreeIter},
+    internal::{
+        MerkleBatchProof, MerkleNode, MerkleProof, MerkleProofIter, MerkleTreeCommitment,
+        MerkleTreeIntoIter, MerkleTreeIter,
+    },
     DigestAlgorithm, Element, ForgetableMerkleTreeScheme, ForgetableUniversalMerkleTreeScheme,
-    Index, LookupResult, MerkleCommitment, MerkleTreeScheme, NodeValue, ToTraversalPath,
-    UniversalMerkleTreeScheme,
+    Index, LookupResult, MerkleCommitment, MerkleTreeScheme, MissingSubtree, NodeValue,
+    ToTraversalPath, UniversalMerkleTreeScheme,
 };
 use crate::{
     errors::{PrimitivesError, VerificationResult},
     impl_forgetable_merkle_tree_scheme, impl_merkle_tree_scheme,
 };
+use alloc::sync::Arc;
 use ark_std::{
     borrow::Borrow, boxed::Box, fmt::Debug, marker::PhantomData, string::ToString, vec, vec::Vec,
 };
@@ -38,7 +49,7 @@ where
     /// Initialize an empty Merkle tree.
     pub fn new(height: usize) -> Self {
         Self {
-            root: Box::new(MerkleNode::<E, I, T>::Empty),
+            root: Arc::new(MerkleNode::<E, I, T>::Empty),
             height,
             num_leaves: 0,
             _phantom: PhantomData,
@@ -101,13 +112,52 @@ where
     {
         let pos = pos.borrow();
         let traversal_path = pos.to_traversal_path(self.height);
-        let (delta, result) =
-            self.root
-                .update_with_internal::<H, Arity, F>(self.height, pos, &traversal_path, f)?;
+        let (delta, result) = Arc::make_mut(&mut self.root).update_with_internal::<H, Arity, F>(
+            self.height,
+            pos,
+            &traversal_path,
+            f,
+        )?;
         self.num_leaves = (delta + self.num_leaves as i64) as u64;
         Ok(result)
     }
 
+    fn update_batch(
+        &mut self,
+        updates: impl IntoIterator<Item = (impl Borrow<Self::Index>, impl Borrow<Self::Element>)>,
+    ) -> Result<(), PrimitivesError> {
+        let mut updates = updates
+            .into_iter()
+            .map(|(pos, elem)| {
+                let pos = pos.borrow();
+                (
+                    pos.to_traversal_path(self.height),
+                    pos.clone(),
+                    elem.borrow().clone(),
+                )
+            })
+            .collect::<Vec<_>>();
+        // Sort the updates by traversal path from the root so that every
+        // subtree is handled in one go, keeping only the last update for each
+        // position.
+        updates.reverse();
+        updates.sort_by(|(a, ..), (b, ..)| a.iter().rev().cmp(b.iter().rev()));
+        updates.dedup_by(|(a, ..), (b, ..)| a == b);
+        // Check upfront that nothing falls into a forgotten subtree, so that
+        // the tree is left untouched on error.
+        if updates
+            .iter()
+            .any(|(path, ..)| self.root.descend(self.height, path).is_forgotten())
+        {
+            return Err(PrimitivesError::ParameterError(
+                "Given part of merkle tree is not in memory".to_string(),
+            ));
+        }
+        self.num_leaves += Arc::make_mut(&mut self.root)
+            .update_batch_internal::<H, Arity>(self.height, &updates)?;
+        Ok(())
+    }
+
     fn non_membership_verify(
         &self,
         pos: impl Borrow<Self::Index>,
@@ -128,17 +178,38 @@ where
         proof.verify_non_membership_proof::<H>(&self.root.value())
     }
 
+    fn verify_non_membership(
+        root: impl Borrow<Self::NodeValue>,
+        pos: impl Borrow<Self::Index>,
+        proof: impl Borrow<Self::NonMembershipProof>,
+    ) -> Result<VerificationResult, PrimitivesError> {
+        let proof = proof.borrow();
+        if *pos.borrow() != proof.pos {
+            return Ok(Err(())); // invalid proof for the given pos
+        }
+        if proof.verify_non_membership_proof::<H>(root.borrow())? {
+            Ok(Ok(()))
+        } else {
+            Ok(Err(()))
+        }
+    }
+
     fn universal_lookup(
         &self,
         pos: impl Borrow<Self::Index>,
-    ) -> LookupResult<&Self::Element, Self::MembershipProof, Self::NonMembershipProof> {
+    ) -> LookupResult<
+        &Self::Element,
+        Self::MembershipProof,
+        Self::NonMembershipProof,
+        Option<MissingSubtree<Self::NodeValue>>,
+    > {
         let pos = pos.borrow();
         let traversal_path = pos.to_traversal_path(self.height);
         match self.root.lookup_internal(self.height, &traversal_path) {
             LookupResult::Ok(value, proof) => {
                 LookupResult::Ok(value, MerkleProof::new(pos.clone(), proof))
             },
-            LookupResult::NotInMemory => LookupResult::NotInMemory,
+            LookupResult::NotInMemory(missing) => LookupResult::NotInMemory(Some(missing)),
             LookupResult::NotFound(non_membership_proof) => {
                 LookupResult::NotFound(MerkleProof::new(pos.clone(), non_membership_proof))
             },
@@ -160,9 +231,9 @@ where
         pos: Self::Index,
     ) -> LookupResult<Self::Element, Self::MembershipProof, Self::NonMembershipProof> {
         let traversal_path = pos.to_traversal_path(self.height);
-        match self.root.forget_internal(self.height, &traversal_path) {
+        match Arc::make_mut(&mut self.root).forget_internal(self.height, &traversal_path) {
             LookupResult::Ok(elem, proof) => LookupResult::Ok(elem, MerkleProof::new(pos, proof)),
-            LookupResult::NotInMemory => LookupResult::NotInMemory,
+            LookupResult::NotInMemory(()) => LookupResult::NotInMemory(()),
             LookupResult::NotFound(proof) => LookupResult::NotFound(MerkleProof::new(pos, proof)),
         }
     }
@@ -202,7 +273,7 @@ where
                         }
                     },
                 )?;
-            self.root.remember_internal::<H, Arity>(
+            Arc::make_mut(&mut self.root).remember_internal::<H, Arity>(
                 self.height,
                 &traversal_path,
                 &path_values,
@@ -235,6 +306,149 @@ mod mt_tests {
     use num_bigint::BigUint;
     use typenum::U3;
 
+    #[test]
+    fn test_universal_mt_snapshot() {
+        test_universal_mt_snapshot_helper::<Fq254>();
+        test_universal_mt_snapshot_helper::<Fq377>();
+        test_universal_mt_snapshot_helper::<Fq381>();
+    }
+
+    fn test_universal_mt_snapshot_helper<F: RescueParameter>() {
+        let mut mt = RescueSparseMerkleTree::<BigUint, F>::from_kv_set(
+            10,
+            (0..5u64).map(|i| (BigUint::from(i * 7), F::from(i))),
+        )
+        .unwrap();
+        let snapshot = mt.snapshot();
+        let root = snapshot.commitment().digest();
+
+        mt.update(BigUint::from(0u64), F::from(100u64)).unwrap();
+        mt.remove(BigUint::from(7u64)).unwrap();
+        mt.update(BigUint::from(100u64), F::from(1u64)).unwrap();
+        assert_ne!(mt.commitment().digest(), root);
+
+        assert_eq!(snapshot.commitment().digest(), root);
+        assert_eq!(snapshot.num_leaves(), 5);
+        for i in 0..5u64 {
+            let (val, proof) = snapshot.lookup(BigUint::from(i * 7)).expect_ok().unwrap();
+            assert_eq!(val, &F::from(i));
+            assert!(RescueSparseMerkleTree::<BigUint, F>::verify(
+                &root,
+                BigUint::from(i * 7),
+                &proof
+            )
+            .unwrap()
+            .is_ok());
+        }
+        let proof = snapshot
+            .universal_lookup(BigUint::from(100u64))
+            .expect_not_found()
+            .unwrap();
+        assert!(snapshot
+            .non_membership_verify(BigUint::from(100u64), &proof)
+            .unwrap());
+        assert_eq!(
+            mt.lookup(BigUint::from(0u64)).expect_ok().unwrap().0,
+            &F::from(100u64)
+        );
+        assert!(mt.lookup(BigUint::from(7u64)).expect_not_found().is_ok());
+    }
+
+    #[test]
+    fn test_universal_mt_wide_indices() {
+        test_universal_mt_wide_indices_helper::<Fq254>();
+        test_universal_mt_wide_indices_helper::<Fq377>();
+        test_universal_mt_wide_indices_helper::<Fq381>();
+    }
+
+    fn test_universal_mt_wide_indices_helper<F: RescueParameter>() {
+        // 3^81 > 2^128
+        let mut mt = RescueSparseMerkleTree::<u128, F>::new(81);
+        let positions = [0u128, u64::MAX as u128 + 1, u128::MAX];
+        for (i, pos) in positions.iter().enumerate() {
+            mt.update(pos, F::from(i as u64)).unwrap();
+        }
+        let root = mt.commitment().digest();
+        for (i, pos) in positions.iter().enumerate() {
+            let (elem, proof) = mt.lookup(pos).expect_ok().unwrap();
+            assert_eq!(elem, &F::from(i as u64));
+            assert_eq!(proof.tree_height(), 82);
+            assert!(RescueSparseMerkleTree::<u128, F>::verify(root, pos, &proof)
+                .unwrap()
+                .is_ok());
+        }
+        // Indices which only differ beyond the 64 lowest bits are distinct
+        let pos = u64::MAX as u128 + 2;
+        let proof = mt.universal_lookup(pos).expect_not_found().unwrap();
+        assert!(
+            RescueSparseMerkleTree::<u128, F>::verify_non_membership(root, pos, &proof)
+                .unwrap()
+                .is_ok()
+        );
+
+        // 160-bit addresses, 3^101 > 2^160
+        let address = BigUint::from_bytes_be(&[0xab; 20]);
+        let mt = RescueSparseMerkleTree::<BigUint, F>::from_kv_set(
+            101,
+            [(address.clone(), F::from(7u64))],
+        )
+        .unwrap();
+        let (elem, proof) = mt.lookup(&address).expect_ok().unwrap();
+        assert_eq!(elem, &F::from(7u64));
+        assert!(RescueSparseMerkleTree::<BigUint, F>::verify(
+            mt.commitment().digest(),
+            &address,
+            &proof
+        )
+        .unwrap()
+        .is_ok());
+    }
+
+    #[test]
+    fn test_universal_mt_empty_subtrees() {
+        test_universal_mt_empty_subtrees_helper::<Fq254>();
+        test_universal_mt_empty_subtrees_helper::<Fq377>();
+        test_universal_mt_empty_subtrees_helper::<Fq381>();
+    }
+
+    fn test_universal_mt_empty_subtrees_helper<F: RescueParameter>() {
+        let height = 64;
+        let mut mt = RescueSparseMerkleTree::<BigUint, F>::new(height);
+        assert_eq!(mt.commitment().digest(), F::default());
+
+        let pos = BigUint::from(u64::MAX);
+        mt.update(&pos, F::from(1u64)).unwrap();
+        let (_, proof) = mt.lookup(&pos).expect_ok().unwrap();
+        assert_eq!(proof.tree_height(), height + 1);
+
+        // A single leaf: every sibling along the path is an empty subtree
+        // whose digest is `F::default()`.
+        let traversal_path = ToTraversalPath::<U3>::to_traversal_path(&pos, height);
+        let mut expected_root =
+            <RescueHash<F> as DigestAlgorithm<F, BigUint, F>>::digest_leaf(&pos, &F::from(1u64))
+                .unwrap();
+        for (branch, node) in traversal_path.iter().zip(proof.proof.iter().skip(1)) {
+            if let MerkleNode::Branch { value: _, children } = node {
+                children.iter().enumerate().for_each(|(i, child)| {
+                    if i != *branch {
+                        assert!(matches!(**child, MerkleNode::Empty));
+                    }
+                });
+            } else {
+                unreachable!();
+            }
+            let mut data = [F::default(); 3];
+            data[*branch] = expected_root;
+            expected_root =
+                <RescueHash<F> as DigestAlgorithm<F, BigUint, F>>::digest(&data).unwrap();
+        }
+        assert_eq!(mt.commitment().digest(), expected_root);
+
+        // Removing the leaf collapses the whole path back to an empty tree.
+        mt.remove(&pos).unwrap();
+        assert_eq!(mt.commitment().digest(), F::default());
+    }
+
     #[test]
     fn test_universal_mt_builder() {
         test_universal_mt_builder_helper::<Fq254>();
@@ -284,8 +498,50 @@ mod mt_tests {
         let verify_result = mt.non_membership_verify(BigUint::from(1u64), &proof);
         assert!(verify_result.is_ok() && !verify_result.unwrap());
 
-        let verify_result = mt.non_membership_verify(BigUint::from(4u64), proof);
+        let verify_result = mt.non_membership_verify(BigUint::from(4u64), &proof);
         assert!(verify_result.is_err());
+
+        // Verification against a root, without the tree
+        let root = mt.commitment().digest();
+        let proof = mt
+            .universal_lookup(BigUint::from(3u64))
+            .expect_not_found()
+            .unwrap();
+        assert!(RescueSparseMerkleTree::<BigUint, F>::verify_non_membership(
+            &root,
+            BigUint::from(3u64),
+            &proof
+        )
+        .unwrap()
+        .is_ok());
+        assert!(RescueSparseMerkleTree::<BigUint, F>::verify_non_membership(
+            &root,
+            BigUint::from(4u64),
+            &proof
+        )
+        .unwrap()
+        .is_err());
+        let mut bad_proof = proof.clone();
+        bad_proof.pos = BigUint::from(1u64);
+        assert!(RescueSparseMerkleTree::<BigUint, F>::verify_non_membership(
+            &root,
+            BigUint::from(1u64),
+            &bad_proof
+        )
+        .unwrap()
+        .is_err());
+
+        // A non-membership proof is no longer valid once the index is occupied
+        let mut mt = mt;
+        mt.update(BigUint::from(3u64), F::from(5u64)).unwrap();
+        let new_root = mt.commitment().digest();
+        assert!(RescueSparseMerkleTree::<BigUint, F>::verify_non_membership(
+            &new_root,
+            BigUint::from(3u64),
+            &proof
+        )
+        .unwrap()
+        .is_err());
     }
 
     #[test]
@@ -339,13 +595,179 @@ mod mt_tests {
                 .is_ok()
         );
 
+        // Read-modify-write returns the previous element
+        let old = mt
+            .update_with(F::from(7u64), |elem| elem.map(|elem| *elem + F::one()))
+            .unwrap();
+        assert_eq!(old.expect_ok().unwrap().0, F::from(7u64));
+        let (val, _) = mt.universal_lookup(F::from(7u64)).expect_ok().unwrap();
+        assert_eq!(val, &F::from(8u64));
+
         // Remove index 8
-        mt.update_with(F::from(8u64), |_| None).unwrap();
+        let old = mt.update_with(F::from(8u64), |_| None).unwrap();
+        assert_eq!(old.expect_ok().unwrap().0, F::from(8u64));
         assert!(mt
             .universal_lookup(F::from(8u64))
             .expect_not_found()
             .is_ok());
         assert_eq!(mt.num_leaves(), 9);
+        assert!(mt
+            .update_with(F::from(8u64), |_| None)
+            .unwrap()
+            .expect_not_found()
+            .is_ok());
+        assert_eq!(mt.num_leaves(), 9);
+
+        // Forgotten leaves are not updated
+        mt.forget(F::from(6u64)).expect_ok().unwrap();
+        let root = mt.commitment().digest();
+        assert!(mt
+            .update_with(F::from(6u64), |_| panic!("leaf is not in memory"))
+            .unwrap()
+            .expect_not_in_memory()
+            .is_ok());
+        assert_eq!(mt.commitment().digest(), root);
+    }
+
+    #[test]
+    fn test_universal_mt_update_batch() {
+        test_universal_mt_update_batch_helper::<Fq254>();
+        test_universal_mt_update_batch_helper::<Fq377>();
+        test_universal_mt_update_batch_helper::<Fq381>();
+    }
+
+    fn test_universal_mt_update_batch_helper<F: RescueParameter>() {
+        let mut mt = RescueSparseMerkleTree::<BigUint, F>::new(10);
+        let mut expected = RescueSparseMerkleTree::<BigUint, F>::new(10);
+        mt.update_batch([(BigUint::from(5u64), F::from(5u64))])
+            .unwrap();
+        expected.update(BigUint::from(5u64), F::from(5u64)).unwrap();
+
+        // Updates overwrite, insert and repeat positions in arbitrary order
+        let updates: Vec<(BigUint, F)> = [3u64, 5, 1000, 4, 59048, 5, 0, 3]
+            .iter()
+            .enumerate()
+            .map(|(i, pos)| (BigUint::from(*pos), F::from(100 + i as u64)))
+            .collect();
+        mt.update_batch(updates.iter().map(|(pos, elem)| (pos, elem)))
+            .unwrap();
+        for (pos, elem) in updates.iter() {
+            expected.update(pos, elem).unwrap();
+        }
+        assert_eq!(mt.num_leaves(), 6);
+        assert_eq!(mt.commitment(), expected.commitment());
+        let (elem, proof) = mt
+            .universal_lookup(BigUint::from(5u64))
+            .expect_ok()
+            .unwrap();
+        assert_eq!(elem, &F::from(105u64));
+        assert!(RescueSparseMerkleTree::<BigUint, F>::verify(
+            &mt.commitment().digest(),
+            BigUint::from(5u64),
+            &proof
+        )
+        .unwrap()
+        .is_ok());
+        mt.update_batch(Vec::<(BigUint, F)>::new()).unwrap();
+        assert_eq!(mt.commitment(), expected.commitment());
+
+        // A batch touching a forgotten leaf is rejected as a whole
+        mt.forget(BigUint::from(1000u64)).expect_ok().unwrap();
+        let commitment = mt.commitment();
+        assert!(mt
+            .update_batch([
+                (BigUint::from(1u64), F::from(1u64)),
+                (BigUint::from(1000u64), F::from(1u64)),
+            ])
+            .is_err());
+        assert_eq!(mt.commitment(), commitment);
+        assert!(mt
+            .universal_lookup(BigUint::from(1u64))
+            .expect_not_found()
+            .is_ok());
+    }
+
+    #[test]
+    fn test_universal_mt_diff() {
+        test_universal_mt_diff_helper::<Fq254>();
+        test_universal_mt_diff_helper::<Fq377>();
+        test_universal_mt_diff_helper::<Fq381>();
+    }
+
+    fn test_universal_mt_diff_helper<F: RescueParameter>() {
+        let kv = (0..50u64)
+            .map(|i| (BigUint::from(i * 97), F::from(i)))
+            .collect::<Vec<_>>();
+        let mt = RescueSparseMerkleTree::<BigUint, F>::from_kv_set(10, &kv).unwrap();
+        let mut other = mt.clone();
+        assert!(mt.diff(&other).unwrap().is_empty());
+
+        // Updated, inserted and removed leaves
+        other.update(BigUint::from(97u64), F::from(100u64)).unwrap();
+        other.update(BigUint::from(1000u64), F::from(0u64)).unwrap();
+        other.remove(BigUint::from(4753u64)).unwrap();
+        let expected = [97u64, 1000, 4753].map(BigUint::from).to_vec();
+        assert_eq!(mt.diff(&other).unwrap(), expected);
+        assert_eq!(other.diff(&mt).unwrap(), expected);
+        assert_eq!(
+            RescueSparseMerkleTree::<BigUint, F>::new(10)
+                .diff(&mt)
+                .unwrap(),
+            kv.iter().map(|(k, _)| k.clone()).collect::<Vec<_>>()
+        );
+
+        // Forgotten sub-trees are fine as long as they are identical
+        let mut forgotten = mt.clone();
+        forgotten.forget(BigUint::from(0u64)).expect_ok().unwrap();
+        assert_eq!(forgotten.diff(&other).unwrap(), expected);
+        forgotten.forget(BigUint::from(97u64)).expect_ok().unwrap();
+        assert!(forgotten.diff(&other).is_err());
+        assert!(mt
+            .diff(&RescueSparseMerkleTree::<BigUint, F>::new(9))
+            .is_err());
+    }
+
+    #[test]
+    fn test_universal_mt_batch_lookup() {
+        test_universal_mt_batch_lookup_helper::<Fq254>();
+        test_universal_mt_batch_lookup_helper::<Fq377>();
+        test_universal_mt_batch_lookup_helper::<Fq381>();
+    }
+
+    fn test_universal_mt_batch_lookup_helper<F: RescueParameter>() {
+        let mt = RescueSparseMerkleTree::<BigUint, F>::from_kv_set(
+            10,
+            [
+                (BigUint::from(1u64), F::from(1u64)),
+                (BigUint::from(100u64), F::from(2u64)),
+                (BigUint::from(2000u64), F::from(3u64)),
+            ],
+        )
+        .unwrap();
+        let root = mt.commitment().digest();
+        let indices = [
+            BigUint::from(2000u64),
+            BigUint::from(1u64),
+            BigUint::from(100u64),
+        ];
+        let (vals, proof) = mt.multi_lookup(&indices).expect_ok().unwrap();
+        assert_eq!(vals, [F::from(3u64), F::from(1u64), F::from(2u64)]);
+        assert!(
+            RescueSparseMerkleTree::<BigUint, F>::verify_batch(&root, &indices, &proof)
+                .unwrap()
+                .is_ok()
+        );
+        assert!(
+            RescueSparseMerkleTree::<BigUint, F>::verify_batch(&root, &indices[..2], &proof)
+                .unwrap()
+                .is_err()
+        );
+
+        // Empty leaves cannot be proven
+        assert!(mt
+            .multi_lookup([BigUint::from(1u64), BigUint::from(3u64)])
+            .expect_not_found()
+            .is_ok());
     }
 
     #[test]
@@ -395,11 +817,11 @@ mod mt_tests {
         // Forgetting or looking up an element that is already forgotten should fail.
         assert!(matches!(
             mt.universal_forget(0u64.into()),
-            LookupResult::NotInMemory
+            LookupResult::NotInMemory(())
         ));
         assert!(matches!(
             mt.universal_lookup(BigUint::from(0u64)),
-            LookupResult::NotInMemory
+            LookupResult::NotInMemory(Some(_))
         ));
 
         // We should still be able to look up an element that is not forgotten.
@@ -466,15 +888,15 @@ mod mt_tests {
             .unwrap();
         assert!(matches!(
             mt.universal_lookup(BigUint::from(0u64)),
-            LookupResult::NotInMemory
+            LookupResult::NotInMemory(Some(missing)) if missing.height == mt.height()
         ));
         assert!(matches!(
             mt.universal_lookup(BigUint::from(1u64)),
-            LookupResult::NotInMemory
+            LookupResult::NotInMemory(Some(missing)) if missing.height == mt.height()
         ));
         assert!(matches!(
             mt.universal_lookup(BigUint::from(2u64)),
-            LookupResult::NotInMemory
+            LookupResult::NotInMemory(Some(missing)) if missing.height == mt.height()
         ));
 
         // Remember should fail if the proof is invalid.
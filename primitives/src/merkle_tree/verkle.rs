@@ -0,0 +1,436 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+//! Implementation of an append-only Verkle tree.
+//!
+//! A Verkle tree is a Merkle tree where every internal node is a univariate
+//! KZG commitment to its children rather than a hash of them. A node with
+//! `arity` children commits to the polynomial which evaluates to the value of
+//! its `i`-th child at `\omega^i`, where `\omega` generates the multiplicative
+//! subgroup of size `arity`. Leaves are scalar field elements; an internal
+//! node is turned into a scalar by hashing its commitment.
+//!
+//! A branch of a membership proof is a single commitment and a single KZG
+//! opening, whatever the arity, so high-arity trees get short proofs.
+
+use super::LookupResult;
+use crate::{
+    errors::{PrimitivesError, VerificationResult},
+    hash_to_field::hash_to_field,
+    pcs::{
+        prelude::{
+            Commitment, UnivariateKzgPCS, UnivariateKzgProof, UnivariateProverParam,
+            UnivariateVerifierParam,
+        },
+        PolynomialCommitmentScheme,
+    },
+};
+use ark_ec::pairing::Pairing;
+use ark_poly::{
+    univariate::DensePolynomial, DenseUVPolynomial, EvaluationDomain, Radix2EvaluationDomain,
+};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use ark_std::{borrow::Borrow, format, string::ToString, vec, vec::Vec, Zero};
+
+/// Domain separation tag of the hash of a commitment to the scalar field.
+const COMMITMENT_DST: &[u8] = b"JF-VERKLE-V01-COMMITMENT-TO-FIELD";
+
+/// A membership proof of a Verkle tree.
+#[derive(Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct VerkleProof<E: Pairing> {
+    /// Position of the proven leaf
+    pub pos: u64,
+    /// Commitments of the ancestors of the leaf, from the parent of the leaf
+    /// up to, but excluding, the root
+    pub commitments: Vec<Commitment<E>>,
+    /// KZG openings of every ancestor at the position of its child on the
+    /// path, from the parent of the leaf up to the root
+    pub openings: Vec<UnivariateKzgProof<E>>,
+}
+
+impl<E: Pairing> VerkleProof<E> {
+    /// Return the height of the tree this proof is for.
+    pub fn tree_height(&self) -> usize {
+        self.openings.len()
+    }
+}
+
+/// An append-only Verkle tree over the scalar field of `E`, see the
+/// [module documentation](self).
+#[derive(Clone, Debug)]
+pub struct VerkleTree<E: Pairing> {
+    ck: UnivariateProverParam<E>,
+    domain: Radix2EvaluationDomain<E::ScalarField>,
+    height: usize,
+    leaves: Vec<E::ScalarField>,
+    /// `nodes[l]` are the commitments of the existing nodes at height `l + 1`
+    nodes: Vec<Vec<Commitment<E>>>,
+}
+
+impl<E: Pairing> VerkleTree<E> {
+    /// Initialize an empty Verkle tree.
+    /// * `ck` - KZG prover parameters, supporting degree at least `arity - 1`
+    /// * `arity` - number of children of an internal node, a power of two
+    ///   larger than 1
+    /// * `height` - height of the tree, at least 1
+    pub fn new(
+        ck: impl Borrow<UnivariateProverParam<E>>,
+        arity: usize,
+        height: usize,
+    ) -> Result<Self, PrimitivesError> {
+        let domain = Self::domain(arity)?;
+        if height == 0 {
+            return Err(PrimitivesError::ParameterError(
+                "A Verkle tree should have height at least 1".to_string(),
+            ));
+        }
+        (arity as u64)
+            .checked_pow(height as u32)
+            .ok_or_else(|| PrimitivesError::ParameterError("Tree height too large".to_string()))?;
+        let ck = ck.borrow().clone();
+        if ck.powers_of_g.len() < arity {
+            return Err(PrimitivesError::ParameterError(format!(
+                "Prover parameters support degree {}, but arity {} is requested",
+                ck.powers_of_g.len() as isize - 1,
+                arity
+            )));
+        }
+        let mut tree = Self {
+            ck,
+            domain,
+            height,
+            leaves: Vec::new(),
+            nodes: vec![Vec::new(); height],
+        };
+        let root = tree.compute_node(height - 1, 0)?;
+        tree.nodes[height - 1].push(root);
+        Ok(tree)
+    }
+
+    /// Build a Verkle tree from elements.
+    /// * `ck` - KZG prover parameters, see [`Self::new`]
+    /// * `arity` - number of children of an internal node
+    /// * `height` - height of the tree
+    /// * `elems` - elements to insert in the tree
+    pub fn from_elems(
+        ck: impl Borrow<UnivariateProverParam<E>>,
+        arity: usize,
+        height: usize,
+        elems: impl IntoIterator<Item = impl Borrow<E::ScalarField>>,
+    ) -> Result<Self, PrimitivesError> {
+        let mut tree = Self::new(ck, arity, height)?;
+        tree.extend(elems)?;
+        Ok(tree)
+    }
+
+    /// Return the arity of this tree.
+    pub fn arity(&self) -> usize {
+        self.domain.size()
+    }
+
+    /// Return the height of this tree.
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Return the maximum number of leaves of this tree.
+    pub fn capacity(&self) -> u64 {
+        (self.arity() as u64).pow(self.height as u32)
+    }
+
+    /// Return the current number of leaves.
+    pub fn num_leaves(&self) -> u64 {
+        self.leaves.len() as u64
+    }
+
+    /// Return the root commitment of this tree.
+    pub fn commitment(&self) -> Commitment<E> {
+        self.nodes[self.height - 1][0]
+    }
+
+    /// Insert a new element at the end of the tree.
+    pub fn push(&mut self, elem: impl Borrow<E::ScalarField>) -> Result<(), PrimitivesError> {
+        self.extend([elem])
+    }
+
+    /// Insert a list of new elements at the end of the tree. Every touched
+    /// node is recomputed only once.
+    pub fn extend(
+        &mut self,
+        elems: impl IntoIterator<Item = impl Borrow<E::ScalarField>>,
+    ) -> Result<(), PrimitivesError> {
+        let first = self.num_leaves();
+        let elems: Vec<_> = elems.into_iter().map(|elem| *elem.borrow()).collect();
+        if elems.is_empty() {
+            return Ok(());
+        }
+        if first + elems.len() as u64 > self.capacity() {
+            return Err(PrimitivesError::ParameterError(
+                "Exceeding the tree capacity".to_string(),
+            ));
+        }
+        self.leaves.extend(elems);
+        self.refresh(first, self.num_leaves() - 1)
+    }
+
+    /// Replace the element at position `pos`.
+    /// * `returns` - the previous element at `pos`
+    pub fn update(
+        &mut self,
+        pos: u64,
+        elem: impl Borrow<E::ScalarField>,
+    ) -> Result<E::ScalarField, PrimitivesError> {
+        if pos >= self.num_leaves() {
+            return Err(PrimitivesError::ParameterError(
+                "Updating a non-existing leaf".to_string(),
+            ));
+        }
+        let old = ark_std::mem::replace(&mut self.leaves[pos as usize], *elem.borrow());
+        self.refresh(pos, pos)?;
+        Ok(old)
+    }
+
+    /// Return the element at position `pos` along with its membership proof.
+    pub fn lookup(&self, pos: u64) -> LookupResult<&E::ScalarField, VerkleProof<E>, ()> {
+        if pos >= self.num_leaves() {
+            return LookupResult::NotFound(());
+        }
+        let arity = self.arity() as u64;
+        let mut commitments = Vec::with_capacity(self.height - 1);
+        let mut openings = Vec::with_capacity(self.height);
+        let mut idx = pos;
+        for level in 0..self.height {
+            let (parent, child) = (idx / arity, idx % arity);
+            let poly = self.node_polynomial(level, parent as usize);
+            let (opening, _) =
+                UnivariateKzgPCS::<E>::open(&self.ck, &poly, &self.domain.element(child as usize))
+                    .expect("Prover parameters are checked upon construction");
+            openings.push(opening);
+            if level + 1 < self.height {
+                commitments.push(self.nodes[level][parent as usize]);
+            }
+            idx = parent;
+        }
+        LookupResult::Ok(
+            &self.leaves[pos as usize],
+            VerkleProof {
+                pos,
+                commitments,
+                openings,
+            },
+        )
+    }
+
+    /// Verify a membership proof against a root commitment.
+    /// * `vk` - KZG verifier parameters
+    /// * `arity` - arity of the tree
+    /// * `root` - root commitment of the tree
+    /// * `pos` - position of the leaf
+    /// * `elem` - claimed element at `pos`
+    /// * `proof` - membership proof of `elem`
+    /// * `returns` - `Ok(true)` if the proof is accepted, `Ok(false)` if not.
+    ///   `Err()` if the proof is malformed.
+    pub fn verify(
+        vk: &UnivariateVerifierParam<E>,
+        arity: usize,
+        root: &Commitment<E>,
+        pos: u64,
+        elem: impl Borrow<E::ScalarField>,
+        proof: &VerkleProof<E>,
+    ) -> Result<VerificationResult, PrimitivesError> {
+        let domain = Self::domain(arity)?;
+        let height = proof.tree_height();
+        if height == 0 || proof.commitments.len() + 1 != height {
+            return Err(PrimitivesError::ParameterError(
+                "Malformed Verkle proof".to_string(),
+            ));
+        }
+        if proof.pos != pos {
+            return Ok(Err(()));
+        }
+        let mut value = *elem.borrow();
+        let mut idx = pos;
+        for (level, opening) in proof.openings.iter().enumerate() {
+            let comm = proof.commitments.get(level).unwrap_or(root);
+            let point = domain.element((idx % arity as u64) as usize);
+            if !UnivariateKzgPCS::<E>::verify(vk, comm, &point, &value, opening)
+                .map_err(|e| PrimitivesError::ParameterError(format!("{e}")))?
+            {
+                return Ok(Err(()));
+            }
+            value = Self::commitment_to_field(comm)?;
+            idx /= arity as u64;
+        }
+        // Positions beyond the capacity would otherwise alias smaller ones
+        if idx != 0 {
+            return Ok(Err(()));
+        }
+        Ok(Ok(()))
+    }
+
+    fn domain(arity: usize) -> Result<Radix2EvaluationDomain<E::ScalarField>, PrimitivesError> {
+        match Radix2EvaluationDomain::new(arity) {
+            Some(domain) if arity >= 2 && domain.size() == arity => Ok(domain),
+            _ => Err(PrimitivesError::ParameterError(format!(
+                "Arity {arity} is not a supported power of two"
+            ))),
+        }
+    }
+
+    /// Map a commitment to the scalar field, so that it can be committed by
+    /// its parent.
+    fn commitment_to_field(comm: &Commitment<E>) -> Result<E::ScalarField, PrimitivesError> {
+        let mut bytes = Vec::new();
+        comm.serialize_compressed(&mut bytes)?;
+        Ok(hash_to_field(&bytes, COMMITMENT_DST, 1)?[0])
+    }
+
+    /// Values of the children of the `idx`-th node at height `level + 1`,
+    /// where non-existing children count as zero.
+    fn children_values(&self, level: usize, idx: usize) -> Vec<E::ScalarField> {
+        let arity = self.arity();
+        let mut values = vec![E::ScalarField::zero(); arity];
+        let start = idx * arity;
+        if level == 0 {
+            for (value, leaf) in values.iter_mut().zip(self.leaves.iter().skip(start)) {
+                *value = *leaf;
+            }
+        } else {
+            for (value, comm) in values
+                .iter_mut()
+                .zip(self.nodes[level - 1].iter().skip(start))
+            {
+                // Serializing an affine point never fails
+                *value = Self::commitment_to_field(comm).unwrap();
+            }
+        }
+        values
+    }
+
+    fn node_polynomial(&self, level: usize, idx: usize) -> DensePolynomial<E::ScalarField> {
+        let values = self.children_values(level, idx);
+        DensePolynomial::from_coefficients_vec(self.domain.ifft(&values))
+    }
+
+    fn compute_node(&self, level: usize, idx: usize) -> Result<Commitment<E>, PrimitivesError> {
+        UnivariateKzgPCS::<E>::commit(&self.ck, &self.node_polynomial(level, idx))
+            .map_err(|e| PrimitivesError::InternalError(format!("{e}")))
+    }
+
+    /// Recompute every node above the leaves from `first` to `last`.
+    fn refresh(&mut self, first: u64, last: u64) -> Result<(), PrimitivesError> {
+        let arity = self.arity() as u64;
+        let (mut first, mut last) = (first, last);
+        for level in 0..self.height {
+            first /= arity;
+            last /= arity;
+            for idx in first..=last {
+                let comm = self.compute_node(level, idx as usize)?;
+                match self.nodes[level].get_mut(idx as usize) {
+                    Some(node) => *node = comm,
+                    None => self.nodes[level].push(comm),
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod mt_tests {
+    use crate::{
+        merkle_tree::{verkle::VerkleTree, LookupResult},
+        pcs::prelude::{PolynomialCommitmentScheme, UnivariateKzgPCS},
+    };
+    use ark_bls12_381::Bls12_381;
+    use ark_bn254::Bn254;
+    use ark_ec::pairing::Pairing;
+    use ark_std::{vec, vec::Vec};
+    use jf_utils::test_rng;
+
+    #[test]
+    fn test_verkle_tree() {
+        test_verkle_tree_helper::<Bn254>();
+        test_verkle_tree_helper::<Bls12_381>();
+    }
+
+    fn test_verkle_tree_helper<E: Pairing>() {
+        let rng = &mut test_rng();
+        let srs = UnivariateKzgPCS::<E>::gen_srs_for_testing(rng, 7).unwrap();
+        let (ck, vk) = UnivariateKzgPCS::<E>::trim(&srs, 7, None).unwrap();
+        let (small_ck, _) = UnivariateKzgPCS::<E>::trim(&srs, 2, None).unwrap();
+
+        assert!(VerkleTree::<E>::new(&ck, 3, 2).is_err());
+        assert!(VerkleTree::<E>::new(&ck, 1, 2).is_err());
+        assert!(VerkleTree::<E>::new(&ck, 4, 0).is_err());
+        assert!(VerkleTree::<E>::new(&small_ck, 4, 2).is_err());
+
+        let elems: Vec<_> = (0..20u64).map(E::ScalarField::from).collect();
+        let mut mt = VerkleTree::<E>::new(&ck, 4, 3).unwrap();
+        assert_eq!(mt.capacity(), 64);
+        for elem in elems.iter() {
+            mt.push(elem).unwrap();
+        }
+        let batch = VerkleTree::<E>::from_elems(&ck, 4, 3, &elems).unwrap();
+        assert_eq!(mt.num_leaves(), 20);
+        assert_eq!(mt.commitment(), batch.commitment());
+        assert!(matches!(mt.lookup(20), LookupResult::NotFound(())));
+
+        let root = mt.commitment();
+        for pos in [0u64, 5, 19] {
+            let (elem, proof) = mt.lookup(pos).expect_ok().unwrap();
+            assert_eq!(elem, &elems[pos as usize]);
+            assert_eq!(proof.tree_height(), 3);
+            assert!(VerkleTree::<E>::verify(&vk, 4, &root, pos, elem, &proof)
+                .unwrap()
+                .is_ok());
+            assert!(
+                VerkleTree::<E>::verify(&vk, 4, &root, pos + 1, elem, &proof)
+                    .unwrap()
+                    .is_err()
+            );
+            assert!(
+                VerkleTree::<E>::verify(&vk, 4, &root, pos, elems[1] + elem, &proof)
+                    .unwrap()
+                    .is_err()
+            );
+            assert!(VerkleTree::<E>::verify(&vk, 2, &root, pos, elem, &proof)
+                .unwrap()
+                .is_err());
+            assert!(VerkleTree::<E>::verify(&vk, 3, &root, pos, elem, &proof).is_err());
+        }
+
+        // Updating a leaf changes the root and invalidates old proofs
+        let (_, old_proof) = mt.lookup(5).expect_ok().unwrap();
+        let new_elem = E::ScalarField::from(100u64);
+        assert_eq!(mt.update(5, new_elem).unwrap(), elems[5]);
+        assert!(mt.update(20, new_elem).is_err());
+        let new_root = mt.commitment();
+        assert_ne!(root, new_root);
+        assert!(
+            VerkleTree::<E>::verify(&vk, 4, &new_root, 5, elems[5], &old_proof)
+                .unwrap()
+                .is_err()
+        );
+        let (_, proof) = mt.lookup(5).expect_ok().unwrap();
+        assert!(
+            VerkleTree::<E>::verify(&vk, 4, &new_root, 5, new_elem, &proof)
+                .unwrap()
+                .is_ok()
+        );
+
+        // Capacity
+        assert!(mt.extend(vec![new_elem; 45]).is_err());
+        mt.extend(vec![new_elem; 44]).unwrap();
+        assert!(mt.push(new_elem).is_err());
+        let (_, proof) = mt.lookup(63).expect_ok().unwrap();
+        assert!(
+            VerkleTree::<E>::verify(&vk, 4, &mt.commitment(), 63, new_elem, &proof)
+                .unwrap()
+                .is_ok()
+        );
+    }
+}
@@ -0,0 +1,402 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+//! A versioned Merkle tree retains the last few states of an underlying tree,
+//! so that lookups and proofs can be served against recent historical roots
+//! and not only the latest one.
+//!
+//! Updates are applied in batches, and every successful batch produces a new
+//! version. Each retained version is a snapshot of the whole underlying tree,
+//! so the memory cost grows with the number of retained versions.
+//!
+//! Services which only need to accept proofs against recent roots, without
+//! serving them, can keep a [`RootHistory`] instead.
+
+use super::{
+    LookupResult, MerkleCommitment, MerkleTreeScheme, MissingSubtree, NodeValue,
+    UniversalMerkleTreeScheme,
+};
+use crate::errors::{PrimitivesError, VerificationResult};
+use ark_std::{borrow::Borrow, collections::VecDeque, string::ToString};
+
+/// A Merkle tree which keeps its `max_versions` latest versions, see the
+/// [module documentation](self).
+#[derive(Debug, Clone)]
+pub struct VersionedMerkleTree<MT>
+where
+    MT: MerkleTreeScheme + Clone,
+{
+    /// Retained versions of the tree along with their version numbers, from
+    /// the oldest to the latest
+    versions: VecDeque<(u64, MT)>,
+    /// Maximum number of retained versions
+    max_versions: usize,
+}
+
+impl<MT> VersionedMerkleTree<MT>
+where
+    MT: MerkleTreeScheme + Clone,
+{
+    /// Start versioning the given tree, which becomes version 0.
+    /// * `max_versions` - number of versions to retain, at least 1
+    pub fn new(tree: MT, max_versions: usize) -> Result<Self, PrimitivesError> {
+        if max_versions == 0 {
+            return Err(PrimitivesError::ParameterError(
+                "At least one version must be retained".to_string(),
+            ));
+        }
+        let mut versions = VecDeque::with_capacity(max_versions);
+        versions.push_back((0, tree));
+        Ok(Self {
+            versions,
+            max_versions,
+        })
+    }
+
+    /// Apply a batch of updates on top of the latest version, producing a new
+    /// version. The batch is atomic: if `updates` fails, the error is returned
+    /// and no version is created.
+    /// * `returns` - the number of the new version
+    pub fn apply<F>(&mut self, updates: F) -> Result<u64, PrimitivesError>
+    where
+        F: FnOnce(&mut MT) -> Result<(), PrimitivesError>,
+    {
+        let mut tree = self.latest().clone();
+        updates(&mut tree)?;
+        let version = self.latest_version() + 1;
+        if self.versions.len() == self.max_versions {
+            self.versions.pop_front();
+        }
+        self.versions.push_back((version, tree));
+        Ok(version)
+    }
+
+    /// Return the number of the latest version.
+    pub fn latest_version(&self) -> u64 {
+        self.versions.back().unwrap().0
+    }
+
+    /// Return the latest version of the tree.
+    pub fn latest(&self) -> &MT {
+        &self.versions.back().unwrap().1
+    }
+
+    /// Return the numbers of the retained versions, from the oldest to the
+    /// latest.
+    pub fn versions(&self) -> impl Iterator<Item = u64> + '_ {
+        self.versions.iter().map(|(version, _)| *version)
+    }
+
+    /// Return the given version of the tree, or `None` if it is not retained.
+    pub fn version(&self, version: u64) -> Option<&MT> {
+        // Retained version numbers are consecutive
+        let oldest = self.versions.front().unwrap().0;
+        let idx = version.checked_sub(oldest)?;
+        self.versions.get(idx as usize).map(|(_, tree)| tree)
+    }
+
+    /// Return the commitment of the given version of the tree, or `None` if it
+    /// is not retained.
+    pub fn commitment(&self, version: u64) -> Option<MT::Commitment> {
+        self.version(version).map(|tree| tree.commitment())
+    }
+
+    /// Return the latest retained version whose root is `root`, if any.
+    pub fn version_by_root(&self, root: impl Borrow<MT::NodeValue>) -> Option<u64> {
+        self.versions
+            .iter()
+            .rev()
+            .find(|(_, tree)| tree.commitment().digest() == *root.borrow())
+            .map(|(version, _)| *version)
+    }
+
+    /// Look up an element in the given version of the tree, see
+    /// [`MerkleTreeScheme::lookup`].
+    /// * `returns` - same as [`MerkleTreeScheme::lookup`], and
+    ///   `LookupResult::NotInMemory` if the version is not retained
+    pub fn lookup(
+        &self,
+        version: u64,
+        pos: impl Borrow<MT::Index>,
+    ) -> LookupResult<&MT::Element, MT::MembershipProof, (), Option<MissingSubtree<MT::NodeValue>>>
+    {
+        match self.version(version) {
+            Some(tree) => tree.lookup(pos),
+            None => LookupResult::NotInMemory(None),
+        }
+    }
+}
+
+impl<MT> VersionedMerkleTree<MT>
+where
+    MT: UniversalMerkleTreeScheme + Clone,
+{
+    /// Look up an element in the given version of the tree, see
+    /// [`UniversalMerkleTreeScheme::universal_lookup`].
+    /// * `returns` - same as [`UniversalMerkleTreeScheme::universal_lookup`],
+    ///   and `LookupResult::NotInMemory` if the version is not retained
+    pub fn universal_lookup(
+        &self,
+        version: u64,
+        pos: impl Borrow<MT::Index>,
+    ) -> LookupResult<
+        &MT::Element,
+        MT::MembershipProof,
+        MT::NonMembershipProof,
+        Option<MissingSubtree<MT::NodeValue>>,
+    > {
+        match self.version(version) {
+            Some(tree) => tree.universal_lookup(pos),
+            None => LookupResult::NotInMemory(None),
+        }
+    }
+}
+
+/// A ring buffer of the `capacity` latest roots of a tree, against which
+/// slightly stale proofs can be verified, see
+/// [`MerkleTreeScheme::verify_against_roots`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RootHistory<T: NodeValue> {
+    /// Retained roots, from the oldest to the latest
+    roots: VecDeque<T>,
+    /// Maximum number of retained roots
+    capacity: usize,
+}
+
+impl<T: NodeValue> RootHistory<T> {
+    /// Create an empty history.
+    /// * `capacity` - number of roots to retain, at least 1
+    pub fn new(capacity: usize) -> Result<Self, PrimitivesError> {
+        if capacity == 0 {
+            return Err(PrimitivesError::ParameterError(
+                "At least one root must be retained".to_string(),
+            ));
+        }
+        Ok(Self {
+            roots: VecDeque::with_capacity(capacity),
+            capacity,
+        })
+    }
+
+    /// Record a new latest root, dropping the oldest one if the history is
+    /// full. The same root may be recorded several times, e.g. for blocks
+    /// which did not change the tree, and then takes several slots.
+    pub fn push(&mut self, root: T) {
+        if self.roots.len() == self.capacity {
+            self.roots.pop_front();
+        }
+        self.roots.push_back(root);
+    }
+
+    /// Return the maximum number of retained roots.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Return the latest root, or `None` if no root has been recorded.
+    pub fn latest(&self) -> Option<&T> {
+        self.roots.back()
+    }
+
+    /// Return the retained roots, from the oldest to the latest.
+    pub fn roots(&self) -> impl Iterator<Item = &T> + '_ {
+        self.roots.iter()
+    }
+
+    /// Return whether `root` is one of the retained roots.
+    pub fn contains(&self, root: impl Borrow<T>) -> bool {
+        self.roots.contains(root.borrow())
+    }
+
+    /// Verify a membership proof against any of the retained roots, see
+    /// [`MerkleTreeScheme::verify_against_roots`].
+    pub fn verify<MT>(
+        &self,
+        pos: impl Borrow<MT::Index>,
+        proof: impl Borrow<MT::MembershipProof>,
+    ) -> Result<VerificationResult, PrimitivesError>
+    where
+        MT: MerkleTreeScheme<NodeValue = T>,
+    {
+        // Recent proofs are the most common, start from the latest root
+        MT::verify_against_roots(self.roots.iter().rev(), pos, proof)
+    }
+}
+
+#[cfg(test)]
+mod mt_tests {
+    use crate::{
+        merkle_tree::{
+            prelude::{RescueMerkleTree, RescueSparseMerkleTree},
+            versioned::{RootHistory, VersionedMerkleTree},
+            *,
+        },
+        rescue::RescueParameter,
+    };
+    use ark_ed_on_bls12_377::Fq as Fq377;
+    use ark_ed_on_bls12_381::Fq as Fq381;
+    use ark_ed_on_bn254::Fq as Fq254;
+    use num_bigint::BigUint;
+
+    #[test]
+    fn test_versioned_mt() {
+        test_versioned_mt_helper::<Fq254>();
+        test_versioned_mt_helper::<Fq377>();
+        test_versioned_mt_helper::<Fq381>();
+    }
+
+    fn test_versioned_mt_helper<F: RescueParameter>() {
+        assert!(VersionedMerkleTree::new(RescueMerkleTree::<F>::new(3), 0).is_err());
+        let mut mt = VersionedMerkleTree::new(RescueMerkleTree::<F>::new(3), 3).unwrap();
+        assert_eq!(mt.latest_version(), 0);
+
+        let mut roots = vec![mt.commitment(0).unwrap().digest()];
+        for i in 1..=5u64 {
+            let version = mt
+                .apply(|tree| tree.extend((0..i).map(|j| F::from(10 * i + j))))
+                .unwrap();
+            assert_eq!(version, i);
+            roots.push(mt.commitment(version).unwrap().digest());
+        }
+        assert_eq!(mt.versions().collect::<Vec<_>>(), vec![3, 4, 5]);
+        assert_eq!(mt.latest().num_leaves(), 15);
+
+        // Proofs are served against every retained version
+        for version in 3..=5u64 {
+            assert_eq!(mt.version_by_root(roots[version as usize]), Some(version));
+            let num_leaves = mt.version(version).unwrap().num_leaves();
+            assert_eq!(num_leaves, version * (version + 1) / 2);
+            let (elem, proof) = mt.lookup(version, num_leaves - 1).expect_ok().unwrap();
+            assert_eq!(elem, &F::from(11 * version - 1));
+            assert!(
+                RescueMerkleTree::<F>::verify(roots[version as usize], num_leaves - 1, &proof)
+                    .unwrap()
+                    .is_ok()
+            );
+            assert!(mt.lookup(version, num_leaves).expect_not_found().is_ok());
+        }
+
+        // Old versions are dropped
+        assert!(mt.version(2).is_none());
+        assert!(mt.commitment(2).is_none());
+        assert!(mt.version_by_root(roots[2]).is_none());
+        assert!(mt.lookup(2, 0).expect_not_in_memory().is_ok());
+        assert!(mt.version(6).is_none());
+
+        // A failed batch does not produce a new version
+        assert!(mt.apply(|tree| tree.extend([F::from(0u64); 20])).is_err());
+        assert_eq!(mt.latest_version(), 5);
+        assert_eq!(mt.latest().num_leaves(), 15);
+    }
+
+    #[test]
+    fn test_root_history() {
+        test_root_history_helper::<Fq254>();
+        test_root_history_helper::<Fq377>();
+        test_root_history_helper::<Fq381>();
+    }
+
+    fn test_root_history_helper<F: RescueParameter>() {
+        assert!(RootHistory::<F>::new(0).is_err());
+        let mut history = RootHistory::new(3).unwrap();
+        assert!(history.latest().is_none());
+        let mut mt = RescueMerkleTree::<F>::new(3);
+        let mut proofs = vec![];
+        for i in 0..5u64 {
+            mt.push(F::from(i)).unwrap();
+            history.push(mt.commitment().digest());
+            proofs.push(mt.lookup(i).expect_ok().unwrap().1);
+        }
+        assert_eq!(history.roots().count(), 3);
+        assert_eq!(history.latest(), Some(&mt.commitment().digest()));
+
+        // Proofs against the 3 latest roots are accepted, older ones aren't
+        for (i, proof) in proofs.iter().enumerate() {
+            let accepted = history
+                .verify::<RescueMerkleTree<F>>(i as u64, proof)
+                .unwrap()
+                .is_ok();
+            assert_eq!(accepted, i >= 2);
+        }
+        let roots: Vec<F> = history.roots().copied().collect();
+        assert!(
+            RescueMerkleTree::<F>::verify_against_roots(&roots, 2u64, &proofs[2])
+                .unwrap()
+                .is_ok()
+        );
+        assert!(
+            RescueMerkleTree::<F>::verify_against_roots(&roots[1..], 2u64, &proofs[2])
+                .unwrap()
+                .is_err()
+        );
+        assert!(
+            RescueMerkleTree::<F>::verify_against_roots(&[] as &[F], 2u64, &proofs[2])
+                .unwrap()
+                .is_err()
+        );
+        // A proof for another position is rejected against every root
+        assert!(history
+            .verify::<RescueMerkleTree<F>>(3u64, &proofs[4])
+            .unwrap()
+            .is_err());
+        assert!(!history.contains(F::from(0u64)));
+        assert!(history.contains(roots[0]));
+    }
+
+    #[test]
+    fn test_versioned_universal_mt() {
+        test_versioned_universal_mt_helper::<Fq254>();
+        test_versioned_universal_mt_helper::<Fq377>();
+        test_versioned_universal_mt_helper::<Fq381>();
+    }
+
+    fn test_versioned_universal_mt_helper<F: RescueParameter>() {
+        let mut mt =
+            VersionedMerkleTree::new(RescueSparseMerkleTree::<BigUint, F>::new(10), 2).unwrap();
+        mt.apply(|tree| {
+            tree.update(BigUint::from(1u64), F::from(1u64))?;
+            tree.update(BigUint::from(2u64), F::from(2u64))?;
+            Ok(())
+        })
+        .unwrap();
+        mt.apply(|tree| tree.update(BigUint::from(1u64), F::from(3u64)).map(|_| ()))
+            .unwrap();
+
+        let old_root = mt.commitment(1).unwrap().digest();
+        let (elem, proof) = mt
+            .universal_lookup(1, BigUint::from(1u64))
+            .expect_ok()
+            .unwrap();
+        assert_eq!(elem, &F::from(1u64));
+        assert!(RescueSparseMerkleTree::<BigUint, F>::verify(
+            &old_root,
+            BigUint::from(1u64),
+            &proof
+        )
+        .unwrap()
+        .is_ok());
+        let (elem, _) = mt
+            .universal_lookup(2, BigUint::from(1u64))
+            .expect_ok()
+            .unwrap();
+        assert_eq!(elem, &F::from(3u64));
+        let proof = mt
+            .universal_lookup(1, BigUint::from(3u64))
+            .expect_not_found()
+            .unwrap();
+        assert!(RescueSparseMerkleTree::<BigUint, F>::verify_non_membership(
+            &old_root,
+            BigUint::from(3u64),
+            &proof
+        )
+        .unwrap()
+        .is_ok());
+        assert!(mt
+            .universal_lookup(0, BigUint::from(1u64))
+            .expect_not_in_memory()
+            .is_ok());
+    }
+}
@@ -0,0 +1,18 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+//! The Goldilocks field of order `p = 2^64 - 2^32 + 1`.
+
+use ark_ff::fields::{Fp64, MontBackend, MontConfig};
+
+/// Configuration of the Goldilocks field.
+#[derive(MontConfig)]
+#[modulus = "18446744069414584321"]
+#[generator = "7"]
+pub struct GoldilocksConfig;
+
+/// The Goldilocks field of order `p = 2^64 - 2^32 + 1`.
+pub type Goldilocks = Fp64<MontBackend<GoldilocksConfig, 1>>;
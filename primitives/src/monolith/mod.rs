@@ -0,0 +1,342 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+#![deny(missing_docs)]
+//! This module implements the Monolith hash function over the
+//! [Goldilocks](goldilocks::Goldilocks) field.
+//!
+//! The permutation follows the [Monolith paper](https://eprint.iacr.org/2023/1025.pdf)
+//! for a state of 12 elements: an initial `Concrete` layer, then 6 rounds of
+//! - `Bars`, which decomposes each of the first 4 elements into 8 bytes and
+//!   applies the 8-bit S-box `y -> (y ^ (!y <<< 1 & y <<< 2 & y <<< 3)) <<< 1`
+//!   to each of them,
+//! - `Bricks`, the Feistel layer `x_i -> x_i + x_{i-1}^2` for `i > 0`,
+//! - `Concrete`, the multiplication by the circulant MDS matrix of the paper,
+//! - the addition of the round constants, except for the last round.
+//!
+//! `Bars` is a lookup table rather than a low degree map, so Monolith is
+//! meant for proof systems with lookup arguments over 64-bit fields.
+//!
+//! The round constants are derived as in the [reference implementation](https://github.com/HorizenLabs/monolith).
+//! SHAKE128 is fed with `"Monolith"`, the bytes `STATE_SIZE` and `ROUNDS`,
+//! the field order as 8 little-endian bytes and the bit sizes of the 8 limbs
+//! of `Bars`. Each constant is then the next 8-byte little-endian integer of
+//! its output which is smaller than the field order. They are generated once
+//! on first use.
+//!
+//! Unlike the plonky2 sponge of the reference implementation, which
+//! overwrites the rate with the input, [`MonolithSponge`] adds the input to
+//! the rate, as the other sponges of this crate.
+
+#![deny(warnings)]
+
+pub mod goldilocks;
+
+use crate::{
+    errors::PrimitivesError,
+    sponge::{PermutationSponge, SpongePermutation},
+};
+use ark_crypto_primitives::sponge::{CryptographicSponge, FieldBasedCryptographicSponge};
+use ark_ff::{One, PrimeField};
+use ark_std::{boxed::Box, format, vec::Vec};
+use goldilocks::Goldilocks;
+use jf_utils::pad_with_zeros;
+use once_cell::race::OnceBox;
+use sha3::{
+    digest::{ExtendableOutput, Update, XofReader},
+    Shake128,
+};
+
+/// The state size of monolith hash.
+pub const STATE_SIZE: usize = 12;
+/// The rate of the sponge used in MonolithCRHF.
+pub const CRHF_RATE: usize = 8;
+/// The # of rounds of monolith hash.
+pub const ROUNDS: usize = 6;
+/// The # of elements going through `Bars`.
+const NUM_BARS: usize = 4;
+/// First row of the circulant matrix of `Concrete`.
+const MDS_ROW: [u64; STATE_SIZE] = [7, 23, 8, 26, 13, 10, 9, 7, 6, 22, 21, 8];
+
+/// The Monolith permutation over a state of `STATE_SIZE` Goldilocks elements,
+/// see the [module documentation](self).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MonolithPermutation {
+    /// Round constants of all rounds but the last one, in order
+    round_constants: Vec<[Goldilocks; STATE_SIZE]>,
+}
+
+impl Default for MonolithPermutation {
+    fn default() -> Self {
+        Self::get().clone()
+    }
+}
+
+impl MonolithPermutation {
+    /// The monolith permutation, generated on first use.
+    pub fn get() -> &'static Self {
+        static PERMUTATION: OnceBox<MonolithPermutation> = OnceBox::new();
+        PERMUTATION.get_or_init(|| Box::new(Self::derive()))
+    }
+
+    /// Derive the round constants of the permutation.
+    fn derive() -> Self {
+        let modulus = Goldilocks::MODULUS.0[0];
+        let mut hasher = Shake128::default();
+        hasher.update(b"Monolith");
+        hasher.update(&[STATE_SIZE as u8, ROUNDS as u8]);
+        hasher.update(&modulus.to_le_bytes());
+        hasher.update(&[8; 8]);
+        let mut reader = hasher.finalize_xof();
+        let mut next = || loop {
+            let mut bytes = [0u8; 8];
+            reader.read(&mut bytes);
+            let value = u64::from_le_bytes(bytes);
+            if value < modulus {
+                return Goldilocks::from(value);
+            }
+        };
+        let round_constants = (1..ROUNDS)
+            .map(|_| [(); STATE_SIZE].map(|_| next()))
+            .collect();
+        Self { round_constants }
+    }
+
+    /// Return the round constants, which are added after every round but the
+    /// last one.
+    pub fn round_constants(&self) -> &[[Goldilocks; STATE_SIZE]] {
+        &self.round_constants
+    }
+
+    /// Compute the permutation on `input`.
+    pub fn eval(&self, input: &[Goldilocks; STATE_SIZE]) -> [Goldilocks; STATE_SIZE] {
+        let mut state = *input;
+        self.permute_in_place(&mut state);
+        state
+    }
+
+    /// Compute the permutation on `state` in place.
+    pub fn permute_in_place(&self, state: &mut [Goldilocks; STATE_SIZE]) {
+        concrete(state);
+        for round in 0..ROUNDS {
+            bars(state);
+            bricks(state);
+            concrete(state);
+            if let Some(constants) = self.round_constants.get(round) {
+                state.iter_mut().zip(constants).for_each(|(x, c)| *x += c);
+            }
+        }
+    }
+}
+
+/// The 8-bit S-box of `Bars`.
+fn s_box(y: u8) -> u8 {
+    (y ^ ((!y).rotate_left(1) & y.rotate_left(2) & y.rotate_left(3))).rotate_left(1)
+}
+
+/// Apply the S-box to every byte of the first `NUM_BARS` elements. The
+/// result is always canonical for the Goldilocks field.
+fn bars(state: &mut [Goldilocks; STATE_SIZE]) {
+    state[..NUM_BARS].iter_mut().for_each(|x| {
+        let bytes = x.into_bigint().0[0].to_le_bytes().map(s_box);
+        *x = Goldilocks::from(u64::from_le_bytes(bytes));
+    });
+}
+
+/// Compute `x_i -> x_i + x_{i-1}^2` for `i > 0`.
+fn bricks(state: &mut [Goldilocks; STATE_SIZE]) {
+    for i in (1..STATE_SIZE).rev() {
+        state[i] += state[i - 1].square();
+    }
+}
+
+/// Multiply `state` by the circulant matrix of first row `MDS_ROW`.
+fn concrete(state: &mut [Goldilocks; STATE_SIZE]) {
+    let input = *state;
+    for (row, x) in state.iter_mut().enumerate() {
+        *x = MDS_ROW
+            .iter()
+            .enumerate()
+            .map(|(i, m)| Goldilocks::from(*m) * input[(i + row) % STATE_SIZE])
+            .sum();
+    }
+}
+
+impl SpongePermutation<Goldilocks, STATE_SIZE> for MonolithPermutation {
+    fn permute(&self, state: &mut [Goldilocks; STATE_SIZE]) {
+        self.permute_in_place(state);
+    }
+}
+
+/// A sponge over the monolith permutation, absorbing `RATE` elements per
+/// permutation into the first elements of the state.
+pub type MonolithSponge<const RATE: usize> =
+    PermutationSponge<Goldilocks, MonolithPermutation, STATE_SIZE, RATE>;
+
+/// CRHF
+#[derive(Debug, Clone)]
+pub struct MonolithCRHF {
+    sponge: MonolithSponge<CRHF_RATE>,
+}
+
+impl MonolithCRHF {
+    /// Sponge hashing based on monolith permutation for RATE 8. It allows
+    /// unrestricted variable length input and returns a vector of
+    /// `num_outputs` elements, with "bit padding": "1" is always appended,
+    /// then as many "0" as required for the overall length to be a multiple
+    /// of RATE.
+    pub fn sponge_with_bit_padding(input: &[Goldilocks], num_outputs: usize) -> Vec<Goldilocks> {
+        let mut padded = input.to_vec();
+        padded.push(Goldilocks::one());
+        pad_with_zeros(&mut padded, CRHF_RATE);
+        Self::sponge_no_padding(padded.as_slice(), num_outputs)
+            .expect("Bug in JF Primitives : bad padding of input for FSKS construction")
+    }
+
+    /// Similar to [`MonolithCRHF::sponge_with_bit_padding`] except we use
+    /// "zero padding" where as many "0" as required are added for the overall
+    /// length to be a multiple of RATE.
+    pub fn sponge_with_zero_padding(input: &[Goldilocks], num_outputs: usize) -> Vec<Goldilocks> {
+        let mut padded = input.to_vec();
+        pad_with_zeros(&mut padded, CRHF_RATE);
+        Self::sponge_no_padding(padded.as_slice(), num_outputs)
+            .expect("Bug in JF Primitives : bad padding of input for FSKS construction")
+    }
+
+    /// Sponge hashing based on monolith permutation for RATE 8 and CAPACITY
+    /// 4. It allows inputs with length that is a multiple of `CRHF_RATE` and
+    /// returns a vector of `num_outputs` elements.
+    pub fn sponge_no_padding(
+        input: &[Goldilocks],
+        num_output: usize,
+    ) -> Result<Vec<Goldilocks>, PrimitivesError> {
+        if input.len() % CRHF_RATE != 0 {
+            return Err(PrimitivesError::ParameterError(format!(
+                "Monolith sponge Error : input length {} to sponge hashing function is not \
+                 multiple of RATE.",
+                input.len()
+            )));
+        }
+        let mut crhf = Self {
+            sponge: MonolithSponge::new(MonolithPermutation::get()),
+        };
+        // ABSORB PHASE
+        crhf.sponge.absorb(&input);
+        // SQUEEZE PHASE
+        Ok(crhf.sponge.squeeze_native_field_elements(num_output))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use ark_ff::Zero;
+    use ark_std::UniformRand;
+
+    #[test]
+    fn test_monolith_permutation() {
+        let rng = &mut jf_utils::test_rng();
+        let permutation = MonolithPermutation::get();
+        assert_eq!(permutation.round_constants().len(), ROUNDS - 1);
+        // permutation is cached
+        assert!(core::ptr::eq(permutation, MonolithPermutation::get()));
+        assert_eq!(&MonolithPermutation::default(), permutation);
+
+        // The S-box is a permutation of the bytes, and Bars maps the largest
+        // field elements to field elements
+        let mut images: Vec<u8> = (0..=255).map(s_box).collect();
+        images.sort_unstable();
+        assert_eq!(images, (0..=255).collect::<Vec<u8>>());
+        for value in Goldilocks::MODULUS.0[0] - 5..Goldilocks::MODULUS.0[0] {
+            let bytes = value.to_le_bytes().map(s_box);
+            assert!(u64::from_le_bytes(bytes) < Goldilocks::MODULUS.0[0]);
+        }
+
+        let state = [(); STATE_SIZE].map(|_| Goldilocks::rand(rng));
+        let output = permutation.eval(&state);
+        assert_ne!(output, state);
+        let mut other = state;
+        other[STATE_SIZE - 1] += Goldilocks::one();
+        let other_output = permutation.eval(&other);
+        assert!(output.iter().zip(other_output).all(|(x, y)| *x != y));
+    }
+
+    // The permutation vector is the one of the reference implementation at
+    // https://github.com/HorizenLabs/monolith, for Monolith-64 with a state of
+    // 12 elements. The sponge vector is computed from it for the sponge of
+    // this module, which adds the input to the rate.
+
+    #[test]
+    fn test_monolith_vectors() {
+        let input = [0u64, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11].map(Goldilocks::from);
+        let expected = [
+            5867581605548782913u64,
+            588867029099903233,
+            6043817495575026667,
+            805786589926590032,
+            9919982299747097782,
+            6718641691835914685,
+            7951881005429661950,
+            15453177927755089358,
+            974633365445157727,
+            9654662171963364206,
+            6281307445101925412,
+            13745376999934453119,
+        ]
+        .map(Goldilocks::from);
+        assert_eq!(MonolithPermutation::get().eval(&input), expected);
+
+        let input: Vec<Goldilocks> = (1..=16u64).map(Goldilocks::from).collect();
+        let expected = [
+            17425356484415476508u64,
+            16741471104723109624,
+            17134907751234173302,
+            15514948714424653404,
+        ]
+        .map(Goldilocks::from);
+        assert_eq!(
+            MonolithCRHF::sponge_no_padding(&input, 4).unwrap(),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_monolith_crhf() {
+        let rng = &mut jf_utils::test_rng();
+        let input: Vec<Goldilocks> = (0..2 * CRHF_RATE).map(|_| Goldilocks::rand(rng)).collect();
+        let output = MonolithCRHF::sponge_no_padding(&input, 10).unwrap();
+        assert_eq!(output.len(), 10);
+        assert_eq!(
+            output[..4],
+            MonolithCRHF::sponge_no_padding(&input, 4).unwrap()
+        );
+
+        // The sponge absorbs into the rate and squeezes from it
+        let permutation = MonolithPermutation::get();
+        let mut state = [Goldilocks::zero(); STATE_SIZE];
+        for chunk in input.chunks(CRHF_RATE) {
+            state.iter_mut().zip(chunk).for_each(|(x, elem)| *x += elem);
+            permutation.permute_in_place(&mut state);
+        }
+        assert_eq!(output[..CRHF_RATE], state[..CRHF_RATE]);
+        assert_eq!(output[CRHF_RATE..], permutation.eval(&state)[..2]);
+
+        assert!(MonolithCRHF::sponge_no_padding(&input[..CRHF_RATE + 1], 1).is_err());
+        let mut padded = input[..3].to_vec();
+        padded.resize(CRHF_RATE, Goldilocks::zero());
+        assert_eq!(
+            MonolithCRHF::sponge_with_zero_padding(&input[..3], 1),
+            MonolithCRHF::sponge_no_padding(&padded, 1).unwrap()
+        );
+        let mut padded = input[..CRHF_RATE].to_vec();
+        padded.push(Goldilocks::one());
+        padded.resize(2 * CRHF_RATE, Goldilocks::zero());
+        assert_eq!(
+            MonolithCRHF::sponge_with_bit_padding(&input[..CRHF_RATE], 1),
+            MonolithCRHF::sponge_no_padding(&padded, 1).unwrap()
+        );
+    }
+}
@@ -0,0 +1,154 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+//! Poseidon over the bn254 scalar field, compatible with the `Poseidon` and
+//! `PoseidonEx` templates of [circomlib](https://github.com/iden3/circomlib)
+//! and with `circomlibjs`.
+//!
+//! Hashing `n` inputs uses a state of `t = n + 1` elements, 8 full rounds and
+//! the # of partial rounds of circomlib for that width. Round constants and
+//! MDS matrices are derived from the Grain LFSR of the reference
+//! implementation, exactly like [`super::PoseidonParameter`] but for a state
+//! size of `t`. There is no sponge padding: the state is
+//! `[initial_state, inputs...]`, it is permuted once, and the outputs are the
+//! first elements of the permuted state.
+
+use super::{constants, PoseidonConstants};
+use crate::errors::PrimitivesError;
+use ark_bn254::Fr;
+use ark_ff::Zero;
+use ark_std::{format, vec::Vec};
+
+/// The maximum # of inputs of a circomlib poseidon hash.
+pub const MAX_INPUTS: usize = 16;
+
+/// Poseidon hash with circomlib parameters, see the [module
+/// documentation](self).
+#[derive(Debug, Clone, Copy)]
+pub struct CircomPoseidon;
+
+impl CircomPoseidon {
+    /// The poseidon constants used to hash `num_inputs` elements.
+    /// * `num_inputs` - between 1 and `MAX_INPUTS`
+    /// * `returns` - Err() if `num_inputs` is out of range
+    pub fn constants(num_inputs: usize) -> Result<PoseidonConstants<Fr>, PrimitivesError> {
+        if num_inputs == 0 || num_inputs > MAX_INPUTS {
+            return Err(PrimitivesError::ParameterError(format!(
+                "Circom poseidon Error : {} inputs, expected between 1 and {}.",
+                num_inputs, MAX_INPUTS
+            )));
+        }
+        Ok(constants::CIRCOM[num_inputs - 1])
+    }
+
+    /// Same as `Poseidon(n)` of circomlib, i.e. `poseidon(inputs)` of
+    /// circomlibjs.
+    /// * `inputs` - between 1 and `MAX_INPUTS` elements
+    /// * `returns` - Err() if the # of inputs is out of range
+    pub fn hash(inputs: &[Fr]) -> Result<Fr, PrimitivesError> {
+        Ok(Self::hash_ex(Fr::zero(), inputs, 1)?[0])
+    }
+
+    /// Same as `PoseidonEx(n, num_outputs)` of circomlib, i.e.
+    /// `poseidon(inputs, initial_state, num_outputs)` of circomlibjs.
+    /// * `initial_state` - the first element of the state, 0 for `hash`
+    /// * `inputs` - between 1 and `MAX_INPUTS` elements
+    /// * `num_outputs` - at most `inputs.len() + 1`
+    /// * `returns` - Err() if the # of inputs or outputs is out of range
+    pub fn hash_ex(
+        initial_state: Fr,
+        inputs: &[Fr],
+        num_outputs: usize,
+    ) -> Result<Vec<Fr>, PrimitivesError> {
+        let constants = Self::constants(inputs.len())?;
+        if num_outputs > inputs.len() + 1 {
+            return Err(PrimitivesError::ParameterError(format!(
+                "Circom poseidon Error : {} outputs, but the state has only {} elements.",
+                num_outputs,
+                inputs.len() + 1
+            )));
+        }
+        let mut state = Vec::with_capacity(inputs.len() + 1);
+        state.push(initial_state);
+        state.extend_from_slice(inputs);
+        constants.permute(&mut state);
+        state.truncate(num_outputs);
+        Ok(state)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{super::FULL_ROUNDS, *};
+    use ark_crypto_primitives::sponge::poseidon::find_poseidon_ark_and_mds;
+    use ark_ff::{MontFp, PrimeField};
+    use ark_std::{vec, UniformRand};
+
+    #[test]
+    fn test_circom_vectors() {
+        // test vectors of circomlibjs
+        assert_eq!(
+            CircomPoseidon::hash(&[Fr::from(1u64)]).unwrap(),
+            MontFp!(
+                "18586133768512220936620570745912940619677854269274689475585506675881198879027"
+            )
+        );
+        assert_eq!(
+            CircomPoseidon::hash(&[Fr::from(1u64), Fr::from(2u64)]).unwrap(),
+            MontFp!("7853200120776062878684798364095072458815029376092732009249414926327459813530")
+        );
+        let inputs: Vec<Fr> = (1..=4u64).map(Fr::from).collect();
+        assert_eq!(
+            CircomPoseidon::hash(&inputs).unwrap(),
+            MontFp!(
+                "18821383157269793795438455681495246036402687001665670618754263018637548127333"
+            )
+        );
+    }
+
+    /// The # of partial rounds for `1..=MAX_INPUTS` inputs, from circomlib.
+    const PARTIAL_ROUNDS: [usize; MAX_INPUTS] = [
+        56, 57, 56, 60, 60, 63, 64, 63, 60, 66, 60, 65, 70, 60, 64, 68,
+    ];
+
+    #[test]
+    fn test_circom_poseidon() {
+        let rng = &mut jf_utils::test_rng();
+        for num_inputs in 1..=MAX_INPUTS {
+            let constants = CircomPoseidon::constants(num_inputs).unwrap();
+            assert_eq!(constants.partial_rounds, PARTIAL_ROUNDS[num_inputs - 1]);
+            assert_eq!(constants.alpha, 5);
+            assert_eq!(
+                constants.ark.len(),
+                FULL_ROUNDS + PARTIAL_ROUNDS[num_inputs - 1]
+            );
+            assert_eq!(constants.state_size(), num_inputs + 1);
+            let (ark, mds) = find_poseidon_ark_and_mds::<Fr>(
+                Fr::MODULUS_BIT_SIZE as u64,
+                num_inputs,
+                FULL_ROUNDS as u64,
+                PARTIAL_ROUNDS[num_inputs - 1] as u64,
+                0,
+            );
+            let config = constants.config(num_inputs);
+            assert_eq!(config.ark, ark);
+            assert_eq!(config.mds, mds);
+
+            let inputs: Vec<Fr> = (0..num_inputs).map(|_| Fr::rand(rng)).collect();
+            let output = CircomPoseidon::hash(&inputs).unwrap();
+            let outputs = CircomPoseidon::hash_ex(Fr::zero(), &inputs, num_inputs + 1).unwrap();
+            assert_eq!(outputs.len(), num_inputs + 1);
+            assert_eq!(outputs[0], output);
+            assert_ne!(
+                CircomPoseidon::hash_ex(Fr::from(1u64), &inputs, 1).unwrap()[0],
+                output
+            );
+            assert!(CircomPoseidon::hash_ex(Fr::zero(), &inputs, num_inputs + 2).is_err());
+        }
+        assert!(CircomPoseidon::hash(&[]).is_err());
+        assert!(CircomPoseidon::hash(&vec![Fr::zero(); MAX_INPUTS + 1]).is_err());
+    }
+}
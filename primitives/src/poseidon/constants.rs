@@ -0,0 +1,18 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+//! Round constants and MDS matrices of the poseidon instances, generated by
+//! the build script of this crate as `const` tables of field elements.
+//!
+//! Each instance `NAME` is a `const` [`PoseidonConstants`] over the tables
+//! `NAME_ARK`, which has one row of round constants per round, and
+//! `NAME_MDS`, the MDS matrix. `CIRCOM[n - 1]` is the instance of circomlib
+//! for `n` inputs.
+
+use super::PoseidonConstants;
+use ark_ff::MontFp;
+
+include!(concat!(env!("OUT_DIR"), "/poseidon_constants.rs"));
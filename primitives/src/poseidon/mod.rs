@@ -0,0 +1,269 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+#![deny(missing_docs)]
+//! This module implements Poseidon hash function over the following fields
+//! - ed_on_bls12_377 base field (i.e. bls12_377 scalar field)
+//! - ed_on_bls12_381 base field (i.e. bls12_381 scalar field)
+//! - ed_on_bn254 base field (i.e. bn254 scalar field)
+//!
+//! The permutation itself is the one from `ark-crypto-primitives`; round
+//! constants and MDS matrices are derived from the Grain LFSR as specified in
+//! the [Poseidon paper](https://eprint.iacr.org/2019/458.pdf). The derivation
+//! runs in the build script of this crate, which emits them as `const`
+//! tables, so no constant is computed at runtime.
+//!
+//! [`circom::CircomPoseidon`] is a separate instantiation over the bn254
+//! scalar field that matches circomlib.
+
+#![deny(warnings)]
+
+pub mod circom;
+mod constants;
+
+use crate::errors::PrimitivesError;
+use ark_crypto_primitives::sponge::{
+    poseidon::{PoseidonConfig, PoseidonSponge},
+    Absorb, CryptographicSponge, FieldBasedCryptographicSponge,
+};
+use ark_ff::PrimeField;
+use ark_std::{format, vec::Vec};
+use jf_utils::{bytes_to_field_elements, pad_with_zeros};
+
+/// The state size of poseidon hash.
+pub const STATE_SIZE: usize = 4;
+/// The rate of the sponge used in PoseidonCRHF.
+pub const CRHF_RATE: usize = 3;
+
+/// The # of full rounds of poseidon hash.
+// Round numbers are derived with `calc_round_numbers.py` from the reference
+// implementation (https://extgit.iaik.tugraz.at/krypto/hadeshash) for 128 bits
+// security and a state size of 4, including the recommended security margin
+// (+2 full rounds, +7.5% partial rounds).
+pub const FULL_ROUNDS: usize = 8;
+
+/// The constants of a poseidon permutation with `FULL_ROUNDS` full rounds
+/// over a state of `mds.len()` elements, as `const` tables.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PoseidonConstants<F: 'static> {
+    /// The # of partial rounds.
+    pub partial_rounds: usize,
+    /// parameter alpha of the S-box `x^alpha`
+    pub alpha: u64,
+    /// The round constants, one row per round.
+    pub ark: &'static [&'static [F]],
+    /// The MDS matrix.
+    pub mds: &'static [&'static [F]],
+}
+
+impl<F: PrimeField> PoseidonConstants<F> {
+    /// Return the # of elements of the state.
+    pub fn state_size(&self) -> usize {
+        self.mds.len()
+    }
+
+    /// The configuration of ark for a sponge with `rate` and the rest of the
+    /// state as capacity. [`PoseidonSponge`] owns a copy of its
+    /// configuration, so the tables are copied once per sponge.
+    pub fn config(&self, rate: usize) -> PoseidonConfig<F> {
+        PoseidonConfig::new(
+            FULL_ROUNDS,
+            self.partial_rounds,
+            self.alpha,
+            self.mds.iter().map(|row| row.to_vec()).collect(),
+            self.ark.iter().map(|row| row.to_vec()).collect(),
+            rate,
+            self.state_size() - rate,
+        )
+    }
+
+    /// The poseidon permutation, applied in place on `state`, the same as the
+    /// one of [`PoseidonSponge`] which is not exposed by ark.
+    pub fn permute(&self, state: &mut [F]) {
+        let half_full_rounds = FULL_ROUNDS / 2;
+        for (round, constants) in self.ark.iter().enumerate() {
+            state
+                .iter_mut()
+                .zip(constants.iter())
+                .for_each(|(elem, c)| *elem += c);
+            if round < half_full_rounds || round >= half_full_rounds + self.partial_rounds {
+                state
+                    .iter_mut()
+                    .for_each(|elem| *elem = elem.pow([self.alpha]));
+            } else {
+                state[0] = state[0].pow([self.alpha]);
+            }
+            let next: Vec<F> = self
+                .mds
+                .iter()
+                .map(|row| row.iter().zip(state.iter()).map(|(m, x)| *m * x).sum())
+                .collect();
+            state.copy_from_slice(&next);
+        }
+    }
+}
+
+/// This trait defines constants that are used for poseidon hash functions.
+pub trait PoseidonParameter: PrimeField + Absorb {
+    /// The poseidon constants for a state of `STATE_SIZE` elements, used with
+    /// `RATE = CRHF_RATE` and `CAPACITY = 1`.
+    const CONSTANTS: PoseidonConstants<Self>;
+}
+
+impl PoseidonParameter for ark_ed_on_bn254::Fq {
+    const CONSTANTS: PoseidonConstants<Self> = constants::ED_ON_BN254;
+}
+impl PoseidonParameter for ark_ed_on_bls12_381::Fq {
+    const CONSTANTS: PoseidonConstants<Self> = constants::ED_ON_BLS12_381;
+}
+impl PoseidonParameter for ark_ed_on_bls12_377::Fq {
+    const CONSTANTS: PoseidonConstants<Self> = constants::ED_ON_BLS12_377;
+}
+
+/// CRHF
+#[derive(Debug, Clone)]
+pub struct PoseidonCRHF<F: PoseidonParameter> {
+    sponge: PoseidonSponge<F>,
+}
+
+impl<F: PoseidonParameter> Default for PoseidonCRHF<F> {
+    fn default() -> Self {
+        Self {
+            sponge: PoseidonSponge::new(&F::CONSTANTS.config(CRHF_RATE)),
+        }
+    }
+}
+
+impl<F: PoseidonParameter> PoseidonCRHF<F> {
+    /// Sponge hashing in the domain of an application-level
+    /// `domain_separator`. A non-empty domain separator is encoded with
+    /// [`bytes_to_field_elements`], which starts with its length, zero-padded
+    /// to a multiple of `CRHF_RATE` and absorbed before any input. An empty
+    /// domain separator is the same as [`PoseidonCRHF::default`].
+    pub fn with_domain_separator(domain_separator: &[u8]) -> Self {
+        let mut crhf = Self::default();
+        let mut elems = bytes_to_field_elements::<_, F>(domain_separator);
+        pad_with_zeros(&mut elems, CRHF_RATE);
+        elems.iter().for_each(|x| crhf.sponge.absorb(x));
+        crhf
+    }
+
+    /// Sponge hashing based on poseidon permutation for RATE 3 and CAPACITY 1.
+    /// It allows inputs with length that is a multiple of `CRHF_RATE` and
+    /// returns a vector of `num_outputs` elements.
+    pub fn sponge_no_padding(input: &[F], num_output: usize) -> Result<Vec<F>, PrimitivesError> {
+        Self::default().hash_no_padding(input, num_output)
+    }
+
+    /// Same as [`PoseidonCRHF::sponge_no_padding`], in the domain of this
+    /// CRHF.
+    pub fn hash_no_padding(
+        &self,
+        input: &[F],
+        num_output: usize,
+    ) -> Result<Vec<F>, PrimitivesError> {
+        if input.len() % CRHF_RATE != 0 {
+            return Err(PrimitivesError::ParameterError(format!(
+                "Poseidon sponge Error : input length {} to sponge hashing function is not \
+                 multiple of RATE.",
+                input.len()
+            )));
+        }
+        let mut sponge = self.sponge.clone();
+        // ABSORB PHASE
+        input.iter().for_each(|x| sponge.absorb(x));
+        // SQUEEZE PHASE
+        Ok(sponge.squeeze_native_field_elements(num_output))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use ark_crypto_primitives::sponge::poseidon::find_poseidon_ark_and_mds;
+    use ark_ed_on_bls12_377::Fq as Fq377;
+    use ark_ed_on_bls12_381::Fq as Fq381;
+    use ark_ed_on_bn254::Fq as Fq254;
+    use ark_std::{vec, UniformRand};
+
+    #[test]
+    fn test_poseidon_crhf() {
+        test_poseidon_crhf_helper::<Fq254>();
+        test_poseidon_crhf_helper::<Fq377>();
+        test_poseidon_crhf_helper::<Fq381>();
+    }
+
+    fn test_poseidon_crhf_helper<F: PoseidonParameter>() {
+        let rng = &mut jf_utils::test_rng();
+        let constants = F::CONSTANTS;
+        assert_eq!(constants.ark.len(), FULL_ROUNDS + constants.partial_rounds);
+        assert_eq!(constants.state_size(), STATE_SIZE);
+        // the tables generated at build time are the ones of the Grain LFSR
+        let (ark, mds) = find_poseidon_ark_and_mds::<F>(
+            F::MODULUS_BIT_SIZE as u64,
+            CRHF_RATE,
+            FULL_ROUNDS as u64,
+            constants.partial_rounds as u64,
+            0,
+        );
+        let config = constants.config(CRHF_RATE);
+        assert_eq!(config.ark, ark);
+        assert_eq!(config.mds, mds);
+        assert_eq!(
+            (config.full_rounds, config.partial_rounds, config.alpha),
+            (FULL_ROUNDS, constants.partial_rounds, constants.alpha)
+        );
+        assert_eq!(
+            (config.rate, config.capacity),
+            (CRHF_RATE, STATE_SIZE - CRHF_RATE)
+        );
+
+        let input: Vec<F> = (0..2 * CRHF_RATE).map(|_| F::rand(rng)).collect();
+        let output = PoseidonCRHF::sponge_no_padding(&input, 2).unwrap();
+        assert_eq!(output.len(), 2);
+        assert_eq!(output, PoseidonCRHF::sponge_no_padding(&input, 2).unwrap());
+        assert_eq!(
+            output[..1],
+            PoseidonCRHF::sponge_no_padding(&input, 1).unwrap()
+        );
+
+        let mut other = input.clone();
+        other[0] += F::one();
+        assert_ne!(output, PoseidonCRHF::sponge_no_padding(&other, 2).unwrap());
+
+        assert!(PoseidonCRHF::sponge_no_padding(&vec![F::zero(); CRHF_RATE + 1], 1).is_err());
+
+        // An empty domain separator is the same as none, and a non-empty one is
+        // absorbed before the input
+        let crhf = PoseidonCRHF::<F>::with_domain_separator(b"");
+        assert_eq!(crhf.hash_no_padding(&input, 2).unwrap(), output);
+        let crhf = PoseidonCRHF::<F>::with_domain_separator(b"jellyfish test");
+        let ds_output = crhf.hash_no_padding(&input, 2).unwrap();
+        let mut prefixed = bytes_to_field_elements::<_, F>(&b"jellyfish test"[..]);
+        pad_with_zeros(&mut prefixed, CRHF_RATE);
+        prefixed.extend_from_slice(&input);
+        assert_eq!(
+            ds_output,
+            PoseidonCRHF::sponge_no_padding(&prefixed, 2).unwrap()
+        );
+        assert_ne!(ds_output, output);
+        assert_ne!(
+            ds_output,
+            PoseidonCRHF::<F>::with_domain_separator(b"jellyfish test2")
+                .hash_no_padding(&input, 2)
+                .unwrap()
+        );
+
+        // The local permutation is the one of the ark sponge
+        let mut state = vec![F::zero()];
+        state.extend_from_slice(&input[..CRHF_RATE]);
+        constants.permute(&mut state);
+        assert_eq!(
+            state[1..3],
+            PoseidonCRHF::sponge_no_padding(&input[..CRHF_RATE], 2).unwrap()
+        );
+    }
+}
@@ -0,0 +1,355 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+#![deny(missing_docs)]
+//! This module implements the Poseidon2 hash function over the following
+//! fields
+//! - ed_on_bls12_381 base field (i.e. bls12_381 scalar field)
+//! - ed_on_bn254 base field (i.e. bn254 scalar field)
+//!
+//! The permutation follows the [Poseidon2 paper](https://eprint.iacr.org/2023/323.pdf)
+//! for a state of 3 elements: an initial external linear layer, then half of
+//! the external rounds, the internal rounds which apply the S-box to the first
+//! element only, and the other half of the external rounds. Both linear layers
+//! only need additions: the external matrix is `circ(2, 1, 1)` and the
+//! internal one is `[[2, 1, 1], [1, 2, 1], [1, 1, 3]]`.
+//!
+//! Round constants follow the [reference implementation](https://github.com/HorizenLabs/poseidon2):
+//! the Grain LFSR is seeded as for [Poseidon](crate::poseidon), and its
+//! `EXTERNAL_ROUNDS * STATE_SIZE + INTERNAL_ROUNDS` first field elements are
+//! the constants of the rounds in order, a single one for each internal
+//! round. They are generated once per field on first use.
+
+#![deny(warnings)]
+
+use crate::{
+    errors::PrimitivesError,
+    sponge::{PermutationSponge, SpongePermutation},
+};
+use ark_crypto_primitives::sponge::{
+    poseidon::find_poseidon_ark_and_mds, Absorb, CryptographicSponge, FieldBasedCryptographicSponge,
+};
+use ark_ff::PrimeField;
+use ark_std::{boxed::Box, format, vec::Vec};
+use jf_utils::pad_with_zeros;
+use once_cell::race::OnceBox;
+
+/// The state size of poseidon2 hash.
+pub const STATE_SIZE: usize = 3;
+/// The rate of the sponge used in Poseidon2CRHF.
+pub const CRHF_RATE: usize = 2;
+
+/// The # of external rounds of poseidon2 hash, half of them before the
+/// internal rounds and half after.
+// Same number of full rounds as Poseidon, i.e. 6 for statistical attacks plus
+// the recommended security margin of 2.
+pub const EXTERNAL_ROUNDS: usize = 8;
+
+/// This trait defines constants that are used for poseidon2 hash functions.
+pub trait Poseidon2Parameter: PrimeField + Absorb {
+    /// parameter alpha of the S-box `x^alpha`
+    const ALPHA: u64;
+    /// The # of internal rounds.
+    const INTERNAL_ROUNDS: usize;
+
+    /// The poseidon2 permutation of this field.
+    fn permutation() -> &'static Poseidon2Permutation<Self>;
+}
+
+/// The Poseidon2 permutation over a state of `STATE_SIZE` elements.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Poseidon2Permutation<F: Poseidon2Parameter> {
+    /// Round constants of the external rounds, in order
+    external_constants: Vec<[F; STATE_SIZE]>,
+    /// Round constants of the internal rounds, in order
+    internal_constants: Vec<F>,
+}
+
+impl<F: Poseidon2Parameter> Default for Poseidon2Permutation<F> {
+    fn default() -> Self {
+        F::permutation().clone()
+    }
+}
+
+impl<F: Poseidon2Parameter> Poseidon2Permutation<F> {
+    /// Derive the round constants of the permutation of `F`.
+    fn derive() -> Self {
+        let (ark, _) = find_poseidon_ark_and_mds::<F>(
+            F::MODULUS_BIT_SIZE as u64,
+            CRHF_RATE,
+            EXTERNAL_ROUNDS as u64,
+            F::INTERNAL_ROUNDS as u64,
+            0,
+        );
+        // The LFSR is seeded as for Poseidon with the same rounds, and the
+        // reference implementation reads one constant per internal round, so
+        // its constants are the first ones of the Poseidon round constants.
+        let mut constants = ark.into_iter().flatten();
+        let mut next = || constants.next().expect("poseidon has more round constants");
+        let half = EXTERNAL_ROUNDS / 2;
+        let mut external_constants: Vec<[F; STATE_SIZE]> =
+            (0..half).map(|_| [next(), next(), next()]).collect();
+        let internal_constants = (0..F::INTERNAL_ROUNDS).map(|_| next()).collect();
+        external_constants.extend((half..EXTERNAL_ROUNDS).map(|_| [next(), next(), next()]));
+        Self {
+            external_constants,
+            internal_constants,
+        }
+    }
+
+    /// Return the round constants of the external rounds.
+    pub fn external_constants(&self) -> &[[F; STATE_SIZE]] {
+        &self.external_constants
+    }
+
+    /// Return the round constants of the internal rounds.
+    pub fn internal_constants(&self) -> &[F] {
+        &self.internal_constants
+    }
+
+    /// Compute the permutation on `input`.
+    pub fn eval(&self, input: &[F; STATE_SIZE]) -> [F; STATE_SIZE] {
+        let mut state = *input;
+        self.permute_in_place(&mut state);
+        state
+    }
+
+    /// Compute the permutation on `state` in place.
+    pub fn permute_in_place(&self, state: &mut [F; STATE_SIZE]) {
+        let (first, last) = self.external_constants.split_at(EXTERNAL_ROUNDS / 2);
+        external_linear_layer(state);
+        for constants in first {
+            external_round(state, constants);
+        }
+        for constant in &self.internal_constants {
+            state[0] = (state[0] + constant).pow([F::ALPHA]);
+            internal_linear_layer(state);
+        }
+        for constants in last {
+            external_round(state, constants);
+        }
+    }
+}
+
+fn external_round<F: Poseidon2Parameter>(state: &mut [F; STATE_SIZE], constants: &[F; STATE_SIZE]) {
+    state
+        .iter_mut()
+        .zip(constants)
+        .for_each(|(x, c)| *x = (*x + c).pow([F::ALPHA]));
+    external_linear_layer(state);
+}
+
+/// Multiply `state` by `circ(2, 1, 1)`.
+fn external_linear_layer<F: PrimeField>(state: &mut [F; STATE_SIZE]) {
+    let sum: F = state.iter().sum();
+    state.iter_mut().for_each(|x| *x += sum);
+}
+
+/// Multiply `state` by `[[2, 1, 1], [1, 2, 1], [1, 1, 3]]`.
+fn internal_linear_layer<F: PrimeField>(state: &mut [F; STATE_SIZE]) {
+    let sum: F = state.iter().sum();
+    state[2].double_in_place();
+    state.iter_mut().for_each(|x| *x += sum);
+}
+
+macro_rules! impl_poseidon2_parameter {
+    ($field:ty, $alpha:expr, $internal_rounds:expr) => {
+        impl Poseidon2Parameter for $field {
+            const ALPHA: u64 = $alpha;
+            const INTERNAL_ROUNDS: usize = $internal_rounds;
+
+            fn permutation() -> &'static Poseidon2Permutation<Self> {
+                static PERMUTATION: OnceBox<Poseidon2Permutation<$field>> = OnceBox::new();
+                PERMUTATION.get_or_init(|| Box::new(Poseidon2Permutation::derive()))
+            }
+        }
+    };
+}
+
+// gcd(5, p - 1) = 1 for both fields, and 56 internal rounds give 128 bits of
+// security with the security margin of the Poseidon2 paper
+impl_poseidon2_parameter!(ark_ed_on_bn254::Fq, 5, 56);
+impl_poseidon2_parameter!(ark_ed_on_bls12_381::Fq, 5, 56);
+
+impl<F: Poseidon2Parameter> SpongePermutation<F, STATE_SIZE> for Poseidon2Permutation<F> {
+    fn permute(&self, state: &mut [F; STATE_SIZE]) {
+        self.permute_in_place(state);
+    }
+}
+
+/// A sponge over the poseidon2 permutation, absorbing `RATE` elements per
+/// permutation into the first elements of the state.
+pub type Poseidon2Sponge<F, const RATE: usize> =
+    PermutationSponge<F, Poseidon2Permutation<F>, STATE_SIZE, RATE>;
+
+/// CRHF
+#[derive(Debug, Clone)]
+pub struct Poseidon2CRHF<F: Poseidon2Parameter> {
+    sponge: Poseidon2Sponge<F, CRHF_RATE>,
+}
+
+impl<F: Poseidon2Parameter> Poseidon2CRHF<F> {
+    /// Sponge hashing based on poseidon2 permutation for RATE 2. It allows
+    /// unrestricted variable length input and returns a vector of
+    /// `num_outputs` elements, with "bit padding": "1" is always appended,
+    /// then as many "0" as required for the overall length to be a multiple
+    /// of RATE.
+    pub fn sponge_with_bit_padding(input: &[F], num_outputs: usize) -> Vec<F> {
+        let mut padded = input.to_vec();
+        padded.push(F::one());
+        pad_with_zeros(&mut padded, CRHF_RATE);
+        Self::sponge_no_padding(padded.as_slice(), num_outputs)
+            .expect("Bug in JF Primitives : bad padding of input for FSKS construction")
+    }
+
+    /// Similar to [`Poseidon2CRHF::sponge_with_bit_padding`] except we use
+    /// "zero padding" where as many "0" as required are added for the overall
+    /// length to be a multiple of RATE.
+    pub fn sponge_with_zero_padding(input: &[F], num_outputs: usize) -> Vec<F> {
+        let mut padded = input.to_vec();
+        pad_with_zeros(&mut padded, CRHF_RATE);
+        Self::sponge_no_padding(padded.as_slice(), num_outputs)
+            .expect("Bug in JF Primitives : bad padding of input for FSKS construction")
+    }
+
+    /// Sponge hashing based on poseidon2 permutation for RATE 2 and CAPACITY
+    /// 1. It allows inputs with length that is a multiple of `CRHF_RATE` and
+    /// returns a vector of `num_outputs` elements.
+    pub fn sponge_no_padding(input: &[F], num_output: usize) -> Result<Vec<F>, PrimitivesError> {
+        if input.len() % CRHF_RATE != 0 {
+            return Err(PrimitivesError::ParameterError(format!(
+                "Poseidon2 sponge Error : input length {} to sponge hashing function is not \
+                 multiple of RATE.",
+                input.len()
+            )));
+        }
+        let mut crhf = Self {
+            sponge: Poseidon2Sponge::new(F::permutation()),
+        };
+        // ABSORB PHASE
+        crhf.sponge.absorb(&input);
+        // SQUEEZE PHASE
+        Ok(crhf.sponge.squeeze_native_field_elements(num_output))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use ark_ed_on_bls12_381::Fq as Fq381;
+    use ark_ed_on_bn254::Fq as Fq254;
+    use ark_ff::MontFp;
+    use ark_std::{vec, UniformRand};
+
+    #[test]
+    fn test_poseidon2_permutation() {
+        test_poseidon2_permutation_helper::<Fq254>();
+        test_poseidon2_permutation_helper::<Fq381>();
+    }
+
+    fn test_poseidon2_permutation_helper<F: Poseidon2Parameter>() {
+        let rng = &mut jf_utils::test_rng();
+        let permutation = F::permutation();
+        assert_eq!(permutation.external_constants().len(), EXTERNAL_ROUNDS);
+        assert_eq!(permutation.internal_constants().len(), F::INTERNAL_ROUNDS);
+        // permutation is cached
+        assert!(core::ptr::eq(permutation, F::permutation()));
+        assert_eq!(&Poseidon2Permutation::<F>::default(), permutation);
+
+        // The linear layers are the multiplications by their matrices
+        let state = [F::rand(rng), F::rand(rng), F::rand(rng)];
+        let mul = |matrix: [[u64; STATE_SIZE]; STATE_SIZE]| {
+            let mut res = [F::zero(); STATE_SIZE];
+            for (res, row) in res.iter_mut().zip(matrix) {
+                *res = row.iter().zip(state).map(|(m, x)| F::from(*m) * x).sum();
+            }
+            res
+        };
+        let mut external = state;
+        external_linear_layer(&mut external);
+        assert_eq!(external, mul([[2, 1, 1], [1, 2, 1], [1, 1, 2]]));
+        let mut internal = state;
+        internal_linear_layer(&mut internal);
+        assert_eq!(internal, mul([[2, 1, 1], [1, 2, 1], [1, 1, 3]]));
+
+        let output = permutation.eval(&state);
+        assert_ne!(output, state);
+        assert_eq!(output, permutation.eval(&state));
+        let mut other = state;
+        other[2] += F::one();
+        let other_output = permutation.eval(&other);
+        assert!(output.iter().zip(other_output).all(|(x, y)| *x != y));
+    }
+
+    // Test vector of the reference implementation for the BN254 scalar field,
+    // `poseidon2_instance_bn256.rs` of https://github.com/HorizenLabs/poseidon2
+    #[test]
+    fn test_poseidon2_reference_vector() {
+        let permutation = Fq254::permutation();
+        assert_eq!(
+            permutation.external_constants()[0][0],
+            MontFp!(
+                "13128406282895484157369354038809433636203389051939936481821261911791933663254"
+            )
+        );
+        let input = [0u64, 1, 2].map(Fq254::from);
+        let expected: [Fq254; STATE_SIZE] = [
+            MontFp!("5297208644449048816064511434384511824916970985131888684874823260532015509555"),
+            MontFp!(
+                "21816030159894113985964609355246484851575571273661473159848781012394295965040"
+            ),
+            MontFp!(
+                "13940986381491601233448981668101586453321811870310341844570924906201623195336"
+            ),
+        ];
+        assert_eq!(permutation.eval(&input), expected);
+    }
+
+    #[test]
+    fn test_poseidon2_crhf() {
+        test_poseidon2_crhf_helper::<Fq254>();
+        test_poseidon2_crhf_helper::<Fq381>();
+    }
+
+    fn test_poseidon2_crhf_helper<F: Poseidon2Parameter>() {
+        let rng = &mut jf_utils::test_rng();
+        let input: Vec<F> = (0..2 * CRHF_RATE).map(|_| F::rand(rng)).collect();
+        let output = Poseidon2CRHF::sponge_no_padding(&input, 3).unwrap();
+        assert_eq!(output.len(), 3);
+        assert_eq!(output, Poseidon2CRHF::sponge_no_padding(&input, 3).unwrap());
+        assert_eq!(
+            output[..1],
+            Poseidon2CRHF::sponge_no_padding(&input, 1).unwrap()
+        );
+
+        // The sponge absorbs into the rate and squeezes from it
+        let permutation = F::permutation();
+        let mut state = [F::zero(); STATE_SIZE];
+        for chunk in input.chunks(CRHF_RATE) {
+            state[0] += chunk[0];
+            state[1] += chunk[1];
+            permutation.permute_in_place(&mut state);
+        }
+        assert_eq!(output[..2], state[..2]);
+        assert_eq!(output[2], permutation.eval(&state)[0]);
+
+        let mut other = input.clone();
+        other[0] += F::one();
+        assert_ne!(output, Poseidon2CRHF::sponge_no_padding(&other, 3).unwrap());
+
+        assert!(Poseidon2CRHF::sponge_no_padding(&vec![F::zero(); CRHF_RATE + 1], 1).is_err());
+        assert_eq!(
+            Poseidon2CRHF::sponge_with_zero_padding(&input[..3], 1),
+            Poseidon2CRHF::sponge_no_padding(&[input[0], input[1], input[2], F::zero()], 1)
+                .unwrap()
+        );
+        assert_eq!(
+            Poseidon2CRHF::sponge_with_bit_padding(&input[..2], 1),
+            Poseidon2CRHF::sponge_no_padding(&[input[0], input[1], F::one(), F::zero()], 1)
+                .unwrap()
+        );
+    }
+}
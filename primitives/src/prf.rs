@@ -5,19 +5,33 @@
 // along with the Jellyfish library. If not, see <https://mit-license.org/>.
 
 //! This module implements a pseudo random function that is derived from
-//! the rescue hash function.
+//! the rescue hash function, and byte-oriented ones derived from the ChaCha20
+//! and AES stream ciphers. The latter are much faster outside of circuits,
+//! but have no efficient circuit counterpart.
 
 use crate::{
     errors::PrimitivesError,
-    rescue::{sponge::RescuePRFCore, RescueParameter},
+    rescue::{
+        sponge::{RescuePRFCore, RescueSpongeConfig},
+        RescueParameter, STATE_SIZE,
+    },
 };
+use aes::Aes256;
 use ark_std::{
     borrow::Borrow,
     fmt::Debug,
     marker::PhantomData,
     rand::{CryptoRng, RngCore},
+    string::ToString,
+    vec::Vec,
     UniformRand,
 };
+use chacha20::{
+    cipher::{KeyIvInit, StreamCipher},
+    ChaCha20,
+};
+use ctr::Ctr32BE;
+use zeroize::Zeroize;
 
 /// Trait for Pseudo-random Functions
 pub trait PRF {
@@ -28,8 +42,8 @@ pub trait PRF {
     /// Output of the PRF
     type Output: Clone + Debug + PartialEq + Eq;
     /// The random seed/key that index a specific function from the PRF
-    /// ensembles
-    type Seed: Clone + Debug + Default + UniformRand;
+    /// ensembles, to be zeroized by its owner once no longer needed
+    type Seed: Clone + Debug + Default + UniformRand + Zeroize;
 
     /// Compute PRF output with a user-provided randomly generated `seed`
     fn evaluate<S: Borrow<Self::Seed>, I: Borrow<Self::Input>>(
@@ -76,18 +90,195 @@ impl<F: RescueParameter, const INPUT_LEN: usize, const OUTPUT_LEN: usize> PRF
         Ok(output)
     }
 }
+
+impl<F: RescueParameter, const INPUT_LEN: usize, const OUTPUT_LEN: usize>
+    RescuePRF<F, INPUT_LEN, OUTPUT_LEN>
+{
+    /// Same as [`PRF::evaluate`] with a keyed sponge of the rate/capacity
+    /// split of `config` instead of the full state, `input` being zero-padded
+    /// to a multiple of its rate. The seed is always the last element of the
+    /// initial state.
+    pub fn evaluate_with_config<S: Borrow<F>, I: Borrow<[F; INPUT_LEN]>>(
+        config: RescueSpongeConfig,
+        seed: S,
+        input: I,
+    ) -> Result<[F; OUTPUT_LEN], PrimitivesError> {
+        let mut output = [F::zero(); OUTPUT_LEN];
+        output.clone_from_slice(&RescuePRFCore::keyed_sponge_with_zero_padding(
+            config,
+            seed.borrow(),
+            input.borrow(),
+            OUTPUT_LEN,
+        )?);
+        Ok(output)
+    }
+}
+
+#[derive(Debug, Clone)]
+/// A rescue-based PRF in counter mode, which derives an arbitrarily long
+/// stream of field elements from one seed and input.
+///
+/// The stream is the concatenation of blocks of `STATE_SIZE` elements, where
+/// block `i` is the output of the Full State Keyed sponge of [`RescuePRF`] on
+/// `input || i`, zero-padded to a multiple of `STATE_SIZE`. Blocks can thus
+/// be computed independently of each other.
+pub struct RescueCounterPRF<F: RescueParameter, const INPUT_LEN: usize>(PhantomData<F>);
+
+impl<F: RescueParameter, const INPUT_LEN: usize> RescueCounterPRF<F, INPUT_LEN> {
+    /// Compute the first `num_outputs` elements of the stream of `seed` and
+    /// `input`.
+    pub fn evaluate<S: Borrow<F>, I: Borrow<[F; INPUT_LEN]>>(
+        seed: S,
+        input: I,
+        num_outputs: usize,
+    ) -> Result<Vec<F>, PrimitivesError> {
+        let mut output = Vec::with_capacity(num_outputs);
+        let mut counter = 0;
+        while output.len() < num_outputs {
+            let block = Self::evaluate_block(seed.borrow(), input.borrow(), counter)?;
+            let extract = (num_outputs - output.len()).min(STATE_SIZE);
+            output.extend_from_slice(&block[..extract]);
+            counter += 1;
+        }
+        Ok(output)
+    }
+
+    /// Compute the block of the stream of `seed` and `input` at position
+    /// `counter`, i.e. elements `counter * STATE_SIZE` to
+    /// `(counter + 1) * STATE_SIZE - 1`.
+    pub fn evaluate_block<S: Borrow<F>, I: Borrow<[F; INPUT_LEN]>>(
+        seed: S,
+        input: I,
+        counter: u64,
+    ) -> Result<[F; STATE_SIZE], PrimitivesError> {
+        let mut data = input.borrow().to_vec();
+        data.push(F::from(counter));
+        let mut block = [F::zero(); STATE_SIZE];
+        block.clone_from_slice(&RescuePRFCore::full_state_keyed_sponge_with_zero_padding(
+            seed.borrow(),
+            &data,
+            STATE_SIZE,
+        )?);
+        Ok(block)
+    }
+
+    /// Return the stream of `seed` and `input` as an iterator, which computes
+    /// one block at a time.
+    pub fn stream(seed: F, input: [F; INPUT_LEN]) -> RescuePRFStream<F, INPUT_LEN> {
+        RescuePRFStream {
+            seed,
+            input,
+            counter: 0,
+            block: [F::zero(); STATE_SIZE],
+            next: STATE_SIZE,
+        }
+    }
+}
+
+/// Iterator over the stream of a [`RescueCounterPRF`].
+#[derive(Debug, Clone)]
+pub struct RescuePRFStream<F: RescueParameter, const INPUT_LEN: usize> {
+    seed: F,
+    input: [F; INPUT_LEN],
+    /// counter of the next block
+    counter: u64,
+    block: [F; STATE_SIZE],
+    /// position of the next element in `block`
+    next: usize,
+}
+
+impl<F: RescueParameter, const INPUT_LEN: usize> Drop for RescuePRFStream<F, INPUT_LEN> {
+    fn drop(&mut self) {
+        self.seed.zeroize();
+        self.block.zeroize();
+    }
+}
+
+impl<F: RescueParameter, const INPUT_LEN: usize> Iterator for RescuePRFStream<F, INPUT_LEN> {
+    type Item = F;
+
+    fn next(&mut self) -> Option<F> {
+        if self.next == STATE_SIZE {
+            self.block = RescueCounterPRF::<F, INPUT_LEN>::evaluate_block(
+                self.seed,
+                self.input,
+                self.counter,
+            )
+            .expect("Bug in JF Primitives : bad padding of input for FSKS construction");
+            self.counter += 1;
+            self.next = 0;
+        }
+        self.next += 1;
+        Some(self.block[self.next - 1])
+    }
+}
+
+#[derive(Debug, Clone)]
+/// A ChaCha20-based PRF, whose output is the first `OUTPUT_LEN` bytes of the
+/// keystream of ChaCha20 (RFC 8439) under the seed as key and the input as
+/// nonce, from block counter 0.
+pub struct ChaCha20PRF<const OUTPUT_LEN: usize>;
+
+impl<const OUTPUT_LEN: usize> PRF for ChaCha20PRF<OUTPUT_LEN> {
+    type Input = [u8; 12];
+    type Output = [u8; OUTPUT_LEN];
+    type Seed = [u8; 32];
+
+    fn evaluate<S: Borrow<Self::Seed>, I: Borrow<Self::Input>>(
+        seed: S,
+        input: I,
+    ) -> Result<Self::Output, PrimitivesError> {
+        let mut cipher = ChaCha20::new(seed.borrow().into(), input.borrow().into());
+        keystream(&mut cipher)
+    }
+}
+
+#[derive(Debug, Clone)]
+/// An AES-CTR-based PRF, whose output is the first `OUTPUT_LEN` bytes of the
+/// keystream of AES-256 in counter mode under the seed as key, from the
+/// counter block made of the input followed by a 32-bit big-endian counter
+/// starting at 0.
+pub struct AesCtrPRF<const OUTPUT_LEN: usize>;
+
+impl<const OUTPUT_LEN: usize> PRF for AesCtrPRF<OUTPUT_LEN> {
+    type Input = [u8; 12];
+    type Output = [u8; OUTPUT_LEN];
+    type Seed = [u8; 32];
+
+    fn evaluate<S: Borrow<Self::Seed>, I: Borrow<Self::Input>>(
+        seed: S,
+        input: I,
+    ) -> Result<Self::Output, PrimitivesError> {
+        let mut block = [0u8; 16];
+        block[..12].copy_from_slice(input.borrow());
+        let mut cipher = Ctr32BE::<Aes256>::new(seed.borrow().into(), (&block).into());
+        keystream(&mut cipher)
+    }
+}
+
+// The first `OUTPUT_LEN` bytes of the keystream of `cipher`.
+fn keystream<C: StreamCipher, const OUTPUT_LEN: usize>(
+    cipher: &mut C,
+) -> Result<[u8; OUTPUT_LEN], PrimitivesError> {
+    let mut output = [0u8; OUTPUT_LEN];
+    cipher
+        .try_apply_keystream(&mut output)
+        .map_err(|_| PrimitivesError::ParameterError("output exceeds the keystream".to_string()))?;
+    Ok(output)
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{
-        prf::{RescuePRF, PRF},
-        rescue::sponge::RescuePRFCore,
+        prf::{AesCtrPRF, ChaCha20PRF, RescueCounterPRF, RescuePRF, PRF},
+        rescue::{sponge::RescuePRFCore, RescueParameter, STATE_SIZE},
     };
     use ark_bls12_377::Fq as Fq377;
     use ark_bn254::Fq as Fq254;
     use ark_ed_on_bls12_377::Fq as FqEd377;
     use ark_ed_on_bls12_381::Fq as FqEd381;
     use ark_ed_on_bn254::Fq as FqEd254;
-    use ark_std::UniformRand;
+    use ark_std::{vec::Vec, UniformRand};
     macro_rules! test_prf {
         ($tr:tt) => {
             let mut rng = jf_utils::test_rng();
@@ -114,4 +305,124 @@ mod tests {
         test_prf!(Fq377);
         test_prf!(Fq254);
     }
+
+    #[test]
+    fn test_counter_prf() {
+        test_counter_prf_helper::<FqEd254>();
+        test_counter_prf_helper::<FqEd377>();
+        test_counter_prf_helper::<FqEd381>();
+        test_counter_prf_helper::<Fq377>();
+        test_counter_prf_helper::<Fq254>();
+    }
+
+    fn test_counter_prf_helper<F: RescueParameter>() {
+        let mut rng = jf_utils::test_rng();
+        let seed = F::rand(&mut rng);
+        let input = [F::rand(&mut rng), F::rand(&mut rng)];
+
+        let output = RescueCounterPRF::<F, 2>::evaluate(&seed, &input, 3 * STATE_SIZE + 1).unwrap();
+        assert_eq!(output.len(), 3 * STATE_SIZE + 1);
+        // shorter outputs are prefixes of longer ones
+        assert_eq!(
+            RescueCounterPRF::<F, 2>::evaluate(&seed, &input, 5).unwrap(),
+            output[..5]
+        );
+        assert!(RescueCounterPRF::<F, 2>::evaluate(&seed, &input, 0)
+            .unwrap()
+            .is_empty());
+        // the stream is the same, and made of independent blocks
+        let stream: Vec<F> = RescueCounterPRF::<F, 2>::stream(seed, input)
+            .take(output.len())
+            .collect();
+        assert_eq!(stream, output);
+        for (counter, block) in output.chunks(STATE_SIZE).enumerate() {
+            let expected =
+                RescueCounterPRF::<F, 2>::evaluate_block(&seed, &input, counter as u64).unwrap();
+            assert_eq!(block, &expected[..block.len()]);
+        }
+        assert_eq!(
+            output[..STATE_SIZE],
+            RescuePRFCore::full_state_keyed_sponge_with_zero_padding(
+                &seed,
+                &[input[0], input[1], F::zero()],
+                STATE_SIZE
+            )
+            .unwrap()
+        );
+        assert_ne!(output[..STATE_SIZE], output[STATE_SIZE..2 * STATE_SIZE]);
+
+        let other_seed = seed + F::one();
+        assert_ne!(
+            RescueCounterPRF::<F, 2>::evaluate(&other_seed, &input, 1).unwrap(),
+            output[..1]
+        );
+    }
+
+    fn from_hex(hex: &str) -> Vec<u8> {
+        (0..hex.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_chacha20_prf() {
+        // RFC 8439, Appendix A.1, test vectors #1 and #2
+        let output = ChaCha20PRF::<128>::evaluate([0u8; 32], [0u8; 12]).unwrap();
+        assert_eq!(
+            output.to_vec(),
+            from_hex(
+                "76b8e0ada0f13d90405d6ae55386bd28bdd219b8a08ded1aa836efcc8b770dc7\
+                 da41597c5157488d7724e03fb8d84a376a43b8f41518a11cc387b669b2ee6586\
+                 9f07e7be5551387a98ba977c732d080dcb0f29a048e3656912c6533e32ee7aed\
+                 29b721769ce64e43d57133b074d839d531ed1f28510afb45ace10a1f4b794d6f"
+            )
+        );
+        let mut seed = [0u8; 32];
+        seed.iter_mut().enumerate().for_each(|(i, b)| *b = i as u8);
+        let mut input = [0u8; 12];
+        input[3] = 0x09;
+        input[7] = 0x4a;
+        assert_eq!(
+            ChaCha20PRF::<40>::evaluate(&seed, &input).unwrap().to_vec(),
+            from_hex(
+                "8adc91fd9ff4f0f51b0fad50ff15d637e40efda206cc52c783a74200503c1582\
+                 cd9833367d0a54d5"
+            )
+        );
+        test_byte_prf_helper::<ChaCha20PRF<40>>();
+    }
+
+    #[test]
+    fn test_aes_ctr_prf() {
+        // NIST SP 800-38A, F.5.5 key, with the last 4 bytes of the initial
+        // counter block replaced by 0
+        let seed: [u8; 32] =
+            from_hex("603deb1015ca71be2b73aef0857d77811f352c073b6108d72d9810a30914dff4")
+                .try_into()
+                .unwrap();
+        let input: [u8; 12] = from_hex("f0f1f2f3f4f5f6f7f8f9fafb").try_into().unwrap();
+        assert_eq!(
+            AesCtrPRF::<40>::evaluate(&seed, &input).unwrap().to_vec(),
+            from_hex(
+                "a2f8cca1b0bfe6f6af55575d4d9156e410bbc361abb81e65db0ea7ea77e0c305\
+                 def31a69ceaa3f16"
+            )
+        );
+        test_byte_prf_helper::<AesCtrPRF<40>>();
+    }
+
+    fn test_byte_prf_helper<P: PRF<Input = [u8; 12], Seed = [u8; 32]>>() {
+        let mut rng = jf_utils::test_rng();
+        let input = [1u8; 12];
+        let (seed, output) = P::evaluate_with_rand_seed(&mut rng, &input).unwrap();
+        assert_eq!(P::evaluate(&seed, &input).unwrap(), output);
+
+        let mut other_input = input;
+        other_input[11] ^= 1;
+        assert_ne!(P::evaluate(&seed, &other_input).unwrap(), output);
+        let mut other_seed = seed;
+        other_seed[0] ^= 1;
+        assert_ne!(P::evaluate(&other_seed, &input).unwrap(), output);
+    }
 }
@@ -10,22 +10,35 @@
 //! - ed_on_bls12_377 base field
 //! - ed_on_bls12_381 base field
 //! - ed_on_bn254 base field
+//! - bn254 base field
+//! - pallas base field (i.e. vesta scalar field)
+//! - vesta base field (i.e. pallas scalar field)
+//!
+//! The base and scalar fields of grumpkin are the scalar and base fields of
+//! bn254, so it is covered by the ed_on_bn254 and bn254 base fields.
 //!
 //! It also has place holders for
 //! - bls12_381 base field
-//! - bn254 base field
 //! - bw6_761 base field
 //!
-//! Those three place holders should never be used.
+//! Those two place holders should never be used.
+//!
+//! The [`prime`] module implements the Rescue-Prime profile of the
+//! permutation and hash, for protocols that pin that specification.
 
 #![deny(warnings)]
 pub mod errors;
+pub mod prime;
 mod rescue_constants;
+mod simd;
 pub mod sponge;
 
 use ark_crypto_primitives::sponge::Absorb;
 use ark_ff::{PrimeField, Zero};
 use ark_std::{vec, vec::Vec};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use zeroize::Zeroize;
 
 /// The state size of rescue hash.
 pub const STATE_SIZE: usize = 4;
@@ -75,6 +88,9 @@ pub const CRHF_RATE: usize = 3;
 // We may consider to use ROUNDS = 10 for BLS12-377 (alpha = 11) in futures.
 pub const ROUNDS: usize = 12;
 
+/// The # of permutations evaluated at once by [`Permutation::eval_batch`].
+pub const BATCH_LANES: usize = 4;
+
 /// This trait defines constants that are used for rescue hash functions.
 pub trait RescueParameter: PrimeField + Absorb {
     /// parameter A, a.k.a., alpha
@@ -97,6 +113,13 @@ pub struct RescueVector<F> {
     pub(crate) vec: [F; STATE_SIZE],
 }
 
+// the vector may hold a key, e.g. in counter-mode encryption
+impl<F: PrimeField> Zeroize for RescueVector<F> {
+    fn zeroize(&mut self) {
+        self.vec.zeroize();
+    }
+}
+
 // Public functions
 impl<F: PrimeField> RescueVector<F> {
     /// zero vector
@@ -417,6 +440,61 @@ impl<F: RescueParameter> Permutation<F> {
         self.rescue_prp
             .prp_with_round_keys(self.round_keys.as_slice(), input)
     }
+
+    /// Compute the permutation on every RescueVector of `inputs`.
+    ///
+    /// Inputs are evaluated `BATCH_LANES` at a time. On CPUs with AVX2
+    /// (detected at runtime with the `std` feature) or NEON, the lanes are
+    /// those of SIMD vectors, whose field arithmetic works on 29-bit limbs.
+    /// Elsewhere, and for fields wider than 377 bits, each step of a round is
+    /// applied to all lanes before the next one, so the independent field
+    /// operations of the lanes can be interleaved by the CPU. Groups of lanes
+    /// are evaluated in parallel if the `parallel` feature is on.
+    ///
+    /// Neither AVX2 nor NEON multiplies 64-bit limbs, so whether the SIMD
+    /// lanes beat the scalar 64-bit multiplications of ark depends on the
+    /// CPU, and on recent x86 cores they are about even.
+    pub fn eval_batch(&self, inputs: &[RescueVector<F>]) -> Vec<RescueVector<F>> {
+        #[cfg(feature = "parallel")]
+        {
+            inputs
+                .par_chunks(BATCH_LANES)
+                .flat_map_iter(|lanes| self.eval_lanes(lanes))
+                .collect()
+        }
+        #[cfg(not(feature = "parallel"))]
+        {
+            inputs
+                .chunks(BATCH_LANES)
+                .flat_map(|lanes| self.eval_lanes(lanes))
+                .collect()
+        }
+    }
+
+    fn eval_lanes(&self, lanes: &[RescueVector<F>]) -> Vec<RescueVector<F>> {
+        simd::eval_lanes(self, lanes).unwrap_or_else(|| self.eval_lockstep(lanes))
+    }
+
+    fn eval_lockstep(&self, lanes: &[RescueVector<F>]) -> Vec<RescueVector<F>> {
+        let mds = self.mds_matrix_ref();
+        let mut states: Vec<RescueVector<F>> = lanes
+            .iter()
+            .map(|lane| lane.add(&self.round_keys[0]))
+            .collect();
+        self.round_keys[1..]
+            .iter()
+            .enumerate()
+            .for_each(|(round, key)| {
+                let exp: &[u64] = if (round % 2).is_zero() {
+                    F::A_INV
+                } else {
+                    &[F::A]
+                };
+                states.iter_mut().for_each(|state| state.pow(exp));
+                states.iter_mut().for_each(|state| state.linear(mds, key));
+            });
+        states
+    }
 }
 
 #[cfg(test)]
@@ -427,6 +505,7 @@ mod test_prp {
     use ark_ed_on_bls12_377::Fq as Fr377;
     use ark_ed_on_bls12_381::Fq as Fr381;
     use ark_ed_on_bn254::Fq as Fr254;
+    use ark_pallas::{Fq as FqPallas, Fr as FqVesta};
 
     // hash output on vector [0, 0, 0, 0]
     // this value is cross checked with sage script
@@ -558,6 +637,58 @@ mod test_prp {
         ],
     ];
 
+    // permutation output on vector [0, 0, 0, 0], computed with an independent
+    // implementation of the parameter generation of the build script, see
+    // rescue_constants.rs
+    const OUTPUTPALLAS: [[u8; 32]; 4] = [
+        [
+            0xFB, 0x11, 0xC7, 0x50, 0x5B, 0xAA, 0x5A, 0x18, 0x19, 0x41, 0x99, 0xB7, 0x7D, 0x80,
+            0xED, 0x35, 0x9F, 0x55, 0x01, 0xF7, 0x6A, 0xD5, 0xD7, 0x86, 0xA1, 0x22, 0xDB, 0x8F,
+            0x54, 0x47, 0xA6, 0x26,
+        ],
+        [
+            0x40, 0x80, 0xFD, 0x68, 0xE2, 0x55, 0x8C, 0x81, 0x32, 0x5A, 0x67, 0xB8, 0xAA, 0xB9,
+            0x78, 0x4A, 0x9A, 0xF2, 0x65, 0x50, 0x7A, 0x3F, 0x7B, 0x3A, 0xB5, 0x96, 0xB3, 0x70,
+            0x53, 0x48, 0xAD, 0x35,
+        ],
+        [
+            0x76, 0x00, 0x4A, 0x52, 0xB3, 0x9E, 0xC4, 0x28, 0x08, 0x8F, 0x54, 0x07, 0xE5, 0x67,
+            0xFE, 0xFC, 0x06, 0x86, 0x57, 0x30, 0x26, 0xDA, 0x0A, 0xE8, 0x63, 0xB3, 0x8E, 0x7E,
+            0xB2, 0x17, 0x4D, 0x2B,
+        ],
+        [
+            0x04, 0x9F, 0x8F, 0x12, 0x5B, 0x2F, 0x5F, 0x46, 0x24, 0x42, 0xC2, 0xA0, 0x88, 0x75,
+            0xA9, 0x89, 0xA4, 0xEC, 0xC2, 0x0C, 0x3C, 0xE2, 0x8B, 0x49, 0x3C, 0x28, 0x9F, 0x52,
+            0xD5, 0x82, 0x6E, 0x02,
+        ],
+    ];
+
+    // permutation output on vector [0, 0, 0, 0], computed with an independent
+    // implementation of the parameter generation of the build script, see
+    // rescue_constants.rs
+    const OUTPUTVESTA: [[u8; 32]; 4] = [
+        [
+            0xD6, 0x11, 0xB0, 0xD6, 0xC3, 0xBE, 0xCC, 0x85, 0x11, 0x93, 0x44, 0xEC, 0x67, 0x42,
+            0x3A, 0x82, 0x0C, 0xB4, 0x69, 0x61, 0xE7, 0x01, 0xE1, 0x26, 0x64, 0xA8, 0x3F, 0x8B,
+            0xC9, 0xC3, 0x41, 0x18,
+        ],
+        [
+            0x70, 0x5C, 0xE0, 0x49, 0x6D, 0xCD, 0x1E, 0x66, 0x2C, 0x4C, 0xDF, 0x25, 0x39, 0x6B,
+            0xFC, 0x34, 0x44, 0xC9, 0x6A, 0x18, 0x3E, 0xBB, 0x1F, 0xC4, 0x66, 0xC7, 0x2E, 0xFF,
+            0x54, 0x33, 0x08, 0x22,
+        ],
+        [
+            0x61, 0x8F, 0xCE, 0xDD, 0x24, 0xD5, 0xE5, 0x12, 0xAF, 0x8D, 0x0D, 0xC1, 0xDD, 0xDA,
+            0x9C, 0xD0, 0x41, 0xFB, 0xDD, 0xCB, 0xDE, 0x8C, 0x4E, 0x1D, 0xC9, 0x6A, 0x1D, 0x53,
+            0xC6, 0x32, 0x92, 0x14,
+        ],
+        [
+            0x7D, 0x85, 0x91, 0xD1, 0x0C, 0x6B, 0xB1, 0xF5, 0xE5, 0x12, 0x3D, 0x3E, 0x96, 0x02,
+            0xF9, 0x97, 0x35, 0x51, 0xC4, 0x11, 0x6F, 0x5C, 0xD5, 0x1E, 0x01, 0x84, 0x84, 0x1A,
+            0x03, 0x6A, 0xD2, 0x11,
+        ],
+    ];
+
     #[test]
     fn test_rescue_perm_on_0_vec() {
         test_rescue_perm_on_0_vec_fq254();
@@ -565,6 +696,8 @@ mod test_prp {
         test_rescue_perm_on_0_vec_377();
         test_rescue_perm_on_0_vec_381();
         test_rescue_perm_on_0_vec_761();
+        test_rescue_perm_on_0_vec_pallas();
+        test_rescue_perm_on_0_vec_vesta();
     }
 
     fn test_rescue_perm_on_0_vec_fq254() {
@@ -654,6 +787,40 @@ mod test_prp {
         assert_eq!(real_output, expected);
     }
 
+    fn test_rescue_perm_on_0_vec_pallas() {
+        let rescue = PRP::<FqPallas>::default();
+        let key = RescueVector::zero();
+        let input = RescueVector::zero();
+        let expected = RescueVector::from_elems_le_bytes(
+            &OUTPUTPALLAS[0],
+            &OUTPUTPALLAS[1],
+            &OUTPUTPALLAS[2],
+            &OUTPUTPALLAS[3],
+        );
+        let real_output = rescue.prp(&key, &input);
+        let round_keys = rescue.key_schedule(&key);
+        let real_output_with_round_keys = rescue.prp_with_round_keys(&round_keys, &input);
+        assert_eq!(real_output, real_output_with_round_keys);
+        assert_eq!(real_output, expected);
+    }
+
+    fn test_rescue_perm_on_0_vec_vesta() {
+        let rescue = PRP::<FqVesta>::default();
+        let key = RescueVector::zero();
+        let input = RescueVector::zero();
+        let expected = RescueVector::from_elems_le_bytes(
+            &OUTPUTVESTA[0],
+            &OUTPUTVESTA[1],
+            &OUTPUTVESTA[2],
+            &OUTPUTVESTA[3],
+        );
+        let real_output = rescue.prp(&key, &input);
+        let round_keys = rescue.key_schedule(&key);
+        let real_output_with_round_keys = rescue.prp_with_round_keys(&round_keys, &input);
+        assert_eq!(real_output, real_output_with_round_keys);
+        assert_eq!(real_output, expected);
+    }
+
     // printing vectors as hex bytes little endian
     // use ark_ff::{BigInteger, PrimeField};
     // use ark_std::{format, println, string::String, vec::Vec};
@@ -682,7 +849,7 @@ mod test_prp {
 mod test_permutation {
     use crate::rescue::{
         sponge::{RescueCRHF, RescuePRFCore},
-        Permutation, RescueParameter, RescueVector, PRP,
+        Permutation, RescueParameter, RescueVector, BATCH_LANES, PRP, STATE_SIZE,
     };
     use ark_bls12_377::Fq as Fq377;
     use ark_bn254::Fq as Fq254;
@@ -690,7 +857,8 @@ mod test_permutation {
     use ark_ed_on_bls12_381::Fq as Fr381;
     use ark_ed_on_bn254::Fq as Fr254;
     use ark_ff::PrimeField;
-    use ark_std::{vec, Zero};
+    use ark_pallas::{Fq as FqPallas, Fr as FqVesta};
+    use ark_std::{vec, UniformRand, Zero};
 
     #[test]
     fn test_round_keys() {
@@ -699,6 +867,8 @@ mod test_permutation {
         test_round_keys_helper::<Fr377>();
         test_round_keys_helper::<Fr381>();
         test_round_keys_helper::<Fq377>();
+        test_round_keys_helper::<FqPallas>();
+        test_round_keys_helper::<FqVesta>();
     }
 
     fn test_round_keys_helper<F: RescueParameter>() {
@@ -830,6 +1000,75 @@ mod test_permutation {
         ],
     ];
 
+    // first three vectors of the permutation output on vector [0, 0, 0, 0],
+    // computed as in test_prp
+    const OUTPUTPALLAS: [[u8; 32]; 3] = [
+        [
+            0xFB, 0x11, 0xC7, 0x50, 0x5B, 0xAA, 0x5A, 0x18, 0x19, 0x41, 0x99, 0xB7, 0x7D, 0x80,
+            0xED, 0x35, 0x9F, 0x55, 0x01, 0xF7, 0x6A, 0xD5, 0xD7, 0x86, 0xA1, 0x22, 0xDB, 0x8F,
+            0x54, 0x47, 0xA6, 0x26,
+        ],
+        [
+            0x40, 0x80, 0xFD, 0x68, 0xE2, 0x55, 0x8C, 0x81, 0x32, 0x5A, 0x67, 0xB8, 0xAA, 0xB9,
+            0x78, 0x4A, 0x9A, 0xF2, 0x65, 0x50, 0x7A, 0x3F, 0x7B, 0x3A, 0xB5, 0x96, 0xB3, 0x70,
+            0x53, 0x48, 0xAD, 0x35,
+        ],
+        [
+            0x76, 0x00, 0x4A, 0x52, 0xB3, 0x9E, 0xC4, 0x28, 0x08, 0x8F, 0x54, 0x07, 0xE5, 0x67,
+            0xFE, 0xFC, 0x06, 0x86, 0x57, 0x30, 0x26, 0xDA, 0x0A, 0xE8, 0x63, 0xB3, 0x8E, 0x7E,
+            0xB2, 0x17, 0x4D, 0x2B,
+        ],
+    ];
+
+    // first three vectors of the permutation output on vector [0, 0, 0, 0],
+    // computed as in test_prp
+    const OUTPUTVESTA: [[u8; 32]; 3] = [
+        [
+            0xD6, 0x11, 0xB0, 0xD6, 0xC3, 0xBE, 0xCC, 0x85, 0x11, 0x93, 0x44, 0xEC, 0x67, 0x42,
+            0x3A, 0x82, 0x0C, 0xB4, 0x69, 0x61, 0xE7, 0x01, 0xE1, 0x26, 0x64, 0xA8, 0x3F, 0x8B,
+            0xC9, 0xC3, 0x41, 0x18,
+        ],
+        [
+            0x70, 0x5C, 0xE0, 0x49, 0x6D, 0xCD, 0x1E, 0x66, 0x2C, 0x4C, 0xDF, 0x25, 0x39, 0x6B,
+            0xFC, 0x34, 0x44, 0xC9, 0x6A, 0x18, 0x3E, 0xBB, 0x1F, 0xC4, 0x66, 0xC7, 0x2E, 0xFF,
+            0x54, 0x33, 0x08, 0x22,
+        ],
+        [
+            0x61, 0x8F, 0xCE, 0xDD, 0x24, 0xD5, 0xE5, 0x12, 0xAF, 0x8D, 0x0D, 0xC1, 0xDD, 0xDA,
+            0x9C, 0xD0, 0x41, 0xFB, 0xDD, 0xCB, 0xDE, 0x8C, 0x4E, 0x1D, 0xC9, 0x6A, 0x1D, 0x53,
+            0xC6, 0x32, 0x92, 0x14,
+        ],
+    ];
+
+    #[test]
+    fn test_eval_batch() {
+        test_eval_batch_helper::<Fq254>();
+        test_eval_batch_helper::<Fr254>();
+        test_eval_batch_helper::<Fr377>();
+        test_eval_batch_helper::<Fr381>();
+        test_eval_batch_helper::<Fq377>();
+        test_eval_batch_helper::<FqPallas>();
+        test_eval_batch_helper::<FqVesta>();
+    }
+
+    fn test_eval_batch_helper<F: RescueParameter>() {
+        let mut prng = jf_utils::test_rng();
+        let permutation = Permutation::<F>::default();
+        assert!(permutation.eval_batch(&[]).is_empty());
+        for num_inputs in [1, BATCH_LANES, 2 * BATCH_LANES + 1] {
+            let inputs: Vec<RescueVector<F>> = (0..num_inputs)
+                .map(|_| RescueVector {
+                    vec: [(); STATE_SIZE].map(|_| F::rand(&mut prng)),
+                })
+                .collect();
+            let expected: Vec<_> = inputs.iter().map(|input| permutation.eval(input)).collect();
+            assert_eq!(permutation.eval_batch(&inputs), expected);
+            for (lanes, expected) in inputs.chunks(BATCH_LANES).zip(expected.chunks(BATCH_LANES)) {
+                assert_eq!(permutation.eval_lockstep(lanes), expected);
+            }
+        }
+    }
+
     #[test]
     fn test_sponge() {
         test_sponge_helper::<Fq254>();
@@ -837,6 +1076,8 @@ mod test_permutation {
         test_sponge_helper::<Fr377>();
         test_sponge_helper::<Fr381>();
         test_sponge_helper::<Fq377>();
+        test_sponge_helper::<FqPallas>();
+        test_sponge_helper::<FqVesta>();
     }
 
     fn test_sponge_helper<F: RescueParameter>() {
@@ -869,7 +1110,9 @@ mod test_permutation {
         test_rescue_hash_on_0_vec_254();
         test_rescue_hash_on_0_vec_377();
         test_rescue_hash_on_0_vec_381();
-        test_rescue_hash_on_0_vec_761()
+        test_rescue_hash_on_0_vec_761();
+        test_rescue_hash_on_0_vec_pallas();
+        test_rescue_hash_on_0_vec_vesta();
     }
 
     fn test_rescue_hash_on_0_vec_fq254() {
@@ -927,6 +1170,28 @@ mod test_permutation {
         assert_eq!(real_output, expected);
     }
 
+    fn test_rescue_hash_on_0_vec_pallas() {
+        let input = [FqPallas::zero(); 3];
+        let expected = vec![
+            FqPallas::from_le_bytes_mod_order(&OUTPUTPALLAS[0]),
+            FqPallas::from_le_bytes_mod_order(&OUTPUTPALLAS[1]),
+            FqPallas::from_le_bytes_mod_order(&OUTPUTPALLAS[2]),
+        ];
+        let real_output = RescueCRHF::sponge_no_padding(&input, 3).unwrap();
+        assert_eq!(real_output, expected);
+    }
+
+    fn test_rescue_hash_on_0_vec_vesta() {
+        let input = [FqVesta::zero(); 3];
+        let expected = vec![
+            FqVesta::from_le_bytes_mod_order(&OUTPUTVESTA[0]),
+            FqVesta::from_le_bytes_mod_order(&OUTPUTVESTA[1]),
+            FqVesta::from_le_bytes_mod_order(&OUTPUTVESTA[2]),
+        ];
+        let real_output = RescueCRHF::sponge_no_padding(&input, 3).unwrap();
+        assert_eq!(real_output, expected);
+    }
+
     #[test]
     fn test_fsks_no_padding_errors() {
         test_fsks_no_padding_errors_helper::<Fq254>();
@@ -934,6 +1199,8 @@ mod test_permutation {
         test_fsks_no_padding_errors_helper::<Fr377>();
         test_fsks_no_padding_errors_helper::<Fr381>();
         test_fsks_no_padding_errors_helper::<Fq377>();
+        test_fsks_no_padding_errors_helper::<FqPallas>();
+        test_fsks_no_padding_errors_helper::<FqVesta>();
     }
     fn test_fsks_no_padding_errors_helper<F: RescueParameter>() {
         let key = F::rand(&mut jf_utils::test_rng());
@@ -969,6 +1236,8 @@ mod test_permutation {
         test_variable_output_sponge_and_fsks_helper::<Fr377>();
         test_variable_output_sponge_and_fsks_helper::<Fr381>();
         test_variable_output_sponge_and_fsks_helper::<Fq377>();
+        test_variable_output_sponge_and_fsks_helper::<FqPallas>();
+        test_variable_output_sponge_and_fsks_helper::<FqVesta>();
     }
     fn test_variable_output_sponge_and_fsks_helper<F: RescueParameter>() {
         let input = [F::zero(), F::one(), F::zero()];
@@ -0,0 +1,390 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+//! The Rescue-Prime profile of rescue, following the
+//! [Rescue-Prime specification](https://eprint.iacr.org/2020/1143.pdf) and its
+//! reference implementation, for a state of `STATE_SIZE` elements, a capacity
+//! of `CAPACITY` element and `SECURITY_LEVEL` bits of security.
+//!
+//! It differs from the default profile of this module in:
+//! - the # of rounds, given by [`rescue_prime_rounds`], i.e. 1.5 times the # of
+//!   rounds resisting Gröbner basis attacks, with a minimum of 5;
+//! - the rounds, which start with the `x^alpha` S-box and have no key schedule:
+//!   each half-round is an S-box, the MDS matrix, then the addition of round
+//!   constants;
+//! - the constants: the MDS matrix is the transpose of the right half of the
+//!   reduced echelon form of `[g^(i * j)]`, where `g` is the smallest primitive
+//!   element of the field (which is the `GENERATOR` of arkworks for all the
+//!   fields implemented here), and the round constants are sampled from
+//!   SHAKE256 seeded with `Rescue-XLIX(p,m,capacity,security_level)`;
+//! - the padding of the hash, which appends a one and as many zeros as needed,
+//!   and returns the rate of the state after absorption.
+//!
+//! The profile is selected per instantiation by using
+//! [`RescuePrimePermutation`], [`RescuePrimeCRHF`] or
+//! [`VariableLengthRescuePrimeCRHF`](crate::crhf::VariableLengthRescuePrimeCRHF)
+//! instead of their default counterparts.
+
+use super::{RescueMatrix, RescueParameter, RescueVector, CRHF_RATE, STATE_SIZE};
+use ark_ff::Field;
+use ark_std::{boxed::Box, format, vec::Vec};
+use digest::{ExtendableOutput, Update, XofReader};
+use jf_utils::pad_with_zeros;
+use num_bigint::BigUint;
+use once_cell::race::OnceBox;
+use sha3::Shake256;
+
+/// The capacity of the Rescue-Prime profile.
+pub const CAPACITY: usize = STATE_SIZE - CRHF_RATE;
+/// The security level in bits of the Rescue-Prime profile.
+pub const SECURITY_LEVEL: usize = 128;
+
+/// Fields with a Rescue-Prime permutation.
+pub trait RescuePrimeParameter: RescueParameter {
+    /// The Rescue-Prime permutation of this field.
+    fn rescue_prime() -> &'static RescuePrimePermutation<Self>;
+}
+
+/// The # of rounds of Rescue-Prime over a state of `STATE_SIZE` elements,
+/// from the formula of the specification.
+/// * `alpha` - the degree of the S-box
+/// * `capacity` - the capacity of the sponge
+/// * `security_level` - in bits
+pub fn rescue_prime_rounds(alpha: u64, capacity: usize, security_level: usize) -> usize {
+    let m = STATE_SIZE as u64;
+    let rate = m - capacity as u64;
+    let target = BigUint::from(1u64) << security_level;
+    let rounds = (1..25u64)
+        .find(|&n| {
+            // degree of the constraints, and # of variables, after n rounds
+            let dcon = (alpha - 1) * m * (n - 1) / 2 + 2;
+            let v = m * (n - 1) + rate;
+            let binomial = (1..=v).fold(BigUint::from(1u64), |acc, i| {
+                acc * BigUint::from(dcon + i) / BigUint::from(i)
+            });
+            &binomial * &binomial > target
+        })
+        .unwrap_or(25);
+    (3 * rounds.max(5) as usize + 1) / 2
+}
+
+/// The Rescue-Prime permutation, see the [module documentation](self).
+#[derive(Debug, Clone)]
+pub struct RescuePrimePermutation<F> {
+    mds: RescueMatrix<F>,
+    /// Two vectors of round constants per round, in order
+    round_constants: Vec<RescueVector<F>>,
+}
+
+impl<F: RescuePrimeParameter> Default for RescuePrimePermutation<F> {
+    fn default() -> Self {
+        F::rescue_prime().clone()
+    }
+}
+
+impl<F: RescueParameter> RescuePrimePermutation<F> {
+    /// Derive the MDS matrix and round constants of `F`.
+    fn derive() -> Self {
+        let rounds = rescue_prime_rounds(F::A, CAPACITY, SECURITY_LEVEL);
+
+        // systematic generator matrix of the Reed-Solomon code over g^i
+        let mut generator_matrix: Vec<Vec<F>> = (0..STATE_SIZE as u64)
+            .map(|i| {
+                (0..2 * STATE_SIZE as u64)
+                    .map(|j| F::GENERATOR.pow([i * j]))
+                    .collect()
+            })
+            .collect();
+        reduced_echelon_form(&mut generator_matrix);
+        let mut mds = [RescueVector::zero(); STATE_SIZE];
+        for (i, row) in mds.iter_mut().enumerate() {
+            for (j, elem) in row.vec.iter_mut().enumerate() {
+                *elem = generator_matrix[j][STATE_SIZE + i];
+            }
+        }
+
+        let modulus: BigUint = F::MODULUS.into();
+        let seed = format!(
+            "Rescue-XLIX({},{},{},{})",
+            modulus, STATE_SIZE, CAPACITY, SECURITY_LEVEL
+        );
+        let bytes_per_elem = (F::MODULUS_BIT_SIZE as usize + 7) / 8 + 1;
+        let mut bytes = ark_std::vec![0u8; bytes_per_elem * 2 * STATE_SIZE * rounds];
+        Shake256::default()
+            .chain(seed.as_bytes())
+            .finalize_xof()
+            .read(&mut bytes);
+        let round_constants = bytes
+            .chunks(bytes_per_elem * STATE_SIZE)
+            .map(|chunk| {
+                let mut constants = RescueVector::zero();
+                let elems_bytes = chunk.chunks(bytes_per_elem);
+                for (elem, elem_bytes) in constants.vec.iter_mut().zip(elems_bytes) {
+                    *elem = F::from_le_bytes_mod_order(elem_bytes);
+                }
+                constants
+            })
+            .collect();
+
+        Self {
+            mds: RescueMatrix::from(&mds),
+            round_constants,
+        }
+    }
+
+    /// Return the # of rounds.
+    pub fn rounds(&self) -> usize {
+        self.round_constants.len() / 2
+    }
+
+    /// Return a pointer to the mds matrix.
+    pub fn mds_matrix_ref(&self) -> &RescueMatrix<F> {
+        &self.mds
+    }
+
+    /// Return a pointer to the round constants, two vectors per round.
+    pub fn round_constants_ref(&self) -> &[RescueVector<F>] {
+        &self.round_constants
+    }
+
+    /// Compute the permutation on RescueVector `input`.
+    pub fn eval(&self, input: &RescueVector<F>) -> RescueVector<F> {
+        let mut state = *input;
+        for constants in self.round_constants.chunks(2) {
+            state.pow(&[F::A]);
+            state.linear(&self.mds, &constants[0]);
+            state.pow(F::A_INV);
+            state.linear(&self.mds, &constants[1]);
+        }
+        state
+    }
+}
+
+/// Reduce the full rank `matrix` with more columns than rows to its reduced
+/// row echelon form.
+fn reduced_echelon_form<F: Field>(matrix: &mut [Vec<F>]) {
+    for col in 0..matrix.len() {
+        let pivot = (col..matrix.len())
+            .find(|&row| !matrix[row][col].is_zero())
+            .expect("a Vandermonde matrix has full rank");
+        matrix.swap(col, pivot);
+        let inverse = matrix[col][col].inverse().expect("the pivot is not zero");
+        matrix[col].iter_mut().for_each(|elem| *elem *= inverse);
+        let pivot_row = matrix[col].clone();
+        for (row, elems) in matrix.iter_mut().enumerate() {
+            if row != col {
+                let factor = elems[col];
+                elems
+                    .iter_mut()
+                    .zip(pivot_row.iter())
+                    .for_each(|(elem, pivot)| *elem -= factor * pivot);
+            }
+        }
+    }
+}
+
+/// The hash function of Rescue-Prime, see the [module
+/// documentation](self).
+#[derive(Debug, Clone)]
+pub struct RescuePrimeCRHF<F: RescuePrimeParameter> {
+    permutation: &'static RescuePrimePermutation<F>,
+}
+
+impl<F: RescuePrimeParameter> Default for RescuePrimeCRHF<F> {
+    fn default() -> Self {
+        Self {
+            permutation: F::rescue_prime(),
+        }
+    }
+}
+
+impl<F: RescuePrimeParameter> RescuePrimeCRHF<F> {
+    /// Hash `input` with the padding of Rescue-Prime: a "1" is appended, then
+    /// as many "0" as required for the length to be a multiple of
+    /// `CRHF_RATE`. The first `CRHF_RATE` outputs are the hash of the
+    /// specification; if more are requested, the permutation is applied again
+    /// to squeeze them.
+    pub fn sponge_with_padding(input: &[F], num_outputs: usize) -> Vec<F> {
+        Self::default().hash_with_padding(input, num_outputs)
+    }
+
+    /// Same as [`Self::sponge_with_padding`].
+    pub fn hash_with_padding(&self, input: &[F], num_outputs: usize) -> Vec<F> {
+        let mut padded = input.to_vec();
+        padded.push(F::one());
+        pad_with_zeros(&mut padded, CRHF_RATE);
+
+        let mut state = RescueVector::zero();
+        for chunk in padded.chunks(CRHF_RATE) {
+            state.add_assign_elems(chunk);
+            state = self.permutation.eval(&state);
+        }
+        let mut output = Vec::with_capacity(num_outputs);
+        loop {
+            let extract = (num_outputs - output.len()).min(CRHF_RATE);
+            output.extend_from_slice(&state.vec[..extract]);
+            if output.len() == num_outputs {
+                return output;
+            }
+            state = self.permutation.eval(&state);
+        }
+    }
+}
+
+macro_rules! impl_rescue_prime_parameter {
+    ($field:ty) => {
+        impl RescuePrimeParameter for $field {
+            fn rescue_prime() -> &'static RescuePrimePermutation<Self> {
+                static PERMUTATION: OnceBox<RescuePrimePermutation<$field>> = OnceBox::new();
+                PERMUTATION.get_or_init(|| Box::new(RescuePrimePermutation::derive()))
+            }
+        }
+    };
+}
+
+impl_rescue_prime_parameter!(ark_bls12_377::Fq);
+impl_rescue_prime_parameter!(ark_bn254::Fq);
+impl_rescue_prime_parameter!(ark_ed_on_bls12_377::Fq);
+impl_rescue_prime_parameter!(ark_ed_on_bls12_381::Fq);
+impl_rescue_prime_parameter!(ark_ed_on_bn254::Fq);
+impl_rescue_prime_parameter!(ark_pallas::Fq);
+impl_rescue_prime_parameter!(ark_pallas::Fr);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::rescue::Permutation;
+    use ark_ed_on_bls12_377::Fq as Fr377;
+    use ark_ed_on_bn254::Fq as Fr254;
+    use ark_ff::MontFp;
+    use ark_std::{vec, UniformRand};
+
+    #[test]
+    fn test_rescue_prime_rounds() {
+        // alpha = 3 and 7 are not used here, but are checked against the
+        // reference implementation as well
+        assert_eq!(rescue_prime_rounds(3, 1, 128), 14);
+        assert_eq!(rescue_prime_rounds(5, 1, 128), 11);
+        assert_eq!(rescue_prime_rounds(7, 1, 128), 9);
+        assert_eq!(rescue_prime_rounds(11, 1, 128), 8);
+    }
+
+    #[test]
+    fn test_rescue_prime_vectors() {
+        // computed with a Python implementation of the reference
+        // implementation of Rescue-Prime
+        let permutation = Fr254::rescue_prime();
+        assert_eq!(permutation.rounds(), 11);
+        assert_eq!(
+            permutation.mds_matrix_ref().vec(0).elems()[1],
+            Fr254::from(19500u64)
+        );
+        let rc0: Fr254 = MontFp!(
+            "19372445876017403532017686159715766973255657576148543734955949270184900210002"
+        );
+        assert_eq!(permutation.round_constants_ref()[0].elems()[0], rc0);
+        let input = RescueVector::from(&[
+            Fr254::from(0u64),
+            Fr254::from(1u64),
+            Fr254::from(2u64),
+            Fr254::from(3u64),
+        ]);
+        let expected: Vec<Fr254> = vec![
+            MontFp!(
+                "17333178955977042199479936369437270786487137858152022215081994148672921158408"
+            ),
+            MontFp!("8006726097758931559973272907884845846453113410286106509125997875515397162743"),
+            MontFp!("9434084398625838466297011603701866751011358678150517537600748135805911517609"),
+            MontFp!("7622214221431152665281586291361980203350090867725753540724535009239019393868"),
+        ];
+        assert_eq!(permutation.eval(&input).elems(), expected);
+
+        let input: Vec<Fr254> = (1..=5u64).map(Fr254::from).collect();
+        let expected: Vec<Fr254> = vec![
+            MontFp!(
+                "13868284933588671797265101672532369387915022254861707414325798819248357817804"
+            ),
+            MontFp!(
+                "19502156309363328685809816420287561395666366604131971594180514309087359910492"
+            ),
+            MontFp!(
+                "16216822848354930959069969012932408653566388163766892063354546226546177187701"
+            ),
+        ];
+        assert_eq!(RescuePrimeCRHF::sponge_with_padding(&input, 3), expected);
+        let expected: Vec<Fr254> = vec![MontFp!(
+            "9263109350190961464475892523485190606654852374411282212720694626196667592396"
+        )];
+        assert_eq!(RescuePrimeCRHF::sponge_with_padding(&[], 1), expected);
+
+        let permutation = Fr377::rescue_prime();
+        assert_eq!(permutation.rounds(), 8);
+        let input = RescueVector::from(&[
+            Fr377::from(0u64),
+            Fr377::from(1u64),
+            Fr377::from(2u64),
+            Fr377::from(3u64),
+        ]);
+        let expected: Vec<Fr377> = vec![
+            MontFp!("1422294765460064871215491912436541802583607803912877915735743040986667848610"),
+            MontFp!("2174715206839565202011305573419790373176246214985009981065525135156548892407"),
+            MontFp!("5613424755992844700873333823916554660534556873841260830723462959988867814588"),
+            MontFp!("6042233144886916252166985165606554713137288585791326684560655859597101538852"),
+        ];
+        assert_eq!(permutation.eval(&input).elems(), expected);
+    }
+
+    #[test]
+    fn test_rescue_prime() {
+        test_rescue_prime_helper::<ark_bls12_377::Fq>();
+        test_rescue_prime_helper::<ark_bn254::Fq>();
+        test_rescue_prime_helper::<Fr377>();
+        test_rescue_prime_helper::<ark_ed_on_bls12_381::Fq>();
+        test_rescue_prime_helper::<Fr254>();
+        test_rescue_prime_helper::<ark_pallas::Fq>();
+        test_rescue_prime_helper::<ark_pallas::Fr>();
+    }
+
+    fn test_rescue_prime_helper<F: RescuePrimeParameter>() {
+        let rng = &mut jf_utils::test_rng();
+        let permutation = F::rescue_prime();
+        // permutation is cached
+        assert!(core::ptr::eq(permutation, F::rescue_prime()));
+        assert_eq!(
+            RescuePrimePermutation::<F>::default().round_constants_ref(),
+            permutation.round_constants_ref()
+        );
+        assert_eq!(
+            permutation.rounds(),
+            rescue_prime_rounds(F::A, CAPACITY, SECURITY_LEVEL)
+        );
+
+        let input = RescueVector::from(&[F::rand(rng), F::rand(rng), F::rand(rng), F::rand(rng)]);
+        let output = permutation.eval(&input);
+        assert_ne!(output, input);
+        assert_ne!(output, Permutation::<F>::default().eval(&input));
+
+        // padding always appends a one
+        let input: Vec<F> = (0..CRHF_RATE).map(|_| F::rand(rng)).collect();
+        let output = RescuePrimeCRHF::sponge_with_padding(&input, 5);
+        assert_eq!(output.len(), 5);
+        assert_eq!(
+            output[..2],
+            RescuePrimeCRHF::sponge_with_padding(&input, 2)[..]
+        );
+        let mut state = RescueVector::from(&[input[0], input[1], input[2], F::zero()]);
+        state = permutation.eval(&state);
+        state.vec[0] += F::one();
+        state = permutation.eval(&state);
+        assert_eq!(output[..CRHF_RATE], state.vec[..CRHF_RATE]);
+        let mut padded = input.clone();
+        padded.push(F::one());
+        assert_ne!(
+            RescuePrimeCRHF::sponge_with_padding(&padded, 3),
+            output[..3]
+        );
+    }
+}
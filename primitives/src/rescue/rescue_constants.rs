@@ -0,0 +1,65 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+//! Rescue parameters of every supported field, generated by the build script
+//! of this crate with `Rescue(128, p, 4, alpha)` of the Marvellous scripts and
+//! ROUNDS = 12.
+//!
+//! The initial constants, key schedule matrices and key schedule constants
+//! of the bls12_377, ed_on_bls12_377, ed_on_bls12_381, ed_on_bn254 and bn254
+//! base fields are the ones sampled by the
+//! [Marvellous scripts](https://github.com/EspressoSystems/Marvellous). For
+//! the pallas and vesta base fields, they are sampled, in this order, from
+//! SHAKE128 seeded with the ASCII string "Jellyfish Rescue pallas_base"
+//! (resp. "Jellyfish Rescue vesta_base"): each element is a 64 bytes
+//! little-endian chunk of the output reduced mod p, and a key schedule matrix
+//! candidate that is not invertible is discarded.
+//!
+//! The MDS matrix, the key injection constants and the permutation round keys
+//! are then derived from those, and from the multiplicative generator of the
+//! field, as the scripts do.
+
+use crate::rescue::{RescueParameter, ROUNDS, STATE_SIZE};
+
+include!(concat!(env!("OUT_DIR"), "/rescue_constants.rs"));
+
+// !!!Warning!!!
+// The parameters below are mock parameters for testing purpose.
+// They are not correct and shall not be used for anything else
+
+/// This is a dummy implementation of Rescue parameters
+/// to satisfy trait bound for Fq.
+/// This code should not be used for any other purpose.
+impl RescueParameter for ark_bls12_381::Fq {
+    const A: u64 = 5;
+    const A_INV: &'static [u64] = &[0, 0, 0, 0, 0, 0];
+
+    const MDS_LE: [[&'static [u8]; STATE_SIZE]; STATE_SIZE] =
+        [[&[0u8; 32]; STATE_SIZE]; STATE_SIZE];
+
+    const INIT_VEC_LE: [&'static [u8]; STATE_SIZE] = [&[0u8; 32]; STATE_SIZE];
+
+    const KEY_INJECTION_LE: [[&'static [u8]; 4]; 2 * ROUNDS] = [[&[0u8; 32]; 4]; 2 * ROUNDS];
+
+    const PERMUTATION_ROUND_KEYS: [[&'static [u8]; 4]; 25] = [[&[0u8; 32]; 4]; 25];
+}
+
+/// This is a dummy implementation of Rescue parameters
+/// to satisfy trait bound for Fq.
+/// This code should not be used for any other purpose.
+impl RescueParameter for ark_bw6_761::Fq {
+    const A: u64 = 5;
+    const A_INV: &'static [u64] = &[0, 0, 0, 0, 0, 0];
+
+    const MDS_LE: [[&'static [u8]; STATE_SIZE]; STATE_SIZE] =
+        [[&[0u8; 32]; STATE_SIZE]; STATE_SIZE];
+
+    const INIT_VEC_LE: [&'static [u8]; STATE_SIZE] = [&[0u8; 32]; STATE_SIZE];
+
+    const KEY_INJECTION_LE: [[&'static [u8]; 4]; 2 * ROUNDS] = [[&[0u8; 32]; 4]; 2 * ROUNDS];
+
+    const PERMUTATION_ROUND_KEYS: [[&'static [u8]; 4]; 25] = [[&[0u8; 32]; 4]; 25];
+}
@@ -1,1522 +0,0 @@
-// Copyright (c) 2022 Espresso Systems (espressosys.com)
-// This file is part of the Jellyfish library.
-
-// You should have received a copy of the MIT License
-// along with the Jellyfish library. If not, see <https://mit-license.org/>.
-
-use crate::rescue::{RescueParameter, ROUNDS, STATE_SIZE};
-use ark_bls12_377::Fq;
-
-// the constants in this file are generated with
-// https://github.com/EspressoSystems/Marvellous/blob/fcd4c41672f485ac2f62526bc87a16789d4d0459/rescue761.sage
-
-// q761 = 258664426012969094010652733694893533536393512754914660539884262666720468348340822774968888139573360124440321458177
-// rescue761 = Rescue(128, q761, 4, 5)
-
-impl RescueParameter for Fq {
-    const A: u64 = 5;
-    // 1/5%(q761-1) =
-    // 206931540810375275208522186955914826829114810203931728431907410133376374678672658219975110511658688099552257166541
-    const A_INV: &'static [u64] = &[
-        0xd0d3cccccccccccd,
-        0x126f7dd026666666,
-        0x18c2b4f2fb3aa000,
-        0x7b4f14c2672a760c,
-        0x04fc0499f0810762,
-        0x1582e9e796a73ef,
-    ];
-
-    // The following constants are from the output of
-    //  rescue761.MDS
-    // MDS[0,0]= "52435875175126190479447740508185965837690552500527637822603658699938581066864"
-    // MDS[0,1]= "52435875175126190479447740508185965837690552500527637822603658699938534124913"
-    // MDS[0,2]= "52435875175126190479447740508185965837690552500527637822603658699922104442063"
-    // MDS[0,3]= "52435875175126190479447740508185965837690552500527637822603658694270581781713"
-    // MDS[1,0]= "137200"
-    // MDS[1,1]= "54762351"
-    // MDS[1,2]= "19167800400"
-    // MDS[1,3]= "6593435097550"
-    // MDS[2,0]= "52435875175126190479447740508185965837690552500527637822603658699938581164563"
-    // MDS[2,1]= "52435875175126190479447740508185965837690552500527637822603658699938573341713"
-    // MDS[2,2]= "52435875175126190479447740508185965837690552500527637822603658699935841949364"
-    // MDS[2,3]= "52435875175126190479447740508185965837690552500527637822603658698996613844913"
-    // MDS[3,0]= "400"
-    // MDS[3,1]= "140050"
-    // MDS[3,2]= "48177200"
-    // MDS[3,3]= "16531644851"
-    // MDS matrix in little-endian 32 bytes elements
-    const MDS_LE: [[&'static [u8]; STATE_SIZE]; STATE_SIZE] = [
-        [
-            &[
-                0x60, 0x31, 0x52, 0xFF, 0xFF, 0xBF, 0x08, 0x85, 0x00, 0x00, 0x00, 0x30, 0x44, 0x5D,
-                0x0B, 0x17, 0x00, 0x48, 0x09, 0xBA, 0x2F, 0x62, 0xF3, 0x1E, 0x8F, 0x13, 0xF5, 0x00,
-                0xF3, 0xD9, 0x22, 0x1A, 0x3B, 0x49, 0xA1, 0x6C, 0xC0, 0x05, 0x3B, 0xC6, 0xEA, 0x10,
-                0xC5, 0x17, 0x46, 0x3A, 0xAE, 0x01,
-            ],
-            &[
-                0xE1, 0x5D, 0xF9, 0x68, 0xF6, 0xBF, 0x08, 0x85, 0x00, 0x00, 0x00, 0x30, 0x44, 0x5D,
-                0x0B, 0x17, 0x00, 0x48, 0x09, 0xBA, 0x2F, 0x62, 0xF3, 0x1E, 0x8F, 0x13, 0xF5, 0x00,
-                0xF3, 0xD9, 0x22, 0x1A, 0x3B, 0x49, 0xA1, 0x6C, 0xC0, 0x05, 0x3B, 0xC6, 0xEA, 0x10,
-                0xC5, 0x17, 0x46, 0x3A, 0xAE, 0x01,
-            ],
-            &[
-                0x1F, 0xB2, 0xB8, 0x7C, 0x01, 0x41, 0x08, 0x85, 0x00, 0x00, 0x00, 0x30, 0x44, 0x5D,
-                0x0B, 0x17, 0x00, 0x48, 0x09, 0xBA, 0x2F, 0x62, 0xF3, 0x1E, 0x8F, 0x13, 0xF5, 0x00,
-                0xF3, 0xD9, 0x22, 0x1A, 0x3B, 0x49, 0xA1, 0x6C, 0xC0, 0x05, 0x3B, 0xC6, 0xEA, 0x10,
-                0xC5, 0x17, 0x46, 0x3A, 0xAE, 0x01,
-            ],
-            &[
-                0xA1, 0xEB, 0xAC, 0xC3, 0x9C, 0x03, 0x7E, 0x7E, 0x00, 0x00, 0x00, 0x30, 0x44, 0x5D,
-                0x0B, 0x17, 0x00, 0x48, 0x09, 0xBA, 0x2F, 0x62, 0xF3, 0x1E, 0x8F, 0x13, 0xF5, 0x00,
-                0xF3, 0xD9, 0x22, 0x1A, 0x3B, 0x49, 0xA1, 0x6C, 0xC0, 0x05, 0x3B, 0xC6, 0xEA, 0x10,
-                0xC5, 0x17, 0x46, 0x3A, 0xAE, 0x01,
-            ],
-        ],
-        [
-            &[
-                0xE0, 0x37, 0xBA, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-                0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            ],
-            &[
-                0x5F, 0x6D, 0xA7, 0x45, 0x0A, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-                0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            ],
-            &[
-                0xA0, 0x39, 0x0F, 0x6A, 0x06, 0x88, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-                0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            ],
-            &[
-                0x1E, 0xA6, 0xFA, 0xCC, 0x0F, 0xD4, 0x01, 0x07, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-                0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            ],
-        ],
-        [
-            &[
-                0xA3, 0x88, 0xF3, 0xFF, 0xFF, 0xBF, 0x08, 0x85, 0x00, 0x00, 0x00, 0x30, 0x44, 0x5D,
-                0x0B, 0x17, 0x00, 0x48, 0x09, 0xBA, 0x2F, 0x62, 0xF3, 0x1E, 0x8F, 0x13, 0xF5, 0x00,
-                0xF3, 0xD9, 0x22, 0x1A, 0x3B, 0x49, 0xA1, 0x6C, 0xC0, 0x05, 0x3B, 0xC6, 0xEA, 0x10,
-                0xC5, 0x17, 0x46, 0x3A, 0xAE, 0x01,
-            ],
-            &[
-                0x21, 0x28, 0xA4, 0x50, 0xFF, 0xBF, 0x08, 0x85, 0x00, 0x00, 0x00, 0x30, 0x44, 0x5D,
-                0x0B, 0x17, 0x00, 0x48, 0x09, 0xBA, 0x2F, 0x62, 0xF3, 0x1E, 0x8F, 0x13, 0xF5, 0x00,
-                0xF3, 0xD9, 0x22, 0x1A, 0x3B, 0x49, 0xA1, 0x6C, 0xC0, 0x05, 0x3B, 0xC6, 0xEA, 0x10,
-                0xC5, 0x17, 0x46, 0x3A, 0xAE, 0x01,
-            ],
-            &[
-                0xE4, 0x7B, 0x81, 0x76, 0xEE, 0xB6, 0x08, 0x85, 0x00, 0x00, 0x00, 0x30, 0x44, 0x5D,
-                0x0B, 0x17, 0x00, 0x48, 0x09, 0xBA, 0x2F, 0x62, 0xF3, 0x1E, 0x8F, 0x13, 0xF5, 0x00,
-                0xF3, 0xD9, 0x22, 0x1A, 0x3B, 0x49, 0xA1, 0x6C, 0xC0, 0x05, 0x3B, 0xC6, 0xEA, 0x10,
-                0xC5, 0x17, 0x46, 0x3A, 0xAE, 0x01,
-            ],
-            &[
-                0x61, 0xA6, 0xAE, 0xA5, 0x4A, 0x29, 0x91, 0x84, 0x00, 0x00, 0x00, 0x30, 0x44, 0x5D,
-                0x0B, 0x17, 0x00, 0x48, 0x09, 0xBA, 0x2F, 0x62, 0xF3, 0x1E, 0x8F, 0x13, 0xF5, 0x00,
-                0xF3, 0xD9, 0x22, 0x1A, 0x3B, 0x49, 0xA1, 0x6C, 0xC0, 0x05, 0x3B, 0xC6, 0xEA, 0x10,
-                0xC5, 0x17, 0x46, 0x3A, 0xAE, 0x01,
-            ],
-        ],
-        [
-            &[
-                0x20, 0x0E, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-                0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            ],
-            &[
-                0xA2, 0x0C, 0xBB, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-                0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            ],
-            &[
-                0x60, 0x98, 0xB6, 0xA2, 0x09, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-                0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            ],
-            &[
-                0xE3, 0xC7, 0xA9, 0xC9, 0x08, 0x7F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-                0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            ],
-        ],
-    ];
-
-    // The following constants are from the output of
-    // rescue761.initial_constant
-    // a.k.a. INIT_VEC
-    // [ 77507741804555316993326082334329667156580222818473918560077191823296473118317123729262887858623098431781053309697]
-    // [177993200452785627663434075184773911478631764927526237009207307351752484358508273099085386344157374772412110572723]
-    // [ 42671898647707236005613181291770821066233014145576918788154689427213509168112961864404241863766382952036322055440]
-    // [ 45033590264584434678143420627501913399760575844210996496564881493751236188913039187009657997807557295408210829651]
-
-    const INIT_VEC_LE: [&'static [u8]; STATE_SIZE] = [
-        &[
-            0x01, 0xA3, 0xE6, 0xF7, 0xEB, 0x5C, 0x3E, 0xA8, 0x11, 0x17, 0xC8, 0xD8, 0xA7, 0x9B,
-            0x1D, 0x50, 0x86, 0x39, 0xCF, 0xF8, 0xA2, 0x59, 0xB9, 0xE0, 0x70, 0x1F, 0x45, 0x88,
-            0xA1, 0x8F, 0x72, 0xF3, 0xDB, 0xD5, 0xFC, 0xE9, 0x8B, 0x0C, 0x8C, 0xA0, 0x18, 0x8E,
-            0x5E, 0x35, 0x7C, 0xEA, 0x80, 0x00,
-        ],
-        &[
-            0xB3, 0x8C, 0xCB, 0x48, 0x1D, 0x58, 0x01, 0xAD, 0x88, 0x91, 0x83, 0xCB, 0x20, 0xE8,
-            0x12, 0xBA, 0xCB, 0xAB, 0x56, 0x29, 0x5E, 0x9D, 0xDC, 0xB7, 0x73, 0x7A, 0xEE, 0x48,
-            0xE7, 0x74, 0x80, 0x2D, 0xBA, 0x2A, 0x98, 0x82, 0x28, 0x20, 0xC6, 0x34, 0xFC, 0xF3,
-            0xFF, 0x38, 0xCA, 0x0C, 0x28, 0x01,
-        ],
-        &[
-            0x10, 0x15, 0x95, 0x26, 0x66, 0xD2, 0xB9, 0x8A, 0x65, 0x27, 0x3E, 0x05, 0xB6, 0x29,
-            0x44, 0x13, 0x96, 0x7D, 0x10, 0x55, 0xF2, 0xDA, 0xE7, 0xE4, 0xEF, 0xB1, 0xBC, 0xC6,
-            0x70, 0x8A, 0xB5, 0xFF, 0xB5, 0xE0, 0xDC, 0xA2, 0xCE, 0x83, 0x8E, 0xFF, 0x3F, 0x87,
-            0x2B, 0xE8, 0x85, 0xF9, 0x46, 0x00,
-        ],
-        &[
-            0x53, 0xE1, 0xB8, 0x63, 0xDA, 0x13, 0xA1, 0xD9, 0xDE, 0x80, 0xAE, 0x92, 0x20, 0x40,
-            0xB9, 0xB8, 0xC6, 0x2B, 0x57, 0xF0, 0x9B, 0x39, 0x5F, 0xB4, 0xFA, 0x78, 0x7A, 0x55,
-            0xA3, 0x91, 0x26, 0x17, 0xCB, 0xB8, 0x01, 0x31, 0x73, 0xB8, 0x4A, 0xBB, 0x8C, 0xB3,
-            0x83, 0x33, 0x1F, 0xE7, 0x4A, 0x00,
-        ],
-    ];
-
-    // The following constants are from the output of
-    // sage: rescue761.constants_matrix
-    // a.k.a., KEY_SCHED_MATRIX
-    // [ 21388085173807843040147935573417925569769020225193617578144868856502027742399743078769141493256606803926503599374
-    // 148746542684121341018714066208374265919075055377306679043064024347118850671751185133057962508923687274838824500925
-    // 143819582770047829101663158711390879513705210146311295028160623473148675467598427198647083753029698768858402079541
-    // 3320680946084112103693932626141017732413334717099114430920766606434955369475855543216863218041474664802403073058]
-    // [183768457328422669222977223024408961920779409488665102575667756351407991511156109410669976864277433172454062790865
-    // 86262470992355870287064697438766110866091133226131257608030067284797613730404633680389970467544288414448970640431
-    // 10141529452607706846164010038504864885212242001966416290596439642012716063698654410399480463149170988637418892069
-    // 34929829384562879985325569510344487753295144848070995250563110087160439452627991533789013213400479994287969078208]
-    // [  6650937378529070682526639898286326847015452054856629877525247129417093488313988331200070521759802495574854233202
-    // 11207651975622440481011491604501335135454465278672018847895237603502933967106346119682895132466234315451111630038
-    // 178758033912697096063915270457152713862989796724245057415505952206286592260540400038234250838461711082589505119629
-    // 37770614912116059492309039778060447526645530728888326579646215635246607032626978794416808338344285355744338833098]
-    // [ 48007962636822532786431277677071434318837497813225484890354314276686082130099472494756251599795523661039717661648
-    // 40817968235770745163853460250471094884778138289227149084515575536005465633031575643407841686155502248718950599741
-    // 117774843913239531037310660892595295228882572377609668933575123042777696376612146381343869264422107812884980847766
-    // 247129210489551277563634781872704504466060455510724849767887332822384975784500582930038181009342808401241993040433]
-    //
-
-    // The following constants are from the output of
-    // sage: rescue761.constants_constant
-    // a.k.a., KEY_SCHED_VEC
-    // [ 79702341772299253897339955563653516863594788002161465441781145347864436800928803472079765054940723020608613684412]
-    // [175404047423848792009109941610650776559153917447549764559702460944568985816882495819821877031781301935630268652925]
-    // [163231881198883668972116510882189788203165805540745582259684539022051705322963949586079216081621597497526516392877]
-    // [111733091351916013764359218715441472223367220983119617059118647873633602779707362569392865689770593452084742051420]
-
-    // The key injection constants are precomputed computed as
-    // 1. KEY_INJECTION[-1] = INIT_VECTOR
-    // 2. for i in [0 .. 2 * ROUNDS]
-    // 3. KEY_INJECTION[i] = KEY_SCHED_MATRIX * KEY_INJECTION[ i - 1] +
-    // KEY_SCHED_VEC
-    // where the INIT_VECTOR, KEY_SCHED_MATRIX and KEY_INJECTION are defined above
-    // Those constants can be obtained via
-    //   key_injection(init_vec_761, key_sched_matrix_761, key_sched_vec_761, q761)
-    // from rescue761.sage
-    const KEY_INJECTION_LE: [[&'static [u8]; 4]; 2 * ROUNDS] = [
-        [
-            &[
-                0xB6, 0x52, 0xFA, 0xF2, 0x03, 0x38, 0x3B, 0xE7, 0x58, 0x96, 0xA2, 0x4A, 0x53, 0x8A,
-                0x5A, 0xBA, 0x7D, 0xAD, 0xBD, 0xC6, 0x84, 0x3F, 0x1D, 0x4A, 0x74, 0x50, 0x77, 0x2A,
-                0xCD, 0xE4, 0xD1, 0x90, 0x23, 0xBB, 0xE8, 0x54, 0x5A, 0x53, 0xE6, 0x08, 0x0E, 0xC5,
-                0x00, 0xFE, 0xDE, 0xB0, 0x55, 0x01,
-            ],
-            &[
-                0xE1, 0xB1, 0xD1, 0x2F, 0x24, 0xC6, 0x81, 0x07, 0xEA, 0x3F, 0x4E, 0xCC, 0x90, 0x49,
-                0x9B, 0x5D, 0x3C, 0xEC, 0xFE, 0xD1, 0x99, 0x75, 0xF4, 0x2B, 0x60, 0x93, 0x06, 0x69,
-                0x3C, 0xD6, 0x06, 0x78, 0x45, 0xBB, 0xFB, 0x93, 0x0E, 0xBD, 0x16, 0x1B, 0x1C, 0xC0,
-                0x42, 0xF4, 0xB2, 0x8B, 0xA7, 0x01,
-            ],
-            &[
-                0x78, 0xFC, 0x5B, 0x46, 0x68, 0x7B, 0x84, 0x0A, 0xA5, 0x7A, 0x07, 0x26, 0x47, 0xF4,
-                0x9B, 0x54, 0x02, 0x96, 0xF4, 0xB9, 0x69, 0xA8, 0x65, 0x3D, 0xC6, 0x86, 0x8C, 0x15,
-                0x06, 0x6C, 0x76, 0x24, 0x45, 0xA5, 0xA9, 0x18, 0x83, 0xA0, 0x95, 0x28, 0xF5, 0x96,
-                0x65, 0x09, 0xB3, 0xD7, 0x6E, 0x00,
-            ],
-            &[
-                0xD5, 0x6A, 0xB0, 0x52, 0xE1, 0x3B, 0x9B, 0x3F, 0x40, 0x02, 0x82, 0xE8, 0x5F, 0xB7,
-                0x45, 0xBE, 0x77, 0xE8, 0x59, 0x4A, 0x7A, 0x2C, 0xB4, 0xA0, 0x42, 0x5F, 0xAF, 0xD2,
-                0xCD, 0x17, 0x73, 0xEB, 0x11, 0x5C, 0xB9, 0x82, 0x47, 0x3B, 0x7D, 0x66, 0x7F, 0x34,
-                0xAE, 0xF9, 0x3F, 0x3B, 0x0F, 0x01,
-            ],
-        ],
-        [
-            &[
-                0xA7, 0x48, 0xAB, 0xDE, 0x89, 0x6F, 0x97, 0xFE, 0x58, 0x98, 0x0E, 0xDB, 0x09, 0xFF,
-                0xA4, 0x7B, 0x0B, 0x9D, 0x88, 0x3A, 0x7F, 0x79, 0xDC, 0x54, 0xCD, 0x19, 0x73, 0xB5,
-                0xAC, 0x9A, 0x3B, 0xF7, 0xDB, 0x35, 0x88, 0xB8, 0x95, 0xC3, 0x62, 0xDD, 0x5B, 0xC3,
-                0x72, 0x85, 0x6E, 0x6D, 0x76, 0x00,
-            ],
-            &[
-                0x28, 0xC7, 0x2F, 0x38, 0xF4, 0x84, 0xF8, 0xCC, 0xF5, 0x3C, 0x44, 0xB2, 0xD1, 0xD4,
-                0x59, 0xF4, 0x77, 0x0B, 0xCF, 0xFC, 0x28, 0x4E, 0x87, 0xFD, 0xBB, 0xA4, 0x23, 0xE3,
-                0xEB, 0x34, 0x77, 0xCA, 0xD0, 0x5A, 0xF5, 0xCF, 0xCC, 0x98, 0x34, 0x73, 0x77, 0xFF,
-                0xA0, 0xDA, 0x96, 0x7C, 0x8D, 0x00,
-            ],
-            &[
-                0x3F, 0x43, 0x84, 0x39, 0xE9, 0x8D, 0x1F, 0xB5, 0xE2, 0xDF, 0x88, 0x7A, 0x06, 0xD8,
-                0x36, 0xA6, 0x26, 0x90, 0x2F, 0x09, 0x6F, 0x83, 0xA4, 0x1C, 0x60, 0xAE, 0xA0, 0xC1,
-                0x23, 0x97, 0xF7, 0x75, 0x12, 0x9C, 0xF0, 0x40, 0xF7, 0x09, 0xB7, 0x8A, 0x4E, 0x67,
-                0xD9, 0x5A, 0xF7, 0x09, 0xAF, 0x00,
-            ],
-            &[
-                0xBA, 0x70, 0xCF, 0xF0, 0xA7, 0x7C, 0x9C, 0xD3, 0x89, 0x1D, 0x02, 0x21, 0x5E, 0x29,
-                0x0B, 0xDC, 0x48, 0xD6, 0xC6, 0x76, 0xC9, 0xD4, 0x1A, 0x91, 0xE0, 0x9C, 0xD6, 0x06,
-                0xFC, 0x4E, 0x0F, 0xB1, 0x76, 0xD0, 0x81, 0xA4, 0x30, 0x03, 0x08, 0x07, 0x82, 0x15,
-                0xE1, 0xA5, 0x15, 0xE9, 0xA8, 0x00,
-            ],
-        ],
-        [
-            &[
-                0xA9, 0x94, 0x9D, 0x24, 0x8A, 0x9E, 0xED, 0x8A, 0x2E, 0xA2, 0x8E, 0x67, 0xA7, 0x34,
-                0xAD, 0xE4, 0x8C, 0xEC, 0x02, 0xBE, 0xD7, 0xE9, 0x0F, 0x23, 0x39, 0xE7, 0xBE, 0x9E,
-                0xF0, 0x60, 0x52, 0x4F, 0x05, 0x44, 0xF2, 0xBA, 0xD7, 0x09, 0x65, 0x5B, 0x35, 0xBF,
-                0x24, 0x1A, 0x1E, 0x84, 0xA9, 0x01,
-            ],
-            &[
-                0x91, 0x28, 0x77, 0x01, 0xBC, 0xED, 0x15, 0x18, 0x4D, 0xAF, 0xD0, 0x93, 0x64, 0x93,
-                0x9A, 0x60, 0x9E, 0x93, 0x98, 0xB5, 0x1F, 0xFD, 0x34, 0xED, 0x47, 0xD4, 0x95, 0xAB,
-                0x44, 0x3D, 0x7E, 0xB2, 0xF1, 0xE7, 0xB7, 0x60, 0x2E, 0x3A, 0xC0, 0x3C, 0x80, 0x7F,
-                0x52, 0xA0, 0x49, 0xFD, 0x75, 0x01,
-            ],
-            &[
-                0xAF, 0x9E, 0xCA, 0x5F, 0xBC, 0xC6, 0xA3, 0xB8, 0xF8, 0x86, 0xD8, 0xC9, 0x5E, 0x65,
-                0x34, 0xFC, 0x12, 0x52, 0xCF, 0x01, 0x2A, 0x80, 0x2D, 0xF7, 0xF2, 0xC3, 0x8B, 0x9E,
-                0x53, 0x2A, 0x7C, 0x53, 0xBB, 0xB6, 0x23, 0xCA, 0xD3, 0x67, 0xA8, 0x43, 0x37, 0xBA,
-                0xA5, 0xEE, 0xD5, 0x9F, 0x7C, 0x00,
-            ],
-            &[
-                0x06, 0x3E, 0x39, 0x81, 0xB4, 0x5E, 0x06, 0x7D, 0x96, 0xF0, 0x55, 0xD1, 0x2E, 0x2E,
-                0x1E, 0x21, 0x4F, 0x2E, 0x32, 0x46, 0x70, 0x3E, 0x6B, 0x9F, 0xF2, 0x0C, 0x88, 0xDC,
-                0xB9, 0x14, 0xD4, 0xC2, 0x89, 0xDD, 0x3F, 0x27, 0xEB, 0x26, 0x7C, 0x90, 0x7B, 0xF9,
-                0x76, 0x65, 0xF8, 0xD8, 0xBE, 0x00,
-            ],
-        ],
-        [
-            &[
-                0x19, 0x1E, 0xD0, 0xE6, 0xE8, 0xB5, 0x0D, 0x53, 0x4B, 0x59, 0x12, 0x47, 0x00, 0x48,
-                0xED, 0xAB, 0x48, 0x32, 0xA2, 0x16, 0xCF, 0x19, 0x2B, 0x01, 0x32, 0xFC, 0xBE, 0xDD,
-                0x1A, 0xC8, 0xD5, 0x4D, 0x77, 0x2E, 0xB5, 0x96, 0xBA, 0x0F, 0x92, 0xD9, 0xB0, 0x85,
-                0x2D, 0x0E, 0xB4, 0x5C, 0x1F, 0x00,
-            ],
-            &[
-                0xC0, 0x86, 0x45, 0x0A, 0x17, 0x49, 0xAC, 0xC8, 0x1D, 0xED, 0xC7, 0x2B, 0x4B, 0x9B,
-                0xCC, 0x94, 0x4C, 0x11, 0x9B, 0x53, 0x0B, 0x59, 0x32, 0xF5, 0x60, 0x6E, 0x51, 0xE2,
-                0x03, 0x88, 0x5D, 0x93, 0x04, 0x30, 0xDE, 0x04, 0xDE, 0xEA, 0x2D, 0xE9, 0xCA, 0xD5,
-                0xC1, 0xE5, 0xB9, 0x0C, 0x5A, 0x01,
-            ],
-            &[
-                0x52, 0xC2, 0x6B, 0x70, 0xB6, 0x3F, 0xB6, 0x52, 0xBB, 0x65, 0x05, 0x0D, 0xA4, 0x56,
-                0x23, 0x98, 0x6C, 0x8E, 0xE5, 0x0E, 0x95, 0x96, 0xBB, 0xED, 0x37, 0x68, 0xC5, 0xAD,
-                0x8C, 0x4C, 0x3A, 0x23, 0x62, 0xE4, 0x8A, 0x4A, 0x2D, 0xC5, 0x41, 0x60, 0x99, 0x70,
-                0xB6, 0x8F, 0x4F, 0x2E, 0x30, 0x01,
-            ],
-            &[
-                0x81, 0xEC, 0xA9, 0xC5, 0xE3, 0x55, 0x1C, 0x66, 0xA4, 0x0C, 0x04, 0x5D, 0x08, 0xB8,
-                0xC6, 0x7C, 0x80, 0xE3, 0xF4, 0x23, 0xDE, 0xCB, 0x9E, 0xDE, 0x2D, 0x06, 0x1C, 0x2F,
-                0x2D, 0xA7, 0xBE, 0xA9, 0x88, 0x82, 0x93, 0xAB, 0xF2, 0x28, 0x3B, 0x19, 0x6F, 0x7C,
-                0x48, 0xB1, 0xE7, 0xAD, 0x66, 0x00,
-            ],
-        ],
-        [
-            &[
-                0x0D, 0x1E, 0x51, 0x9D, 0x85, 0x80, 0x11, 0x1A, 0xCF, 0xB2, 0xB7, 0x0C, 0x6C, 0x72,
-                0x1F, 0xDB, 0x62, 0xA4, 0xFE, 0xDC, 0x37, 0x45, 0xFD, 0x00, 0x30, 0x23, 0x54, 0x40,
-                0xCB, 0x0A, 0x5C, 0x6C, 0x55, 0x60, 0xD3, 0xBE, 0x78, 0xBC, 0x9A, 0xB0, 0xA0, 0x03,
-                0xA5, 0xD5, 0x7C, 0x05, 0x71, 0x00,
-            ],
-            &[
-                0x72, 0x1F, 0x2B, 0xAF, 0x6C, 0xCF, 0x69, 0x29, 0xCA, 0xCF, 0x9B, 0x8F, 0xCC, 0x04,
-                0xFF, 0x84, 0x28, 0xA3, 0x92, 0x1E, 0xAC, 0x85, 0xAF, 0x0A, 0x3E, 0x7A, 0x21, 0x1E,
-                0x37, 0x6D, 0xB6, 0xF4, 0xC8, 0x9D, 0xAD, 0x88, 0x90, 0xDE, 0xD8, 0x9A, 0xF0, 0x2F,
-                0x2D, 0xC3, 0x1A, 0x1C, 0x24, 0x00,
-            ],
-            &[
-                0x0D, 0x88, 0xE7, 0xA2, 0x01, 0x28, 0x0C, 0x1A, 0x0C, 0x48, 0xA8, 0x7C, 0x5D, 0xC0,
-                0x4B, 0xC5, 0x19, 0xF3, 0x26, 0x18, 0x93, 0x59, 0x26, 0x3F, 0xA5, 0xC0, 0xA2, 0x8E,
-                0x16, 0xAA, 0x9D, 0x8D, 0x41, 0x1D, 0x66, 0xBC, 0xC1, 0x9D, 0xED, 0x4E, 0x4C, 0x15,
-                0xE0, 0xC0, 0x0C, 0xC1, 0x38, 0x00,
-            ],
-            &[
-                0xDC, 0xCE, 0x2F, 0x66, 0x57, 0xBE, 0x3D, 0x4C, 0x19, 0x25, 0x90, 0x0B, 0xFE, 0x94,
-                0xED, 0x6A, 0xE6, 0x6F, 0x59, 0x6F, 0xE5, 0x73, 0x24, 0x48, 0xA8, 0x6A, 0xA6, 0xA2,
-                0x84, 0x95, 0xAF, 0x15, 0xDA, 0xA4, 0xAC, 0xCD, 0xD0, 0xF6, 0xBF, 0x78, 0xB5, 0x08,
-                0xE0, 0x93, 0x5D, 0x09, 0x37, 0x00,
-            ],
-        ],
-        [
-            &[
-                0x8B, 0x2E, 0xC3, 0xE0, 0x78, 0x07, 0x5A, 0x9D, 0x53, 0x05, 0xC2, 0xE6, 0x21, 0x5B,
-                0x00, 0x83, 0x0B, 0xB0, 0x7C, 0x3C, 0x66, 0x5F, 0x7F, 0x97, 0x66, 0x3A, 0x02, 0xE4,
-                0x79, 0x81, 0x1E, 0x81, 0x28, 0x0F, 0xB4, 0x33, 0x17, 0xF6, 0x5A, 0x1A, 0xF5, 0x16,
-                0xBF, 0xF7, 0x02, 0xAA, 0xB8, 0x00,
-            ],
-            &[
-                0xF0, 0x3B, 0xCA, 0x9A, 0xB7, 0x2B, 0xBD, 0x43, 0xC2, 0x49, 0x70, 0xF3, 0x29, 0x05,
-                0x67, 0xD5, 0xD4, 0x9F, 0x26, 0xF6, 0x40, 0x44, 0xE6, 0x80, 0x8F, 0x0E, 0xE4, 0x44,
-                0x21, 0x8D, 0xE9, 0x35, 0x87, 0xDC, 0x28, 0x93, 0x39, 0xFD, 0xFC, 0x46, 0x16, 0x49,
-                0xE3, 0x64, 0x2D, 0xCD, 0xBB, 0x00,
-            ],
-            &[
-                0x2D, 0x70, 0x05, 0x5C, 0xA6, 0x81, 0x98, 0xA3, 0x50, 0xAE, 0x49, 0xAC, 0x9D, 0x5C,
-                0x9A, 0x07, 0x2B, 0x39, 0xCC, 0x54, 0xA3, 0x3F, 0x13, 0xBA, 0x53, 0x94, 0x3E, 0x10,
-                0x1D, 0x36, 0x10, 0x3A, 0x0D, 0x26, 0x5E, 0x61, 0x46, 0x3C, 0x9F, 0x74, 0x04, 0x47,
-                0x2C, 0x2C, 0x93, 0x6D, 0x7B, 0x00,
-            ],
-            &[
-                0xAF, 0x90, 0xDB, 0xB4, 0xCA, 0xAC, 0xE7, 0xE6, 0xAA, 0x8A, 0xD5, 0xBB, 0x70, 0x85,
-                0x66, 0xD4, 0x07, 0x5D, 0xEF, 0xE6, 0xAA, 0x85, 0xB5, 0xF4, 0x02, 0x0D, 0x9D, 0xF8,
-                0x59, 0xCB, 0x7C, 0x9D, 0x78, 0x2C, 0xE8, 0x29, 0x71, 0xB0, 0x8F, 0x7B, 0xB9, 0x92,
-                0x22, 0xEE, 0xBA, 0xD4, 0x48, 0x01,
-            ],
-        ],
-        [
-            &[
-                0x81, 0x51, 0x62, 0x56, 0x87, 0x9F, 0xFC, 0xCE, 0x7E, 0x9C, 0xEC, 0x9A, 0x5F, 0x98,
-                0x51, 0x8B, 0x2D, 0xEC, 0x06, 0x8B, 0x97, 0x1A, 0x5A, 0x31, 0x53, 0x77, 0xB5, 0x68,
-                0x47, 0x59, 0xBC, 0xE5, 0x84, 0x5A, 0xAE, 0x86, 0xDD, 0x73, 0x88, 0x70, 0xF8, 0x8C,
-                0x68, 0x93, 0x82, 0x15, 0x95, 0x00,
-            ],
-            &[
-                0x40, 0x8C, 0x3C, 0xAB, 0x45, 0x3B, 0x21, 0xC7, 0x67, 0x21, 0xCE, 0xC2, 0xB4, 0xB7,
-                0xED, 0x21, 0xF2, 0x32, 0x6E, 0xD6, 0x56, 0x70, 0x7F, 0x23, 0xD7, 0xEA, 0xCB, 0x01,
-                0x95, 0x7D, 0x95, 0x0D, 0x80, 0x59, 0x6B, 0xC3, 0xF8, 0x93, 0xE3, 0xFA, 0x64, 0x06,
-                0xFD, 0xD9, 0x3E, 0x0F, 0xA3, 0x00,
-            ],
-            &[
-                0xA3, 0xB5, 0x15, 0xC1, 0xFA, 0xD0, 0x16, 0xE8, 0xD3, 0x20, 0xE8, 0x73, 0x1E, 0x5B,
-                0x0F, 0x96, 0x99, 0x9C, 0x31, 0xF9, 0x5C, 0x48, 0x69, 0xBE, 0x7F, 0xDB, 0x2A, 0x29,
-                0x2A, 0x7C, 0xD8, 0x95, 0xB6, 0xCB, 0x62, 0xEB, 0xDD, 0x1E, 0xDD, 0x69, 0x37, 0x5C,
-                0x36, 0xE7, 0xEA, 0x3A, 0x0C, 0x00,
-            ],
-            &[
-                0xE8, 0x62, 0x63, 0x1A, 0xF7, 0xFC, 0xA9, 0x95, 0x96, 0xC2, 0x22, 0xA4, 0xCE, 0xB7,
-                0xD2, 0xE8, 0x5F, 0x7F, 0x52, 0xA3, 0xFA, 0xDD, 0x00, 0xF0, 0x06, 0x0B, 0xBF, 0x6E,
-                0xAD, 0x96, 0x26, 0xB3, 0xEE, 0xF6, 0x87, 0xA8, 0x9B, 0x46, 0x81, 0x21, 0x10, 0xC7,
-                0x17, 0x36, 0x2C, 0xA6, 0x74, 0x00,
-            ],
-        ],
-        [
-            &[
-                0x45, 0x73, 0xFB, 0x8F, 0x08, 0x70, 0x69, 0xBD, 0xCF, 0x9E, 0xD5, 0x0B, 0x5C, 0x36,
-                0x9E, 0x4D, 0xD0, 0x30, 0xBE, 0x88, 0x2C, 0xB4, 0x91, 0xBB, 0x65, 0x39, 0x17, 0xFF,
-                0xD8, 0xD4, 0x1B, 0xC1, 0xA3, 0x73, 0xD5, 0x8F, 0x92, 0x9E, 0x27, 0x90, 0x39, 0x5E,
-                0x23, 0x7F, 0xA7, 0x83, 0x81, 0x00,
-            ],
-            &[
-                0x44, 0xE8, 0x63, 0x2C, 0x0C, 0xE5, 0xCC, 0x18, 0x07, 0x6B, 0x8C, 0x84, 0xCF, 0x19,
-                0x70, 0x5B, 0x89, 0x55, 0xCE, 0x5E, 0x0F, 0xC2, 0x36, 0x95, 0x6D, 0xF4, 0x54, 0xE2,
-                0xC9, 0xB2, 0x98, 0xAF, 0x81, 0x84, 0x6E, 0x0C, 0xAF, 0x82, 0xF0, 0x78, 0x32, 0x18,
-                0xA1, 0xD3, 0xCB, 0xE4, 0x34, 0x01,
-            ],
-            &[
-                0xFC, 0x7D, 0xA4, 0xD0, 0x16, 0x84, 0xF2, 0xB1, 0x4B, 0x79, 0xBA, 0xF3, 0x95, 0x52,
-                0x5C, 0x1A, 0xDD, 0x5E, 0x27, 0x51, 0x02, 0x05, 0x67, 0xAC, 0x32, 0x66, 0xFC, 0x09,
-                0x78, 0x35, 0xAA, 0x4C, 0x0D, 0xE0, 0xE1, 0x31, 0x64, 0xC0, 0x0B, 0x3E, 0x6F, 0x2C,
-                0x0B, 0x47, 0x27, 0x21, 0xBE, 0x00,
-            ],
-            &[
-                0x71, 0x3F, 0x28, 0x1B, 0xC0, 0x40, 0xE1, 0x0B, 0x6C, 0x81, 0x00, 0x05, 0x1C, 0x31,
-                0x15, 0x41, 0xC4, 0x39, 0x81, 0x62, 0x5A, 0x23, 0x17, 0xE8, 0xFF, 0xBB, 0x67, 0x55,
-                0x2B, 0x72, 0x7D, 0xDF, 0x25, 0x77, 0x44, 0xF7, 0x5F, 0xEA, 0x12, 0xC4, 0x3D, 0xC6,
-                0xC4, 0x67, 0x40, 0x41, 0xC8, 0x00,
-            ],
-        ],
-        [
-            &[
-                0x9B, 0x32, 0x6C, 0xAA, 0xA2, 0xC0, 0xC6, 0x4E, 0xF9, 0xFE, 0x6D, 0x9D, 0xE3, 0xAD,
-                0x57, 0xA1, 0xDC, 0x5E, 0xE9, 0xB1, 0x92, 0x11, 0xE2, 0x26, 0x21, 0x4B, 0x32, 0xF5,
-                0x4A, 0xEA, 0xCA, 0xDA, 0xBD, 0x43, 0x1F, 0x91, 0xBB, 0x9F, 0xC7, 0x5E, 0xF7, 0xB1,
-                0xD6, 0xEF, 0xA3, 0x39, 0x8A, 0x00,
-            ],
-            &[
-                0x84, 0x76, 0xB1, 0x42, 0x4E, 0x79, 0x28, 0x58, 0x88, 0xAE, 0x31, 0x93, 0xDF, 0x86,
-                0x80, 0xC1, 0x6C, 0xC5, 0xE5, 0x33, 0x55, 0x68, 0x66, 0x87, 0x2A, 0xFD, 0x95, 0x1A,
-                0x23, 0x31, 0xB4, 0x5A, 0x20, 0xE3, 0x5B, 0xEE, 0x6D, 0x94, 0xCA, 0xA3, 0x23, 0x1B,
-                0x16, 0x0B, 0xA0, 0xB7, 0x97, 0x01,
-            ],
-            &[
-                0xD2, 0xE2, 0x2F, 0x43, 0xB3, 0x14, 0x7A, 0x85, 0x1B, 0x37, 0x51, 0xEC, 0xC5, 0xCB,
-                0x4D, 0x1A, 0xA1, 0xFF, 0x1C, 0xC1, 0x9A, 0xFD, 0xCA, 0xE8, 0x84, 0x08, 0x94, 0xD2,
-                0x4E, 0xE1, 0x9D, 0xB3, 0xC4, 0xC4, 0xB9, 0x8B, 0x63, 0xBA, 0xEA, 0x27, 0x23, 0x7D,
-                0x68, 0x1A, 0x58, 0xDD, 0x76, 0x01,
-            ],
-            &[
-                0x8B, 0x3B, 0x19, 0xE0, 0xCA, 0x8E, 0xDB, 0x4B, 0x5D, 0x32, 0xEF, 0xBC, 0x97, 0xBE,
-                0xCF, 0x50, 0xF4, 0xD9, 0xAE, 0x63, 0xF8, 0x8F, 0xEA, 0x3F, 0x5A, 0xDB, 0xEF, 0xEC,
-                0xC9, 0xAC, 0xB3, 0x43, 0x38, 0x66, 0xCC, 0xF2, 0x72, 0xF9, 0xBA, 0x63, 0x7F, 0x9F,
-                0x72, 0xE2, 0xE6, 0xBD, 0x2C, 0x00,
-            ],
-        ],
-        [
-            &[
-                0xBD, 0xD4, 0xB3, 0xD2, 0x62, 0xD9, 0x4C, 0xBF, 0x7D, 0x75, 0x86, 0x4B, 0xE4, 0x21,
-                0xE0, 0xB1, 0x8C, 0x2D, 0x78, 0xA9, 0xB5, 0x0A, 0xF2, 0xA3, 0x47, 0x05, 0x0F, 0x6B,
-                0xA0, 0xB7, 0xCB, 0x2B, 0x5E, 0x5A, 0x2C, 0xFD, 0x90, 0x02, 0x40, 0x6A, 0x1A, 0x6F,
-                0x88, 0x72, 0x1A, 0x6A, 0x58, 0x00,
-            ],
-            &[
-                0x16, 0xC8, 0xEA, 0xED, 0x39, 0x89, 0x34, 0x67, 0x8C, 0xA6, 0xCB, 0x85, 0x50, 0x09,
-                0x80, 0x19, 0x57, 0x3D, 0x74, 0xD0, 0x78, 0x35, 0xBA, 0x31, 0xE7, 0xA6, 0x10, 0xEF,
-                0xA2, 0x5E, 0x8E, 0x68, 0xF6, 0x9D, 0x91, 0xE2, 0x35, 0x31, 0x84, 0x20, 0xE4, 0x8F,
-                0xAF, 0x14, 0x74, 0x95, 0xC7, 0x00,
-            ],
-            &[
-                0x4C, 0x5A, 0x25, 0x94, 0x49, 0xE1, 0xFE, 0xB6, 0x88, 0x61, 0xA5, 0xDD, 0x96, 0x37,
-                0xAA, 0xDE, 0xDF, 0x73, 0xCC, 0x86, 0x78, 0xB1, 0x3F, 0x96, 0x4F, 0xBE, 0xB2, 0x58,
-                0xC8, 0x04, 0xBA, 0x2B, 0x7F, 0xAC, 0x44, 0x5C, 0xBF, 0x8A, 0xCB, 0x60, 0xDB, 0x7D,
-                0x19, 0x56, 0xFA, 0x98, 0xA7, 0x01,
-            ],
-            &[
-                0x26, 0xD3, 0xCF, 0x96, 0xA5, 0x24, 0xB8, 0xC8, 0x7C, 0xE7, 0xFB, 0x62, 0x2E, 0xB7,
-                0x7A, 0x7D, 0x73, 0x99, 0xE3, 0xF8, 0xFF, 0x61, 0xE3, 0x4B, 0x37, 0x96, 0x3B, 0x24,
-                0x72, 0x5B, 0xC2, 0x59, 0x8B, 0x2D, 0x94, 0x69, 0xE3, 0x98, 0x9A, 0x96, 0x68, 0x9A,
-                0xA6, 0xC5, 0x2E, 0x8F, 0xC2, 0x00,
-            ],
-        ],
-        [
-            &[
-                0xF2, 0xDC, 0xD1, 0x76, 0xE1, 0x59, 0x82, 0x55, 0xA0, 0x70, 0xFB, 0x2D, 0x7F, 0xDA,
-                0x63, 0x17, 0xB0, 0xC1, 0x2F, 0xC5, 0xCE, 0xB3, 0xF8, 0xE4, 0x03, 0xF4, 0x5E, 0x5F,
-                0x9A, 0x1B, 0x2F, 0xA3, 0xC1, 0x5E, 0x6C, 0xB5, 0x59, 0x02, 0xA0, 0x77, 0xB8, 0xE5,
-                0xCA, 0xA4, 0x8E, 0x7C, 0xC4, 0x00,
-            ],
-            &[
-                0xD7, 0x9D, 0x89, 0x5A, 0x34, 0x9E, 0x41, 0xE1, 0xBB, 0xE6, 0xE8, 0x9E, 0x56, 0xA3,
-                0x01, 0x67, 0xE4, 0x9B, 0x89, 0xB2, 0xFC, 0x3D, 0x69, 0xD3, 0x81, 0x13, 0xAC, 0xF0,
-                0x37, 0x2A, 0x6B, 0x2B, 0xE6, 0x1F, 0x6F, 0x83, 0x78, 0x56, 0xA7, 0xFD, 0x5B, 0x0C,
-                0x0D, 0xC0, 0xE9, 0x3A, 0x9F, 0x01,
-            ],
-            &[
-                0x6B, 0x74, 0x54, 0xAD, 0xB0, 0x6C, 0x3F, 0x89, 0x25, 0x8A, 0xE9, 0xCC, 0x47, 0x3A,
-                0x70, 0x9E, 0xDE, 0x26, 0x54, 0x93, 0xD4, 0x51, 0xB9, 0xAE, 0x7A, 0x9D, 0xD2, 0x9E,
-                0xD5, 0xC5, 0x94, 0x8B, 0x66, 0xDE, 0x57, 0x98, 0xC3, 0x72, 0xA1, 0xD1, 0x70, 0x59,
-                0xF6, 0x3F, 0xAF, 0x1A, 0x3A, 0x00,
-            ],
-            &[
-                0xC1, 0x63, 0x52, 0xF1, 0x51, 0x3C, 0xCD, 0x38, 0xAC, 0xEB, 0xC8, 0x1A, 0x5C, 0xB8,
-                0x39, 0x61, 0xE4, 0x14, 0xE6, 0x10, 0xED, 0x48, 0x6C, 0xCF, 0xEB, 0x73, 0x47, 0xE4,
-                0x94, 0x91, 0xA7, 0xD4, 0x52, 0xF9, 0xD1, 0x19, 0x35, 0x92, 0xB4, 0x79, 0xDD, 0x8B,
-                0x66, 0x27, 0x67, 0xB4, 0x85, 0x00,
-            ],
-        ],
-        [
-            &[
-                0x3A, 0x57, 0xB7, 0x51, 0xD1, 0x5C, 0x00, 0xE3, 0x48, 0x16, 0x65, 0xF6, 0xEC, 0xAF,
-                0x41, 0x12, 0x0A, 0x00, 0x30, 0x18, 0x8E, 0xA2, 0x8C, 0x50, 0xFD, 0xCB, 0x75, 0x71,
-                0x98, 0x8B, 0x66, 0x85, 0xEB, 0xDE, 0x9D, 0x97, 0x3F, 0xF1, 0x51, 0x4D, 0x1B, 0xE3,
-                0xA7, 0xC7, 0xBF, 0x62, 0xC3, 0x00,
-            ],
-            &[
-                0xD3, 0x3C, 0x37, 0x8A, 0x15, 0xEB, 0x0D, 0x55, 0xFA, 0x67, 0x28, 0xAE, 0x22, 0x3E,
-                0x58, 0xBD, 0xDF, 0xAD, 0xD1, 0x84, 0x98, 0xA7, 0x14, 0xFB, 0x69, 0x6D, 0x6F, 0xAA,
-                0x60, 0x8C, 0x57, 0x66, 0x94, 0xED, 0x9E, 0xAD, 0x50, 0xA6, 0x2F, 0xBA, 0xE0, 0x8C,
-                0xFF, 0xB2, 0x12, 0xD7, 0xBC, 0x00,
-            ],
-            &[
-                0xF5, 0xBF, 0xCC, 0xDC, 0xB5, 0x6A, 0x28, 0x0D, 0x40, 0x3B, 0x78, 0x9C, 0x0F, 0xC5,
-                0x87, 0x85, 0x85, 0x86, 0xBF, 0x4F, 0xE7, 0xD4, 0x9F, 0xC6, 0x52, 0xEA, 0xAF, 0x29,
-                0x3E, 0x26, 0x84, 0xD9, 0x2A, 0x1B, 0x24, 0x3F, 0xC4, 0xBD, 0xD5, 0xE4, 0x52, 0xDD,
-                0x39, 0x26, 0xA2, 0x6D, 0xE6, 0x00,
-            ],
-            &[
-                0x9B, 0xD7, 0x10, 0x6A, 0xFB, 0xDE, 0x04, 0xE7, 0x63, 0x83, 0x9D, 0xBD, 0xB6, 0x76,
-                0xEB, 0xE9, 0x41, 0x7D, 0x9F, 0x9D, 0x4E, 0x21, 0x20, 0xBA, 0x58, 0xB1, 0x6C, 0x18,
-                0xD8, 0x1E, 0x1B, 0x97, 0xAF, 0xB0, 0xD8, 0xAE, 0x92, 0x1B, 0xF1, 0x4E, 0xCC, 0xE0,
-                0xC5, 0x04, 0x42, 0xA2, 0x89, 0x01,
-            ],
-        ],
-        [
-            &[
-                0xB2, 0x64, 0xE7, 0xEF, 0x81, 0xB2, 0xFC, 0x02, 0xAD, 0x2A, 0xFA, 0x53, 0x83, 0x71,
-                0x8E, 0x91, 0xF5, 0x4C, 0xB3, 0x21, 0xA6, 0xD1, 0xBE, 0x1A, 0x91, 0x19, 0xD0, 0x91,
-                0xCD, 0x0D, 0x90, 0xA7, 0xA7, 0x05, 0x81, 0x5C, 0xFA, 0x8E, 0x8D, 0x00, 0x47, 0x13,
-                0x75, 0x45, 0x47, 0x13, 0x70, 0x00,
-            ],
-            &[
-                0xC0, 0x9B, 0x88, 0x02, 0x35, 0xCC, 0x93, 0xA9, 0xFC, 0x23, 0x71, 0xA0, 0x97, 0x9E,
-                0x40, 0xB3, 0x4C, 0xB2, 0x94, 0x0B, 0x80, 0x82, 0x66, 0x66, 0x27, 0x51, 0xCA, 0x0D,
-                0x7F, 0xCE, 0x36, 0x5D, 0xD3, 0xC0, 0xF3, 0x36, 0xEF, 0x99, 0x8A, 0xCE, 0xAE, 0xDD,
-                0xF5, 0x82, 0x4F, 0xB5, 0x92, 0x01,
-            ],
-            &[
-                0xCD, 0xAC, 0x70, 0xCE, 0x5B, 0xA1, 0x40, 0x3E, 0x9B, 0x05, 0xB5, 0xE3, 0x29, 0xC6,
-                0x76, 0x13, 0x09, 0xAE, 0xD5, 0x08, 0x7D, 0xD7, 0x70, 0x89, 0x9F, 0x79, 0x6A, 0xF4,
-                0xE4, 0xAC, 0x14, 0x5C, 0x17, 0x4C, 0xE2, 0x36, 0xC1, 0xE2, 0x13, 0x29, 0x1D, 0x2F,
-                0x97, 0xA4, 0x0D, 0x30, 0xA7, 0x01,
-            ],
-            &[
-                0xFA, 0x1D, 0x71, 0xB2, 0xED, 0x80, 0x3F, 0xEF, 0x74, 0x6E, 0xD5, 0xB9, 0x96, 0xFA,
-                0x48, 0xA1, 0xE5, 0x51, 0x8B, 0x2A, 0x70, 0x97, 0xC9, 0xA7, 0x65, 0xDC, 0xB5, 0xE9,
-                0xD6, 0x14, 0x6A, 0x4A, 0xA9, 0x90, 0x9D, 0x7E, 0x50, 0x57, 0x92, 0x0D, 0x34, 0xBD,
-                0x6F, 0x0E, 0x2F, 0x17, 0x11, 0x00,
-            ],
-        ],
-        [
-            &[
-                0xB6, 0x63, 0x21, 0x83, 0xA4, 0xCC, 0x77, 0x4F, 0x58, 0xE5, 0x5D, 0xE7, 0xD1, 0x96,
-                0x29, 0x04, 0x68, 0x63, 0x0A, 0xAE, 0xD4, 0x99, 0xD2, 0xDA, 0xA6, 0x1E, 0xE0, 0x8C,
-                0x9D, 0xC4, 0x54, 0xC2, 0xC2, 0xD1, 0x3D, 0xF7, 0x23, 0xCC, 0x68, 0x71, 0x15, 0xF4,
-                0xCF, 0x2B, 0xFF, 0x3F, 0x65, 0x00,
-            ],
-            &[
-                0x4F, 0x42, 0xD8, 0x91, 0x21, 0x75, 0xC5, 0xC1, 0x20, 0x9A, 0x7A, 0x23, 0x69, 0x88,
-                0xBC, 0x16, 0x13, 0x6C, 0xE7, 0x32, 0x80, 0xA6, 0x4B, 0x91, 0x73, 0xD3, 0x16, 0x13,
-                0xB3, 0x79, 0x4A, 0xD7, 0x79, 0x8A, 0x42, 0x1F, 0x42, 0xFB, 0xF5, 0xB1, 0x10, 0x9B,
-                0x22, 0x5C, 0x58, 0x6F, 0x53, 0x00,
-            ],
-            &[
-                0x69, 0xC9, 0x0E, 0x0C, 0xC5, 0x1D, 0x2E, 0xE5, 0xE7, 0xF9, 0x03, 0xA4, 0x24, 0xE4,
-                0x83, 0x97, 0x5F, 0xCB, 0x1F, 0xAB, 0xEF, 0xE9, 0x8E, 0xD9, 0x73, 0x82, 0x90, 0xF0,
-                0x18, 0x46, 0x99, 0xC6, 0x6B, 0x6A, 0x33, 0x21, 0xE6, 0xD0, 0x48, 0xDD, 0x99, 0x1D,
-                0x7B, 0xBA, 0x72, 0xB0, 0x93, 0x00,
-            ],
-            &[
-                0x4A, 0x0B, 0x64, 0xFD, 0x5F, 0xDC, 0x63, 0x1D, 0x6F, 0xBD, 0xDF, 0x95, 0xAA, 0x2F,
-                0xD2, 0x1B, 0xCE, 0xB3, 0x9D, 0xDA, 0x8D, 0x9A, 0xFA, 0x84, 0xF0, 0x46, 0x44, 0xB3,
-                0x0A, 0x62, 0x9F, 0x19, 0x84, 0x1E, 0x6A, 0x2C, 0xA7, 0x5C, 0x06, 0x59, 0x42, 0x0D,
-                0xB0, 0x9E, 0xC0, 0x1C, 0x7A, 0x01,
-            ],
-        ],
-        [
-            &[
-                0x72, 0x22, 0xBA, 0xD8, 0xAA, 0xBD, 0x0A, 0xAF, 0xA2, 0x95, 0xD1, 0xC2, 0x37, 0x1B,
-                0x16, 0xF7, 0xEA, 0xAD, 0x5D, 0x57, 0xEF, 0x5F, 0x17, 0xF2, 0x62, 0xBC, 0x82, 0x13,
-                0xFD, 0xE8, 0x39, 0x73, 0x42, 0x55, 0xCB, 0x1B, 0x9F, 0x07, 0x13, 0x51, 0x07, 0xC5,
-                0x3A, 0x93, 0x5D, 0xA5, 0x58, 0x01,
-            ],
-            &[
-                0xA6, 0xBE, 0x59, 0x20, 0x64, 0x7C, 0x18, 0x32, 0x36, 0xEA, 0xE2, 0x81, 0x11, 0xF6,
-                0x49, 0x43, 0xFA, 0x85, 0xFF, 0x60, 0xCA, 0x80, 0x44, 0xBE, 0xEE, 0x06, 0xA9, 0xF2,
-                0xC3, 0x8A, 0x99, 0x9E, 0x18, 0x48, 0xFE, 0x32, 0xA9, 0x12, 0x0E, 0x51, 0x9F, 0x06,
-                0x93, 0x8E, 0x75, 0x9C, 0xC5, 0x00,
-            ],
-            &[
-                0x5B, 0x52, 0x49, 0x50, 0x0C, 0x77, 0x1A, 0x85, 0x2D, 0xE4, 0xD5, 0x42, 0xFA, 0x75,
-                0x2F, 0x52, 0x21, 0xE7, 0x78, 0x8B, 0xAB, 0x0D, 0x6E, 0x11, 0xD2, 0x45, 0x0B, 0x25,
-                0x64, 0x29, 0x83, 0x35, 0x4C, 0xCE, 0x3A, 0xAC, 0x4C, 0x82, 0x98, 0xE6, 0x7B, 0x97,
-                0xE1, 0x99, 0x7B, 0x3E, 0x9D, 0x01,
-            ],
-            &[
-                0x6F, 0xC7, 0x9B, 0xEB, 0x6E, 0xC9, 0xB2, 0xF7, 0x74, 0x9E, 0xFD, 0xCD, 0xB6, 0xC7,
-                0x15, 0xD8, 0x99, 0xFD, 0x57, 0xFA, 0xE3, 0x99, 0x30, 0xC4, 0xB5, 0xEE, 0x02, 0x7B,
-                0x53, 0x34, 0x99, 0xDE, 0x4A, 0xEE, 0x39, 0x58, 0xE6, 0x41, 0x98, 0x55, 0xA7, 0xB3,
-                0xF3, 0x05, 0x01, 0x78, 0x14, 0x01,
-            ],
-        ],
-        [
-            &[
-                0x75, 0xB2, 0x2C, 0x83, 0x1E, 0x3D, 0x09, 0x80, 0x10, 0xBB, 0xF1, 0x9B, 0x4C, 0xC2,
-                0x72, 0x6D, 0xC6, 0x98, 0x77, 0x8F, 0x84, 0x20, 0xC7, 0x8C, 0xE2, 0x3B, 0xB3, 0x1A,
-                0x3A, 0x84, 0xF6, 0xBC, 0xD2, 0xE9, 0x21, 0xDD, 0xD5, 0x4F, 0x89, 0x5A, 0x34, 0xEC,
-                0x43, 0xED, 0x88, 0x8D, 0x60, 0x01,
-            ],
-            &[
-                0x0C, 0x3E, 0x31, 0xCE, 0x2B, 0xE8, 0x2F, 0x4A, 0x01, 0xD7, 0xED, 0xB5, 0x5B, 0x2E,
-                0xF2, 0x42, 0xD3, 0x43, 0x10, 0x9A, 0x02, 0xC6, 0xD8, 0xE7, 0xC0, 0x11, 0xF5, 0x66,
-                0x7A, 0x98, 0xB1, 0xA0, 0xED, 0x87, 0xCE, 0x78, 0x25, 0xBD, 0xD6, 0x02, 0x0F, 0x1E,
-                0xB2, 0x8D, 0xB7, 0xBF, 0x75, 0x00,
-            ],
-            &[
-                0x4A, 0x87, 0xEF, 0x59, 0xB7, 0xA0, 0x76, 0xB2, 0x30, 0x44, 0xB0, 0xC3, 0xC3, 0xB2,
-                0x73, 0xCB, 0x9C, 0x26, 0xB5, 0xFA, 0x21, 0xB6, 0x37, 0x52, 0x4B, 0xBF, 0x6E, 0xFC,
-                0x96, 0x33, 0x47, 0x97, 0x70, 0xA9, 0x94, 0x85, 0xEE, 0xC8, 0xA8, 0xBB, 0x25, 0x25,
-                0xB2, 0x93, 0xA9, 0xD9, 0xD4, 0x00,
-            ],
-            &[
-                0xBC, 0xB0, 0xCF, 0x26, 0xAC, 0xBE, 0xD4, 0x80, 0x51, 0x92, 0xFD, 0x98, 0x94, 0xD2,
-                0x49, 0x45, 0x85, 0xFC, 0x97, 0x82, 0x9F, 0xC1, 0xA1, 0x9A, 0x7D, 0xB4, 0xEA, 0xA2,
-                0xA0, 0xEE, 0x4E, 0x0E, 0xC4, 0x18, 0xA3, 0x1F, 0xAF, 0x64, 0xB2, 0x7B, 0x8D, 0xB3,
-                0x67, 0xB4, 0x6B, 0xA9, 0x8C, 0x01,
-            ],
-        ],
-        [
-            &[
-                0xCE, 0x0E, 0x2A, 0x66, 0x5E, 0x55, 0xF2, 0xAA, 0x54, 0x78, 0x9E, 0x60, 0x34, 0x68,
-                0x3F, 0x0D, 0x8C, 0x0C, 0xE2, 0xA4, 0x88, 0x3F, 0xBA, 0x47, 0xBE, 0xDD, 0x1B, 0x81,
-                0x85, 0x15, 0x26, 0x0F, 0xC8, 0x24, 0xD3, 0xFD, 0x4B, 0x94, 0xB5, 0xC4, 0xBD, 0xDF,
-                0xDE, 0xCE, 0xD8, 0x77, 0xF2, 0x00,
-            ],
-            &[
-                0xD7, 0x6F, 0x6E, 0xAE, 0x73, 0x9F, 0x99, 0xA2, 0x92, 0x99, 0x23, 0x74, 0x4D, 0xB5,
-                0xD3, 0xAC, 0x68, 0x89, 0x9F, 0x6B, 0xA7, 0xBE, 0xB1, 0x32, 0x63, 0x91, 0xD3, 0xD5,
-                0xE9, 0x83, 0x7F, 0xE7, 0x3E, 0x08, 0x85, 0xF2, 0xEE, 0xBF, 0xFB, 0xD6, 0xBC, 0xA9,
-                0x21, 0x7C, 0x15, 0x86, 0x09, 0x01,
-            ],
-            &[
-                0x23, 0xC9, 0xFD, 0x94, 0x7E, 0xAC, 0x78, 0xE1, 0xB7, 0xAD, 0xDE, 0x02, 0xCD, 0x71,
-                0x97, 0xAE, 0x87, 0x2F, 0x2E, 0x7D, 0xC6, 0x82, 0xF9, 0xC6, 0x63, 0xCA, 0xB8, 0x99,
-                0x16, 0x6E, 0x8A, 0x36, 0xD6, 0xE6, 0x1E, 0xA0, 0x09, 0xB9, 0x4F, 0xDA, 0xC3, 0x88,
-                0x7E, 0x3A, 0xDF, 0x23, 0xA6, 0x01,
-            ],
-            &[
-                0xBC, 0xE0, 0x0B, 0x37, 0xE5, 0x3D, 0x9F, 0x02, 0xC7, 0x34, 0xEB, 0xEB, 0xDB, 0x1A,
-                0x98, 0x83, 0x3A, 0x41, 0x4D, 0x3D, 0xAF, 0xEE, 0x80, 0x7E, 0x29, 0x58, 0x75, 0xDE,
-                0x8F, 0xA6, 0xAD, 0x72, 0xB8, 0x06, 0x13, 0x07, 0xF6, 0xB0, 0x1B, 0x9F, 0xD2, 0x5A,
-                0x5D, 0xE6, 0xCE, 0x6D, 0x7E, 0x01,
-            ],
-        ],
-        [
-            &[
-                0x22, 0x7A, 0xF7, 0x55, 0xB4, 0xF5, 0x8D, 0xEC, 0xD4, 0x66, 0xCE, 0x0E, 0x87, 0x4D,
-                0xFF, 0x85, 0x68, 0x6E, 0xA7, 0x26, 0x54, 0xCD, 0x18, 0x00, 0xC3, 0xD8, 0x5D, 0x65,
-                0xF7, 0x34, 0xE9, 0x6B, 0x5B, 0xDD, 0x23, 0xB6, 0xCC, 0x99, 0xF3, 0xDE, 0xD7, 0xD4,
-                0x9B, 0xFC, 0x4D, 0x9C, 0x61, 0x01,
-            ],
-            &[
-                0x8D, 0xFB, 0xEC, 0x32, 0xC3, 0x6A, 0x28, 0xF1, 0x97, 0xFB, 0x3C, 0xF5, 0x5C, 0x78,
-                0x86, 0x96, 0x88, 0x20, 0x46, 0x87, 0x14, 0x75, 0x3E, 0x53, 0xAE, 0xA3, 0x5F, 0xAA,
-                0xE8, 0x9C, 0xFC, 0x7E, 0xBC, 0x24, 0x79, 0xB5, 0x79, 0x3E, 0x14, 0xA1, 0xF8, 0xA5,
-                0xB3, 0xCA, 0xE2, 0x0A, 0x46, 0x01,
-            ],
-            &[
-                0x53, 0x75, 0x27, 0x9D, 0x72, 0xBA, 0x70, 0x70, 0x40, 0x7A, 0x90, 0xCE, 0x11, 0xDE,
-                0x25, 0x6E, 0x1E, 0x2C, 0xB4, 0x0F, 0xB0, 0xB8, 0x71, 0xB9, 0x00, 0x1E, 0x16, 0x2A,
-                0xA4, 0xA0, 0x14, 0x78, 0x09, 0xB6, 0x2A, 0x81, 0x7F, 0x41, 0xCF, 0xAE, 0xBE, 0xD6,
-                0xCD, 0xFF, 0x7E, 0x3B, 0x1C, 0x01,
-            ],
-            &[
-                0x36, 0xF9, 0x9C, 0xB2, 0x27, 0x7A, 0x27, 0x10, 0x65, 0x07, 0xCD, 0x27, 0xCC, 0x14,
-                0xC3, 0xAC, 0x2F, 0x85, 0x99, 0xF9, 0xA6, 0xCE, 0x97, 0x5E, 0x06, 0xCA, 0x38, 0x30,
-                0xB5, 0xEE, 0xDD, 0xF6, 0x74, 0x8C, 0xF2, 0x01, 0x3D, 0xC4, 0xF9, 0xF5, 0x70, 0x67,
-                0xA9, 0x71, 0xAE, 0x0E, 0x0B, 0x00,
-            ],
-        ],
-        [
-            &[
-                0x85, 0xCB, 0x0F, 0xA9, 0x5C, 0xE7, 0x5D, 0x15, 0x50, 0x0A, 0xE3, 0xCB, 0x04, 0xE4,
-                0x9B, 0x1B, 0x1F, 0x28, 0x19, 0x71, 0x40, 0x78, 0x47, 0xC0, 0xEB, 0x02, 0xEF, 0x16,
-                0xC5, 0xDC, 0x39, 0xE0, 0x42, 0x70, 0xA2, 0x07, 0xDF, 0xC0, 0xFC, 0xEC, 0x49, 0xB1,
-                0xE0, 0x7B, 0xE8, 0x84, 0x04, 0x00,
-            ],
-            &[
-                0x12, 0x5B, 0xC1, 0xD1, 0x7F, 0x46, 0x75, 0x7C, 0x14, 0x47, 0x9D, 0xE7, 0xED, 0xCD,
-                0x0A, 0xC9, 0x8D, 0xB8, 0x1B, 0xF5, 0xDD, 0x98, 0x23, 0xD2, 0xBF, 0xBD, 0xB0, 0x47,
-                0x35, 0x8C, 0xAE, 0x48, 0xC8, 0xA1, 0xAE, 0x2E, 0x19, 0x54, 0x2C, 0x0E, 0x21, 0x11,
-                0xF3, 0xD4, 0xBB, 0xBD, 0x0E, 0x00,
-            ],
-            &[
-                0x3B, 0x26, 0x44, 0xCD, 0x87, 0xE2, 0x18, 0xC0, 0xD5, 0x08, 0xB2, 0x77, 0x30, 0xBC,
-                0x6D, 0x88, 0x8C, 0x7E, 0x8C, 0x4C, 0x71, 0x3B, 0x26, 0xDB, 0xB4, 0x91, 0xCF, 0x07,
-                0x0F, 0x61, 0x81, 0x56, 0xE9, 0xED, 0x7D, 0xC2, 0x5D, 0x85, 0x50, 0x5C, 0xC5, 0x48,
-                0x4E, 0x57, 0xDB, 0xE3, 0xA7, 0x00,
-            ],
-            &[
-                0x55, 0x9E, 0x16, 0x1E, 0x79, 0x32, 0xCF, 0xA0, 0xCB, 0x06, 0x90, 0xD5, 0x3F, 0xE8,
-                0xC5, 0x46, 0x28, 0x48, 0x3A, 0x2C, 0x20, 0x60, 0x76, 0xDF, 0xAF, 0xF1, 0xA8, 0xF2,
-                0xB2, 0x26, 0x64, 0x40, 0x0E, 0x4C, 0x6C, 0x49, 0xCD, 0xE7, 0xBB, 0x93, 0x72, 0xB6,
-                0x7B, 0xC6, 0xE3, 0x81, 0x6A, 0x00,
-            ],
-        ],
-        [
-            &[
-                0xF1, 0xFF, 0x54, 0x4F, 0x2D, 0x4D, 0x44, 0x95, 0xB4, 0x5D, 0xF8, 0xE7, 0x72, 0x6A,
-                0x51, 0x84, 0x18, 0x2D, 0x19, 0x73, 0xE6, 0xDF, 0x32, 0x21, 0x84, 0xEA, 0x6E, 0xE9,
-                0x72, 0xE8, 0x01, 0x1E, 0xC7, 0x45, 0x63, 0x9D, 0x8F, 0x67, 0x86, 0xE8, 0xBD, 0x56,
-                0x60, 0xC5, 0x31, 0x51, 0x81, 0x01,
-            ],
-            &[
-                0x03, 0x87, 0x9E, 0x38, 0xCB, 0xA5, 0x29, 0x14, 0x61, 0x5A, 0x0B, 0x67, 0xE2, 0x32,
-                0x1E, 0x8D, 0xC3, 0x1E, 0x8F, 0x84, 0x60, 0x79, 0xA6, 0xA3, 0xF7, 0xE4, 0xAA, 0xDD,
-                0x71, 0x30, 0x4E, 0x74, 0x83, 0xE3, 0xC9, 0xB1, 0x0D, 0xE2, 0x2C, 0x37, 0x1B, 0xA6,
-                0x70, 0xB6, 0x6F, 0x73, 0x3A, 0x01,
-            ],
-            &[
-                0x75, 0xD1, 0x0B, 0x59, 0xAD, 0x1A, 0xBD, 0xD8, 0x3E, 0xBE, 0xF4, 0xE9, 0x12, 0xE8,
-                0x60, 0x81, 0x26, 0xC8, 0x36, 0x0D, 0x0D, 0x34, 0xC2, 0x36, 0x97, 0x2B, 0xD0, 0xE7,
-                0x64, 0x6E, 0x28, 0xC5, 0xC1, 0x97, 0x87, 0x74, 0x2C, 0x3A, 0x95, 0x7E, 0xEB, 0x64,
-                0x51, 0x74, 0x59, 0x11, 0x95, 0x01,
-            ],
-            &[
-                0x38, 0x16, 0xC0, 0xB4, 0xD3, 0x7F, 0xDD, 0x7D, 0xD8, 0x0C, 0x17, 0x60, 0x27, 0xA1,
-                0xAB, 0x8B, 0x60, 0x2C, 0x55, 0x86, 0x63, 0x5C, 0x80, 0x43, 0x73, 0x68, 0x1D, 0xBD,
-                0xFA, 0x8E, 0x5E, 0xA1, 0x70, 0x81, 0x28, 0x61, 0xDF, 0x33, 0xC7, 0x3A, 0xA6, 0xD0,
-                0x63, 0xEC, 0xDD, 0x97, 0x54, 0x01,
-            ],
-        ],
-        [
-            &[
-                0x16, 0xF3, 0x65, 0x9E, 0x38, 0x68, 0x61, 0xAF, 0x56, 0x1A, 0x97, 0x2C, 0x09, 0x8D,
-                0x0B, 0xA4, 0xCD, 0xA9, 0x3C, 0xEF, 0xA1, 0x28, 0xDB, 0x69, 0xB1, 0xBB, 0x32, 0x6C,
-                0x6B, 0x95, 0xC3, 0x4E, 0x24, 0x8E, 0x34, 0x42, 0x55, 0x32, 0x93, 0x5A, 0x73, 0xCE,
-                0x9B, 0xEE, 0x6D, 0xFF, 0x0A, 0x00,
-            ],
-            &[
-                0x79, 0xD8, 0x25, 0xE4, 0x5F, 0x89, 0x1D, 0xA7, 0x9A, 0x3B, 0x0D, 0x07, 0xE0, 0xFF,
-                0x3D, 0x0D, 0xD8, 0xFA, 0xE9, 0x38, 0x93, 0xE0, 0x93, 0x7B, 0x06, 0x5E, 0x8B, 0xD2,
-                0xCD, 0xBD, 0xB6, 0x5A, 0xB8, 0xFB, 0x94, 0x19, 0xF2, 0x14, 0xA2, 0xDC, 0xBA, 0x47,
-                0x7C, 0x75, 0x81, 0xE8, 0x1B, 0x00,
-            ],
-            &[
-                0x91, 0x1D, 0x30, 0x39, 0x1F, 0xE5, 0xE8, 0x02, 0x22, 0x6B, 0x00, 0xE9, 0x8E, 0x47,
-                0x00, 0x89, 0x63, 0x79, 0xE9, 0xFC, 0x34, 0x4C, 0x62, 0x07, 0x5E, 0xCE, 0xD1, 0x89,
-                0x44, 0x2D, 0x96, 0xA5, 0x8B, 0x91, 0x0A, 0x5A, 0x8D, 0x18, 0xCF, 0x9C, 0x9A, 0x88,
-                0x1E, 0x30, 0x11, 0xA9, 0x68, 0x01,
-            ],
-            &[
-                0x8E, 0x29, 0xDA, 0x76, 0x11, 0x02, 0xAA, 0xF6, 0x5F, 0x31, 0x0F, 0x1C, 0xF8, 0x3F,
-                0x25, 0x8A, 0x52, 0xB7, 0xAA, 0x7A, 0x89, 0xF2, 0x7A, 0xA1, 0x30, 0xFF, 0x0B, 0xF9,
-                0x49, 0x2C, 0xF6, 0xCD, 0xE4, 0xA7, 0x3E, 0xA5, 0x41, 0x7C, 0x49, 0x29, 0x4D, 0x7F,
-                0x4C, 0x27, 0x2E, 0x18, 0x6D, 0x00,
-            ],
-        ],
-        [
-            &[
-                0xA2, 0x1F, 0xE6, 0x5B, 0x1B, 0xB7, 0x09, 0x63, 0x58, 0x68, 0x77, 0xC4, 0x95, 0xE1,
-                0xC4, 0xB9, 0xC7, 0xB3, 0x08, 0x7C, 0x70, 0xBE, 0xCB, 0xE3, 0xF7, 0x69, 0x1F, 0x0F,
-                0x3D, 0x46, 0x65, 0xA9, 0xCD, 0x1D, 0x0A, 0x28, 0xC8, 0x36, 0x31, 0xBB, 0xA9, 0x1A,
-                0xC5, 0xD6, 0x0A, 0xC7, 0x0B, 0x00,
-            ],
-            &[
-                0xAF, 0x25, 0xE9, 0x52, 0xD2, 0xC3, 0x01, 0xE0, 0xCC, 0xB5, 0x4C, 0x31, 0x8E, 0x0B,
-                0x10, 0xE9, 0x47, 0x84, 0xB6, 0x56, 0x57, 0x69, 0xD3, 0x3D, 0xF4, 0xEF, 0x04, 0xE9,
-                0xF8, 0x16, 0xFA, 0x87, 0x9E, 0x90, 0xC3, 0xFA, 0x00, 0x73, 0x2A, 0xB8, 0x06, 0x5F,
-                0xB7, 0xC9, 0xA2, 0x15, 0x75, 0x00,
-            ],
-            &[
-                0xAA, 0x58, 0x2E, 0xE4, 0x85, 0xD7, 0x01, 0xB0, 0xA4, 0x87, 0x9F, 0x6A, 0x84, 0x3A,
-                0x58, 0xFE, 0xEB, 0x67, 0x08, 0x78, 0x75, 0xEB, 0x4E, 0x35, 0x95, 0xA0, 0x41, 0x57,
-                0xA0, 0xBA, 0xC6, 0x16, 0x24, 0xDD, 0x35, 0xE4, 0x86, 0x61, 0xD9, 0x8E, 0x02, 0x31,
-                0x61, 0x10, 0x7F, 0x70, 0x59, 0x00,
-            ],
-            &[
-                0x02, 0x91, 0x98, 0xAE, 0x86, 0x14, 0x96, 0x3D, 0x00, 0xEB, 0xE9, 0xC3, 0x44, 0x76,
-                0x95, 0xA8, 0x3D, 0x21, 0x27, 0xEF, 0x7A, 0x64, 0x0C, 0x52, 0xD5, 0x4E, 0x17, 0xE4,
-                0x71, 0x03, 0x0E, 0xD8, 0xDC, 0xA3, 0xA9, 0xDC, 0xF6, 0xB2, 0x2B, 0x02, 0x75, 0x4A,
-                0x03, 0x30, 0xF9, 0xF4, 0x4B, 0x00,
-            ],
-        ],
-        [
-            &[
-                0x76, 0xEE, 0x63, 0x38, 0xB4, 0x01, 0xB8, 0xC3, 0x61, 0x73, 0xBA, 0x59, 0x57, 0x3F,
-                0xAB, 0xB9, 0xDF, 0xEB, 0xAF, 0x51, 0xE9, 0x2C, 0x88, 0x7F, 0x05, 0x3F, 0x35, 0xB6,
-                0x85, 0xCD, 0x90, 0xEC, 0x30, 0x2A, 0x68, 0xD2, 0x71, 0x1B, 0xF7, 0x9D, 0x78, 0x28,
-                0x6B, 0xBE, 0x51, 0x78, 0xBC, 0x00,
-            ],
-            &[
-                0x7E, 0xB1, 0x41, 0xCB, 0x51, 0xA7, 0x56, 0x23, 0x77, 0x93, 0x85, 0xAC, 0xC7, 0x90,
-                0x11, 0xC4, 0x1C, 0xB9, 0x4D, 0x09, 0x2A, 0xD4, 0x39, 0x6B, 0xDD, 0xBC, 0x39, 0x89,
-                0x57, 0x46, 0x10, 0xEE, 0xCA, 0x49, 0x99, 0xD7, 0x38, 0xEB, 0xE2, 0x7F, 0x39, 0x02,
-                0x69, 0xA1, 0x04, 0x02, 0x5D, 0x01,
-            ],
-            &[
-                0x7F, 0x03, 0x0E, 0x8B, 0x17, 0x02, 0x30, 0xBB, 0x33, 0x2B, 0x9C, 0xF0, 0x44, 0xB7,
-                0xD7, 0x1F, 0x9B, 0x85, 0xB0, 0xAE, 0x36, 0x41, 0x61, 0xD6, 0x0A, 0x0B, 0xDF, 0xA7,
-                0x26, 0xCB, 0xE1, 0x89, 0x41, 0x32, 0x86, 0x0C, 0x86, 0xF8, 0x4A, 0xDB, 0x8C, 0xA8,
-                0xC9, 0x93, 0x3F, 0x83, 0x1C, 0x01,
-            ],
-            &[
-                0xC3, 0xB7, 0xD7, 0x67, 0x7E, 0x57, 0xD0, 0xBF, 0x71, 0x5B, 0xDA, 0xF0, 0xAA, 0xDD,
-                0x67, 0x59, 0x37, 0x51, 0xC7, 0xF6, 0x0D, 0xA1, 0x6C, 0x01, 0x90, 0x80, 0x82, 0x03,
-                0xDA, 0xA9, 0x39, 0xE4, 0xAC, 0x46, 0xF8, 0x39, 0x73, 0x7A, 0x58, 0x47, 0x60, 0x58,
-                0xD7, 0x43, 0x48, 0x79, 0x6F, 0x01,
-            ],
-        ],
-        [
-            &[
-                0xE3, 0x82, 0x84, 0x22, 0xC6, 0xDC, 0x56, 0x17, 0xF1, 0x1E, 0x21, 0xE3, 0xE3, 0xC8,
-                0xC1, 0xEE, 0xB0, 0xBC, 0x2A, 0x12, 0x1E, 0x6D, 0xFF, 0x07, 0xDC, 0xCF, 0x2E, 0xF3,
-                0x32, 0x04, 0x44, 0x7C, 0x09, 0x78, 0xF1, 0xE4, 0xC8, 0xA2, 0x80, 0xFC, 0x54, 0x9B,
-                0x94, 0xFE, 0x5E, 0xD1, 0x77, 0x00,
-            ],
-            &[
-                0xBC, 0x57, 0xC6, 0x89, 0x92, 0x5B, 0x86, 0x9D, 0xE3, 0x3C, 0x67, 0x59, 0xD5, 0x97,
-                0x3F, 0x8E, 0x5A, 0xD1, 0x5E, 0xD2, 0xB1, 0x15, 0x62, 0xC9, 0x6C, 0x56, 0x22, 0x57,
-                0x46, 0x20, 0xB9, 0x48, 0x22, 0xAD, 0x86, 0xB8, 0xCB, 0xFB, 0x65, 0x5F, 0x0B, 0x42,
-                0x68, 0xBB, 0xFA, 0x26, 0x4E, 0x01,
-            ],
-            &[
-                0x53, 0xC0, 0xA5, 0x65, 0xE5, 0xD9, 0x9C, 0xEA, 0xA0, 0x90, 0x18, 0xAA, 0xF5, 0xB4,
-                0xA9, 0xF1, 0x80, 0x21, 0x03, 0xCB, 0x1E, 0x3C, 0x76, 0x6F, 0x41, 0xDF, 0xEA, 0x47,
-                0x52, 0xF5, 0x82, 0xD1, 0x4B, 0x06, 0xA8, 0x38, 0xE9, 0xC9, 0x78, 0x2B, 0xDC, 0x5E,
-                0x88, 0xE8, 0x6F, 0x37, 0xDD, 0x00,
-            ],
-            &[
-                0x29, 0x7A, 0xEF, 0x8F, 0x1D, 0xA4, 0x19, 0x04, 0x7C, 0x7E, 0xF2, 0x1D, 0x40, 0x5F,
-                0x2E, 0xFD, 0xB7, 0xEF, 0xA3, 0xE8, 0x8D, 0x2F, 0x19, 0x6F, 0xA4, 0x74, 0xEA, 0xA2,
-                0xC7, 0xB6, 0x7E, 0x58, 0x51, 0x85, 0x7F, 0x17, 0x54, 0xA7, 0x7C, 0x90, 0x16, 0x27,
-                0xBF, 0xC0, 0x9D, 0xDD, 0xA0, 0x00,
-            ],
-        ],
-    ];
-
-    // // the following code is used to dump the key schedule to screen
-    // // in a sage friendly format
-    // let keys2 = rescue_perm.key_schedule(&zero);
-    // for e in keys2.iter() {
-    //     for f in e.vec.iter() {
-    //         ark_std::println!("permutation_round_key.append(0x{})",
-    // f.into_bigint());     }
-    // }
-    // The keys are not cross-checked with sage, however, the hash function
-    // is, which implies that the permutation round keys must be correct
-    // (or else, the hash function outputs will not match)
-    // see unit tests:
-    //   - test_sponge()
-    //   - test_rescue_hash_on_0_vec()
-    //   - test_rescue_perm_on_0_vec()
-    const PERMUTATION_ROUND_KEYS: [[&'static [u8]; 4]; 25] = [
-        [
-            &[
-                0x01, 0xA3, 0xE6, 0xF7, 0xEB, 0x5C, 0x3E, 0xA8, 0x11, 0x17, 0xC8, 0xD8, 0xA7, 0x9B,
-                0x1D, 0x50, 0x86, 0x39, 0xCF, 0xF8, 0xA2, 0x59, 0xB9, 0xE0, 0x70, 0x1F, 0x45, 0x88,
-                0xA1, 0x8F, 0x72, 0xF3, 0xDB, 0xD5, 0xFC, 0xE9, 0x8B, 0x0C, 0x8C, 0xA0, 0x18, 0x8E,
-                0x5E, 0x35, 0x7C, 0xEA, 0x80, 0x00,
-            ],
-            &[
-                0xB3, 0x8C, 0xCB, 0x48, 0x1D, 0x58, 0x01, 0xAD, 0x88, 0x91, 0x83, 0xCB, 0x20, 0xE8,
-                0x12, 0xBA, 0xCB, 0xAB, 0x56, 0x29, 0x5E, 0x9D, 0xDC, 0xB7, 0x73, 0x7A, 0xEE, 0x48,
-                0xE7, 0x74, 0x80, 0x2D, 0xBA, 0x2A, 0x98, 0x82, 0x28, 0x20, 0xC6, 0x34, 0xFC, 0xF3,
-                0xFF, 0x38, 0xCA, 0x0C, 0x28, 0x01,
-            ],
-            &[
-                0x10, 0x15, 0x95, 0x26, 0x66, 0xD2, 0xB9, 0x8A, 0x65, 0x27, 0x3E, 0x05, 0xB6, 0x29,
-                0x44, 0x13, 0x96, 0x7D, 0x10, 0x55, 0xF2, 0xDA, 0xE7, 0xE4, 0xEF, 0xB1, 0xBC, 0xC6,
-                0x70, 0x8A, 0xB5, 0xFF, 0xB5, 0xE0, 0xDC, 0xA2, 0xCE, 0x83, 0x8E, 0xFF, 0x3F, 0x87,
-                0x2B, 0xE8, 0x85, 0xF9, 0x46, 0x00,
-            ],
-            &[
-                0x53, 0xE1, 0xB8, 0x63, 0xDA, 0x13, 0xA1, 0xD9, 0xDE, 0x80, 0xAE, 0x92, 0x20, 0x40,
-                0xB9, 0xB8, 0xC6, 0x2B, 0x57, 0xF0, 0x9B, 0x39, 0x5F, 0xB4, 0xFA, 0x78, 0x7A, 0x55,
-                0xA3, 0x91, 0x26, 0x17, 0xCB, 0xB8, 0x01, 0x31, 0x73, 0xB8, 0x4A, 0xBB, 0x8C, 0xB3,
-                0x83, 0x33, 0x1F, 0xE7, 0x4A, 0x00,
-            ],
-        ],
-        [
-            &[
-                0x77, 0x2C, 0x20, 0x9C, 0x1B, 0x49, 0xAC, 0xB0, 0xC9, 0xBD, 0xD6, 0xAB, 0x47, 0xB2,
-                0x0C, 0x65, 0x7C, 0x73, 0x63, 0x48, 0xD2, 0xA3, 0xEC, 0x43, 0xB1, 0x81, 0xE7, 0x20,
-                0x50, 0x24, 0x04, 0xE1, 0x6F, 0x17, 0x2C, 0x5F, 0xBF, 0x4D, 0xC6, 0x74, 0xD7, 0x93,
-                0x23, 0x63, 0xEF, 0x42, 0x56, 0x01,
-            ],
-            &[
-                0x14, 0xBF, 0x76, 0xAE, 0x7B, 0x8B, 0x28, 0xE1, 0x77, 0xCA, 0x96, 0x28, 0x41, 0x70,
-                0x6B, 0x63, 0xE7, 0xEF, 0xC6, 0x2E, 0x56, 0x88, 0xBA, 0x31, 0xC3, 0xF6, 0x3E, 0x0A,
-                0x52, 0xB1, 0x45, 0xE2, 0x48, 0x87, 0x29, 0x4B, 0xF2, 0xCC, 0x06, 0x89, 0xE3, 0xE2,
-                0x9A, 0x68, 0x37, 0xEC, 0x8B, 0x01,
-            ],
-            &[
-                0xB7, 0xBA, 0xD5, 0x74, 0xFD, 0x2A, 0xFF, 0x1C, 0xA1, 0xF4, 0xA5, 0x23, 0x2A, 0xEE,
-                0x02, 0xD5, 0x32, 0x8C, 0xB2, 0x1B, 0x79, 0xAE, 0xD8, 0xD0, 0x63, 0xD7, 0x53, 0x4B,
-                0x2D, 0x1A, 0x97, 0x7A, 0x3E, 0xE2, 0xB4, 0xD5, 0x38, 0xAD, 0xF7, 0x71, 0x08, 0xBE,
-                0x0C, 0x48, 0xAA, 0x00, 0x52, 0x01,
-            ],
-            &[
-                0x48, 0x14, 0xA1, 0xD7, 0x2F, 0x90, 0x21, 0x3E, 0xA9, 0x9E, 0x7C, 0x79, 0x83, 0x72,
-                0x4F, 0xB2, 0x94, 0x24, 0x6C, 0x55, 0x0D, 0x9C, 0x79, 0xDD, 0x29, 0x9A, 0x9B, 0x5C,
-                0xD6, 0x5E, 0x52, 0x15, 0x6D, 0x94, 0xAD, 0xC1, 0x97, 0xF7, 0x18, 0x21, 0x00, 0xEE,
-                0x7C, 0xAB, 0x52, 0x08, 0xF1, 0x00,
-            ],
-        ],
-        [
-            &[
-                0x78, 0x11, 0x39, 0xC4, 0xEA, 0x84, 0x50, 0xE6, 0x50, 0x95, 0xDE, 0x5B, 0xA4, 0xC7,
-                0xBE, 0xAC, 0x3E, 0x73, 0xD7, 0x92, 0x95, 0x8C, 0x0F, 0xA9, 0xFF, 0x82, 0xA3, 0x42,
-                0xCB, 0xFF, 0x61, 0xA3, 0xA2, 0xF7, 0x18, 0x0F, 0x96, 0x67, 0xD3, 0x33, 0xBC, 0x97,
-                0x04, 0xA2, 0xD9, 0x94, 0xA9, 0x01,
-            ],
-            &[
-                0xC7, 0x95, 0x69, 0x1D, 0x72, 0xDC, 0xB3, 0xB4, 0x28, 0xB6, 0x7F, 0x64, 0xB3, 0xF1,
-                0x11, 0x2F, 0x6A, 0xFD, 0xB7, 0xF6, 0xCF, 0xFA, 0x39, 0x92, 0x9A, 0x4A, 0xBE, 0xD5,
-                0x59, 0x40, 0x45, 0xF0, 0xA6, 0x43, 0xC1, 0xC8, 0xA5, 0x4C, 0x20, 0xB7, 0x82, 0x53,
-                0x16, 0x0D, 0xF9, 0x71, 0x52, 0x00,
-            ],
-            &[
-                0xA5, 0x5B, 0xB6, 0xBF, 0x8C, 0x58, 0xB5, 0xEC, 0xA8, 0xD6, 0x29, 0x23, 0xA1, 0xC3,
-                0x03, 0x6B, 0xFD, 0xB7, 0x02, 0x20, 0xA8, 0x19, 0x8A, 0x09, 0x67, 0xEF, 0xE1, 0xA2,
-                0x52, 0x2A, 0x5C, 0x7E, 0x76, 0x4F, 0xE2, 0xF8, 0x80, 0x4C, 0xDC, 0x7B, 0x77, 0x7F,
-                0xBB, 0x1A, 0xD6, 0x98, 0xA8, 0x01,
-            ],
-            &[
-                0xFC, 0xFD, 0x50, 0xD5, 0x6D, 0x84, 0xBF, 0xB8, 0xD4, 0xF7, 0xA1, 0xDA, 0x1D, 0xB9,
-                0xF4, 0x67, 0xDF, 0xFC, 0xEC, 0x45, 0xCE, 0x24, 0xDC, 0x67, 0xFE, 0x06, 0x61, 0x5E,
-                0x82, 0x60, 0x99, 0x19, 0xC7, 0xD9, 0xA1, 0x0F, 0x17, 0xC7, 0x2D, 0x9D, 0x71, 0x33,
-                0xEC, 0xDB, 0x94, 0x29, 0x35, 0x01,
-            ],
-        ],
-        [
-            &[
-                0x8E, 0x78, 0x34, 0x78, 0x34, 0xDA, 0x46, 0x52, 0x25, 0x4C, 0x84, 0x60, 0x19, 0x76,
-                0xAC, 0x3E, 0x4D, 0x50, 0xBA, 0x90, 0xD7, 0x66, 0x17, 0x54, 0x08, 0x1A, 0x3E, 0xAC,
-                0xCE, 0xFE, 0x82, 0x50, 0x5C, 0xFA, 0xDE, 0x1A, 0x1B, 0xC6, 0xED, 0x9B, 0x00, 0xE0,
-                0xBE, 0x4F, 0x77, 0x52, 0x00, 0x01,
-            ],
-            &[
-                0xC1, 0x4F, 0x03, 0x0A, 0xC4, 0xF1, 0x83, 0x94, 0xF5, 0x4A, 0x4A, 0xD0, 0x9B, 0x58,
-                0x07, 0x67, 0xA3, 0x75, 0x75, 0xB2, 0x46, 0x20, 0x0A, 0x99, 0x77, 0xFF, 0x1E, 0x32,
-                0x69, 0xCD, 0x03, 0x58, 0xF0, 0xE7, 0x06, 0x34, 0x77, 0xB6, 0xDD, 0x7F, 0xC6, 0xB6,
-                0x75, 0xCE, 0x57, 0xF1, 0x78, 0x01,
-            ],
-            &[
-                0xE8, 0xED, 0x3D, 0xE1, 0x38, 0x95, 0x07, 0xA8, 0x1D, 0x7E, 0x27, 0x5B, 0x57, 0xE1,
-                0x40, 0x10, 0x75, 0x84, 0x35, 0x80, 0xA1, 0x10, 0x7A, 0x3E, 0xEF, 0xE0, 0xF2, 0x79,
-                0x5F, 0x20, 0xDF, 0xC6, 0x3F, 0x1E, 0xBA, 0x07, 0x31, 0xAC, 0xEA, 0xB3, 0x97, 0xB1,
-                0xA4, 0xEF, 0x6F, 0x95, 0x4D, 0x00,
-            ],
-            &[
-                0xCF, 0x73, 0x6B, 0x18, 0xB6, 0xC6, 0x08, 0xA1, 0xCB, 0x1E, 0x1A, 0x05, 0x70, 0xCD,
-                0xAB, 0x94, 0xC8, 0x17, 0x63, 0xB5, 0x0E, 0x05, 0x7C, 0x84, 0x76, 0xC8, 0x55, 0x30,
-                0x1E, 0xE7, 0x53, 0x02, 0xC6, 0xD3, 0xDF, 0x63, 0xB6, 0x4C, 0x38, 0x71, 0x78, 0x3C,
-                0xF8, 0x9F, 0x56, 0x69, 0x05, 0x00,
-            ],
-        ],
-        [
-            &[
-                0x25, 0x12, 0x04, 0xE1, 0xD6, 0x3E, 0xA4, 0xFF, 0x0D, 0xFC, 0x14, 0x68, 0x53, 0x16,
-                0xFD, 0x2F, 0x3E, 0x70, 0x5E, 0x39, 0xEB, 0xB5, 0xAA, 0xF6, 0x87, 0x42, 0x8B, 0xC8,
-                0x24, 0x7F, 0x25, 0x83, 0xA5, 0x7E, 0x6F, 0x59, 0xB1, 0xD6, 0x8B, 0xD5, 0x4A, 0x11,
-                0x1C, 0xB8, 0x24, 0x69, 0x7A, 0x01,
-            ],
-            &[
-                0x6D, 0xE9, 0xE2, 0x0F, 0x83, 0x8F, 0x94, 0x43, 0x86, 0xD4, 0x52, 0xCB, 0xEC, 0x7D,
-                0xC5, 0xA7, 0xDF, 0xB9, 0xEF, 0x97, 0x47, 0x7E, 0x7C, 0xA4, 0x89, 0x4F, 0x1D, 0x8E,
-                0xED, 0x35, 0x7D, 0x3A, 0x9E, 0xEC, 0x42, 0x1F, 0x53, 0x6D, 0x1E, 0x33, 0x83, 0xB1,
-                0xC2, 0xC0, 0x73, 0xCA, 0x51, 0x01,
-            ],
-            &[
-                0x9E, 0xC6, 0xDB, 0x5E, 0x3E, 0x12, 0x8C, 0x91, 0x2D, 0x0B, 0x91, 0x52, 0x96, 0x85,
-                0x59, 0xC8, 0x25, 0x14, 0xDA, 0x23, 0x19, 0x3C, 0x60, 0x6F, 0x44, 0xBC, 0x33, 0xB5,
-                0x70, 0x0D, 0x31, 0xBE, 0x8C, 0xF7, 0xE9, 0xC5, 0x36, 0x2D, 0x5B, 0xE5, 0x9A, 0x0D,
-                0x3C, 0xE6, 0x68, 0x23, 0x51, 0x00,
-            ],
-            &[
-                0x5E, 0x20, 0x10, 0x88, 0x7D, 0xD9, 0xDE, 0xA6, 0xDF, 0xBE, 0x3F, 0x84, 0x42, 0x36,
-                0x80, 0x68, 0xCC, 0x08, 0x19, 0xCB, 0x96, 0xCA, 0x09, 0x90, 0x31, 0x3E, 0x8E, 0x57,
-                0x53, 0x2B, 0x44, 0xCA, 0x44, 0x93, 0x1C, 0xB7, 0x30, 0xDE, 0x7E, 0xB1, 0x26, 0x07,
-                0x89, 0x14, 0x8F, 0x97, 0x23, 0x00,
-            ],
-        ],
-        [
-            &[
-                0x45, 0xE1, 0xAB, 0x6E, 0x75, 0x34, 0xA5, 0x2F, 0x2D, 0x2A, 0xEC, 0x8A, 0x11, 0x4B,
-                0x58, 0xAF, 0x6C, 0x66, 0xD6, 0x3F, 0x12, 0x73, 0x3D, 0xA8, 0xCF, 0xA8, 0x3A, 0x3A,
-                0xF0, 0xFE, 0x81, 0x42, 0xB5, 0xFD, 0xD7, 0xF5, 0x40, 0xC5, 0xD1, 0x97, 0xC9, 0xF8,
-                0x84, 0x24, 0xAA, 0xCF, 0x6F, 0x00,
-            ],
-            &[
-                0xD1, 0x1B, 0x49, 0x18, 0xEE, 0xFD, 0x37, 0x5F, 0x97, 0x3B, 0x66, 0xDE, 0x74, 0x78,
-                0xFC, 0x1E, 0x3A, 0xA5, 0x8B, 0x6B, 0x09, 0x83, 0x93, 0x07, 0x48, 0x20, 0x74, 0x7A,
-                0x12, 0x1B, 0xF3, 0xEF, 0x94, 0x38, 0x94, 0x5A, 0xF0, 0x04, 0x68, 0xAA, 0xCE, 0x5A,
-                0xCB, 0xAE, 0x5E, 0x74, 0x8E, 0x00,
-            ],
-            &[
-                0x50, 0xD3, 0xE4, 0x93, 0xD3, 0x65, 0x70, 0x9C, 0xA5, 0xB3, 0x6B, 0x52, 0x61, 0xDF,
-                0x7F, 0x2A, 0x1A, 0xDA, 0xC2, 0x84, 0xB4, 0xC4, 0x8F, 0xB8, 0xE2, 0x3E, 0x43, 0x9C,
-                0x7E, 0x26, 0xBB, 0x67, 0x96, 0xB6, 0x51, 0x81, 0x60, 0x86, 0xC4, 0x2A, 0xA1, 0xDB,
-                0x09, 0x68, 0x97, 0xEF, 0xF4, 0x00,
-            ],
-            &[
-                0x48, 0x53, 0x76, 0x63, 0x34, 0xD8, 0x3A, 0x78, 0xB9, 0x73, 0xE8, 0x4B, 0xD0, 0xFF,
-                0xDE, 0x90, 0x3E, 0x56, 0xFA, 0x67, 0xD1, 0xBE, 0x5C, 0x05, 0xAC, 0xBB, 0x9F, 0x8D,
-                0xD0, 0x76, 0xCF, 0x7F, 0xF0, 0x20, 0x43, 0x91, 0x52, 0xB0, 0x9F, 0xD3, 0x21, 0xFF,
-                0x30, 0x2F, 0xDA, 0x22, 0x98, 0x00,
-            ],
-        ],
-        [
-            &[
-                0x1C, 0x2A, 0xA0, 0x0E, 0x7C, 0x41, 0xBD, 0xDD, 0xAF, 0xBE, 0x29, 0x49, 0x09, 0xC1,
-                0x5A, 0xD9, 0x93, 0x7A, 0xFE, 0x4C, 0xFA, 0xB3, 0x31, 0x25, 0x87, 0x8D, 0x5B, 0xF7,
-                0xDB, 0xBA, 0x4F, 0x7D, 0x30, 0x38, 0x7E, 0xA2, 0xE2, 0x6D, 0x70, 0x8B, 0xFD, 0xC5,
-                0xDE, 0x4A, 0xE4, 0x49, 0xAB, 0x00,
-            ],
-            &[
-                0x0C, 0x61, 0x26, 0xFE, 0x8C, 0xCA, 0x6F, 0x21, 0x5B, 0x84, 0x9C, 0x0C, 0x05, 0xBD,
-                0xA1, 0x89, 0x80, 0x25, 0xE7, 0xCC, 0x84, 0x77, 0x9C, 0xDD, 0xCF, 0x6B, 0x47, 0x25,
-                0x11, 0xDA, 0xBA, 0x0C, 0x0D, 0xA6, 0xD8, 0x32, 0x44, 0xEA, 0xE2, 0x05, 0xA5, 0xBE,
-                0x1C, 0x31, 0x14, 0x48, 0x27, 0x01,
-            ],
-            &[
-                0x26, 0x8A, 0x7B, 0x0A, 0xD6, 0x2B, 0xDD, 0x8C, 0xC6, 0x85, 0x1D, 0x87, 0x03, 0xA9,
-                0x42, 0x6E, 0xC8, 0x2A, 0x0E, 0x95, 0xB2, 0x5A, 0xCD, 0x2B, 0x89, 0xD3, 0x33, 0x50,
-                0x05, 0x61, 0x4D, 0xA1, 0xE5, 0x7B, 0x93, 0xCE, 0x6C, 0x9B, 0x86, 0x1C, 0x91, 0xEC,
-                0xDA, 0x37, 0x3D, 0x6D, 0xE1, 0x00,
-            ],
-            &[
-                0x2A, 0x47, 0xD7, 0x46, 0x57, 0x21, 0x1F, 0xEC, 0x7C, 0x34, 0x5F, 0x03, 0xB5, 0xC6,
-                0x5D, 0x31, 0xF7, 0xB1, 0xDF, 0xA8, 0xAE, 0x2E, 0xDB, 0xFD, 0x81, 0xAA, 0x05, 0xFF,
-                0xCF, 0x06, 0x51, 0x02, 0xF2, 0x4B, 0xEC, 0xD5, 0xE0, 0x77, 0x8F, 0x27, 0x90, 0x3F,
-                0xA0, 0x8B, 0x78, 0xB1, 0x53, 0x00,
-            ],
-        ],
-        [
-            &[
-                0xF6, 0xAE, 0x6B, 0xF9, 0x3A, 0xB4, 0xE0, 0xE2, 0x01, 0x65, 0x8D, 0xA8, 0x25, 0x79,
-                0x94, 0x6E, 0xD9, 0x9A, 0xD4, 0x08, 0xE6, 0x75, 0x7F, 0xDB, 0xC9, 0x3C, 0x75, 0x83,
-                0x1C, 0x2E, 0x66, 0xC9, 0xFD, 0x68, 0xAB, 0x9A, 0x80, 0xA6, 0xF3, 0x61, 0xF4, 0x1D,
-                0x0C, 0xF0, 0x50, 0x60, 0xA1, 0x01,
-            ],
-            &[
-                0xC6, 0x5B, 0x34, 0x4D, 0x83, 0x1E, 0xF8, 0xB0, 0x1C, 0xFD, 0x27, 0x18, 0xB4, 0xA5,
-                0x63, 0x47, 0x63, 0x9A, 0x68, 0xE0, 0xFC, 0x3D, 0x36, 0xC6, 0x58, 0x5D, 0x01, 0x6F,
-                0xC4, 0xD4, 0x43, 0x16, 0xAE, 0x5F, 0x40, 0x4E, 0xA6, 0x16, 0x68, 0x62, 0x16, 0x21,
-                0x74, 0x01, 0x6D, 0x53, 0x9E, 0x01,
-            ],
-            &[
-                0x53, 0x63, 0xC8, 0x5E, 0xAE, 0xA6, 0x56, 0x70, 0xCA, 0xD4, 0x30, 0x32, 0x29, 0x3E,
-                0xBC, 0xC2, 0xF3, 0xE2, 0x3E, 0xA4, 0xF6, 0xC1, 0xC0, 0x7C, 0x2A, 0xF4, 0x95, 0x20,
-                0x7D, 0x42, 0x82, 0xC1, 0x47, 0x71, 0xF3, 0x7E, 0x08, 0xBF, 0x2C, 0x82, 0x55, 0x29,
-                0x5D, 0xC7, 0xC4, 0x70, 0x35, 0x01,
-            ],
-            &[
-                0x28, 0xB4, 0x94, 0x3B, 0x34, 0xA5, 0xB0, 0x85, 0x63, 0x44, 0x43, 0x06, 0x65, 0x6B,
-                0x7E, 0x1F, 0x09, 0x7A, 0x7F, 0x74, 0xD5, 0x94, 0xB9, 0x63, 0xDE, 0x46, 0x13, 0xEA,
-                0xB6, 0x3D, 0xE4, 0x28, 0xD7, 0xF3, 0x4E, 0xDF, 0x05, 0xD8, 0x32, 0x0A, 0xE8, 0xC9,
-                0xE4, 0x71, 0x6B, 0xB2, 0xDD, 0x00,
-            ],
-        ],
-        [
-            &[
-                0x02, 0x41, 0x79, 0xD5, 0x65, 0x18, 0xB8, 0xEF, 0xDA, 0xC3, 0x82, 0xA9, 0xC0, 0xDD,
-                0x5F, 0xEC, 0xFD, 0x58, 0xDA, 0x84, 0xE2, 0xDA, 0x66, 0x55, 0xE3, 0x0C, 0x45, 0xCE,
-                0x65, 0x95, 0x26, 0x61, 0x3F, 0x5B, 0x18, 0x04, 0xBD, 0x4E, 0x10, 0xBC, 0x73, 0x9C,
-                0xC2, 0xDE, 0x01, 0x66, 0x7A, 0x00,
-            ],
-            &[
-                0x16, 0xD1, 0x1B, 0x08, 0x70, 0xD6, 0x56, 0xE1, 0x72, 0x6B, 0x39, 0xF7, 0x4E, 0x79,
-                0x3A, 0xFC, 0xB1, 0x2F, 0xB9, 0x89, 0xB4, 0x7F, 0x41, 0x59, 0xA4, 0xD9, 0x4B, 0x64,
-                0x74, 0x40, 0x76, 0x7E, 0xB4, 0x5A, 0xAC, 0x81, 0x97, 0xE7, 0x1B, 0x31, 0x2A, 0xC6,
-                0x02, 0xCB, 0x11, 0x1C, 0x81, 0x00,
-            ],
-            &[
-                0x0B, 0xA1, 0x19, 0x09, 0xAC, 0xEF, 0x63, 0x7F, 0x8F, 0xD8, 0xC8, 0xBA, 0x40, 0x09,
-                0x73, 0x4A, 0x8A, 0x8A, 0xBC, 0x35, 0x1C, 0xB5, 0x44, 0xB4, 0x03, 0x5E, 0x51, 0x96,
-                0x8B, 0x59, 0xD5, 0xAB, 0xCB, 0x94, 0xBC, 0x19, 0x0D, 0xDB, 0x6D, 0xC2, 0x7B, 0x89,
-                0xD3, 0xF0, 0xD4, 0xD8, 0x27, 0x00,
-            ],
-            &[
-                0x1B, 0x73, 0xCA, 0x09, 0xDF, 0x72, 0x73, 0x43, 0x79, 0xD5, 0xB6, 0x99, 0x2D, 0x7F,
-                0xA5, 0x54, 0xC6, 0xB2, 0x71, 0x87, 0xC8, 0xAE, 0xA5, 0x0E, 0x8F, 0x56, 0x4F, 0x50,
-                0x1A, 0xBE, 0x8B, 0xC9, 0x54, 0x35, 0x10, 0xB4, 0xFC, 0x99, 0x0B, 0x4C, 0xD0, 0x6C,
-                0x18, 0xB1, 0x75, 0xD5, 0xF0, 0x00,
-            ],
-        ],
-        [
-            &[
-                0x1E, 0x2A, 0xB5, 0x21, 0x55, 0xB9, 0x8F, 0x22, 0x12, 0xD2, 0x9A, 0x3A, 0x7F, 0xA2,
-                0xEA, 0xE7, 0xD6, 0x1E, 0xC8, 0x18, 0x15, 0xB3, 0x32, 0x00, 0x44, 0x05, 0x4B, 0x34,
-                0x14, 0x7D, 0x09, 0xD4, 0x89, 0xAC, 0xB6, 0x63, 0xDD, 0x4B, 0xB1, 0xB6, 0x7B, 0xD0,
-                0xF2, 0x7B, 0x85, 0x99, 0x87, 0x00,
-            ],
-            &[
-                0x41, 0x98, 0x4C, 0x2C, 0x68, 0xF7, 0x94, 0x65, 0xED, 0xF1, 0xDC, 0x39, 0xA2, 0x30,
-                0xD6, 0xB9, 0xAC, 0xCB, 0x4C, 0x26, 0x2A, 0xEE, 0x9D, 0x73, 0x1E, 0x41, 0xC0, 0x1E,
-                0xF3, 0xF0, 0xD1, 0xA6, 0x92, 0xE1, 0x28, 0x21, 0x1B, 0x7F, 0x97, 0x47, 0xD3, 0x97,
-                0xF2, 0xEF, 0x20, 0x43, 0x3E, 0x00,
-            ],
-            &[
-                0xCF, 0xF9, 0x6E, 0xFE, 0xE9, 0x98, 0xBB, 0x23, 0xFB, 0xB4, 0xFC, 0x42, 0xEA, 0xBC,
-                0xA8, 0xD9, 0xFD, 0xC1, 0x65, 0xE9, 0xE8, 0x51, 0xD5, 0xB2, 0x3D, 0xC5, 0x15, 0x0B,
-                0x0F, 0xA2, 0x63, 0x31, 0x7D, 0x15, 0xC8, 0x8B, 0xE7, 0xF4, 0x35, 0x04, 0xC0, 0x02,
-                0x9F, 0x25, 0xCE, 0xAD, 0x55, 0x01,
-            ],
-            &[
-                0xDE, 0xCC, 0xE7, 0x4A, 0xC0, 0xAC, 0x1F, 0xF4, 0xB7, 0x6B, 0x85, 0x7B, 0x9B, 0x59,
-                0xFD, 0x58, 0x11, 0x41, 0x6B, 0xFC, 0x74, 0x8C, 0x35, 0x06, 0xCA, 0xE6, 0x19, 0x3B,
-                0x88, 0x80, 0xCE, 0x5A, 0xF3, 0x14, 0x47, 0xB6, 0x38, 0x55, 0x22, 0x47, 0x58, 0x8D,
-                0xAC, 0x09, 0x4E, 0x43, 0x3E, 0x00,
-            ],
-        ],
-        [
-            &[
-                0xD1, 0x04, 0x7B, 0xF7, 0xCD, 0xD4, 0x0D, 0x17, 0xFC, 0x57, 0x7C, 0xF9, 0x28, 0x04,
-                0x1D, 0xC1, 0xCA, 0xAC, 0xA8, 0x93, 0xE9, 0x11, 0x45, 0x59, 0x81, 0x6C, 0xD3, 0x7F,
-                0x69, 0x2A, 0xD5, 0x10, 0xDF, 0xCE, 0x2A, 0x57, 0x1B, 0x92, 0xBC, 0x98, 0x16, 0xB1,
-                0xC4, 0x43, 0x2F, 0xBC, 0x53, 0x00,
-            ],
-            &[
-                0x89, 0x99, 0xFA, 0x22, 0x81, 0xF1, 0x95, 0x60, 0x9C, 0xD7, 0xDA, 0xA7, 0xF9, 0x53,
-                0xD5, 0xD2, 0x8D, 0x8E, 0xAE, 0x53, 0x1C, 0x8E, 0x8F, 0x04, 0xB4, 0x88, 0xF1, 0x38,
-                0x86, 0xB4, 0x00, 0xA4, 0x1C, 0x88, 0x0F, 0xB1, 0x59, 0x70, 0xEF, 0x65, 0x54, 0x22,
-                0xCF, 0x75, 0x2E, 0x75, 0x86, 0x00,
-            ],
-            &[
-                0x2A, 0x90, 0x99, 0x2C, 0x53, 0x97, 0xF2, 0xEA, 0x9E, 0xF5, 0x23, 0x45, 0x9C, 0xE9,
-                0x07, 0xB0, 0x80, 0x2F, 0x17, 0xFA, 0x40, 0xA0, 0x94, 0xB1, 0x2A, 0x4E, 0xF7, 0x4A,
-                0x57, 0x6F, 0x88, 0xF8, 0xE9, 0x1D, 0x46, 0xF8, 0x4C, 0x95, 0xB1, 0x09, 0xD5, 0x3D,
-                0x46, 0x19, 0x7C, 0xA0, 0x6E, 0x01,
-            ],
-            &[
-                0xE1, 0x87, 0xDA, 0x7B, 0x2C, 0x7F, 0xA8, 0x8A, 0x9B, 0xA1, 0xD6, 0xF6, 0x30, 0xDD,
-                0xBC, 0xF1, 0x10, 0xD9, 0xFE, 0xE4, 0x21, 0xB1, 0x65, 0x02, 0x31, 0xE2, 0x7C, 0xBC,
-                0x22, 0x36, 0x22, 0x13, 0xE8, 0x44, 0x99, 0x90, 0xC0, 0x3A, 0x8E, 0xAC, 0x58, 0x1F,
-                0x50, 0xDB, 0xBE, 0x61, 0x66, 0x01,
-            ],
-        ],
-        [
-            &[
-                0x5A, 0x6B, 0x92, 0xE7, 0x91, 0xF5, 0x79, 0x6D, 0xFB, 0x41, 0x51, 0x08, 0x6B, 0xAA,
-                0x2E, 0x44, 0x8F, 0x53, 0xF3, 0x93, 0x9C, 0x6E, 0x84, 0x5A, 0x1F, 0xEF, 0xEC, 0x6A,
-                0x13, 0x7B, 0xD4, 0xAA, 0xFE, 0x56, 0xDE, 0x07, 0x82, 0x74, 0x9E, 0x94, 0xA9, 0x4A,
-                0xA8, 0x5F, 0x45, 0x87, 0x07, 0x01,
-            ],
-            &[
-                0xC3, 0x15, 0x6B, 0xA7, 0xCC, 0x4D, 0x7C, 0x19, 0x5C, 0xD4, 0x92, 0x99, 0xD2, 0xE9,
-                0x04, 0x75, 0x68, 0x96, 0xA5, 0xA7, 0xFE, 0xF7, 0x90, 0x1B, 0x83, 0x00, 0x9F, 0x9A,
-                0x83, 0xF5, 0x8E, 0xC7, 0x36, 0x76, 0x48, 0x6E, 0x94, 0xAA, 0xD8, 0x7E, 0x77, 0xF1,
-                0x06, 0x88, 0x3D, 0x85, 0x76, 0x00,
-            ],
-            &[
-                0xC1, 0xDD, 0xC8, 0xFC, 0xA3, 0x4B, 0x7F, 0x9C, 0xF1, 0xAE, 0x3E, 0x53, 0x9D, 0x55,
-                0xA6, 0xFD, 0xD9, 0xFA, 0x23, 0x10, 0xD8, 0xA4, 0x13, 0x41, 0x70, 0xCD, 0xD1, 0x75,
-                0xAD, 0x39, 0x6E, 0x86, 0x75, 0x85, 0x57, 0x43, 0xC2, 0x8E, 0x2A, 0x69, 0x3F, 0x5C,
-                0xD3, 0xCC, 0x81, 0xCF, 0x6E, 0x01,
-            ],
-            &[
-                0xF9, 0xEE, 0x94, 0x57, 0xF2, 0xBA, 0xBF, 0x3D, 0x20, 0x3B, 0xA3, 0x06, 0x88, 0x19,
-                0x51, 0xF8, 0xE9, 0xEF, 0x0A, 0xD3, 0xFC, 0x00, 0xF4, 0xB5, 0x4A, 0x6E, 0xF3, 0xD5,
-                0x99, 0x80, 0x83, 0xC5, 0xA8, 0x88, 0x09, 0x91, 0xDE, 0x04, 0x65, 0xFE, 0x22, 0xEE,
-                0x5C, 0xE2, 0xFD, 0x88, 0xA0, 0x00,
-            ],
-        ],
-        [
-            &[
-                0xAD, 0x4C, 0xCA, 0xCC, 0x7A, 0x9C, 0x67, 0x8D, 0x38, 0x9C, 0x84, 0x0F, 0x74, 0x3F,
-                0x75, 0x78, 0x37, 0x60, 0x7F, 0x6B, 0x77, 0x4D, 0x75, 0x2F, 0xB5, 0xEB, 0xD4, 0x01,
-                0x73, 0xD5, 0x58, 0x0B, 0xE2, 0x3B, 0xCB, 0xBF, 0xAB, 0x1C, 0xA5, 0x10, 0x87, 0x1A,
-                0xCF, 0x74, 0xD5, 0xAD, 0xB4, 0x00,
-            ],
-            &[
-                0x26, 0x5D, 0x80, 0xCF, 0x41, 0x31, 0x19, 0x3F, 0x79, 0xCD, 0x81, 0x5E, 0x90, 0xE6,
-                0x89, 0x97, 0xE0, 0x6F, 0x8E, 0x06, 0xE8, 0x9C, 0x23, 0x9F, 0xD2, 0xFB, 0x6B, 0x7D,
-                0xA4, 0x52, 0x5E, 0x9E, 0xAC, 0x9C, 0x4E, 0xD9, 0xE4, 0xEF, 0x22, 0x5D, 0xC5, 0x17,
-                0xDD, 0x86, 0xFF, 0xFF, 0x5C, 0x01,
-            ],
-            &[
-                0x92, 0x39, 0xA4, 0xB0, 0xEA, 0x09, 0xBF, 0xA7, 0xD1, 0x58, 0x8A, 0x73, 0x0C, 0x35,
-                0x5C, 0xA2, 0x48, 0xFD, 0xB8, 0x14, 0xBF, 0x46, 0x07, 0x7B, 0xE3, 0xCF, 0xC5, 0x44,
-                0xE4, 0x4B, 0x1D, 0x3A, 0x5F, 0x37, 0x42, 0xED, 0xD6, 0x97, 0x3C, 0xE2, 0x45, 0x62,
-                0xE2, 0xC3, 0xB5, 0xA1, 0x6C, 0x01,
-            ],
-            &[
-                0xE3, 0xBF, 0x71, 0x3F, 0x7F, 0x4D, 0x17, 0x8C, 0x13, 0x48, 0x80, 0x7A, 0x98, 0xF5,
-                0x59, 0xF5, 0x52, 0x56, 0xF9, 0xB5, 0x96, 0x5C, 0xD6, 0xEE, 0x33, 0x1A, 0xF7, 0x8E,
-                0x80, 0x25, 0x35, 0x0A, 0x56, 0xD6, 0x14, 0xFF, 0x08, 0x8E, 0x12, 0x4C, 0x24, 0xEA,
-                0x83, 0xF6, 0xA3, 0xF9, 0x60, 0x01,
-            ],
-        ],
-        [
-            &[
-                0xC7, 0x28, 0x44, 0x10, 0x2D, 0xEE, 0x07, 0x48, 0xAC, 0xD6, 0x32, 0xEB, 0x9B, 0xF4,
-                0x83, 0x35, 0x18, 0xCF, 0xC9, 0x99, 0xA8, 0x7E, 0xD3, 0xB6, 0x93, 0x5E, 0xE0, 0xD4,
-                0x26, 0x61, 0xDC, 0x20, 0xC3, 0xD2, 0x19, 0xB1, 0x93, 0x44, 0xEB, 0xC8, 0x59, 0x57,
-                0x2C, 0x87, 0x06, 0x63, 0xDB, 0x00,
-            ],
-            &[
-                0x61, 0x48, 0x4B, 0x0B, 0x8C, 0xAF, 0xB7, 0x06, 0x6A, 0x05, 0x0F, 0xD5, 0x14, 0xD0,
-                0x8E, 0x2F, 0x56, 0x9A, 0x9D, 0x1D, 0xC4, 0xE6, 0x14, 0xA7, 0x38, 0x99, 0x40, 0xB7,
-                0x51, 0x8C, 0x6F, 0xB3, 0x25, 0xB3, 0xB0, 0xBD, 0x8A, 0x41, 0x85, 0xE2, 0xE2, 0x6D,
-                0x5F, 0x4C, 0x5A, 0x9F, 0xFC, 0x00,
-            ],
-            &[
-                0x50, 0x18, 0xFD, 0xF2, 0x14, 0x2A, 0xD7, 0xD8, 0xD5, 0x44, 0xA7, 0x42, 0x55, 0xBE,
-                0xB9, 0x4C, 0xDA, 0x31, 0xC5, 0xCC, 0x46, 0x84, 0x62, 0x3D, 0x97, 0xCB, 0x41, 0xE0,
-                0xFC, 0x95, 0xE8, 0x52, 0xF3, 0x72, 0x47, 0xB1, 0x2B, 0x68, 0x0D, 0x58, 0x60, 0x6C,
-                0x1A, 0x73, 0x52, 0xBD, 0x27, 0x00,
-            ],
-            &[
-                0xC6, 0x21, 0x82, 0x2D, 0xCA, 0xAE, 0x0A, 0xD9, 0x6B, 0x3A, 0xD5, 0xD5, 0x7D, 0x84,
-                0xA9, 0x67, 0x0A, 0x02, 0xB9, 0x2F, 0x99, 0x0E, 0x12, 0xEF, 0x0D, 0x59, 0x7B, 0xBC,
-                0x05, 0x36, 0x2E, 0xEC, 0xA8, 0xEA, 0x25, 0xDA, 0xF4, 0xC0, 0x94, 0x86, 0x62, 0x8C,
-                0x25, 0x6D, 0xD1, 0x91, 0x98, 0x01,
-            ],
-        ],
-        [
-            &[
-                0x03, 0xEF, 0xBE, 0x0E, 0xB9, 0xBF, 0xA9, 0x78, 0x3B, 0x1D, 0x9A, 0xD5, 0x85, 0xBD,
-                0x68, 0x72, 0x49, 0x76, 0x38, 0x7A, 0xF2, 0x31, 0x9F, 0xDD, 0x97, 0x11, 0x0C, 0x4A,
-                0xFA, 0xA8, 0xA2, 0x5F, 0x01, 0x3B, 0xAB, 0x4E, 0xB1, 0x32, 0xB2, 0x86, 0x89, 0xAB,
-                0xEA, 0xA1, 0xE7, 0x8C, 0xEB, 0x00,
-            ],
-            &[
-                0xDC, 0xD9, 0xD7, 0x9C, 0x2B, 0xF7, 0x8F, 0xA0, 0xAB, 0xFF, 0xA3, 0x56, 0xB2, 0x72,
-                0x22, 0x86, 0xF8, 0x5D, 0xED, 0xDC, 0xBF, 0xFB, 0xBB, 0x8B, 0x63, 0x00, 0x49, 0x03,
-                0xB4, 0xC3, 0xB6, 0x07, 0x3A, 0x0F, 0x39, 0xF2, 0x27, 0x7F, 0x5B, 0x0E, 0x72, 0x40,
-                0xA3, 0x42, 0xF7, 0x59, 0xAE, 0x00,
-            ],
-            &[
-                0x44, 0xAE, 0x0A, 0x06, 0xCD, 0x12, 0x24, 0xE1, 0x9E, 0xAD, 0xD9, 0xDA, 0x8D, 0xB9,
-                0xFA, 0xAA, 0x65, 0x27, 0x6C, 0xFA, 0x5F, 0x5C, 0x8C, 0xC6, 0xE8, 0x2C, 0x0B, 0x54,
-                0x26, 0xA8, 0x63, 0xC1, 0x25, 0x7D, 0xB9, 0x11, 0x0D, 0x49, 0x12, 0x7F, 0x70, 0x37,
-                0x89, 0x60, 0xED, 0xF1, 0x0B, 0x00,
-            ],
-            &[
-                0xEE, 0xB3, 0xBC, 0x6F, 0x71, 0x6B, 0x9D, 0x0D, 0x19, 0xEA, 0x1C, 0x7D, 0x6A, 0x68,
-                0x72, 0x60, 0x05, 0xBE, 0x65, 0xAA, 0x31, 0x7C, 0x8C, 0x2E, 0xAD, 0x65, 0xB4, 0x1F,
-                0x90, 0x25, 0x95, 0x20, 0xB1, 0x8A, 0x73, 0x2B, 0xD1, 0x3A, 0x5F, 0x7D, 0xD1, 0x28,
-                0x30, 0x9F, 0xB9, 0x20, 0x95, 0x00,
-            ],
-        ],
-        [
-            &[
-                0xA8, 0xE1, 0x88, 0x73, 0x1F, 0x86, 0x33, 0xA5, 0x62, 0x03, 0x86, 0xB0, 0xC6, 0x21,
-                0x9E, 0xC0, 0xA1, 0x91, 0x61, 0x00, 0x8E, 0x57, 0x43, 0xCF, 0x0E, 0x0F, 0x16, 0xC3,
-                0x06, 0x92, 0x2C, 0x94, 0x69, 0xBD, 0x16, 0x09, 0x5D, 0xB7, 0x36, 0x17, 0x09, 0x87,
-                0xE3, 0x36, 0xA4, 0xBC, 0xE7, 0x00,
-            ],
-            &[
-                0x54, 0xDB, 0xC4, 0x7F, 0x24, 0x29, 0x38, 0x4B, 0xA8, 0xCE, 0x8B, 0x03, 0xC4, 0x89,
-                0x28, 0xA0, 0xDA, 0x34, 0x54, 0xCF, 0x32, 0x97, 0xDF, 0xBF, 0x2D, 0x5B, 0x44, 0xAF,
-                0x3A, 0x90, 0xFA, 0x8C, 0xF3, 0x47, 0x50, 0xC9, 0x7F, 0xF4, 0x5E, 0xAA, 0xD4, 0x4E,
-                0xA2, 0xBA, 0xD1, 0x9E, 0x19, 0x01,
-            ],
-            &[
-                0x5D, 0xC9, 0x81, 0xF8, 0x1E, 0xEF, 0x95, 0x9E, 0xEF, 0xF6, 0x61, 0xE1, 0x1A, 0xBE,
-                0xF4, 0xC1, 0x70, 0x28, 0xDF, 0x46, 0xE5, 0x5A, 0xD8, 0x9B, 0x82, 0xAF, 0x35, 0x1B,
-                0x4D, 0xBD, 0x22, 0x3C, 0xC2, 0x05, 0x92, 0x82, 0x1C, 0x0D, 0x5B, 0xBE, 0x83, 0x0E,
-                0x85, 0x9F, 0xF8, 0xC9, 0x02, 0x00,
-            ],
-            &[
-                0x42, 0xB7, 0x76, 0xCB, 0x1C, 0xE5, 0x50, 0x8F, 0x2A, 0xF2, 0x2E, 0x49, 0x74, 0x4D,
-                0xDE, 0x73, 0x88, 0xD1, 0x00, 0x11, 0x05, 0xBA, 0xD8, 0x26, 0xF2, 0x38, 0x1D, 0x77,
-                0xCA, 0xEF, 0x8F, 0x15, 0x6F, 0x64, 0x65, 0x87, 0xF7, 0x2C, 0x4F, 0xC5, 0x3F, 0xF1,
-                0x22, 0xB9, 0xA0, 0x15, 0x84, 0x00,
-            ],
-        ],
-        [
-            &[
-                0x94, 0x3B, 0x4A, 0xEE, 0x6A, 0x76, 0x9B, 0x94, 0x0C, 0xCF, 0x46, 0xEC, 0x57, 0x6C,
-                0xCE, 0xE3, 0xAF, 0x8F, 0xF2, 0x9E, 0x61, 0xA3, 0xE3, 0xD7, 0x80, 0x5A, 0x2B, 0x10,
-                0x99, 0x9F, 0x3B, 0xDC, 0x4B, 0xEE, 0xBD, 0x15, 0xE7, 0x06, 0x01, 0xFF, 0xD2, 0x2B,
-                0x92, 0x92, 0xEA, 0xB0, 0x1A, 0x00,
-            ],
-            &[
-                0x07, 0x6A, 0xA2, 0xFC, 0x5C, 0x51, 0xCB, 0xE3, 0xE4, 0x70, 0xE2, 0x13, 0xD7, 0xE9,
-                0x07, 0x1E, 0x30, 0x1A, 0xE3, 0x45, 0x56, 0xD4, 0x43, 0x85, 0x5D, 0x0F, 0x0D, 0x95,
-                0x93, 0x76, 0x84, 0x6B, 0xD1, 0x2E, 0xDC, 0xC1, 0xC6, 0x95, 0x56, 0x37, 0x08, 0x83,
-                0x71, 0x03, 0x61, 0x54, 0x9D, 0x01,
-            ],
-            &[
-                0xD4, 0x74, 0xDF, 0x8B, 0xCA, 0xD1, 0x14, 0x5D, 0xED, 0xD9, 0xF9, 0x7A, 0x79, 0x9B,
-                0x1C, 0xA7, 0x06, 0xB0, 0x72, 0x96, 0xA7, 0x12, 0xDA, 0xE2, 0x6B, 0x64, 0x71, 0xDE,
-                0x5B, 0x19, 0xC3, 0x86, 0x47, 0xC3, 0xD2, 0xCE, 0x0B, 0x78, 0xC8, 0x9E, 0x6C, 0xCC,
-                0xC0, 0x68, 0x32, 0xE9, 0x4E, 0x01,
-            ],
-            &[
-                0xC5, 0xBC, 0x84, 0x32, 0x33, 0xFC, 0xFB, 0x1F, 0xEA, 0x22, 0xBA, 0x62, 0xB2, 0xCD,
-                0xBB, 0x38, 0xE7, 0xCB, 0x71, 0xBF, 0x98, 0x78, 0x47, 0xE7, 0x12, 0xD5, 0x29, 0x70,
-                0x67, 0xD1, 0x76, 0xB7, 0x15, 0x2C, 0x8E, 0xB9, 0x95, 0x53, 0x2C, 0x9F, 0xDF, 0x40,
-                0xB3, 0x05, 0x30, 0x70, 0x8C, 0x01,
-            ],
-        ],
-        [
-            &[
-                0x18, 0xCD, 0x21, 0x40, 0x3D, 0x1F, 0xD4, 0x23, 0x78, 0xD9, 0xAE, 0x1B, 0x2C, 0x86,
-                0xE2, 0x40, 0xE2, 0xD6, 0xE5, 0x02, 0x74, 0x93, 0x4E, 0x3C, 0x25, 0x8A, 0x6F, 0xF3,
-                0x26, 0xD0, 0x21, 0x0E, 0x54, 0x53, 0x36, 0x90, 0xD6, 0xB5, 0x18, 0x8F, 0xB8, 0x52,
-                0xE2, 0x2F, 0xEA, 0x81, 0x70, 0x01,
-            ],
-            &[
-                0x03, 0x6E, 0x90, 0x20, 0x67, 0xFD, 0x96, 0x89, 0xC1, 0x0A, 0x22, 0x62, 0xBE, 0x52,
-                0x9C, 0x2F, 0xA1, 0x1D, 0x18, 0x81, 0x75, 0x48, 0x19, 0x21, 0xFD, 0x10, 0x61, 0x56,
-                0xFD, 0x9B, 0x1B, 0x5B, 0xBA, 0x39, 0x82, 0xB9, 0x44, 0x94, 0x54, 0x62, 0x16, 0xB2,
-                0x95, 0xF4, 0x6F, 0x51, 0xAC, 0x01,
-            ],
-            &[
-                0x86, 0x4C, 0x75, 0xB2, 0x4E, 0xC1, 0x60, 0xD3, 0xC5, 0xB8, 0x6D, 0x54, 0x66, 0x8A,
-                0xF5, 0x15, 0x67, 0x02, 0xF5, 0x77, 0xA7, 0x47, 0x78, 0xA2, 0x48, 0x34, 0x5C, 0xA5,
-                0x5C, 0xD9, 0x80, 0xF1, 0x2F, 0xE5, 0xAE, 0xAB, 0x42, 0x49, 0x4A, 0x3F, 0xE8, 0x9A,
-                0xF1, 0x64, 0x82, 0x18, 0x8A, 0x00,
-            ],
-            &[
-                0xF1, 0x59, 0x92, 0xAA, 0x29, 0xAB, 0x15, 0xAF, 0xCD, 0x08, 0x5D, 0x13, 0x30, 0xD0,
-                0x1F, 0x1A, 0x65, 0x5E, 0x54, 0x13, 0xE9, 0x92, 0x6E, 0x3E, 0x5E, 0x91, 0xC4, 0x64,
-                0x0A, 0xAA, 0xB0, 0x7A, 0x4A, 0xED, 0x31, 0xB2, 0x54, 0x72, 0xB8, 0x1F, 0x40, 0xFE,
-                0xA6, 0x26, 0x63, 0x65, 0x4B, 0x00,
-            ],
-        ],
-        [
-            &[
-                0x59, 0x14, 0x34, 0xA2, 0x27, 0x56, 0xDA, 0x02, 0xB5, 0xD7, 0x10, 0xB3, 0x46, 0x8F,
-                0xA9, 0x42, 0xBD, 0x52, 0x81, 0x68, 0x97, 0x42, 0x85, 0x56, 0x85, 0x36, 0x17, 0x3B,
-                0x0F, 0x62, 0x70, 0x91, 0xA1, 0xD0, 0x26, 0x1F, 0x0C, 0xC7, 0xE4, 0x03, 0x02, 0x88,
-                0x46, 0x9D, 0xF5, 0xED, 0xF6, 0x00,
-            ],
-            &[
-                0xE6, 0xE2, 0x0E, 0x75, 0x2A, 0x64, 0x4C, 0xB3, 0x3F, 0x5B, 0x45, 0xDF, 0xFE, 0x86,
-                0xDC, 0xB1, 0x7C, 0xF7, 0x74, 0xDB, 0x39, 0x49, 0x1D, 0xBD, 0x02, 0x2C, 0x8B, 0x10,
-                0xF3, 0x34, 0xF5, 0x62, 0x66, 0x5C, 0x3C, 0xB6, 0x67, 0x56, 0x9C, 0xAB, 0x1E, 0xC6,
-                0xC2, 0x22, 0x98, 0xE0, 0x21, 0x01,
-            ],
-            &[
-                0xCF, 0xAD, 0xAB, 0x3D, 0xDB, 0xA8, 0xA2, 0x57, 0x29, 0x54, 0x7B, 0xCB, 0x75, 0x91,
-                0x5D, 0xCD, 0xA7, 0xBF, 0x87, 0x82, 0x35, 0xFF, 0x6F, 0xED, 0xAF, 0xD0, 0xD6, 0xD2,
-                0xA9, 0xCC, 0xAE, 0xDF, 0x69, 0x62, 0x8E, 0xD2, 0x1C, 0xC4, 0x63, 0xA5, 0xB0, 0xD8,
-                0x8C, 0xF8, 0x8B, 0x7F, 0x2B, 0x00,
-            ],
-            &[
-                0xBF, 0x4A, 0xD5, 0x20, 0x13, 0x9D, 0x29, 0x54, 0x64, 0x89, 0x21, 0x1C, 0x35, 0x0D,
-                0xA1, 0xA6, 0x3F, 0x44, 0x81, 0x48, 0x68, 0xE0, 0xCA, 0xCD, 0xC3, 0x75, 0x96, 0x5C,
-                0x17, 0xDE, 0xBA, 0xE4, 0xBF, 0x1E, 0x98, 0x43, 0x6D, 0xA3, 0xE4, 0x51, 0xCA, 0xBB,
-                0xC6, 0x28, 0x94, 0x63, 0x83, 0x01,
-            ],
-        ],
-        [
-            &[
-                0x52, 0x83, 0x33, 0xD6, 0xA1, 0x78, 0x4A, 0xFB, 0x06, 0xCE, 0x6F, 0x44, 0x79, 0x39,
-                0xFE, 0xB8, 0x83, 0x85, 0xA6, 0xB6, 0x3F, 0x34, 0x4E, 0x23, 0x69, 0x1C, 0xF5, 0x92,
-                0xA3, 0xB1, 0x4B, 0x0D, 0xE3, 0xB1, 0x52, 0x10, 0x15, 0x10, 0x74, 0xF8, 0x1F, 0xA2,
-                0xB8, 0xBF, 0x2A, 0x10, 0xD5, 0x00,
-            ],
-            &[
-                0xE4, 0x71, 0x24, 0x80, 0xA7, 0x70, 0x52, 0xE2, 0x70, 0x65, 0x41, 0xBF, 0x44, 0xF6,
-                0xB7, 0xE1, 0x67, 0xEB, 0x09, 0x1B, 0x29, 0xC3, 0xD1, 0x70, 0x3A, 0x8E, 0x62, 0x13,
-                0x9A, 0x9F, 0x95, 0x94, 0x27, 0x34, 0xCC, 0x2A, 0x71, 0x2E, 0x06, 0xCD, 0x47, 0x49,
-                0x2A, 0x2D, 0x49, 0x4F, 0x82, 0x00,
-            ],
-            &[
-                0x8F, 0x9B, 0x29, 0x85, 0xF5, 0x27, 0x57, 0xB6, 0xD4, 0xBE, 0x7B, 0xD3, 0x09, 0x99,
-                0x67, 0xE0, 0xA8, 0xF4, 0xBE, 0xA8, 0x58, 0xDB, 0x5D, 0x7E, 0xBD, 0x04, 0x41, 0xFE,
-                0x4F, 0xE7, 0x63, 0x96, 0x96, 0xF5, 0xEA, 0x65, 0x16, 0x2B, 0xC8, 0xB6, 0x42, 0xD8,
-                0x64, 0x65, 0x38, 0x0C, 0x8C, 0x00,
-            ],
-            &[
-                0xDB, 0x08, 0x8A, 0xA2, 0x52, 0x86, 0xDD, 0xF2, 0x61, 0xD3, 0x14, 0x2B, 0x0F, 0x81,
-                0xA8, 0xCC, 0x44, 0xC3, 0xA4, 0x10, 0xC4, 0x91, 0xD9, 0xF6, 0xE7, 0x9C, 0x89, 0x46,
-                0xF0, 0x6F, 0xCB, 0x1A, 0x84, 0x90, 0x26, 0x0A, 0x73, 0x61, 0xFA, 0x15, 0x42, 0xD7,
-                0x52, 0x88, 0x57, 0x11, 0x12, 0x00,
-            ],
-        ],
-        [
-            &[
-                0x7A, 0x5F, 0x7F, 0x73, 0x33, 0xBE, 0x98, 0x64, 0xE2, 0x9F, 0x94, 0xFE, 0x77, 0x9A,
-                0x32, 0x14, 0x42, 0xBA, 0xF8, 0x80, 0x93, 0xB1, 0xDB, 0x75, 0xD3, 0x02, 0x05, 0xCB,
-                0xE5, 0x97, 0x0C, 0xB5, 0x71, 0xD5, 0xD6, 0x09, 0x94, 0xDF, 0xBE, 0x44, 0x93, 0x88,
-                0xB9, 0xF9, 0x5C, 0x21, 0x5A, 0x01,
-            ],
-            &[
-                0x85, 0x68, 0x5D, 0x4E, 0x6C, 0x0F, 0x88, 0xB2, 0x59, 0xF1, 0xFD, 0x2B, 0xD2, 0x43,
-                0x71, 0xB5, 0x5B, 0x3D, 0x3F, 0x0D, 0x7C, 0xC7, 0x98, 0x6C, 0xBF, 0x23, 0xE1, 0x96,
-                0xF6, 0x31, 0x3B, 0x36, 0xEC, 0x69, 0x2A, 0xBC, 0x24, 0xC8, 0x76, 0xDE, 0xBA, 0x43,
-                0x3B, 0x76, 0xFA, 0xE3, 0x95, 0x00,
-            ],
-            &[
-                0x5E, 0x76, 0x0B, 0x14, 0x76, 0xD7, 0x43, 0x25, 0xC5, 0x5D, 0xDF, 0xC4, 0x73, 0x5B,
-                0x13, 0x26, 0x2B, 0x00, 0x94, 0xAC, 0xCA, 0x84, 0xFA, 0x0E, 0x61, 0xAC, 0x9D, 0x7B,
-                0x76, 0xF3, 0x07, 0xCB, 0x3D, 0xE8, 0xB3, 0x23, 0xF7, 0x08, 0x0C, 0xE7, 0x4A, 0x2E,
-                0xBB, 0xCD, 0x4A, 0x78, 0xA6, 0x00,
-            ],
-            &[
-                0xED, 0xC6, 0x2F, 0x9B, 0xAB, 0x6E, 0x53, 0x9D, 0xBB, 0x68, 0x87, 0xEB, 0x59, 0x8B,
-                0x27, 0xD3, 0xE3, 0x7B, 0x5D, 0x28, 0x53, 0xE0, 0xCA, 0x0C, 0xA8, 0x54, 0xCE, 0xCA,
-                0x4B, 0xB5, 0x55, 0x0D, 0x12, 0xB7, 0x62, 0x92, 0xDA, 0x46, 0x99, 0x7C, 0xD8, 0xF0,
-                0x1C, 0x10, 0x80, 0x82, 0x1A, 0x00,
-            ],
-        ],
-        [
-            &[
-                0x83, 0xF5, 0x23, 0xC8, 0x10, 0x2D, 0xEB, 0x5C, 0x99, 0xCA, 0x68, 0xE6, 0xAF, 0xBD,
-                0x5D, 0x7E, 0x27, 0x8E, 0x3F, 0x7D, 0x20, 0xBC, 0x6A, 0xF5, 0xC3, 0x82, 0x6D, 0x20,
-                0xB2, 0x0A, 0x1B, 0x35, 0x54, 0x3C, 0x84, 0x07, 0x96, 0xEC, 0x95, 0xE8, 0x94, 0x99,
-                0x35, 0x4F, 0x11, 0xA0, 0x51, 0x00,
-            ],
-            &[
-                0x43, 0xE5, 0x4B, 0xC6, 0xC8, 0x14, 0xA3, 0x25, 0xB4, 0xBE, 0x16, 0x1F, 0x58, 0xBB,
-                0x1D, 0xEB, 0x3E, 0x6B, 0xDF, 0xCA, 0xB5, 0x03, 0x90, 0x12, 0xAD, 0xFC, 0x03, 0x3E,
-                0xC9, 0x44, 0xB1, 0x23, 0x16, 0x1E, 0x30, 0x64, 0x66, 0xC6, 0xCA, 0xA5, 0xD5, 0xF3,
-                0x14, 0x9B, 0x0F, 0x0F, 0x68, 0x00,
-            ],
-            &[
-                0x20, 0x0D, 0xB4, 0xC6, 0x65, 0x40, 0x0B, 0x69, 0x67, 0x6B, 0x50, 0x69, 0x56, 0xE0,
-                0x79, 0x06, 0x05, 0x4E, 0x85, 0x3B, 0x1C, 0x27, 0x2B, 0x2E, 0xCA, 0xD0, 0x9E, 0xA0,
-                0x26, 0x20, 0x4A, 0xCA, 0x97, 0x4F, 0x6B, 0x39, 0xEC, 0xE0, 0xD3, 0xA4, 0xAE, 0xBE,
-                0xB4, 0x75, 0x4F, 0xC0, 0x94, 0x01,
-            ],
-            &[
-                0x64, 0xE2, 0x40, 0x68, 0xDD, 0xFA, 0x89, 0x19, 0x26, 0xF7, 0xB4, 0x1E, 0xCB, 0x92,
-                0x7B, 0xF8, 0xD7, 0x69, 0x30, 0xAE, 0x0B, 0x13, 0x42, 0xBE, 0x58, 0x56, 0xFC, 0x9C,
-                0x91, 0x48, 0xC5, 0x8B, 0x19, 0xF1, 0x7F, 0xF6, 0x45, 0x87, 0x8B, 0xDA, 0xB5, 0xA7,
-                0x0C, 0xC0, 0xD3, 0x54, 0x75, 0x00,
-            ],
-        ],
-        [
-            &[
-                0x7F, 0xC9, 0xA9, 0xC8, 0xFA, 0x36, 0x5E, 0xD1, 0x4E, 0xE5, 0xF8, 0xF0, 0xE6, 0xDF,
-                0x5C, 0xBE, 0x23, 0x91, 0x8B, 0xC9, 0xF8, 0xF5, 0x4A, 0xF1, 0x9D, 0xAA, 0x3C, 0x1F,
-                0xB7, 0xA6, 0x2B, 0x48, 0xB4, 0x62, 0x21, 0x08, 0x62, 0x9A, 0xC6, 0xEB, 0x6E, 0x2D,
-                0x50, 0x16, 0x58, 0xB7, 0xD1, 0x00,
-            ],
-            &[
-                0xED, 0xA2, 0x78, 0x08, 0xEE, 0x1B, 0xAB, 0x42, 0x90, 0xFE, 0x33, 0x4A, 0xC4, 0x15,
-                0xF9, 0xC8, 0xA0, 0xD5, 0xCC, 0x12, 0x5F, 0x97, 0x3E, 0x48, 0x4E, 0x87, 0x02, 0x14,
-                0x4F, 0x83, 0x29, 0xFF, 0x83, 0x78, 0x11, 0xB6, 0x7A, 0x83, 0x14, 0x0F, 0x35, 0xEE,
-                0x74, 0xBC, 0xFB, 0x80, 0x45, 0x00,
-            ],
-            &[
-                0x7C, 0x54, 0x7D, 0x99, 0x79, 0xE8, 0x5E, 0x7B, 0x6C, 0xDE, 0x5A, 0x1B, 0x0B, 0x1D,
-                0x00, 0x20, 0xC4, 0xBF, 0x2B, 0x3F, 0xCF, 0xF3, 0x90, 0xC7, 0x8B, 0xC8, 0xAF, 0x2B,
-                0xCE, 0xB0, 0x57, 0x28, 0x3B, 0x20, 0xD5, 0x19, 0xB6, 0x80, 0x76, 0x5B, 0xE2, 0x49,
-                0xC9, 0x95, 0x12, 0x52, 0x16, 0x00,
-            ],
-            &[
-                0xBB, 0xC5, 0x4F, 0x7B, 0x6D, 0x72, 0x9C, 0xB5, 0x2E, 0x68, 0x1B, 0x00, 0x36, 0x1A,
-                0xB2, 0xA3, 0xD7, 0x84, 0xC6, 0x99, 0x62, 0xF7, 0x8C, 0x8F, 0x2B, 0x9B, 0xB0, 0x35,
-                0xE1, 0xAE, 0x05, 0x9B, 0x9F, 0x6B, 0xE2, 0x8E, 0xBB, 0xB6, 0x18, 0xC7, 0x62, 0x55,
-                0xA3, 0x2F, 0x49, 0x76, 0x1D, 0x00,
-            ],
-        ],
-        [
-            &[
-                0x48, 0x4E, 0x1B, 0xC2, 0x11, 0xB9, 0x07, 0xDD, 0x9E, 0x38, 0x3A, 0xAE, 0x75, 0xC9,
-                0x79, 0xAB, 0xBF, 0x26, 0xC4, 0x11, 0xEB, 0xEE, 0x14, 0x35, 0x68, 0x2F, 0x86, 0x55,
-                0xC1, 0xBA, 0x57, 0xA1, 0xB3, 0x94, 0xBF, 0x78, 0x75, 0x62, 0x43, 0x42, 0xDB, 0xC3,
-                0x9D, 0xE2, 0xA8, 0xC3, 0x0B, 0x01,
-            ],
-            &[
-                0xDB, 0x72, 0xE1, 0x72, 0x7B, 0xB2, 0x65, 0xCC, 0x6C, 0x07, 0xAD, 0xDA, 0x9A, 0x39,
-                0x10, 0x5A, 0xE4, 0x3A, 0xA5, 0x5B, 0x5B, 0x84, 0xDD, 0xF9, 0xC0, 0xEC, 0x65, 0xA7,
-                0xAF, 0x29, 0xF6, 0xDD, 0x36, 0x5E, 0xB2, 0x71, 0x45, 0x76, 0xC1, 0x25, 0xB6, 0x3A,
-                0x9B, 0x06, 0x61, 0xD3, 0xD0, 0x00,
-            ],
-            &[
-                0x18, 0xDB, 0xEE, 0x5B, 0xE4, 0xA6, 0x51, 0xF5, 0xF2, 0x8B, 0xF3, 0x39, 0xD8, 0xB5,
-                0xDD, 0x77, 0xB0, 0x0F, 0x84, 0x15, 0xD6, 0x49, 0x00, 0x7E, 0x12, 0x0F, 0x68, 0x7E,
-                0x81, 0xEC, 0xED, 0xB8, 0x2D, 0x5E, 0xDD, 0xC9, 0xF9, 0x09, 0x55, 0xC7, 0x2D, 0xD8,
-                0x9E, 0x9C, 0x4B, 0x2A, 0x16, 0x01,
-            ],
-            &[
-                0xBB, 0xC8, 0x21, 0x79, 0xF0, 0xDF, 0xCC, 0x5B, 0x65, 0x32, 0xDB, 0x19, 0xE1, 0x47,
-                0x7B, 0x53, 0x81, 0xCD, 0xA8, 0x10, 0x4F, 0x8E, 0x99, 0xC8, 0x1F, 0x0E, 0x98, 0xF3,
-                0x4E, 0xEB, 0xCA, 0xD6, 0x53, 0x60, 0x23, 0x15, 0x5B, 0x8E, 0x4D, 0x86, 0x24, 0xC7,
-                0xCB, 0x71, 0xC6, 0x0F, 0xB9, 0x00,
-            ],
-        ],
-        [
-            &[
-                0xDC, 0x90, 0x1C, 0x48, 0xD8, 0x12, 0x66, 0x2B, 0xDD, 0x16, 0xD4, 0x3B, 0x0F, 0x05,
-                0xA2, 0x8A, 0x84, 0xBF, 0x56, 0x2B, 0xAC, 0x78, 0x52, 0x65, 0xB8, 0x10, 0x89, 0xCC,
-                0x12, 0xBD, 0x17, 0x00, 0x72, 0xD8, 0x60, 0x00, 0x21, 0x78, 0xE1, 0x47, 0x5C, 0x8D,
-                0xED, 0xDC, 0x7D, 0x69, 0x47, 0x00,
-            ],
-            &[
-                0x5E, 0xE5, 0xC4, 0x76, 0x55, 0x79, 0xC0, 0x74, 0x99, 0x83, 0x7C, 0x1E, 0x3B, 0x0D,
-                0x38, 0x84, 0xBF, 0xA3, 0x52, 0xDF, 0x40, 0xD5, 0x1A, 0xB2, 0x91, 0xAD, 0xB3, 0xBB,
-                0x38, 0x58, 0x47, 0xE0, 0x33, 0xCA, 0x23, 0xB2, 0xF0, 0x53, 0xC4, 0x11, 0xE3, 0xAB,
-                0x4B, 0xE2, 0xA4, 0x0F, 0x26, 0x00,
-            ],
-            &[
-                0xF7, 0x1B, 0x03, 0x93, 0x42, 0x18, 0x98, 0xF4, 0x8E, 0x3C, 0x6D, 0xA9, 0x57, 0xFB,
-                0x35, 0xA1, 0x9A, 0x2B, 0x99, 0x22, 0x3F, 0x83, 0x98, 0x30, 0x1A, 0xDB, 0x3C, 0xC3,
-                0xD9, 0x25, 0x13, 0xB1, 0xAA, 0xAD, 0x26, 0x6A, 0xC5, 0xC4, 0x81, 0x27, 0xC2, 0xDF,
-                0x13, 0x6F, 0x6B, 0x34, 0x6A, 0x00,
-            ],
-            &[
-                0x29, 0xF4, 0xDD, 0xAC, 0xD7, 0x3F, 0x7E, 0xD0, 0xE9, 0xF2, 0x83, 0xE8, 0xB6, 0x9D,
-                0x3B, 0x60, 0x18, 0x9F, 0x40, 0xB8, 0x27, 0x7E, 0xF0, 0x6C, 0xBF, 0xBB, 0xCE, 0x15,
-                0x27, 0xA9, 0x8A, 0x88, 0x3B, 0xF5, 0x94, 0x31, 0xC8, 0xEC, 0xEA, 0xC7, 0x8F, 0x2A,
-                0x1D, 0x71, 0x54, 0x67, 0x5A, 0x00,
-            ],
-        ],
-    ];
-}
@@ -1,1332 +0,0 @@
-// Copyright (c) 2022 Espresso Systems (espressosys.com)
-// This file is part of the Jellyfish library.
-
-// You should have received a copy of the MIT License
-// along with the Jellyfish library. If not, see <https://mit-license.org/>.
-
-use crate::rescue::{RescueParameter, ROUNDS, STATE_SIZE};
-use ark_ed_on_bls12_377::Fq;
-
-// the constants in this file are generated with
-// https://github.com/EspressoSystems/Marvellous/blob/fcd4c41672f485ac2f62526bc87a16789d4d0459/rescue377.sage
-
-// q377 = 0x12ab655e9a2ca55660b44d1e5c37b00159aa76fed00000010a11800000000001
-// rescue377 = Rescue(128, q377, 4, 5)
-
-impl RescueParameter for Fq {
-    // rescue377.alpha
-    const A: u64 = 11;
-    // 1/11 % (q371-1)
-    // A_INV = 0xf466a36210d417537d9565ea88aa746ec45a72d92e8ba2f655422e8ba2e8ba3
-    //       = 6909105067714121256203584040821265343853008546944234041037918282114243922851
-    const A_INV: &'static [u64] = &[
-        0x655422e8ba2e8ba3,
-        0xec45a72d92e8ba2f,
-        0x37d9565ea88aa746,
-        0xf466a36210d4175,
-    ];
-
-    // The following constants are from the output of
-    // rescue377.MDS
-    // MDS[0,0]= "8444461749428370424248824938781546531375899335154063827935233455917295859137"
-    // MDS[0,1]= "8444461749428370424248824938781546531375899335154063827935233454652656409921"
-    // MDS[0,2]= "8444461749428370424248824938781546531375899335154063827935219960949733275201"
-    // MDS[0,3]= "8444461749428370424248824938781546531375899335154063827791525545136070306881"
-    // MDS[1,0]= "118778440"
-    // MDS[1,1]= "1324860118296"
-    // MDS[1,2]= "14136260080508280"
-    // MDS[1,3]= "150537006982534008760"
-    // MDS[2,0]= "8444461749428370424248824938781546531375899335154063827935233455917403829351"
-    // MDS[2,1]= "8444461749428370424248824938781546531375899335154063827935233455857182925531"
-    // MDS[2,2]= "8444461749428370424248824938781546531375899335154063827935232813357514551187"
-    // MDS[2,3]= "8444461749428370424248824938781546531375899335154063827928390863423559055971"
-    // MDS[3,0]= "11155"
-    // MDS[3,1]= "119024335"
-    // MDS[3,2]= "1267490143415"
-    // MDS[3,3]= "13496292655106471"
-    // MDS matrix in little-endian 32 bytes elements
-    const MDS_LE: [[&'static [u8]; STATE_SIZE]; STATE_SIZE] = [
-        [
-            &[
-                0xC1, 0xF5, 0x3D, 0xF9, 0xFF, 0x7F, 0x11, 0x0A, 0x01, 0x00, 0x00, 0xD0, 0xFE, 0x76,
-                0xAA, 0x59, 0x01, 0xB0, 0x37, 0x5C, 0x1E, 0x4D, 0xB4, 0x60, 0x56, 0xA5, 0x2C, 0x9A,
-                0x5E, 0x65, 0xAB, 0x12,
-            ],
-            &[
-                0x41, 0x5D, 0xDB, 0x86, 0xD9, 0x7E, 0x11, 0x0A, 0x01, 0x00, 0x00, 0xD0, 0xFE, 0x76,
-                0xAA, 0x59, 0x01, 0xB0, 0x37, 0x5C, 0x1E, 0x4D, 0xB4, 0x60, 0x56, 0xA5, 0x2C, 0x9A,
-                0x5E, 0x65, 0xAB, 0x12,
-            ],
-            &[
-                0x41, 0x36, 0x6B, 0xFD, 0x65, 0x8E, 0xE1, 0x09, 0x01, 0x00, 0x00, 0xD0, 0xFE, 0x76,
-                0xAA, 0x59, 0x01, 0xB0, 0x37, 0x5C, 0x1E, 0x4D, 0xB4, 0x60, 0x56, 0xA5, 0x2C, 0x9A,
-                0x5E, 0x65, 0xAB, 0x12,
-            ],
-            &[
-                0x41, 0xAC, 0xC3, 0xA0, 0x8A, 0x70, 0xB8, 0x3F, 0xF9, 0xFF, 0xFF, 0xCF, 0xFE, 0x76,
-                0xAA, 0x59, 0x01, 0xB0, 0x37, 0x5C, 0x1E, 0x4D, 0xB4, 0x60, 0x56, 0xA5, 0x2C, 0x9A,
-                0x5E, 0x65, 0xAB, 0x12,
-            ],
-        ],
-        [
-            &[
-                0x48, 0x6A, 0x14, 0x07, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-                0x00, 0x00, 0x00, 0x00,
-            ],
-            &[
-                0x18, 0x15, 0xD1, 0x77, 0x34, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-                0x00, 0x00, 0x00, 0x00,
-            ],
-            &[
-                0x78, 0x1D, 0x8D, 0x88, 0xDA, 0x38, 0x32, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-                0x00, 0x00, 0x00, 0x00,
-            ],
-            &[
-                0xB8, 0x0F, 0x70, 0x78, 0x45, 0xE2, 0x1E, 0x29, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00,
-                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-                0x00, 0x00, 0x00, 0x00,
-            ],
-        ],
-        [
-            &[
-                0x67, 0x74, 0xAD, 0xFF, 0xFF, 0x7F, 0x11, 0x0A, 0x01, 0x00, 0x00, 0xD0, 0xFE, 0x76,
-                0xAA, 0x59, 0x01, 0xB0, 0x37, 0x5C, 0x1E, 0x4D, 0xB4, 0x60, 0x56, 0xA5, 0x2C, 0x9A,
-                0x5E, 0x65, 0xAB, 0x12,
-            ],
-            &[
-                0xDB, 0x62, 0x3B, 0xFA, 0xF1, 0x7F, 0x11, 0x0A, 0x01, 0x00, 0x00, 0xD0, 0xFE, 0x76,
-                0xAA, 0x59, 0x01, 0xB0, 0x37, 0x5C, 0x1E, 0x4D, 0xB4, 0x60, 0x56, 0xA5, 0x2C, 0x9A,
-                0x5E, 0x65, 0xAB, 0x12,
-            ],
-            &[
-                0x93, 0xEF, 0xBA, 0x5D, 0x98, 0x37, 0x0F, 0x0A, 0x01, 0x00, 0x00, 0xD0, 0xFE, 0x76,
-                0xAA, 0x59, 0x01, 0xB0, 0x37, 0x5C, 0x1E, 0x4D, 0xB4, 0x60, 0x56, 0xA5, 0x2C, 0x9A,
-                0x5E, 0x65, 0xAB, 0x12,
-            ],
-            &[
-                0x63, 0x3A, 0x4E, 0x65, 0x61, 0xBA, 0x1B, 0xAB, 0x00, 0x00, 0x00, 0xD0, 0xFE, 0x76,
-                0xAA, 0x59, 0x01, 0xB0, 0x37, 0x5C, 0x1E, 0x4D, 0xB4, 0x60, 0x56, 0xA5, 0x2C, 0x9A,
-                0x5E, 0x65, 0xAB, 0x12,
-            ],
-        ],
-        [
-            &[
-                0x93, 0x2B, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-                0x00, 0x00, 0x00, 0x00,
-            ],
-            &[
-                0xCF, 0x2A, 0x18, 0x07, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-                0x00, 0x00, 0x00, 0x00,
-            ],
-            &[
-                0xB7, 0xBC, 0x4C, 0x1C, 0x27, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-                0x00, 0x00, 0x00, 0x00,
-            ],
-            &[
-                0xA7, 0x09, 0x7E, 0x81, 0xCE, 0xF2, 0x2F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-                0x00, 0x00, 0x00, 0x00,
-            ],
-        ],
-    ];
-
-    // The following constants are from the output of
-    // rescue377.initial_constant
-    // a.k.a. INIT_VEC
-    // INIT_VEC[0]=
-    // "7195166260762508599252723804118999247617987830477524724582535753522743429902"
-    // INIT_VEC[1]=
-    // "157568923057870085185801131226812508154648823377393772750758002166934615414"
-    // INIT_VEC[2]=
-    // "8368974368714179058560102222209821528142740294286535716971241458054331447299"
-    // INIT_VEC[3]=
-    // "2193144866846120881109650827139290126159606618067745139279401080525317213903"
-    //
-    const INIT_VEC_LE: [&'static [u8]; STATE_SIZE] = [
-        &[
-            0x0E, 0x4B, 0x21, 0x08, 0x01, 0x45, 0xB1, 0xBE, 0x83, 0x49, 0x2B, 0xCE, 0x0B, 0x49,
-            0xB4, 0xFA, 0xDF, 0x93, 0xE2, 0x19, 0xBC, 0x27, 0x3F, 0xA3, 0x71, 0x5B, 0x13, 0x7F,
-            0xDD, 0x51, 0xE8, 0x0F,
-        ],
-        &[
-            0x76, 0x35, 0x95, 0xBD, 0x7D, 0xC7, 0x99, 0x9C, 0x0E, 0x54, 0xEC, 0xCF, 0x4D, 0x6D,
-            0x74, 0x2A, 0x94, 0x7F, 0xFF, 0x41, 0xDD, 0xB3, 0x4E, 0x06, 0x3A, 0xBD, 0x85, 0x2F,
-            0x4C, 0x2E, 0x59, 0x00,
-        ],
-        &[
-            0x03, 0x38, 0x61, 0xEB, 0x1C, 0x39, 0xE2, 0x72, 0x0C, 0xCA, 0xB6, 0xDD, 0xFA, 0xAD,
-            0xF9, 0x09, 0xDA, 0x88, 0x59, 0x13, 0xA7, 0x98, 0xB1, 0x12, 0x00, 0x20, 0xA7, 0x08,
-            0xF0, 0xAB, 0x80, 0x12,
-        ],
-        &[
-            0xCF, 0x0E, 0xB8, 0xED, 0x6C, 0xB3, 0x1D, 0xA8, 0x53, 0x05, 0x9C, 0x14, 0x7D, 0x1B,
-            0x65, 0xD7, 0x34, 0x0C, 0x95, 0xCA, 0x4C, 0x43, 0xA2, 0xE3, 0xF4, 0x16, 0x67, 0x4B,
-            0xA8, 0x46, 0xD9, 0x04,
-        ],
-    ];
-
-    // The following constants are from the output of
-    // sage: rescue381.constants_matrix
-    // a.k.a., KEY_SCHED_MATRIX
-    // KEY_SCHED_MATRIX[0,0]=
-    // "7706576124239544837371600760424887325666871237646566433582815095059014199792"
-    // KEY_SCHED_MATRIX[0,1]=
-    // "5866850164576261478222180177187623784425095431438009129648961690750738724033"
-    // KEY_SCHED_MATRIX[0,2]=
-    // "5967963618215854522411152750142075651481461240008734293775989630299855162504"
-    // KEY_SCHED_MATRIX[0,3]=
-    // "4846413119387162211456448903358698082073272134778198460673863015758257657569"
-    // KEY_SCHED_MATRIX[1,0]=
-    // "1245960362769537792640019594237304364405740843817026904000374334904749831719"
-    // KEY_SCHED_MATRIX[1,1]=
-    // "7953944257726793928692708838487408421612439320876245321369789667138797299913"
-    // KEY_SCHED_MATRIX[1,2]=
-    // "4754797182399066897135937139456358243903942729099936743831234828037073492871"
-    // KEY_SCHED_MATRIX[1,3]=
-    // "7499009130375379463556804089865923760153528008940973027774256635004918389563"
-    // KEY_SCHED_MATRIX[2,0]=
-    // "6473093830626604929963072604064598990230085260662177396257715740435130466686"
-    // KEY_SCHED_MATRIX[2,1]=
-    // "4390337253131514942705827559491664950984265110689583880841633000427121081888"
-    // KEY_SCHED_MATRIX[2,2]=
-    // "3522051946840768458577251601407876587632962453320814154152205148191545374550"
-    // KEY_SCHED_MATRIX[2,3]=
-    // "6319999439708992933292400869565273518174083699772881386205747820203056621547"
-    // KEY_SCHED_MATRIX[3,0]=
-    // "5289326583391743443615481307619780473919202444935065353437083634733980820257"
-    // KEY_SCHED_MATRIX[3,1]=
-    // "6938831675933050844113924302108891647008288148791676051457678992328784482036"
-    // KEY_SCHED_MATRIX[3,2]=
-    // "1512276495893019861517996168661692331234716309646677384152244252805703243785"
-    // KEY_SCHED_MATRIX[3,3]=
-    // "1059856104783947562758392666568158542266706238365295453209304533860586199352"
-    //
-
-    // The following constants are from the output of
-    // sage: rescue381.constants_constant
-    // a.k.a., KEY_SCHED_VEC
-    // KEY_SCHED_VEC[0]=
-    // "6719983214629867114203683304402864967396584522104316535218975820603408161605"
-    // KEY_SCHED_VEC[1]=
-    // "1631001820510975681976157041507139599858468300000865485702360346888662886649"
-    // KEY_SCHED_VEC[2]=
-    // "6512151801847166617260532883979631278806942338258734789784404511769472570827"
-    // KEY_SCHED_VEC[3]=
-    // "396144897873338732377306209560320349606829120111196081730035080973497562014"
-    //
-
-    // The key injection constants are precomputed computed as
-    // 1. KEY_INJECTION[-1] = INIT_VECTOR
-    // 2. for i in [0 .. 2 * ROUNDS]
-    // 3. KEY_INJECTION[i] = KEY_SCHED_MATRIX * KEY_INJECTION[ i - 1] +
-    // KEY_SCHED_VEC
-    // where the INIT_VECTOR, KEY_SCHED_MATRIX and KEY_INJECTION are defined above
-    // Those constants can be obtained via
-    //   key_injection(init_vec_377, key_sched_matrix_377, key_sched_vec_377, q377)
-    // from rescue377.sage
-    const KEY_INJECTION_LE: [[&'static [u8]; 4]; 2 * ROUNDS] = [
-        [
-            &[
-                0x83, 0x11, 0x89, 0xE1, 0x62, 0x52, 0xD6, 0x02, 0xCD, 0xC5, 0xA2, 0x95, 0xB2, 0xE3,
-                0xBA, 0xE3, 0xD7, 0xA6, 0xCA, 0x72, 0xFC, 0x0A, 0xB0, 0x9B, 0x5D, 0x2F, 0xC8, 0x58,
-                0x17, 0x6A, 0xD7, 0x04,
-            ],
-            &[
-                0x46, 0x01, 0x04, 0xE4, 0x52, 0x0F, 0x2F, 0x2E, 0x82, 0x4D, 0x67, 0xBA, 0x64, 0x73,
-                0xD5, 0x3C, 0x84, 0xDA, 0x8D, 0xF6, 0x37, 0x59, 0xBD, 0x1C, 0x1B, 0x30, 0x91, 0x7F,
-                0x93, 0x33, 0x1F, 0x06,
-            ],
-            &[
-                0x31, 0xE6, 0xE4, 0x00, 0x6B, 0xF1, 0xF8, 0xD5, 0x1D, 0x1F, 0xBE, 0x0C, 0x1C, 0xBB,
-                0x4F, 0x0B, 0x1E, 0x68, 0xE2, 0xAB, 0xF5, 0x9B, 0xF0, 0x41, 0xF6, 0x3A, 0x18, 0x24,
-                0x8A, 0x72, 0x46, 0x10,
-            ],
-            &[
-                0xD0, 0x82, 0x92, 0xB1, 0x05, 0x84, 0xEB, 0xFE, 0x82, 0x48, 0xD9, 0x50, 0x27, 0x85,
-                0x80, 0xA5, 0xCC, 0x65, 0xF6, 0x4D, 0xE1, 0x50, 0x46, 0x67, 0x9B, 0x6F, 0xD4, 0x23,
-                0x67, 0x36, 0x6C, 0x02,
-            ],
-        ],
-        [
-            &[
-                0x8F, 0xBF, 0x4A, 0x1B, 0x84, 0xB3, 0x99, 0x92, 0x96, 0x9C, 0x27, 0x45, 0xBE, 0x4D,
-                0x70, 0x52, 0xBE, 0x1C, 0x6D, 0xB6, 0x65, 0x15, 0xB8, 0x6D, 0x57, 0x30, 0x77, 0xCF,
-                0xFF, 0xDC, 0x41, 0x03,
-            ],
-            &[
-                0xAF, 0x31, 0xD7, 0x7D, 0xD6, 0x9C, 0x59, 0xDA, 0xEF, 0x2A, 0x80, 0x15, 0x69, 0x1D,
-                0x8A, 0xBC, 0xF1, 0x16, 0xB2, 0x2D, 0x68, 0xF1, 0x4F, 0x00, 0x4A, 0xBF, 0xEC, 0x87,
-                0xB9, 0x63, 0x5A, 0x07,
-            ],
-            &[
-                0x89, 0x2E, 0xF6, 0x0B, 0x7A, 0x35, 0x0A, 0x1F, 0x5A, 0xB4, 0x6F, 0x6C, 0x35, 0x60,
-                0xC7, 0x11, 0x14, 0x68, 0x78, 0x7D, 0x3C, 0x3C, 0x81, 0x8C, 0x45, 0xA3, 0x3E, 0x46,
-                0x7F, 0xA5, 0x3A, 0x10,
-            ],
-            &[
-                0x31, 0x9E, 0x76, 0x79, 0xE2, 0x78, 0x72, 0x0D, 0x9A, 0xC7, 0x89, 0x7D, 0x35, 0xDC,
-                0xA5, 0xED, 0x8B, 0x68, 0x23, 0xC1, 0x76, 0xBB, 0xBE, 0xCE, 0xF6, 0x3E, 0x60, 0x3E,
-                0x9F, 0x70, 0x7C, 0x09,
-            ],
-        ],
-        [
-            &[
-                0x5E, 0x29, 0x53, 0x52, 0x9D, 0x07, 0xF0, 0xFD, 0xB4, 0xF1, 0x0D, 0x80, 0x13, 0x4B,
-                0x80, 0x4A, 0xD5, 0x5F, 0x87, 0xCD, 0x63, 0xC7, 0x8A, 0x4F, 0xB6, 0x50, 0x11, 0x0F,
-                0x13, 0x7E, 0x13, 0x0D,
-            ],
-            &[
-                0xFF, 0xE0, 0x88, 0xD5, 0xA6, 0xD5, 0x76, 0x52, 0x87, 0xCB, 0xD7, 0x58, 0x55, 0x7C,
-                0x03, 0x8F, 0xFA, 0x48, 0x65, 0x53, 0x3C, 0xE8, 0xA9, 0xB6, 0x07, 0x34, 0x65, 0x13,
-                0x7B, 0x55, 0xB1, 0x06,
-            ],
-            &[
-                0x72, 0x3B, 0xA0, 0xF1, 0xCF, 0xBC, 0x3D, 0xEA, 0x77, 0xF7, 0xEC, 0x24, 0x62, 0x2E,
-                0x5F, 0x5B, 0x35, 0xC2, 0xDD, 0x74, 0x20, 0x9D, 0x21, 0x92, 0xC4, 0xDC, 0x44, 0xF8,
-                0x3E, 0xA0, 0x4E, 0x04,
-            ],
-            &[
-                0xED, 0xD7, 0x41, 0x65, 0x7E, 0xB9, 0xCD, 0xC2, 0x1F, 0x9A, 0xA3, 0xC6, 0x8E, 0xF1,
-                0x3A, 0x11, 0x40, 0xAC, 0xAD, 0x52, 0xC0, 0xA6, 0x59, 0x60, 0x89, 0xF2, 0x64, 0x67,
-                0xC0, 0xF4, 0x54, 0x07,
-            ],
-        ],
-        [
-            &[
-                0xAC, 0xE0, 0x9E, 0x5B, 0x58, 0x22, 0x3F, 0x1F, 0x3C, 0xA4, 0x86, 0x37, 0xAA, 0x26,
-                0xDA, 0x48, 0x7E, 0x4F, 0x61, 0x90, 0xB7, 0xE4, 0xC3, 0x2F, 0xB5, 0x8F, 0xCF, 0x64,
-                0xDF, 0x3C, 0x93, 0x0C,
-            ],
-            &[
-                0x9D, 0x87, 0x6A, 0x50, 0x56, 0xD6, 0x5E, 0x28, 0xA1, 0x6D, 0x0E, 0x72, 0xD2, 0x76,
-                0x6F, 0x13, 0x82, 0x7B, 0x76, 0x3D, 0xA0, 0xE6, 0x53, 0x90, 0x1F, 0xE8, 0x83, 0x8D,
-                0xF6, 0xF6, 0xD3, 0x11,
-            ],
-            &[
-                0x9E, 0x04, 0x77, 0x32, 0xE5, 0xF7, 0xCE, 0x24, 0x2B, 0x82, 0x03, 0x8C, 0xC4, 0xEC,
-                0x15, 0x31, 0xA7, 0x09, 0xAF, 0x64, 0x16, 0x27, 0xC8, 0x91, 0x4F, 0x17, 0x11, 0x4E,
-                0x67, 0xF4, 0x84, 0x07,
-            ],
-            &[
-                0x22, 0x6D, 0x07, 0x57, 0xD5, 0x0E, 0xE2, 0x83, 0xD8, 0x9F, 0x4E, 0x9C, 0x3B, 0xAB,
-                0x1D, 0xF0, 0xD1, 0x1A, 0x5A, 0xB8, 0x16, 0xA3, 0xAD, 0x54, 0x66, 0xCD, 0x83, 0x6D,
-                0xC2, 0xE1, 0xA9, 0x03,
-            ],
-        ],
-        [
-            &[
-                0xD2, 0x98, 0x19, 0x16, 0x55, 0xA3, 0x0F, 0x31, 0x84, 0xF5, 0x8A, 0x1F, 0x6B, 0xD4,
-                0x58, 0x9A, 0x2C, 0x12, 0x76, 0xBE, 0xEF, 0xEE, 0xDA, 0x19, 0x85, 0xF0, 0xBD, 0x52,
-                0x54, 0xB8, 0xDD, 0x0F,
-            ],
-            &[
-                0x31, 0xC6, 0x81, 0xC3, 0xB7, 0xCC, 0xC4, 0xA2, 0xE6, 0xDA, 0xE7, 0x09, 0x9C, 0xFB,
-                0x39, 0xCD, 0x93, 0x9A, 0xF4, 0xCA, 0xD9, 0xD9, 0x3C, 0x83, 0x94, 0xDB, 0xEC, 0x2B,
-                0x5D, 0xD4, 0xC0, 0x0B,
-            ],
-            &[
-                0x22, 0x12, 0x81, 0xFA, 0x1F, 0x85, 0x71, 0x3B, 0x76, 0x66, 0xA8, 0x26, 0xFD, 0xF0,
-                0x0A, 0xEC, 0x0F, 0x87, 0x94, 0xE0, 0xC3, 0x7E, 0x1E, 0x9A, 0x6D, 0xA3, 0x1F, 0x1E,
-                0xD1, 0xA6, 0xD7, 0x0B,
-            ],
-            &[
-                0x40, 0x59, 0xE8, 0x50, 0x2C, 0x97, 0xC0, 0x88, 0xF0, 0x70, 0xAD, 0x56, 0x8C, 0x0B,
-                0xD9, 0x30, 0x6A, 0xA2, 0x57, 0x60, 0x29, 0x3A, 0x4B, 0xBE, 0x6D, 0x83, 0xEC, 0x9B,
-                0xD0, 0xA5, 0x11, 0x0F,
-            ],
-        ],
-        [
-            &[
-                0xBC, 0x37, 0x85, 0xE2, 0x25, 0x98, 0x41, 0xB0, 0xD9, 0x9A, 0x40, 0x5C, 0xE7, 0xEB,
-                0x32, 0xCE, 0x5A, 0x88, 0xD4, 0x34, 0xD9, 0x0B, 0x0E, 0x92, 0x1B, 0x01, 0x7D, 0xBA,
-                0xA6, 0x61, 0xC3, 0x01,
-            ],
-            &[
-                0xCD, 0xEB, 0xF9, 0xEE, 0x05, 0x73, 0x48, 0x34, 0x34, 0x77, 0xAE, 0x9A, 0x85, 0x7F,
-                0xBB, 0x88, 0xE3, 0x3F, 0x3B, 0xEF, 0x6E, 0x3F, 0x4E, 0xC7, 0x2F, 0xBC, 0x84, 0x66,
-                0x5E, 0x9B, 0xFA, 0x04,
-            ],
-            &[
-                0xAE, 0x81, 0xA1, 0x64, 0x1D, 0x53, 0x2C, 0x6F, 0x92, 0xA6, 0x00, 0x97, 0x2D, 0x2E,
-                0xA4, 0x89, 0xE8, 0x62, 0x3D, 0xDE, 0x0E, 0x1E, 0x35, 0xFA, 0x77, 0xBE, 0x5E, 0x68,
-                0x08, 0xC9, 0x52, 0x0F,
-            ],
-            &[
-                0xD9, 0x1B, 0x51, 0x82, 0x8E, 0xCF, 0x4E, 0x04, 0x97, 0x8D, 0x46, 0xC0, 0x6B, 0xEF,
-                0x36, 0xFA, 0x6F, 0xE6, 0xB8, 0x03, 0xD7, 0x5C, 0xED, 0x8D, 0x5B, 0xD8, 0x50, 0xCF,
-                0xF1, 0x9F, 0x70, 0x02,
-            ],
-        ],
-        [
-            &[
-                0xB4, 0xFD, 0x50, 0x46, 0x57, 0x7D, 0x87, 0x44, 0x08, 0x6D, 0x5B, 0x22, 0x93, 0xC2,
-                0x11, 0x9E, 0x42, 0x5E, 0x13, 0x06, 0xED, 0xA3, 0x95, 0x2D, 0x3C, 0xD5, 0xC6, 0x02,
-                0x14, 0x59, 0x8F, 0x12,
-            ],
-            &[
-                0x71, 0x03, 0x84, 0xA6, 0x32, 0xB6, 0xF1, 0xAD, 0xBB, 0xC9, 0x50, 0xB3, 0xF6, 0xF1,
-                0xA3, 0x7C, 0x48, 0x50, 0xA4, 0x74, 0xA9, 0xBF, 0x4A, 0x56, 0x19, 0x0D, 0x4C, 0xA2,
-                0x6E, 0x28, 0x4A, 0x04,
-            ],
-            &[
-                0xD6, 0x4C, 0xBE, 0x5C, 0x9C, 0x3A, 0xB9, 0x54, 0x76, 0xF4, 0x8A, 0xFD, 0xF1, 0x91,
-                0x5D, 0x52, 0x04, 0x5D, 0xF4, 0x25, 0x2E, 0x97, 0xD6, 0xDB, 0x38, 0x8C, 0x41, 0xB8,
-                0xAC, 0xF1, 0x11, 0x02,
-            ],
-            &[
-                0xBA, 0x5A, 0x62, 0x0A, 0x5F, 0x36, 0xC6, 0x45, 0x31, 0xFE, 0x88, 0x79, 0x5A, 0x9A,
-                0x86, 0x17, 0x36, 0xA6, 0x4E, 0xF6, 0xDC, 0x4E, 0xDB, 0x1D, 0x58, 0x1A, 0x84, 0x7D,
-                0x58, 0xFD, 0x22, 0x0E,
-            ],
-        ],
-        [
-            &[
-                0x14, 0xEE, 0x78, 0x27, 0x93, 0x5B, 0x11, 0xEC, 0xB3, 0xAD, 0xCC, 0x15, 0xF0, 0xD6,
-                0x3C, 0x6A, 0x07, 0x30, 0x86, 0xD7, 0xF2, 0x06, 0xA7, 0x8A, 0x1D, 0xBE, 0xD9, 0x00,
-                0x18, 0x46, 0x75, 0x02,
-            ],
-            &[
-                0x8B, 0x2D, 0x36, 0x03, 0x94, 0xAA, 0x78, 0x82, 0x16, 0x06, 0x4A, 0xED, 0x0A, 0x6D,
-                0xF1, 0xF0, 0xC1, 0x00, 0x2A, 0x98, 0x24, 0x11, 0xCC, 0x77, 0x41, 0xDF, 0xF5, 0x2C,
-                0x98, 0x2B, 0x64, 0x0B,
-            ],
-            &[
-                0xF2, 0x7F, 0x50, 0xE5, 0xA5, 0xA2, 0x48, 0x6F, 0x28, 0x1E, 0x7C, 0xA2, 0xF0, 0x20,
-                0x85, 0x54, 0x9B, 0xA5, 0x28, 0x16, 0x54, 0x3A, 0x24, 0x84, 0x66, 0x38, 0x04, 0xC2,
-                0x80, 0x9F, 0xF3, 0x09,
-            ],
-            &[
-                0xA5, 0x36, 0x8C, 0xA6, 0x7B, 0x05, 0x22, 0x05, 0x1A, 0x31, 0xD4, 0x3D, 0x2A, 0xD0,
-                0x09, 0xEB, 0xC2, 0x81, 0x77, 0xC7, 0x12, 0xE4, 0x10, 0x74, 0x9E, 0x6E, 0xBB, 0xB3,
-                0xF8, 0x9F, 0x7F, 0x06,
-            ],
-        ],
-        [
-            &[
-                0x42, 0xEF, 0xD9, 0x88, 0xA1, 0xF1, 0x7F, 0x4B, 0x4D, 0xE9, 0xFD, 0x8B, 0xCF, 0x29,
-                0x85, 0xD6, 0x73, 0xA1, 0x17, 0x0A, 0x8F, 0x78, 0x05, 0xD3, 0x82, 0xBB, 0x5C, 0x97,
-                0xBB, 0x55, 0xE3, 0x06,
-            ],
-            &[
-                0x6D, 0x03, 0x82, 0xC4, 0xF1, 0x1A, 0xB6, 0x71, 0xE2, 0xC4, 0xC9, 0x6B, 0x39, 0x7E,
-                0xC9, 0x3A, 0xA7, 0x60, 0xA7, 0x03, 0x78, 0x6E, 0xAC, 0x26, 0x13, 0x2D, 0xAE, 0x24,
-                0xF3, 0x88, 0x1F, 0x05,
-            ],
-            &[
-                0x43, 0x0C, 0xA8, 0x79, 0xB7, 0x6D, 0x11, 0xF2, 0xBB, 0x16, 0x60, 0x5E, 0xAE, 0x6D,
-                0x31, 0x5A, 0x3C, 0x2D, 0xDC, 0xD7, 0x55, 0xE6, 0xD1, 0xF8, 0xEE, 0xE1, 0xBD, 0x75,
-                0x66, 0x7B, 0x97, 0x12,
-            ],
-            &[
-                0x84, 0xD5, 0x8C, 0x75, 0xBE, 0xB5, 0x93, 0x7B, 0xF4, 0xD8, 0xAF, 0x8A, 0x1B, 0xF7,
-                0x28, 0x05, 0x5D, 0x9F, 0x8A, 0xDA, 0xBE, 0x2B, 0x71, 0xD0, 0xF6, 0x2C, 0xAD, 0xC1,
-                0xB8, 0xCF, 0xBA, 0x0D,
-            ],
-        ],
-        [
-            &[
-                0xD0, 0x27, 0x57, 0x17, 0xC6, 0xAB, 0x94, 0x53, 0x4E, 0x52, 0x04, 0xEB, 0x26, 0x41,
-                0xA2, 0x85, 0xD1, 0xF5, 0x22, 0xF1, 0x58, 0x2B, 0xE5, 0xE2, 0xD0, 0x98, 0xB7, 0x08,
-                0xE2, 0x3E, 0xD6, 0x03,
-            ],
-            &[
-                0x12, 0x97, 0x20, 0x3E, 0x20, 0x2C, 0xC6, 0x69, 0xFC, 0xCF, 0x4E, 0x29, 0xF1, 0xC5,
-                0xA6, 0x29, 0x77, 0x6A, 0xF5, 0x0C, 0x5A, 0x25, 0x41, 0x86, 0x8A, 0xAD, 0xD7, 0xBB,
-                0x74, 0xE1, 0xFD, 0x0B,
-            ],
-            &[
-                0x75, 0xCC, 0xBD, 0x18, 0xD8, 0x92, 0xAC, 0xB6, 0x05, 0x19, 0x7F, 0x78, 0x79, 0x5C,
-                0x7C, 0x60, 0xED, 0xDF, 0x58, 0x58, 0x5D, 0x5B, 0xBE, 0x3F, 0x61, 0xEA, 0x59, 0x5C,
-                0x65, 0xF4, 0x4F, 0x0F,
-            ],
-            &[
-                0xA6, 0x52, 0x7C, 0xE1, 0x64, 0x67, 0x09, 0x61, 0x76, 0x70, 0x7D, 0xA9, 0x4A, 0xA8,
-                0x40, 0xE9, 0x7A, 0x16, 0xCB, 0x69, 0x62, 0x1C, 0xDB, 0xAC, 0x1A, 0xBD, 0xB4, 0x15,
-                0x8C, 0x1A, 0x35, 0x0B,
-            ],
-        ],
-        [
-            &[
-                0x00, 0x03, 0x44, 0xF1, 0xA3, 0x73, 0x55, 0x5B, 0xA7, 0x33, 0x79, 0xEC, 0x1A, 0x21,
-                0x9D, 0xB3, 0x16, 0x5C, 0xBB, 0x35, 0x76, 0x85, 0x15, 0xF3, 0xC7, 0x51, 0x97, 0xAF,
-                0x2D, 0x24, 0x36, 0x05,
-            ],
-            &[
-                0xA9, 0xDB, 0xA9, 0x02, 0x7C, 0x94, 0x7F, 0x0E, 0xAD, 0xE9, 0xF8, 0xCE, 0x50, 0xE4,
-                0xB2, 0xBD, 0xB3, 0x0E, 0x31, 0xBF, 0x3A, 0x9E, 0x08, 0x67, 0x17, 0x6A, 0x9C, 0x33,
-                0xB9, 0xD7, 0xD6, 0x0E,
-            ],
-            &[
-                0x53, 0xC1, 0x47, 0xA5, 0xA0, 0x60, 0xEA, 0x7E, 0xA7, 0xA5, 0xC9, 0x1A, 0x48, 0x62,
-                0x1F, 0x4C, 0x83, 0xFA, 0x20, 0x19, 0x92, 0x92, 0x98, 0x2C, 0x0A, 0x9A, 0x22, 0x0C,
-                0x33, 0x9A, 0xAB, 0x02,
-            ],
-            &[
-                0x97, 0xE0, 0x9A, 0xE2, 0x9A, 0xB0, 0xDC, 0xC5, 0xA5, 0x8B, 0x66, 0xE1, 0xF0, 0xB0,
-                0xFD, 0x04, 0x4E, 0xC7, 0x74, 0x43, 0x7D, 0x69, 0x63, 0x72, 0xD6, 0xF2, 0xFD, 0x28,
-                0x1B, 0xDD, 0xC5, 0x0E,
-            ],
-        ],
-        [
-            &[
-                0xA2, 0x9E, 0xE1, 0x9B, 0xBA, 0xB8, 0xB7, 0x30, 0xCD, 0x2D, 0x59, 0xD9, 0x0B, 0xA2,
-                0xE7, 0x7E, 0x58, 0xA3, 0x7B, 0xF5, 0x73, 0x58, 0x30, 0x94, 0x18, 0x6E, 0x63, 0x51,
-                0xAF, 0xEC, 0x74, 0x02,
-            ],
-            &[
-                0xED, 0xF0, 0xE5, 0xB0, 0x1B, 0xFD, 0x45, 0x29, 0x53, 0x97, 0x66, 0x62, 0x49, 0xF0,
-                0x42, 0x19, 0x46, 0x04, 0xDE, 0x1D, 0xF4, 0xF8, 0x23, 0xAF, 0xF9, 0x38, 0xC7, 0x79,
-                0x8C, 0x79, 0xBC, 0x0C,
-            ],
-            &[
-                0x3E, 0xF7, 0x87, 0x44, 0x8B, 0x8D, 0xF5, 0x81, 0x82, 0xFF, 0x8D, 0xE3, 0x90, 0xA7,
-                0x77, 0xD0, 0xE4, 0xE6, 0xC1, 0x88, 0x67, 0x63, 0xD6, 0x16, 0xC1, 0x41, 0x5C, 0x29,
-                0xC4, 0x97, 0x03, 0x0B,
-            ],
-            &[
-                0x16, 0x98, 0x61, 0xEE, 0xB3, 0xAC, 0x72, 0xD5, 0xC1, 0x0B, 0xCA, 0x2E, 0x7E, 0xAC,
-                0x6B, 0x12, 0x55, 0x32, 0x24, 0xD3, 0x7F, 0xF5, 0x0E, 0xCB, 0x55, 0x85, 0xCB, 0x49,
-                0xEF, 0xEE, 0x03, 0x11,
-            ],
-        ],
-        [
-            &[
-                0x9B, 0x99, 0xED, 0x5E, 0x45, 0x6F, 0xF6, 0x7C, 0xFB, 0xEC, 0x58, 0x82, 0x2D, 0x1A,
-                0x01, 0x62, 0x4E, 0xB1, 0x42, 0xAE, 0x48, 0xDA, 0x72, 0xD0, 0x7F, 0xBA, 0xA7, 0xF1,
-                0x63, 0xAE, 0x00, 0x05,
-            ],
-            &[
-                0xE3, 0x2B, 0x8C, 0x93, 0x05, 0xB6, 0xD0, 0xC5, 0x73, 0x0E, 0x9C, 0x5E, 0xE8, 0x8D,
-                0x5B, 0x1F, 0xD5, 0x8B, 0xDE, 0xE8, 0x04, 0xC2, 0xE7, 0x65, 0x7A, 0x8F, 0xF9, 0xA5,
-                0xDB, 0xB5, 0xE4, 0x07,
-            ],
-            &[
-                0x96, 0x36, 0x3F, 0x09, 0xF2, 0x6E, 0xDC, 0xF5, 0x08, 0x05, 0x36, 0x85, 0x78, 0xEE,
-                0x05, 0x9B, 0x34, 0x0C, 0x16, 0x21, 0xCF, 0x6C, 0xA9, 0x61, 0xCE, 0xF2, 0xAB, 0xE7,
-                0x03, 0x99, 0x8B, 0x08,
-            ],
-            &[
-                0x51, 0x7F, 0x60, 0x18, 0x71, 0x49, 0x48, 0xE1, 0x5C, 0x4E, 0x20, 0x01, 0x13, 0x09,
-                0xDB, 0xD0, 0xD6, 0x34, 0xA4, 0x9F, 0xDC, 0xA6, 0x12, 0x3E, 0x68, 0x36, 0x04, 0x58,
-                0x26, 0x5F, 0x8E, 0x0C,
-            ],
-        ],
-        [
-            &[
-                0x92, 0x7D, 0x97, 0xB0, 0x4C, 0xB2, 0xB1, 0x1C, 0xC1, 0x11, 0x6F, 0x06, 0x57, 0xA2,
-                0x75, 0xB6, 0xCB, 0xFD, 0xFE, 0x1A, 0x63, 0x71, 0x79, 0xCC, 0xE8, 0xFC, 0xB6, 0x14,
-                0x1C, 0xBC, 0x42, 0x06,
-            ],
-            &[
-                0x23, 0xA4, 0x43, 0x4C, 0xBD, 0xC5, 0x84, 0x52, 0xB4, 0xD5, 0x5A, 0x3B, 0xFB, 0xBB,
-                0x97, 0xB6, 0x9C, 0x4A, 0x90, 0x6C, 0x0D, 0xEC, 0x84, 0x02, 0x2F, 0x3F, 0xAE, 0x33,
-                0xE8, 0xBA, 0x99, 0x0D,
-            ],
-            &[
-                0x90, 0x35, 0x40, 0xD2, 0x42, 0x5F, 0x76, 0x20, 0x7D, 0x1B, 0x38, 0xD5, 0xBB, 0x38,
-                0x2B, 0xF3, 0x48, 0xD9, 0x51, 0xFE, 0xB4, 0x0D, 0x8A, 0x1C, 0x66, 0x21, 0x8B, 0x3C,
-                0x61, 0x99, 0x1E, 0x0B,
-            ],
-            &[
-                0x80, 0x91, 0xD9, 0x43, 0xD3, 0x9D, 0xC6, 0x72, 0x34, 0x53, 0xC1, 0x6A, 0x15, 0xBD,
-                0xCC, 0x48, 0x37, 0xE9, 0xB8, 0xA4, 0x9E, 0xF8, 0xF6, 0x47, 0x6A, 0x30, 0xA4, 0x68,
-                0x4C, 0xD4, 0x33, 0x07,
-            ],
-        ],
-        [
-            &[
-                0x6D, 0x65, 0xF5, 0x9C, 0xE7, 0x47, 0x8A, 0x24, 0x59, 0xF1, 0xA5, 0x18, 0xA9, 0xBF,
-                0xA5, 0xBA, 0x3C, 0x2F, 0xCC, 0x43, 0x86, 0xC8, 0xD6, 0xED, 0x12, 0xF3, 0xAB, 0xF0,
-                0x31, 0x73, 0xD0, 0x0D,
-            ],
-            &[
-                0xE1, 0x6A, 0x0E, 0x69, 0x7C, 0x6F, 0x77, 0x19, 0xFD, 0xEA, 0xE5, 0x43, 0x0B, 0xA7,
-                0xEA, 0xCD, 0xEB, 0x32, 0xC4, 0x17, 0xEA, 0x97, 0x2B, 0xB0, 0x90, 0xF3, 0x57, 0xB7,
-                0x1D, 0xF6, 0x95, 0x0B,
-            ],
-            &[
-                0x58, 0x26, 0xA0, 0x00, 0xEF, 0x0B, 0xB7, 0xE8, 0x04, 0x35, 0xFB, 0xBD, 0x06, 0x0A,
-                0xB3, 0x48, 0x49, 0x6A, 0x75, 0xEF, 0x83, 0xCE, 0x88, 0x06, 0xC1, 0x47, 0x46, 0x5F,
-                0x27, 0x3E, 0x6B, 0x12,
-            ],
-            &[
-                0xD4, 0x6E, 0x96, 0xBB, 0x05, 0xB1, 0x95, 0x9C, 0x99, 0x39, 0xFF, 0x12, 0xAD, 0x57,
-                0x77, 0xBE, 0xFA, 0x99, 0x28, 0x85, 0xB7, 0xA6, 0x72, 0xC9, 0xE6, 0xCE, 0x1E, 0xE3,
-                0xD5, 0xF9, 0x29, 0x0A,
-            ],
-        ],
-        [
-            &[
-                0x8F, 0xC1, 0x6D, 0xAA, 0x66, 0x65, 0x95, 0x77, 0xCC, 0x6C, 0xF2, 0xDC, 0xF9, 0x0F,
-                0x91, 0x42, 0x24, 0xF5, 0xC5, 0xCF, 0xA9, 0xE3, 0x27, 0x73, 0x5F, 0xA6, 0xB3, 0x9D,
-                0x21, 0xA4, 0xC1, 0x09,
-            ],
-            &[
-                0x25, 0x86, 0x65, 0xBA, 0xDC, 0xF6, 0x9D, 0x41, 0x48, 0x96, 0x49, 0xAD, 0x0D, 0xDC,
-                0x6F, 0x3A, 0xC0, 0xD9, 0xBE, 0x7E, 0x92, 0xA4, 0xDB, 0x7D, 0xC5, 0x4B, 0xC1, 0x1D,
-                0xBF, 0x72, 0xB8, 0x08,
-            ],
-            &[
-                0xAB, 0x3C, 0x2F, 0x72, 0xD7, 0x59, 0x12, 0xC4, 0x46, 0x41, 0xC3, 0x1E, 0x58, 0x69,
-                0x3B, 0x60, 0x0D, 0xCE, 0x5D, 0xBC, 0x43, 0xBD, 0x6D, 0xA6, 0xDD, 0xC4, 0x12, 0x76,
-                0x5C, 0xE3, 0xCF, 0x11,
-            ],
-            &[
-                0xBD, 0xE2, 0x6B, 0x38, 0x07, 0x55, 0x03, 0x43, 0xCA, 0x4A, 0xDF, 0xCA, 0xDA, 0x61,
-                0x83, 0x75, 0xE4, 0x75, 0xC4, 0x5E, 0xF3, 0x02, 0x9C, 0xCD, 0xE3, 0x11, 0x3A, 0xD9,
-                0x6F, 0x45, 0x3A, 0x03,
-            ],
-        ],
-        [
-            &[
-                0xCD, 0x60, 0xD6, 0x7E, 0x66, 0xA0, 0xD3, 0x71, 0x9E, 0x2F, 0x1C, 0x4C, 0x98, 0x6F,
-                0x19, 0xD1, 0x60, 0x1A, 0xBF, 0x99, 0x2E, 0x16, 0x5B, 0x0E, 0xBD, 0x05, 0x43, 0x80,
-                0x2C, 0x2F, 0x94, 0x06,
-            ],
-            &[
-                0x8B, 0xC6, 0xB8, 0xA3, 0x55, 0x42, 0xE6, 0xE7, 0xD8, 0xEC, 0x87, 0x03, 0x4F, 0x71,
-                0x73, 0x37, 0x49, 0x70, 0xE5, 0xEB, 0x24, 0xAC, 0x5B, 0x29, 0x00, 0x19, 0x35, 0x2A,
-                0x86, 0xE3, 0xFA, 0x10,
-            ],
-            &[
-                0x78, 0xBC, 0x14, 0x16, 0x70, 0x95, 0x90, 0x34, 0xF8, 0x6C, 0x5B, 0x0D, 0x63, 0x8B,
-                0xDC, 0x64, 0x8B, 0xE8, 0x47, 0x45, 0xC4, 0x16, 0xAC, 0xE0, 0x08, 0x88, 0x55, 0xAC,
-                0x37, 0xE6, 0x7F, 0x11,
-            ],
-            &[
-                0xCF, 0x7D, 0x4D, 0x7B, 0x3C, 0x7F, 0x02, 0x37, 0xCC, 0x00, 0x4E, 0x3B, 0xBC, 0x2D,
-                0x46, 0x76, 0x15, 0x15, 0xD6, 0x05, 0x46, 0xCB, 0x51, 0x98, 0xFE, 0xA0, 0x31, 0xE0,
-                0x5C, 0x56, 0x43, 0x11,
-            ],
-        ],
-        [
-            &[
-                0x45, 0x8C, 0x38, 0x26, 0x14, 0xE5, 0x7D, 0x5A, 0x00, 0x1F, 0x94, 0x6E, 0x98, 0x15,
-                0x4B, 0x63, 0x39, 0x6F, 0x76, 0xAB, 0x15, 0xE8, 0x31, 0xE3, 0x09, 0xFB, 0x2B, 0xEC,
-                0xBB, 0x57, 0x42, 0x00,
-            ],
-            &[
-                0x04, 0xE4, 0x7B, 0xBC, 0xFE, 0x3B, 0x9E, 0xDC, 0x0F, 0x83, 0x66, 0x4E, 0x3F, 0x68,
-                0xD0, 0x8A, 0xDF, 0xE2, 0xC9, 0x13, 0x8D, 0x57, 0xFD, 0x3A, 0xB8, 0x6F, 0xA4, 0xBD,
-                0x6A, 0x71, 0x1A, 0x11,
-            ],
-            &[
-                0x8E, 0x12, 0x11, 0xD0, 0xFB, 0x27, 0x99, 0x71, 0x81, 0x89, 0x4B, 0xA2, 0x42, 0xC7,
-                0x9D, 0xEC, 0xC7, 0xD4, 0x5E, 0x47, 0x31, 0x42, 0x94, 0x1B, 0x73, 0x6C, 0x91, 0x72,
-                0xE3, 0x47, 0x3A, 0x04,
-            ],
-            &[
-                0xFE, 0x54, 0xD5, 0x6D, 0x48, 0xB6, 0x9B, 0xA2, 0xCC, 0x7A, 0x33, 0xAA, 0x5B, 0x92,
-                0x48, 0xA7, 0x2F, 0x00, 0x90, 0x48, 0xF9, 0x81, 0x34, 0x8F, 0x0B, 0xE1, 0x31, 0xB9,
-                0xAF, 0x48, 0x6E, 0x11,
-            ],
-        ],
-        [
-            &[
-                0x84, 0x25, 0xF9, 0x3C, 0x8B, 0xC4, 0x67, 0xFD, 0x37, 0x95, 0xC0, 0x24, 0xEC, 0xF8,
-                0xB6, 0x40, 0xEE, 0xF3, 0x02, 0x8A, 0x93, 0x01, 0xB3, 0x3C, 0x74, 0x5C, 0x7B, 0x1E,
-                0x49, 0x34, 0x76, 0x01,
-            ],
-            &[
-                0x25, 0x46, 0xC7, 0x0B, 0x7C, 0x00, 0x18, 0x31, 0x26, 0x2A, 0x47, 0x2A, 0x74, 0x3A,
-                0x71, 0x5B, 0xE8, 0x0E, 0x54, 0x10, 0x25, 0x39, 0xBD, 0x21, 0x1A, 0x51, 0xB2, 0xCA,
-                0xA2, 0xF8, 0xE0, 0x0C,
-            ],
-            &[
-                0x43, 0x13, 0xD9, 0x31, 0x7F, 0x8F, 0xEB, 0xB5, 0x6C, 0xDC, 0xB3, 0xB6, 0x25, 0x80,
-                0x24, 0xFC, 0xCB, 0xFC, 0x3D, 0xF2, 0xF1, 0x30, 0xE0, 0xC1, 0xB0, 0x15, 0x5B, 0xBD,
-                0x31, 0x53, 0x4D, 0x0C,
-            ],
-            &[
-                0x56, 0xB4, 0x79, 0xDC, 0xB3, 0x5A, 0xA1, 0xB5, 0x49, 0x3C, 0x23, 0x17, 0x98, 0x14,
-                0x58, 0x5B, 0xD4, 0x27, 0x32, 0x66, 0x95, 0xBD, 0xF9, 0x46, 0x96, 0xAA, 0xA4, 0x49,
-                0x47, 0x4D, 0xD4, 0x0A,
-            ],
-        ],
-        [
-            &[
-                0x95, 0xA9, 0x98, 0x99, 0x95, 0xEE, 0xBB, 0xC2, 0x32, 0x52, 0xDA, 0x26, 0x97, 0xD8,
-                0x56, 0x70, 0x86, 0xA7, 0x40, 0x55, 0x0B, 0x33, 0x5E, 0xDF, 0x5D, 0x7E, 0xDD, 0xD1,
-                0x33, 0x79, 0x57, 0x11,
-            ],
-            &[
-                0x73, 0x10, 0x11, 0xC2, 0xAF, 0xB0, 0xBD, 0x83, 0x17, 0x5B, 0x69, 0x4E, 0x73, 0x97,
-                0xB6, 0x3C, 0xD0, 0xCC, 0xC2, 0x85, 0x4C, 0x7A, 0xE7, 0x54, 0x9B, 0x34, 0xAB, 0xE1,
-                0xD4, 0xCB, 0x5B, 0x0B,
-            ],
-            &[
-                0x03, 0x91, 0xC9, 0xD2, 0x4A, 0x90, 0x2E, 0xFF, 0xBC, 0xFE, 0xA5, 0x17, 0x3E, 0x94,
-                0xCB, 0x0C, 0xC8, 0xBE, 0x7A, 0x34, 0xE0, 0xFA, 0x60, 0xEE, 0x05, 0x14, 0x94, 0x54,
-                0x49, 0x87, 0xD9, 0x06,
-            ],
-            &[
-                0x3C, 0xB3, 0x48, 0xF8, 0x7B, 0x9E, 0xC4, 0x66, 0x91, 0x37, 0xB2, 0x2C, 0x32, 0x96,
-                0xF0, 0xF9, 0xF3, 0xC8, 0xD4, 0x33, 0x6F, 0xF7, 0x7E, 0x9F, 0x9B, 0xA7, 0x87, 0xDA,
-                0x3C, 0xB8, 0x61, 0x10,
-            ],
-        ],
-        [
-            &[
-                0xB7, 0xF9, 0x1B, 0x51, 0x2B, 0x2D, 0x5F, 0x9F, 0x7A, 0x4D, 0xA8, 0x02, 0xFC, 0x3E,
-                0xAC, 0xBD, 0xA9, 0xAF, 0xFB, 0xC0, 0x02, 0x45, 0x21, 0x01, 0x36, 0x3C, 0x96, 0x7F,
-                0x34, 0xC8, 0x19, 0x02,
-            ],
-            &[
-                0xE4, 0x27, 0xBA, 0xE8, 0x74, 0x97, 0x41, 0x08, 0xE2, 0xB8, 0xFE, 0xEA, 0x8B, 0xB0,
-                0x7D, 0xBD, 0x90, 0xB9, 0x3F, 0x10, 0xDD, 0xEA, 0xB9, 0x57, 0x58, 0x60, 0x0C, 0x5D,
-                0x4E, 0x46, 0x6D, 0x02,
-            ],
-            &[
-                0xAC, 0x39, 0xB5, 0xAC, 0x0F, 0xDA, 0xAB, 0x90, 0xE7, 0x45, 0x16, 0x75, 0x09, 0x75,
-                0x27, 0xDA, 0xCA, 0x7A, 0xCD, 0xD8, 0x87, 0x62, 0x94, 0x08, 0x4C, 0x17, 0xB0, 0x03,
-                0x73, 0x56, 0x37, 0x0F,
-            ],
-            &[
-                0x75, 0xEA, 0x14, 0x55, 0x4C, 0x76, 0x2E, 0x24, 0xA0, 0x83, 0x4D, 0xD7, 0x98, 0x4E,
-                0xBD, 0x43, 0x0C, 0xED, 0x20, 0x00, 0x1C, 0x90, 0x4A, 0x10, 0x4A, 0x32, 0xEB, 0x4B,
-                0x3D, 0xB3, 0x12, 0x10,
-            ],
-        ],
-        [
-            &[
-                0xE1, 0xD0, 0xE4, 0x15, 0x9E, 0x4E, 0x05, 0xC0, 0x96, 0x4C, 0xA1, 0x23, 0x7C, 0x78,
-                0xF6, 0x92, 0x09, 0x27, 0x8D, 0x1F, 0x58, 0xA0, 0x2F, 0x2F, 0x1F, 0x37, 0xF2, 0x3E,
-                0x70, 0xF5, 0x93, 0x01,
-            ],
-            &[
-                0xCC, 0x1F, 0x62, 0x61, 0xE4, 0x06, 0xC2, 0xC6, 0x6B, 0xB0, 0xEE, 0x9E, 0xA4, 0x8E,
-                0x34, 0x40, 0x59, 0x06, 0xBA, 0x3E, 0x80, 0x2A, 0x3E, 0x8B, 0x55, 0xF0, 0x0E, 0x12,
-                0x3D, 0xBA, 0x0B, 0x04,
-            ],
-            &[
-                0x1E, 0x51, 0x75, 0x13, 0x60, 0x58, 0xB0, 0x81, 0x78, 0xCC, 0x97, 0x20, 0x91, 0xD8,
-                0x32, 0xE9, 0xF5, 0x92, 0xF3, 0xD9, 0x00, 0x79, 0x07, 0x0E, 0xD6, 0x6B, 0x67, 0x82,
-                0x84, 0x85, 0xD0, 0x05,
-            ],
-            &[
-                0x69, 0x1E, 0x75, 0x41, 0xE9, 0x07, 0x7D, 0x9E, 0xED, 0x56, 0xA3, 0x10, 0x58, 0xD9,
-                0x3B, 0x81, 0x38, 0xAA, 0xD3, 0x6A, 0x3B, 0x78, 0x8F, 0x3A, 0x58, 0x3A, 0x82, 0x34,
-                0xDB, 0x9A, 0xDB, 0x09,
-            ],
-        ],
-        [
-            &[
-                0x99, 0x9B, 0x34, 0xB4, 0x69, 0x5D, 0x4C, 0xEA, 0xFC, 0xDC, 0x72, 0x95, 0xDD, 0x9B,
-                0xC1, 0xAF, 0xAC, 0x40, 0x0B, 0xC3, 0x41, 0x63, 0x7E, 0xF0, 0x32, 0xD8, 0xAC, 0x67,
-                0x0A, 0xC9, 0xF3, 0x03,
-            ],
-            &[
-                0x63, 0x09, 0x25, 0x87, 0x36, 0xAC, 0x4A, 0xD7, 0xA6, 0xE9, 0x94, 0x8F, 0x9C, 0xC4,
-                0xD4, 0x19, 0x83, 0x5C, 0x1E, 0x7A, 0x3E, 0x28, 0x0C, 0xFF, 0x78, 0x08, 0x8A, 0x27,
-                0x53, 0x25, 0x7D, 0x0B,
-            ],
-            &[
-                0x24, 0x4E, 0x02, 0xE5, 0xA5, 0x2B, 0x42, 0x56, 0x56, 0x18, 0xC4, 0xFB, 0x24, 0x29,
-                0xCE, 0x83, 0xEA, 0x1D, 0xE5, 0x4A, 0x28, 0xB5, 0x77, 0xD1, 0xFE, 0x08, 0x99, 0x65,
-                0x25, 0xF8, 0x78, 0x05,
-            ],
-            &[
-                0x56, 0xC0, 0x97, 0x97, 0x5B, 0xE6, 0xC9, 0x91, 0x21, 0x4A, 0xC1, 0xC2, 0x3F, 0x23,
-                0x62, 0x1E, 0x1C, 0x5F, 0x5C, 0x5D, 0xA7, 0x5F, 0x58, 0xB3, 0xE8, 0x5D, 0x1B, 0x61,
-                0xC1, 0x11, 0x05, 0x12,
-            ],
-        ],
-        [
-            &[
-                0xFB, 0x3E, 0x91, 0xD3, 0x9E, 0x65, 0xDE, 0xEC, 0x87, 0x37, 0x50, 0xCC, 0x42, 0xCD,
-                0x51, 0x8A, 0xA0, 0x50, 0xE1, 0x3B, 0xD6, 0x8F, 0xAA, 0x98, 0x23, 0x2B, 0x46, 0x81,
-                0xAF, 0x3C, 0xB3, 0x10,
-            ],
-            &[
-                0x9E, 0x5C, 0x23, 0xBF, 0xC4, 0x03, 0x0B, 0x61, 0xE4, 0x6B, 0x3B, 0x2D, 0xF9, 0xED,
-                0xF1, 0xC1, 0x0B, 0xAE, 0xF2, 0x62, 0xD4, 0x8D, 0x8E, 0x2B, 0x2E, 0x8D, 0x59, 0x95,
-                0xF6, 0x6D, 0xD5, 0x11,
-            ],
-            &[
-                0xF0, 0xB4, 0x66, 0x5D, 0x3F, 0xA9, 0xBC, 0x36, 0x2F, 0x2C, 0x77, 0xC2, 0x40, 0xAF,
-                0x03, 0xB1, 0x6B, 0xAA, 0x97, 0xBE, 0x8C, 0x63, 0xB2, 0x2C, 0xE0, 0xF9, 0x18, 0x16,
-                0x10, 0xA4, 0xF2, 0x00,
-            ],
-            &[
-                0x14, 0x74, 0xD9, 0xE4, 0x2E, 0x17, 0x97, 0x38, 0xB1, 0x10, 0x40, 0x83, 0xCB, 0xBE,
-                0xE8, 0x36, 0x7B, 0xA8, 0x61, 0x44, 0x18, 0xA9, 0x60, 0x89, 0x89, 0x57, 0xAA, 0xF0,
-                0x93, 0x57, 0x46, 0x02,
-            ],
-        ],
-    ];
-
-    // // the following code is used to dump the key schedule to screen
-    // // in a sage friendly format
-    // let keys2 = rescue_perm.key_schedule(&zero);
-    // for e in keys2.iter() {
-    //     for f in e.vec.iter() {
-    //         ark_std::println!("permutation_round_key.append(0x{})",
-    // f.into_bigint());     }
-    // }
-    // The keys are not cross-checked with sage, however, the hash function
-    // is, which implies that the permutation round keys must be correct
-    // (or else, the hash function outputs will not match)
-    // see unit tests:
-    //   - test_sponge()
-    //   - test_rescue_hash_on_0_vec()
-    //   - test_rescue_perm_on_0_vec()
-
-    const PERMUTATION_ROUND_KEYS: [[&'static [u8]; 4]; 25] = [
-        [
-            &[
-                0x0E, 0x4B, 0x21, 0x08, 0x01, 0x45, 0xB1, 0xBE, 0x83, 0x49, 0x2B, 0xCE, 0x0B, 0x49,
-                0xB4, 0xFA, 0xDF, 0x93, 0xE2, 0x19, 0xBC, 0x27, 0x3F, 0xA3, 0x71, 0x5B, 0x13, 0x7F,
-                0xDD, 0x51, 0xE8, 0x0F,
-            ],
-            &[
-                0x76, 0x35, 0x95, 0xBD, 0x7D, 0xC7, 0x99, 0x9C, 0x0E, 0x54, 0xEC, 0xCF, 0x4D, 0x6D,
-                0x74, 0x2A, 0x94, 0x7F, 0xFF, 0x41, 0xDD, 0xB3, 0x4E, 0x06, 0x3A, 0xBD, 0x85, 0x2F,
-                0x4C, 0x2E, 0x59, 0x00,
-            ],
-            &[
-                0x03, 0x38, 0x61, 0xEB, 0x1C, 0x39, 0xE2, 0x72, 0x0C, 0xCA, 0xB6, 0xDD, 0xFA, 0xAD,
-                0xF9, 0x09, 0xDA, 0x88, 0x59, 0x13, 0xA7, 0x98, 0xB1, 0x12, 0x00, 0x20, 0xA7, 0x08,
-                0xF0, 0xAB, 0x80, 0x12,
-            ],
-            &[
-                0xCF, 0x0E, 0xB8, 0xED, 0x6C, 0xB3, 0x1D, 0xA8, 0x53, 0x05, 0x9C, 0x14, 0x7D, 0x1B,
-                0x65, 0xD7, 0x34, 0x0C, 0x95, 0xCA, 0x4C, 0x43, 0xA2, 0xE3, 0xF4, 0x16, 0x67, 0x4B,
-                0xA8, 0x46, 0xD9, 0x04,
-            ],
-        ],
-        [
-            &[
-                0xFA, 0xA8, 0x9F, 0x35, 0xDE, 0x59, 0x0B, 0x50, 0x9A, 0x1B, 0xBB, 0x79, 0xE5, 0x91,
-                0xCA, 0x34, 0x45, 0x4C, 0x80, 0x1F, 0xAD, 0xD4, 0xCB, 0xDC, 0x00, 0xA4, 0xFF, 0x1D,
-                0xB9, 0xFA, 0xF4, 0x0A,
-            ],
-            &[
-                0x6D, 0x31, 0x7C, 0xD2, 0x9D, 0xDF, 0x29, 0x84, 0x1C, 0xE7, 0x7C, 0x6A, 0xD1, 0x1E,
-                0x5E, 0x4F, 0x19, 0xD9, 0xCF, 0x40, 0x2F, 0x6F, 0x01, 0x53, 0x45, 0x3E, 0xA3, 0x55,
-                0x98, 0x74, 0x6B, 0x11,
-            ],
-            &[
-                0xCD, 0xF1, 0x00, 0x36, 0x40, 0x28, 0xA8, 0xDE, 0xE3, 0xD2, 0x23, 0xFE, 0x80, 0x54,
-                0xD3, 0xD1, 0x32, 0x8D, 0xEB, 0xA4, 0x4B, 0x48, 0xE0, 0x74, 0x7D, 0x24, 0x75, 0x8F,
-                0x4E, 0x88, 0xE3, 0x00,
-            ],
-            &[
-                0xF1, 0x8F, 0xCB, 0x0D, 0x02, 0x60, 0xA3, 0x31, 0x02, 0x96, 0x6B, 0xD5, 0xCD, 0x33,
-                0x43, 0x24, 0xB4, 0xF7, 0x9F, 0x07, 0x23, 0xC6, 0x84, 0xDF, 0x49, 0xE9, 0x65, 0x72,
-                0x27, 0x1A, 0xD9, 0x08,
-            ],
-        ],
-        [
-            &[
-                0x8B, 0xA0, 0x5C, 0xE7, 0x15, 0xC3, 0x95, 0x2E, 0x7F, 0xD2, 0x12, 0xB6, 0xB9, 0x38,
-                0x6F, 0xA6, 0x11, 0x3D, 0xF8, 0xD1, 0x20, 0x6B, 0xA1, 0xCA, 0x05, 0x1E, 0x2E, 0x2F,
-                0xE0, 0x6E, 0xC5, 0x10,
-            ],
-            &[
-                0x20, 0x32, 0xD2, 0x8B, 0x34, 0x35, 0x10, 0x8B, 0x5F, 0x30, 0x49, 0x7E, 0xA8, 0xF4,
-                0x25, 0x7D, 0x8E, 0x62, 0x30, 0x30, 0xB8, 0x47, 0x3C, 0x17, 0x09, 0x6F, 0x66, 0x39,
-                0xD4, 0xC8, 0xD2, 0x09,
-            ],
-            &[
-                0xA5, 0xB4, 0x1A, 0xB6, 0x68, 0x14, 0x7B, 0xFA, 0xF0, 0xE7, 0x2A, 0xAC, 0x4F, 0x36,
-                0xE0, 0xF1, 0xED, 0x5C, 0xB1, 0xE7, 0x2A, 0x4F, 0x9E, 0x1A, 0x88, 0x49, 0xB8, 0x6A,
-                0xA2, 0xB3, 0xE8, 0x0F,
-            ],
-            &[
-                0x5A, 0x93, 0xE8, 0x9D, 0x46, 0x72, 0x94, 0xCF, 0x64, 0xBC, 0x3D, 0x5A, 0x67, 0x85,
-                0x4B, 0x2B, 0xD5, 0x83, 0xF6, 0x4D, 0xB7, 0xEC, 0x21, 0xA2, 0x93, 0x1A, 0x69, 0x08,
-                0x09, 0xAC, 0xAA, 0x12,
-            ],
-        ],
-        [
-            &[
-                0xEE, 0x4D, 0xC4, 0x60, 0xC4, 0xC6, 0xDA, 0x0B, 0xCA, 0xD6, 0x43, 0xFD, 0x9E, 0x7D,
-                0x72, 0x96, 0x01, 0x7B, 0x3D, 0xFC, 0xB1, 0xAD, 0x72, 0x4C, 0x26, 0x1B, 0x3B, 0x12,
-                0x20, 0xDE, 0x4F, 0x0A,
-            ],
-            &[
-                0x2C, 0xC0, 0x7C, 0x1E, 0x8B, 0x33, 0x26, 0x5D, 0xC5, 0xCA, 0xC1, 0x35, 0x4E, 0xFD,
-                0x85, 0xE9, 0x6F, 0xBB, 0xE6, 0x05, 0xCF, 0x29, 0x0B, 0x4E, 0x18, 0xB7, 0x3D, 0x43,
-                0x05, 0x0A, 0xE4, 0x01,
-            ],
-            &[
-                0x28, 0xC9, 0x2D, 0x53, 0xD5, 0xDB, 0x9A, 0xEA, 0x1E, 0x0D, 0x77, 0xB5, 0x32, 0xF8,
-                0x58, 0x0B, 0xDE, 0x75, 0x29, 0xC4, 0x99, 0xD9, 0xD9, 0xED, 0xDC, 0xB7, 0xBC, 0x72,
-                0xAC, 0xFC, 0xCA, 0x0D,
-            ],
-            &[
-                0x5E, 0x15, 0xF3, 0xC4, 0xA7, 0xFB, 0x81, 0x12, 0x8D, 0xFE, 0x4F, 0x8A, 0x77, 0x47,
-                0xEA, 0x39, 0xC6, 0xD9, 0xEF, 0x8C, 0x33, 0x23, 0x82, 0x89, 0x56, 0x7E, 0x3B, 0x59,
-                0x16, 0x6A, 0x96, 0x0A,
-            ],
-        ],
-        [
-            &[
-                0xA5, 0xAF, 0x90, 0x1E, 0x6F, 0xC2, 0x91, 0xE5, 0x95, 0xBC, 0x12, 0x32, 0xED, 0x98,
-                0x08, 0x60, 0x0B, 0x70, 0x7B, 0x3D, 0x60, 0x13, 0x41, 0x44, 0x59, 0x45, 0x1F, 0x0B,
-                0xD6, 0x2D, 0x94, 0x05,
-            ],
-            &[
-                0xEF, 0x4F, 0x3B, 0xD6, 0xFD, 0xF2, 0x9A, 0x67, 0x0E, 0x3C, 0xD5, 0xD6, 0x5D, 0x72,
-                0x83, 0x18, 0x55, 0x0A, 0x4E, 0x5E, 0xFC, 0xD1, 0x9B, 0x7C, 0x5C, 0x20, 0xE3, 0xE5,
-                0xF5, 0xDA, 0xB6, 0x08,
-            ],
-            &[
-                0xF2, 0xF3, 0xF3, 0xFF, 0xA2, 0x15, 0x7B, 0x3E, 0x8E, 0x79, 0xED, 0xBF, 0x9E, 0x20,
-                0xE4, 0x87, 0x6F, 0x55, 0x4B, 0x93, 0x26, 0x7F, 0xD2, 0x86, 0x0B, 0x1E, 0x72, 0x63,
-                0x2F, 0xE9, 0x18, 0x0C,
-            ],
-            &[
-                0x5B, 0x28, 0x9E, 0xF0, 0xDA, 0x86, 0x23, 0x81, 0xB7, 0x70, 0x3B, 0xAC, 0x32, 0x08,
-                0x35, 0x1E, 0x40, 0x01, 0x4A, 0x8D, 0xB0, 0xAF, 0xA7, 0x5D, 0x58, 0x8D, 0xB1, 0x65,
-                0xE9, 0x77, 0x19, 0x10,
-            ],
-        ],
-        [
-            &[
-                0xD2, 0x47, 0x22, 0xA0, 0xEE, 0x60, 0x1A, 0xC6, 0xE6, 0x64, 0xEB, 0x03, 0x8E, 0xE9,
-                0x35, 0x67, 0xCD, 0xB7, 0x3F, 0x79, 0x5F, 0x10, 0x78, 0xE8, 0x20, 0x99, 0xC4, 0xF8,
-                0x21, 0x05, 0xE3, 0x10,
-            ],
-            &[
-                0x31, 0x63, 0xB7, 0x90, 0xE3, 0xDD, 0xF9, 0xDD, 0x76, 0x3B, 0x5E, 0x1F, 0x4C, 0x53,
-                0x77, 0x02, 0xE5, 0x4A, 0xA8, 0x47, 0x50, 0x78, 0x37, 0x76, 0x60, 0xF5, 0xB8, 0x7A,
-                0x23, 0xCD, 0x40, 0x06,
-            ],
-            &[
-                0x2A, 0x61, 0x94, 0x44, 0x98, 0x29, 0xDF, 0x92, 0x91, 0x09, 0xC0, 0x96, 0xD8, 0x4D,
-                0xEE, 0xC4, 0x0D, 0x18, 0xB5, 0xD1, 0xBD, 0xD4, 0x46, 0x67, 0x29, 0xDC, 0x05, 0xC8,
-                0xF8, 0xE9, 0x63, 0x06,
-            ],
-            &[
-                0xB8, 0x31, 0x55, 0x0D, 0x03, 0x33, 0x82, 0x49, 0x80, 0x77, 0xC6, 0xC3, 0x88, 0x6F,
-                0xA9, 0x16, 0x24, 0x43, 0xD8, 0x51, 0x9A, 0x4A, 0x70, 0x09, 0xAC, 0x9B, 0xD2, 0x72,
-                0x8E, 0xF7, 0x6C, 0x05,
-            ],
-        ],
-        [
-            &[
-                0x07, 0xE0, 0xA8, 0x97, 0x28, 0x0E, 0xB9, 0xA5, 0x29, 0xF3, 0x22, 0xDD, 0x54, 0xAB,
-                0x65, 0x5D, 0x32, 0x06, 0x77, 0x77, 0x29, 0x26, 0x7C, 0xDC, 0x20, 0x7F, 0xE1, 0x82,
-                0x46, 0x25, 0x86, 0x04,
-            ],
-            &[
-                0x15, 0x60, 0x05, 0x22, 0x59, 0xE6, 0x5B, 0xAD, 0xB1, 0xA2, 0xB6, 0x5E, 0x4E, 0xC2,
-                0x5A, 0xED, 0xAE, 0x8C, 0xF6, 0xB4, 0x70, 0x35, 0xA4, 0xBE, 0xF8, 0xF9, 0x3C, 0xED,
-                0x2B, 0x9C, 0x3A, 0x08,
-            ],
-            &[
-                0x88, 0xA8, 0x7D, 0xF3, 0x25, 0x0A, 0x05, 0x14, 0xE3, 0x9C, 0xAC, 0xFF, 0x07, 0x11,
-                0x4F, 0x0D, 0x77, 0x03, 0xCC, 0x8D, 0x73, 0x87, 0x4C, 0x30, 0x70, 0xF6, 0xD2, 0xE4,
-                0x32, 0xF3, 0xD8, 0x04,
-            ],
-            &[
-                0xF8, 0x52, 0x6E, 0x17, 0x26, 0xBD, 0xF7, 0x2C, 0x16, 0x70, 0xDC, 0xF7, 0x00, 0x6B,
-                0xC4, 0x57, 0xB5, 0xCB, 0x5B, 0xF4, 0xAE, 0xC0, 0x99, 0x5A, 0xBC, 0xFF, 0x1F, 0xAE,
-                0xF0, 0x99, 0xD6, 0x06,
-            ],
-        ],
-        [
-            &[
-                0xC9, 0x61, 0xD5, 0xEC, 0xDC, 0xD8, 0xA2, 0x35, 0xBD, 0x52, 0xB0, 0x72, 0x0C, 0xC9,
-                0xEB, 0x75, 0x78, 0x5A, 0x92, 0x48, 0x80, 0xE9, 0x45, 0x32, 0xD7, 0xDE, 0xC2, 0x9A,
-                0x9D, 0x5D, 0x4E, 0x11,
-            ],
-            &[
-                0xFF, 0xDF, 0xC1, 0x11, 0xD4, 0xCE, 0x25, 0x2C, 0xE3, 0x1F, 0xA2, 0xEF, 0xA2, 0x07,
-                0x24, 0x24, 0x79, 0x95, 0x38, 0x4A, 0x13, 0x1A, 0x47, 0x8C, 0x1C, 0x1E, 0xFD, 0x96,
-                0xDE, 0x5F, 0x79, 0x08,
-            ],
-            &[
-                0x53, 0x42, 0xE3, 0xEF, 0xFC, 0xD6, 0xA1, 0xA3, 0xB4, 0x0D, 0xB8, 0x13, 0x4A, 0x3E,
-                0xBA, 0x8E, 0xAE, 0xE1, 0x47, 0x9A, 0x9B, 0x7F, 0x62, 0x9D, 0x6D, 0x80, 0xEF, 0x73,
-                0x70, 0x83, 0x91, 0x10,
-            ],
-            &[
-                0x06, 0x7C, 0xDA, 0xB8, 0x44, 0x1C, 0x64, 0xCE, 0xE7, 0x75, 0xB5, 0xCC, 0x63, 0x6E,
-                0x21, 0x03, 0x28, 0x66, 0xFF, 0xB7, 0xA8, 0xF8, 0x82, 0xC0, 0x3B, 0xB4, 0xFF, 0x39,
-                0x52, 0x96, 0x9C, 0x06,
-            ],
-        ],
-        [
-            &[
-                0xA1, 0xF5, 0xCD, 0x0C, 0x4C, 0x4D, 0x78, 0x3F, 0x0C, 0xCE, 0x20, 0x32, 0x02, 0x99,
-                0x64, 0x53, 0xB3, 0x50, 0x33, 0xE2, 0x64, 0x7F, 0x18, 0xA0, 0xC5, 0x01, 0xD9, 0x53,
-                0xDF, 0x98, 0x2F, 0x05,
-            ],
-            &[
-                0xCE, 0xBC, 0xE0, 0x40, 0xD6, 0x06, 0x6B, 0xD4, 0x4B, 0x93, 0xF4, 0x03, 0x5D, 0xFB,
-                0x9B, 0xDC, 0x85, 0xB5, 0x03, 0x60, 0x7B, 0xCB, 0xE0, 0xC1, 0xC3, 0xC5, 0x36, 0xC1,
-                0xDF, 0xD4, 0xD1, 0x10,
-            ],
-            &[
-                0xC4, 0xF1, 0x27, 0x88, 0xD9, 0x5C, 0x48, 0xA3, 0x59, 0x58, 0x4C, 0x3E, 0x5C, 0xDB,
-                0x75, 0xFF, 0xF2, 0x11, 0xF3, 0x2E, 0x54, 0x27, 0xD0, 0x95, 0xC9, 0xB3, 0x1A, 0x73,
-                0xA3, 0xA1, 0x50, 0x05,
-            ],
-            &[
-                0xBE, 0x45, 0x27, 0x37, 0x9D, 0x7D, 0xF4, 0x95, 0x75, 0x9C, 0xD3, 0xBA, 0xE3, 0x6F,
-                0xE2, 0x6D, 0xE4, 0x28, 0x62, 0xA8, 0xA9, 0xC4, 0x80, 0xB8, 0xA8, 0xA5, 0x8D, 0x56,
-                0x48, 0xC0, 0xBE, 0x07,
-            ],
-        ],
-        [
-            &[
-                0xCC, 0xCE, 0x28, 0xBA, 0x3F, 0xA3, 0x96, 0x47, 0xB6, 0xB6, 0xB8, 0x1F, 0xEE, 0x04,
-                0xEC, 0xD5, 0x2E, 0x13, 0x13, 0x87, 0x1B, 0xA8, 0xD8, 0x78, 0xA3, 0x82, 0x16, 0xF5,
-                0xA9, 0x75, 0x79, 0x11,
-            ],
-            &[
-                0x12, 0x18, 0x9F, 0x02, 0xF3, 0xC9, 0x91, 0xC7, 0xF8, 0xDE, 0xAE, 0xE9, 0xE3, 0x32,
-                0x42, 0xEA, 0xFA, 0x64, 0xA0, 0x48, 0x6B, 0x15, 0x7F, 0x2A, 0x2E, 0x77, 0xEC, 0xAB,
-                0x68, 0xB5, 0xD8, 0x0E,
-            ],
-            &[
-                0x1D, 0xD3, 0xDE, 0x48, 0x42, 0xD4, 0xA8, 0x63, 0x03, 0xC0, 0x0C, 0x39, 0xF0, 0x84,
-                0x20, 0x12, 0x8B, 0xBE, 0x33, 0xA6, 0x16, 0x74, 0xAD, 0x19, 0xE9, 0x81, 0x68, 0x12,
-                0x99, 0x74, 0x9E, 0x09,
-            ],
-            &[
-                0x9A, 0x94, 0x6F, 0xF0, 0xBB, 0x91, 0x87, 0x54, 0x49, 0x26, 0x93, 0x98, 0x63, 0x53,
-                0x70, 0x14, 0x16, 0xE8, 0x5C, 0xE0, 0xC8, 0x67, 0xFB, 0x26, 0xD9, 0xF2, 0xE2, 0x4D,
-                0xA8, 0x56, 0xA7, 0x03,
-            ],
-        ],
-        [
-            &[
-                0x45, 0x5C, 0x10, 0xE3, 0xF6, 0xBB, 0x1F, 0x08, 0x07, 0x4F, 0x79, 0xFB, 0xFD, 0xAD,
-                0x7D, 0x70, 0xC8, 0x3F, 0xB8, 0x50, 0x25, 0x92, 0x30, 0xD4, 0xE4, 0x8E, 0x42, 0xC2,
-                0x95, 0xFD, 0x98, 0x0B,
-            ],
-            &[
-                0x34, 0x01, 0x58, 0x17, 0xE0, 0x1F, 0xB6, 0xDE, 0x0A, 0x65, 0x14, 0xB1, 0xD9, 0x04,
-                0xB9, 0xF6, 0x46, 0x0D, 0x77, 0x0A, 0xC3, 0xDD, 0x8A, 0xB5, 0xCD, 0xE7, 0x59, 0xF4,
-                0x13, 0x2F, 0x9F, 0x11,
-            ],
-            &[
-                0x82, 0x86, 0xED, 0xC8, 0x07, 0xD6, 0x9C, 0xE2, 0x4A, 0x42, 0x68, 0xCC, 0xF4, 0x2A,
-                0xDD, 0x0B, 0xF5, 0x5E, 0xD6, 0x7F, 0x02, 0xCA, 0x4D, 0x6C, 0xC0, 0x88, 0x27, 0x29,
-                0x71, 0xB5, 0xBB, 0x0A,
-            ],
-            &[
-                0x46, 0xD6, 0xAD, 0x70, 0xC2, 0x54, 0x64, 0x82, 0x66, 0xA9, 0x69, 0x7A, 0xAC, 0xEB,
-                0xCA, 0x68, 0x69, 0x04, 0xFE, 0xB8, 0x1E, 0x94, 0x40, 0x22, 0x9C, 0x69, 0x1F, 0xC6,
-                0xBF, 0x2E, 0x34, 0x0F,
-            ],
-        ],
-        [
-            &[
-                0xE8, 0xCB, 0xE2, 0xCA, 0x7C, 0xFC, 0x64, 0x65, 0x5D, 0xBB, 0x8C, 0x0E, 0xAF, 0xBF,
-                0xDD, 0x16, 0xC1, 0xD5, 0x95, 0xDC, 0x84, 0x11, 0x47, 0x17, 0x34, 0x35, 0xD9, 0x93,
-                0xB8, 0x32, 0xFA, 0x04,
-            ],
-            &[
-                0xF1, 0x8B, 0x8A, 0x01, 0xED, 0x56, 0x70, 0x43, 0x40, 0x3A, 0x22, 0x90, 0xE0, 0x0D,
-                0x24, 0xF2, 0x25, 0x8B, 0xB1, 0x1E, 0x73, 0x37, 0x50, 0xF7, 0xDF, 0x4A, 0x25, 0x33,
-                0xCC, 0xD6, 0x16, 0x01,
-            ],
-            &[
-                0x4D, 0xA4, 0x5F, 0x15, 0x60, 0xB6, 0x2B, 0x11, 0x14, 0x12, 0xAA, 0x42, 0xA0, 0xFC,
-                0x91, 0xBB, 0x45, 0x58, 0x3F, 0x38, 0x07, 0xCB, 0x9C, 0x73, 0x8B, 0x23, 0xD9, 0xD9,
-                0x6C, 0x07, 0x6B, 0x00,
-            ],
-            &[
-                0x38, 0xE2, 0xE3, 0x3F, 0xFB, 0xF2, 0xFC, 0xFD, 0x00, 0x49, 0x9E, 0x3F, 0x8B, 0xEC,
-                0xF0, 0xB0, 0xF3, 0x31, 0x3C, 0x70, 0x6C, 0x7F, 0x71, 0xE8, 0xB2, 0x4B, 0x63, 0x37,
-                0x0F, 0x0F, 0x7B, 0x03,
-            ],
-        ],
-        [
-            &[
-                0xED, 0x77, 0x32, 0x95, 0xCE, 0x9C, 0x70, 0xC4, 0x57, 0x52, 0x4E, 0xE6, 0x6C, 0x95,
-                0x33, 0x90, 0x9A, 0x51, 0x80, 0x13, 0xB3, 0xD9, 0xFA, 0x7B, 0x2D, 0xD8, 0x6D, 0x34,
-                0x0E, 0x9E, 0x2D, 0x11,
-            ],
-            &[
-                0x6F, 0x5D, 0xDD, 0xD7, 0x63, 0x5B, 0xDC, 0xCC, 0x02, 0xBC, 0x22, 0x95, 0xA2, 0xFD,
-                0x43, 0xFB, 0x65, 0x8E, 0xD9, 0xE9, 0x4E, 0x2E, 0x4D, 0x9E, 0x37, 0x09, 0x12, 0x9C,
-                0xB9, 0xCC, 0xDB, 0x08,
-            ],
-            &[
-                0xA7, 0xF4, 0x7A, 0x90, 0xF2, 0xB6, 0x44, 0x08, 0xA0, 0xE5, 0x0C, 0x9E, 0x6B, 0xCC,
-                0xE4, 0x97, 0x02, 0xA0, 0x16, 0x02, 0xC8, 0x49, 0x88, 0xF3, 0x51, 0xCB, 0x8E, 0xBB,
-                0xC2, 0x3D, 0xC3, 0x0C,
-            ],
-            &[
-                0x0C, 0x52, 0xA9, 0x7A, 0xAF, 0x73, 0xFB, 0xAC, 0x96, 0x39, 0x82, 0x48, 0xFD, 0x5E,
-                0x01, 0x53, 0x6B, 0xD2, 0xD9, 0x00, 0x56, 0x6C, 0x5E, 0x4D, 0x0C, 0xD7, 0xA7, 0x73,
-                0x27, 0xBE, 0x2D, 0x07,
-            ],
-        ],
-        [
-            &[
-                0xA6, 0x73, 0xF3, 0xCC, 0xB7, 0x45, 0x0E, 0x96, 0x8A, 0xC5, 0xB9, 0xEA, 0x0E, 0x1B,
-                0x7E, 0x81, 0x5F, 0x74, 0x95, 0x27, 0xA7, 0x90, 0xF9, 0xA3, 0x7C, 0x30, 0x72, 0xD2,
-                0x49, 0x25, 0xB4, 0x06,
-            ],
-            &[
-                0xEE, 0xCE, 0xD0, 0x67, 0x13, 0xC5, 0x18, 0x89, 0x82, 0x5D, 0xB4, 0x67, 0xB7, 0x50,
-                0x72, 0xBD, 0xD3, 0xD9, 0xD8, 0x98, 0x41, 0x63, 0x01, 0x9F, 0x49, 0x88, 0xAB, 0xB6,
-                0x5B, 0x20, 0x5B, 0x04,
-            ],
-            &[
-                0x68, 0x36, 0xA4, 0x39, 0x35, 0xC0, 0x26, 0x57, 0x27, 0x2F, 0x14, 0xAD, 0x26, 0x8F,
-                0xF1, 0xD2, 0x01, 0xEF, 0x78, 0xBA, 0x7C, 0xD5, 0xE0, 0xCF, 0xCF, 0x57, 0x02, 0xDF,
-                0xF6, 0xE7, 0x50, 0x00,
-            ],
-            &[
-                0xC2, 0x61, 0xD0, 0xA0, 0x8C, 0xAE, 0xE7, 0xFB, 0x2C, 0xB1, 0x73, 0x79, 0xE8, 0x51,
-                0xFC, 0xF0, 0xB8, 0x93, 0x27, 0xA5, 0x8D, 0xF9, 0x91, 0x5B, 0x8D, 0x4E, 0x60, 0x00,
-                0x72, 0x91, 0x4E, 0x02,
-            ],
-        ],
-        [
-            &[
-                0x86, 0x73, 0xEA, 0x01, 0x35, 0xF7, 0x6B, 0xB3, 0xE5, 0xA4, 0xDB, 0xDC, 0x64, 0x97,
-                0x38, 0x0E, 0x29, 0x63, 0x6D, 0x4A, 0xD0, 0xAE, 0xE4, 0xA0, 0x06, 0xB1, 0xA3, 0xC3,
-                0xA0, 0xC2, 0x49, 0x04,
-            ],
-            &[
-                0x13, 0x6B, 0x42, 0xA2, 0xFC, 0x92, 0xE0, 0xB0, 0x9D, 0xAD, 0xE9, 0x92, 0x98, 0x44,
-                0xA5, 0x77, 0xEF, 0x9C, 0x2C, 0xDA, 0x8B, 0xB2, 0x49, 0xBB, 0x50, 0x02, 0x8B, 0xA8,
-                0x15, 0x4A, 0x03, 0x09,
-            ],
-            &[
-                0xAE, 0x27, 0x90, 0x05, 0x86, 0x30, 0x63, 0xEC, 0xCA, 0x9D, 0xB8, 0x1C, 0xE0, 0x1C,
-                0x1D, 0xE3, 0x23, 0x29, 0xED, 0xDE, 0xF4, 0x19, 0xF0, 0xBE, 0x57, 0xFE, 0xA4, 0xB8,
-                0xE3, 0xF2, 0x18, 0x11,
-            ],
-            &[
-                0x48, 0xA3, 0x95, 0x59, 0x98, 0x8E, 0x30, 0xD9, 0x83, 0xCC, 0xFF, 0x85, 0x3D, 0x18,
-                0xF2, 0xA5, 0x52, 0x4A, 0x11, 0x8A, 0x00, 0xCB, 0x4F, 0xE6, 0xFA, 0xF8, 0x48, 0xBD,
-                0xB1, 0x57, 0x0B, 0x04,
-            ],
-        ],
-        [
-            &[
-                0x57, 0xE0, 0xD5, 0xE6, 0x82, 0xD1, 0x2A, 0xD3, 0x41, 0x2D, 0x1F, 0xF1, 0xAF, 0x60,
-                0x88, 0x09, 0xE8, 0xA2, 0x7B, 0xE5, 0x8F, 0x54, 0x44, 0xB6, 0x31, 0x7B, 0x21, 0x06,
-                0xC4, 0xFC, 0xB2, 0x09,
-            ],
-            &[
-                0xD5, 0x66, 0x84, 0xD4, 0x89, 0xE4, 0xBB, 0x55, 0xD1, 0xAD, 0xF4, 0x8F, 0x8D, 0x8B,
-                0x8D, 0x86, 0xDD, 0xF6, 0x7B, 0x38, 0x4C, 0x1D, 0x5D, 0x0A, 0xF0, 0x01, 0xC8, 0x1B,
-                0x5A, 0x4A, 0x4A, 0x06,
-            ],
-            &[
-                0x4C, 0xD7, 0x0D, 0xC3, 0xD2, 0xF3, 0x21, 0x33, 0x7F, 0xA3, 0x6F, 0xFA, 0xA9, 0xB3,
-                0xDC, 0x3D, 0x2F, 0x49, 0xD8, 0xBE, 0x53, 0x3C, 0x83, 0x77, 0x4F, 0xEF, 0x98, 0x96,
-                0x52, 0xC3, 0xE6, 0x00,
-            ],
-            &[
-                0x6D, 0x34, 0x9D, 0xD4, 0x7D, 0x6B, 0x01, 0xBB, 0x1B, 0xA1, 0xD9, 0xFE, 0xA8, 0xCE,
-                0xA3, 0xAE, 0xDF, 0x31, 0x72, 0x42, 0x7C, 0xC7, 0xA6, 0xAE, 0x11, 0x5B, 0xE8, 0x67,
-                0x26, 0x5B, 0x10, 0x04,
-            ],
-        ],
-        [
-            &[
-                0x57, 0xA9, 0xAB, 0xAB, 0xA5, 0x73, 0xA0, 0x0B, 0x1D, 0xC0, 0x02, 0xB9, 0xE8, 0x0C,
-                0x6F, 0xB9, 0x96, 0xE9, 0x24, 0x2F, 0x52, 0x54, 0xA4, 0x66, 0xFF, 0x4F, 0xC1, 0x53,
-                0xC8, 0xA8, 0xA6, 0x0B,
-            ],
-            &[
-                0xE1, 0x66, 0x57, 0x93, 0xC0, 0x9B, 0xA7, 0x9E, 0x1D, 0xFB, 0xE2, 0x52, 0x3D, 0xFB,
-                0x63, 0x83, 0xC5, 0x47, 0xE0, 0x8D, 0xA6, 0xC7, 0x88, 0x15, 0x11, 0xED, 0xA3, 0x53,
-                0xE1, 0x98, 0x4E, 0x0C,
-            ],
-            &[
-                0xAE, 0x5E, 0xF4, 0xE8, 0xC3, 0x24, 0x29, 0xDF, 0x4F, 0xA0, 0x87, 0x91, 0x44, 0xB6,
-                0xC1, 0xB8, 0x2E, 0x6F, 0x24, 0x7D, 0x7E, 0x0F, 0xCB, 0xE9, 0x01, 0x42, 0xF9, 0xD5,
-                0x19, 0xB4, 0xD9, 0x0E,
-            ],
-            &[
-                0xC4, 0x81, 0x38, 0x4D, 0xF4, 0x31, 0x43, 0x7D, 0xD5, 0xC6, 0x1E, 0xA6, 0xE7, 0x4F,
-                0xD0, 0x3A, 0x4F, 0x85, 0x59, 0x4C, 0xE0, 0x67, 0x11, 0x19, 0x1F, 0x91, 0x28, 0x6C,
-                0x38, 0xA1, 0xD2, 0x0C,
-            ],
-        ],
-        [
-            &[
-                0x43, 0x1A, 0x93, 0x6D, 0x83, 0x3E, 0x4F, 0x10, 0x01, 0x03, 0x61, 0xAA, 0xFA, 0x6A,
-                0xB4, 0x79, 0xD6, 0x66, 0x43, 0x94, 0x23, 0xBD, 0x9D, 0x3F, 0x75, 0xFD, 0x9B, 0x96,
-                0x02, 0x11, 0xDF, 0x10,
-            ],
-            &[
-                0x1B, 0x21, 0xC1, 0xF6, 0xA0, 0xA7, 0x99, 0xA0, 0xF4, 0x8D, 0x81, 0x5F, 0x3C, 0x78,
-                0x46, 0xAA, 0x31, 0x68, 0x70, 0x5B, 0xE5, 0x32, 0xB4, 0x02, 0xE2, 0x2B, 0x25, 0xFA,
-                0x9E, 0x4A, 0xFA, 0x0E,
-            ],
-            &[
-                0x4F, 0x07, 0x7A, 0x94, 0x10, 0x17, 0xD6, 0xDC, 0x20, 0x83, 0x2B, 0x46, 0x89, 0x88,
-                0x94, 0xDD, 0xC1, 0x76, 0xC2, 0xED, 0x3F, 0x9E, 0xDD, 0x1A, 0xA9, 0x9D, 0x66, 0x92,
-                0xC4, 0x05, 0x94, 0x0D,
-            ],
-            &[
-                0x11, 0xFD, 0x5F, 0x22, 0xB1, 0x81, 0x94, 0x6B, 0xC5, 0xD6, 0x62, 0x85, 0x07, 0xE0,
-                0xF0, 0x6D, 0xCC, 0x66, 0xAD, 0x3C, 0xBE, 0x7D, 0x98, 0xC9, 0x35, 0x0F, 0x89, 0xAF,
-                0xD2, 0x79, 0xE2, 0x0F,
-            ],
-        ],
-        [
-            &[
-                0x26, 0xB7, 0x60, 0x24, 0x14, 0x59, 0x58, 0xC4, 0x9C, 0x7A, 0xFC, 0xF4, 0x98, 0x97,
-                0x54, 0x65, 0x9B, 0x66, 0xA6, 0x86, 0x0B, 0x3B, 0x3E, 0x74, 0x0B, 0x6B, 0x43, 0xC6,
-                0x2F, 0x75, 0xB4, 0x0C,
-            ],
-            &[
-                0xEE, 0xCC, 0x61, 0x27, 0x10, 0xF2, 0x24, 0x3E, 0x08, 0x5C, 0x20, 0x4E, 0x71, 0x0F,
-                0x6B, 0xCF, 0xAE, 0x52, 0x75, 0x02, 0x5E, 0xE8, 0x62, 0x1C, 0x71, 0xFC, 0xF2, 0xC3,
-                0x02, 0x53, 0x4F, 0x0C,
-            ],
-            &[
-                0x4C, 0xED, 0xB7, 0x2B, 0xF3, 0x1C, 0xA1, 0x67, 0xD1, 0xC5, 0x99, 0xAC, 0x9E, 0x97,
-                0xA5, 0x87, 0x6C, 0x84, 0x1C, 0x0A, 0xBB, 0x1D, 0x19, 0x78, 0x51, 0x6C, 0x33, 0x18,
-                0x7E, 0x43, 0xA5, 0x0F,
-            ],
-            &[
-                0x18, 0xE8, 0x1A, 0xEE, 0xE0, 0x54, 0x92, 0xC3, 0x25, 0x2F, 0xA5, 0x7D, 0x64, 0xA5,
-                0x68, 0xF9, 0x5E, 0xFF, 0xA0, 0x39, 0x44, 0x96, 0x44, 0xE0, 0x6B, 0xBE, 0xF7, 0xC7,
-                0x8A, 0x06, 0xE7, 0x00,
-            ],
-        ],
-        [
-            &[
-                0xD1, 0x36, 0x41, 0x27, 0xA8, 0x93, 0xAE, 0x22, 0x99, 0x5B, 0x45, 0x37, 0x4E, 0xB9,
-                0x4A, 0x6F, 0x40, 0xBF, 0x44, 0x41, 0xE4, 0x3F, 0x41, 0x8A, 0x0D, 0x11, 0x60, 0x70,
-                0x69, 0xA5, 0x78, 0x02,
-            ],
-            &[
-                0x02, 0x62, 0x80, 0x8A, 0xC7, 0xBF, 0x3C, 0xB5, 0x2D, 0xF9, 0xBB, 0x7E, 0x20, 0xA6,
-                0x1E, 0x40, 0xFD, 0xDB, 0xBA, 0x26, 0xAC, 0x88, 0xEA, 0xAD, 0x73, 0x00, 0xA9, 0x53,
-                0x4B, 0x0E, 0xDF, 0x08,
-            ],
-            &[
-                0x82, 0x2B, 0x32, 0x21, 0xC8, 0xBD, 0x4E, 0x0D, 0x3F, 0xD1, 0x44, 0xB1, 0x93, 0x0B,
-                0x0D, 0x27, 0x61, 0x80, 0x96, 0x0A, 0xFD, 0x2B, 0xF9, 0x7A, 0xF7, 0x85, 0x53, 0x9C,
-                0x33, 0xD7, 0x55, 0x02,
-            ],
-            &[
-                0xAE, 0xD1, 0x4F, 0x9F, 0xAA, 0xBE, 0x1B, 0x52, 0xAB, 0xAD, 0xA3, 0x8F, 0x0F, 0x5F,
-                0xBE, 0xAE, 0xF6, 0x27, 0x90, 0x59, 0x28, 0xE0, 0x8B, 0x00, 0xC9, 0xAA, 0x65, 0x99,
-                0x96, 0x38, 0x0E, 0x02,
-            ],
-        ],
-        [
-            &[
-                0x58, 0xF5, 0xE8, 0x6D, 0x9F, 0xFB, 0xB3, 0xD9, 0x13, 0x00, 0xAE, 0xAE, 0x4B, 0xA0,
-                0x4E, 0x9B, 0x7C, 0xFD, 0xF1, 0xA5, 0xF1, 0x1D, 0xB3, 0xF6, 0xD3, 0x20, 0x6A, 0x61,
-                0xAF, 0xEC, 0x42, 0x05,
-            ],
-            &[
-                0xFE, 0x5D, 0x50, 0xC7, 0x6C, 0x0F, 0xD7, 0xE4, 0x1E, 0x04, 0xF5, 0x28, 0xD7, 0x4F,
-                0xDD, 0xDD, 0x2B, 0x81, 0x7B, 0x87, 0x23, 0x22, 0x6E, 0x65, 0xD5, 0x6D, 0x5B, 0x15,
-                0x5F, 0x4C, 0x99, 0x0D,
-            ],
-            &[
-                0x68, 0xA0, 0x9C, 0x22, 0x3B, 0x31, 0xA3, 0x2E, 0x34, 0x4B, 0xC1, 0x24, 0xFE, 0xED,
-                0x43, 0x4C, 0xEB, 0x3B, 0x98, 0x56, 0xDB, 0x9C, 0x31, 0xBA, 0x15, 0xC9, 0x74, 0x4E,
-                0x61, 0x62, 0xF7, 0x04,
-            ],
-            &[
-                0x1C, 0x89, 0x79, 0x4D, 0x87, 0x59, 0xE7, 0x55, 0x30, 0x19, 0x15, 0x6C, 0xD1, 0x35,
-                0x54, 0x43, 0xF7, 0xCE, 0x20, 0x1F, 0x26, 0x66, 0x5D, 0x8F, 0xE3, 0x5B, 0x0C, 0x49,
-                0x9F, 0xC7, 0x65, 0x08,
-            ],
-        ],
-        [
-            &[
-                0x22, 0x2D, 0x87, 0xBE, 0x02, 0xE7, 0x58, 0x38, 0x80, 0x22, 0xBF, 0x2F, 0xFF, 0xFB,
-                0xE0, 0x2B, 0x32, 0xA5, 0x00, 0xF4, 0x98, 0xB8, 0xB9, 0x74, 0xB5, 0xE5, 0x27, 0x78,
-                0x4A, 0x86, 0x59, 0x11,
-            ],
-            &[
-                0x71, 0xA8, 0x4E, 0x93, 0xB4, 0x89, 0xFA, 0x72, 0x8A, 0xFC, 0x01, 0xE5, 0x66, 0x6E,
-                0x72, 0x8A, 0x4E, 0xD5, 0xBB, 0x11, 0xA2, 0xCB, 0xC9, 0x71, 0x79, 0xF3, 0x84, 0x3F,
-                0x15, 0xFA, 0x8F, 0x07,
-            ],
-            &[
-                0x6A, 0x69, 0xC6, 0x97, 0x7F, 0x82, 0x66, 0x8B, 0x05, 0x69, 0xA7, 0xC8, 0x0E, 0x08,
-                0xCF, 0xB8, 0x47, 0xD2, 0x08, 0xE5, 0x6D, 0x5A, 0x2A, 0x3A, 0xD6, 0x35, 0xE7, 0x71,
-                0xE2, 0x60, 0x63, 0x00,
-            ],
-            &[
-                0xE0, 0xB5, 0x3A, 0x92, 0x7E, 0x2B, 0x31, 0x7C, 0xD1, 0x0B, 0x48, 0x7F, 0x6C, 0x0C,
-                0x8A, 0x55, 0xF4, 0x56, 0x24, 0xE7, 0xEA, 0x3F, 0xDB, 0xE4, 0xCB, 0x34, 0x95, 0xD1,
-                0xD0, 0x99, 0xA4, 0x03,
-            ],
-        ],
-        [
-            &[
-                0xB3, 0x2B, 0xFE, 0xF4, 0x4F, 0x9D, 0xB2, 0x1A, 0xB8, 0xED, 0x29, 0x6C, 0xC7, 0xA4,
-                0xC5, 0xE2, 0x8C, 0xF1, 0x42, 0x9F, 0x25, 0xBE, 0x62, 0x1B, 0x78, 0xF7, 0x7B, 0xC0,
-                0xB6, 0x02, 0xE5, 0x09,
-            ],
-            &[
-                0x65, 0xFA, 0x35, 0xF1, 0xAD, 0x2A, 0x42, 0xE1, 0x3E, 0x0A, 0xE1, 0x3A, 0xDA, 0x19,
-                0x61, 0xD1, 0xB7, 0x8F, 0xC8, 0x6E, 0xC5, 0x12, 0x9B, 0x37, 0xCC, 0x36, 0xCC, 0x86,
-                0x33, 0xD2, 0x29, 0x0B,
-            ],
-            &[
-                0xC3, 0xDE, 0x58, 0xAB, 0x8F, 0x00, 0xA9, 0x8E, 0x2A, 0xFF, 0x59, 0x3D, 0x03, 0x61,
-                0xF2, 0x04, 0xB7, 0xDC, 0x9C, 0x1D, 0x46, 0xDB, 0x26, 0xAE, 0x8F, 0x1E, 0xD2, 0xE8,
-                0xBD, 0x32, 0x5D, 0x03,
-            ],
-            &[
-                0x14, 0x19, 0xE8, 0x7E, 0xE8, 0x05, 0x6B, 0xCF, 0x1E, 0xA0, 0xE7, 0x79, 0x26, 0x08,
-                0x24, 0xD9, 0x0B, 0xF8, 0x95, 0x66, 0x31, 0x86, 0xCC, 0xB6, 0xB7, 0xF1, 0x5F, 0xFD,
-                0xE8, 0x10, 0xF9, 0x0B,
-            ],
-        ],
-        [
-            &[
-                0xEA, 0x6D, 0x1E, 0x77, 0x71, 0xEC, 0xC0, 0x8F, 0xAC, 0xAF, 0xC4, 0x84, 0xD9, 0x54,
-                0x5A, 0x43, 0xA4, 0x4D, 0x92, 0x7B, 0x05, 0x79, 0x88, 0x0D, 0xE2, 0x21, 0x53, 0x4F,
-                0x14, 0x4B, 0x92, 0x05,
-            ],
-            &[
-                0x84, 0xF6, 0xF5, 0x2D, 0x18, 0x08, 0xAE, 0x53, 0x8B, 0x30, 0x2A, 0x58, 0xF4, 0x71,
-                0xE3, 0x06, 0x5C, 0x25, 0x80, 0x62, 0x5E, 0x3F, 0xAC, 0x77, 0xDE, 0xCE, 0x6B, 0x43,
-                0x9E, 0x60, 0x73, 0x0B,
-            ],
-            &[
-                0x37, 0xDC, 0xAE, 0x2B, 0x87, 0x1F, 0xB8, 0x4F, 0xC7, 0xC5, 0x81, 0xCF, 0xDF, 0x1A,
-                0xF9, 0x53, 0x78, 0xD7, 0x7A, 0x0A, 0x69, 0xD1, 0x41, 0x5A, 0xA3, 0x6F, 0x81, 0x5B,
-                0xE2, 0xAA, 0x2C, 0x06,
-            ],
-            &[
-                0xD6, 0x8E, 0x6B, 0x79, 0xE7, 0xCC, 0x34, 0x29, 0x7B, 0xC2, 0x5D, 0xA1, 0xC0, 0x85,
-                0xEB, 0xEF, 0x81, 0x81, 0xA3, 0xE6, 0x7A, 0xE7, 0xCF, 0xF8, 0x65, 0x58, 0xE9, 0xAC,
-                0x9C, 0x4E, 0x30, 0x00,
-            ],
-        ],
-        [
-            &[
-                0xFB, 0xFB, 0x7A, 0x3E, 0xAC, 0xB5, 0x06, 0xD9, 0xF2, 0x6C, 0xB6, 0x95, 0xC1, 0x6C,
-                0x3D, 0x76, 0xBF, 0xB1, 0xFD, 0xFC, 0x4E, 0x41, 0x0F, 0xFF, 0xB2, 0xEE, 0xEA, 0x2F,
-                0x3B, 0x38, 0xDA, 0x03,
-            ],
-            &[
-                0x06, 0xBE, 0x61, 0x7F, 0xA9, 0x46, 0x63, 0xA1, 0x5C, 0xD3, 0x13, 0xB7, 0x29, 0x11,
-                0x33, 0x30, 0xEB, 0xB2, 0x2F, 0xB7, 0x81, 0x35, 0xBE, 0xD7, 0x46, 0xCC, 0xCA, 0xA3,
-                0xE6, 0xA2, 0x0B, 0x05,
-            ],
-            &[
-                0x86, 0xB1, 0x70, 0x50, 0x68, 0xB4, 0xDC, 0x52, 0xD4, 0xCE, 0x5B, 0x4E, 0x44, 0xB1,
-                0xB2, 0x4F, 0x5B, 0x25, 0x81, 0xE3, 0xFA, 0x43, 0x70, 0x5B, 0x5E, 0x0F, 0x38, 0x89,
-                0x0F, 0x89, 0x72, 0x0A,
-            ],
-            &[
-                0xA8, 0x14, 0x29, 0x32, 0x31, 0x57, 0x95, 0x77, 0x4D, 0x4B, 0xD8, 0x8D, 0x20, 0xA1,
-                0x03, 0x09, 0x98, 0xEC, 0xD5, 0x09, 0xDF, 0x0B, 0x53, 0xE5, 0xDC, 0x42, 0x30, 0x1F,
-                0x89, 0xA3, 0x25, 0x10,
-            ],
-        ],
-    ];
-}
@@ -1,1302 +0,0 @@
-// Copyright (c) 2022 Espresso Systems (espressosys.com)
-// This file is part of the Jellyfish library.
-
-// You should have received a copy of the MIT License
-// along with the Jellyfish library. If not, see <https://mit-license.org/>.
-
-use crate::rescue::{RescueParameter, ROUNDS, STATE_SIZE};
-use ark_ed_on_bls12_381::Fq;
-
-// the constants in this file are generated with
-// https://github.com/EspressoSystems/Marvellous/blob/fcd4c41672f485ac2f62526bc87a16789d4d0459/rescue381.sage
-
-// q381 = 52435875175126190479447740508185965837690552500527637822603658699938581184513
-// rescue381 = Rescue(128, q381, 4, 5)
-
-impl RescueParameter for Fq {
-    const A: u64 = 5;
-    // 1/5%(q381-1)
-    const A_INV: &'static [u64] = &[
-        0x33333332CCCCCCCD,
-        0x217F0E679998F199,
-        0xE14A56699D73F002,
-        0x2E5F0FBADD72321C,
-    ];
-
-    // The following constants are from the output of
-    //  rescue381.MDS
-    // MDS[0,0]= "52435875175126190479447740508185965837690552500527637822603658699938581066864"
-    // MDS[0,1]= "52435875175126190479447740508185965837690552500527637822603658699938534124913"
-    // MDS[0,2]= "52435875175126190479447740508185965837690552500527637822603658699922104442063"
-    // MDS[0,3]= "52435875175126190479447740508185965837690552500527637822603658694270581781713"
-    // MDS[1,0]= "137200"
-    // MDS[1,1]= "54762351"
-    // MDS[1,2]= "19167800400"
-    // MDS[1,3]= "6593435097550"
-    // MDS[2,0]= "52435875175126190479447740508185965837690552500527637822603658699938581164563"
-    // MDS[2,1]= "52435875175126190479447740508185965837690552500527637822603658699938573341713"
-    // MDS[2,2]= "52435875175126190479447740508185965837690552500527637822603658699935841949364"
-    // MDS[2,3]= "52435875175126190479447740508185965837690552500527637822603658698996613844913"
-    // MDS[3,0]= "400"
-    // MDS[3,1]= "140050"
-    // MDS[3,2]= "48177200"
-    // MDS[3,3]= "16531644851"
-    // MDS matrix in little-endian 32 bytes elements
-    const MDS_LE: [[&'static [u8]; STATE_SIZE]; STATE_SIZE] = [
-        [
-            &[
-                0x70, 0x34, 0xFE, 0xFF, 0xFE, 0xFF, 0xFF, 0xFF, 0xFE, 0x5B, 0xFE, 0xFF, 0x02, 0xA4,
-                0xBD, 0x53, 0x05, 0xD8, 0xA1, 0x09, 0x08, 0xD8, 0x39, 0x33, 0x48, 0x7D, 0x9D, 0x29,
-                0x53, 0xA7, 0xED, 0x73,
-            ],
-            &[
-                0x71, 0xED, 0x31, 0xFD, 0xFE, 0xFF, 0xFF, 0xFF, 0xFE, 0x5B, 0xFE, 0xFF, 0x02, 0xA4,
-                0xBD, 0x53, 0x05, 0xD8, 0xA1, 0x09, 0x08, 0xD8, 0x39, 0x33, 0x48, 0x7D, 0x9D, 0x29,
-                0x53, 0xA7, 0xED, 0x73,
-            ],
-            &[
-                0xCF, 0xDC, 0xE8, 0x29, 0xFB, 0xFF, 0xFF, 0xFF, 0xFE, 0x5B, 0xFE, 0xFF, 0x02, 0xA4,
-                0xBD, 0x53, 0x05, 0xD8, 0xA1, 0x09, 0x08, 0xD8, 0x39, 0x33, 0x48, 0x7D, 0x9D, 0x29,
-                0x53, 0xA7, 0xED, 0x73,
-            ],
-            &[
-                0xD1, 0xB4, 0xE8, 0x50, 0xD7, 0xFA, 0xFF, 0xFF, 0xFE, 0x5B, 0xFE, 0xFF, 0x02, 0xA4,
-                0xBD, 0x53, 0x05, 0xD8, 0xA1, 0x09, 0x08, 0xD8, 0x39, 0x33, 0x48, 0x7D, 0x9D, 0x29,
-                0x53, 0xA7, 0xED, 0x73,
-            ],
-        ],
-        [
-            &[
-                0xF0, 0x17, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-                0x00, 0x00, 0x00, 0x00,
-            ],
-            &[
-                0x6F, 0x9B, 0x43, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-                0x00, 0x00, 0x00, 0x00,
-            ],
-            &[
-                0x50, 0x6C, 0x7D, 0x76, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-                0x00, 0x00, 0x00, 0x00,
-            ],
-            &[
-                0xCE, 0x59, 0x5B, 0x27, 0xFF, 0x05, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-                0x00, 0x00, 0x00, 0x00,
-            ],
-        ],
-        [
-            &[
-                0x13, 0xB2, 0xFF, 0xFF, 0xFE, 0xFF, 0xFF, 0xFF, 0xFE, 0x5B, 0xFE, 0xFF, 0x02, 0xA4,
-                0xBD, 0x53, 0x05, 0xD8, 0xA1, 0x09, 0x08, 0xD8, 0x39, 0x33, 0x48, 0x7D, 0x9D, 0x29,
-                0x53, 0xA7, 0xED, 0x73,
-            ],
-            &[
-                0x11, 0x54, 0x88, 0xFF, 0xFE, 0xFF, 0xFF, 0xFF, 0xFE, 0x5B, 0xFE, 0xFF, 0x02, 0xA4,
-                0xBD, 0x53, 0x05, 0xD8, 0xA1, 0x09, 0x08, 0xD8, 0x39, 0x33, 0x48, 0x7D, 0x9D, 0x29,
-                0x53, 0xA7, 0xED, 0x73,
-            ],
-            &[
-                0xB4, 0x96, 0xBA, 0x5C, 0xFE, 0xFF, 0xFF, 0xFF, 0xFE, 0x5B, 0xFE, 0xFF, 0x02, 0xA4,
-                0xBD, 0x53, 0x05, 0xD8, 0xA1, 0x09, 0x08, 0xD8, 0x39, 0x33, 0x48, 0x7D, 0x9D, 0x29,
-                0x53, 0xA7, 0xED, 0x73,
-            ],
-            &[
-                0xB1, 0x0F, 0x5F, 0xAE, 0x23, 0xFF, 0xFF, 0xFF, 0xFE, 0x5B, 0xFE, 0xFF, 0x02, 0xA4,
-                0xBD, 0x53, 0x05, 0xD8, 0xA1, 0x09, 0x08, 0xD8, 0x39, 0x33, 0x48, 0x7D, 0x9D, 0x29,
-                0x53, 0xA7, 0xED, 0x73,
-            ],
-        ],
-        [
-            &[
-                0x90, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-                0x00, 0x00, 0x00, 0x00,
-            ],
-            &[
-                0x12, 0x23, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-                0x00, 0x00, 0x00, 0x00,
-            ],
-            &[
-                0x30, 0x20, 0xDF, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-                0x00, 0x00, 0x00, 0x00,
-            ],
-            &[
-                0xB3, 0xE1, 0x5C, 0xD9, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-                0x00, 0x00, 0x00, 0x00,
-            ],
-        ],
-    ];
-
-    // The following constants are from the output of
-    // rescue381.initial_constant
-    // a.k.a. INIT_VEC
-    // [23134431890904997735913685390433273947519177060544011867815065124418348995661]
-    // [ 7910743581020883359489900822814213105822551758045258908574127548576902234202]
-    // [ 6452335108146897903818881932868089947456740590166061243393158685187431809297]
-    // [  864592593827916191968939823230510547087468030011538620003456937932684270153]
-    const INIT_VEC_LE: [&'static [u8]; STATE_SIZE] = [
-        &[
-            0x4D, 0x54, 0x21, 0x08, 0xC7, 0xC6, 0x88, 0xB1, 0xBD, 0x2E, 0x14, 0xE1, 0xA3, 0xB1,
-            0xD4, 0x8B, 0x4E, 0xF9, 0x83, 0x36, 0x09, 0xD9, 0x7F, 0x6D, 0x3E, 0x1E, 0xC1, 0x9F,
-            0xDA, 0x9F, 0x25, 0x33,
-        ],
-        &[
-            0x5A, 0x40, 0x95, 0xBD, 0x94, 0x49, 0xC2, 0x4D, 0xA3, 0x1F, 0x5A, 0xC3, 0x9E, 0x48,
-            0xD9, 0x10, 0xF9, 0xAD, 0x9E, 0x27, 0x28, 0x44, 0xD1, 0x3C, 0x90, 0x13, 0xEB, 0x78,
-            0x7B, 0x52, 0x7D, 0x11,
-        ],
-        &[
-            0x11, 0x41, 0x61, 0xEB, 0xD9, 0xBA, 0xC2, 0x1D, 0x01, 0xEB, 0x90, 0xD0, 0xF2, 0xE4,
-            0x25, 0x3C, 0x2A, 0xA6, 0x0D, 0xA2, 0x5B, 0x69, 0x21, 0xC1, 0xC4, 0xD4, 0xC0, 0xB1,
-            0x6A, 0xE4, 0x43, 0x0E,
-        ],
-        &[
-            0x49, 0x1A, 0xB8, 0xED, 0xA0, 0xB5, 0x82, 0x63, 0xBF, 0x65, 0x39, 0x78, 0xA2, 0x96,
-            0x7F, 0xF3, 0xF0, 0xD2, 0xCE, 0x13, 0xE9, 0x46, 0xA9, 0xEA, 0x82, 0xD7, 0x2C, 0xAB,
-            0x91, 0x57, 0xE9, 0x01,
-        ],
-    ];
-
-    // The following constants are from the output of
-    // sage: rescue381.constants_matrix
-    // a.k.a., KEY_SCHED_MATRIX
-    // [20508694040621567351648110034447675442497630601368754313856884903339587777311
-    // 21833126749675005420257511508741654628498211767962591705355382899857469438170
-    // 10094259962876866855921243786568400951965635335279511958062644189442373357290
-    // 19703017446530302026954081842172601421653728134317178448940156258115853425803]
-    // [10251498144790729877114984073212046753223574188577263253763854582885300616634
-    // 23799697215770479455315980501436150478606639549341703710964277906023879274693
-    // 3007859742890615231402252990001597094047140204544326329227026628202747223471
-    // 28206680002640467010077105488518988050496606663258696654543362871739559141116]
-    // [ 3001386190657217866716811031197002190094834176598601007437349105366277408753
-    // 3302832234223427084389235892793462946069958738751625733789554798277785616852
-    // 33828191304584863092326289783666465913001308709873493077072772336792329272781
-    // 39527082973012175895755035046102602497048600747962062191946750704586900696815]
-    // [28051483866417948291356906371063959987011977735069581088198413305545643762525
-    // 940230548799789892304826428424685764994822279495712794369041189518965610982
-    // 51086698257646416011541091115454938869982232807222651212625724712549497545484
-    // 17476372527237931823914329908105757745889986759257828348803723712031461055028]
-    //
-
-    // The following constants are from the output of
-    // sage: rescue381.constants_constant
-    // a.k.a., KEY_SCHED_VEC
-    // [47547237971610965741643776816276041546468880714675495834455049663798422970459]
-    // [10004118136888058764408398782965078987905732598601545000387602435395348015578]
-    // [41751554506863950612723183999266149980852802057217063263129581065630539355943]
-    // [28630474321717538333837377020183699597240697917209889448356171144508785456174]
-
-    // The key injection constants are precomputed computed as
-    // 1. KEY_INJECTION[-1] = INIT_VECTOR
-    // 2. for i in [0 .. 2 * ROUNDS]
-    // 3. KEY_INJECTION[i] = KEY_SCHED_MATRIX * KEY_INJECTION[ i - 1] +
-    // KEY_SCHED_VEC
-    // where the INIT_VECTOR, KEY_SCHED_MATRIX and KEY_INJECTION are defined above
-    // Those constants can be obtained via
-    //   key_injection(init_vec_381, key_sched_matrix_381, key_sched_vec_381, q381)
-    // from rescue381.sage
-    const KEY_INJECTION_LE: [[&'static [u8]; 4]; 2 * ROUNDS] = [
-        [
-            &[
-                0xE6, 0x24, 0xDE, 0xDC, 0x1D, 0xDA, 0xB0, 0x64, 0x2C, 0xD0, 0x9D, 0xA7, 0x0D, 0x91,
-                0xEE, 0x0C, 0xBB, 0x25, 0xA9, 0xE4, 0x05, 0xD1, 0xAB, 0x58, 0x24, 0x98, 0x30, 0x57,
-                0x4E, 0xE1, 0xF7, 0x68,
-            ],
-            &[
-                0x73, 0xC9, 0x17, 0x7D, 0x73, 0xCF, 0xC6, 0x54, 0x35, 0x64, 0x6E, 0xAA, 0xD1, 0x89,
-                0xAE, 0x68, 0x8D, 0x6A, 0x1C, 0x42, 0x81, 0xE8, 0x85, 0x1E, 0x0C, 0x02, 0x79, 0x74,
-                0x8E, 0xF8, 0x40, 0x03,
-            ],
-            &[
-                0x3E, 0xB3, 0x47, 0xFC, 0x76, 0x7D, 0x1C, 0xAB, 0x54, 0x6A, 0xB9, 0x0E, 0x09, 0xBF,
-                0x60, 0x40, 0x26, 0x6D, 0x67, 0xA5, 0x04, 0x88, 0x3C, 0xFE, 0x47, 0xDA, 0x2A, 0x3F,
-                0xF4, 0x82, 0x78, 0x66,
-            ],
-            &[
-                0x14, 0x83, 0xC2, 0xD6, 0x45, 0xEF, 0x87, 0xE9, 0xF7, 0xFC, 0xA8, 0xD3, 0xCE, 0x48,
-                0xC5, 0x96, 0xF7, 0x14, 0x45, 0xDB, 0x5C, 0x20, 0xA5, 0xAB, 0xE8, 0x93, 0x95, 0x28,
-                0xC5, 0xC1, 0x53, 0x73,
-            ],
-        ],
-        [
-            &[
-                0x61, 0xB9, 0xB8, 0x7E, 0xEF, 0xD7, 0x88, 0x1D, 0xCF, 0x98, 0x14, 0xBE, 0x2A, 0x40,
-                0xD3, 0xFE, 0xC0, 0x71, 0x33, 0x5B, 0x91, 0x9C, 0xA1, 0x2D, 0x76, 0x58, 0xBB, 0xF0,
-                0x69, 0xF0, 0x45, 0x0E,
-            ],
-            &[
-                0x93, 0x1D, 0xF8, 0x81, 0xEA, 0xCA, 0xF5, 0x21, 0x73, 0x70, 0x78, 0x0B, 0x9E, 0xD4,
-                0x7E, 0x72, 0x4A, 0x8E, 0x55, 0x82, 0xD3, 0x38, 0xFC, 0xB8, 0x42, 0x22, 0x52, 0x75,
-                0xFF, 0x05, 0xC4, 0x26,
-            ],
-            &[
-                0x85, 0x5B, 0xA2, 0xE0, 0x19, 0x02, 0x27, 0xB8, 0x8B, 0x03, 0x34, 0x28, 0x4C, 0x1B,
-                0x09, 0x00, 0x91, 0xED, 0xD6, 0xA3, 0x6C, 0x74, 0x2D, 0x33, 0xD2, 0xBA, 0xBF, 0x56,
-                0xEF, 0x56, 0x0D, 0x04,
-            ],
-            &[
-                0x00, 0xD0, 0x81, 0x18, 0x74, 0x44, 0x71, 0xFA, 0xDD, 0xA9, 0xCE, 0xEB, 0xBD, 0x77,
-                0x03, 0x70, 0xA1, 0x64, 0xFF, 0x4F, 0x00, 0xD9, 0xD5, 0x65, 0x3B, 0xD2, 0x66, 0x7E,
-                0x02, 0xF8, 0x10, 0x32,
-            ],
-        ],
-        [
-            &[
-                0x36, 0x2D, 0xCA, 0xCC, 0x6A, 0x40, 0x25, 0x57, 0xA2, 0x94, 0xDA, 0xAA, 0xB3, 0xE3,
-                0xD7, 0x74, 0x36, 0x64, 0xDD, 0xAC, 0x90, 0xA1, 0x59, 0x15, 0x90, 0x3C, 0x62, 0xEA,
-                0x30, 0xB9, 0x22, 0x47,
-            ],
-            &[
-                0x1B, 0x8B, 0x1A, 0x45, 0x85, 0xDF, 0x24, 0x59, 0x38, 0x19, 0x01, 0xDE, 0x2E, 0x93,
-                0x8A, 0xD7, 0x9F, 0xD5, 0x55, 0xB3, 0x1A, 0x35, 0xD3, 0x7A, 0x1A, 0xAF, 0xA3, 0x75,
-                0x2D, 0x2B, 0x17, 0x6F,
-            ],
-            &[
-                0x0D, 0xE1, 0x6A, 0xD7, 0x13, 0x58, 0x94, 0x4F, 0xAE, 0x7E, 0x85, 0xD3, 0x9F, 0xE0,
-                0x5A, 0x3C, 0x1C, 0xA4, 0xD4, 0x55, 0x28, 0x7A, 0x96, 0x8C, 0x82, 0x74, 0x07, 0xDE,
-                0xEF, 0xA8, 0x57, 0x0A,
-            ],
-            &[
-                0xC0, 0xBA, 0x46, 0x6C, 0x6E, 0xBC, 0x73, 0xF8, 0xAC, 0xE4, 0x94, 0x94, 0x75, 0x56,
-                0x7F, 0xAE, 0xFF, 0xD2, 0x0B, 0xA3, 0xA1, 0x6F, 0x0D, 0xE6, 0x4B, 0x75, 0xF6, 0x99,
-                0x49, 0xE3, 0xCE, 0x23,
-            ],
-        ],
-        [
-            &[
-                0xBD, 0x42, 0xE7, 0xA5, 0x82, 0x38, 0xCE, 0x58, 0x35, 0xDE, 0xDE, 0xB9, 0x8E, 0xE8,
-                0xB0, 0x1E, 0x8B, 0x16, 0xEF, 0x3A, 0x5C, 0x77, 0x9B, 0xAF, 0xBF, 0xF5, 0xBD, 0xC7,
-                0x13, 0x69, 0x9B, 0x2F,
-            ],
-            &[
-                0x6D, 0x16, 0xEF, 0x0F, 0x42, 0xBB, 0x22, 0x0A, 0x4B, 0x8B, 0xA0, 0xBA, 0x59, 0x0A,
-                0x46, 0x6F, 0xA1, 0x00, 0x5F, 0x32, 0x7E, 0x14, 0x90, 0x0D, 0x93, 0x66, 0x51, 0x5F,
-                0x09, 0xF3, 0x68, 0x62,
-            ],
-            &[
-                0x1E, 0x9D, 0x49, 0x14, 0xC4, 0x8C, 0xE3, 0x95, 0xAF, 0x68, 0x09, 0x21, 0x69, 0x9B,
-                0xEC, 0xA2, 0x45, 0x9B, 0x4F, 0x51, 0x28, 0x54, 0x10, 0x94, 0xFB, 0xE5, 0xF3, 0x8A,
-                0xD0, 0xCB, 0x77, 0x16,
-            ],
-            &[
-                0x81, 0x61, 0xEB, 0xA4, 0x2E, 0xE0, 0xFD, 0x17, 0x72, 0x96, 0x35, 0xB2, 0x67, 0xAA,
-                0xC8, 0x24, 0xB9, 0x91, 0x71, 0x52, 0x8C, 0xD5, 0x85, 0xB5, 0xE2, 0x58, 0x2E, 0x78,
-                0xB9, 0x2A, 0x95, 0x32,
-            ],
-        ],
-        [
-            &[
-                0x2A, 0x83, 0xFB, 0x00, 0x9E, 0xE8, 0x3D, 0x31, 0x22, 0xBA, 0xA2, 0xF0, 0x78, 0x51,
-                0x4B, 0x52, 0x2C, 0x3F, 0xA0, 0xA9, 0x09, 0x9D, 0xBD, 0x1E, 0x2C, 0xB5, 0x3E, 0x58,
-                0xE9, 0x61, 0x7B, 0x3A,
-            ],
-            &[
-                0x9B, 0xF1, 0x2B, 0x52, 0xAD, 0x5F, 0x3C, 0x69, 0xF5, 0x7F, 0x13, 0xA8, 0x64, 0x73,
-                0x81, 0x50, 0xDB, 0xBC, 0xFE, 0x25, 0x96, 0x5B, 0x63, 0xDB, 0x26, 0x35, 0xFF, 0x36,
-                0x5E, 0x62, 0xEA, 0x34,
-            ],
-            &[
-                0x69, 0xA9, 0x55, 0xFF, 0x89, 0x91, 0x6B, 0x62, 0x15, 0x7E, 0xE7, 0xAE, 0xAE, 0x69,
-                0x72, 0x43, 0x8E, 0xF3, 0x0E, 0x7E, 0x78, 0x71, 0xBA, 0x47, 0x92, 0xC9, 0x8C, 0xBA,
-                0x4B, 0x48, 0xA0, 0x51,
-            ],
-            &[
-                0xE9, 0x0A, 0x75, 0x68, 0xC2, 0x35, 0x65, 0x25, 0xCB, 0x13, 0x3D, 0x78, 0x99, 0xBA,
-                0x7A, 0xB9, 0x63, 0x20, 0xF3, 0x2A, 0x20, 0xF7, 0x27, 0x9D, 0x7E, 0x3C, 0x66, 0x77,
-                0x13, 0x2B, 0x56, 0x48,
-            ],
-        ],
-        [
-            &[
-                0x17, 0xD9, 0xF3, 0x43, 0x26, 0x0A, 0x4E, 0x48, 0xAE, 0xB3, 0xF7, 0x8D, 0x8E, 0x86,
-                0x01, 0xD6, 0xD9, 0x48, 0x39, 0xB6, 0x28, 0x97, 0x02, 0x2B, 0xEA, 0x0B, 0x14, 0xA8,
-                0xE1, 0x57, 0xAF, 0x71,
-            ],
-            &[
-                0x41, 0xFE, 0xE4, 0xE0, 0xF5, 0xCC, 0x6E, 0x30, 0x97, 0x8A, 0x46, 0xB8, 0xE4, 0x13,
-                0x80, 0x3F, 0xB5, 0x66, 0x36, 0x6F, 0xE4, 0x49, 0x44, 0x30, 0x1A, 0xA3, 0x67, 0x91,
-                0xAE, 0xC3, 0x26, 0x26,
-            ],
-            &[
-                0xDC, 0xA4, 0x7F, 0x3B, 0x1A, 0xAE, 0x8B, 0x9E, 0x5E, 0x11, 0x51, 0x64, 0x39, 0xAD,
-                0xF7, 0x6C, 0xFD, 0xB9, 0x1A, 0x26, 0xF8, 0x22, 0x05, 0x5D, 0x37, 0xCF, 0x80, 0x06,
-                0x2F, 0x15, 0xD4, 0x73,
-            ],
-            &[
-                0x95, 0x56, 0x48, 0x21, 0x9F, 0x3F, 0x13, 0xEE, 0xAE, 0xFC, 0x8B, 0x94, 0xF0, 0xDB,
-                0x6C, 0x1F, 0x6C, 0x13, 0xAE, 0xCD, 0xDB, 0x8E, 0x27, 0x2C, 0x59, 0xDA, 0x1E, 0x1C,
-                0x34, 0xEC, 0x44, 0x47,
-            ],
-        ],
-        [
-            &[
-                0xB8, 0x6C, 0x28, 0x95, 0x2F, 0xCB, 0x62, 0xD7, 0xE5, 0x42, 0x70, 0x2D, 0x75, 0x16,
-                0x1B, 0x31, 0xBC, 0x7C, 0x27, 0xF3, 0xC2, 0x15, 0x35, 0x92, 0x87, 0x11, 0xE6, 0x3A,
-                0xF6, 0xD8, 0x69, 0x20,
-            ],
-            &[
-                0xD6, 0xB7, 0xA9, 0xEB, 0x91, 0xA7, 0x2A, 0xDE, 0x4F, 0x2D, 0x40, 0x93, 0xCA, 0xB0,
-                0xC1, 0x79, 0x06, 0x24, 0xD0, 0xC5, 0x11, 0xD2, 0xBD, 0xAE, 0x82, 0x59, 0x9A, 0x73,
-                0xE8, 0xBE, 0xB4, 0x3E,
-            ],
-            &[
-                0xF0, 0xF5, 0x8A, 0xA7, 0xB8, 0x44, 0x00, 0x73, 0x3B, 0x05, 0x19, 0x8E, 0x6C, 0x26,
-                0xD7, 0x48, 0x94, 0x9E, 0x0F, 0x99, 0xD8, 0x13, 0x6A, 0x15, 0x38, 0xEA, 0xB7, 0x34,
-                0x88, 0x2E, 0x95, 0x11,
-            ],
-            &[
-                0xD5, 0x73, 0x90, 0x0D, 0xDD, 0x74, 0x81, 0x4B, 0x72, 0x4C, 0x46, 0x15, 0x88, 0xC2,
-                0xDC, 0x87, 0x0B, 0x28, 0x96, 0xA8, 0x32, 0x37, 0x38, 0x6D, 0x30, 0xDD, 0x38, 0x71,
-                0xBC, 0xBD, 0xB7, 0x0E,
-            ],
-        ],
-        [
-            &[
-                0x63, 0xA0, 0x63, 0xD0, 0x85, 0x5B, 0xA7, 0x8F, 0xDC, 0xD0, 0x8E, 0x02, 0x00, 0xC1,
-                0x63, 0x92, 0x36, 0xB1, 0xC8, 0x06, 0x79, 0xBE, 0xF5, 0x23, 0xFC, 0xAC, 0x85, 0xD8,
-                0x2E, 0x30, 0x9C, 0x4A,
-            ],
-            &[
-                0x9C, 0xDC, 0xAB, 0xFE, 0x1F, 0x4E, 0x91, 0xF5, 0x1F, 0x01, 0x11, 0x2D, 0x8F, 0x0A,
-                0xB3, 0x56, 0x42, 0xED, 0x1B, 0xA4, 0xE3, 0x3F, 0x93, 0xEE, 0xD2, 0x9D, 0x29, 0x91,
-                0x19, 0x9F, 0x14, 0x48,
-            ],
-            &[
-                0xAD, 0xFF, 0xE0, 0x27, 0x0A, 0xDA, 0x1B, 0x8D, 0x9A, 0xE2, 0x60, 0x4A, 0xD4, 0x03,
-                0x74, 0xC6, 0x7E, 0x16, 0x9D, 0x70, 0x9F, 0x1E, 0x47, 0x28, 0xB4, 0xC1, 0x8A, 0x6B,
-                0xBF, 0x26, 0x60, 0x38,
-            ],
-            &[
-                0x6F, 0x47, 0x9D, 0x78, 0x59, 0xC3, 0x75, 0xA4, 0xF2, 0x41, 0x18, 0xC6, 0x78, 0x55,
-                0x3E, 0xC7, 0xDD, 0xB4, 0x23, 0x98, 0x58, 0xE2, 0x89, 0xD4, 0xDC, 0x4D, 0xA4, 0x4A,
-                0xE5, 0xC4, 0x6E, 0x49,
-            ],
-        ],
-        [
-            &[
-                0xAC, 0x24, 0xDC, 0x31, 0xC6, 0x6D, 0x43, 0xA0, 0x4C, 0xCE, 0x85, 0xA7, 0x61, 0x70,
-                0x6E, 0x08, 0x3F, 0x2F, 0xFB, 0xAA, 0x46, 0x22, 0xE7, 0xD7, 0xC7, 0x25, 0x34, 0x53,
-                0x45, 0x03, 0xC5, 0x6E,
-            ],
-            &[
-                0x27, 0x79, 0x9B, 0xCF, 0x22, 0xA1, 0x9C, 0xB3, 0xAA, 0x0C, 0xF2, 0x31, 0x78, 0x1E,
-                0xAA, 0x04, 0x4C, 0xCC, 0x8C, 0x3F, 0xD4, 0x6A, 0x52, 0x9A, 0x27, 0x4A, 0x94, 0xD9,
-                0xD1, 0xAC, 0xB8, 0x42,
-            ],
-            &[
-                0xDE, 0xEE, 0xC3, 0xDE, 0xAF, 0x4D, 0x7A, 0x18, 0x6F, 0x10, 0xAE, 0xEB, 0xF8, 0xC3,
-                0x6D, 0x43, 0xB1, 0x0B, 0x3D, 0x79, 0xAE, 0x76, 0x9F, 0x04, 0xA8, 0x3A, 0xEB, 0xCA,
-                0x00, 0x59, 0xA9, 0x0C,
-            ],
-            &[
-                0x60, 0xBF, 0x48, 0xDB, 0x65, 0x55, 0xF9, 0x40, 0x54, 0x73, 0x5C, 0xBC, 0x19, 0xF6,
-                0x0F, 0x9E, 0x23, 0x1C, 0x7B, 0x8E, 0x41, 0xAE, 0x5D, 0x57, 0x5E, 0x70, 0xDF, 0xB5,
-                0x9A, 0x2D, 0x13, 0x49,
-            ],
-        ],
-        [
-            &[
-                0xE4, 0x39, 0xCF, 0x83, 0x2B, 0x7B, 0x0A, 0x4D, 0xED, 0xCE, 0x0C, 0x6A, 0x51, 0xC7,
-                0x39, 0x13, 0xBD, 0x07, 0x08, 0x23, 0x9A, 0x42, 0xEA, 0xBD, 0xD5, 0x65, 0x3E, 0xC6,
-                0xC3, 0x09, 0xE8, 0x0E,
-            ],
-            &[
-                0xDA, 0x2D, 0x65, 0x47, 0x42, 0x61, 0x95, 0x47, 0xC2, 0x07, 0xA6, 0x37, 0x6E, 0x22,
-                0x93, 0x87, 0x14, 0xA5, 0x82, 0x7E, 0xF7, 0x52, 0x53, 0x92, 0xB9, 0x86, 0x97, 0xF8,
-                0xF9, 0x3B, 0x17, 0x42,
-            ],
-            &[
-                0x58, 0x93, 0x1D, 0x72, 0xD9, 0xB9, 0x67, 0x58, 0x62, 0x7E, 0x9A, 0x39, 0x96, 0xCF,
-                0x3E, 0xAC, 0xC6, 0x1E, 0x85, 0x70, 0xB7, 0x6A, 0xFF, 0xB6, 0xDC, 0x4F, 0x48, 0x1F,
-                0x60, 0x5B, 0x64, 0x31,
-            ],
-            &[
-                0x99, 0xFE, 0xFE, 0xF7, 0x9A, 0x6A, 0x3A, 0xAB, 0x5A, 0x7A, 0xBE, 0x6A, 0x2C, 0xDB,
-                0x2B, 0xF0, 0x49, 0x6C, 0x28, 0x0E, 0x53, 0xA3, 0x33, 0xFF, 0x71, 0xB9, 0xA0, 0x1F,
-                0xE3, 0x75, 0x74, 0x0D,
-            ],
-        ],
-        [
-            &[
-                0xD9, 0xDB, 0x66, 0x43, 0x72, 0xA0, 0xB2, 0x0B, 0x62, 0x42, 0x96, 0xB6, 0xE0, 0xE7,
-                0x7F, 0xE0, 0x5E, 0x89, 0x02, 0x90, 0x4A, 0x32, 0x8F, 0x89, 0xC4, 0x38, 0xC7, 0x4F,
-                0x4F, 0xEE, 0x41, 0x6E,
-            ],
-            &[
-                0x5D, 0x8E, 0x4C, 0xBF, 0xBC, 0xFA, 0x58, 0xAC, 0xB4, 0x9A, 0x12, 0xA9, 0x80, 0x17,
-                0x28, 0xB4, 0xE3, 0xD8, 0x3A, 0x09, 0xA9, 0x5B, 0x15, 0xB7, 0x99, 0x83, 0xAC, 0x91,
-                0x68, 0x24, 0x5C, 0x23,
-            ],
-            &[
-                0x8F, 0xA3, 0xB2, 0x90, 0x39, 0x59, 0xAB, 0x44, 0x5D, 0xA0, 0x1F, 0xE9, 0xC2, 0xC1,
-                0x5F, 0xF5, 0x9A, 0xB2, 0xA2, 0xDB, 0x65, 0xA4, 0x4A, 0xFD, 0xA1, 0x57, 0xAA, 0x3A,
-                0xB3, 0xA7, 0xE1, 0x49,
-            ],
-            &[
-                0x72, 0x71, 0x37, 0xD4, 0x4C, 0xB8, 0x20, 0xDA, 0x32, 0x50, 0x43, 0xB9, 0x92, 0xE7,
-                0x0B, 0xD6, 0x95, 0x2A, 0xFE, 0xF8, 0xE1, 0x88, 0x1C, 0xFE, 0x84, 0x40, 0x2E, 0x80,
-                0x6F, 0xED, 0x90, 0x53,
-            ],
-        ],
-        [
-            &[
-                0xE7, 0x3F, 0x50, 0xB1, 0x19, 0x7A, 0x12, 0xC7, 0xCF, 0xFA, 0x29, 0x3F, 0xF0, 0x28,
-                0xCA, 0x58, 0x00, 0xAD, 0x21, 0xD0, 0xEC, 0x9D, 0x7A, 0xC1, 0xC2, 0xAD, 0x67, 0x05,
-                0xFC, 0xEF, 0x60, 0x1F,
-            ],
-            &[
-                0xDF, 0xD1, 0x9F, 0x09, 0xBC, 0x7C, 0xFB, 0x23, 0xFA, 0xC0, 0x58, 0x08, 0xE0, 0x76,
-                0xA7, 0x91, 0x47, 0xDF, 0x4C, 0x1D, 0x81, 0x6E, 0x43, 0x5B, 0x5D, 0x88, 0x9E, 0x97,
-                0x4F, 0x94, 0x1E, 0x1B,
-            ],
-            &[
-                0xF9, 0x95, 0x8A, 0x02, 0xE8, 0x08, 0xEE, 0x5B, 0x5B, 0x08, 0xFC, 0x0D, 0x89, 0x89,
-                0x2F, 0x0F, 0xB5, 0x84, 0xF8, 0xB0, 0x4E, 0x93, 0x47, 0xE7, 0x1F, 0x8A, 0xB8, 0xCB,
-                0x51, 0xBA, 0xE5, 0x55,
-            ],
-            &[
-                0xF3, 0x6E, 0x9C, 0x4C, 0x95, 0x89, 0x6C, 0x79, 0x25, 0xAB, 0x23, 0x0D, 0x7D, 0x51,
-                0x81, 0xDE, 0xEB, 0x59, 0xBE, 0xE7, 0xBC, 0xF1, 0xAE, 0xE2, 0x8F, 0x77, 0xAA, 0x97,
-                0x45, 0xE3, 0x1B, 0x4C,
-            ],
-        ],
-        [
-            &[
-                0x8E, 0xAE, 0xE1, 0x48, 0xF2, 0xF5, 0xBE, 0x6C, 0xFC, 0x42, 0x9A, 0xE2, 0x92, 0x35,
-                0x8C, 0xE8, 0x49, 0x64, 0xCD, 0x1C, 0xAE, 0x02, 0x92, 0x5B, 0xA9, 0x39, 0x0D, 0x22,
-                0x79, 0xEE, 0x0E, 0x3B,
-            ],
-            &[
-                0x09, 0x54, 0x55, 0xEE, 0xE5, 0xB2, 0x48, 0x82, 0x01, 0x38, 0x47, 0xF4, 0x0E, 0x2E,
-                0xF5, 0x46, 0x27, 0x20, 0x09, 0x11, 0x28, 0x6A, 0x8C, 0xC7, 0x98, 0x64, 0x0E, 0x0B,
-                0xC9, 0x39, 0xF3, 0x15,
-            ],
-            &[
-                0xE3, 0x04, 0x2C, 0x04, 0xCE, 0xA6, 0x7B, 0xB3, 0xED, 0x9B, 0x25, 0x93, 0x35, 0xF0,
-                0x06, 0xCC, 0xA0, 0xE7, 0xA6, 0x8F, 0x49, 0x84, 0x81, 0x12, 0x03, 0x76, 0x72, 0xD1,
-                0xEA, 0x71, 0x0B, 0x40,
-            ],
-            &[
-                0x96, 0xEE, 0xEA, 0xBD, 0x33, 0x3C, 0x0E, 0x2D, 0xA4, 0x4F, 0xA4, 0x14, 0xE5, 0x4E,
-                0x43, 0x0C, 0x8C, 0x3A, 0xF8, 0x0C, 0x76, 0x90, 0x7E, 0xAA, 0x62, 0x47, 0xDF, 0xE5,
-                0xD3, 0x67, 0x7F, 0x48,
-            ],
-        ],
-        [
-            &[
-                0x92, 0x44, 0xE1, 0x41, 0x18, 0xB4, 0x5D, 0xDD, 0x3B, 0x07, 0x96, 0xD0, 0x8A, 0x4C,
-                0x74, 0x51, 0x2C, 0xA9, 0x31, 0xC6, 0x67, 0x7C, 0x75, 0xAE, 0xE4, 0xB1, 0xB0, 0x67,
-                0x9F, 0x72, 0x30, 0x06,
-            ],
-            &[
-                0x0E, 0x74, 0xA5, 0x3A, 0x98, 0x8A, 0x03, 0x73, 0x7C, 0xAC, 0xE5, 0xAD, 0xBE, 0x05,
-                0xCE, 0xC8, 0xC8, 0xE7, 0xF2, 0xA6, 0xF1, 0x9B, 0xB0, 0x64, 0xC9, 0xDE, 0x7B, 0xA9,
-                0xC6, 0x05, 0x1E, 0x1C,
-            ],
-            &[
-                0x26, 0xEF, 0xCE, 0x83, 0xBF, 0x42, 0x19, 0x83, 0x0A, 0x77, 0x1A, 0x33, 0x7A, 0x49,
-                0x46, 0xF7, 0x00, 0x69, 0xA1, 0x91, 0x6A, 0xF1, 0x73, 0xE7, 0x63, 0x64, 0x7F, 0x8C,
-                0x52, 0x83, 0x8C, 0x05,
-            ],
-            &[
-                0x47, 0xD5, 0xC7, 0x2F, 0xEB, 0x32, 0xF9, 0x8A, 0xF6, 0x22, 0xEE, 0xAD, 0x12, 0x60,
-                0x63, 0x98, 0xBE, 0x85, 0xEA, 0x57, 0x09, 0x62, 0xBA, 0x9E, 0x09, 0x8A, 0x9C, 0xCF,
-                0xA4, 0x4D, 0xC2, 0x14,
-            ],
-        ],
-        [
-            &[
-                0x3A, 0x58, 0xC2, 0x40, 0x13, 0x20, 0xDC, 0x7E, 0x42, 0x1B, 0x88, 0xFF, 0x87, 0xAA,
-                0x62, 0x19, 0x19, 0xFA, 0xB8, 0xE9, 0xA8, 0xB4, 0xDE, 0x88, 0x76, 0x0C, 0x20, 0x2B,
-                0xCE, 0x50, 0xDB, 0x61,
-            ],
-            &[
-                0xA1, 0x68, 0x18, 0x6A, 0xC9, 0x17, 0xC2, 0x24, 0xBC, 0x04, 0xF5, 0xAD, 0x88, 0x63,
-                0x3D, 0x40, 0xB6, 0x54, 0xFC, 0xE3, 0x6E, 0x42, 0xD9, 0x20, 0xE9, 0x45, 0x2E, 0xE1,
-                0x67, 0xAF, 0x62, 0x01,
-            ],
-            &[
-                0xE6, 0x63, 0xEF, 0x9E, 0xBD, 0x23, 0x76, 0x76, 0x43, 0x60, 0x2F, 0x92, 0x6A, 0x70,
-                0x1C, 0xFE, 0x8A, 0x24, 0x97, 0xBE, 0x48, 0xAF, 0x2B, 0xAF, 0x97, 0xFB, 0xC5, 0x9D,
-                0xCD, 0x70, 0x09, 0x6E,
-            ],
-            &[
-                0x66, 0x5A, 0x0D, 0x76, 0x62, 0x6C, 0x86, 0x35, 0x1D, 0xE8, 0xF4, 0xB1, 0x64, 0x83,
-                0x2E, 0xD2, 0x78, 0x1E, 0xED, 0x6F, 0xA9, 0x93, 0x5C, 0xF4, 0xDC, 0x12, 0xFA, 0x79,
-                0x07, 0xDF, 0xFC, 0x61,
-            ],
-        ],
-        [
-            &[
-                0xEE, 0xB6, 0xFF, 0xC4, 0x0A, 0x76, 0xAB, 0xBF, 0xA3, 0x66, 0x55, 0x52, 0x3B, 0x48,
-                0x22, 0x82, 0x1B, 0x6E, 0x26, 0xEC, 0xA1, 0xD2, 0x6B, 0xF3, 0x28, 0x68, 0x25, 0x8F,
-                0x48, 0x21, 0x3C, 0x50,
-            ],
-            &[
-                0x12, 0xC3, 0x02, 0x6D, 0xA3, 0x67, 0x0E, 0x1E, 0x5B, 0x01, 0xF9, 0x8F, 0xF1, 0x4B,
-                0xAC, 0xBA, 0xD8, 0x8A, 0x0C, 0xFD, 0xDE, 0x94, 0x78, 0x04, 0x4A, 0x83, 0x55, 0xEF,
-                0x16, 0xF5, 0x76, 0x61,
-            ],
-            &[
-                0x2C, 0xDF, 0x6B, 0x9B, 0x58, 0x35, 0x13, 0xA8, 0xA5, 0x98, 0x55, 0x86, 0xCD, 0x21,
-                0xEC, 0xEE, 0xD5, 0x26, 0xBE, 0x3D, 0x96, 0x37, 0xC0, 0x2A, 0xBB, 0x11, 0xD3, 0xAA,
-                0x64, 0x73, 0xD7, 0x3F,
-            ],
-            &[
-                0x03, 0xDE, 0x1A, 0x4B, 0xC9, 0x7D, 0xA7, 0xBD, 0x35, 0xD6, 0x5A, 0x60, 0x6C, 0x52,
-                0x11, 0x32, 0xC6, 0xDD, 0x3C, 0xF8, 0x48, 0x90, 0x5D, 0x97, 0x3E, 0x74, 0x58, 0x69,
-                0x34, 0x4D, 0xB1, 0x42,
-            ],
-        ],
-        [
-            &[
-                0x76, 0xAC, 0x99, 0x09, 0xFD, 0x57, 0x0B, 0x83, 0xA5, 0x14, 0x25, 0xEB, 0x88, 0x55,
-                0x7D, 0x20, 0x45, 0x9D, 0xD8, 0x29, 0x1C, 0x00, 0x6B, 0x51, 0x41, 0x8D, 0x2C, 0x84,
-                0x6C, 0x47, 0x28, 0x33,
-            ],
-            &[
-                0xEA, 0x51, 0x7D, 0xA4, 0x3D, 0x67, 0xB0, 0xD0, 0x0E, 0xF5, 0x16, 0x40, 0xCC, 0x07,
-                0xAE, 0xE7, 0xBE, 0x11, 0x65, 0xA4, 0x60, 0x18, 0x51, 0x54, 0x0A, 0x67, 0x1C, 0xE3,
-                0x72, 0xE1, 0xD1, 0x52,
-            ],
-            &[
-                0xDC, 0x63, 0xD0, 0x86, 0x32, 0x3B, 0x66, 0xAC, 0xA1, 0x26, 0x5F, 0x0C, 0xCA, 0x44,
-                0x94, 0x95, 0xD9, 0x50, 0x25, 0x99, 0xFA, 0x38, 0xF6, 0xD6, 0x02, 0x1A, 0x1B, 0x5D,
-                0x8D, 0xDD, 0x20, 0x13,
-            ],
-            &[
-                0x19, 0x3C, 0x8B, 0x93, 0x3E, 0x99, 0xE9, 0x6E, 0x4F, 0xCE, 0xBC, 0xB0, 0x92, 0x1F,
-                0x76, 0xA4, 0x8B, 0x81, 0x2D, 0xED, 0xEB, 0xBC, 0xCF, 0x93, 0xFF, 0x87, 0x56, 0x97,
-                0xBD, 0x81, 0xE3, 0x73,
-            ],
-        ],
-        [
-            &[
-                0xD8, 0xEB, 0xDD, 0xFB, 0x4B, 0x48, 0xF0, 0x34, 0x67, 0x1D, 0xF0, 0x6B, 0xC5, 0xE4,
-                0x46, 0xD2, 0x8C, 0xA7, 0x2C, 0x38, 0xA8, 0x63, 0x81, 0x38, 0x31, 0xDB, 0x62, 0x3D,
-                0xF6, 0xF7, 0x27, 0x6E,
-            ],
-            &[
-                0x48, 0x95, 0xFF, 0x22, 0x53, 0xA4, 0x15, 0xAD, 0x75, 0xAF, 0xAE, 0xFD, 0x2A, 0x43,
-                0xF6, 0x62, 0xBC, 0x0C, 0x8B, 0xB5, 0xFA, 0xD4, 0x33, 0xF3, 0x6A, 0x33, 0x95, 0x44,
-                0xCB, 0x84, 0x1D, 0x1A,
-            ],
-            &[
-                0x18, 0x2B, 0xE6, 0xAC, 0x68, 0xA7, 0x50, 0xC9, 0x0B, 0xDF, 0x75, 0xD8, 0x6B, 0x87,
-                0x61, 0x94, 0x6A, 0x61, 0x5B, 0x9B, 0x42, 0x2C, 0xF2, 0xEF, 0x3E, 0xB9, 0x72, 0xFD,
-                0xD0, 0x14, 0x7F, 0x58,
-            ],
-            &[
-                0x84, 0x29, 0x8D, 0xB3, 0x82, 0xE2, 0xE6, 0x1B, 0xC9, 0x61, 0xF6, 0xF9, 0x92, 0x5E,
-                0xFD, 0x6A, 0x26, 0xD7, 0x91, 0x0D, 0xFD, 0x09, 0xAB, 0xF5, 0xA9, 0x6D, 0x9E, 0xAB,
-                0x90, 0xD0, 0xF8, 0x40,
-            ],
-        ],
-        [
-            &[
-                0x32, 0x3F, 0x3F, 0xCB, 0x9F, 0xDD, 0x98, 0xC2, 0x7A, 0xD3, 0xC3, 0x91, 0xDC, 0x92,
-                0x14, 0x7E, 0x5E, 0x51, 0xED, 0x04, 0x94, 0x73, 0x2C, 0xCD, 0xBC, 0x4A, 0x95, 0x43,
-                0x97, 0x85, 0x1F, 0x2A,
-            ],
-            &[
-                0x4A, 0x05, 0x8C, 0x38, 0x27, 0xFE, 0xA1, 0x57, 0xEE, 0x34, 0x75, 0xFB, 0x84, 0xC2,
-                0x19, 0xAF, 0x57, 0x61, 0x93, 0x88, 0xC6, 0xD4, 0x91, 0x4E, 0x44, 0x0F, 0xE5, 0xD3,
-                0x2F, 0x61, 0x70, 0x53,
-            ],
-            &[
-                0xBC, 0x0C, 0x8C, 0x69, 0x55, 0x85, 0x4C, 0x73, 0xAD, 0x87, 0x19, 0xD9, 0x60, 0xC6,
-                0xA1, 0x70, 0x29, 0x47, 0x84, 0x6F, 0x96, 0x6B, 0xF6, 0x2C, 0x14, 0x00, 0xB4, 0xF2,
-                0x21, 0xF5, 0x19, 0x57,
-            ],
-            &[
-                0x0B, 0x60, 0x19, 0xE1, 0x95, 0xAD, 0xAB, 0x5D, 0x67, 0x4C, 0xC3, 0xFB, 0xC5, 0xF4,
-                0xC5, 0xAD, 0x56, 0x8C, 0x16, 0xD9, 0xFC, 0x97, 0x72, 0x90, 0xC5, 0xD8, 0x4F, 0x2D,
-                0x57, 0x4F, 0xF1, 0x1A,
-            ],
-        ],
-        [
-            &[
-                0x85, 0x03, 0x58, 0x2B, 0x8E, 0x7A, 0xD7, 0xD6, 0xFC, 0x87, 0x76, 0xF0, 0xB3, 0x75,
-                0xCB, 0x33, 0xBB, 0x2B, 0x4E, 0x45, 0x39, 0x4A, 0x97, 0x8C, 0x6D, 0xEA, 0x4C, 0xEF,
-                0x26, 0x77, 0x23, 0x61,
-            ],
-            &[
-                0x46, 0x45, 0xC1, 0xBB, 0xF3, 0x18, 0x67, 0xD8, 0x8D, 0xAA, 0x3C, 0x5C, 0xCD, 0xE8,
-                0x7F, 0xBF, 0xCB, 0x7B, 0x87, 0x42, 0x62, 0xBE, 0x76, 0x46, 0x00, 0xAC, 0xF0, 0x3A,
-                0x37, 0x59, 0xE5, 0x28,
-            ],
-            &[
-                0x79, 0x37, 0x89, 0x6D, 0xAD, 0xE4, 0x78, 0x05, 0x72, 0x75, 0xDD, 0x5E, 0xAC, 0xF4,
-                0x99, 0x19, 0x66, 0x0F, 0x66, 0x8A, 0x03, 0x0A, 0x6E, 0x43, 0xFC, 0x4D, 0xF1, 0x52,
-                0xA9, 0x52, 0x82, 0x52,
-            ],
-            &[
-                0x25, 0x2B, 0xBA, 0xAA, 0xBF, 0xAF, 0x38, 0x1A, 0xA0, 0xE5, 0x0B, 0x11, 0x75, 0x8E,
-                0xF6, 0xBA, 0xE8, 0x15, 0x58, 0x82, 0xE7, 0xA9, 0x39, 0x94, 0x5A, 0x07, 0x1C, 0x0A,
-                0x72, 0x10, 0x8E, 0x44,
-            ],
-        ],
-        [
-            &[
-                0x19, 0xE4, 0x51, 0x7A, 0xB2, 0x93, 0xD4, 0xF2, 0x74, 0xEE, 0xDD, 0x46, 0xC3, 0x6D,
-                0x80, 0xB9, 0x10, 0xB6, 0x69, 0x7E, 0xFA, 0x31, 0xBE, 0x3C, 0x5B, 0xB8, 0x09, 0x89,
-                0x2C, 0x05, 0xDE, 0x3C,
-            ],
-            &[
-                0xB3, 0x8E, 0xD4, 0x61, 0x3A, 0xA4, 0x8F, 0x5A, 0xF6, 0xB8, 0x15, 0x47, 0xDA, 0xD6,
-                0xAD, 0xF3, 0xEC, 0xFC, 0x47, 0x38, 0x46, 0xBA, 0x03, 0x45, 0x43, 0xE8, 0x45, 0xE6,
-                0x36, 0x00, 0x84, 0x1E,
-            ],
-            &[
-                0x0A, 0xF2, 0xF3, 0x40, 0x0B, 0xA9, 0xDD, 0xEA, 0x8B, 0x56, 0xA4, 0x75, 0x34, 0xFB,
-                0x16, 0x7C, 0x67, 0xBF, 0xCC, 0x1C, 0x63, 0x65, 0x96, 0x4D, 0x35, 0x6E, 0xBA, 0xF6,
-                0xC3, 0x08, 0xB7, 0x42,
-            ],
-            &[
-                0x1B, 0xCA, 0x95, 0x01, 0xF3, 0xD4, 0xC6, 0xB8, 0xFC, 0x17, 0x55, 0x65, 0xA6, 0xE3,
-                0xD3, 0xE0, 0x38, 0xEC, 0x37, 0xA8, 0x98, 0xD3, 0x54, 0xFD, 0x4E, 0xC4, 0xAF, 0x1F,
-                0x05, 0xD2, 0xA6, 0x14,
-            ],
-        ],
-        [
-            &[
-                0xEB, 0x85, 0x5F, 0xFE, 0x14, 0x9F, 0x8C, 0xCC, 0xD5, 0xEB, 0x4F, 0xB3, 0x15, 0x4B,
-                0x5F, 0x87, 0x58, 0x62, 0x52, 0xE8, 0x0B, 0x9D, 0x20, 0xF9, 0x04, 0x5B, 0xA3, 0x6F,
-                0x62, 0x35, 0xAA, 0x53,
-            ],
-            &[
-                0xC5, 0xE0, 0x49, 0x38, 0xB7, 0xD9, 0x40, 0x05, 0x5A, 0x60, 0x31, 0x11, 0x27, 0x12,
-                0xF9, 0xBD, 0xC7, 0x31, 0xE9, 0xD1, 0x02, 0x0B, 0x09, 0xB5, 0x0B, 0x5F, 0xCE, 0xC1,
-                0xAD, 0xD5, 0x1A, 0x02,
-            ],
-            &[
-                0x39, 0xEE, 0x4E, 0xA5, 0xFC, 0xF8, 0xB5, 0x99, 0x12, 0x56, 0xB9, 0x5C, 0x77, 0xA0,
-                0x3B, 0x71, 0x44, 0xC2, 0xEB, 0x70, 0x03, 0xA2, 0x63, 0x99, 0xF0, 0x73, 0x02, 0x27,
-                0x75, 0x68, 0x3C, 0x01,
-            ],
-            &[
-                0xBC, 0xD2, 0x29, 0xF7, 0x02, 0xFA, 0x52, 0x1A, 0x57, 0xB4, 0x94, 0xA3, 0xC7, 0x9B,
-                0x76, 0x1B, 0xB0, 0x9E, 0xFC, 0xC3, 0x57, 0xED, 0x1C, 0xF9, 0x55, 0xC9, 0x75, 0xCA,
-                0x57, 0x30, 0x31, 0x66,
-            ],
-        ],
-        [
-            &[
-                0xC1, 0xDE, 0x2D, 0x03, 0x8E, 0x54, 0xB8, 0x77, 0x99, 0x2E, 0x0B, 0x1A, 0x97, 0xD9,
-                0xD0, 0x83, 0x8E, 0x4F, 0x9A, 0x01, 0x13, 0xED, 0xCC, 0x50, 0x10, 0xDD, 0x46, 0x81,
-                0x1E, 0x8F, 0x16, 0x29,
-            ],
-            &[
-                0x42, 0xE3, 0x0D, 0x6A, 0xE6, 0x60, 0x01, 0x77, 0xA5, 0xE7, 0x4E, 0xE0, 0xFD, 0x87,
-                0x07, 0x2F, 0x58, 0xE7, 0xFB, 0x3E, 0x34, 0x47, 0x9F, 0xFC, 0x64, 0x1F, 0xF2, 0x27,
-                0xEA, 0x08, 0xB5, 0x0C,
-            ],
-            &[
-                0x02, 0x3E, 0xF4, 0xC7, 0x65, 0x65, 0xB0, 0x2F, 0x5B, 0xAE, 0xE7, 0x90, 0xB8, 0xCB,
-                0x2D, 0x59, 0xD0, 0xBB, 0x9B, 0x9E, 0x85, 0x76, 0x03, 0xD6, 0x88, 0x20, 0xCA, 0x70,
-                0xDC, 0x86, 0x83, 0x03,
-            ],
-            &[
-                0x38, 0x32, 0x58, 0xF1, 0x85, 0x4E, 0x0A, 0x69, 0x1D, 0x86, 0x12, 0x85, 0xA9, 0x05,
-                0xD0, 0xC7, 0x20, 0x05, 0x71, 0x77, 0x30, 0x93, 0x73, 0xDF, 0x40, 0xDD, 0x14, 0x98,
-                0x6A, 0xEF, 0x0F, 0x2B,
-            ],
-        ],
-        [
-            &[
-                0x5D, 0x1B, 0xCE, 0x24, 0xD2, 0x87, 0x04, 0xB6, 0x64, 0xC5, 0x3D, 0xC7, 0x58, 0xA4,
-                0x46, 0x27, 0xAC, 0x54, 0xAA, 0x0E, 0x95, 0x5E, 0x7D, 0x24, 0x7B, 0x5E, 0x02, 0x33,
-                0x27, 0x77, 0xB7, 0x6E,
-            ],
-            &[
-                0x17, 0xA0, 0xD6, 0x4D, 0xF8, 0x74, 0x52, 0x7A, 0x89, 0x78, 0x80, 0x15, 0x40, 0xEB,
-                0xB6, 0x64, 0xD4, 0xA6, 0x52, 0xDD, 0xAB, 0xD9, 0xB3, 0xF9, 0x3D, 0x5B, 0x9B, 0x1F,
-                0xE9, 0x6F, 0xAF, 0x55,
-            ],
-            &[
-                0xB0, 0x2F, 0x97, 0x56, 0x77, 0x36, 0x3A, 0x83, 0x73, 0x85, 0xAC, 0x7A, 0x5B, 0xB2,
-                0x05, 0x04, 0x37, 0x30, 0xD9, 0x81, 0x69, 0xB9, 0x62, 0xD0, 0x00, 0x0C, 0x85, 0xF6,
-                0x62, 0x3B, 0x3A, 0x37,
-            ],
-            &[
-                0x9F, 0x51, 0xAB, 0x3F, 0x81, 0xD4, 0xA6, 0x38, 0x46, 0x05, 0x38, 0x6E, 0x17, 0xEA,
-                0x33, 0xE9, 0xB9, 0x78, 0x09, 0x60, 0x40, 0x6C, 0x43, 0xCE, 0x70, 0x32, 0x0A, 0x2C,
-                0x5E, 0xB2, 0xEB, 0x15,
-            ],
-        ],
-    ];
-
-    // // the following code is used to dump the key schedule to screen
-    // // in a sage friendly format
-    // let keys2 = rescue_perm.key_schedule(&zero);
-    // for e in keys2.iter() {
-    //     for f in e.vec.iter() {
-    //         ark_std::println!("permutation_round_key.append(0x{})",
-    // f.into_bigint());     }
-    // }
-    // The keys are not cross-checked with sage, however, the hash function
-    // is, which implies that the permutation round keys must be correct
-    // (or else, the hash function outputs will not match)
-    // see unit tests:
-    //   - test_sponge()
-    //   - test_rescue_hash_on_0_vec()
-    //   - test_rescue_perm_on_0_vec()
-    const PERMUTATION_ROUND_KEYS: [[&'static [u8]; 4]; 25] = [
-        [
-            &[
-                0x4D, 0x54, 0x21, 0x08, 0xC7, 0xC6, 0x88, 0xB1, 0xBD, 0x2E, 0x14, 0xE1, 0xA3, 0xB1,
-                0xD4, 0x8B, 0x4E, 0xF9, 0x83, 0x36, 0x09, 0xD9, 0x7F, 0x6D, 0x3E, 0x1E, 0xC1, 0x9F,
-                0xDA, 0x9F, 0x25, 0x33,
-            ],
-            &[
-                0x5A, 0x40, 0x95, 0xBD, 0x94, 0x49, 0xC2, 0x4D, 0xA3, 0x1F, 0x5A, 0xC3, 0x9E, 0x48,
-                0xD9, 0x10, 0xF9, 0xAD, 0x9E, 0x27, 0x28, 0x44, 0xD1, 0x3C, 0x90, 0x13, 0xEB, 0x78,
-                0x7B, 0x52, 0x7D, 0x11,
-            ],
-            &[
-                0x11, 0x41, 0x61, 0xEB, 0xD9, 0xBA, 0xC2, 0x1D, 0x01, 0xEB, 0x90, 0xD0, 0xF2, 0xE4,
-                0x25, 0x3C, 0x2A, 0xA6, 0x0D, 0xA2, 0x5B, 0x69, 0x21, 0xC1, 0xC4, 0xD4, 0xC0, 0xB1,
-                0x6A, 0xE4, 0x43, 0x0E,
-            ],
-            &[
-                0x49, 0x1A, 0xB8, 0xED, 0xA0, 0xB5, 0x82, 0x63, 0xBF, 0x65, 0x39, 0x78, 0xA2, 0x96,
-                0x7F, 0xF3, 0xF0, 0xD2, 0xCE, 0x13, 0xE9, 0x46, 0xA9, 0xEA, 0x82, 0xD7, 0x2C, 0xAB,
-                0x91, 0x57, 0xE9, 0x01,
-            ],
-        ],
-        [
-            &[
-                0x15, 0x30, 0xC1, 0x26, 0x0A, 0xBF, 0xC3, 0xE2, 0xBB, 0x64, 0x34, 0xA4, 0x39, 0x9E,
-                0xA9, 0xD0, 0x6B, 0xB6, 0xB6, 0xFB, 0xB2, 0x12, 0x45, 0x9E, 0x41, 0x6D, 0x4E, 0xAC,
-                0x4C, 0x17, 0x40, 0x55,
-            ],
-            &[
-                0xFE, 0x39, 0xCC, 0xBB, 0x1B, 0xD4, 0x07, 0x0A, 0x73, 0x23, 0xCD, 0x4A, 0xCC, 0x83,
-                0x82, 0x17, 0x09, 0xD8, 0xA7, 0x39, 0x89, 0xAE, 0xA3, 0x51, 0x5E, 0x0A, 0x7A, 0x3A,
-                0xA6, 0xAC, 0x3B, 0x62,
-            ],
-            &[
-                0x85, 0xBF, 0x73, 0x74, 0x07, 0xB3, 0xD8, 0x04, 0x8B, 0xEF, 0x93, 0xBE, 0xDF, 0x00,
-                0x0B, 0xF6, 0x34, 0xC9, 0x57, 0x6A, 0x68, 0xDC, 0xFA, 0x83, 0xEE, 0x94, 0x24, 0x46,
-                0xBA, 0x18, 0x7B, 0x1E,
-            ],
-            &[
-                0x63, 0x2C, 0x33, 0x28, 0x32, 0x8B, 0x2A, 0x35, 0xCC, 0x25, 0x0A, 0xD8, 0xB7, 0x05,
-                0xF9, 0x77, 0x32, 0x1D, 0xF5, 0x66, 0xE4, 0x7F, 0x80, 0xB6, 0xE1, 0x2F, 0xE5, 0x65,
-                0x53, 0x20, 0xAA, 0x25,
-            ],
-        ],
-        [
-            &[
-                0x5F, 0xA2, 0x5D, 0x72, 0xCF, 0xC6, 0xEC, 0xC9, 0xE6, 0xD4, 0x00, 0xFE, 0x02, 0xA4,
-                0x16, 0x99, 0x2F, 0xD6, 0xF8, 0x3A, 0x29, 0x53, 0x8F, 0xE7, 0x0D, 0xB0, 0xC2, 0xD9,
-                0xED, 0xA7, 0x9D, 0x49,
-            ],
-            &[
-                0x5B, 0x69, 0xAB, 0x71, 0xF7, 0x37, 0xEC, 0x2E, 0x35, 0x35, 0x04, 0x16, 0x27, 0x69,
-                0x51, 0x85, 0xE2, 0x90, 0x5F, 0xBC, 0x30, 0x76, 0x01, 0x61, 0x49, 0x74, 0x8F, 0xF0,
-                0x5C, 0x85, 0x6E, 0x43,
-            ],
-            &[
-                0x90, 0x1B, 0x70, 0x76, 0xF0, 0xB7, 0x80, 0x33, 0x89, 0x32, 0xB7, 0x77, 0x8E, 0xE3,
-                0x92, 0xFB, 0x4A, 0x81, 0x2C, 0x7E, 0x3D, 0xDC, 0x4F, 0xB3, 0x24, 0xB4, 0x6C, 0x84,
-                0x5E, 0xF6, 0xB7, 0x3E,
-            ],
-            &[
-                0x01, 0x0D, 0x21, 0x16, 0x28, 0x00, 0xA4, 0x81, 0x18, 0x2C, 0x5E, 0x6A, 0xB7, 0xA3,
-                0x86, 0xD7, 0x7E, 0x44, 0xC6, 0xF9, 0xBE, 0xD8, 0xB1, 0x46, 0xC0, 0x07, 0xFD, 0x76,
-                0xFD, 0xD2, 0x0B, 0x16,
-            ],
-        ],
-        [
-            &[
-                0xA3, 0x87, 0x3A, 0xEA, 0xED, 0xFF, 0xFD, 0xF5, 0x59, 0x4A, 0x87, 0xBD, 0x68, 0x86,
-                0xDC, 0xD9, 0x43, 0xC7, 0xAA, 0x98, 0x92, 0xE2, 0x7B, 0x50, 0x00, 0x6A, 0x79, 0xAC,
-                0x05, 0x71, 0x52, 0x54,
-            ],
-            &[
-                0x81, 0x47, 0x15, 0x4D, 0xED, 0x43, 0x1A, 0xA2, 0x7D, 0x92, 0x22, 0x17, 0x91, 0xFB,
-                0x6C, 0x0E, 0xE9, 0x89, 0xB9, 0x87, 0x30, 0xDD, 0x64, 0xFF, 0x86, 0x18, 0xF1, 0x55,
-                0xF2, 0x6B, 0xE3, 0x28,
-            ],
-            &[
-                0x32, 0x50, 0xC6, 0x51, 0x92, 0xA9, 0x85, 0x9E, 0xCE, 0xE4, 0x95, 0x31, 0x88, 0xBA,
-                0xA4, 0x84, 0x91, 0xAC, 0xCB, 0x8E, 0x65, 0xCC, 0x57, 0xB0, 0xC8, 0x49, 0x02, 0xEF,
-                0x81, 0x03, 0x45, 0x57,
-            ],
-            &[
-                0x49, 0x6A, 0xD6, 0x3F, 0xC5, 0x0B, 0x90, 0xBD, 0x5B, 0x50, 0x29, 0xAD, 0x3A, 0xB1,
-                0x49, 0x5B, 0xB2, 0x5E, 0xCC, 0xF5, 0xF8, 0x64, 0xE5, 0x36, 0xC5, 0x6E, 0xCD, 0x18,
-                0x39, 0x96, 0xF6, 0x6C,
-            ],
-        ],
-        [
-            &[
-                0xA9, 0x9D, 0x9D, 0x99, 0x5F, 0xFC, 0x1D, 0xA6, 0x32, 0x9A, 0xB8, 0x7A, 0x15, 0x4D,
-                0xF1, 0x6B, 0x63, 0xD7, 0x35, 0x8E, 0x37, 0xFB, 0xA2, 0x79, 0xA6, 0x2D, 0x17, 0xD3,
-                0x34, 0xA1, 0x11, 0x53,
-            ],
-            &[
-                0x49, 0x1C, 0x91, 0x13, 0x5F, 0x9D, 0x02, 0x79, 0xFF, 0xAD, 0x28, 0xF5, 0x4F, 0x0C,
-                0x0B, 0x5B, 0x55, 0xA9, 0x43, 0x5B, 0xB7, 0xC0, 0xCB, 0x54, 0xE0, 0x40, 0xA1, 0xB4,
-                0x02, 0x02, 0x0C, 0x3C,
-            ],
-            &[
-                0x72, 0x60, 0x9C, 0xDB, 0x2E, 0x67, 0xE0, 0x57, 0xC6, 0x66, 0x84, 0x96, 0x96, 0x8D,
-                0xC1, 0x69, 0xF7, 0xD5, 0x15, 0x03, 0xB2, 0x62, 0xF9, 0x01, 0x12, 0x3A, 0x34, 0xBD,
-                0xE2, 0xA2, 0x04, 0x6F,
-            ],
-            &[
-                0xE1, 0x8E, 0x14, 0xD1, 0x15, 0xA6, 0xB8, 0x8B, 0xE7, 0x74, 0x21, 0xD4, 0xCB, 0x1E,
-                0x4D, 0xB3, 0x1E, 0x5E, 0x69, 0xCC, 0xE1, 0xA5, 0x93, 0x2B, 0xC5, 0x18, 0xC5, 0xD0,
-                0x57, 0x7F, 0x62, 0x49,
-            ],
-        ],
-        [
-            &[
-                0x60, 0xF1, 0xB7, 0x1C, 0xE3, 0x04, 0xC3, 0x11, 0xE5, 0xAC, 0x62, 0xB1, 0xD3, 0xB4,
-                0xF7, 0xEA, 0x0B, 0xBA, 0x2F, 0x10, 0x5E, 0xC2, 0x5F, 0xA6, 0xB6, 0xB6, 0xA4, 0x8A,
-                0xDB, 0xA2, 0xC0, 0x6F,
-            ],
-            &[
-                0x26, 0x28, 0xF8, 0x1F, 0x12, 0xEC, 0x66, 0xF4, 0x66, 0x58, 0x73, 0xB6, 0x48, 0x80,
-                0x73, 0xE8, 0x1B, 0xE3, 0x24, 0xC0, 0xD6, 0x26, 0x28, 0x9B, 0xDE, 0x14, 0x63, 0x18,
-                0xE1, 0x0B, 0xB6, 0x63,
-            ],
-            &[
-                0xC8, 0x91, 0x9F, 0x1B, 0x74, 0xC5, 0xE2, 0xC7, 0xDD, 0xC0, 0x9B, 0x97, 0x8F, 0xD5,
-                0xDA, 0x64, 0xAA, 0x1A, 0x1A, 0x81, 0xFA, 0x80, 0x36, 0x41, 0x6D, 0x18, 0x95, 0x15,
-                0xB5, 0x68, 0xC1, 0x3E,
-            ],
-            &[
-                0xE3, 0x33, 0xB3, 0xF9, 0x9F, 0x63, 0xD5, 0x71, 0x33, 0xC2, 0x2E, 0x64, 0xB3, 0xAA,
-                0x37, 0x17, 0x71, 0x8F, 0xA8, 0x9F, 0x53, 0x23, 0x04, 0x07, 0x7F, 0xE5, 0x42, 0xED,
-                0xD8, 0x7E, 0x7F, 0x4E,
-            ],
-        ],
-        [
-            &[
-                0x54, 0x3F, 0xF7, 0x16, 0xFA, 0x10, 0xB8, 0x71, 0xA8, 0x04, 0x2B, 0xB3, 0xA2, 0x99,
-                0xAC, 0x8F, 0x59, 0x4B, 0x56, 0x3B, 0x34, 0x12, 0xFA, 0x2E, 0xC5, 0xDD, 0x85, 0xD3,
-                0x8F, 0x06, 0x57, 0x24,
-            ],
-            &[
-                0x2A, 0xB4, 0xE0, 0x6D, 0xA5, 0x67, 0xF0, 0xBF, 0xFF, 0xC3, 0x53, 0x54, 0xDC, 0xDD,
-                0xCC, 0x64, 0x0B, 0xB9, 0x8B, 0xEE, 0x39, 0xC9, 0x35, 0xDC, 0xF3, 0x0C, 0x19, 0xA7,
-                0x5B, 0xE8, 0x20, 0x6D,
-            ],
-            &[
-                0x77, 0xFA, 0x55, 0x41, 0x99, 0x6E, 0x53, 0x17, 0x51, 0x27, 0x2A, 0xA7, 0x7C, 0x8C,
-                0x36, 0xEB, 0x98, 0x46, 0xA8, 0x2D, 0xCE, 0x6E, 0xF3, 0x78, 0x7F, 0x8D, 0xA3, 0x27,
-                0xCB, 0x48, 0x7F, 0x12,
-            ],
-            &[
-                0xAB, 0x15, 0x83, 0x0B, 0xF7, 0xAC, 0x4D, 0x66, 0x4E, 0x7D, 0x15, 0x25, 0x5D, 0x25,
-                0x92, 0x2A, 0xAC, 0x65, 0xB4, 0xAE, 0xB8, 0xA0, 0x11, 0x3A, 0x4C, 0x84, 0x8A, 0x9A,
-                0x0A, 0x81, 0xC3, 0x47,
-            ],
-        ],
-        [
-            &[
-                0xA1, 0x7C, 0x1A, 0x41, 0xDE, 0x1C, 0x1A, 0x5F, 0x26, 0x2C, 0x9C, 0xB8, 0x04, 0x9D,
-                0x65, 0x13, 0x51, 0xA0, 0xF3, 0x5C, 0x79, 0x8E, 0x22, 0xB6, 0x3D, 0x33, 0xA6, 0x42,
-                0xE4, 0xE5, 0xF6, 0x31,
-            ],
-            &[
-                0xFD, 0xF2, 0xCF, 0xEF, 0xC2, 0xEE, 0x89, 0x0B, 0x41, 0x9C, 0x48, 0xB3, 0xC9, 0x23,
-                0x47, 0x66, 0xCB, 0x27, 0x00, 0x5E, 0x60, 0x15, 0x77, 0xA8, 0xFF, 0xFC, 0x54, 0x9D,
-                0x35, 0xD1, 0xE1, 0x23,
-            ],
-            &[
-                0x97, 0x38, 0x9B, 0xF5, 0x11, 0x28, 0x22, 0x02, 0x98, 0xE1, 0xBA, 0x95, 0x1F, 0x58,
-                0xC5, 0xD2, 0x76, 0x92, 0xFE, 0xE3, 0xC7, 0x2D, 0xFF, 0x56, 0x9C, 0xAF, 0x90, 0x81,
-                0xE3, 0xAC, 0xBA, 0x26,
-            ],
-            &[
-                0x4D, 0x5D, 0x31, 0x62, 0x10, 0xA5, 0x6C, 0xF9, 0xFE, 0x79, 0xFE, 0x47, 0x83, 0xF5,
-                0xA5, 0x60, 0xCF, 0x52, 0x47, 0x3F, 0x8E, 0xE6, 0xCB, 0x84, 0x91, 0x09, 0x9D, 0x08,
-                0x07, 0x3D, 0x95, 0x65,
-            ],
-        ],
-        [
-            &[
-                0x3D, 0xDF, 0x8B, 0xAD, 0x02, 0x96, 0x2C, 0xEA, 0x33, 0x6D, 0x5B, 0xD1, 0xBD, 0x2E,
-                0xEF, 0x38, 0x9B, 0x63, 0x84, 0x3B, 0x31, 0x1B, 0x74, 0x50, 0xC1, 0x0A, 0xC9, 0xA5,
-                0x09, 0x04, 0x85, 0x54,
-            ],
-            &[
-                0xB5, 0xD8, 0x38, 0x97, 0xE3, 0x58, 0x47, 0x7F, 0xDC, 0xA6, 0x84, 0xA0, 0x3B, 0xF3,
-                0x41, 0x73, 0x8A, 0xA0, 0x88, 0xFF, 0xF1, 0x64, 0xA2, 0x17, 0x88, 0xDE, 0x2E, 0xC7,
-                0x8A, 0x02, 0x7F, 0x2A,
-            ],
-            &[
-                0x16, 0x43, 0x5B, 0xA1, 0x25, 0x76, 0x47, 0x37, 0xB8, 0xE7, 0xA2, 0x5B, 0x86, 0x46,
-                0x86, 0x0D, 0xA8, 0x5A, 0x0B, 0xE9, 0xFB, 0xDD, 0xD0, 0x63, 0xE3, 0x4A, 0x3C, 0xC0,
-                0x4E, 0xA4, 0x78, 0x49,
-            ],
-            &[
-                0x2A, 0x06, 0xC5, 0xC1, 0x67, 0xFE, 0x17, 0x4F, 0xAE, 0xC7, 0xCB, 0x4D, 0x45, 0x29,
-                0x88, 0x7E, 0xD3, 0x34, 0x30, 0xAB, 0xBD, 0x5F, 0xEC, 0xC2, 0xE7, 0x8C, 0x75, 0x3B,
-                0x6E, 0xE5, 0x4A, 0x62,
-            ],
-        ],
-        [
-            &[
-                0x2E, 0x93, 0x1D, 0xFF, 0x5E, 0x47, 0x1D, 0x08, 0x8F, 0x5B, 0x8B, 0x3A, 0x3B, 0x43,
-                0xAF, 0xB7, 0x3D, 0x8F, 0xE8, 0x30, 0x8D, 0x9F, 0xE2, 0x6F, 0xD7, 0xB7, 0xD7, 0x9E,
-                0x69, 0x98, 0x46, 0x35,
-            ],
-            &[
-                0xC3, 0xFE, 0x5E, 0x7F, 0x9A, 0xB6, 0xEE, 0xC6, 0x0C, 0xA4, 0xDA, 0x59, 0x1E, 0xFE,
-                0x9A, 0x45, 0x67, 0x39, 0xB4, 0x84, 0xF6, 0xFA, 0x0E, 0x2D, 0x16, 0xD3, 0x3A, 0x8D,
-                0xFE, 0x4F, 0xBC, 0x4B,
-            ],
-            &[
-                0x78, 0x33, 0xE9, 0x2C, 0x4D, 0x74, 0xE1, 0x01, 0xB3, 0x16, 0x23, 0x66, 0xCE, 0xCB,
-                0x8D, 0x9E, 0xF7, 0x38, 0x0D, 0x75, 0x14, 0x8F, 0xC7, 0xCE, 0x61, 0xBA, 0x71, 0xD6,
-                0xF3, 0x6F, 0xD3, 0x3B,
-            ],
-            &[
-                0x0E, 0xE2, 0x8A, 0x9A, 0x04, 0xB2, 0xFA, 0xF5, 0xAE, 0x46, 0x98, 0xDC, 0x51, 0x75,
-                0x4F, 0x81, 0xBB, 0x4E, 0x29, 0x2C, 0x1F, 0x15, 0x81, 0x25, 0x3F, 0x48, 0xC6, 0x4E,
-                0x18, 0xE4, 0x80, 0x26,
-            ],
-        ],
-        [
-            &[
-                0xD2, 0x34, 0x30, 0x47, 0xA0, 0x28, 0x63, 0xDD, 0x59, 0xE6, 0x79, 0x0E, 0xFB, 0x76,
-                0xF1, 0xC9, 0xE3, 0x73, 0x7E, 0xEC, 0xE0, 0xBA, 0xCA, 0x4F, 0x80, 0x1B, 0x68, 0x1C,
-                0xA2, 0x31, 0x19, 0x73,
-            ],
-            &[
-                0x4B, 0xCF, 0x2C, 0x71, 0x16, 0xDE, 0x4D, 0x4E, 0x5A, 0x5A, 0xAF, 0x0A, 0x9C, 0xE9,
-                0x62, 0x0D, 0x7E, 0xC9, 0xCA, 0x9E, 0x90, 0xC2, 0x12, 0xF7, 0x95, 0xCA, 0x92, 0xF0,
-                0x7B, 0x30, 0x7C, 0x03,
-            ],
-            &[
-                0x76, 0x95, 0xA3, 0x2A, 0x6F, 0x75, 0x35, 0xC2, 0x03, 0x59, 0xFC, 0x4C, 0x45, 0xC2,
-                0xF3, 0x3F, 0x29, 0xE0, 0xB0, 0x22, 0xD9, 0x26, 0x45, 0xFE, 0x3D, 0x09, 0x15, 0x3F,
-                0xB4, 0x31, 0x3F, 0x12,
-            ],
-            &[
-                0xD0, 0x05, 0x2A, 0x02, 0x7F, 0xCE, 0x98, 0x7B, 0x60, 0xF0, 0x7B, 0x46, 0x3A, 0x5E,
-                0x1E, 0xB0, 0xBF, 0xE6, 0x16, 0x48, 0x0A, 0x9F, 0x97, 0x75, 0xDD, 0x2F, 0x5F, 0x03,
-                0xB6, 0x08, 0xAB, 0x21,
-            ],
-        ],
-        [
-            &[
-                0x3C, 0x41, 0x99, 0xCC, 0x63, 0x6B, 0xBB, 0xAA, 0x59, 0x36, 0x8A, 0x3C, 0x76, 0xA3,
-                0x56, 0xB6, 0x94, 0x53, 0xE5, 0x55, 0xBA, 0xE2, 0x86, 0xBD, 0x65, 0xA2, 0x52, 0x6B,
-                0x6F, 0xB2, 0xBE, 0x55,
-            ],
-            &[
-                0x7A, 0xD4, 0xAA, 0x7B, 0x26, 0xC9, 0x7B, 0x79, 0xCB, 0x14, 0x12, 0x9C, 0x06, 0x4C,
-                0x9E, 0x3A, 0x4E, 0x2B, 0xBB, 0x94, 0x34, 0xDE, 0xF0, 0xBB, 0x1C, 0x5B, 0x9D, 0xD6,
-                0x45, 0x11, 0xAF, 0x32,
-            ],
-            &[
-                0x7E, 0x3A, 0xEE, 0x83, 0x02, 0xE1, 0x2E, 0x2A, 0xBF, 0x33, 0x7E, 0xDF, 0xCA, 0x9D,
-                0x69, 0x46, 0xCA, 0xFF, 0x3A, 0xEF, 0x5B, 0x89, 0x91, 0x08, 0xBB, 0x0C, 0x47, 0x0C,
-                0xF1, 0x59, 0x1D, 0x56,
-            ],
-            &[
-                0x62, 0x08, 0x94, 0x3C, 0x02, 0x01, 0xED, 0xB6, 0x19, 0x32, 0xD1, 0xEA, 0xA1, 0xE6,
-                0x2A, 0xB7, 0xEA, 0x0E, 0x50, 0xD7, 0xB9, 0xFD, 0xFC, 0xF3, 0x3B, 0x48, 0x0F, 0xA8,
-                0xB4, 0xAE, 0x9B, 0x19,
-            ],
-        ],
-        [
-            &[
-                0x2E, 0xF3, 0xB2, 0xC9, 0xB6, 0xBF, 0x85, 0x2C, 0x26, 0xDE, 0x0E, 0xD5, 0x71, 0x47,
-                0xC6, 0xA1, 0x4B, 0x42, 0xF4, 0x51, 0x71, 0x32, 0x12, 0x1F, 0xC3, 0x68, 0xDD, 0x62,
-                0xC3, 0xA7, 0x14, 0x05,
-            ],
-            &[
-                0xA4, 0x0A, 0xE7, 0x48, 0x7B, 0xD4, 0x35, 0xD7, 0x13, 0x17, 0x93, 0x0C, 0xBA, 0xA9,
-                0xBF, 0xAA, 0xBC, 0x7B, 0xEB, 0x2C, 0x61, 0xD1, 0x0F, 0xE6, 0x4F, 0xF1, 0x1A, 0x3A,
-                0xB8, 0x37, 0xE9, 0x68,
-            ],
-            &[
-                0x47, 0x11, 0x66, 0xB6, 0xC0, 0x9E, 0x17, 0x63, 0x37, 0x53, 0xBD, 0xB2, 0xEC, 0xEB,
-                0xF3, 0xF5, 0x35, 0xB6, 0xCA, 0x29, 0x30, 0xE6, 0x50, 0xF0, 0x82, 0xF5, 0x47, 0x35,
-                0x92, 0xE0, 0x76, 0x35,
-            ],
-            &[
-                0x88, 0x10, 0x61, 0x29, 0x67, 0xC1, 0x06, 0x89, 0xC0, 0x9E, 0x7E, 0x19, 0xC4, 0x5A,
-                0xFF, 0xB1, 0xD4, 0xB2, 0x55, 0xF8, 0xD8, 0x05, 0x34, 0x67, 0x1D, 0x65, 0x76, 0xE3,
-                0xD9, 0xA7, 0xED, 0x20,
-            ],
-        ],
-        [
-            &[
-                0xB0, 0x37, 0xF1, 0x7B, 0x7E, 0xA6, 0x29, 0x42, 0x20, 0x2B, 0x9B, 0x3E, 0xFD, 0x47,
-                0x1C, 0x6A, 0x05, 0x0C, 0x34, 0x80, 0xE3, 0xC4, 0xC3, 0x8B, 0xBF, 0xA1, 0x30, 0xD9,
-                0x20, 0x38, 0xFF, 0x40,
-            ],
-            &[
-                0x8F, 0x77, 0xFA, 0x3F, 0x40, 0x96, 0xBE, 0x2F, 0x68, 0x59, 0x28, 0xDB, 0x5C, 0x70,
-                0x56, 0x3E, 0xA4, 0x5F, 0x8C, 0xC7, 0x0D, 0xFA, 0xB1, 0x64, 0xAB, 0x2D, 0xCC, 0x14,
-                0xA5, 0x98, 0x8B, 0x50,
-            ],
-            &[
-                0xA6, 0xF7, 0xF6, 0x2D, 0x47, 0x8F, 0x36, 0xFD, 0x93, 0xFD, 0x14, 0xA4, 0x04, 0xFE,
-                0xDD, 0x04, 0x2C, 0xA9, 0x8D, 0x99, 0x99, 0x3D, 0x15, 0x59, 0xB9, 0xF4, 0x60, 0xA1,
-                0xB4, 0x0C, 0xAE, 0x50,
-            ],
-            &[
-                0xDA, 0x88, 0x94, 0x68, 0x2B, 0xC3, 0x8C, 0xF5, 0x25, 0x36, 0x26, 0xCF, 0x69, 0x89,
-                0xF6, 0x80, 0x21, 0xDB, 0x4A, 0x5E, 0x1F, 0x3C, 0xFF, 0xA9, 0x2F, 0xD8, 0xE6, 0xCC,
-                0x7E, 0x2B, 0x0D, 0x58,
-            ],
-        ],
-        [
-            &[
-                0x13, 0x25, 0x5D, 0x44, 0xE1, 0x90, 0x91, 0x6B, 0x40, 0x33, 0xE8, 0x8A, 0xB2, 0x06,
-                0x4A, 0xBC, 0x39, 0x39, 0xBC, 0xB2, 0x7B, 0xAE, 0x5C, 0xC4, 0xF4, 0x9B, 0x9B, 0xF6,
-                0x71, 0x54, 0x0E, 0x51,
-            ],
-            &[
-                0xD5, 0x90, 0xD0, 0xB7, 0x90, 0xE3, 0xD4, 0xD4, 0xC1, 0xED, 0xD1, 0x04, 0x84, 0x0E,
-                0xA9, 0xE3, 0x9B, 0x48, 0x41, 0x92, 0x04, 0x9E, 0x69, 0x46, 0x92, 0xFC, 0xB6, 0x50,
-                0x8F, 0x29, 0x0D, 0x54,
-            ],
-            &[
-                0x7F, 0xF3, 0x56, 0x03, 0x65, 0x4E, 0xD3, 0x1D, 0x19, 0xC4, 0x6A, 0xE6, 0xC3, 0x41,
-                0x1F, 0xE0, 0xC6, 0x1B, 0xAA, 0x71, 0x69, 0x78, 0x5A, 0xA2, 0x38, 0x45, 0x20, 0x5D,
-                0x03, 0x79, 0x3B, 0x38,
-            ],
-            &[
-                0x9F, 0xA3, 0xED, 0xC3, 0xC6, 0x3C, 0xF5, 0x7D, 0xEF, 0x74, 0x71, 0x24, 0xDE, 0xEE,
-                0x72, 0xDA, 0xFA, 0x56, 0x46, 0x1B, 0x07, 0x01, 0xFB, 0x37, 0x77, 0xC5, 0xC3, 0x3C,
-                0x0E, 0x04, 0xB4, 0x49,
-            ],
-        ],
-        [
-            &[
-                0xD9, 0xBB, 0x69, 0x9F, 0xC6, 0xDE, 0x0B, 0x75, 0xDB, 0x6B, 0x3F, 0x3D, 0xC3, 0xD9,
-                0xE5, 0x7E, 0x33, 0xF4, 0x47, 0x50, 0x5F, 0xC1, 0xFC, 0xDA, 0x2E, 0x15, 0x74, 0x48,
-                0x2B, 0x62, 0xB8, 0x4F,
-            ],
-            &[
-                0xD8, 0xD8, 0x30, 0x97, 0xE5, 0x62, 0x54, 0xBE, 0x2C, 0xD2, 0x17, 0x9D, 0x4A, 0x73,
-                0xC0, 0xAB, 0x9A, 0x4A, 0xCF, 0x17, 0x75, 0x37, 0x69, 0xDD, 0xBF, 0x5F, 0x5C, 0x21,
-                0x6F, 0xC9, 0x01, 0x21,
-            ],
-            &[
-                0x28, 0x60, 0x9F, 0x78, 0xB3, 0xA3, 0xA7, 0x7D, 0x32, 0x82, 0x33, 0x4E, 0xAC, 0x91,
-                0x82, 0x09, 0x6D, 0x5E, 0xCC, 0x92, 0xBE, 0x46, 0x76, 0x04, 0xAA, 0x72, 0x32, 0x79,
-                0x58, 0xE7, 0x56, 0x66,
-            ],
-            &[
-                0x01, 0x69, 0x26, 0x73, 0x87, 0xCE, 0xEB, 0xC7, 0x30, 0x2C, 0x15, 0x6E, 0x52, 0xF3,
-                0xC3, 0x00, 0xF6, 0x06, 0x14, 0x53, 0x2C, 0xCC, 0xEA, 0x51, 0x35, 0x53, 0xDD, 0x7D,
-                0x33, 0x98, 0x32, 0x1F,
-            ],
-        ],
-        [
-            &[
-                0x2F, 0xEE, 0x77, 0xF3, 0x1A, 0x20, 0x4A, 0xD5, 0x0F, 0x91, 0x95, 0x08, 0x09, 0x9F,
-                0x99, 0x23, 0x90, 0x2A, 0xDF, 0xA3, 0xCA, 0x0F, 0xCE, 0x02, 0xBE, 0x15, 0x75, 0xEC,
-                0xD4, 0x52, 0xF9, 0x33,
-            ],
-            &[
-                0x5F, 0xAA, 0xD2, 0x88, 0xDC, 0x6E, 0x97, 0x23, 0x5D, 0x67, 0x40, 0x52, 0xA4, 0x82,
-                0xEA, 0x65, 0x78, 0x98, 0xE5, 0xC0, 0x7A, 0x8A, 0x0C, 0xF6, 0x05, 0x1D, 0x06, 0x28,
-                0xFF, 0xC3, 0x51, 0x24,
-            ],
-            &[
-                0x89, 0x6E, 0x8D, 0xE3, 0x00, 0x3B, 0x01, 0xC6, 0xB5, 0x93, 0x6B, 0x02, 0x51, 0xAE,
-                0x12, 0x3A, 0x89, 0x34, 0xB3, 0x09, 0x57, 0x63, 0x4B, 0xD0, 0xF8, 0xDF, 0x1B, 0x1F,
-                0x6E, 0x9C, 0xC7, 0x58,
-            ],
-            &[
-                0x6D, 0x65, 0x11, 0xC5, 0x69, 0xEB, 0xDC, 0xE7, 0x67, 0x5C, 0x1D, 0x78, 0xE1, 0x39,
-                0x07, 0x3C, 0xB1, 0x3D, 0x1C, 0x5D, 0xE0, 0xDD, 0x37, 0xF0, 0xB5, 0x47, 0xC2, 0x7F,
-                0xD0, 0xB2, 0x83, 0x3A,
-            ],
-        ],
-        [
-            &[
-                0x1D, 0xC7, 0xE9, 0x7B, 0x11, 0x75, 0xAC, 0x79, 0x90, 0x57, 0x37, 0xFE, 0xB8, 0xD5,
-                0x3E, 0x2A, 0x9F, 0xD7, 0x59, 0x1F, 0x26, 0x4C, 0x75, 0x9B, 0x54, 0x77, 0xE3, 0x66,
-                0x28, 0xE9, 0x7E, 0x1C,
-            ],
-            &[
-                0x66, 0xFD, 0x2E, 0x26, 0xBF, 0xC9, 0xFF, 0x3C, 0xC9, 0x77, 0xE9, 0x61, 0x2D, 0x2E,
-                0xB7, 0x05, 0x09, 0x59, 0xEC, 0xA9, 0x75, 0x52, 0x8B, 0xA8, 0x7E, 0x15, 0x00, 0x01,
-                0xA8, 0xF9, 0xFF, 0x0E,
-            ],
-            &[
-                0x28, 0xD4, 0xE7, 0xF3, 0x4E, 0x40, 0x37, 0xB1, 0xF6, 0x4A, 0xBD, 0x41, 0xE7, 0xBF,
-                0xE4, 0x7A, 0xD5, 0x3F, 0x2D, 0x39, 0xAD, 0x1D, 0x1A, 0x3F, 0x08, 0x2D, 0x24, 0x85,
-                0x9F, 0xD4, 0xE7, 0x61,
-            ],
-            &[
-                0xC4, 0x2B, 0x70, 0x6B, 0x84, 0x0B, 0xE2, 0xD3, 0x27, 0xD8, 0x13, 0x8F, 0x35, 0x75,
-                0x81, 0x1C, 0x31, 0x42, 0x68, 0x22, 0x7C, 0x8A, 0x2E, 0x4C, 0x2F, 0xA8, 0x10, 0x0F,
-                0x7B, 0xA9, 0xB4, 0x2F,
-            ],
-        ],
-        [
-            &[
-                0xAF, 0x1A, 0x4C, 0x8E, 0x18, 0xF0, 0xF1, 0xC5, 0xAF, 0x69, 0xC0, 0x22, 0x6A, 0x8C,
-                0x40, 0x71, 0x1C, 0xA4, 0x26, 0x66, 0xD5, 0x4A, 0x49, 0x55, 0x55, 0x7D, 0x34, 0x71,
-                0xD0, 0x1F, 0x82, 0x55,
-            ],
-            &[
-                0xED, 0x9D, 0x2C, 0xB9, 0xDD, 0xFD, 0x53, 0x74, 0xBE, 0xE5, 0x62, 0x74, 0x40, 0x83,
-                0x26, 0xFC, 0xB4, 0x70, 0x02, 0x7F, 0x24, 0xA2, 0x3B, 0x69, 0x1E, 0xC8, 0xD4, 0x5E,
-                0x64, 0x2D, 0xF7, 0x28,
-            ],
-            &[
-                0x18, 0x6E, 0xCF, 0xFD, 0x9E, 0x6C, 0xEC, 0x2F, 0xD8, 0x95, 0xE6, 0xAD, 0x02, 0x9B,
-                0x3D, 0x10, 0x65, 0xC4, 0x3A, 0x86, 0x3F, 0x7D, 0xFF, 0x9F, 0x39, 0x76, 0x6C, 0x58,
-                0x52, 0x49, 0xEE, 0x57,
-            ],
-            &[
-                0x2B, 0xB9, 0xBE, 0x2D, 0xB2, 0xAB, 0x9B, 0x87, 0x3E, 0xA1, 0xC9, 0x53, 0x00, 0x56,
-                0x75, 0xE5, 0x9F, 0x7F, 0xC0, 0x67, 0xAA, 0xEE, 0xDA, 0x09, 0xEB, 0x15, 0x64, 0x82,
-                0x96, 0x55, 0x9B, 0x6B,
-            ],
-        ],
-        [
-            &[
-                0xF5, 0x1A, 0x76, 0xEC, 0xAF, 0x9E, 0x75, 0x75, 0xAA, 0x07, 0xC8, 0x96, 0xF4, 0xEF,
-                0xFC, 0x9C, 0x66, 0xE4, 0x1F, 0xE3, 0x7E, 0xDE, 0x8C, 0x32, 0xEB, 0xDA, 0x8A, 0xBA,
-                0x58, 0x6B, 0x9E, 0x39,
-            ],
-            &[
-                0x22, 0x98, 0xFE, 0x99, 0x30, 0x4F, 0x97, 0xBB, 0x6F, 0x5F, 0xDB, 0x5D, 0x45, 0xBA,
-                0x8D, 0xC2, 0x42, 0xEC, 0x81, 0xD1, 0xA4, 0xEF, 0x74, 0x19, 0x57, 0x0B, 0xCB, 0xB5,
-                0x27, 0x01, 0x1D, 0x36,
-            ],
-            &[
-                0x06, 0x53, 0xD2, 0x7B, 0x4D, 0x88, 0x33, 0x00, 0x12, 0xFA, 0x01, 0x26, 0xA0, 0x76,
-                0x1E, 0x6A, 0x59, 0x44, 0xAA, 0x02, 0x11, 0x85, 0xD1, 0x1B, 0x51, 0x43, 0x11, 0xC5,
-                0xD2, 0xFB, 0x08, 0x4F,
-            ],
-            &[
-                0xBE, 0x00, 0x98, 0x08, 0x6A, 0x3E, 0x8C, 0x0B, 0x65, 0x6A, 0x51, 0xFD, 0x98, 0xEC,
-                0xE5, 0x97, 0xB8, 0xB3, 0x32, 0xF0, 0x52, 0xB0, 0x94, 0xFD, 0xCD, 0xF0, 0x9A, 0x1B,
-                0x96, 0xF6, 0xAB, 0x69,
-            ],
-        ],
-        [
-            &[
-                0xDA, 0xF8, 0x16, 0x0C, 0x55, 0x73, 0xFE, 0xB5, 0x87, 0x3C, 0x25, 0x38, 0xC7, 0xD6,
-                0x99, 0x0D, 0x58, 0x8D, 0x89, 0xF9, 0x4C, 0xAC, 0xDB, 0xE9, 0x5B, 0x60, 0x0C, 0x93,
-                0x31, 0x75, 0xB8, 0x58,
-            ],
-            &[
-                0x11, 0xAA, 0x44, 0x8C, 0x28, 0x01, 0x6D, 0xF5, 0xAB, 0x71, 0x35, 0x30, 0x8D, 0x4A,
-                0x66, 0xB1, 0x2C, 0x60, 0x30, 0xC3, 0xFD, 0x3C, 0x2C, 0xB2, 0x7A, 0x61, 0x2E, 0xD5,
-                0x70, 0x12, 0xBF, 0x32,
-            ],
-            &[
-                0x19, 0x02, 0x68, 0xE3, 0x65, 0x99, 0x77, 0xB5, 0xD5, 0x81, 0xC7, 0x95, 0x81, 0xBB,
-                0x37, 0xD7, 0x9A, 0xFD, 0xFF, 0x6E, 0xA3, 0x0F, 0x35, 0x67, 0x22, 0xE0, 0x74, 0x57,
-                0x03, 0x5D, 0x11, 0x42,
-            ],
-            &[
-                0x72, 0x3F, 0x90, 0x21, 0xCF, 0x65, 0xF2, 0x19, 0xA1, 0xDA, 0x4C, 0xE3, 0xFD, 0xDA,
-                0xAF, 0x2F, 0x62, 0xA5, 0xDB, 0xE5, 0x1D, 0x08, 0x17, 0x7D, 0xAF, 0x10, 0xC5, 0x92,
-                0x00, 0xED, 0x88, 0x23,
-            ],
-        ],
-        [
-            &[
-                0xD3, 0xCA, 0xC8, 0x8C, 0xDF, 0xCB, 0xEE, 0x78, 0xAA, 0xC2, 0x32, 0x87, 0xEE, 0xE7,
-                0x7E, 0x60, 0x72, 0xE4, 0x80, 0x19, 0x41, 0x0D, 0x3F, 0x0D, 0xEF, 0xC6, 0x84, 0xD7,
-                0xEF, 0xFB, 0x04, 0x36,
-            ],
-            &[
-                0xCB, 0x0D, 0x9C, 0x39, 0xD5, 0x9B, 0x4B, 0x35, 0x83, 0x64, 0x12, 0xCA, 0xE0, 0xB4,
-                0xA0, 0x40, 0x9C, 0xDF, 0xD0, 0x41, 0x22, 0x63, 0x52, 0x83, 0x5F, 0xCC, 0x81, 0x57,
-                0xC3, 0xF1, 0xDC, 0x64,
-            ],
-            &[
-                0x10, 0x4B, 0xD3, 0x9C, 0x84, 0x4D, 0xE2, 0xDA, 0x90, 0xFA, 0x26, 0xAA, 0xC7, 0x79,
-                0x78, 0x16, 0x18, 0x78, 0x3A, 0x76, 0x10, 0xDF, 0xEB, 0x86, 0x54, 0x0E, 0x2C, 0x9F,
-                0x12, 0xED, 0xE2, 0x66,
-            ],
-            &[
-                0x81, 0xFA, 0x28, 0xA1, 0x08, 0x2B, 0x72, 0xB4, 0x45, 0xC7, 0x3B, 0xE6, 0x83, 0x05,
-                0xE5, 0xFA, 0x47, 0xB5, 0xDA, 0x18, 0x4B, 0xB1, 0xFB, 0x8F, 0x4A, 0xF3, 0xF6, 0x39,
-                0xEB, 0x5A, 0xEB, 0x61,
-            ],
-        ],
-        [
-            &[
-                0xC1, 0xDA, 0x80, 0xCC, 0x9E, 0x99, 0xB8, 0x78, 0xBB, 0xF5, 0x36, 0xAD, 0x2F, 0x50,
-                0x56, 0xE9, 0x9C, 0x7E, 0x05, 0xD0, 0xCD, 0x47, 0xB0, 0x6D, 0x08, 0xA5, 0xF0, 0x47,
-                0x15, 0x44, 0x9D, 0x35,
-            ],
-            &[
-                0x07, 0x28, 0x39, 0x40, 0x27, 0x9E, 0xDC, 0x0D, 0x12, 0x1A, 0xA4, 0xD3, 0x47, 0xA7,
-                0x46, 0x7E, 0xB4, 0xB8, 0x51, 0x2B, 0x2D, 0x9E, 0x71, 0xA8, 0x62, 0x7E, 0xC9, 0xAD,
-                0x51, 0x48, 0x4C, 0x38,
-            ],
-            &[
-                0xF7, 0x08, 0xE3, 0xB1, 0x30, 0x3A, 0x52, 0xC5, 0xEB, 0xCB, 0x07, 0xCF, 0x5C, 0xD7,
-                0x85, 0x98, 0xA4, 0xE6, 0x75, 0x91, 0x48, 0x81, 0x3E, 0x0E, 0x22, 0xBD, 0xA4, 0x2E,
-                0x4A, 0x92, 0xD3, 0x6A,
-            ],
-            &[
-                0x72, 0xD3, 0x60, 0xF2, 0x88, 0xED, 0x8B, 0x33, 0x62, 0xD6, 0xCF, 0x90, 0x7C, 0xEE,
-                0xB7, 0x77, 0xA6, 0x60, 0xA8, 0x05, 0x53, 0x7D, 0x6A, 0xB6, 0xFD, 0x8B, 0x32, 0xE4,
-                0xDE, 0x34, 0x9D, 0x3C,
-            ],
-        ],
-        [
-            &[
-                0x2C, 0x56, 0x59, 0x99, 0x4C, 0x44, 0xB5, 0x74, 0x51, 0xE8, 0x3F, 0x1B, 0x85, 0x2E,
-                0x18, 0xA1, 0x66, 0x2F, 0x76, 0xDB, 0xAF, 0x0E, 0xC0, 0x6B, 0xB2, 0x75, 0xC9, 0x63,
-                0x61, 0xC2, 0x70, 0x00,
-            ],
-            &[
-                0x1F, 0x75, 0x98, 0x8A, 0x1A, 0xA2, 0x01, 0x0A, 0xF2, 0x9F, 0x25, 0x7E, 0xA7, 0x31,
-                0xBC, 0xC1, 0x2F, 0x46, 0xDA, 0x3A, 0xA7, 0xBB, 0xE7, 0x77, 0xA5, 0xFD, 0xAF, 0x2B,
-                0x07, 0x7B, 0xF5, 0x0B,
-            ],
-            &[
-                0xC4, 0xD2, 0x7D, 0xA2, 0x33, 0x98, 0xC3, 0x31, 0xDE, 0x61, 0xBF, 0x0B, 0x84, 0x2F,
-                0x50, 0xA8, 0xB6, 0x57, 0x77, 0x31, 0x33, 0x9E, 0xD7, 0x43, 0xE9, 0xBC, 0xB9, 0x0D,
-                0x1C, 0x78, 0xB3, 0x1D,
-            ],
-            &[
-                0x92, 0x74, 0x90, 0x10, 0x72, 0x8F, 0xB5, 0x0B, 0xD5, 0x15, 0xD8, 0xC6, 0x2F, 0x5B,
-                0x74, 0x32, 0xAD, 0x44, 0x8D, 0xD6, 0x61, 0x6B, 0x49, 0xC8, 0xCA, 0x72, 0x2D, 0x2C,
-                0x77, 0x2D, 0xCF, 0x55,
-            ],
-        ],
-        [
-            &[
-                0x9B, 0x26, 0x16, 0x16, 0xA9, 0xAE, 0x59, 0x59, 0xCE, 0xCD, 0xA1, 0x85, 0x74, 0xBD,
-                0x70, 0xC8, 0xCE, 0x1A, 0x5E, 0xE9, 0x9E, 0x39, 0x55, 0xF5, 0x5A, 0x64, 0x82, 0x9D,
-                0xC2, 0x35, 0xE9, 0x27,
-            ],
-            &[
-                0x3C, 0xDE, 0x05, 0x8F, 0x14, 0xBE, 0x60, 0x83, 0x30, 0x2E, 0xB7, 0xEB, 0x08, 0x08,
-                0xB6, 0x6D, 0x70, 0xDF, 0x83, 0xB8, 0xF2, 0xC6, 0x0F, 0x30, 0x49, 0x8D, 0xFA, 0x4A,
-                0x1B, 0xB2, 0x26, 0x00,
-            ],
-            &[
-                0x52, 0x85, 0x20, 0x32, 0xC0, 0xDE, 0xE1, 0x3A, 0xE7, 0x09, 0xA4, 0xCC, 0x15, 0x9F,
-                0x25, 0xEB, 0x94, 0x41, 0xE0, 0xA0, 0xE6, 0x5E, 0x9C, 0x53, 0x7C, 0xB3, 0x7D, 0xC2,
-                0xE7, 0xDB, 0x62, 0x06,
-            ],
-            &[
-                0x44, 0x7F, 0x49, 0x61, 0xED, 0x41, 0x5B, 0x4D, 0x42, 0xEB, 0xD3, 0xB4, 0xE5, 0x01,
-                0x86, 0xA7, 0xB3, 0xA1, 0x65, 0x27, 0xB0, 0xB3, 0x5C, 0x28, 0xF4, 0x43, 0x0F, 0x08,
-                0x7B, 0xF3, 0xD0, 0x47,
-            ],
-        ],
-    ];
-}
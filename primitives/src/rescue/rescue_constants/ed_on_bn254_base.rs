@@ -1,1308 +0,0 @@
-// Copyright (c) 2022 Espresso Systems (espressosys.com)
-// This file is part of the Jellyfish library.
-
-// You should have received a copy of the MIT License
-// along with the Jellyfish library. If not, see <https://mit-license.org/>.
-
-use crate::rescue::{RescueParameter, ROUNDS, STATE_SIZE};
-use ark_ed_on_bn254::Fq;
-
-// the constants in this file are generated with
-// https://github.com/EspressoSystems/Marvellous/blob/fcd4c41672f485ac2f62526bc87a16789d4d0459/rescue254.sage
-
-// q254 = 21888242871839275222246405745257275088548364400416034343698204186575808495617
-// rescue254 = Rescue(128, q254, 4, 5)
-
-impl RescueParameter for Fq {
-    // rescue254.alpha
-    const A: u64 = 5;
-    // 1/5 % (q254-1)
-    // A_INV = 0x26b6a528b427b35493736af8679aad17535cb9d394945a0dcfe7f7a98ccccccd
-    //       = 17510594297471420177797124596205820070838691520332827474958563349260646796493
-    const A_INV: &'static [u64] = &[
-        0xcfe7f7a98ccccccd,
-        0x535cb9d394945a0d,
-        0x93736af8679aad17,
-        0x26b6a528b427b354,
-    ];
-
-    // The following constants are from the output of
-    // rescue254.MDS
-    // [21888242871839275222246405745257275088548364400416034343698204186575808479992
-    // 21888242871839275222246405745257275088548364400416034343698204186575806058117
-    // 21888242871839275222246405745257275088548364400416034343698204186575491214367
-    // 21888242871839275222246405745257275088548364400416034343698204186535831058117]
-    // [
-    // 19500
-    // 3026375
-    // 393529500
-    // 49574560750]
-    // [21888242871839275222246405745257275088548364400416034343698204186575808491587
-    // 21888242871839275222246405745257275088548364400416034343698204186575807886437
-    // 21888242871839275222246405745257275088548364400416034343698204186575729688812
-    // 21888242871839275222246405745257275088548364400416034343698204186565891044437]
-    // [
-    // 156
-    // 20306
-    // 2558556
-    // 320327931]
-    // MDS matrix in little-endian 32 bytes elements
-    const MDS_LE: [[&'static [u8]; STATE_SIZE]; STATE_SIZE] = [
-        [
-            &[
-                0xF8, 0xC2, 0xFF, 0xEF, 0x93, 0xF5, 0xE1, 0x43, 0x91, 0x70, 0xB9, 0x79, 0x48, 0xE8,
-                0x33, 0x28, 0x5D, 0x58, 0x81, 0x81, 0xB6, 0x45, 0x50, 0xB8, 0x29, 0xA0, 0x31, 0xE1,
-                0x72, 0x4E, 0x64, 0x30,
-            ],
-            &[
-                0x85, 0xCE, 0xDA, 0xEF, 0x93, 0xF5, 0xE1, 0x43, 0x91, 0x70, 0xB9, 0x79, 0x48, 0xE8,
-                0x33, 0x28, 0x5D, 0x58, 0x81, 0x81, 0xB6, 0x45, 0x50, 0xB8, 0x29, 0xA0, 0x31, 0xE1,
-                0x72, 0x4E, 0x64, 0x30,
-            ],
-            &[
-                0x1F, 0xAC, 0x16, 0xDD, 0x93, 0xF5, 0xE1, 0x43, 0x91, 0x70, 0xB9, 0x79, 0x48, 0xE8,
-                0x33, 0x28, 0x5D, 0x58, 0x81, 0x81, 0xB6, 0x45, 0x50, 0xB8, 0x29, 0xA0, 0x31, 0xE1,
-                0x72, 0x4E, 0x64, 0x30,
-            ],
-            &[
-                0xC5, 0xB6, 0x28, 0xA1, 0x8A, 0xF5, 0xE1, 0x43, 0x91, 0x70, 0xB9, 0x79, 0x48, 0xE8,
-                0x33, 0x28, 0x5D, 0x58, 0x81, 0x81, 0xB6, 0x45, 0x50, 0xB8, 0x29, 0xA0, 0x31, 0xE1,
-                0x72, 0x4E, 0x64, 0x30,
-            ],
-        ],
-        [
-            &[
-                0x2C, 0x4C, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-                0x00, 0x00, 0x00, 0x00,
-            ],
-            &[
-                0xC7, 0x2D, 0x2E, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-                0x00, 0x00, 0x00, 0x00,
-            ],
-            &[
-                0x9C, 0xC8, 0x74, 0x17, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-                0x00, 0x00, 0x00, 0x00,
-            ],
-            &[
-                0xEE, 0xC3, 0xDF, 0x8A, 0x0B, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-                0x00, 0x00, 0x00, 0x00,
-            ],
-        ],
-        [
-            &[
-                0x43, 0xF0, 0xFF, 0xEF, 0x93, 0xF5, 0xE1, 0x43, 0x91, 0x70, 0xB9, 0x79, 0x48, 0xE8,
-                0x33, 0x28, 0x5D, 0x58, 0x81, 0x81, 0xB6, 0x45, 0x50, 0xB8, 0x29, 0xA0, 0x31, 0xE1,
-                0x72, 0x4E, 0x64, 0x30,
-            ],
-            &[
-                0x65, 0xB4, 0xF6, 0xEF, 0x93, 0xF5, 0xE1, 0x43, 0x91, 0x70, 0xB9, 0x79, 0x48, 0xE8,
-                0x33, 0x28, 0x5D, 0x58, 0x81, 0x81, 0xB6, 0x45, 0x50, 0xB8, 0x29, 0xA0, 0x31, 0xE1,
-                0x72, 0x4E, 0x64, 0x30,
-            ],
-            &[
-                0xEC, 0x80, 0x4D, 0xEB, 0x93, 0xF5, 0xE1, 0x43, 0x91, 0x70, 0xB9, 0x79, 0x48, 0xE8,
-                0x33, 0x28, 0x5D, 0x58, 0x81, 0x81, 0xB6, 0x45, 0x50, 0xB8, 0x29, 0xA0, 0x31, 0xE1,
-                0x72, 0x4E, 0x64, 0x30,
-            ],
-            &[
-                0x55, 0xB4, 0xDF, 0xA0, 0x91, 0xF5, 0xE1, 0x43, 0x91, 0x70, 0xB9, 0x79, 0x48, 0xE8,
-                0x33, 0x28, 0x5D, 0x58, 0x81, 0x81, 0xB6, 0x45, 0x50, 0xB8, 0x29, 0xA0, 0x31, 0xE1,
-                0x72, 0x4E, 0x64, 0x30,
-            ],
-        ],
-        [
-            &[
-                0x9C, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-                0x00, 0x00, 0x00, 0x00,
-            ],
-            &[
-                0x52, 0x4F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-                0x00, 0x00, 0x00, 0x00,
-            ],
-            &[
-                0x5C, 0x0A, 0x27, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-                0x00, 0x00, 0x00, 0x00,
-            ],
-            &[
-                0xFB, 0xD0, 0x17, 0x13, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-                0x00, 0x00, 0x00, 0x00,
-            ],
-        ],
-    ];
-    // The following constants are from the output of
-    // rescue254.initial_constant
-    // [14613516837064033601098425266946467918409544647446217386229959902054563533267]
-    // [  376600575581954944138907282479272751264978206975465380433764825531344567663]
-    // [ 7549886658634274343394883631367643327196152481472281919735617268044202589860]
-    // [ 3682071510138521345600424597536598375718773365536872232193107639375194756918]
-    //
-    const INIT_VEC_LE: [&'static [u8]; STATE_SIZE] = [
-        &[
-            0xD3, 0x51, 0x21, 0x08, 0x45, 0x10, 0x35, 0x31, 0x97, 0xEB, 0x0C, 0x8A, 0xF0, 0x5A,
-            0x8A, 0x31, 0xD6, 0x86, 0xD1, 0xE5, 0xA3, 0xAF, 0xEC, 0xF0, 0xF9, 0x9A, 0x24, 0x5A,
-            0x19, 0xF5, 0x4E, 0x20,
-        ],
-        &[
-            0x6F, 0x3D, 0x95, 0xDD, 0xA5, 0x78, 0x32, 0x5C, 0x16, 0xAA, 0x46, 0x2D, 0xF2, 0xCF,
-            0x39, 0xBE, 0x97, 0x9E, 0x1C, 0xBF, 0xFF, 0xA0, 0xB6, 0x48, 0x86, 0xD4, 0x87, 0xB6,
-            0xFB, 0x25, 0xD5, 0x00,
-        ],
-        &[
-            0xA4, 0x3E, 0x61, 0x8B, 0x17, 0x1F, 0xDA, 0x72, 0x5F, 0x18, 0x88, 0xEF, 0x5E, 0xC0,
-            0xA6, 0x64, 0x83, 0x33, 0xC8, 0x1B, 0x5D, 0xA5, 0x6B, 0x4E, 0x8C, 0xAD, 0xDE, 0x4F,
-            0x9C, 0x15, 0xB1, 0x10,
-        ],
-        &[
-            0x36, 0x17, 0xB8, 0x5D, 0xB5, 0xB3, 0x0B, 0x26, 0xEE, 0x04, 0xFB, 0x12, 0xC4, 0x16,
-            0x5E, 0x47, 0x0E, 0x6B, 0xC5, 0xDA, 0x78, 0x52, 0x79, 0x16, 0x68, 0x9E, 0x40, 0xED,
-            0x86, 0xFA, 0x23, 0x08,
-        ],
-    ];
-
-    // The following constants are from the output of
-    // rescue254.constants_matrix
-    // a.k.a., KEY_SCHED_MATRIX
-    // Keep this for reference
-    // [ 7897853564276958334214468603203897520154891489600286770348232887190718230697
-    // 11310972723508634666485784440114195323987623373489240386156363657972592637889
-    // 16383651337644090722313274572289379168218245780523520967193539841029993172551
-    // 19306563486653957297319716776525895326416632603921620229753286581552964639495]
-    // [13972664482939365210126934094159234477516423614171933027224960720992131660723
-    // 3714466336318438487208859168496175617840683365103447095286552900687717723809
-    // 1596938859090899602275687562366103397961147554801212225870985431436994895131
-    // 1804362872899866995550019591021113047181201776456876099010313193931142028794]
-    // [11997476979649706166628591475876380529791396928089723905860179374857864726071
-    //  3358221985266097444445103450467306798663635253390735595238412840047057109206
-    //  20936039221738076169991664483088569164149507060948581631797398419708108094482
-    //  19667773949019642472493030024822902610198030586241139616586889558416819285262]
-    // [13863553253145612564629096839163156810802216429640669856987145967323207410509
-    // 19384255837703781581818619941103992392218719827512626671850429446834300524280
-    // 21184773793927862104225525427705251552748684553382600329427001063711335358489
-    // 17250450671778424279069213596445482771974600736740494308485859852865380915697]
-    //
-
-    // The following constants are from the output of
-    // rescue254.constants_constant
-    // a.k.a., KEY_SCHED_VEC
-    // [14910919218145812220780527690731032179494541897268776131239962376171657535230]
-    // [10257734867868901489281448873462275888117956878438433971429895315446063901086]
-    // [  400456570908486696849138298375881100690963025475664563258385409447538235081]
-    // [21663317380571791832909997299481896459241929404413878318271683915422925222920]
-    //
-
-    // The key injection constants are precomputed computed as
-    // 1. KEY_INJECTION[-1] = INIT_VECTOR
-    // 2. for i in [0 .. 2 * ROUNDS]
-    // 3. KEY_INJECTION[i] = KEY_SCHED_MATRIX * KEY_INJECTION[ i - 1] +
-    // KEY_SCHED_VEC
-    // where the INIT_VECTOR, KEY_SCHED_MATRIX and KEY_INJECTION are defined above
-    // Those constants can be obtained via
-    //   key_injection(init_vec_254, key_sched_matrix_254, key_sched_vec_254, q254)
-    // from rescue254.sage
-    const KEY_INJECTION_LE: [[&'static [u8]; 4]; 2 * ROUNDS] = [
-        [
-            &[
-                0x93, 0x41, 0x96, 0xAB, 0xAA, 0x69, 0xF9, 0xD1, 0x86, 0xFA, 0x00, 0xB7, 0x7E, 0xFA,
-                0xA8, 0x24, 0x13, 0x4A, 0xD9, 0x99, 0x77, 0x9C, 0x71, 0xB8, 0x92, 0x38, 0x74, 0x02,
-                0x85, 0x33, 0xD6, 0x1D,
-            ],
-            &[
-                0xB4, 0x14, 0x4B, 0x66, 0x1B, 0x79, 0xED, 0xD1, 0x21, 0x14, 0x1F, 0xC1, 0x5F, 0x06,
-                0x3E, 0x36, 0xD6, 0x6E, 0x36, 0x0A, 0x9E, 0x18, 0xC1, 0xBA, 0xAF, 0xC0, 0x1F, 0x3D,
-                0x1A, 0x09, 0x35, 0x25,
-            ],
-            &[
-                0x8D, 0xBE, 0x55, 0x50, 0xDA, 0x57, 0xA2, 0xD3, 0xA5, 0x54, 0xD1, 0xB8, 0x28, 0xE3,
-                0x8C, 0x57, 0x10, 0xBB, 0x6A, 0x01, 0x9C, 0x19, 0x67, 0xAB, 0xA4, 0x23, 0xDF, 0x2A,
-                0x2E, 0x3F, 0x6B, 0x0B,
-            ],
-            &[
-                0x82, 0xE6, 0xF1, 0xD3, 0xA4, 0x99, 0x7C, 0x99, 0x94, 0xC1, 0xB8, 0x3D, 0x48, 0x3E,
-                0x33, 0x1B, 0x75, 0x5C, 0x2B, 0xF6, 0x93, 0xFC, 0x1D, 0xC5, 0x0F, 0x33, 0xA3, 0x7B,
-                0x4D, 0xCB, 0x63, 0x27,
-            ],
-        ],
-        [
-            &[
-                0x29, 0x35, 0xD8, 0x6F, 0xF5, 0xAB, 0xD5, 0x97, 0x5F, 0x83, 0xCA, 0xAB, 0x19, 0x66,
-                0xA0, 0xF6, 0x4B, 0x27, 0x45, 0xA1, 0xF7, 0xF4, 0x2F, 0x79, 0x75, 0xB3, 0x29, 0xE9,
-                0x8E, 0x58, 0x95, 0x2A,
-            ],
-            &[
-                0xEC, 0xC4, 0x16, 0x3B, 0x01, 0x33, 0x11, 0x78, 0xA0, 0x5F, 0xD4, 0xB6, 0x45, 0xCB,
-                0xEE, 0x1F, 0x2E, 0xB8, 0xA5, 0x14, 0xCC, 0x4C, 0xA4, 0x4D, 0xCE, 0xEA, 0xD1, 0x8D,
-                0xB6, 0xEB, 0xF4, 0x13,
-            ],
-            &[
-                0xFB, 0x07, 0x96, 0x1C, 0xBC, 0x05, 0xC6, 0x5D, 0x0A, 0x51, 0x21, 0x20, 0xA9, 0x36,
-                0xBF, 0xB4, 0x5A, 0xF9, 0x27, 0xEB, 0x12, 0x66, 0x83, 0x88, 0xD7, 0xC1, 0x44, 0x72,
-                0xC8, 0x3A, 0xA2, 0x01,
-            ],
-            &[
-                0x0C, 0xF4, 0x57, 0xC8, 0x62, 0x7C, 0x9F, 0xA5, 0x23, 0x8C, 0x7D, 0x45, 0x29, 0x71,
-                0x91, 0x4C, 0xD7, 0x60, 0xEE, 0xE2, 0xF4, 0xF9, 0xFB, 0x43, 0x4D, 0x6C, 0xEF, 0xDC,
-                0x14, 0xDA, 0xE5, 0x2F,
-            ],
-        ],
-        [
-            &[
-                0xFD, 0x33, 0xE8, 0xC5, 0x8B, 0xCE, 0xB8, 0x4B, 0x21, 0xEB, 0xA9, 0xF2, 0x36, 0xFD,
-                0x92, 0x24, 0xA6, 0x9D, 0x77, 0xD8, 0x8D, 0x50, 0xC4, 0xCB, 0xAE, 0xB7, 0x77, 0x17,
-                0x2F, 0x0B, 0xC0, 0x1B,
-            ],
-            &[
-                0x1A, 0x9D, 0x87, 0xA1, 0xDB, 0x88, 0x02, 0x92, 0xF4, 0x3B, 0x3B, 0xED, 0xDB, 0x9F,
-                0xB2, 0x80, 0x03, 0x61, 0x59, 0x96, 0xD4, 0x37, 0x84, 0x1C, 0x44, 0xB3, 0xD5, 0x94,
-                0xE5, 0xB9, 0xE5, 0x24,
-            ],
-            &[
-                0x97, 0xF4, 0xD4, 0x3C, 0x37, 0x48, 0x7E, 0x08, 0x50, 0xE3, 0xE6, 0xAA, 0x58, 0x35,
-                0xB1, 0x1B, 0xF5, 0xC6, 0x2E, 0x99, 0x0A, 0x01, 0xB7, 0x8C, 0x24, 0x12, 0x26, 0x4C,
-                0x96, 0x79, 0x8B, 0x1F,
-            ],
-            &[
-                0xC0, 0xCE, 0xAD, 0x0C, 0x5A, 0x14, 0x58, 0x8D, 0x74, 0x39, 0xC7, 0x1F, 0xE1, 0x79,
-                0xE1, 0x75, 0xD8, 0x6B, 0x03, 0x71, 0x7D, 0xE0, 0x12, 0xB4, 0xB6, 0x4D, 0x45, 0x24,
-                0x9E, 0x99, 0xA1, 0x02,
-            ],
-        ],
-        [
-            &[
-                0x6D, 0xFC, 0x45, 0x92, 0x88, 0x47, 0xFD, 0x32, 0xD9, 0x73, 0x42, 0xAA, 0x41, 0x1E,
-                0x52, 0x49, 0x01, 0x36, 0x81, 0x35, 0x0A, 0xD5, 0x73, 0xCC, 0xFF, 0xCC, 0x28, 0x5B,
-                0xE4, 0x48, 0x60, 0x05,
-            ],
-            &[
-                0x1A, 0x5C, 0x1B, 0xBF, 0xC5, 0xA6, 0xC5, 0x68, 0x7C, 0xC3, 0xBF, 0x52, 0xC3, 0x9F,
-                0x6D, 0xDA, 0x05, 0x99, 0x44, 0xDB, 0x20, 0x24, 0xA7, 0x22, 0x76, 0x14, 0x69, 0x79,
-                0xCF, 0x4A, 0x17, 0x05,
-            ],
-            &[
-                0xD8, 0x55, 0x27, 0xA1, 0x8F, 0x78, 0xD0, 0x84, 0x7E, 0x46, 0xC8, 0x4B, 0xD0, 0x20,
-                0xC2, 0xEE, 0x8A, 0x85, 0x72, 0x3D, 0xBF, 0x87, 0x50, 0x01, 0xBE, 0x03, 0xA0, 0x0F,
-                0xCE, 0x77, 0x52, 0x1E,
-            ],
-            &[
-                0x2E, 0xD7, 0x8C, 0xB9, 0x2F, 0xEF, 0xE4, 0x30, 0xD0, 0xB8, 0x2A, 0xEA, 0xF4, 0x03,
-                0x52, 0xBC, 0xFE, 0x90, 0x6D, 0x7C, 0x7B, 0xFC, 0x62, 0x37, 0xAF, 0x1B, 0x8F, 0x2E,
-                0x7F, 0x03, 0xC0, 0x1E,
-            ],
-        ],
-        [
-            &[
-                0x81, 0x44, 0x12, 0xFC, 0x66, 0x84, 0x90, 0x4D, 0x72, 0x68, 0x53, 0x1D, 0x50, 0xBA,
-                0x36, 0x34, 0x6C, 0xB3, 0x6F, 0x83, 0x72, 0x02, 0x4F, 0x63, 0x32, 0x57, 0x59, 0xB1,
-                0x17, 0xE7, 0xA7, 0x2B,
-            ],
-            &[
-                0x79, 0xB5, 0xED, 0xD5, 0x77, 0xCC, 0x7B, 0x60, 0xB9, 0x9D, 0x45, 0x2D, 0x0F, 0x39,
-                0xC8, 0xA2, 0x1F, 0x19, 0x2B, 0x3A, 0x9E, 0x25, 0xBE, 0x0D, 0xE0, 0x19, 0xDD, 0x48,
-                0xDB, 0x0C, 0xB4, 0x25,
-            ],
-            &[
-                0xBD, 0xAE, 0xC0, 0x53, 0x11, 0x5D, 0xDE, 0xDB, 0xCE, 0x44, 0x16, 0xBC, 0xC5, 0xFB,
-                0xDD, 0xDE, 0xC6, 0xA8, 0x47, 0x49, 0xD2, 0xA8, 0x60, 0x1C, 0xC0, 0x05, 0x53, 0x4D,
-                0x76, 0x24, 0xF0, 0x16,
-            ],
-            &[
-                0xEC, 0x65, 0x64, 0x35, 0xCF, 0xB5, 0x10, 0x87, 0x29, 0xE5, 0x42, 0xF3, 0xC5, 0xF9,
-                0xAB, 0xF3, 0xAA, 0x63, 0x46, 0xF0, 0xD2, 0x82, 0xB5, 0x4B, 0x77, 0x9A, 0x34, 0x61,
-                0x7B, 0x06, 0xC7, 0x18,
-            ],
-        ],
-        [
-            &[
-                0xD4, 0xBA, 0xB8, 0xD5, 0x19, 0xB4, 0xB7, 0x84, 0xFF, 0x9B, 0x41, 0xCE, 0x8A, 0xAE,
-                0x88, 0x71, 0xF9, 0xBE, 0x96, 0x3D, 0xA3, 0x3E, 0x47, 0x4B, 0xF0, 0xBB, 0xF2, 0x4C,
-                0xF1, 0x9B, 0x33, 0x0A,
-            ],
-            &[
-                0x7D, 0x95, 0x46, 0x37, 0x71, 0xC8, 0xED, 0x0D, 0x72, 0xC1, 0xB7, 0x3C, 0x02, 0xB9,
-                0x8F, 0xDA, 0x0D, 0x16, 0x43, 0x0F, 0x1D, 0x90, 0x34, 0xCF, 0x83, 0x84, 0xEB, 0x12,
-                0x76, 0xC2, 0x82, 0x19,
-            ],
-            &[
-                0x7D, 0xEC, 0x30, 0x9B, 0xBA, 0x95, 0xCE, 0x27, 0xA1, 0x03, 0x74, 0xF9, 0x8E, 0xDC,
-                0xB8, 0xEE, 0xA9, 0xDD, 0x2B, 0x85, 0x27, 0xC9, 0x4A, 0x55, 0x29, 0x2E, 0xE8, 0xED,
-                0xA9, 0xE2, 0xA2, 0x1A,
-            ],
-            &[
-                0xD2, 0xC3, 0xA0, 0x4E, 0x69, 0x92, 0x90, 0xA7, 0xB4, 0x05, 0x9A, 0x15, 0x5E, 0xE4,
-                0x03, 0x5F, 0x7F, 0x78, 0xED, 0x61, 0x01, 0xA7, 0x2C, 0x29, 0x12, 0x1E, 0xFA, 0xDB,
-                0x03, 0xC8, 0xA3, 0x09,
-            ],
-        ],
-        [
-            &[
-                0xFA, 0x87, 0x82, 0xF7, 0x60, 0x00, 0x17, 0xD4, 0x2B, 0x02, 0x13, 0xF1, 0xFB, 0x3C,
-                0xED, 0xCB, 0x62, 0xA9, 0x17, 0xC7, 0xEB, 0x17, 0x13, 0xA0, 0x6F, 0xD8, 0x32, 0x95,
-                0x5D, 0xB3, 0x53, 0x09,
-            ],
-            &[
-                0x2E, 0x63, 0x08, 0xE3, 0xC8, 0x10, 0xDD, 0x52, 0xC1, 0x43, 0xAE, 0x7D, 0x84, 0x9A,
-                0xC2, 0x54, 0x8A, 0x4B, 0x39, 0x3B, 0x91, 0x1A, 0xD6, 0x60, 0xD9, 0xEF, 0xD1, 0x18,
-                0x15, 0x1D, 0x45, 0x2A,
-            ],
-            &[
-                0x6B, 0xD7, 0x48, 0x39, 0x4B, 0x8A, 0x58, 0xCC, 0xBE, 0x34, 0xA8, 0x54, 0x42, 0x71,
-                0x3A, 0xBA, 0xB8, 0x13, 0x08, 0x47, 0xA5, 0xB4, 0xAB, 0x71, 0x16, 0xF7, 0x63, 0x00,
-                0x24, 0xDB, 0x72, 0x22,
-            ],
-            &[
-                0xD5, 0x2C, 0x16, 0xA1, 0x3C, 0xF0, 0x0A, 0x15, 0x2A, 0x56, 0xFE, 0x6D, 0x3F, 0x38,
-                0x63, 0x53, 0x35, 0x11, 0x49, 0xDA, 0x46, 0x16, 0xC2, 0xD2, 0xEB, 0xBC, 0x47, 0x1D,
-                0xD6, 0x82, 0x01, 0x25,
-            ],
-        ],
-        [
-            &[
-                0xB3, 0x31, 0x76, 0xB3, 0x91, 0x15, 0x4E, 0x80, 0x6A, 0x6C, 0xF1, 0x30, 0xB5, 0xCC,
-                0xD5, 0xBD, 0xF9, 0x77, 0x61, 0x91, 0x77, 0x1E, 0xD2, 0x5D, 0xC4, 0xFF, 0x83, 0x75,
-                0xE6, 0x28, 0x0E, 0x10,
-            ],
-            &[
-                0x3A, 0x85, 0x2F, 0x7B, 0x07, 0x29, 0x18, 0x87, 0x75, 0x4C, 0x93, 0xA5, 0x4F, 0x7F,
-                0x77, 0xE5, 0x87, 0x2C, 0xB9, 0x92, 0x87, 0xD7, 0x41, 0x5D, 0x85, 0x7A, 0xA1, 0xD3,
-                0x1C, 0xFC, 0x46, 0x2C,
-            ],
-            &[
-                0xE2, 0x1B, 0x9E, 0xC8, 0xDA, 0xCF, 0x4F, 0x07, 0xAB, 0x91, 0x38, 0xD4, 0x26, 0xAE,
-                0xA0, 0xBE, 0xF2, 0x0B, 0xAE, 0x81, 0x95, 0x05, 0xFC, 0xC7, 0x56, 0x3D, 0x50, 0x91,
-                0x88, 0x51, 0xD1, 0x2B,
-            ],
-            &[
-                0xDF, 0x13, 0xCD, 0x35, 0xD4, 0x3C, 0x64, 0x65, 0x76, 0x32, 0x39, 0xEA, 0x7F, 0x3E,
-                0xDE, 0x82, 0x63, 0x99, 0x53, 0x7B, 0xE7, 0xFA, 0xCE, 0x43, 0xB9, 0x9B, 0xAF, 0x12,
-                0xD5, 0x39, 0x6B, 0x16,
-            ],
-        ],
-        [
-            &[
-                0x32, 0x43, 0x73, 0xB8, 0x3C, 0x7E, 0xAF, 0x28, 0x98, 0xC4, 0x88, 0x41, 0xFD, 0xB1,
-                0x5D, 0x82, 0xDD, 0x6B, 0x2D, 0x1F, 0x58, 0x35, 0xA0, 0x81, 0x0A, 0xDF, 0xC9, 0x3E,
-                0xFD, 0xF2, 0x5C, 0x16,
-            ],
-            &[
-                0xB9, 0x4F, 0x09, 0x65, 0x9C, 0xE8, 0x52, 0xBE, 0xFE, 0x78, 0x51, 0x7A, 0xAB, 0xB1,
-                0xEB, 0xB5, 0xA4, 0xCF, 0x6A, 0x5F, 0x46, 0xAD, 0xBC, 0x09, 0x75, 0x30, 0x31, 0x9D,
-                0x72, 0x7D, 0x80, 0x16,
-            ],
-            &[
-                0xB4, 0x8D, 0x1C, 0xF5, 0x50, 0x3E, 0x7E, 0x70, 0x32, 0x17, 0x20, 0x86, 0x50, 0x5A,
-                0x40, 0xE3, 0xBD, 0xA8, 0xBF, 0xC9, 0x9A, 0xA1, 0xF8, 0x42, 0x94, 0xBA, 0x87, 0x60,
-                0xBE, 0xC1, 0x6B, 0x11,
-            ],
-            &[
-                0x5D, 0x9C, 0x66, 0x80, 0xEE, 0x56, 0xE4, 0xA8, 0x89, 0x38, 0xD2, 0x89, 0x7B, 0x9D,
-                0xEB, 0x3E, 0x5B, 0x45, 0xC3, 0x73, 0x5D, 0x68, 0x6B, 0x5E, 0xDB, 0x79, 0x06, 0x9E,
-                0x97, 0x9D, 0x68, 0x1B,
-            ],
-        ],
-        [
-            &[
-                0x4B, 0x21, 0xC3, 0xD8, 0xB6, 0x15, 0x09, 0xDC, 0x63, 0xE9, 0x5F, 0xEB, 0xBA, 0x73,
-                0x85, 0xD8, 0xB4, 0x74, 0x28, 0xF4, 0x08, 0x36, 0x40, 0x3E, 0xCB, 0x54, 0xFA, 0xD8,
-                0x51, 0xA8, 0xCC, 0x0B,
-            ],
-            &[
-                0x2F, 0xD8, 0x1E, 0x95, 0x37, 0xAF, 0x91, 0xF4, 0x95, 0x97, 0x51, 0x9A, 0x36, 0x4A,
-                0xFA, 0xB1, 0x71, 0x59, 0x0A, 0x19, 0x2B, 0x84, 0x42, 0x19, 0x43, 0x17, 0x63, 0xB6,
-                0x58, 0x6B, 0x2A, 0x1E,
-            ],
-            &[
-                0x61, 0x55, 0x0E, 0x72, 0x34, 0xC7, 0xAE, 0x7F, 0x2C, 0xB5, 0x31, 0x59, 0x07, 0x0A,
-                0xE1, 0x63, 0x59, 0xE6, 0xED, 0x12, 0x3A, 0xCF, 0xAC, 0x19, 0xC9, 0x15, 0xF3, 0x94,
-                0xFD, 0xF4, 0x63, 0x13,
-            ],
-            &[
-                0xFE, 0xAF, 0x80, 0xDE, 0x1C, 0x77, 0x04, 0xDF, 0xB8, 0xD4, 0x1D, 0xF1, 0x2C, 0xF3,
-                0xA7, 0xC7, 0x89, 0x71, 0x45, 0xDB, 0x55, 0x42, 0x8D, 0xEB, 0xB1, 0x31, 0xAC, 0x3B,
-                0x9D, 0xBD, 0x20, 0x18,
-            ],
-        ],
-        [
-            &[
-                0x83, 0x8B, 0x72, 0xF2, 0x69, 0x38, 0x39, 0x75, 0x95, 0x23, 0xBD, 0x43, 0x06, 0x9C,
-                0x82, 0x0E, 0xFD, 0xE8, 0x01, 0x66, 0x34, 0x24, 0xC4, 0xD9, 0xA8, 0xEC, 0xCA, 0xC9,
-                0x40, 0xE2, 0x01, 0x08,
-            ],
-            &[
-                0x5C, 0xD8, 0x4A, 0x87, 0x7E, 0x1D, 0xFB, 0x4D, 0x36, 0xD7, 0xD6, 0x77, 0xC5, 0x9D,
-                0xF9, 0x1F, 0xA9, 0x3D, 0x91, 0x11, 0x9B, 0x10, 0x9A, 0x9E, 0x9D, 0xB9, 0x0C, 0x8F,
-                0xA2, 0xBB, 0x22, 0x28,
-            ],
-            &[
-                0x29, 0xD2, 0x4E, 0x6E, 0xDB, 0x3E, 0xB3, 0x20, 0xAF, 0x49, 0xFC, 0x2A, 0xB0, 0x4B,
-                0x37, 0x3C, 0xBB, 0x06, 0xF7, 0x2E, 0x8A, 0x6E, 0x0D, 0x8B, 0x73, 0xF1, 0x85, 0x99,
-                0xE7, 0x79, 0xF9, 0x1D,
-            ],
-            &[
-                0x84, 0xE4, 0x1C, 0x9B, 0x97, 0xDD, 0xA4, 0x9F, 0x6B, 0x3E, 0xD3, 0x06, 0xF9, 0x91,
-                0xAB, 0x1D, 0x27, 0x51, 0x38, 0x0E, 0x6C, 0x80, 0xA5, 0xB9, 0x7A, 0x43, 0x95, 0xF5,
-                0xDC, 0x47, 0xB7, 0x1D,
-            ],
-        ],
-        [
-            &[
-                0x23, 0xA2, 0xB0, 0x1C, 0xD5, 0x50, 0xB3, 0x6D, 0xD9, 0x37, 0x27, 0x21, 0xA6, 0xEE,
-                0x83, 0x95, 0x58, 0xCB, 0xF7, 0x56, 0x77, 0x94, 0x43, 0x5C, 0xA3, 0x1B, 0xCF, 0x61,
-                0xE2, 0xC6, 0xD0, 0x22,
-            ],
-            &[
-                0x3E, 0x6F, 0xF0, 0xD6, 0xA4, 0x9E, 0x1F, 0x08, 0xB9, 0x37, 0xC1, 0x7B, 0xB4, 0x5E,
-                0xBD, 0x14, 0x2B, 0x2E, 0x08, 0x1B, 0x77, 0x1E, 0x78, 0xDC, 0xE6, 0xBF, 0x3E, 0x62,
-                0x05, 0xA0, 0x71, 0x20,
-            ],
-            &[
-                0x2E, 0xD7, 0x0A, 0xAA, 0x90, 0xF3, 0x24, 0xC9, 0xEF, 0xE5, 0x0E, 0x2C, 0xDE, 0x74,
-                0x24, 0x2E, 0x24, 0x6C, 0xBB, 0x28, 0x21, 0xEF, 0xD0, 0xC1, 0xBF, 0xA6, 0x00, 0xE5,
-                0x57, 0x4A, 0xA1, 0x2F,
-            ],
-            &[
-                0x76, 0xA6, 0xD8, 0x43, 0x3C, 0xE4, 0xB5, 0xBE, 0x6B, 0x21, 0x69, 0x13, 0x3A, 0x83,
-                0x22, 0x3C, 0x9B, 0x31, 0x09, 0x21, 0xB2, 0x94, 0x64, 0xA5, 0xBE, 0x26, 0x7D, 0x03,
-                0x36, 0xD1, 0xE0, 0x26,
-            ],
-        ],
-        [
-            &[
-                0x55, 0x10, 0xD5, 0x5A, 0xE9, 0xE0, 0x21, 0xB0, 0x48, 0xD2, 0x29, 0x67, 0x7A, 0xD2,
-                0xC1, 0xF7, 0x47, 0x6C, 0xD4, 0xCA, 0xD6, 0x77, 0x5D, 0x99, 0x19, 0x16, 0x75, 0x58,
-                0x67, 0xEC, 0x8C, 0x20,
-            ],
-            &[
-                0xDC, 0xF8, 0x6D, 0xC1, 0xF0, 0x50, 0x05, 0x48, 0xBF, 0xB9, 0xA2, 0x56, 0xFC, 0xA3,
-                0x3A, 0xE4, 0x0D, 0x1B, 0xB1, 0x8E, 0x04, 0x5B, 0xE2, 0x12, 0x92, 0x64, 0xC9, 0xE0,
-                0xEE, 0x92, 0xF6, 0x0B,
-            ],
-            &[
-                0x2A, 0x95, 0x62, 0x16, 0x65, 0xD7, 0x22, 0x43, 0xB6, 0x1D, 0x51, 0xEE, 0xC8, 0xB0,
-                0x6E, 0xDC, 0x29, 0x31, 0xB9, 0x30, 0x44, 0x03, 0x15, 0xF8, 0x16, 0x45, 0x77, 0x86,
-                0x50, 0x09, 0xFC, 0x0C,
-            ],
-            &[
-                0xA4, 0x8E, 0x72, 0x6F, 0x52, 0xDE, 0xA8, 0x95, 0xCF, 0x55, 0x4E, 0x65, 0x01, 0x75,
-                0xBE, 0x65, 0xE2, 0xAE, 0xF3, 0x73, 0xF2, 0x23, 0x1E, 0x7D, 0xFC, 0x24, 0xB1, 0x9D,
-                0x45, 0x40, 0x4F, 0x29,
-            ],
-        ],
-        [
-            &[
-                0xE6, 0xC4, 0x52, 0xDD, 0x9B, 0x5B, 0x81, 0x0A, 0x11, 0x38, 0x68, 0xC0, 0xE4, 0xF2,
-                0x3E, 0x68, 0x22, 0xD0, 0xEF, 0x31, 0xF2, 0xE2, 0xC5, 0x9D, 0xBF, 0x65, 0x1F, 0x5F,
-                0x06, 0x40, 0xF5, 0x10,
-            ],
-            &[
-                0x41, 0xBF, 0x30, 0x5D, 0x90, 0xEC, 0xCC, 0x24, 0xBE, 0x3E, 0x69, 0x9F, 0xEA, 0xB6,
-                0x01, 0xF0, 0x74, 0x3A, 0xFE, 0xD2, 0x1E, 0x76, 0x19, 0xAE, 0xFE, 0xFE, 0xDB, 0x0C,
-                0x13, 0x1D, 0x21, 0x1D,
-            ],
-            &[
-                0x33, 0x05, 0xAA, 0x96, 0x7B, 0xDB, 0xDC, 0x22, 0xF9, 0x70, 0xCD, 0x12, 0xD5, 0x34,
-                0xFB, 0x1B, 0x35, 0xD5, 0xD0, 0x6A, 0x42, 0xD2, 0x56, 0xE7, 0x36, 0x47, 0xCF, 0x70,
-                0x1F, 0x3D, 0xE8, 0x24,
-            ],
-            &[
-                0x53, 0x4F, 0xDC, 0xA2, 0x12, 0xB6, 0xE2, 0xEE, 0x54, 0x73, 0x05, 0x40, 0x47, 0x4A,
-                0xC1, 0xD8, 0x3A, 0x4E, 0x8E, 0x98, 0x17, 0xFC, 0xD0, 0x3B, 0x43, 0x63, 0x73, 0x1C,
-                0x4D, 0x94, 0xC6, 0x06,
-            ],
-        ],
-        [
-            &[
-                0x44, 0x0B, 0x06, 0x84, 0x30, 0xC1, 0x29, 0x93, 0xEC, 0xB4, 0xF2, 0x7A, 0x63, 0xD5,
-                0xD2, 0xD2, 0x66, 0x64, 0xA8, 0x2A, 0xFE, 0x6F, 0x6F, 0x1C, 0x16, 0x53, 0x64, 0x1D,
-                0x1D, 0xF3, 0x98, 0x2B,
-            ],
-            &[
-                0xB7, 0xEF, 0xAA, 0x6A, 0xF1, 0x0D, 0xBD, 0xBB, 0x3E, 0xBB, 0x78, 0x56, 0x80, 0x59,
-                0x0F, 0x0C, 0xED, 0x40, 0x12, 0x05, 0xB5, 0xD4, 0x94, 0x80, 0xED, 0xB4, 0xF6, 0xD5,
-                0xD7, 0xB2, 0x2D, 0x27,
-            ],
-            &[
-                0x10, 0x07, 0x8F, 0xBD, 0x9C, 0x2B, 0x04, 0xD1, 0xD7, 0xD3, 0x8C, 0xCB, 0x8B, 0x9B,
-                0xB3, 0x27, 0x94, 0xE2, 0x79, 0xCC, 0xC3, 0x8A, 0xD6, 0x99, 0xFA, 0x64, 0x1B, 0xA2,
-                0xBD, 0xA9, 0x88, 0x0F,
-            ],
-            &[
-                0xB0, 0xF2, 0xB0, 0x39, 0xF7, 0x9D, 0x2A, 0xFC, 0x9D, 0xAE, 0x26, 0xFA, 0xEA, 0x44,
-                0x4E, 0x54, 0x1E, 0x6E, 0x96, 0xF0, 0x3F, 0x47, 0x4D, 0x85, 0xF7, 0x27, 0x99, 0x24,
-                0x15, 0x09, 0xD5, 0x1C,
-            ],
-        ],
-        [
-            &[
-                0x33, 0x4E, 0xEF, 0x3A, 0x8B, 0x4E, 0x7E, 0x53, 0x69, 0xE5, 0xB3, 0x02, 0xD5, 0x9A,
-                0xB4, 0xD2, 0x69, 0x40, 0x31, 0xD7, 0x6F, 0xE2, 0xF3, 0x34, 0x60, 0x0B, 0x6D, 0xAF,
-                0x12, 0x6F, 0xD3, 0x0A,
-            ],
-            &[
-                0xFC, 0xBA, 0xE7, 0xB4, 0x87, 0x1B, 0x83, 0xAB, 0xFF, 0x53, 0x3E, 0x61, 0xAF, 0x4A,
-                0x20, 0x08, 0xB2, 0x54, 0xF6, 0x3E, 0xB6, 0x5B, 0xB7, 0x93, 0x30, 0x8A, 0x80, 0x9F,
-                0xFC, 0xB5, 0x19, 0x0E,
-            ],
-            &[
-                0x5B, 0xE4, 0x87, 0x43, 0xEA, 0xF7, 0x9E, 0xAF, 0x9A, 0x84, 0x17, 0x38, 0x2C, 0x1F,
-                0x5B, 0x70, 0x83, 0xC9, 0x72, 0x03, 0xC4, 0x98, 0x93, 0x54, 0xCA, 0x1C, 0xF1, 0x3D,
-                0x4A, 0x65, 0x67, 0x23,
-            ],
-            &[
-                0xA1, 0xD4, 0x0A, 0x2F, 0x6B, 0xD2, 0xC2, 0x69, 0x28, 0x31, 0x77, 0x0A, 0x92, 0x35,
-                0x02, 0x43, 0x14, 0xCA, 0x76, 0x6F, 0x60, 0x8C, 0x74, 0x10, 0x25, 0x1A, 0x37, 0x2C,
-                0x91, 0xC2, 0x26, 0x25,
-            ],
-        ],
-        [
-            &[
-                0xD4, 0x46, 0xB6, 0xD7, 0xC2, 0x2C, 0x44, 0x32, 0x61, 0x4C, 0xE9, 0xFE, 0x1B, 0x48,
-                0xB4, 0x1C, 0x8F, 0x37, 0x00, 0xFA, 0xB6, 0x11, 0xC0, 0xC3, 0xC8, 0xA7, 0xFE, 0x31,
-                0xC3, 0x63, 0x58, 0x28,
-            ],
-            &[
-                0xAF, 0x87, 0xCE, 0xF7, 0x7B, 0xF5, 0x09, 0xC2, 0x00, 0x76, 0x3C, 0x2D, 0x47, 0x6D,
-                0xED, 0xA8, 0x10, 0x1D, 0xBE, 0x58, 0xE2, 0x31, 0x79, 0x2F, 0xA8, 0x50, 0xC7, 0x41,
-                0xAE, 0x3D, 0x66, 0x10,
-            ],
-            &[
-                0xE0, 0x3E, 0x55, 0xB5, 0x4D, 0x32, 0x73, 0xEC, 0xEE, 0x63, 0xF2, 0xF8, 0xC6, 0xE4,
-                0x3B, 0x12, 0x92, 0x2E, 0x33, 0x9B, 0xE9, 0x05, 0xC2, 0x6C, 0x16, 0x31, 0x36, 0x35,
-                0xCF, 0x03, 0xAD, 0x0B,
-            ],
-            &[
-                0xDC, 0xC0, 0xB5, 0x87, 0x97, 0x14, 0xF1, 0xD9, 0x88, 0xF2, 0x5A, 0x60, 0xFF, 0xBF,
-                0x9A, 0xE1, 0x10, 0x58, 0x5D, 0xA9, 0xCE, 0x57, 0x8B, 0x20, 0x6B, 0xDB, 0x85, 0x70,
-                0x49, 0xD4, 0xEE, 0x2C,
-            ],
-        ],
-        [
-            &[
-                0x9D, 0x59, 0xB1, 0xA9, 0x87, 0xC4, 0x5A, 0xC2, 0x39, 0x7B, 0xF2, 0x39, 0xF8, 0x5B,
-                0xF4, 0x61, 0xE6, 0xEF, 0xFB, 0xCC, 0x2F, 0x75, 0xA6, 0x0B, 0xFB, 0xC8, 0x69, 0x90,
-                0xCC, 0x5B, 0xA2, 0x1D,
-            ],
-            &[
-                0x59, 0xAC, 0xC3, 0xAE, 0xD6, 0x34, 0x87, 0x21, 0xB4, 0xC9, 0xF7, 0x33, 0xAD, 0x0A,
-                0x7A, 0xC4, 0x58, 0xE1, 0x11, 0xD6, 0xE6, 0xFA, 0x84, 0x38, 0x9B, 0xA0, 0x91, 0x5D,
-                0x61, 0x3D, 0xAE, 0x13,
-            ],
-            &[
-                0xE1, 0x95, 0x05, 0x67, 0x4C, 0x2F, 0x79, 0xB6, 0x26, 0x7A, 0x5C, 0x9E, 0xD7, 0xDA,
-                0x25, 0x1E, 0x62, 0x71, 0xB6, 0x4A, 0x9D, 0xC5, 0x84, 0x5E, 0x7F, 0x14, 0x71, 0x47,
-                0x58, 0xA8, 0x14, 0x2E,
-            ],
-            &[
-                0xEA, 0xF3, 0x85, 0xFA, 0x34, 0xCE, 0xDA, 0xA4, 0x9F, 0x81, 0x68, 0x0B, 0x45, 0x77,
-                0xCE, 0x98, 0x36, 0x65, 0xB2, 0x78, 0x4A, 0x74, 0x50, 0x18, 0x92, 0x7E, 0xEC, 0xD2,
-                0xE0, 0x37, 0x9E, 0x2F,
-            ],
-        ],
-        [
-            &[
-                0x1E, 0x27, 0x97, 0x55, 0x2F, 0x26, 0xAB, 0xD9, 0x7E, 0x5E, 0x52, 0xE3, 0x0D, 0xEC,
-                0x0F, 0xE9, 0x41, 0x0F, 0x3F, 0x51, 0x6A, 0xC9, 0xC5, 0x95, 0xEC, 0xA8, 0xE4, 0x9A,
-                0x9A, 0x12, 0xE1, 0x1B,
-            ],
-            &[
-                0xE9, 0xF1, 0x78, 0xC4, 0x86, 0x47, 0xA9, 0xA1, 0xDE, 0x7A, 0xC3, 0x56, 0xE1, 0x01,
-                0xF9, 0x5E, 0xDA, 0x6D, 0x67, 0x38, 0xF8, 0xB7, 0x13, 0xCD, 0x8C, 0x64, 0xC2, 0x36,
-                0x26, 0x4C, 0x40, 0x11,
-            ],
-            &[
-                0xB4, 0x0F, 0xE4, 0xC4, 0x8E, 0x2A, 0x1D, 0x40, 0x90, 0x5A, 0x2C, 0xBC, 0x55, 0x0D,
-                0xC8, 0x3C, 0x4E, 0xD7, 0x56, 0x16, 0x2A, 0x3C, 0xA6, 0x96, 0x6D, 0x7E, 0x8D, 0x59,
-                0x50, 0x7C, 0x17, 0x0F,
-            ],
-            &[
-                0x22, 0x3D, 0xF8, 0x57, 0x02, 0x7D, 0xB5, 0xE3, 0x23, 0xF2, 0x6A, 0x64, 0xB7, 0x90,
-                0x25, 0x9D, 0x03, 0x54, 0xE3, 0xB1, 0xE9, 0x98, 0xAA, 0x68, 0x43, 0x51, 0x0E, 0xDD,
-                0x17, 0x24, 0x6E, 0x1E,
-            ],
-        ],
-        [
-            &[
-                0x9F, 0x7B, 0xB5, 0x5A, 0x4B, 0xD5, 0xFA, 0xFF, 0x7F, 0x47, 0xC7, 0xA7, 0x9B, 0x2D,
-                0x73, 0xC3, 0x64, 0x41, 0x63, 0x3E, 0xF2, 0xCC, 0x53, 0x13, 0xD9, 0x34, 0xB4, 0xB1,
-                0x1C, 0xFD, 0xEC, 0x12,
-            ],
-            &[
-                0xB3, 0x72, 0x79, 0xF4, 0x37, 0x75, 0x78, 0xA0, 0xA2, 0x19, 0xF2, 0x44, 0xA1, 0x31,
-                0x23, 0x99, 0x36, 0x8D, 0x9A, 0xD8, 0xF1, 0x96, 0xFE, 0xF9, 0x39, 0x48, 0x93, 0x20,
-                0x23, 0x29, 0xAA, 0x0A,
-            ],
-            &[
-                0xFD, 0x4D, 0x29, 0xDF, 0x88, 0xDC, 0xF8, 0x1C, 0xB6, 0x06, 0xB0, 0xCA, 0xBE, 0x1D,
-                0xFE, 0xB5, 0xB8, 0x45, 0x1A, 0xAB, 0x6E, 0x5D, 0x23, 0xBD, 0x2A, 0x37, 0xAC, 0x73,
-                0xCC, 0x7B, 0xCD, 0x09,
-            ],
-            &[
-                0x3E, 0x7C, 0x27, 0xD6, 0xED, 0x6A, 0x89, 0x0D, 0xD5, 0x84, 0x9E, 0xD9, 0x39, 0x27,
-                0x3D, 0xD4, 0xBA, 0x96, 0x55, 0x1F, 0x74, 0x3F, 0xDF, 0xAB, 0xCC, 0xA2, 0x39, 0x40,
-                0x08, 0xCB, 0xB6, 0x0F,
-            ],
-        ],
-        [
-            &[
-                0x07, 0x77, 0x5E, 0xE1, 0x62, 0xD2, 0x3E, 0xF4, 0x3E, 0xB2, 0xF3, 0xA4, 0x69, 0x14,
-                0xE5, 0x5D, 0x99, 0x91, 0xEA, 0x43, 0x7F, 0xF5, 0xCD, 0x9C, 0xAD, 0x6A, 0x9C, 0xBA,
-                0x16, 0x83, 0x09, 0x2F,
-            ],
-            &[
-                0x1F, 0xC4, 0xF9, 0xA4, 0x14, 0xE4, 0x97, 0x55, 0x24, 0x5F, 0x01, 0x70, 0xD2, 0xC3,
-                0x61, 0x43, 0xF8, 0x2F, 0xB6, 0x1E, 0x99, 0x5B, 0x74, 0x32, 0x6D, 0x53, 0xD3, 0xDB,
-                0x24, 0x41, 0xB6, 0x00,
-            ],
-            &[
-                0x6B, 0x3E, 0x5F, 0xF5, 0xD0, 0x60, 0x91, 0xA1, 0x26, 0x60, 0xFC, 0x5D, 0x2D, 0x29,
-                0x43, 0x1E, 0xEE, 0xA2, 0x62, 0x4D, 0x30, 0xD9, 0x54, 0x3D, 0xDF, 0xBF, 0x5D, 0x0F,
-                0x28, 0xF4, 0x14, 0x03,
-            ],
-            &[
-                0xEF, 0x11, 0x57, 0xD8, 0x3B, 0x59, 0x3F, 0xC6, 0xCA, 0xB0, 0x4D, 0x0C, 0x77, 0x39,
-                0xAB, 0xA0, 0x16, 0x7A, 0xC3, 0x3D, 0x6C, 0x56, 0x1F, 0xE9, 0x99, 0x9B, 0x19, 0x25,
-                0x92, 0x85, 0xA6, 0x02,
-            ],
-        ],
-        [
-            &[
-                0xC8, 0x68, 0x0F, 0xEC, 0x87, 0xBE, 0xE3, 0x23, 0x3E, 0xF3, 0xA8, 0xFA, 0x4F, 0x5B,
-                0x94, 0x9D, 0xA6, 0xE2, 0xB5, 0xD9, 0x7E, 0x49, 0x06, 0x27, 0xAB, 0x4B, 0x23, 0x0B,
-                0x02, 0xAF, 0xCB, 0x18,
-            ],
-            &[
-                0x8D, 0x0B, 0x5B, 0x9C, 0x9B, 0x19, 0x66, 0x59, 0x24, 0xAD, 0x3E, 0x5E, 0xEC, 0xCF,
-                0x91, 0x58, 0x36, 0x01, 0xCD, 0xFE, 0xB1, 0x6C, 0xE0, 0x0C, 0xDD, 0x86, 0x88, 0x91,
-                0x3B, 0xC5, 0x36, 0x20,
-            ],
-            &[
-                0x2E, 0xF4, 0x90, 0x61, 0x65, 0x55, 0xDB, 0x8F, 0x0C, 0xCF, 0xA8, 0xC3, 0x26, 0x0F,
-                0x67, 0x31, 0x7A, 0x96, 0xC5, 0x55, 0x5D, 0x10, 0x13, 0xF6, 0xE9, 0xF3, 0x12, 0x7A,
-                0xC3, 0xB3, 0x62, 0x24,
-            ],
-            &[
-                0x1F, 0xB2, 0x03, 0x8A, 0x31, 0x8A, 0x67, 0xFC, 0x64, 0x0C, 0x71, 0xA1, 0x6D, 0xB0,
-                0x3B, 0xFC, 0x35, 0x81, 0x36, 0x0A, 0x4E, 0x30, 0xDD, 0xC0, 0xCC, 0x3E, 0xFD, 0x09,
-                0xB5, 0x00, 0x31, 0x17,
-            ],
-        ],
-        [
-            &[
-                0x79, 0xFF, 0x6A, 0x09, 0x10, 0x63, 0xAA, 0x3D, 0x03, 0x16, 0xA4, 0xDB, 0x24, 0x31,
-                0x2C, 0x73, 0x98, 0x3D, 0x82, 0xEC, 0x63, 0x9A, 0x1B, 0xCA, 0xAF, 0x61, 0xFD, 0xDE,
-                0x5C, 0xBB, 0xB4, 0x11,
-            ],
-            &[
-                0xFC, 0x61, 0x77, 0x6B, 0x7A, 0xCA, 0x9D, 0xAD, 0x19, 0x29, 0xB5, 0xF2, 0x6C, 0x2D,
-                0xBF, 0x93, 0x4A, 0x1D, 0xE6, 0x92, 0xAA, 0x9D, 0x9F, 0x46, 0x43, 0xDA, 0x6A, 0x95,
-                0x06, 0x76, 0xF4, 0x0D,
-            ],
-            &[
-                0x4A, 0xA7, 0x75, 0xC3, 0xDD, 0x79, 0xF7, 0xD0, 0x40, 0x6B, 0xE6, 0x22, 0xA4, 0x82,
-                0x11, 0x0C, 0x3D, 0xC5, 0x89, 0x65, 0x56, 0xC5, 0x0A, 0x32, 0x49, 0x93, 0x4A, 0xB7,
-                0x46, 0x10, 0x34, 0x03,
-            ],
-            &[
-                0x30, 0x2C, 0xB6, 0x0A, 0x9B, 0x86, 0xE1, 0x9D, 0xF0, 0xEA, 0xFC, 0x97, 0xE7, 0x34,
-                0xB5, 0xA4, 0x61, 0x00, 0xEE, 0x7D, 0x84, 0x9B, 0x75, 0xD2, 0x48, 0x86, 0x76, 0x94,
-                0x61, 0x85, 0x34, 0x12,
-            ],
-        ],
-        [
-            &[
-                0xFA, 0x5A, 0x28, 0xA2, 0xE6, 0x04, 0x0D, 0x69, 0x83, 0x66, 0xF6, 0x88, 0x40, 0xC9,
-                0xB8, 0xD4, 0x88, 0x44, 0x8E, 0x8C, 0x80, 0xB4, 0x11, 0x68, 0x88, 0x9D, 0xC7, 0xE1,
-                0xCA, 0x2E, 0x97, 0x24,
-            ],
-            &[
-                0xE5, 0xB1, 0xD3, 0xAB, 0x8B, 0xD3, 0xC9, 0x2B, 0x63, 0x5B, 0x16, 0x24, 0xFE, 0xA0,
-                0x24, 0xD3, 0xEF, 0x6B, 0x25, 0xB6, 0x9C, 0x10, 0xAE, 0x2A, 0xC6, 0xE0, 0x11, 0x78,
-                0xD9, 0x57, 0xDE, 0x25,
-            ],
-            &[
-                0xE1, 0x87, 0x4D, 0xDC, 0xE2, 0xD4, 0xB8, 0x9E, 0x62, 0xF6, 0x37, 0x88, 0xE7, 0xFB,
-                0x4D, 0x90, 0x2E, 0x59, 0xD6, 0x9B, 0xA3, 0x95, 0x48, 0x46, 0x82, 0x15, 0x5E, 0x12,
-                0xFE, 0x15, 0x7B, 0x02,
-            ],
-            &[
-                0x3D, 0x60, 0x88, 0xEA, 0x1B, 0x59, 0x8B, 0x0B, 0x39, 0xEC, 0x00, 0x5E, 0xB0, 0x81,
-                0x45, 0x18, 0xB7, 0xB9, 0x53, 0x58, 0x12, 0x27, 0x13, 0x9D, 0x5C, 0x1C, 0x95, 0xBE,
-                0x92, 0x7D, 0xBE, 0x2F,
-            ],
-        ],
-    ];
-
-    // // the following code is used to dump the key schedule to screen
-    // // in a sage friendly format
-    // let keys2 = rescue_perm.key_schedule(&zero);
-    // for e in keys2.iter() {
-    //     for f in e.vec.iter() {
-    //         ark_std::println!("permutation_round_key.append(0x{})",
-    // f.into_bigint());     }
-    // }
-    // The keys are not cross-checked with sage, however, the hash function
-    // is, which implies that the permutation round keys must be correct
-    // (or else, the hash function outputs will not match)
-    // see unit tests:
-    //   - test_sponge()
-    //   - test_rescue_hash_on_0_vec()
-    //   - test_rescue_perm_on_0_vec()
-    const PERMUTATION_ROUND_KEYS: [[&'static [u8]; 4]; 25] = [
-        [
-            &[
-                0xD3, 0x51, 0x21, 0x08, 0x45, 0x10, 0x35, 0x31, 0x97, 0xEB, 0x0C, 0x8A, 0xF0, 0x5A,
-                0x8A, 0x31, 0xD6, 0x86, 0xD1, 0xE5, 0xA3, 0xAF, 0xEC, 0xF0, 0xF9, 0x9A, 0x24, 0x5A,
-                0x19, 0xF5, 0x4E, 0x20,
-            ],
-            &[
-                0x6F, 0x3D, 0x95, 0xDD, 0xA5, 0x78, 0x32, 0x5C, 0x16, 0xAA, 0x46, 0x2D, 0xF2, 0xCF,
-                0x39, 0xBE, 0x97, 0x9E, 0x1C, 0xBF, 0xFF, 0xA0, 0xB6, 0x48, 0x86, 0xD4, 0x87, 0xB6,
-                0xFB, 0x25, 0xD5, 0x00,
-            ],
-            &[
-                0xA4, 0x3E, 0x61, 0x8B, 0x17, 0x1F, 0xDA, 0x72, 0x5F, 0x18, 0x88, 0xEF, 0x5E, 0xC0,
-                0xA6, 0x64, 0x83, 0x33, 0xC8, 0x1B, 0x5D, 0xA5, 0x6B, 0x4E, 0x8C, 0xAD, 0xDE, 0x4F,
-                0x9C, 0x15, 0xB1, 0x10,
-            ],
-            &[
-                0x36, 0x17, 0xB8, 0x5D, 0xB5, 0xB3, 0x0B, 0x26, 0xEE, 0x04, 0xFB, 0x12, 0xC4, 0x16,
-                0x5E, 0x47, 0x0E, 0x6B, 0xC5, 0xDA, 0x78, 0x52, 0x79, 0x16, 0x68, 0x9E, 0x40, 0xED,
-                0x86, 0xFA, 0x23, 0x08,
-            ],
-        ],
-        [
-            &[
-                0x3A, 0x59, 0x4A, 0x35, 0x05, 0xA3, 0xCB, 0x99, 0x37, 0x9E, 0x91, 0xDE, 0xC3, 0x60,
-                0x0C, 0xB7, 0xDA, 0xDD, 0x21, 0x0B, 0x17, 0xDF, 0x84, 0x02, 0x3C, 0xCD, 0xBA, 0x01,
-                0xD6, 0xC7, 0x3F, 0x29,
-            ],
-            &[
-                0x3F, 0xB1, 0x80, 0x83, 0xA4, 0xB9, 0x6B, 0xD1, 0xCD, 0x62, 0xFE, 0x9E, 0xB5, 0xB3,
-                0x38, 0xAA, 0xFA, 0xC3, 0x6C, 0x51, 0x6C, 0xD6, 0x04, 0x3E, 0x41, 0xF7, 0x7F, 0x7F,
-                0x3F, 0xC5, 0x51, 0x11,
-            ],
-            &[
-                0x1C, 0x2D, 0xE2, 0x8D, 0xD0, 0xF9, 0x08, 0x46, 0x8F, 0xCC, 0x2C, 0x81, 0x86, 0xAC,
-                0x57, 0xFB, 0x4F, 0x8F, 0xFC, 0x29, 0xC2, 0x4B, 0x72, 0x4E, 0x52, 0xAC, 0x15, 0x46,
-                0x2C, 0x8A, 0x19, 0x2F,
-            ],
-            &[
-                0xB2, 0x16, 0x76, 0x28, 0xEF, 0xC9, 0x90, 0x21, 0x7C, 0x58, 0xA1, 0x89, 0xBA, 0x3D,
-                0x15, 0x82, 0xE0, 0x79, 0xCE, 0x4C, 0x5F, 0xD2, 0xFC, 0xC3, 0xA5, 0x6D, 0x1F, 0xDD,
-                0xF8, 0x34, 0x70, 0x17,
-            ],
-        ],
-        [
-            &[
-                0x8D, 0xFA, 0x4E, 0x6C, 0x38, 0x65, 0xDD, 0x91, 0xC6, 0x10, 0xC0, 0x69, 0x43, 0x37,
-                0x7E, 0x0D, 0xDB, 0x07, 0x55, 0xC2, 0x7B, 0xDA, 0x4A, 0x80, 0x73, 0x6D, 0x73, 0xEB,
-                0xD3, 0x4E, 0x81, 0x07,
-            ],
-            &[
-                0xF6, 0xD5, 0x32, 0x62, 0x80, 0xEB, 0xBF, 0x82, 0xE4, 0x02, 0xAF, 0x66, 0x33, 0xFA,
-                0xEE, 0x3D, 0x3C, 0x27, 0xB8, 0xC2, 0x81, 0x7C, 0xCD, 0x41, 0xA6, 0xA6, 0x47, 0xD7,
-                0x10, 0x05, 0xC3, 0x28,
-            ],
-            &[
-                0xB5, 0xF5, 0x64, 0xFB, 0x86, 0xC2, 0xAC, 0xBC, 0x35, 0x19, 0x8C, 0x08, 0x82, 0x1C,
-                0xFE, 0x4A, 0xE5, 0xF0, 0x99, 0x8E, 0x51, 0x0E, 0x1E, 0x0B, 0xE5, 0xE4, 0xC7, 0xE3,
-                0x53, 0x47, 0x85, 0x14,
-            ],
-            &[
-                0x7E, 0x95, 0x90, 0xF3, 0xF0, 0xBC, 0xAB, 0xE4, 0x6A, 0x6C, 0x52, 0x48, 0x44, 0x11,
-                0xCF, 0x8F, 0x6F, 0x4A, 0x74, 0x38, 0x40, 0xCD, 0x0F, 0x50, 0x8E, 0xBC, 0xC7, 0x58,
-                0x5F, 0xBD, 0x47, 0x10,
-            ],
-        ],
-        [
-            &[
-                0x2D, 0xDD, 0x6A, 0xDF, 0xA1, 0x53, 0x0F, 0x75, 0xE5, 0x82, 0xB1, 0xEA, 0x09, 0x6B,
-                0x21, 0xFB, 0x54, 0xC7, 0xC4, 0x92, 0x5C, 0x68, 0x1F, 0xD3, 0x56, 0xE1, 0xEF, 0xCD,
-                0x20, 0xCF, 0xAD, 0x00,
-            ],
-            &[
-                0x39, 0xB5, 0xA3, 0x6B, 0x0B, 0x21, 0x45, 0x8D, 0xDF, 0x6C, 0x0E, 0xB5, 0x55, 0xB9,
-                0x6A, 0xDA, 0xA0, 0xC0, 0x51, 0x49, 0x35, 0xFF, 0x60, 0x75, 0x59, 0x9F, 0x14, 0x3E,
-                0xF8, 0xAB, 0xFD, 0x2D,
-            ],
-            &[
-                0xEA, 0x2F, 0x2D, 0xD9, 0x23, 0x12, 0xE8, 0x89, 0x0E, 0xD8, 0x7F, 0x87, 0x90, 0xD2,
-                0xFD, 0x0C, 0xF6, 0x18, 0xE2, 0x27, 0x34, 0xF2, 0x24, 0xD1, 0x46, 0xD0, 0x2F, 0x4E,
-                0x15, 0xB2, 0xAC, 0x2B,
-            ],
-            &[
-                0xC3, 0x1E, 0x35, 0xF5, 0xD7, 0x19, 0x5C, 0x73, 0xEF, 0xD5, 0xD5, 0x02, 0x3A, 0x74,
-                0xF3, 0x20, 0x80, 0xB4, 0x64, 0x20, 0xDD, 0x56, 0xFC, 0xC2, 0xE4, 0xD9, 0x6B, 0x80,
-                0xFE, 0x30, 0x00, 0x08,
-            ],
-        ],
-        [
-            &[
-                0x21, 0xAE, 0xF2, 0x26, 0x5D, 0xC5, 0xDE, 0x31, 0x76, 0x0B, 0x48, 0x17, 0xCE, 0x0A,
-                0x2B, 0x17, 0xA3, 0x6A, 0xAF, 0xD4, 0xBD, 0x7B, 0x56, 0xED, 0x62, 0xEA, 0x9C, 0xB3,
-                0xE3, 0xE7, 0xC1, 0x05,
-            ],
-            &[
-                0x03, 0xF5, 0xEE, 0x61, 0xAA, 0x67, 0x32, 0x81, 0xDD, 0xDA, 0x6E, 0xC3, 0xE1, 0xB7,
-                0xBC, 0x7A, 0x8C, 0x60, 0x9C, 0x0D, 0x8A, 0x10, 0xAB, 0xA0, 0x6A, 0x76, 0x47, 0x60,
-                0xAA, 0xE6, 0x36, 0x2F,
-            ],
-            &[
-                0xF8, 0xDE, 0xC8, 0x97, 0x82, 0x6B, 0xC3, 0x1F, 0x32, 0xDF, 0xF5, 0xE4, 0xB5, 0x1C,
-                0xE4, 0xDC, 0x9B, 0x26, 0xED, 0x6D, 0x59, 0xCB, 0x97, 0xD6, 0xBA, 0xE4, 0x7B, 0x3E,
-                0x0F, 0x01, 0x17, 0x01,
-            ],
-            &[
-                0x72, 0xF8, 0x9B, 0x0D, 0x0C, 0x61, 0xEE, 0xC6, 0x96, 0x38, 0xAD, 0xFF, 0x70, 0x25,
-                0xB2, 0x14, 0x8F, 0xB9, 0xC2, 0x6B, 0x8D, 0x35, 0x64, 0x6E, 0x2F, 0x68, 0x97, 0xE9,
-                0xE9, 0x8A, 0xC8, 0x12,
-            ],
-        ],
-        [
-            &[
-                0xF3, 0xF5, 0xD3, 0xD1, 0xC8, 0x79, 0x50, 0xD5, 0xC2, 0x3E, 0x4C, 0x24, 0x1C, 0xCB,
-                0x93, 0x69, 0x2C, 0xC4, 0x1B, 0x40, 0x1F, 0xC4, 0x44, 0x11, 0xEC, 0x80, 0x44, 0x49,
-                0xC8, 0xE9, 0x81, 0x22,
-            ],
-            &[
-                0x83, 0x43, 0x1A, 0x33, 0x84, 0xBA, 0x0F, 0xEB, 0x4C, 0x00, 0xFC, 0xAD, 0x7D, 0x0B,
-                0x8C, 0x82, 0x32, 0xB8, 0x09, 0xFB, 0x98, 0xF3, 0x03, 0xB3, 0x5A, 0xC3, 0x7E, 0x27,
-                0xFD, 0x99, 0x23, 0x24,
-            ],
-            &[
-                0x14, 0xA0, 0x16, 0x62, 0xE1, 0x0F, 0x21, 0x17, 0xE1, 0xF2, 0x34, 0xFE, 0x14, 0xFC,
-                0xAA, 0x84, 0x7F, 0xD1, 0x37, 0x09, 0x80, 0x72, 0xFD, 0x6B, 0x6E, 0xA2, 0xC6, 0x2C,
-                0x67, 0x0E, 0x9E, 0x2E,
-            ],
-            &[
-                0x3F, 0x11, 0x7E, 0xED, 0x9E, 0xB2, 0x41, 0xD1, 0x86, 0x32, 0xCB, 0x13, 0xFD, 0x23,
-                0x32, 0x5D, 0xCE, 0x53, 0x2E, 0x51, 0xAF, 0x4B, 0x9F, 0xB9, 0xC2, 0xA3, 0x7D, 0xD2,
-                0x60, 0x00, 0xA2, 0x2D,
-            ],
-        ],
-        [
-            &[
-                0x60, 0x19, 0x0F, 0x34, 0x3D, 0x9C, 0x77, 0xC7, 0x78, 0x7B, 0xA3, 0xB0, 0x81, 0x10,
-                0xAA, 0x05, 0x44, 0x8D, 0xE8, 0x02, 0xB4, 0x3B, 0x79, 0xF6, 0xA8, 0x6B, 0x99, 0xE3,
-                0xEE, 0xB3, 0x4C, 0x0D,
-            ],
-            &[
-                0x3D, 0xC4, 0xF0, 0xDC, 0xE0, 0xE9, 0x2D, 0x00, 0x46, 0xC3, 0x3A, 0x2C, 0x01, 0x28,
-                0x18, 0x16, 0x76, 0x36, 0x11, 0x53, 0xF9, 0x6A, 0x6A, 0xBD, 0x23, 0x3B, 0x9B, 0x65,
-                0x73, 0xDB, 0xA1, 0x1B,
-            ],
-            &[
-                0xB0, 0xC0, 0x0B, 0xE3, 0x2E, 0xA0, 0x24, 0xFD, 0xC5, 0x5B, 0x1C, 0x50, 0xA3, 0x47,
-                0x95, 0xEC, 0x7F, 0xD3, 0x2F, 0x7F, 0x5C, 0x9B, 0x81, 0x88, 0xF1, 0xA5, 0x04, 0xDD,
-                0x73, 0x64, 0xD8, 0x17,
-            ],
-            &[
-                0x79, 0x69, 0xAC, 0xDD, 0xF1, 0x65, 0x62, 0xEE, 0xE7, 0x0D, 0x6A, 0x15, 0xBF, 0xF2,
-                0x23, 0x25, 0x37, 0xD5, 0xC3, 0x9A, 0xB6, 0xA8, 0xF3, 0x31, 0x41, 0x1B, 0xFD, 0xD3,
-                0x20, 0xA6, 0x4C, 0x0C,
-            ],
-        ],
-        [
-            &[
-                0xF0, 0x32, 0xA6, 0x45, 0x74, 0x64, 0x8F, 0xF2, 0x22, 0x11, 0x8E, 0xBE, 0x52, 0xA3,
-                0xC2, 0x25, 0x56, 0x06, 0xB2, 0xA1, 0x19, 0x1E, 0x97, 0x1D, 0x49, 0xFE, 0x51, 0x40,
-                0xAE, 0x92, 0x23, 0x0A,
-            ],
-            &[
-                0x16, 0x78, 0x9F, 0x04, 0x05, 0xC7, 0xC3, 0x94, 0xC6, 0x52, 0x29, 0xD8, 0x1A, 0x2F,
-                0x4D, 0x2D, 0x9A, 0x4C, 0x98, 0x1A, 0x23, 0x0F, 0xB3, 0xE5, 0x64, 0x3C, 0x18, 0x34,
-                0x5E, 0xAD, 0xFC, 0x11,
-            ],
-            &[
-                0x00, 0x4B, 0xDF, 0xA9, 0x2D, 0x5E, 0xFA, 0x4F, 0x41, 0xCB, 0xFD, 0x3B, 0xFA, 0x86,
-                0xA0, 0x33, 0xB9, 0xA7, 0x9F, 0x52, 0x77, 0xCA, 0x38, 0xC2, 0x7A, 0x0C, 0x3B, 0x1D,
-                0xD7, 0x76, 0x01, 0x0A,
-            ],
-            &[
-                0x4F, 0x85, 0xF3, 0x37, 0x12, 0xC2, 0xB1, 0x01, 0x05, 0x64, 0x53, 0x5B, 0x19, 0x1B,
-                0x6A, 0x8B, 0x22, 0x8A, 0xD9, 0xFF, 0x3C, 0x68, 0xFF, 0xA8, 0xAD, 0xD8, 0xD5, 0x88,
-                0x26, 0x2C, 0x9C, 0x05,
-            ],
-        ],
-        [
-            &[
-                0xED, 0xB6, 0x74, 0x03, 0xA8, 0x00, 0x46, 0xDB, 0xB6, 0x98, 0xF9, 0xA2, 0xAE, 0x43,
-                0x7A, 0x2B, 0xC1, 0xBE, 0xBE, 0x7D, 0x2E, 0x33, 0x91, 0xB7, 0x1C, 0x85, 0x58, 0x35,
-                0x9A, 0xF3, 0x61, 0x0F,
-            ],
-            &[
-                0xC5, 0x0F, 0x7B, 0xDA, 0x0E, 0x87, 0x7C, 0xDD, 0xC9, 0x2C, 0x2B, 0x8A, 0x3F, 0x67,
-                0xF5, 0x59, 0x78, 0xD4, 0xF1, 0x17, 0x95, 0x90, 0xB5, 0x8F, 0xBB, 0x29, 0xAB, 0x2B,
-                0xD6, 0xA9, 0xE1, 0x25,
-            ],
-            &[
-                0x0F, 0xB4, 0x5B, 0xFD, 0x36, 0x81, 0x46, 0xFD, 0x47, 0xD6, 0xBD, 0xAD, 0x22, 0x87,
-                0x4C, 0x42, 0xF0, 0x0C, 0x96, 0x77, 0x69, 0x75, 0xB8, 0x0D, 0x15, 0x61, 0x46, 0xE4,
-                0xDE, 0x03, 0x3C, 0x2E,
-            ],
-            &[
-                0xCD, 0xEB, 0xB8, 0x96, 0x77, 0xE2, 0xB4, 0xFA, 0x42, 0x4C, 0x23, 0x76, 0xFC, 0x9E,
-                0x36, 0x60, 0x41, 0x8F, 0x27, 0x09, 0x1C, 0x5A, 0xDA, 0x4D, 0x66, 0x0B, 0xEA, 0x77,
-                0x11, 0xD0, 0x5F, 0x23,
-            ],
-        ],
-        [
-            &[
-                0xCC, 0x44, 0xAB, 0xE4, 0x14, 0x4D, 0xB1, 0xB8, 0xEF, 0xC9, 0xFA, 0x54, 0x8A, 0xF2,
-                0x0D, 0xCF, 0x30, 0x58, 0x4C, 0x76, 0x73, 0x51, 0x6D, 0x82, 0x6E, 0x98, 0x66, 0xA1,
-                0x72, 0xD0, 0x9B, 0x16,
-            ],
-            &[
-                0x32, 0x80, 0x9C, 0xC8, 0x36, 0x68, 0x17, 0x19, 0x42, 0x8B, 0x1C, 0x05, 0x88, 0xE1,
-                0x1C, 0xD5, 0xA5, 0xE4, 0xE5, 0xFF, 0x9B, 0x77, 0xD7, 0x33, 0x12, 0x54, 0x0C, 0xE7,
-                0xFD, 0x5D, 0x16, 0x29,
-            ],
-            &[
-                0xCA, 0x40, 0x7D, 0x99, 0x1C, 0x7D, 0x46, 0xC9, 0x41, 0x90, 0xB5, 0x04, 0xBD, 0x48,
-                0x56, 0x42, 0x96, 0x15, 0xD6, 0xAA, 0x04, 0xC9, 0x36, 0x5C, 0x6D, 0xAB, 0xD5, 0x02,
-                0x15, 0x07, 0x7B, 0x14,
-            ],
-            &[
-                0x90, 0x2C, 0x22, 0xF3, 0x29, 0xC4, 0xF4, 0xFD, 0xD7, 0x07, 0x2C, 0xF4, 0xF7, 0xAA,
-                0xB1, 0x27, 0x7A, 0xDF, 0xE9, 0xAA, 0x88, 0xC8, 0x91, 0xB1, 0x56, 0x0B, 0xB0, 0x1E,
-                0xDF, 0xE4, 0xA3, 0x2E,
-            ],
-        ],
-        [
-            &[
-                0xEF, 0x6D, 0xC5, 0xEF, 0x9F, 0x27, 0xAF, 0x5A, 0x66, 0x12, 0x96, 0xDF, 0xC9, 0xEA,
-                0x1E, 0x14, 0xF1, 0x1F, 0x93, 0x6A, 0x07, 0x04, 0x26, 0x44, 0x53, 0xAD, 0xBE, 0xC9,
-                0x4C, 0xD2, 0xE7, 0x05,
-            ],
-            &[
-                0x7F, 0x2E, 0x3B, 0xF9, 0x56, 0x97, 0xBD, 0x8D, 0x01, 0x3D, 0x2C, 0xA1, 0xFA, 0x6B,
-                0x65, 0x69, 0x7C, 0x99, 0xD7, 0xBA, 0x75, 0xBF, 0xDC, 0x98, 0x66, 0x98, 0x2F, 0x69,
-                0xAB, 0xFD, 0xD3, 0x2A,
-            ],
-            &[
-                0xDF, 0x97, 0x8E, 0xA6, 0xC5, 0x97, 0x59, 0x3A, 0x02, 0x99, 0xAF, 0xC2, 0x59, 0xAF,
-                0x72, 0xDD, 0x9B, 0xD6, 0xF8, 0xC6, 0xCE, 0x02, 0x89, 0x33, 0x8F, 0xE5, 0xC3, 0x40,
-                0xA4, 0xAA, 0x11, 0x0C,
-            ],
-            &[
-                0xE2, 0x06, 0xD2, 0x5D, 0x4F, 0x2C, 0x23, 0x4D, 0xDA, 0x8C, 0x0B, 0xCA, 0x96, 0xB1,
-                0x8E, 0xE5, 0xEB, 0xFD, 0x08, 0x53, 0x58, 0xE5, 0x52, 0x80, 0x67, 0x04, 0x08, 0x76,
-                0x93, 0x37, 0x38, 0x10,
-            ],
-        ],
-        [
-            &[
-                0x18, 0x89, 0xF5, 0x85, 0x97, 0x75, 0x66, 0x99, 0x5F, 0x14, 0x22, 0xB6, 0xA6, 0xD6,
-                0xC7, 0x6A, 0xC9, 0x11, 0xAC, 0x40, 0xB4, 0x1D, 0xAB, 0xA3, 0xF2, 0x39, 0x66, 0x8B,
-                0xA0, 0xB4, 0xE2, 0x16,
-            ],
-            &[
-                0x38, 0xE8, 0x95, 0xA3, 0x84, 0xFD, 0x7C, 0xBD, 0x64, 0x90, 0x85, 0xBC, 0xB5, 0xBF,
-                0x44, 0x75, 0xB1, 0x93, 0x38, 0x98, 0xE7, 0x33, 0x89, 0x4D, 0x3B, 0xD4, 0xAB, 0xBB,
-                0xD0, 0xB6, 0x68, 0x17,
-            ],
-            &[
-                0x13, 0xE3, 0x91, 0x5C, 0xD6, 0x12, 0x30, 0xB3, 0x2C, 0xFD, 0x1D, 0x1B, 0x8F, 0x51,
-                0xEB, 0x69, 0x7F, 0x4D, 0x46, 0x67, 0x3C, 0x6A, 0x20, 0xD5, 0x69, 0x00, 0x68, 0x5E,
-                0x41, 0xA6, 0xAE, 0x1F,
-            ],
-            &[
-                0xEC, 0x71, 0x94, 0x05, 0x54, 0x2D, 0x35, 0x7B, 0x92, 0x2B, 0x60, 0x93, 0x00, 0x16,
-                0xE5, 0x99, 0x17, 0x73, 0xC5, 0x68, 0x79, 0x9C, 0xF0, 0xEF, 0x03, 0xDD, 0xE0, 0xB9,
-                0xE0, 0x53, 0xE1, 0x2D,
-            ],
-        ],
-        [
-            &[
-                0x36, 0xC5, 0x98, 0x60, 0x0E, 0xEA, 0xDE, 0x6B, 0x50, 0x07, 0x1C, 0x12, 0x68, 0x3A,
-                0xF6, 0x1D, 0xCC, 0x64, 0x8F, 0xB8, 0xD2, 0x1D, 0xF6, 0x9B, 0x0D, 0x2E, 0x2E, 0x52,
-                0x93, 0x9A, 0xDE, 0x2A,
-            ],
-            &[
-                0x10, 0xFD, 0xD8, 0xBD, 0x4E, 0xD2, 0x25, 0x91, 0x80, 0xED, 0x8B, 0x5C, 0x82, 0x51,
-                0x5A, 0xAC, 0x90, 0x3D, 0x66, 0x3A, 0x53, 0x5F, 0x7A, 0xFD, 0xE1, 0x23, 0x16, 0x70,
-                0x0A, 0x22, 0x38, 0x09,
-            ],
-            &[
-                0x72, 0xCB, 0xD8, 0x38, 0x46, 0x0C, 0xD6, 0x83, 0x74, 0x69, 0xA4, 0xB8, 0x44, 0x25,
-                0xB2, 0xEE, 0x57, 0x0A, 0x01, 0x46, 0x20, 0xCE, 0x88, 0x8D, 0xE5, 0x34, 0x05, 0xCE,
-                0x7A, 0x53, 0xAA, 0x06,
-            ],
-            &[
-                0xC5, 0x4F, 0xD3, 0x23, 0x95, 0x96, 0xE9, 0x59, 0xB6, 0xE6, 0xDC, 0xA3, 0x23, 0x9F,
-                0x90, 0xB1, 0x1A, 0x05, 0x64, 0xFB, 0xA5, 0xB1, 0x55, 0x02, 0xF9, 0x5E, 0xB9, 0x05,
-                0xC7, 0x41, 0x95, 0x1F,
-            ],
-        ],
-        [
-            &[
-                0xF9, 0x0F, 0xE5, 0x46, 0x24, 0x89, 0x3E, 0xCD, 0xEF, 0x82, 0x05, 0x02, 0x49, 0x58,
-                0x56, 0x05, 0xC3, 0x71, 0x2A, 0x28, 0xA8, 0x69, 0x79, 0x9A, 0x36, 0x21, 0x30, 0x00,
-                0x8C, 0xBB, 0xDE, 0x23,
-            ],
-            &[
-                0x77, 0x63, 0x5D, 0xF3, 0x1A, 0x28, 0x4C, 0x94, 0xC5, 0xC6, 0xC3, 0xD7, 0xCC, 0x2A,
-                0x2D, 0xC6, 0xAE, 0xF1, 0xFF, 0x15, 0xE9, 0xB0, 0x62, 0xA2, 0x14, 0xBB, 0x1C, 0x45,
-                0x96, 0xCA, 0xF2, 0x0B,
-            ],
-            &[
-                0x8E, 0x35, 0xB4, 0x12, 0x38, 0x61, 0xE2, 0xCC, 0x22, 0xE3, 0x5B, 0x56, 0xB8, 0x11,
-                0x19, 0x9D, 0x98, 0x64, 0x17, 0xA3, 0x88, 0xDE, 0xC0, 0xAF, 0x68, 0x00, 0xCE, 0xCA,
-                0x09, 0x98, 0x7F, 0x2C,
-            ],
-            &[
-                0xEC, 0x0E, 0xD1, 0xDF, 0x63, 0x5F, 0x1B, 0x82, 0x74, 0xE5, 0xA7, 0xB9, 0x03, 0x72,
-                0x0E, 0x7F, 0xE7, 0x52, 0x36, 0x8F, 0xC8, 0x0A, 0x57, 0xB6, 0x43, 0x06, 0x5A, 0x37,
-                0xD9, 0x71, 0x07, 0x21,
-            ],
-        ],
-        [
-            &[
-                0x76, 0xF4, 0x52, 0x47, 0x23, 0x85, 0x1B, 0xA2, 0x96, 0x1F, 0xFC, 0x6C, 0x62, 0x21,
-                0xAF, 0xA1, 0xEA, 0xF3, 0xEA, 0x05, 0xAF, 0x9F, 0xE5, 0x98, 0x3F, 0x93, 0x3C, 0x29,
-                0x55, 0xFE, 0x67, 0x00,
-            ],
-            &[
-                0x8B, 0xAC, 0xB9, 0x71, 0x21, 0x5C, 0xCC, 0x82, 0xA7, 0x5E, 0x53, 0x48, 0xA3, 0x44,
-                0x9D, 0xC6, 0xBE, 0x6F, 0x69, 0x1D, 0xE1, 0xDC, 0x9C, 0x43, 0xB5, 0x53, 0x50, 0x1F,
-                0x34, 0xF1, 0xF8, 0x21,
-            ],
-            &[
-                0xA5, 0xAE, 0x69, 0xF0, 0x6E, 0xD9, 0x92, 0x20, 0x21, 0xF8, 0xDD, 0x89, 0x80, 0x41,
-                0x80, 0xF1, 0xFC, 0xCA, 0xFF, 0xBB, 0x52, 0xD7, 0x76, 0x84, 0xD1, 0xC6, 0x4D, 0xF5,
-                0xC8, 0x9A, 0x41, 0x16,
-            ],
-            &[
-                0xE6, 0x49, 0x79, 0xF1, 0xF6, 0xC5, 0x43, 0xBB, 0x93, 0xC0, 0xB9, 0x78, 0x8C, 0x70,
-                0x83, 0xF6, 0x5B, 0x97, 0x43, 0x5C, 0x27, 0xCF, 0xD5, 0xC9, 0x22, 0x28, 0xA6, 0xD4,
-                0xA3, 0x4E, 0xAA, 0x0A,
-            ],
-        ],
-        [
-            &[
-                0x2A, 0xC4, 0x8B, 0xBC, 0x7F, 0x66, 0xB4, 0x06, 0x5E, 0x0C, 0x9A, 0x0E, 0x71, 0xE9,
-                0x5E, 0xF5, 0x84, 0x37, 0xBB, 0x3E, 0x5A, 0x39, 0x0E, 0x2B, 0x4F, 0x60, 0x0F, 0x05,
-                0x33, 0xD7, 0x37, 0x0C,
-            ],
-            &[
-                0xC3, 0xA6, 0x7E, 0x31, 0x05, 0x18, 0x00, 0x63, 0x75, 0xC9, 0xCF, 0x91, 0xA4, 0xA4,
-                0xB6, 0xB1, 0xE0, 0xCD, 0xF0, 0xD2, 0x0B, 0xD0, 0x0D, 0xA1, 0x77, 0x84, 0x6E, 0xC2,
-                0xB6, 0xF5, 0x24, 0x28,
-            ],
-            &[
-                0x97, 0xA3, 0x0F, 0x65, 0x31, 0xCC, 0x22, 0x91, 0xDB, 0xC9, 0x28, 0xEE, 0x7B, 0x59,
-                0xD0, 0x6E, 0xA0, 0x2B, 0x3E, 0x2C, 0x3D, 0xB8, 0x06, 0xBF, 0xB5, 0x63, 0xDF, 0xE4,
-                0xF1, 0x5B, 0xB2, 0x01,
-            ],
-            &[
-                0x39, 0x8D, 0x40, 0x60, 0x37, 0x9A, 0x2B, 0x7B, 0xCE, 0x4F, 0x0E, 0x62, 0xEA, 0xCF,
-                0x5D, 0x1F, 0x24, 0x35, 0x93, 0x15, 0x57, 0x09, 0x11, 0x06, 0x5F, 0xBB, 0xFF, 0x3E,
-                0x81, 0x67, 0x0B, 0x24,
-            ],
-        ],
-        [
-            &[
-                0x04, 0xFF, 0xCA, 0x66, 0x84, 0x2F, 0x30, 0xD2, 0x65, 0x11, 0xE3, 0x90, 0xA6, 0x49,
-                0xB8, 0x28, 0x8A, 0xC5, 0x96, 0x72, 0xCF, 0x0B, 0xA0, 0x2A, 0xB7, 0x5C, 0xD6, 0xC8,
-                0x86, 0xCD, 0x36, 0x22,
-            ],
-            &[
-                0x36, 0xBF, 0x0F, 0x35, 0x27, 0x4A, 0xDB, 0x27, 0x70, 0xCA, 0xF2, 0x9F, 0x42, 0xAB,
-                0x95, 0xCA, 0x98, 0x2B, 0xAA, 0x93, 0x3B, 0x04, 0xEA, 0xDA, 0xB8, 0xDC, 0x82, 0x81,
-                0xD1, 0x0E, 0x39, 0x20,
-            ],
-            &[
-                0xE2, 0xEA, 0xD6, 0x5E, 0x24, 0x78, 0x34, 0x80, 0xDE, 0xB7, 0x46, 0x94, 0xE5, 0x08,
-                0xC2, 0xA5, 0x15, 0x31, 0x9F, 0xD9, 0xF0, 0xEC, 0x57, 0x99, 0x84, 0x82, 0x73, 0x38,
-                0xDB, 0x3C, 0x64, 0x1E,
-            ],
-            &[
-                0xC7, 0x94, 0xC2, 0xF4, 0x68, 0x54, 0x15, 0x41, 0xB8, 0x88, 0x3F, 0x6D, 0x51, 0x96,
-                0x42, 0x13, 0x5A, 0x3D, 0x79, 0x29, 0xB1, 0x66, 0xF7, 0x06, 0x95, 0x05, 0x89, 0x1F,
-                0xA3, 0x4B, 0x85, 0x1E,
-            ],
-        ],
-        [
-            &[
-                0xC2, 0x91, 0x90, 0xD5, 0x1D, 0x29, 0x6F, 0x7F, 0xCB, 0x7A, 0xF8, 0xF6, 0xB3, 0xA4,
-                0xCB, 0xC6, 0xE1, 0xF2, 0xF7, 0x58, 0x98, 0xF4, 0x07, 0xC3, 0x53, 0x1A, 0x42, 0xBE,
-                0x52, 0x49, 0xCA, 0x07,
-            ],
-            &[
-                0x06, 0x8F, 0x6E, 0xF2, 0xE7, 0x85, 0x12, 0xBB, 0xC8, 0x30, 0x90, 0x4E, 0x93, 0x0C,
-                0xAA, 0x2E, 0x4D, 0x9B, 0x10, 0xA4, 0x54, 0x77, 0x2B, 0x1E, 0xF4, 0x94, 0xDD, 0x41,
-                0xC5, 0x91, 0x15, 0x13,
-            ],
-            &[
-                0x84, 0x05, 0xED, 0xA4, 0xD8, 0x2B, 0x68, 0x68, 0x89, 0x5F, 0xFD, 0x92, 0xFA, 0xE0,
-                0x52, 0x5D, 0xA5, 0xDC, 0x46, 0x55, 0x7C, 0x08, 0x40, 0xAB, 0xFC, 0x6C, 0x5A, 0x6C,
-                0x8A, 0x8E, 0xDD, 0x2C,
-            ],
-            &[
-                0x5C, 0x6C, 0xEA, 0x27, 0x47, 0x86, 0xBC, 0x40, 0x95, 0x0C, 0x63, 0xD0, 0x6B, 0x79,
-                0x5C, 0xA2, 0xA9, 0x6E, 0x22, 0xB2, 0xE9, 0x8E, 0x5B, 0x9E, 0x59, 0xB6, 0xBE, 0x28,
-                0x0B, 0xF1, 0xC3, 0x10,
-            ],
-        ],
-        [
-            &[
-                0x89, 0xCB, 0xE9, 0x00, 0xE6, 0x66, 0x23, 0x1B, 0x46, 0xE9, 0x6C, 0x7E, 0x40, 0x88,
-                0xD4, 0x73, 0x9D, 0x90, 0xC0, 0x4D, 0x55, 0xEC, 0x18, 0x3E, 0xC2, 0xD9, 0x5F, 0x8B,
-                0x9F, 0x43, 0xB8, 0x10,
-            ],
-            &[
-                0x7B, 0xF9, 0x4A, 0xA1, 0x66, 0x86, 0x6C, 0x17, 0x69, 0x70, 0x1F, 0xFA, 0x81, 0xDB,
-                0x4B, 0x23, 0x6C, 0x2F, 0x86, 0x93, 0x93, 0x8C, 0xD5, 0x25, 0xA1, 0x1B, 0xE3, 0xE1,
-                0xD3, 0x2D, 0x0E, 0x1C,
-            ],
-            &[
-                0xBD, 0x2E, 0x97, 0xA0, 0x61, 0x4C, 0xC5, 0xB8, 0xF9, 0x93, 0xBD, 0x8D, 0x1F, 0x5A,
-                0x85, 0x15, 0x33, 0x57, 0xC6, 0x61, 0x65, 0xB4, 0xDD, 0x08, 0xC6, 0x3C, 0x58, 0xB8,
-                0x67, 0xBC, 0x5E, 0x30,
-            ],
-            &[
-                0xE7, 0xCD, 0x84, 0xCE, 0x85, 0x7D, 0x63, 0x2C, 0x3A, 0x4B, 0x98, 0xEE, 0x67, 0x0B,
-                0xBB, 0x56, 0xF2, 0x0F, 0x8E, 0x50, 0x23, 0xDA, 0x73, 0xE9, 0xE6, 0x96, 0x6A, 0x2E,
-                0x61, 0x96, 0x94, 0x07,
-            ],
-        ],
-        [
-            &[
-                0xB3, 0xD4, 0x7E, 0xAB, 0x9C, 0x1F, 0x8F, 0x4A, 0x95, 0x25, 0x25, 0x56, 0x2F, 0x16,
-                0x81, 0x77, 0xD7, 0x75, 0x0D, 0x9B, 0x3E, 0x00, 0x78, 0xAC, 0x3B, 0xAC, 0x76, 0x25,
-                0x4D, 0xCD, 0xA4, 0x05,
-            ],
-            &[
-                0x7E, 0x16, 0x00, 0x7E, 0x12, 0x30, 0x88, 0xB6, 0xC7, 0x9C, 0x1D, 0x0B, 0x6D, 0xFD,
-                0x89, 0x49, 0x2D, 0x77, 0x65, 0x1D, 0xB4, 0xB8, 0x45, 0xC5, 0xAB, 0x70, 0x4D, 0xD4,
-                0x96, 0xBB, 0x4D, 0x1E,
-            ],
-            &[
-                0xAE, 0x7B, 0x11, 0x85, 0x5C, 0xE5, 0x0E, 0x46, 0xCF, 0xB6, 0xBF, 0xA4, 0x45, 0x94,
-                0x4F, 0xD5, 0xE7, 0xFE, 0x11, 0xCB, 0xBE, 0x44, 0xB3, 0x47, 0x90, 0x3E, 0xE1, 0xCB,
-                0x59, 0xA7, 0xBE, 0x2B,
-            ],
-            &[
-                0x12, 0x4C, 0x89, 0x72, 0x0D, 0x60, 0x3B, 0xAF, 0x5A, 0x28, 0x4C, 0x73, 0x9E, 0xE7,
-                0xFC, 0xB2, 0xFA, 0xFB, 0xFD, 0xD9, 0x1B, 0x08, 0x2D, 0x00, 0x93, 0x24, 0x49, 0x82,
-                0x92, 0xEE, 0x03, 0x1A,
-            ],
-        ],
-        [
-            &[
-                0x28, 0xBD, 0x23, 0x70, 0xFE, 0x54, 0xFE, 0x97, 0xCA, 0x11, 0x30, 0xE5, 0x23, 0x02,
-                0xA8, 0x17, 0x1C, 0x0F, 0xA0, 0x3D, 0xD4, 0x13, 0x8A, 0x01, 0x9E, 0xBF, 0x07, 0x26,
-                0x66, 0xAC, 0xD0, 0x02,
-            ],
-            &[
-                0x33, 0xB3, 0x04, 0xA1, 0xD5, 0xEB, 0x15, 0x46, 0xBE, 0xCA, 0xCE, 0xC9, 0x32, 0xE7,
-                0xAE, 0x05, 0x09, 0x9C, 0xD9, 0x88, 0xE2, 0xA6, 0xA0, 0xE4, 0x61, 0x04, 0x5A, 0xAA,
-                0x97, 0x4B, 0xB7, 0x2C,
-            ],
-            &[
-                0xF5, 0x86, 0x73, 0xEE, 0xB6, 0xC7, 0x06, 0xA8, 0x61, 0x0A, 0x87, 0xD7, 0xA1, 0xE2,
-                0xB2, 0x9C, 0x4A, 0x42, 0xC2, 0x6E, 0x99, 0xB2, 0x67, 0xFD, 0xFB, 0x37, 0x0A, 0x91,
-                0xCB, 0xCC, 0xFA, 0x1C,
-            ],
-            &[
-                0x88, 0x73, 0x55, 0x2C, 0xA5, 0xA3, 0x88, 0xFB, 0xD6, 0xBF, 0x5D, 0xFB, 0x57, 0xF4,
-                0x19, 0x81, 0x55, 0x57, 0xDE, 0x95, 0xC1, 0x34, 0x13, 0x01, 0xD5, 0x31, 0xC4, 0xDE,
-                0xA3, 0xB5, 0x60, 0x17,
-            ],
-        ],
-        [
-            &[
-                0x0F, 0x5F, 0x6A, 0xA9, 0x30, 0x0D, 0xF9, 0x88, 0xA5, 0x6D, 0xD8, 0xEB, 0xA3, 0x30,
-                0xE4, 0x4A, 0x87, 0xE5, 0x5A, 0xCA, 0x1A, 0x62, 0x12, 0xFF, 0x28, 0x58, 0x1E, 0xE9,
-                0x62, 0x33, 0x48, 0x2F,
-            ],
-            &[
-                0x4D, 0xAF, 0xC1, 0x6C, 0xC6, 0xED, 0x53, 0x70, 0x41, 0x33, 0x26, 0x8D, 0xB6, 0xD6,
-                0xA5, 0x40, 0x88, 0xD7, 0x23, 0xBD, 0x5E, 0xD8, 0xBC, 0xD2, 0x09, 0x2C, 0xF6, 0x27,
-                0xFA, 0x5E, 0xCE, 0x22,
-            ],
-            &[
-                0xB6, 0x28, 0x0F, 0x7E, 0x4F, 0x0E, 0x0E, 0x6D, 0xB4, 0x6D, 0x4E, 0x68, 0x98, 0x45,
-                0x5D, 0x9A, 0xB5, 0xC9, 0xEE, 0x92, 0x49, 0x4D, 0x25, 0x92, 0xE3, 0x95, 0xC4, 0x61,
-                0xDE, 0xD1, 0xED, 0x23,
-            ],
-            &[
-                0xC7, 0x16, 0xC8, 0xA3, 0xBA, 0xDC, 0xD0, 0xBF, 0x50, 0x30, 0x44, 0xFE, 0x7B, 0xCC,
-                0x0A, 0x1D, 0x9B, 0x5A, 0x6B, 0xA4, 0x25, 0xC9, 0x91, 0x48, 0xC5, 0x34, 0x0F, 0x01,
-                0x6D, 0xB3, 0xFB, 0x0B,
-            ],
-        ],
-        [
-            &[
-                0xD7, 0x67, 0xD1, 0x28, 0x58, 0xBD, 0x9E, 0x72, 0xDE, 0x61, 0x02, 0x1F, 0x82, 0x43,
-                0x54, 0x7F, 0xE9, 0xF0, 0xC7, 0xD0, 0x8C, 0x9A, 0xF3, 0x59, 0x88, 0x5F, 0xDE, 0x6E,
-                0x7F, 0x29, 0x2E, 0x20,
-            ],
-            &[
-                0xDA, 0x45, 0xB5, 0x78, 0x52, 0x0F, 0x3D, 0xF9, 0xA7, 0x67, 0xDA, 0x64, 0x62, 0xAA,
-                0xDD, 0xAF, 0x8E, 0xCE, 0xB0, 0xA8, 0x1D, 0xA6, 0xB5, 0xAC, 0x79, 0x9A, 0x91, 0x8B,
-                0xF9, 0xAB, 0xAE, 0x21,
-            ],
-            &[
-                0x52, 0x5D, 0xC1, 0x07, 0x4D, 0xF9, 0xF3, 0x5F, 0xE7, 0x3F, 0xEB, 0x54, 0x73, 0xE3,
-                0x6B, 0x94, 0x32, 0xF9, 0x88, 0x7B, 0x23, 0xE1, 0xFA, 0xBF, 0xD3, 0x44, 0x75, 0xC8,
-                0xF8, 0x91, 0x3E, 0x2B,
-            ],
-            &[
-                0x16, 0x80, 0xAB, 0x5E, 0x02, 0x24, 0x18, 0xBA, 0x44, 0x82, 0x7C, 0xAE, 0x1D, 0xE0,
-                0x3B, 0xD6, 0x7E, 0xFC, 0x5F, 0x14, 0x7E, 0x07, 0x01, 0x54, 0x21, 0xCB, 0x84, 0x3C,
-                0xC9, 0x9E, 0x4F, 0x30,
-            ],
-        ],
-        [
-            &[
-                0x85, 0x4C, 0xE2, 0xD3, 0x6B, 0xE3, 0x35, 0xD5, 0xEA, 0xEB, 0x4F, 0x8D, 0x92, 0x17,
-                0x3B, 0xC9, 0x66, 0x48, 0xCF, 0x7D, 0x5B, 0xC3, 0x57, 0x82, 0xFD, 0xC0, 0x84, 0x20,
-                0x21, 0xE6, 0x2C, 0x2A,
-            ],
-            &[
-                0x49, 0xCB, 0x00, 0xD7, 0x85, 0xB4, 0xC2, 0xAE, 0x81, 0x19, 0x7A, 0x4A, 0xBD, 0xBE,
-                0x5A, 0xE1, 0x65, 0x27, 0xFF, 0x7A, 0xEF, 0xB4, 0x93, 0x4E, 0x73, 0xFD, 0x6B, 0x0C,
-                0x2E, 0x63, 0x4C, 0x2A,
-            ],
-            &[
-                0x58, 0x19, 0x2B, 0x1B, 0x71, 0x99, 0x5C, 0x6C, 0x2A, 0xBC, 0xF5, 0xD0, 0xB1, 0xE8,
-                0x35, 0x87, 0x06, 0x17, 0x1B, 0x5C, 0x7A, 0xBA, 0xB9, 0xF5, 0x55, 0x8D, 0x25, 0xB6,
-                0x3A, 0xF4, 0x9A, 0x0E,
-            ],
-            &[
-                0xB2, 0x22, 0xE3, 0x1B, 0x78, 0xF9, 0x6B, 0xF6, 0x91, 0x5F, 0xCF, 0xFC, 0x3A, 0x8A,
-                0x1F, 0xE8, 0x97, 0x28, 0x3D, 0xE2, 0xAE, 0x7B, 0x44, 0xB8, 0x70, 0x3E, 0xEC, 0x2B,
-                0x55, 0x49, 0xB8, 0x1D,
-            ],
-        ],
-        [
-            &[
-                0x94, 0xB1, 0x47, 0xED, 0xC5, 0x33, 0x6F, 0x24, 0x2D, 0x0E, 0xAE, 0xBA, 0x39, 0xCA,
-                0xB6, 0x68, 0xE5, 0x15, 0xC7, 0x6C, 0x78, 0x5D, 0x9B, 0x3B, 0xE1, 0x87, 0x43, 0xD3,
-                0xD8, 0x09, 0x7A, 0x1A,
-            ],
-            &[
-                0x04, 0x2C, 0x81, 0x19, 0x06, 0x49, 0x25, 0xBF, 0xF6, 0xCF, 0xD0, 0x47, 0xDB, 0xCC,
-                0x8F, 0x79, 0x0F, 0xBF, 0xEA, 0xF1, 0xC5, 0x33, 0x5E, 0xF1, 0x70, 0xC7, 0xDD, 0xEC,
-                0x49, 0x2F, 0x5F, 0x17,
-            ],
-            &[
-                0xB7, 0x10, 0xE9, 0xAE, 0x62, 0xCE, 0x4C, 0x57, 0x35, 0x94, 0xD3, 0x6A, 0x8C, 0xDA,
-                0xDC, 0x0B, 0x18, 0x7E, 0xC3, 0xD1, 0xA7, 0x78, 0x89, 0xC9, 0x43, 0xF2, 0x5D, 0x45,
-                0xEB, 0x5C, 0xF3, 0x0B,
-            ],
-            &[
-                0x80, 0x7D, 0x13, 0x3E, 0x09, 0x01, 0x32, 0x9C, 0x50, 0x49, 0xEB, 0xB7, 0x2C, 0xCA,
-                0x9C, 0xA8, 0x64, 0x1C, 0x21, 0x9F, 0x82, 0x08, 0x4B, 0xC6, 0x83, 0xD9, 0x76, 0xD3,
-                0x39, 0x2C, 0x44, 0x29,
-            ],
-        ],
-    ];
-}
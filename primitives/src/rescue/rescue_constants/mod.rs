@@ -1,13 +0,0 @@
-// Copyright (c) 2022 Espresso Systems (espressosys.com)
-// This file is part of the Jellyfish library.
-
-// You should have received a copy of the MIT License
-// along with the Jellyfish library. If not, see <https://mit-license.org/>.
-
-mod bls12_377_base;
-mod bls12_381_base;
-mod bn254_base;
-mod bw6_761_base;
-mod ed_on_bls12_377_base;
-mod ed_on_bls12_381_base;
-mod ed_on_bn254_base;
@@ -0,0 +1,661 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+//! SIMD evaluation of [`Permutation`]s, used by
+//! [`Permutation::eval_batch`].
+//!
+//! AVX2 and NEON have no 64x64-bit multiplication, so a vector holds one
+//! 64-bit lane per permutation and field elements are split into limbs of
+//! [`LIMB_BITS`] bits: the 32x32-bit multiplications of the vectors give the
+//! full products of limbs, and the sums of a multiplication fit in the lanes
+//! without propagating carries. The arithmetic is Montgomery's, for a
+//! Montgomery constant of its own, so elements are converted from and to ark's
+//! form around each evaluation.
+//!
+//! AVX2 is detected at runtime with the `std` feature, and at compile time
+//! otherwise. NEON is used on aarch64 targets which enable it.
+#![cfg_attr(
+    not(any(
+        target_arch = "x86_64",
+        all(target_arch = "aarch64", target_feature = "neon")
+    )),
+    allow(dead_code)
+)]
+
+use super::{Permutation, RescueParameter, RescueVector, BATCH_LANES as LANES, STATE_SIZE};
+use ark_ff::{BigInteger, PrimeField};
+use ark_std::{vec, vec::Vec};
+
+/// The # of bits of the limbs of field elements, small enough that the
+/// products of limbs can be accumulated in 64-bit lanes without carries.
+const LIMB_BITS: u32 = 29;
+
+const LIMB_MASK: u64 = (1 << LIMB_BITS) - 1;
+
+/// Evaluate the permutation on at most [`LANES`] inputs with the SIMD
+/// instructions of the CPU, or return `None` if it has none or if the field is
+/// too large.
+pub(super) fn eval_lanes<F: RescueParameter>(
+    permutation: &Permutation<F>,
+    lanes: &[RescueVector<F>],
+) -> Option<Vec<RescueVector<F>>> {
+    if !has_simd() || lanes.len() > LANES {
+        return None;
+    }
+    // the Montgomery constant must be larger than twice the modulus
+    let num_limbs = (F::MODULUS_BIT_SIZE + LIMB_BITS) / LIMB_BITS;
+    if num_limbs <= 9 {
+        Some(eval::<F, 9>(permutation, lanes, permute::<9>))
+    } else if num_limbs <= 14 {
+        Some(eval::<F, 14>(permutation, lanes, permute::<14>))
+    } else {
+        None
+    }
+}
+
+fn has_simd() -> bool {
+    #[cfg(all(target_arch = "x86_64", feature = "std"))]
+    {
+        std::is_x86_feature_detected!("avx2")
+    }
+    #[cfg(all(target_arch = "x86_64", not(feature = "std")))]
+    {
+        cfg!(target_feature = "avx2")
+    }
+    #[cfg(not(target_arch = "x86_64"))]
+    {
+        cfg!(all(target_arch = "aarch64", target_feature = "neon"))
+    }
+}
+
+/// The limbs of the Montgomery forms of [`LANES`] states, by element of the
+/// state, by limb and by lane.
+type States<const L: usize> = [[[u64; LANES]; L]; STATE_SIZE];
+
+/// A permutation of [`States`], the Montgomery forms of its constants being
+/// given as [`Params`].
+type Permute<const L: usize> = unsafe fn(&Params<L>, &mut States<L>, u64, &[u64]);
+
+// The backend of the CPU, which `has_simd` has detected.
+unsafe fn permute<const L: usize>(
+    params: &Params<L>,
+    states: &mut States<L>,
+    a: u64,
+    a_inv: &[u64],
+) {
+    #[cfg(target_arch = "x86_64")]
+    avx2::permute(params, states, a, a_inv);
+    #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+    neon::permute(params, states, a, a_inv);
+    #[cfg(not(any(
+        target_arch = "x86_64",
+        all(target_arch = "aarch64", target_feature = "neon")
+    )))]
+    {
+        let _ = (params, states, a, a_inv);
+        unreachable!("no SIMD backend")
+    }
+}
+
+fn eval<F: RescueParameter, const L: usize>(
+    permutation: &Permutation<F>,
+    lanes: &[RescueVector<F>],
+    kernel: Permute<L>,
+) -> Vec<RescueVector<F>> {
+    let converter = Converter::<F, L>::new();
+    let params = Params::new(&converter, permutation);
+    let mut states = [[[0; LANES]; L]; STATE_SIZE];
+    for (lane, input) in lanes.iter().enumerate() {
+        for (state, elem) in states.iter_mut().zip(input.vec.iter()) {
+            for (limb, value) in state.iter_mut().zip(converter.limbs(elem)) {
+                limb[lane] = value;
+            }
+        }
+    }
+    // SAFETY: `kernel` runs on the CPU.
+    unsafe { kernel(&params, &mut states, F::A, F::A_INV) };
+    (0..lanes.len())
+        .map(|lane| {
+            let mut output = RescueVector::zero();
+            for (elem, state) in output.vec.iter_mut().zip(states.iter()) {
+                *elem = converter.elem(&state.map(|limb| limb[lane]));
+            }
+            output
+        })
+        .collect()
+}
+
+/// Conversions between field elements and the limbs of their Montgomery
+/// forms, for the Montgomery constant `R = 2^(LIMB_BITS * L)`.
+struct Converter<F, const L: usize> {
+    r: F,
+    r_inv: F,
+}
+
+impl<F: PrimeField, const L: usize> Converter<F, L> {
+    fn new() -> Self {
+        let r = F::from(2u64).pow([(LIMB_BITS as usize * L) as u64]);
+        Self {
+            r,
+            // `R` is a power of 2 and the modulus is odd
+            r_inv: r.inverse().unwrap(),
+        }
+    }
+
+    fn limbs(&self, elem: &F) -> [u64; L] {
+        split_limbs(&(*elem * self.r).into_bigint().to_bytes_le())
+    }
+
+    fn elem(&self, limbs: &[u64; L]) -> F {
+        let mut bytes = vec![0u8; (LIMB_BITS as usize * L + 7) / 8];
+        for (i, limb) in limbs.iter().enumerate() {
+            for bit in 0..LIMB_BITS as usize {
+                let pos = LIMB_BITS as usize * i + bit;
+                bytes[pos / 8] |= (((limb >> bit) & 1) as u8) << (pos % 8);
+            }
+        }
+        F::from_le_bytes_mod_order(&bytes) * self.r_inv
+    }
+}
+
+// Split an integer below `2^(LIMB_BITS * L)`, given by its little-endian bytes.
+fn split_limbs<const L: usize>(bytes: &[u8]) -> [u64; L] {
+    let mut limbs = [0; L];
+    for (i, byte) in bytes.iter().enumerate() {
+        for bit in 0..8 {
+            let pos = 8 * i + bit;
+            if (byte >> bit) & 1 == 1 {
+                limbs[pos / LIMB_BITS as usize] |= 1 << (pos % LIMB_BITS as usize);
+            }
+        }
+    }
+    limbs
+}
+
+/// The modulus in limbs, and the limbs of the Montgomery forms of the
+/// constants of a permutation.
+struct Params<const L: usize> {
+    p: [u64; L],
+    one: [u64; L],
+    mds: [[[u64; L]; STATE_SIZE]; STATE_SIZE],
+    round_keys: Vec<[[u64; L]; STATE_SIZE]>,
+}
+
+impl<const L: usize> Params<L> {
+    fn new<F: RescueParameter>(converter: &Converter<F, L>, permutation: &Permutation<F>) -> Self {
+        let vector = |elems: &RescueVector<F>| elems.vec.map(|elem| converter.limbs(&elem));
+        let mds = permutation.mds_matrix_ref();
+        Self {
+            p: split_limbs(&F::MODULUS.to_bytes_le()),
+            one: converter.limbs(&F::one()),
+            mds: [0, 1, 2, 3].map(|i| vector(&mds.vec(i))),
+            round_keys: permutation.round_keys_ref().iter().map(vector).collect(),
+        }
+    }
+}
+
+// Evaluate the permutation with the vectors `V`, which the CPU must support.
+#[inline(always)]
+unsafe fn permute_with<V: Lanes, const L: usize>(
+    params: &Params<L>,
+    states: &mut States<L>,
+    a: u64,
+    a_inv: &[u64],
+) {
+    let splat = |elem: &[u64; L]| elem.map(|limb| V::splat(limb));
+    let vector = |elems: &[[u64; L]; STATE_SIZE]| elems.map(|elem| splat(&elem));
+    let constants = Constants {
+        modulus: Modulus::new(&params.p),
+        one: splat(&params.one),
+        mds: params.mds.map(|row| vector(&row)),
+        round_keys: params.round_keys.iter().map(vector).collect(),
+    };
+    let mut state = states.map(|elem| elem.map(|limb| V::from_array(limb)));
+    constants.permute(&mut state, a, a_inv);
+    *states = state.map(|elem| elem.map(|limb| limb.to_array()));
+}
+
+/// [`LANES`] 64-bit lanes.
+///
+/// # Safety
+/// The methods may only be called on CPUs supporting the instructions of the
+/// implementation.
+trait Lanes: Copy {
+    unsafe fn splat(x: u64) -> Self;
+    unsafe fn from_array(x: [u64; LANES]) -> Self;
+    unsafe fn to_array(self) -> [u64; LANES];
+    unsafe fn add(self, other: Self) -> Self;
+    unsafe fn sub(self, other: Self) -> Self;
+    // The products of the low 32 bits of the lanes.
+    unsafe fn mul32(self, other: Self) -> Self;
+    // Shift the lanes right by `LIMB_BITS`.
+    unsafe fn shr_limb(self) -> Self;
+    unsafe fn and(self, other: Self) -> Self;
+    // The lanes of `a` where `mask` is all ones, and of `b` where it is zero.
+    unsafe fn select(mask: Self, a: Self, b: Self) -> Self;
+}
+
+/// The modulus `p` of the field in limbs, and `-p^-1 mod 2^LIMB_BITS`. Field
+/// elements are limbs of their Montgomery forms, for a Montgomery constant
+/// `R = 2^(LIMB_BITS * L)` larger than `2p`, and are fully reduced.
+struct Modulus<V, const L: usize> {
+    p: [V; L],
+    n_prime: V,
+}
+
+impl<V: Lanes, const L: usize> Modulus<V, L> {
+    #[inline(always)]
+    unsafe fn new(p: &[u64; L]) -> Self {
+        // Newton iterations for `p^-1 mod 2^32`, `p` being odd
+        let p0 = p[0] as u32;
+        let mut inv = 1u32;
+        for _ in 0..5 {
+            inv = inv.wrapping_mul(2u32.wrapping_sub(p0.wrapping_mul(inv)));
+        }
+        Self {
+            p: p.map(|limb| V::splat(limb)),
+            n_prime: V::splat(inv.wrapping_neg() as u64 & LIMB_MASK),
+        }
+    }
+
+    // Propagate the carries of `t`, whose value is below `2p`, and subtract
+    // `p` if it is at least `p`.
+    #[inline(always)]
+    unsafe fn reduce(&self, mut t: [V; L]) -> [V; L] {
+        let mask = V::splat(LIMB_MASK);
+        let mut carry = V::splat(0);
+        for limb in t.iter_mut() {
+            let sum = limb.add(carry);
+            *limb = sum.and(mask);
+            carry = sum.shr_limb();
+        }
+        let base = V::splat(1 << LIMB_BITS);
+        let one = V::splat(1);
+        let mut diff = [V::splat(0); L];
+        let mut borrow = V::splat(0);
+        for ((diff, t), p) in diff.iter_mut().zip(&t).zip(&self.p) {
+            let d = t.add(base).sub(*p).sub(borrow);
+            *diff = d.and(mask);
+            borrow = one.sub(d.shr_limb());
+        }
+        // keep `t` if the subtraction borrows, i.e. if `t < p`
+        let keep = V::splat(0).sub(borrow);
+        for (diff, t) in diff.iter_mut().zip(&t) {
+            *diff = V::select(keep, *t, *diff);
+        }
+        diff
+    }
+
+    #[inline(always)]
+    unsafe fn add(&self, a: &[V; L], b: &[V; L]) -> [V; L] {
+        let mut t = *a;
+        for (t, b) in t.iter_mut().zip(b) {
+            *t = t.add(*b);
+        }
+        self.reduce(t)
+    }
+
+    // Montgomery multiplication `a * b / R mod p`, by operand scanning with
+    // delayed carries: each of the `L` accumulators receives at most `2L`
+    // products of limbs and a carry, which fit in 64 bits for `L <= 16`.
+    #[inline(always)]
+    unsafe fn mul(&self, a: &[V; L], b: &[V; L]) -> [V; L] {
+        let mask = V::splat(LIMB_MASK);
+        let mut t = [V::splat(0); L];
+        for b_i in b.iter() {
+            for (t, a_j) in t.iter_mut().zip(a) {
+                *t = t.add(a_j.mul32(*b_i));
+            }
+            // only the low bits of `t[0]` matter modulo `2^LIMB_BITS`
+            let m = t[0].mul32(self.n_prime).and(mask);
+            for (t, p_j) in t.iter_mut().zip(&self.p) {
+                *t = t.add(m.mul32(*p_j));
+            }
+            // `t[0]` is now divisible by `2^LIMB_BITS`
+            let carry = t[0].shr_limb();
+            t.copy_within(1.., 0);
+            t[0] = t[0].add(carry);
+            t[L - 1] = V::splat(0);
+        }
+        self.reduce(t)
+    }
+
+    // `a^exp`, with `one` the Montgomery form of `1` and `exp` given by its
+    // little-endian 64-bit limbs.
+    #[inline(always)]
+    unsafe fn pow(&self, a: &[V; L], one: &[V; L], exp: &[u64]) -> [V; L] {
+        let mut res = *one;
+        let mut started = false;
+        for limb in exp.iter().rev() {
+            for bit in (0..64).rev() {
+                if started {
+                    res = self.mul(&res, &res);
+                }
+                if (limb >> bit) & 1 == 1 {
+                    res = self.mul(&res, a);
+                    started = true;
+                }
+            }
+        }
+        res
+    }
+}
+
+/// The constants of a permutation over lanes.
+struct Constants<V, const L: usize> {
+    modulus: Modulus<V, L>,
+    one: [V; L],
+    mds: [[[V; L]; STATE_SIZE]; STATE_SIZE],
+    round_keys: Vec<[[V; L]; STATE_SIZE]>,
+}
+
+impl<V: Lanes, const L: usize> Constants<V, L> {
+    // The rounds of `Permutation::eval`.
+    #[inline(always)]
+    unsafe fn permute(&self, state: &mut [[V; L]; STATE_SIZE], a: u64, a_inv: &[u64]) {
+        let m = &self.modulus;
+        for (x, key) in state.iter_mut().zip(&self.round_keys[0]) {
+            *x = m.add(x, key);
+        }
+        for (round, key) in self.round_keys[1..].iter().enumerate() {
+            for x in state.iter_mut() {
+                *x = if round % 2 == 0 {
+                    m.pow(x, &self.one, a_inv)
+                } else {
+                    m.pow(x, &self.one, &[a])
+                };
+            }
+            let mut next = *key;
+            for (next, row) in next.iter_mut().zip(&self.mds) {
+                for (x, entry) in state.iter().zip(row) {
+                    *next = m.add(next, &m.mul(x, entry));
+                }
+            }
+            *state = next;
+        }
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+mod avx2 {
+    use super::{permute_with, Lanes, Params, States, LANES, LIMB_BITS};
+    use core::arch::x86_64::*;
+
+    #[target_feature(enable = "avx2")]
+    pub(super) unsafe fn permute<const L: usize>(
+        params: &Params<L>,
+        states: &mut States<L>,
+        a: u64,
+        a_inv: &[u64],
+    ) {
+        permute_with::<Avx2, L>(params, states, a, a_inv)
+    }
+
+    #[derive(Clone, Copy)]
+    struct Avx2(__m256i);
+
+    impl Lanes for Avx2 {
+        #[inline(always)]
+        unsafe fn splat(x: u64) -> Self {
+            Self(_mm256_set1_epi64x(x as i64))
+        }
+
+        #[inline(always)]
+        unsafe fn from_array(x: [u64; LANES]) -> Self {
+            Self(_mm256_loadu_si256(x.as_ptr() as *const __m256i))
+        }
+
+        #[inline(always)]
+        unsafe fn to_array(self) -> [u64; LANES] {
+            let mut x = [0; LANES];
+            _mm256_storeu_si256(x.as_mut_ptr() as *mut __m256i, self.0);
+            x
+        }
+
+        #[inline(always)]
+        unsafe fn add(self, other: Self) -> Self {
+            Self(_mm256_add_epi64(self.0, other.0))
+        }
+
+        #[inline(always)]
+        unsafe fn sub(self, other: Self) -> Self {
+            Self(_mm256_sub_epi64(self.0, other.0))
+        }
+
+        #[inline(always)]
+        unsafe fn mul32(self, other: Self) -> Self {
+            Self(_mm256_mul_epu32(self.0, other.0))
+        }
+
+        #[inline(always)]
+        unsafe fn shr_limb(self) -> Self {
+            Self(_mm256_srli_epi64::<{ LIMB_BITS as i32 }>(self.0))
+        }
+
+        #[inline(always)]
+        unsafe fn and(self, other: Self) -> Self {
+            Self(_mm256_and_si256(self.0, other.0))
+        }
+
+        #[inline(always)]
+        unsafe fn select(mask: Self, a: Self, b: Self) -> Self {
+            Self(_mm256_blendv_epi8(b.0, a.0, mask.0))
+        }
+    }
+}
+
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+mod neon {
+    use super::{permute_with, Lanes, Params, States, LANES, LIMB_BITS};
+    use core::arch::aarch64::*;
+
+    pub(super) unsafe fn permute<const L: usize>(
+        params: &Params<L>,
+        states: &mut States<L>,
+        a: u64,
+        a_inv: &[u64],
+    ) {
+        permute_with::<Neon, L>(params, states, a, a_inv)
+    }
+
+    // Two vectors of two lanes.
+    #[derive(Clone, Copy)]
+    struct Neon(uint64x2_t, uint64x2_t);
+
+    impl Lanes for Neon {
+        #[inline(always)]
+        unsafe fn splat(x: u64) -> Self {
+            Self(vdupq_n_u64(x), vdupq_n_u64(x))
+        }
+
+        #[inline(always)]
+        unsafe fn from_array(x: [u64; LANES]) -> Self {
+            Self(vld1q_u64(x.as_ptr()), vld1q_u64(x.as_ptr().add(2)))
+        }
+
+        #[inline(always)]
+        unsafe fn to_array(self) -> [u64; LANES] {
+            let mut x = [0; LANES];
+            vst1q_u64(x.as_mut_ptr(), self.0);
+            vst1q_u64(x.as_mut_ptr().add(2), self.1);
+            x
+        }
+
+        #[inline(always)]
+        unsafe fn add(self, other: Self) -> Self {
+            Self(vaddq_u64(self.0, other.0), vaddq_u64(self.1, other.1))
+        }
+
+        #[inline(always)]
+        unsafe fn sub(self, other: Self) -> Self {
+            Self(vsubq_u64(self.0, other.0), vsubq_u64(self.1, other.1))
+        }
+
+        #[inline(always)]
+        unsafe fn mul32(self, other: Self) -> Self {
+            Self(
+                vmull_u32(vmovn_u64(self.0), vmovn_u64(other.0)),
+                vmull_u32(vmovn_u64(self.1), vmovn_u64(other.1)),
+            )
+        }
+
+        #[inline(always)]
+        unsafe fn shr_limb(self) -> Self {
+            Self(
+                vshrq_n_u64::<{ LIMB_BITS as i32 }>(self.0),
+                vshrq_n_u64::<{ LIMB_BITS as i32 }>(self.1),
+            )
+        }
+
+        #[inline(always)]
+        unsafe fn and(self, other: Self) -> Self {
+            Self(vandq_u64(self.0, other.0), vandq_u64(self.1, other.1))
+        }
+
+        #[inline(always)]
+        unsafe fn select(mask: Self, a: Self, b: Self) -> Self {
+            Self(vbslq_u64(mask.0, a.0, b.0), vbslq_u64(mask.1, a.1, b.1))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        eval, eval_lanes, permute_with, split_limbs, Converter, Lanes, Modulus, Params, States,
+        LANES, LIMB_BITS, LIMB_MASK,
+    };
+    use crate::rescue::{Permutation, RescueParameter, RescueVector, STATE_SIZE};
+    use ark_bls12_377::Fq as Fq377;
+    use ark_bn254::Fq as Fq254;
+    use ark_ed_on_bls12_377::Fq as Fr377;
+    use ark_ed_on_bls12_381::Fq as Fr381;
+    use ark_ed_on_bn254::Fq as Fr254;
+    use ark_ff::{BigInteger, Field, PrimeField};
+    use ark_pallas::{Fq as FqPallas, Fr as FqVesta};
+    use ark_std::{vec::Vec, One, UniformRand};
+
+    // The lanes as an array, to check the kernel on any CPU.
+    #[derive(Clone, Copy)]
+    struct Portable([u64; LANES]);
+
+    impl Lanes for Portable {
+        unsafe fn splat(x: u64) -> Self {
+            Self([x; LANES])
+        }
+
+        unsafe fn from_array(x: [u64; LANES]) -> Self {
+            Self(x)
+        }
+
+        unsafe fn to_array(self) -> [u64; LANES] {
+            self.0
+        }
+
+        unsafe fn add(self, other: Self) -> Self {
+            Self(core::array::from_fn(|i| self.0[i].wrapping_add(other.0[i])))
+        }
+
+        unsafe fn sub(self, other: Self) -> Self {
+            Self(core::array::from_fn(|i| self.0[i].wrapping_sub(other.0[i])))
+        }
+
+        unsafe fn mul32(self, other: Self) -> Self {
+            Self(core::array::from_fn(|i| {
+                (self.0[i] & 0xffff_ffff) * (other.0[i] & 0xffff_ffff)
+            }))
+        }
+
+        unsafe fn shr_limb(self) -> Self {
+            Self(self.0.map(|x| x >> LIMB_BITS))
+        }
+
+        unsafe fn and(self, other: Self) -> Self {
+            Self(core::array::from_fn(|i| self.0[i] & other.0[i]))
+        }
+
+        unsafe fn select(mask: Self, a: Self, b: Self) -> Self {
+            Self(core::array::from_fn(|i| {
+                (a.0[i] & mask.0[i]) | (b.0[i] & !mask.0[i])
+            }))
+        }
+    }
+
+    unsafe fn permute_portable<const L: usize>(
+        params: &Params<L>,
+        states: &mut States<L>,
+        a: u64,
+        a_inv: &[u64],
+    ) {
+        permute_with::<Portable, L>(params, states, a, a_inv)
+    }
+
+    #[test]
+    fn test_eval() {
+        test_eval_helper::<Fq254, 9>();
+        test_eval_helper::<Fr254, 9>();
+        test_eval_helper::<Fr377, 9>();
+        test_eval_helper::<Fr381, 9>();
+        test_eval_helper::<Fq377, 14>();
+        test_eval_helper::<FqPallas, 9>();
+        test_eval_helper::<FqVesta, 9>();
+    }
+
+    fn test_eval_helper<F: RescueParameter, const L: usize>() {
+        assert!(((F::MODULUS_BIT_SIZE + LIMB_BITS) / LIMB_BITS) as usize <= L);
+        let mut prng = jf_utils::test_rng();
+        let permutation = Permutation::<F>::default();
+        // the largest elements exercise the final subtractions
+        let edge = RescueVector {
+            vec: [-F::one(), F::zero(), F::one(), -F::from(2u64)],
+        };
+        for num_inputs in [1, LANES] {
+            let mut inputs: Vec<RescueVector<F>> = (0..num_inputs)
+                .map(|_| RescueVector {
+                    vec: [(); STATE_SIZE].map(|_| F::rand(&mut prng)),
+                })
+                .collect();
+            inputs[0] = edge;
+            let expected: Vec<_> = inputs.iter().map(|input| permutation.eval(input)).collect();
+            assert_eq!(
+                eval::<F, L>(&permutation, &inputs, permute_portable::<L>),
+                expected
+            );
+            if let Some(outputs) = eval_lanes(&permutation, &inputs) {
+                assert_eq!(outputs, expected);
+            }
+        }
+        assert!(eval_lanes(&permutation, &[edge; LANES + 1]).is_none());
+    }
+
+    #[test]
+    fn test_limbs() {
+        let modulus = Modulus::<Portable, 9>::new(&split_limbs(&Fq254::MODULUS.to_bytes_le()));
+        let converter = Converter::<Fq254, 9>::new();
+        let mut prng = jf_utils::test_rng();
+        for _ in 0..10 {
+            let x = Fq254::rand(&mut prng);
+            let y = Fq254::rand(&mut prng);
+            let limbs = converter.limbs(&x);
+            assert!(limbs.iter().all(|limb| *limb <= LIMB_MASK));
+            assert_eq!(converter.elem(&limbs), x);
+            let splat = |elem: &Fq254| {
+                converter
+                    .limbs(elem)
+                    .map(|limb| unsafe { Portable::splat(limb) })
+            };
+            let lanes = |elem: [Portable; 9]| converter.elem(&elem.map(|limb| limb.0[0]));
+            unsafe {
+                assert_eq!(lanes(modulus.mul(&splat(&x), &splat(&y))), x * y);
+                assert_eq!(lanes(modulus.add(&splat(&x), &splat(&y))), x + y);
+                assert_eq!(
+                    lanes(modulus.pow(&splat(&x), &splat(&Fq254::one()), &[5, 3])),
+                    x.pow([5, 3])
+                );
+            }
+        }
+    }
+}
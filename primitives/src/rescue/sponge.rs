@@ -10,34 +10,159 @@ use ark_crypto_primitives::sponge::{
     Absorb, CryptographicSponge, FieldBasedCryptographicSponge, FieldElementSize, SpongeExt,
 };
 use ark_ff::PrimeField;
-use ark_std::{string::ToString, vec, vec::Vec};
-use jf_utils::pad_with_zeros;
+use ark_std::{format, string::ToString, vec, vec::Vec};
+use jf_utils::{bytes_to_field_elements, pad_with_zeros};
+use zeroize::Zeroize;
 
 use super::{
     errors::RescueError, Permutation, RescueParameter, RescueVector, CRHF_RATE, STATE_SIZE,
 };
 
-#[derive(Clone, Default, Debug)]
+/// Rate/capacity split of a rescue sponge over a state of `STATE_SIZE`
+/// elements, and domain separator absorbed by the sponge before any input.
+/// The default is the split of [`RescueCRHF`], i.e. a rate of `CRHF_RATE`,
+/// with an empty domain separator.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct RescueSpongeConfig {
+    rate: usize,
+    domain_separator: &'static [u8],
+}
+
+impl Default for RescueSpongeConfig {
+    fn default() -> Self {
+        Self {
+            rate: CRHF_RATE,
+            domain_separator: &[],
+        }
+    }
+}
+
+impl RescueSpongeConfig {
+    /// Configure a sponge absorbing and squeezing `rate` elements per
+    /// permutation, leaving `STATE_SIZE - rate` elements of capacity.
+    /// * `returns` - Err() if `rate` is 0 or larger than `STATE_SIZE`
+    pub fn new(rate: usize) -> Result<Self, RescueError> {
+        if rate == 0 || rate > STATE_SIZE {
+            return Err(RescueError::ParameterError(format!(
+                "Rescue sponge Error : rate {rate} is not between 1 and {STATE_SIZE}."
+            )));
+        }
+        Ok(Self {
+            rate,
+            domain_separator: &[],
+        })
+    }
+
+    /// The full state configuration, with no capacity, as used by the keyed
+    /// sponge of [`RescuePRF`](crate::prf::RescuePRF).
+    pub fn full_state() -> Self {
+        Self {
+            rate: STATE_SIZE,
+            domain_separator: &[],
+        }
+    }
+
+    /// Same configuration, with an application-level `domain_separator`.
+    ///
+    /// A non-empty domain separator is encoded with
+    /// [`bytes_to_field_elements`], which starts with its length, zero-padded
+    /// to a multiple of the rate and absorbed when the sponge is created, so
+    /// hashes of different domains cannot collide. An empty domain separator
+    /// leaves the sponge unchanged.
+    pub fn with_domain_separator(self, domain_separator: &'static [u8]) -> Self {
+        Self {
+            domain_separator,
+            ..self
+        }
+    }
+
+    /// Return the domain separator.
+    pub fn domain_separator(&self) -> &'static [u8] {
+        self.domain_separator
+    }
+
+    /// The elements absorbed before any input, see
+    /// [`Self::with_domain_separator`].
+    fn domain_separator_elems<F: PrimeField>(&self) -> Vec<F> {
+        let mut elems = bytes_to_field_elements(self.domain_separator);
+        pad_with_zeros(&mut elems, self.rate);
+        elems
+    }
+
+    /// Return the rate.
+    pub fn rate(&self) -> usize {
+        self.rate
+    }
+
+    /// Return the capacity.
+    pub fn capacity(&self) -> usize {
+        STATE_SIZE - self.rate
+    }
+}
+
+#[derive(Clone, Debug)]
 /// A rescue hash function consists of a permutation function and
 /// an internal state.
-struct RescueSponge<F: RescueParameter, const RATE: usize> {
+struct RescueSponge<F: RescueParameter> {
     pub(crate) state: RescueVector<F>,
     pub(crate) permutation: Permutation<F>,
+    pub(crate) config: RescueSpongeConfig,
+}
+
+impl<F: RescueParameter> RescueSponge<F> {
+    /// Create a sponge from its initial `state`, then absorb the domain
+    /// separator of `config`.
+    fn with_config(state: RescueVector<F>, config: RescueSpongeConfig) -> Self {
+        let mut sponge = Self {
+            state,
+            permutation: Permutation::default(),
+            config,
+        };
+        sponge.absorb(&config.domain_separator_elems::<F>());
+        sponge
+    }
 }
 
 /// CRHF
 #[derive(Debug, Clone)]
 pub struct RescueCRHF<F: RescueParameter> {
-    sponge: RescueSponge<F, CRHF_RATE>,
+    sponge: RescueSponge<F>,
 }
 
 /// PRF
 #[derive(Debug, Clone)]
 pub(crate) struct RescuePRFCore<F: RescueParameter> {
-    sponge: RescueSponge<F, STATE_SIZE>,
+    sponge: RescueSponge<F>,
+}
+
+impl<F: RescueParameter> Default for RescueCRHF<F> {
+    fn default() -> Self {
+        Self {
+            sponge: RescueSponge::with_config(RescueVector::zero(), RescueSpongeConfig::default()),
+        }
+    }
 }
 
 impl<F: RescueParameter> RescueCRHF<F> {
+    /// Sponge hashing based on rescue permutation with the rate/capacity split
+    /// of `config`.
+    /// * `returns` - Err() if `config` has no capacity
+    pub fn new(config: RescueSpongeConfig) -> Result<Self, RescueError> {
+        if config.capacity() == 0 {
+            return Err(RescueError::ParameterError(
+                "Rescue sponge Error : a CRHF requires a non-zero capacity.".to_string(),
+            ));
+        }
+        Ok(Self {
+            sponge: RescueSponge::with_config(RescueVector::zero(), config),
+        })
+    }
+
+    /// Return the rate/capacity split of this CRHF.
+    pub fn config(&self) -> RescueSpongeConfig {
+        self.sponge.config
+    }
+
     /// Sponge hashing based on rescue permutation for RATE 3. It allows
     /// unrestricted variable length input and returns a vector of
     /// `num_outputs` elements.
@@ -48,11 +173,7 @@ impl<F: RescueParameter> RescueCRHF<F> {
     ///
     /// [padding]: https://en.wikipedia.org/wiki/Padding_(cryptography)#Bit_padding
     pub fn sponge_with_bit_padding(input: &[F], num_outputs: usize) -> Vec<F> {
-        let mut padded = input.to_vec();
-        padded.push(F::one());
-        pad_with_zeros(&mut padded, CRHF_RATE);
-        Self::sponge_no_padding(padded.as_slice(), num_outputs)
-            .expect("Bug in JF Primitives : bad padding of input for FSKS construction")
+        Self::default().hash_with_bit_padding(input, num_outputs)
     }
 
     /// Similar to [`RescueCRHF::sponge_with_bit_padding`] except we use ["zero
@@ -61,30 +182,216 @@ impl<F: RescueParameter> RescueCRHF<F> {
     ///
     /// [padding]: https://en.wikipedia.org/wiki/Padding_(cryptography)#Zero_padding
     pub fn sponge_with_zero_padding(input: &[F], num_outputs: usize) -> Vec<F> {
-        let mut padded = input.to_vec();
-        pad_with_zeros(&mut padded, CRHF_RATE);
-        Self::sponge_no_padding(padded.as_slice(), num_outputs)
-            .expect("Bug in JF Primitives : bad padding of input for FSKS construction")
+        Self::default().hash_with_zero_padding(input, num_outputs)
     }
 
     /// Sponge hashing based on rescue permutation for RATE 3 and CAPACITY 1. It
     /// allows inputs with length that is a multiple of `CRHF_RATE` and
     /// returns a vector of `num_outputs` elements.
     pub fn sponge_no_padding(input: &[F], num_output: usize) -> Result<Vec<F>, RescueError> {
-        if input.len() % CRHF_RATE != 0 {
+        Self::default().hash_no_padding(input, num_output)
+    }
+
+    /// Same as [`RescueCRHF::sponge_with_bit_padding`], padding `input` to a
+    /// multiple of the rate of this CRHF.
+    pub fn hash_with_bit_padding(&self, input: &[F], num_outputs: usize) -> Vec<F> {
+        let mut padded = input.to_vec();
+        padded.push(F::one());
+        pad_with_zeros(&mut padded, self.sponge.config.rate());
+        self.hash_no_padding(padded.as_slice(), num_outputs)
+            .expect("Bug in JF Primitives : bad padding of input for FSKS construction")
+    }
+
+    /// Same as [`RescueCRHF::sponge_with_zero_padding`], padding `input` to a
+    /// multiple of the rate of this CRHF.
+    pub fn hash_with_zero_padding(&self, input: &[F], num_outputs: usize) -> Vec<F> {
+        let mut padded = input.to_vec();
+        pad_with_zeros(&mut padded, self.sponge.config.rate());
+        self.hash_no_padding(padded.as_slice(), num_outputs)
+            .expect("Bug in JF Primitives : bad padding of input for FSKS construction")
+    }
+
+    /// Same as [`RescueCRHF::sponge_no_padding`] for inputs with length that
+    /// is a multiple of the rate of this CRHF.
+    pub fn hash_no_padding(&self, input: &[F], num_output: usize) -> Result<Vec<F>, RescueError> {
+        if input.len() % self.sponge.config.rate() != 0 {
             return Err(RescueError::ParameterError(
                 "Rescue sponge Error : input to sponge hashing function is not multiple of RATE."
                     .to_string(),
             ));
         }
         // ABSORB PHASE
-        let mut r = Self {
-            sponge: RescueSponge::from_state(RescueVector::zero(), &Permutation::default()),
-        };
-        r.sponge.absorb(&input);
+        let mut sponge = self.sponge.clone();
+        sponge.absorb(&input);
+
+        // SQUEEZE PHASE
+        Ok(sponge.squeeze_native_field_elements(num_output))
+    }
+
+    /// Same as [`RescueCRHF::sponge_no_padding`] on each of `inputs`, with
+    /// all the sponges permuted together through [`Permutation::eval_batch`].
+    /// Inputs may have different lengths, as long as they are multiples of
+    /// `CRHF_RATE`.
+    pub fn batch_sponge_no_padding(
+        inputs: &[&[F]],
+        num_outputs: usize,
+    ) -> Result<Vec<Vec<F>>, RescueError> {
+        Self::default().batch_hash_no_padding(inputs, num_outputs)
+    }
+
+    /// Same as [`RescueCRHF::hash_no_padding`] on each of `inputs`, with all
+    /// the sponges permuted together through [`Permutation::eval_batch`].
+    pub fn batch_hash_no_padding(
+        &self,
+        inputs: &[&[F]],
+        num_outputs: usize,
+    ) -> Result<Vec<Vec<F>>, RescueError> {
+        let rate = self.sponge.config.rate();
+        if inputs.iter().any(|input| input.len() % rate != 0) {
+            return Err(RescueError::ParameterError(
+                "Rescue sponge Error : input to sponge hashing function is not multiple of RATE."
+                    .to_string(),
+            ));
+        }
+        let permutation = &self.sponge.permutation;
+        let mut states = vec![self.sponge.state; inputs.len()];
+
+        // ABSORB PHASE
+        let num_blocks = inputs.iter().map(|input| input.len() / rate).max();
+        for block in 0..num_blocks.unwrap_or(0) {
+            let (indices, absorbed): (Vec<usize>, Vec<RescueVector<F>>) = inputs
+                .iter()
+                .enumerate()
+                .filter_map(|(i, input)| {
+                    let chunk = input.get(block * rate..(block + 1) * rate)?;
+                    let mut state = states[i];
+                    state.add_assign_elems(chunk);
+                    Some((i, state))
+                })
+                .unzip();
+            for (i, state) in indices.into_iter().zip(permutation.eval_batch(&absorbed)) {
+                states[i] = state;
+            }
+        }
 
         // SQUEEZE PHASE
-        Ok(r.sponge.squeeze_native_field_elements(num_output))
+        let mut outputs = vec![Vec::with_capacity(num_outputs); inputs.len()];
+        let mut remaining = num_outputs;
+        loop {
+            let extract = remaining.min(rate);
+            for (output, state) in outputs.iter_mut().zip(&states) {
+                output.extend_from_slice(&state.vec[..extract]);
+            }
+            remaining -= extract;
+            if remaining == 0 {
+                break;
+            }
+            states = permutation.eval_batch(&states);
+        }
+        Ok(outputs)
+    }
+}
+
+/// Where the next element of a [`RescueStreamingSponge`] is absorbed into or
+/// squeezed from.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum StreamingMode {
+    Absorbing(usize),
+    Squeezing(usize),
+}
+
+/// Incremental rescue sponge: inputs are absorbed and outputs squeezed in as
+/// many calls as needed, without buffering the whole input.
+///
+/// Absorbing the concatenation of several inputs in separate calls is the
+/// same as absorbing it at once, and so is squeezing. Hashing an input of
+/// length multiple of the rate gives the same output as
+/// [`RescueCRHF::hash_no_padding`], and
+/// [`RescueCRHF::hash_with_zero_padding`] otherwise. Absorbing after a
+/// squeeze keeps the sponge going, so it can be used as a transcript.
+#[derive(Clone, Debug)]
+pub struct RescueStreamingSponge<F: RescueParameter> {
+    state: RescueVector<F>,
+    permutation: Permutation<F>,
+    config: RescueSpongeConfig,
+    mode: StreamingMode,
+}
+
+impl<F: RescueParameter> Default for RescueStreamingSponge<F> {
+    fn default() -> Self {
+        Self {
+            state: RescueVector::zero(),
+            permutation: Permutation::default(),
+            config: RescueSpongeConfig::default(),
+            mode: StreamingMode::Absorbing(0),
+        }
+    }
+}
+
+impl<F: RescueParameter> RescueStreamingSponge<F> {
+    /// Create a sponge with the rate/capacity split of `config`, which has
+    /// absorbed nothing but the domain separator of `config`.
+    /// * `returns` - Err() if `config` has no capacity
+    pub fn new(config: RescueSpongeConfig) -> Result<Self, RescueError> {
+        if config.capacity() == 0 {
+            return Err(RescueError::ParameterError(
+                "Rescue sponge Error : a streaming sponge requires a non-zero capacity."
+                    .to_string(),
+            ));
+        }
+        let mut sponge = Self {
+            config,
+            ..Self::default()
+        };
+        sponge.absorb(&config.domain_separator_elems::<F>());
+        Ok(sponge)
+    }
+
+    /// Return the rate/capacity split of this sponge.
+    pub fn config(&self) -> RescueSpongeConfig {
+        self.config
+    }
+
+    /// Absorb `elems` into the sponge. The permutation is applied every time
+    /// the rate is filled.
+    pub fn absorb(&mut self, elems: &[F]) {
+        let mut next = match self.mode {
+            StreamingMode::Absorbing(next) => next,
+            StreamingMode::Squeezing(_) => 0,
+        };
+        for elem in elems {
+            self.state.vec[next] += elem;
+            next += 1;
+            if next == self.config.rate() {
+                self.state = self.permutation.eval(&self.state);
+                next = 0;
+            }
+        }
+        self.mode = StreamingMode::Absorbing(next);
+    }
+
+    /// Squeeze `num_outputs` elements from the sponge. A partially filled
+    /// rate is permuted first, as if it was padded with zeros.
+    pub fn squeeze(&mut self, num_outputs: usize) -> Vec<F> {
+        let mut next = match self.mode {
+            StreamingMode::Absorbing(0) => 0,
+            StreamingMode::Absorbing(_) => {
+                self.state = self.permutation.eval(&self.state);
+                0
+            },
+            StreamingMode::Squeezing(next) => next,
+        };
+        let mut result = Vec::with_capacity(num_outputs);
+        for _ in 0..num_outputs {
+            if next == self.config.rate() {
+                self.state = self.permutation.eval(&self.state);
+                next = 0;
+            }
+            result.push(self.state.vec[next]);
+            next += 1;
+        }
+        self.mode = StreamingMode::Squeezing(next);
+        result
     }
 }
 
@@ -110,32 +417,61 @@ impl<F: RescueParameter> RescuePRFCore<F> {
         input: &[F],
         num_outputs: usize,
     ) -> Result<Vec<F>, RescueError> {
-        if input.len() % STATE_SIZE != 0 {
+        Self::keyed_sponge_no_padding(RescueSpongeConfig::full_state(), key, input, num_outputs)
+    }
+
+    /// Similar to [`Self::keyed_sponge_no_padding`] except the input is padded
+    /// with "0" until its length is a multiple of the rate of `config`.
+    pub(crate) fn keyed_sponge_with_zero_padding(
+        config: RescueSpongeConfig,
+        key: &F,
+        input: &[F],
+        num_outputs: usize,
+    ) -> Result<Vec<F>, RescueError> {
+        let mut padded = input.to_vec();
+        pad_with_zeros(&mut padded, config.rate());
+        Self::keyed_sponge_no_padding(config, key, padded.as_slice(), num_outputs)
+    }
+
+    /// Pseudorandom function based on rescue permutation, with the key in the
+    /// last element of the initial state and the rate/capacity split of
+    /// `config`. It allows inputs with length that is a multiple of the rate
+    /// and returns a vector of `num_outputs` elements.
+    pub(crate) fn keyed_sponge_no_padding(
+        config: RescueSpongeConfig,
+        key: &F,
+        input: &[F],
+        num_outputs: usize,
+    ) -> Result<Vec<F>, RescueError> {
+        if input.len() % config.rate() != 0 {
             return Err(RescueError::ParameterError(
-                "Rescue FSKS PRF Error: input to prf function is not multiple of STATE_SIZE."
-                    .to_string(),
+                "Rescue FSKS PRF Error: input to prf function is not multiple of RATE.".to_string(),
             ));
         }
         // ABSORB PHASE
         let mut state = RescueVector::zero();
         state.vec[STATE_SIZE - 1] = *key;
         let mut r = Self {
-            sponge: RescueSponge::from_state(state, &Permutation::default()),
+            sponge: RescueSponge::with_config(state, config),
         };
         r.sponge.absorb(&input);
 
         // SQUEEZE PHASE
-        Ok(r.sponge.squeeze_native_field_elements(num_outputs))
+        let output = r.sponge.squeeze_native_field_elements(num_outputs);
+        // the permutation is invertible, so that the final state reveals the key
+        r.sponge.state.zeroize();
+        Ok(output)
     }
 }
 
-impl<F: RescueParameter, const RATE: usize> SpongeExt for RescueSponge<F, RATE> {
+impl<F: RescueParameter> SpongeExt for RescueSponge<F> {
     type State = RescueVector<F>;
 
     fn from_state(state: Self::State, permutation: &Self::Config) -> Self {
         Self {
             state,
             permutation: permutation.clone(),
+            config: RescueSpongeConfig::default(),
         }
     }
 
@@ -144,9 +480,7 @@ impl<F: RescueParameter, const RATE: usize> SpongeExt for RescueSponge<F, RATE>
     }
 }
 
-impl<T: RescueParameter + PrimeField, const RATE: usize> CryptographicSponge
-    for RescueSponge<T, RATE>
-{
+impl<T: RescueParameter + PrimeField> CryptographicSponge for RescueSponge<T> {
     /// Config used by the sponge.
     type Config = Permutation<T>;
 
@@ -155,20 +489,23 @@ impl<T: RescueParameter + PrimeField, const RATE: usize> CryptographicSponge
         Self {
             state: RescueVector::default(),
             permutation: permutation.clone(),
+            config: RescueSpongeConfig::default(),
         }
     }
 
     /// Absorb an input into the sponge.
-    /// This function will absorb the entire input, in chunks of `RATE`,
-    /// even if the input length is not a multiple of `RATE`.
+    /// This function will absorb the entire input, in chunks of the rate,
+    /// even if the input length is not a multiple of the rate.
     fn absorb(&mut self, input: &impl Absorb) {
         let input_field_elements = input.to_sponge_field_elements_as_vec();
 
         // Absorb input.
-        input_field_elements.chunks(RATE).for_each(|chunk| {
-            self.state.add_assign_elems(chunk);
-            self.state = self.permutation.eval(&self.state)
-        });
+        input_field_elements
+            .chunks(self.config.rate())
+            .for_each(|chunk| {
+                self.state.add_assign_elems(chunk);
+                self.state = self.permutation.eval(&self.state)
+            });
     }
 
     /// WARNING! This trait method is unimplemented and should not be used.
@@ -212,9 +549,7 @@ impl<T: RescueParameter + PrimeField, const RATE: usize> CryptographicSponge
 
 /// The interface for field-based cryptographic sponge.
 /// `T` is the native field used by the cryptographic sponge implementation.
-impl<T: RescueParameter, const RATE: usize> FieldBasedCryptographicSponge<T>
-    for RescueSponge<T, RATE>
-{
+impl<T: RescueParameter> FieldBasedCryptographicSponge<T> for RescueSponge<T> {
     /// Squeeze `num_elements` field elements from the sponge.
     fn squeeze_native_field_elements(&mut self, num_elements: usize) -> Vec<T> {
         // SQUEEZE PHASE
@@ -222,7 +557,7 @@ impl<T: RescueParameter, const RATE: usize> FieldBasedCryptographicSponge<T>
         let mut remaining = num_elements;
         // extract current rate before calling PRP again
         loop {
-            let extract = remaining.min(RATE);
+            let extract = remaining.min(self.config.rate());
             result.extend_from_slice(&self.state.vec[0..extract]);
             remaining -= extract;
             if remaining == 0 {
@@ -243,11 +578,12 @@ impl<T: RescueParameter, const RATE: usize> FieldBasedCryptographicSponge<T>
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::prf::{RescuePRF, PRF};
     use ark_bls12_381::Fr;
     use ark_crypto_primitives::{
         absorb, collect_sponge_bytes, collect_sponge_field_elements, sponge::AbsorbWithLength,
     };
-    use ark_ff::{One, UniformRand};
+    use ark_ff::{One, UniformRand, Zero};
     use jf_utils::test_rng;
 
     fn assert_different_encodings<F: RescueParameter, A: Absorb>(a: &A, b: &A) {
@@ -256,8 +592,8 @@ mod test {
         assert_ne!(bytes1, bytes2);
 
         let sponge_param = Permutation::default();
-        let mut sponge1 = RescueSponge::<F, 3>::new(&sponge_param);
-        let mut sponge2 = RescueSponge::<F, 3>::new(&sponge_param);
+        let mut sponge1 = RescueSponge::<F>::new(&sponge_param);
+        let mut sponge2 = RescueSponge::<F>::new(&sponge_param);
 
         sponge1.absorb(&a);
         sponge2.absorb(&b);
@@ -268,6 +604,223 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_sponge_config() {
+        assert!(RescueSpongeConfig::new(0).is_err());
+        assert!(RescueSpongeConfig::new(STATE_SIZE + 1).is_err());
+        let config = RescueSpongeConfig::new(2).unwrap();
+        assert_eq!((config.rate(), config.capacity()), (2, 2));
+        assert_eq!(RescueSpongeConfig::default().rate(), CRHF_RATE);
+        assert_eq!(RescueSpongeConfig::full_state().capacity(), 0);
+
+        // The CRHF requires some capacity, and defaults to RATE 3
+        assert!(RescueCRHF::<Fr>::new(RescueSpongeConfig::full_state()).is_err());
+        let mut rng = test_rng();
+        let input: Vec<Fr> = (0..6).map(|_| Fr::rand(&mut rng)).collect();
+        let crhf = RescueCRHF::<Fr>::new(RescueSpongeConfig::default()).unwrap();
+        assert_eq!(
+            crhf.hash_no_padding(&input, 2).unwrap(),
+            RescueCRHF::sponge_no_padding(&input, 2).unwrap()
+        );
+
+        // With RATE 2, the CRHF absorbs and squeezes 2 elements per permutation
+        let crhf = RescueCRHF::<Fr>::new(config).unwrap();
+        assert_eq!(crhf.config(), config);
+        let permutation = Permutation::<Fr>::default();
+        let mut state = RescueVector::zero();
+        for chunk in input.chunks(2) {
+            state.add_assign_elems(chunk);
+            state = permutation.eval(&state);
+        }
+        let mut expected = state.vec[..2].to_vec();
+        expected.push(permutation.eval(&state).vec[0]);
+        assert_eq!(crhf.hash_no_padding(&input, 3).unwrap(), expected);
+        assert_ne!(
+            crhf.hash_no_padding(&input, 3).unwrap(),
+            RescueCRHF::sponge_no_padding(&input, 3).unwrap()
+        );
+        assert!(crhf.hash_no_padding(&input[..3], 1).is_err());
+        assert_eq!(
+            crhf.hash_with_zero_padding(&input[..3], 1),
+            crhf.hash_no_padding(&[input[0], input[1], input[2], Fr::zero()], 1)
+                .unwrap()
+        );
+        assert_eq!(
+            crhf.hash_with_bit_padding(&input[..2], 1),
+            crhf.hash_no_padding(&[input[0], input[1], Fr::one(), Fr::zero()], 1)
+                .unwrap()
+        );
+
+        // The keyed sponge of the PRF follows the rate as well
+        let key = Fr::rand(&mut rng);
+        assert_eq!(
+            RescuePRFCore::keyed_sponge_no_padding(
+                RescueSpongeConfig::full_state(),
+                &key,
+                &input[..4],
+                2
+            )
+            .unwrap(),
+            RescuePRFCore::full_state_keyed_sponge_no_padding(&key, &input[..4], 2).unwrap()
+        );
+        let mut state = RescueVector::zero();
+        state.vec[STATE_SIZE - 1] = key;
+        for chunk in input.chunks(2) {
+            state.add_assign_elems(chunk);
+            state = permutation.eval(&state);
+        }
+        assert_eq!(
+            RescuePRFCore::keyed_sponge_no_padding(config, &key, &input, 2).unwrap(),
+            state.vec[..2]
+        );
+        assert!(RescuePRFCore::keyed_sponge_no_padding(config, &key, &input[..3], 1).is_err());
+        assert_eq!(
+            RescuePRF::<Fr, 3, 2>::evaluate_with_config(
+                config,
+                key,
+                [input[0], input[1], input[2]]
+            )
+            .unwrap()
+            .to_vec(),
+            RescuePRFCore::keyed_sponge_no_padding(
+                config,
+                &key,
+                &[input[0], input[1], input[2], Fr::zero()],
+                2
+            )
+            .unwrap()
+        );
+        assert_eq!(
+            RescuePRF::<Fr, 3, 2>::evaluate_with_config(
+                RescueSpongeConfig::full_state(),
+                key,
+                [input[0], input[1], input[2]]
+            )
+            .unwrap(),
+            RescuePRF::<Fr, 3, 2>::evaluate(key, [input[0], input[1], input[2]]).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_domain_separator() {
+        let mut rng = test_rng();
+        let input: Vec<Fr> = (0..6).map(|_| Fr::rand(&mut rng)).collect();
+        let config = RescueSpongeConfig::default().with_domain_separator(b"jellyfish test");
+        assert_eq!(config.domain_separator(), b"jellyfish test");
+        assert_eq!(config.rate(), CRHF_RATE);
+
+        // An empty domain separator is the same as none
+        let crhf = RescueCRHF::<Fr>::new(RescueSpongeConfig::default().with_domain_separator(b""))
+            .unwrap();
+        assert_eq!(
+            crhf.hash_no_padding(&input, 2).unwrap(),
+            RescueCRHF::sponge_no_padding(&input, 2).unwrap()
+        );
+
+        // The domain separator is absorbed before the input
+        let crhf = RescueCRHF::<Fr>::new(config).unwrap();
+        let output = crhf.hash_no_padding(&input, 2).unwrap();
+        let mut prefixed = bytes_to_field_elements::<_, Fr>(&b"jellyfish test"[..]);
+        pad_with_zeros(&mut prefixed, CRHF_RATE);
+        prefixed.extend_from_slice(&input);
+        assert_eq!(output, RescueCRHF::sponge_no_padding(&prefixed, 2).unwrap());
+        assert_ne!(output, RescueCRHF::sponge_no_padding(&input, 2).unwrap());
+        let other = RescueCRHF::<Fr>::new(config.with_domain_separator(b"jellyfish test2"))
+            .unwrap()
+            .hash_no_padding(&input, 2)
+            .unwrap();
+        assert_ne!(output, other);
+        assert_eq!(
+            crhf.batch_hash_no_padding(&[&input[..]], 2).unwrap(),
+            vec![output.clone()]
+        );
+
+        let mut sponge = RescueStreamingSponge::<Fr>::new(config).unwrap();
+        sponge.absorb(&input);
+        assert_eq!(sponge.squeeze(2), output);
+
+        // The keyed sponge absorbs it as well
+        let key = Fr::rand(&mut rng);
+        let config = RescueSpongeConfig::full_state();
+        assert_ne!(
+            RescuePRFCore::keyed_sponge_no_padding(
+                config.with_domain_separator(b"jellyfish test"),
+                &key,
+                &input[..4],
+                1
+            )
+            .unwrap(),
+            RescuePRFCore::keyed_sponge_no_padding(config, &key, &input[..4], 1).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_streaming_sponge() {
+        let mut rng = test_rng();
+        let input: Vec<Fr> = (0..8).map(|_| Fr::rand(&mut rng)).collect();
+        assert!(RescueStreamingSponge::<Fr>::new(RescueSpongeConfig::full_state()).is_err());
+
+        for rate in 1..STATE_SIZE {
+            let config = RescueSpongeConfig::new(rate).unwrap();
+            let crhf = RescueCRHF::<Fr>::new(config).unwrap();
+            let expected = crhf.hash_with_zero_padding(&input, 7);
+
+            // Absorbing and squeezing in pieces matches the one-shot hash
+            let mut sponge = RescueStreamingSponge::<Fr>::new(config).unwrap();
+            assert_eq!(sponge.config(), config);
+            sponge.absorb(&[]);
+            sponge.absorb(&input[..3]);
+            sponge.absorb(&input[3..]);
+            let mut output = sponge.squeeze(0);
+            output.extend(sponge.squeeze(rate));
+            output.extend(sponge.squeeze(7 - rate));
+            assert_eq!(output, expected);
+
+            let mut sponge = RescueStreamingSponge::<Fr>::new(config).unwrap();
+            input.iter().for_each(|elem| sponge.absorb(&[*elem]));
+            assert_eq!(sponge.squeeze(7), expected);
+
+            // Absorbing after squeezing changes the next outputs
+            let mut sponge = RescueStreamingSponge::<Fr>::new(config).unwrap();
+            sponge.absorb(&input);
+            let first = sponge.squeeze(2);
+            let mut other = sponge.clone();
+            sponge.absorb(&input[..1]);
+            other.absorb(&input[1..2]);
+            assert_eq!(first, expected[..2]);
+            assert_ne!(sponge.squeeze(2), other.squeeze(2));
+        }
+
+        let mut sponge = RescueStreamingSponge::<Fr>::default();
+        sponge.absorb(&input[..6]);
+        assert_eq!(
+            sponge.squeeze(4),
+            RescueCRHF::sponge_no_padding(&input[..6], 4).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_batch_sponge() {
+        let mut rng = test_rng();
+        let input: Vec<Fr> = (0..12).map(|_| Fr::rand(&mut rng)).collect();
+        let inputs = [&input[..], &input[3..9], &[], &input[9..], &input[..6]];
+        let outputs = RescueCRHF::batch_sponge_no_padding(&inputs, 5).unwrap();
+        assert_eq!(outputs.len(), inputs.len());
+        for (input, output) in inputs.iter().zip(&outputs) {
+            assert_eq!(output, &RescueCRHF::sponge_no_padding(input, 5).unwrap());
+        }
+        assert!(RescueCRHF::batch_sponge_no_padding(&[], 5)
+            .unwrap()
+            .is_empty());
+        assert!(RescueCRHF::batch_sponge_no_padding(&[&input[..3], &input[..4]], 1).is_err());
+
+        let crhf = RescueCRHF::<Fr>::new(RescueSpongeConfig::new(2).unwrap()).unwrap();
+        let outputs = crhf.batch_hash_no_padding(&inputs[..3], 3).unwrap();
+        for (input, output) in inputs.iter().zip(outputs) {
+            assert_eq!(output, crhf.hash_no_padding(input, 3).unwrap());
+        }
+    }
+
     #[test]
     fn single_field_element() {
         let mut rng = test_rng();
@@ -316,11 +869,11 @@ mod test {
     #[test]
     fn test_macros() {
         let sponge_param = Permutation::default();
-        let mut sponge1 = RescueSponge::<Fr, 3>::new(&sponge_param);
+        let mut sponge1 = RescueSponge::<Fr>::new(&sponge_param);
         sponge1.absorb(&vec![1u8, 2, 3, 4, 5, 6]);
         sponge1.absorb(&Fr::from(114514u128));
 
-        let mut sponge2 = RescueSponge::<Fr, 3>::new(&sponge_param);
+        let mut sponge2 = RescueSponge::<Fr>::new(&sponge_param);
         absorb!(&mut sponge2, vec![1u8, 2, 3, 4, 5, 6], Fr::from(114514u128));
 
         let expected = sponge1.squeeze_native_field_elements(3);
@@ -0,0 +1,483 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+//! The [SAFE](https://eprint.iacr.org/2023/522.pdf) (Sponge API for Field
+//! Elements) calling convention, over the Rescue and Poseidon permutations.
+//!
+//! A protocol declares upfront the sequence of absorb and squeeze calls it
+//! makes, its [`IOPattern`]. The pattern and an application-level domain
+//! separator are hashed into a tag which initializes the capacity, so sponges
+//! used for different patterns or domains are independent. Every call to a
+//! [`SafeSponge`] is checked against the pattern, and
+//! [`SafeSponge::finish`] checks that it was followed to the end: any
+//! deviation is an error and erases the state.
+//!
+//! Following the specification:
+//! - consecutive calls of the same kind are aggregated, both in the declared
+//!   pattern and in the actual calls, so `absorb(1); absorb(2)` matches a
+//!   pattern `Absorb(3)`;
+//! - the pattern is encoded as 32-bit big-endian words, `0x80000000 | n` for
+//!   `Absorb(n)` and `n` for `Squeeze(n)`, followed by the domain separator,
+//!   and the tag is the first 128 bits of its SHA3-256 hash, read as a
+//!   little-endian integer;
+//! - the permutation is applied lazily, before absorbing into a full rate and
+//!   before squeezing, so there is no padding.
+
+use crate::{
+    errors::PrimitivesError,
+    poseidon::PoseidonConstants,
+    rescue::{
+        prime::RescuePrimePermutation, Permutation, RescueParameter, RescueVector, CRHF_RATE,
+        STATE_SIZE,
+    },
+};
+use ark_ff::PrimeField;
+use ark_std::{format, ops::Range, string::ToString, vec, vec::Vec};
+use sha3::{Digest, Sha3_256};
+
+/// The bit flagging an absorb call in the encoding of an [`IOPattern`].
+const ABSORB_FLAG: u32 = 1 << 31;
+
+/// A call of an [`IOPattern`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SpongeOp {
+    /// Absorb this # of elements.
+    Absorb(u32),
+    /// Squeeze this # of elements.
+    Squeeze(u32),
+}
+
+impl SpongeOp {
+    fn len(&self) -> u32 {
+        match self {
+            SpongeOp::Absorb(len) | SpongeOp::Squeeze(len) => *len,
+        }
+    }
+
+    fn is_absorb(&self) -> bool {
+        matches!(self, SpongeOp::Absorb(_))
+    }
+}
+
+/// The sequence of calls made to a [`SafeSponge`], with consecutive calls of
+/// the same kind aggregated.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct IOPattern {
+    ops: Vec<SpongeOp>,
+}
+
+impl IOPattern {
+    /// Aggregate `ops` into a pattern.
+    /// * `returns` - Err() if a call is empty, or if an aggregated call has
+    ///   `2^31` elements or more
+    pub fn new(ops: &[SpongeOp]) -> Result<Self, PrimitivesError> {
+        let mut aggregated: Vec<SpongeOp> = Vec::with_capacity(ops.len());
+        for op in ops {
+            if op.len() == 0 {
+                return Err(PrimitivesError::ParameterError(
+                    "SAFE sponge Error : empty call in the IO pattern.".to_string(),
+                ));
+            }
+            let merged = match (aggregated.last(), op) {
+                (Some(SpongeOp::Absorb(prev)), SpongeOp::Absorb(len)) => {
+                    Some(SpongeOp::Absorb(prev.saturating_add(*len)))
+                },
+                (Some(SpongeOp::Squeeze(prev)), SpongeOp::Squeeze(len)) => {
+                    Some(SpongeOp::Squeeze(prev.saturating_add(*len)))
+                },
+                _ => None,
+            };
+            match merged {
+                Some(merged) => *aggregated.last_mut().expect("a last call was merged") = merged,
+                None => aggregated.push(*op),
+            }
+        }
+        if let Some(op) = aggregated.iter().find(|op| op.len() >= ABSORB_FLAG) {
+            return Err(PrimitivesError::ParameterError(format!(
+                "SAFE sponge Error : {:?} is too long, calls must have less than 2^31 elements.",
+                op
+            )));
+        }
+        Ok(Self { ops: aggregated })
+    }
+
+    /// Return the aggregated calls.
+    pub fn ops(&self) -> &[SpongeOp] {
+        &self.ops
+    }
+
+    /// The tag of this pattern in the domain of `domain_separator`, see the
+    /// [module documentation](self).
+    pub fn tag<F: PrimeField>(&self, domain_separator: &[u8]) -> F {
+        let mut hasher = Sha3_256::new();
+        for op in self.ops.iter() {
+            let word = match op {
+                SpongeOp::Absorb(len) => ABSORB_FLAG | len,
+                SpongeOp::Squeeze(len) => *len,
+            };
+            hasher.update(word.to_be_bytes());
+        }
+        hasher.update(domain_separator);
+        F::from_le_bytes_mod_order(&hasher.finalize()[..16])
+    }
+}
+
+/// A permutation over which a [`SafeSponge`] can be instantiated.
+pub trait SafePermutation<F: PrimeField> {
+    /// Return the # of elements of the state.
+    fn state_size(&self) -> usize;
+
+    /// Return the elements of the state that are absorbed into and squeezed
+    /// from.
+    fn rate_range(&self) -> Range<usize>;
+
+    /// Return the index of the capacity element initialized with the tag.
+    fn tag_index(&self) -> usize;
+
+    /// Apply the permutation on `state`, of `self.state_size()` elements.
+    fn permute(&self, state: &mut [F]);
+}
+
+impl<F: PrimeField, P: SafePermutation<F>> SafePermutation<F> for &P {
+    fn state_size(&self) -> usize {
+        (**self).state_size()
+    }
+
+    fn rate_range(&self) -> Range<usize> {
+        (**self).rate_range()
+    }
+
+    fn tag_index(&self) -> usize {
+        (**self).tag_index()
+    }
+
+    fn permute(&self, state: &mut [F]) {
+        (**self).permute(state)
+    }
+}
+
+/// The rescue permutation with the rate/capacity split of
+/// [`RescueCRHF`](crate::rescue::sponge::RescueCRHF): the rate is the first
+/// `CRHF_RATE` elements and the tag is the last one.
+impl<F: RescueParameter> SafePermutation<F> for Permutation<F> {
+    fn state_size(&self) -> usize {
+        STATE_SIZE
+    }
+
+    fn rate_range(&self) -> Range<usize> {
+        0..CRHF_RATE
+    }
+
+    fn tag_index(&self) -> usize {
+        CRHF_RATE
+    }
+
+    fn permute(&self, state: &mut [F]) {
+        let output = self.eval(&RescueVector::from(&*state));
+        state.copy_from_slice(&output.elems());
+    }
+}
+
+/// The Rescue-Prime permutation, with the same layout as [`Permutation`].
+impl<F: RescueParameter> SafePermutation<F> for RescuePrimePermutation<F> {
+    fn state_size(&self) -> usize {
+        STATE_SIZE
+    }
+
+    fn rate_range(&self) -> Range<usize> {
+        0..CRHF_RATE
+    }
+
+    fn tag_index(&self) -> usize {
+        CRHF_RATE
+    }
+
+    fn permute(&self, state: &mut [F]) {
+        let output = self.eval(&RescueVector::from(&*state));
+        state.copy_from_slice(&output.elems());
+    }
+}
+
+/// The poseidon permutation with a capacity of one element, with the layout
+/// of [`PoseidonSponge`](ark_crypto_primitives::sponge::poseidon::PoseidonSponge):
+/// the capacity comes first and is the tag.
+impl<F: PrimeField> SafePermutation<F> for PoseidonConstants<F> {
+    fn state_size(&self) -> usize {
+        self.mds.len()
+    }
+
+    fn rate_range(&self) -> Range<usize> {
+        1..self.mds.len()
+    }
+
+    fn tag_index(&self) -> usize {
+        0
+    }
+
+    fn permute(&self, state: &mut [F]) {
+        PoseidonConstants::permute(self, state)
+    }
+}
+
+/// A SAFE sponge over the rescue permutation.
+pub type RescueSafeSponge<F> = SafeSponge<F, Permutation<F>>;
+
+/// A SAFE sponge over the Rescue-Prime permutation of
+/// [`RescuePrimeParameter::rescue_prime`](crate::rescue::prime::RescuePrimeParameter::rescue_prime).
+pub type RescuePrimeSafeSponge<F> = SafeSponge<F, &'static RescuePrimePermutation<F>>;
+
+/// A SAFE sponge over the poseidon permutation of
+/// [`PoseidonParameter::CONSTANTS`](crate::poseidon::PoseidonParameter::CONSTANTS).
+pub type PoseidonSafeSponge<F> = SafeSponge<F, PoseidonConstants<F>>;
+
+/// A sponge following the SAFE calling convention, see the [module
+/// documentation](self).
+#[derive(Clone, Debug)]
+pub struct SafeSponge<F: PrimeField, P: SafePermutation<F>> {
+    permutation: P,
+    state: Vec<F>,
+    pattern: IOPattern,
+    /// Index of the current call of the pattern
+    op_index: usize,
+    /// # of elements of the current call already absorbed or squeezed
+    op_progress: u32,
+    absorb_pos: usize,
+    squeeze_pos: usize,
+}
+
+impl<F: PrimeField, P: SafePermutation<F>> SafeSponge<F, P> {
+    /// Start a sponge over `permutation`, which will be called as declared by
+    /// `pattern`, in the domain of `domain_separator`.
+    /// * `returns` - Err() if the permutation has no rate or no capacity
+    pub fn start(
+        permutation: P,
+        pattern: IOPattern,
+        domain_separator: &[u8],
+    ) -> Result<Self, PrimitivesError> {
+        let rate = permutation.rate_range().len();
+        if rate == 0 || rate >= permutation.state_size() {
+            return Err(PrimitivesError::ParameterError(format!(
+                "SAFE sponge Error : rate {} is not between 1 and the state size {} minus one.",
+                rate,
+                permutation.state_size()
+            )));
+        }
+        let mut state = vec![F::zero(); permutation.state_size()];
+        state[permutation.tag_index()] = pattern.tag(domain_separator);
+        Ok(Self {
+            permutation,
+            state,
+            pattern,
+            op_index: 0,
+            op_progress: 0,
+            absorb_pos: 0,
+            squeeze_pos: rate,
+        })
+    }
+
+    /// Absorb `elems`, the next elements declared by the pattern.
+    /// * `returns` - Err() if the pattern does not expect to absorb that many
+    ///   elements, in which case the sponge can no longer be used
+    pub fn absorb(&mut self, elems: &[F]) -> Result<(), PrimitivesError> {
+        self.follow(true, elems.len())?;
+        let rate = self.permutation.rate_range();
+        for elem in elems {
+            if self.absorb_pos == rate.len() {
+                self.permutation.permute(&mut self.state);
+                self.absorb_pos = 0;
+            }
+            self.state[rate.start + self.absorb_pos] += elem;
+            self.absorb_pos += 1;
+        }
+        self.squeeze_pos = rate.len();
+        Ok(())
+    }
+
+    /// Squeeze `num_outputs` elements, the next ones declared by the pattern.
+    /// * `returns` - Err() if the pattern does not expect to squeeze that many
+    ///   elements, in which case the sponge can no longer be used
+    pub fn squeeze(&mut self, num_outputs: usize) -> Result<Vec<F>, PrimitivesError> {
+        self.follow(false, num_outputs)?;
+        let rate = self.permutation.rate_range();
+        let mut result = Vec::with_capacity(num_outputs);
+        for _ in 0..num_outputs {
+            if self.squeeze_pos == rate.len() {
+                self.permutation.permute(&mut self.state);
+                self.squeeze_pos = 0;
+                self.absorb_pos = 0;
+            }
+            result.push(self.state[rate.start + self.squeeze_pos]);
+            self.squeeze_pos += 1;
+        }
+        Ok(result)
+    }
+
+    /// Close the sponge and erase its state.
+    /// * `returns` - Err() if the pattern was not followed to the end
+    pub fn finish(mut self) -> Result<(), PrimitivesError> {
+        let done = match self.pattern.ops.get(self.op_index) {
+            Some(op) => self.op_index + 1 == self.pattern.ops.len() && self.op_progress == op.len(),
+            None => true,
+        };
+        self.erase();
+        if done {
+            Ok(())
+        } else {
+            Err(PrimitivesError::ParameterError(
+                "SAFE sponge Error : finished before the end of the IO pattern.".to_string(),
+            ))
+        }
+    }
+
+    /// Check that a call of `len` elements follows the pattern, and move
+    /// forward in it.
+    fn follow(&mut self, absorb: bool, len: usize) -> Result<(), PrimitivesError> {
+        if len == 0 {
+            return Ok(());
+        }
+        if let Some(op) = self.pattern.ops.get(self.op_index) {
+            if self.op_progress == op.len() {
+                self.op_index += 1;
+                self.op_progress = 0;
+            }
+        }
+        let expected = self.pattern.ops.get(self.op_index).copied();
+        match expected {
+            Some(op)
+                if op.is_absorb() == absorb && len <= (op.len() - self.op_progress) as usize =>
+            {
+                self.op_progress += len as u32;
+                Ok(())
+            },
+            _ => {
+                self.erase();
+                Err(PrimitivesError::ParameterError(format!(
+                    "SAFE sponge Error : {} {} elements, but the IO pattern expects {:?}.",
+                    if absorb { "absorbing" } else { "squeezing" },
+                    len,
+                    expected
+                )))
+            },
+        }
+    }
+
+    /// Erase the state and make any further call fail.
+    fn erase(&mut self) {
+        self.state.iter_mut().for_each(|x| *x = F::zero());
+        self.op_index = self.pattern.ops.len();
+        self.op_progress = 0;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{poseidon::PoseidonParameter, rescue::prime::RescuePrimeParameter};
+    use ark_bls12_377::Fq as Fq377;
+    use ark_ed_on_bls12_377::Fq as FqEd377;
+    use ark_ed_on_bls12_381::Fq as FqEd381;
+    use ark_ed_on_bn254::Fq as FqEd254;
+    use ark_std::UniformRand;
+
+    #[test]
+    fn test_io_pattern() {
+        use SpongeOp::*;
+        let pattern = IOPattern::new(&[Absorb(1), Absorb(2), Squeeze(1), Absorb(3)]).unwrap();
+        assert_eq!(pattern.ops(), &[Absorb(3), Squeeze(1), Absorb(3)]);
+        assert!(IOPattern::new(&[Absorb(1), Squeeze(0)]).is_err());
+        assert!(IOPattern::new(&[Absorb(1 << 30), Absorb(1 << 30)]).is_err());
+        assert!(IOPattern::new(&[Squeeze(1 << 31)]).is_err());
+
+        // the tag depends on the aggregated pattern and the domain
+        let tag: FqEd254 = pattern.tag(b"jellyfish");
+        let same = IOPattern::new(&[Absorb(3), Squeeze(1), Absorb(1), Absorb(2)]).unwrap();
+        assert_eq!(tag, same.tag(b"jellyfish"));
+        assert_ne!(tag, pattern.tag(b"jellyfish2"));
+        let other = IOPattern::new(&[Absorb(3), Squeeze(1), Absorb(2)]).unwrap();
+        assert_ne!(tag, other.tag(b"jellyfish"));
+
+        // the tag is the first 128 bits of the SHA3-256 hash of the encoding
+        let mut encoding = Vec::new();
+        encoding.extend_from_slice(&[0x80, 0, 0, 3, 0, 0, 0, 1, 0x80, 0, 0, 3]);
+        encoding.extend_from_slice(b"jellyfish");
+        let digest = Sha3_256::digest(&encoding);
+        assert_eq!(tag, FqEd254::from_le_bytes_mod_order(&digest[..16]));
+    }
+
+    #[test]
+    fn test_safe_sponge() {
+        test_safe_sponge_helper::<FqEd254, _>(Permutation::default);
+        test_safe_sponge_helper::<FqEd377, _>(Permutation::default);
+        test_safe_sponge_helper::<FqEd381, _>(Permutation::default);
+        test_safe_sponge_helper::<Fq377, _>(Permutation::default);
+        test_safe_sponge_helper::<FqEd254, _>(FqEd254::rescue_prime);
+        test_safe_sponge_helper::<FqEd377, _>(FqEd377::rescue_prime);
+        test_safe_sponge_helper::<FqEd254, _>(|| FqEd254::CONSTANTS);
+        test_safe_sponge_helper::<FqEd377, _>(|| FqEd377::CONSTANTS);
+        test_safe_sponge_helper::<FqEd381, _>(|| FqEd381::CONSTANTS);
+    }
+
+    fn test_safe_sponge_helper<F, P>(permutation: impl Fn() -> P)
+    where
+        F: PrimeField,
+        P: SafePermutation<F>,
+    {
+        use SpongeOp::*;
+        let rng = &mut jf_utils::test_rng();
+        let input: Vec<F> = (0..7).map(|_| F::rand(rng)).collect();
+        let pattern = IOPattern::new(&[Absorb(7), Squeeze(5), Absorb(1), Squeeze(1)]).unwrap();
+
+        let run = |pattern: &IOPattern, domain: &[u8], input: &[F]| {
+            let mut sponge = SafeSponge::start(permutation(), pattern.clone(), domain).unwrap();
+            sponge.absorb(&input[..2]).unwrap();
+            sponge.absorb(&input[2..]).unwrap();
+            let mut output = sponge.squeeze(2).unwrap();
+            output.extend(sponge.squeeze(3).unwrap());
+            sponge.absorb(&input[..1]).unwrap();
+            output.extend(sponge.squeeze(1).unwrap());
+            sponge.finish().unwrap();
+            output
+        };
+        let output = run(&pattern, b"test", &input);
+        assert_eq!(output.len(), 6);
+        assert_eq!(output, run(&pattern, b"test", &input));
+        assert_ne!(output, run(&pattern, b"test2", &input));
+        let mut other = input.clone();
+        other[6] += F::one();
+        assert_ne!(output, run(&pattern, b"test", &other));
+
+        // the state is the tag, absorbed into lazily
+        let p = permutation();
+        let rate = p.rate_range();
+        let mut state = vec![F::zero(); p.state_size()];
+        state[p.tag_index()] = pattern.tag(b"test");
+        for (i, x) in input.iter().enumerate() {
+            if i > 0 && i % rate.len() == 0 {
+                p.permute(&mut state);
+            }
+            state[rate.start + i % rate.len()] += x;
+        }
+        p.permute(&mut state);
+        assert_eq!(output[..rate.len()], state[rate.clone()]);
+
+        // deviations from the pattern are errors, and erase the sponge
+        let mut sponge = SafeSponge::start(permutation(), pattern.clone(), b"test").unwrap();
+        assert!(sponge.squeeze(1).is_err());
+        assert!(sponge.absorb(&input).is_err());
+        let mut sponge = SafeSponge::start(permutation(), pattern.clone(), b"test").unwrap();
+        assert!(sponge
+            .absorb(&[input.clone(), input.clone()].concat())
+            .is_err());
+        assert!(sponge.state.iter().all(|x| x.is_zero()));
+        let mut sponge = SafeSponge::start(permutation(), pattern.clone(), b"test").unwrap();
+        sponge.absorb(&input).unwrap();
+        sponge.squeeze(5).unwrap();
+        assert!(sponge.squeeze(1).is_err());
+        let mut sponge = SafeSponge::start(permutation(), pattern, b"test").unwrap();
+        sponge.absorb(&input).unwrap();
+        assert!(sponge.finish().is_err());
+    }
+}
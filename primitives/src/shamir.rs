@@ -0,0 +1,336 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+//! This module implements the `t`-out-of-`n` secret sharing of Shamir over a
+//! prime field.
+//!
+//! The secret `s` is split by sampling a random polynomial `f` of degree `t-1`
+//! with `f(0)=s`, and party `i` (for `i` in `1..=n`) gets the share `f(i)`.
+//! Any `t` shares reconstruct `s` by Lagrange interpolation at `0`, while
+//! fewer reveal nothing about it. The shares are not verifiable: a party can
+//! not tell a wrong share from a valid one, see the [VSS](crate::vss) schemes
+//! when the dealer or the holders of the shares are not trusted.
+//!
+//! ```
+//! use jf_primitives::shamir::Shamir;
+//!
+//! let mut rng = jf_utils::test_rng();
+//! let shamir = Shamir::new(2, 3).unwrap();
+//! let secret = ark_bn254::Fr::from(42u8);
+//! let shares = shamir.split(&secret, &mut rng);
+//! assert_eq!(shamir.reconstruct(&shares[1..]).unwrap(), secret);
+//! assert!(shamir.reconstruct(&shares[..1]).is_err());
+//! ```
+
+use crate::errors::PrimitivesError::{self, ParameterError};
+use ark_ff::PrimeField;
+use ark_serialize::*;
+use ark_std::{
+    format,
+    rand::{CryptoRng, RngCore},
+    string::ToString,
+    vec::Vec,
+};
+use jf_utils::canonical;
+use serde::{Deserialize, Serialize};
+use zeroize::Zeroize;
+
+/// Parameters of a `t`-out-of-`n` Shamir secret sharing.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    Serialize,
+    Deserialize,
+    CanonicalSerialize,
+    CanonicalDeserialize,
+)]
+pub struct Shamir {
+    threshold: u32,
+    num_parties: u32,
+}
+
+/// The share of a secret held by one party.
+#[derive(
+    Clone, Debug, PartialEq, Eq, Serialize, Deserialize, CanonicalSerialize, CanonicalDeserialize,
+)]
+pub struct Share<F: PrimeField> {
+    index: u32,
+    #[serde(with = "canonical")]
+    value: F,
+}
+
+impl<F: PrimeField> Drop for Share<F> {
+    fn drop(&mut self) {
+        self.value.zeroize();
+    }
+}
+
+impl<F: PrimeField> Share<F> {
+    /// Import the share `value` of the party of index `index`.
+    /// * `returns` - Err() if `index` is zero
+    pub fn new(index: u32, value: F) -> Result<Self, PrimitivesError> {
+        if index == 0 {
+            return Err(ParameterError("party indices start at 1".to_string()));
+        }
+        Ok(Self { index, value })
+    }
+
+    /// The index of the party holding the share, in `1..=n`.
+    pub fn index(&self) -> u32 {
+        self.index
+    }
+
+    /// The value of the share.
+    pub fn value(&self) -> &F {
+        &self.value
+    }
+}
+
+impl Shamir {
+    /// Setup a sharing where any `threshold` out of `num_parties` shares
+    /// reconstruct the secret.
+    /// * `returns` - Err() if `threshold` is zero or greater than `num_parties`
+    pub fn new(threshold: u32, num_parties: u32) -> Result<Self, PrimitivesError> {
+        check_params(threshold, num_parties)?;
+        Ok(Self {
+            threshold,
+            num_parties,
+        })
+    }
+
+    /// The number of shares needed to reconstruct the secret.
+    pub fn threshold(&self) -> u32 {
+        self.threshold
+    }
+
+    /// The number of parties holding a share.
+    pub fn num_parties(&self) -> u32 {
+        self.num_parties
+    }
+
+    /// Split a secret into the shares of the parties.
+    /// * `returns` - the shares, ordered by index
+    pub fn split<F: PrimeField, R: CryptoRng + RngCore>(
+        &self,
+        secret: &F,
+        prng: &mut R,
+    ) -> Vec<Share<F>> {
+        let mut coeffs = random_polynomial(secret, self.threshold, prng);
+        let shares = eval_shares(&coeffs, self.num_parties);
+        coeffs.zeroize();
+        shares
+    }
+
+    /// Reconstruct the secret from shares, in any order. Only the first
+    /// `threshold` shares are used.
+    /// * `returns` - Err() if there are fewer than `threshold` shares, or if an
+    ///   index is out of range or repeated
+    pub fn reconstruct<F: PrimeField>(&self, shares: &[Share<F>]) -> Result<F, PrimitivesError> {
+        reconstruct(shares, self.threshold, self.num_parties)
+    }
+}
+
+// A random polynomial of degree `threshold-1` whose constant coefficient is
+// `secret`.
+pub(crate) fn random_polynomial<F: PrimeField, R: CryptoRng + RngCore>(
+    secret: &F,
+    threshold: u32,
+    prng: &mut R,
+) -> Vec<F> {
+    let mut coeffs = Vec::with_capacity(threshold as usize);
+    coeffs.push(*secret);
+    coeffs.extend((1..threshold).map(|_| F::rand(prng)));
+    coeffs
+}
+
+// The shares of the parties of index `1..=num_parties`, evaluations of the
+// polynomial of coefficients `coeffs`.
+pub(crate) fn eval_shares<F: PrimeField>(coeffs: &[F], num_parties: u32) -> Vec<Share<F>> {
+    (1..=num_parties)
+        .map(|index| {
+            let x = F::from(index);
+            let value = coeffs
+                .iter()
+                .rev()
+                .fold(F::zero(), |acc, coeff| acc * x + coeff);
+            Share { index, value }
+        })
+        .collect()
+}
+
+// The Lagrange coefficient of `x_i` at `0` over the distinct points `xs`,
+// which include `x_i`.
+pub(crate) fn lagrange_coeff_at_zero<F: PrimeField>(x_i: F, xs: &[F]) -> F {
+    let mut num = F::one();
+    let mut den = F::one();
+    for x_j in xs.iter().filter(|x_j| **x_j != x_i) {
+        num *= x_j;
+        den *= *x_j - x_i;
+    }
+    // `den` is non-zero as the points are distinct.
+    num * den.inverse().unwrap()
+}
+
+// Check that `threshold` out of `num_parties` is a valid threshold.
+pub(crate) fn check_params(threshold: u32, num_parties: u32) -> Result<(), PrimitivesError> {
+    if threshold == 0 || threshold > num_parties {
+        return Err(ParameterError(format!(
+            "invalid threshold {threshold} for {num_parties} parties"
+        )));
+    }
+    Ok(())
+}
+
+// Check that `index` is a party index, in `1..=num_parties`.
+pub(crate) fn check_index(index: u32, num_parties: u32) -> Result<(), PrimitivesError> {
+    if index == 0 || index > num_parties {
+        return Err(ParameterError(format!(
+            "party index {index} out of range 1..={num_parties}"
+        )));
+    }
+    Ok(())
+}
+
+// Check that there are at least `threshold` party indices in `indices`, and
+// that the first `threshold` ones are in `1..=num_parties` and distinct.
+pub(crate) fn check_indices(
+    indices: &[u32],
+    threshold: u32,
+    num_parties: u32,
+) -> Result<(), PrimitivesError> {
+    if indices.len() < threshold as usize {
+        return Err(ParameterError(format!(
+            "{} shares for a threshold of {}",
+            indices.len(),
+            threshold
+        )));
+    }
+    let indices = &indices[..threshold as usize];
+    for (i, index) in indices.iter().enumerate() {
+        check_index(*index, num_parties)?;
+        if indices[..i].contains(index) {
+            return Err(ParameterError(format!("repeated party index {index}")));
+        }
+    }
+    Ok(())
+}
+
+// Interpolate the first `threshold` shares at `0`.
+pub(crate) fn reconstruct<F: PrimeField>(
+    shares: &[Share<F>],
+    threshold: u32,
+    num_parties: u32,
+) -> Result<F, PrimitivesError> {
+    let indices: Vec<u32> = shares.iter().map(|s| s.index).collect();
+    check_indices(&indices, threshold, num_parties)?;
+    let shares = &shares[..threshold as usize];
+
+    let xs: Vec<F> = shares.iter().map(|s| F::from(s.index)).collect();
+    Ok(shares
+        .iter()
+        .zip(xs.iter())
+        .map(|(share, x_i)| share.value * lagrange_coeff_at_zero(*x_i, &xs))
+        .sum())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_std::vec;
+
+    fn test_shamir_helper<F: PrimeField>() {
+        let mut rng = jf_utils::test_rng();
+        let shamir = Shamir::new(3, 5).unwrap();
+        let secret = F::rand(&mut rng);
+        let shares = shamir.split(&secret, &mut rng);
+        assert_eq!(shares.len(), 5);
+        for (share, index) in shares.iter().zip(1..) {
+            assert_eq!(share.index(), index);
+        }
+
+        // any `t` shares reconstruct the secret, in any order
+        for subset in [
+            vec![0, 1, 2],
+            vec![4, 2, 0],
+            vec![1, 3, 4],
+            vec![3, 2, 1, 0],
+        ] {
+            let subset: Vec<_> = subset.into_iter().map(|i| shares[i].clone()).collect();
+            assert_eq!(shamir.reconstruct(&subset).unwrap(), secret);
+        }
+        // the shares of two splits are independent
+        let other = shamir.split(&secret, &mut rng);
+        assert_ne!(other, shares);
+        let mixed = vec![shares[0].clone(), other[1].clone(), other[2].clone()];
+        assert_ne!(shamir.reconstruct(&mixed).unwrap(), secret);
+
+        assert!(shamir.reconstruct(&shares[..2]).is_err());
+        let repeated = vec![shares[0].clone(), shares[1].clone(), shares[0].clone()];
+        assert!(shamir.reconstruct(&repeated).is_err());
+        let wrong = Share::new(1, *shares[0].value() + F::from(1u8)).unwrap();
+        let wrong = vec![wrong, shares[1].clone(), shares[2].clone()];
+        assert_ne!(shamir.reconstruct(&wrong).unwrap(), secret);
+        let out_of_range = Share::new(6, *shares[0].value()).unwrap();
+        let out_of_range = vec![out_of_range, shares[1].clone(), shares[2].clone()];
+        assert!(shamir.reconstruct(&out_of_range).is_err());
+        assert!(Share::new(0, secret).is_err());
+    }
+
+    #[test]
+    fn test_shamir() {
+        test_shamir_helper::<ark_bn254::Fr>();
+        test_shamir_helper::<ark_bn254::Fq>();
+        test_shamir_helper::<ark_bls12_381::Fr>();
+        test_shamir_helper::<ark_ed_on_bn254::Fr>();
+    }
+
+    #[test]
+    fn test_shamir_params() {
+        let mut rng = jf_utils::test_rng();
+        assert!(Shamir::new(0, 3).is_err());
+        assert!(Shamir::new(4, 3).is_err());
+        let shamir = Shamir::new(2, 3).unwrap();
+        assert_eq!(shamir.threshold(), 2);
+        assert_eq!(shamir.num_parties(), 3);
+
+        // 1-out-of-n shares are copies of the secret
+        let shamir = Shamir::new(1, 3).unwrap();
+        let secret = ark_bn254::Fr::from(7u8);
+        let shares = shamir.split(&secret, &mut rng);
+        assert!(shares.iter().all(|share| share.value() == &secret));
+        assert_eq!(shamir.reconstruct(&shares[2..]).unwrap(), secret);
+
+        // n-out-of-n
+        let shamir = Shamir::new(3, 3).unwrap();
+        let shares = shamir.split(&secret, &mut rng);
+        assert_eq!(shamir.reconstruct(&shares).unwrap(), secret);
+        assert!(shamir.reconstruct(&shares[1..]).is_err());
+    }
+
+    #[test]
+    fn test_serde() {
+        let mut rng = jf_utils::test_rng();
+        let shamir = Shamir::new(2, 3).unwrap();
+        let shares = shamir.split(&ark_bn254::Fr::from(3u8), &mut rng);
+
+        let bytes = bincode::serialize(&shamir).unwrap();
+        assert_eq!(bincode::deserialize::<Shamir>(&bytes).unwrap(), shamir);
+        let bytes = bincode::serialize(&shares[0]).unwrap();
+        assert_eq!(
+            bincode::deserialize::<Share<ark_bn254::Fr>>(&bytes).unwrap(),
+            shares[0]
+        );
+        let mut bytes = Vec::new();
+        shares[0].serialize_compressed(&mut bytes).unwrap();
+        assert_eq!(
+            Share::deserialize_compressed(&bytes[..]).unwrap(),
+            shares[0]
+        );
+    }
+}
@@ -0,0 +1,813 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+//! This module implements a small framework of sigma protocols, made
+//! non-interactive with the Fiat-Shamir transform over an [`IOPTranscript`].
+//!
+//! A [`SigmaProtocol`] proves knowledge of a witness for a statement in three
+//! moves: the prover sends a commitment, receives a random challenge, and
+//! answers with a response. The basic protocols are
+//! * [`DLog`], the Schnorr proof of knowledge of `x` such that `X=xB`,
+//! * [`Dleq`], the Chaum-Pedersen proof that `X=xB` and `Y=xH` for the same
+//!   `x`,
+//! * [`Representation`], the proof of knowledge of `x_1, ..., x_n` such that
+//!   `X=x_1B_1+...+x_nB_n`, e.g. of the opening of a Pedersen commitment,
+//!
+//! and [`And`] and [`Or`] compose any two protocols over the same challenge
+//! field into a proof of both statements, resp. of one of them without
+//! revealing which. Compositions nest, e.g. `Or<And<A, B>, C>`.
+//!
+//! The challenge binds the statement, the commitment, and everything appended
+//! to the transcript beforehand, so that a proof can be attached to some
+//! context, e.g. a ciphertext or a commitment, by appending it first.
+//!
+//! ```
+//! use ark_bn254::{Fr, G1Projective};
+//! use ark_ec::Group;
+//! use ark_std::UniformRand;
+//! use jf_primitives::{
+//!     pcs::transcript::IOPTranscript,
+//!     sigma::{DLog, DLogStatement, SigmaProtocol},
+//! };
+//!
+//! let mut rng = jf_utils::test_rng();
+//! let x = Fr::rand(&mut rng);
+//! let base = G1Projective::generator();
+//! let statement = DLogStatement {
+//!     base,
+//!     point: base * x,
+//! };
+//!
+//! let mut transcript = IOPTranscript::new(b"example");
+//! transcript.append_message(b"context", b"some context").unwrap();
+//! let proof = DLog::prove(&mut transcript, &statement, &x, &mut rng).unwrap();
+//!
+//! let mut transcript = IOPTranscript::new(b"example");
+//! transcript.append_message(b"context", b"some context").unwrap();
+//! assert!(DLog::verify(&mut transcript, &statement, &proof).is_ok());
+//! ```
+
+use crate::{
+    constants::CS_ID_SIGMA,
+    errors::PrimitivesError::{self, ParameterError, VerificationError},
+    pcs::transcript::{IOPTranscript, TranscriptError},
+};
+use ark_ec::CurveGroup;
+use ark_ff::PrimeField;
+use ark_serialize::*;
+use ark_std::{
+    fmt::Debug,
+    format,
+    marker::PhantomData,
+    rand::{CryptoRng, RngCore},
+    string::ToString,
+    vec::Vec,
+    UniformRand,
+};
+use zeroize::Zeroize;
+
+/// Trait for sigma protocols, i.e. three-move public-coin proofs of knowledge
+/// of a witness for a statement, which are special honest-verifier
+/// zero-knowledge.
+pub trait SigmaProtocol {
+    /// The field of the challenges
+    type Challenge: PrimeField;
+    /// The public statement
+    type Statement: CanonicalSerialize;
+    /// The secret witness of the statement
+    type Witness;
+    /// The first message of the prover
+    type Commitment: Clone + Debug + PartialEq + CanonicalSerialize + CanonicalDeserialize;
+    /// The last message of the prover
+    type Response: Clone + Debug + PartialEq + CanonicalSerialize + CanonicalDeserialize;
+    /// The secret state of the prover between its two messages
+    type ProverState;
+
+    /// Compute the commitment of the prover.
+    fn commit<R: CryptoRng + RngCore>(
+        statement: &Self::Statement,
+        witness: &Self::Witness,
+        rng: &mut R,
+    ) -> (Self::ProverState, Self::Commitment);
+
+    /// Answer `challenge`, consuming the state returned by
+    /// [`Self::commit`] for the same statement and witness.
+    /// * `returns` - Err() if `state` was not computed from `witness`
+    fn respond(
+        statement: &Self::Statement,
+        witness: &Self::Witness,
+        state: Self::ProverState,
+        challenge: &Self::Challenge,
+    ) -> Result<Self::Response, PrimitivesError>;
+
+    /// Whether the transcript of `commitment`, `challenge` and `response` is
+    /// accepted for `statement`.
+    fn check(
+        statement: &Self::Statement,
+        commitment: &Self::Commitment,
+        challenge: &Self::Challenge,
+        response: &Self::Response,
+    ) -> bool;
+
+    /// Sample an accepting commitment and response for `challenge` without
+    /// the witness, distributed as in an honest execution.
+    fn simulate<R: CryptoRng + RngCore>(
+        statement: &Self::Statement,
+        challenge: &Self::Challenge,
+        rng: &mut R,
+    ) -> (Self::Commitment, Self::Response);
+
+    /// Non-interactively prove the knowledge of `witness` for `statement`,
+    /// the challenge being derived from `transcript`.
+    /// * `returns` - Err() if `witness` is not a witness of `statement`
+    fn prove<R: CryptoRng + RngCore>(
+        transcript: &mut IOPTranscript<Self::Challenge>,
+        statement: &Self::Statement,
+        witness: &Self::Witness,
+        rng: &mut R,
+    ) -> Result<Proof<Self>, PrimitivesError>
+    where
+        Self: Sized,
+    {
+        let (state, commitment) = Self::commit(statement, witness, rng);
+        let challenge = fiat_shamir_challenge::<Self>(transcript, statement, &commitment)?;
+        let response = Self::respond(statement, witness, state, &challenge)?;
+        if !Self::check(statement, &commitment, &challenge, &response) {
+            return Err(ParameterError(
+                "the witness does not match the statement".to_string(),
+            ));
+        }
+        Ok(Proof {
+            commitment,
+            response,
+        })
+    }
+
+    /// Verify a proof of [`Self::prove`], with the transcript in the same
+    /// state as the one of the prover.
+    /// * `returns` - Err() if the proof is invalid
+    fn verify(
+        transcript: &mut IOPTranscript<Self::Challenge>,
+        statement: &Self::Statement,
+        proof: &Proof<Self>,
+    ) -> Result<(), PrimitivesError>
+    where
+        Self: Sized,
+    {
+        let challenge = fiat_shamir_challenge::<Self>(transcript, statement, &proof.commitment)?;
+        if Self::check(statement, &proof.commitment, &challenge, &proof.response) {
+            Ok(())
+        } else {
+            Err(VerificationError("invalid sigma proof".to_string()))
+        }
+    }
+}
+
+/// A non-interactive proof of a sigma protocol.
+#[derive(CanonicalSerialize, CanonicalDeserialize, Derivative)]
+#[derivative(
+    Clone(bound = "S: SigmaProtocol"),
+    Debug(bound = "S: SigmaProtocol"),
+    PartialEq(bound = "S: SigmaProtocol")
+)]
+pub struct Proof<S: SigmaProtocol> {
+    commitment: S::Commitment,
+    response: S::Response,
+}
+
+// Append the statement and the commitment to the transcript, and derive the
+// challenge from it.
+fn fiat_shamir_challenge<S: SigmaProtocol>(
+    transcript: &mut IOPTranscript<S::Challenge>,
+    statement: &S::Statement,
+    commitment: &S::Commitment,
+) -> Result<S::Challenge, PrimitivesError> {
+    let transcript_error = |e: TranscriptError| PrimitivesError::InternalError(format!("{e}"));
+    transcript
+        .append_message(b"cs_id", CS_ID_SIGMA.as_bytes())
+        .map_err(transcript_error)?;
+    transcript
+        .append_serializable_element(b"statement", statement)
+        .map_err(transcript_error)?;
+    transcript
+        .append_serializable_element(b"commitment", commitment)
+        .map_err(transcript_error)?;
+    transcript
+        .get_and_append_challenge(b"challenge")
+        .map_err(transcript_error)
+}
+
+/// The statement `point = x * base` of a proof of knowledge of `x`.
+#[derive(Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct DLogStatement<G: CurveGroup> {
+    /// The base point
+    pub base: G,
+    /// The point whose discrete logarithm is known
+    pub point: G,
+}
+
+/// The Schnorr proof of knowledge of a discrete logarithm, whose witness is
+/// the discrete logarithm.
+#[derive(Clone, Debug)]
+pub struct DLog<G: CurveGroup>(PhantomData<G>);
+
+impl<G: CurveGroup> SigmaProtocol for DLog<G> {
+    type Challenge = G::ScalarField;
+    type Statement = DLogStatement<G>;
+    type Witness = G::ScalarField;
+    type Commitment = G;
+    type Response = G::ScalarField;
+    type ProverState = G::ScalarField;
+
+    fn commit<R: CryptoRng + RngCore>(
+        statement: &Self::Statement,
+        _witness: &Self::Witness,
+        rng: &mut R,
+    ) -> (Self::ProverState, Self::Commitment) {
+        let r = G::ScalarField::rand(rng);
+        (r, statement.base * r)
+    }
+
+    fn respond(
+        _statement: &Self::Statement,
+        witness: &Self::Witness,
+        mut state: Self::ProverState,
+        challenge: &Self::Challenge,
+    ) -> Result<Self::Response, PrimitivesError> {
+        let response = state + *challenge * witness;
+        state.zeroize();
+        Ok(response)
+    }
+
+    fn check(
+        statement: &Self::Statement,
+        commitment: &Self::Commitment,
+        challenge: &Self::Challenge,
+        response: &Self::Response,
+    ) -> bool {
+        statement.base * response == *commitment + statement.point * challenge
+    }
+
+    fn simulate<R: CryptoRng + RngCore>(
+        statement: &Self::Statement,
+        challenge: &Self::Challenge,
+        rng: &mut R,
+    ) -> (Self::Commitment, Self::Response) {
+        let response = G::ScalarField::rand(rng);
+        (
+            statement.base * response - statement.point * challenge,
+            response,
+        )
+    }
+}
+
+/// The statement `points[i] = x * bases[i]` for both `i`, of a proof of
+/// equality of discrete logarithms.
+#[derive(Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct DleqStatement<G: CurveGroup> {
+    /// The two base points
+    pub bases: [G; 2],
+    /// The two points of equal discrete logarithms
+    pub points: [G; 2],
+}
+
+/// The Chaum-Pedersen proof of equality of discrete logarithms, e.g. that
+/// `(B, xB, H, xH)` is a Diffie-Hellman tuple, whose witness is the common
+/// discrete logarithm.
+#[derive(Clone, Debug)]
+pub struct Dleq<G: CurveGroup>(PhantomData<G>);
+
+impl<G: CurveGroup> SigmaProtocol for Dleq<G> {
+    type Challenge = G::ScalarField;
+    type Statement = DleqStatement<G>;
+    type Witness = G::ScalarField;
+    type Commitment = [G; 2];
+    type Response = G::ScalarField;
+    type ProverState = G::ScalarField;
+
+    fn commit<R: CryptoRng + RngCore>(
+        statement: &Self::Statement,
+        _witness: &Self::Witness,
+        rng: &mut R,
+    ) -> (Self::ProverState, Self::Commitment) {
+        let r = G::ScalarField::rand(rng);
+        (r, [statement.bases[0] * r, statement.bases[1] * r])
+    }
+
+    fn respond(
+        _statement: &Self::Statement,
+        witness: &Self::Witness,
+        mut state: Self::ProverState,
+        challenge: &Self::Challenge,
+    ) -> Result<Self::Response, PrimitivesError> {
+        let response = state + *challenge * witness;
+        state.zeroize();
+        Ok(response)
+    }
+
+    fn check(
+        statement: &Self::Statement,
+        commitment: &Self::Commitment,
+        challenge: &Self::Challenge,
+        response: &Self::Response,
+    ) -> bool {
+        (0..2).all(|i| {
+            statement.bases[i] * response == commitment[i] + statement.points[i] * challenge
+        })
+    }
+
+    fn simulate<R: CryptoRng + RngCore>(
+        statement: &Self::Statement,
+        challenge: &Self::Challenge,
+        rng: &mut R,
+    ) -> (Self::Commitment, Self::Response) {
+        let response = G::ScalarField::rand(rng);
+        let commitment =
+            [0, 1].map(|i| statement.bases[i] * response - statement.points[i] * challenge);
+        (commitment, response)
+    }
+}
+
+/// The statement `point = sum_i x_i * bases[i]` of a proof of knowledge of a
+/// representation `(x_i)` of `point`.
+#[derive(Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct RepresentationStatement<G: CurveGroup> {
+    /// The base points
+    pub bases: Vec<G>,
+    /// The point whose representation is known
+    pub point: G,
+}
+
+/// The proof of knowledge of a representation over several bases, which
+/// generalizes [`DLog`], whose witness is the vector of coefficients.
+#[derive(Clone, Debug)]
+pub struct Representation<G: CurveGroup>(PhantomData<G>);
+
+impl<G: CurveGroup> SigmaProtocol for Representation<G> {
+    type Challenge = G::ScalarField;
+    type Statement = RepresentationStatement<G>;
+    type Witness = Vec<G::ScalarField>;
+    type Commitment = G;
+    type Response = Vec<G::ScalarField>;
+    type ProverState = Vec<G::ScalarField>;
+
+    fn commit<R: CryptoRng + RngCore>(
+        statement: &Self::Statement,
+        _witness: &Self::Witness,
+        rng: &mut R,
+    ) -> (Self::ProverState, Self::Commitment) {
+        let r: Vec<_> = statement
+            .bases
+            .iter()
+            .map(|_| G::ScalarField::rand(rng))
+            .collect();
+        let commitment = linear_combination(&statement.bases, &r);
+        (r, commitment)
+    }
+
+    fn respond(
+        statement: &Self::Statement,
+        witness: &Self::Witness,
+        mut state: Self::ProverState,
+        challenge: &Self::Challenge,
+    ) -> Result<Self::Response, PrimitivesError> {
+        if witness.len() != statement.bases.len() || state.len() != statement.bases.len() {
+            return Err(ParameterError(format!(
+                "{} coefficients for {} bases",
+                witness.len(),
+                statement.bases.len()
+            )));
+        }
+        let response = state
+            .iter()
+            .zip(witness.iter())
+            .map(|(r, x)| *r + *challenge * x)
+            .collect();
+        state.zeroize();
+        Ok(response)
+    }
+
+    fn check(
+        statement: &Self::Statement,
+        commitment: &Self::Commitment,
+        challenge: &Self::Challenge,
+        response: &Self::Response,
+    ) -> bool {
+        response.len() == statement.bases.len()
+            && linear_combination(&statement.bases, response)
+                == *commitment + statement.point * challenge
+    }
+
+    fn simulate<R: CryptoRng + RngCore>(
+        statement: &Self::Statement,
+        challenge: &Self::Challenge,
+        rng: &mut R,
+    ) -> (Self::Commitment, Self::Response) {
+        let response: Vec<_> = statement
+            .bases
+            .iter()
+            .map(|_| G::ScalarField::rand(rng))
+            .collect();
+        (
+            linear_combination(&statement.bases, &response) - statement.point * challenge,
+            response,
+        )
+    }
+}
+
+// `sum_i scalars[i] * bases[i]`, over the shortest of the two.
+fn linear_combination<G: CurveGroup>(bases: &[G], scalars: &[G::ScalarField]) -> G {
+    bases
+        .iter()
+        .zip(scalars.iter())
+        .map(|(base, scalar)| *base * scalar)
+        .sum()
+}
+
+/// The conjunction of two sigma protocols, proving both statements under the
+/// same challenge. Statements, witnesses and messages are pairs of those of
+/// `A` and `B`.
+#[derive(Clone, Debug)]
+pub struct And<A, B>(PhantomData<(A, B)>);
+
+impl<A, B> SigmaProtocol for And<A, B>
+where
+    A: SigmaProtocol,
+    B: SigmaProtocol<Challenge = A::Challenge>,
+{
+    type Challenge = A::Challenge;
+    type Statement = (A::Statement, B::Statement);
+    type Witness = (A::Witness, B::Witness);
+    type Commitment = (A::Commitment, B::Commitment);
+    type Response = (A::Response, B::Response);
+    type ProverState = (A::ProverState, B::ProverState);
+
+    fn commit<R: CryptoRng + RngCore>(
+        statement: &Self::Statement,
+        witness: &Self::Witness,
+        rng: &mut R,
+    ) -> (Self::ProverState, Self::Commitment) {
+        let (state_a, commitment_a) = A::commit(&statement.0, &witness.0, rng);
+        let (state_b, commitment_b) = B::commit(&statement.1, &witness.1, rng);
+        ((state_a, state_b), (commitment_a, commitment_b))
+    }
+
+    fn respond(
+        statement: &Self::Statement,
+        witness: &Self::Witness,
+        state: Self::ProverState,
+        challenge: &Self::Challenge,
+    ) -> Result<Self::Response, PrimitivesError> {
+        Ok((
+            A::respond(&statement.0, &witness.0, state.0, challenge)?,
+            B::respond(&statement.1, &witness.1, state.1, challenge)?,
+        ))
+    }
+
+    fn check(
+        statement: &Self::Statement,
+        commitment: &Self::Commitment,
+        challenge: &Self::Challenge,
+        response: &Self::Response,
+    ) -> bool {
+        A::check(&statement.0, &commitment.0, challenge, &response.0)
+            && B::check(&statement.1, &commitment.1, challenge, &response.1)
+    }
+
+    fn simulate<R: CryptoRng + RngCore>(
+        statement: &Self::Statement,
+        challenge: &Self::Challenge,
+        rng: &mut R,
+    ) -> (Self::Commitment, Self::Response) {
+        let (commitment_a, response_a) = A::simulate(&statement.0, challenge, rng);
+        let (commitment_b, response_b) = B::simulate(&statement.1, challenge, rng);
+        ((commitment_a, commitment_b), (response_a, response_b))
+    }
+}
+
+/// The witness of a disjunction, for either of the two statements.
+#[derive(Clone, Debug)]
+pub enum OrWitness<A, B> {
+    /// A witness of the first statement
+    Left(A),
+    /// A witness of the second statement
+    Right(B),
+}
+
+/// The state of the prover of a disjunction: the state of the branch of the
+/// witness, with the challenge and response simulated for the other branch.
+pub enum OrProverState<A: SigmaProtocol, B: SigmaProtocol> {
+    /// The witness is for the first statement
+    Left(A::ProverState, A::Challenge, B::Response),
+    /// The witness is for the second statement
+    Right(B::ProverState, A::Challenge, A::Response),
+}
+
+/// The disjunction of two sigma protocols, proving one of the statements
+/// without revealing which (Cramer-Damgård-Schoenmakers): the challenges of
+/// both branches sum to the challenge, and the prover simulates the branch it
+/// has no witness for. The response includes the challenge of the first
+/// branch.
+#[derive(Clone, Debug)]
+pub struct Or<A, B>(PhantomData<(A, B)>);
+
+impl<A, B> SigmaProtocol for Or<A, B>
+where
+    A: SigmaProtocol,
+    B: SigmaProtocol<Challenge = A::Challenge>,
+{
+    type Challenge = A::Challenge;
+    type Statement = (A::Statement, B::Statement);
+    type Witness = OrWitness<A::Witness, B::Witness>;
+    type Commitment = (A::Commitment, B::Commitment);
+    type Response = (A::Challenge, A::Response, B::Response);
+    type ProverState = OrProverState<A, B>;
+
+    fn commit<R: CryptoRng + RngCore>(
+        statement: &Self::Statement,
+        witness: &Self::Witness,
+        rng: &mut R,
+    ) -> (Self::ProverState, Self::Commitment) {
+        let simulated_challenge = A::Challenge::rand(rng);
+        match witness {
+            OrWitness::Left(witness) => {
+                let (state, commitment_a) = A::commit(&statement.0, witness, rng);
+                let (commitment_b, response_b) =
+                    B::simulate(&statement.1, &simulated_challenge, rng);
+                (
+                    OrProverState::Left(state, simulated_challenge, response_b),
+                    (commitment_a, commitment_b),
+                )
+            },
+            OrWitness::Right(witness) => {
+                let (state, commitment_b) = B::commit(&statement.1, witness, rng);
+                let (commitment_a, response_a) =
+                    A::simulate(&statement.0, &simulated_challenge, rng);
+                (
+                    OrProverState::Right(state, simulated_challenge, response_a),
+                    (commitment_a, commitment_b),
+                )
+            },
+        }
+    }
+
+    fn respond(
+        statement: &Self::Statement,
+        witness: &Self::Witness,
+        state: Self::ProverState,
+        challenge: &Self::Challenge,
+    ) -> Result<Self::Response, PrimitivesError> {
+        match (witness, state) {
+            (OrWitness::Left(witness), OrProverState::Left(state, challenge_b, response_b)) => {
+                let challenge_a = *challenge - challenge_b;
+                let response_a = A::respond(&statement.0, witness, state, &challenge_a)?;
+                Ok((challenge_a, response_a, response_b))
+            },
+            (OrWitness::Right(witness), OrProverState::Right(state, challenge_a, response_a)) => {
+                let challenge_b = *challenge - challenge_a;
+                let response_b = B::respond(&statement.1, witness, state, &challenge_b)?;
+                Ok((challenge_a, response_a, response_b))
+            },
+            _ => Err(ParameterError(
+                "the state is for the other branch of the disjunction".to_string(),
+            )),
+        }
+    }
+
+    fn check(
+        statement: &Self::Statement,
+        commitment: &Self::Commitment,
+        challenge: &Self::Challenge,
+        response: &Self::Response,
+    ) -> bool {
+        let (challenge_a, response_a, response_b) = response;
+        A::check(&statement.0, &commitment.0, challenge_a, response_a)
+            && B::check(
+                &statement.1,
+                &commitment.1,
+                &(*challenge - challenge_a),
+                response_b,
+            )
+    }
+
+    fn simulate<R: CryptoRng + RngCore>(
+        statement: &Self::Statement,
+        challenge: &Self::Challenge,
+        rng: &mut R,
+    ) -> (Self::Commitment, Self::Response) {
+        let challenge_a = A::Challenge::rand(rng);
+        let (commitment_a, response_a) = A::simulate(&statement.0, &challenge_a, rng);
+        let (commitment_b, response_b) =
+            B::simulate(&statement.1, &(*challenge - challenge_a), rng);
+        (
+            (commitment_a, commitment_b),
+            (challenge_a, response_a, response_b),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_ec::Group;
+
+    fn dlog<G: CurveGroup>(rng: &mut impl RngCore) -> (DLogStatement<G>, G::ScalarField) {
+        let x = G::ScalarField::rand(rng);
+        let base = G::rand(rng);
+        (
+            DLogStatement {
+                base,
+                point: base * x,
+            },
+            x,
+        )
+    }
+
+    // Prove `statement` with `witness`, check that the proof verifies, and
+    // that it does not for `other_statement` or under a different transcript.
+    fn prove_and_verify<S: SigmaProtocol>(
+        statement: &S::Statement,
+        witness: &S::Witness,
+        other_statement: &S::Statement,
+    ) {
+        let mut rng = jf_utils::test_rng();
+        let proof = S::prove(
+            &mut IOPTranscript::new(b"test"),
+            statement,
+            witness,
+            &mut rng,
+        )
+        .unwrap();
+        assert!(S::verify(&mut IOPTranscript::new(b"test"), statement, &proof).is_ok());
+        assert!(S::verify(&mut IOPTranscript::new(b"other"), statement, &proof).is_err());
+        assert!(S::verify(&mut IOPTranscript::new(b"test"), other_statement, &proof).is_err());
+        let mut transcript = IOPTranscript::new(b"test");
+        transcript.append_message(b"context", b"context").unwrap();
+        assert!(S::verify(&mut transcript, statement, &proof).is_err());
+
+        // simulated transcripts are accepting
+        let challenge = S::Challenge::rand(&mut rng);
+        let (commitment, response) = S::simulate(statement, &challenge, &mut rng);
+        assert!(S::check(statement, &commitment, &challenge, &response));
+        // but do not make a valid proof
+        let forged = Proof::<S> {
+            commitment,
+            response,
+        };
+        assert!(S::verify(&mut IOPTranscript::new(b"test"), statement, &forged).is_err());
+    }
+
+    fn test_sigma_helper<G: CurveGroup>() {
+        let mut rng = jf_utils::test_rng();
+        let (statement, x) = dlog::<G>(&mut rng);
+        let (other, y) = dlog::<G>(&mut rng);
+
+        // discrete logarithm
+        prove_and_verify::<DLog<G>>(&statement, &x, &other);
+        assert!(DLog::prove(&mut IOPTranscript::new(b"test"), &statement, &y, &mut rng).is_err());
+
+        // equality of discrete logarithms
+        let h = G::rand(&mut rng);
+        let dleq = DleqStatement {
+            bases: [statement.base, h],
+            points: [statement.point, h * x],
+        };
+        let bad_dleq = DleqStatement {
+            bases: [statement.base, h],
+            points: [statement.point, h * y],
+        };
+        prove_and_verify::<Dleq<G>>(&dleq, &x, &bad_dleq);
+        assert!(Dleq::prove(&mut IOPTranscript::new(b"test"), &bad_dleq, &x, &mut rng).is_err());
+
+        // representation
+        let bases: Vec<G> = (0..3).map(|_| G::rand(&mut rng)).collect();
+        let coeffs: Vec<G::ScalarField> = (0..3).map(|_| G::ScalarField::rand(&mut rng)).collect();
+        let representation = RepresentationStatement {
+            point: linear_combination(&bases, &coeffs),
+            bases,
+        };
+        let mut bad_representation = representation.clone();
+        bad_representation.bases.swap(0, 1);
+        prove_and_verify::<Representation<G>>(&representation, &coeffs, &bad_representation);
+        assert!(Representation::prove(
+            &mut IOPTranscript::new(b"test"),
+            &bad_representation,
+            &coeffs,
+            &mut rng
+        )
+        .is_err());
+        assert!(Representation::prove(
+            &mut IOPTranscript::new(b"test"),
+            &representation,
+            &coeffs[..2].to_vec(),
+            &mut rng
+        )
+        .is_err());
+
+        // conjunction
+        let and = (statement.clone(), other.clone());
+        let bad_and = (statement.clone(), statement.clone());
+        prove_and_verify::<And<DLog<G>, DLog<G>>>(&and, &(x, y), &bad_and);
+        assert!(And::<DLog<G>, DLog<G>>::prove(
+            &mut IOPTranscript::new(b"test"),
+            &bad_and,
+            &(x, y),
+            &mut rng
+        )
+        .is_err());
+
+        // disjunction, with a witness for either branch
+        let (unknown, _) = dlog::<G>(&mut rng);
+        let or = (statement.clone(), unknown.clone());
+        let bad_or = (other.clone(), unknown.clone());
+        prove_and_verify::<Or<DLog<G>, DLog<G>>>(&or, &OrWitness::Left(x), &bad_or);
+        let or = (unknown.clone(), statement.clone());
+        let bad_or = (unknown.clone(), other.clone());
+        prove_and_verify::<Or<DLog<G>, DLog<G>>>(&or, &OrWitness::Right(x), &bad_or);
+        assert!(Or::<DLog<G>, DLog<G>>::prove(
+            &mut IOPTranscript::new(b"test"),
+            &or,
+            &OrWitness::Left(x),
+            &mut rng
+        )
+        .is_err());
+
+        // nested composition
+        let nested = ((statement.clone(), dleq), unknown.clone());
+        let bad_nested = ((statement, bad_dleq), unknown);
+        prove_and_verify::<Or<And<DLog<G>, Dleq<G>>, DLog<G>>>(
+            &nested,
+            &OrWitness::Left((x, x)),
+            &bad_nested,
+        );
+    }
+
+    #[test]
+    fn test_sigma() {
+        test_sigma_helper::<ark_bn254::G1Projective>();
+        test_sigma_helper::<ark_bls12_381::G1Projective>();
+        test_sigma_helper::<ark_ed_on_bn254::EdwardsProjective>();
+    }
+
+    #[test]
+    fn test_or_state_mismatch() {
+        type G = ark_bn254::G1Projective;
+        let mut rng = jf_utils::test_rng();
+        let (statement, x) = dlog::<G>(&mut rng);
+        let (other, _) = dlog::<G>(&mut rng);
+        let or = (statement, other);
+        let (state, _) = Or::<DLog<G>, DLog<G>>::commit(&or, &OrWitness::Left(x), &mut rng);
+        let challenge = ark_bn254::Fr::rand(&mut rng);
+        assert!(
+            Or::<DLog<G>, DLog<G>>::respond(&or, &OrWitness::Right(x), state, &challenge).is_err()
+        );
+    }
+
+    #[test]
+    fn test_serde() {
+        type G = ark_bn254::G1Projective;
+        let mut rng = jf_utils::test_rng();
+        let (statement, x) = dlog::<G>(&mut rng);
+        let (unknown, _) = dlog::<G>(&mut rng);
+        let or = (statement, unknown);
+        let proof = Or::<DLog<G>, DLog<G>>::prove(
+            &mut IOPTranscript::new(b"test"),
+            &or,
+            &OrWitness::Left(x),
+            &mut rng,
+        )
+        .unwrap();
+
+        let mut bytes = Vec::new();
+        proof.serialize_compressed(&mut bytes).unwrap();
+        let decoded = Proof::<Or<DLog<G>, DLog<G>>>::deserialize_compressed(&bytes[..]).unwrap();
+        assert_eq!(decoded, proof);
+        assert!(
+            Or::<DLog<G>, DLog<G>>::verify(&mut IOPTranscript::new(b"test"), &or, &decoded).is_ok()
+        );
+        let mut bytes = Vec::new();
+        or.serialize_compressed(&mut bytes).unwrap();
+        assert_eq!(
+            <(DLogStatement<G>, DLogStatement<G>)>::deserialize_compressed(&bytes[..]).unwrap(),
+            or
+        );
+
+        let generator = G::generator();
+        let statement = DLogStatement {
+            base: generator,
+            point: generator * x,
+        };
+        let proof =
+            DLog::prove(&mut IOPTranscript::new(b"test"), &statement, &x, &mut rng).unwrap();
+        let mut bytes = Vec::new();
+        proof.serialize_compressed(&mut bytes).unwrap();
+        assert_eq!(bytes.len(), proof.compressed_size());
+        assert_eq!(
+            Proof::<DLog<G>>::deserialize_compressed(&bytes[..]).unwrap(),
+            proof
+        );
+    }
+}
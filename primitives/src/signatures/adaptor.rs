@@ -0,0 +1,348 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+//! This module implements adaptor signatures on top of the
+//! [Schnorr signature][schnorr] over the various Edwards curves.
+//!
+//! A pre-signature is bound to an adaptor point `T=g^t`: it is checked
+//! against `T` without knowing `t`, and turns into a Schnorr signature once
+//! adapted with `t`. Conversely, anyone holding the pre-signature learns `t`
+//! from the adapted signature, which is the basis of atomic swaps and
+//! payment channels.
+//!
+//! **PreSign(x,m,T)**
+//!    * sample `r`, `R=g^r T`, return `(s'=r+c(X,R,m) x, R)`
+//!
+//! **PreVerify(X,m,T,(s',R))**
+//!    * check that `g^{s'} T=R X^{c(X,R,m)}`
+//!
+//! **Adapt((s',R),t)**
+//!    * return `(s=s'+t, R)`
+//!
+//! **Extract((s',R),(s,R))**
+//!    * return `t=s-s'`
+//!
+//! The nonce is sampled at random rather than derived from the message, as
+//! pre-signing the same message under two adaptor points with the same nonce
+//! would leak the signing key to anyone learning both secrets.
+//!
+//! ```
+//! use ark_ed_on_bn254::{EdwardsConfig, Fq};
+//! use jf_primitives::signatures::{
+//!     adaptor::{AdaptorSecret, PreSignature},
+//!     schnorr::KeyPair,
+//!     SchnorrSignatureScheme, SignatureScheme,
+//! };
+//!
+//! let mut rng = jf_utils::test_rng();
+//! let key_pair = KeyPair::<EdwardsConfig>::generate(&mut rng);
+//! let msg = [Fq::from(42u64)];
+//! let secret = AdaptorSecret::generate(&mut rng);
+//! let point = secret.point();
+//!
+//! let pre_sig = PreSignature::sign(&key_pair, &msg, &point, &mut rng);
+//! assert!(pre_sig.verify(key_pair.ver_key_ref(), &msg, &point).is_ok());
+//!
+//! let sig = pre_sig.adapt(&secret);
+//! assert!(SchnorrSignatureScheme::<EdwardsConfig>::verify(
+//!     &(),
+//!     key_pair.ver_key_ref(),
+//!     &msg,
+//!     &sig
+//! )
+//! .is_ok());
+//! assert_eq!(pre_sig.extract(&sig, &point).unwrap(), secret);
+//! ```
+//!
+//! [schnorr]: crate::signatures::schnorr
+
+use super::schnorr::{KeyPair, Signature, VerKey};
+use crate::{constants::CS_ID_SCHNORR, errors::PrimitivesError, rescue::RescueParameter};
+use ark_ec::{
+    twisted_edwards::{Projective, TECurveConfig as Config},
+    AffineRepr, CurveGroup, Group,
+};
+use ark_ff::Zero;
+use ark_serialize::*;
+use ark_std::{
+    hash::{Hash, Hasher},
+    rand::{CryptoRng, RngCore},
+    string::ToString,
+    UniformRand,
+};
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+/// The secret `t` of an adaptor point.
+#[derive(CanonicalSerialize, CanonicalDeserialize, Derivative)]
+#[derivative(
+    Debug(bound = "P: Config"),
+    Clone(bound = "P: Config"),
+    PartialEq(bound = "P: Config")
+)]
+pub struct AdaptorSecret<P: Config>(P::ScalarField);
+
+impl<P: Config> Drop for AdaptorSecret<P> {
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl<P: Config> ZeroizeOnDrop for AdaptorSecret<P> {}
+
+/// The adaptor point `T=g^t` that a pre-signature is bound to.
+#[derive(CanonicalSerialize, CanonicalDeserialize, Derivative)]
+#[derivative(
+    Debug(bound = "P: Config"),
+    Clone(bound = "P: Config"),
+    Copy(bound = "P: Config")
+)]
+pub struct AdaptorPoint<P: Config>(Projective<P>);
+
+impl<P: Config> PartialEq for AdaptorPoint<P> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.into_affine() == other.0.into_affine()
+    }
+}
+
+impl<P: Config> Eq for AdaptorPoint<P> {}
+
+impl<P: Config> Hash for AdaptorPoint<P> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        Hash::hash(&self.0.into_affine(), state)
+    }
+}
+
+/// A Schnorr pre-signature, bound to an adaptor point.
+#[derive(CanonicalSerialize, CanonicalDeserialize, Derivative)]
+#[derivative(
+    Debug(bound = "P: Config"),
+    Clone(bound = "P: Config"),
+    PartialEq(bound = "P: Config")
+)]
+#[allow(non_snake_case)]
+pub struct PreSignature<P: Config> {
+    s: P::ScalarField,
+    R: Projective<P>,
+}
+
+impl<P: Config> AdaptorSecret<P> {
+    /// Sample a random adaptor secret.
+    pub fn generate<R: CryptoRng + RngCore>(prng: &mut R) -> Self {
+        Self(P::ScalarField::rand(prng))
+    }
+
+    /// The adaptor point of the secret.
+    pub fn point(&self) -> AdaptorPoint<P> {
+        AdaptorPoint(Projective::<P>::generator() * self.0)
+    }
+
+    /// Get the internal of the secret, namely a P::ScalarField element
+    pub fn internal(&self) -> &P::ScalarField {
+        &self.0
+    }
+}
+
+impl<P: Config> From<P::ScalarField> for AdaptorSecret<P> {
+    fn from(t: P::ScalarField) -> Self {
+        Self(t)
+    }
+}
+
+impl<P: Config> AdaptorPoint<P> {
+    /// Get the internal of the adaptor point, namely a curve point
+    pub fn internal(&self) -> &Projective<P> {
+        &self.0
+    }
+}
+
+impl<P: Config> From<Projective<P>> for AdaptorPoint<P> {
+    fn from(point: Projective<P>) -> Self {
+        Self(point)
+    }
+}
+
+impl<F, P> PreSignature<P>
+where
+    F: RescueParameter,
+    P: Config<BaseField = F>,
+{
+    /// Pre-sign `msg` with `key_pair`, bound to the adaptor point `point`.
+    #[allow(non_snake_case)]
+    pub fn sign<R: CryptoRng + RngCore>(
+        key_pair: &KeyPair<P>,
+        msg: &[F],
+        point: &AdaptorPoint<P>,
+        prng: &mut R,
+    ) -> Self {
+        let mut r = P::ScalarField::rand(prng);
+        let R = Projective::<P>::generator() * r + point.0;
+        let c = key_pair.ver_key_ref().challenge(&R, msg, CS_ID_SCHNORR);
+        let s = r + c * key_pair.sign_key_internal();
+        r.zeroize();
+        Self { s, R }
+    }
+
+    /// Verify the pre-signature of `msg` under `vk`, bound to the adaptor
+    /// point `point`.
+    pub fn verify(
+        &self,
+        vk: &VerKey<P>,
+        msg: &[F],
+        point: &AdaptorPoint<P>,
+    ) -> Result<(), PrimitivesError> {
+        // Reject if public key is of small order
+        if vk.to_affine().mul_by_cofactor_to_group().is_zero() {
+            return Err(PrimitivesError::VerificationError(
+                "public key is not valid: not in the correct subgroup".to_string(),
+            ));
+        }
+
+        let c = vk.challenge(&self.R, msg, CS_ID_SCHNORR);
+        let lhs = Projective::<P>::generator() * self.s + point.0;
+        let rhs = self.R + *vk.internal() * c;
+        if lhs == rhs {
+            Ok(())
+        } else {
+            Err(PrimitivesError::VerificationError(
+                "Pre-signature verification error".to_string(),
+            ))
+        }
+    }
+
+    /// Adapt the pre-signature into a Schnorr signature with the secret of
+    /// its adaptor point. The result is only a valid signature if `secret`
+    /// matches the adaptor point of the pre-signature.
+    pub fn adapt(&self, secret: &AdaptorSecret<P>) -> Signature<P> {
+        Signature {
+            s: self.s + secret.0,
+            R: self.R,
+        }
+    }
+
+    /// Extract the secret of the adaptor point `point` from the adaptation
+    /// `sig` of the pre-signature.
+    /// * `returns` - Err() if `sig` is not an adaptation of the pre-signature
+    ///   with the secret of `point`
+    pub fn extract(
+        &self,
+        sig: &Signature<P>,
+        point: &AdaptorPoint<P>,
+    ) -> Result<AdaptorSecret<P>, PrimitivesError> {
+        let secret = AdaptorSecret(sig.s - self.s);
+        if sig.R != self.R || secret.point() != *point {
+            return Err(PrimitivesError::ParameterError(
+                "the signature is not an adaptation of the pre-signature".to_string(),
+            ));
+        }
+        Ok(secret)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::signatures::{SchnorrSignatureScheme, SignatureScheme};
+    use ark_ec::CurveConfig;
+    use ark_ed_on_bls12_377::EdwardsConfig as Param377;
+    use ark_ed_on_bls12_381::EdwardsConfig as Param381;
+    use ark_ed_on_bls12_381_bandersnatch::EdwardsConfig as Param381b;
+    use ark_ed_on_bn254::EdwardsConfig as Param254;
+    use ark_std::vec;
+
+    macro_rules! test_adaptor {
+        ($curve_param:tt) => {
+            let mut rng = jf_utils::test_rng();
+            let key_pair = KeyPair::<$curve_param>::generate(&mut rng);
+            let vk = key_pair.ver_key();
+            let msg = vec![
+                <$curve_param as CurveConfig>::BaseField::rand(&mut rng),
+                <$curve_param as CurveConfig>::BaseField::rand(&mut rng),
+            ];
+            let secret = AdaptorSecret::<$curve_param>::generate(&mut rng);
+            let point = secret.point();
+
+            let pre_sig = PreSignature::sign(&key_pair, &msg, &point, &mut rng);
+            assert!(pre_sig.verify(&vk, &msg, &point).is_ok());
+            assert!(pre_sig.verify(&vk, &msg[..1], &point).is_err());
+            let other_point = AdaptorSecret::<$curve_param>::generate(&mut rng).point();
+            assert!(pre_sig.verify(&vk, &msg, &other_point).is_err());
+            let other_vk = KeyPair::<$curve_param>::generate(&mut rng).ver_key();
+            assert!(pre_sig.verify(&other_vk, &msg, &point).is_err());
+            // a pre-signature is not a signature
+            let sig = Signature {
+                s: pre_sig.s,
+                R: pre_sig.R,
+            };
+            assert!(vk.verify(&msg, &sig, CS_ID_SCHNORR).is_err());
+
+            let sig = pre_sig.adapt(&secret);
+            assert!(SchnorrSignatureScheme::<$curve_param>::verify(&(), &vk, &msg, &sig).is_ok());
+            assert_eq!(pre_sig.extract(&sig, &point).unwrap(), secret);
+            assert!(pre_sig.extract(&sig, &other_point).is_err());
+
+            // adapting with the wrong secret
+            let other_secret = AdaptorSecret::<$curve_param>::generate(&mut rng);
+            let bad_sig = pre_sig.adapt(&other_secret);
+            assert!(vk.verify(&msg, &bad_sig, CS_ID_SCHNORR).is_err());
+            assert!(pre_sig.extract(&bad_sig, &point).is_err());
+
+            // a signature from another pre-signature
+            let other_pre_sig = PreSignature::sign(&key_pair, &msg, &point, &mut rng);
+            let other_sig = other_pre_sig.adapt(&secret);
+            assert!(pre_sig.extract(&other_sig, &point).is_err());
+
+            // the identity adaptor point gives plain Schnorr signatures
+            let zero = AdaptorSecret::from(<$curve_param as CurveConfig>::ScalarField::from(0u64));
+            let pre_sig = PreSignature::sign(&key_pair, &msg, &zero.point(), &mut rng);
+            let sig = Signature {
+                s: pre_sig.s,
+                R: pre_sig.R,
+            };
+            assert!(vk.verify(&msg, &sig, CS_ID_SCHNORR).is_ok());
+
+            // a small order key
+            let small_vk = VerKey(Projective::<$curve_param>::zero());
+            assert!(pre_sig.verify(&small_vk, &msg, &point).is_err());
+        };
+    }
+
+    #[test]
+    fn test_adaptor() {
+        test_adaptor!(Param254);
+        test_adaptor!(Param377);
+        test_adaptor!(Param381);
+        test_adaptor!(Param381b);
+    }
+
+    #[test]
+    fn test_serde() {
+        let mut rng = jf_utils::test_rng();
+        let key_pair = KeyPair::<Param254>::generate(&mut rng);
+        let msg = [<Param254 as CurveConfig>::BaseField::from(7u64)];
+        let secret = AdaptorSecret::<Param254>::generate(&mut rng);
+        let point = secret.point();
+        let pre_sig = PreSignature::sign(&key_pair, &msg, &point, &mut rng);
+
+        let mut bytes = vec![];
+        secret.serialize_compressed(&mut bytes).unwrap();
+        assert_eq!(
+            AdaptorSecret::deserialize_compressed(&bytes[..]).unwrap(),
+            secret
+        );
+        let mut bytes = vec![];
+        point.serialize_compressed(&mut bytes).unwrap();
+        assert_eq!(
+            AdaptorPoint::deserialize_compressed(&bytes[..]).unwrap(),
+            point
+        );
+        let mut bytes = vec![];
+        pre_sig.serialize_compressed(&mut bytes).unwrap();
+        assert_eq!(
+            PreSignature::deserialize_compressed(&bytes[..]).unwrap(),
+            pre_sig
+        );
+    }
+}
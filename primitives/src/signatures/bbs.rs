@@ -0,0 +1,767 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+//! This module implements BBS+ signatures over BLS12-381, as specified by the
+//! [IRTF draft][bbs]: a signature of a vector of messages, from which its
+//! holder derives zero-knowledge proofs of knowledge disclosing any subset of
+//! the messages, the building block of anonymous credentials.
+//!
+//! With the secret key `x`, the verification key `W=P2^x` and the generators
+//! `P1, Q1, H_1, ..., H_L` of G1, the messages `m_1, ..., m_L` are signed by
+//! `(A, e)` where
+//!
+//! `A = B^{1/(x+e)}`, `B = P1 * Q1^domain * prod_i H_i^{m_i}`
+//!
+//! the domain binding the verification key, the generators and a header,
+//! and `e` being derived from the secret key and the messages. It verifies
+//! when `e(A, W * P2^e) = e(B, P2)`.
+//!
+//! A [`Proof`] randomizes the signature into `(Abar, Bbar, D)`, with
+//! `e(Abar, W) = e(Bbar, P2)`, along with a Schnorr proof of knowledge of `e`
+//! and of the undisclosed messages, so that proofs of the same signature are
+//! unlinkable. It is bound to a presentation header, e.g. a nonce of the
+//! verifier preventing replays.
+//!
+//! Messages are scalars: byte strings are mapped to scalars by
+//! [`message_to_scalar`]. Keys, generators and signatures follow the
+//! BLS12-381-SHA-256 ciphersuite [`CS_ID_BBS`] of the draft, and signatures
+//! match its test vectors. Proofs are built along the lines of its `ProofGen`,
+//! but are not checked against its fixtures and are serialized with arkworks
+//! rather than with the octet encoding of the draft: they are only meant to be
+//! verified by this module, not by other implementations of the draft.
+//!
+//! ```
+//! use jf_primitives::signatures::bbs::{message_to_scalar, KeyPair};
+//!
+//! let mut rng = jf_utils::test_rng();
+//! let key_pair = KeyPair::generate(&mut rng);
+//! let vk = key_pair.ver_key();
+//! let messages: Vec<_> = [b"name".as_ref(), b"birth date", b"nationality"]
+//!     .iter()
+//!     .map(|message| message_to_scalar(message))
+//!     .collect();
+//! let sig = key_pair.sign(b"credential", &messages).unwrap();
+//! assert!(vk.verify(b"credential", &messages, &sig).is_ok());
+//!
+//! // disclose the nationality only
+//! let proof = vk
+//!     .prove(&sig, b"credential", b"nonce", &messages, &[2], &mut rng)
+//!     .unwrap();
+//! let disclosed = [(2, messages[2])];
+//! assert!(vk
+//!     .verify_proof(&proof, b"credential", b"nonce", &disclosed)
+//!     .is_ok());
+//! ```
+//!
+//! [bbs]: https://datatracker.ietf.org/doc/draft-irtf-cfrg-bbs-signatures/
+//! [`CS_ID_BBS`]: crate::constants::CS_ID_BBS
+
+use crate::{
+    constants::CS_ID_BBS,
+    errors::PrimitivesError::{self, InternalError, ParameterError, VerificationError},
+    hash_to_field::{expand_message_xmd, hash_to_field},
+    hash_to_group::SWHashToGroup,
+};
+use ark_bls12_381::{g1, Bls12_381, Fr as ScalarField, G1Affine, G1Projective, G2Affine};
+use ark_ec::{pairing::Pairing, AffineRepr, CurveGroup, VariableBaseMSM};
+use ark_ff::{BigInteger, Field, One, PrimeField, UniformRand, Zero};
+use ark_serialize::*;
+use ark_std::{
+    format,
+    rand::{CryptoRng, RngCore},
+    string::ToString,
+    vec,
+    vec::Vec,
+};
+use sha2::Sha256;
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+// The # of bytes of the seeds of the generators.
+const SEED_LEN: usize = 48;
+
+/// The signing key of BBS+ signatures.
+#[derive(Clone, Debug, PartialEq, Eq, Zeroize, CanonicalSerialize, CanonicalDeserialize)]
+pub struct SignKey(ScalarField);
+
+impl Drop for SignKey {
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl ZeroizeOnDrop for SignKey {}
+
+/// The verification key of BBS+ signatures.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, CanonicalSerialize, CanonicalDeserialize)]
+pub struct VerKey(G2Affine);
+
+/// A BBS+ key pair.
+#[derive(Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct KeyPair {
+    sk: SignKey,
+    vk: VerKey,
+}
+
+/// A BBS+ signature of a vector of messages.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, CanonicalSerialize, CanonicalDeserialize)]
+pub struct Signature {
+    a: G1Affine,
+    e: ScalarField,
+}
+
+/// A zero-knowledge proof of knowledge of a signature, disclosing some of its
+/// messages. Its serialization is the arkworks one, not the octet encoding of
+/// the draft.
+#[derive(Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct Proof {
+    a_bar: G1Affine,
+    b_bar: G1Affine,
+    d: G1Affine,
+    e_hat: ScalarField,
+    r1_hat: ScalarField,
+    r3_hat: ScalarField,
+    // the responses for the undisclosed messages, by increasing index
+    m_hat: Vec<ScalarField>,
+    challenge: ScalarField,
+}
+
+/// The generators of the signatures of `L` messages: `P1`, `Q1` and
+/// `H_1, ..., H_L`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Generators {
+    p1: G1Affine,
+    q1: G1Affine,
+    h: Vec<G1Affine>,
+}
+
+impl Generators {
+    /// The generators of the signatures of `num_messages` messages, hashed to
+    /// G1 so that nobody knows their discrete logarithms.
+    pub fn new(num_messages: usize) -> Self {
+        let p1 = create_generators(b"BP_MESSAGE_GENERATOR_SEED", 1)[0];
+        let mut h = create_generators(b"MESSAGE_GENERATOR_SEED", num_messages + 1);
+        let q1 = h.remove(0);
+        Self { p1, q1, h }
+    }
+
+    /// The number of messages `L`.
+    pub fn num_messages(&self) -> usize {
+        self.h.len()
+    }
+
+    /// The base point `P1` of the signatures.
+    pub fn base(&self) -> G1Affine {
+        self.p1
+    }
+
+    /// The generator `Q1` of the domain.
+    pub fn domain_generator(&self) -> G1Affine {
+        self.q1
+    }
+
+    /// The generators `H_1, ..., H_L` of the messages.
+    pub fn message_generators(&self) -> &[G1Affine] {
+        &self.h
+    }
+}
+
+/// Map a byte string to a message scalar.
+pub fn message_to_scalar(message: &[u8]) -> ScalarField {
+    hash_to_scalar(message, b"MAP_MSG_TO_SCALAR_AS_HASH_")
+}
+
+impl SignKey {
+    /// Sample a random signing key.
+    pub fn generate<R: CryptoRng + RngCore>(prng: &mut R) -> Self {
+        let mut sk = ScalarField::rand(prng);
+        while sk.is_zero() {
+            sk = ScalarField::rand(prng);
+        }
+        Self(sk)
+    }
+
+    /// Derive a signing key from the secret `key_material` and the public
+    /// `key_info`, as `KeyGen` of the draft.
+    /// * `returns` - Err() if `key_material` is shorter than 32 bytes or
+    ///   `key_info` longer than 65535 bytes
+    pub fn key_gen(key_material: &[u8], key_info: &[u8]) -> Result<Self, PrimitivesError> {
+        if key_material.len() < 32 {
+            return Err(ParameterError(
+                "BBS+ key material must be at least 32 bytes".to_string(),
+            ));
+        }
+        let key_info_len = u16::try_from(key_info.len())
+            .map_err(|_| ParameterError("BBS+ key info must be at most 65535 bytes".to_string()))?;
+        let mut input = [key_material, &key_info_len.to_be_bytes(), key_info].concat();
+        let sk = hash_to_scalar(&input, b"KEYGEN_DST_");
+        input.zeroize();
+        if sk.is_zero() {
+            return Err(InternalError("zero BBS+ signing key".to_string()));
+        }
+        Ok(Self(sk))
+    }
+}
+
+impl From<&SignKey> for VerKey {
+    fn from(sk: &SignKey) -> Self {
+        Self((G2Affine::generator() * sk.0).into_affine())
+    }
+}
+
+impl KeyPair {
+    /// Sample a random key pair.
+    pub fn generate<R: CryptoRng + RngCore>(prng: &mut R) -> Self {
+        Self::generate_with_sign_key(SignKey::generate(prng))
+    }
+
+    /// The key pair of the signing key `sk`.
+    pub fn generate_with_sign_key(sk: SignKey) -> Self {
+        let vk = VerKey::from(&sk);
+        Self { sk, vk }
+    }
+
+    /// The verification key.
+    pub fn ver_key(&self) -> VerKey {
+        self.vk
+    }
+
+    /// The verification key, by reference.
+    pub fn ver_key_ref(&self) -> &VerKey {
+        &self.vk
+    }
+
+    /// Sign `messages` along with `header`, which is public and is needed to
+    /// verify the signature and its proofs.
+    pub fn sign(
+        &self,
+        header: &[u8],
+        messages: &[ScalarField],
+    ) -> Result<Signature, PrimitivesError> {
+        let generators = Generators::new(messages.len());
+        let domain = domain(&self.vk, &generators, header);
+        let b = signed_point(&generators, domain, messages);
+
+        // `e` is derived from the secret key, so that a bad RNG cannot leak it
+        let mut input = Vec::with_capacity(32 * (messages.len() + 2));
+        input.extend(scalar_to_octets(&self.sk.0));
+        for message in messages {
+            input.extend(scalar_to_octets(message));
+        }
+        input.extend(scalar_to_octets(&domain));
+        let e = hash_to_scalar(&input, b"H2S_");
+        input.zeroize();
+
+        let mut exp = self.sk.0 + e;
+        let a = exp
+            .inverse()
+            .map(|inv| (b * inv).into_affine())
+            .ok_or_else(|| InternalError("degenerate BBS+ signature".to_string()));
+        exp.zeroize();
+        Ok(Signature { a: a?, e })
+    }
+}
+
+impl Signature {
+    /// The point `A` of the signature.
+    pub fn a(&self) -> G1Affine {
+        self.a
+    }
+
+    /// The scalar `e` of the signature.
+    pub fn e(&self) -> ScalarField {
+        self.e
+    }
+}
+
+impl Proof {
+    /// The number of undisclosed messages.
+    pub fn num_undisclosed(&self) -> usize {
+        self.m_hat.len()
+    }
+}
+
+impl VerKey {
+    /// The verification key as a point of G2.
+    pub fn to_affine(&self) -> G2Affine {
+        self.0
+    }
+
+    /// Verify a signature of `messages` along with `header`.
+    /// * `returns` - Err() if the signature is invalid
+    pub fn verify(
+        &self,
+        header: &[u8],
+        messages: &[ScalarField],
+        sig: &Signature,
+    ) -> Result<(), PrimitivesError> {
+        if sig.a.is_zero() {
+            return Err(VerificationError("identity BBS+ signature".to_string()));
+        }
+        let generators = Generators::new(messages.len());
+        let domain = domain(self, &generators, header);
+        let b = signed_point(&generators, domain, messages);
+        let p2 = G2Affine::generator();
+        // `e(A, W * P2^e) * e(B, P2)^-1 = 1`
+        let w = (p2 * sig.e + self.0).into_affine();
+        if Bls12_381::multi_pairing([sig.a, (-b).into_affine()], [w, p2])
+            .0
+            .is_one()
+        {
+            Ok(())
+        } else {
+            Err(VerificationError("invalid BBS+ signature".to_string()))
+        }
+    }
+
+    /// Prove the knowledge of the signature `sig` of `messages` along with
+    /// `header`, disclosing the messages of indices `disclosed`, with the
+    /// presentation header `ph`.
+    /// * `disclosed` - the indices of the disclosed messages, in increasing
+    ///   order
+    /// * `returns` - Err() if the signature is invalid or the indices are not
+    ///   increasing and smaller than the number of messages
+    pub fn prove<R: CryptoRng + RngCore>(
+        &self,
+        sig: &Signature,
+        header: &[u8],
+        ph: &[u8],
+        messages: &[ScalarField],
+        disclosed: &[usize],
+        prng: &mut R,
+    ) -> Result<Proof, PrimitivesError> {
+        check_indices(disclosed.iter(), messages.len())?;
+        self.verify(header, messages, sig)?;
+        let generators = Generators::new(messages.len());
+        let domain = domain(self, &generators, header);
+        let b = signed_point(&generators, domain, messages);
+        let undisclosed: Vec<usize> = (0..messages.len())
+            .filter(|i| disclosed.binary_search(i).is_err())
+            .collect();
+
+        let r1 = nonzero_scalar(prng);
+        let r2 = nonzero_scalar(prng);
+        let e_tilde = ScalarField::rand(prng);
+        let r1_tilde = ScalarField::rand(prng);
+        let r3_tilde = ScalarField::rand(prng);
+        let m_tilde: Vec<ScalarField> = undisclosed
+            .iter()
+            .map(|_| ScalarField::rand(prng))
+            .collect();
+
+        // `D = B^r2`, `Abar = A^(r1*r2)` and `Bbar = D^r1 * Abar^-e`
+        let d = b * r2;
+        let a_bar = sig.a * (r1 * r2);
+        let b_bar = d * r1 - a_bar * sig.e;
+        let t1 = a_bar * e_tilde + d * r1_tilde;
+        let h: Vec<G1Affine> = undisclosed.iter().map(|&i| generators.h[i]).collect();
+        let t2 = d * r3_tilde + G1Projective::msm_unchecked(&h, &m_tilde);
+
+        let points = G1Projective::normalize_batch(&[a_bar, b_bar, d, t1, t2]);
+        let disclosed_messages: Vec<(usize, ScalarField)> =
+            disclosed.iter().map(|&i| (i, messages[i])).collect();
+        let challenge = proof_challenge(&points, &disclosed_messages, domain, ph);
+
+        // safe unwrap: `r2` is not zero
+        let r3 = r2.inverse().unwrap();
+        Ok(Proof {
+            a_bar: points[0],
+            b_bar: points[1],
+            d: points[2],
+            e_hat: e_tilde + sig.e * challenge,
+            r1_hat: r1_tilde - r1 * challenge,
+            r3_hat: r3_tilde - r3 * challenge,
+            m_hat: undisclosed
+                .iter()
+                .zip(m_tilde)
+                .map(|(&i, m_tilde)| m_tilde + messages[i] * challenge)
+                .collect(),
+            challenge,
+        })
+    }
+
+    /// Verify a proof of [`Self::prove`], with the same header and
+    /// presentation header.
+    /// * `disclosed` - the disclosed messages along with their indices, in
+    ///   increasing order of indices
+    /// * `returns` - Err() if the proof is invalid
+    pub fn verify_proof(
+        &self,
+        proof: &Proof,
+        header: &[u8],
+        ph: &[u8],
+        disclosed: &[(usize, ScalarField)],
+    ) -> Result<(), PrimitivesError> {
+        let num_messages = disclosed.len() + proof.m_hat.len();
+        check_indices(disclosed.iter().map(|(i, _)| i), num_messages)?;
+        if proof.a_bar.is_zero() {
+            return Err(VerificationError("identity BBS+ proof".to_string()));
+        }
+        let generators = Generators::new(num_messages);
+        let domain = domain(self, &generators, header);
+        let c = proof.challenge;
+
+        // `T1 = Bbar^c * Abar^e_hat * D^r1_hat`
+        let t1 = G1Projective::msm_unchecked(
+            &[proof.b_bar, proof.a_bar, proof.d],
+            &[c, proof.e_hat, proof.r1_hat],
+        );
+        // `T2 = Bv^c * D^r3_hat * prod_{undisclosed} H_j^m_hat_j`, with `Bv` the
+        // product of `P1 * Q1^domain` and of the disclosed messages
+        let mut bases = vec![generators.p1, generators.q1];
+        let mut scalars = vec![c, domain * c];
+        for (i, m) in disclosed {
+            bases.push(generators.h[*i]);
+            scalars.push(*m * c);
+        }
+        bases.push(proof.d);
+        scalars.push(proof.r3_hat);
+        let undisclosed = (0..num_messages).filter(|i| !disclosed.iter().any(|(j, _)| i == j));
+        for (i, m_hat) in undisclosed.zip(proof.m_hat.iter()) {
+            bases.push(generators.h[i]);
+            scalars.push(*m_hat);
+        }
+        let t2 = G1Projective::msm_unchecked(&bases, &scalars);
+
+        let points = [
+            proof.a_bar,
+            proof.b_bar,
+            proof.d,
+            t1.into_affine(),
+            t2.into_affine(),
+        ];
+        if proof_challenge(&points, disclosed, domain, ph) != c {
+            return Err(VerificationError("invalid BBS+ proof".to_string()));
+        }
+        // `e(Abar, W) * e(Bbar, P2)^-1 = 1`
+        let p2 = G2Affine::generator();
+        if Bls12_381::multi_pairing([proof.a_bar, -proof.b_bar], [self.0, p2])
+            .0
+            .is_one()
+        {
+            Ok(())
+        } else {
+            Err(VerificationError("invalid BBS+ proof".to_string()))
+        }
+    }
+}
+
+// Check that `indices` are increasing and smaller than `num_messages`.
+fn check_indices<'a>(
+    indices: impl Iterator<Item = &'a usize>,
+    num_messages: usize,
+) -> Result<(), PrimitivesError> {
+    let mut next = 0;
+    for &i in indices {
+        if i < next || i >= num_messages {
+            return Err(ParameterError(format!(
+                "disclosed indices must be increasing and smaller than {num_messages}"
+            )));
+        }
+        next = i + 1;
+    }
+    Ok(())
+}
+
+// `B = P1 * Q1^domain * prod_i H_i^{m_i}`
+fn signed_point(
+    generators: &Generators,
+    domain: ScalarField,
+    messages: &[ScalarField],
+) -> G1Projective {
+    generators.q1 * domain + generators.p1 + G1Projective::msm_unchecked(&generators.h, messages)
+}
+
+// The domain of the signatures of `vk` with `generators` and `header`, as
+// `calculate_domain` of the draft.
+fn domain(vk: &VerKey, generators: &Generators, header: &[u8]) -> ScalarField {
+    let mut input = Vec::new();
+    // safe unwraps: serializing to a vector cannot fail. The compressed
+    // encoding of BLS12-381 points of arkworks is the one of the draft.
+    vk.0.serialize_compressed(&mut input).unwrap();
+    input.extend((generators.num_messages() as u64).to_be_bytes());
+    generators.q1.serialize_compressed(&mut input).unwrap();
+    for h in generators.h.iter() {
+        h.serialize_compressed(&mut input).unwrap();
+    }
+    input.extend(CS_ID_BBS.as_bytes());
+    input.extend((header.len() as u64).to_be_bytes());
+    input.extend(header);
+    hash_to_scalar(&input, b"H2S_")
+}
+
+// The Fiat-Shamir challenge of a proof, from the points `Abar, Bbar, D, T1,
+// T2`, after `ProofChallengeCalculate` of the draft.
+fn proof_challenge(
+    points: &[G1Affine],
+    disclosed: &[(usize, ScalarField)],
+    domain: ScalarField,
+    ph: &[u8],
+) -> ScalarField {
+    let mut input = Vec::new();
+    input.extend((disclosed.len() as u64).to_be_bytes());
+    for (i, m) in disclosed {
+        input.extend((*i as u64).to_be_bytes());
+        input.extend(scalar_to_octets(m));
+    }
+    // safe unwraps: serializing to a vector cannot fail
+    for point in points {
+        point.serialize_compressed(&mut input).unwrap();
+    }
+    input.extend(scalar_to_octets(&domain));
+    input.extend((ph.len() as u64).to_be_bytes());
+    input.extend(ph);
+    hash_to_scalar(&input, b"H2S_")
+}
+
+// `create_generators` of the draft: `count` points hashed to G1 from a chain
+// of seeds starting at `seed`.
+fn create_generators(seed: &[u8], count: usize) -> Vec<G1Affine> {
+    let seed_dst = [CS_ID_BBS.as_bytes(), b"SIG_GENERATOR_SEED_"].concat();
+    let generator_dst = [CS_ID_BBS.as_bytes(), b"SIG_GENERATOR_DST_"].concat();
+    // safe unwraps: the DSTs are not empty and the seeds are short
+    let mut v =
+        expand_message_xmd::<Sha256>(&[CS_ID_BBS.as_bytes(), seed].concat(), &seed_dst, SEED_LEN)
+            .unwrap();
+    (1..=count as u64)
+        .map(|i| {
+            v = expand_message_xmd::<Sha256>(
+                &[&v[..], &i.to_be_bytes()].concat(),
+                &seed_dst,
+                SEED_LEN,
+            )
+            .unwrap();
+            g1::Config::hash_to_group(&v[..], &generator_dst[..])
+                .unwrap()
+                .into_affine()
+        })
+        .collect()
+}
+
+// `hash_to_scalar` of the draft, which is `hash_to_field` with 48 bytes per
+// scalar.
+fn hash_to_scalar(input: &[u8], label: &[u8]) -> ScalarField {
+    let dst = [CS_ID_BBS.as_bytes(), label].concat();
+    // safe unwrap: the DST is not empty
+    hash_to_field::<ScalarField>(input, &dst, 1).unwrap()[0]
+}
+
+// `I2OSP(scalar, 32)`: scalars are big-endian in the draft while arkworks
+// serializes them in little-endian.
+fn scalar_to_octets(scalar: &ScalarField) -> Vec<u8> {
+    scalar.into_bigint().to_bytes_be()
+}
+
+fn nonzero_scalar<R: CryptoRng + RngCore>(prng: &mut R) -> ScalarField {
+    loop {
+        let scalar = ScalarField::rand(prng);
+        if !scalar.is_zero() {
+            return scalar;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use jf_utils::test_rng;
+
+    fn from_hex(s: &str) -> Vec<u8> {
+        (0..s.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    fn to_compressed<T: CanonicalSerialize>(point: &T) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        point.serialize_compressed(&mut bytes).unwrap();
+        bytes
+    }
+
+    // test vectors of the BLS12-381-SHA-256 ciphersuite of the draft
+    #[test]
+    fn test_bbs_draft_vectors() {
+        let key_material = from_hex("746869732d49532d6a7573742d616e2d546573742d494b4d2d746f2d67656e65726174652d246528724074232d6b6579");
+        let key_info = from_hex("746869732d49532d736f6d652d6b65792d6d657461646174612d746f2d62652d757365642d696e2d746573742d6b65792d67656e");
+        let sk = SignKey::key_gen(&key_material, &key_info).unwrap();
+        assert_eq!(
+            scalar_to_octets(&sk.0),
+            from_hex("60e55110f76883a13d030b2f6bd11883422d5abde717569fc0731f51237169fc")
+        );
+        let key_pair = KeyPair::generate_with_sign_key(sk);
+        assert_eq!(
+            to_compressed(&key_pair.ver_key().to_affine()),
+            from_hex("a820f230f6ae38503b86c70dc50b61c58a77e45c39ab25c0652bbaa8fa136f2851bd4781c9dcde39fc9d1d52c9e60268061e7d7632171d91aa8d460acee0e96f1e7c4cfb12d3ff9ab5d5dc91c277db75c845d649ef3c4f63aebc364cd55ded0c")
+        );
+        assert!(SignKey::key_gen(&key_material[..31], &key_info).is_err());
+
+        let generators = Generators::new(2);
+        assert_eq!(
+            to_compressed(&generators.base()),
+            from_hex("a8ce256102840821a3e94ea9025e4662b205762f9776b3a766c872b948f1fd225e7c59698588e70d11406d161b4e28c9")
+        );
+        assert_eq!(
+            to_compressed(&generators.domain_generator()),
+            from_hex("a9ec65b70a7fbe40c874c9eb041c2cb0a7af36ccec1bea48fa2ba4c2eb67ef7f9ecb17ed27d38d27cdeddff44c8137be")
+        );
+        assert_eq!(
+            to_compressed(&generators.message_generators()[0]),
+            from_hex("98cd5313283aaf5db1b3ba8611fe6070d19e605de4078c38df36019fbaad0bd28dd090fd24ed27f7f4d22d5ff5dea7d4")
+        );
+        assert_eq!(
+            to_compressed(&generators.message_generators()[1]),
+            from_hex("a31fbe20c5c135bcaa8d9fc4e4ac665cc6db0226f35e737507e803044093f37697a9d452490a970eea6f9ad6c3dcaa3a")
+        );
+
+        let message = message_to_scalar(&from_hex(
+            "9872ad089e452c7b6e283dfac2a80d58e8d0ff71cc4d5e310a1debdda4a45f02",
+        ));
+        assert_eq!(
+            scalar_to_octets(&message),
+            from_hex("1cb5bb86114b34dc438a911617655a1db595abafac92f47c5001799cf624b430")
+        );
+        let header = from_hex("11223344556677889900aabbccddeeff");
+        let sig = key_pair.sign(&header, &[message]).unwrap();
+        assert_eq!(
+            [to_compressed(&sig.a()), scalar_to_octets(&sig.e())].concat(),
+            from_hex("84773160b824e194073a57493dac1a20b667af70cd2352d8af241c77658da5253aa8458317cca0eae615690d55b1f27164657dcafee1d5c1973947aa70e2cfbb4c892340be5969920d0916067b4565a0")
+        );
+        assert!(key_pair.ver_key().verify(&header, &[message], &sig).is_ok());
+    }
+
+    #[test]
+    fn test_bbs_sign_and_verify() {
+        let rng = &mut test_rng();
+        let key_pair = KeyPair::generate(rng);
+        let vk = key_pair.ver_key();
+        let messages: Vec<ScalarField> = (0..5).map(|_| ScalarField::rand(rng)).collect();
+        let sig = key_pair.sign(b"header", &messages).unwrap();
+        assert!(vk.verify(b"header", &messages, &sig).is_ok());
+        // signatures are deterministic
+        assert_eq!(key_pair.sign(b"header", &messages).unwrap(), sig);
+
+        // wrong header, messages, key or signature
+        assert!(vk.verify(b"other header", &messages, &sig).is_err());
+        let mut bad_messages = messages.clone();
+        bad_messages[3] += ScalarField::one();
+        assert!(vk.verify(b"header", &bad_messages, &sig).is_err());
+        assert!(vk.verify(b"header", &messages[..4], &sig).is_err());
+        let other_vk = KeyPair::generate(rng).ver_key();
+        assert!(other_vk.verify(b"header", &messages, &sig).is_err());
+        let bad_sig = Signature {
+            a: sig.a,
+            e: sig.e + ScalarField::one(),
+        };
+        assert!(vk.verify(b"header", &messages, &bad_sig).is_err());
+        let identity = Signature {
+            a: G1Affine::zero(),
+            e: -key_pair.sk.0,
+        };
+        assert!(vk.verify(b"header", &messages, &identity).is_err());
+
+        // no messages
+        let sig = key_pair.sign(b"header", &[]).unwrap();
+        assert!(vk.verify(b"header", &[], &sig).is_ok());
+
+        // serde
+        let mut bytes = Vec::new();
+        sig.serialize_compressed(&mut bytes).unwrap();
+        assert_eq!(Signature::deserialize_compressed(&bytes[..]).unwrap(), sig);
+        let mut bytes = Vec::new();
+        key_pair.serialize_compressed(&mut bytes).unwrap();
+        assert_eq!(
+            KeyPair::deserialize_compressed(&bytes[..]).unwrap(),
+            key_pair
+        );
+    }
+
+    #[test]
+    fn test_bbs_proofs() {
+        let rng = &mut test_rng();
+        let key_pair = KeyPair::generate(rng);
+        let vk = key_pair.ver_key();
+        let messages: Vec<ScalarField> = [b"alice".as_ref(), b"1990-01-01", b"FR", b"student"]
+            .iter()
+            .map(|message| message_to_scalar(message))
+            .collect();
+        let sig = key_pair.sign(b"header", &messages).unwrap();
+
+        for disclosed in [vec![], vec![0], vec![1, 3], vec![0, 1, 2, 3]] {
+            let proof = vk
+                .prove(&sig, b"header", b"ph", &messages, &disclosed, rng)
+                .unwrap();
+            assert_eq!(proof.num_undisclosed(), messages.len() - disclosed.len());
+            let revealed: Vec<(usize, ScalarField)> =
+                disclosed.iter().map(|&i| (i, messages[i])).collect();
+            assert!(vk.verify_proof(&proof, b"header", b"ph", &revealed).is_ok());
+
+            // wrong header, presentation header or key
+            assert!(vk
+                .verify_proof(&proof, b"other header", b"ph", &revealed)
+                .is_err());
+            assert!(vk
+                .verify_proof(&proof, b"header", b"other ph", &revealed)
+                .is_err());
+            let other_vk = KeyPair::generate(rng).ver_key();
+            assert!(other_vk
+                .verify_proof(&proof, b"header", b"ph", &revealed)
+                .is_err());
+            // wrong disclosed message
+            if let Some((_, m)) = revealed.first() {
+                let mut bad_revealed = revealed.clone();
+                bad_revealed[0].1 = *m + ScalarField::one();
+                assert!(vk
+                    .verify_proof(&proof, b"header", b"ph", &bad_revealed)
+                    .is_err());
+            }
+            // tampered proof
+            let mut bad_proof = proof.clone();
+            bad_proof.e_hat += ScalarField::one();
+            assert!(vk
+                .verify_proof(&bad_proof, b"header", b"ph", &revealed)
+                .is_err());
+
+            // proofs of the same signature are unlinkable
+            let other_proof = vk
+                .prove(&sig, b"header", b"ph", &messages, &disclosed, rng)
+                .unwrap();
+            assert_ne!(other_proof.a_bar, proof.a_bar);
+            assert_ne!(other_proof.d, proof.d);
+
+            // serde
+            let mut bytes = Vec::new();
+            proof.serialize_compressed(&mut bytes).unwrap();
+            assert_eq!(Proof::deserialize_compressed(&bytes[..]).unwrap(), proof);
+        }
+
+        // wrong message indices
+        let revealed = [(2, messages[2]), (1, messages[1])];
+        let proof = vk
+            .prove(&sig, b"header", b"ph", &messages, &[1, 2], rng)
+            .unwrap();
+        assert!(vk
+            .verify_proof(&proof, b"header", b"ph", &revealed)
+            .is_err());
+        assert!(vk
+            .verify_proof(
+                &proof,
+                b"header",
+                b"ph",
+                &[(1, messages[1]), (3, messages[3])]
+            )
+            .is_err());
+        for disclosed in [vec![2, 1], vec![1, 1], vec![4]] {
+            assert!(vk
+                .prove(&sig, b"header", b"ph", &messages, &disclosed, rng)
+                .is_err());
+        }
+        // invalid signature
+        let bad_sig = key_pair.sign(b"other header", &messages).unwrap();
+        assert!(vk
+            .prove(&bad_sig, b"header", b"ph", &messages, &[0], rng)
+            .is_err());
+    }
+}
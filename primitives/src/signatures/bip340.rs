@@ -0,0 +1,494 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+//! This module implements the Schnorr signature over secp256k1 specified by
+//! [BIP-340][bip340], so that the signatures interoperate with Bitcoin
+//! tooling.
+//!
+//! Let `G` be the generator of secp256k1 and `n` its order, and let
+//! `hash_tag(x)=SHA256(SHA256(tag)||SHA256(tag)||x)` be the tagged hash.
+//!
+//! **KeyGen()**
+//!    * sample a random `d` in `[1,n-1]`, return the key pair `(d,x(dG))`: the
+//!      public key is the x-only encoding of `dG`, which stands for the point
+//!      of x-coordinate `x(dG)` with an even y-coordinate
+//!
+//! **Sign(d,m)**
+//!    * negate `d` unless `P=dG` has an even y-coordinate
+//!    * `k=hash_nonce((d xor hash_aux(a))||x(P)||m) mod n` for 32 random bytes
+//!      `a`, negate `k` unless `R=kG` has an even y-coordinate
+//!    * `e=hash_challenge(x(R)||x(P)||m) mod n`, return `(x(R),k+ed mod n)`
+//!
+//! **Verify(x(P),m,(r,s))**
+//!    * `R=sG-eP` where `P` is the point of x-coordinate `x(P)` with an even
+//!      y-coordinate and `e=hash_challenge(r||x(P)||m) mod n`
+//!    * check that `R` is not the identity, that `R` has an even y-coordinate
+//!      and that `x(R)=r`
+//!
+//! Keys and signatures are stored in their BIP-340 byte encodings.
+//!
+//! [bip340]: https://github.com/bitcoin/bips/blob/master/bip-0340.mediawiki
+
+use super::SignatureScheme;
+use crate::{
+    constants::CS_ID_BIP340,
+    errors::PrimitivesError,
+    utils::{bytes_from_int, int_from_bytes},
+};
+use ark_ec::{AffineRepr, CurveGroup, Group};
+use ark_ff::{BigInteger, PrimeField, Zero};
+use ark_secp256k1::{Affine, Fq, Fr, Projective};
+use ark_std::{
+    rand::{CryptoRng, RngCore},
+    string::ToString,
+    UniformRand,
+};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+/// Tag of the hash of the auxiliary randomness.
+const TAG_AUX: &str = "BIP0340/aux";
+
+/// Tag of the hash deriving the nonce.
+const TAG_NONCE: &str = "BIP0340/nonce";
+
+/// BIP-340 Schnorr signature scheme over secp256k1.
+#[derive(Debug, PartialEq, Clone)]
+pub struct BIP340SignatureScheme;
+
+impl SignatureScheme for BIP340SignatureScheme {
+    const CS_ID: &'static str = CS_ID_BIP340;
+
+    /// Signing key.
+    type SigningKey = SignKey;
+
+    /// Verification key
+    type VerificationKey = VerKey;
+
+    /// Public Parameter
+    type PublicParameter = ();
+
+    /// Signature
+    type Signature = Signature;
+
+    /// A message is &\[MessageUnit\]
+    type MessageUnit = u8;
+
+    /// generate public parameters from RNG.
+    fn param_gen<R: CryptoRng + RngCore>(
+        _prng: Option<&mut R>,
+    ) -> Result<Self::PublicParameter, PrimitivesError> {
+        Ok(())
+    }
+
+    /// Sample a pair of keys.
+    fn key_gen<R: CryptoRng + RngCore>(
+        _pp: &Self::PublicParameter,
+        prng: &mut R,
+    ) -> Result<(Self::SigningKey, Self::VerificationKey), PrimitivesError> {
+        let mut d = Fr::zero();
+        while d.is_zero() {
+            d = Fr::rand(prng);
+        }
+        let sk = SignKey::from_scalar(&d);
+        let vk = sk.ver_key();
+        d.zeroize();
+        Ok((sk, vk))
+    }
+
+    /// Sign a message with the signing key, using 32 bytes of auxiliary
+    /// randomness from `prng`.
+    fn sign<R: CryptoRng + RngCore, M: AsRef<[Self::MessageUnit]>>(
+        _pp: &Self::PublicParameter,
+        sk: &Self::SigningKey,
+        msg: M,
+        prng: &mut R,
+    ) -> Result<Self::Signature, PrimitivesError> {
+        let mut aux_rand = [0u8; 32];
+        prng.fill_bytes(&mut aux_rand);
+        sk.sign_with_aux_rand(msg.as_ref(), &aux_rand)
+    }
+
+    /// Verify a signature.
+    fn verify<M: AsRef<[Self::MessageUnit]>>(
+        _pp: &Self::PublicParameter,
+        vk: &Self::VerificationKey,
+        msg: M,
+        sig: &Self::Signature,
+    ) -> Result<(), PrimitivesError> {
+        vk.verify(msg.as_ref(), sig)
+    }
+}
+
+// =====================================================
+// Signing key
+// =====================================================
+
+/// Signing key for BIP-340 signature, i.e. a 32-byte big-endian scalar in
+/// `[1,n-1]`, which is checked upon deserialization.
+#[derive(Clone, Debug, Zeroize, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "[u8; 32]")]
+pub struct SignKey([u8; 32]);
+
+impl Drop for SignKey {
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl ZeroizeOnDrop for SignKey {}
+
+impl TryFrom<[u8; 32]> for SignKey {
+    type Error = PrimitivesError;
+
+    fn try_from(mut bytes: [u8; 32]) -> Result<Self, Self::Error> {
+        let sk = Self::from_bytes(&bytes);
+        bytes.zeroize();
+        sk
+    }
+}
+
+impl SignKey {
+    /// Parse a signing key from its 32-byte encoding.
+    /// * `returns` - Err() if the key is zero or not less than the group order
+    pub fn from_bytes(bytes: &[u8; 32]) -> Result<Self, PrimitivesError> {
+        match int_from_bytes::<Fr>(bytes) {
+            Some(d) if !d.is_zero() => Ok(Self(*bytes)),
+            _ => Err(PrimitivesError::ParameterError(
+                "signing key out of range".to_string(),
+            )),
+        }
+    }
+
+    /// The 32-byte encoding of the signing key.
+    pub fn to_bytes(&self) -> [u8; 32] {
+        self.0
+    }
+
+    /// The x-only verification key of the signing key.
+    pub fn ver_key(&self) -> VerKey {
+        let p = (Projective::generator() * self.scalar()).into_affine();
+        VerKey(bytes_from_int(&p.x))
+    }
+
+    /// Sign a message with the given 32 bytes of auxiliary randomness, as
+    /// specified by BIP-340 for its test vectors.
+    #[allow(non_snake_case)]
+    pub fn sign_with_aux_rand(
+        &self,
+        msg: &[u8],
+        aux_rand: &[u8; 32],
+    ) -> Result<Signature, PrimitivesError> {
+        let mut d0 = self.scalar();
+        let P = (Projective::generator() * d0).into_affine();
+        let mut d = if has_even_y(&P) { d0 } else { -d0 };
+        d0.zeroize();
+        let p_bytes = bytes_from_int(&P.x);
+
+        let mut t = bytes_from_int(&d);
+        for (t_byte, aux_byte) in t.iter_mut().zip(tagged_hash(TAG_AUX, &[&aux_rand[..]])) {
+            *t_byte ^= aux_byte;
+        }
+        let mut rand = tagged_hash(TAG_NONCE, &[&t[..], &p_bytes[..], msg]);
+        t.zeroize();
+        let mut k0 = Fr::from_be_bytes_mod_order(&rand);
+        rand.zeroize();
+        if k0.is_zero() {
+            d.zeroize();
+            return Err(PrimitivesError::InternalError(
+                "BIP-340 nonce is zero".to_string(),
+            ));
+        }
+        let R = (Projective::generator() * k0).into_affine();
+        let mut k = if has_even_y(&R) { k0 } else { -k0 };
+        k0.zeroize();
+        let r_bytes = bytes_from_int(&R.x);
+
+        let e = challenge(&r_bytes, &p_bytes, msg);
+        let s = k + e * d;
+        d.zeroize();
+        k.zeroize();
+        Ok(Signature {
+            r: r_bytes,
+            s: bytes_from_int(&s),
+        })
+    }
+
+    fn from_scalar(d: &Fr) -> Self {
+        Self(bytes_from_int(d))
+    }
+
+    // the key is validated by `from_bytes` and upon deserialization
+    fn scalar(&self) -> Fr {
+        Fr::from_be_bytes_mod_order(&self.0)
+    }
+}
+
+// =====================================================
+// Verification key
+// =====================================================
+
+/// Signature public verification key, i.e. the 32-byte x-only encoding of a
+/// curve point.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub struct VerKey([u8; 32]);
+
+impl VerKey {
+    /// Parse a verification key from its 32-byte x-only encoding.
+    /// * `returns` - Err() if the encoding is not the x-coordinate of a point
+    pub fn from_bytes(bytes: &[u8; 32]) -> Result<Self, PrimitivesError> {
+        lift_x(bytes)?;
+        Ok(Self(*bytes))
+    }
+
+    /// The 32-byte x-only encoding of the verification key.
+    pub fn to_bytes(&self) -> [u8; 32] {
+        self.0
+    }
+
+    /// Signature verification function
+    #[allow(non_snake_case)]
+    pub fn verify(&self, msg: &[u8], sig: &Signature) -> Result<(), PrimitivesError> {
+        let P = lift_x(&self.0)?;
+        let r = int_from_bytes::<Fq>(&sig.r).ok_or_else(|| {
+            PrimitivesError::VerificationError("signature r out of range".to_string())
+        })?;
+        let s = int_from_bytes::<Fr>(&sig.s).ok_or_else(|| {
+            PrimitivesError::VerificationError("signature s out of range".to_string())
+        })?;
+        let e = challenge(&sig.r, &self.0, msg);
+        let R = (Projective::generator() * s - P * e).into_affine();
+        if R.is_zero() || !has_even_y(&R) || R.x != r {
+            return Err(PrimitivesError::VerificationError(
+                "Signature verification error".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+// =====================================================
+// Signature
+// =====================================================
+
+/// The signature of BIP-340 signature scheme, i.e. the x-coordinate `r` of
+/// the nonce point and the scalar `s`, both as 32-byte big-endian integers.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub struct Signature {
+    r: [u8; 32],
+    s: [u8; 32],
+}
+
+impl Signature {
+    /// Parse a signature from its 64-byte encoding `r||s`. The encoding is
+    /// checked upon verification.
+    pub fn from_bytes(bytes: &[u8; 64]) -> Self {
+        let mut r = [0u8; 32];
+        let mut s = [0u8; 32];
+        r.copy_from_slice(&bytes[..32]);
+        s.copy_from_slice(&bytes[32..]);
+        Self { r, s }
+    }
+
+    /// The 64-byte encoding `r||s` of the signature.
+    pub fn to_bytes(&self) -> [u8; 64] {
+        let mut bytes = [0u8; 64];
+        bytes[..32].copy_from_slice(&self.r);
+        bytes[32..].copy_from_slice(&self.s);
+        bytes
+    }
+}
+
+// =====================================================
+// end of definitions
+// =====================================================
+
+// `SHA256(SHA256(tag)||SHA256(tag)||chunks)`
+fn tagged_hash(tag: &str, chunks: &[&[u8]]) -> [u8; 32] {
+    let tag_hash = Sha256::digest(tag.as_bytes());
+    let mut hasher = Sha256::new();
+    hasher.update(&tag_hash);
+    hasher.update(&tag_hash);
+    for chunk in chunks {
+        hasher.update(chunk);
+    }
+    hasher.finalize().into()
+}
+
+fn challenge(r_bytes: &[u8; 32], p_bytes: &[u8; 32], msg: &[u8]) -> Fr {
+    Fr::from_be_bytes_mod_order(&tagged_hash(
+        CS_ID_BIP340,
+        &[&r_bytes[..], &p_bytes[..], msg],
+    ))
+}
+
+fn has_even_y(point: &Affine) -> bool {
+    point.y.into_bigint().is_even()
+}
+
+// The point of x-coordinate `bytes` with an even y-coordinate.
+fn lift_x(bytes: &[u8; 32]) -> Result<Affine, PrimitivesError> {
+    let point = int_from_bytes::<Fq>(bytes)
+        .and_then(|x| Affine::get_point_from_x_unchecked(x, true))
+        .ok_or_else(|| {
+            PrimitivesError::VerificationError(
+                "public key is not valid: not the x-coordinate of a point".to_string(),
+            )
+        })?;
+    Ok(if has_even_y(&point) { point } else { -point })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::signatures::tests::{failed_verification, sign_and_verify};
+    use ark_std::vec::Vec;
+
+    fn from_hex<const N: usize>(hex: &str) -> [u8; N] {
+        let bytes: Vec<u8> = (0..hex.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+            .collect();
+        bytes.try_into().unwrap()
+    }
+
+    #[test]
+    fn test_bip340_vectors() {
+        // (secret key, public key, aux_rand, message, signature) from
+        // https://github.com/bitcoin/bips/blob/master/bip-0340/test-vectors.csv
+        let vectors = [
+            (
+                "0000000000000000000000000000000000000000000000000000000000000003",
+                "F9308A019258C31049344F85F89D5229B531C845836F99B08601F113BCE036F9",
+                "0000000000000000000000000000000000000000000000000000000000000000",
+                "0000000000000000000000000000000000000000000000000000000000000000",
+                "E907831F80848D1069A5371B402410364BDF1C5F8307B0084C55F1CE2DCA821525F66A4A85EA8B71E482A74F382D2CE5EBEEE8FDB2172F477DF4900D310536C0",
+            ),
+            (
+                "B7E151628AED2A6ABF7158809CF4F3C762E7160F38B4DA56A784D9045190CFEF",
+                "DFF1D77F2A671C5F36183726DB2341BE58FEAE1DA2DECED843240F7B502BA659",
+                "0000000000000000000000000000000000000000000000000000000000000001",
+                "243F6A8885A308D313198A2E03707344A4093822299F31D0082EFA98EC4E6C89",
+                "6896BD60EEAE296DB48A229FF71DFE071BDE413E6D43F917DC8DCF8C78DE33418906D11AC976ABCCB20B091292BFF4EA897EFCB639EA871CFA95F6DE339E4B0A",
+            ),
+            (
+                "C90FDAA22168C234C4C6628B80DC1CD129024E088A67CC74020BBEA63B14E5C9",
+                "DD308AFEC5777E13121FA72B9CC1B7CC0139715309B086C960E18FD969774EB8",
+                "C87AA53824B4D7AE2EB035A2B5BBBCCC080E76CDC6D1692C4B0B62D798E6D906",
+                "7E2D58D8B3BCDF1ABADEC7829054F90DDA9805AAB56C77333024B9D0A508B75C",
+                "5831AAEED7B44BB74E5EAB94BA9D4294C49BCF2A60728D8B4C200F50DD313C1BAB745879A5AD954A72C45A91C3A51D3C7ADEA98D82F8481E0E1E03674A6F3FB7",
+            ),
+            (
+                "0B432B2677937381AEF05BB02A66ECD012773062CF3FA2549E44F58ED2401710",
+                "25D1DFF95105F5253C4022F628A996AD3A0D95FBF21D468A1B33F8C160D8F517",
+                "FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF",
+                "FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF",
+                "7EB0509757E246F19449885651611CB965ECC1A187DD51B64FDA1EDC9637D5EC97582B9CB13DB3933705B32BA982AF5AF25FD78881EBB32771FC5922EFC66EA3",
+            ),
+        ];
+        for (sk, vk, aux_rand, msg, sig) in vectors {
+            let sk = SignKey::from_bytes(&from_hex(sk)).unwrap();
+            let vk = VerKey::from_bytes(&from_hex(vk)).unwrap();
+            let msg: [u8; 32] = from_hex(msg);
+            let expected = Signature::from_bytes(&from_hex(sig));
+            assert_eq!(sk.ver_key(), vk);
+            let sig = sk.sign_with_aux_rand(&msg, &from_hex(aux_rand)).unwrap();
+            assert_eq!(sig, expected);
+            assert!(BIP340SignatureScheme::verify(&(), &vk, msg, &sig).is_ok());
+        }
+
+        // verification only
+        let vk = VerKey::from_bytes(&from_hex(
+            "D69C3509BB99E412E68B0FE8544E72837DFA30746D8BE2AA65975F29D22DC7B9",
+        ))
+        .unwrap();
+        let msg: [u8; 32] =
+            from_hex("4DF3C3F68FCC83B27E9D42C90431A72499F17875C81A599B566C9889B9696703");
+        let sig = Signature::from_bytes(&from_hex(
+            "00000000000000000000003B78CE563F89A0ED9414F5AA28AD0D96D6795F9C6376AFB1548AF603B3EB45C9F8207DEE1060CB71C04E80F593060B07D28308D7F4",
+        ));
+        assert!(vk.verify(&msg, &sig).is_ok());
+    }
+
+    #[test]
+    fn test_bip340_invalid() {
+        let sk = SignKey::from_bytes(&from_hex(
+            "B7E151628AED2A6ABF7158809CF4F3C762E7160F38B4DA56A784D9045190CFEF",
+        ))
+        .unwrap();
+        let vk = sk.ver_key();
+        let msg: [u8; 32] =
+            from_hex("243F6A8885A308D313198A2E03707344A4093822299F31D0082EFA98EC4E6C89");
+        let sig = sk.sign_with_aux_rand(&msg, &[1u8; 32]).unwrap();
+        assert!(vk.verify(&msg, &sig).is_ok());
+
+        // wrong message
+        assert!(vk.verify(&msg[1..], &sig).is_err());
+        // negated s
+        let s = -int_from_bytes::<Fr>(&sig.s).unwrap();
+        let bad_sig = Signature {
+            r: sig.r,
+            s: bytes_from_int(&s),
+        };
+        assert!(vk.verify(&msg, &bad_sig).is_err());
+        // r not less than the field size
+        let bad_sig = Signature {
+            r: from_hex("FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEFFFFFC2F"),
+            s: sig.s,
+        };
+        assert!(vk.verify(&msg, &bad_sig).is_err());
+        // s not less than the group order
+        let bad_sig = Signature {
+            r: sig.r,
+            s: from_hex("FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEBAAEDCE6AF48A03BBFD25E8CD0364141"),
+        };
+        assert!(vk.verify(&msg, &bad_sig).is_err());
+
+        // public key not on the curve, or not less than the field size
+        let bad_vk = "EEFDEA4CDB677750A420FEE807EACF21EB9898AE79B9768766E4FAA04A2D4A34";
+        assert!(VerKey::from_bytes(&from_hex(bad_vk)).is_err());
+        assert!(VerKey(from_hex(bad_vk)).verify(&msg, &sig).is_err());
+        let bad_vk = "FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEFFFFFC30";
+        assert!(VerKey::from_bytes(&from_hex(bad_vk)).is_err());
+
+        // signing keys out of range
+        assert!(SignKey::from_bytes(&[0u8; 32]).is_err());
+        assert!(SignKey::from_bytes(&from_hex(
+            "FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEBAAEDCE6AF48A03BBFD25E8CD0364141"
+        ))
+        .is_err());
+    }
+
+    #[test]
+    fn test_sig_trait() {
+        let message = [87u8, 32u8];
+        let wrong_message = [255u8];
+        sign_and_verify::<BIP340SignatureScheme>(&message);
+        sign_and_verify::<BIP340SignatureScheme>(&[]);
+        failed_verification::<BIP340SignatureScheme>(&message, &wrong_message);
+    }
+
+    #[test]
+    fn test_serde() {
+        let mut rng = jf_utils::test_rng();
+        let (sk, vk) = BIP340SignatureScheme::key_gen(&(), &mut rng).unwrap();
+        let sig = BIP340SignatureScheme::sign(&(), &sk, [1u8, 2, 3], &mut rng).unwrap();
+        assert_eq!(SignKey::from_bytes(&sk.to_bytes()).unwrap(), sk);
+        assert_eq!(VerKey::from_bytes(&vk.to_bytes()).unwrap(), vk);
+        assert_eq!(Signature::from_bytes(&sig.to_bytes()), sig);
+        assert_eq!(sk.ver_key(), vk);
+
+        let bytes = bincode::serialize(&sk).unwrap();
+        assert_eq!(bincode::deserialize::<SignKey>(&bytes).unwrap(), sk);
+        // out-of-range keys are rejected
+        let order: [u8; 32] = Fr::MODULUS.to_bytes_be().try_into().unwrap();
+        for d in [[0u8; 32], order, [0xff; 32]] {
+            let bytes = bincode::serialize(&d).unwrap();
+            assert!(bincode::deserialize::<SignKey>(&bytes).is_err());
+        }
+    }
+}
@@ -0,0 +1,349 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+//! This module implements the three-move blind [Schnorr signature][schnorr]
+//! protocol over the various Edwards curves, in its [clause][clause] variant.
+//!
+//! A user obtains a signature of a message from a signer who learns neither
+//! the message nor the signature. The plain blind Schnorr protocol is not
+//! secure once the signer runs many sessions concurrently, due to the ROS
+//! attack. The clause variant runs two instances of the protocol in parallel,
+//! of which the signer completes a single one chosen at random, which defeats
+//! the attack.
+//!
+//! **Commit (signer)**
+//!    * sample `r_0,r_1`, send `R_i=g^{r_i}`
+//!
+//! **Challenge (user)**
+//!    * for `i=0,1`, sample `alpha_i,beta_i`
+//!    * `R'_i=R_i g^{alpha_i} X^{beta_i}`, `c'_i=c(X,R'_i,m)`
+//!    * send `c_i=c'_i+beta_i`
+//!
+//! **Respond (signer)**
+//!    * sample a bit `b`, send `(b, s=r_b+c_b x)`
+//!
+//! **Finalize (user)**
+//!    * check that `g^s=R_b X^{c_b}`, return `(s+alpha_b, R'_b)`
+//!
+//! The signature is an ordinary Schnorr signature, and verifies with
+//! [`SchnorrSignatureScheme::verify`].
+//!
+//! ```
+//! use ark_ed_on_bn254::{EdwardsConfig, Fq};
+//! use jf_primitives::signatures::{
+//!     blind_schnorr::{SignerSession, UserSession},
+//!     schnorr::KeyPair,
+//!     SchnorrSignatureScheme, SignatureScheme,
+//! };
+//!
+//! let mut rng = jf_utils::test_rng();
+//! let key_pair = KeyPair::<EdwardsConfig>::generate(&mut rng);
+//! let msg = [Fq::from(42u64)];
+//!
+//! let (signer, commitment) = SignerSession::commit(&mut rng);
+//! let (user, challenge) =
+//!     UserSession::challenge(key_pair.ver_key_ref(), &commitment, &msg, &mut rng).unwrap();
+//! let response = signer.respond(&key_pair, &challenge, &mut rng);
+//! let sig = user.finalize(&response).unwrap();
+//!
+//! assert!(SchnorrSignatureScheme::<EdwardsConfig>::verify(
+//!     &(),
+//!     key_pair.ver_key_ref(),
+//!     &msg,
+//!     &sig
+//! )
+//! .is_ok());
+//! ```
+//!
+//! [schnorr]: crate::signatures::schnorr
+//! [clause]: https://eprint.iacr.org/2019/877
+//! [`SchnorrSignatureScheme::verify`]: crate::signatures::SignatureScheme::verify
+
+use super::schnorr::{KeyPair, Signature, VerKey};
+use crate::{constants::CS_ID_SCHNORR, errors::PrimitivesError, rescue::RescueParameter};
+use ark_ec::{
+    twisted_edwards::{Projective, TECurveConfig as Config},
+    AffineRepr, Group,
+};
+use ark_ff::Zero;
+use ark_serialize::*;
+use ark_std::{
+    rand::{CryptoRng, RngCore},
+    string::ToString,
+    UniformRand,
+};
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+/// The state of the signer between the commitment and the response.
+pub struct SignerSession<P: Config>([P::ScalarField; 2]);
+
+impl<P: Config> Drop for SignerSession<P> {
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl<P: Config> ZeroizeOnDrop for SignerSession<P> {}
+
+/// The nonces of the two parallel instances, sent by the signer to the user.
+#[derive(CanonicalSerialize, CanonicalDeserialize, Derivative)]
+#[derivative(
+    Debug(bound = "P: Config"),
+    Clone(bound = "P: Config"),
+    PartialEq(bound = "P: Config")
+)]
+pub struct SignerCommitment<P: Config>([Projective<P>; 2]);
+
+/// The blinded challenges of the two parallel instances, sent by the user to
+/// the signer.
+#[derive(CanonicalSerialize, CanonicalDeserialize, Derivative)]
+#[derivative(
+    Debug(bound = "P: Config"),
+    Clone(bound = "P: Config"),
+    PartialEq(bound = "P: Config")
+)]
+pub struct UserChallenge<P: Config>([P::ScalarField; 2]);
+
+/// The answer of the signer to one of the two parallel instances, sent to
+/// the user.
+#[derive(CanonicalSerialize, CanonicalDeserialize, Derivative)]
+#[derivative(
+    Debug(bound = "P: Config"),
+    Clone(bound = "P: Config"),
+    PartialEq(bound = "P: Config")
+)]
+pub struct SignerResponse<P: Config> {
+    // the index of the completed instance
+    bit: bool,
+    s: P::ScalarField,
+}
+
+/// The state of the user between the challenge and the signature.
+#[allow(non_snake_case)]
+pub struct UserSession<P: Config> {
+    vk: VerKey<P>,
+    commitment: SignerCommitment<P>,
+    challenges: [P::ScalarField; 2],
+    // the blinding factors `alpha_i`
+    alphas: [P::ScalarField; 2],
+    // the blinded nonces `R'_i`
+    blinded_R: [Projective<P>; 2],
+}
+
+impl<P: Config> Drop for UserSession<P> {
+    fn drop(&mut self) {
+        self.alphas.zeroize();
+    }
+}
+
+impl<P: Config> ZeroizeOnDrop for UserSession<P> {}
+
+impl<P: Config> SignerSession<P> {
+    /// Start a signing session, producing the commitment of the signer.
+    pub fn commit<R: CryptoRng + RngCore>(prng: &mut R) -> (Self, SignerCommitment<P>) {
+        let mut r = [P::ScalarField::rand(prng), P::ScalarField::rand(prng)];
+        let g = Projective::<P>::generator();
+        let commitment = SignerCommitment([g * r[0], g * r[1]]);
+        let session = Self(r);
+        r.zeroize();
+        (session, commitment)
+    }
+
+    /// Answer the challenge of the user with the key of `key_pair`, consuming
+    /// the session. Only one of the two instances is completed, chosen at
+    /// random.
+    pub fn respond<R: CryptoRng + RngCore>(
+        self,
+        key_pair: &KeyPair<P>,
+        challenge: &UserChallenge<P>,
+        prng: &mut R,
+    ) -> SignerResponse<P> {
+        let bit = prng.next_u32() & 1 == 1;
+        let i = bit as usize;
+        SignerResponse {
+            bit,
+            s: self.0[i] + challenge.0[i] * key_pair.sign_key_internal(),
+        }
+    }
+}
+
+impl<F, P> UserSession<P>
+where
+    F: RescueParameter,
+    P: Config<BaseField = F>,
+{
+    /// Blind the commitment of the signer for signing `msg` under `vk`,
+    /// producing the challenge sent to the signer.
+    /// * `returns` - Err() if `vk` is of small order
+    #[allow(non_snake_case)]
+    pub fn challenge<R: CryptoRng + RngCore>(
+        vk: &VerKey<P>,
+        commitment: &SignerCommitment<P>,
+        msg: &[F],
+        prng: &mut R,
+    ) -> Result<(Self, UserChallenge<P>), PrimitivesError> {
+        if vk.to_affine().mul_by_cofactor_to_group().is_zero() {
+            return Err(PrimitivesError::ParameterError(
+                "public key is not valid: not in the correct subgroup".to_string(),
+            ));
+        }
+        let g = Projective::<P>::generator();
+        let mut alphas = [P::ScalarField::zero(); 2];
+        let mut blinded_R = [Projective::<P>::zero(); 2];
+        let mut challenges = [P::ScalarField::zero(); 2];
+        for i in 0..2 {
+            let alpha = P::ScalarField::rand(prng);
+            let beta = P::ScalarField::rand(prng);
+            let R = commitment.0[i] + g * alpha + *vk.internal() * beta;
+            let c = vk.challenge(&R, msg, CS_ID_SCHNORR);
+            alphas[i] = alpha;
+            blinded_R[i] = R;
+            challenges[i] = c + beta;
+        }
+        let session = Self {
+            vk: vk.clone(),
+            commitment: commitment.clone(),
+            challenges,
+            alphas,
+            blinded_R,
+        };
+        Ok((session, UserChallenge(challenges)))
+    }
+
+    /// Unblind the response of the signer into a Schnorr signature, consuming
+    /// the session.
+    /// * `returns` - Err() if the response is invalid
+    pub fn finalize(self, response: &SignerResponse<P>) -> Result<Signature<P>, PrimitivesError> {
+        let i = response.bit as usize;
+        let lhs = Projective::<P>::generator() * response.s;
+        let rhs = self.commitment.0[i] + *self.vk.internal() * self.challenges[i];
+        if lhs != rhs {
+            return Err(PrimitivesError::VerificationError(
+                "Signer response verification error".to_string(),
+            ));
+        }
+        Ok(Signature {
+            s: response.s + self.alphas[i],
+            R: self.blinded_R[i],
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::signatures::{SchnorrSignatureScheme, SignatureScheme};
+    use ark_ec::CurveConfig;
+    use ark_ed_on_bls12_377::EdwardsConfig as Param377;
+    use ark_ed_on_bls12_381::EdwardsConfig as Param381;
+    use ark_ed_on_bls12_381_bandersnatch::EdwardsConfig as Param381b;
+    use ark_ed_on_bn254::EdwardsConfig as Param254;
+    use ark_std::vec;
+
+    macro_rules! test_blind_schnorr {
+        ($curve_param:tt) => {
+            let mut rng = jf_utils::test_rng();
+            let key_pair = KeyPair::<$curve_param>::generate(&mut rng);
+            let vk = key_pair.ver_key();
+            let msg = vec![
+                <$curve_param as CurveConfig>::BaseField::rand(&mut rng),
+                <$curve_param as CurveConfig>::BaseField::rand(&mut rng),
+            ];
+
+            // both instances are completed over the sessions
+            let mut bits = [false; 2];
+            for _ in 0..16 {
+                let (signer, commitment) = SignerSession::commit(&mut rng);
+                let (user, challenge) =
+                    UserSession::challenge(&vk, &commitment, &msg, &mut rng).unwrap();
+                let response = signer.respond(&key_pair, &challenge, &mut rng);
+                bits[response.bit as usize] = true;
+                let sig = user.finalize(&response).unwrap();
+                assert!(
+                    SchnorrSignatureScheme::<$curve_param>::verify(&(), &vk, &msg, &sig).is_ok()
+                );
+                assert!(vk.verify(&msg[..1], &sig, CS_ID_SCHNORR).is_err());
+                // the signature is unlinkable to the session
+                assert!(!commitment.0.contains(&sig.R));
+                assert!(!challenge
+                    .0
+                    .contains(&vk.challenge(&sig.R, &msg, CS_ID_SCHNORR)));
+            }
+            assert_eq!(bits, [true, true]);
+
+            // an invalid response
+            let (signer, commitment) = SignerSession::commit(&mut rng);
+            let (user, challenge) =
+                UserSession::challenge(&vk, &commitment, &msg, &mut rng).unwrap();
+            let mut response = signer.respond(&key_pair, &challenge, &mut rng);
+            response.bit = !response.bit;
+            assert!(user.finalize(&response).is_err());
+
+            // a response under another key
+            let other = KeyPair::<$curve_param>::generate(&mut rng);
+            let (signer, commitment) = SignerSession::commit(&mut rng);
+            let (user, challenge) =
+                UserSession::challenge(&vk, &commitment, &msg, &mut rng).unwrap();
+            let response = signer.respond(&other, &challenge, &mut rng);
+            assert!(user.finalize(&response).is_err());
+
+            // a response to another challenge
+            let (signer, commitment) = SignerSession::commit(&mut rng);
+            let (user, _) = UserSession::challenge(&vk, &commitment, &msg, &mut rng).unwrap();
+            let (_, challenge) = UserSession::challenge(&vk, &commitment, &msg, &mut rng).unwrap();
+            let response = signer.respond(&key_pair, &challenge, &mut rng);
+            assert!(user.finalize(&response).is_err());
+
+            // a small order key
+            let (_, commitment) = SignerSession::commit(&mut rng);
+            assert!(UserSession::challenge(
+                &VerKey(Projective::zero()),
+                &commitment,
+                &msg,
+                &mut rng
+            )
+            .is_err());
+        };
+    }
+
+    #[test]
+    fn test_blind_schnorr() {
+        test_blind_schnorr!(Param254);
+        test_blind_schnorr!(Param377);
+        test_blind_schnorr!(Param381);
+        test_blind_schnorr!(Param381b);
+    }
+
+    #[test]
+    fn test_serde() {
+        let mut rng = jf_utils::test_rng();
+        let key_pair = KeyPair::<Param254>::generate(&mut rng);
+        let msg = [<Param254 as CurveConfig>::BaseField::from(7u64)];
+        let (signer, commitment) = SignerSession::commit(&mut rng);
+        let (_, challenge) =
+            UserSession::challenge(key_pair.ver_key_ref(), &commitment, &msg, &mut rng).unwrap();
+        let response = signer.respond(&key_pair, &challenge, &mut rng);
+
+        let mut bytes = vec![];
+        commitment.serialize_compressed(&mut bytes).unwrap();
+        assert_eq!(
+            SignerCommitment::deserialize_compressed(&bytes[..]).unwrap(),
+            commitment
+        );
+        let mut bytes = vec![];
+        challenge.serialize_compressed(&mut bytes).unwrap();
+        assert_eq!(
+            UserChallenge::deserialize_compressed(&bytes[..]).unwrap(),
+            challenge
+        );
+        let mut bytes = vec![];
+        response.serialize_compressed(&mut bytes).unwrap();
+        assert_eq!(
+            SignerResponse::deserialize_compressed(&bytes[..]).unwrap(),
+            response
+        );
+    }
+}
@@ -70,28 +70,142 @@
 //! ```
 //!
 //! [zeroize]: https://github.com/RustCrypto/utils/tree/master/zeroize
+//!
+//! ## Ethereum ciphersuite
+//!
+//! The ciphersuite of this module has its verification keys in G2. The
+//! ciphersuite of the Ethereum consensus layer, with the keys in G1 and proofs
+//! of possession, is implemented by [`min_pk`].
+//!
+//! ## Hierarchical deterministic keys
+//!
+//! Validator keys compatible with the Ethereum staking tools are derived from
+//! a seed with the tree of [EIP-2333][eip2333], and the paths of
+//! [EIP-2334][eip2334]: the signing key of validator `i` is at
+//! `m/12381/3600/i/0/0` and its withdrawal key at `m/12381/3600/i/0`.
+//!
+//! ```
+//! use jf_primitives::signatures::{SignatureScheme, bls_over_bls12381::BLSSignatureScheme};
+//!
+//! let seed = [42u8; 32]; // derived from a mnemonic in practice!
+//! let path = BLSSignatureScheme::eip2334_signing_path(0);
+//! let (sk, pk) = BLSSignatureScheme::derive_key_from_path(&seed, &path)?;
+//!
+//! let mut sk_child = BLSSignatureScheme::derive_master_sk(&seed)?;
+//! for index in [12381, 3600, 0, 0, 0] {
+//!     sk_child = BLSSignatureScheme::derive_child_sk(&sk_child, index);
+//! }
+//! assert_eq!(sk_child, sk);
+//!
+//! let msg = "attestation";
+//! let sig = BLSSignatureScheme::sign(&(), &sk, &msg, &mut jf_utils::test_rng())?;
+//! assert!(BLSSignatureScheme::verify(&(), &pk, &msg, &sig).is_ok());
+//!
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+//!
+//! [eip2333]: https://eips.ethereum.org/EIPS/eip-2333
+//! [eip2334]: https://eips.ethereum.org/EIPS/eip-2334
+//!
+//! ## Aggregation
+//!
+//! [`BLSSignatureScheme`] implements [`AggregateableSignatureSchemes`]:
+//! signatures are aggregated by summing them, an aggregate over distinct
+//! messages is checked by [`aggregate_verify`][agg_verify], and an aggregate
+//! over the same message by [`multi_sig_verify`][fast_agg_verify], i.e. the
+//! `FastAggregateVerify` of the IRTF draft.
+//!
+//! ```
+//! use rand_chacha::{ChaCha20Rng, rand_core::SeedableRng};
+//! use jf_primitives::signatures::{
+//!     AggregateableSignatureSchemes, SignatureScheme, bls_over_bls12381::BLSSignatureScheme,
+//! };
+//!
+//! let mut rng = ChaCha20Rng::from_seed([0u8; 32]);
+//! let (sk1, pk1) = BLSSignatureScheme::key_gen(&(), &mut rng)?;
+//! let (sk2, pk2) = BLSSignatureScheme::key_gen(&(), &mut rng)?;
+//! let pks = [pk1, pk2];
+//!
+//! let msgs = ["vote for block 42", "vote for block 43"];
+//! let sigs = [
+//!     BLSSignatureScheme::sign(&(), &sk1, &msgs[0], &mut rng)?,
+//!     BLSSignatureScheme::sign(&(), &sk2, &msgs[1], &mut rng)?,
+//! ];
+//! let agg_sig = BLSSignatureScheme::aggregate(&(), &pks, &sigs)?;
+//! assert!(BLSSignatureScheme::aggregate_verify(&(), &pks, &msgs, &agg_sig).is_ok());
+//!
+//! let msg = "vote for block 42";
+//! let sigs = [
+//!     BLSSignatureScheme::sign(&(), &sk1, &msg, &mut rng)?,
+//!     BLSSignatureScheme::sign(&(), &sk2, &msg, &mut rng)?,
+//! ];
+//! let multi_sig = BLSSignatureScheme::aggregate(&(), &pks, &sigs)?;
+//! assert!(BLSSignatureScheme::multi_sig_verify(&(), &pks, msg.as_ref(), &multi_sig).is_ok());
+//!
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+//!
+//! ### Subgroup checks
+//!
+//! Every signature is checked to be in the prime order subgroup when it is
+//! aggregated, and so is the aggregate signature when it is verified.
+//! Verification keys must be valid, i.e. non-identity points of the prime
+//! order subgroup: [`aggregate_verify`][agg_verify] validates them, while
+//! [`multi_sig_verify`][fast_agg_verify] expects them to be validated
+//! beforehand, e.g. by deserializing them with validation, so that the check
+//! is done once per key rather than once per verification.
+//!
+//! ### Rogue-key attacks
+//!
+//! With aggregation, an adversary can choose its verification key as a
+//! function of the honest ones, e.g. `pk' = g^x - pk`, and forge an aggregate
+//! for `[pk, pk']` without the secret key of `pk`. The ciphersuite of this
+//! module is the basic scheme of the IRTF draft: only an aggregate over
+//! distinct messages is protected against such attacks, and it is the
+//! responsibility of the caller to ensure that messages are distinct.
+//! Aggregates over a single message must only be verified with keys whose
+//! owners have proven possession of the matching secret keys.
+//!
+//! ### Proofs of possession
+//!
+//! A proof of possession is a signature of the compressed verification key
+//! under the distinct ciphersuite [`CS_ID_BLS_MIN_SIG_POP`], so that it can
+//! never be mistaken for a signature of a message. It is produced by
+//! [`BLSSignatureScheme::pop_prove`] and checked by
+//! [`BLSSignatureScheme::pop_verify`], typically once, when the key is
+//! registered. [`BLSSignatureScheme::verify_aggregate_with_pop`] checks a
+//! multisignature along with the proofs of possession of its keys; once the
+//! proofs are checked, [`multi_sig_verify`][fast_agg_verify] alone is safe.
+//!
+//! [agg_verify]: AggregateableSignatureSchemes::aggregate_verify
+//! [fast_agg_verify]: AggregateableSignatureSchemes::multi_sig_verify
 
-use super::SignatureScheme;
+use super::{AggregateableSignatureSchemes, SignatureScheme};
 use crate::{
     constants::{
         BLS_SIG_COMPRESSED_PK_SIZE, BLS_SIG_COMPRESSED_SIGNATURE_SIZE, BLS_SIG_PK_SIZE,
-        BLS_SIG_SIGNATURE_SIZE, BLS_SIG_SK_SIZE,
+        BLS_SIG_SIGNATURE_SIZE, BLS_SIG_SK_SIZE, EIP2334_COIN_TYPE, EIP2334_PURPOSE,
     },
     errors::PrimitivesError,
 };
 
-use crate::constants::CS_ID_BLS_MIN_SIG;
+use crate::constants::{CS_ID_BLS_MIN_SIG, CS_ID_BLS_MIN_SIG_POP};
 use ark_serialize::*;
 use ark_std::{
     format,
     ops::{Deref, DerefMut},
     rand::{CryptoRng, RngCore},
+    string::{String, ToString},
+    vec::Vec,
 };
 use blst::{min_sig::*, BLST_ERROR};
 use espresso_systems_common::jellyfish::tag;
+use serde::{Deserialize, Serialize};
 use tagged_base64::tagged;
 use zeroize::{Zeroize, Zeroizing};
 
+pub mod min_pk;
+
 #[tagged(tag::BLS_SIGNING_KEY)]
 #[derive(Clone, Debug, Zeroize)]
 #[zeroize(drop)]
@@ -323,7 +437,7 @@ impl Valid for BLSSignature {
 
 /// BLS signature scheme. Wrapping around structs from the `blst` crate.
 /// See [module-level documentation](self) for example usage.
-#[derive(Clone, Debug)]
+#[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct BLSSignatureScheme;
 
 impl SignatureScheme for BLSSignatureScheme {
@@ -394,6 +508,79 @@ impl SignatureScheme for BLSSignatureScheme {
     }
 }
 
+impl AggregateableSignatureSchemes for BLSSignatureScheme {
+    /// Aggregate multiple signatures into a single signature, checking that
+    /// each of them is in the prime order subgroup.
+    /// Follow the instantiation from <https://www.ietf.org/archive/id/draft-irtf-cfrg-bls-signature-05.html#name-aggregate>
+    fn aggregate(
+        _pp: &Self::PublicParameter,
+        _vks: &[Self::VerificationKey],
+        sigs: &[Self::Signature],
+    ) -> Result<Self::Signature, PrimitivesError> {
+        if sigs.is_empty() {
+            return Err(PrimitivesError::ParameterError(
+                "no signatures to aggregate".to_string(),
+            ));
+        }
+        let sigs: Vec<&Signature> = sigs.iter().map(|sig| &sig.0).collect();
+        let agg_sig = AggregateSignature::aggregate(&sigs, true)?;
+        Ok(BLSSignature(agg_sig.to_signature()))
+    }
+
+    /// Verify an aggregate signature w.r.t. a list of messages and public keys.
+    /// The public keys are validated. The messages must be distinct, see the
+    /// [module-level documentation](self) about rogue-key attacks.
+    /// Follow the instantiation from <https://www.ietf.org/archive/id/draft-irtf-cfrg-bls-signature-05.html#name-coreaggregateverify>
+    fn aggregate_verify<M: AsRef<[Self::MessageUnit]>>(
+        _pp: &Self::PublicParameter,
+        vks: &[Self::VerificationKey],
+        msgs: &[M],
+        sig: &Self::Signature,
+    ) -> Result<(), PrimitivesError> {
+        if vks.is_empty() {
+            return Err(PrimitivesError::ParameterError(
+                "no verification key for signature verification".to_string(),
+            ));
+        }
+        if vks.len() != msgs.len() {
+            return Err(PrimitivesError::ParameterError(format!(
+                "vks.len = {}; msgs.len = {}",
+                vks.len(),
+                msgs.len(),
+            )));
+        }
+        let msgs: Vec<&[u8]> = msgs.iter().map(|msg| msg.as_ref()).collect();
+        let vks: Vec<&PublicKey> = vks.iter().map(|vk| &vk.0).collect();
+        match sig.aggregate_verify(true, &msgs, Self::CS_ID.as_bytes(), &vks, true) {
+            BLST_ERROR::BLST_SUCCESS => Ok(()),
+            e => Err(PrimitivesError::VerificationError(format!("{e:?}"))),
+        }
+    }
+
+    /// Verify a multisignature w.r.t. a single message and a list of public
+    /// keys. It is user's responsibility to ensure that the public keys are
+    /// validated, and that their owners have proven possession of the secret
+    /// keys, see the [module-level documentation](self).
+    /// Follow the instantiation from <https://www.ietf.org/archive/id/draft-irtf-cfrg-bls-signature-05.html#name-fastaggregateverify>
+    fn multi_sig_verify(
+        _pp: &Self::PublicParameter,
+        vks: &[Self::VerificationKey],
+        msg: &[Self::MessageUnit],
+        sig: &Self::Signature,
+    ) -> Result<(), PrimitivesError> {
+        if vks.is_empty() {
+            return Err(PrimitivesError::ParameterError(
+                "no verification key for signature verification".to_string(),
+            ));
+        }
+        let vks: Vec<&PublicKey> = vks.iter().map(|vk| &vk.0).collect();
+        match sig.fast_aggregate_verify(true, msg, Self::CS_ID.as_bytes(), &vks) {
+            BLST_ERROR::BLST_SUCCESS => Ok(()),
+            e => Err(PrimitivesError::VerificationError(format!("{e:?}"))),
+        }
+    }
+}
+
 impl BLSSignatureScheme {
     /// Alternative deterministic key_gen compatible with [IRTF draft v5][v5].
     ///
@@ -424,13 +611,134 @@ impl BLSSignatureScheme {
 
         Ok((BLSSignKey(sk), BLSVerKey(vk)))
     }
+
+    /// Derive the master key of `seed` with `derive_master_SK` of
+    /// [EIP-2333][eip2333].
+    /// * `seed` - a secret of at least 32 bytes, e.g. derived from a mnemonic
+    /// * `returns` - Err() if the seed is shorter than 32 bytes
+    ///
+    /// [eip2333]: https://eips.ethereum.org/EIPS/eip-2333
+    pub fn derive_master_sk(seed: &[u8]) -> Result<BLSSignKey, PrimitivesError> {
+        Ok(BLSSignKey(SecretKey::derive_master_eip2333(seed)?))
+    }
+
+    /// Derive the child of index `index` of `parent` with `derive_child_SK` of
+    /// [EIP-2333][eip2333].
+    ///
+    /// [eip2333]: https://eips.ethereum.org/EIPS/eip-2333
+    pub fn derive_child_sk(parent: &BLSSignKey, index: u32) -> BLSSignKey {
+        BLSSignKey(parent.derive_child_eip2333(index))
+    }
+
+    /// Derive the key pair of `seed` at `path`, e.g. `m/12381/3600/0/0/0`,
+    /// by deriving the children of the master key along the path.
+    /// * `returns` - Err() if the seed is shorter than 32 bytes or the path is
+    ///   not `m` followed by `/`-separated decimal 32-bit indices
+    pub fn derive_key_from_path(
+        seed: &[u8],
+        path: &str,
+    ) -> Result<(BLSSignKey, BLSVerKey), PrimitivesError> {
+        let indices = parse_path(path)?;
+        let mut sk = Self::derive_master_sk(seed)?;
+        for index in indices {
+            sk = Self::derive_child_sk(&sk, index);
+        }
+        let vk = sk.sk_to_pk();
+        Ok((sk, BLSVerKey(vk)))
+    }
+
+    /// The [EIP-2334][eip2334] path of the signing key of the validator of
+    /// index `index`, `m/12381/3600/index/0/0`.
+    ///
+    /// [eip2334]: https://eips.ethereum.org/EIPS/eip-2334
+    pub fn eip2334_signing_path(index: u32) -> String {
+        format!("{}/0", Self::eip2334_withdrawal_path(index))
+    }
+
+    /// The [EIP-2334][eip2334] path of the withdrawal key of the validator of
+    /// index `index`, `m/12381/3600/index/0`.
+    ///
+    /// [eip2334]: https://eips.ethereum.org/EIPS/eip-2334
+    pub fn eip2334_withdrawal_path(index: u32) -> String {
+        format!("m/{EIP2334_PURPOSE}/{EIP2334_COIN_TYPE}/{index}/0")
+    }
+
+    /// Prove the possession of `sk`, by signing its verification key under
+    /// [`CS_ID_BLS_MIN_SIG_POP`].
+    /// Follow the instantiation from <https://www.ietf.org/archive/id/draft-irtf-cfrg-bls-signature-05.html#name-popprove>
+    pub fn pop_prove(sk: &BLSSignKey) -> BLSSignature {
+        let vk = sk.sk_to_pk();
+        BLSSignature(sk.sign(&vk.compress(), CS_ID_BLS_MIN_SIG_POP.as_bytes(), &[]))
+    }
+
+    /// Verify a proof of possession of the secret key of `vk`. The key and the
+    /// proof are validated.
+    /// Follow the instantiation from <https://www.ietf.org/archive/id/draft-irtf-cfrg-bls-signature-05.html#name-popverify>
+    pub fn pop_verify(vk: &BLSVerKey, pop: &BLSSignature) -> Result<(), PrimitivesError> {
+        match pop.verify(
+            true,
+            &vk.compress(),
+            CS_ID_BLS_MIN_SIG_POP.as_bytes(),
+            &[],
+            vk,
+            true,
+        ) {
+            BLST_ERROR::BLST_SUCCESS => Ok(()),
+            e => Err(PrimitivesError::VerificationError(format!("{e:?}"))),
+        }
+    }
+
+    /// Verify a multisignature w.r.t. a single message and a list of public
+    /// keys along with their proofs of possession, which rule out rogue-key
+    /// attacks.
+    /// * `pops` - the proof of possession of each key of `vks`
+    /// * `returns` - Err() if a proof of possession or the multisignature is
+    ///   invalid
+    pub fn verify_aggregate_with_pop<M: AsRef<[u8]>>(
+        vks: &[BLSVerKey],
+        pops: &[BLSSignature],
+        msg: M,
+        sig: &BLSSignature,
+    ) -> Result<(), PrimitivesError> {
+        if vks.len() != pops.len() {
+            return Err(PrimitivesError::ParameterError(format!(
+                "vks.len = {}; pops.len = {}",
+                vks.len(),
+                pops.len(),
+            )));
+        }
+        for (vk, pop) in vks.iter().zip(pops) {
+            Self::pop_verify(vk, pop)?;
+        }
+        Self::multi_sig_verify(&(), vks, msg.as_ref(), sig)
+    }
+}
+
+// The indices of a derivation path `m/i_1/.../i_n`.
+fn parse_path(path: &str) -> Result<Vec<u32>, PrimitivesError> {
+    let mut components = path.split('/');
+    if components.next() != Some("m") {
+        return Err(PrimitivesError::ParameterError(format!(
+            "derivation path {path} does not start with m"
+        )));
+    }
+    components
+        .map(|component| {
+            // `u32::from_str` also accepts a leading `+`
+            if component.is_empty() || !component.bytes().all(|b| b.is_ascii_digit()) {
+                return None;
+            }
+            component.parse().ok()
+        })
+        .collect::<Option<Vec<u32>>>()
+        .ok_or_else(|| PrimitivesError::ParameterError(format!("invalid derivation path {path}")))
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::signatures::tests::{failed_verification, sign_and_verify};
-    use ark_std::{fmt::Debug, vec};
+    use crate::signatures::tests::{agg_sign_and_verify, failed_verification, sign_and_verify};
+    use ark_std::{fmt::Debug, vec, vec::Vec};
 
     #[test]
     fn test_bls_sig() {
@@ -440,6 +748,185 @@ mod test {
         failed_verification::<BLSSignatureScheme>(message.as_ref(), message_bad.as_ref());
     }
 
+    #[test]
+    fn test_bls_agg_sig() {
+        let m1 = [87u8, 54, 82, 2];
+        let m2 = [12u8, 2, 91];
+        let m3 = [0u8];
+        let m4 = [255u8, 255];
+        let messages = vec![&m1[..], &m2[..], &m3[..], &m4[..]];
+        let wrong_message = vec![42u8];
+        agg_sign_and_verify::<BLSSignatureScheme>(messages.as_slice(), wrong_message.as_slice());
+
+        let mut rng = jf_utils::test_rng();
+        let (sk1, pk1) = BLSSignatureScheme::key_gen(&(), &mut rng).unwrap();
+        let (sk2, pk2) = BLSSignatureScheme::key_gen(&(), &mut rng).unwrap();
+        let sig1 = BLSSignatureScheme::sign(&(), &sk1, m1, &mut rng).unwrap();
+        let sig2 = BLSSignatureScheme::sign(&(), &sk2, m2, &mut rng).unwrap();
+        assert!(BLSSignatureScheme::aggregate(&(), &[], &[]).is_err());
+        // An aggregate of a single signature is the signature
+        assert_eq!(
+            BLSSignatureScheme::aggregate(&(), &[pk1], &[sig1]).unwrap(),
+            sig1
+        );
+        let agg_sig = BLSSignatureScheme::aggregate(&(), &[pk1, pk2], &[sig1, sig2]).unwrap();
+        assert!(
+            BLSSignatureScheme::aggregate_verify(&(), &[pk1, pk2], &[m1, m1], &agg_sig).is_err()
+        );
+        // keys and messages are bound together
+        assert!(BLSSignatureScheme::aggregate_verify(
+            &(),
+            &[pk2, pk1],
+            &[&m1[..], &m2[..]],
+            &agg_sig
+        )
+        .is_err());
+        // invalid keys are rejected
+        assert!(BLSSignatureScheme::aggregate_verify(
+            &(),
+            &[pk1, BLSVerKey::default()],
+            &[&m1[..], &m2[..]],
+            &agg_sig
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_bls_pop() {
+        let mut rng = jf_utils::test_rng();
+        let keys: Vec<_> = (0..4)
+            .map(|_| BLSSignatureScheme::key_gen(&(), &mut rng).unwrap())
+            .collect();
+        let pks: Vec<_> = keys.iter().map(|(_, pk)| *pk).collect();
+        let pops: Vec<_> = keys
+            .iter()
+            .map(|(sk, _)| BLSSignatureScheme::pop_prove(sk))
+            .collect();
+        for (pk, pop) in pks.iter().zip(&pops) {
+            assert!(BLSSignatureScheme::pop_verify(pk, pop).is_ok());
+        }
+        assert!(BLSSignatureScheme::pop_verify(&pks[0], &pops[1]).is_err());
+        // A proof of possession is not a signature of the key, and vice versa
+        let pk_bytes = pks[0].compress();
+        assert!(BLSSignatureScheme::verify(&(), &pks[0], pk_bytes, &pops[0]).is_err());
+        let sig = BLSSignatureScheme::sign(&(), &keys[0].0, pk_bytes, &mut rng).unwrap();
+        assert!(BLSSignatureScheme::pop_verify(&pks[0], &sig).is_err());
+
+        let msg = "vote for block 42";
+        let sigs: Vec<_> = keys
+            .iter()
+            .map(|(sk, _)| BLSSignatureScheme::sign(&(), sk, msg, &mut rng).unwrap())
+            .collect();
+        let multi_sig = BLSSignatureScheme::aggregate(&(), &pks, &sigs).unwrap();
+        assert!(
+            BLSSignatureScheme::verify_aggregate_with_pop(&pks, &pops, msg, &multi_sig).is_ok()
+        );
+        assert!(BLSSignatureScheme::verify_aggregate_with_pop(
+            &pks,
+            &pops,
+            "vote for block 43",
+            &multi_sig
+        )
+        .is_err());
+        assert!(
+            BLSSignatureScheme::verify_aggregate_with_pop(&pks, &pops[1..], msg, &multi_sig)
+                .is_err()
+        );
+        let mut bad_pops = pops.clone();
+        bad_pops.swap(0, 1);
+        assert!(
+            BLSSignatureScheme::verify_aggregate_with_pop(&pks, &bad_pops, msg, &multi_sig)
+                .is_err()
+        );
+    }
+
+    fn from_hex(hex: &str) -> Vec<u8> {
+        (0..hex.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_eip2333_vectors() {
+        // test cases of EIP-2333, the keys in big-endian hexadecimal
+        let vectors = [
+            (
+                "c55257c360c07c72029aebc1b53c05ed0362ada38ead3e3e9efa3708e53495531f09a6987599d18264c1e1c92f2cf141630c7a3c4ab7c81b2f001698e7463b04",
+                "0d7359d57963ab8fbbde1852dcf553fedbc31f464d80ee7d40ae683122b45070",
+                0,
+                "2d18bd6c14e6d15bf8b5085c9b74f3daae3b03cc2014770a599d8c1539e50f8e",
+            ),
+            (
+                "3141592653589793238462643383279502884197169399375105820974944592",
+                "41c9e07822b092a93fd6797396338c3ada4170cc81829fdfce6b5d34bd5e7ec7",
+                3141592653,
+                "384843fad5f3d777ea39de3e47a8f999ae91f89e42bffa993d91d9782d152a0f",
+            ),
+            (
+                "0099FF991111002299DD7744EE3355BBDD8844115566CC55663355668888CC00",
+                "3cfa341ab3910a7d00d933d8f7c4fe87c91798a0397421d6b19fd5b815132e80",
+                4294967295,
+                "40e86285582f35b28821340f6a53b448588efa575bc4d88c32ef8567b8d9479b",
+            ),
+            (
+                "d4e56740f876aef8c010b86a40d5f56745a118d0906a34e69aec8c0db1cb8fa3",
+                "2a0e28ffa5fbbe2f8e7aad4ed94f745d6bf755c51182e119bb1694fe61d3afca",
+                42,
+                "455c0dc9fccb3395825d92a60d2672d69416be1c2578a87a7a3d3ced11ebb88d",
+            ),
+        ];
+        for (seed, master_sk, index, child_sk) in vectors {
+            let seed = from_hex(seed);
+            let master = BLSSignatureScheme::derive_master_sk(&seed).unwrap();
+            assert_eq!(master.to_bytes().to_vec(), from_hex(master_sk));
+            let child = BLSSignatureScheme::derive_child_sk(&master, index);
+            assert_eq!(child.to_bytes().to_vec(), from_hex(child_sk));
+
+            let path = format!("m/{index}");
+            let (sk, pk) = BLSSignatureScheme::derive_key_from_path(&seed, &path).unwrap();
+            assert_eq!(sk, child);
+            assert_eq!(pk, BLSVerKey(child.sk_to_pk()));
+        }
+        assert!(BLSSignatureScheme::derive_master_sk(&[0u8; 31]).is_err());
+    }
+
+    #[test]
+    fn test_eip2334_paths() {
+        assert_eq!(
+            BLSSignatureScheme::eip2334_signing_path(7),
+            "m/12381/3600/7/0/0"
+        );
+        assert_eq!(
+            BLSSignatureScheme::eip2334_withdrawal_path(7),
+            "m/12381/3600/7/0"
+        );
+
+        let seed = from_hex("c55257c360c07c72029aebc1b53c05ed0362ada38ead3e3e9efa3708e53495531f09a6987599d18264c1e1c92f2cf141630c7a3c4ab7c81b2f001698e7463b04");
+        let path = BLSSignatureScheme::eip2334_signing_path(0);
+        let (sk, _) = BLSSignatureScheme::derive_key_from_path(&seed, &path).unwrap();
+        assert_eq!(
+            sk.to_bytes().to_vec(),
+            from_hex("032e6c3c7359223e127e9479afc521c4342f8903bc29ae01b671bcbcc98be0f6")
+        );
+        let (master, _) = BLSSignatureScheme::derive_key_from_path(&seed, "m").unwrap();
+        assert_eq!(master, BLSSignatureScheme::derive_master_sk(&seed).unwrap());
+
+        for path in [
+            "",
+            "n/0",
+            "m/",
+            "m//0",
+            "m/a",
+            "m/+1",
+            "m/-1",
+            "m/4294967296",
+            "/m/0",
+        ] {
+            assert!(BLSSignatureScheme::derive_key_from_path(&seed, path).is_err());
+        }
+    }
+
     #[test]
     fn test_canonical_serde() {
         let mut rng = jf_utils::test_rng();
@@ -0,0 +1,568 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+//! BLS signatures with the ciphersuite of the Ethereum consensus layer,
+//! `BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_` of the [IRTF draft][irtf]:
+//! verification keys in G1 (the "minimal-pubkey-size" variant), messages
+//! hashed to G2, and proofs of possession against rogue-key attacks.
+//!
+//! It is the same scheme as the [parent module](super) with the groups
+//! swapped, so that keys and signatures interoperate with beacon-chain
+//! clients: [`BLSMinPkSignatureScheme::verify`] is `Verify`,
+//! [`multi_sig_verify`][fast_agg_verify] is `FastAggregateVerify` and
+//! [`aggregate_verify`][agg_verify] is `AggregateVerify` of the draft. The
+//! keys of validators are derived with [EIP-2333][eip2333] by the parent
+//! module, and converted into the [`BLSSignKey`] of this module.
+//!
+//! ```
+//! use jf_primitives::signatures::{
+//!     bls_over_bls12381::{min_pk::*, BLSSignatureScheme},
+//!     SignatureScheme,
+//! };
+//!
+//! let seed = [42u8; 32]; // derived from a mnemonic in practice!
+//! let path = BLSSignatureScheme::eip2334_signing_path(0);
+//! let (sk, _) = BLSSignatureScheme::derive_key_from_path(&seed, &path)?;
+//! let sk = BLSSignKey::from(&sk);
+//! let pk = sk.ver_key();
+//!
+//! let msg = "attestation";
+//! let sig = BLSMinPkSignatureScheme::sign(&(), &sk, &msg, &mut jf_utils::test_rng())?;
+//! assert!(BLSMinPkSignatureScheme::verify(&(), &pk, &msg, &sig).is_ok());
+//!
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+//!
+//! [irtf]: https://datatracker.ietf.org/doc/pdf/draft-irtf-cfrg-bls-signature-05
+//! [eip2333]: https://eips.ethereum.org/EIPS/eip-2333
+//! [agg_verify]: AggregateableSignatureSchemes::aggregate_verify
+//! [fast_agg_verify]: AggregateableSignatureSchemes::multi_sig_verify
+
+use crate::{
+    constants::{
+        BLS_MIN_PK_COMPRESSED_PK_SIZE, BLS_MIN_PK_COMPRESSED_SIGNATURE_SIZE, BLS_MIN_PK_PK_SIZE,
+        BLS_MIN_PK_SIGNATURE_SIZE, BLS_SIG_SK_SIZE, CS_ID_BLS_MIN_PK, CS_ID_BLS_MIN_PK_POP,
+    },
+    errors::PrimitivesError,
+    signatures::{AggregateableSignatureSchemes, SignatureScheme},
+};
+use ark_serialize::*;
+use ark_std::{
+    format,
+    ops::{Deref, DerefMut},
+    rand::{CryptoRng, RngCore},
+    string::ToString,
+    vec::Vec,
+};
+use blst::{min_pk::*, BLST_ERROR};
+use serde::{Deserialize, Serialize};
+use tagged_base64::tagged;
+use zeroize::{Zeroize, Zeroizing};
+
+#[tagged("BLS_MIN_PK_SIGNING_KEY")]
+#[derive(Clone, Debug, Zeroize)]
+#[zeroize(drop)]
+/// A BLS Secret Key (Signing Key) of the min-pk ciphersuite.
+pub struct BLSSignKey(SecretKey);
+
+impl Deref for BLSSignKey {
+    type Target = SecretKey;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<&super::BLSSignKey> for BLSSignKey {
+    /// The same secret scalar, e.g. a key derived with EIP-2333.
+    fn from(sk: &super::BLSSignKey) -> Self {
+        let bytes = Zeroizing::new(sk.to_bytes());
+        // safe unwrap: the bytes of a valid secret key
+        let sk = SecretKey::from_bytes(&bytes[..]).unwrap();
+        Self(sk)
+    }
+}
+
+impl BLSSignKey {
+    /// The verification key of the signing key.
+    pub fn ver_key(&self) -> BLSVerKey {
+        BLSVerKey(self.sk_to_pk())
+    }
+}
+
+impl CanonicalSerialize for BLSSignKey {
+    /// Secret key can only be serialized in compressed mode.
+    fn serialize_with_mode<W: Write>(
+        &self,
+        writer: W,
+        _compress: Compress,
+    ) -> Result<(), SerializationError> {
+        CanonicalSerialize::serialize_compressed(&Zeroizing::new(self.to_bytes())[..], writer)
+    }
+
+    fn serialized_size(&self, _compress: Compress) -> usize {
+        BLS_SIG_SK_SIZE
+    }
+}
+
+impl CanonicalDeserialize for BLSSignKey {
+    fn deserialize_with_mode<R: Read>(
+        mut reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        let len = <usize as ark_serialize::CanonicalDeserialize>::deserialize_with_mode(
+            &mut reader,
+            compress,
+            validate,
+        )?;
+        if len != BLS_SIG_SK_SIZE {
+            return Err(SerializationError::InvalidData);
+        }
+
+        let mut sk_bytes = Zeroizing::new([0u8; BLS_SIG_SK_SIZE]);
+        reader.read_exact(sk_bytes.deref_mut())?;
+        SecretKey::deserialize(sk_bytes.deref())
+            .map(Self)
+            .map_err(|_| SerializationError::InvalidData)
+    }
+}
+
+impl Valid for BLSSignKey {
+    fn check(&self) -> Result<(), ark_serialize::SerializationError> {
+        Ok(())
+    }
+}
+
+impl PartialEq for BLSSignKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.serialize() == other.0.serialize()
+    }
+}
+
+impl Eq for BLSSignKey {}
+
+#[tagged("BLS_MIN_PK_VER_KEY")]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Copy)]
+/// A BLS Public Key (Verification Key) of the min-pk ciphersuite, a point of
+/// G1.
+pub struct BLSVerKey(PublicKey);
+
+impl Deref for BLSVerKey {
+    type Target = PublicKey;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl CanonicalSerialize for BLSVerKey {
+    fn serialize_with_mode<W: Write>(
+        &self,
+        writer: W,
+        compress: Compress,
+    ) -> Result<(), SerializationError> {
+        if compress == Compress::No {
+            CanonicalSerialize::serialize_compressed(&self.serialize()[..], writer)
+        } else {
+            CanonicalSerialize::serialize_compressed(&self.compress()[..], writer)
+        }
+    }
+
+    fn serialized_size(&self, compress: Compress) -> usize {
+        if compress == Compress::Yes {
+            BLS_MIN_PK_COMPRESSED_PK_SIZE
+        } else {
+            BLS_MIN_PK_PK_SIZE
+        }
+    }
+}
+
+impl CanonicalDeserialize for BLSVerKey {
+    fn deserialize_with_mode<R: Read>(
+        mut reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        let bytes = Vec::<u8>::deserialize_with_mode(&mut reader, Compress::Yes, validate)?;
+        let pk = match (compress, bytes.len()) {
+            (Compress::Yes, BLS_MIN_PK_COMPRESSED_PK_SIZE) => PublicKey::uncompress(&bytes),
+            (Compress::No, BLS_MIN_PK_PK_SIZE) => PublicKey::deserialize(&bytes),
+            _ => return Err(SerializationError::InvalidData),
+        }
+        .map_err(|_| SerializationError::InvalidData)?;
+
+        let ver_key = Self(pk);
+        if validate == Validate::Yes {
+            ver_key.check()?;
+        }
+        Ok(ver_key)
+    }
+}
+
+impl Valid for BLSVerKey {
+    /// `KeyValidate` of the draft: a non-identity point of the prime order
+    /// subgroup.
+    fn check(&self) -> Result<(), ark_serialize::SerializationError> {
+        self.0
+            .validate()
+            .map_err(|_| SerializationError::InvalidData)
+    }
+}
+
+/// A BLS Signature of the min-pk ciphersuite, a point of G2.
+#[derive(Clone, Debug, PartialEq, Eq, Copy)]
+#[tagged("BLS_MIN_PK_SIG")]
+pub struct BLSSignature(Signature);
+
+impl Deref for BLSSignature {
+    type Target = Signature;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl CanonicalSerialize for BLSSignature {
+    fn serialize_with_mode<W: Write>(
+        &self,
+        writer: W,
+        compress: Compress,
+    ) -> Result<(), SerializationError> {
+        if compress == Compress::No {
+            CanonicalSerialize::serialize_compressed(&self.serialize()[..], writer)
+        } else {
+            CanonicalSerialize::serialize_compressed(&self.compress()[..], writer)
+        }
+    }
+
+    fn serialized_size(&self, compress: Compress) -> usize {
+        if compress == Compress::Yes {
+            BLS_MIN_PK_COMPRESSED_SIGNATURE_SIZE
+        } else {
+            BLS_MIN_PK_SIGNATURE_SIZE
+        }
+    }
+}
+
+impl CanonicalDeserialize for BLSSignature {
+    fn deserialize_with_mode<R: Read>(
+        mut reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        let bytes = Vec::<u8>::deserialize_with_mode(&mut reader, Compress::Yes, validate)?;
+        let sig = match (compress, bytes.len()) {
+            (Compress::Yes, BLS_MIN_PK_COMPRESSED_SIGNATURE_SIZE) => Signature::uncompress(&bytes),
+            (Compress::No, BLS_MIN_PK_SIGNATURE_SIZE) => Signature::deserialize(&bytes),
+            _ => return Err(SerializationError::InvalidData),
+        }
+        .map_err(|_| SerializationError::InvalidData)?;
+
+        let sig = Self(sig);
+        if validate == Validate::Yes {
+            sig.check()?;
+        }
+        Ok(sig)
+    }
+}
+
+impl Valid for BLSSignature {
+    fn check(&self) -> Result<(), ark_serialize::SerializationError> {
+        self.0
+            .validate(true)
+            .map_err(|_| SerializationError::InvalidData)
+    }
+}
+
+/// BLS signature scheme with the Ethereum ciphersuite. Wrapping around
+/// structs from the `blst` crate.
+/// See [module-level documentation](self) for example usage.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct BLSMinPkSignatureScheme;
+
+impl SignatureScheme for BLSMinPkSignatureScheme {
+    const CS_ID: &'static str = CS_ID_BLS_MIN_PK;
+
+    /// Signing key
+    type SigningKey = BLSSignKey;
+
+    /// Verification key
+    type VerificationKey = BLSVerKey;
+
+    /// Public parameter
+    type PublicParameter = ();
+
+    /// Signature
+    type Signature = BLSSignature;
+
+    /// A message is &\[MessageUnit\]
+    type MessageUnit = u8;
+
+    /// There are no public parameters.
+    fn param_gen<R: CryptoRng + RngCore>(
+        _prng: Option<&mut R>,
+    ) -> Result<Self::PublicParameter, PrimitivesError> {
+        Ok(())
+    }
+
+    /// Generate a BLS key pair.
+    /// Make sure the `prng` passed in are properly seeded with trusted entropy.
+    fn key_gen<R: CryptoRng + RngCore>(
+        _pp: &Self::PublicParameter,
+        prng: &mut R,
+    ) -> Result<(Self::SigningKey, Self::VerificationKey), PrimitivesError> {
+        let mut ikm = Zeroizing::new([0u8; 32]);
+        prng.fill_bytes(ikm.deref_mut());
+
+        let sk = BLSSignKey(SecretKey::key_gen(ikm.deref(), &[])?);
+        let vk = sk.ver_key();
+        Ok((sk, vk))
+    }
+
+    /// Sign a message
+    fn sign<R: CryptoRng + RngCore, M: AsRef<[Self::MessageUnit]>>(
+        _pp: &Self::PublicParameter,
+        sk: &Self::SigningKey,
+        msg: M,
+        _prng: &mut R,
+    ) -> Result<Self::Signature, PrimitivesError> {
+        Ok(BLSSignature(sk.sign(
+            msg.as_ref(),
+            Self::CS_ID.as_bytes(),
+            &[],
+        )))
+    }
+
+    /// Verify a signature, validating the key and the signature.
+    fn verify<M: AsRef<[Self::MessageUnit]>>(
+        _pp: &Self::PublicParameter,
+        vk: &Self::VerificationKey,
+        msg: M,
+        sig: &Self::Signature,
+    ) -> Result<(), PrimitivesError> {
+        match sig.verify(true, msg.as_ref(), Self::CS_ID.as_bytes(), &[], vk, true) {
+            BLST_ERROR::BLST_SUCCESS => Ok(()),
+            e => Err(PrimitivesError::VerificationError(format!("{e:?}"))),
+        }
+    }
+}
+
+impl AggregateableSignatureSchemes for BLSMinPkSignatureScheme {
+    /// Aggregate multiple signatures into a single signature, checking that
+    /// each of them is in the prime order subgroup.
+    fn aggregate(
+        _pp: &Self::PublicParameter,
+        _vks: &[Self::VerificationKey],
+        sigs: &[Self::Signature],
+    ) -> Result<Self::Signature, PrimitivesError> {
+        if sigs.is_empty() {
+            return Err(PrimitivesError::ParameterError(
+                "no signatures to aggregate".to_string(),
+            ));
+        }
+        let sigs: Vec<&Signature> = sigs.iter().map(|sig| &sig.0).collect();
+        let agg_sig = AggregateSignature::aggregate(&sigs, true)?;
+        Ok(BLSSignature(agg_sig.to_signature()))
+    }
+
+    /// Verify an aggregate signature w.r.t. a list of messages and public keys,
+    /// which are validated. With proofs of possession, the messages do not
+    /// need to be distinct.
+    fn aggregate_verify<M: AsRef<[Self::MessageUnit]>>(
+        _pp: &Self::PublicParameter,
+        vks: &[Self::VerificationKey],
+        msgs: &[M],
+        sig: &Self::Signature,
+    ) -> Result<(), PrimitivesError> {
+        if vks.is_empty() {
+            return Err(PrimitivesError::ParameterError(
+                "no verification key for signature verification".to_string(),
+            ));
+        }
+        if vks.len() != msgs.len() {
+            return Err(PrimitivesError::ParameterError(format!(
+                "vks.len = {}; msgs.len = {}",
+                vks.len(),
+                msgs.len(),
+            )));
+        }
+        let msgs: Vec<&[u8]> = msgs.iter().map(|msg| msg.as_ref()).collect();
+        let vks: Vec<&PublicKey> = vks.iter().map(|vk| &vk.0).collect();
+        match sig.aggregate_verify(true, &msgs, Self::CS_ID.as_bytes(), &vks, true) {
+            BLST_ERROR::BLST_SUCCESS => Ok(()),
+            e => Err(PrimitivesError::VerificationError(format!("{e:?}"))),
+        }
+    }
+
+    /// Verify a multisignature w.r.t. a single message and a list of public
+    /// keys, i.e. `FastAggregateVerify`. It is user's responsibility to ensure
+    /// that the public keys are validated, and that their owners have proven
+    /// possession of the secret keys.
+    fn multi_sig_verify(
+        _pp: &Self::PublicParameter,
+        vks: &[Self::VerificationKey],
+        msg: &[Self::MessageUnit],
+        sig: &Self::Signature,
+    ) -> Result<(), PrimitivesError> {
+        if vks.is_empty() {
+            return Err(PrimitivesError::ParameterError(
+                "no verification key for signature verification".to_string(),
+            ));
+        }
+        let vks: Vec<&PublicKey> = vks.iter().map(|vk| &vk.0).collect();
+        match sig.fast_aggregate_verify(true, msg, Self::CS_ID.as_bytes(), &vks) {
+            BLST_ERROR::BLST_SUCCESS => Ok(()),
+            e => Err(PrimitivesError::VerificationError(format!("{e:?}"))),
+        }
+    }
+}
+
+impl BLSMinPkSignatureScheme {
+    /// Prove the possession of `sk`, by signing its verification key under
+    /// [`CS_ID_BLS_MIN_PK_POP`].
+    pub fn pop_prove(sk: &BLSSignKey) -> BLSSignature {
+        let vk = sk.sk_to_pk();
+        BLSSignature(sk.sign(&vk.compress(), CS_ID_BLS_MIN_PK_POP.as_bytes(), &[]))
+    }
+
+    /// Verify a proof of possession of the secret key of `vk`. The key and the
+    /// proof are validated.
+    pub fn pop_verify(vk: &BLSVerKey, pop: &BLSSignature) -> Result<(), PrimitivesError> {
+        match pop.verify(
+            true,
+            &vk.compress(),
+            CS_ID_BLS_MIN_PK_POP.as_bytes(),
+            &[],
+            vk,
+            true,
+        ) {
+            BLST_ERROR::BLST_SUCCESS => Ok(()),
+            e => Err(PrimitivesError::VerificationError(format!("{e:?}"))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::signatures::tests::{agg_sign_and_verify, failed_verification, sign_and_verify};
+    use ark_std::vec;
+
+    fn from_hex(hex: &str) -> Vec<u8> {
+        (0..hex.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_bls_min_pk_sig() {
+        let message = "this is a test message";
+        let message_bad = "this is a wrong message";
+        sign_and_verify::<BLSMinPkSignatureScheme>(message.as_ref());
+        failed_verification::<BLSMinPkSignatureScheme>(message.as_ref(), message_bad.as_ref());
+
+        let m1 = [87u8, 54, 82, 2];
+        let m2 = [12u8, 2, 91];
+        let m3 = [0u8];
+        let messages = vec![&m1[..], &m2[..], &m3[..]];
+        agg_sign_and_verify::<BLSMinPkSignatureScheme>(messages.as_slice(), &[42u8]);
+    }
+
+    // test vectors of the Ethereum consensus specs, signing the zero 32-byte
+    // message
+    #[test]
+    fn test_eth_vectors() {
+        let vectors = [
+            (
+                "263dbd792f5b1be47ed85f8938c0f29586af0d3ac7b977f21c278fe1462040e3",
+                "a491d1b0ecd9bb917989f0e74f0dea0422eac4a873e5e2644f368dffb9a6e20fd6e10c1b77654d067c0618f6e5a7f79a",
+                "b6ed936746e01f8ecf281f020953fbf1f01debd5657c4a383940b020b26507f6076334f91e2366c96e9ab279fb5158090352ea1c5b0c9274504f4f0e7053af24802e51e4568d164fe986834f41e55c8e850ce1f98458c0cfc9ab380b55285a55",
+            ),
+            (
+                "47b8192d77bf871b62e87859d653922725724a5c031afeabc60bcef5ff665138",
+                "b301803f8b5ac4a1133581fc676dfedc60d891dd5fa99028805e5ea5b08d3491af75d0707adab3b70c6a6a580217bf81",
+                "b23c46be3a001c63ca711f87a005c200cc550b9429d5f4eb38d74322144f1b63926da3388979e5321012fb1a0526bcd100b5ef5fe72628ce4cd5e904aeaa3279527843fae5ca9ca675f4f51ed8f83bbf7155da9ecc9663100a885d5dc6df96d9",
+            ),
+            (
+                "328388aff0d4a5b7dc9205abd374e7e98f3cd9f3418edb4eafda5fb16473d216",
+                "b53d21a4cfd562c469cc81514d4ce5a6b577d8403d32a394dc265dd190b47fa9f829fdd7963afdf972e5e77854051f6f",
+                "948a7cb99f76d616c2c564ce9bf4a519f1bea6b0a624a02276443c245854219fabb8d4ce061d255af5330b078d5380681751aa7053da2c98bae898edc218c75f07e24d8802a17cd1f6833b71e58f5eb5b94208b4d0bb3848cecb075ea21be115",
+            ),
+        ];
+        let rng = &mut jf_utils::test_rng();
+        let msg = [0u8; 32];
+        let mut vks = Vec::new();
+        let mut sigs = Vec::new();
+        for (sk, vk, sig) in vectors {
+            let sk = BLSSignKey(SecretKey::from_bytes(&from_hex(sk)).unwrap());
+            let vk = BLSVerKey(PublicKey::uncompress(&from_hex(vk)).unwrap());
+            assert_eq!(sk.ver_key(), vk);
+            let expected = BLSSignature(Signature::uncompress(&from_hex(sig)).unwrap());
+            let sig = BLSMinPkSignatureScheme::sign(&(), &sk, msg, rng).unwrap();
+            assert_eq!(sig, expected);
+            assert!(BLSMinPkSignatureScheme::verify(&(), &vk, msg, &sig).is_ok());
+            assert!(BLSMinPkSignatureScheme::verify(&(), &vk, [1u8; 32], &sig).is_err());
+            vks.push(vk);
+            sigs.push(sig);
+        }
+        let agg_sig = BLSMinPkSignatureScheme::aggregate(&(), &vks, &sigs).unwrap();
+        assert!(BLSMinPkSignatureScheme::multi_sig_verify(&(), &vks, &msg, &agg_sig).is_ok());
+        assert!(BLSMinPkSignatureScheme::aggregate_verify(&(), &vks, &[msg; 3], &agg_sig).is_ok());
+        assert!(BLSMinPkSignatureScheme::multi_sig_verify(&(), &vks[1..], &msg, &agg_sig).is_err());
+
+        // the identity key and signature are rejected
+        let mut identity_vk = [0u8; BLS_MIN_PK_COMPRESSED_PK_SIZE];
+        identity_vk[0] = 0xc0;
+        let mut bytes = Vec::new();
+        identity_vk[..].serialize_compressed(&mut bytes).unwrap();
+        assert!(BLSVerKey::deserialize_compressed(&bytes[..]).is_err());
+        let mut identity_sig = [0u8; BLS_MIN_PK_COMPRESSED_SIGNATURE_SIZE];
+        identity_sig[0] = 0xc0;
+        let mut bytes = Vec::new();
+        identity_sig[..].serialize_compressed(&mut bytes).unwrap();
+        assert!(BLSSignature::deserialize_compressed(&bytes[..]).is_err());
+        assert!(
+            BLSMinPkSignatureScheme::verify(&(), &BLSVerKey::default(), msg, &sigs[0]).is_err()
+        );
+    }
+
+    #[test]
+    fn test_bls_min_pk_pop_and_keys() {
+        let rng = &mut jf_utils::test_rng();
+        let (sk, vk) = BLSMinPkSignatureScheme::key_gen(&(), rng).unwrap();
+        let pop = BLSMinPkSignatureScheme::pop_prove(&sk);
+        assert!(BLSMinPkSignatureScheme::pop_verify(&vk, &pop).is_ok());
+        let (_, other_vk) = BLSMinPkSignatureScheme::key_gen(&(), rng).unwrap();
+        assert!(BLSMinPkSignatureScheme::pop_verify(&other_vk, &pop).is_err());
+        // a proof of possession is not a signature of the key
+        assert!(BLSMinPkSignatureScheme::verify(&(), &vk, vk.compress(), &pop).is_err());
+
+        // keys derived with EIP-2333, as by the Ethereum staking tools
+        let seed = [7u8; 32];
+        let (min_sig_sk, _) =
+            super::super::BLSSignatureScheme::derive_key_from_path(&seed, "m/12381/3600/0/0/0")
+                .unwrap();
+        let sk = BLSSignKey::from(&min_sig_sk);
+        assert_eq!(sk.to_bytes(), min_sig_sk.to_bytes());
+
+        // serde
+        let sig = BLSMinPkSignatureScheme::sign(&(), &sk, b"msg", rng).unwrap();
+        let vk = sk.ver_key();
+        for compress in [Compress::Yes, Compress::No] {
+            let mut bytes = Vec::new();
+            vk.serialize_with_mode(&mut bytes, compress).unwrap();
+            assert_eq!(bytes.len(), 8 + vk.serialized_size(compress));
+            let vk2 = BLSVerKey::deserialize_with_mode(&bytes[..], compress, Validate::Yes);
+            assert_eq!(vk2.unwrap(), vk);
+            let mut bytes = Vec::new();
+            sig.serialize_with_mode(&mut bytes, compress).unwrap();
+            let sig2 = BLSSignature::deserialize_with_mode(&bytes[..], compress, Validate::Yes);
+            assert_eq!(sig2.unwrap(), sig);
+        }
+        let mut bytes = Vec::new();
+        sk.serialize_compressed(&mut bytes).unwrap();
+        assert_eq!(BLSSignKey::deserialize_compressed(&bytes[..]).unwrap(), sk);
+    }
+}
@@ -29,13 +29,25 @@
 //! * `H` is implemented using the "hash-and-pray" approach. See function
 //!   [`hash_to_curve`]
 //!
+//! Multisignatures over a single message, checked by
+//! [`multi_sig_verify`][fast_agg_verify], are subject to rogue-key attacks:
+//! a key `pk' = g2^x / pk` lets its owner forge a multisignature on any
+//! message for the pair `(pk, pk')`. Keys must therefore come with a proof of
+//! possession of their secret key, see
+//! [`BLSOverBN254CurveSignatureScheme::pop_prove`] and
+//! [`BLSOverBN254CurveSignatureScheme::verify_aggregate_with_pop`].
+//!
 //! [bls]: https://hovav.net/ucsd/dist/sigs.pdf
 //! [bn254]: https://eprint.iacr.org/2005/133.pdf
 //! [eip196]: https://eips.ethereum.org/EIPS/eip-196
 //! [eip197]: https://eips.ethereum.org/EIPS/eip-197
+//! [fast_agg_verify]: AggregateableSignatureSchemes::multi_sig_verify
 
 use super::{AggregateableSignatureSchemes, SignatureScheme};
-use crate::{constants::CS_ID_BLS_BN254, errors::PrimitivesError};
+use crate::{
+    constants::{CS_ID_BLS_BN254, CS_ID_BLS_BN254_POP},
+    errors::PrimitivesError,
+};
 use ark_bn254::{
     Bn254, Fq as BaseField, Fr as ScalarField, G1Affine, G1Projective, G2Affine, G2Projective,
 };
@@ -218,6 +230,59 @@ impl AggregateableSignatureSchemes for BLSOverBN254CurveSignatureScheme {
         Self::verify(pp, &VerKey(agg_vk), msg, sig)
     }
 }
+
+impl BLSOverBN254CurveSignatureScheme {
+    /// Prove the possession of `sk`, by signing the compressed serialization
+    /// of its verification key under [`CS_ID_BLS_BN254_POP`].
+    /// Follow the instantiation from <https://www.ietf.org/archive/id/draft-irtf-cfrg-bls-signature-05.html#name-popprove>
+    pub fn pop_prove(sk: &SignKey) -> Result<Signature, PrimitivesError> {
+        let kp = KeyPair::generate_with_sign_key(sk.0);
+        let mut vk_bytes = Vec::new();
+        kp.vk.serialize_compressed(&mut vk_bytes)?;
+        Ok(kp.sign(&vk_bytes, CS_ID_BLS_BN254_POP))
+    }
+
+    /// Verify a proof of possession of the secret key of `vk`.
+    /// * `returns` - Err() if `vk` is the identity or if `pop` is invalid
+    pub fn pop_verify(vk: &VerKey, pop: &Signature) -> Result<(), PrimitivesError> {
+        if vk.to_affine().is_zero() {
+            return Err(VerificationError(
+                "the verification key is the identity".to_string(),
+            ));
+        }
+        let mut vk_bytes = Vec::new();
+        vk.serialize_compressed(&mut vk_bytes)?;
+        vk.verify(&vk_bytes, pop, CS_ID_BLS_BN254_POP)
+    }
+
+    /// Verify a multisignature w.r.t. a single message and a list of public
+    /// keys along with their proofs of possession, which rule out rogue-key
+    /// attacks. Proofs of possession only need to be checked once per key,
+    /// after which [`multi_sig_verify`][fast_agg_verify] alone is safe.
+    /// * `pops` - the proof of possession of each key of `vks`
+    /// * `returns` - Err() if a proof of possession or the multisignature is
+    ///   invalid
+    ///
+    /// [fast_agg_verify]: AggregateableSignatureSchemes::multi_sig_verify
+    pub fn verify_aggregate_with_pop<M: AsRef<[u8]>>(
+        vks: &[VerKey],
+        pops: &[Signature],
+        msg: M,
+        sig: &Signature,
+    ) -> Result<(), PrimitivesError> {
+        if vks.len() != pops.len() {
+            return Err(ParameterError(format!(
+                "vks.len = {}; pops.len = {}",
+                vks.len(),
+                pops.len(),
+            )));
+        }
+        for (vk, pop) in vks.iter().zip(pops) {
+            Self::pop_verify(vk, pop)?;
+        }
+        Self::multi_sig_verify(&(), vks, msg.as_ref(), sig)
+    }
+}
 // =====================================================
 // Signing key
 // =====================================================
@@ -449,11 +514,13 @@ mod tests {
                 BLSOverBN254CurveSignatureScheme, KeyPair, SignKey, Signature, VerKey,
             },
             tests::{agg_sign_and_verify, failed_verification, sign_and_verify},
+            AggregateableSignatureSchemes, SignatureScheme,
         },
     };
+    use ark_bn254::{Fr as ScalarField, G1Projective, G2Projective};
     use ark_ff::vec;
     use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
-    use ark_std::{vec::Vec, UniformRand};
+    use ark_std::{vec::Vec, UniformRand, Zero};
 
     #[test]
     fn test_bls_signature_internals() {
@@ -516,6 +583,54 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_pop() {
+        type Scheme = BLSOverBN254CurveSignatureScheme;
+        let mut rng = jf_utils::test_rng();
+        let key_pairs: Vec<_> = (0..3).map(|_| KeyPair::generate(&mut rng)).collect();
+        let vks: Vec<_> = key_pairs.iter().map(|kp| kp.ver_key()).collect();
+        let pops: Vec<_> = key_pairs
+            .iter()
+            .map(|kp| Scheme::pop_prove(kp.sign_key_ref()).unwrap())
+            .collect();
+        for (vk, pop) in vks.iter().zip(&pops) {
+            assert!(Scheme::pop_verify(vk, pop).is_ok());
+        }
+        assert!(Scheme::pop_verify(&vks[0], &pops[1]).is_err());
+        let identity_vk = VerKey(G2Projective::zero());
+        let identity_sig = Signature {
+            sigma: G1Projective::zero(),
+        };
+        assert!(Scheme::pop_verify(&identity_vk, &identity_sig).is_err());
+        // A proof of possession is not a signature of the key bytes
+        let mut vk_bytes = Vec::new();
+        vks[0].serialize_compressed(&mut vk_bytes).unwrap();
+        assert!(Scheme::verify(&(), &vks[0], &vk_bytes, &pops[0]).is_err());
+
+        let msg = [1u8, 2, 3];
+        let sigs: Vec<_> = key_pairs
+            .iter()
+            .map(|kp| kp.sign(&msg, CS_ID_BLS_BN254))
+            .collect();
+        let multi_sig = Scheme::aggregate(&(), &vks, &sigs).unwrap();
+        assert!(Scheme::verify_aggregate_with_pop(&vks, &pops, msg, &multi_sig).is_ok());
+        assert!(Scheme::verify_aggregate_with_pop(&vks, &pops[1..], msg, &multi_sig).is_err());
+        assert!(Scheme::verify_aggregate_with_pop(&vks, &pops, [4u8], &multi_sig).is_err());
+
+        // Rogue key: pk' = g2^x - pk makes the aggregated key g2^x
+        let x = ScalarField::rand(&mut rng);
+        let rogue_kp = KeyPair::generate_with_sign_key(x);
+        let rogue_vk = VerKey(rogue_kp.ver_key().0 - vks[0].0);
+        let forged = rogue_kp.sign(&msg, CS_ID_BLS_BN254);
+        let rogue_vks = [vks[0], rogue_vk];
+        assert!(Scheme::multi_sig_verify(&(), &rogue_vks, &msg, &forged).is_ok());
+        let rogue_pops = [
+            pops[0].clone(),
+            Scheme::pop_prove(rogue_kp.sign_key_ref()).unwrap(),
+        ];
+        assert!(Scheme::verify_aggregate_with_pop(&rogue_vks, &rogue_pops, msg, &forged).is_err());
+    }
+
     #[test]
     fn test_serde() {
         let mut rng = jf_utils::test_rng();
@@ -0,0 +1,643 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+//! This module implements ECDSA over secp256k1, compatible with the
+//! signatures of Ethereum.
+//!
+//! Let `G` be the generator of secp256k1 and `n` its order.
+//!
+//! **KeyGen()**
+//!    * sample a random `x` in `[1,n-1]`, return the key pair `(x,P=xG)`
+//!
+//! **Sign(x,m)**
+//!    * `e=Keccak256(m) mod n`, and `k` is derived from `x` and `e` following
+//!      [RFC 6979][rfc6979] with HMAC-SHA256
+//!    * `R=kG`, `r=x(R) mod n`, return `(r,s=(e+rx)/k mod n)`, where `s` is
+//!      replaced by `n-s` if greater than `n/2`
+//!
+//! **Verify(P,m,(r,s))**
+//!    * check that `r,s` are in `[1,n-1]` and that `s` is at most `n/2`
+//!    * check that `x((e/s)G+(r/s)P) mod n = r`
+//!
+//! As [EIP-2][eip2] does for Ethereum transactions, only the low-`s` form of a
+//! signature is accepted, so signatures are not malleable. Signatures carry
+//! the recovery id `v` in `0..4`, from which [`VerKey::recover`] computes the
+//! verification key of the signer. Unlike Ethereum transactions, `v` is not
+//! offset by 27.
+//!
+//! [`SignKey::sign_prehash`], [`VerKey::verify_prehash`] and
+//! [`VerKey::recover_prehash`] take the 32-byte message hash instead, e.g. for
+//! messages hashed with a prefix by `personal_sign`.
+//!
+//! [rfc6979]: https://datatracker.ietf.org/doc/html/rfc6979
+//! [eip2]: https://eips.ethereum.org/EIPS/eip-2
+
+use super::SignatureScheme;
+use crate::{
+    constants::CS_ID_ECDSA_SECP256K1,
+    errors::PrimitivesError,
+    utils::{bytes_from_int, int_from_bytes},
+};
+use ark_ec::{AffineRepr, CurveGroup, Group};
+use ark_ff::{BigInt, BigInteger, Field, PrimeField, Zero};
+use ark_secp256k1::{Affine, Fq, Fr, Projective};
+use ark_std::{
+    rand::{CryptoRng, RngCore},
+    string::ToString,
+    vec::Vec,
+    UniformRand,
+};
+use jf_utils::canonical;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use sha3::Keccak256;
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+/// ECDSA signature scheme over secp256k1 with Keccak256 message hashes.
+#[derive(Debug, PartialEq, Clone)]
+pub struct ECDSASignatureScheme;
+
+impl SignatureScheme for ECDSASignatureScheme {
+    const CS_ID: &'static str = CS_ID_ECDSA_SECP256K1;
+
+    /// Signing key.
+    type SigningKey = SignKey;
+
+    /// Verification key
+    type VerificationKey = VerKey;
+
+    /// Public Parameter
+    type PublicParameter = ();
+
+    /// Signature
+    type Signature = Signature;
+
+    /// A message is &\[MessageUnit\]
+    type MessageUnit = u8;
+
+    /// generate public parameters from RNG.
+    fn param_gen<R: CryptoRng + RngCore>(
+        _prng: Option<&mut R>,
+    ) -> Result<Self::PublicParameter, PrimitivesError> {
+        Ok(())
+    }
+
+    /// Sample a pair of keys.
+    fn key_gen<R: CryptoRng + RngCore>(
+        _pp: &Self::PublicParameter,
+        prng: &mut R,
+    ) -> Result<(Self::SigningKey, Self::VerificationKey), PrimitivesError> {
+        let mut x = Fr::zero();
+        while x.is_zero() {
+            x = Fr::rand(prng);
+        }
+        let sk = SignKey(bytes_from_int(&x));
+        x.zeroize();
+        let vk = sk.ver_key();
+        Ok((sk, vk))
+    }
+
+    /// Sign a message with the signing key. The nonce is deterministic, so
+    /// `prng` is unused.
+    fn sign<R: CryptoRng + RngCore, M: AsRef<[Self::MessageUnit]>>(
+        _pp: &Self::PublicParameter,
+        sk: &Self::SigningKey,
+        msg: M,
+        _prng: &mut R,
+    ) -> Result<Self::Signature, PrimitivesError> {
+        sk.sign(msg.as_ref())
+    }
+
+    /// Verify a signature.
+    fn verify<M: AsRef<[Self::MessageUnit]>>(
+        _pp: &Self::PublicParameter,
+        vk: &Self::VerificationKey,
+        msg: M,
+        sig: &Self::Signature,
+    ) -> Result<(), PrimitivesError> {
+        vk.verify(msg.as_ref(), sig)
+    }
+}
+
+// =====================================================
+// Signing key
+// =====================================================
+
+/// Signing key for ECDSA signature, i.e. a 32-byte big-endian scalar in
+/// `[1,n-1]`.
+#[derive(Clone, Debug, Zeroize, Eq, PartialEq, Serialize, Deserialize)]
+pub struct SignKey([u8; 32]);
+
+impl Drop for SignKey {
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl ZeroizeOnDrop for SignKey {}
+
+impl SignKey {
+    /// Parse a signing key from its 32-byte encoding.
+    /// * `returns` - Err() if the key is zero or not less than the group order
+    pub fn from_bytes(bytes: &[u8; 32]) -> Result<Self, PrimitivesError> {
+        match int_from_bytes::<Fr>(bytes) {
+            Some(x) if !x.is_zero() => Ok(Self(*bytes)),
+            _ => Err(PrimitivesError::ParameterError(
+                "signing key out of range".to_string(),
+            )),
+        }
+    }
+
+    /// The 32-byte encoding of the signing key.
+    pub fn to_bytes(&self) -> [u8; 32] {
+        self.0
+    }
+
+    /// The verification key of the signing key.
+    pub fn ver_key(&self) -> VerKey {
+        VerKey((Projective::generator() * self.scalar()).into_affine())
+    }
+
+    /// Sign the Keccak256 hash of a message.
+    pub fn sign(&self, msg: &[u8]) -> Result<Signature, PrimitivesError> {
+        self.sign_prehash(&Keccak256::digest(msg).into())
+    }
+
+    /// Sign a 32-byte message hash, with the deterministic nonce of RFC 6979.
+    #[allow(non_snake_case)]
+    pub fn sign_prehash(&self, hash: &[u8; 32]) -> Result<Signature, PrimitivesError> {
+        let mut x = self.scalar();
+        if x.is_zero() {
+            return Err(PrimitivesError::ParameterError(
+                "signing key out of range".to_string(),
+            ));
+        }
+        let e = Fr::from_be_bytes_mod_order(hash);
+        let mut nonces = Rfc6979::new(&self.0, &bytes_from_int(&e));
+        loop {
+            let mut k: Fr = nonces.next_nonce();
+            let R = (Projective::generator() * k).into_affine();
+            let r = Fr::from_be_bytes_mod_order(&bytes_from_int(&R.x));
+            // safe unwrap: `k` is non-zero
+            let s = k.inverse().unwrap() * (e + r * x);
+            k.zeroize();
+            if r.is_zero() || s.is_zero() {
+                continue;
+            }
+            x.zeroize();
+            let mut v = R.y.into_bigint().is_odd() as u8;
+            if R.x.into_bigint() >= Fr::MODULUS {
+                v |= 2;
+            }
+            let s = if s.into_bigint() > Fr::MODULUS_MINUS_ONE_DIV_TWO {
+                v ^= 1;
+                -s
+            } else {
+                s
+            };
+            return Ok(Signature {
+                r: bytes_from_int(&r),
+                s: bytes_from_int(&s),
+                v,
+            });
+        }
+    }
+
+    // the key is validated by `from_bytes`, but not upon deserialization
+    fn scalar(&self) -> Fr {
+        Fr::from_be_bytes_mod_order(&self.0)
+    }
+}
+
+// =====================================================
+// Verification key
+// =====================================================
+
+/// Signature public verification key
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub struct VerKey(#[serde(with = "canonical")] Affine);
+
+impl VerKey {
+    /// Parse a verification key from its SEC1 encoding, either compressed (33
+    /// bytes) or uncompressed (65 bytes).
+    /// * `returns` - Err() if the encoding is invalid, or is the identity
+    pub fn from_sec1_bytes(bytes: &[u8]) -> Result<Self, PrimitivesError> {
+        let invalid = || PrimitivesError::ParameterError("invalid SEC1 public key".to_string());
+        let point = match (bytes.len(), bytes.first()) {
+            (33, Some(&tag)) if tag == 2 || tag == 3 => {
+                let x = int_from_bytes::<Fq>(bytes[1..].try_into().unwrap()).ok_or_else(invalid)?;
+                let point = Affine::get_point_from_x_unchecked(x, true).ok_or_else(invalid)?;
+                if point.y.into_bigint().is_odd() == (tag == 3) {
+                    point
+                } else {
+                    -point
+                }
+            },
+            (65, Some(&4)) => {
+                let x =
+                    int_from_bytes::<Fq>(bytes[1..33].try_into().unwrap()).ok_or_else(invalid)?;
+                let y =
+                    int_from_bytes::<Fq>(bytes[33..].try_into().unwrap()).ok_or_else(invalid)?;
+                let point = Affine::new_unchecked(x, y);
+                if !point.is_on_curve() {
+                    return Err(invalid());
+                }
+                point
+            },
+            _ => return Err(invalid()),
+        };
+        Ok(Self(point))
+    }
+
+    /// The SEC1 encoding of the verification key, either compressed (33
+    /// bytes) or uncompressed (65 bytes).
+    pub fn to_sec1_bytes(&self, compressed: bool) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(65);
+        if compressed {
+            bytes.push(2 + self.0.y.into_bigint().is_odd() as u8);
+            bytes.extend_from_slice(&bytes_from_int(&self.0.x));
+        } else {
+            bytes.push(4);
+            bytes.extend_from_slice(&bytes_from_int(&self.0.x));
+            bytes.extend_from_slice(&bytes_from_int(&self.0.y));
+        }
+        bytes
+    }
+
+    /// The Ethereum address of the verification key, i.e. the last 20 bytes
+    /// of the Keccak256 hash of its uncompressed encoding without the tag.
+    pub fn to_eth_address(&self) -> [u8; 20] {
+        let hash = Keccak256::digest(&self.to_sec1_bytes(false)[1..]);
+        let mut address = [0u8; 20];
+        address.copy_from_slice(&hash[12..]);
+        address
+    }
+
+    /// Verify a signature of the Keccak256 hash of a message.
+    pub fn verify(&self, msg: &[u8], sig: &Signature) -> Result<(), PrimitivesError> {
+        self.verify_prehash(&Keccak256::digest(msg).into(), sig)
+    }
+
+    /// Verify a signature of a 32-byte message hash. The recovery id is not
+    /// checked.
+    #[allow(non_snake_case)]
+    pub fn verify_prehash(&self, hash: &[u8; 32], sig: &Signature) -> Result<(), PrimitivesError> {
+        if self.0.is_zero() {
+            return Err(PrimitivesError::VerificationError(
+                "public key is the identity".to_string(),
+            ));
+        }
+        let (r, s) = sig.scalars()?;
+        let e = Fr::from_be_bytes_mod_order(hash);
+        // safe unwrap: `s` is non-zero
+        let s_inv = s.inverse().unwrap();
+        let R = (Projective::generator() * (e * s_inv) + self.0 * (r * s_inv)).into_affine();
+        if R.is_zero() || Fr::from_be_bytes_mod_order(&bytes_from_int(&R.x)) != r {
+            return Err(PrimitivesError::VerificationError(
+                "Signature verification error".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Recover the verification key of the signer of the Keccak256 hash of a
+    /// message.
+    pub fn recover(msg: &[u8], sig: &Signature) -> Result<Self, PrimitivesError> {
+        Self::recover_prehash(&Keccak256::digest(msg).into(), sig)
+    }
+
+    /// Recover the verification key of the signer of a 32-byte message hash,
+    /// from the recovery id of the signature.
+    /// * `returns` - Err() if the signature or the recovery id is invalid
+    #[allow(non_snake_case)]
+    pub fn recover_prehash(hash: &[u8; 32], sig: &Signature) -> Result<Self, PrimitivesError> {
+        let invalid = || PrimitivesError::VerificationError("invalid recovery id".to_string());
+        let (r, s) = sig.scalars()?;
+        if sig.v > 3 {
+            return Err(invalid());
+        }
+        // `x(R)` is `r`, or `r+n` if the second bit of the recovery id is set
+        let mut x = r.into_bigint();
+        if sig.v & 2 != 0 && x.add_with_carry(&Fr::MODULUS) {
+            return Err(invalid());
+        }
+        let x = Fq::from_bigint(x).ok_or_else(invalid)?;
+        let R = Affine::get_point_from_x_unchecked(x, true).ok_or_else(invalid)?;
+        let R = if R.y.into_bigint().is_odd() == (sig.v & 1 == 1) {
+            R
+        } else {
+            -R
+        };
+        let e = Fr::from_be_bytes_mod_order(hash);
+        // safe unwrap: `r` is non-zero
+        let r_inv = r.inverse().unwrap();
+        let vk = Self((R * (s * r_inv) - Projective::generator() * (e * r_inv)).into_affine());
+        vk.verify_prehash(hash, sig)?;
+        Ok(vk)
+    }
+}
+
+impl From<&SignKey> for VerKey {
+    fn from(sk: &SignKey) -> Self {
+        sk.ver_key()
+    }
+}
+
+// =====================================================
+// Signature
+// =====================================================
+
+/// The signature of ECDSA signature scheme: the scalars `r` and `s` as 32-byte
+/// big-endian integers, and the recovery id `v`.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub struct Signature {
+    r: [u8; 32],
+    s: [u8; 32],
+    v: u8,
+}
+
+impl Signature {
+    /// Parse a signature from its 65-byte encoding `r||s||v`. The encoding is
+    /// checked upon verification.
+    pub fn from_bytes(bytes: &[u8; 65]) -> Self {
+        let mut r = [0u8; 32];
+        let mut s = [0u8; 32];
+        r.copy_from_slice(&bytes[..32]);
+        s.copy_from_slice(&bytes[32..64]);
+        Self { r, s, v: bytes[64] }
+    }
+
+    /// The 65-byte encoding `r||s||v` of the signature.
+    pub fn to_bytes(&self) -> [u8; 65] {
+        let mut bytes = [0u8; 65];
+        bytes[..32].copy_from_slice(&self.r);
+        bytes[32..64].copy_from_slice(&self.s);
+        bytes[64] = self.v;
+        bytes
+    }
+
+    /// The recovery id of the signature.
+    pub fn recovery_id(&self) -> u8 {
+        self.v
+    }
+
+    // `r` and `s`, checked to be in `[1,n-1]` with `s` at most `n/2`
+    fn scalars(&self) -> Result<(Fr, Fr), PrimitivesError> {
+        let invalid = || PrimitivesError::VerificationError("signature out of range".to_string());
+        let r = int_from_bytes::<Fr>(&self.r).ok_or_else(invalid)?;
+        let s = int_from_bytes::<Fr>(&self.s).ok_or_else(invalid)?;
+        if r.is_zero() || s.is_zero() || s.into_bigint() > Fr::MODULUS_MINUS_ONE_DIV_TWO {
+            return Err(invalid());
+        }
+        Ok((r, s))
+    }
+}
+
+// =====================================================
+// end of definitions
+// =====================================================
+
+// The HMAC-DRBG of RFC 6979, section 3.2, for a 256-bit group order and
+// HMAC-SHA256. The state is derived from the signing key, and is wiped on
+// drop.
+#[derive(Zeroize)]
+#[zeroize(drop)]
+pub(crate) struct Rfc6979 {
+    k: [u8; 32],
+    v: [u8; 32],
+}
+
+impl Rfc6979 {
+    pub(crate) fn new(x: &[u8; 32], h: &[u8; 32]) -> Self {
+        let mut drbg = Self {
+            k: [0u8; 32],
+            v: [1u8; 32],
+        };
+        drbg.k = hmac_sha256(&drbg.k, &[&drbg.v[..], &[0x00], &x[..], &h[..]]);
+        drbg.v = hmac_sha256(&drbg.k, &[&drbg.v[..]]);
+        drbg.k = hmac_sha256(&drbg.k, &[&drbg.v[..], &[0x01], &x[..], &h[..]]);
+        drbg.v = hmac_sha256(&drbg.k, &[&drbg.v[..]]);
+        drbg
+    }
+
+    // The next candidate nonce in `[1,n-1]`, following step h. The state is
+    // updated after each candidate, so that the next call yields the candidate
+    // to use if this one is rejected.
+    pub(crate) fn next_nonce<F: PrimeField<BigInt = BigInt<4>>>(&mut self) -> F {
+        loop {
+            self.v = hmac_sha256(&self.k, &[&self.v[..]]);
+            let candidate = int_from_bytes::<F>(&self.v);
+            self.k = hmac_sha256(&self.k, &[&self.v[..], &[0x00]]);
+            self.v = hmac_sha256(&self.k, &[&self.v[..]]);
+            match candidate {
+                Some(k) if !k.is_zero() => return k,
+                _ => continue,
+            }
+        }
+    }
+}
+
+fn hmac_sha256(key: &[u8; 32], chunks: &[&[u8]]) -> [u8; 32] {
+    let mut inner_pad = [0x36u8; 64];
+    let mut outer_pad = [0x5cu8; 64];
+    for (i, byte) in key.iter().enumerate() {
+        inner_pad[i] ^= byte;
+        outer_pad[i] ^= byte;
+    }
+    let mut inner = Sha256::new();
+    inner.update(inner_pad);
+    for chunk in chunks {
+        inner.update(chunk);
+    }
+    let mut outer = Sha256::new();
+    outer.update(outer_pad);
+    inner_pad.zeroize();
+    outer_pad.zeroize();
+    outer.update(inner.finalize());
+    outer.finalize().into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::signatures::tests::{failed_verification, sign_and_verify};
+
+    fn from_hex<const N: usize>(hex: &str) -> [u8; N] {
+        let bytes: Vec<u8> = (0..hex.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+            .collect();
+        bytes.try_into().unwrap()
+    }
+
+    #[test]
+    fn test_rfc6979_nonce() {
+        // RFC 6979, A.2.5: the nonce of the secp256k1 DRBG does not depend on
+        // the curve, besides the order, so we check the first candidate of
+        // the P-256 vector for SHA-256 and "sample", which is less than `n`.
+        let x = from_hex("C9AFA9D845BA75166B5C215767B1D6934E50C3DB36E89B127B8A622B120F6721");
+        // SHA-256("sample") mod q, where q is the order of P-256
+        let h: [u8; 32] =
+            from_hex("AF2BDBE1AA9B6EC1E2ADE1D694F41FC71A831D0268E9891562113D8A62ADD1BF");
+        let mut drbg = Rfc6979::new(&x, &h);
+        let expected: [u8; 32] =
+            from_hex("A6E3C57DD01ABE90086538398355DD4C3B17AA873382B0F24D6129493D8AAD60");
+        assert_eq!(bytes_from_int(&drbg.next_nonce::<Fr>()), expected);
+    }
+
+    #[test]
+    fn test_ecdsa_vectors() {
+        // (signing key, message, r, s, v, uncompressed public key, address),
+        // checked against an independent implementation
+        let vectors = [
+            (
+                "0000000000000000000000000000000000000000000000000000000000000001",
+                "Satoshi Nakamoto",
+                "13f3c69fad2f18208f7fc379de4052e982d2cc76aaec6bf962338d6ebcd6fed6",
+                "65af5e417ee953b071c041150044a79a201fc02e9f075f2260de1599389a7f58",
+                1,
+                "79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8",
+                "7e5f4552091a69125d5dfcb7b8c2659029395bdf",
+            ),
+            (
+                "c9afa9d845ba75166b5c215767b1d6934e50c3db36e89b127b8a622b120f6721",
+                "sample",
+                "e80c734be586869ac3c1b30a2581cd5bf99e58d8ff60043f2bf54c4aaca75531",
+                "408872ab26b7a37b7f9c490642a2475a6f30b4ca574831548bd3f346d086e9c5",
+                0,
+                "2c8c31fc9f990c6b55e3865a184a4ce50e09481f2eaeb3e60ec1cea13a6ae64564b95e4fdb6948c0386e189b006a29f686769b011704275e4459822dc3328085",
+                "e471a8d4e306eefa74ba35df7500a4512aeb542c",
+            ),
+            (
+                "fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364140",
+                "jellyfish",
+                "e05a765138ecf3692105c238962cb14998701d413a0ce912c726e9724305f78b",
+                "2be5d08f36b8c615eefcbb30e2644f01eb965f1e69b3d82194f474b0505730d0",
+                0,
+                "79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798b7c52588d95c3b9aa25b0403f1eef75702e84bb7597aabe663b82f6f04ef2777",
+                "80c0dbf239224071c59dd8970ab9d542e3414ab2",
+            ),
+            (
+                "00000000000000000000000000000000000000000000000000000000deadbeef",
+                "",
+                "a4d6d641f5d2f4b9624603bbeee23b6fd81092de9ea7f09e06e4c4bd1493572b",
+                "725e3f9169859a0fd4c7888ea332816fa7328dba0938e7b4307fd3bccb65095b",
+                0,
+                "76d2fdf1302d1fa9556f4df94ec84cefba6d482e54f47c6c2a238c1baa560f0eb754ac7e7a3e09c44184cb451a4f5fb557f32053eb015dffebb655b5cfd54d8a",
+                "e8a78b476ae1403b7fd39b662545ae608aced7c7",
+            ),
+        ];
+        for (sk, msg, r, s, v, vk, address) in vectors {
+            let sk = SignKey::from_bytes(&from_hex(sk)).unwrap();
+            let vk_bytes: [u8; 65] = from_hex(&["04", vk].concat());
+            let vk = VerKey::from_sec1_bytes(&vk_bytes).unwrap();
+            assert_eq!(sk.ver_key(), vk);
+            assert_eq!(vk.to_sec1_bytes(false), vk_bytes);
+            assert_eq!(vk.to_eth_address(), from_hex::<20>(address));
+
+            let sig = sk.sign(msg.as_bytes()).unwrap();
+            let expected = Signature {
+                r: from_hex(r),
+                s: from_hex(s),
+                v,
+            };
+            assert_eq!(sig, expected);
+            assert!(vk.verify(msg.as_bytes(), &sig).is_ok());
+            assert_eq!(VerKey::recover(msg.as_bytes(), &sig).unwrap(), vk);
+        }
+    }
+
+    #[test]
+    fn test_ecdsa_invalid() {
+        let mut rng = jf_utils::test_rng();
+        let (sk, vk) = ECDSASignatureScheme::key_gen(&(), &mut rng).unwrap();
+        let msg = b"jellyfish";
+        let sig = sk.sign(msg).unwrap();
+        assert!(vk.verify(msg, &sig).is_ok());
+
+        // wrong message or key
+        assert!(vk.verify(b"jellyfisH", &sig).is_err());
+        let (_, other_vk) = ECDSASignatureScheme::key_gen(&(), &mut rng).unwrap();
+        assert!(other_vk.verify(msg, &sig).is_err());
+
+        // the high-s form is rejected
+        let s = -int_from_bytes::<Fr>(&sig.s).unwrap();
+        let high_s = Signature {
+            s: bytes_from_int(&s),
+            v: sig.v ^ 1,
+            ..sig
+        };
+        assert!(vk.verify(msg, &high_s).is_err());
+        assert!(VerKey::recover(msg, &high_s).is_err());
+
+        // zero or out of range scalars
+        let n: [u8; 32] =
+            from_hex("FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEBAAEDCE6AF48A03BBFD25E8CD0364141");
+        for bad_sig in [
+            Signature {
+                r: [0u8; 32],
+                ..sig
+            },
+            Signature {
+                s: [0u8; 32],
+                ..sig
+            },
+            Signature { r: n, ..sig },
+            Signature { s: n, ..sig },
+        ] {
+            assert!(vk.verify(msg, &bad_sig).is_err());
+            assert!(VerKey::recover(msg, &bad_sig).is_err());
+        }
+
+        // the wrong recovery id recovers another key, or fails
+        for v in 0..4 {
+            let recovered = VerKey::recover(msg, &Signature { v, ..sig });
+            assert_eq!(recovered.is_ok() && recovered.unwrap() == vk, v == sig.v);
+        }
+        assert!(VerKey::recover(msg, &Signature { v: 4, ..sig }).is_err());
+
+        // invalid keys
+        assert!(SignKey::from_bytes(&[0u8; 32]).is_err());
+        assert!(SignKey::from_bytes(&n).is_err());
+        let mut bytes = vk.to_sec1_bytes(false);
+        bytes[64] ^= 1;
+        assert!(VerKey::from_sec1_bytes(&bytes).is_err());
+        assert!(VerKey::from_sec1_bytes(&bytes[..33]).is_err());
+        assert!(VerKey::from_sec1_bytes(&[]).is_err());
+    }
+
+    #[test]
+    fn test_sec1_encoding() {
+        let mut rng = jf_utils::test_rng();
+        for _ in 0..10 {
+            let (_, vk) = ECDSASignatureScheme::key_gen(&(), &mut rng).unwrap();
+            for compressed in [true, false] {
+                let bytes = vk.to_sec1_bytes(compressed);
+                assert_eq!(bytes.len(), if compressed { 33 } else { 65 });
+                assert_eq!(VerKey::from_sec1_bytes(&bytes).unwrap(), vk);
+            }
+        }
+    }
+
+    #[test]
+    fn test_sig_trait() {
+        let message = [87u8, 32u8];
+        let wrong_message = [255u8];
+        sign_and_verify::<ECDSASignatureScheme>(&message);
+        sign_and_verify::<ECDSASignatureScheme>(&[]);
+        failed_verification::<ECDSASignatureScheme>(&message, &wrong_message);
+    }
+
+    #[test]
+    fn test_serde() {
+        let mut rng = jf_utils::test_rng();
+        let (sk, vk) = ECDSASignatureScheme::key_gen(&(), &mut rng).unwrap();
+        let sig = ECDSASignatureScheme::sign(&(), &sk, [1u8, 2, 3], &mut rng).unwrap();
+        assert_eq!(SignKey::from_bytes(&sk.to_bytes()).unwrap(), sk);
+        assert_eq!(Signature::from_bytes(&sig.to_bytes()), sig);
+        assert_eq!(VerKey::from(&sk), vk);
+    }
+}
@@ -0,0 +1,314 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+//! This module implements the Ed25519 signature of [RFC 8032][rfc8032], as a
+//! wrapper of the `ed25519-dalek` library.
+//!
+//! The signing key is the 32-byte secret seed of RFC 8032, from which the
+//! signing scalar and the nonce prefix are derived with SHA-512, so that
+//! signing is deterministic. Verification follows the cofactorless equation
+//! of RFC 8032 and rejects non-canonical `s`.
+//!
+//! [`Ed25519SignatureScheme::batch_verify`] checks many signatures at once
+//! with a random linear combination, at a fraction of the cost of checking
+//! them one by one. It uses the cofactored verification equation, so it may
+//! accept a signature crafted with a small-order component that
+//! [`Ed25519SignatureScheme::verify`] rejects.
+//!
+//! [rfc8032]: https://datatracker.ietf.org/doc/html/rfc8032
+//! [`Ed25519SignatureScheme::verify`]: crate::signatures::SignatureScheme::verify
+
+use super::SignatureScheme;
+use crate::{constants::CS_ID_ED25519, errors::PrimitivesError};
+use ark_std::{
+    format,
+    rand::{CryptoRng, RngCore},
+    vec::Vec,
+};
+use ed25519_dalek::{Signer, SigningKey, Verifier, VerifyingKey};
+use serde::{Deserialize, Serialize};
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+/// Ed25519 signature scheme.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Ed25519SignatureScheme;
+
+impl SignatureScheme for Ed25519SignatureScheme {
+    const CS_ID: &'static str = CS_ID_ED25519;
+
+    /// Signing key.
+    type SigningKey = SignKey;
+
+    /// Verification key
+    type VerificationKey = VerKey;
+
+    /// Public Parameter
+    type PublicParameter = ();
+
+    /// Signature
+    type Signature = Signature;
+
+    /// A message is &\[MessageUnit\]
+    type MessageUnit = u8;
+
+    /// generate public parameters from RNG.
+    fn param_gen<R: CryptoRng + RngCore>(
+        _prng: Option<&mut R>,
+    ) -> Result<Self::PublicParameter, PrimitivesError> {
+        Ok(())
+    }
+
+    /// Sample a pair of keys.
+    fn key_gen<R: CryptoRng + RngCore>(
+        _pp: &Self::PublicParameter,
+        prng: &mut R,
+    ) -> Result<(Self::SigningKey, Self::VerificationKey), PrimitivesError> {
+        let mut seed = [0u8; 32];
+        prng.fill_bytes(&mut seed);
+        let sk = SignKey(seed);
+        seed.zeroize();
+        let vk = sk.ver_key();
+        Ok((sk, vk))
+    }
+
+    /// Sign a message with the signing key. Signing is deterministic, so
+    /// `prng` is unused.
+    fn sign<R: CryptoRng + RngCore, M: AsRef<[Self::MessageUnit]>>(
+        _pp: &Self::PublicParameter,
+        sk: &Self::SigningKey,
+        msg: M,
+        _prng: &mut R,
+    ) -> Result<Self::Signature, PrimitivesError> {
+        Ok(sk.sign(msg.as_ref()))
+    }
+
+    /// Verify a signature.
+    fn verify<M: AsRef<[Self::MessageUnit]>>(
+        _pp: &Self::PublicParameter,
+        vk: &Self::VerificationKey,
+        msg: M,
+        sig: &Self::Signature,
+    ) -> Result<(), PrimitivesError> {
+        vk.0.verify(msg.as_ref(), &sig.0)
+            .map_err(|e| PrimitivesError::VerificationError(format!("{e}")))
+    }
+}
+
+impl Ed25519SignatureScheme {
+    /// Verify a batch of signatures, where `sigs[i]` is the signature of
+    /// `msgs[i]` under `vks[i]`.
+    /// * `returns` - Err() if the lengths differ, or if any signature is
+    ///   invalid
+    pub fn batch_verify<M: AsRef<[u8]>>(
+        vks: &[VerKey],
+        msgs: &[M],
+        sigs: &[Signature],
+    ) -> Result<(), PrimitivesError> {
+        if vks.len() != msgs.len() || vks.len() != sigs.len() {
+            return Err(PrimitivesError::ParameterError(format!(
+                "vks.len = {}; msgs.len = {}; sigs.len = {}",
+                vks.len(),
+                msgs.len(),
+                sigs.len(),
+            )));
+        }
+        let msgs: Vec<&[u8]> = msgs.iter().map(|msg| msg.as_ref()).collect();
+        let sigs: Vec<_> = sigs.iter().map(|sig| sig.0).collect();
+        let vks: Vec<_> = vks.iter().map(|vk| vk.0).collect();
+        ed25519_dalek::verify_batch(&msgs, &sigs, &vks)
+            .map_err(|e| PrimitivesError::VerificationError(format!("{e}")))
+    }
+}
+
+// =====================================================
+// Signing key
+// =====================================================
+
+/// Signing key for Ed25519 signature, i.e. the 32-byte secret seed.
+#[derive(Clone, Debug, Zeroize, Eq, PartialEq, Serialize, Deserialize)]
+pub struct SignKey([u8; 32]);
+
+impl Drop for SignKey {
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl ZeroizeOnDrop for SignKey {}
+
+impl SignKey {
+    /// Build a signing key from its 32-byte secret seed.
+    pub fn from_bytes(bytes: &[u8; 32]) -> Self {
+        Self(*bytes)
+    }
+
+    /// The 32-byte secret seed of the signing key.
+    pub fn to_bytes(&self) -> [u8; 32] {
+        self.0
+    }
+
+    /// The verification key of the signing key.
+    pub fn ver_key(&self) -> VerKey {
+        VerKey(SigningKey::from_bytes(&self.0).verifying_key())
+    }
+
+    /// Sign a message.
+    pub fn sign(&self, msg: &[u8]) -> Signature {
+        Signature(SigningKey::from_bytes(&self.0).sign(msg))
+    }
+}
+
+// =====================================================
+// Verification key
+// =====================================================
+
+/// Signature public verification key
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct VerKey(VerifyingKey);
+
+impl VerKey {
+    /// Parse a verification key from its 32-byte encoding.
+    /// * `returns` - Err() if the encoding is not a curve point
+    pub fn from_bytes(bytes: &[u8; 32]) -> Result<Self, PrimitivesError> {
+        VerifyingKey::from_bytes(bytes)
+            .map(Self)
+            .map_err(|e| PrimitivesError::ParameterError(format!("{e}")))
+    }
+
+    /// The 32-byte encoding of the verification key.
+    pub fn to_bytes(&self) -> [u8; 32] {
+        self.0.to_bytes()
+    }
+}
+
+impl From<&SignKey> for VerKey {
+    fn from(sk: &SignKey) -> Self {
+        sk.ver_key()
+    }
+}
+
+// =====================================================
+// Signature
+// =====================================================
+
+/// The signature of Ed25519 signature scheme
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Signature(ed25519_dalek::Signature);
+
+impl Signature {
+    /// Parse a signature from its 64-byte encoding. The encoding is checked
+    /// upon verification.
+    pub fn from_bytes(bytes: &[u8; 64]) -> Self {
+        Self(ed25519_dalek::Signature::from_bytes(bytes))
+    }
+
+    /// The 64-byte encoding of the signature.
+    pub fn to_bytes(&self) -> [u8; 64] {
+        self.0.to_bytes()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::signatures::tests::{failed_verification, sign_and_verify};
+    use ark_std::vec;
+
+    fn from_hex<const N: usize>(hex: &str) -> [u8; N] {
+        let bytes: Vec<u8> = (0..hex.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+            .collect();
+        bytes.try_into().unwrap()
+    }
+
+    #[test]
+    fn test_rfc8032_vectors() {
+        // (secret key, public key, message, signature) from RFC 8032, 7.1
+        let vectors = [
+            (
+                "9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60",
+                "d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a",
+                vec![],
+                "e5564300c360ac729086e2cc806e828a84877f1eb8e5d974d873e065224901555fb8821590a33bacc61e39701cf9b46bd25bf5f0595bbe24655141438e7a100b",
+            ),
+            (
+                "4ccd089b28ff96da9db6c346ec114e0f5b8a319f35aba624da8cf6ed4fb8a6fb",
+                "3d4017c3e843895a92b70aa74d1b7ebc9c982ccf2ec4968cc0cd55f12af4660c",
+                vec![0x72],
+                "92a009a9f0d4cab8720e820b5f642540a2b27b5416503f8fb3762223ebdb69da085ac1e43e15996e458f3613d0f11d8c387b2eaeb4302aeeb00d291612bb0c00",
+            ),
+            (
+                "c5aa8df43f9f837bedb7442f31dcb7b166d38535076f094b85ce3a2e0b4458f7",
+                "fc51cd8e6218a1a38da47ed00230f0580816ed13ba3303ac5deb911548908025",
+                vec![0xaf, 0x82],
+                "6291d657deec24024827e69c3abe01a30ce548a284743a445e3680d7db5ac3ac18ff9b538d16f290ae67f760984dc6594a7c15e9716ed28dc027beceea1ec40a",
+            ),
+        ];
+        for (sk, vk, msg, sig) in vectors {
+            let sk = SignKey::from_bytes(&from_hex(sk));
+            let vk = VerKey::from_bytes(&from_hex(vk)).unwrap();
+            let expected = Signature::from_bytes(&from_hex(sig));
+            assert_eq!(sk.ver_key(), vk);
+            let sig = sk.sign(&msg);
+            assert_eq!(sig, expected);
+            assert!(Ed25519SignatureScheme::verify(&(), &vk, &msg, &sig).is_ok());
+        }
+    }
+
+    #[test]
+    fn test_batch_verify() {
+        let mut rng = jf_utils::test_rng();
+        let mut vks = vec![];
+        let mut msgs = vec![];
+        let mut sigs = vec![];
+        for i in 0..8u8 {
+            let (sk, vk) = Ed25519SignatureScheme::key_gen(&(), &mut rng).unwrap();
+            let msg = vec![i; i as usize];
+            let sig = Ed25519SignatureScheme::sign(&(), &sk, &msg, &mut rng).unwrap();
+            vks.push(vk);
+            msgs.push(msg);
+            sigs.push(sig);
+        }
+        assert!(Ed25519SignatureScheme::batch_verify(&vks, &msgs, &sigs).is_ok());
+
+        // a single invalid signature fails the batch
+        let mut bad_msgs = msgs.clone();
+        bad_msgs[3].push(0);
+        assert!(Ed25519SignatureScheme::batch_verify(&vks, &bad_msgs, &sigs).is_err());
+        let mut bad_sigs = sigs.clone();
+        bad_sigs.swap(1, 2);
+        assert!(Ed25519SignatureScheme::batch_verify(&vks, &msgs, &bad_sigs).is_err());
+
+        // mismatching lengths
+        assert!(Ed25519SignatureScheme::batch_verify(&vks[1..], &msgs, &sigs).is_err());
+        assert!(Ed25519SignatureScheme::batch_verify(&vks, &msgs, &sigs[1..]).is_err());
+    }
+
+    #[test]
+    fn test_sig_trait() {
+        let message = [87u8, 32u8];
+        let wrong_message = [255u8];
+        sign_and_verify::<Ed25519SignatureScheme>(&message);
+        sign_and_verify::<Ed25519SignatureScheme>(&[]);
+        failed_verification::<Ed25519SignatureScheme>(&message, &wrong_message);
+    }
+
+    #[test]
+    fn test_serde() {
+        let mut rng = jf_utils::test_rng();
+        let (sk, vk) = Ed25519SignatureScheme::key_gen(&(), &mut rng).unwrap();
+        let sig = Ed25519SignatureScheme::sign(&(), &sk, [1u8, 2, 3], &mut rng).unwrap();
+        assert_eq!(SignKey::from_bytes(&sk.to_bytes()), sk);
+        assert_eq!(VerKey::from_bytes(&vk.to_bytes()).unwrap(), vk);
+        assert_eq!(Signature::from_bytes(&sig.to_bytes()), sig);
+
+        let bytes = bincode::serialize(&(&sk, &vk, &sig)).unwrap();
+        let (sk_de, vk_de, sig_de): (SignKey, VerKey, Signature) =
+            bincode::deserialize(&bytes).unwrap();
+        assert_eq!((sk_de, vk_de, sig_de), (sk, vk, sig));
+    }
+}
@@ -3,9 +3,19 @@
 use crate::errors::PrimitivesError;
 use ark_std::rand::{CryptoRng, RngCore};
 
+pub mod adaptor;
+pub mod bbs;
+pub mod bip340;
+pub mod blind_schnorr;
 pub mod bls_over_bls12381;
 pub mod bls_over_bn254;
+pub mod ecdsa;
+pub mod ed25519;
+pub mod musig2;
+pub mod ring;
 pub mod schnorr;
+pub mod signer;
+pub mod threshold_bls;
 
 pub use bls_over_bls12381::BLSSignatureScheme;
 use core::fmt::Debug;
@@ -132,6 +142,7 @@ mod tests {
     use super::*;
     use ark_std::{rand::prelude::StdRng, vec, vec::Vec};
     use jf_utils::test_rng;
+    use zeroize::ZeroizeOnDrop;
 
     pub(crate) fn sign_and_verify<S: SignatureScheme>(message: &[S::MessageUnit]) {
         let rng = &mut test_rng();
@@ -191,4 +202,19 @@ mod tests {
         let sig = S::sign(&parameters, &sk, message, rng).unwrap();
         assert!(S::verify(&parameters, &pk, bad_message, &sig).is_err());
     }
+
+    fn assert_zeroize_on_drop<T: ZeroizeOnDrop>() {}
+
+    #[test]
+    fn test_secrets_zeroize_on_drop() {
+        use ark_ed_on_bn254::{EdwardsConfig, Fr};
+        assert_zeroize_on_drop::<schnorr::SignKey<Fr>>();
+        assert_zeroize_on_drop::<ed25519::SignKey>();
+        assert_zeroize_on_drop::<ecdsa::SignKey>();
+        assert_zeroize_on_drop::<bip340::SignKey>();
+        assert_zeroize_on_drop::<adaptor::AdaptorSecret<EdwardsConfig>>();
+        assert_zeroize_on_drop::<blind_schnorr::SignerSession<EdwardsConfig>>();
+        assert_zeroize_on_drop::<blind_schnorr::UserSession<EdwardsConfig>>();
+        assert_zeroize_on_drop::<musig2::SecretNonce<EdwardsConfig>>();
+    }
 }
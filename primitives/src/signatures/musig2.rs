@@ -0,0 +1,429 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+//! This module implements the [MuSig2][musig2] two-round multisignature on top
+//! of the [Schnorr signature][schnorr] over the various Edwards curves.
+//!
+//! Let `H_agg`, `H_non` be Rescue-based hashes into the scalar field, domain
+//! separated by [`CS_ID_MUSIG2_KEY_AGG`] and [`CS_ID_MUSIG2_NONCE`], and `c`
+//! the challenge of the Schnorr scheme.
+//!
+//! **KeyAgg(X_1,...,X_n)**
+//!    * `a_i=H_agg(L,X_i)` for `L=(X_1,...,X_n)`, return `X=prod_i X_i^{a_i}`
+//!
+//! **NonceGen() (first round)**
+//!    * sample `r_{i,1},r_{i,2}`, publish `R_{i,j}=g^{r_{i,j}}`
+//!
+//! **Sign(x_i,m) (second round)**
+//!    * `R_j=prod_i R_{i,j}`, `b=H_non(X,R_1,R_2,m)`, `R=R_1 R_2^b`
+//!    * return `s_i=r_{i,1}+b r_{i,2}+c(X,R,m) a_i x_i`
+//!
+//! **Aggregate(s_1,...,s_n)**
+//!    * return `(s=sum_i s_i, R)`
+//!
+//! The aggregated signature is an ordinary Schnorr signature under the
+//! aggregated key `X`, and verifies with [`SchnorrSignatureScheme::verify`].
+//! The first round does not depend on the message, so it can be run ahead of
+//! time. A [`SecretNonce`] must never be used twice: it is consumed by
+//! [`SigningSession::partial_sign`], and cannot be cloned or serialized.
+//!
+//! ```
+//! use ark_ed_on_bn254::{EdwardsConfig, Fq};
+//! use jf_primitives::signatures::{
+//!     musig2::{nonce_gen, AggNonce, KeyAggContext, SigningSession},
+//!     schnorr::KeyPair,
+//!     SchnorrSignatureScheme, SignatureScheme,
+//! };
+//!
+//! let mut rng = jf_utils::test_rng();
+//! let key_pairs: Vec<KeyPair<EdwardsConfig>> =
+//!     (0..3).map(|_| KeyPair::generate(&mut rng)).collect();
+//! let vks: Vec<_> = key_pairs.iter().map(|kp| kp.ver_key()).collect();
+//! let ctx = KeyAggContext::new(&vks).unwrap();
+//!
+//! // first round
+//! let (sec_nonces, pub_nonces): (Vec<_>, Vec<_>) =
+//!     (0..3).map(|_| nonce_gen(&mut rng)).unzip();
+//! let agg_nonce = AggNonce::aggregate(&pub_nonces).unwrap();
+//!
+//! // second round
+//! let msg = [Fq::from(42u64)];
+//! let session = SigningSession::new(&ctx, &agg_nonce, &msg);
+//! let partial_sigs: Vec<_> = key_pairs
+//!     .iter()
+//!     .zip(sec_nonces)
+//!     .map(|(kp, sec_nonce)| session.partial_sign(kp, sec_nonce).unwrap())
+//!     .collect();
+//! let sig = session.aggregate(&partial_sigs);
+//!
+//! let agg_vk = ctx.agg_ver_key();
+//! assert!(
+//!     SchnorrSignatureScheme::<EdwardsConfig>::verify(&(), agg_vk, &msg, &sig).is_ok()
+//! );
+//! ```
+//!
+//! [musig2]: https://eprint.iacr.org/2020/1261
+//! [schnorr]: crate::signatures::schnorr
+//! [`SchnorrSignatureScheme::verify`]: crate::signatures::SignatureScheme::verify
+
+use super::schnorr::{KeyPair, Signature, VerKey};
+use crate::{
+    constants::{CS_ID_MUSIG2_KEY_AGG, CS_ID_MUSIG2_NONCE, CS_ID_SCHNORR},
+    crhf::{VariableLengthRescueCRHF, CRHF},
+    errors::PrimitivesError,
+    rescue::RescueParameter,
+};
+use ark_ec::{
+    twisted_edwards::{Projective, TECurveConfig as Config},
+    CurveGroup, Group,
+};
+use ark_ff::Zero;
+use ark_serialize::*;
+use ark_std::{
+    rand::{CryptoRng, RngCore},
+    string::ToString,
+    vec,
+    vec::Vec,
+    UniformRand,
+};
+use jf_utils::fq_to_fr_with_mask;
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+/// The aggregation of a list of verification keys, along with the
+/// coefficient of each key.
+#[derive(Derivative)]
+#[derivative(
+    Debug(bound = "P: Config"),
+    Clone(bound = "P: Config"),
+    PartialEq(bound = "P: Config")
+)]
+pub struct KeyAggContext<P: Config> {
+    vks: Vec<VerKey<P>>,
+    coeffs: Vec<P::ScalarField>,
+    agg_vk: VerKey<P>,
+}
+
+/// The secret nonce of a signer for a single signing session.
+pub struct SecretNonce<P: Config>([P::ScalarField; 2]);
+
+impl<P: Config> Drop for SecretNonce<P> {
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl<P: Config> ZeroizeOnDrop for SecretNonce<P> {}
+
+/// The public nonce of a signer, sent to the other signers in the first round.
+#[derive(CanonicalSerialize, CanonicalDeserialize, Derivative)]
+#[derivative(
+    Debug(bound = "P: Config"),
+    Clone(bound = "P: Config"),
+    PartialEq(bound = "P: Config")
+)]
+pub struct PublicNonce<P: Config>([Projective<P>; 2]);
+
+/// The aggregation of the public nonces of all signers.
+#[derive(CanonicalSerialize, CanonicalDeserialize, Derivative)]
+#[derivative(
+    Debug(bound = "P: Config"),
+    Clone(bound = "P: Config"),
+    PartialEq(bound = "P: Config")
+)]
+pub struct AggNonce<P: Config>([Projective<P>; 2]);
+
+/// The partial signature of a signer, sent to the aggregator in the second
+/// round.
+#[derive(CanonicalSerialize, CanonicalDeserialize, Derivative)]
+#[derivative(
+    Debug(bound = "P: Config"),
+    Clone(bound = "P: Config"),
+    PartialEq(bound = "P: Config")
+)]
+pub struct PartialSignature<P: Config>(P::ScalarField);
+
+/// The state of the second round, shared by all signers of a message.
+#[derive(Derivative)]
+#[derivative(Debug(bound = "P: Config"), Clone(bound = "P: Config"))]
+#[allow(non_snake_case)]
+pub struct SigningSession<'a, P: Config> {
+    ctx: &'a KeyAggContext<P>,
+    // the nonce coefficient `b`
+    b: P::ScalarField,
+    // the signature nonce `R`
+    R: Projective<P>,
+    // the Schnorr challenge `c`
+    c: P::ScalarField,
+}
+
+// Hash `input`, prefixed by the domain separator `csid`, into the scalar field.
+fn hash_to_scalar<F, P>(csid: &str, input: &[F]) -> P::ScalarField
+where
+    F: RescueParameter,
+    P: Config<BaseField = F>,
+{
+    let mut hash_input = vec![F::from_be_bytes_mod_order(csid.as_bytes())];
+    hash_input.extend_from_slice(input);
+    let hash = VariableLengthRescueCRHF::<F, 1>::evaluate(hash_input).unwrap()[0]; // safe unwrap
+    fq_to_fr_with_mask(&hash)
+}
+
+// The affine coordinates of the points.
+fn points_to_fields<P: Config>(points: &[Projective<P>]) -> Vec<P::BaseField> {
+    CurveGroup::normalize_batch(points)
+        .into_iter()
+        .flat_map(|p| [p.x, p.y])
+        .collect()
+}
+
+/// Sample the secret and public nonces of a signer for a signing session.
+pub fn nonce_gen<P, R>(prng: &mut R) -> (SecretNonce<P>, PublicNonce<P>)
+where
+    P: Config,
+    R: CryptoRng + RngCore,
+{
+    let mut r = [P::ScalarField::rand(prng), P::ScalarField::rand(prng)];
+    let g = Projective::<P>::generator();
+    let pub_nonce = PublicNonce([g * r[0], g * r[1]]);
+    let sec_nonce = SecretNonce(r);
+    r.zeroize();
+    (sec_nonce, pub_nonce)
+}
+
+impl<F, P> KeyAggContext<P>
+where
+    F: RescueParameter,
+    P: Config<BaseField = F>,
+{
+    /// Aggregate a list of verification keys. The aggregated key depends on
+    /// the order of the keys.
+    /// * `returns` - Err() if `vks` is empty
+    pub fn new(vks: &[VerKey<P>]) -> Result<Self, PrimitivesError> {
+        if vks.is_empty() {
+            return Err(PrimitivesError::ParameterError(
+                "no verification key to aggregate".to_string(),
+            ));
+        }
+        let points: Vec<_> = vks.iter().map(|vk| *vk.internal()).collect();
+        let key_list = points_to_fields(&points);
+        let coeffs: Vec<P::ScalarField> = key_list
+            .chunks(2)
+            .map(|vk| {
+                let mut input = key_list.clone();
+                input.extend_from_slice(vk);
+                hash_to_scalar::<F, P>(CS_ID_MUSIG2_KEY_AGG, &input)
+            })
+            .collect();
+        let agg_vk = points
+            .iter()
+            .zip(coeffs.iter())
+            .map(|(point, coeff)| *point * coeff)
+            .sum();
+        Ok(Self {
+            vks: vks.to_vec(),
+            coeffs,
+            agg_vk: VerKey(agg_vk),
+        })
+    }
+
+    /// The aggregated verification key, under which the aggregated signatures
+    /// verify.
+    pub fn agg_ver_key(&self) -> &VerKey<P> {
+        &self.agg_vk
+    }
+
+    /// The aggregation coefficient of `vk`, if it is one of the aggregated
+    /// keys.
+    pub fn coeff(&self, vk: &VerKey<P>) -> Option<P::ScalarField> {
+        self.vks
+            .iter()
+            .position(|key| key == vk)
+            .map(|i| self.coeffs[i])
+    }
+}
+
+impl<P: Config> AggNonce<P> {
+    /// Aggregate the public nonces of all signers.
+    /// * `returns` - Err() if `pub_nonces` is empty
+    pub fn aggregate(pub_nonces: &[PublicNonce<P>]) -> Result<Self, PrimitivesError> {
+        if pub_nonces.is_empty() {
+            return Err(PrimitivesError::ParameterError(
+                "no public nonce to aggregate".to_string(),
+            ));
+        }
+        let mut agg = [Projective::<P>::zero(); 2];
+        for nonce in pub_nonces {
+            agg[0] += nonce.0[0];
+            agg[1] += nonce.0[1];
+        }
+        Ok(Self(agg))
+    }
+}
+
+impl<'a, F, P> SigningSession<'a, P>
+where
+    F: RescueParameter,
+    P: Config<BaseField = F>,
+{
+    /// Start the second round for signing `msg` under the aggregated key of
+    /// `ctx`.
+    #[allow(non_snake_case)]
+    pub fn new(ctx: &'a KeyAggContext<P>, agg_nonce: &AggNonce<P>, msg: &[F]) -> Self {
+        let mut input = points_to_fields(&[ctx.agg_vk.0, agg_nonce.0[0], agg_nonce.0[1]]);
+        input.extend_from_slice(msg);
+        let b = hash_to_scalar::<F, P>(CS_ID_MUSIG2_NONCE, &input);
+        let R = agg_nonce.0[0] + agg_nonce.0[1] * b;
+        let c = ctx.agg_vk.challenge(&R, msg, CS_ID_SCHNORR);
+        Self { ctx, b, R, c }
+    }
+
+    /// Produce the partial signature of the owner of `key_pair`, consuming its
+    /// secret nonce.
+    /// * `returns` - Err() if the key is not one of the aggregated keys
+    pub fn partial_sign(
+        &self,
+        key_pair: &KeyPair<P>,
+        sec_nonce: SecretNonce<P>,
+    ) -> Result<PartialSignature<P>, PrimitivesError> {
+        let coeff = self.ctx.coeff(key_pair.ver_key_ref()).ok_or_else(|| {
+            PrimitivesError::ParameterError("the key is not an aggregated key".to_string())
+        })?;
+        let r = &sec_nonce.0;
+        Ok(PartialSignature(
+            r[0] + self.b * r[1] + self.c * coeff * key_pair.sign_key_internal(),
+        ))
+    }
+
+    /// Verify the partial signature of the owner of `vk`, given their public
+    /// nonce.
+    /// * `returns` - Err() if the key is not one of the aggregated keys or if
+    ///   the partial signature is invalid
+    pub fn partial_verify(
+        &self,
+        vk: &VerKey<P>,
+        pub_nonce: &PublicNonce<P>,
+        partial_sig: &PartialSignature<P>,
+    ) -> Result<(), PrimitivesError> {
+        let coeff = self.ctx.coeff(vk).ok_or_else(|| {
+            PrimitivesError::ParameterError("the key is not an aggregated key".to_string())
+        })?;
+        let lhs = Projective::<P>::generator() * partial_sig.0;
+        let rhs = pub_nonce.0[0] + pub_nonce.0[1] * self.b + *vk.internal() * (self.c * coeff);
+        if lhs == rhs {
+            Ok(())
+        } else {
+            Err(PrimitivesError::VerificationError(
+                "Partial signature verification error".to_string(),
+            ))
+        }
+    }
+
+    /// Aggregate the partial signatures of all signers into a Schnorr
+    /// signature under the aggregated key. The partial signatures are not
+    /// checked, see [`Self::partial_verify`].
+    pub fn aggregate(&self, partial_sigs: &[PartialSignature<P>]) -> Signature<P> {
+        Signature {
+            s: partial_sigs.iter().map(|sig| sig.0).sum(),
+            R: self.R,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::signatures::{SchnorrSignatureScheme, SignatureScheme};
+    use ark_ec::CurveConfig;
+    use ark_ed_on_bls12_377::EdwardsConfig as Param377;
+    use ark_ed_on_bls12_381::EdwardsConfig as Param381;
+    use ark_ed_on_bls12_381_bandersnatch::EdwardsConfig as Param381b;
+    use ark_ed_on_bn254::EdwardsConfig as Param254;
+
+    macro_rules! test_musig2 {
+        ($curve_param:tt) => {
+            let mut rng = jf_utils::test_rng();
+            let n = 4;
+            let key_pairs: Vec<KeyPair<$curve_param>> =
+                (0..n).map(|_| KeyPair::generate(&mut rng)).collect();
+            let vks: Vec<_> = key_pairs.iter().map(|kp| kp.ver_key()).collect();
+            let ctx = KeyAggContext::new(&vks).unwrap();
+            let msg = vec![
+                <$curve_param as CurveConfig>::BaseField::rand(&mut rng),
+                <$curve_param as CurveConfig>::BaseField::rand(&mut rng),
+            ];
+
+            let (sec_nonces, pub_nonces): (Vec<_>, Vec<_>) =
+                (0..n).map(|_| nonce_gen(&mut rng)).unzip();
+            let agg_nonce = AggNonce::aggregate(&pub_nonces).unwrap();
+            let session = SigningSession::new(&ctx, &agg_nonce, &msg);
+            let partial_sigs: Vec<_> = key_pairs
+                .iter()
+                .zip(sec_nonces)
+                .map(|(kp, sec_nonce)| session.partial_sign(kp, sec_nonce).unwrap())
+                .collect();
+            for ((vk, pub_nonce), partial_sig) in vks.iter().zip(&pub_nonces).zip(&partial_sigs) {
+                assert!(session.partial_verify(vk, pub_nonce, partial_sig).is_ok());
+            }
+            assert!(session
+                .partial_verify(&vks[0], &pub_nonces[1], &partial_sigs[0])
+                .is_err());
+            assert!(session
+                .partial_verify(&vks[1], &pub_nonces[0], &partial_sigs[0])
+                .is_err());
+
+            let sig = session.aggregate(&partial_sigs);
+            let agg_vk = ctx.agg_ver_key();
+            assert!(
+                SchnorrSignatureScheme::<$curve_param>::verify(&(), agg_vk, &msg, &sig).is_ok()
+            );
+            assert!(agg_vk.verify(&msg[..1], &sig, CS_ID_SCHNORR).is_err());
+            assert!(vks[0].verify(&msg, &sig, CS_ID_SCHNORR).is_err());
+            // the aggregated key is not the plain sum of the keys
+            let sum: Projective<$curve_param> = vks.iter().map(|vk| *vk.internal()).sum();
+            assert!(VerKey(sum).verify(&msg, &sig, CS_ID_SCHNORR).is_err());
+
+            // a missing partial signature
+            let sig = session.aggregate(&partial_sigs[1..]);
+            assert!(agg_vk.verify(&msg, &sig, CS_ID_SCHNORR).is_err());
+
+            // a signer outside of the aggregated keys
+            let outsider = KeyPair::<$curve_param>::generate(&mut rng);
+            let (sec_nonce, pub_nonce) = nonce_gen(&mut rng);
+            assert!(session.partial_sign(&outsider, sec_nonce).is_err());
+            assert!(session
+                .partial_verify(outsider.ver_key_ref(), &pub_nonce, &partial_sigs[0])
+                .is_err());
+
+            // a single signer
+            let ctx = KeyAggContext::new(&vks[..1]).unwrap();
+            let (sec_nonce, pub_nonce) = nonce_gen(&mut rng);
+            let agg_nonce = AggNonce::aggregate(&[pub_nonce]).unwrap();
+            let session = SigningSession::new(&ctx, &agg_nonce, &msg);
+            let partial_sig = session.partial_sign(&key_pairs[0], sec_nonce).unwrap();
+            let sig = session.aggregate(&[partial_sig]);
+            assert!(ctx.agg_ver_key().verify(&msg, &sig, CS_ID_SCHNORR).is_ok());
+
+            // the order of the keys matters
+            let mut reversed = vks.clone();
+            reversed.reverse();
+            assert_ne!(
+                KeyAggContext::new(&reversed).unwrap().agg_ver_key(),
+                KeyAggContext::new(&vks).unwrap().agg_ver_key()
+            );
+
+            assert!(KeyAggContext::<$curve_param>::new(&[]).is_err());
+            assert!(AggNonce::<$curve_param>::aggregate(&[]).is_err());
+        };
+    }
+
+    #[test]
+    fn test_musig2() {
+        test_musig2!(Param254);
+        test_musig2!(Param377);
+        test_musig2!(Param381);
+        test_musig2!(Param381b);
+    }
+}
@@ -0,0 +1,367 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+//! This module implements ring signatures over the Edwards curves of the
+//! [Schnorr signature][schnorr], with optional linkability tags following
+//! [LSAG][lsag].
+//!
+//! A ring signature proves that the signer owns one of the keys of a ring
+//! `(P_0,...,P_{n-1})`, without revealing which one. When linkable, the
+//! signature also carries the key image `I=x*H_p(P)` of the signing key `x`
+//! with `P=xG`, where `H_p` hashes into the curve. The key image does not
+//! depend on the ring or on the message, so two signatures by the same key
+//! are linked by their key images, while still hiding the signer.
+//!
+//! **Sign(x,ring,m)**, where the signer owns `P_j`
+//!    * sample `alpha`, `L_j=alpha*G`, `R_j=alpha*H_p(P_j)`, `c_{j+1}=H(L_j,
+//!      R_j)`
+//!    * for `i=j+1,...,j-1 mod n`, sample `s_i`, `L_i=s_i*G+c_i*P_i`,
+//!      `R_i=s_i*H_p(P_i)+c_i*I`, `c_{i+1}=H(L_i,R_i)`
+//!    * `s_j=alpha-c_j*x`, return `(c_0,s_0,...,s_{n-1},I)`
+//!
+//! **Verify(ring,m,(c_0,s_0,...,s_{n-1},I))**
+//!    * recompute the `c_i` from `c_0` as above, and check that `c_n=c_0`
+//!
+//! Here `H` hashes the ring, the key image and the message along with its
+//! inputs, and the `R_i` are dropped from signatures which are not linkable.
+//! All hashes are Rescue over the base field of the curve, as for the
+//! Schnorr challenge, so that the ring can be committed to as leaves of a
+//! Rescue Merkle tree of verification keys.
+//!
+//! [schnorr]: crate::signatures::schnorr
+//! [lsag]: https://eprint.iacr.org/2004/027
+
+use super::schnorr::{KeyPair, VerKey};
+use crate::{
+    constants::{CS_ID_RING_SIG, CS_ID_RING_SIG_KEY_IMAGE},
+    crhf::{VariableLengthRescueCRHF, CRHF},
+    errors::PrimitivesError,
+    rescue::RescueParameter,
+};
+use ark_ec::{
+    twisted_edwards::{Affine, Projective, TECurveConfig as Config},
+    AffineRepr, CurveGroup, Group,
+};
+use ark_ff::Zero;
+use ark_serialize::*;
+use ark_std::{
+    hash::{Hash, Hasher},
+    rand::{CryptoRng, RngCore},
+    string::ToString,
+    vec,
+    vec::Vec,
+    UniformRand,
+};
+use jf_utils::fq_to_fr_with_mask;
+
+/// The linkability tag of a signing key, shared by all the linkable ring
+/// signatures produced with the key.
+#[derive(CanonicalSerialize, CanonicalDeserialize, Derivative)]
+#[derivative(
+    Debug(bound = "P: Config"),
+    Clone(bound = "P: Config"),
+    Copy(bound = "P: Config")
+)]
+pub struct KeyImage<P: Config>(Projective<P>);
+
+impl<P: Config> PartialEq for KeyImage<P> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.into_affine() == other.0.into_affine()
+    }
+}
+
+impl<P: Config> Eq for KeyImage<P> {}
+
+impl<P: Config> Hash for KeyImage<P> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        Hash::hash(&self.0.into_affine(), state)
+    }
+}
+
+/// A ring signature, linkable if it carries a key image.
+#[derive(CanonicalSerialize, CanonicalDeserialize, Derivative)]
+#[derivative(
+    Debug(bound = "P: Config"),
+    Clone(bound = "P: Config"),
+    PartialEq(bound = "P: Config")
+)]
+pub struct RingSignature<P: Config> {
+    c_0: P::ScalarField,
+    responses: Vec<P::ScalarField>,
+    key_image: Option<KeyImage<P>>,
+}
+
+impl<F, P> KeyImage<P>
+where
+    F: RescueParameter,
+    P: Config<BaseField = F>,
+{
+    /// The key image of a key pair.
+    pub fn new(key_pair: &KeyPair<P>) -> Self {
+        Self(hash_to_curve(key_pair.ver_key_ref()) * key_pair.sign_key_internal())
+    }
+}
+
+impl<F, P> RingSignature<P>
+where
+    F: RescueParameter,
+    P: Config<BaseField = F>,
+{
+    /// Sign `msg` on behalf of `ring`, which must contain the verification
+    /// key of `key_pair`. The signature is linkable if `linkable` is set.
+    /// * `returns` - Err() if the verification key is not in the ring
+    #[allow(non_snake_case)]
+    pub fn sign<R: CryptoRng + RngCore>(
+        ring: &[VerKey<P>],
+        key_pair: &KeyPair<P>,
+        msg: &[F],
+        linkable: bool,
+        prng: &mut R,
+    ) -> Result<Self, PrimitivesError> {
+        let n = ring.len();
+        let j = ring
+            .iter()
+            .position(|vk| vk == key_pair.ver_key_ref())
+            .ok_or_else(|| {
+                PrimitivesError::ParameterError(
+                    "the verification key is not in the ring".to_string(),
+                )
+            })?;
+        let key_image = linkable.then(|| KeyImage::new(key_pair));
+        let bases: Vec<_> = if linkable {
+            ring.iter().map(|vk| hash_to_curve::<F, P>(vk)).collect()
+        } else {
+            vec![]
+        };
+        let prefix = hash_prefix(ring, key_image.as_ref(), msg);
+        let G = Projective::<P>::generator();
+
+        let alpha = P::ScalarField::rand(prng);
+        let mut responses: Vec<_> = (0..n).map(|_| P::ScalarField::rand(prng)).collect();
+        let mut challenges = vec![P::ScalarField::default(); n];
+        let mut L = G * alpha;
+        let mut R = bases.get(j).map(|base| *base * alpha);
+        for k in 1..=n {
+            let i = (j + k) % n;
+            challenges[i] = round_challenge(&prefix, &L, R.as_ref());
+            if i == j {
+                break;
+            }
+            L = G * responses[i] + *ring[i].internal() * challenges[i];
+            R = key_image
+                .as_ref()
+                .map(|image| bases[i] * responses[i] + image.0 * challenges[i]);
+        }
+        responses[j] = alpha - challenges[j] * key_pair.sign_key_internal();
+
+        Ok(Self {
+            c_0: challenges[0],
+            responses,
+            key_image,
+        })
+    }
+
+    /// Verify the signature of `msg` on behalf of `ring`.
+    #[allow(non_snake_case)]
+    pub fn verify(&self, ring: &[VerKey<P>], msg: &[F]) -> Result<(), PrimitivesError> {
+        if ring.is_empty() || ring.len() != self.responses.len() {
+            return Err(PrimitivesError::ParameterError(
+                "ring size mismatch".to_string(),
+            ));
+        }
+        if ring
+            .iter()
+            .any(|vk| vk.to_affine().mul_by_cofactor_to_group().is_zero())
+        {
+            return Err(PrimitivesError::VerificationError(
+                "public key is not valid: not in the correct subgroup".to_string(),
+            ));
+        }
+        if let Some(image) = &self.key_image {
+            let image = image.0.into_affine();
+            if image.is_zero() || !image.is_in_correct_subgroup_assuming_on_curve() {
+                return Err(PrimitivesError::VerificationError(
+                    "key image is not valid: not in the correct subgroup".to_string(),
+                ));
+            }
+        }
+
+        let prefix = hash_prefix(ring, self.key_image.as_ref(), msg);
+        let G = Projective::<P>::generator();
+        let mut c = self.c_0;
+        for (vk, s) in ring.iter().zip(self.responses.iter()) {
+            let L = G * s + *vk.internal() * c;
+            let R = self
+                .key_image
+                .as_ref()
+                .map(|image| hash_to_curve(vk) * s + image.0 * c);
+            c = round_challenge(&prefix, &L, R.as_ref());
+        }
+        if c == self.c_0 {
+            Ok(())
+        } else {
+            Err(PrimitivesError::VerificationError(
+                "Ring signature verification error".to_string(),
+            ))
+        }
+    }
+}
+
+impl<P: Config> RingSignature<P> {
+    /// The key image of the signature, if it is linkable.
+    pub fn key_image(&self) -> Option<&KeyImage<P>> {
+        self.key_image.as_ref()
+    }
+
+    /// Whether both signatures are linkable and were produced by the same
+    /// signing key.
+    pub fn is_linked_to(&self, other: &Self) -> bool {
+        matches!((&self.key_image, &other.key_image), (Some(a), Some(b)) if a == b)
+    }
+}
+
+// Hash a verification key into the prime-order subgroup, such that the
+// discrete logarithm of the result is unknown: try-and-increment on the
+// y-coordinate.
+fn hash_to_curve<F, P>(vk: &VerKey<P>) -> Projective<P>
+where
+    F: RescueParameter,
+    P: Config<BaseField = F>,
+{
+    let vk = vk.to_affine();
+    let domain = F::from_be_bytes_mod_order(CS_ID_RING_SIG_KEY_IMAGE.as_bytes());
+    let mut counter = F::zero();
+    loop {
+        let y =
+            VariableLengthRescueCRHF::<F, 1>::evaluate([domain, vk.x, vk.y, counter]).unwrap()[0]; // safe unwrap
+        if let Some(point) = Affine::<P>::get_point_from_y_unchecked(y, false) {
+            let point = point.mul_by_cofactor_to_group();
+            if !point.is_zero() {
+                return point;
+            }
+        }
+        counter += F::one();
+    }
+}
+
+// Hash the ring, the key image and the message, which are bound to every
+// challenge.
+fn hash_prefix<F, P>(ring: &[VerKey<P>], key_image: Option<&KeyImage<P>>, msg: &[F]) -> F
+where
+    F: RescueParameter,
+    P: Config<BaseField = F>,
+{
+    let mut points: Vec<_> = ring.iter().map(|vk| *vk.internal()).collect();
+    points.extend(key_image.map(|image| image.0));
+    let mut input = vec![
+        F::from_be_bytes_mod_order(CS_ID_RING_SIG.as_bytes()),
+        F::from(ring.len() as u64),
+    ];
+    input.extend(
+        CurveGroup::normalize_batch(&points)
+            .into_iter()
+            .flat_map(|p| [p.x, p.y]),
+    );
+    input.extend_from_slice(msg);
+    VariableLengthRescueCRHF::<F, 1>::evaluate(input).unwrap()[0] // safe unwrap
+}
+
+#[allow(non_snake_case)]
+fn round_challenge<F, P>(prefix: &F, L: &Projective<P>, R: Option<&Projective<P>>) -> P::ScalarField
+where
+    F: RescueParameter,
+    P: Config<BaseField = F>,
+{
+    let L = L.into_affine();
+    let mut input = vec![*prefix, L.x, L.y];
+    if let Some(R) = R {
+        let R = R.into_affine();
+        input.extend([R.x, R.y]);
+    }
+    let challenge = VariableLengthRescueCRHF::<F, 1>::evaluate(input).unwrap()[0]; // safe unwrap
+    fq_to_fr_with_mask(&challenge)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_ec::CurveConfig;
+    use ark_ed_on_bls12_377::EdwardsConfig as Param377;
+    use ark_ed_on_bls12_381::EdwardsConfig as Param381;
+    use ark_ed_on_bls12_381_bandersnatch::EdwardsConfig as Param381b;
+    use ark_ed_on_bn254::EdwardsConfig as Param254;
+
+    macro_rules! test_ring_signature {
+        ($curve_param:tt) => {
+            let mut rng = jf_utils::test_rng();
+            let key_pairs: Vec<KeyPair<$curve_param>> =
+                (0..5).map(|_| KeyPair::generate(&mut rng)).collect();
+            let ring: Vec<_> = key_pairs.iter().map(|kp| kp.ver_key()).collect();
+            let msg = vec![<$curve_param as CurveConfig>::BaseField::rand(&mut rng)];
+            let other_msg = vec![<$curve_param as CurveConfig>::BaseField::rand(&mut rng)];
+
+            for linkable in [false, true] {
+                for key_pair in key_pairs.iter() {
+                    let sig =
+                        RingSignature::sign(&ring, key_pair, &msg, linkable, &mut rng).unwrap();
+                    assert!(sig.verify(&ring, &msg).is_ok());
+                    assert_eq!(sig.key_image().is_some(), linkable);
+                    // wrong message, ring or response
+                    assert!(sig.verify(&ring, &other_msg).is_err());
+                    assert!(sig.verify(&ring[1..], &msg).is_err());
+                    let mut reordered = ring.clone();
+                    reordered.swap(0, 1);
+                    assert!(sig.verify(&reordered, &msg).is_err());
+                    let mut bad_sig = sig.clone();
+                    bad_sig.responses[2] += <$curve_param as CurveConfig>::ScalarField::from(1u64);
+                    assert!(bad_sig.verify(&ring, &msg).is_err());
+                }
+            }
+
+            // linkability across messages and rings
+            let sig1 = RingSignature::sign(&ring, &key_pairs[1], &msg, true, &mut rng).unwrap();
+            let sig2 = RingSignature::sign(&ring[1..4], &key_pairs[1], &other_msg, true, &mut rng)
+                .unwrap();
+            let sig3 = RingSignature::sign(&ring, &key_pairs[2], &msg, true, &mut rng).unwrap();
+            let sig4 = RingSignature::sign(&ring, &key_pairs[1], &msg, false, &mut rng).unwrap();
+            assert!(sig2.verify(&ring[1..4], &other_msg).is_ok());
+            assert!(sig1.is_linked_to(&sig2));
+            assert!(!sig1.is_linked_to(&sig3));
+            assert!(!sig1.is_linked_to(&sig4));
+            assert_eq!(sig1.key_image(), Some(&KeyImage::new(&key_pairs[1])));
+
+            // a key image cannot be replaced, nor dropped
+            let mut bad_sig = sig1.clone();
+            bad_sig.key_image = sig3.key_image;
+            assert!(bad_sig.verify(&ring, &msg).is_err());
+            bad_sig.key_image = None;
+            assert!(bad_sig.verify(&ring, &msg).is_err());
+
+            // a ring of one
+            let sig = RingSignature::sign(&ring[..1], &key_pairs[0], &msg, true, &mut rng).unwrap();
+            assert!(sig.verify(&ring[..1], &msg).is_ok());
+
+            // the signer must be in the ring
+            assert!(RingSignature::sign(&ring[1..], &key_pairs[0], &msg, true, &mut rng).is_err());
+            assert!(RingSignature::sign(&[], &key_pairs[0], &msg, false, &mut rng).is_err());
+            assert!(sig1.verify(&[], &msg).is_err());
+
+            // serialization
+            let mut bytes = Vec::new();
+            sig1.serialize_compressed(&mut bytes).unwrap();
+            let sig_de = RingSignature::<$curve_param>::deserialize_compressed(&bytes[..]).unwrap();
+            assert_eq!(sig_de, sig1);
+        };
+    }
+
+    #[test]
+    fn test_ring_signature() {
+        test_ring_signature!(Param254);
+        test_ring_signature!(Param377);
+        test_ring_signature!(Param381);
+        test_ring_signature!(Param381b);
+    }
+}
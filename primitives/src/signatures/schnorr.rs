@@ -32,7 +32,7 @@ use ark_std::{
 use espresso_systems_common::jellyfish::tag;
 use jf_utils::{fq_to_fr, fq_to_fr_with_mask, fr_to_fq};
 use tagged_base64::tagged;
-use zeroize::Zeroize;
+use zeroize::{Zeroize, ZeroizeOnDrop};
 
 /// Schnorr signature scheme.
 #[derive(Debug, PartialEq, Clone)]
@@ -116,6 +116,8 @@ impl<F: PrimeField> Drop for SignKey<F> {
     }
 }
 
+impl<F: PrimeField> ZeroizeOnDrop for SignKey<F> {}
+
 impl<F: PrimeField> SignKey<F> {
     // returns the randomized key
     fn randomize_with(&self, randomizer: &F) -> Self {
@@ -307,11 +309,13 @@ where
         let mut msg_input = vec![instance_description, fr_to_fq::<F, P>(&self.sk.0)];
         msg_input.extend(msg.iter());
 
-        let r =
+        let mut r =
             fq_to_fr::<F, P>(&VariableLengthRescueCRHF::<F, 1>::evaluate(&msg_input).unwrap()[0]); // safe unwrap
+        msg_input[1].zeroize();
         let R = Projective::<P>::generator() * r;
         let c = self.vk.challenge(&R, msg, csid);
         let s = c * self.sk.0 + r;
+        r.zeroize();
 
         Signature { s, R }
     }
@@ -394,7 +398,12 @@ where
     // TODO: this function should be generic w.r.t. hash functions
     // Fixme after the hash-api PR is merged.
     #[allow(non_snake_case)]
-    fn challenge<B: AsRef<[u8]>>(&self, R: &Projective<P>, msg: &[F], csid: B) -> P::ScalarField {
+    pub(crate) fn challenge<B: AsRef<[u8]>>(
+        &self,
+        R: &Projective<P>,
+        msg: &[F],
+        csid: B,
+    ) -> P::ScalarField {
         // is the domain separator always an Fr? If so how about using Fr as domain
         // separator rather than bytes?
         let instance_description = F::from_be_bytes_mod_order(csid.as_ref());
@@ -0,0 +1,229 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+//! Signers holding their signing keys outside of the application, e.g. in a
+//! hardware security module or a remote signing service.
+//!
+//! A [`Signer`] of a [`SignatureScheme`] only exposes its verification key,
+//! and a [`RemoteSigner`] a signing method, so that the key never has to be
+//! loaded in memory. An [`AsyncSigner`] returns a future instead, e.g. for a
+//! signing service reached over the network. The traits are object safe, so
+//! that signers of different kinds can be used as `dyn` trait objects, and
+//! every `RemoteSigner` is an `AsyncSigner`. The signatures are verified by the
+//! scheme as usual, with [`SignatureScheme::verify`] and the verification key
+//! of the signer.
+//!
+//! [`LocalSigner`] is the signer of a key held in memory, so that code written
+//! against the traits also accepts the keys of the schemes.
+//!
+//! ```
+//! use jf_primitives::signatures::{
+//!     bls_over_bls12381::BLSSignatureScheme,
+//!     signer::{LocalSigner, RemoteSigner, Signer},
+//!     SignatureScheme,
+//! };
+//!
+//! let mut rng = jf_utils::test_rng();
+//! let (sk, vk) = BLSSignatureScheme::key_gen(&(), &mut rng)?;
+//! let signer: Box<dyn RemoteSigner<BLSSignatureScheme>> =
+//!     Box::new(LocalSigner::new((), sk, vk));
+//!
+//! let msg = b"attestation";
+//! let sig = signer.sign(msg, &mut rng)?;
+//! assert!(BLSSignatureScheme::verify(&(), &signer.ver_key(), msg, &sig).is_ok());
+//!
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use super::SignatureScheme;
+use crate::errors::PrimitivesError;
+use ark_std::{
+    boxed::Box,
+    future::{ready, Future},
+    pin::Pin,
+    rand::{CryptoRng, RngCore},
+};
+
+/// A cryptographically secure RNG, usable as a trait object.
+pub trait SignerRng: CryptoRng + RngCore {}
+
+impl<R: CryptoRng + RngCore + ?Sized> SignerRng for R {}
+
+/// The future of a signature of [`AsyncSigner::sign_async`].
+pub type SignatureFuture<'a, S> = Pin<
+    Box<
+        dyn Future<Output = Result<<S as SignatureScheme>::Signature, PrimitivesError>> + Send + 'a,
+    >,
+>;
+
+/// A signer of the scheme `S`, identified by its verification key.
+pub trait Signer<S: SignatureScheme> {
+    /// The verification key of the signatures.
+    fn ver_key(&self) -> S::VerificationKey;
+}
+
+/// A signer of the scheme `S`, whose signing key may be held outside of the
+/// application.
+pub trait RemoteSigner<S: SignatureScheme>: Signer<S> {
+    /// Sign `msg`. Signers holding the key elsewhere may ignore `prng`.
+    /// * `returns` - Err() if the signer fails or refuses to sign
+    fn sign(
+        &self,
+        msg: &[S::MessageUnit],
+        prng: &mut dyn SignerRng,
+    ) -> Result<S::Signature, PrimitivesError>;
+}
+
+/// A signer of the scheme `S` whose signatures are produced asynchronously,
+/// e.g. by a remote signing service.
+pub trait AsyncSigner<S: SignatureScheme>: Signer<S> {
+    /// Sign `msg`. As the future does not borrow `prng`, any randomness is
+    /// drawn before the future is returned.
+    fn sign_async<'a>(
+        &'a self,
+        msg: &'a [S::MessageUnit],
+        prng: &mut dyn SignerRng,
+    ) -> SignatureFuture<'a, S>;
+}
+
+impl<S, T> AsyncSigner<S> for T
+where
+    S: SignatureScheme,
+    T: RemoteSigner<S> + ?Sized,
+{
+    fn sign_async<'a>(
+        &'a self,
+        msg: &'a [S::MessageUnit],
+        prng: &mut dyn SignerRng,
+    ) -> SignatureFuture<'a, S> {
+        Box::pin(ready(self.sign(msg, prng)))
+    }
+}
+
+impl<S, T> Signer<S> for Box<T>
+where
+    S: SignatureScheme,
+    T: Signer<S> + ?Sized,
+{
+    fn ver_key(&self) -> S::VerificationKey {
+        self.as_ref().ver_key()
+    }
+}
+
+impl<S, T> RemoteSigner<S> for Box<T>
+where
+    S: SignatureScheme,
+    T: RemoteSigner<S> + ?Sized,
+{
+    fn sign(
+        &self,
+        msg: &[S::MessageUnit],
+        prng: &mut dyn SignerRng,
+    ) -> Result<S::Signature, PrimitivesError> {
+        self.as_ref().sign(msg, prng)
+    }
+}
+
+/// The signer of a signing key held in memory.
+#[derive(Derivative)]
+#[derivative(Debug(bound = ""), Clone(bound = ""))]
+pub struct LocalSigner<S: SignatureScheme> {
+    pp: S::PublicParameter,
+    sk: S::SigningKey,
+    vk: S::VerificationKey,
+}
+
+impl<S: SignatureScheme> LocalSigner<S> {
+    /// The signer of `sk`, whose verification key is `vk`.
+    pub fn new(pp: S::PublicParameter, sk: S::SigningKey, vk: S::VerificationKey) -> Self {
+        Self { pp, sk, vk }
+    }
+
+    /// The public parameter of the scheme.
+    pub fn public_parameter(&self) -> &S::PublicParameter {
+        &self.pp
+    }
+}
+
+impl<S: SignatureScheme> Signer<S> for LocalSigner<S> {
+    fn ver_key(&self) -> S::VerificationKey {
+        self.vk.clone()
+    }
+}
+
+impl<S: SignatureScheme> RemoteSigner<S> for LocalSigner<S> {
+    fn sign(
+        &self,
+        msg: &[S::MessageUnit],
+        mut prng: &mut dyn SignerRng,
+    ) -> Result<S::Signature, PrimitivesError> {
+        S::sign(&self.pp, &self.sk, msg, &mut prng)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        errors::PrimitivesError::InternalError,
+        signatures::{bls_over_bls12381::BLSSignatureScheme, schnorr::SchnorrSignatureScheme},
+    };
+    use ark_ed_on_bls12_381::EdwardsConfig;
+    use ark_std::{string::ToString, vec, vec::Vec};
+    use futures::executor::block_on;
+
+    // a signer refusing to sign, as a remote service would on failure
+    struct UnavailableSigner<S: SignatureScheme>(S::VerificationKey);
+
+    impl<S: SignatureScheme> Signer<S> for UnavailableSigner<S> {
+        fn ver_key(&self) -> S::VerificationKey {
+            self.0.clone()
+        }
+    }
+
+    impl<S: SignatureScheme> RemoteSigner<S> for UnavailableSigner<S> {
+        fn sign(
+            &self,
+            _msg: &[S::MessageUnit],
+            _prng: &mut dyn SignerRng,
+        ) -> Result<S::Signature, PrimitivesError> {
+            Err(InternalError("signing service unavailable".to_string()))
+        }
+    }
+
+    fn test_signer_helper<S: SignatureScheme>(msg: &[S::MessageUnit]) {
+        let rng = &mut jf_utils::test_rng();
+        let pp = S::param_gen(Some(rng)).unwrap();
+        let (sk, vk) = S::key_gen(&pp, rng).unwrap();
+        let (_, other_vk) = S::key_gen(&pp, rng).unwrap();
+        let signers: Vec<Box<dyn RemoteSigner<S>>> = vec![
+            Box::new(LocalSigner::<S>::new(pp.clone(), sk, vk.clone())),
+            Box::new(UnavailableSigner::<S>(other_vk)),
+        ];
+
+        let sig = signers[0].sign(msg, rng).unwrap();
+        assert!(S::verify(&pp, &signers[0].ver_key(), msg, &sig).is_ok());
+        assert!(S::verify(&pp, &signers[1].ver_key(), msg, &sig).is_err());
+        assert!(signers[1].sign(msg, rng).is_err());
+
+        // every signer is asynchronous
+        let async_signers: Vec<&dyn AsyncSigner<S>> = signers
+            .iter()
+            .map(|signer| signer as &dyn AsyncSigner<S>)
+            .collect();
+        let sig = block_on(async_signers[0].sign_async(msg, rng)).unwrap();
+        assert!(S::verify(&pp, &async_signers[0].ver_key(), msg, &sig).is_ok());
+        assert!(block_on(async_signers[1].sign_async(msg, rng)).is_err());
+    }
+
+    #[test]
+    fn test_signers() {
+        test_signer_helper::<BLSSignatureScheme>(b"message");
+        test_signer_helper::<SchnorrSignatureScheme<EdwardsConfig>>(&[
+            ark_ed_on_bls12_381::Fq::from(7u64),
+        ]);
+    }
+}
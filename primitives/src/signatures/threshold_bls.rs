@@ -0,0 +1,390 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+//! This module implements `t`-out-of-`n` threshold BLS signatures over the
+//! BN254 curve, on top of [`BLSOverBN254CurveSignatureScheme`][bls].
+//!
+//! The secret key `s` is split by a dealer into `n` Shamir shares: it samples
+//! a random polynomial `f` of degree `t-1` with `f(0)=s`, and party `i` (for
+//! `i` in `1..=n`) gets the share `s_i=f(i)` along with the public
+//! verification share `g2^{s_i}`. The shares can also be generated without a
+//! dealer by the [distributed key generation][dkg] of the parties.
+//!
+//! **PartialSign(s_i,m)**
+//!    * return `sigma_i=H(m)^{s_i}`
+//!
+//! **PartialVerify(g2^{s_i},m,sigma_i)**
+//!    * the check of a standard BLS signature under `g2^{s_i}`
+//!
+//! **Combine({(i,sigma_i)})**
+//!    * given `t` partial signatures with distinct indices `I`, return
+//!      `sigma=prod_{i in I} sigma_i^{L_i}` where `L_i=prod_{j in I, j!=i}
+//!      j/(j-i)` is the Lagrange coefficient of `i` at `0`
+//!
+//! The combined signature is `H(m)^s`, a standard BLS signature which verifies
+//! under the group verification key `g2^s` with
+//! [`BLSOverBN254CurveSignatureScheme::verify`], whichever `t` shares
+//! signed. [`ThresholdBLS::combine`] does not check the partial signatures, so
+//! they should be checked with [`ThresholdBLS::partial_verify`] beforehand,
+//! unless the signers are trusted.
+//!
+//! ```
+//! use jf_primitives::signatures::{
+//!     bls_over_bn254::BLSOverBN254CurveSignatureScheme, threshold_bls::ThresholdBLS,
+//!     SignatureScheme,
+//! };
+//!
+//! let mut rng = jf_utils::test_rng();
+//! let tbls = ThresholdBLS::new(2, 3).unwrap();
+//! let (vk, vk_shares, sk_shares) = tbls.key_gen(&mut rng);
+//!
+//! let msg = b"committee decision";
+//! let partials: Vec<_> = sk_shares[1..]
+//!     .iter()
+//!     .map(|share| ThresholdBLS::partial_sign(share, msg))
+//!     .collect();
+//! for partial in partials.iter() {
+//!     let vk_share = &vk_shares[partial.index() as usize - 1];
+//!     assert!(ThresholdBLS::partial_verify(vk_share, msg, partial).is_ok());
+//! }
+//!
+//! let sig = tbls.combine(&partials).unwrap();
+//! assert!(BLSOverBN254CurveSignatureScheme::verify(&(), &vk, msg, &sig).is_ok());
+//! ```
+//!
+//! [bls]: crate::signatures::bls_over_bn254::BLSOverBN254CurveSignatureScheme
+//! [dkg]: crate::dkg
+//! [`BLSOverBN254CurveSignatureScheme::verify`]: crate::signatures::SignatureScheme::verify
+
+use super::bls_over_bn254::{KeyPair, SignKey, Signature, VerKey};
+use crate::{
+    constants::CS_ID_BLS_BN254,
+    errors::PrimitivesError::{self, ParameterError},
+    shamir::{check_indices, check_params, eval_shares, lagrange_coeff_at_zero, random_polynomial},
+};
+use ark_bn254::{Fr as ScalarField, G1Projective};
+use ark_serialize::*;
+use ark_std::{
+    format,
+    rand::{CryptoRng, RngCore},
+    string::ToString,
+    vec::Vec,
+    Zero,
+};
+use zeroize::Zeroize;
+
+/// Parameters of a `t`-out-of-`n` threshold BLS signature scheme.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct ThresholdBLS {
+    threshold: u32,
+    num_parties: u32,
+}
+
+/// The share of the signing key held by one party.
+#[derive(Clone, Debug, PartialEq, Eq, Zeroize, CanonicalSerialize, CanonicalDeserialize)]
+pub struct SignKeyShare {
+    index: u32,
+    sk: SignKey,
+}
+
+/// The public verification share of one party.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, CanonicalSerialize, CanonicalDeserialize)]
+pub struct VerKeyShare {
+    index: u32,
+    vk: VerKey,
+}
+
+/// The partial signature produced by one party.
+#[derive(Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct PartialSignature {
+    index: u32,
+    sig: Signature,
+}
+
+impl SignKeyShare {
+    /// Import the key share `sk` of the party of index `index`, for instance
+    /// from a distributed key generation.
+    /// * `returns` - Err() if `index` is zero
+    pub fn new(index: u32, sk: SignKey) -> Result<Self, PrimitivesError> {
+        if index == 0 {
+            return Err(ParameterError("party indices start at 1".to_string()));
+        }
+        Ok(Self { index, sk })
+    }
+
+    /// The index of the party holding the share, in `1..=n`.
+    pub fn index(&self) -> u32 {
+        self.index
+    }
+
+    /// The verification share matching this key share.
+    pub fn ver_key_share(&self) -> VerKeyShare {
+        VerKeyShare {
+            index: self.index,
+            vk: VerKey::from(&self.sk),
+        }
+    }
+}
+
+impl VerKeyShare {
+    /// Import the verification share `vk` of the party of index `index`.
+    /// * `returns` - Err() if `index` is zero
+    pub fn new(index: u32, vk: VerKey) -> Result<Self, PrimitivesError> {
+        if index == 0 {
+            return Err(ParameterError("party indices start at 1".to_string()));
+        }
+        Ok(Self { index, vk })
+    }
+
+    /// The index of the party holding the share, in `1..=n`.
+    pub fn index(&self) -> u32 {
+        self.index
+    }
+
+    /// The verification share as a BLS verification key.
+    pub fn ver_key(&self) -> VerKey {
+        self.vk
+    }
+}
+
+impl PartialSignature {
+    /// The index of the party which produced the partial signature.
+    pub fn index(&self) -> u32 {
+        self.index
+    }
+
+    /// The partial signature as a BLS signature under the verification share
+    /// of its signer.
+    pub fn signature(&self) -> &Signature {
+        &self.sig
+    }
+}
+
+impl ThresholdBLS {
+    /// Setup a scheme where any `threshold` out of `num_parties` parties can
+    /// sign.
+    /// * `returns` - Err() if `threshold` is zero or greater than `num_parties`
+    pub fn new(threshold: u32, num_parties: u32) -> Result<Self, PrimitivesError> {
+        check_params(threshold, num_parties)?;
+        Ok(Self {
+            threshold,
+            num_parties,
+        })
+    }
+
+    /// The number of partial signatures needed to sign.
+    pub fn threshold(&self) -> u32 {
+        self.threshold
+    }
+
+    /// The number of parties holding a key share.
+    pub fn num_parties(&self) -> u32 {
+        self.num_parties
+    }
+
+    /// Sample a signing key and share it between the parties.
+    /// * `returns` - the group verification key, and the verification shares
+    ///   and key shares of the parties, ordered by index
+    pub fn key_gen<R: CryptoRng + RngCore>(
+        &self,
+        prng: &mut R,
+    ) -> (VerKey, Vec<VerKeyShare>, Vec<SignKeyShare>) {
+        let sk = SignKey::generate(prng);
+        self.share_key(&sk, prng)
+    }
+
+    /// Share an existing signing key `sk` between the parties, by a trusted
+    /// dealer which knows `sk`.
+    /// * `returns` - the group verification key, and the verification shares
+    ///   and key shares of the parties, ordered by index
+    pub fn share_key<R: CryptoRng + RngCore>(
+        &self,
+        sk: &SignKey,
+        prng: &mut R,
+    ) -> (VerKey, Vec<VerKeyShare>, Vec<SignKeyShare>) {
+        let mut coeffs = random_polynomial(&sk.0, self.threshold, prng);
+        let sk_shares: Vec<_> = eval_shares(&coeffs, self.num_parties)
+            .iter()
+            .map(|share| SignKeyShare {
+                index: share.index(),
+                sk: SignKey(*share.value()),
+            })
+            .collect();
+        coeffs.zeroize();
+
+        let vk_shares = sk_shares.iter().map(|s| s.ver_key_share()).collect();
+        (VerKey::from(sk), vk_shares, sk_shares)
+    }
+
+    /// Sign a message with a key share.
+    pub fn partial_sign<M: AsRef<[u8]>>(share: &SignKeyShare, msg: M) -> PartialSignature {
+        let kp = KeyPair::generate_with_sign_key(share.sk.0);
+        PartialSignature {
+            index: share.index,
+            sig: kp.sign(msg.as_ref(), CS_ID_BLS_BN254),
+        }
+    }
+
+    /// Verify a partial signature against the verification share of its
+    /// signer.
+    /// * `returns` - Err() if the indices differ or if the signature is invalid
+    pub fn partial_verify<M: AsRef<[u8]>>(
+        vk_share: &VerKeyShare,
+        msg: M,
+        partial: &PartialSignature,
+    ) -> Result<(), PrimitivesError> {
+        if vk_share.index != partial.index {
+            return Err(ParameterError(format!(
+                "verification share of party {} for a partial signature of party {}",
+                vk_share.index, partial.index
+            )));
+        }
+        vk_share
+            .vk
+            .verify(msg.as_ref(), &partial.sig, CS_ID_BLS_BN254)
+    }
+
+    /// Combine partial signatures into a standard BLS signature under the
+    /// group verification key. Only the first `threshold` partial signatures
+    /// are used.
+    /// * `returns` - Err() if there are fewer than `threshold` partial
+    ///   signatures, or if an index is out of range or repeated
+    pub fn combine(&self, partials: &[PartialSignature]) -> Result<Signature, PrimitivesError> {
+        let indices: Vec<u32> = partials.iter().map(|p| p.index).collect();
+        check_indices(&indices, self.threshold, self.num_parties)?;
+        let partials = &partials[..self.threshold as usize];
+
+        let indices: Vec<ScalarField> = partials
+            .iter()
+            .map(|p| ScalarField::from(p.index))
+            .collect();
+        let mut sigma = G1Projective::zero();
+        for (partial, x_i) in partials.iter().zip(indices.iter()) {
+            sigma += partial.sig.sigma * lagrange_coeff_at_zero(*x_i, &indices);
+        }
+        Ok(Signature { sigma })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::signatures::{bls_over_bn254::BLSOverBN254CurveSignatureScheme, SignatureScheme};
+    use ark_std::vec;
+
+    #[test]
+    fn test_threshold_bls() {
+        let mut rng = jf_utils::test_rng();
+        let tbls = ThresholdBLS::new(3, 5).unwrap();
+        let sk = SignKey::generate(&mut rng);
+        let (vk, vk_shares, sk_shares) = tbls.share_key(&sk, &mut rng);
+        assert_eq!(vk, VerKey::from(&sk));
+        assert_eq!(vk_shares.len(), 5);
+
+        let msg = b"threshold message";
+        let expected = KeyPair::generate_with_sign_key(sk.0).sign(msg, CS_ID_BLS_BN254);
+        let partials: Vec<_> = sk_shares
+            .iter()
+            .map(|share| ThresholdBLS::partial_sign(share, msg))
+            .collect();
+        for (vk_share, partial) in vk_shares.iter().zip(partials.iter()) {
+            assert!(ThresholdBLS::partial_verify(vk_share, msg, partial).is_ok());
+            assert!(ThresholdBLS::partial_verify(vk_share, b"other", partial).is_err());
+        }
+        assert!(ThresholdBLS::partial_verify(&vk_shares[0], msg, &partials[1]).is_err());
+
+        // any `t` partial signatures combine into the same signature
+        for subset in [
+            vec![0, 1, 2],
+            vec![4, 2, 0],
+            vec![1, 3, 4],
+            vec![3, 2, 1, 0],
+        ] {
+            let subset: Vec<_> = subset.into_iter().map(|i| partials[i].clone()).collect();
+            let sig = tbls.combine(&subset).unwrap();
+            assert_eq!(sig, expected);
+            assert!(BLSOverBN254CurveSignatureScheme::verify(&(), &vk, msg, &sig).is_ok());
+        }
+
+        // too few, repeated or out of range partial signatures
+        assert!(tbls.combine(&partials[..2]).is_err());
+        let repeated = vec![
+            partials[0].clone(),
+            partials[1].clone(),
+            partials[0].clone(),
+        ];
+        assert!(tbls.combine(&repeated).is_err());
+        let mut out_of_range = partials[..3].to_vec();
+        out_of_range[2].index = 6;
+        assert!(tbls.combine(&out_of_range).is_err());
+
+        // a wrong partial signature spoils the combined signature
+        let mut bad = partials[..3].to_vec();
+        bad[1] = ThresholdBLS::partial_sign(&sk_shares[1], b"other");
+        let sig = tbls.combine(&bad).unwrap();
+        assert!(BLSOverBN254CurveSignatureScheme::verify(&(), &vk, msg, &sig).is_err());
+    }
+
+    #[test]
+    fn test_threshold_bls_params() {
+        let mut rng = jf_utils::test_rng();
+        assert!(ThresholdBLS::new(0, 3).is_err());
+        assert!(ThresholdBLS::new(4, 3).is_err());
+
+        // 1-out-of-n shares are copies of the signing key
+        let tbls = ThresholdBLS::new(1, 3).unwrap();
+        let (vk, vk_shares, _) = tbls.key_gen(&mut rng);
+        assert!(vk_shares.iter().all(|share| share.ver_key() == vk));
+
+        // n-out-of-n
+        let tbls = ThresholdBLS::new(4, 4).unwrap();
+        let (vk, _, sk_shares) = tbls.key_gen(&mut rng);
+        let partials: Vec<_> = sk_shares
+            .iter()
+            .map(|share| ThresholdBLS::partial_sign(share, [7u8]))
+            .collect();
+        let sig = tbls.combine(&partials).unwrap();
+        assert!(BLSOverBN254CurveSignatureScheme::verify(&(), &vk, [7u8], &sig).is_ok());
+        assert!(tbls.combine(&partials[1..]).is_err());
+
+        // imported key shares
+        let share = &sk_shares[0];
+        assert_eq!(&SignKeyShare::new(1, share.sk.clone()).unwrap(), share);
+        assert_eq!(
+            VerKeyShare::new(1, share.ver_key_share().ver_key()).unwrap(),
+            share.ver_key_share()
+        );
+        assert!(SignKeyShare::new(0, share.sk.clone()).is_err());
+        assert!(VerKeyShare::new(0, vk).is_err());
+    }
+
+    #[test]
+    fn test_serde() {
+        let mut rng = jf_utils::test_rng();
+        let tbls = ThresholdBLS::new(2, 3).unwrap();
+        let (_, vk_shares, sk_shares) = tbls.key_gen(&mut rng);
+        let partial = ThresholdBLS::partial_sign(&sk_shares[0], [1u8]);
+
+        let mut bytes = Vec::new();
+        sk_shares[0].serialize_compressed(&mut bytes).unwrap();
+        assert_eq!(
+            SignKeyShare::deserialize_compressed(&bytes[..]).unwrap(),
+            sk_shares[0]
+        );
+        let mut bytes = Vec::new();
+        vk_shares[0].serialize_compressed(&mut bytes).unwrap();
+        assert_eq!(
+            VerKeyShare::deserialize_compressed(&bytes[..]).unwrap(),
+            vk_shares[0]
+        );
+        let mut bytes = Vec::new();
+        partial.serialize_compressed(&mut bytes).unwrap();
+        assert_eq!(
+            PartialSignature::deserialize_compressed(&bytes[..]).unwrap(),
+            partial
+        );
+    }
+}
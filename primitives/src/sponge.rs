@@ -0,0 +1,181 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+//! A sponge over any permutation of a fixed-size state of field elements.
+//!
+//! [`PermutationSponge`] absorbs and squeezes `RATE` elements per call to the
+//! permutation, in the first elements of the state. It backs the sponges of
+//! [Poseidon2](crate::poseidon2), [Griffin](crate::griffin),
+//! [Monolith](crate::monolith) and [Tip5](crate::tip5), which only differ by
+//! their permutation and, for Tip5, by the way a chunk of input enters the
+//! state.
+
+use ark_crypto_primitives::sponge::{Absorb, CryptographicSponge, FieldBasedCryptographicSponge};
+use ark_ff::{BigInteger, PrimeField};
+use ark_std::{vec, vec::Vec};
+
+/// A permutation of a state of `STATE_SIZE` elements of `F`, usable in a
+/// [`PermutationSponge`].
+pub trait SpongePermutation<F: PrimeField, const STATE_SIZE: usize>: Clone {
+    /// Compute the permutation on `state` in place.
+    fn permute(&self, state: &mut [F; STATE_SIZE]);
+
+    /// Absorb `chunk`, which has at most the rate elements, into the first
+    /// elements of `state`. The default adds the chunk to the state.
+    fn absorb_chunk(state: &mut [F; STATE_SIZE], chunk: &[F]) {
+        state.iter_mut().zip(chunk).for_each(|(x, elem)| *x += elem);
+    }
+}
+
+/// A sponge over the permutation `P`, absorbing and squeezing `RATE` elements
+/// per permutation in the first elements of a state of `STATE_SIZE` elements.
+#[derive(Debug, Clone)]
+pub struct PermutationSponge<F, P, const STATE_SIZE: usize, const RATE: usize> {
+    state: [F; STATE_SIZE],
+    permutation: P,
+}
+
+impl<F, P, const STATE_SIZE: usize, const RATE: usize> PermutationSponge<F, P, STATE_SIZE, RATE>
+where
+    F: PrimeField,
+    P: SpongePermutation<F, STATE_SIZE>,
+{
+    /// The # of bits of a squeezed element used by
+    /// [`CryptographicSponge::squeeze_bits`], which are uniformly distributed
+    /// up to a negligible bias.
+    const USABLE_BITS: usize = F::MODULUS_BIT_SIZE as usize - 1;
+}
+
+impl<F, P, const STATE_SIZE: usize, const RATE: usize> CryptographicSponge
+    for PermutationSponge<F, P, STATE_SIZE, RATE>
+where
+    F: PrimeField,
+    P: SpongePermutation<F, STATE_SIZE>,
+{
+    /// Config used by the sponge.
+    type Config = P;
+
+    /// Initialize a new instance of the sponge, with the zero state.
+    fn new(permutation: &Self::Config) -> Self {
+        Self {
+            state: [F::zero(); STATE_SIZE],
+            permutation: permutation.clone(),
+        }
+    }
+
+    /// Absorb an input into the sponge.
+    /// This function will absorb the entire input, in chunks of `RATE`,
+    /// even if the input length is not a multiple of `RATE`.
+    fn absorb(&mut self, input: &impl Absorb) {
+        let input_field_elements: Vec<F> = input.to_sponge_field_elements_as_vec();
+        input_field_elements.chunks(RATE).for_each(|chunk| {
+            P::absorb_chunk(&mut self.state, chunk);
+            self.permutation.permute(&mut self.state);
+        });
+    }
+
+    /// Squeeze `num_bytes` bytes, taking the `USABLE_BITS / 8` low bytes of
+    /// each native field element squeezed.
+    fn squeeze_bytes(&mut self, num_bytes: usize) -> Vec<u8> {
+        let usable_bytes = Self::USABLE_BITS / 8;
+        let num_elements = (num_bytes + usable_bytes - 1) / usable_bytes;
+        let mut bytes: Vec<u8> = self
+            .squeeze_native_field_elements(num_elements)
+            .iter()
+            .flat_map(|elem| elem.into_bigint().to_bytes_le()[..usable_bytes].to_vec())
+            .collect();
+        bytes.truncate(num_bytes);
+        bytes
+    }
+
+    /// Squeeze `num_bits` bits, taking the `USABLE_BITS` low bits of each
+    /// native field element squeezed, in little-endian order.
+    fn squeeze_bits(&mut self, num_bits: usize) -> Vec<bool> {
+        let num_elements = (num_bits + Self::USABLE_BITS - 1) / Self::USABLE_BITS;
+        let mut bits: Vec<bool> = self
+            .squeeze_native_field_elements(num_elements)
+            .iter()
+            .flat_map(|elem| elem.into_bigint().to_bits_le()[..Self::USABLE_BITS].to_vec())
+            .collect();
+        bits.truncate(num_bits);
+        bits
+    }
+}
+
+impl<F, P, const STATE_SIZE: usize, const RATE: usize> FieldBasedCryptographicSponge<F>
+    for PermutationSponge<F, P, STATE_SIZE, RATE>
+where
+    F: PrimeField,
+    P: SpongePermutation<F, STATE_SIZE>,
+{
+    /// Squeeze `num_elements` field elements from the sponge.
+    fn squeeze_native_field_elements(&mut self, num_elements: usize) -> Vec<F> {
+        let mut result = vec![];
+        let mut remaining = num_elements;
+        // extract current rate before calling the permutation again
+        loop {
+            let extract = remaining.min(RATE);
+            result.extend_from_slice(&self.state[0..extract]);
+            remaining -= extract;
+            if remaining == 0 {
+                break;
+            }
+            self.permutation.permute(&mut self.state);
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::poseidon2::{Poseidon2Parameter, Poseidon2Sponge};
+    use ark_crypto_primitives::sponge::FieldElementSize;
+    use ark_ed_on_bn254::Fq;
+    use ark_ff::{One, Zero};
+
+    #[test]
+    fn test_squeeze_derived_from_native_elements() {
+        let mut sponge = Poseidon2Sponge::<Fq, 2>::new(Fq::permutation());
+        sponge.absorb(&vec![Fq::one(), Fq::zero()]);
+        let elems = sponge.clone().squeeze_native_field_elements(3);
+
+        let usable_bytes = (Fq::MODULUS_BIT_SIZE as usize - 1) / 8;
+        let bytes = sponge.clone().squeeze_bytes(2 * usable_bytes + 1);
+        assert_eq!(bytes.len(), 2 * usable_bytes + 1);
+        for (i, chunk) in bytes.chunks(usable_bytes).enumerate() {
+            let elem_bytes = elems[i].into_bigint().to_bytes_le();
+            assert_eq!(chunk, &elem_bytes[..chunk.len()]);
+        }
+
+        let usable_bits = Fq::MODULUS_BIT_SIZE as usize - 1;
+        let bits = sponge.clone().squeeze_bits(usable_bits + 3);
+        assert_eq!(bits.len(), usable_bits + 3);
+        assert_eq!(
+            bits[..usable_bits],
+            elems[0].into_bigint().to_bits_le()[..usable_bits]
+        );
+        assert_eq!(
+            bits[usable_bits..],
+            elems[1].into_bigint().to_bits_le()[..3]
+        );
+
+        // full size native elements are the native squeeze, other sizes are
+        // derived from the bits
+        assert_eq!(
+            sponge
+                .clone()
+                .squeeze_native_field_elements_with_sizes(&[FieldElementSize::Full; 3]),
+            elems
+        );
+        assert_eq!(sponge.clone().squeeze_field_elements::<Fq>(3).len(), 3);
+        let truncated: Vec<Fq> = sponge.squeeze_field_elements_with_sizes(&[
+            FieldElementSize::Truncated(8),
+            FieldElementSize::Truncated(8),
+        ]);
+        assert!(truncated.iter().all(|x| x.into_bigint() < 256u64.into()));
+    }
+}
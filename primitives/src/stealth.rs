@@ -0,0 +1,326 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+//! This module implements dual-key stealth addresses over the Edwards curves:
+//! a payer derives a fresh one-time address from the public stealth address
+//! of a recipient for every payment, so that payments to the same recipient
+//! cannot be linked.
+//!
+//! With `G` the generator of the curve, the recipient holds a scan key `s`
+//! and a spend key `b`, and publishes its stealth address `(S,B)=(s*G,b*G)`.
+//!
+//! **DeriveOneTime((S,B))**
+//!    * sample `r`, the one-time address is `(R,P)=(r*G,H(r*S)*G+B)`
+//!
+//! **Detect(s,B,(R,P))**
+//!    * the address belongs to the recipient if `P=H(s*R)*G+B`
+//!
+//! **OneTimeKey(s,b,(R,P))**
+//!    * the secret key of `P` is `H(s*R)+b`
+//!
+//! Detection only needs the [`ScanKey`] `(s,B)`, which can be delegated to a
+//! scanning service without the ability to spend. The shared points are
+//! multiplied by the cofactor of the curve, and `H` hashes them with the
+//! Rescue CRHF, the output being masked to a scalar as the Schnorr
+//! challenges. The key of a one-time address is a [Schnorr][schnorr]
+//! verification key, and [`OneTimeAddress::to_scalars`] gives the base
+//! field elements to commit to in a note, e.g. a leaf of a Rescue
+//! [Merkle tree][merkle].
+//!
+//! ```
+//! use ark_ed_on_bn254::EdwardsConfig;
+//! use jf_primitives::{constants::CS_ID_SCHNORR, stealth::StealthKeys};
+//!
+//! let mut rng = jf_utils::test_rng();
+//! let keys = StealthKeys::<EdwardsConfig>::generate(&mut rng);
+//! let output = keys.address().derive_one_time(&mut rng);
+//! assert!(keys.scan_key().detect(&output));
+//!
+//! let key_pair = keys.one_time_key_pair(&output).unwrap();
+//! let msg = [ark_ed_on_bn254::Fq::from(42u64)];
+//! let sig = key_pair.sign(&msg, CS_ID_SCHNORR);
+//! assert!(output.ver_key().verify(&msg, &sig, CS_ID_SCHNORR).is_ok());
+//! ```
+//!
+//! [schnorr]: crate::signatures::schnorr
+//! [merkle]: crate::merkle_tree
+
+use crate::{
+    constants::CS_ID_STEALTH_ADDRESS,
+    crhf::{VariableLengthRescueCRHF, CRHF},
+    rescue::RescueParameter,
+    signatures::schnorr,
+};
+use ark_ec::{
+    twisted_edwards::{Projective, TECurveConfig as Config},
+    AffineRepr, CurveGroup, Group,
+};
+use ark_ff::UniformRand;
+use ark_serialize::*;
+use ark_std::{
+    rand::{CryptoRng, RngCore},
+    vec,
+    vec::Vec,
+};
+use jf_utils::fq_to_fr_with_mask;
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+/// The secret keys of a recipient: the scan key and the spend key.
+#[derive(CanonicalSerialize, CanonicalDeserialize, Zeroize, Derivative)]
+#[derivative(
+    Debug(bound = "P: Config"),
+    Clone(bound = "P: Config"),
+    PartialEq(bound = "P: Config")
+)]
+pub struct StealthKeys<P: Config> {
+    scan: P::ScalarField,
+    spend: P::ScalarField,
+}
+
+impl<P: Config> Drop for StealthKeys<P> {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl<P: Config> ZeroizeOnDrop for StealthKeys<P> {}
+
+/// The key detecting the one-time addresses of a recipient, without the
+/// ability to spend from them.
+#[derive(CanonicalSerialize, CanonicalDeserialize, Derivative)]
+#[derivative(
+    Debug(bound = "P: Config"),
+    Clone(bound = "P: Config"),
+    PartialEq(bound = "P: Config")
+)]
+pub struct ScanKey<P: Config> {
+    scan: P::ScalarField,
+    spend_pk: Projective<P>,
+}
+
+impl<P: Config> Drop for ScanKey<P> {
+    fn drop(&mut self) {
+        self.scan.zeroize();
+    }
+}
+
+impl<P: Config> ZeroizeOnDrop for ScanKey<P> {}
+
+/// The public stealth address of a recipient `(S,B)`.
+#[derive(CanonicalSerialize, CanonicalDeserialize, Derivative)]
+#[derivative(
+    Debug(bound = "P: Config"),
+    Clone(bound = "P: Config"),
+    PartialEq(bound = "P: Config"),
+    Eq(bound = "P: Config"),
+    Hash(bound = "P: Config")
+)]
+pub struct StealthAddress<P: Config> {
+    scan_pk: Projective<P>,
+    spend_pk: Projective<P>,
+}
+
+/// A one-time address `(R,P)`, with the ephemeral public key `R` of the payer
+/// and the one-time public key `P`.
+#[derive(CanonicalSerialize, CanonicalDeserialize, Derivative)]
+#[derivative(
+    Debug(bound = "P: Config"),
+    Clone(bound = "P: Config"),
+    PartialEq(bound = "P: Config"),
+    Eq(bound = "P: Config"),
+    Hash(bound = "P: Config")
+)]
+pub struct OneTimeAddress<P: Config> {
+    ephemeral: Projective<P>,
+    key: Projective<P>,
+}
+
+impl<F, P> StealthKeys<P>
+where
+    F: RescueParameter,
+    P: Config<BaseField = F>,
+{
+    /// Sample the keys of a recipient.
+    pub fn generate<R: CryptoRng + RngCore>(rng: &mut R) -> Self {
+        Self {
+            scan: P::ScalarField::rand(rng),
+            spend: P::ScalarField::rand(rng),
+        }
+    }
+
+    /// The public stealth address.
+    pub fn address(&self) -> StealthAddress<P> {
+        let generator = Projective::<P>::generator();
+        StealthAddress {
+            scan_pk: generator * self.scan,
+            spend_pk: generator * self.spend,
+        }
+    }
+
+    /// The key detecting the one-time addresses of the recipient.
+    pub fn scan_key(&self) -> ScanKey<P> {
+        ScanKey {
+            scan: self.scan,
+            spend_pk: Projective::<P>::generator() * self.spend,
+        }
+    }
+
+    /// The Schnorr key pair of the one-time key of `output`.
+    /// * `returns` - None if `output` is not an address of the recipient
+    pub fn one_time_key_pair(&self, output: &OneTimeAddress<P>) -> Option<schnorr::KeyPair<P>> {
+        let mut sk = shared_scalar(&output.ephemeral, &self.scan) + self.spend;
+        let key_pair = if Projective::<P>::generator() * sk == output.key {
+            Some(schnorr::KeyPair::generate_with_sign_key(sk))
+        } else {
+            None
+        };
+        sk.zeroize();
+        key_pair
+    }
+}
+
+impl<F, P> ScanKey<P>
+where
+    F: RescueParameter,
+    P: Config<BaseField = F>,
+{
+    /// Whether `output` is an address of the recipient.
+    pub fn detect(&self, output: &OneTimeAddress<P>) -> bool {
+        Projective::<P>::generator() * shared_scalar(&output.ephemeral, &self.scan) + self.spend_pk
+            == output.key
+    }
+}
+
+impl<F, P> StealthAddress<P>
+where
+    F: RescueParameter,
+    P: Config<BaseField = F>,
+{
+    /// Derive a fresh one-time address of the recipient.
+    pub fn derive_one_time<R: CryptoRng + RngCore>(&self, rng: &mut R) -> OneTimeAddress<P> {
+        let mut r = P::ScalarField::rand(rng);
+        let ephemeral = Projective::<P>::generator() * r;
+        let key = Projective::<P>::generator() * shared_scalar(&self.scan_pk, &r) + self.spend_pk;
+        r.zeroize();
+        OneTimeAddress { ephemeral, key }
+    }
+}
+
+impl<P: Config> StealthAddress<P> {
+    /// The public scan key `S`.
+    pub fn scan_pk(&self) -> &Projective<P> {
+        &self.scan_pk
+    }
+
+    /// The public spend key `B`.
+    pub fn spend_pk(&self) -> &Projective<P> {
+        &self.spend_pk
+    }
+}
+
+impl<P: Config> OneTimeAddress<P> {
+    /// The ephemeral public key `R` of the payer.
+    pub fn ephemeral(&self) -> &Projective<P> {
+        &self.ephemeral
+    }
+
+    /// The one-time public key `P`, as a Schnorr verification key.
+    pub fn ver_key(&self) -> schnorr::VerKey<P> {
+        schnorr::VerKey(self.key)
+    }
+
+    /// The coordinates of `R` and `P`.
+    pub fn to_scalars(&self) -> Vec<P::BaseField> {
+        let points = Projective::normalize_batch(&[self.ephemeral, self.key]);
+        vec![points[0].x, points[0].y, points[1].x, points[1].y]
+    }
+}
+
+// `H(k*Q)`, with `Q` multiplied by the cofactor so that the shared point is in
+// the prime order subgroup whichever point the payer chose.
+fn shared_scalar<F, P>(point: &Projective<P>, scalar: &P::ScalarField) -> P::ScalarField
+where
+    F: RescueParameter,
+    P: Config<BaseField = F>,
+{
+    let shared = (point.into_affine().mul_by_cofactor_to_group() * scalar).into_affine();
+    let input = vec![
+        F::from_be_bytes_mod_order(CS_ID_STEALTH_ADDRESS.as_bytes()),
+        shared.x,
+        shared.y,
+    ];
+    // safe unwrap: the CRHF accepts any input length
+    fq_to_fr_with_mask(&VariableLengthRescueCRHF::<F, 1>::evaluate(input).unwrap()[0])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::CS_ID_SCHNORR;
+    use ark_ed_on_bls12_377::EdwardsConfig as Param377;
+    use ark_ed_on_bls12_381::EdwardsConfig as Param381;
+    use ark_ed_on_bn254::EdwardsConfig as Param254;
+
+    fn test_stealth_address_helper<F, P>()
+    where
+        F: RescueParameter,
+        P: Config<BaseField = F>,
+    {
+        let rng = &mut jf_utils::test_rng();
+        let keys = StealthKeys::<P>::generate(rng);
+        let address = keys.address();
+        let scan_key = keys.scan_key();
+
+        let output = address.derive_one_time(rng);
+        let other_output = address.derive_one_time(rng);
+        assert_ne!(output, other_output);
+        assert_ne!(output.ver_key(), other_output.ver_key());
+        for output in [&output, &other_output] {
+            assert!(scan_key.detect(output));
+            let key_pair = keys.one_time_key_pair(output).unwrap();
+            assert_eq!(key_pair.ver_key(), output.ver_key());
+            let msg = output.to_scalars();
+            let sig = key_pair.sign(&msg, CS_ID_SCHNORR);
+            assert!(output.ver_key().verify(&msg, &sig, CS_ID_SCHNORR).is_ok());
+        }
+
+        // addresses of another recipient
+        let other_keys = StealthKeys::<P>::generate(rng);
+        let foreign = other_keys.address().derive_one_time(rng);
+        assert!(!scan_key.detect(&foreign));
+        assert!(keys.one_time_key_pair(&foreign).is_none());
+        let mut tampered = output.clone();
+        tampered.ephemeral += Projective::<P>::generator();
+        assert!(!scan_key.detect(&tampered));
+        assert!(keys.one_time_key_pair(&tampered).is_none());
+
+        // serde
+        let mut bytes = Vec::new();
+        address.serialize_compressed(&mut bytes).unwrap();
+        assert_eq!(
+            StealthAddress::<P>::deserialize_compressed(&bytes[..]).unwrap(),
+            address
+        );
+        let mut bytes = Vec::new();
+        output.serialize_compressed(&mut bytes).unwrap();
+        assert_eq!(
+            OneTimeAddress::<P>::deserialize_compressed(&bytes[..]).unwrap(),
+            output
+        );
+        let mut bytes = Vec::new();
+        scan_key.serialize_compressed(&mut bytes).unwrap();
+        assert!(ScanKey::<P>::deserialize_compressed(&bytes[..])
+            .unwrap()
+            .detect(&output));
+    }
+
+    #[test]
+    fn test_stealth_address() {
+        test_stealth_address_helper::<_, Param254>();
+        test_stealth_address_helper::<_, Param377>();
+        test_stealth_address_helper::<_, Param381>();
+    }
+}
@@ -0,0 +1,216 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+//! Timelock encryption with the time-lock puzzles of [Rivest, Shamir and
+//! Wagner][rsw]: messages encrypted to the future, which anyone can decrypt
+//! after a given number of sequential squarings modulo an RSA modulus.
+//!
+//! The puzzle of a message is a random element `x` of the group of the
+//! [Wesolowski VDF](crate::vdf::wesolowski), and its solution is
+//! `y = x^(2^T)`. The message is encrypted with [`crate::aead`] under the key
+//! pair derived by [`crate::kdf`] from `y`. The sender knows the factors of
+//! the modulus, i.e. the order of the group, and computes `y` with a single
+//! short exponentiation, whereas the receiver performs the `T` squarings
+//! with [`solve`].
+//!
+//! The solution comes with a Wesolowski proof, so that the solver can publish
+//! it and anyone can then check it and decrypt in a few exponentiations with
+//! [`decrypt`].
+//!
+//! [rsw]: https://people.csail.mit.edu/rivest/pubs/RSW96.pdf
+
+use crate::{
+    aead,
+    constants::CS_ID_TIMELOCK,
+    errors::PrimitivesError::{self, ParameterError},
+    kdf::MasterKey,
+    vdf::{
+        wesolowski::{sample_prime, RsaGroupElement, Wesolowski, WesolowskiPP, MIN_MODULUS_BITS},
+        VDF,
+    },
+};
+use ark_serialize::*;
+use ark_std::{
+    format,
+    rand::{CryptoRng, RngCore},
+    vec::Vec,
+};
+use num_bigint::BigUint;
+
+/// The trapdoor of the puzzles of a public parameter, i.e. the order of the
+/// group, with which messages are encrypted. It must be kept secret, as it
+/// allows to solve the puzzles without delay.
+#[derive(Clone, Debug)]
+pub struct TimelockSecret {
+    pp: WesolowskiPP,
+    // `(p-1)(q-1)` for the factors `p` and `q` of the modulus
+    order: BigUint,
+}
+
+/// A message encrypted to the future, along with its puzzle.
+#[derive(Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct TimelockCiphertext {
+    puzzle: RsaGroupElement,
+    ciphertext: aead::Ciphertext,
+}
+
+/// The solution of the puzzle of a ciphertext, with its proof.
+#[derive(Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct TimelockSolution {
+    solution: RsaGroupElement,
+    proof: RsaGroupElement,
+}
+
+impl TimelockCiphertext {
+    /// The puzzle, to be solved to decrypt the ciphertext.
+    pub fn puzzle(&self) -> &RsaGroupElement {
+        &self.puzzle
+    }
+}
+
+impl TimelockSolution {
+    /// The solution `y = x^(2^T)` of the puzzle `x`.
+    pub fn solution(&self) -> &RsaGroupElement {
+        &self.solution
+    }
+}
+
+impl TimelockSecret {
+    /// Sample a modulus of `modulus_bits` bits and its trapdoor, for puzzles
+    /// of `difficulty` squarings.
+    /// * `returns` - Err() if the modulus has fewer than [`MIN_MODULUS_BITS`]
+    ///   bits
+    pub fn generate<R: CryptoRng + RngCore>(
+        difficulty: u64,
+        modulus_bits: u64,
+        rng: &mut R,
+    ) -> Result<Self, PrimitivesError> {
+        if modulus_bits < MIN_MODULUS_BITS {
+            return Err(ParameterError(format!(
+                "RSA modulus of {modulus_bits} bits, at least {MIN_MODULUS_BITS} required"
+            )));
+        }
+        let (p, q) = loop {
+            let p = sample_prime(modulus_bits / 2, rng);
+            let q = sample_prime(modulus_bits - modulus_bits / 2, rng);
+            if p != q {
+                break (p, q);
+            }
+        };
+        let pp = Wesolowski::setup_with_modulus(difficulty, &p * &q)?;
+        Ok(Self {
+            pp,
+            order: (p - 1u8) * (q - 1u8),
+        })
+    }
+
+    /// The public parameter of the puzzles, needed to solve them.
+    pub fn public_parameter(&self) -> &WesolowskiPP {
+        &self.pp
+    }
+
+    /// Encrypt `message` with the authenticated associated data `aad`, under
+    /// a fresh puzzle.
+    pub fn encrypt<R: CryptoRng + RngCore>(
+        &self,
+        rng: &mut R,
+        message: &[u8],
+        aad: &[u8],
+    ) -> Result<TimelockCiphertext, PrimitivesError> {
+        let mut seed = [0u8; 32];
+        rng.fill_bytes(&mut seed);
+        let puzzle = self.pp.hash_to_group(&seed);
+
+        // `x^(2^T) = x^(2^T mod (p-1)(q-1))` for `x` in `Z_N^*`
+        let exp = BigUint::from(2u8).modpow(&BigUint::from(self.pp.difficulty()), &self.order);
+        let solution = self
+            .pp
+            .normalize(puzzle.value().modpow(&exp, self.pp.modulus()));
+        let key_pair = derive_key_pair(&self.pp, &puzzle, &solution)?;
+        let ciphertext = key_pair.enc_key_ref().encrypt(rng, message, aad)?;
+        Ok(TimelockCiphertext { puzzle, ciphertext })
+    }
+}
+
+/// Solve the puzzle of `ciphertext`, with `T` sequential squarings. The
+/// solution can be published for [`decrypt`].
+/// * `returns` - Err() if the puzzle is not a valid group element
+pub fn solve(
+    pp: &WesolowskiPP,
+    ciphertext: &TimelockCiphertext,
+) -> Result<TimelockSolution, PrimitivesError> {
+    let (solution, proof) = Wesolowski::eval(pp, &ciphertext.puzzle)?;
+    Ok(TimelockSolution { solution, proof })
+}
+
+/// Decrypt `ciphertext` with the solution of its puzzle and the associated
+/// data used during encryption.
+/// * `returns` - Err() if the solution is invalid or the decryption fails
+pub fn decrypt(
+    pp: &WesolowskiPP,
+    ciphertext: &TimelockCiphertext,
+    solution: &TimelockSolution,
+    aad: &[u8],
+) -> Result<Vec<u8>, PrimitivesError> {
+    Wesolowski::verify(pp, &ciphertext.puzzle, &solution.solution, &solution.proof)?;
+    derive_key_pair(pp, &ciphertext.puzzle, solution.solution.value())?
+        .decrypt(&ciphertext.ciphertext, aad)
+}
+
+// The AEAD key pair of a puzzle, derived from its solution.
+fn derive_key_pair(
+    pp: &WesolowskiPP,
+    puzzle: &RsaGroupElement,
+    solution: &BigUint,
+) -> Result<aead::KeyPair, PrimitivesError> {
+    let master_key = MasterKey::new(&pp.encode(solution), Some(CS_ID_TIMELOCK.as_bytes()))?;
+    Ok(master_key.derive_aead_key_pair(&pp.encode(puzzle.value())))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_timelock() {
+        let rng = &mut jf_utils::test_rng();
+        let secret = TimelockSecret::generate(1000, MIN_MODULUS_BITS, rng).unwrap();
+        let pp = secret.public_parameter();
+        assert_eq!(pp.modulus().bits(), MIN_MODULUS_BITS);
+        assert!(TimelockSecret::generate(1000, MIN_MODULUS_BITS - 1, rng).is_err());
+
+        let message = b"revealed in the future";
+        let ciphertext = secret.encrypt(rng, message, b"aad").unwrap();
+        let solution = solve(pp, &ciphertext).unwrap();
+        assert_eq!(
+            decrypt(pp, &ciphertext, &solution, b"aad").unwrap(),
+            message.to_vec()
+        );
+        assert!(decrypt(pp, &ciphertext, &solution, b"other aad").is_err());
+
+        // the solution of another puzzle
+        let other_ciphertext = secret.encrypt(rng, message, b"aad").unwrap();
+        assert_ne!(other_ciphertext.puzzle(), ciphertext.puzzle());
+        let other_solution = solve(pp, &other_ciphertext).unwrap();
+        assert!(decrypt(pp, &ciphertext, &other_solution, b"aad").is_err());
+        // a solution with fewer squarings
+        let easier = Wesolowski::setup_with_modulus(999, pp.modulus().clone()).unwrap();
+        let easier_solution = solve(&easier, &ciphertext).unwrap();
+        assert!(decrypt(pp, &ciphertext, &easier_solution, b"aad").is_err());
+
+        // serde
+        let mut bytes = Vec::new();
+        ciphertext.serialize_compressed(&mut bytes).unwrap();
+        let ciphertext = TimelockCiphertext::deserialize_compressed(&bytes[..]).unwrap();
+        let mut bytes = Vec::new();
+        solution.serialize_compressed(&mut bytes).unwrap();
+        let solution = TimelockSolution::deserialize_compressed(&bytes[..]).unwrap();
+        assert_eq!(
+            decrypt(pp, &ciphertext, &solution, b"aad").unwrap(),
+            message.to_vec()
+        );
+    }
+}
@@ -0,0 +1,412 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+#![deny(missing_docs)]
+//! This module implements the Tip5 hash function over the
+//! [Goldilocks](crate::monolith::goldilocks::Goldilocks) field.
+//!
+//! The permutation follows the [Tip5 paper](https://eprint.iacr.org/2023/107.pdf)
+//! for a state of 16 elements, a rate of 10 and a digest of 5 elements. Each
+//! of its 5 rounds consists of
+//! - the S-box layer: the split-and-lookup map on the first 4 elements, which
+//!   decomposes the Montgomery representation `x * 2^64 mod p` of an element
+//!   into 8 bytes and maps each of them with the table `T(y) = (y + 1)^3 mod
+//!   257 - 1`, and the power map `x -> x^7` on the other 12 elements,
+//! - the multiplication by the circulant MDS matrix of first column
+//!   `MDS_FIRST_COLUMN`,
+//! - the addition of the round constants.
+//!
+//! `T` fixes `0` and `255`, so split-and-lookup maps canonical
+//! representations to canonical representations. As for
+//! [Monolith](crate::monolith), the lookup table makes Tip5 efficient for
+//! proof systems with lookup arguments over 64-bit fields.
+//!
+//! The round constants are derived as in [twenty-first](https://github.com/Neptune-Crypto/twenty-first),
+//! the reference implementation of Triton VM: the first 16 bytes of the
+//! BLAKE3 hash of `"Tip5"` followed by the byte `16 * i + j` are read as a
+//! little-endian integer `v`, and the `16 * i + j`-th constant is `v * 2^-64
+//! mod p`, since twenty-first reduces `v` as a Montgomery representation.
+//! They are generated once on first use.
+//!
+//! The sponge overwrites the rate with the input instead of adding to it.
+//! Variable-length hashes start from the zero state, while
+//! [`Tip5CRHF::hash_pair`], which compresses two digests, sets the capacity to
+//! ones, so that both domains are separated.
+
+#![deny(warnings)]
+
+use crate::{
+    errors::PrimitivesError,
+    monolith::goldilocks::Goldilocks,
+    sponge::{PermutationSponge, SpongePermutation},
+};
+use ark_crypto_primitives::sponge::{CryptographicSponge, FieldBasedCryptographicSponge};
+use ark_ff::{BigInt, Field, One, PrimeField, Zero};
+use ark_std::{boxed::Box, format, vec::Vec};
+use jf_utils::pad_with_zeros;
+use once_cell::race::OnceBox;
+
+/// The state size of tip5 hash.
+pub const STATE_SIZE: usize = 16;
+/// The rate of the sponge used in Tip5CRHF.
+pub const CRHF_RATE: usize = 10;
+/// The # of elements of a tip5 digest.
+pub const DIGEST_LEN: usize = 5;
+/// The # of rounds of tip5 hash.
+pub const ROUNDS: usize = 5;
+/// The # of elements going through split-and-lookup.
+const NUM_SPLIT_AND_LOOKUP: usize = 4;
+/// First column of the circulant MDS matrix.
+const MDS_FIRST_COLUMN: [u64; STATE_SIZE] = [
+    61402, 1108, 28750, 33823, 7454, 43244, 53865, 12034, 56951, 27521, 41351, 40901, 12021, 59689,
+    26798, 17845,
+];
+/// The byte lookup table `T(y) = (y + 1)^3 mod 257 - 1`.
+const LOOKUP_TABLE: [u8; 256] = lookup_table();
+
+const fn lookup_table() -> [u8; 256] {
+    let mut table = [0u8; 256];
+    let mut y = 0;
+    while y < 256 {
+        table[y] = ((y + 1) * (y + 1) % 257 * (y + 1) % 257 - 1) as u8;
+        y += 1;
+    }
+    table
+}
+
+/// The Tip5 permutation over a state of `STATE_SIZE` Goldilocks elements, see
+/// the [module documentation](self).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Tip5Permutation {
+    /// Round constants of every round, in order
+    round_constants: Vec<[Goldilocks; STATE_SIZE]>,
+}
+
+impl Default for Tip5Permutation {
+    fn default() -> Self {
+        Self::get().clone()
+    }
+}
+
+impl Tip5Permutation {
+    /// The tip5 permutation, generated on first use.
+    pub fn get() -> &'static Self {
+        static PERMUTATION: OnceBox<Tip5Permutation> = OnceBox::new();
+        PERMUTATION.get_or_init(|| Box::new(Self::derive()))
+    }
+
+    /// Derive the round constants of the permutation.
+    fn derive() -> Self {
+        let round_constants = (0..ROUNDS)
+            .map(|round| {
+                let mut constants = [Goldilocks::zero(); STATE_SIZE];
+                for (i, constant) in constants.iter_mut().enumerate() {
+                    let hash =
+                        blake3::hash(&[b'T', b'i', b'p', b'5', (round * STATE_SIZE + i) as u8]);
+                    // v mod p is the Montgomery representation of v * 2^-64
+                    let v = Goldilocks::from_le_bytes_mod_order(&hash.as_bytes()[..16]);
+                    *constant = Goldilocks::new_unchecked(v.into_bigint());
+                }
+                constants
+            })
+            .collect();
+        Self { round_constants }
+    }
+
+    /// Return the round constants, which are added at the end of every round.
+    pub fn round_constants(&self) -> &[[Goldilocks; STATE_SIZE]] {
+        &self.round_constants
+    }
+
+    /// Compute the permutation on `input`.
+    pub fn eval(&self, input: &[Goldilocks; STATE_SIZE]) -> [Goldilocks; STATE_SIZE] {
+        let mut state = *input;
+        self.permute_in_place(&mut state);
+        state
+    }
+
+    /// Compute the permutation on `state` in place.
+    pub fn permute_in_place(&self, state: &mut [Goldilocks; STATE_SIZE]) {
+        for constants in self.round_constants.iter() {
+            s_box_layer(state);
+            mds(state);
+            state.iter_mut().zip(constants).for_each(|(x, c)| *x += c);
+        }
+    }
+}
+
+/// Map each byte of the Montgomery representation of `x` through
+/// `LOOKUP_TABLE`.
+fn split_and_lookup(x: &mut Goldilocks) {
+    let bytes = x.0 .0[0]
+        .to_le_bytes()
+        .map(|byte| LOOKUP_TABLE[byte as usize]);
+    *x = Goldilocks::new_unchecked(BigInt([u64::from_le_bytes(bytes)]));
+}
+
+/// Apply split-and-lookup to the first `NUM_SPLIT_AND_LOOKUP` elements and
+/// `x -> x^7` to the others.
+fn s_box_layer(state: &mut [Goldilocks; STATE_SIZE]) {
+    let (lookups, powers) = state.split_at_mut(NUM_SPLIT_AND_LOOKUP);
+    lookups.iter_mut().for_each(split_and_lookup);
+    powers.iter_mut().for_each(|x| *x = x.pow([7]));
+}
+
+/// Multiply `state` by the circulant matrix of first column
+/// `MDS_FIRST_COLUMN`.
+fn mds(state: &mut [Goldilocks; STATE_SIZE]) {
+    let input = *state;
+    for (row, x) in state.iter_mut().enumerate() {
+        *x = input
+            .iter()
+            .enumerate()
+            .map(|(col, y)| {
+                Goldilocks::from(MDS_FIRST_COLUMN[(STATE_SIZE + row - col) % STATE_SIZE]) * y
+            })
+            .sum();
+    }
+}
+
+impl SpongePermutation<Goldilocks, STATE_SIZE> for Tip5Permutation {
+    fn permute(&self, state: &mut [Goldilocks; STATE_SIZE]) {
+        self.permute_in_place(state);
+    }
+
+    /// A chunk of input overwrites the first elements of the state, so a
+    /// shorter last chunk leaves the rest of the rate unchanged.
+    fn absorb_chunk(state: &mut [Goldilocks; STATE_SIZE], chunk: &[Goldilocks]) {
+        state[..chunk.len()].copy_from_slice(chunk);
+    }
+}
+
+/// A sponge over the tip5 permutation, overwriting the first `RATE` elements
+/// of the state with each chunk of input before a permutation.
+pub type Tip5Sponge<const RATE: usize> =
+    PermutationSponge<Goldilocks, Tip5Permutation, STATE_SIZE, RATE>;
+
+/// CRHF
+#[derive(Debug, Clone)]
+pub struct Tip5CRHF {
+    sponge: Tip5Sponge<CRHF_RATE>,
+}
+
+impl Tip5CRHF {
+    /// Sponge hashing based on tip5 permutation for RATE 10. It allows
+    /// unrestricted variable length input and returns a vector of
+    /// `num_outputs` elements, with "bit padding": "1" is always appended,
+    /// then as many "0" as required for the overall length to be a multiple
+    /// of RATE.
+    pub fn sponge_with_bit_padding(input: &[Goldilocks], num_outputs: usize) -> Vec<Goldilocks> {
+        let mut padded = input.to_vec();
+        padded.push(Goldilocks::one());
+        pad_with_zeros(&mut padded, CRHF_RATE);
+        Self::sponge_no_padding(padded.as_slice(), num_outputs)
+            .expect("Bug in JF Primitives : bad padding of input for FSKS construction")
+    }
+
+    /// Sponge hashing based on tip5 permutation for RATE 10 and CAPACITY 6.
+    /// It allows inputs with length that is a multiple of `CRHF_RATE` and
+    /// returns a vector of `num_outputs` elements.
+    pub fn sponge_no_padding(
+        input: &[Goldilocks],
+        num_output: usize,
+    ) -> Result<Vec<Goldilocks>, PrimitivesError> {
+        if input.len() % CRHF_RATE != 0 {
+            return Err(PrimitivesError::ParameterError(format!(
+                "Tip5 sponge Error : input length {} to sponge hashing function is not multiple \
+                 of RATE.",
+                input.len()
+            )));
+        }
+        let mut crhf = Self {
+            sponge: Tip5Sponge::new(Tip5Permutation::get()),
+        };
+        // ABSORB PHASE
+        crhf.sponge.absorb(&input);
+        // SQUEEZE PHASE
+        Ok(crhf.sponge.squeeze_native_field_elements(num_output))
+    }
+
+    /// The `DIGEST_LEN` elements digest of `input`, see
+    /// [`Self::sponge_with_bit_padding`].
+    pub fn hash_varlen(input: &[Goldilocks]) -> [Goldilocks; DIGEST_LEN] {
+        let mut digest = [Goldilocks::zero(); DIGEST_LEN];
+        digest.copy_from_slice(&Self::sponge_with_bit_padding(input, DIGEST_LEN));
+        digest
+    }
+
+    /// Compress two digests with a single permutation, in the fixed-length
+    /// domain where the capacity is initialized to ones.
+    pub fn hash_pair(
+        left: &[Goldilocks; DIGEST_LEN],
+        right: &[Goldilocks; DIGEST_LEN],
+    ) -> [Goldilocks; DIGEST_LEN] {
+        let mut state = [Goldilocks::one(); STATE_SIZE];
+        state[..DIGEST_LEN].copy_from_slice(left);
+        state[DIGEST_LEN..CRHF_RATE].copy_from_slice(right);
+        Tip5Permutation::get().permute_in_place(&mut state);
+        let mut digest = [Goldilocks::zero(); DIGEST_LEN];
+        digest.copy_from_slice(&state[..DIGEST_LEN]);
+        digest
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use ark_std::UniformRand;
+
+    #[test]
+    fn test_tip5_permutation() {
+        let rng = &mut jf_utils::test_rng();
+        let permutation = Tip5Permutation::get();
+        assert_eq!(permutation.round_constants().len(), ROUNDS);
+        // permutation is cached
+        assert!(core::ptr::eq(permutation, Tip5Permutation::get()));
+        assert_eq!(&Tip5Permutation::default(), permutation);
+
+        // The lookup table is a permutation of the bytes which fixes 0 and 255,
+        // and split-and-lookup maps the largest field elements to field
+        // elements
+        let mut images = LOOKUP_TABLE.to_vec();
+        images.sort_unstable();
+        assert_eq!(images, (0..=255).collect::<Vec<u8>>());
+        assert_eq!(LOOKUP_TABLE[..8], [0, 7, 26, 63, 124, 215, 85, 254]);
+        assert_eq!(LOOKUP_TABLE[255], 255);
+        for value in Goldilocks::MODULUS.0[0] - 5..Goldilocks::MODULUS.0[0] {
+            let mut x = Goldilocks::new_unchecked(BigInt([value]));
+            split_and_lookup(&mut x);
+            assert!(x.0 .0[0] < Goldilocks::MODULUS.0[0]);
+        }
+
+        let state = [(); STATE_SIZE].map(|_| Goldilocks::rand(rng));
+        let output = permutation.eval(&state);
+        assert_ne!(output, state);
+        let mut other = state;
+        other[STATE_SIZE - 1] += Goldilocks::one();
+        let other_output = permutation.eval(&other);
+        assert!(output.iter().zip(other_output).all(|(x, y)| *x != y));
+    }
+
+    // The round constants are checked against those of twenty-first, and the
+    // vectors are computed from them with its permutation and its
+    // variable-length and fixed-length domains.
+    #[test]
+    fn test_tip5_vectors() {
+        let permutation = Tip5Permutation::get();
+        assert_eq!(
+            permutation.round_constants()[0][..3],
+            [
+                13630775303355457758u64,
+                16896927574093233874,
+                10379449653650130495
+            ]
+            .map(Goldilocks::from)
+        );
+        assert_eq!(
+            permutation.round_constants()[ROUNDS - 1][STATE_SIZE - 1],
+            Goldilocks::from(6024642864597845108u64)
+        );
+
+        let input = [0u64, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15].map(Goldilocks::from);
+        let expected = [
+            14273019456630489802u64,
+            12225354657803044645,
+            18223679466392555512,
+            4879234115918641111,
+            198243361942729835,
+            6697571774370475124,
+            3935892719377798608,
+            2781322532457452310,
+            7475933807446249354,
+            7334965145562953054,
+            1275437117587945070,
+            2445375571864276273,
+            17005006372293520413,
+            9537835648539327419,
+            12703602725074524970,
+            5428520427373770602,
+        ]
+        .map(Goldilocks::from);
+        assert_eq!(permutation.eval(&input), expected);
+
+        let input: Vec<Goldilocks> = (1..=12u64).map(Goldilocks::from).collect();
+        let expected = [
+            3293182670601345530u64,
+            11826397834005555247,
+            15567595121000154017,
+            3255006421256488012,
+            16284278290683412169,
+        ]
+        .map(Goldilocks::from);
+        assert_eq!(Tip5CRHF::hash_varlen(&input), expected);
+        let expected = [
+            2335476311349343808u64,
+            1307299401243390569,
+            3414029282375928929,
+            2141465175172981451,
+            5966553798353564426,
+        ]
+        .map(Goldilocks::from);
+        assert_eq!(Tip5CRHF::hash_varlen(&[]), expected);
+
+        let left = [1u64, 2, 3, 4, 5].map(Goldilocks::from);
+        let right = [6u64, 7, 8, 9, 10].map(Goldilocks::from);
+        let expected = [
+            10818500669765797222u64,
+            7750847691288459381,
+            17271032843874487437,
+            1108553480921430050,
+            6029014391627118288,
+        ]
+        .map(Goldilocks::from);
+        assert_eq!(Tip5CRHF::hash_pair(&left, &right), expected);
+    }
+
+    #[test]
+    fn test_tip5_crhf() {
+        let rng = &mut jf_utils::test_rng();
+        let input: Vec<Goldilocks> = (0..2 * CRHF_RATE).map(|_| Goldilocks::rand(rng)).collect();
+        let output = Tip5CRHF::sponge_no_padding(&input, 12).unwrap();
+        assert_eq!(output.len(), 12);
+        assert_eq!(output[..4], Tip5CRHF::sponge_no_padding(&input, 4).unwrap());
+
+        // The sponge overwrites the rate and squeezes from it
+        let permutation = Tip5Permutation::get();
+        let mut state = [Goldilocks::zero(); STATE_SIZE];
+        for chunk in input.chunks(CRHF_RATE) {
+            state[..CRHF_RATE].copy_from_slice(chunk);
+            permutation.permute_in_place(&mut state);
+        }
+        assert_eq!(output[..CRHF_RATE], state[..CRHF_RATE]);
+        assert_eq!(output[CRHF_RATE..], permutation.eval(&state)[..2]);
+
+        assert!(Tip5CRHF::sponge_no_padding(&input[..CRHF_RATE + 1], 1).is_err());
+        let mut padded = input[..CRHF_RATE].to_vec();
+        padded.push(Goldilocks::one());
+        padded.resize(2 * CRHF_RATE, Goldilocks::zero());
+        assert_eq!(
+            Tip5CRHF::sponge_with_bit_padding(&input[..CRHF_RATE], 1),
+            Tip5CRHF::sponge_no_padding(&padded, 1).unwrap()
+        );
+        assert_eq!(
+            Tip5CRHF::hash_varlen(&input)[..],
+            Tip5CRHF::sponge_with_bit_padding(&input, DIGEST_LEN)[..]
+        );
+
+        // Compressing a pair differs from hashing its concatenation
+        let left = Tip5CRHF::hash_varlen(&input[..1]);
+        let right = Tip5CRHF::hash_varlen(&input[1..2]);
+        assert_ne!(
+            Tip5CRHF::hash_pair(&left, &right)[..],
+            Tip5CRHF::sponge_no_padding(&[left, right].concat(), DIGEST_LEN).unwrap()[..]
+        );
+        assert_ne!(
+            Tip5CRHF::hash_pair(&left, &right),
+            Tip5CRHF::hash_pair(&right, &left)
+        );
+    }
+}
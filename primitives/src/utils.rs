@@ -6,7 +6,7 @@
 
 use crate::{elgamal, signatures::schnorr};
 use ark_ec::{twisted_edwards::TECurveConfig as Config, CurveGroup};
-use ark_ff::PrimeField;
+use ark_ff::{BigInt, BigInteger, PrimeField};
 use ark_std::vec::Vec;
 use jf_relation::Variable;
 
@@ -63,3 +63,19 @@ pub(crate) fn challenge_bit_len<F: PrimeField>() -> usize {
 pub(crate) fn curve_cofactor<P: Config>() -> u64 {
     P::COFACTOR[0]
 }
+
+/// Parse a 32-byte big-endian integer, which must be less than the modulus.
+pub(crate) fn int_from_bytes<F: PrimeField<BigInt = BigInt<4>>>(bytes: &[u8; 32]) -> Option<F> {
+    let mut limbs = [0u64; 4];
+    for (limb, chunk) in limbs.iter_mut().rev().zip(bytes.chunks(8)) {
+        *limb = u64::from_be_bytes(chunk.try_into().unwrap()); // safe unwrap
+    }
+    F::from_bigint(BigInt(limbs))
+}
+
+/// The 32-byte big-endian encoding of a field element.
+pub(crate) fn bytes_from_int<F: PrimeField<BigInt = BigInt<4>>>(f: &F) -> [u8; 32] {
+    let mut bytes = [0u8; 32];
+    bytes.copy_from_slice(&f.into_bigint().to_bytes_be());
+    bytes
+}
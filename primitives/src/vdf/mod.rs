@@ -13,6 +13,7 @@ use ark_std::{
 };
 
 pub mod minroot;
+pub mod wesolowski;
 
 /// A trait for VDF proof, evaluation and verification.
 pub trait VDF {
@@ -0,0 +1,465 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+//! Instantiation of the VDF of Wesolowski <https://eprint.iacr.org/2018/623.pdf>
+//! over an RSA group.
+//!
+//! The output for the input `x` and difficulty `T` is `y = x^(2^T)`, computed
+//! by `T` sequential squarings in the group `Z_N^*/{1,-1}`, whose elements
+//! are represented by the smaller of `x` and `N-x`. The proof is
+//! `pi = x^floor(2^T/l)` for a 256-bit prime `l` derived by hashing `x` and
+//! `y`, and is verified with `pi^l * x^(2^T mod l) = y`, i.e. two short
+//! exponentiations.
+//!
+//! The delay relies on the order of the group being unknown. Without a
+//! random number generator, [`Wesolowski::setup`] uses the modulus of the
+//! RSA-2048 factoring challenge, which nobody is known to be able to factor.
+//! With one, it samples a fresh modulus whose factors are known during the
+//! setup: they must be discarded, as anyone knowing them can evaluate the
+//! function fast.
+//!
+//! Evaluation takes `2T` multiplications modulo `N`: the `T` squarings and as
+//! many for the proof.
+
+use crate::{
+    constants::CS_ID_WESOLOWSKI_VDF,
+    errors::PrimitivesError::{self, ParameterError, VerificationError},
+    vdf::VDF,
+};
+use ark_serialize::*;
+use ark_std::{
+    format,
+    rand::{CryptoRng, RngCore},
+    string::ToString,
+    vec,
+    vec::Vec,
+};
+use digest::Digest;
+use num_bigint::BigUint;
+use num_traits::{One, Zero};
+use sha2::Sha256;
+
+/// Size in bits of the moduli sampled by [`Wesolowski::setup`].
+pub const MODULUS_BITS: u64 = 2048;
+
+/// Minimal size in bits of the moduli accepted by
+/// [`Wesolowski::setup_with_modulus`].
+pub const MIN_MODULUS_BITS: u64 = 512;
+
+// size in bytes of the challenge primes
+const CHALLENGE_BYTES: usize = 32;
+
+// the modulus of the RSA-2048 factoring challenge, big-endian
+const RSA_2048: [u8; 256] = [
+    0xc7, 0x97, 0x0c, 0xee, 0xdc, 0xc3, 0xb0, 0x75, 0x44, 0x90, 0x20, 0x1a, 0x7a, 0xa6, 0x13, 0xcd,
+    0x73, 0x91, 0x10, 0x81, 0xc7, 0x90, 0xf5, 0xf1, 0xa8, 0x72, 0x6f, 0x46, 0x35, 0x50, 0xbb, 0x5b,
+    0x7f, 0xf0, 0xdb, 0x8e, 0x1e, 0xa1, 0x18, 0x9e, 0xc7, 0x2f, 0x93, 0xd1, 0x65, 0x00, 0x11, 0xbd,
+    0x72, 0x1a, 0xee, 0xac, 0xc2, 0xac, 0xde, 0x32, 0xa0, 0x41, 0x07, 0xf0, 0x64, 0x8c, 0x28, 0x13,
+    0xa3, 0x1f, 0x5b, 0x0b, 0x77, 0x65, 0xff, 0x8b, 0x44, 0xb4, 0xb6, 0xff, 0xc9, 0x33, 0x84, 0xb6,
+    0x46, 0xeb, 0x09, 0xc7, 0xcf, 0x5e, 0x85, 0x92, 0xd4, 0x0e, 0xa3, 0x3c, 0x80, 0x03, 0x9f, 0x35,
+    0xb4, 0xf1, 0x4a, 0x04, 0xb5, 0x1f, 0x7b, 0xfd, 0x78, 0x1b, 0xe4, 0xd1, 0x67, 0x31, 0x64, 0xba,
+    0x8e, 0xb9, 0x91, 0xc2, 0xc4, 0xd7, 0x30, 0xbb, 0xbe, 0x35, 0xf5, 0x92, 0xbd, 0xef, 0x52, 0x4a,
+    0xf7, 0xe8, 0xda, 0xef, 0xd2, 0x6c, 0x66, 0xfc, 0x02, 0xc4, 0x79, 0xaf, 0x89, 0xd6, 0x4d, 0x37,
+    0x3f, 0x44, 0x27, 0x09, 0x43, 0x9d, 0xe6, 0x6c, 0xeb, 0x95, 0x5f, 0x3e, 0xa3, 0x7d, 0x51, 0x59,
+    0xf6, 0x13, 0x58, 0x09, 0xf8, 0x53, 0x34, 0xb5, 0xcb, 0x18, 0x13, 0xad, 0xdc, 0x80, 0xcd, 0x05,
+    0x60, 0x9f, 0x10, 0xac, 0x6a, 0x95, 0xad, 0x65, 0x87, 0x2c, 0x90, 0x95, 0x25, 0xbd, 0xad, 0x32,
+    0xbc, 0x72, 0x95, 0x92, 0x64, 0x29, 0x20, 0xf2, 0x4c, 0x61, 0xdc, 0x5b, 0x3c, 0x3b, 0x79, 0x23,
+    0xe5, 0x6b, 0x16, 0xa4, 0xd9, 0xd3, 0x73, 0xd8, 0x72, 0x1f, 0x24, 0xa3, 0xfc, 0x0f, 0x1b, 0x31,
+    0x31, 0xf5, 0x56, 0x15, 0x17, 0x28, 0x66, 0xbc, 0xcc, 0x30, 0xf9, 0x50, 0x54, 0xc8, 0x24, 0xe7,
+    0x33, 0xa5, 0xeb, 0x68, 0x17, 0xf7, 0xbc, 0x16, 0x39, 0x9d, 0x48, 0xc6, 0x36, 0x1c, 0xc7, 0xe5,
+];
+
+const SMALL_PRIMES: [u32; 54] = [
+    2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59, 61, 67, 71, 73, 79, 83, 89, 97,
+    101, 103, 107, 109, 113, 127, 131, 137, 139, 149, 151, 157, 163, 167, 173, 179, 181, 191, 193,
+    197, 199, 211, 223, 227, 229, 233, 239, 241, 251,
+];
+
+/// Public parameter of the Wesolowski VDF, i.e. the RSA modulus and the
+/// number of squarings.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WesolowskiPP {
+    modulus: BigUint,
+    difficulty: u64,
+}
+
+impl WesolowskiPP {
+    /// The RSA modulus `N`.
+    pub fn modulus(&self) -> &BigUint {
+        &self.modulus
+    }
+
+    /// The number of sequential squarings.
+    pub fn difficulty(&self) -> u64 {
+        self.difficulty
+    }
+
+    /// Map `seed` to a group element, e.g. the randomness of a beacon to the
+    /// input of its next round.
+    pub fn hash_to_group(&self, seed: &[u8]) -> RsaGroupElement {
+        // 128 more bits than the modulus, so that the reduction is close to
+        // uniform
+        let num_blocks = (self.modulus.bits() as usize + 128 + 255) / 256;
+        let mut bytes = Vec::with_capacity(num_blocks * 32);
+        for block in 0..num_blocks as u32 {
+            bytes.extend_from_slice(
+                Sha256::new()
+                    .chain_update(CS_ID_WESOLOWSKI_VDF.as_bytes())
+                    .chain_update(b"hash_to_group")
+                    .chain_update(block.to_be_bytes())
+                    .chain_update(seed)
+                    .finalize()
+                    .as_ref(),
+            );
+        }
+        RsaGroupElement(self.normalize(BigUint::from_bytes_be(&bytes)))
+    }
+
+    // The representative of `x` in `Z_N^*/{1,-1}`.
+    pub(crate) fn normalize(&self, x: BigUint) -> BigUint {
+        let x = x % &self.modulus;
+        if &x << 1 > self.modulus {
+            &self.modulus - x
+        } else {
+            x
+        }
+    }
+
+    // Check that `elem` is a normalized non-zero representative.
+    fn check_element(&self, elem: &RsaGroupElement) -> Result<(), PrimitivesError> {
+        if elem.0.is_zero() || &elem.0 << 1 > self.modulus {
+            return Err(ParameterError(
+                "not a normalized element of the group".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    // The big-endian encoding of `x`, padded to the size of the modulus.
+    pub(crate) fn encode(&self, x: &BigUint) -> Vec<u8> {
+        let len = (self.modulus.bits() as usize + 7) / 8;
+        let bytes = x.to_bytes_be();
+        let mut encoding = vec![0u8; len - bytes.len()];
+        encoding.extend_from_slice(&bytes);
+        encoding
+    }
+
+    // The challenge prime `l` of the proof that `output` is the output of
+    // `input`.
+    fn hash_to_prime(&self, input: &RsaGroupElement, output: &RsaGroupElement) -> BigUint {
+        let mut counter = 0u64;
+        loop {
+            let hash = Sha256::new()
+                .chain_update(CS_ID_WESOLOWSKI_VDF.as_bytes())
+                .chain_update(b"hash_to_prime")
+                .chain_update(self.encode(&self.modulus))
+                .chain_update(self.difficulty.to_be_bytes())
+                .chain_update(self.encode(&input.0))
+                .chain_update(self.encode(&output.0))
+                .chain_update(counter.to_be_bytes())
+                .finalize();
+            let mut candidate = [0u8; CHALLENGE_BYTES];
+            candidate.copy_from_slice(hash.as_ref());
+            candidate[0] |= 0x80;
+            candidate[CHALLENGE_BYTES - 1] |= 1;
+            let candidate = BigUint::from_bytes_be(&candidate);
+            if is_probable_prime(&candidate) {
+                return candidate;
+            }
+            counter += 1;
+        }
+    }
+}
+
+/// An element of the group `Z_N^*/{1,-1}`: the input, the output and the
+/// proof of the VDF.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct RsaGroupElement(BigUint);
+
+impl RsaGroupElement {
+    /// The representative of the element, the smaller of `x` and `N-x`.
+    pub fn value(&self) -> &BigUint {
+        &self.0
+    }
+}
+
+impl CanonicalSerialize for RsaGroupElement {
+    fn serialize_with_mode<W: Write>(
+        &self,
+        writer: W,
+        compress: Compress,
+    ) -> Result<(), SerializationError> {
+        self.0.to_bytes_be().serialize_with_mode(writer, compress)
+    }
+
+    fn serialized_size(&self, compress: Compress) -> usize {
+        self.0.to_bytes_be().serialized_size(compress)
+    }
+}
+
+impl CanonicalDeserialize for RsaGroupElement {
+    fn deserialize_with_mode<R: Read>(
+        reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        let bytes = Vec::<u8>::deserialize_with_mode(reader, compress, validate)?;
+        // only the minimal encoding is accepted, zero being invalid anyway
+        if bytes.first().map_or(true, |byte| *byte == 0) {
+            return Err(SerializationError::InvalidData);
+        }
+        Ok(Self(BigUint::from_bytes_be(&bytes)))
+    }
+}
+
+impl Valid for RsaGroupElement {
+    fn check(&self) -> Result<(), SerializationError> {
+        Ok(())
+    }
+}
+
+/// Dummy struct for the Wesolowski VDF.
+pub struct Wesolowski;
+
+impl Wesolowski {
+    /// Generates a public parameter with a given RSA modulus, e.g. the output
+    /// of a multi-party computation, so that nobody knows its factors.
+    /// * `returns` - Err() if the modulus is even or has fewer than
+    ///   [`MIN_MODULUS_BITS`] bits
+    pub fn setup_with_modulus(
+        difficulty: u64,
+        modulus: BigUint,
+    ) -> Result<WesolowskiPP, PrimitivesError> {
+        if modulus.bits() < MIN_MODULUS_BITS || !modulus.bit(0) {
+            return Err(ParameterError(format!(
+                "invalid RSA modulus of {} bits",
+                modulus.bits()
+            )));
+        }
+        Ok(WesolowskiPP {
+            modulus,
+            difficulty,
+        })
+    }
+}
+
+impl VDF for Wesolowski {
+    type PublicParameter = WesolowskiPP;
+    type Proof = RsaGroupElement;
+    type Input = RsaGroupElement;
+    type Output = RsaGroupElement;
+
+    fn setup<R: CryptoRng + RngCore>(
+        difficulty: u64,
+        prng: Option<&mut R>,
+    ) -> Result<Self::PublicParameter, PrimitivesError> {
+        let modulus = match prng {
+            Some(prng) => sample_modulus(MODULUS_BITS, prng),
+            None => BigUint::from_bytes_be(&RSA_2048),
+        };
+        Self::setup_with_modulus(difficulty, modulus)
+    }
+
+    fn eval(
+        pp: &Self::PublicParameter,
+        input: &Self::Input,
+    ) -> Result<(Self::Output, Self::Proof), PrimitivesError> {
+        pp.check_element(input)?;
+        let n = &pp.modulus;
+        let x = &input.0;
+        let mut y = x.clone();
+        for _ in 0..pp.difficulty {
+            y = &y * &y % n;
+        }
+        let output = RsaGroupElement(pp.normalize(y));
+
+        // `pi = x^floor(2^T/l)`, with the quotient computed bit by bit by long
+        // division of `2^T` by `l`, `r` being the remainder
+        let l = pp.hash_to_prime(input, &output);
+        let mut pi = BigUint::one();
+        let mut r = BigUint::one();
+        for _ in 0..pp.difficulty {
+            pi = &pi * &pi % n;
+            r <<= 1;
+            if r >= l {
+                r -= &l;
+                pi = pi * x % n;
+            }
+        }
+        Ok((output, RsaGroupElement(pp.normalize(pi))))
+    }
+
+    fn verify(
+        pp: &Self::PublicParameter,
+        input: &Self::Input,
+        output: &Self::Output,
+        proof: &Self::Proof,
+    ) -> Result<(), PrimitivesError> {
+        pp.check_element(input)?;
+        pp.check_element(output)?;
+        pp.check_element(proof)?;
+        let n = &pp.modulus;
+        let l = pp.hash_to_prime(input, output);
+        let r = BigUint::from(2u8).modpow(&BigUint::from(pp.difficulty), &l);
+        let expected = pp.normalize(proof.0.modpow(&l, n) * input.0.modpow(&r, n));
+        if expected == output.0 {
+            Ok(())
+        } else {
+            Err(VerificationError("invalid VDF proof".to_string()))
+        }
+    }
+}
+
+// A random RSA modulus of `bits` bits, whose factors are discarded.
+fn sample_modulus<R: CryptoRng + RngCore>(bits: u64, prng: &mut R) -> BigUint {
+    loop {
+        let p = sample_prime(bits / 2, prng);
+        let q = sample_prime(bits - bits / 2, prng);
+        if p != q {
+            return p * q;
+        }
+    }
+}
+
+// A random prime of `bits` bits whose two most significant bits are set, so
+// that the product of two of them has the sum of their sizes.
+pub(crate) fn sample_prime<R: CryptoRng + RngCore>(bits: u64, prng: &mut R) -> BigUint {
+    let mut bytes = vec![0u8; ((bits + 7) / 8) as usize];
+    loop {
+        prng.fill_bytes(&mut bytes);
+        let mut candidate = BigUint::from_bytes_be(&bytes) >> (8 * bytes.len() as u64 - bits);
+        candidate.set_bit(bits - 1, true);
+        candidate.set_bit(bits - 2, true);
+        candidate.set_bit(0, true);
+        if is_probable_prime(&candidate) {
+            return candidate;
+        }
+    }
+}
+
+// The Miller-Rabin test to the bases of the first 20 primes, after trial
+// division by the small primes. The candidates are sampled at random or by
+// hashing, rather than chosen adversarially.
+fn is_probable_prime(n: &BigUint) -> bool {
+    for p in SMALL_PRIMES {
+        let p = BigUint::from(p);
+        if *n == p {
+            return true;
+        }
+        if (n % &p).is_zero() {
+            return false;
+        }
+    }
+    if n.bits() <= 1 {
+        return false;
+    }
+
+    // `n-1 = d * 2^s` with `d` odd
+    let n_minus_one = n - 1u8;
+    let s = n_minus_one.trailing_zeros().unwrap_or(0); // `n-1` is even and non-zero
+    let d = &n_minus_one >> s;
+    'witness: for a in &SMALL_PRIMES[..20] {
+        let mut x = BigUint::from(*a).modpow(&d, n);
+        if x.is_one() || x == n_minus_one {
+            continue;
+        }
+        for _ in 1..s {
+            x = &x * &x % n;
+            if x == n_minus_one {
+                continue 'witness;
+            }
+        }
+        return false;
+    }
+    true
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use ark_std::rand::rngs::StdRng;
+
+    fn test_wesolowski_helper(pp: &WesolowskiPP) {
+        let input = pp.hash_to_group(b"seed");
+        assert_eq!(input, pp.hash_to_group(b"seed"));
+        assert_ne!(input, pp.hash_to_group(b"other seed"));
+        let (output, proof) = Wesolowski::eval(pp, &input).unwrap();
+        assert!(Wesolowski::verify(pp, &input, &output, &proof).is_ok());
+
+        // the output is `x^(2^T)` up to sign
+        let n = pp.modulus();
+        let exp = BigUint::one() << pp.difficulty();
+        assert_eq!(output.0, pp.normalize(input.0.modpow(&exp, n)));
+
+        // wrong input, output or proof
+        let other = pp.hash_to_group(b"other seed");
+        assert!(Wesolowski::verify(pp, &other, &output, &proof).is_err());
+        assert!(Wesolowski::verify(pp, &input, &other, &proof).is_err());
+        assert!(Wesolowski::verify(pp, &input, &output, &other).is_err());
+        assert!(Wesolowski::verify(pp, &input, &output, &output).is_err());
+        // non-normalized representatives
+        let negated = RsaGroupElement(n - &output.0);
+        assert!(Wesolowski::verify(pp, &input, &negated, &proof).is_err());
+        let negated = RsaGroupElement(n - &proof.0);
+        assert!(Wesolowski::verify(pp, &input, &output, &negated).is_err());
+        assert!(Wesolowski::eval(pp, &RsaGroupElement(BigUint::zero())).is_err());
+        // wrong difficulty
+        let easier = Wesolowski::setup_with_modulus(pp.difficulty() - 1, n.clone()).unwrap();
+        assert!(Wesolowski::verify(&easier, &input, &output, &proof).is_err());
+        let (easier_output, easier_proof) = Wesolowski::eval(&easier, &input).unwrap();
+        assert!(Wesolowski::verify(pp, &input, &easier_output, &easier_proof).is_err());
+    }
+
+    #[test]
+    fn test_wesolowski() {
+        let pp = Wesolowski::setup::<StdRng>(200, None).unwrap();
+        assert_eq!(pp.modulus().bits(), 2048);
+        test_wesolowski_helper(&pp);
+
+        let mut rng = jf_utils::test_rng();
+        let modulus = sample_modulus(MIN_MODULUS_BITS, &mut rng);
+        assert_eq!(modulus.bits(), MIN_MODULUS_BITS);
+        let pp = Wesolowski::setup_with_modulus(1000, modulus).unwrap();
+        test_wesolowski_helper(&pp);
+
+        assert!(Wesolowski::setup_with_modulus(10, BigUint::one() << 600u32).is_err());
+        assert!(Wesolowski::setup_with_modulus(10, BigUint::from(u64::MAX)).is_err());
+    }
+
+    #[test]
+    fn test_is_probable_prime() {
+        for p in [2u64, 3, 251, 257, 65537, (1 << 61) - 1] {
+            assert!(is_probable_prime(&BigUint::from(p)));
+        }
+        assert!(is_probable_prime(&((BigUint::one() << 127u32) - 1u8)));
+        // Carmichael numbers and a product of two large primes
+        for n in [0u64, 1, 4, 561, 41041, 825265, 65537 * 65539] {
+            assert!(!is_probable_prime(&BigUint::from(n)));
+        }
+        assert!(!is_probable_prime(&((BigUint::one() << 128u32) + 1u8)));
+    }
+
+    #[test]
+    fn test_serde() {
+        let pp = Wesolowski::setup::<StdRng>(10, None).unwrap();
+        let (output, proof) = Wesolowski::eval(&pp, &pp.hash_to_group(b"seed")).unwrap();
+        for elem in [output, proof] {
+            let mut bytes = Vec::new();
+            elem.serialize_compressed(&mut bytes).unwrap();
+            assert_eq!(bytes.len(), elem.compressed_size());
+            assert_eq!(
+                RsaGroupElement::deserialize_compressed(&bytes[..]).unwrap(),
+                elem
+            );
+        }
+        // non-minimal encodings
+        let mut bytes = Vec::new();
+        vec![0u8, 1].serialize_compressed(&mut bytes).unwrap();
+        assert!(RsaGroupElement::deserialize_compressed(&bytes[..]).is_err());
+        let mut bytes = Vec::new();
+        Vec::<u8>::new().serialize_compressed(&mut bytes).unwrap();
+        assert!(RsaGroupElement::deserialize_compressed(&bytes[..]).is_err());
+    }
+}
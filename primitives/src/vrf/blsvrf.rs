@@ -1,12 +1,25 @@
 //! BLS signature based VRF
+//!
+//! The proof of an input is the BLS signature of the input, which is unique,
+//! and the output is the hash of the proof. Two schemes are provided, with
+//! the keys of [`BLSSignatureScheme`] over BLS12-381, and with the keys of
+//! [`BLSOverBN254CurveSignatureScheme`] over BN254. The latter signs under
+//! its own ciphersuite identifier [`CS_ID_BLS_VRF_BN254`], so that the proofs
+//! are never valid signatures, and conversely.
 use super::Vrf;
 use crate::{
+    constants::CS_ID_BLS_VRF_BN254,
     errors::PrimitivesError,
     signatures::{
         bls_over_bls12381::{BLSSignKey, BLSSignature, BLSVerKey},
+        bls_over_bn254::{
+            BLSOverBN254CurveSignatureScheme, KeyPair as BN254KeyPair, SignKey as BN254SignKey,
+            Signature as BN254Signature, VerKey as BN254VerKey,
+        },
         BLSSignatureScheme, SignatureScheme,
     },
 };
+use ark_serialize::CanonicalSerialize;
 use ark_std::{
     boxed::Box,
     rand::{CryptoRng, RngCore},
@@ -124,6 +137,117 @@ impl Vrf for BLSVRFScheme {
     }
 }
 
+/// Supported Cipher Suites for BLS VRF over BN254.
+#[allow(non_camel_case_types)]
+#[derive(Debug)]
+pub enum BLSOverBN254VRFCipherSuite {
+    /// using arkworks and VRF output from SHA256 hashing
+    VRF_BLS_BN254_SHA256,
+    /// using arkworks and VRF output from SHA512 hashing
+    VRF_BLS_BN254_SHA512,
+}
+
+/// BLS VRF scheme over BN254.
+/// Optimized for signature size, i.e.: PK in G2 and sig in G1
+pub struct BLSOverBN254VRFScheme {
+    hasher: Box<dyn DynDigest>,
+}
+
+impl BLSOverBN254VRFScheme {
+    /// Creates a new BLS VRF instance over BN254 with the given ciphersuite.
+    pub fn new(cs_id: BLSOverBN254VRFCipherSuite) -> Self {
+        match cs_id {
+            BLSOverBN254VRFCipherSuite::VRF_BLS_BN254_SHA256 => Self {
+                hasher: Box::new(Sha256::new()),
+            },
+            BLSOverBN254VRFCipherSuite::VRF_BLS_BN254_SHA512 => Self {
+                hasher: Box::new(Sha512::new()),
+            },
+        }
+    }
+}
+
+impl Vrf for BLSOverBN254VRFScheme {
+    /// Public Parameter.
+    /// For BLS signatures, we want to use default
+    /// prime subgroup generators. So here we don't need
+    /// to specify which PP it is.
+    type PublicParameter = ();
+
+    /// VRF public key.
+    type PublicKey = BN254VerKey;
+
+    /// VRF secret key.
+    type SecretKey = BN254SignKey;
+
+    /// VRF signature.
+    type Proof = BN254Signature;
+
+    /// The input of VRF proof.
+    type Input = Vec<u8>;
+
+    /// The output of VRF evaluation.
+    type Output = Vec<u8>;
+
+    /// generate public parameters from RNG.
+    fn param_gen<R: CryptoRng + RngCore>(
+        &self,
+        _prng: Option<&mut R>,
+    ) -> Result<Self::PublicParameter, PrimitivesError> {
+        Ok(())
+    }
+
+    /// Creates a pair of VRF public and private keys.
+    fn key_gen<R: CryptoRng + RngCore>(
+        &self,
+        pp: &Self::PublicParameter,
+        prng: &mut R,
+    ) -> Result<(Self::SecretKey, Self::PublicKey), PrimitivesError> {
+        <BLSOverBN254CurveSignatureScheme as SignatureScheme>::key_gen(pp, prng)
+    }
+
+    /// Creates the VRF proof associated with a VRF secret key.
+    fn prove<R: CryptoRng + RngCore>(
+        &self,
+        _pp: &Self::PublicParameter,
+        secret_key: &Self::SecretKey,
+        input: &Self::Input,
+        _prng: &mut R,
+    ) -> Result<Self::Proof, PrimitivesError> {
+        let kp = BN254KeyPair::generate_with_sign_key(secret_key.0);
+        Ok(kp.sign(input, CS_ID_BLS_VRF_BN254))
+    }
+
+    /// Computes the VRF output associated with a VRF proof.
+    fn proof_to_hash(
+        &mut self,
+        _pp: &Self::PublicParameter,
+        proof: &Self::Proof,
+    ) -> Result<Self::Output, PrimitivesError> {
+        let mut proof_serialized = Vec::new();
+        proof.serialize_compressed(&mut proof_serialized)?;
+        let mut hasher = (*self.hasher).box_clone();
+        hasher.update(&proof_serialized);
+        let output = hasher.finalize();
+        Ok(output.to_vec())
+    }
+
+    /// Verifies a VRF proof.
+    fn verify(
+        &mut self,
+        pp: &Self::PublicParameter,
+        proof: &Self::Proof,
+        public_key: &Self::PublicKey,
+        input: &Self::Input,
+    ) -> Result<(bool, Option<Self::Output>), PrimitivesError> {
+        if public_key.verify(input, proof, CS_ID_BLS_VRF_BN254).is_ok() {
+            Ok((true, Some(Self::proof_to_hash(self, pp, proof)?)))
+        } else {
+            Ok((false, None))
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use jf_utils::test_rng;
@@ -162,6 +286,74 @@ mod test {
         assert!(!is_correct);
     }
 
+    fn bn254_prove_and_verify<H: Digest>(
+        vrf: &mut BLSOverBN254VRFScheme,
+        message: &<BLSOverBN254VRFScheme as Vrf>::Input,
+        bad_message: &<BLSOverBN254VRFScheme as Vrf>::Input,
+    ) {
+        let rng = &mut test_rng();
+
+        let (sk, pk) = vrf.key_gen(&(), rng).unwrap();
+        let vrf_proof = vrf.prove(&(), &sk, message, rng).unwrap();
+        let vrf_output = vrf.proof_to_hash(&(), &vrf_proof).unwrap();
+        let (is_correct, output) = vrf.verify(&(), &vrf_proof, &pk, message).unwrap();
+        assert!(is_correct);
+        assert_eq!(output, Some(vrf_output.clone()));
+
+        // check that proof_to_hash(proof) == evaluate(sk, message)
+        let out = vrf.evaluate(&(), &sk, message, rng).unwrap();
+        assert_eq!(out, vrf_output);
+
+        // check the VRF output vs. hashing the proof directly
+        let mut proof_serialized = Vec::new();
+        vrf_proof
+            .serialize_compressed(&mut proof_serialized)
+            .unwrap();
+        let mut hasher = H::new();
+        hasher.update(proof_serialized);
+        assert_eq!(hasher.finalize().to_vec(), vrf_output);
+
+        let (is_correct, output) = vrf.verify(&(), &vrf_proof, &pk, bad_message).unwrap();
+        assert!(!is_correct);
+        assert!(output.is_none());
+        let (_, other_pk) = vrf.key_gen(&(), rng).unwrap();
+        let (is_correct, _) = vrf.verify(&(), &vrf_proof, &other_pk, message).unwrap();
+        assert!(!is_correct);
+
+        // the proofs are not signatures of the input, and conversely
+        assert!(
+            <BLSOverBN254CurveSignatureScheme as SignatureScheme>::verify(
+                &(),
+                &pk,
+                message,
+                &vrf_proof
+            )
+            .is_err()
+        );
+        let sig =
+            <BLSOverBN254CurveSignatureScheme as SignatureScheme>::sign(&(), &sk, message, rng)
+                .unwrap();
+        let (is_correct, _) = vrf.verify(&(), &sig, &pk, message).unwrap();
+        assert!(!is_correct);
+    }
+
+    #[test]
+    fn test_bls_vrf_bn254() {
+        let rng = &mut test_rng();
+        for _ in 0..10 {
+            let message = rng.gen::<[u8; 32]>().to_vec();
+            // bad message is truncated
+            let message_bad = message.clone()[..31].to_vec();
+            let mut vrf256 =
+                BLSOverBN254VRFScheme::new(BLSOverBN254VRFCipherSuite::VRF_BLS_BN254_SHA256);
+            bn254_prove_and_verify::<Sha256>(&mut vrf256, &message, &message_bad);
+
+            let mut vrf512 =
+                BLSOverBN254VRFScheme::new(BLSOverBN254VRFCipherSuite::VRF_BLS_BN254_SHA512);
+            bn254_prove_and_verify::<Sha512>(&mut vrf512, &message, &message_bad);
+        }
+    }
+
     #[test]
     fn test_bls_vrf() {
         let rng = &mut test_rng();
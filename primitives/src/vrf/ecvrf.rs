@@ -1 +1,1123 @@
-//! Place holder for ECVRF
+//! Elliptic curve VRF of [RFC 9381][rfc9381].
+//!
+//! Keys, proofs and outputs are the byte strings of the RFC, so that proofs
+//! produced here verify with any other implementation of the same
+//! ciphersuite, and conversely. Both ciphersuites hash to the curve with the
+//! try-and-increment method:
+//! * ECVRF-P256-SHA256-TAI, with 81-byte proofs and 32-byte outputs, and nonces
+//!   derived as in RFC 6979;
+//! * ECVRF-EDWARDS25519-SHA512-TAI, with 80-byte proofs and 64-byte outputs,
+//!   and keys and nonces derived as for Ed25519 signatures.
+//!
+//! Public keys are validated upon verification, rejecting keys of small order.
+//!
+//! The RFC proofs `(Gamma,c,s)` can only be verified one at a time, as the
+//! nonce points `U=s*B-c*Y` and `V=s*H-c*Gamma` must be recomputed to check
+//! the challenge `c`. The batchable proofs `(Gamma,U,V,s)` carry the nonce
+//! points instead, so that many of them, under the same key or not, are
+//! verified at once by checking a random linear combination of the equations
+//! of `U` and `V` with a single multi-scalar multiplication. A batchable proof
+//! has the same output as the RFC proof, into which it converts given the
+//! public key and the input.
+//!
+//! [rfc9381]: https://www.rfc-editor.org/rfc/rfc9381
+use super::Vrf;
+use crate::{
+    errors::PrimitivesError,
+    utils::{bytes_from_int, int_from_bytes},
+};
+use ark_ff::Zero;
+use ark_std::{
+    fmt,
+    rand::{CryptoRng, RngCore},
+    string::ToString,
+    vec::Vec,
+    UniformRand,
+};
+use hashbrown::HashMap;
+use serde::{Deserialize, Serialize};
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+/// Supported Cipher Suites for ECVRF.
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ECVRFCipherSuite {
+    /// ECVRF-P256-SHA256-TAI, with suite string 0x01
+    ECVRF_P256_SHA256_TAI,
+    /// ECVRF-EDWARDS25519-SHA512-TAI, with suite string 0x03
+    ECVRF_EDWARDS25519_SHA512_TAI,
+}
+
+/// ECVRF scheme.
+pub struct ECVRFScheme {
+    cs_id: ECVRFCipherSuite,
+}
+
+/// ECVRF secret key: a 32-byte big-endian scalar in `[1,n-1]` for P-256,
+/// and a 32-byte seed for edwards25519.
+#[derive(Clone, Zeroize, Eq, PartialEq, Serialize, Deserialize)]
+pub struct ECVRFSecretKey([u8; 32]);
+
+// the secret bytes are not printed
+impl fmt::Debug for ECVRFSecretKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("ECVRFSecretKey(..)")
+    }
+}
+
+impl Drop for ECVRFSecretKey {
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl ZeroizeOnDrop for ECVRFSecretKey {}
+
+/// ECVRF public key, in the point encoding of the ciphersuite.
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub struct ECVRFPublicKey(Vec<u8>);
+
+/// ECVRF proof, in the proof encoding of the ciphersuite.
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub struct ECVRFProof(Vec<u8>);
+
+/// ECVRF batchable proof `(Gamma,U,V,s)`, in the point and scalar encodings
+/// of the ciphersuite.
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub struct ECVRFBatchableProof(Vec<u8>);
+
+impl ECVRFSecretKey {
+    /// The secret key of the 32-byte encoding `bytes`. The key is checked to
+    /// be in range for the ciphersuite when used.
+    pub fn from_bytes(bytes: &[u8; 32]) -> Self {
+        Self(*bytes)
+    }
+
+    /// The 32-byte encoding of the secret key.
+    pub fn to_bytes(&self) -> [u8; 32] {
+        self.0
+    }
+}
+
+impl ECVRFPublicKey {
+    /// The public key of the encoding `bytes`. The key is checked upon
+    /// verification.
+    pub fn from_bytes(bytes: &[u8]) -> Self {
+        Self(bytes.to_vec())
+    }
+
+    /// The encoding of the public key.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl ECVRFProof {
+    /// The proof of the encoding `bytes`. The proof is checked upon
+    /// verification.
+    pub fn from_bytes(bytes: &[u8]) -> Self {
+        Self(bytes.to_vec())
+    }
+
+    /// The encoding of the proof.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl ECVRFBatchableProof {
+    /// The batchable proof of the encoding `bytes`. The proof is checked upon
+    /// verification.
+    pub fn from_bytes(bytes: &[u8]) -> Self {
+        Self(bytes.to_vec())
+    }
+
+    /// The encoding of the batchable proof.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl ECVRFScheme {
+    /// Creates a new ECVRF instance with the given ciphersuite.
+    pub fn new(cs_id: ECVRFCipherSuite) -> Self {
+        Self { cs_id }
+    }
+
+    /// The public key of a secret key.
+    /// * `returns` - Err() if the secret key is out of range for the
+    ///   ciphersuite
+    pub fn public_key(&self, sk: &ECVRFSecretKey) -> Result<ECVRFPublicKey, PrimitivesError> {
+        let pk = match self.cs_id {
+            ECVRFCipherSuite::ECVRF_P256_SHA256_TAI => p256::public_key(&sk.0)?,
+            ECVRFCipherSuite::ECVRF_EDWARDS25519_SHA512_TAI => edwards25519::public_key(&sk.0),
+        };
+        Ok(ECVRFPublicKey(pk))
+    }
+
+    /// Creates the batchable VRF proof of `input`, of 131 bytes for P-256 and
+    /// 128 bytes for edwards25519.
+    /// * `returns` - Err() if the secret key is out of range for the
+    ///   ciphersuite
+    pub fn prove_batchable(
+        &self,
+        secret_key: &ECVRFSecretKey,
+        input: &[u8],
+    ) -> Result<ECVRFBatchableProof, PrimitivesError> {
+        let proof = match self.cs_id {
+            ECVRFCipherSuite::ECVRF_P256_SHA256_TAI => p256::prove_batchable(&secret_key.0, input)?,
+            ECVRFCipherSuite::ECVRF_EDWARDS25519_SHA512_TAI => {
+                edwards25519::prove_batchable(&secret_key.0, input)?
+            },
+        };
+        Ok(ECVRFBatchableProof(proof))
+    }
+
+    /// Converts a batchable proof of `input` under `public_key` into its RFC
+    /// 9381 proof. The batchable proof is not verified, but the conversion
+    /// of an invalid proof is an invalid proof.
+    /// * `returns` - Err() if the public key or the proof are malformed
+    pub fn batchable_to_proof(
+        &self,
+        proof: &ECVRFBatchableProof,
+        public_key: &ECVRFPublicKey,
+        input: &[u8],
+    ) -> Result<ECVRFProof, PrimitivesError> {
+        let proof = match self.cs_id {
+            ECVRFCipherSuite::ECVRF_P256_SHA256_TAI => {
+                p256::batchable_to_proof(&public_key.0, &proof.0, input)?
+            },
+            ECVRFCipherSuite::ECVRF_EDWARDS25519_SHA512_TAI => {
+                edwards25519::batchable_to_proof(&public_key.0, &proof.0, input)?
+            },
+        };
+        Ok(ECVRFProof(proof))
+    }
+
+    /// Verifies a batch of batchable proofs, the i-th proof being of
+    /// `inputs[i]` under `public_keys[i]`. The keys need not be distinct, and
+    /// the verification is faster when they are shared.
+    /// * `returns` - the VRF outputs of the proofs, Err() if the lengths do not
+    ///   match or if any proof is invalid
+    pub fn batch_verify<R: CryptoRng + RngCore>(
+        &self,
+        proofs: &[ECVRFBatchableProof],
+        public_keys: &[ECVRFPublicKey],
+        inputs: &[Vec<u8>],
+        prng: &mut R,
+    ) -> Result<Vec<Vec<u8>>, PrimitivesError> {
+        if proofs.len() != public_keys.len() || proofs.len() != inputs.len() {
+            return Err(PrimitivesError::ParameterError(
+                "mismatched number of proofs, public keys and inputs".to_string(),
+            ));
+        }
+        match self.cs_id {
+            ECVRFCipherSuite::ECVRF_P256_SHA256_TAI => {
+                p256::batch_verify(proofs, public_keys, inputs, prng)
+            },
+            ECVRFCipherSuite::ECVRF_EDWARDS25519_SHA512_TAI => {
+                edwards25519::batch_verify(proofs, public_keys, inputs, prng)
+            },
+        }
+    }
+}
+
+impl Vrf for ECVRFScheme {
+    /// Public Parameter.
+    /// The ciphersuites fix the group generators, so here we don't need
+    /// to specify which PP it is.
+    type PublicParameter = ();
+
+    /// VRF public key.
+    type PublicKey = ECVRFPublicKey;
+
+    /// VRF secret key.
+    type SecretKey = ECVRFSecretKey;
+
+    /// VRF signature.
+    type Proof = ECVRFProof;
+
+    /// The input of VRF proof.
+    type Input = Vec<u8>;
+
+    /// The output of VRF evaluation.
+    type Output = Vec<u8>;
+
+    /// generate public parameters from RNG.
+    fn param_gen<R: CryptoRng + RngCore>(
+        &self,
+        _prng: Option<&mut R>,
+    ) -> Result<Self::PublicParameter, PrimitivesError> {
+        Ok(())
+    }
+
+    /// Creates a pair of VRF public and private keys.
+    fn key_gen<R: CryptoRng + RngCore>(
+        &self,
+        _pp: &Self::PublicParameter,
+        prng: &mut R,
+    ) -> Result<(Self::SecretKey, Self::PublicKey), PrimitivesError> {
+        let sk = match self.cs_id {
+            ECVRFCipherSuite::ECVRF_P256_SHA256_TAI => {
+                let mut x = ark_secp256r1::Fr::zero();
+                while x.is_zero() {
+                    x = ark_secp256r1::Fr::rand(prng);
+                }
+                let sk = ECVRFSecretKey(bytes_from_int(&x));
+                x.zeroize();
+                sk
+            },
+            ECVRFCipherSuite::ECVRF_EDWARDS25519_SHA512_TAI => {
+                let mut seed = [0u8; 32];
+                prng.fill_bytes(&mut seed);
+                let sk = ECVRFSecretKey(seed);
+                seed.zeroize();
+                sk
+            },
+        };
+        let pk = self.public_key(&sk)?;
+        Ok((sk, pk))
+    }
+
+    /// Creates the VRF proof associated with a VRF secret key. The nonce is
+    /// deterministic, so `prng` is unused.
+    fn prove<R: CryptoRng + RngCore>(
+        &self,
+        _pp: &Self::PublicParameter,
+        secret_key: &Self::SecretKey,
+        input: &Self::Input,
+        _prng: &mut R,
+    ) -> Result<Self::Proof, PrimitivesError> {
+        let proof = match self.cs_id {
+            ECVRFCipherSuite::ECVRF_P256_SHA256_TAI => p256::prove(&secret_key.0, input)?,
+            ECVRFCipherSuite::ECVRF_EDWARDS25519_SHA512_TAI => {
+                edwards25519::prove(&secret_key.0, input)?
+            },
+        };
+        Ok(ECVRFProof(proof))
+    }
+
+    /// Computes the VRF output associated with a VRF proof.
+    fn proof_to_hash(
+        &mut self,
+        _pp: &Self::PublicParameter,
+        proof: &Self::Proof,
+    ) -> Result<Self::Output, PrimitivesError> {
+        match self.cs_id {
+            ECVRFCipherSuite::ECVRF_P256_SHA256_TAI => p256::proof_to_hash(&proof.0),
+            ECVRFCipherSuite::ECVRF_EDWARDS25519_SHA512_TAI => {
+                edwards25519::proof_to_hash(&proof.0)
+            },
+        }
+    }
+
+    /// Verifies a VRF proof.
+    fn verify(
+        &mut self,
+        _pp: &Self::PublicParameter,
+        proof: &Self::Proof,
+        public_key: &Self::PublicKey,
+        input: &Self::Input,
+    ) -> Result<(bool, Option<Self::Output>), PrimitivesError> {
+        let output = match self.cs_id {
+            ECVRFCipherSuite::ECVRF_P256_SHA256_TAI => p256::verify(&public_key.0, &proof.0, input),
+            ECVRFCipherSuite::ECVRF_EDWARDS25519_SHA512_TAI => {
+                edwards25519::verify(&public_key.0, &proof.0, input)
+            },
+        };
+        match output {
+            Ok(output) => Ok((true, Some(output))),
+            Err(_) => Ok((false, None)),
+        }
+    }
+}
+
+fn invalid_proof() -> PrimitivesError {
+    PrimitivesError::VerificationError("ECVRF proof verification error".to_string())
+}
+
+// A random 128-bit coefficient of batch verification.
+fn randomizer<R: CryptoRng + RngCore>(prng: &mut R) -> u128 {
+    (u128::from(prng.next_u64()) << 64) | u128::from(prng.next_u64())
+}
+
+fn invalid_hash_to_curve() -> PrimitivesError {
+    PrimitivesError::InternalError("ECVRF hash to curve failed".to_string())
+}
+
+// ECVRF-P256-SHA256-TAI, with big-endian integers and compressed SEC1 points.
+#[allow(non_snake_case)]
+mod p256 {
+    use super::*;
+    use crate::signatures::ecdsa::Rfc6979;
+    use ark_ec::{AffineRepr, CurveGroup, Group, VariableBaseMSM};
+    use ark_ff::{BigInteger, PrimeField};
+    use ark_secp256r1::{Affine, Fq, Fr, Projective};
+    use sha2::{Digest, Sha256};
+
+    const SUITE: u8 = 0x01;
+
+    pub(super) fn public_key(sk: &[u8; 32]) -> Result<Vec<u8>, PrimitivesError> {
+        let x = scalar(sk)?;
+        Ok(encode(&(Projective::generator() * x).into_affine()))
+    }
+
+    pub(super) fn prove(sk: &[u8; 32], alpha: &[u8]) -> Result<Vec<u8>, PrimitivesError> {
+        let (Gamma, _, c, s) = prove_parts(sk, alpha)?;
+        let mut proof = encode(&Gamma);
+        proof.extend_from_slice(&c);
+        proof.extend_from_slice(&bytes_from_int(&s));
+        Ok(proof)
+    }
+
+    pub(super) fn prove_batchable(sk: &[u8; 32], alpha: &[u8]) -> Result<Vec<u8>, PrimitivesError> {
+        let (Gamma, [U, V], _, s) = prove_parts(sk, alpha)?;
+        let mut proof = encode(&Gamma);
+        proof.extend(encode(&U));
+        proof.extend(encode(&V));
+        proof.extend_from_slice(&bytes_from_int(&s));
+        Ok(proof)
+    }
+
+    pub(super) fn proof_to_hash(proof: &[u8]) -> Result<Vec<u8>, PrimitivesError> {
+        let (Gamma, ..) = decode_proof(proof)?;
+        Ok(gamma_to_hash(&Gamma))
+    }
+
+    pub(super) fn verify(
+        pk: &[u8],
+        proof: &[u8],
+        alpha: &[u8],
+    ) -> Result<Vec<u8>, PrimitivesError> {
+        // the identity has no compressed encoding, and the cofactor is 1
+        let Y = decode(pk).ok_or_else(invalid_proof)?;
+        let (Gamma, c, s) = decode_proof(proof)?;
+        let H = encode_to_curve(pk, alpha)?;
+        let c_scalar = Fr::from_be_bytes_mod_order(&c);
+        let U = Projective::generator() * s - Y * c_scalar;
+        let V = H * s - Gamma * c_scalar;
+        if challenge(&[Y.into_group(), H.into_group(), Gamma.into_group(), U, V]) != c {
+            return Err(invalid_proof());
+        }
+        Ok(gamma_to_hash(&Gamma))
+    }
+
+    pub(super) fn batchable_to_proof(
+        pk: &[u8],
+        proof: &[u8],
+        alpha: &[u8],
+    ) -> Result<Vec<u8>, PrimitivesError> {
+        let Y = decode(pk).ok_or_else(invalid_proof)?;
+        let (Gamma, U, V, s) = decode_batchable_proof(proof)?;
+        let H = encode_to_curve(pk, alpha)?;
+        let c = challenge(&[Y, H, Gamma, U, V].map(|point| point.into_group()));
+        let mut standard = encode(&Gamma);
+        standard.extend_from_slice(&c);
+        standard.extend_from_slice(&bytes_from_int(&s));
+        Ok(standard)
+    }
+
+    pub(super) fn batch_verify<R: CryptoRng + RngCore>(
+        proofs: &[ECVRFBatchableProof],
+        pks: &[ECVRFPublicKey],
+        inputs: &[Vec<u8>],
+        prng: &mut R,
+    ) -> Result<Vec<Vec<u8>>, PrimitivesError> {
+        // the distinct keys, along with their coefficients
+        let mut key_indices: HashMap<&[u8], usize> = HashMap::new();
+        let mut keys = Vec::new();
+        let mut key_coeffs = Vec::new();
+        let mut base_coeff = Fr::zero();
+        let mut bases = Vec::with_capacity(4 * proofs.len() + 1);
+        let mut scalars = Vec::with_capacity(4 * proofs.len() + 1);
+        let mut outputs = Vec::with_capacity(proofs.len());
+        for ((proof, pk), alpha) in proofs.iter().zip(pks).zip(inputs) {
+            let i = match key_indices.get(pk.as_bytes()) {
+                Some(&i) => i,
+                None => {
+                    keys.push(decode(pk.as_bytes()).ok_or_else(invalid_proof)?);
+                    key_coeffs.push(Fr::zero());
+                    key_indices.insert(pk.as_bytes(), keys.len() - 1);
+                    keys.len() - 1
+                },
+            };
+            let (Gamma, U, V, s) = decode_batchable_proof(proof.as_bytes())?;
+            let H = encode_to_curve(pk.as_bytes(), alpha)?;
+            let c = challenge(&[keys[i], H, Gamma, U, V].map(|point| point.into_group()));
+            let c = Fr::from_be_bytes_mod_order(&c);
+
+            // r (s B - c Y - U) + l (s H - c Gamma - V)
+            let r = Fr::from(randomizer(prng));
+            let l = Fr::from(randomizer(prng));
+            base_coeff += r * s;
+            key_coeffs[i] -= r * c;
+            bases.extend([U, H, Gamma, V]);
+            scalars.extend([-r, l * s, -(l * c), -l]);
+            outputs.push(gamma_to_hash(&Gamma));
+        }
+        bases.push(Affine::generator());
+        scalars.push(base_coeff);
+        bases.extend(keys);
+        scalars.extend(key_coeffs);
+        if Projective::msm_unchecked(&bases, &scalars).is_zero() {
+            Ok(outputs)
+        } else {
+            Err(invalid_proof())
+        }
+    }
+
+    // the nonce points `U=kB`, `V=kH` are returned as well
+    fn prove_parts(
+        sk: &[u8; 32],
+        alpha: &[u8],
+    ) -> Result<(Affine, [Affine; 2], [u8; 16], Fr), PrimitivesError> {
+        let mut x = scalar(sk)?;
+        let Y = (Projective::generator() * x).into_affine();
+        let H = encode_to_curve(&encode(&Y), alpha)?;
+        let h_string = encode(&H);
+        let Gamma = H * x;
+        let h = Fr::from_be_bytes_mod_order(&Sha256::digest(&h_string));
+        let mut k: Fr = Rfc6979::new(sk, &bytes_from_int(&h)).next_nonce();
+        let U = Projective::generator() * k;
+        let V = H * k;
+        let c = challenge(&[Y.into_group(), H.into_group(), Gamma, U, V]);
+        let s = k + Fr::from_be_bytes_mod_order(&c) * x;
+        x.zeroize();
+        k.zeroize();
+        let points = Projective::normalize_batch(&[Gamma, U, V]);
+        Ok((points[0], [points[1], points[2]], c, s))
+    }
+
+    fn gamma_to_hash(Gamma: &Affine) -> Vec<u8> {
+        Sha256::new()
+            .chain_update([SUITE, 0x03])
+            .chain_update(encode(Gamma))
+            .chain_update([0x00])
+            .finalize()
+            .to_vec()
+    }
+    // the secret scalar, checked to be in `[1,n-1]`
+    fn scalar(sk: &[u8; 32]) -> Result<Fr, PrimitivesError> {
+        match int_from_bytes::<Fr>(sk) {
+            Some(x) if !x.is_zero() => Ok(x),
+            _ => Err(PrimitivesError::ParameterError(
+                "secret key out of range".to_string(),
+            )),
+        }
+    }
+
+    fn encode_to_curve(salt: &[u8], alpha: &[u8]) -> Result<Affine, PrimitivesError> {
+        for ctr in 0..=u8::MAX {
+            let hash = Sha256::new()
+                .chain_update([SUITE, 0x01])
+                .chain_update(salt)
+                .chain_update(alpha)
+                .chain_update([ctr, 0x00])
+                .finalize();
+            let mut bytes = [0x02; 33];
+            bytes[1..].copy_from_slice(&hash);
+            if let Some(H) = decode(&bytes) {
+                return Ok(H);
+            }
+        }
+        Err(invalid_hash_to_curve())
+    }
+
+    fn challenge(points: &[Projective; 5]) -> [u8; 16] {
+        let mut hasher = Sha256::new().chain_update([SUITE, 0x02]);
+        for point in Projective::normalize_batch(points) {
+            hasher.update(encode(&point));
+        }
+        let hash = hasher.chain_update([0x00]).finalize();
+        let mut c = [0u8; 16];
+        c.copy_from_slice(&hash[..16]);
+        c
+    }
+
+    fn decode_proof(proof: &[u8]) -> Result<(Affine, [u8; 16], Fr), PrimitivesError> {
+        if proof.len() != 81 {
+            return Err(invalid_proof());
+        }
+        let Gamma = decode(&proof[..33]).ok_or_else(invalid_proof)?;
+        let mut c = [0u8; 16];
+        c.copy_from_slice(&proof[33..49]);
+        let s = int_from_bytes::<Fr>(proof[49..].try_into().unwrap()).ok_or_else(invalid_proof)?; // safe unwrap
+        Ok((Gamma, c, s))
+    }
+
+    fn decode_batchable_proof(
+        proof: &[u8],
+    ) -> Result<(Affine, Affine, Affine, Fr), PrimitivesError> {
+        if proof.len() != 131 {
+            return Err(invalid_proof());
+        }
+        let Gamma = decode(&proof[..33]).ok_or_else(invalid_proof)?;
+        let U = decode(&proof[33..66]).ok_or_else(invalid_proof)?;
+        let V = decode(&proof[66..99]).ok_or_else(invalid_proof)?;
+        let s = int_from_bytes::<Fr>(proof[99..].try_into().unwrap()).ok_or_else(invalid_proof)?; // safe unwrap
+        Ok((Gamma, U, V, s))
+    }
+
+    // The compressed SEC1 encoding, or a single zero byte for the identity.
+    fn encode(point: &Affine) -> Vec<u8> {
+        if point.is_zero() {
+            return [0u8].to_vec();
+        }
+        let mut bytes = Vec::with_capacity(33);
+        bytes.push(2 + point.y.into_bigint().is_odd() as u8);
+        bytes.extend_from_slice(&bytes_from_int(&point.x));
+        bytes
+    }
+
+    fn decode(bytes: &[u8]) -> Option<Affine> {
+        match (bytes.len(), bytes.first()) {
+            (33, Some(&tag)) if tag == 2 || tag == 3 => {
+                let x = int_from_bytes::<Fq>(bytes[1..].try_into().unwrap())?; // safe unwrap
+                let point = Affine::get_point_from_x_unchecked(x, true)?;
+                if point.y.into_bigint().is_odd() == (tag == 3) {
+                    Some(point)
+                } else {
+                    Some(-point)
+                }
+            },
+            _ => None,
+        }
+    }
+}
+
+// ECVRF-EDWARDS25519-SHA512-TAI, with little-endian integers and the point
+// encoding of RFC 8032.
+#[allow(non_snake_case)]
+mod edwards25519 {
+    use super::*;
+    use curve25519_dalek::{
+        constants::ED25519_BASEPOINT_POINT,
+        edwards::{CompressedEdwardsY, EdwardsPoint},
+        scalar::Scalar,
+        traits::{IsIdentity, VartimeMultiscalarMul},
+    };
+    use sha2::{Digest, Sha512};
+
+    const SUITE: u8 = 0x03;
+
+    pub(super) fn public_key(sk: &[u8; 32]) -> Vec<u8> {
+        let (x, _) = expand(sk);
+        EdwardsPoint::mul_base(&x).compress().to_bytes().to_vec()
+    }
+
+    pub(super) fn prove(sk: &[u8; 32], alpha: &[u8]) -> Result<Vec<u8>, PrimitivesError> {
+        let (Gamma, _, c, s) = prove_parts(sk, alpha)?;
+        let mut proof = Gamma.compress().to_bytes().to_vec();
+        proof.extend_from_slice(&c);
+        proof.extend_from_slice(s.as_bytes());
+        Ok(proof)
+    }
+
+    pub(super) fn prove_batchable(sk: &[u8; 32], alpha: &[u8]) -> Result<Vec<u8>, PrimitivesError> {
+        let (Gamma, [U, V], _, s) = prove_parts(sk, alpha)?;
+        let mut proof = Vec::with_capacity(128);
+        for point in [Gamma, U, V] {
+            proof.extend_from_slice(point.compress().as_bytes());
+        }
+        proof.extend_from_slice(s.as_bytes());
+        Ok(proof)
+    }
+
+    pub(super) fn proof_to_hash(proof: &[u8]) -> Result<Vec<u8>, PrimitivesError> {
+        let (Gamma, ..) = decode_proof(proof)?;
+        Ok(gamma_to_hash(&Gamma))
+    }
+
+    pub(super) fn verify(
+        pk: &[u8],
+        proof: &[u8],
+        alpha: &[u8],
+    ) -> Result<Vec<u8>, PrimitivesError> {
+        let Y = decode_key(pk)?;
+        let (Gamma, c, s) = decode_proof(proof)?;
+        let H = encode_to_curve(pk, alpha)?;
+        let c_scalar = challenge_scalar(&c);
+        let U = EdwardsPoint::mul_base(&s) - Y * c_scalar;
+        let V = H * s - Gamma * c_scalar;
+        if challenge(&[Y, H, Gamma, U, V]) != c {
+            return Err(invalid_proof());
+        }
+        Ok(gamma_to_hash(&Gamma))
+    }
+
+    pub(super) fn batchable_to_proof(
+        pk: &[u8],
+        proof: &[u8],
+        alpha: &[u8],
+    ) -> Result<Vec<u8>, PrimitivesError> {
+        let Y = decode_key(pk)?;
+        let (Gamma, U, V, s) = decode_batchable_proof(proof)?;
+        let H = encode_to_curve(pk, alpha)?;
+        let c = challenge(&[Y, H, Gamma, U, V]);
+        let mut standard = proof[..32].to_vec();
+        standard.extend_from_slice(&c);
+        standard.extend_from_slice(s.as_bytes());
+        Ok(standard)
+    }
+
+    pub(super) fn batch_verify<R: CryptoRng + RngCore>(
+        proofs: &[ECVRFBatchableProof],
+        pks: &[ECVRFPublicKey],
+        inputs: &[Vec<u8>],
+        prng: &mut R,
+    ) -> Result<Vec<Vec<u8>>, PrimitivesError> {
+        // the distinct keys, along with their coefficients
+        let mut key_indices: HashMap<&[u8], usize> = HashMap::new();
+        let mut keys = Vec::new();
+        let mut key_coeffs = Vec::new();
+        let mut base_coeff = Scalar::ZERO;
+        let mut points = Vec::with_capacity(4 * proofs.len() + 1);
+        let mut scalars = Vec::with_capacity(4 * proofs.len() + 1);
+        let mut outputs = Vec::with_capacity(proofs.len());
+        for ((proof, pk), alpha) in proofs.iter().zip(pks).zip(inputs) {
+            let i = match key_indices.get(pk.as_bytes()) {
+                Some(&i) => i,
+                None => {
+                    keys.push(decode_key(pk.as_bytes())?);
+                    key_coeffs.push(Scalar::ZERO);
+                    key_indices.insert(pk.as_bytes(), keys.len() - 1);
+                    keys.len() - 1
+                },
+            };
+            let (Gamma, U, V, s) = decode_batchable_proof(proof.as_bytes())?;
+            let H = encode_to_curve(pk.as_bytes(), alpha)?;
+            let c = challenge_scalar(&challenge(&[keys[i], H, Gamma, U, V]));
+
+            // r (s B - c Y - U) + l (s H - c Gamma - V)
+            let r = Scalar::from(randomizer(prng));
+            let l = Scalar::from(randomizer(prng));
+            base_coeff += r * s;
+            key_coeffs[i] -= r * c;
+            points.extend([U, H, Gamma, V]);
+            scalars.extend([-r, l * s, -(l * c), -l]);
+            outputs.push(gamma_to_hash(&Gamma));
+        }
+        points.push(ED25519_BASEPOINT_POINT);
+        scalars.push(base_coeff);
+        points.extend(keys);
+        scalars.extend(key_coeffs);
+        // cofactored, so that small order components of the points of the
+        // proofs, which do not affect the outputs, are ignored
+        if EdwardsPoint::vartime_multiscalar_mul(scalars, points)
+            .mul_by_cofactor()
+            .is_identity()
+        {
+            Ok(outputs)
+        } else {
+            Err(invalid_proof())
+        }
+    }
+
+    // the nonce points `U=kB`, `V=kH` are returned as well
+    fn prove_parts(
+        sk: &[u8; 32],
+        alpha: &[u8],
+    ) -> Result<(EdwardsPoint, [EdwardsPoint; 2], [u8; 16], Scalar), PrimitivesError> {
+        let (mut x, mut prefix) = expand(sk);
+        let Y = EdwardsPoint::mul_base(&x);
+        let H = encode_to_curve(Y.compress().as_bytes(), alpha)?;
+        let Gamma = H * x;
+        let mut hash = [0u8; 64];
+        hash.copy_from_slice(
+            &Sha512::new()
+                .chain_update(prefix)
+                .chain_update(H.compress().as_bytes())
+                .finalize(),
+        );
+        let mut k = Scalar::from_bytes_mod_order_wide(&hash);
+        prefix.zeroize();
+        hash.zeroize();
+        let U = EdwardsPoint::mul_base(&k);
+        let V = H * k;
+        let c = challenge(&[Y, H, Gamma, U, V]);
+        let s = k + challenge_scalar(&c) * x;
+        x.zeroize();
+        k.zeroize();
+        Ok((Gamma, [U, V], c, s))
+    }
+
+    fn gamma_to_hash(Gamma: &EdwardsPoint) -> Vec<u8> {
+        Sha512::new()
+            .chain_update([SUITE, 0x03])
+            .chain_update(Gamma.mul_by_cofactor().compress().as_bytes())
+            .chain_update([0x00])
+            .finalize()
+            .to_vec()
+    }
+
+    // the public key, checked not to be of small order
+    fn decode_key(pk: &[u8]) -> Result<EdwardsPoint, PrimitivesError> {
+        match decode(pk) {
+            Some(Y) if !Y.is_small_order() => Ok(Y),
+            _ => Err(invalid_proof()),
+        }
+    }
+    // The secret scalar and the nonce prefix, as for Ed25519 signatures.
+    fn expand(sk: &[u8; 32]) -> (Scalar, [u8; 32]) {
+        let hash = Sha512::digest(sk);
+        let mut scalar_bytes = [0u8; 32];
+        let mut prefix = [0u8; 32];
+        scalar_bytes.copy_from_slice(&hash[..32]);
+        prefix.copy_from_slice(&hash[32..]);
+        scalar_bytes[0] &= 248;
+        scalar_bytes[31] &= 127;
+        scalar_bytes[31] |= 64;
+        // the base points have prime order, so reducing the scalar is harmless
+        let x = Scalar::from_bytes_mod_order(scalar_bytes);
+        scalar_bytes.zeroize();
+        (x, prefix)
+    }
+
+    fn encode_to_curve(salt: &[u8], alpha: &[u8]) -> Result<EdwardsPoint, PrimitivesError> {
+        for ctr in 0..=u8::MAX {
+            let hash = Sha512::new()
+                .chain_update([SUITE, 0x01])
+                .chain_update(salt)
+                .chain_update(alpha)
+                .chain_update([ctr, 0x00])
+                .finalize();
+            if let Some(H) = decode(&hash[..32]) {
+                let H = H.mul_by_cofactor();
+                if !H.is_identity() {
+                    return Ok(H);
+                }
+            }
+        }
+        Err(invalid_hash_to_curve())
+    }
+
+    fn challenge(points: &[EdwardsPoint; 5]) -> [u8; 16] {
+        let mut hasher = Sha512::new().chain_update([SUITE, 0x02]);
+        for point in points {
+            hasher.update(point.compress().as_bytes());
+        }
+        let hash = hasher.chain_update([0x00]).finalize();
+        let mut c = [0u8; 16];
+        c.copy_from_slice(&hash[..16]);
+        c
+    }
+
+    fn challenge_scalar(c: &[u8; 16]) -> Scalar {
+        let mut bytes = [0u8; 32];
+        bytes[..16].copy_from_slice(c);
+        Scalar::from_bytes_mod_order(bytes)
+    }
+
+    fn decode_proof(proof: &[u8]) -> Result<(EdwardsPoint, [u8; 16], Scalar), PrimitivesError> {
+        if proof.len() != 80 {
+            return Err(invalid_proof());
+        }
+        let Gamma = decode(&proof[..32]).ok_or_else(invalid_proof)?;
+        let mut c = [0u8; 16];
+        c.copy_from_slice(&proof[32..48]);
+        let s = Option::<Scalar>::from(Scalar::from_canonical_bytes(
+            proof[48..].try_into().unwrap(), // safe unwrap
+        ))
+        .ok_or_else(invalid_proof)?;
+        Ok((Gamma, c, s))
+    }
+
+    #[allow(clippy::type_complexity)]
+    fn decode_batchable_proof(
+        proof: &[u8],
+    ) -> Result<(EdwardsPoint, EdwardsPoint, EdwardsPoint, Scalar), PrimitivesError> {
+        if proof.len() != 128 {
+            return Err(invalid_proof());
+        }
+        let Gamma = decode(&proof[..32]).ok_or_else(invalid_proof)?;
+        let U = decode(&proof[32..64]).ok_or_else(invalid_proof)?;
+        let V = decode(&proof[64..96]).ok_or_else(invalid_proof)?;
+        let s = Option::<Scalar>::from(Scalar::from_canonical_bytes(
+            proof[96..].try_into().unwrap(), // safe unwrap
+        ))
+        .ok_or_else(invalid_proof)?;
+        Ok((Gamma, U, V, s))
+    }
+
+    fn decode(bytes: &[u8]) -> Option<EdwardsPoint> {
+        CompressedEdwardsY::from_slice(bytes).ok()?.decompress()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use jf_utils::test_rng;
+
+    fn from_hex(hex: &str) -> Vec<u8> {
+        (0..hex.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_ecvrf_vectors() {
+        // (ciphersuite, sk, pk, alpha, pi, beta); the first vector of each
+        // ciphersuite is from RFC 9381, Appendix B, and the others were
+        // checked against an independent implementation
+        let vectors = [
+            (
+                ECVRFCipherSuite::ECVRF_P256_SHA256_TAI,
+                "c9afa9d845ba75166b5c215767b1d6934e50c3db36e89b127b8a622b120f6721",
+                "0360fed4ba255a9d31c961eb74c6356d68c049b8923b61fa6ce669622e60f29fb6",
+                "73616d706c65",
+                "035b5c726e8c0e2c488a107c600578ee75cb702343c153cb1eb8dec77f4b5071b4a53f0a46f018bc2c56e58d383f2305e0975972c26feea0eb122fe7893c15af376b33edf7de17c6ea056d4d82de6bc02f",
+                "a3ad7b0ef73d8fc6655053ea22f9bede8c743f08bbed3d38821f0e16474b505e",
+            ),
+            (
+                ECVRFCipherSuite::ECVRF_P256_SHA256_TAI,
+                "c9afa9d845ba75166b5c215767b1d6934e50c3db36e89b127b8a622b120f6721",
+                "0360fed4ba255a9d31c961eb74c6356d68c049b8923b61fa6ce669622e60f29fb6",
+                "74657374",
+                "034dac60aba508ba0c01aa9be80377ebd7562c4a52d74722e0abae7dc3080ddb56c19e067b15a8a8174905b13617804534214f935b94c2287f797e393eb0816969d864f37625b443f30f1a5a33f2b3c854",
+                "a284f94ceec2ff4b3794629da7cbafa49121972671b466cab4ce170aa365f26d",
+            ),
+            (
+                ECVRFCipherSuite::ECVRF_P256_SHA256_TAI,
+                "2ca1411a41b17b24cc8c3b089cfd033f1920202a6c0de8abb97df1498d50d2c8",
+                "03596375e6ce57e0f20294fc46bdfcfd19a39f8161b58695b3ec5b3d16427c274d",
+                "4578616d706c65206f66204543445341207769746820616e736970323536723120616e64205348412d323536",
+                "030b002a87426005cf0e1a3f07c691881824157b3c1c5d1a330b06602d25453d6fb18150f8dee88080975edc989199e59a75a0d1bbe836914e8f6abc39e21e3976cb4c51f4db3434b0b1404b4630e50a6c",
+                "f1c929389f0330c80707ee1326d4412c0061462615efc6986d93485bdaac49e8",
+            ),
+            (
+                ECVRFCipherSuite::ECVRF_EDWARDS25519_SHA512_TAI,
+                "9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60",
+                "d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a",
+                "",
+                "8657106690b5526245a92b003bb079ccd1a92130477671f6fc01ad16f26f723f26f8a57ccaed74ee1b190bed1f479d9727d2d0f9b005a6e456a35d4fb0daab1268a1b0db10836d9826a528ca76567805",
+                "90cf1df3b703cce59e2a35b925d411164068269d7b2d29f3301c03dd757876ff66b71dda49d2de59d03450451af026798e8f81cd2e333de5cdf4f3e140fdd8ae",
+            ),
+            (
+                ECVRFCipherSuite::ECVRF_EDWARDS25519_SHA512_TAI,
+                "4ccd089b28ff96da9db6c346ec114e0f5b8a319f35aba624da8cf6ed4fb8a6fb",
+                "3d4017c3e843895a92b70aa74d1b7ebc9c982ccf2ec4968cc0cd55f12af4660c",
+                "72",
+                "f3141cd382dc42909d19ec5110469e4feae18300e94f304590abdced48aed5933bf0864a62558b3ed7f2fea45c92a465301b3bbf5e3e54ddf2d935be3b67926da3ef39226bbc355bdc9850112c8f4b02",
+                "eb4440665d3891d668e7e0fcaf587f1b4bd7fbfe99d0eb2211ccec90496310eb5e33821bc613efb94db5e5b54c70a848a0bef4553a41befc57663b56373a5031",
+            ),
+            (
+                ECVRFCipherSuite::ECVRF_EDWARDS25519_SHA512_TAI,
+                "c5aa8df43f9f837bedb7442f31dcb7b166d38535076f094b85ce3a2e0b4458f7",
+                "fc51cd8e6218a1a38da47ed00230f0580816ed13ba3303ac5deb911548908025",
+                "af82",
+                "9bc0f79119cc5604bf02d23b4caede71393cedfbb191434dd016d30177ccbf8096bb474e53895c362d8628ee9f9ea3c0e52c7a5c691b6c18c9979866568add7a2d41b00b05081ed0f58ee5e31b3a970e",
+                "645427e5d00c62a23fb703732fa5d892940935942101e456ecca7bb217c61c452118fec1219202a0edcf038bb6373241578be7217ba85a2687f7a0310b2df19f",
+            ),
+        ];
+        let rng = &mut test_rng();
+        for (cs_id, sk, pk, alpha, pi, beta) in vectors {
+            let mut vrf = ECVRFScheme::new(cs_id);
+            let sk = ECVRFSecretKey::from_bytes(&from_hex(sk).try_into().unwrap());
+            let pk = ECVRFPublicKey::from_bytes(&from_hex(pk));
+            let alpha = from_hex(alpha);
+            let pi = ECVRFProof::from_bytes(&from_hex(pi));
+            let beta = from_hex(beta);
+
+            assert_eq!(vrf.public_key(&sk).unwrap(), pk);
+            assert_eq!(vrf.prove(&(), &sk, &alpha, rng).unwrap(), pi);
+            assert_eq!(vrf.proof_to_hash(&(), &pi).unwrap(), beta);
+            assert_eq!(
+                vrf.verify(&(), &pi, &pk, &alpha).unwrap(),
+                (true, Some(beta.clone()))
+            );
+
+            let batchable = vrf.prove_batchable(&sk, &alpha).unwrap();
+            assert_eq!(vrf.batchable_to_proof(&batchable, &pk, &alpha).unwrap(), pi);
+            assert_eq!(
+                vrf.batch_verify(&[batchable], &[pk], &[alpha], rng)
+                    .unwrap(),
+                [beta]
+            );
+        }
+    }
+
+    fn prove_and_verify(cs_id: ECVRFCipherSuite) {
+        let rng = &mut test_rng();
+        let mut vrf = ECVRFScheme::new(cs_id);
+        let message = b"jellyfish".to_vec();
+        let bad_message = b"jellyfisH".to_vec();
+
+        let (sk, pk) = vrf.key_gen(&(), rng).unwrap();
+        let proof = vrf.prove(&(), &sk, &message, rng).unwrap();
+        let output = vrf.proof_to_hash(&(), &proof).unwrap();
+        assert_eq!(
+            vrf.verify(&(), &proof, &pk, &message).unwrap(),
+            (true, Some(output.clone()))
+        );
+        assert_eq!(vrf.evaluate(&(), &sk, &message, rng).unwrap(), output);
+
+        assert_eq!(
+            vrf.verify(&(), &proof, &pk, &bad_message).unwrap(),
+            (false, None)
+        );
+        let (_, other_pk) = vrf.key_gen(&(), rng).unwrap();
+        assert_eq!(
+            vrf.verify(&(), &proof, &other_pk, &message).unwrap(),
+            (false, None)
+        );
+        // tampering with any part of the proof
+        for i in [0, proof.0.len() / 2, proof.0.len() - 1] {
+            let mut bad_proof = proof.clone();
+            bad_proof.0[i] ^= 1;
+            assert_eq!(
+                vrf.verify(&(), &bad_proof, &pk, &message).unwrap(),
+                (false, None)
+            );
+        }
+        let mut short_proof = proof.clone();
+        short_proof.0.pop();
+        assert_eq!(
+            vrf.verify(&(), &short_proof, &pk, &message).unwrap(),
+            (false, None)
+        );
+        assert!(vrf.proof_to_hash(&(), &short_proof).is_err());
+        assert_eq!(
+            vrf.verify(&(), &proof, &ECVRFPublicKey::from_bytes(&[]), &message)
+                .unwrap(),
+            (false, None)
+        );
+    }
+
+    #[test]
+    fn test_ecvrf() {
+        prove_and_verify(ECVRFCipherSuite::ECVRF_P256_SHA256_TAI);
+        prove_and_verify(ECVRFCipherSuite::ECVRF_EDWARDS25519_SHA512_TAI);
+    }
+
+    #[test]
+    fn test_ecvrf_invalid_keys() {
+        let rng = &mut test_rng();
+        let message = b"jellyfish".to_vec();
+
+        // P-256 secret keys must be in `[1,n-1]`
+        let vrf = ECVRFScheme::new(ECVRFCipherSuite::ECVRF_P256_SHA256_TAI);
+        for sk in [[0u8; 32], [0xff; 32]] {
+            let sk = ECVRFSecretKey::from_bytes(&sk);
+            assert!(vrf.public_key(&sk).is_err());
+            assert!(vrf.prove(&(), &sk, &message, rng).is_err());
+        }
+
+        // edwards25519 public keys of small order are rejected
+        let mut vrf = ECVRFScheme::new(ECVRFCipherSuite::ECVRF_EDWARDS25519_SHA512_TAI);
+        let (sk, _) = vrf.key_gen(&(), rng).unwrap();
+        let proof = vrf.prove(&(), &sk, &message, rng).unwrap();
+        let mut identity = [0u8; 32];
+        identity[0] = 1;
+        assert_eq!(
+            vrf.verify(
+                &(),
+                &proof,
+                &ECVRFPublicKey::from_bytes(&identity),
+                &message
+            )
+            .unwrap(),
+            (false, None)
+        );
+
+        // keys and proofs of one ciphersuite are rejected by the other
+        let mut p256 = ECVRFScheme::new(ECVRFCipherSuite::ECVRF_P256_SHA256_TAI);
+        let (_, pk) = vrf.key_gen(&(), rng).unwrap();
+        assert_eq!(
+            p256.verify(&(), &proof, &pk, &message).unwrap(),
+            (false, None)
+        );
+    }
+
+    fn batch_prove_and_verify(cs_id: ECVRFCipherSuite) {
+        let rng = &mut test_rng();
+        let mut vrf = ECVRFScheme::new(cs_id);
+        let keys: Vec<_> = (0..3).map(|_| vrf.key_gen(&(), rng).unwrap()).collect();
+
+        // several proofs per key
+        let mut proofs = Vec::new();
+        let mut pks = Vec::new();
+        let mut inputs = Vec::new();
+        for i in 0..10u8 {
+            let (sk, pk) = &keys[i as usize % keys.len()];
+            let input = [b"round".to_vec(), [i].to_vec()].concat();
+            proofs.push(vrf.prove_batchable(sk, &input).unwrap());
+            pks.push(pk.clone());
+            inputs.push(input);
+        }
+        let outputs = vrf.batch_verify(&proofs, &pks, &inputs, rng).unwrap();
+        for (((proof, pk), input), output) in proofs.iter().zip(&pks).zip(&inputs).zip(&outputs) {
+            let proof = vrf.batchable_to_proof(proof, pk, input).unwrap();
+            assert_eq!(
+                vrf.verify(&(), &proof, pk, input).unwrap(),
+                (true, Some(output.clone()))
+            );
+        }
+        // a single key
+        let n = proofs.len();
+        let same_key: Vec<usize> = (0..n).step_by(keys.len()).collect();
+        assert!(vrf
+            .batch_verify(
+                &same_key
+                    .iter()
+                    .map(|&i| proofs[i].clone())
+                    .collect::<Vec<_>>(),
+                &same_key.iter().map(|&i| pks[i].clone()).collect::<Vec<_>>(),
+                &same_key
+                    .iter()
+                    .map(|&i| inputs[i].clone())
+                    .collect::<Vec<_>>(),
+                rng
+            )
+            .is_ok());
+        assert!(vrf.batch_verify(&[], &[], &[], rng).unwrap().is_empty());
+
+        // any invalid proof fails the batch
+        let mut bad_inputs = inputs.clone();
+        bad_inputs[n - 1].push(0);
+        assert!(vrf.batch_verify(&proofs, &pks, &bad_inputs, rng).is_err());
+        let mut bad_pks = pks.clone();
+        bad_pks.swap(0, 1);
+        assert!(vrf.batch_verify(&proofs, &bad_pks, &inputs, rng).is_err());
+        for i in [0, proofs[0].0.len() / 2, proofs[0].0.len() - 1] {
+            let mut bad_proofs = proofs.clone();
+            bad_proofs[3].0[i] ^= 1;
+            assert!(vrf.batch_verify(&bad_proofs, &pks, &inputs, rng).is_err());
+        }
+        // RFC proofs are not batchable proofs
+        let (sk, pk) = &keys[0];
+        let proof = vrf.prove(&(), sk, &inputs[0], rng).unwrap();
+        let proof = ECVRFBatchableProof::from_bytes(proof.as_bytes());
+        assert!(vrf
+            .batch_verify(&[proof], &[pk.clone()], &inputs[..1], rng)
+            .is_err());
+
+        assert!(vrf.batch_verify(&proofs, &pks[1..], &inputs, rng).is_err());
+        assert!(vrf.batch_verify(&proofs, &pks, &inputs[1..], rng).is_err());
+    }
+
+    #[test]
+    fn test_batch_verify() {
+        batch_prove_and_verify(ECVRFCipherSuite::ECVRF_P256_SHA256_TAI);
+        batch_prove_and_verify(ECVRFCipherSuite::ECVRF_EDWARDS25519_SHA512_TAI);
+    }
+
+    #[test]
+    fn test_serde() {
+        let rng = &mut test_rng();
+        let vrf = ECVRFScheme::new(ECVRFCipherSuite::ECVRF_EDWARDS25519_SHA512_TAI);
+        let (sk, pk) = vrf.key_gen(&(), rng).unwrap();
+        let proof = vrf.prove(&(), &sk, &b"jellyfish".to_vec(), rng).unwrap();
+
+        let bytes = bincode::serialize(&sk).unwrap();
+        assert_eq!(bincode::deserialize::<ECVRFSecretKey>(&bytes).unwrap(), sk);
+        let bytes = bincode::serialize(&pk).unwrap();
+        assert_eq!(bincode::deserialize::<ECVRFPublicKey>(&bytes).unwrap(), pk);
+        let bytes = bincode::serialize(&proof).unwrap();
+        assert_eq!(bincode::deserialize::<ECVRFProof>(&bytes).unwrap(), proof);
+
+        // the secret key is not printed
+        assert_eq!(ark_std::format!("{:?}", sk), "ECVRFSecretKey(..)");
+    }
+}
@@ -4,6 +4,7 @@ use crate::errors::PrimitivesError;
 use ark_std::rand::{CryptoRng, RngCore};
 pub mod blsvrf;
 pub mod ecvrf;
+pub mod sortition;
 use core::fmt::Debug;
 use serde::{Deserialize, Serialize};
 use zeroize::Zeroize;
@@ -0,0 +1,225 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+//! Cryptographic sortition over VRF outputs, as in [Algorand][algorand].
+//!
+//! Each unit of stake is selected independently with probability
+//! `p=expected_size/total_stake`, so that the number of seats of a
+//! participant with stake `w` follows the binomial distribution `B(w,p)`, and
+//! a committee has `expected_size` seats on average. The VRF output of the
+//! participant, read as a big-endian fraction `h` in `[0,1)`, selects the
+//! number of seats `j` such that `CDF(j-1) <= h < CDF(j)`.
+//!
+//! The probabilities are computed with integer fixed-point arithmetic, whose
+//! precision grows with the expected number of seats of the participant so
+//! that the probabilities of the distribution do not underflow. The number of
+//! seats is therefore the same on every platform, which is not the case of
+//! implementations relying on floating-point arithmetic.
+//!
+//! [algorand]: https://eprint.iacr.org/2017/454
+
+use crate::errors::PrimitivesError;
+use ark_std::{cmp::min, string::ToString, Zero};
+use num_bigint::BigUint;
+
+// The fractional bits on top of those needed to represent the smallest
+// probability, which bound the relative error of the probabilities.
+const GUARD_BITS: u128 = 128;
+
+// The limit of the fractional bits, reached for about 40000 expected seats of
+// a single participant.
+const MAX_FRACTIONAL_BITS: u128 = 1 << 16;
+
+/// The number of seats of a participant in a committee, selected by its VRF
+/// output.
+/// * `output` - the VRF output of the participant, on an input specific to the
+///   committee
+/// * `stake` - the stake of the participant
+/// * `total_stake` - the total stake of all participants
+/// * `expected_size` - the expected number of seats of the committee
+/// * `returns` - the number of seats, at most `stake`. Err() if `stake` or
+///   `expected_size` exceed `total_stake`, or if the participant expects too
+///   many seats for the supported precision
+pub fn sortition(
+    output: &[u8],
+    stake: u64,
+    total_stake: u64,
+    expected_size: u64,
+) -> Result<u64, PrimitivesError> {
+    if stake > total_stake || expected_size > total_stake {
+        return Err(PrimitivesError::ParameterError(
+            "stake and expected size must not exceed the total stake".to_string(),
+        ));
+    }
+    if stake == 0 || expected_size == 0 {
+        return Ok(0);
+    }
+    if expected_size == total_stake {
+        return Ok(stake);
+    }
+    let (w, e, q) = (stake, expected_size, total_stake - expected_size);
+
+    // The smallest probability `P(0)=(1-p)^w` is at least `2^{-b}`, for `b`
+    // the smaller of `1.5 w p/(1-p)` and `w log2(1/(1-p))`, both rounded up.
+    let seats_bound = (w as u128 * e as u128 + q as u128 - 1) / q as u128;
+    let log_bound = w as u128 * (bits(total_stake) - bits(q) + 1) as u128;
+    let underflow_bits = min(seats_bound * 3 / 2 + 1, log_bound);
+    if underflow_bits > MAX_FRACTIONAL_BITS {
+        return Err(PrimitivesError::ParameterError(
+            "too many expected seats for the sortition precision".to_string(),
+        ));
+    }
+    let frac_bits = (GUARD_BITS + underflow_bits) as usize;
+
+    // `h < CDF(j)` iff `output 2^frac_bits < CDF(j) 2^(8 len(output))`
+    let target = BigUint::from_bytes_be(output) << frac_bits;
+    let output_bits = 8 * output.len();
+
+    let mut prob = pow_fixed(
+        &((BigUint::from(q) << frac_bits) / total_stake),
+        w,
+        frac_bits,
+    );
+    let mut cdf = prob.clone();
+    let mut seats = 0;
+    while seats < w && target >= &cdf << output_bits {
+        // P(j+1) = P(j) (w-j) p / ((j+1) (1-p))
+        prob = prob * (w - seats) * e / (BigUint::from(seats + 1) * q);
+        seats += 1;
+        if prob.is_zero() {
+            // `h` is in the tail beyond the precision
+            break;
+        }
+        cdf += &prob;
+    }
+    Ok(seats)
+}
+
+// The number of bits of `x`.
+fn bits(x: u64) -> u64 {
+    (u64::BITS - x.leading_zeros()) as u64
+}
+
+// `base^exp`, for `base` with `frac_bits` fractional bits, truncated to
+// `frac_bits` fractional bits after each multiplication.
+fn pow_fixed(base: &BigUint, mut exp: u64, frac_bits: usize) -> BigUint {
+    let mut result = BigUint::from(1u8) << frac_bits;
+    let mut base = base.clone();
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = (result * &base) >> frac_bits;
+        }
+        exp >>= 1;
+        if exp > 0 {
+            base = (&base * &base) >> frac_bits;
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_std::{rand::RngCore, vec::Vec};
+    use jf_utils::test_rng;
+
+    fn from_hex(hex: &str) -> Vec<u8> {
+        (0..hex.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_sortition_boundaries() {
+        // `CDF(j) -/+ 2^-100` for `B(10,0.2)`, computed with exact rational
+        // arithmetic
+        let boundaries = [
+            (
+                "1b7cdfd9d7bdbab7d6ae6881bb5109a365f7e0df99d2255b971b0845d4079a21",
+                "1b7cdfd9d7bdbab7d6ae6881db5109a365f7e0df99d2255b971b0845d4079a21",
+            ),
+            (
+                "60350f7a73180d836f626dc6379ba1bbe4e3930e9a5f82c090de9cf4661a9b74",
+                "60350f7a73180d836f626dc6579ba1bbe4e3930e9a5f82c090de9cf4661a9b74",
+            ),
+            (
+                "ad84450f21ddaaa87b2cf3b3436f8cd773acbb837afe8bd229daa438ca6ffcf2",
+                "ad84450f21ddaaa87b2cf3b3636f8cd773acbb837afe8bd229daa438ca6ffcf2",
+            ),
+            (
+                "e10e68c7966168c12db3f7a6a0a77ee9d2dd8126bb6891dde52d53bbb7fe3df0",
+                "e10e68c7966168c12db3f7a6c0a77ee9d2dd8126bb6891dde52d53bbb7fe3df0",
+            ),
+            (
+                "f79ad868495b0bebfbcf0961196ff8d1dc82d79e2796f48307218084ffec7a60",
+                "f79ad868495b0bebfbcf0961396ff8d1dc82d79e2796f48307218084ffec7a60",
+            ),
+        ];
+        for (j, (below, above)) in boundaries.iter().enumerate() {
+            assert_eq!(sortition(&from_hex(below), 10, 100, 20).unwrap(), j as u64);
+            assert_eq!(
+                sortition(&from_hex(above), 10, 100, 20).unwrap(),
+                j as u64 + 1
+            );
+        }
+    }
+
+    #[test]
+    fn test_sortition_edge_cases() {
+        let zero = [0u8; 32];
+        let max = [0xffu8; 32];
+        assert_eq!(sortition(&zero, 10, 100, 20).unwrap(), 0);
+        assert_eq!(sortition(&max, 10, 100, 20).unwrap(), 10);
+        assert_eq!(sortition(&[], 10, 100, 20).unwrap(), 0);
+        assert_eq!(sortition(&max, 0, 100, 20).unwrap(), 0);
+        assert_eq!(sortition(&max, 10, 100, 0).unwrap(), 0);
+        // every unit of stake is selected
+        assert_eq!(sortition(&zero, 10, 100, 100).unwrap(), 10);
+        // a single participant
+        assert_eq!(sortition(&zero, 100, 100, 20).unwrap(), 0);
+        assert!(sortition(&max, 100, 100, 99).unwrap() <= 100);
+
+        assert!(sortition(&zero, 101, 100, 20).is_err());
+        assert!(sortition(&zero, 10, 100, 101).is_err());
+        assert!(sortition(&zero, 0, 0, 0).unwrap() == 0);
+        // too many expected seats
+        assert!(sortition(&zero, 1 << 40, 1 << 41, 1 << 20).is_err());
+    }
+
+    #[test]
+    fn test_sortition_distribution() {
+        let rng = &mut test_rng();
+
+        // the average number of seats is `w p`
+        let (n, w, total, expected) = (2000, 1000, 10000, 100);
+        let mut sum = 0;
+        for _ in 0..n {
+            let mut output = [0u8; 32];
+            rng.fill_bytes(&mut output);
+            let seats = sortition(&output, w, total, expected).unwrap();
+            assert!(seats <= w);
+            sum += seats;
+        }
+        let mean = sum as f64 / n as f64;
+        assert!((mean - 10.0).abs() < 0.5);
+
+        // the median number of seats of a large participant is close to `w p`
+        let mut output = [0u8; 32];
+        output[0] = 0x80;
+        let seats = sortition(&output, 500_000, 1_000_000, 2000).unwrap();
+        assert!((900..1100).contains(&seats));
+
+        // more stake never yields fewer seats
+        rng.fill_bytes(&mut output);
+        let mut last = 0;
+        for w in (0..=1000).step_by(100) {
+            let seats = sortition(&output, w, 10000, 100).unwrap();
+            assert!(seats >= last);
+            last = seats;
+        }
+    }
+}
@@ -0,0 +1,514 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+//! This module implements the `t`-out-of-`n` verifiable secret sharing (VSS)
+//! schemes of Feldman and Pedersen, over the scalar field of a group with
+//! generator `G`.
+//!
+//! The dealer splits the secret `s` with the [Shamir](crate::shamir) secret
+//! sharing: it samples a random polynomial `f` of degree `t-1` with `f(0)=s`,
+//! and party `i` (for `i` in `1..=n`) gets the share `f(i)`. The dealer also
+//! publishes commitments to the coefficients `a_k` of `f`, against which each
+//! party verifies its share. Any `t` shares reconstruct `s` by Lagrange
+//! interpolation at `0`.
+//!
+//! **Feldman**
+//!    * the commitments are `C_k=a_k*G`, and the share `s_i` of party `i` is
+//!      valid if `s_i*G=sum_k i^k*C_k`. `C_0=s*G` reveals the public value of
+//!      the secret, as for the key shares of [`ThresholdBLS`] and
+//!      [`ThresholdElGamal`]
+//!
+//! **Pedersen**
+//!    * the dealer samples a second random polynomial `g` with coefficients
+//!      `b_k`, and party `i` also gets `r_i=g(i)`. The commitments are
+//!      `C_k=a_k*G+b_k*H`, and the shares of party `i` are valid if
+//!      `s_i*G+r_i*H=sum_k i^k*C_k`. The commitments are hiding: they reveal
+//!      nothing about `s`, as long as the discrete logarithm of `H` is unknown
+//!
+//! The blinding base `H` of [`PedersenVss`] is sampled with a seed derived
+//! from a fixed domain separator, by rejection sampling on the coordinates of
+//! the curve points, so that no one knows its discrete logarithm.
+//!
+//! ```
+//! use jf_primitives::vss::FeldmanVss;
+//!
+//! let mut rng = jf_utils::test_rng();
+//! let vss = FeldmanVss::new(2, 3).unwrap();
+//! let secret = ark_bn254::Fr::from(42u8);
+//! let (commitment, shares) = vss.share::<ark_bn254::G1Projective, _>(&secret, &mut rng);
+//! for share in shares.iter() {
+//!     assert!(vss.verify_share(&commitment, share).is_ok());
+//! }
+//! assert_eq!(vss.reconstruct(&shares[1..]).unwrap(), secret);
+//! ```
+//!
+//! [`ThresholdBLS`]: crate::signatures::threshold_bls::ThresholdBLS
+//! [`ThresholdElGamal`]: crate::elgamal::threshold::ThresholdElGamal
+
+use crate::{
+    constants::CS_ID_PEDERSEN_VSS,
+    errors::PrimitivesError::{self, VerificationError},
+    shamir::{check_index, check_params, eval_shares, random_polynomial, reconstruct, Share},
+};
+use ark_ec::CurveGroup;
+use ark_ff::PrimeField;
+use ark_serialize::*;
+use ark_std::{
+    format,
+    rand::{CryptoRng, RngCore, SeedableRng},
+    vec::Vec,
+    UniformRand,
+};
+use digest::Digest;
+use rand_chacha::ChaCha20Rng;
+use sha2::Sha256;
+use zeroize::Zeroize;
+
+/// Parameters of a `t`-out-of-`n` Feldman VSS scheme.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct FeldmanVss {
+    threshold: u32,
+    num_parties: u32,
+}
+
+/// Parameters of a `t`-out-of-`n` Pedersen VSS scheme, with its blinding
+/// base.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PedersenVss<G: CurveGroup> {
+    threshold: u32,
+    num_parties: u32,
+    blinding_base: G,
+}
+
+/// The shares of a secret and of the blinding polynomial held by one party,
+/// in the Pedersen VSS.
+#[derive(Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct PedersenShare<F: PrimeField> {
+    share: Share<F>,
+    blinding: F,
+}
+
+impl<F: PrimeField> Drop for PedersenShare<F> {
+    fn drop(&mut self) {
+        self.blinding.zeroize();
+    }
+}
+
+/// The commitments of a dealer to the coefficients of its polynomial, in the
+/// Feldman VSS.
+#[derive(Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct FeldmanCommitment<G: CurveGroup> {
+    coeffs: Vec<G>,
+}
+
+/// The commitments of a dealer to the coefficients of its polynomials, in the
+/// Pedersen VSS.
+#[derive(Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct PedersenCommitment<G: CurveGroup> {
+    coeffs: Vec<G>,
+}
+
+impl<F: PrimeField> PedersenShare<F> {
+    /// The index of the party holding the share, in `1..=n`.
+    pub fn index(&self) -> u32 {
+        self.share.index()
+    }
+
+    /// The share of the secret.
+    pub fn share(&self) -> &Share<F> {
+        &self.share
+    }
+
+    /// The share of the blinding polynomial.
+    pub fn blinding(&self) -> &F {
+        &self.blinding
+    }
+}
+
+impl<G: CurveGroup> FeldmanCommitment<G> {
+    /// Import the commitments `coeffs` to the coefficients of a polynomial,
+    /// from the constant one.
+    pub fn new(coeffs: Vec<G>) -> Self {
+        Self { coeffs }
+    }
+
+    /// The commitments to the coefficients, from the constant one.
+    pub fn coeffs(&self) -> &[G] {
+        &self.coeffs
+    }
+
+    /// The commitment `s*G` to the secret `s`.
+    /// * `returns` - the identity if there are no commitments
+    pub fn secret_commitment(&self) -> G {
+        self.coeffs.first().copied().unwrap_or_else(G::zero)
+    }
+
+    /// The commitment `s_i*G` to the share `s_i` of the party of index `index`,
+    /// which is its public verification share.
+    pub fn eval(&self, index: u32) -> G {
+        eval_commitments(&self.coeffs, index)
+    }
+}
+
+impl<G: CurveGroup> PedersenCommitment<G> {
+    /// The commitments to the coefficients, from the constant one.
+    pub fn coeffs(&self) -> &[G] {
+        &self.coeffs
+    }
+
+    /// The commitment `s_i*G+r_i*H` to the shares of the party of index
+    /// `index`.
+    pub fn eval(&self, index: u32) -> G {
+        eval_commitments(&self.coeffs, index)
+    }
+}
+
+impl FeldmanVss {
+    /// Setup a scheme where any `threshold` out of `num_parties` shares
+    /// reconstruct the secret.
+    /// * `returns` - Err() if `threshold` is zero or greater than `num_parties`
+    pub fn new(threshold: u32, num_parties: u32) -> Result<Self, PrimitivesError> {
+        check_params(threshold, num_parties)?;
+        Ok(Self {
+            threshold,
+            num_parties,
+        })
+    }
+
+    /// The number of shares needed to reconstruct the secret.
+    pub fn threshold(&self) -> u32 {
+        self.threshold
+    }
+
+    /// The number of parties holding a share.
+    pub fn num_parties(&self) -> u32 {
+        self.num_parties
+    }
+
+    /// Share a secret between the parties.
+    /// * `returns` - the commitments of the dealer, and the shares of the
+    ///   parties, ordered by index
+    pub fn share<G: CurveGroup, R: CryptoRng + RngCore>(
+        &self,
+        secret: &G::ScalarField,
+        prng: &mut R,
+    ) -> (FeldmanCommitment<G>, Vec<Share<G::ScalarField>>) {
+        let mut coeffs = random_polynomial(secret, self.threshold, prng);
+        let commitment = FeldmanCommitment {
+            coeffs: coeffs.iter().map(|a| G::generator() * a).collect(),
+        };
+        let shares = eval_shares(&coeffs, self.num_parties);
+        coeffs.zeroize();
+        (commitment, shares)
+    }
+
+    /// Verify a share against the commitments of its dealer.
+    /// * `returns` - Err() if the commitments do not have `threshold`
+    ///   coefficients, if the index of the share is out of range, or if the
+    ///   share does not match the commitments
+    pub fn verify_share<G: CurveGroup>(
+        &self,
+        commitment: &FeldmanCommitment<G>,
+        share: &Share<G::ScalarField>,
+    ) -> Result<(), PrimitivesError> {
+        check_commitment(commitment.coeffs.len(), self.threshold)?;
+        check_index(share.index(), self.num_parties)?;
+        if G::generator() * share.value() != commitment.eval(share.index()) {
+            return Err(VerificationError(format!(
+                "share of party {} does not match the commitments",
+                share.index()
+            )));
+        }
+        Ok(())
+    }
+
+    /// Reconstruct the secret from shares. Only the first `threshold` shares
+    /// are used.
+    /// * `returns` - Err() if there are fewer than `threshold` shares, or if an
+    ///   index is out of range or repeated
+    pub fn reconstruct<F: PrimeField>(&self, shares: &[Share<F>]) -> Result<F, PrimitivesError> {
+        reconstruct(shares, self.threshold, self.num_parties)
+    }
+}
+
+impl<G: CurveGroup> PedersenVss<G> {
+    /// Setup a scheme where any `threshold` out of `num_parties` shares
+    /// reconstruct the secret.
+    /// * `returns` - Err() if `threshold` is zero or greater than `num_parties`
+    pub fn new(threshold: u32, num_parties: u32) -> Result<Self, PrimitivesError> {
+        check_params(threshold, num_parties)?;
+        let mut seed = [0u8; 32];
+        seed.copy_from_slice(Sha256::digest(CS_ID_PEDERSEN_VSS.as_bytes()).as_ref());
+        Ok(Self {
+            threshold,
+            num_parties,
+            blinding_base: G::rand(&mut ChaCha20Rng::from_seed(seed)),
+        })
+    }
+
+    /// The number of shares needed to reconstruct the secret.
+    pub fn threshold(&self) -> u32 {
+        self.threshold
+    }
+
+    /// The number of parties holding a share.
+    pub fn num_parties(&self) -> u32 {
+        self.num_parties
+    }
+
+    /// The blinding base `H` of the commitments.
+    pub fn blinding_base(&self) -> &G {
+        &self.blinding_base
+    }
+
+    /// Share a secret between the parties.
+    /// * `returns` - the commitments of the dealer, and the shares of the
+    ///   parties, ordered by index
+    pub fn share<R: CryptoRng + RngCore>(
+        &self,
+        secret: &G::ScalarField,
+        prng: &mut R,
+    ) -> (PedersenCommitment<G>, Vec<PedersenShare<G::ScalarField>>) {
+        let mut coeffs = random_polynomial(secret, self.threshold, prng);
+        let mut blinding_coeffs =
+            random_polynomial(&G::ScalarField::rand(prng), self.threshold, prng);
+        let commitment = PedersenCommitment {
+            coeffs: coeffs
+                .iter()
+                .zip(blinding_coeffs.iter())
+                .map(|(a, b)| G::generator() * a + self.blinding_base * b)
+                .collect(),
+        };
+        let shares = eval_shares(&coeffs, self.num_parties)
+            .into_iter()
+            .zip(eval_shares(&blinding_coeffs, self.num_parties).iter())
+            .map(|(share, blinding)| PedersenShare {
+                share,
+                blinding: *blinding.value(),
+            })
+            .collect();
+        coeffs.zeroize();
+        blinding_coeffs.zeroize();
+        (commitment, shares)
+    }
+
+    /// Verify the shares of a party against the commitments of its dealer.
+    /// * `returns` - Err() if the commitments do not have `threshold`
+    ///   coefficients, if the index of the shares is out of range, or if the
+    ///   shares do not match the commitments
+    pub fn verify_share(
+        &self,
+        commitment: &PedersenCommitment<G>,
+        share: &PedersenShare<G::ScalarField>,
+    ) -> Result<(), PrimitivesError> {
+        check_commitment(commitment.coeffs.len(), self.threshold)?;
+        let index = share.index();
+        check_index(index, self.num_parties)?;
+        if G::generator() * share.share.value() + self.blinding_base * share.blinding
+            != commitment.eval(index)
+        {
+            return Err(VerificationError(format!(
+                "shares of party {index} do not match the commitments"
+            )));
+        }
+        Ok(())
+    }
+
+    /// Reconstruct the secret from shares. Only the first `threshold` shares
+    /// are used.
+    /// * `returns` - Err() if there are fewer than `threshold` shares, or if an
+    ///   index is out of range or repeated
+    pub fn reconstruct(
+        &self,
+        shares: &[PedersenShare<G::ScalarField>],
+    ) -> Result<G::ScalarField, PrimitivesError> {
+        let shares: Vec<_> = shares.iter().map(|share| share.share.clone()).collect();
+        reconstruct(&shares, self.threshold, self.num_parties)
+    }
+}
+
+// Check that a commitment with `len` coefficients is for a polynomial of
+// degree `threshold-1`.
+fn check_commitment(len: usize, threshold: u32) -> Result<(), PrimitivesError> {
+    if len != threshold as usize {
+        return Err(VerificationError(format!(
+            "{len} commitments for a threshold of {threshold}"
+        )));
+    }
+    Ok(())
+}
+
+// `sum_k index^k*C_k`, the commitment to the evaluation at `index` of the
+// polynomial committed to by `coeffs`.
+fn eval_commitments<G: CurveGroup>(coeffs: &[G], index: u32) -> G {
+    let x = G::ScalarField::from(index);
+    coeffs
+        .iter()
+        .rev()
+        .fold(G::zero(), |acc, coeff| acc * x + coeff)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_ec::twisted_edwards::Projective;
+    use ark_ed_on_bls12_377::EdwardsConfig as Param377;
+    use ark_ed_on_bls12_381::EdwardsConfig as Param381;
+    use ark_ed_on_bls12_381_bandersnatch::EdwardsConfig as Param381b;
+    use ark_ed_on_bn254::EdwardsConfig as Param254;
+    use ark_std::vec;
+
+    fn test_feldman_vss_helper<G: CurveGroup>() {
+        let mut rng = jf_utils::test_rng();
+        let vss = FeldmanVss::new(3, 5).unwrap();
+        let secret = G::ScalarField::rand(&mut rng);
+        let (commitment, shares) = vss.share::<G, _>(&secret, &mut rng);
+        assert_eq!(commitment.coeffs().len(), 3);
+        assert_eq!(commitment.secret_commitment(), G::generator() * secret);
+        assert_eq!(shares.len(), 5);
+        for (share, index) in shares.iter().zip(1..) {
+            assert_eq!(share.index(), index);
+            assert!(vss.verify_share(&commitment, share).is_ok());
+            assert_eq!(commitment.eval(index), G::generator() * share.value());
+        }
+
+        // invalid shares and commitments
+        let wrong = Share::new(1, *shares[0].value() + G::ScalarField::from(1u8)).unwrap();
+        assert!(vss.verify_share(&commitment, &wrong).is_err());
+        let moved = Share::new(2, *shares[0].value()).unwrap();
+        assert!(vss.verify_share(&commitment, &moved).is_err());
+        let out_of_range = Share::new(6, *shares[0].value()).unwrap();
+        assert!(vss.verify_share(&commitment, &out_of_range).is_err());
+        let truncated = FeldmanCommitment::new(commitment.coeffs()[..2].to_vec());
+        assert!(vss.verify_share(&truncated, &shares[0]).is_err());
+        let (other, _) = vss.share::<G, _>(&secret, &mut rng);
+        assert!(vss.verify_share(&other, &shares[0]).is_err());
+
+        // any `t` shares reconstruct the secret
+        for subset in [
+            vec![0, 1, 2],
+            vec![4, 2, 0],
+            vec![1, 3, 4],
+            vec![3, 2, 1, 0],
+        ] {
+            let subset: Vec<_> = subset.into_iter().map(|i| shares[i].clone()).collect();
+            assert_eq!(vss.reconstruct(&subset).unwrap(), secret);
+        }
+        assert!(vss.reconstruct(&shares[..2]).is_err());
+        let repeated = vec![shares[0].clone(), shares[1].clone(), shares[0].clone()];
+        assert!(vss.reconstruct(&repeated).is_err());
+        let wrong = vec![wrong, shares[1].clone(), shares[2].clone()];
+        assert_ne!(vss.reconstruct(&wrong).unwrap(), secret);
+        let out_of_range = vec![out_of_range, shares[1].clone(), shares[2].clone()];
+        assert!(vss.reconstruct(&out_of_range).is_err());
+    }
+
+    fn test_pedersen_vss_helper<G: CurveGroup>() {
+        let mut rng = jf_utils::test_rng();
+        let vss = PedersenVss::<G>::new(3, 5).unwrap();
+        assert!(!vss.blinding_base().is_zero());
+        assert_ne!(vss.blinding_base(), &G::generator());
+        assert_eq!(vss, PedersenVss::<G>::new(3, 5).unwrap());
+
+        let secret = G::ScalarField::rand(&mut rng);
+        let (commitment, shares) = vss.share(&secret, &mut rng);
+        assert_eq!(commitment.coeffs().len(), 3);
+        // the commitments hide the secret
+        assert_ne!(commitment.coeffs()[0], G::generator() * secret);
+        for (share, index) in shares.iter().zip(1..) {
+            assert_eq!(share.index(), index);
+            assert!(vss.verify_share(&commitment, share).is_ok());
+            assert_eq!(
+                commitment.eval(index),
+                G::generator() * share.share().value() + *vss.blinding_base() * share.blinding()
+            );
+        }
+
+        let mut wrong = shares[0].clone();
+        wrong.blinding += G::ScalarField::from(1u8);
+        assert!(vss.verify_share(&commitment, &wrong).is_err());
+        let (other, _) = vss.share(&secret, &mut rng);
+        assert!(vss.verify_share(&other, &shares[0]).is_err());
+
+        assert_eq!(vss.reconstruct(&shares[2..]).unwrap(), secret);
+        assert_eq!(vss.reconstruct(&shares[..3]).unwrap(), secret);
+        assert!(vss.reconstruct(&shares[..2]).is_err());
+    }
+
+    #[test]
+    fn test_feldman_vss() {
+        test_feldman_vss_helper::<ark_bn254::G1Projective>();
+        test_feldman_vss_helper::<ark_bn254::G2Projective>();
+        test_feldman_vss_helper::<Projective<Param254>>();
+        test_feldman_vss_helper::<Projective<Param377>>();
+        test_feldman_vss_helper::<Projective<Param381>>();
+        test_feldman_vss_helper::<Projective<Param381b>>();
+    }
+
+    #[test]
+    fn test_pedersen_vss() {
+        test_pedersen_vss_helper::<ark_bn254::G1Projective>();
+        test_pedersen_vss_helper::<Projective<Param254>>();
+        test_pedersen_vss_helper::<Projective<Param377>>();
+        test_pedersen_vss_helper::<Projective<Param381>>();
+        test_pedersen_vss_helper::<Projective<Param381b>>();
+    }
+
+    #[test]
+    fn test_vss_params() {
+        let mut rng = jf_utils::test_rng();
+        assert!(FeldmanVss::new(0, 3).is_err());
+        assert!(FeldmanVss::new(4, 3).is_err());
+        assert!(PedersenVss::<ark_bn254::G1Projective>::new(0, 3).is_err());
+        assert!(PedersenVss::<ark_bn254::G1Projective>::new(4, 3).is_err());
+        let vss = FeldmanVss::new(2, 3).unwrap();
+        assert_eq!(vss.threshold(), 2);
+        assert_eq!(vss.num_parties(), 3);
+
+        // 1-out-of-n shares are copies of the secret
+        let vss = FeldmanVss::new(1, 3).unwrap();
+        let secret = ark_bn254::Fr::from(7u8);
+        let (_, shares) = vss.share::<ark_bn254::G1Projective, _>(&secret, &mut rng);
+        assert!(shares.iter().all(|share| share.value() == &secret));
+        assert_eq!(vss.reconstruct(&shares[2..]).unwrap(), secret);
+    }
+
+    #[test]
+    fn test_serde() {
+        let mut rng = jf_utils::test_rng();
+        let vss = PedersenVss::<Projective<Param254>>::new(2, 3).unwrap();
+        let (commitment, shares) = vss.share(&ark_ed_on_bn254::Fr::from(3u8), &mut rng);
+
+        let mut bytes = Vec::new();
+        commitment.serialize_compressed(&mut bytes).unwrap();
+        assert_eq!(
+            PedersenCommitment::deserialize_compressed(&bytes[..]).unwrap(),
+            commitment
+        );
+        let mut bytes = Vec::new();
+        shares[0].serialize_compressed(&mut bytes).unwrap();
+        assert_eq!(
+            PedersenShare::deserialize_compressed(&bytes[..]).unwrap(),
+            shares[0]
+        );
+
+        let vss = FeldmanVss::new(2, 3).unwrap();
+        let (commitment, shares) =
+            vss.share::<Projective<Param254>, _>(&ark_ed_on_bn254::Fr::from(3u8), &mut rng);
+        let mut bytes = Vec::new();
+        commitment.serialize_compressed(&mut bytes).unwrap();
+        assert_eq!(
+            FeldmanCommitment::deserialize_compressed(&bytes[..]).unwrap(),
+            commitment
+        );
+        let mut bytes = Vec::new();
+        shares[0].serialize_compressed(&mut bytes).unwrap();
+        assert_eq!(
+            Share::deserialize_compressed(&bytes[..]).unwrap(),
+            shares[0]
+        );
+    }
+}